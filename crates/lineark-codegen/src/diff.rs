@@ -0,0 +1,790 @@
+//! Schema diffing for `lineark-codegen diff old.graphql new.graphql`.
+//!
+//! Compares two schemas type by type and classifies every added, removed or
+//! changed type, field, argument and enum value as breaking or not for
+//! consumers of the generated SDK. A change is breaking when code that
+//! compiled against the old SDK may stop compiling or lose data:
+//!
+//! - anything removed, or an object that stops implementing an interface;
+//! - a field type changed, except output nullability, since generated object
+//!   fields are always `Option`;
+//! - a required argument or input field added, as it becomes a required
+//!   parameter or struct field.
+//!
+//! Added enum values, union members and interface implementations are not
+//! breaking: generated enums are `#[non_exhaustive]`, and older SDKs decode
+//! the new values as `Unknown`.
+
+use crate::emit_queries::gql_type_string;
+use crate::parser::{FieldDef, GqlType, ParsedSchema};
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::fmt;
+
+/// Whether a change can break code written against the old SDK.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Breaking,
+    NonBreaking,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeKind {
+    Added,
+    Removed,
+    Changed,
+}
+
+/// A single difference between two schemas.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Change {
+    pub severity: Severity,
+    pub kind: ChangeKind,
+    /// What changed: `type`, `field`, `argument`, `enum value`, ….
+    pub subject: &'static str,
+    /// `Type`, `Type.field`, `Type.field(argument)` or `Enum.VALUE`.
+    pub path: String,
+    /// Old and new type, reason, etc.
+    pub detail: Option<String>,
+}
+
+impl Change {
+    /// The type the change belongs to.
+    fn type_name(&self) -> &str {
+        self.path.split(['.', '(']).next().unwrap_or(&self.path)
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "breaking": self.severity == Severity::Breaking,
+            "kind": match self.kind {
+                ChangeKind::Added => "added",
+                ChangeKind::Removed => "removed",
+                ChangeKind::Changed => "changed",
+            },
+            "subject": self.subject,
+            "path": self.path,
+            "detail": self.detail,
+        })
+    }
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = match self.kind {
+            ChangeKind::Added => "added",
+            ChangeKind::Removed => "removed",
+            ChangeKind::Changed => "changed",
+        };
+        write!(f, "{} {} `{}`", kind, self.subject, self.path)?;
+        if let Some(detail) = &self.detail {
+            write!(f, ": {}", detail)?;
+        }
+        Ok(())
+    }
+}
+
+/// An `operations.toml` entry touched by breaking changes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AffectedOperation {
+    /// `queries`, `mutations` or `subscriptions`.
+    pub section: &'static str,
+    pub name: String,
+    /// Paths of the breaking changes that reach the operation.
+    pub changes: Vec<String>,
+}
+
+/// The result of comparing two schemas.
+#[derive(Debug, Default)]
+pub struct SchemaDiff {
+    pub changes: Vec<Change>,
+    pub affected: Vec<AffectedOperation>,
+}
+
+impl SchemaDiff {
+    fn with_severity(&self, severity: Severity) -> impl Iterator<Item = &Change> {
+        self.changes.iter().filter(move |c| c.severity == severity)
+    }
+
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "breaking": self.with_severity(Severity::Breaking).count(),
+            "non_breaking": self.with_severity(Severity::NonBreaking).count(),
+            "changes": self.changes.iter().map(Change::to_json).collect::<Vec<_>>(),
+            "affected_operations": self
+                .affected
+                .iter()
+                .map(|op| serde_json::json!({
+                    "section": op.section,
+                    "name": op.name,
+                    "changes": op.changes,
+                }))
+                .collect::<Vec<_>>(),
+        })
+    }
+}
+
+impl fmt::Display for SchemaDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.changes.is_empty() {
+            return writeln!(f, "No schema changes.");
+        }
+        for (title, severity, marker) in [
+            ("Breaking changes", Severity::Breaking, '!'),
+            ("Non-breaking changes", Severity::NonBreaking, '+'),
+        ] {
+            let changes: Vec<&Change> = self.with_severity(severity).collect();
+            writeln!(f, "{} ({}):", title, changes.len())?;
+            for change in changes {
+                writeln!(f, "  {} {}", marker, change)?;
+            }
+            writeln!(f)?;
+        }
+        writeln!(
+            f,
+            "Affected operations.toml entries ({}):",
+            self.affected.len()
+        )?;
+        for op in &self.affected {
+            writeln!(f, "  {}.{}: {}", op.section, op.name, op.changes.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+/// The parts of a type definition that matter to the generated SDK.
+enum Shape<'a> {
+    Scalar,
+    Enum(Vec<(&'a str, &'a Option<String>)>),
+    Object {
+        fields: &'a [FieldDef],
+        interfaces: &'a [String],
+    },
+    Interface {
+        fields: &'a [FieldDef],
+        interfaces: &'a [String],
+    },
+    Union(&'a [String]),
+    Input(&'a [FieldDef]),
+}
+
+impl Shape<'_> {
+    fn kind(&self) -> &'static str {
+        match self {
+            Shape::Scalar => "scalar",
+            Shape::Enum(_) => "enum",
+            Shape::Object { .. } => "object",
+            Shape::Interface { .. } => "interface",
+            Shape::Union(_) => "union",
+            Shape::Input(_) => "input",
+        }
+    }
+}
+
+/// Root operation types, with their `operations.toml` section.
+const ROOTS: [(&str, &str); 3] = [
+    ("Query", "queries"),
+    ("Mutation", "mutations"),
+    ("Subscription", "subscriptions"),
+];
+
+fn shapes(schema: &ParsedSchema) -> BTreeMap<&str, Shape<'_>> {
+    let mut shapes = BTreeMap::new();
+    for s in &schema.scalars {
+        shapes.insert(s.name.as_str(), Shape::Scalar);
+    }
+    for e in &schema.enums {
+        let values = e
+            .values
+            .iter()
+            .map(|v| (v.name.as_str(), &v.deprecated))
+            .collect();
+        shapes.insert(e.name.as_str(), Shape::Enum(values));
+    }
+    for o in &schema.objects {
+        shapes.insert(
+            o.name.as_str(),
+            Shape::Object {
+                fields: &o.fields,
+                interfaces: &o.interfaces,
+            },
+        );
+    }
+    for i in &schema.interfaces {
+        shapes.insert(
+            i.name.as_str(),
+            Shape::Interface {
+                fields: &i.fields,
+                interfaces: &i.interfaces,
+            },
+        );
+    }
+    for u in &schema.unions {
+        shapes.insert(u.name.as_str(), Shape::Union(&u.members));
+    }
+    for i in &schema.inputs {
+        shapes.insert(i.name.as_str(), Shape::Input(&i.fields));
+    }
+    for ((root, _), fields) in ROOTS.iter().zip([
+        &schema.query_fields,
+        &schema.mutation_fields,
+        &schema.subscription_fields,
+    ]) {
+        if !fields.is_empty() {
+            shapes.insert(
+                *root,
+                Shape::Object {
+                    fields,
+                    interfaces: &[],
+                },
+            );
+        }
+    }
+    shapes
+}
+
+/// Compare `old` with `new`. `allowed` holds the enabled operation names for
+/// each `operations.toml` section.
+pub fn diff(
+    old: &ParsedSchema,
+    new: &ParsedSchema,
+    allowed: &[(&str, &HashSet<String>)],
+) -> SchemaDiff {
+    let old_shapes = shapes(old);
+    let new_shapes = shapes(new);
+    let mut changes = Vec::new();
+
+    let names: BTreeSet<&str> = old_shapes
+        .keys()
+        .chain(new_shapes.keys())
+        .copied()
+        .collect();
+    for name in names {
+        match (old_shapes.get(name), new_shapes.get(name)) {
+            (Some(old), None) => changes.push(Change {
+                severity: Severity::Breaking,
+                kind: ChangeKind::Removed,
+                subject: old.kind(),
+                path: name.to_string(),
+                detail: None,
+            }),
+            (None, Some(new)) => changes.push(Change {
+                severity: Severity::NonBreaking,
+                kind: ChangeKind::Added,
+                subject: new.kind(),
+                path: name.to_string(),
+                detail: None,
+            }),
+            (Some(old), Some(new)) => diff_shape(name, old, new, &mut changes),
+            (None, None) => unreachable!(),
+        }
+    }
+
+    let affected = affected_operations(&old_shapes, &changes, allowed);
+    SchemaDiff { changes, affected }
+}
+
+fn diff_shape(name: &str, old: &Shape, new: &Shape, changes: &mut Vec<Change>) {
+    match (old, new) {
+        (Shape::Scalar, Shape::Scalar) => {}
+        (Shape::Enum(old), Shape::Enum(new)) => {
+            let old_values: BTreeMap<&str, &Option<String>> = old.iter().copied().collect();
+            let new_values: BTreeMap<&str, &Option<String>> = new.iter().copied().collect();
+            diff_named(
+                name,
+                &old_values,
+                &new_values,
+                "enum value",
+                |_| Severity::NonBreaking,
+                |path, old, new, changes| diff_deprecation("enum value", path, old, new, changes),
+                changes,
+            );
+        }
+        (
+            Shape::Object {
+                fields: old_fields,
+                interfaces: old_interfaces,
+            },
+            Shape::Object {
+                fields: new_fields,
+                interfaces: new_interfaces,
+            },
+        )
+        | (
+            Shape::Interface {
+                fields: old_fields,
+                interfaces: old_interfaces,
+            },
+            Shape::Interface {
+                fields: new_fields,
+                interfaces: new_interfaces,
+            },
+        ) => {
+            diff_members(name, "interface", old_interfaces, new_interfaces, changes);
+            diff_fields(name, old_fields, new_fields, false, changes);
+        }
+        (Shape::Union(old), Shape::Union(new)) => {
+            diff_members(name, "union member", old, new, changes);
+        }
+        (Shape::Input(old), Shape::Input(new)) => {
+            diff_fields(name, old, new, true, changes);
+        }
+        (old, new) => changes.push(Change {
+            severity: Severity::Breaking,
+            kind: ChangeKind::Changed,
+            subject: "type",
+            path: name.to_string(),
+            detail: Some(format!("{} → {}", old.kind(), new.kind())),
+        }),
+    }
+}
+
+/// Added and removed entries of a keyed collection, with `on_both` called
+/// for entries present in both.
+fn diff_named<T>(
+    parent: &str,
+    old: &BTreeMap<&str, T>,
+    new: &BTreeMap<&str, T>,
+    subject: &'static str,
+    added_severity: impl Fn(&T) -> Severity,
+    mut on_both: impl FnMut(String, &T, &T, &mut Vec<Change>),
+    changes: &mut Vec<Change>,
+) {
+    for (name, old_item) in old {
+        let path = format!("{}.{}", parent, name);
+        match new.get(name) {
+            Some(new_item) => on_both(path, old_item, new_item, changes),
+            None => changes.push(Change {
+                severity: Severity::Breaking,
+                kind: ChangeKind::Removed,
+                subject,
+                path,
+                detail: None,
+            }),
+        }
+    }
+    for (name, new_item) in new {
+        if !old.contains_key(name) {
+            changes.push(Change {
+                severity: added_severity(new_item),
+                kind: ChangeKind::Added,
+                subject,
+                path: format!("{}.{}", parent, name),
+                detail: None,
+            });
+        }
+    }
+}
+
+/// Interfaces implemented or union members: only removals break.
+fn diff_members(
+    parent: &str,
+    subject: &'static str,
+    old: &[String],
+    new: &[String],
+    changes: &mut Vec<Change>,
+) {
+    let old: BTreeMap<&str, ()> = old.iter().map(|m| (m.as_str(), ())).collect();
+    let new: BTreeMap<&str, ()> = new.iter().map(|m| (m.as_str(), ())).collect();
+    diff_named(
+        parent,
+        &old,
+        &new,
+        subject,
+        |_| Severity::NonBreaking,
+        |_, _, _, _| {},
+        changes,
+    );
+}
+
+fn diff_fields(
+    parent: &str,
+    old: &[FieldDef],
+    new: &[FieldDef],
+    is_input: bool,
+    changes: &mut Vec<Change>,
+) {
+    let old: BTreeMap<&str, &FieldDef> = old.iter().map(|f| (f.name.as_str(), f)).collect();
+    let new: BTreeMap<&str, &FieldDef> = new.iter().map(|f| (f.name.as_str(), f)).collect();
+    diff_named(
+        parent,
+        &old,
+        &new,
+        if is_input { "input field" } else { "field" },
+        |f| added_severity(&f.ty, is_input),
+        |path, old, new, changes| {
+            let subject = if is_input { "input field" } else { "field" };
+            diff_type(subject, &path, &old.ty, &new.ty, is_input, changes);
+            diff_deprecation(
+                subject,
+                path.clone(),
+                &old.deprecated,
+                &new.deprecated,
+                changes,
+            );
+            diff_arguments(&path, old, new, changes);
+        },
+        changes,
+    );
+}
+
+fn diff_arguments(field_path: &str, old: &FieldDef, new: &FieldDef, changes: &mut Vec<Change>) {
+    let old_args: BTreeMap<&str, _> = old.arguments.iter().map(|a| (a.name.as_str(), a)).collect();
+    let new_args: BTreeMap<&str, _> = new.arguments.iter().map(|a| (a.name.as_str(), a)).collect();
+    for (name, old_arg) in &old_args {
+        let path = format!("{}({})", field_path, name);
+        match new_args.get(name) {
+            Some(new_arg) => {
+                diff_type("argument", &path, &old_arg.ty, &new_arg.ty, true, changes);
+                diff_deprecation(
+                    "argument",
+                    path,
+                    &old_arg.deprecated,
+                    &new_arg.deprecated,
+                    changes,
+                );
+            }
+            None => changes.push(Change {
+                severity: Severity::Breaking,
+                kind: ChangeKind::Removed,
+                subject: "argument",
+                path,
+                detail: None,
+            }),
+        }
+    }
+    for (name, new_arg) in &new_args {
+        if !old_args.contains_key(name) {
+            changes.push(Change {
+                severity: added_severity(&new_arg.ty, true),
+                kind: ChangeKind::Added,
+                subject: "argument",
+                path: format!("{}({})", field_path, name),
+                detail: Some(gql_type_string(&new_arg.ty)),
+            });
+        }
+    }
+}
+
+/// Adding a required input field or argument breaks callers; anything else
+/// can be added freely.
+fn added_severity(ty: &GqlType, is_input: bool) -> Severity {
+    if is_input && matches!(ty, GqlType::NonNull(_)) {
+        Severity::Breaking
+    } else {
+        Severity::NonBreaking
+    }
+}
+
+fn diff_type(
+    subject: &'static str,
+    path: &str,
+    old: &GqlType,
+    new: &GqlType,
+    is_input: bool,
+    changes: &mut Vec<Change>,
+) {
+    let (old_str, new_str) = (gql_type_string(old), gql_type_string(new));
+    if old_str == new_str {
+        return;
+    }
+    // Generated object fields are `Option` whatever their nullability.
+    let same_rust_type =
+        !is_input && old_str.trim_end_matches('!') == new_str.trim_end_matches('!');
+    changes.push(Change {
+        severity: if same_rust_type {
+            Severity::NonBreaking
+        } else {
+            Severity::Breaking
+        },
+        kind: ChangeKind::Changed,
+        subject,
+        path: path.to_string(),
+        detail: Some(format!("{} → {}", old_str, new_str)),
+    });
+}
+
+fn diff_deprecation(
+    subject: &'static str,
+    path: String,
+    old: &Option<String>,
+    new: &Option<String>,
+    changes: &mut Vec<Change>,
+) {
+    if let (None, Some(reason)) = (old, new) {
+        changes.push(Change {
+            severity: Severity::NonBreaking,
+            kind: ChangeKind::Changed,
+            subject,
+            path,
+            detail: Some(format!("deprecated: {}", reason)),
+        });
+    }
+}
+
+/// Enabled operations that a breaking change reaches: through the operation
+/// itself, its argument types, its return type or, for connections and
+/// mutation payloads, the objects they wrap.
+fn affected_operations(
+    old_shapes: &BTreeMap<&str, Shape>,
+    changes: &[Change],
+    allowed: &[(&str, &HashSet<String>)],
+) -> Vec<AffectedOperation> {
+    let breaking: Vec<&Change> = changes
+        .iter()
+        .filter(|c| c.severity == Severity::Breaking)
+        .collect();
+    let mut affected = Vec::new();
+    for (root, section) in ROOTS {
+        let Some((_, allowed)) = allowed.iter().find(|(s, _)| *s == section) else {
+            continue;
+        };
+        let Some(Shape::Object { fields, .. }) = old_shapes.get(root) else {
+            continue;
+        };
+        let mut names: Vec<&String> = allowed.iter().collect();
+        names.sort();
+        for name in names {
+            let own = format!("{}.{}", root, name);
+            let types = fields
+                .iter()
+                .find(|f| &f.name == name)
+                .map(|f| operation_types(f, old_shapes))
+                .unwrap_or_default();
+            let paths: Vec<String> = breaking
+                .iter()
+                .filter(|c| {
+                    c.path == own
+                        || c.path.starts_with(&format!("{}(", own))
+                        || types.contains(c.type_name())
+                })
+                .map(|c| c.path.clone())
+                .collect();
+            if !paths.is_empty() {
+                affected.push(AffectedOperation {
+                    section,
+                    name: name.clone(),
+                    changes: paths,
+                });
+            }
+        }
+    }
+    affected
+}
+
+fn operation_types<'a>(
+    field: &'a FieldDef,
+    shapes: &BTreeMap<&str, Shape<'a>>,
+) -> HashSet<&'a str> {
+    let mut types = HashSet::new();
+    let ret = field.ty.base_name();
+    types.insert(ret);
+    // Generated types select only their own scalar and enum fields, but
+    // connections and payloads exist to carry the objects they wrap.
+    let wraps = ret.ends_with("Connection") || ret.ends_with("Payload");
+    if let Some(Shape::Object { fields, .. }) = shapes.get(ret).filter(|_| wraps) {
+        for f in fields.iter() {
+            let base = f.ty.base_name();
+            if matches!(
+                shapes.get(base),
+                Some(Shape::Object { .. } | Shape::Interface { .. })
+            ) {
+                types.insert(base);
+            }
+        }
+    }
+    // Input types, transitively through their fields.
+    let mut stack: Vec<&str> = field.arguments.iter().map(|a| a.ty.base_name()).collect();
+    while let Some(name) = stack.pop() {
+        if !types.insert(name) {
+            continue;
+        }
+        if let Some(Shape::Input(fields)) = shapes.get(name) {
+            stack.extend(fields.iter().map(|f| f.ty.base_name()));
+        }
+    }
+    types
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser;
+
+    const OLD: &str = r#"
+        scalar DateTime
+        enum Status { todo done }
+        interface Node { id: ID! }
+        type Issue implements Node {
+            id: ID!
+            title: String
+            priority: Int!
+            estimate: Float
+            legacy: String
+        }
+        type Team { id: ID! name: String! }
+        union SearchResult = Issue | Team
+        input IssueFilter { title: String }
+        input IssueCreateInput { title: String! teamId: String! }
+        type IssuePayload { success: Boolean! issue: Issue }
+        type IssueConnection { nodes: [Issue!]! }
+        type Query {
+            issues(filter: IssueFilter, first: Int): IssueConnection!
+            team(id: String!): Team!
+            roadmap: Team
+        }
+        type Mutation {
+            issueCreate(input: IssueCreateInput!): IssuePayload!
+        }
+    "#;
+
+    const NEW: &str = r#"
+        scalar DateTime
+        enum Status { todo done canceled }
+        interface Node { id: ID! }
+        type Issue {
+            id: ID!
+            title: String!
+            priority: Float!
+            estimate: Float @deprecated(reason: "Use points.")
+            points: Float
+        }
+        type Team { id: ID! name: String! }
+        union SearchResult = Issue
+        input IssueFilter { title: String teamId: String }
+        input IssueCreateInput { title: String! teamId: String! stateId: String! }
+        type IssuePayload { success: Boolean! issue: Issue }
+        type IssueConnection { nodes: [Issue!]! }
+        type Initiative { id: ID! }
+        type Query {
+            issues(filter: IssueFilter, first: Int, orderBy: String): IssueConnection!
+            team(id: ID!): Team!
+        }
+        type Mutation {
+            issueCreate(input: IssueCreateInput!): IssuePayload!
+        }
+    "#;
+
+    fn diffed() -> SchemaDiff {
+        let queries: HashSet<String> = ["issues", "team"].map(String::from).into();
+        let mutations: HashSet<String> = ["issueCreate"].map(String::from).into();
+        diff(
+            &parser::parse(OLD),
+            &parser::parse(NEW),
+            &[("queries", &queries), ("mutations", &mutations)],
+        )
+    }
+
+    fn find<'a>(diff: &'a SchemaDiff, path: &str) -> Vec<&'a Change> {
+        diff.changes.iter().filter(|c| c.path == path).collect()
+    }
+
+    fn severity(diff: &SchemaDiff, path: &str) -> Severity {
+        let changes = find(diff, path);
+        assert_eq!(
+            changes.len(),
+            1,
+            "expected one change at {path}: {changes:?}"
+        );
+        changes[0].severity
+    }
+
+    #[test]
+    fn classifies_field_changes() {
+        let diff = diffed();
+        assert_eq!(severity(&diff, "Issue.legacy"), Severity::Breaking);
+        assert_eq!(severity(&diff, "Issue.priority"), Severity::Breaking);
+        // Generated object fields are `Option` either way.
+        assert_eq!(severity(&diff, "Issue.title"), Severity::NonBreaking);
+        assert_eq!(severity(&diff, "Issue.points"), Severity::NonBreaking);
+        assert_eq!(
+            find(&diff, "Issue.estimate")[0].detail.as_deref(),
+            Some("deprecated: Use points.")
+        );
+        assert_eq!(severity(&diff, "Issue.Node"), Severity::Breaking);
+    }
+
+    #[test]
+    fn classifies_type_enum_and_union_changes() {
+        let diff = diffed();
+        assert_eq!(severity(&diff, "Initiative"), Severity::NonBreaking);
+        assert_eq!(severity(&diff, "Status.canceled"), Severity::NonBreaking);
+        assert_eq!(severity(&diff, "SearchResult.Team"), Severity::Breaking);
+        assert!(find(&diff, "DateTime").is_empty());
+    }
+
+    #[test]
+    fn required_inputs_and_arguments_break() {
+        let diff = diffed();
+        assert_eq!(severity(&diff, "IssueFilter.teamId"), Severity::NonBreaking);
+        assert_eq!(
+            severity(&diff, "IssueCreateInput.stateId"),
+            Severity::Breaking
+        );
+        assert_eq!(
+            severity(&diff, "Query.issues(orderBy)"),
+            Severity::NonBreaking
+        );
+        assert_eq!(severity(&diff, "Query.team(id)"), Severity::Breaking);
+        assert_eq!(severity(&diff, "Query.roadmap"), Severity::Breaking);
+    }
+
+    #[test]
+    fn reports_affected_operations() {
+        let diff = diffed();
+        let affected: Vec<(&str, &str)> = diff
+            .affected
+            .iter()
+            .map(|op| (op.section, op.name.as_str()))
+            .collect();
+        assert_eq!(
+            affected,
+            vec![
+                ("queries", "issues"),
+                ("queries", "team"),
+                ("mutations", "issueCreate")
+            ]
+        );
+        let issue_create = &diff.affected[2];
+        assert!(issue_create
+            .changes
+            .contains(&"IssueCreateInput.stateId".to_string()));
+        assert!(issue_create.changes.contains(&"Issue.legacy".to_string()));
+        // `roadmap` is removed but not enabled.
+        assert!(!diff.affected.iter().any(|op| op.name == "roadmap"));
+    }
+
+    #[test]
+    fn renders_text_and_json() {
+        let diff = diffed();
+        let text = diff.to_string();
+        assert!(text.contains("! removed field `Issue.legacy`"));
+        assert!(text.contains("! changed argument `Query.team(id)`: String! → ID!"));
+        assert!(text.contains("+ added enum value `Status.canceled`"));
+        assert!(text.contains("queries.team: Query.team(id)"));
+
+        let json = diff.to_json();
+        assert_eq!(
+            json["breaking"].as_u64().unwrap() as usize,
+            diff.changes
+                .iter()
+                .filter(|c| c.severity == Severity::Breaking)
+                .count()
+        );
+        assert!(json["changes"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|c| c["path"] == "Issue.legacy"
+                && c["breaking"] == true
+                && c["kind"] == "removed"));
+        assert_eq!(json["affected_operations"][1]["name"], "team");
+    }
+
+    #[test]
+    fn identical_schemas_have_no_changes() {
+        let schema = parser::parse(OLD);
+        let diff = diff(&schema, &schema, &[]);
+        assert!(diff.changes.is_empty());
+        assert_eq!(diff.to_string(), "No schema changes.\n");
+    }
+}
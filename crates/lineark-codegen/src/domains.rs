@@ -0,0 +1,171 @@
+//! Cargo features grouping operations by domain.
+//!
+//! Operations enabled in operations.toml's `[queries]`, `[mutations]` and
+//! `[subscriptions]` sections are always compiled. Every other operation is
+//! generated behind the `lineark-sdk` feature of its domain, so downstream
+//! crates opt into the parts of the API they use.
+//!
+//! The `[domains]` section lists name prefixes per domain. An operation
+//! belongs to the domain with the longest prefix of its name; an entry in
+//! one of the operation sections can instead name it with `feature = "..."`.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use std::collections::BTreeMap;
+
+/// The `[domains]` section of operations.toml.
+pub struct Domains {
+    /// `(prefix, domain)`, longest prefix first.
+    prefixes: Vec<(String, String)>,
+}
+
+impl Domains {
+    pub fn parse(operations: &toml::Value) -> Self {
+        let mut prefixes = Vec::new();
+        if let Some(table) = operations.get("domains").and_then(|d| d.as_table()) {
+            for (domain, list) in table {
+                let list = list.as_array().unwrap_or_else(|| {
+                    panic!("[domains] {} must be a list of name prefixes", domain)
+                });
+                for prefix in list {
+                    let prefix = prefix.as_str().unwrap_or_else(|| {
+                        panic!("[domains] {} must be a list of name prefixes", domain)
+                    });
+                    prefixes.push((prefix.to_string(), domain.clone()));
+                }
+            }
+        }
+        prefixes.sort_by(|(a, _), (b, _)| b.len().cmp(&a.len()).then_with(|| a.cmp(b)));
+        Self { prefixes }
+    }
+
+    /// The domain of the operation `name`, if any prefix matches.
+    pub fn domain_of(&self, name: &str) -> Option<&str> {
+        self.prefixes
+            .iter()
+            .find(|(prefix, _)| name.starts_with(prefix.as_str()))
+            .map(|(_, domain)| domain.as_str())
+    }
+
+    /// Every domain name, sorted.
+    pub fn names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self.prefixes.iter().map(|(_, d)| d.as_str()).collect();
+        names.sort_unstable();
+        names.dedup();
+        names
+    }
+}
+
+/// Check that `lineark-sdk`'s Cargo.toml declares a feature per domain, and a
+/// `full` feature enabling all of them. Returns the problems found.
+pub fn check_features(manifest: &toml::Value, domains: &[&str]) -> Vec<String> {
+    let features = manifest.get("features").and_then(|f| f.as_table());
+    let mut problems = Vec::new();
+    let full: Vec<&str> = features
+        .and_then(|f| f.get("full"))
+        .and_then(|f| f.as_array())
+        .map(|list| list.iter().filter_map(|v| v.as_str()).collect())
+        .unwrap_or_default();
+    for domain in domains {
+        if !features.is_some_and(|f| f.contains_key(*domain)) {
+            problems.push(format!("missing feature `{} = []`", domain));
+        }
+        if !full.contains(domain) {
+            problems.push(format!("feature `full` does not enable `{}`", domain));
+        }
+    }
+    problems
+}
+
+/// `#[cfg(feature = "...")]` for an operation gated behind `feature`, with a
+/// doc line saying so. Empty for always-compiled operations.
+pub fn cfg_tokens(feature: Option<&str>) -> TokenStream {
+    match feature {
+        Some(feature) => {
+            let doc = format!(" Requires the `{}` feature.", feature);
+            quote! { #[doc = ""] #[doc = #doc] #[cfg(feature = #feature)] }
+        }
+        None => TokenStream::new(),
+    }
+}
+
+/// The `#[cfg(feature = "...")]` of [`cfg_tokens`] alone, for impl blocks,
+/// which rustdoc shows no docs for.
+pub fn cfg_impl_tokens(feature: Option<&str>) -> TokenStream {
+    match feature {
+        Some(feature) => quote! { #[cfg(feature = #feature)] },
+        None => TokenStream::new(),
+    }
+}
+
+/// Count of gated operations per feature, for the codegen summary.
+pub fn summary<'a>(features: impl IntoIterator<Item = &'a String>) -> String {
+    let mut counts: BTreeMap<&str, usize> = BTreeMap::new();
+    for feature in features {
+        *counts.entry(feature.as_str()).or_default() += 1;
+    }
+    counts
+        .iter()
+        .map(|(feature, count)| format!("{} {}", feature, count))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn domains() -> Domains {
+        let operations: toml::Value = r#"
+            [domains]
+            issues = ["issue", "comment"]
+            releases = ["release", "issueToRelease"]
+        "#
+        .parse()
+        .unwrap();
+        Domains::parse(&operations)
+    }
+
+    #[test]
+    fn longest_prefix_wins() {
+        let domains = domains();
+        assert_eq!(domains.domain_of("issueCreate"), Some("issues"));
+        assert_eq!(domains.domain_of("commentResolve"), Some("issues"));
+        assert_eq!(domains.domain_of("issueToReleaseCreate"), Some("releases"));
+        assert_eq!(domains.domain_of("releases"), Some("releases"));
+        assert_eq!(domains.domain_of("webhooks"), None);
+        assert_eq!(domains.names(), ["issues", "releases"]);
+    }
+
+    #[test]
+    fn check_features_reports_missing_features() {
+        let manifest: toml::Value = r#"
+            [features]
+            full = ["issues"]
+            issues = []
+        "#
+        .parse()
+        .unwrap();
+        assert!(check_features(&manifest, &["issues"]).is_empty());
+        assert_eq!(
+            check_features(&manifest, &["issues", "releases"]),
+            [
+                "missing feature `releases = []`",
+                "feature `full` does not enable `releases`",
+            ]
+        );
+    }
+
+    #[test]
+    fn cfg_tokens_gate_and_document() {
+        assert!(cfg_tokens(None).is_empty());
+        let tokens = cfg_tokens(Some("issues")).to_string();
+        assert!(tokens.contains("cfg (feature = \"issues\")"));
+        assert!(tokens.contains("Requires the `issues` feature."));
+        assert!(cfg_impl_tokens(None).is_empty());
+        assert_eq!(
+            cfg_impl_tokens(Some("issues")).to_string(),
+            "# [cfg (feature = \"issues\")]"
+        );
+    }
+}
@@ -0,0 +1,848 @@
+//! Typed operations from hand-written `.graphql` documents.
+//!
+//! `lineark-codegen documents --out <file.rs> <file.graphql>...` validates
+//! each named query or mutation against the schema and emits one module per
+//! operation, holding:
+//!
+//! - `Variables`, with a field per declared variable;
+//! - `ResponseData` and a struct per nested selection, named after its
+//!   response key path (`Issues`, `IssuesNodes`, …);
+//! - `Operation`, implementing `lineark_sdk::GraphQLDocument` so it runs
+//!   through `Client::execute_document`.
+//!
+//! Fragments are flattened into the structs of the fields that spread them.
+//! Fragments on the object types of an interface or union become an enum
+//! tagged by `__typename`, which the selection must then include.
+
+use crate::parser::{self, FieldDef, GqlType, ParsedSchema, TypeKind};
+use apollo_parser::cst::{self, CstNode};
+use heck::{ToSnakeCase, ToUpperCamelCase};
+use proc_macro2::TokenStream;
+use quote::quote;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+/// A `.graphql` document to generate operations from.
+pub struct Source {
+    /// Where the document came from, for error messages.
+    pub path: String,
+    pub text: String,
+}
+
+/// A selection with fragment spreads resolved.
+#[derive(Debug, Clone)]
+enum Selection {
+    Field(FieldSelection),
+    /// An inline fragment or a spread named fragment.
+    Fragment {
+        on: Option<String>,
+        selections: Vec<Selection>,
+    },
+}
+
+#[derive(Debug, Clone)]
+struct FieldSelection {
+    /// The response key: the alias if any, else the name.
+    key: String,
+    name: String,
+    arguments: Vec<String>,
+    variables: Vec<String>,
+    selections: Vec<Selection>,
+}
+
+/// The fields a struct selects, merged by response key, and the
+/// type-conditioned selections of its variants.
+#[derive(Default)]
+struct Shape {
+    fields: Vec<(String, String, Vec<Selection>)>,
+    variants: BTreeMap<String, Vec<Selection>>,
+}
+
+struct Fragment {
+    on: String,
+    selection_set: Option<cst::SelectionSet>,
+    text: String,
+}
+
+/// Emit a module per operation in `sources`, or every validation error.
+pub fn emit(schema: &ParsedSchema, sources: &[Source]) -> Result<TokenStream, Vec<String>> {
+    let mut errors = Vec::new();
+    let mut fragments: HashMap<String, Fragment> = HashMap::new();
+    let mut operations = Vec::new();
+
+    for source in sources {
+        let tree = apollo_parser::Parser::new(&source.text).parse();
+        for err in tree.errors() {
+            errors.push(format!("{}: {}", source.path, err.message()));
+        }
+        for def in tree.document().definitions() {
+            match def {
+                cst::Definition::OperationDefinition(op) => operations.push((&source.path, op)),
+                cst::Definition::FragmentDefinition(f) => {
+                    let name = parser::extract_name(&f.fragment_name().and_then(|n| n.name()));
+                    let on = f
+                        .type_condition()
+                        .and_then(|c| c.named_type())
+                        .map(|t| parser::extract_name(&t.name()))
+                        .unwrap_or_default();
+                    let fragment = Fragment {
+                        on,
+                        selection_set: f.selection_set(),
+                        text: f.syntax().to_string().trim().to_string(),
+                    };
+                    if fragments.insert(name.clone(), fragment).is_some() {
+                        errors.push(format!(
+                            "{}: fragment `{}` is defined twice",
+                            source.path, name
+                        ));
+                    }
+                }
+                _ => errors.push(format!(
+                    "{}: only operations and fragments are allowed",
+                    source.path
+                )),
+            }
+        }
+    }
+
+    let lookup = Lookup::new(schema);
+    let mut modules = Vec::new();
+    let mut names = BTreeSet::new();
+    for (path, op) in operations {
+        let name = parser::extract_name(&op.name());
+        if name.is_empty() {
+            errors.push(format!("{}: operations must be named", path));
+            continue;
+        }
+        if !names.insert(name.clone()) {
+            errors.push(format!("{}: operation `{}` is defined twice", path, name));
+            continue;
+        }
+        let mut ctx = Context {
+            lookup: &lookup,
+            fragments: &fragments,
+            operation: name.clone(),
+            errors: Vec::new(),
+            used_fragments: BTreeSet::new(),
+            used_variables: BTreeSet::new(),
+            items: Vec::new(),
+        };
+        let module = ctx.emit_operation(&op);
+        errors.extend(ctx.errors.into_iter().map(|e| format!("{}: {}", path, e)));
+        modules.push(module);
+    }
+
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+    Ok(quote! {
+        //! Typed GraphQL operations.
+        //!
+        //! Generated by `lineark-codegen documents` — do not edit.
+
+        #(#modules)*
+    })
+}
+
+/// Schema lookups by type name.
+struct Lookup<'a> {
+    kinds: &'a HashMap<String, TypeKind>,
+    fields: HashMap<&'a str, &'a [FieldDef]>,
+    /// The object types each object, interface and union can be.
+    possible: HashMap<&'a str, BTreeSet<&'a str>>,
+}
+
+impl<'a> Lookup<'a> {
+    fn new(schema: &'a ParsedSchema) -> Self {
+        let mut fields: HashMap<&str, &[FieldDef]> = HashMap::new();
+        let mut possible: HashMap<&str, BTreeSet<&str>> = HashMap::new();
+        for o in &schema.objects {
+            fields.insert(&o.name, &o.fields);
+            possible.entry(&o.name).or_default().insert(&o.name);
+            for i in &o.interfaces {
+                possible.entry(i).or_default().insert(&o.name);
+            }
+        }
+        for i in &schema.interfaces {
+            fields.insert(&i.name, &i.fields);
+            possible.entry(&i.name).or_default();
+        }
+        for u in &schema.unions {
+            possible.insert(&u.name, u.members.iter().map(String::as_str).collect());
+        }
+        fields.insert("Query", &schema.query_fields);
+        fields.insert("Mutation", &schema.mutation_fields);
+        Self {
+            kinds: &schema.type_kind_map,
+            fields,
+            possible,
+        }
+    }
+
+    fn kind(&self, name: &str) -> Option<TypeKind> {
+        self.kinds.get(name).copied()
+    }
+
+    fn field(&self, parent: &str, name: &str) -> Option<&'a FieldDef> {
+        self.fields.get(parent)?.iter().find(|f| f.name == name)
+    }
+
+    fn is_composite(&self, name: &str) -> bool {
+        matches!(
+            self.kind(name),
+            Some(TypeKind::Object | TypeKind::Interface | TypeKind::Union)
+        )
+    }
+
+    fn possible(&self, name: &str) -> BTreeSet<&'a str> {
+        self.possible.get(name).cloned().unwrap_or_default()
+    }
+}
+
+struct Context<'a> {
+    lookup: &'a Lookup<'a>,
+    fragments: &'a HashMap<String, Fragment>,
+    operation: String,
+    errors: Vec<String>,
+    used_fragments: BTreeSet<String>,
+    used_variables: BTreeSet<String>,
+    items: Vec<TokenStream>,
+}
+
+impl Context<'_> {
+    fn error(&mut self, message: String) {
+        self.errors.push(format!("{}: {}", self.operation, message));
+    }
+
+    fn emit_operation(&mut self, op: &cst::OperationDefinition) -> TokenStream {
+        let root = match op.operation_type() {
+            None => "Query",
+            Some(t) if t.query_token().is_some() => "Query",
+            Some(t) if t.mutation_token().is_some() => "Mutation",
+            Some(_) => {
+                self.error(
+                    "subscriptions are not supported; use the generated subscription builders"
+                        .to_string(),
+                );
+                return TokenStream::new();
+            }
+        };
+
+        let variables = self.emit_variables(op);
+        let selections = self.convert_set(&op.selection_set(), &mut Vec::new());
+        self.emit_struct("ResponseData", root, &selections, "");
+
+        let declared: BTreeSet<String> = op
+            .variable_definitions()
+            .into_iter()
+            .flat_map(|d| d.variable_definitions())
+            .map(|d| parser::extract_name(&d.variable().and_then(|v| v.name())))
+            .collect();
+        let used = std::mem::take(&mut self.used_variables);
+        for unused in declared.difference(&used) {
+            self.error(format!("variable `${}` is never used", unused));
+        }
+        for undeclared in used.difference(&declared) {
+            self.error(format!("variable `${}` is not declared", undeclared));
+        }
+
+        let mut document = op.syntax().to_string().trim().to_string();
+        for name in &self.used_fragments {
+            document.push_str("\n\n");
+            document.push_str(&self.fragments[name].text);
+        }
+
+        let module = quote::format_ident!("{}", self.operation.to_snake_case());
+        let operation_name = &self.operation;
+        let module_doc = format!(" The `{}` operation.", operation_name);
+        let items = &self.items;
+        quote! {
+            #[doc = #module_doc]
+            pub mod #module {
+                use serde::{Deserialize, Serialize};
+
+                pub const OPERATION_NAME: &str = #operation_name;
+                pub const DOCUMENT: &str = #document;
+
+                #variables
+                #(#items)*
+
+                /// Marker to run the operation with `Client::execute_document`.
+                pub struct Operation;
+
+                impl lineark_sdk::GraphQLDocument for Operation {
+                    type Variables = Variables;
+                    type ResponseData = ResponseData;
+                    const OPERATION_NAME: &'static str = OPERATION_NAME;
+                    const DOCUMENT: &'static str = DOCUMENT;
+                }
+            }
+        }
+    }
+
+    fn emit_variables(&mut self, op: &cst::OperationDefinition) -> TokenStream {
+        let mut fields = Vec::new();
+        for def in op
+            .variable_definitions()
+            .into_iter()
+            .flat_map(|d| d.variable_definitions())
+        {
+            let name = parser::extract_name(&def.variable().and_then(|v| v.name()));
+            let ty = parser::extract_type(&def.ty());
+            let base = ty.base_name();
+            let inner = match self.lookup.kind(base) {
+                Some(TypeKind::Scalar) => leaf_type(base, TypeKind::Scalar),
+                Some(TypeKind::Enum) => leaf_type(base, TypeKind::Enum),
+                Some(TypeKind::InputObject) => {
+                    let ident = quote::format_ident!("{}", base);
+                    quote! { lineark_sdk::generated::inputs::#ident }
+                }
+                Some(_) => {
+                    self.error(format!("variable `${}` has output type `{}`", name, base));
+                    continue;
+                }
+                None => {
+                    self.error(format!("variable `${}` has unknown type `{}`", name, base));
+                    continue;
+                }
+            };
+            // A variable with a default value may be omitted.
+            let required = def.default_value().is_none() && matches!(ty, GqlType::NonNull(_));
+            let rust_type = wrap_required(&ty, inner);
+            let field_ident = field_ident(&name);
+            let rename = rename_attr(&field_ident, &name);
+            fields.push(if required {
+                quote! { #rename pub #field_ident: #rust_type, }
+            } else {
+                quote! {
+                    #rename
+                    #[serde(skip_serializing_if = "Option::is_none")]
+                    pub #field_ident: Option<#rust_type>,
+                }
+            });
+        }
+        let doc = format!(" Variables of `{}`.", self.operation);
+        quote! {
+            #[doc = #doc]
+            #[derive(Debug, Clone, Serialize)]
+            pub struct Variables {
+                #(#fields)*
+            }
+        }
+    }
+
+    /// Convert a selection set, resolving fragment spreads. `stack` holds the
+    /// fragments being expanded, to reject cycles.
+    fn convert_set(
+        &mut self,
+        set: &Option<cst::SelectionSet>,
+        stack: &mut Vec<String>,
+    ) -> Vec<Selection> {
+        let Some(set) = set else {
+            return Vec::new();
+        };
+        let mut selections = Vec::new();
+        for selection in set.selections() {
+            match selection {
+                cst::Selection::Field(f) => {
+                    let name = parser::extract_name(&f.name());
+                    let key = f
+                        .alias()
+                        .map(|a| parser::extract_name(&a.name()))
+                        .unwrap_or_else(|| name.clone());
+                    let mut arguments = Vec::new();
+                    let mut variables = Vec::new();
+                    for arg in f.arguments().into_iter().flat_map(|a| a.arguments()) {
+                        arguments.push(parser::extract_name(&arg.name()));
+                        if let Some(value) = arg.value() {
+                            variables.extend(
+                                value
+                                    .syntax()
+                                    .descendants()
+                                    .filter_map(cst::Variable::cast)
+                                    .map(|v| parser::extract_name(&v.name())),
+                            );
+                        }
+                    }
+                    selections.push(Selection::Field(FieldSelection {
+                        key,
+                        name,
+                        arguments,
+                        variables,
+                        selections: self.convert_set(&f.selection_set(), stack),
+                    }));
+                }
+                cst::Selection::InlineFragment(f) => {
+                    let on = f
+                        .type_condition()
+                        .and_then(|c| c.named_type())
+                        .map(|t| parser::extract_name(&t.name()));
+                    let inner = self.convert_set(&f.selection_set(), stack);
+                    selections.push(Selection::Fragment {
+                        on,
+                        selections: inner,
+                    });
+                }
+                cst::Selection::FragmentSpread(s) => {
+                    let name = parser::extract_name(&s.fragment_name().and_then(|n| n.name()));
+                    let Some(fragment) = self.fragments.get(&name) else {
+                        self.error(format!("fragment `{}` is not defined", name));
+                        continue;
+                    };
+                    if stack.contains(&name) {
+                        self.error(format!("fragment `{}` spreads itself", name));
+                        continue;
+                    }
+                    self.used_fragments.insert(name.clone());
+                    stack.push(name);
+                    let inner = self.convert_set(&fragment.selection_set, stack);
+                    stack.pop();
+                    selections.push(Selection::Fragment {
+                        on: Some(fragment.on.clone()),
+                        selections: inner,
+                    });
+                }
+            }
+        }
+        selections
+    }
+
+    /// Sort `selections` on `parent` into the fields every value has and the
+    /// selections that only apply to some object types.
+    fn flatten(&mut self, parent: &str, selections: &[Selection], shape: &mut Shape, at: &str) {
+        for selection in selections {
+            match selection {
+                Selection::Field(f) => {
+                    if f.name != "__typename" && self.lookup.field(parent, &f.name).is_none() {
+                        self.error(format!(
+                            "`{}` has no field `{}`{}",
+                            parent,
+                            f.name,
+                            location(at)
+                        ));
+                        continue;
+                    }
+                    match shape.fields.iter_mut().find(|(key, _, _)| *key == f.key) {
+                        Some((_, name, children)) if *name == f.name => {
+                            children.extend(f.selections.iter().cloned());
+                        }
+                        Some(_) => self.error(format!(
+                            "response key `{}` selects different fields{}",
+                            f.key,
+                            location(at)
+                        )),
+                        None => {
+                            shape
+                                .fields
+                                .push((f.key.clone(), f.name.clone(), f.selections.clone()))
+                        }
+                    }
+                }
+                Selection::Fragment { on, selections } => {
+                    let on = on.as_deref().unwrap_or(parent);
+                    if !self.lookup.is_composite(on) {
+                        self.error(format!(
+                            "fragment on `{}` must be on an object, interface or union{}",
+                            on,
+                            location(at)
+                        ));
+                        continue;
+                    }
+                    let parent_types = self.lookup.possible(parent);
+                    let on_types = self.lookup.possible(on);
+                    if on == parent || parent_types.is_subset(&on_types) {
+                        self.flatten(parent, selections, shape, at);
+                    } else if self.lookup.kind(on) == Some(TypeKind::Object)
+                        && parent_types.contains(on)
+                    {
+                        shape
+                            .variants
+                            .entry(on.to_string())
+                            .or_default()
+                            .extend(selections.iter().cloned());
+                    } else if parent_types.is_disjoint(&on_types) {
+                        self.error(format!(
+                            "fragment on `{}` can never apply to `{}`{}",
+                            on,
+                            parent,
+                            location(at)
+                        ));
+                    } else {
+                        self.error(format!(
+                            "fragment on `{}` inside `{}` is not supported; use a fragment per object type{}",
+                            on,
+                            parent,
+                            location(at)
+                        ));
+                    }
+                }
+            }
+        }
+    }
+
+    /// Emit struct `name` for `selections` on `parent`, plus its nested
+    /// structs. `at` is the response key path, for error messages.
+    fn emit_struct(&mut self, name: &str, parent: &str, selections: &[Selection], at: &str) {
+        let mut shape = Shape::default();
+        self.flatten(parent, selections, &mut shape, at);
+        let prefix = if name == "ResponseData" { "" } else { name };
+        let polymorphic = !shape.variants.is_empty();
+
+        let mut fields = Vec::new();
+        for (key, field_name, children) in &shape.fields {
+            let field_at = if at.is_empty() {
+                key.clone()
+            } else {
+                format!("{}.{}", at, key)
+            };
+            let ident = field_ident(key);
+            let rename = rename_attr(&ident, key);
+            if field_name == "__typename" {
+                // The tag of the variants enum consumes `__typename`.
+                if !polymorphic {
+                    fields.push(quote! { #rename pub #ident: String, });
+                }
+                continue;
+            }
+            let def = self
+                .lookup
+                .field(parent, field_name)
+                .expect("checked by flatten");
+            self.check_arguments(def, &field_at, selections, key);
+            let base = def.ty.base_name();
+            let inner = match self.lookup.kind(base) {
+                Some(kind @ (TypeKind::Scalar | TypeKind::Enum)) => {
+                    if !children.is_empty() {
+                        self.error(format!(
+                            "`{}` is a {} and has no fields to select (at {})",
+                            base,
+                            if kind == TypeKind::Enum {
+                                "enum"
+                            } else {
+                                "scalar"
+                            },
+                            field_at
+                        ));
+                    }
+                    leaf_type(base, kind)
+                }
+                _ => {
+                    if children.is_empty() {
+                        self.error(format!(
+                            "`{}` of type `{}` needs a selection (at {})",
+                            field_name, base, field_at
+                        ));
+                        continue;
+                    }
+                    let child = format!("{}{}", prefix, key.to_upper_camel_case());
+                    self.emit_struct(&child, base, children, &field_at);
+                    let ident = quote::format_ident!("{}", child);
+                    quote! { #ident }
+                }
+            };
+            let rust_type = wrap(&def.ty, inner);
+            fields.push(quote! { #rename pub #ident: #rust_type, });
+        }
+
+        if polymorphic {
+            if !shape.fields.iter().any(|(_, name, _)| name == "__typename") {
+                self.error(format!(
+                    "fragments on object types of `{}` need `__typename` selected{}",
+                    parent,
+                    location(at)
+                ));
+            }
+            let enum_name = format!("{}On", name);
+            let mut variants = Vec::new();
+            for (object, selections) in &shape.variants {
+                let variant_name = format!("{}{}", enum_name, object);
+                self.emit_struct(&variant_name, object, selections, at);
+                let object_ident = quote::format_ident!("{}", object);
+                let variant_ident = quote::format_ident!("{}", variant_name);
+                variants.push(quote! { #object_ident(#variant_ident), });
+            }
+            let enum_ident = quote::format_ident!("{}", enum_name);
+            let enum_doc = format!(" Fields selected on specific `{}` types.", parent);
+            self.items.push(quote! {
+                #[doc = #enum_doc]
+                #[derive(Debug, Clone, Serialize, Deserialize)]
+                #[serde(tag = "__typename")]
+                pub enum #enum_ident {
+                    #(#variants)*
+                    /// Any other type.
+                    #[serde(other)]
+                    Other,
+                }
+            });
+            fields.push(quote! {
+                #[serde(flatten)]
+                pub on: #enum_ident,
+            });
+        }
+
+        let ident = quote::format_ident!("{}", name);
+        let doc = if at.is_empty() {
+            format!(" Data returned by `{}`.", self.operation)
+        } else {
+            format!(" Selection of `{}` on `{}`.", at, parent)
+        };
+        self.items.push(quote! {
+            #[doc = #doc]
+            #[derive(Debug, Clone, Serialize, Deserialize)]
+            pub struct #ident {
+                #(#fields)*
+            }
+        });
+    }
+
+    /// Validate the arguments every selection of `key` passes to `def`, and
+    /// record the variables they use.
+    fn check_arguments(&mut self, def: &FieldDef, at: &str, selections: &[Selection], key: &str) {
+        let mut found = Vec::new();
+        collect_fields(selections, key, &mut found);
+        for f in found {
+            for arg in &f.arguments {
+                if !def.arguments.iter().any(|a| a.name == *arg) {
+                    self.error(format!(
+                        "`{}` has no argument `{}` (at {})",
+                        def.name, arg, at
+                    ));
+                }
+            }
+            for required in def
+                .arguments
+                .iter()
+                .filter(|a| matches!(a.ty, GqlType::NonNull(_)))
+            {
+                if !f.arguments.contains(&required.name) {
+                    self.error(format!(
+                        "`{}` needs argument `{}` (at {})",
+                        def.name, required.name, at
+                    ));
+                }
+            }
+            self.used_variables.extend(f.variables.iter().cloned());
+        }
+    }
+}
+
+/// Every field selection with response key `key` in `selections`, looking
+/// through fragments.
+fn collect_fields<'s>(selections: &'s [Selection], key: &str, found: &mut Vec<&'s FieldSelection>) {
+    for selection in selections {
+        match selection {
+            Selection::Field(f) if f.key == key => found.push(f),
+            Selection::Field(_) => {}
+            Selection::Fragment { selections, .. } => collect_fields(selections, key, found),
+        }
+    }
+}
+
+fn location(at: &str) -> String {
+    if at.is_empty() {
+        String::new()
+    } else {
+        format!(" (at {})", at)
+    }
+}
+
+/// The Rust type of a scalar or enum, as seen from outside the SDK.
+fn leaf_type(name: &str, kind: TypeKind) -> TokenStream {
+    let ident = quote::format_ident!("{}", name);
+    match (name, kind) {
+        ("String" | "ID", _) => quote! { String },
+        ("Int", _) => quote! { i64 },
+        ("Float", _) => quote! { f64 },
+        ("Boolean", _) => quote! { bool },
+        (_, TypeKind::Enum) => quote! { lineark_sdk::generated::enums::#ident },
+        _ if crate::emit_scalars::scalar_to_rust_type(name).is_some() => {
+            quote! { lineark_sdk::generated::scalars::#ident }
+        }
+        _ => quote! { lineark_sdk::generated::scalars::JSON },
+    }
+}
+
+/// Wrap `inner` for `ty`: nullable positions become `Option`, lists `Vec`.
+fn wrap(ty: &GqlType, inner: TokenStream) -> TokenStream {
+    match ty {
+        GqlType::NonNull(ty) => wrap_required(ty, inner),
+        ty => {
+            let required = wrap_required(ty, inner);
+            quote! { Option<#required> }
+        }
+    }
+}
+
+fn wrap_required(ty: &GqlType, inner: TokenStream) -> TokenStream {
+    match ty {
+        GqlType::Named(_) => inner,
+        GqlType::NonNull(ty) => wrap_required(ty, inner),
+        GqlType::List(item) => {
+            let item = wrap(item, inner);
+            quote! { Vec<#item> }
+        }
+    }
+}
+
+fn field_ident(key: &str) -> TokenStream {
+    let name = match key {
+        "__typename" => "typename".to_string(),
+        key => key.to_snake_case(),
+    };
+    parser::safe_ident(&name).parse().unwrap()
+}
+
+/// `#[serde(rename = "key")]` unless the field is already named `key`.
+fn rename_attr(ident: &TokenStream, key: &str) -> TokenStream {
+    if ident.to_string().trim_start_matches("r#") == key {
+        TokenStream::new()
+    } else {
+        quote! { #[serde(rename = #key)] }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SCHEMA: &str = r#"
+        scalar DateTime
+        enum IssuePriority { urgent low }
+        interface Node { id: ID! }
+        type Issue implements Node {
+            id: ID!
+            title: String!
+            priority: IssuePriority
+            createdAt: DateTime!
+            assignee: User
+            labels(first: Int): LabelConnection!
+        }
+        type User implements Node { id: ID! name: String! }
+        type Label { id: ID! name: String! }
+        type LabelConnection { nodes: [Label!]! }
+        type IssueConnection { nodes: [Issue!]! }
+        input IssueFilter { title: String }
+        type IssuePayload { success: Boolean! issue: Issue }
+        type Query {
+            issues(filter: IssueFilter, first: Int): IssueConnection!
+            node(id: String!): Node!
+            viewer: User!
+        }
+        type Mutation {
+            issueArchive(id: String!): IssuePayload!
+        }
+    "#;
+
+    fn generate(document: &str) -> Result<String, Vec<String>> {
+        let schema = parser::parse(SCHEMA);
+        let sources = [Source {
+            path: "test.graphql".to_string(),
+            text: document.to_string(),
+        }];
+        emit(&schema, &sources).map(|tokens| tokens.to_string())
+    }
+
+    fn compact(output: &str) -> String {
+        output.split_whitespace().collect()
+    }
+
+    #[test]
+    fn emits_multi_root_queries_with_aliases_and_variables() {
+        let output = generate(
+            r#"
+            query Dashboard($filter: IssueFilter, $labels: Int!) {
+                mine: issues(filter: $filter) {
+                    nodes { id title priority createdAt labels(first: $labels) { nodes { name } } }
+                }
+                viewer { name }
+            }
+            "#,
+        )
+        .unwrap();
+        let output = compact(&output);
+        assert!(output.contains("pubmoddashboard{"));
+        assert!(output.contains("pubstructVariables{#[serde(skip_serializing_if=\"Option::is_none\")]pubfilter:Option<lineark_sdk::generated::inputs::IssueFilter>,publabels:i64,}"));
+        assert!(output.contains("pubstructResponseData{pubmine:Mine,pubviewer:Viewer,}"));
+        assert!(output.contains("pubstructMineNodes{pubid:String,pubtitle:String,pubpriority:Option<lineark_sdk::generated::enums::IssuePriority>,#[serde(rename=\"createdAt\")]pubcreated_at:lineark_sdk::generated::scalars::DateTime,publabels:MineNodesLabels,}"));
+        assert!(output.contains("pubnodes:Vec<MineNodesLabelsNodes>"));
+        assert!(output.contains("impllineark_sdk::GraphQLDocumentforOperation"));
+    }
+
+    #[test]
+    fn flattens_fragments_and_keeps_them_in_the_document() {
+        let output = generate(
+            r#"
+            query Issues { issues { nodes { ...IssueFields assignee { ... on User { name } } } } }
+            fragment IssueFields on Issue { id title }
+            "#,
+        )
+        .unwrap();
+        assert!(compact(&output).contains(
+            "pubstructIssuesNodes{pubid:String,pubtitle:String,pubassignee:Option<IssuesNodesAssignee>,}"
+        ));
+        assert!(output.contains("fragment IssueFields on Issue { id title }"));
+    }
+
+    #[test]
+    fn type_conditions_on_interfaces_become_tagged_enums() {
+        let output = generate(
+            r#"
+            query Node($id: String!) {
+                node(id: $id) { __typename id ... on Issue { title } ... on User { name } }
+            }
+            "#,
+        )
+        .unwrap();
+        let output = compact(&output);
+        assert!(output.contains("pubstructNode{pubid:String,#[serde(flatten)]pubon:NodeOn,}"));
+        assert!(output.contains(
+            "#[serde(tag=\"__typename\")]pubenumNodeOn{Issue(NodeOnIssue),User(NodeOnUser),"
+        ));
+        assert!(output.contains("pubstructNodeOnIssue{pubtitle:String,}"));
+    }
+
+    #[test]
+    fn reports_validation_errors() {
+        let errors = generate(
+            r#"
+            query Broken($unused: Int, $first: Foo) {
+                issues(sort: $missing) { nodes { nope assignee } }
+                node(id: "x") { id ... on User { name } }
+                viewer { ...Missing }
+            }
+            mutation { issueArchive(id: "x") { success } }
+            subscription Events { issues { nodes { id } } }
+            "#,
+        )
+        .unwrap_err()
+        .join("\n");
+        for expected in [
+            "variable `$first` has unknown type `Foo`",
+            "`Issue` has no field `nope` (at issues.nodes)",
+            "`assignee` of type `User` needs a selection (at issues.nodes.assignee)",
+            "`issues` has no argument `sort` (at issues)",
+            "fragments on object types of `Node` need `__typename` selected (at node)",
+            "fragment `Missing` is not defined",
+            "variable `$unused` is never used",
+            "variable `$missing` is not declared",
+            "operations must be named",
+            "subscriptions are not supported",
+        ] {
+            assert!(
+                errors.contains(expected),
+                "missing {expected:?} in:\n{errors}"
+            );
+        }
+    }
+
+    #[test]
+    fn emitted_code_parses() {
+        let output = generate(
+            r#"
+            query Node($id: String!) { node(id: $id) { __typename ... on Issue { title } } }
+            mutation Archive($id: String!) { issueArchive(id: $id) { success issue { id } } }
+            "#,
+        )
+        .unwrap();
+        syn::parse_file(&output).expect("Generated document code should be valid Rust");
+    }
+}
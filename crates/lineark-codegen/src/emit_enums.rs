@@ -15,7 +15,10 @@ pub fn emit(enums: &[EnumDef]) -> TokenStream {
         //!
         //! Generated by lineark-codegen — do not edit.
 
-        use serde::{Deserialize, Serialize};
+        use crate::error::ParseEnumError;
+        use serde::{Deserialize, Deserializer, Serialize, Serializer};
+        use std::fmt;
+        use std::str::FromStr;
 
         #(#enum_defs)*
     }
@@ -23,37 +26,95 @@ pub fn emit(enums: &[EnumDef]) -> TokenStream {
 
 fn emit_enum(e: &EnumDef) -> TokenStream {
     let name = quote::format_ident!("{}", e.name);
+    let type_name = &e.name;
     let doc = parser::doc_comment_tokens(&e.description);
-    let variants: Vec<TokenStream> = e
+    let values: Vec<(&str, proc_macro2::Ident, TokenStream)> = e
         .values
         .iter()
         .filter(|v| !v.name.is_empty())
         .map(|v| {
-            let original = &v.name;
-            let vdoc = parser::doc_comment_tokens(&v.description);
             // Convert to PascalCase for Rust convention.
-            let rust_name = to_variant_name(original);
-            let variant_ident = quote::format_ident!("{}", rust_name);
-            if rust_name == *original {
-                quote! { #vdoc #variant_ident, }
-            } else {
-                quote! {
-                    #vdoc
-                    #[serde(rename = #original)]
-                    #variant_ident,
-                }
-            }
+            let variant_ident = quote::format_ident!("{}", to_variant_name(&v.name));
+            let vdoc = parser::doc_comment_tokens(&v.description);
+            let deprecated = parser::deprecated_tokens(&v.deprecated);
+            (v.name.as_str(), variant_ident, quote! { #vdoc #deprecated })
         })
         .collect();
 
+    let variants = values
+        .iter()
+        .map(|(_, ident, attrs)| quote! { #attrs #ident, });
+    let all = values.iter().map(|(_, ident, _)| quote! { Self::#ident });
+    let as_str_arms = values
+        .iter()
+        .map(|(original, ident, _)| quote! { Self::#ident => #original, });
+    let parse_arms = values
+        .iter()
+        .map(|(original, ident, _)| quote! { #original => Some(Self::#ident), });
+
     quote! {
         #doc
-        #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        #[non_exhaustive]
         pub enum #name {
             #(#variants)*
-            /// Catch-all for unknown/new variants added by Linear.
-            #[serde(other)]
-            Unknown,
+            /// A value added by Linear after this SDK was generated, kept
+            /// verbatim so it round-trips.
+            Unknown(String),
+        }
+
+        impl #name {
+            /// Every known value, in schema order.
+            pub const ALL: &'static [Self] = &[#(#all),*];
+
+            /// The value as it appears in the GraphQL schema.
+            pub fn as_str(&self) -> &str {
+                match self {
+                    #(#as_str_arms)*
+                    Self::Unknown(value) => value,
+                }
+            }
+
+            fn from_known(value: &str) -> Option<Self> {
+                match value {
+                    #(#parse_arms)*
+                    _ => None,
+                }
+            }
+        }
+
+        impl fmt::Display for #name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl FromStr for #name {
+            type Err = ParseEnumError;
+
+            /// Parse a known value; unknown values are rejected.
+            fn from_str(value: &str) -> Result<Self, Self::Err> {
+                Self::from_known(value).ok_or_else(|| {
+                    ParseEnumError::new(
+                        #type_name,
+                        value,
+                        Self::ALL.iter().map(|v| v.as_str()).collect(),
+                    )
+                })
+            }
+        }
+
+        impl Serialize for #name {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(self.as_str())
+            }
+        }
+
+        impl<'de> Deserialize<'de> for #name {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let value = String::deserialize(deserializer)?;
+                Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+            }
         }
     }
 }
@@ -90,22 +151,26 @@ mod tests {
                 EnumValueDef {
                     name: "RED".to_string(),
                     description: None,
+                    deprecated: None,
                 },
                 EnumValueDef {
                     name: "GREEN".to_string(),
                     description: None,
+                    deprecated: None,
                 },
                 EnumValueDef {
                     name: "BLUE".to_string(),
                     description: None,
+                    deprecated: None,
                 },
             ],
         }];
         let output = emit(&enums).to_string();
-        assert!(output.contains("Color"));
+        assert!(output.contains("# [non_exhaustive] pub enum Color"));
         assert!(output.contains("Unknown"));
-        // SCREAMING_SNAKE values get serde(rename)
-        assert!(output.contains("serde"));
+        // SCREAMING_SNAKE values keep their GraphQL spelling on the wire.
+        assert!(output.contains("Self :: Red => \"RED\""));
+        assert!(output.contains("\"GREEN\" => Some (Self :: Green)"));
     }
 
     #[test]
@@ -116,11 +181,13 @@ mod tests {
             values: vec![EnumValueDef {
                 name: "ACTIVE".to_string(),
                 description: None,
+                deprecated: None,
             }],
         }];
         let output = emit(&enums).to_string();
-        assert!(output.contains("Unknown"));
-        assert!(output.contains("other"));
+        assert!(output.contains("Unknown (String)"));
+        assert!(output.contains("Self :: Unknown (value) => value"));
+        assert!(output.contains("ALL : & 'static [Self] = & [Self :: Active]"));
     }
 
     #[test]
@@ -160,14 +227,17 @@ mod tests {
                 EnumValueDef {
                     name: "noPriority".to_string(),
                     description: None,
+                    deprecated: None,
                 },
                 EnumValueDef {
                     name: "urgent".to_string(),
                     description: None,
+                    deprecated: None,
                 },
                 EnumValueDef {
                     name: "high".to_string(),
                     description: None,
+                    deprecated: None,
                 },
             ],
         }];
@@ -0,0 +1,239 @@
+use crate::parser::{FieldDef, GqlType, ObjectDef};
+use heck::ToUpperCamelCase;
+use proc_macro2::TokenStream;
+use quote::quote;
+use std::collections::BTreeSet;
+
+/// The entity whose ID a root field's `id` argument takes, if any.
+///
+/// That is the field's return type when it is an entity (`issue`), else the
+/// entity in its payload (`projectUpdate` → `ProjectPayload.project`). Delete
+/// payloads carry no entity; Linear names those fields after what they act on
+/// (`issueLabelDelete`), so the entity is the longest object type whose name
+/// is a leading word run of the field name. Only a required `String`/`ID`
+/// argument named `id` qualifies.
+pub fn id_entity(field: &FieldDef, objects: &[ObjectDef]) -> Option<String> {
+    let takes_id = field.arguments.iter().any(|a| {
+        a.name == "id"
+            && matches!(&a.ty, GqlType::NonNull(inner)
+                if matches!(inner.as_ref(), GqlType::Named(n) if n == "String" || n == "ID"))
+    });
+    if !takes_id {
+        return None;
+    }
+    let is_entity = |o: &&ObjectDef| o.fields.iter().any(|f| f.name == "id");
+    let object = |name: &str| objects.iter().find(|o| o.name == name);
+    if let Some(returned) = object(field.ty.base_name()) {
+        let entity = if is_entity(&returned) {
+            Some(returned)
+        } else {
+            returned
+                .fields
+                .iter()
+                .filter_map(|f| object(f.ty.base_name()))
+                .find(is_entity)
+        };
+        if let Some(entity) = entity {
+            return Some(entity.name.clone());
+        }
+    }
+    let camel = field.name.to_upper_camel_case();
+    objects
+        .iter()
+        .filter(is_entity)
+        .filter(|o| {
+            camel.starts_with(&o.name)
+                && camel[o.name.len()..]
+                    .chars()
+                    .next()
+                    .is_none_or(|c| c.is_ascii_uppercase())
+        })
+        .max_by_key(|o| o.name.len())
+        .map(|o| o.name.clone())
+}
+
+/// Name of the ID newtype for an entity (`Issue` → `IssueId`).
+pub fn id_type_ident(entity: &str) -> proc_macro2::Ident {
+    quote::format_ident!("{}Id", entity)
+}
+
+/// Emit `generated/ids.rs`: one ID newtype per entity, plus a `typed_id()`
+/// accessor on the entity's generated type.
+pub fn emit(entities: &BTreeSet<String>) -> TokenStream {
+    let items = entities.iter().map(|entity| {
+        let entity_ident = quote::format_ident!("{}", entity);
+        let id_ident = id_type_ident(entity);
+        let doc = format!(" Identifier of a [`{entity}`](super::types::{entity}).");
+        let accessor_doc = format!(" This entity's ID as an [`{id_ident}`], if selected.");
+        quote! {
+            #[doc = #doc]
+            ///
+            /// Accepted by every operation that takes this entity's `id`.
+            /// Plain strings convert with `From`, so the newtype is opt-in.
+            #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+            #[serde(transparent)]
+            pub struct #id_ident(String);
+
+            impl #id_ident {
+                pub fn new(id: impl Into<String>) -> Self {
+                    Self(id.into())
+                }
+
+                pub fn as_str(&self) -> &str {
+                    &self.0
+                }
+
+                pub fn into_inner(self) -> String {
+                    self.0
+                }
+            }
+
+            impl From<String> for #id_ident {
+                fn from(id: String) -> Self {
+                    Self(id)
+                }
+            }
+
+            impl From<&str> for #id_ident {
+                fn from(id: &str) -> Self {
+                    Self(id.to_string())
+                }
+            }
+
+            impl From<&String> for #id_ident {
+                fn from(id: &String) -> Self {
+                    Self(id.clone())
+                }
+            }
+
+            impl From<&#id_ident> for #id_ident {
+                fn from(id: &#id_ident) -> Self {
+                    id.clone()
+                }
+            }
+
+            impl From<#id_ident> for String {
+                fn from(id: #id_ident) -> Self {
+                    id.0
+                }
+            }
+
+            impl AsRef<str> for #id_ident {
+                fn as_ref(&self) -> &str {
+                    &self.0
+                }
+            }
+
+            impl std::fmt::Display for #id_ident {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    f.write_str(&self.0)
+                }
+            }
+
+            impl super::types::#entity_ident {
+                #[doc = #accessor_doc]
+                pub fn typed_id(&self) -> Option<#id_ident> {
+                    self.id.clone().map(#id_ident)
+                }
+            }
+        }
+    });
+
+    quote! {
+        //! Generated entity ID newtypes.
+        //!
+        //! Operations that take an entity's `id` accept `impl Into<…Id>`, so a
+        //! team ID can't be passed where an issue ID is expected once callers
+        //! opt into the newtypes.
+        //!
+        //! Generated by lineark-codegen — do not edit.
+
+        use serde::{Deserialize, Serialize};
+
+        #(#items)*
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::{ArgumentDef, FieldDef};
+
+    fn object(name: &str) -> ObjectDef {
+        ObjectDef {
+            name: name.to_string(),
+            description: None,
+            fields: vec![field("id", vec![])],
+            interfaces: vec![],
+        }
+    }
+
+    fn field(name: &str, arguments: Vec<ArgumentDef>) -> FieldDef {
+        typed_field(name, "DeletePayload", arguments)
+    }
+
+    fn typed_field(name: &str, ty: &str, arguments: Vec<ArgumentDef>) -> FieldDef {
+        FieldDef {
+            name: name.to_string(),
+            description: None,
+            arguments,
+            ty: GqlType::NonNull(Box::new(GqlType::Named(ty.to_string()))),
+            deprecated: None,
+        }
+    }
+
+    fn id_arg(ty: GqlType) -> ArgumentDef {
+        ArgumentDef {
+            name: "id".to_string(),
+            ty,
+            description: None,
+            deprecated: None,
+        }
+    }
+
+    #[test]
+    fn id_entity_picks_longest_word_prefix() {
+        let objects = [
+            object("Issue"),
+            object("IssueLabel"),
+            object("Team"),
+            object("TeamMembership"),
+        ];
+        let required = || id_arg(GqlType::NonNull(Box::new(GqlType::Named("String".into()))));
+        let entity = |name: &str| id_entity(&field(name, vec![required()]), &objects);
+        assert_eq!(entity("issue").as_deref(), Some("Issue"));
+        assert_eq!(entity("issueLabelDelete").as_deref(), Some("IssueLabel"));
+        assert_eq!(entity("issueLabelsDelete").as_deref(), Some("Issue"));
+        assert_eq!(
+            entity("teamMembershipDelete").as_deref(),
+            Some("TeamMembership")
+        );
+        assert_eq!(entity("viewer"), None);
+        let optional = field("issue", vec![id_arg(GqlType::Named("String".into()))]);
+        assert_eq!(id_entity(&optional, &objects), None);
+    }
+
+    #[test]
+    fn id_entity_prefers_returned_entity() {
+        let payload = ObjectDef {
+            name: "ProjectPayload".to_string(),
+            description: None,
+            fields: vec![
+                typed_field("success", "Boolean", vec![]),
+                typed_field("project", "Project", vec![]),
+            ],
+            interfaces: vec![],
+        };
+        let objects = [object("Project"), object("ProjectUpdate"), payload];
+        let id = || id_arg(GqlType::NonNull(Box::new(GqlType::Named("String".into()))));
+        let update = typed_field("projectUpdate", "ProjectPayload", vec![id()]);
+        assert_eq!(id_entity(&update, &objects).as_deref(), Some("Project"));
+        let query = typed_field("project", "Project", vec![id()]);
+        assert_eq!(id_entity(&query, &objects).as_deref(), Some("Project"));
+        let delete = field("projectUpdateDelete", vec![id()]);
+        assert_eq!(
+            id_entity(&delete, &objects).as_deref(),
+            Some("ProjectUpdate")
+        );
+    }
+}
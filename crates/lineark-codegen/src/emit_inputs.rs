@@ -13,7 +13,15 @@ pub fn emit(inputs: &[InputDef], type_kind_map: &HashMap<String, TypeKind>) -> T
     let structs: Vec<TokenStream> = inputs
         .iter()
         .filter(|i| !i.name.is_empty())
-        .map(|i| emit_input_struct(i, type_kind_map, &defaultable, &reach))
+        .map(|i| {
+            let definition = emit_input_struct(i, type_kind_map, &defaultable, &reach);
+            let dsl = if defaultable.contains(&i.name) {
+                emit_filter_dsl(i, type_kind_map, &reach)
+            } else {
+                quote! {}
+            };
+            quote! { #definition #dsl }
+        })
         .collect();
 
     quote! {
@@ -193,6 +201,7 @@ fn emit_field(
     let is_required = matches!(f.ty, GqlType::NonNull(_));
     let rust_type = resolve_input_type(&f.ty, type_kind_map, container, reach);
     let fdoc = parser::doc_comment_tokens(&f.description);
+    let deprecated = parser::deprecated_tokens(&f.deprecated);
     // If the snake_case name differs from the original camelCase, add serde rename.
     // We use rename_all on the struct level, so individual renames are only needed
     // if to_snake_case -> to_camelCase roundtrip doesn't match.
@@ -216,6 +225,7 @@ fn emit_field(
 
     quote! {
         #fdoc
+        #deprecated
         #serde_attr
         pub #field_ident: #rust_type,
     }
@@ -310,6 +320,236 @@ fn resolve_required(
     }
 }
 
+/// Pairs of comparator fields that negate each other.
+const NEGATIONS: &[(&str, &str)] = &[
+    ("eq", "neq"),
+    ("in", "nin"),
+    ("eqIgnoreCase", "neqIgnoreCase"),
+    ("startsWith", "notStartsWith"),
+    ("endsWith", "notEndsWith"),
+    ("contains", "notContains"),
+    ("containsIgnoreCase", "notContainsIgnoreCase"),
+    ("lt", "gte"),
+    ("lte", "gt"),
+];
+
+/// Emit the filter DSL for `*Comparator` and `*Filter` inputs: one
+/// constructor per field (`StringComparator::eq("x")`,
+/// `IssueFilter::team(..)`), `and`/`or`/`all`/`any` for filters that can be
+/// combined, and `negate` for comparators whose every constraint has a
+/// negated counterpart.
+fn emit_filter_dsl(
+    input: &InputDef,
+    type_kind_map: &HashMap<String, TypeKind>,
+    reach: &HashMap<String, HashSet<String>>,
+) -> TokenStream {
+    let is_comparator = input.name.ends_with("Comparator");
+    let is_filter = input.name.ends_with("Filter");
+    if !is_comparator && !is_filter {
+        return quote! {};
+    }
+    let name = quote::format_ident!("{}", input.name);
+    let fields: Vec<&FieldDef> = input.fields.iter().filter(|f| !f.name.is_empty()).collect();
+    let is_self_list = |f: &FieldDef| {
+        f.ty.base_name() == input.name && matches!(strip_non_null(&f.ty), GqlType::List(_))
+    };
+    let combinable = is_filter
+        && ["and", "or"]
+            .iter()
+            .all(|op| fields.iter().any(|f| f.name == *op && is_self_list(f)));
+
+    let constructors: Vec<TokenStream> = fields
+        .iter()
+        .filter(|f| !(combinable && (f.name == "and" || f.name == "or")))
+        .map(|f| emit_constructor(f, fields.len() > 1, type_kind_map, &input.name, reach))
+        .collect();
+
+    let combinators = if combinable {
+        quote! {
+            /// Match records matching every filter. A single filter is returned
+            /// as is, and no filters give the empty filter, which matches everything.
+            pub fn all(filters: impl IntoIterator<Item = Self>) -> Self {
+                let mut filters: Vec<Self> = filters.into_iter().collect();
+                match filters.len() {
+                    0 => Self::default(),
+                    1 => filters.remove(0),
+                    _ => Self { and: MaybeUndefined::Value(filters), ..Default::default() },
+                }
+            }
+            /// Match records matching any of the filters. A single filter is
+            /// returned as is. No filters give `None`: a disjunction of nothing
+            /// matches nothing, which no filter expresses.
+            pub fn any(filters: impl IntoIterator<Item = Self>) -> Option<Self> {
+                let mut filters: Vec<Self> = filters.into_iter().collect();
+                match filters.len() {
+                    0 => None,
+                    1 => Some(filters.remove(0)),
+                    _ => Some(Self { or: MaybeUndefined::Value(filters), ..Default::default() }),
+                }
+            }
+            /// Match records matching both this filter and `other`.
+            pub fn and(self, other: Self) -> Self {
+                Self::all([self, other])
+            }
+            /// Match records matching this filter or `other`.
+            pub fn or(self, other: Self) -> Self {
+                Self { or: MaybeUndefined::Value(vec![self, other]), ..Default::default() }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let negation = if is_comparator {
+        emit_negation(&name, &fields)
+    } else {
+        quote! {}
+    };
+
+    quote! {
+        impl #name {
+            #(#constructors)*
+            #combinators
+        }
+        #negation
+    }
+}
+
+fn strip_non_null(ty: &GqlType) -> &GqlType {
+    match ty {
+        GqlType::NonNull(inner) => inner,
+        other => other,
+    }
+}
+
+/// Method name for a field: its snake_case name, with a trailing `_` instead
+/// of a raw identifier for keywords (`in_`, `type_`).
+fn method_ident(field: &str) -> proc_macro2::Ident {
+    let snake = field.to_snake_case();
+    if parser::safe_ident(&snake).starts_with("r#") {
+        quote::format_ident!("{}_", snake)
+    } else {
+        quote::format_ident!("{}", snake)
+    }
+}
+
+fn field_ident(field: &str) -> TokenStream {
+    parser::safe_ident(&field.to_snake_case()).parse().unwrap()
+}
+
+/// `Self::field(value)`: a value with only `field` set.
+fn emit_constructor(
+    f: &FieldDef,
+    has_other_fields: bool,
+    type_kind_map: &HashMap<String, TypeKind>,
+    container: &str,
+    reach: &HashMap<String, HashSet<String>>,
+) -> TokenStream {
+    let method = method_ident(&f.name);
+    let field = field_ident(&f.name);
+    let doc = parser::doc_comment_tokens(&f.description);
+    let deprecated = parser::deprecated_tokens(&f.deprecated);
+    let required = matches!(f.ty, GqlType::NonNull(_));
+    let inner = strip_non_null(&f.ty);
+
+    let (param, value) = match inner {
+        // Lists of required scalars, enums and inputs take any iterator.
+        GqlType::List(item) if matches!(**item, GqlType::NonNull(_)) => {
+            let elem = resolve_required(item, type_kind_map, 1, container, reach);
+            (
+                quote! { values: impl IntoIterator<Item = impl Into<#elem>> },
+                quote! { values.into_iter().map(Into::into).collect() },
+            )
+        }
+        GqlType::Named(type_name)
+            if matches!(
+                type_kind_map.get(type_name.as_str()),
+                Some(TypeKind::InputObject)
+            ) =>
+        {
+            let ident = quote::format_ident!("{}", type_name);
+            let value = if reaches(type_name, container, reach) {
+                quote! { Box::new(value) }
+            } else {
+                quote! { value }
+            };
+            (quote! { value: #ident }, value)
+        }
+        _ => {
+            let ty = resolve_required(inner, type_kind_map, 0, container, reach);
+            (quote! { value: impl Into<#ty> }, quote! { value.into() })
+        }
+    };
+    let value = if required {
+        value
+    } else {
+        quote! { MaybeUndefined::Value(#value) }
+    };
+
+    let rest = if has_other_fields {
+        quote! { ..Default::default() }
+    } else {
+        quote! {}
+    };
+
+    quote! {
+        #doc
+        #deprecated
+        pub fn #method(#param) -> Self {
+            Self { #field: #value, #rest }
+        }
+    }
+}
+
+/// `negate()`, swapping the constraint with its negation, when every field of
+/// the comparator has one.
+fn emit_negation(name: &proc_macro2::Ident, fields: &[&FieldDef]) -> TokenStream {
+    let mut swaps = Vec::new();
+    let mut set = Vec::new();
+    for f in fields {
+        let field = field_ident(&f.name);
+        set.push(quote! { usize::from(!self.#field.is_undefined()) });
+        if f.name == "null" {
+            swaps.push(quote! {
+                #field: match self.#field {
+                    MaybeUndefined::Value(is_null) => MaybeUndefined::Value(!is_null),
+                    other => other,
+                }
+            });
+            continue;
+        }
+        let partner = NEGATIONS.iter().find_map(|&(a, b)| {
+            if a == f.name {
+                Some(b)
+            } else if b == f.name {
+                Some(a)
+            } else {
+                None
+            }
+        });
+        match partner {
+            Some(partner) if fields.iter().any(|g| g.name == partner) => {
+                let partner = field_ident(partner);
+                swaps.push(quote! { #field: self.#partner });
+            }
+            _ => return quote! {},
+        }
+    }
+    quote! {
+        impl #name {
+            /// The negated comparator (`eq` ↔ `neq`, `lt` ↔ `gte`, …), or
+            /// `None` unless exactly one constraint is set: negating several
+            /// would need an `or`, which comparators don't have.
+            pub fn negate(self) -> Option<Self> {
+                if #(#set)+* != 1 {
+                    return None;
+                }
+                Some(Self { #(#swaps),* })
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -393,6 +633,7 @@ mod tests {
             description: None,
             ty,
             arguments: vec![],
+            deprecated: None,
         };
         let inputs = vec![InputDef {
             name: "Sample".to_string(),
@@ -445,6 +686,7 @@ mod tests {
             description: None,
             ty,
             arguments: vec![],
+            deprecated: None,
         };
 
         let inputs = vec![
@@ -525,6 +767,7 @@ mod tests {
             description: None,
             ty,
             arguments: vec![],
+            deprecated: None,
         };
 
         let inputs = vec![
@@ -587,4 +830,64 @@ mod tests {
             panic!("emitted code must be valid Rust: {e}\n\n{pretty}");
         }
     }
+
+    #[test]
+    fn emit_filter_dsl_for_comparators_and_filters() {
+        let mk_field = |name: &str, ty: GqlType| FieldDef {
+            name: name.to_string(),
+            description: None,
+            ty,
+            arguments: vec![],
+            deprecated: None,
+        };
+        let named_ty = |name: &str| GqlType::Named(name.to_string());
+        let mut map = type_kind_map();
+        map.insert("Boolean".to_string(), TypeKind::Scalar);
+        map.insert("IDComparator".to_string(), TypeKind::InputObject);
+        map.insert("ThingFilter".to_string(), TypeKind::InputObject);
+        let inputs = vec![
+            InputDef {
+                name: "IDComparator".to_string(),
+                description: None,
+                fields: vec![
+                    mk_field("eq", named()),
+                    mk_field("neq", named()),
+                    mk_field("in", list(nn(named()))),
+                    mk_field("nin", list(nn(named()))),
+                    mk_field("null", named_ty("Boolean")),
+                ],
+            },
+            InputDef {
+                name: "ThingFilter".to_string(),
+                description: None,
+                fields: vec![
+                    mk_field("id", named_ty("IDComparator")),
+                    mk_field("parent", named_ty("ThingFilter")),
+                    mk_field("and", list(nn(named_ty("ThingFilter")))),
+                    mk_field("or", list(nn(named_ty("ThingFilter")))),
+                ],
+            },
+        ];
+        let output = emit(&inputs, &map).to_string();
+        syn::parse_file(&output).expect("emitted filter DSL must be valid Rust");
+        let normalized: String = output.split_whitespace().collect();
+
+        // One constructor per field; keywords get a trailing underscore.
+        assert!(normalized.contains("pubfneq(value:implInto<String>)->Self"));
+        assert!(
+            normalized.contains("pubfnin_(values:implIntoIterator<Item=implInto<String>>)->Self")
+        );
+        assert!(normalized.contains("pubfnid(value:IDComparator)->Self"));
+        // Recursive fields are boxed like the struct field.
+        assert!(normalized.contains("parent:MaybeUndefined::Value(Box::new(value))"));
+        // `and`/`or` become combinators instead of constructors.
+        assert!(normalized.contains("pubfnand(self,other:Self)->Self"));
+        assert!(normalized.contains("pubfnall(filters:implIntoIterator<Item=Self>)->Self"));
+        assert!(!normalized.contains("pubfnand(values"));
+        // Fully pairable comparators are negatable.
+        assert!(normalized.contains("pubfnnegate(self)->Option<Self>"));
+        assert!(normalized.contains("eq:self.neq"));
+        // No filters is the empty filter, not an empty `and`.
+        assert!(normalized.contains("0=>Self::default(),"));
+    }
 }
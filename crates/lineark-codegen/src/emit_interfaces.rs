@@ -0,0 +1,371 @@
+use crate::emit_types::resolve_required;
+use crate::parser::{self, FieldDef, InterfaceDef, ObjectDef, TypeKind, UnionDef};
+use heck::ToSnakeCase;
+use proc_macro2::TokenStream;
+use quote::quote;
+use std::collections::{BTreeSet, HashMap};
+
+/// Emit interface and union types, appended to `types.rs`.
+///
+/// Each interface and union becomes an enum over its object types, tagged by
+/// `__typename` and `#[non_exhaustive]`, since Linear adds members. Each
+/// interface also gets a `{Name}Fields` trait exposing its scalar and enum
+/// fields, implemented by its object types and its enum.
+pub fn emit(
+    interfaces: &[InterfaceDef],
+    unions: &[UnionDef],
+    objects: &[ObjectDef],
+    type_kind_map: &HashMap<String, TypeKind>,
+) -> TokenStream {
+    let interface_map: HashMap<&str, &InterfaceDef> =
+        interfaces.iter().map(|i| (i.name.as_str(), i)).collect();
+
+    let interface_items = interfaces
+        .iter()
+        .filter(|i| !i.name.is_empty())
+        .map(|iface| {
+            let implementors: Vec<&ObjectDef> = objects
+                .iter()
+                .filter(|o| o.interfaces.contains(&iface.name))
+                .collect();
+            let members: Vec<&str> = implementors.iter().map(|o| o.name.as_str()).collect();
+            let enum_tokens = emit_enum(&iface.name, &iface.description, &members);
+            let trait_tokens = emit_fields_trait(iface, &interface_map, type_kind_map);
+            let object_impls = implementors
+                .iter()
+                .map(|o| emit_object_impl(iface, o, &interface_map, type_kind_map));
+            let enum_impls = supers(iface, &interface_map)
+                .into_iter()
+                .chain(std::iter::once(iface))
+                .map(|i| emit_enum_impl(i, &iface.name, &members, &interface_map, type_kind_map));
+            quote! {
+                #enum_tokens
+                #trait_tokens
+                #(#object_impls)*
+                #(#enum_impls)*
+            }
+        });
+
+    let union_items = unions.iter().filter(|u| !u.name.is_empty()).map(|u| {
+        let members: Vec<&str> = u
+            .members
+            .iter()
+            .filter(|m| matches!(type_kind_map.get(m.as_str()), Some(TypeKind::Object)))
+            .map(String::as_str)
+            .collect();
+        emit_enum(&u.name, &u.description, &members)
+    });
+
+    quote! {
+        #(#interface_items)*
+        #(#union_items)*
+    }
+}
+
+/// The `__typename`-tagged enum of `members`, with its `GraphQLFields` impl
+/// selecting each member through an inline fragment.
+fn emit_enum(name: &str, description: &Option<String>, members: &[&str]) -> TokenStream {
+    let ident = quote::format_ident!("{}", name);
+    let doc = parser::doc_comment_tokens(description);
+    let variants = members.iter().map(|m| {
+        let member = quote::format_ident!("{}", m);
+        quote! { #member(Box<#member>), }
+    });
+    let fragments = members.iter().map(|m| {
+        let member = quote::format_ident!("{}", m);
+        quote! { (#m, <#member as GraphQLFields>::selection()) }
+    });
+    quote! {
+        #doc
+        #[derive(Debug, Clone, Serialize, Deserialize)]
+        #[serde(tag = "__typename")]
+        #[non_exhaustive]
+        pub enum #ident {
+            #(#variants)*
+            /// Catch-all for object types unknown to this SDK version.
+            #[serde(other)]
+            Unknown,
+        }
+
+        impl GraphQLFields for #ident {
+            type FullType = Self;
+
+            fn selection() -> String {
+                crate::field_selection::inline_fragments(&[#(#fragments),*])
+            }
+        }
+    }
+}
+
+/// Every interface `iface` implements, directly or through other interfaces.
+fn supers<'a>(
+    iface: &InterfaceDef,
+    interface_map: &HashMap<&str, &'a InterfaceDef>,
+) -> Vec<&'a InterfaceDef> {
+    let mut names = BTreeSet::new();
+    let mut stack: Vec<&str> = iface.interfaces.iter().map(String::as_str).collect();
+    while let Some(name) = stack.pop() {
+        if let Some(parent) = interface_map.get(name) {
+            if names.insert(name) {
+                stack.extend(parent.interfaces.iter().map(String::as_str));
+            }
+        }
+    }
+    names.into_iter().map(|n| interface_map[n]).collect()
+}
+
+/// The scalar and enum fields `iface` declares beyond those of the
+/// interfaces it implements; those come from its supertraits.
+fn own_fields<'a>(
+    iface: &'a InterfaceDef,
+    interface_map: &HashMap<&str, &InterfaceDef>,
+    type_kind_map: &HashMap<String, TypeKind>,
+) -> Vec<&'a FieldDef> {
+    let inherited: BTreeSet<&str> = supers(iface, interface_map)
+        .iter()
+        .flat_map(|i| i.fields.iter().map(|f| f.name.as_str()))
+        .collect();
+    iface
+        .fields
+        .iter()
+        .filter(|f| !f.name.is_empty() && !inherited.contains(f.name.as_str()))
+        .filter(|f| {
+            matches!(
+                type_kind_map.get(f.ty.base_name()),
+                Some(TypeKind::Scalar) | Some(TypeKind::Enum)
+            )
+        })
+        .collect()
+}
+
+fn trait_ident(iface: &InterfaceDef) -> proc_macro2::Ident {
+    quote::format_ident!("{}Fields", iface.name)
+}
+
+fn method_ident(field: &FieldDef) -> TokenStream {
+    parser::safe_ident(&field.name.to_snake_case())
+        .parse()
+        .unwrap()
+}
+
+fn emit_fields_trait(
+    iface: &InterfaceDef,
+    interface_map: &HashMap<&str, &InterfaceDef>,
+    type_kind_map: &HashMap<String, TypeKind>,
+) -> TokenStream {
+    let name = trait_ident(iface);
+    let trait_doc = format!(
+        " Fields shared by every [`{}`] type. Each returns `None` when not selected.",
+        iface.name
+    );
+    let parents: Vec<_> = iface
+        .interfaces
+        .iter()
+        .filter_map(|n| interface_map.get(n.as_str()))
+        .map(|i| trait_ident(i))
+        .collect();
+    let bounds = if parents.is_empty() {
+        quote! {}
+    } else {
+        quote! { : #(#parents)+* }
+    };
+    let methods = own_fields(iface, interface_map, type_kind_map)
+        .into_iter()
+        .map(|f| {
+            let method = method_ident(f);
+            let ty = resolve_required(&f.ty, type_kind_map);
+            let doc = parser::doc_comment_tokens(&f.description);
+            let deprecated = parser::deprecated_tokens(&f.deprecated);
+            quote! {
+                #doc
+                #deprecated
+                fn #method(&self) -> Option<&#ty>;
+            }
+        });
+    quote! {
+        #[doc = #trait_doc]
+        pub trait #name #bounds {
+            #(#methods)*
+        }
+    }
+}
+
+/// `impl {Interface}Fields for Object`, reading the object's own fields.
+fn emit_object_impl(
+    iface: &InterfaceDef,
+    obj: &ObjectDef,
+    interface_map: &HashMap<&str, &InterfaceDef>,
+    type_kind_map: &HashMap<String, TypeKind>,
+) -> TokenStream {
+    let name = trait_ident(iface);
+    let obj_ident = quote::format_ident!("{}", obj.name);
+    let methods = own_fields(iface, interface_map, type_kind_map)
+        .into_iter()
+        .map(|f| {
+            let method = method_ident(f);
+            let ty = resolve_required(&f.ty, type_kind_map);
+            let body = if obj.fields.iter().any(|of| of.name == f.name) {
+                quote! { self.#method.as_ref() }
+            } else {
+                quote! { None }
+            };
+            quote! {
+                fn #method(&self) -> Option<&#ty> {
+                    #body
+                }
+            }
+        });
+    quote! {
+        impl #name for #obj_ident {
+            #(#methods)*
+        }
+    }
+}
+
+/// `impl {Interface}Fields for Enum`, dispatching to the enum's variants.
+fn emit_enum_impl(
+    iface: &InterfaceDef,
+    enum_name: &str,
+    members: &[&str],
+    interface_map: &HashMap<&str, &InterfaceDef>,
+    type_kind_map: &HashMap<String, TypeKind>,
+) -> TokenStream {
+    let name = trait_ident(iface);
+    let enum_ident = quote::format_ident!("{}", enum_name);
+    let methods = own_fields(iface, interface_map, type_kind_map)
+        .into_iter()
+        .map(|f| {
+            let method = method_ident(f);
+            let ty = resolve_required(&f.ty, type_kind_map);
+            let arms = members.iter().map(|m| {
+                let member = quote::format_ident!("{}", m);
+                quote! { Self::#member(v) => #name::#method(&**v), }
+            });
+            quote! {
+                fn #method(&self) -> Option<&#ty> {
+                    match self {
+                        #(#arms)*
+                        Self::Unknown => None,
+                    }
+                }
+            }
+        });
+    quote! {
+        impl #name for #enum_ident {
+            #(#methods)*
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::GqlType;
+
+    fn field(name: &str, ty: &str) -> FieldDef {
+        FieldDef {
+            name: name.to_string(),
+            description: None,
+            ty: GqlType::Named(ty.to_string()),
+            arguments: vec![],
+            deprecated: None,
+        }
+    }
+
+    fn interface(name: &str, fields: Vec<FieldDef>, interfaces: &[&str]) -> InterfaceDef {
+        InterfaceDef {
+            name: name.to_string(),
+            description: None,
+            fields,
+            interfaces: interfaces.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+
+    fn object(name: &str, fields: Vec<FieldDef>, interfaces: &[&str]) -> ObjectDef {
+        ObjectDef {
+            name: name.to_string(),
+            description: None,
+            fields,
+            interfaces: interfaces.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+
+    fn schema() -> (
+        Vec<InterfaceDef>,
+        Vec<UnionDef>,
+        Vec<ObjectDef>,
+        HashMap<String, TypeKind>,
+    ) {
+        let interfaces = vec![
+            interface("Node", vec![field("id", "ID")], &[]),
+            interface(
+                "Entity",
+                vec![field("id", "ID"), field("createdAt", "DateTime")],
+                &["Node"],
+            ),
+        ];
+        let unions = vec![UnionDef {
+            name: "SearchResult".to_string(),
+            description: Some("A search hit.".to_string()),
+            members: vec!["User".to_string(), "Team".to_string()],
+        }];
+        let objects = vec![
+            object(
+                "User",
+                vec![field("id", "ID"), field("createdAt", "DateTime")],
+                &["Entity", "Node"],
+            ),
+            object("Team", vec![field("id", "ID")], &["Node"]),
+        ];
+        let mut map = HashMap::new();
+        for (name, kind) in [
+            ("ID", TypeKind::Scalar),
+            ("DateTime", TypeKind::Scalar),
+            ("Node", TypeKind::Interface),
+            ("Entity", TypeKind::Interface),
+            ("SearchResult", TypeKind::Union),
+            ("User", TypeKind::Object),
+            ("Team", TypeKind::Object),
+        ] {
+            map.insert(name.to_string(), kind);
+        }
+        (interfaces, unions, objects, map)
+    }
+
+    fn emitted() -> String {
+        let (interfaces, unions, objects, map) = schema();
+        emit(&interfaces, &unions, &objects, &map)
+            .to_string()
+            .split_whitespace()
+            .collect()
+    }
+
+    #[test]
+    fn emits_typename_tagged_enums() {
+        let output = emitted();
+        assert!(output
+            .contains("#[serde(tag=\"__typename\")]#[non_exhaustive]pubenumNode{User(Box<User>),Team(Box<Team>),"));
+        assert!(output.contains("pubenumEntity{User(Box<User>),#[doc"));
+        assert!(output
+            .contains("#[non_exhaustive]pubenumSearchResult{User(Box<User>),Team(Box<Team>),"));
+        assert!(output.contains(
+            "inline_fragments(&[(\"User\",<UserasGraphQLFields>::selection()),(\"Team\""
+        ));
+    }
+
+    #[test]
+    fn interface_traits_declare_only_their_own_fields() {
+        let output = emitted();
+        assert!(output.contains("pubtraitNodeFields{fnid(&self)->Option<&String>;}"));
+        assert!(output.contains("pubtraitEntityFields:NodeFields{fncreated_at(&self)"));
+        assert!(output.contains("implEntityFieldsforUser{fncreated_at(&self)"));
+        assert!(output.contains("implNodeFieldsforEntity{fnid(&self)->Option<&String>{matchself{Self::User(v)=>NodeFields::id(&**v),Self::Unknown=>None,}}}"));
+    }
+
+    #[test]
+    fn emitted_code_parses() {
+        let (interfaces, unions, objects, map) = schema();
+        let output = emit(&interfaces, &unions, &objects, &map).to_string();
+        syn::parse_file(&output).expect("Generated interface code should be valid Rust");
+    }
+}
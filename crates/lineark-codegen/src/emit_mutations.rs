@@ -1,9 +1,9 @@
 use crate::emit_queries::gql_type_string;
-use crate::parser::{self, FieldDef, GqlType, ObjectDef, TypeKind};
+use crate::parser::{self, FieldDef, GqlType, InputDef, ObjectDef, TypeKind};
 use heck::ToSnakeCase;
 use proc_macro2::TokenStream;
 use quote::quote;
-use std::collections::{HashMap, HashSet};
+use std::collections::HashMap;
 
 /// Result of emitting all mutation code.
 pub struct MutationEmitResult {
@@ -11,28 +11,52 @@ pub struct MutationEmitResult {
     pub mutations_tokens: TokenStream,
     /// `impl Client` block for `generated/client_impl.rs`.
     pub client_impl_tokens: TokenStream,
+    /// Operation constructors for `generated::operations`.
+    pub operations_tokens: TokenStream,
+    /// `impl blocking::Client` methods for `generated/blocking_client_impl.rs`.
+    pub blocking_client_impl_tokens: TokenStream,
 }
 
 pub fn emit(
     mutation_fields: &[FieldDef],
-    allowed: &HashSet<String>,
+    enabled: &HashMap<String, Option<String>>,
     renames: &HashMap<String, String>,
     objects: &[ObjectDef],
+    inputs: &[InputDef],
+    query_fields: &[FieldDef],
     type_kind_map: &HashMap<String, TypeKind>,
 ) -> MutationEmitResult {
-    let object_map: HashMap<&str, &ObjectDef> =
-        objects.iter().map(|o| (o.name.as_str(), o)).collect();
+    let schema = SchemaLookups {
+        entity_queries: entity_lookups(query_fields),
+        objects: objects.iter().map(|o| (o.name.as_str(), o)).collect(),
+        type_kinds: type_kind_map,
+    };
 
     let mut standalone_fns: Vec<TokenStream> = Vec::new();
+    let mut operation_fns: Vec<TokenStream> = Vec::new();
     let mut client_methods: Vec<TokenStream> = Vec::new();
+    let mut blocking_methods: Vec<TokenStream> = Vec::new();
 
-    for field in mutation_fields.iter().filter(|f| allowed.contains(&f.name)) {
+    for field in mutation_fields {
+        let Some(feature) = enabled.get(&field.name) else {
+            continue;
+        };
         let rename = renames.get(&field.name).map(|s| s.as_str());
-        if let Some((standalone, delegation)) =
-            emit_mutation(field, rename, &object_map, type_kind_map)
-        {
+        let id_entity = crate::emit_ids::id_entity(field, objects);
+        let cfg = crate::domains::cfg_tokens(feature.as_deref());
+        if let Some(emitted) = emit_mutation(
+            field,
+            rename,
+            id_entity.as_deref(),
+            &cfg,
+            client_id_arg(field, inputs),
+            &schema,
+        ) {
+            let (operation, standalone, delegation, blocking) = emitted;
+            operation_fns.push(operation);
             standalone_fns.push(standalone);
             client_methods.push(delegation);
+            blocking_methods.push(blocking);
         }
     }
 
@@ -56,6 +80,9 @@ pub fn emit(
 
             use crate::client::Client;
             use crate::error::LinearError;
+            // Only feature-gated operations may take enum arguments.
+            #[allow(unused_imports)]
+            use super::enums::*;
             use super::inputs::*;
 
             #(#standalone_fns)*
@@ -69,35 +96,95 @@ pub fn emit(
     MutationEmitResult {
         mutations_tokens,
         client_impl_tokens,
+        operations_tokens: quote! { #(#operation_fns)* },
+        blocking_client_impl_tokens: quote! { #(#blocking_methods)* },
     }
 }
 
-/// Generate a standalone mutation function + Client delegation method.
+/// Schema lookups shared by every mutation.
+struct SchemaLookups<'a> {
+    /// See [`entity_lookups`].
+    entity_queries: HashMap<String, String>,
+    objects: HashMap<&'a str, &'a ObjectDef>,
+    type_kinds: &'a HashMap<String, TypeKind>,
+}
+
+/// Root query fields that fetch an entity by `id`, keyed by the entity type
+/// (`Issue` → `issue`).
+fn entity_lookups(query_fields: &[FieldDef]) -> HashMap<String, String> {
+    let mut lookups = HashMap::new();
+    for field in query_fields {
+        let singular = !is_list_type(&field.ty);
+        let by_id = field
+            .arguments
+            .iter()
+            .any(|a| a.name == "id" && matches!(a.ty.base_name(), "String" | "ID"));
+        if singular && by_id {
+            lookups
+                .entry(field.ty.base_name().to_string())
+                .or_insert_with(|| field.name.clone());
+        }
+    }
+    lookups
+}
+
+/// The argument of a create mutation whose input takes an optional
+/// client-generated `id` (`issueCreate(input: IssueCreateInput!)` → `input`).
+fn client_id_arg<'a>(field: &'a FieldDef, inputs: &[InputDef]) -> Option<&'a str> {
+    field
+        .arguments
+        .iter()
+        .find(|arg| {
+            let name = arg.ty.base_name();
+            name.ends_with("CreateInput")
+                && inputs.iter().any(|input| {
+                    input.name == name
+                        && input
+                            .fields
+                            .iter()
+                            .any(|f| f.name == "id" && !matches!(f.ty, GqlType::NonNull(_)))
+                })
+        })
+        .map(|arg| arg.name.as_str())
+}
+
+/// Generate an operation constructor, a standalone mutation function, and
+/// Client and blocking Client delegation methods.
+///
+/// Create mutations with a `client_id_arg` whose entity can be fetched by ID
+/// are marked with `with_client_id`, so idempotent clients can fill in the ID.
 fn emit_mutation(
     field: &FieldDef,
     rename: Option<&str>,
-    object_map: &HashMap<&str, &ObjectDef>,
-    type_kind_map: &HashMap<String, TypeKind>,
-) -> Option<(TokenStream, TokenStream)> {
+    id_entity: Option<&str>,
+    cfg: &TokenStream,
+    client_id_arg: Option<&str>,
+    schema: &SchemaLookups,
+) -> Option<(TokenStream, TokenStream, TokenStream, TokenStream)> {
+    let (lookups, object_map, type_kind_map) =
+        (&schema.entity_queries, &schema.objects, schema.type_kinds);
     let method_name =
         quote::format_ident!("{}", rename.unwrap_or(field.name.as_str()).to_snake_case());
     let payload_type_name = field.ty.base_name();
     let payload_obj = object_map.get(payload_type_name)?;
 
     let (params, variables_json, graphql_args, graphql_params) =
-        build_mutation_args(&field.arguments, type_kind_map);
+        build_mutation_args(&field.arguments, id_entity, type_kind_map);
 
     let mutation_name = &field.name;
     let operation_name = capitalize_first(mutation_name);
     let data_path = mutation_name.as_str();
     let doc = parser::doc_comment_tokens(&field.description);
+    let deprecated = parser::deprecated_tokens(&field.deprecated);
+    let doc = quote! { #doc #deprecated };
 
     // Build call args for client delegation.
     let call_args: Vec<TokenStream> = field
         .arguments
         .iter()
         .map(|arg| {
-            let param_name = quote::format_ident!("{}", arg.name.to_snake_case());
+            let param_name =
+                quote::format_ident!("{}", parser::safe_ident(&arg.name.to_snake_case()));
             quote! { #param_name }
         })
         .collect();
@@ -111,7 +198,7 @@ fn emit_mutation(
     for pf in &payload_obj.fields {
         let base = pf.ty.base_name();
         match type_kind_map.get(base) {
-            Some(TypeKind::Scalar) | Some(TypeKind::Enum) if pf.name != "lastSyncId" => {
+            Some(TypeKind::Scalar) | Some(TypeKind::Enum) => {
                 scalar_parts.push(pf.name.clone());
             }
             // Only treat as entity if the Object type implements Node (has an `id` field),
@@ -135,35 +222,66 @@ fn emit_mutation(
         let doc = quote! { #doc #[doc = ""] #[doc = #type_hint] };
         let entity_field_lit = entity_field_name.as_str();
 
-        let query_prefix = format!(
-            "mutation {}({}) {{ {}({}) {{ success {} {{ ",
-            operation_name, graphql_params, mutation_name, graphql_args, entity_field_name,
+        // `lastSyncId` is selected so the client can track read-your-writes
+        // consistency; it is not part of the decoded entity.
+        let sync_id = if scalar_parts.iter().any(|p| p == "lastSyncId") {
+            "lastSyncId "
+        } else {
+            ""
+        };
+        let selection_prefix = format!(
+            "{}({}) {{ success {}{} {{ ",
+            mutation_name, graphql_args, sync_id, entity_field_name,
         );
-        let query_suffix = " } } }";
+        let selection_suffix = " } }";
 
         // List entity fields (e.g. `issues: [Issue!]!`) use Vec<T>;
         // singular entity fields (e.g. `issue: Issue!`) use T.
         // Both go through execute_mutation — Vec<T> implements GraphQLFields
         // via blanket impl, and serde handles JSON array deserialization.
-        let (return_type, execute_type) = if is_list {
-            (quote! { Vec<T> }, quote! { Vec<T> })
+        let return_type = if is_list {
+            quote! { Vec<T> }
         } else {
-            (quote! { T }, quote! { T })
+            quote! { T }
+        };
+
+        let client_id = match (client_id_arg, lookups.get(&entity_type_name)) {
+            (Some(input_arg), Some(lookup_field)) if !is_list => {
+                let lookup_prefix = format!("{}(id: $id) {{ ", lookup_field);
+                quote! {
+                    .with_client_id(#input_arg, String::from(#lookup_prefix) + &T::selection() + " }")
+                }
+            }
+            _ => quote! {},
+        };
+
+        let operation_fn = quote! {
+            #doc
+            #cfg
+            pub fn #method_name<T: serde::de::DeserializeOwned + crate::field_selection::GraphQLFields<FullType = super::types::#entity_type_ident>>(
+                #(#params),*
+            ) -> Operation<#return_type> {
+                let variables = serde_json::json!({ #(#variables_json),* });
+                let selection = String::from(#selection_prefix) + &T::selection() + #selection_suffix;
+                Operation::mutation(#operation_name, #graphql_params, selection, variables, #data_path)
+                    .with_entity_field(#entity_field_lit)
+                    #client_id
+            }
         };
 
         let standalone_fn = quote! {
             #doc
+            #cfg
             pub async fn #method_name<T: serde::de::DeserializeOwned + crate::field_selection::GraphQLFields<FullType = super::types::#entity_type_ident>>(
                 client: &Client, #(#params),*
             ) -> Result<#return_type, LinearError> {
-                let variables = serde_json::json!({ #(#variables_json),* });
-                let query = String::from(#query_prefix) + &T::selection() + #query_suffix;
-                client.execute_mutation::<#execute_type>(&query, variables, #data_path, #entity_field_lit).await
+                super::operations::#method_name::<T>(#(#call_args),*).execute(client).await
             }
         };
 
         let client_method = quote! {
             #doc
+            #cfg
             pub async fn #method_name<T: serde::de::DeserializeOwned + crate::field_selection::GraphQLFields<FullType = super::types::#entity_type_ident>>(
                 &self, #(#params),*
             ) -> Result<#return_type, LinearError> {
@@ -171,9 +289,68 @@ fn emit_mutation(
             }
         };
 
-        Some((standalone_fn, client_method))
+        let blocking_method = quote! {
+            #doc
+            #cfg
+            pub fn #method_name<T: serde::de::DeserializeOwned + crate::field_selection::GraphQLFields<FullType = super::types::#entity_type_ident>>(
+                &self, #(#params),*
+            ) -> Result<#return_type, LinearError> {
+                self.block_on(crate::generated::mutations::#method_name::<T>(self.as_async(), #(#call_args),*))
+            }
+        };
+
+        Some((operation_fn, standalone_fn, client_method, blocking_method))
+    } else if payload_obj.fields.iter().all(|pf| {
+        matches!(
+            type_kind_map.get(pf.ty.base_name()),
+            Some(TypeKind::Scalar) | Some(TypeKind::Enum)
+        )
+    }) {
+        // ── Scalar-only payload (e.g. DeletePayload): return the typed payload ──
+        let payload_ident = quote::format_ident!("{}", payload_type_name);
+        let payload_type = quote! { super::types::#payload_ident };
+        let selection_prefix = format!("{}({}) {{ ", mutation_name, graphql_args);
+        let selection_suffix = " }";
+
+        let operation_fn = quote! {
+            #doc
+            #cfg
+            pub fn #method_name(#(#params),*) -> Operation<#payload_type> {
+                let variables = serde_json::json!({ #(#variables_json),* });
+                let selection = String::from(#selection_prefix)
+                    + &<#payload_type as crate::field_selection::GraphQLFields>::selection()
+                    + #selection_suffix;
+                Operation::mutation(#operation_name, #graphql_params, selection, variables, #data_path)
+            }
+        };
+
+        let standalone_fn = quote! {
+            #doc
+            #cfg
+            pub async fn #method_name(client: &Client, #(#params),*) -> Result<#payload_type, LinearError> {
+                super::operations::#method_name(#(#call_args),*).execute(client).await
+            }
+        };
+
+        let client_method = quote! {
+            #doc
+            #cfg
+            pub async fn #method_name(&self, #(#params),*) -> Result<#payload_type, LinearError> {
+                crate::generated::mutations::#method_name(self, #(#call_args),*).await
+            }
+        };
+
+        let blocking_method = quote! {
+            #doc
+            #cfg
+            pub fn #method_name(&self, #(#params),*) -> Result<#payload_type, LinearError> {
+                self.block_on(crate::generated::mutations::#method_name(self.as_async(), #(#call_args),*))
+            }
+        };
+
+        Some((operation_fn, standalone_fn, client_method, blocking_method))
     } else {
-        // ── Non-entity mutation (e.g. file_upload): keep Value return ──
+        // ── Payload with nested objects (e.g. file_upload): keep Value return ──
         let mut entity_selection_exprs: Vec<TokenStream> = Vec::new();
 
         for pf in &payload_obj.fields {
@@ -191,11 +368,8 @@ fn emit_mutation(
             }
         }
 
-        let query_prefix = format!(
-            "mutation {}({}) {{ {}({}) {{ ",
-            operation_name, graphql_params, mutation_name, graphql_args,
-        );
-        let query_suffix = " } }";
+        let selection_prefix = format!("{}({}) {{ ", mutation_name, graphql_args);
+        let selection_suffix = " }";
 
         let has_entities = !entity_selection_exprs.is_empty();
         let response_parts_decl = if has_entities {
@@ -204,31 +378,53 @@ fn emit_mutation(
             quote! { let response_parts: Vec<String> = vec![#(#scalar_parts.to_string()),*]; }
         };
 
-        let standalone_fn = quote! {
+        let operation_fn = quote! {
             #doc
-            pub async fn #method_name(client: &Client, #(#params),*) -> Result<serde_json::Value, LinearError> {
+            #cfg
+            pub fn #method_name(#(#params),*) -> Operation<serde_json::Value> {
                 let variables = serde_json::json!({ #(#variables_json),* });
                 #response_parts_decl
                 #(#entity_selection_exprs)*
-                let query = String::from(#query_prefix) + &response_parts.join(" ") + #query_suffix;
-                client.execute::<serde_json::Value>(&query, variables, #data_path).await
+                let selection = String::from(#selection_prefix) + &response_parts.join(" ") + #selection_suffix;
+                Operation::mutation(#operation_name, #graphql_params, selection, variables, #data_path)
+            }
+        };
+
+        let standalone_fn = quote! {
+            #doc
+            #cfg
+            pub async fn #method_name(client: &Client, #(#params),*) -> Result<serde_json::Value, LinearError> {
+                super::operations::#method_name(#(#call_args),*).execute(client).await
             }
         };
 
         let client_method = quote! {
             #doc
+            #cfg
             pub async fn #method_name(&self, #(#params),*) -> Result<serde_json::Value, LinearError> {
                 crate::generated::mutations::#method_name(self, #(#call_args),*).await
             }
         };
 
-        Some((standalone_fn, client_method))
+        let blocking_method = quote! {
+            #doc
+            #cfg
+            pub fn #method_name(&self, #(#params),*) -> Result<serde_json::Value, LinearError> {
+                self.block_on(crate::generated::mutations::#method_name(self.as_async(), #(#call_args),*))
+            }
+        };
+
+        Some((operation_fn, standalone_fn, client_method, blocking_method))
     }
 }
 
 /// Build parameter tokens for mutation arguments.
+///
+/// The `id` argument of a mutation acting on `id_entity` takes
+/// `impl Into<…Id>` rather than a plain `String`.
 fn build_mutation_args(
     arguments: &[crate::parser::ArgumentDef],
+    id_entity: Option<&str>,
     type_kind_map: &HashMap<String, TypeKind>,
 ) -> (
     Vec<TokenStream>, // Rust fn params
@@ -242,14 +438,23 @@ fn build_mutation_args(
     let mut gql_params = Vec::new();
 
     for arg in arguments {
-        let param_name = quote::format_ident!("{}", arg.name.to_snake_case());
+        let param_name = quote::format_ident!("{}", parser::safe_ident(&arg.name.to_snake_case()));
         let gql_type_str = gql_type_string(&arg.ty);
-        let rust_type = resolve_mutation_arg_type(&arg.ty, type_kind_map);
-
-        params.push(quote! { #param_name: #rust_type });
-
         let arg_name_str = &arg.name;
-        variables.push(quote! { #arg_name_str: #param_name });
+        match id_entity.filter(|_| arg.name == "id") {
+            Some(entity) => {
+                let id_type = crate::emit_ids::id_type_ident(entity);
+                params.push(quote! { #param_name: impl Into<super::ids::#id_type> });
+                variables.push(
+                    quote! { #arg_name_str: Into::<super::ids::#id_type>::into(#param_name) },
+                );
+            }
+            None => {
+                let rust_type = resolve_mutation_arg_type(&arg.ty, type_kind_map);
+                params.push(quote! { #param_name: #rust_type });
+                variables.push(quote! { #arg_name_str: #param_name });
+            }
+        }
 
         gql_args.push(format!("{}: ${}", arg.name, arg.name));
         gql_params.push(format!("${}: {}", arg.name, gql_type_str));
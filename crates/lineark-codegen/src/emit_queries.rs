@@ -3,7 +3,7 @@ use crate::parser::{self, ArgumentDef, FieldDef, GqlType, ObjectDef, TypeKind};
 use heck::{ToSnakeCase, ToUpperCamelCase};
 use proc_macro2::TokenStream;
 use quote::quote;
-use std::collections::{HashMap, HashSet};
+use std::collections::HashMap;
 
 /// Result of emitting all query code.
 pub struct QueryEmitResult {
@@ -11,11 +11,17 @@ pub struct QueryEmitResult {
     pub queries_tokens: TokenStream,
     /// `impl Client` block for `generated/client_impl.rs`.
     pub client_impl_tokens: TokenStream,
+    /// Operation constructors for `generated::operations`.
+    pub operations_tokens: TokenStream,
+    /// Blocking builder wrappers for `generated/blocking_client_impl.rs`.
+    pub blocking_builders_tokens: TokenStream,
+    /// `impl blocking::Client` methods for `generated/blocking_client_impl.rs`.
+    pub blocking_client_impl_tokens: TokenStream,
 }
 
 pub fn emit(
     query_fields: &[FieldDef],
-    allowed: &HashSet<String>,
+    enabled: &HashMap<String, Option<String>>,
     renames: &HashMap<String, String>,
     objects: &[ObjectDef],
     type_kind_map: &HashMap<String, TypeKind>,
@@ -25,14 +31,38 @@ pub fn emit(
 
     let mut builder_items: Vec<TokenStream> = Vec::new();
     let mut standalone_fns: Vec<TokenStream> = Vec::new();
+    let mut operation_fns: Vec<TokenStream> = Vec::new();
     let mut client_methods: Vec<TokenStream> = Vec::new();
+    let mut blocking_builders: Vec<TokenStream> = Vec::new();
+    let mut blocking_methods: Vec<TokenStream> = Vec::new();
 
-    for field in query_fields.iter().filter(|f| allowed.contains(&f.name)) {
+    for field in query_fields {
+        let Some(feature) = enabled.get(&field.name) else {
+            continue;
+        };
+        // Results are selected through `T`; scalar results have nothing to select.
+        if !matches!(
+            type_kind_map.get(field.ty.base_name()),
+            Some(TypeKind::Object | TypeKind::Interface | TypeKind::Union)
+        ) {
+            continue;
+        }
         let rename = renames.get(&field.name).map(|s| s.as_str());
-        let result = emit_query(field, rename, &object_map, type_kind_map);
+        let id_entity = crate::emit_ids::id_entity(field, objects);
+        let result = emit_query(
+            field,
+            rename,
+            id_entity.as_deref(),
+            feature.as_deref(),
+            &object_map,
+            type_kind_map,
+        );
         builder_items.extend(result.builders);
         standalone_fns.extend(result.standalone_fns);
+        operation_fns.extend(result.operation_fns);
         client_methods.push(result.client_method);
+        blocking_builders.extend(result.blocking_builders);
+        blocking_methods.push(result.blocking_client_method);
     }
 
     let queries_tokens = quote! {
@@ -47,6 +77,7 @@ pub fn emit(
         use crate::client::Client;
         use crate::error::LinearError;
         use crate::field_selection::GraphQLFields;
+        use crate::operation::Operation;
         use crate::pagination::Connection;
         use serde::de::DeserializeOwned;
         use super::enums::*;
@@ -64,24 +95,36 @@ pub fn emit(
     QueryEmitResult {
         queries_tokens,
         client_impl_tokens,
+        operations_tokens: quote! { #(#operation_fns)* },
+        blocking_builders_tokens: quote! { #(#blocking_builders)* },
+        blocking_client_impl_tokens: quote! { #(#blocking_methods)* },
     }
 }
 
 // ── Argument classification ──────────────────────────────────────────────────
 
 /// Parsed info for a single query argument.
-struct ArgInfo {
-    param_name: proc_macro2::Ident,
-    gql_name: String,
-    rust_inner_type: TokenStream,
-    gql_type_str: String,
-    is_required: bool,
-    is_string_type: bool,
+pub(crate) struct ArgInfo {
+    pub(crate) param_name: proc_macro2::Ident,
+    pub(crate) gql_name: String,
+    pub(crate) rust_inner_type: TokenStream,
+    pub(crate) gql_type_str: String,
+    pub(crate) is_required: bool,
+    pub(crate) is_string_type: bool,
+    pub(crate) is_copy_type: bool,
+    /// Typed as an entity ID newtype and accepted as `impl Into<…Id>`.
+    pub(crate) is_id: bool,
+    /// `#[deprecated]` attribute for the argument's setter, if deprecated.
+    pub(crate) deprecated: TokenStream,
 }
 
 /// Classify query arguments, supporting scalars, enums, and input objects.
-fn classify_args(
+///
+/// The `id` argument of a field acting on `id_entity` is typed as that
+/// entity's ID newtype.
+pub(crate) fn classify_args(
     arguments: &[ArgumentDef],
+    id_entity: Option<&str>,
     type_kind_map: &HashMap<String, TypeKind>,
 ) -> Vec<ArgInfo> {
     arguments
@@ -90,9 +133,17 @@ fn classify_args(
             let base = arg.ty.base_name();
             let is_required = matches!(arg.ty, GqlType::NonNull(_));
             let is_string_type = matches!(base, "String" | "ID");
+            let is_copy_type =
+                matches!(base, "Int" | "Float" | "Boolean") && !is_list_type(&arg.ty);
+            let id_type = id_entity
+                .filter(|_| arg.name == "id")
+                .map(crate::emit_ids::id_type_ident);
 
             let rust_inner_type = match type_kind_map.get(base) {
-                Some(TypeKind::Scalar) => resolve_arg_inner(&arg.ty),
+                Some(TypeKind::Scalar) => match &id_type {
+                    Some(ident) => quote! { super::ids::#ident },
+                    None => resolve_arg_inner(&arg.ty),
+                },
                 Some(TypeKind::Enum) => {
                     let ident = quote::format_ident!("{}", base);
                     quote! { #ident }
@@ -105,12 +156,18 @@ fn classify_args(
             };
 
             Some(ArgInfo {
-                param_name: quote::format_ident!("{}", arg.name.to_snake_case()),
+                param_name: quote::format_ident!(
+                    "{}",
+                    parser::safe_ident(&arg.name.to_snake_case())
+                ),
                 gql_name: arg.name.clone(),
                 rust_inner_type,
                 gql_type_str: gql_type_string(&arg.ty),
                 is_required,
                 is_string_type,
+                is_copy_type,
+                is_id: id_type.is_some(),
+                deprecated: parser::deprecated_tokens(&arg.deprecated),
             })
         })
         .collect()
@@ -121,7 +178,10 @@ fn classify_args(
 struct QueryResult {
     builders: Vec<TokenStream>,
     standalone_fns: Vec<TokenStream>,
+    operation_fns: Vec<TokenStream>,
     client_method: TokenStream,
+    blocking_builders: Vec<TokenStream>,
+    blocking_client_method: TokenStream,
 }
 
 // ── Main dispatch ────────────────────────────────────────────────────────────
@@ -129,17 +189,24 @@ struct QueryResult {
 fn emit_query(
     field: &FieldDef,
     rename: Option<&str>,
+    id_entity: Option<&str>,
+    feature: Option<&str>,
     object_map: &HashMap<&str, &ObjectDef>,
     type_kind_map: &HashMap<String, TypeKind>,
 ) -> QueryResult {
-    let args = classify_args(&field.arguments, type_kind_map);
+    let args = classify_args(&field.arguments, id_entity, type_kind_map);
     let has_optional = args.iter().any(|a| !a.is_required);
     let return_type_name = field.ty.base_name();
-    let is_connection =
-        return_type_name.ends_with("Connection") || return_type_name.ends_with("Payload");
+    // Search payloads page like connections; other payloads are plain objects.
+    let has_nodes = object_map
+        .get(return_type_name)
+        .is_some_and(|obj| obj.fields.iter().any(|f| f.name == "nodes"));
+    let is_connection = return_type_name.ends_with("Connection")
+        || (return_type_name.ends_with("Payload") && has_nodes);
 
     // Nullable return type: schema has `Type` (nullable) vs `Type!` (non-null).
     let is_nullable = !is_connection && !matches!(field.ty, GqlType::NonNull(_));
+    let return_type = result_type(is_connection, is_nullable, is_list_type(&field.ty));
 
     if has_optional {
         emit_builder_query(
@@ -147,9 +214,9 @@ fn emit_query(
             rename,
             &args,
             is_connection,
-            is_nullable,
+            return_type,
+            feature,
             object_map,
-            type_kind_map,
         )
     } else {
         emit_direct_query(
@@ -157,9 +224,9 @@ fn emit_query(
             rename,
             &args,
             is_connection,
-            is_nullable,
+            return_type,
+            feature,
             object_map,
-            type_kind_map,
         )
     }
 }
@@ -171,10 +238,11 @@ fn emit_direct_query(
     rename: Option<&str>,
     args: &[ArgInfo],
     is_connection: bool,
-    is_nullable: bool,
+    return_type: TokenStream,
+    feature: Option<&str>,
     object_map: &HashMap<&str, &ObjectDef>,
-    _type_kind_map: &HashMap<String, TypeKind>,
 ) -> QueryResult {
+    let cfg = crate::domains::cfg_tokens(feature);
     let method_name =
         quote::format_ident!("{}", rename.unwrap_or(field.name.as_str()).to_snake_case());
 
@@ -183,7 +251,11 @@ fn emit_direct_query(
         .map(|a| {
             let name = &a.param_name;
             let ty = &a.rust_inner_type;
-            quote! { #name: #ty }
+            if a.is_id {
+                quote! { #name: impl Into<#ty> }
+            } else {
+                quote! { #name: #ty }
+            }
         })
         .collect();
 
@@ -192,7 +264,12 @@ fn emit_direct_query(
         .map(|a| {
             let gql_name = &a.gql_name;
             let param_name = &a.param_name;
-            quote! { #gql_name: #param_name }
+            let ty = &a.rust_inner_type;
+            if a.is_id {
+                quote! { #gql_name: Into::<#ty>::into(#param_name) }
+            } else {
+                quote! { #gql_name: #param_name }
+            }
         })
         .collect();
 
@@ -211,7 +288,8 @@ fn emit_direct_query(
     };
     let node_type_ident = quote::format_ident!("{}", node_type_name);
     let type_hint = format!(" Full type: [`{node_type_name}`](super::types::{node_type_name})");
-    let doc = quote! { #base_doc #[doc = ""] #[doc = #type_hint] };
+    let deprecated = parser::deprecated_tokens(&field.deprecated);
+    let doc = quote! { #base_doc #[doc = ""] #[doc = #type_hint] #deprecated };
 
     let call_args: Vec<TokenStream> = args
         .iter()
@@ -221,101 +299,72 @@ fn emit_direct_query(
         })
         .collect();
 
-    if is_connection {
-        let query_name = field.name.to_upper_camel_case();
+    let query_name = field.name.to_upper_camel_case();
+    let selection_build = root_selection(data_path, &gql_args_str, is_connection);
 
-        let query_build = if gql_params_str.is_empty() {
-            quote! {
-                let selection = T::selection();
-                let query = format!(
-                    "query {} {{ {} {{ nodes {{ {} }} pageInfo {{ hasNextPage endCursor }} }} }}",
-                    #query_name, #data_path, selection
-                );
-            }
-        } else {
-            quote! {
-                let selection = T::selection();
-                let query = format!(
-                    "query {}({}) {{ {}({}) {{ nodes {{ {} }} pageInfo {{ hasNextPage endCursor }} }} }}",
-                    #query_name, #gql_params_str, #data_path, #gql_args_str, selection
-                );
-            }
-        };
-
-        let standalone_fn = quote! {
-            #doc
-            pub async fn #method_name<T: DeserializeOwned + GraphQLFields<FullType = super::types::#node_type_ident>>(client: &Client, #(#params),*) -> Result<Connection<T>, LinearError> {
-                let variables = serde_json::json!({ #(#variables_json),* });
-                #query_build
-                client.execute_connection::<T>(&query, variables, #data_path).await
-            }
-        };
-
-        let client_method = quote! {
-            #doc
-            pub async fn #method_name<T: DeserializeOwned + GraphQLFields<FullType = super::types::#node_type_ident>>(&self, #(#params),*) -> Result<Connection<T>, LinearError> {
-                crate::generated::queries::#method_name::<T>(self, #(#call_args),*).await
-            }
-        };
-
-        QueryResult {
-            builders: vec![],
-            standalone_fns: vec![standalone_fn],
-            client_method,
+    let operation_fn = quote! {
+        #doc
+        #cfg
+        pub fn #method_name<T: DeserializeOwned + GraphQLFields<FullType = super::types::#node_type_ident>>(#(#params),*) -> Operation<#return_type> {
+            let variables = serde_json::json!({ #(#variables_json),* });
+            #selection_build
+            Operation::query(#query_name, #gql_params_str, selection, variables, #data_path)
         }
-    } else {
-        let query_name = field.name.to_upper_camel_case();
+    };
 
-        let query_build = if gql_params_str.is_empty() {
-            quote! {
-                let selection = T::selection();
-                let query = format!(
-                    "query {} {{ {} {{ {} }} }}",
-                    #query_name, #data_path, selection
-                );
-            }
-        } else {
-            quote! {
-                let selection = T::selection();
-                let query = format!(
-                    "query {}({}) {{ {}({}) {{ {} }} }}",
-                    #query_name, #gql_params_str, #data_path, #gql_args_str, selection
-                );
-            }
-        };
+    let standalone_fn = quote! {
+        #doc
+        #cfg
+        pub async fn #method_name<T: DeserializeOwned + GraphQLFields<FullType = super::types::#node_type_ident>>(client: &Client, #(#params),*) -> Result<#return_type, LinearError> {
+            super::operations::#method_name::<T>(#(#call_args),*).execute(client).await
+        }
+    };
 
-        let (return_type, execute_call) = if is_nullable {
-            (
-                quote! { Option<T> },
-                quote! { client.execute::<Option<T>>(&query, variables, #data_path).await },
-            )
-        } else {
-            (
-                quote! { T },
-                quote! { client.execute::<T>(&query, variables, #data_path).await },
-            )
-        };
+    let client_method = quote! {
+        #doc
+        #cfg
+        pub async fn #method_name<T: DeserializeOwned + GraphQLFields<FullType = super::types::#node_type_ident>>(&self, #(#params),*) -> Result<#return_type, LinearError> {
+            crate::generated::queries::#method_name::<T>(self, #(#call_args),*).await
+        }
+    };
 
-        let standalone_fn = quote! {
-            #doc
-            pub async fn #method_name<T: DeserializeOwned + GraphQLFields<FullType = super::types::#node_type_ident>>(client: &Client, #(#params),*) -> Result<#return_type, LinearError> {
-                let variables = serde_json::json!({ #(#variables_json),* });
-                #query_build
-                #execute_call
-            }
-        };
+    let blocking_client_method = quote! {
+        #doc
+        #cfg
+        pub fn #method_name<T: DeserializeOwned + GraphQLFields<FullType = super::types::#node_type_ident>>(&self, #(#params),*) -> Result<#return_type, LinearError> {
+            self.block_on(crate::generated::queries::#method_name::<T>(self.as_async(), #(#call_args),*))
+        }
+    };
 
-        let client_method = quote! {
-            #doc
-            pub async fn #method_name<T: DeserializeOwned + GraphQLFields<FullType = super::types::#node_type_ident>>(&self, #(#params),*) -> Result<#return_type, LinearError> {
-                crate::generated::queries::#method_name::<T>(self, #(#call_args),*).await
-            }
-        };
+    QueryResult {
+        builders: vec![],
+        standalone_fns: vec![standalone_fn],
+        operation_fns: vec![operation_fn],
+        client_method,
+        blocking_builders: vec![],
+        blocking_client_method,
+    }
+}
 
-        QueryResult {
-            builders: vec![],
-            standalone_fns: vec![standalone_fn],
-            client_method,
+/// Tokens binding `selection` to the root field of a query — the field call
+/// with its arguments plus the sub-selection built from `T::selection()`.
+fn root_selection(data_path: &str, gql_args_str: &str, is_connection: bool) -> TokenStream {
+    let field_call = if gql_args_str.is_empty() {
+        data_path.to_string()
+    } else {
+        format!("{}({})", data_path, gql_args_str)
+    };
+    if is_connection {
+        quote! {
+            let selection = format!(
+                "{} {{ nodes {{ {} }} pageInfo {{ hasNextPage endCursor }} }}",
+                #field_call,
+                T::selection()
+            );
+        }
+    } else {
+        quote! {
+            let selection = format!("{} {{ {} }}", #field_call, T::selection());
         }
     }
 }
@@ -327,10 +376,12 @@ fn emit_builder_query(
     rename: Option<&str>,
     args: &[ArgInfo],
     is_connection: bool,
-    is_nullable: bool,
+    return_type: TokenStream,
+    feature: Option<&str>,
     object_map: &HashMap<&str, &ObjectDef>,
-    _type_kind_map: &HashMap<String, TypeKind>,
 ) -> QueryResult {
+    let cfg = crate::domains::cfg_tokens(feature);
+    let cfg_impl = crate::domains::cfg_impl_tokens(feature);
     let rust_name = rename.unwrap_or(field.name.as_str());
     let method_name = quote::format_ident!("{}", rust_name.to_snake_case());
     let builder_name = quote::format_ident!("{}QueryBuilder", rust_name.to_upper_camel_case());
@@ -347,7 +398,8 @@ fn emit_builder_query(
     };
     let node_type_ident = quote::format_ident!("{}", node_type_name);
     let type_hint = format!(" Full type: [`{node_type_name}`](super::types::{node_type_name})");
-    let doc = quote! { #base_doc #[doc = ""] #[doc = #type_hint] };
+    let deprecated = parser::deprecated_tokens(&field.deprecated);
+    let doc = quote! { #base_doc #[doc = ""] #[doc = #type_hint] #deprecated };
 
     let required_args: Vec<&ArgInfo> = args.iter().filter(|a| a.is_required).collect();
     let optional_args: Vec<&ArgInfo> = args.iter().filter(|a| !a.is_required).collect();
@@ -365,6 +417,7 @@ fn emit_builder_query(
             let ty = &a.rust_inner_type;
             fields.push(quote! { #name: Option<#ty> });
         }
+        fields.push(quote! { extra_variables: serde_json::Map<String, serde_json::Value> });
         fields.push(quote! { _marker: std::marker::PhantomData<T> });
         fields
     };
@@ -375,8 +428,10 @@ fn emit_builder_query(
         .map(|a| {
             let name = &a.param_name;
             let ty = &a.rust_inner_type;
+            let deprecated = &a.deprecated;
             if a.is_string_type {
                 quote! {
+                    #deprecated
                     pub fn #name(mut self, value: impl Into<#ty>) -> Self {
                         self.#name = Some(value.into());
                         self
@@ -384,6 +439,7 @@ fn emit_builder_query(
                 }
             } else {
                 quote! {
+                    #deprecated
                     pub fn #name(mut self, value: #ty) -> Self {
                         self.#name = Some(value);
                         self
@@ -415,7 +471,7 @@ fn emit_builder_query(
         })
         .collect();
     let build_variables = quote! {
-        let mut map = serde_json::Map::new();
+        let mut map = self.extra_variables;
         #(#required_var_inserts)*
         #(#optional_var_inserts)*
         let variables = serde_json::Value::Object(map);
@@ -424,78 +480,10 @@ fn emit_builder_query(
     let (gql_args_str, gql_params_str) = build_gql_strings(args);
     let data_path = &field.name;
 
-    // ── send() return type and body ──
-    let (send_return_type, send_body) = if is_connection {
-        let query_name = field.name.to_upper_camel_case();
-
-        let query_build = if gql_params_str.is_empty() {
-            quote! {
-                let selection = T::selection();
-                let query = format!(
-                    "query {} {{ {} {{ nodes {{ {} }} pageInfo {{ hasNextPage endCursor }} }} }}",
-                    #query_name, #data_path, selection
-                );
-            }
-        } else {
-            quote! {
-                let selection = T::selection();
-                let query = format!(
-                    "query {}({}) {{ {}({}) {{ nodes {{ {} }} pageInfo {{ hasNextPage endCursor }} }} }}",
-                    #query_name, #gql_params_str, #data_path, #gql_args_str, selection
-                );
-            }
-        };
-
-        (
-            quote! { Connection<T> },
-            quote! {
-                #build_variables
-                #query_build
-                self.client.execute_connection::<T>(&query, variables, #data_path).await
-            },
-        )
-    } else {
-        let query_name = field.name.to_upper_camel_case();
-
-        let query_build = if gql_params_str.is_empty() {
-            quote! {
-                let selection = T::selection();
-                let query = format!(
-                    "query {} {{ {} {{ {} }} }}",
-                    #query_name, #data_path, selection
-                );
-            }
-        } else {
-            quote! {
-                let selection = T::selection();
-                let query = format!(
-                    "query {}({}) {{ {}({}) {{ {} }} }}",
-                    #query_name, #gql_params_str, #data_path, #gql_args_str, selection
-                );
-            }
-        };
-
-        let (return_type, execute_call) = if is_nullable {
-            (
-                quote! { Option<T> },
-                quote! { self.client.execute::<Option<T>>(&query, variables, #data_path).await },
-            )
-        } else {
-            (
-                quote! { T },
-                quote! { self.client.execute::<T>(&query, variables, #data_path).await },
-            )
-        };
-
-        (
-            return_type,
-            quote! {
-                #build_variables
-                #query_build
-                #execute_call
-            },
-        )
-    };
+    // ── into_operation() / send() ──
+    let query_name = field.name.to_upper_camel_case();
+    let selection_build = root_selection(data_path, &gql_args_str, is_connection);
+    let send_return_type = return_type;
 
     // ── Builder struct + impl ──
     let builder_doc = match &field.description {
@@ -510,22 +498,48 @@ fn emit_builder_query(
         }
     };
 
+    let is_paginated = is_connection && optional_args.iter().any(|a| a.gql_name == "after");
+    let pagination_methods = if is_paginated {
+        emit_pagination_methods(&required_args, &optional_args)
+    } else {
+        TokenStream::new()
+    };
+
     let builder_tokens = quote! {
         #builder_doc
         ///
         /// Use setter methods to configure optional parameters, then call
         /// [`.send()`](Self::send) to execute the query.
+        #cfg
         #[must_use]
         pub struct #builder_name<'a, T> {
             #(#struct_fields,)*
         }
 
+        #cfg_impl
         impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::#node_type_ident>> #builder_name<'a, T> {
             #(#setters)*
 
+            /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+            pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+                self.extra_variables.insert(name.into(), serde_json::json!(value));
+                self
+            }
+
+            /// Build the query without sending it, e.g. to add it to a
+            /// [`Batch`](crate::batch::Batch).
+            pub fn into_operation(self) -> Operation<#send_return_type> {
+                #build_variables
+                #selection_build
+                Operation::query(#query_name, #gql_params_str, selection, variables, #data_path)
+            }
+
             pub async fn send(self) -> Result<#send_return_type, LinearError> {
-                #send_body
+                let client = self.client;
+                self.into_operation().execute(client).await
             }
+
+            #pagination_methods
         }
     };
 
@@ -557,12 +571,14 @@ fn emit_builder_query(
             let name = &a.param_name;
             inits.push(quote! { #name: None });
         }
+        inits.push(quote! { extra_variables: serde_json::Map::new() });
         inits.push(quote! { _marker: std::marker::PhantomData });
         inits
     };
 
     let standalone_fn = quote! {
         #doc
+        #cfg
         pub fn #method_name<'a, T>(client: &'a Client, #(#constructor_params),*) -> #builder_name<'a, T> {
             #builder_name {
                 #(#field_inits,)*
@@ -581,21 +597,177 @@ fn emit_builder_query(
 
     let client_method = quote! {
         #doc
+        #cfg
         pub fn #method_name<T>(&self, #(#constructor_params),*) -> #builder_name<'_, T> {
             crate::generated::queries::#method_name(self, #(#client_call_args),*)
         }
     };
 
+    // ── Blocking wrapper builder + blocking client method ──
+    let blocking_setters: Vec<TokenStream> = optional_args
+        .iter()
+        .map(|a| {
+            let name = &a.param_name;
+            let ty = &a.rust_inner_type;
+            let value_ty = if a.is_string_type {
+                quote! { impl Into<#ty> }
+            } else {
+                quote! { #ty }
+            };
+            let deprecated = &a.deprecated;
+            quote! {
+                #deprecated
+                pub fn #name(self, value: #value_ty) -> Self {
+                    Self { inner: self.inner.#name(value), client: self.client }
+                }
+            }
+        })
+        .collect();
+
+    let blocking_pagination_methods = if is_paginated {
+        quote! {
+            /// Fetch every page and collect all nodes into a `Vec`.
+            pub fn all(self) -> Result<Vec<T>, LinearError> {
+                self.client.block_on(self.inner.all())
+            }
+
+            /// Fetch pages until `max` nodes have been collected.
+            pub fn take_all(self, max: usize) -> Result<Vec<T>, LinearError> {
+                self.client.block_on(self.inner.take_all(max))
+            }
+        }
+    } else {
+        TokenStream::new()
+    };
+
+    let blocking_builder_doc = format!(
+        " Blocking variant of [`{builder_name}`](crate::generated::queries::{builder_name})."
+    );
+    let blocking_builder = quote! {
+        #[doc = #blocking_builder_doc]
+        #cfg
+        #[must_use]
+        pub struct #builder_name<'a, T> {
+            inner: crate::generated::queries::#builder_name<'a, T>,
+            client: &'a Client,
+        }
+
+        #cfg_impl
+        impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::#node_type_ident>> #builder_name<'a, T> {
+            #(#blocking_setters)*
+
+            /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+            pub fn variable(self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+                Self { inner: self.inner.variable(name, value), client: self.client }
+            }
+
+            /// Build the query without sending it.
+            pub fn into_operation(self) -> Operation<#send_return_type> {
+                self.inner.into_operation()
+            }
+
+            pub fn send(self) -> Result<#send_return_type, LinearError> {
+                self.client.block_on(self.inner.send())
+            }
+
+            #blocking_pagination_methods
+        }
+    };
+
+    let blocking_client_method = quote! {
+        #doc
+        #cfg
+        pub fn #method_name<T>(&self, #(#constructor_params),*) -> #builder_name<'_, T> {
+            #builder_name {
+                inner: crate::generated::queries::#method_name(self.as_async(), #(#client_call_args),*),
+                client: self,
+            }
+        }
+    };
+
     QueryResult {
         builders: vec![builder_tokens],
         standalone_fns: vec![standalone_fn],
+        operation_fns: vec![],
         client_method,
+        blocking_builders: vec![blocking_builder],
+        blocking_client_method,
+    }
+}
+
+/// The result of a query: a page of `T` for connections, else `T` wrapped
+/// like the field's type.
+fn result_type(is_connection: bool, is_nullable: bool, is_list: bool) -> TokenStream {
+    let result = if is_connection {
+        quote! { Connection<T> }
+    } else if is_list {
+        quote! { Vec<T> }
+    } else {
+        quote! { T }
+    };
+    if is_nullable {
+        quote! { Option<#result> }
+    } else {
+        result
+    }
+}
+
+/// Emit `stream()`, `all()` and `take_all()` for a connection builder.
+///
+/// Each page is fetched by rebuilding the builder with `after` set to the
+/// previous page's `endCursor`. Backward-pagination arguments (`before`,
+/// `last`) are cleared since the stream always walks forward.
+fn emit_pagination_methods(required_args: &[&ArgInfo], optional_args: &[&ArgInfo]) -> TokenStream {
+    let mut field_inits = vec![quote! { client: self.client }];
+    for a in required_args.iter().chain(optional_args) {
+        let name = &a.param_name;
+        match a.gql_name.as_str() {
+            "after" if !a.is_required => field_inits.push(quote! { after }),
+            "before" | "last" if !a.is_required => field_inits.push(quote! { #name: None }),
+            _ if a.is_copy_type => field_inits.push(quote! { #name: self.#name }),
+            _ => field_inits.push(quote! { #name: self.#name.clone() }),
+        }
+    }
+    field_inits.push(quote! { extra_variables: self.extra_variables.clone() });
+    field_inits.push(quote! { _marker: std::marker::PhantomData });
+
+    quote! {
+        /// Stream every node across all pages, following `pageInfo.endCursor`.
+        ///
+        /// Starts after the cursor set via `.after()` (or at the beginning) and
+        /// uses `.first()` as the page size. `.before()` / `.last()` are ignored.
+        pub fn stream(self) -> impl futures::Stream<Item = Result<T, LinearError>> + 'a
+        where
+            T: 'a,
+        {
+            let after = self.after.clone();
+            crate::pagination::paginate(after, move |after| {
+                Self { #(#field_inits,)* }.send()
+            })
+        }
+
+        /// Fetch every node across all pages. See [`stream`](Self::stream).
+        pub async fn all(self) -> Result<Vec<T>, LinearError>
+        where
+            T: 'a,
+        {
+            futures::TryStreamExt::try_collect(self.stream()).await
+        }
+
+        /// Fetch nodes across pages until `max` have been collected. Pages past
+        /// the one containing the `max`-th node are never requested.
+        pub async fn take_all(self, max: usize) -> Result<Vec<T>, LinearError>
+        where
+            T: 'a,
+        {
+            futures::TryStreamExt::try_collect(futures::StreamExt::take(self.stream(), max)).await
+        }
     }
 }
 
 // ── Shared helpers ───────────────────────────────────────────────────────────
 
-fn build_gql_strings(args: &[ArgInfo]) -> (String, String) {
+pub(crate) fn build_gql_strings(args: &[ArgInfo]) -> (String, String) {
     let gql_args: Vec<String> = args
         .iter()
         .map(|a| format!("{}: ${}", a.gql_name, a.gql_name))
@@ -622,6 +794,15 @@ pub fn has_required_arguments(field: &FieldDef) -> bool {
         .any(|a| matches!(a.ty, GqlType::NonNull(_)))
 }
 
+/// Check if a GqlType is a list (possibly wrapped in NonNull).
+fn is_list_type(ty: &GqlType) -> bool {
+    match ty {
+        GqlType::List(_) => true,
+        GqlType::NonNull(inner) => is_list_type(inner),
+        GqlType::Named(_) => false,
+    }
+}
+
 /// Convert GqlType back to a GraphQL type string (e.g., `String!`, `[String!]`).
 pub fn gql_type_string(ty: &GqlType) -> String {
     match ty {
@@ -0,0 +1,390 @@
+use crate::emit_queries::{build_gql_strings, classify_args, ArgInfo};
+use crate::parser::{self, FieldDef, TypeKind};
+use heck::{ToSnakeCase, ToUpperCamelCase};
+use proc_macro2::TokenStream;
+use quote::quote;
+use std::collections::HashMap;
+
+/// Result of emitting all subscription code.
+pub struct SubscriptionEmitResult {
+    /// Builder structs + standalone factory functions for `generated::subscriptions`.
+    pub subscriptions_tokens: TokenStream,
+    /// `impl Client` block for `generated/client_impl.rs`.
+    pub client_impl_tokens: TokenStream,
+}
+
+pub fn emit(
+    subscription_fields: &[FieldDef],
+    enabled: &HashMap<String, Option<String>>,
+    renames: &HashMap<String, String>,
+    type_kind_map: &HashMap<String, TypeKind>,
+) -> SubscriptionEmitResult {
+    let mut builder_items: Vec<TokenStream> = Vec::new();
+    let mut standalone_fns: Vec<TokenStream> = Vec::new();
+    let mut client_methods: Vec<TokenStream> = Vec::new();
+
+    for field in subscription_fields {
+        let Some(feature) = enabled.get(&field.name) else {
+            continue;
+        };
+        // Events are objects or interfaces; anything else has no selection.
+        if !matches!(
+            type_kind_map.get(field.ty.base_name()),
+            Some(TypeKind::Object) | Some(TypeKind::Interface)
+        ) {
+            continue;
+        }
+        let rename = renames.get(&field.name).map(|s| s.as_str());
+        let (builder, standalone, client_method) =
+            emit_subscription(field, rename, feature.as_deref(), type_kind_map);
+        builder_items.push(builder);
+        standalone_fns.push(standalone);
+        client_methods.push(client_method);
+    }
+
+    let subscriptions_tokens = quote! {
+        //! Generated subscription builders.
+        //!
+        //! Each subscription is started through a builder whose
+        //! `subscribe()` returns a [`Subscription`] stream of events, typed
+        //! by any `T: DeserializeOwned + GraphQLFields` for the event type.
+        //!
+        //! Generated by lineark-codegen — do not edit.
+
+        use crate::client::Client;
+        use crate::field_selection::GraphQLFields;
+        use crate::subscription::{Subscription, SubscriptionOperation};
+        use serde::de::DeserializeOwned;
+        use super::inputs::*;
+
+        #(#builder_items)*
+
+        #(#standalone_fns)*
+    };
+
+    SubscriptionEmitResult {
+        subscriptions_tokens,
+        client_impl_tokens: quote! { #(#client_methods)* },
+    }
+}
+
+fn emit_subscription(
+    field: &FieldDef,
+    rename: Option<&str>,
+    feature: Option<&str>,
+    type_kind_map: &HashMap<String, TypeKind>,
+) -> (TokenStream, TokenStream, TokenStream) {
+    let cfg = crate::domains::cfg_tokens(feature);
+    let cfg_impl = crate::domains::cfg_impl_tokens(feature);
+    let args = classify_args(&field.arguments, None, type_kind_map);
+    let rust_name = rename.unwrap_or(field.name.as_str());
+    let method_name = quote::format_ident!("{}", rust_name.to_snake_case());
+    let builder_name =
+        quote::format_ident!("{}SubscriptionBuilder", rust_name.to_upper_camel_case());
+    let event_type_name = field.ty.base_name();
+    let event_type_ident = quote::format_ident!("{}", event_type_name);
+    let type_hint = format!(" Event type: [`{event_type_name}`](super::types::{event_type_name})");
+    let base_doc = parser::doc_comment_tokens(&field.description);
+    let deprecated = parser::deprecated_tokens(&field.deprecated);
+    let doc = quote! { #base_doc #[doc = ""] #[doc = #type_hint] #deprecated };
+
+    let required_args: Vec<&ArgInfo> = args.iter().filter(|a| a.is_required).collect();
+    let optional_args: Vec<&ArgInfo> = args.iter().filter(|a| !a.is_required).collect();
+
+    // ── Builder struct fields ──
+    let struct_fields: Vec<TokenStream> = std::iter::once(quote! { client: &'a Client })
+        .chain(required_args.iter().map(|a| {
+            let name = &a.param_name;
+            let ty = &a.rust_inner_type;
+            quote! { #name: #ty }
+        }))
+        .chain(optional_args.iter().map(|a| {
+            let name = &a.param_name;
+            let ty = &a.rust_inner_type;
+            quote! { #name: Option<#ty> }
+        }))
+        .chain([
+            quote! { extra_variables: serde_json::Map<String, serde_json::Value> },
+            quote! { _marker: std::marker::PhantomData<T> },
+        ])
+        .collect();
+
+    // ── Setter methods for optional args ──
+    let setters = optional_args.iter().map(|a| {
+        let name = &a.param_name;
+        let ty = &a.rust_inner_type;
+        let deprecated = &a.deprecated;
+        if a.is_string_type {
+            quote! {
+                #deprecated
+                pub fn #name(mut self, value: impl Into<#ty>) -> Self {
+                    self.#name = Some(value.into());
+                    self
+                }
+            }
+        } else {
+            quote! {
+                #deprecated
+                pub fn #name(mut self, value: #ty) -> Self {
+                    self.#name = Some(value);
+                    self
+                }
+            }
+        }
+    });
+
+    // ── Variables JSON building ──
+    let required_var_inserts = required_args.iter().map(|a| {
+        let gql_name = &a.gql_name;
+        let param_name = &a.param_name;
+        quote! { map.insert(#gql_name.to_string(), serde_json::json!(self.#param_name)); }
+    });
+    let map_binding = if args.is_empty() {
+        quote! { let map = self.extra_variables; }
+    } else {
+        quote! { let mut map = self.extra_variables; }
+    };
+    let optional_var_inserts = optional_args.iter().map(|a| {
+        let gql_name = &a.gql_name;
+        let param_name = &a.param_name;
+        quote! {
+            if let Some(ref v) = self.#param_name {
+                map.insert(#gql_name.to_string(), serde_json::json!(v));
+            }
+        }
+    });
+
+    let (gql_args_str, gql_params_str) = build_gql_strings(&args);
+    let data_path = &field.name;
+    let field_call = if gql_args_str.is_empty() {
+        data_path.to_string()
+    } else {
+        format!("{}({})", data_path, gql_args_str)
+    };
+    let subscription_name = field.name.to_upper_camel_case();
+
+    let builder_doc = match &field.description {
+        Some(desc) => format!(
+            " Subscription builder: {}",
+            crate::parser::sanitize_doc(desc)
+        ),
+        None => format!(" Subscription builder for `{}`.", method_name),
+    };
+
+    let builder = quote! {
+        #[doc = #builder_doc]
+        #[doc = ""]
+        #[doc = #type_hint]
+        ///
+        /// Use setter methods to configure optional arguments, then call
+        /// [`.subscribe()`](Self::subscribe) to start receiving events.
+        #cfg
+        #[must_use]
+        pub struct #builder_name<'a, T> {
+            #(#struct_fields,)*
+        }
+
+        #cfg_impl
+        impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::#event_type_ident>> #builder_name<'a, T> {
+            #(#setters)*
+
+            /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+            pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+                self.extra_variables.insert(name.into(), serde_json::json!(value));
+                self
+            }
+
+            /// Build the subscription without starting it, e.g. to set its
+            /// reconnect policy.
+            pub fn into_operation(self) -> SubscriptionOperation<T> {
+                #map_binding
+                #(#required_var_inserts)*
+                #(#optional_var_inserts)*
+                let selection = format!("{} {{ {} }}", #field_call, T::selection());
+                SubscriptionOperation::new(
+                    #subscription_name,
+                    #gql_params_str,
+                    selection,
+                    serde_json::Value::Object(map),
+                    #data_path,
+                )
+            }
+
+            /// Connect and start receiving events. Must be called within a
+            /// Tokio runtime.
+            pub fn subscribe(self) -> Subscription<T> {
+                let client = self.client;
+                self.into_operation().subscribe(client)
+            }
+        }
+    };
+
+    // ── Standalone factory function ──
+    let constructor_params: Vec<TokenStream> = required_args
+        .iter()
+        .map(|a| {
+            let name = &a.param_name;
+            let ty = &a.rust_inner_type;
+            if a.is_string_type {
+                quote! { #name: impl Into<#ty> }
+            } else {
+                quote! { #name: #ty }
+            }
+        })
+        .collect();
+    let field_inits = std::iter::once(quote! { client })
+        .chain(required_args.iter().map(|a| {
+            let name = &a.param_name;
+            if a.is_string_type {
+                quote! { #name: #name.into() }
+            } else {
+                quote! { #name }
+            }
+        }))
+        .chain(optional_args.iter().map(|a| {
+            let name = &a.param_name;
+            quote! { #name: None }
+        }))
+        .chain([
+            quote! { extra_variables: serde_json::Map::new() },
+            quote! { _marker: std::marker::PhantomData },
+        ]);
+    let standalone = quote! {
+        #doc
+        #cfg
+        pub fn #method_name<'a, T>(client: &'a Client, #(#constructor_params),*) -> #builder_name<'a, T> {
+            #builder_name {
+                #(#field_inits,)*
+            }
+        }
+    };
+
+    // ── Client delegation method ──
+    let call_args = required_args.iter().map(|a| &a.param_name);
+    let client_method = quote! {
+        #doc
+        #cfg
+        pub fn #method_name<T>(&self, #(#constructor_params),*) -> crate::generated::subscriptions::#builder_name<'_, T> {
+            crate::generated::subscriptions::#method_name(self, #(#call_args),*)
+        }
+    };
+
+    (builder, standalone, client_method)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::{ArgumentDef, GqlType};
+
+    fn fields() -> Vec<FieldDef> {
+        vec![
+            FieldDef {
+                name: "issueUpdated".to_string(),
+                description: Some("Triggered when an issue is updated".to_string()),
+                ty: GqlType::NonNull(Box::new(GqlType::Named("Issue".to_string()))),
+                arguments: vec![ArgumentDef {
+                    name: "filter".to_string(),
+                    description: None,
+                    ty: GqlType::Named("IssueSubscriptionFilter".to_string()),
+                    deprecated: None,
+                }],
+                deprecated: None,
+            },
+            FieldDef {
+                name: "commentCreated".to_string(),
+                description: None,
+                ty: GqlType::NonNull(Box::new(GqlType::Named("Comment".to_string()))),
+                arguments: vec![],
+                deprecated: None,
+            },
+        ]
+    }
+
+    fn emitted(allowed: &[&str]) -> SubscriptionEmitResult {
+        let mut map = HashMap::new();
+        map.insert("Issue".to_string(), TypeKind::Object);
+        map.insert("Comment".to_string(), TypeKind::Object);
+        map.insert("IssueSubscriptionFilter".to_string(), TypeKind::InputObject);
+        let enabled = allowed.iter().map(|s| (s.to_string(), None)).collect();
+        emit(&fields(), &enabled, &HashMap::new(), &map)
+    }
+
+    #[test]
+    fn emits_builder_per_allowed_subscription() {
+        let result = emitted(&["issueUpdated"]);
+        let output: String = result
+            .subscriptions_tokens
+            .to_string()
+            .split_whitespace()
+            .collect();
+        assert!(output.contains("pubstructIssueUpdatedSubscriptionBuilder<'a,T>"));
+        assert!(output.contains("filter:Option<IssueSubscriptionFilter>"));
+        assert!(output.contains("\"issueUpdated(filter:$filter)\""));
+        assert!(output.contains("\"$filter:IssueSubscriptionFilter\""));
+        assert!(!output.contains("CommentCreated"));
+    }
+
+    #[test]
+    fn deprecated_subscriptions_and_arguments_are_marked() {
+        let mut fields = fields();
+        fields[0].deprecated = Some("Use issueChanged instead.".to_string());
+        fields[0].arguments[0].deprecated = Some("Filter client-side.".to_string());
+        let mut map = HashMap::new();
+        map.insert("Issue".to_string(), TypeKind::Object);
+        map.insert("IssueSubscriptionFilter".to_string(), TypeKind::InputObject);
+        let enabled = HashMap::from([("issueUpdated".to_string(), None)]);
+        let result = emit(&fields, &enabled, &HashMap::new(), &map);
+        let output: String = result
+            .subscriptions_tokens
+            .to_string()
+            .split_whitespace()
+            .collect();
+        assert!(output.contains("#[deprecated(note=\"Filterclient-side.\")]pubfnfilter(mutself"));
+        assert!(
+            output.contains("#[deprecated(note=\"UseissueChangedinstead.\")]pubfnissue_updated")
+        );
+        let client_impl: String = result
+            .client_impl_tokens
+            .to_string()
+            .split_whitespace()
+            .collect();
+        assert!(client_impl
+            .contains("#[deprecated(note=\"UseissueChangedinstead.\")]pubfnissue_updated"));
+    }
+
+    #[test]
+    fn gated_subscriptions_are_behind_their_feature() {
+        let mut map = HashMap::new();
+        map.insert("Issue".to_string(), TypeKind::Object);
+        map.insert("IssueSubscriptionFilter".to_string(), TypeKind::InputObject);
+        let enabled = HashMap::from([("issueUpdated".to_string(), Some("issues".to_string()))]);
+        let result = emit(&fields(), &enabled, &HashMap::new(), &map);
+        let output: String = result
+            .subscriptions_tokens
+            .to_string()
+            .split_whitespace()
+            .collect();
+        assert!(output.contains(
+            "#[cfg(feature=\"issues\")]#[must_use]pubstructIssueUpdatedSubscriptionBuilder"
+        ));
+        assert!(output.contains("}#[cfg(feature=\"issues\")]impl<'a,T"));
+        assert!(output.contains("#[cfg(feature=\"issues\")]pubfnissue_updated<'a,T>"));
+        let client_impl: String = result
+            .client_impl_tokens
+            .to_string()
+            .split_whitespace()
+            .collect();
+        assert!(client_impl.contains("#[cfg(feature=\"issues\")]pubfnissue_updated<T>"));
+    }
+
+    #[test]
+    fn emitted_code_parses() {
+        let result = emitted(&["issueUpdated", "commentCreated"]);
+        syn::parse_file(&result.subscriptions_tokens.to_string())
+            .expect("Generated subscription code should be valid Rust");
+        let client_impl = result.client_impl_tokens;
+        syn::parse_file(&quote! { impl Client { #client_impl } }.to_string())
+            .expect("Generated client methods should be valid Rust");
+    }
+}
@@ -21,7 +21,7 @@ pub fn emit(objects: &[ObjectDef], type_kind_map: &HashMap<String, TypeKind>) ->
         .collect();
 
     quote! {
-        //! GraphQL object types.
+        //! GraphQL object, interface and union types.
         //!
         //! Generated by lineark-codegen — do not edit.
 
@@ -102,8 +102,10 @@ fn emit_struct(
             };
             let rust_type = resolve_type(&f.ty, type_kind_map, &obj.name, reach);
             let fdoc = parser::doc_comment_tokens(&f.description);
+            let deprecated = parser::deprecated_tokens(&f.deprecated);
             quote! {
                 #fdoc
+                #deprecated
                 pub #field_ident: #rust_type,
             }
         })
@@ -120,13 +122,17 @@ fn emit_struct(
 }
 
 /// Check if a field should be included in the struct.
-/// Includes Scalar, Enum, and Object types (for nested objects).
-/// Excludes Interface and Union types (not representable as simple structs).
+/// Includes Scalar, Enum, Object, Interface and Union types; the latter two
+/// are the enums emitted by [`crate::emit_interfaces`].
 fn is_includable_field(ty: &GqlType, type_kind_map: &HashMap<String, TypeKind>) -> bool {
     let base = ty.base_name();
     matches!(
         type_kind_map.get(base),
-        Some(TypeKind::Scalar) | Some(TypeKind::Enum) | Some(TypeKind::Object)
+        Some(TypeKind::Scalar)
+            | Some(TypeKind::Enum)
+            | Some(TypeKind::Object)
+            | Some(TypeKind::Interface)
+            | Some(TypeKind::Union)
     )
 }
 
@@ -166,10 +172,13 @@ fn resolve_type(
 
 /// Resolve a type as if it were required at its current position. Strips
 /// outer `NonNull` markers, recurses into lists via [`resolve_list_element`].
-fn resolve_required(ty: &GqlType, type_kind_map: &HashMap<String, TypeKind>) -> TokenStream {
+pub(crate) fn resolve_required(
+    ty: &GqlType,
+    type_kind_map: &HashMap<String, TypeKind>,
+) -> TokenStream {
     match ty {
         GqlType::Named(name) => match type_kind_map.get(name.as_str()) {
-            Some(TypeKind::Object) => {
+            Some(TypeKind::Object) | Some(TypeKind::Interface) | Some(TypeKind::Union) => {
                 let ident = quote::format_ident!("{}", name);
                 quote! { #ident }
             }
@@ -262,20 +271,24 @@ mod tests {
                     description: None,
                     ty: GqlType::NonNull(Box::new(GqlType::Named("ID".to_string()))),
                     arguments: vec![],
+                    deprecated: None,
                 },
                 FieldDef {
                     name: "name".to_string(),
                     description: None,
                     ty: GqlType::NonNull(Box::new(GqlType::Named("String".to_string()))),
                     arguments: vec![],
+                    deprecated: None,
                 },
                 FieldDef {
                     name: "active".to_string(),
                     description: None,
                     ty: GqlType::Named("Boolean".to_string()),
                     arguments: vec![],
+                    deprecated: None,
                 },
             ],
+            interfaces: vec![],
         }];
         let output = emit(&objects, &type_kind_map).to_string();
         assert!(output.contains("pub struct User"));
@@ -285,6 +298,28 @@ mod tests {
         assert!(output.contains("Option"));
     }
 
+    #[test]
+    fn emit_marks_deprecated_fields() {
+        let type_kind_map = make_type_kind_map();
+        let objects = vec![ObjectDef {
+            name: "Team".to_string(),
+            description: None,
+            fields: vec![FieldDef {
+                name: "key".to_string(),
+                description: None,
+                ty: GqlType::Named("String".to_string()),
+                arguments: vec![],
+                deprecated: Some("Use identifier instead.".to_string()),
+            }],
+            interfaces: vec![],
+        }];
+        let output: String = emit(&objects, &type_kind_map)
+            .to_string()
+            .split_whitespace()
+            .collect();
+        assert!(output.contains("#[deprecated(note=\"Useidentifierinstead.\")]pubkey:"));
+    }
+
     #[test]
     fn emit_includes_object_fields_without_unnecessary_box() {
         // Single Issue type with a Team field. Team has no schema definition
@@ -299,14 +334,17 @@ mod tests {
                     description: None,
                     ty: GqlType::NonNull(Box::new(GqlType::Named("ID".to_string()))),
                     arguments: vec![],
+                    deprecated: None,
                 },
                 FieldDef {
                     name: "team".to_string(),
                     description: None,
                     ty: GqlType::Named("Team".to_string()),
                     arguments: vec![],
+                    deprecated: None,
                 },
             ],
+            interfaces: vec![],
         }];
         let output = emit(&objects, &type_kind_map).to_string();
         assert!(output.contains("pub id"));
@@ -329,7 +367,9 @@ mod tests {
                 description: None,
                 ty: GqlType::Named("Status".to_string()),
                 arguments: vec![],
+                deprecated: None,
             }],
+            interfaces: vec![],
         }];
         let output = emit(&objects, &type_kind_map).to_string();
         assert!(output.contains("pub status"));
@@ -346,7 +386,9 @@ mod tests {
                 description: None,
                 ty: GqlType::Named("DateTime".to_string()),
                 arguments: vec![],
+                deprecated: None,
             }],
+            interfaces: vec![],
         }];
         let output = emit(&objects, &type_kind_map).to_string();
         assert!(output.contains("rename_all = \"camelCase\""));
@@ -366,14 +408,17 @@ mod tests {
                     description: None,
                     ty: GqlType::NonNull(Box::new(GqlType::Named("ID".to_string()))),
                     arguments: vec![],
+                    deprecated: None,
                 },
                 FieldDef {
                     name: "name".to_string(),
                     description: None,
                     ty: GqlType::Named("String".to_string()),
                     arguments: vec![],
+                    deprecated: None,
                 },
             ],
+            interfaces: vec![],
         }];
         let output = emit(&objects, &type_kind_map).to_string();
         syn::parse_file(&output).expect("Generated type code should be valid Rust");
@@ -386,6 +431,7 @@ mod tests {
             name: "".to_string(),
             description: None,
             fields: vec![],
+            interfaces: vec![],
         }];
         let output = emit(&objects, &type_kind_map).to_string();
         assert!(!output.contains("pub struct"));
@@ -404,7 +450,9 @@ mod tests {
                     "String".to_string(),
                 ))))),
                 arguments: vec![],
+                deprecated: None,
             }],
+            interfaces: vec![],
         }];
         let output = emit(&objects, &type_kind_map).to_string();
         assert!(output.contains("Vec"));
@@ -503,20 +551,24 @@ mod tests {
                         description: None,
                         ty: GqlType::Named("Team".to_string()),
                         arguments: vec![],
+                        deprecated: None,
                     },
                     FieldDef {
                         name: "teams".to_string(),
                         description: None,
                         ty: n(l(n(GqlType::Named("Team".to_string())))),
                         arguments: vec![],
+                        deprecated: None,
                     },
                     FieldDef {
                         name: "loner".to_string(),
                         description: None,
                         ty: GqlType::Named("Loner".to_string()),
                         arguments: vec![],
+                        deprecated: None,
                     },
                 ],
+                interfaces: vec![],
             },
             ObjectDef {
                 name: "Team".to_string(),
@@ -526,7 +578,9 @@ mod tests {
                     description: None,
                     ty: GqlType::Named("Container".to_string()),
                     arguments: vec![],
+                    deprecated: None,
                 }],
+                interfaces: vec![],
             },
             ObjectDef {
                 name: "Loner".to_string(),
@@ -536,7 +590,9 @@ mod tests {
                     description: None,
                     ty: GqlType::Named("String".to_string()),
                     arguments: vec![],
+                    deprecated: None,
                 }],
+                interfaces: vec![],
             },
         ];
 
@@ -589,20 +645,24 @@ mod tests {
                     description: None,
                     ty: GqlType::NonNull(Box::new(GqlType::Named("ID".to_string()))),
                     arguments: vec![],
+                    deprecated: None,
                 },
                 FieldDef {
                     name: "name".to_string(),
                     description: None,
                     ty: GqlType::Named("String".to_string()),
                     arguments: vec![],
+                    deprecated: None,
                 },
                 FieldDef {
                     name: "team".to_string(),
                     description: None,
                     ty: GqlType::Named("Team".to_string()),
                     arguments: vec![],
+                    deprecated: None,
                 },
             ],
+            interfaces: vec![],
         }];
         let output = emit(&objects, &type_kind_map).to_string();
         // selection() should include scalar fields only, not object fields
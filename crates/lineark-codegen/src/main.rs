@@ -1,14 +1,20 @@
 mod dep_graph;
+mod diff;
+mod domains;
+mod emit_documents;
 mod emit_enums;
+mod emit_ids;
 mod emit_inputs;
+mod emit_interfaces;
 mod emit_mutations;
 mod emit_queries;
 mod emit_scalars;
+mod emit_subscriptions;
 mod emit_types;
 mod fetch_schema;
 mod parser;
 
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeSet, HashMap, HashSet};
 use std::path::Path;
 
 fn main() {
@@ -24,6 +30,16 @@ fn main() {
     let operations_path = workspace_root.join("schema/operations.toml");
     let generated_dir = workspace_root.join("crates/lineark-sdk/src/generated");
 
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.first().map(String::as_str) == Some("diff") {
+        run_diff(&args[1..], &operations_path);
+        return;
+    }
+    if args.first().map(String::as_str) == Some("documents") {
+        run_documents(&args[1..], &schema_path);
+        return;
+    }
+
     // Optionally fetch the latest schema from Linear's introspection endpoint.
     if fetch {
         let sdl = fetch_schema::fetch_and_convert()
@@ -39,13 +55,16 @@ fn main() {
     println!("Parsing schema...");
     let schema = parser::parse(&schema_text);
     println!(
-        "  {} scalars, {} enums, {} objects, {} inputs, {} query fields, {} mutation fields",
+        "  {} scalars, {} enums, {} objects, {} interfaces, {} unions, {} inputs, {} query fields, {} mutation fields, {} subscription fields",
         schema.scalars.len(),
         schema.enums.len(),
         schema.objects.len(),
+        schema.interfaces.len(),
+        schema.unions.len(),
         schema.inputs.len(),
         schema.query_fields.len(),
         schema.mutation_fields.len(),
+        schema.subscription_fields.len(),
     );
 
     // Read operations allowlist.
@@ -58,11 +77,101 @@ fn main() {
 
     let (allowed_queries, query_configs) = parse_operations_section(&operations, "queries");
     let (allowed_mutations, mutation_configs) = parse_operations_section(&operations, "mutations");
+    let (allowed_subscriptions, subscription_configs) =
+        parse_operations_section(&operations, "subscriptions");
 
     println!(
-        "  {} allowed queries, {} allowed mutations",
+        "  {} allowed queries, {} allowed mutations, {} allowed subscriptions",
         allowed_queries.len(),
-        allowed_mutations.len()
+        allowed_mutations.len(),
+        allowed_subscriptions.len()
+    );
+
+    // Every other operation is generated behind its domain's cargo feature.
+    let domains = domains::Domains::parse(&operations);
+    let mut unassigned = Vec::new();
+    let query_features = gated_operations(
+        &schema.query_fields,
+        &allowed_queries,
+        &query_configs,
+        &domains,
+        &mut unassigned,
+    );
+    let mutation_features = gated_operations(
+        &schema.mutation_fields,
+        &allowed_mutations,
+        &mutation_configs,
+        &domains,
+        &mut unassigned,
+    );
+    let subscription_features = gated_operations(
+        &schema.subscription_fields,
+        &allowed_subscriptions,
+        &subscription_configs,
+        &domains,
+        &mut unassigned,
+    );
+    if !unassigned.is_empty() {
+        panic!(
+            "No [domains] prefix in operations.toml matches: {}",
+            unassigned.join(", ")
+        );
+    }
+    let features: BTreeSet<&str> = domains
+        .names()
+        .into_iter()
+        .chain(
+            query_features
+                .values()
+                .chain(mutation_features.values())
+                .chain(subscription_features.values())
+                .map(String::as_str),
+        )
+        .collect();
+    let manifest_path = workspace_root.join("crates/lineark-sdk/Cargo.toml");
+    let manifest: toml::Value = std::fs::read_to_string(&manifest_path)
+        .expect("Failed to read lineark-sdk's Cargo.toml")
+        .parse()
+        .expect("Failed to parse lineark-sdk's Cargo.toml");
+    let problems = domains::check_features(&manifest, &features.into_iter().collect::<Vec<_>>());
+    if !problems.is_empty() {
+        panic!(
+            "{} does not match the domains in operations.toml: {}",
+            manifest_path.display(),
+            problems.join("; ")
+        );
+    }
+    println!(
+        "  gated operations by feature: {}",
+        domains::summary(
+            query_features
+                .values()
+                .chain(mutation_features.values())
+                .chain(subscription_features.values())
+        )
+    );
+    // Operations to generate, each with the feature gating it, if any.
+    let enabled = |allowed: &HashSet<String>, features: &HashMap<String, String>| {
+        allowed
+            .iter()
+            .map(|name| (name.clone(), None))
+            .chain(features.iter().map(|(n, f)| (n.clone(), Some(f.clone()))))
+            .collect::<HashMap<String, Option<String>>>()
+    };
+    let enabled_queries = enabled(&allowed_queries, &query_features);
+    let enabled_mutations = enabled(&allowed_mutations, &mutation_features);
+    let enabled_subscriptions = enabled(&allowed_subscriptions, &subscription_features);
+    report_deprecations(
+        &schema,
+        [
+            ("query", &schema.query_fields, &allowed_queries),
+            ("mutation", &schema.mutation_fields, &allowed_mutations),
+            (
+                "subscription",
+                &schema.subscription_fields,
+                &allowed_subscriptions,
+            ),
+        ],
     );
 
     // Generate code.
@@ -78,12 +187,37 @@ fn main() {
 
     // Types
     let types_tokens = emit_types::emit(&schema.objects, &schema.type_kind_map);
-    write_formatted(&generated_dir.join("types.rs"), types_tokens);
+    let interface_tokens = emit_interfaces::emit(
+        &schema.interfaces,
+        &schema.unions,
+        &schema.objects,
+        &schema.type_kind_map,
+    );
+    write_formatted(
+        &generated_dir.join("types.rs"),
+        quote::quote! { #types_tokens #interface_tokens },
+    );
 
     // Inputs
     let inputs_tokens = emit_inputs::emit(&schema.inputs, &schema.type_kind_map);
     write_formatted(&generated_dir.join("inputs.rs"), inputs_tokens);
 
+    // Entity IDs — one newtype per entity whose `id` a generated operation
+    // takes, whichever feature gates the operation.
+    let id_entities: BTreeSet<String> = schema
+        .query_fields
+        .iter()
+        .filter(|f| enabled_queries.contains_key(&f.name))
+        .chain(
+            schema
+                .mutation_fields
+                .iter()
+                .filter(|f| enabled_mutations.contains_key(&f.name)),
+        )
+        .filter_map(|f| emit_ids::id_entity(f, &schema.objects))
+        .collect();
+    write_formatted(&generated_dir.join("ids.rs"), emit_ids::emit(&id_entities));
+
     // Queries (returns queries module + client impl)
     let query_renames: HashMap<String, String> = query_configs
         .iter()
@@ -91,7 +225,7 @@ fn main() {
         .collect();
     let query_result = emit_queries::emit(
         &schema.query_fields,
-        &allowed_queries,
+        &enabled_queries,
         &query_renames,
         &schema.objects,
         &schema.type_kind_map,
@@ -108,9 +242,11 @@ fn main() {
         .collect();
     let mutation_result = emit_mutations::emit(
         &schema.mutation_fields,
-        &allowed_mutations,
+        &enabled_mutations,
         &mutation_renames,
         &schema.objects,
+        &schema.inputs,
+        &schema.query_fields,
         &schema.type_kind_map,
     );
     write_formatted(
@@ -118,15 +254,63 @@ fn main() {
         mutation_result.mutations_tokens,
     );
 
-    // Client impl — combines query + mutation delegation into one file.
+    // Subscriptions (returns subscriptions module + client impl)
+    let subscription_renames: HashMap<String, String> = subscription_configs
+        .iter()
+        .filter_map(|(k, c)| c.rename.as_ref().map(|r| (k.clone(), r.clone())))
+        .collect();
+    let subscription_result = emit_subscriptions::emit(
+        &schema.subscription_fields,
+        &enabled_subscriptions,
+        &subscription_renames,
+        &schema.type_kind_map,
+    );
+    write_formatted(
+        &generated_dir.join("subscriptions.rs"),
+        subscription_result.subscriptions_tokens,
+    );
+
+    // Operations — prepared query/mutation constructors shared by the
+    // standalone functions and request batching.
+    let query_operations = query_result.operations_tokens;
+    let mutation_operations = mutation_result.operations_tokens;
+    let operations_tokens = quote::quote! {
+        //! Generated operation constructors.
+        //!
+        //! Each function builds an [`Operation`] for a query without optional
+        //! arguments or for a mutation, without sending it. Pass it to
+        //! [`Operation::execute`] or add it to a [`Batch`](crate::batch::Batch).
+        //! Queries with optional arguments build operations through their
+        //! builder's `into_operation()`.
+        //!
+        //! Generated by lineark-codegen — do not edit.
+
+        #![allow(clippy::too_many_arguments)]
+
+        use crate::field_selection::GraphQLFields;
+        use crate::operation::Operation;
+        use serde::de::DeserializeOwned;
+        // Only feature-gated operations may take enum arguments.
+        #[allow(unused_imports)]
+        use super::enums::*;
+        use super::inputs::*;
+
+        #query_operations
+        #mutation_operations
+    };
+    write_formatted(&generated_dir.join("operations.rs"), operations_tokens);
+
+    // Client impl — combines query, mutation and subscription delegation
+    // into one file.
     let query_client_impl = query_result.client_impl_tokens;
     let mutation_client_impl = mutation_result.client_impl_tokens;
+    let subscription_client_impl = subscription_result.client_impl_tokens;
     let client_impl_tokens = quote::quote! {
         //! Generated `impl Client` delegation methods.
         //!
         //! This module provides thin wrapper methods on [`Client`] that
-        //! delegate to the standalone functions in [`queries`](super::queries) and
-        //! [`mutations`](super::mutations).
+        //! delegate to the standalone functions in [`queries`](super::queries),
+        //! [`mutations`](super::mutations) and [`subscriptions`](super::subscriptions).
         //!
         //! Generated by lineark-codegen — do not edit.
 
@@ -136,29 +320,78 @@ fn main() {
         use crate::error::LinearError;
         use crate::field_selection::GraphQLFields;
         use serde::de::DeserializeOwned;
+        // Only feature-gated operations may take enum arguments.
+        #[allow(unused_imports)]
+        use super::enums::*;
         use super::queries::*;
         use super::inputs::*;
 
         impl Client {
             #query_client_impl
             #mutation_client_impl
+            #subscription_client_impl
         }
     };
     write_formatted(&generated_dir.join("client_impl.rs"), client_impl_tokens);
 
+    // Blocking client impl — wrapper builders plus `impl blocking::Client`.
+    let blocking_builders = query_result.blocking_builders_tokens;
+    let blocking_query_impl = query_result.blocking_client_impl_tokens;
+    let blocking_mutation_impl = mutation_result.blocking_client_impl_tokens;
+    let blocking_client_impl_tokens = quote::quote! {
+        //! Generated `impl blocking::Client` methods and blocking query builders.
+        //!
+        //! Each method drives the corresponding async operation to completion
+        //! on the blocking client's runtime. Only compiled with the `blocking`
+        //! feature.
+        //!
+        //! Generated by lineark-codegen — do not edit.
+
+        #![allow(clippy::too_many_arguments)]
+
+        use crate::blocking::Client;
+        use crate::error::LinearError;
+        use crate::field_selection::GraphQLFields;
+        use crate::operation::Operation;
+        use crate::pagination::Connection;
+        use serde::de::DeserializeOwned;
+        use super::enums::*;
+        use super::inputs::*;
+
+        #blocking_builders
+
+        impl Client {
+            #blocking_query_impl
+            #blocking_mutation_impl
+        }
+    };
+    write_formatted(
+        &generated_dir.join("blocking_client_impl.rs"),
+        blocking_client_impl_tokens,
+    );
+
     // mod.rs
     let mod_tokens = quote::quote! {
         //! Generated types and operations for the Linear GraphQL API.
         //!
         //! Generated by lineark-codegen — do not edit.
 
+        // Generated code refers to the deprecated items it defines; only
+        // uses outside this module should warn.
+        #![allow(deprecated)]
+
         pub mod enums;
+        pub mod ids;
         pub mod inputs;
         pub mod mutations;
+        pub mod operations;
         pub mod queries;
         pub mod scalars;
+        pub mod subscriptions;
         pub mod types;
         mod client_impl;
+        #[cfg(feature = "blocking")]
+        pub(crate) mod blocking_client_impl;
     };
     write_formatted(&generated_dir.join("mod.rs"), mod_tokens);
 
@@ -175,10 +408,156 @@ fn main() {
     println!("Code generation complete.");
 }
 
+/// `lineark-codegen diff [--json] <old.graphql> <new.graphql>`: report how
+/// the schema changed and which enabled operations breaking changes reach.
+fn run_diff(args: &[String], operations_path: &Path) {
+    let json = args.iter().any(|a| a == "--json");
+    let paths: Vec<&str> = args
+        .iter()
+        .map(String::as_str)
+        .filter(|a| *a != "--json")
+        .collect();
+    let [old_path, new_path] = paths[..] else {
+        eprintln!("Usage: lineark-codegen diff [--json] <old.graphql> <new.graphql>");
+        std::process::exit(2);
+    };
+    let read = |path: &str| {
+        let text = std::fs::read_to_string(path)
+            .unwrap_or_else(|e| panic!("Failed to read {}: {}", path, e));
+        parser::parse(&text)
+    };
+    let (old, new) = (read(old_path), read(new_path));
+
+    let operations_text =
+        std::fs::read_to_string(operations_path).expect("Failed to read operations.toml");
+    let operations: toml::Value = operations_text
+        .parse()
+        .expect("Failed to parse operations.toml");
+    let sections = ["queries", "mutations", "subscriptions"]
+        .map(|section| (section, parse_operations_section(&operations, section).0));
+    let allowed: Vec<(&str, &HashSet<String>)> = sections
+        .iter()
+        .map(|(section, allowed)| (*section, allowed))
+        .collect();
+
+    let diff = diff::diff(&old, &new, &allowed);
+    if json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&diff.to_json()).expect("diff serializes")
+        );
+    } else {
+        print!("{}", diff);
+    }
+}
+
+/// `lineark-codegen documents [--schema <schema.graphql>] --out <file.rs>
+/// <file.graphql>...`: generate typed operations from `.graphql` documents.
+fn run_documents(args: &[String], schema_path: &Path) {
+    let usage = || -> ! {
+        eprintln!(
+            "Usage: lineark-codegen documents [--schema <schema.graphql>] --out <file.rs> <file.graphql>..."
+        );
+        std::process::exit(2);
+    };
+    let mut schema_path = schema_path.to_path_buf();
+    let mut out = None;
+    let mut documents = Vec::new();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--schema" => schema_path = args.next().unwrap_or_else(|| usage()).into(),
+            "--out" => out = Some(Path::new(args.next().unwrap_or_else(|| usage()))),
+            path => documents.push(path),
+        }
+    }
+    let Some(out) = out else { usage() };
+    if documents.is_empty() {
+        usage();
+    }
+
+    let schema_text = std::fs::read_to_string(&schema_path)
+        .unwrap_or_else(|e| panic!("Failed to read {}: {}", schema_path.display(), e));
+    let schema = parser::parse(&schema_text);
+    let sources: Vec<emit_documents::Source> = documents
+        .iter()
+        .map(|path| emit_documents::Source {
+            path: path.to_string(),
+            text: std::fs::read_to_string(path)
+                .unwrap_or_else(|e| panic!("Failed to read {}: {}", path, e)),
+        })
+        .collect();
+
+    match emit_documents::emit(&schema, &sources) {
+        Ok(tokens) => write_formatted(out, tokens),
+        Err(errors) => {
+            for error in &errors {
+                eprintln!("error: {}", error);
+            }
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Print how much of the schema is deprecated, and warn about each
+/// deprecated operation or argument that operations.toml enables.
+fn report_deprecations(
+    schema: &parser::ParsedSchema,
+    sections: [(&str, &[parser::FieldDef], &HashSet<String>); 3],
+) {
+    let fields = || {
+        schema
+            .objects
+            .iter()
+            .flat_map(|o| &o.fields)
+            .chain(schema.interfaces.iter().flat_map(|i| &i.fields))
+            .chain(schema.inputs.iter().flat_map(|i| &i.fields))
+            .chain(&schema.query_fields)
+            .chain(&schema.mutation_fields)
+            .chain(&schema.subscription_fields)
+    };
+    println!(
+        "  {} deprecated fields, {} deprecated arguments, {} deprecated enum values",
+        fields().filter(|f| f.deprecated.is_some()).count(),
+        fields()
+            .flat_map(|f| &f.arguments)
+            .filter(|a| a.deprecated.is_some())
+            .count(),
+        schema
+            .enums
+            .iter()
+            .flat_map(|e| &e.values)
+            .filter(|v| v.deprecated.is_some())
+            .count(),
+    );
+
+    for (kind, fields, allowed) in sections {
+        for field in fields.iter().filter(|f| allowed.contains(&f.name)) {
+            if let Some(reason) = &field.deprecated {
+                eprintln!(
+                    "Warning: operations.toml enables deprecated {} `{}`: {}",
+                    kind, field.name, reason
+                );
+            }
+            for arg in &field.arguments {
+                if let Some(reason) = &arg.deprecated {
+                    eprintln!(
+                        "Warning: argument `{}` of {} `{}` is deprecated: {}",
+                        arg.name, kind, field.name, reason
+                    );
+                }
+            }
+        }
+    }
+}
+
 /// Per-operation configuration parsed from operations.toml.
 #[derive(Debug, Clone)]
 struct OperationConfig {
     rename: Option<String>,
+    /// Cargo feature to gate the operation behind instead of always
+    /// compiling it.
+    feature: Option<String>,
 }
 
 /// Parse an operations section from operations.toml.
@@ -187,6 +566,8 @@ struct OperationConfig {
 /// - `name = true` — use default method name
 /// - `name = "rename"` — use a custom Rust method name
 /// - `name = { rename = "..." }` — table config with rename
+/// - `name = { feature = "...", rename = "..." }` — generated behind a cargo
+///   feature rather than always; not part of the returned allowed set
 fn parse_operations_section(
     operations: &toml::Value,
     section: &str,
@@ -206,13 +587,17 @@ fn parse_operations_section(
                         key.clone(),
                         OperationConfig {
                             rename: Some(rename.clone()),
+                            feature: None,
                         },
                     );
                 }
                 toml::Value::Table(t) => {
-                    allowed.insert(key.clone());
                     let rename = t.get("rename").and_then(|v| v.as_str()).map(String::from);
-                    configs.insert(key.clone(), OperationConfig { rename });
+                    let feature = t.get("feature").and_then(|v| v.as_str()).map(String::from);
+                    if feature.is_none() {
+                        allowed.insert(key.clone());
+                    }
+                    configs.insert(key.clone(), OperationConfig { rename, feature });
                 }
                 _ => {}
             }
@@ -222,6 +607,29 @@ fn parse_operations_section(
     (allowed, configs)
 }
 
+/// The cargo feature of each operation in `fields` that isn't always
+/// compiled: the one configured in operations.toml, else its domain's.
+/// Operations with neither are added to `unassigned`.
+fn gated_operations(
+    fields: &[parser::FieldDef],
+    allowed: &HashSet<String>,
+    configs: &HashMap<String, OperationConfig>,
+    domains: &domains::Domains,
+    unassigned: &mut Vec<String>,
+) -> HashMap<String, String> {
+    let mut features = HashMap::new();
+    for field in fields.iter().filter(|f| !allowed.contains(&f.name)) {
+        let configured = configs.get(&field.name).and_then(|c| c.feature.as_deref());
+        match configured.or_else(|| domains.domain_of(&field.name)) {
+            Some(feature) => {
+                features.insert(field.name.clone(), feature.to_string());
+            }
+            None => unassigned.push(field.name.clone()),
+        }
+    }
+    features
+}
+
 fn write_formatted(path: &Path, tokens: proc_macro2::TokenStream) {
     let code = tokens.to_string();
     // Parse with syn and format with prettyplease.
@@ -20,6 +20,8 @@ pub struct FieldDef {
     pub description: Option<String>,
     pub ty: GqlType,
     pub arguments: Vec<ArgumentDef>,
+    /// The `@deprecated` reason, if the field is deprecated.
+    pub deprecated: Option<String>,
 }
 
 /// A simplified argument representation.
@@ -29,6 +31,8 @@ pub struct ArgumentDef {
     pub name: String,
     pub description: Option<String>,
     pub ty: GqlType,
+    /// The `@deprecated` reason, if the argument is deprecated.
+    pub deprecated: Option<String>,
 }
 
 /// A simplified enum value.
@@ -36,6 +40,8 @@ pub struct ArgumentDef {
 pub struct EnumValueDef {
     pub name: String,
     pub description: Option<String>,
+    /// The `@deprecated` reason, if the value is deprecated.
+    pub deprecated: Option<String>,
 }
 
 /// A simplified enum type.
@@ -52,6 +58,26 @@ pub struct ObjectDef {
     pub name: String,
     pub description: Option<String>,
     pub fields: Vec<FieldDef>,
+    /// Interfaces the type implements, as declared (including inherited ones).
+    pub interfaces: Vec<String>,
+}
+
+/// A simplified interface type.
+#[derive(Debug, Clone)]
+pub struct InterfaceDef {
+    pub name: String,
+    pub description: Option<String>,
+    pub fields: Vec<FieldDef>,
+    /// Interfaces this interface implements.
+    pub interfaces: Vec<String>,
+}
+
+/// A simplified union type.
+#[derive(Debug, Clone)]
+pub struct UnionDef {
+    pub name: String,
+    pub description: Option<String>,
+    pub members: Vec<String>,
 }
 
 /// A simplified input type.
@@ -94,8 +120,11 @@ pub struct ParsedSchema {
     pub enums: Vec<EnumDef>,
     pub objects: Vec<ObjectDef>,
     pub inputs: Vec<InputDef>,
+    pub interfaces: Vec<InterfaceDef>,
+    pub unions: Vec<UnionDef>,
     pub query_fields: Vec<FieldDef>,
     pub mutation_fields: Vec<FieldDef>,
+    pub subscription_fields: Vec<FieldDef>,
     pub type_kind_map: HashMap<String, TypeKind>,
 }
 
@@ -117,8 +146,11 @@ pub fn parse(schema_text: &str) -> ParsedSchema {
     let mut enums = Vec::new();
     let mut objects = Vec::new();
     let mut inputs = Vec::new();
+    let mut interfaces = Vec::new();
+    let mut unions = Vec::new();
     let mut query_fields = Vec::new();
     let mut mutation_fields = Vec::new();
+    let mut subscription_fields = Vec::new();
     let mut type_kind_map: HashMap<String, TypeKind> = HashMap::new();
 
     // Register built-in scalars.
@@ -148,11 +180,14 @@ pub fn parse(schema_text: &str) -> ParsedSchema {
                     query_fields = fields;
                 } else if name == "Mutation" {
                     mutation_fields = fields;
+                } else if name == "Subscription" {
+                    subscription_fields = fields;
                 } else {
                     objects.push(ObjectDef {
                         name,
                         description,
                         fields,
+                        interfaces: extract_implements(&o.implements_interfaces()),
                     });
                 }
             }
@@ -164,10 +199,25 @@ pub fn parse(schema_text: &str) -> ParsedSchema {
             cst::Definition::InterfaceTypeDefinition(i) => {
                 let name = extract_name(&i.name());
                 type_kind_map.insert(name.clone(), TypeKind::Interface);
+                interfaces.push(InterfaceDef {
+                    name,
+                    description: extract_description(&i.description()),
+                    fields: extract_fields(&i.fields_definition()),
+                    interfaces: extract_implements(&i.implements_interfaces()),
+                });
             }
             cst::Definition::UnionTypeDefinition(u) => {
                 let name = extract_name(&u.name());
                 type_kind_map.insert(name.clone(), TypeKind::Union);
+                let members = u
+                    .union_member_types()
+                    .map(|m| m.named_types().map(|t| extract_name(&t.name())).collect())
+                    .unwrap_or_default();
+                unions.push(UnionDef {
+                    name,
+                    description: extract_description(&u.description()),
+                    members,
+                });
             }
             _ => {}
         }
@@ -178,13 +228,16 @@ pub fn parse(schema_text: &str) -> ParsedSchema {
         enums,
         objects,
         inputs,
+        interfaces,
+        unions,
         query_fields,
         mutation_fields,
+        subscription_fields,
         type_kind_map,
     }
 }
 
-fn extract_name(name: &Option<cst::Name>) -> String {
+pub(crate) fn extract_name(name: &Option<cst::Name>) -> String {
     name.as_ref()
         .map(|n| n.text().to_string())
         .unwrap_or_default()
@@ -197,7 +250,33 @@ fn extract_description(desc: &Option<cst::Description>) -> Option<String> {
         .filter(|s| !s.is_empty())
 }
 
-fn extract_type(ty: &Option<cst::Type>) -> GqlType {
+fn extract_implements(implements: &Option<cst::ImplementsInterfaces>) -> Vec<String> {
+    implements
+        .as_ref()
+        .map(|i| i.named_types().map(|t| extract_name(&t.name())).collect())
+        .unwrap_or_default()
+}
+
+/// The reason given by an `@deprecated` directive, or the GraphQL default
+/// reason if it has none. `None` if the definition is not deprecated.
+fn extract_deprecation(directives: &Option<cst::Directives>) -> Option<String> {
+    let directive = directives
+        .as_ref()?
+        .directives()
+        .find(|d| extract_name(&d.name()) == "deprecated")?;
+    let reason = directive
+        .arguments()
+        .into_iter()
+        .flat_map(|args| args.arguments())
+        .find(|arg| extract_name(&arg.name()) == "reason")
+        .and_then(|arg| match arg.value() {
+            Some(cst::Value::StringValue(v)) => Some(String::from(v)),
+            _ => None,
+        });
+    Some(reason.unwrap_or_else(|| "No longer supported".to_string()))
+}
+
+pub(crate) fn extract_type(ty: &Option<cst::Type>) -> GqlType {
     match ty {
         None => GqlType::Named("String".to_string()),
         Some(t) => match t {
@@ -239,6 +318,7 @@ fn extract_fields(fields_def: &Option<cst::FieldsDefinition>) -> Vec<FieldDef> {
                 description,
                 ty,
                 arguments,
+                deprecated: extract_deprecation(&f.directives()),
             }
         })
         .collect()
@@ -257,6 +337,7 @@ fn extract_arguments(args_def: &Option<cst::ArgumentsDefinition>) -> Vec<Argumen
                 name,
                 description,
                 ty,
+                deprecated: extract_deprecation(&iv.directives()),
             }
         })
         .collect()
@@ -278,6 +359,7 @@ fn extract_enum(e: &cst::EnumTypeDefinition) -> EnumDef {
                     EnumValueDef {
                         name: val_name,
                         description: val_desc,
+                        deprecated: extract_deprecation(&ev.directives()),
                     }
                 })
                 .collect()
@@ -307,6 +389,7 @@ fn extract_input(i: &cst::InputObjectTypeDefinition) -> InputDef {
                         description: fdesc,
                         ty,
                         arguments: Vec::new(),
+                        deprecated: extract_deprecation(&iv.directives()),
                     }
                 })
                 .collect()
@@ -339,6 +422,14 @@ pub fn doc_comment_tokens(description: &Option<String>) -> proc_macro2::TokenStr
     quote::quote! { #(#lines)* }
 }
 
+/// Emit a `#[deprecated(note = ...)]` attribute for a deprecation reason.
+pub fn deprecated_tokens(deprecated: &Option<String>) -> proc_macro2::TokenStream {
+    match deprecated {
+        Some(reason) => quote::quote! { #[deprecated(note = #reason)] },
+        None => proc_macro2::TokenStream::new(),
+    }
+}
+
 /// Sanitize a GraphQL description for use as a Rust doc comment.
 ///
 /// - Escapes `[Foo]` bracket tags (e.g. `[DEPRECATED]`) that rustdoc would
@@ -687,8 +778,12 @@ mod tests {
             interface Node {
                 id: ID!
             }
-            union SearchResult = User | Team
-            type User {
+            "An entity." interface Entity implements Node {
+                id: ID!
+                createdAt: DateTime
+            }
+            "A search hit." union SearchResult = User | Team
+            type User implements Entity & Node {
                 id: ID!
             }
             type Team {
@@ -701,6 +796,23 @@ mod tests {
             schema.type_kind_map.get("SearchResult"),
             Some(&TypeKind::Union)
         );
+
+        let entity = schema
+            .interfaces
+            .iter()
+            .find(|i| i.name == "Entity")
+            .unwrap();
+        assert_eq!(entity.description.as_deref(), Some("An entity."));
+        assert_eq!(entity.fields.len(), 2);
+        assert_eq!(entity.interfaces, vec!["Node"]);
+
+        assert_eq!(schema.unions.len(), 1);
+        assert_eq!(schema.unions[0].members, vec!["User", "Team"]);
+
+        let user = schema.objects.iter().find(|o| o.name == "User").unwrap();
+        assert_eq!(user.interfaces, vec!["Entity", "Node"]);
+        let team = schema.objects.iter().find(|o| o.name == "Team").unwrap();
+        assert!(team.interfaces.is_empty());
     }
 
     #[test]
@@ -725,4 +837,70 @@ mod tests {
         assert!(names.contains(&"createUser"));
         assert!(names.contains(&"deleteUser"));
     }
+
+    #[test]
+    fn parse_subscription_fields() {
+        let schema_text = r#"
+            type Issue {
+                id: ID!
+            }
+            input IssueSubscriptionFilter {
+                teamId: String
+            }
+            type Subscription {
+                issueCreated(filter: IssueSubscriptionFilter): Issue!
+                issueArchived: Issue!
+            }
+        "#;
+        let schema = parse(schema_text);
+        let names: Vec<&str> = schema
+            .subscription_fields
+            .iter()
+            .map(|f| f.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["issueCreated", "issueArchived"]);
+        assert_eq!(schema.subscription_fields[0].arguments.len(), 1);
+        // Subscription is a root type, not a regular object.
+        assert!(!schema.objects.iter().any(|o| o.name == "Subscription"));
+    }
+
+    #[test]
+    fn parse_deprecations() {
+        let schema_text = r#"
+            enum Kind {
+                a
+                b @deprecated
+            }
+            input RoadmapInput {
+                name: String @deprecated(reason: "Use title instead.")
+                title: String
+            }
+            type Query {
+                roadmaps(first: Int, orderBy: String @deprecated(reason: "Unused.")): Kind!
+                    @deprecated(reason: "Roadmaps are deprecated, use initiatives instead.")
+                initiatives: Kind!
+            }
+        "#;
+        let schema = parse(schema_text);
+        let roadmaps = &schema.query_fields[0];
+        assert_eq!(
+            roadmaps.deprecated.as_deref(),
+            Some("Roadmaps are deprecated, use initiatives instead.")
+        );
+        assert_eq!(roadmaps.arguments[0].deprecated, None);
+        assert_eq!(roadmaps.arguments[1].deprecated.as_deref(), Some("Unused."));
+        assert_eq!(schema.query_fields[1].deprecated, None);
+
+        let input = &schema.inputs[0];
+        assert_eq!(
+            input.fields[0].deprecated.as_deref(),
+            Some("Use title instead.")
+        );
+        assert_eq!(input.fields[1].deprecated, None);
+
+        // A bare directive gets the GraphQL default reason.
+        let values = &schema.enums[0].values;
+        assert_eq!(values[0].deprecated, None);
+        assert_eq!(values[1].deprecated.as_deref(), Some("No longer supported"));
+    }
 }
@@ -22,19 +22,53 @@
 //!
 //! Generates: `"id title state { <StateRef::selection()> }"`
 //!
+//! Nested connections take arguments, as literals or as variables declared
+//! inline and hoisted to the operation:
+//!
+//! ```ignore
+//! #[graphql(nested, args(first = 50, orderBy = "updatedAt", filter = "$commentFilter: CommentFilter"))]
+//! comments: Option<CommentPage>,
+//! ```
+//!
 //! With `full_type`, the macro also generates compile-time validation that
 //! each field exists on the full type with a compatible type.
+//!
+//! # Interfaces and unions
+//!
+//! On an enum tagged by `__typename`, each newtype variant becomes an inline
+//! fragment on the object type it is named after. Unit variants (such as a
+//! `#[serde(other)]` fallback) select nothing:
+//!
+//! ```ignore
+//! #[derive(GraphQLFields, Deserialize)]
+//! #[graphql(full_type = Notification)]
+//! #[serde(tag = "__typename")]
+//! enum MyNotification {
+//!     IssueNotification(IssueNotificationRow),
+//!     #[serde(other)]
+//!     Other,
+//! }
+//! ```
+//!
+//! Generates: `"__typename ... on IssueNotification { <IssueNotificationRow::selection()> }"`
 
 use heck::ToLowerCamelCase;
 use proc_macro::TokenStream;
 use quote::quote;
 use syn::{parse_macro_input, Data, DeriveInput, Fields, Type};
 
-/// Derive `GraphQLFields` for a struct.
+/// Derive `GraphQLFields` for a struct, or for an enum over the object types
+/// of an interface or union.
 ///
-/// Each field becomes a GraphQL selection entry:
+/// Each struct field becomes a GraphQL selection entry:
 /// - Plain fields → `camelCaseName`
 /// - `#[graphql(nested)]` fields → `camelCaseName { <InnerType::selection()> }`
+/// - `#[graphql(args(first = 50, orderBy = "updatedAt"))]` adds arguments:
+///   `camelCaseName(first: 50, orderBy: updatedAt)`. Strings are GraphQL
+///   source; `"$name: Type"` reads a variable, which the operation declares
+///   and `Operation::variable` sets.
+///
+/// Each newtype enum variant becomes `... on VariantName { <InnerType::selection()> }`.
 ///
 /// # Struct-level attributes
 ///
@@ -50,6 +84,7 @@ pub fn derive_graphql_fields(input: TokenStream) -> TokenStream {
     let full_type_path = parse_full_type(&input.attrs);
 
     let fields = match &input.data {
+        Data::Enum(data) => return derive_for_enum(name, data, full_type_path.as_ref()).into(),
         Data::Struct(data) => match &data.fields {
             Fields::Named(named) => &named.named,
             _ => {
@@ -62,9 +97,12 @@ pub fn derive_graphql_fields(input: TokenStream) -> TokenStream {
             }
         },
         _ => {
-            return syn::Error::new_spanned(&input, "GraphQLFields can only be derived on structs")
-                .to_compile_error()
-                .into();
+            return syn::Error::new_spanned(
+                &input,
+                "GraphQLFields can only be derived on structs and enums",
+            )
+            .to_compile_error()
+            .into();
         }
     };
 
@@ -80,27 +118,23 @@ pub fn derive_graphql_fields(input: TokenStream) -> TokenStream {
         let clean_name = rust_name.strip_prefix("r#").unwrap_or(&rust_name);
         let gql_name = clean_name.to_lower_camel_case();
 
-        let is_nested = field.attrs.iter().any(|attr| {
-            if !attr.path().is_ident("graphql") {
-                return false;
-            }
-            let mut found = false;
-            let _ = attr.parse_nested_meta(|meta| {
-                if meta.path.is_ident("nested") {
-                    found = true;
-                }
-                Ok(())
-            });
-            found
-        });
+        let attrs = match parse_field_attrs(&field.attrs) {
+            Ok(attrs) => attrs,
+            Err(err) => return err.to_compile_error().into(),
+        };
+        let field_with_args = if attrs.args.is_empty() {
+            gql_name
+        } else {
+            format!("{}({})", gql_name, attrs.args.join(", "))
+        };
 
-        if is_nested {
+        if attrs.nested {
             // Extract the inner type (unwrap Option<T>, Vec<T>, Box<T>).
             let inner_ty = unwrap_type(&field.ty);
             selection_parts.push(quote! {
                 {
                     let nested = <#inner_ty as GraphQLFields>::selection();
-                    format!("{} {{ {} }}", #gql_name, nested)
+                    format!("{} {{ {} }}", #field_with_args, nested)
                 }
             });
             // For nested fields, only validate field existence (not type compatibility,
@@ -112,7 +146,7 @@ pub fn derive_graphql_fields(input: TokenStream) -> TokenStream {
             }
         } else {
             selection_parts.push(quote! {
-                #gql_name.to_string()
+                #field_with_args.to_string()
             });
             // For scalar fields, validate both field existence AND type compatibility.
             if full_type_path.is_some() {
@@ -165,6 +199,126 @@ pub fn derive_graphql_fields(input: TokenStream) -> TokenStream {
     expanded.into()
 }
 
+/// Derive for an enum: one inline fragment per newtype variant.
+fn derive_for_enum(
+    name: &syn::Ident,
+    data: &syn::DataEnum,
+    full_type_path: Option<&syn::Path>,
+) -> proc_macro2::TokenStream {
+    let mut fragments = Vec::new();
+    let mut validation_checks = Vec::new();
+
+    for variant in &data.variants {
+        let variant_name = &variant.ident;
+        let inner_ty = match &variant.fields {
+            Fields::Unit => continue,
+            Fields::Unnamed(unnamed) if unnamed.unnamed.len() == 1 => {
+                unwrap_type(&unnamed.unnamed[0].ty)
+            }
+            _ => {
+                return syn::Error::new_spanned(
+                    variant,
+                    "GraphQLFields enum variants must be unit or hold a single type",
+                )
+                .to_compile_error();
+            }
+        };
+        let type_name = variant_name.to_string();
+        fragments.push(quote! {
+            (#type_name, <#inner_ty as GraphQLFields>::selection())
+        });
+        // The full type's enum must have a variant for the same object type.
+        if let Some(full_type) = full_type_path {
+            validation_checks.push(quote! {
+                { let _ = matches!(__v, #full_type::#variant_name(_)); }
+            });
+        }
+    }
+
+    let full_type_assoc = match full_type_path {
+        Some(path) => quote! { type FullType = #path; },
+        None => quote! { type FullType = Self; },
+    };
+
+    let validation_block = match full_type_path {
+        Some(full_type) if !validation_checks.is_empty() => quote! {
+            const _: () = {
+                #[allow(unused)]
+                fn __graphql_validate(__v: &#full_type) {
+                    #(#validation_checks)*
+                }
+            };
+        },
+        _ => quote! {},
+    };
+
+    quote! {
+        impl GraphQLFields for #name {
+            #full_type_assoc
+
+            fn selection() -> String {
+                ::lineark_sdk::field_selection::inline_fragments(&[
+                    #(#fragments),*
+                ])
+            }
+        }
+
+        #validation_block
+    }
+}
+
+/// Field-level `#[graphql(...)]` options.
+#[derive(Default)]
+struct FieldAttrs {
+    nested: bool,
+    /// Rendered `name: value` arguments.
+    args: Vec<String>,
+}
+
+/// Parse `#[graphql(nested, args(name = value, ...))]` from field attributes.
+fn parse_field_attrs(attrs: &[syn::Attribute]) -> syn::Result<FieldAttrs> {
+    let mut parsed = FieldAttrs::default();
+    for attr in attrs.iter().filter(|a| a.path().is_ident("graphql")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("nested") {
+                parsed.nested = true;
+                Ok(())
+            } else if meta.path.is_ident("args") {
+                meta.parse_nested_meta(|arg| {
+                    let name = arg
+                        .path
+                        .get_ident()
+                        .ok_or_else(|| arg.error("expected an argument name"))?
+                        .to_string()
+                        .to_lower_camel_case();
+                    let value = argument_value(&arg.value()?.parse()?)?;
+                    parsed.args.push(format!("{}: {}", name, value));
+                    Ok(())
+                })
+            } else {
+                Err(meta.error("expected `nested` or `args(...)`"))
+            }
+        })?;
+    }
+    Ok(parsed)
+}
+
+/// Render an argument value as GraphQL. Numbers and booleans are used as
+/// is; a string is GraphQL source — an enum value such as `"updatedAt"`, an
+/// input object, or a variable declared as `"$name: Type"`.
+fn argument_value(lit: &syn::Lit) -> syn::Result<String> {
+    match lit {
+        syn::Lit::Int(int) => Ok(int.base10_digits().to_string()),
+        syn::Lit::Float(float) => Ok(float.base10_digits().to_string()),
+        syn::Lit::Bool(b) => Ok(b.value.to_string()),
+        syn::Lit::Str(s) => Ok(s.value()),
+        other => Err(syn::Error::new_spanned(
+            other,
+            "expected a number, a boolean, or a string of GraphQL",
+        )),
+    }
+}
+
 /// Parse `#[graphql(full_type = Path)]` from struct-level attributes.
 fn parse_full_type(attrs: &[syn::Attribute]) -> Option<syn::Path> {
     for attr in attrs {
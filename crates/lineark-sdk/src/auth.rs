@@ -2,6 +2,9 @@
 //!
 //! Supports three sources (in precedence order): explicit token, the
 //! `LINEAR_API_TOKEN` environment variable, and a token file at any path.
+//! Applications authenticating through OAuth2 use the [`oauth`] submodule.
+
+pub mod oauth;
 
 use crate::error::LinearError;
 use std::path::Path;
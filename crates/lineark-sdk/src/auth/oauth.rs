@@ -0,0 +1,841 @@
+//! OAuth2 application authentication.
+//!
+//! Linear integrations that act as an OAuth application obtain tokens through
+//! one of two grants:
+//!
+//! - **Authorization code + PKCE** — redirect the user to
+//!   [`AuthorizationRequest::url`], then trade the returned code for a token
+//!   with [`OAuthApp::exchange_code`]. Pass [`Actor::App`] to have mutations
+//!   attributed to the application instead of the authorizing user.
+//! - **Client credentials** — [`OAuthApp::client_credentials`] issues an
+//!   app-actor token directly, without user interaction.
+//!
+//! Hand the resulting [`OAuthToken`] to
+//! [`ClientBuilder::oauth`](crate::ClientBuilder::oauth); the client sends it
+//! as a `Bearer` token and refreshes it transparently when it expires or the
+//! API answers 401.
+//!
+//! ```no_run
+//! # async fn example() -> Result<(), lineark_sdk::LinearError> {
+//! use lineark_sdk::auth::oauth::{OAuthApp, Pkce};
+//! use lineark_sdk::Client;
+//!
+//! let app = OAuthApp::new("client-id")
+//!     .client_secret("client-secret")
+//!     .redirect_uri("https://example.com/callback");
+//!
+//! let pkce = Pkce::new();
+//! let url = app
+//!     .authorization_request()
+//!     .scopes(["read", "write"])
+//!     .state("opaque-state")
+//!     .pkce(&pkce)
+//!     .url();
+//! // ... redirect the user to `url`, receive `code` on the callback ...
+//! # let code = "";
+//!
+//! let token = app.exchange_code(code, Some(&pkce)).await?;
+//! let client = Client::builder().oauth(app, token).build()?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::error::LinearError;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Deserializer, Serialize};
+use sha2::{Digest, Sha256};
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+const LINEAR_AUTHORIZE_URL: &str = "https://linear.app/oauth/authorize";
+const LINEAR_TOKEN_URL: &str = "https://api.linear.app/oauth/token";
+
+/// Tokens this close to their expiry are treated as already expired, so a
+/// request doesn't race the deadline.
+const EXPIRY_LEEWAY_SECS: i64 = 60;
+
+/// A registered Linear OAuth application.
+#[derive(Clone)]
+pub struct OAuthApp {
+    client_id: String,
+    client_secret: Option<String>,
+    redirect_uri: Option<String>,
+    authorize_url: String,
+    token_url: String,
+    http: Option<reqwest::Client>,
+    timeout: Option<Duration>,
+}
+
+impl fmt::Debug for OAuthApp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OAuthApp")
+            .field("client_id", &self.client_id)
+            .field("client_secret", &self.client_secret.as_ref().map(|_| "***"))
+            .field("redirect_uri", &self.redirect_uri)
+            .field("authorize_url", &self.authorize_url)
+            .field("token_url", &self.token_url)
+            .finish()
+    }
+}
+
+impl OAuthApp {
+    /// An application identified by its OAuth client ID.
+    pub fn new(client_id: impl Into<String>) -> Self {
+        Self {
+            client_id: client_id.into(),
+            client_secret: None,
+            redirect_uri: None,
+            authorize_url: LINEAR_AUTHORIZE_URL.to_string(),
+            token_url: LINEAR_TOKEN_URL.to_string(),
+            http: None,
+            timeout: None,
+        }
+    }
+
+    /// The client secret. Required for the client-credentials grant and for
+    /// confidential clients; public clients rely on PKCE alone.
+    pub fn client_secret(mut self, secret: impl Into<String>) -> Self {
+        self.client_secret = Some(secret.into());
+        self
+    }
+
+    /// The redirect URI registered for the application.
+    pub fn redirect_uri(mut self, uri: impl Into<String>) -> Self {
+        self.redirect_uri = Some(uri.into());
+        self
+    }
+
+    /// Override the authorization endpoint.
+    pub fn authorize_url(mut self, url: impl Into<String>) -> Self {
+        self.authorize_url = url.into();
+        self
+    }
+
+    /// Override the token endpoint (e.g. for a mock server in tests).
+    pub fn token_url(mut self, url: impl Into<String>) -> Self {
+        self.token_url = url.into();
+        self
+    }
+
+    /// Use a pre-built [`reqwest::Client`] for token endpoint requests.
+    ///
+    /// Once the app is handed to a [`ClientBuilder`](crate::ClientBuilder),
+    /// refreshes go through the built client's HTTP client and timeout unless
+    /// one was set here.
+    pub fn http_client(mut self, http: reqwest::Client) -> Self {
+        self.http = Some(http);
+        self
+    }
+
+    /// Fill in the transport of the [`Client`](crate::Client) this app
+    /// authenticates, where none was set explicitly.
+    pub(crate) fn with_transport(
+        mut self,
+        http: &reqwest::Client,
+        timeout: Option<Duration>,
+    ) -> Self {
+        self.http.get_or_insert_with(|| http.clone());
+        self.timeout = self.timeout.or(timeout);
+        self
+    }
+
+    /// The application's client ID.
+    pub fn client_id(&self) -> &str {
+        &self.client_id
+    }
+
+    /// Start building the URL users are redirected to for consent.
+    pub fn authorization_request(&self) -> AuthorizationRequest<'_> {
+        AuthorizationRequest {
+            app: self,
+            scopes: Vec::new(),
+            state: None,
+            code_challenge: None,
+            actor: None,
+            prompt_consent: false,
+        }
+    }
+
+    /// Exchange an authorization code for a token. Pass the [`Pkce`] pair used
+    /// to build the authorization URL, if any.
+    pub async fn exchange_code(
+        &self,
+        code: &str,
+        pkce: Option<&Pkce>,
+    ) -> Result<OAuthToken, LinearError> {
+        let mut params = vec![("grant_type", "authorization_code"), ("code", code)];
+        if let Some(uri) = &self.redirect_uri {
+            params.push(("redirect_uri", uri));
+        }
+        if let Some(pkce) = pkce {
+            params.push(("code_verifier", pkce.verifier()));
+        }
+        self.request_token(params).await
+    }
+
+    /// Obtain a fresh access token from a refresh token.
+    ///
+    /// Linear rotates refresh tokens: persist the returned token's
+    /// `refresh_token`, the old one stops working. If the server does not
+    /// return a new refresh token, the one passed in is carried over.
+    pub async fn refresh(&self, refresh_token: &str) -> Result<OAuthToken, LinearError> {
+        let mut token = self
+            .request_token(vec![
+                ("grant_type", "refresh_token"),
+                ("refresh_token", refresh_token),
+            ])
+            .await?;
+        if token.refresh_token.is_none() {
+            token.refresh_token = Some(refresh_token.to_string());
+        }
+        Ok(token)
+    }
+
+    /// Obtain an app-actor token with the client-credentials grant.
+    pub async fn client_credentials(&self, scopes: &[&str]) -> Result<OAuthToken, LinearError> {
+        if self.client_secret.is_none() {
+            return Err(LinearError::AuthConfig(
+                "The client credentials grant requires a client secret".to_string(),
+            ));
+        }
+        let scope = scopes.join(",");
+        let mut params = vec![("grant_type", "client_credentials")];
+        if !scope.is_empty() {
+            params.push(("scope", &scope));
+        }
+        self.request_token(params).await
+    }
+
+    /// POST a form to the token endpoint and parse the token response.
+    async fn request_token(
+        &self,
+        mut params: Vec<(&str, &str)>,
+    ) -> Result<OAuthToken, LinearError> {
+        params.push(("client_id", &self.client_id));
+        if let Some(secret) = &self.client_secret {
+            params.push(("client_secret", secret));
+        }
+        let body = url::form_urlencoded::Serializer::new(String::new())
+            .extend_pairs(params)
+            .finish();
+
+        let mut request = self
+            .http
+            .clone()
+            .unwrap_or_default()
+            .post(&self.token_url)
+            .header("Content-Type", "application/x-www-form-urlencoded")
+            .header("Accept", "application/json")
+            .body(body);
+        if let Some(timeout) = self.timeout {
+            request = request.timeout(timeout);
+        }
+        let response = request.send().await?;
+
+        let status = response.status();
+        let text = response.text().await.unwrap_or_default();
+        if !status.is_success() {
+            // RFC 6749 §5.2 error responses carry `error` / `error_description`.
+            if let Ok(err) = serde_json::from_str::<TokenErrorResponse>(&text) {
+                let message = match err.error_description {
+                    Some(desc) => format!("{}: {}", err.error, desc),
+                    None => err.error,
+                };
+                return Err(LinearError::Authentication(message));
+            }
+            return Err(LinearError::HttpError {
+                status: status.as_u16(),
+                body: text,
+            });
+        }
+
+        let mut token: OAuthToken = serde_json::from_str(&text).map_err(|e| {
+            LinearError::MissingData(format!("Failed to deserialize token response: {}", e))
+        })?;
+        if token.expires_at.is_none() {
+            token.expires_at = token
+                .expires_in
+                .and_then(|secs| i64::try_from(secs).ok())
+                .map(|secs| Utc::now() + chrono::Duration::seconds(secs));
+        }
+        Ok(token)
+    }
+}
+
+#[derive(Deserialize)]
+struct TokenErrorResponse {
+    error: String,
+    #[serde(default)]
+    error_description: Option<String>,
+}
+
+/// Who mutations made with the resulting token are attributed to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Actor {
+    /// The authorizing user (Linear's default).
+    User,
+    /// The application itself.
+    App,
+}
+
+impl Actor {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::User => "user",
+            Self::App => "app",
+        }
+    }
+}
+
+/// Builder for the authorization URL of the authorization-code flow.
+#[derive(Debug, Clone)]
+pub struct AuthorizationRequest<'a> {
+    app: &'a OAuthApp,
+    scopes: Vec<String>,
+    state: Option<String>,
+    code_challenge: Option<String>,
+    actor: Option<Actor>,
+    prompt_consent: bool,
+}
+
+impl AuthorizationRequest<'_> {
+    /// Requested scopes, e.g. `read`, `write`, `issues:create`, `admin`.
+    pub fn scopes<I, S>(mut self, scopes: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.scopes.extend(scopes.into_iter().map(Into::into));
+        self
+    }
+
+    /// Opaque value echoed back on the redirect, for CSRF protection.
+    pub fn state(mut self, state: impl Into<String>) -> Self {
+        self.state = Some(state.into());
+        self
+    }
+
+    /// Attach a PKCE code challenge (S256).
+    pub fn pkce(mut self, pkce: &Pkce) -> Self {
+        self.code_challenge = Some(pkce.challenge().to_string());
+        self
+    }
+
+    /// Who mutations are attributed to.
+    pub fn actor(mut self, actor: Actor) -> Self {
+        self.actor = Some(actor);
+        self
+    }
+
+    /// Always show the consent screen, even if the user already approved.
+    pub fn prompt_consent(mut self) -> Self {
+        self.prompt_consent = true;
+        self
+    }
+
+    /// The URL to redirect the user to.
+    pub fn url(&self) -> String {
+        let mut url = match url::Url::parse(&self.app.authorize_url) {
+            Ok(url) => url,
+            Err(_) => return self.app.authorize_url.clone(),
+        };
+        {
+            let mut query = url.query_pairs_mut();
+            query.append_pair("client_id", &self.app.client_id);
+            query.append_pair("response_type", "code");
+            if let Some(uri) = &self.app.redirect_uri {
+                query.append_pair("redirect_uri", uri);
+            }
+            if !self.scopes.is_empty() {
+                query.append_pair("scope", &self.scopes.join(","));
+            }
+            if let Some(state) = &self.state {
+                query.append_pair("state", state);
+            }
+            if let Some(challenge) = &self.code_challenge {
+                query.append_pair("code_challenge", challenge);
+                query.append_pair("code_challenge_method", "S256");
+            }
+            if let Some(actor) = self.actor {
+                query.append_pair("actor", actor.as_str());
+            }
+            if self.prompt_consent {
+                query.append_pair("prompt", "consent");
+            }
+        }
+        url.into()
+    }
+}
+
+/// A PKCE verifier/challenge pair (RFC 7636, S256 method).
+///
+/// Keep the pair (or just the verifier, via [`Pkce::from_verifier`]) between
+/// building the authorization URL and exchanging the code.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pkce {
+    verifier: String,
+    challenge: String,
+}
+
+impl Pkce {
+    /// A fresh pair with a random 256-bit verifier.
+    pub fn new() -> Self {
+        Self::from_verifier(random_token())
+    }
+
+    /// Recreate the pair from a previously generated verifier.
+    pub fn from_verifier(verifier: impl Into<String>) -> Self {
+        let verifier = verifier.into();
+        let challenge = URL_SAFE_NO_PAD.encode(Sha256::digest(verifier.as_bytes()));
+        Self {
+            verifier,
+            challenge,
+        }
+    }
+
+    /// The secret sent with the code exchange.
+    pub fn verifier(&self) -> &str {
+        &self.verifier
+    }
+
+    /// The hashed value sent with the authorization URL.
+    pub fn challenge(&self) -> &str {
+        &self.challenge
+    }
+}
+
+impl Default for Pkce {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A random URL-safe value suitable for the `state` parameter.
+pub fn generate_state() -> String {
+    random_token()
+}
+
+/// 32 bytes from the OS CSPRNG, base64url-encoded without padding.
+fn random_token() -> String {
+    let mut bytes = [0u8; 32];
+    getrandom::getrandom(&mut bytes).expect("OS random number generator unavailable");
+    URL_SAFE_NO_PAD.encode(bytes)
+}
+
+/// An OAuth access token as returned by the token endpoint.
+///
+/// Serializable so it can be persisted and restored between runs;
+/// `expires_at` is filled in from `expires_in` when the token is received.
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct OAuthToken {
+    pub access_token: String,
+    #[serde(default = "default_token_type")]
+    pub token_type: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub refresh_token: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_scope")]
+    pub scope: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires_in: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+impl fmt::Debug for OAuthToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OAuthToken")
+            .field("access_token", &"***")
+            .field("token_type", &self.token_type)
+            .field("refresh_token", &self.refresh_token.as_ref().map(|_| "***"))
+            .field("scope", &self.scope)
+            .field("expires_in", &self.expires_in)
+            .field("expires_at", &self.expires_at)
+            .finish()
+    }
+}
+
+fn default_token_type() -> String {
+    "Bearer".to_string()
+}
+
+/// Linear returns `scope` as an array; RFC 6749 specifies a space-separated
+/// string. Accept both.
+fn deserialize_scope<'de, D: Deserializer<'de>>(d: D) -> Result<Vec<String>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Scope {
+        List(Vec<String>),
+        Joined(String),
+    }
+    Ok(match Option::<Scope>::deserialize(d)? {
+        Some(Scope::List(list)) => list,
+        Some(Scope::Joined(s)) => s
+            .split([' ', ','])
+            .filter(|s| !s.is_empty())
+            .map(str::to_string)
+            .collect(),
+        None => Vec::new(),
+    })
+}
+
+impl OAuthToken {
+    /// A token with only an access token, e.g. restored from elsewhere.
+    pub fn new(access_token: impl Into<String>) -> Self {
+        Self {
+            access_token: access_token.into(),
+            token_type: default_token_type(),
+            refresh_token: None,
+            scope: Vec::new(),
+            expires_in: None,
+            expires_at: None,
+        }
+    }
+
+    /// Attach a refresh token.
+    pub fn with_refresh_token(mut self, refresh_token: impl Into<String>) -> Self {
+        self.refresh_token = Some(refresh_token.into());
+        self
+    }
+
+    /// Whether the token has expired (or is about to). Tokens without a known
+    /// expiry are never considered expired.
+    pub fn is_expired(&self) -> bool {
+        self.expires_at
+            .is_some_and(|at| at - chrono::Duration::seconds(EXPIRY_LEEWAY_SECS) <= Utc::now())
+    }
+
+    /// The `Authorization` header value for this token.
+    pub fn authorization_header(&self) -> String {
+        format!("Bearer {}", self.access_token)
+    }
+}
+
+/// Callback invoked with every newly obtained token, so it can be persisted.
+#[derive(Clone)]
+pub(crate) struct TokenCallback(pub(crate) Arc<dyn Fn(&OAuthToken) + Send + Sync>);
+
+impl fmt::Debug for TokenCallback {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("TokenCallback")
+    }
+}
+
+/// How an [`OAuthSession`] obtains a replacement token.
+#[derive(Debug, Clone)]
+pub(crate) enum Renewal {
+    /// Use the current token's refresh token.
+    RefreshToken,
+    /// Request a new token with the client-credentials grant.
+    ClientCredentials(Vec<String>),
+}
+
+/// The live OAuth state shared by all clones of a [`Client`](crate::Client).
+pub(crate) struct OAuthSession {
+    app: OAuthApp,
+    renewal: Renewal,
+    token: tokio::sync::Mutex<Option<OAuthToken>>,
+    on_refresh: Option<TokenCallback>,
+}
+
+impl fmt::Debug for OAuthSession {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OAuthSession")
+            .field("app", &self.app)
+            .field("renewal", &self.renewal)
+            .finish_non_exhaustive()
+    }
+}
+
+impl OAuthSession {
+    pub(crate) fn new(
+        app: OAuthApp,
+        renewal: Renewal,
+        token: Option<OAuthToken>,
+        on_refresh: Option<TokenCallback>,
+    ) -> Self {
+        Self {
+            app,
+            renewal,
+            token: tokio::sync::Mutex::new(token),
+            on_refresh,
+        }
+    }
+
+    /// The `Authorization` header to send, renewing the token first if it is
+    /// missing or expired.
+    pub(crate) async fn authorization(&self) -> Result<String, LinearError> {
+        let mut guard = self.token.lock().await;
+        match guard.as_ref() {
+            Some(token) if !token.is_expired() => Ok(token.authorization_header()),
+            _ => {
+                let token = self.renew(guard.as_ref()).await?;
+                let header = token.authorization_header();
+                *guard = Some(token);
+                Ok(header)
+            }
+        }
+    }
+
+    /// Renew the token after the API rejected `rejected` with a 401. If another
+    /// task already replaced that token, this is a no-op.
+    pub(crate) async fn reauthenticate(&self, rejected: &str) -> Result<(), LinearError> {
+        let mut guard = self.token.lock().await;
+        if let Some(current) = guard.as_ref() {
+            if current.authorization_header() != rejected {
+                return Ok(());
+            }
+        }
+        let token = self.renew(guard.as_ref()).await?;
+        *guard = Some(token);
+        Ok(())
+    }
+
+    /// The token currently in use, if one has been obtained.
+    pub(crate) async fn current(&self) -> Option<OAuthToken> {
+        self.token.lock().await.clone()
+    }
+
+    async fn renew(&self, current: Option<&OAuthToken>) -> Result<OAuthToken, LinearError> {
+        let token = match &self.renewal {
+            Renewal::RefreshToken => {
+                let refresh_token = current
+                    .and_then(|t| t.refresh_token.as_deref())
+                    .ok_or_else(|| {
+                        LinearError::Authentication(
+                            "OAuth access token expired and no refresh token is available"
+                                .to_string(),
+                        )
+                    })?;
+                self.app.refresh(refresh_token).await?
+            }
+            Renewal::ClientCredentials(scopes) => {
+                let scopes: Vec<&str> = scopes.iter().map(String::as_str).collect();
+                self.app.client_credentials(&scopes).await?
+            }
+        };
+        if let Some(callback) = &self.on_refresh {
+            (callback.0)(&token);
+        }
+        Ok(token)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{body_string_contains, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn query_pairs(url: &str) -> Vec<(String, String)> {
+        url::Url::parse(url)
+            .unwrap()
+            .query_pairs()
+            .map(|(k, v)| (k.into_owned(), v.into_owned()))
+            .collect()
+    }
+
+    fn query_param(url: &str, key: &str) -> Option<String> {
+        query_pairs(url)
+            .into_iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v)
+    }
+
+    #[test]
+    fn pkce_matches_rfc7636_example() {
+        // RFC 7636 Appendix B.
+        let pkce = Pkce::from_verifier("dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk");
+        assert_eq!(
+            pkce.challenge(),
+            "E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM"
+        );
+    }
+
+    #[test]
+    fn pkce_new_is_random_and_valid_length() {
+        let a = Pkce::new();
+        let b = Pkce::new();
+        assert_ne!(a.verifier(), b.verifier());
+        assert_eq!(a.verifier().len(), 43);
+    }
+
+    #[test]
+    fn authorization_url_includes_all_parameters() {
+        let app = OAuthApp::new("cid").redirect_uri("https://example.com/cb");
+        let pkce = Pkce::from_verifier("verifier");
+        let url = app
+            .authorization_request()
+            .scopes(["read", "write"])
+            .state("xyz")
+            .pkce(&pkce)
+            .actor(Actor::App)
+            .prompt_consent()
+            .url();
+
+        assert!(url.starts_with(LINEAR_AUTHORIZE_URL));
+        assert_eq!(query_param(&url, "client_id").as_deref(), Some("cid"));
+        assert_eq!(query_param(&url, "response_type").as_deref(), Some("code"));
+        assert_eq!(
+            query_param(&url, "redirect_uri").as_deref(),
+            Some("https://example.com/cb")
+        );
+        assert_eq!(query_param(&url, "scope").as_deref(), Some("read,write"));
+        assert_eq!(query_param(&url, "state").as_deref(), Some("xyz"));
+        assert_eq!(
+            query_param(&url, "code_challenge").as_deref(),
+            Some(pkce.challenge())
+        );
+        assert_eq!(
+            query_param(&url, "code_challenge_method").as_deref(),
+            Some("S256")
+        );
+        assert_eq!(query_param(&url, "actor").as_deref(), Some("app"));
+        assert_eq!(query_param(&url, "prompt").as_deref(), Some("consent"));
+    }
+
+    #[test]
+    fn authorization_url_omits_unset_parameters() {
+        let url = OAuthApp::new("cid").authorization_request().url();
+        let keys: Vec<String> = query_pairs(&url).into_iter().map(|(k, _)| k).collect();
+        assert_eq!(keys, vec!["client_id", "response_type"]);
+    }
+
+    #[test]
+    fn token_deserializes_scope_array_and_string() {
+        let t: OAuthToken =
+            serde_json::from_str(r#"{"access_token":"a","scope":["read","write"]}"#).unwrap();
+        assert_eq!(t.scope, vec!["read", "write"]);
+        assert_eq!(t.token_type, "Bearer");
+        let t: OAuthToken =
+            serde_json::from_str(r#"{"access_token":"a","scope":"read write"}"#).unwrap();
+        assert_eq!(t.scope, vec!["read", "write"]);
+    }
+
+    #[test]
+    fn token_expiry() {
+        let mut t = OAuthToken::new("a");
+        assert!(!t.is_expired());
+        t.expires_at = Some(Utc::now() + chrono::Duration::seconds(10));
+        assert!(t.is_expired(), "within leeway counts as expired");
+        t.expires_at = Some(Utc::now() + chrono::Duration::hours(1));
+        assert!(!t.is_expired());
+    }
+
+    #[test]
+    fn debug_redacts_secrets() {
+        let app = OAuthApp::new("cid").client_secret("hunter2");
+        assert!(!format!("{:?}", app).contains("hunter2"));
+        let token = OAuthToken::new("access-secret").with_refresh_token("refresh-secret");
+        let debug = format!("{:?}", token);
+        assert!(!debug.contains("access-secret"));
+        assert!(!debug.contains("refresh-secret"));
+    }
+
+    #[tokio::test]
+    async fn exchange_code_posts_form_and_parses_token() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/oauth/token"))
+            .and(body_string_contains("grant_type=authorization_code"))
+            .and(body_string_contains("code=the-code"))
+            .and(body_string_contains("code_verifier=verifier"))
+            .and(body_string_contains("client_id=cid"))
+            .and(body_string_contains("client_secret=secret"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "access_token": "access-1",
+                "token_type": "Bearer",
+                "expires_in": 86399,
+                "refresh_token": "refresh-1",
+                "scope": ["read", "write"]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let app = OAuthApp::new("cid")
+            .client_secret("secret")
+            .token_url(format!("{}/oauth/token", server.uri()));
+        let token = app
+            .exchange_code("the-code", Some(&Pkce::from_verifier("verifier")))
+            .await
+            .unwrap();
+
+        assert_eq!(token.access_token, "access-1");
+        assert_eq!(token.refresh_token.as_deref(), Some("refresh-1"));
+        assert!(token.expires_at.is_some());
+        assert!(!token.is_expired());
+    }
+
+    #[tokio::test]
+    async fn refresh_keeps_old_refresh_token_when_not_rotated() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(body_string_contains("grant_type=refresh_token"))
+            .and(body_string_contains("refresh_token=refresh-1"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({"access_token": "access-2"})),
+            )
+            .mount(&server)
+            .await;
+
+        let app = OAuthApp::new("cid").token_url(server.uri());
+        let token = app.refresh("refresh-1").await.unwrap();
+        assert_eq!(token.access_token, "access-2");
+        assert_eq!(token.refresh_token.as_deref(), Some("refresh-1"));
+    }
+
+    #[tokio::test]
+    async fn client_credentials_requires_secret() {
+        let err = OAuthApp::new("cid")
+            .client_credentials(&["read"])
+            .await
+            .unwrap_err();
+        assert!(matches!(err, LinearError::AuthConfig(_)));
+    }
+
+    #[tokio::test]
+    async fn client_credentials_requests_scopes() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(body_string_contains("grant_type=client_credentials"))
+            .and(body_string_contains("scope=read%2Cwrite"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({"access_token": "app-token"})),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let app = OAuthApp::new("cid")
+            .client_secret("secret")
+            .token_url(server.uri());
+        let token = app.client_credentials(&["read", "write"]).await.unwrap();
+        assert_eq!(token.access_token, "app-token");
+    }
+
+    #[tokio::test]
+    async fn token_endpoint_error_maps_to_authentication() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+                "error": "invalid_grant",
+                "error_description": "refresh token revoked"
+            })))
+            .mount(&server)
+            .await;
+
+        let app = OAuthApp::new("cid").token_url(server.uri());
+        let err = app.refresh("stale").await.unwrap_err();
+        match err {
+            LinearError::Authentication(msg) => {
+                assert_eq!(msg, "invalid_grant: refresh token revoked")
+            }
+            other => panic!("Expected Authentication, got {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,818 @@
+//! Request batching.
+//!
+//! A [`Batch`] collects several [`Operation`]s and sends them as a single
+//! GraphQL document, aliasing each root field (`b0: issue(...)`,
+//! `b1: team(...)`, …) and renaming variables so they don't collide. Results
+//! come back individually: one entry failing doesn't fail the others.
+//!
+//! ```no_run
+//! # async fn example() -> Result<(), lineark_sdk::LinearError> {
+//! use lineark_sdk::generated::operations;
+//! use lineark_sdk::generated::types::{Issue, Team, WorkflowState};
+//! use lineark_sdk::Client;
+//!
+//! let client = Client::from_env()?;
+//! let mut batch = client.batch();
+//! let team = batch.add(operations::team::<Team>("team-id"));
+//! let states = batch.add(client.workflow_states::<WorkflowState>().first(50).into_operation());
+//! let issue = batch.add(operations::issue::<Issue>("ENG-123"));
+//!
+//! let mut results = batch.send().await?;
+//! let team = results.take(team)?;
+//! let states = results.take(states)?.nodes;
+//! let issue = results.take(issue)?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Linear rejects documents whose estimated complexity is too high, so a batch
+//! is split into several requests when it exceeds
+//! [`max_operations`](Batch::max_operations) or
+//! [`max_complexity`](Batch::max_complexity). Queries and mutations never share
+//! a request, and operations are sent in the order they were added.
+
+use crate::client::{decode_mutation_payload, Client};
+use crate::error::{GraphQLError, LinearError};
+use crate::idempotency::assign_id;
+use crate::operation::{Operation, OperationKind};
+use serde::de::DeserializeOwned;
+use std::marker::PhantomData;
+
+/// Default cap on operations per request.
+const DEFAULT_MAX_OPERATIONS: usize = 25;
+
+/// Default cap on estimated complexity per request. Linear's limit is 10,000
+/// points per query; the estimate is rough, so stay below it.
+const DEFAULT_MAX_COMPLEXITY: u32 = 5_000;
+
+/// Page size Linear assumes for connections without `first`/`last`.
+const DEFAULT_PAGE_SIZE: u32 = 50;
+
+/// A set of operations to send together. Create one with
+/// [`Client::batch`](crate::Client::batch).
+#[derive(Debug)]
+#[must_use]
+pub struct Batch<'a> {
+    client: &'a Client,
+    entries: Vec<Entry>,
+    max_operations: usize,
+    max_complexity: u32,
+}
+
+/// A type-erased [`Operation`].
+#[derive(Debug)]
+struct Entry {
+    kind: OperationKind,
+    name: String,
+    variable_defs: String,
+    selection: String,
+    variables: serde_json::Map<String, serde_json::Value>,
+    data_path: String,
+    entity_field: Option<String>,
+    with_meta: bool,
+    /// Why the operation can't be sent; fails the whole batch.
+    invalid: Option<String>,
+}
+
+/// Handle for retrieving one operation's result from [`BatchResults`].
+#[derive(Debug)]
+#[must_use]
+pub struct BatchKey<T> {
+    index: usize,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<'a> Batch<'a> {
+    pub(crate) fn new(client: &'a Client) -> Self {
+        Self {
+            client,
+            entries: Vec::new(),
+            max_operations: DEFAULT_MAX_OPERATIONS,
+            max_complexity: DEFAULT_MAX_COMPLEXITY,
+        }
+    }
+
+    /// Maximum number of operations per request (default 25, minimum 1).
+    pub fn max_operations(mut self, max: usize) -> Self {
+        self.max_operations = max.max(1);
+        self
+    }
+
+    /// Maximum estimated complexity per request (default 5,000). An operation
+    /// that exceeds the limit on its own is still sent, alone.
+    pub fn max_complexity(mut self, max: u32) -> Self {
+        self.max_complexity = max;
+        self
+    }
+
+    /// Add an operation; use the returned key to read its result.
+    ///
+    /// Create mutations get a client-generated ID when the client has
+    /// [`idempotent_creates`](crate::ClientBuilder::idempotent_creates) on, but
+    /// are not looked up or retried when they fail.
+    pub fn add<T: DeserializeOwned>(&mut self, mut operation: Operation<T>) -> BatchKey<T> {
+        if let Some(client_id) = &operation.client_id {
+            if self.client.idempotent_creates() {
+                assign_id(&mut operation.variables, &client_id.input_variable);
+            }
+        }
+        let invalid = operation
+            .invalid
+            .map(|reason| format!("Invalid operation {}: {}", operation.name, reason));
+        let index = self.entries.len();
+        self.entries.push(Entry {
+            kind: operation.kind,
+            name: operation.name,
+            variable_defs: operation.variable_defs,
+            selection: operation.selection,
+            variables: operation.variables,
+            data_path: operation.data_path,
+            entity_field: operation.entity_field,
+            with_meta: operation.with_meta,
+            invalid,
+        });
+        BatchKey {
+            index,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Number of operations added so far.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether no operations have been added.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Send all operations, splitting them into as many requests as needed.
+    ///
+    /// GraphQL errors are reported per operation through
+    /// [`BatchResults::take`], including those Linear answers with a 4xx
+    /// status. Transport-level failures (network, HTTP status, authentication)
+    /// abort the whole batch; requests sent before the failure
+    /// are not rolled back. An operation that can't be sent at all, like one
+    /// declaring a variable twice, fails the batch before anything is sent.
+    pub async fn send(self) -> Result<BatchResults, LinearError> {
+        if let Some(reason) = self.entries.iter().find_map(|e| e.invalid.as_ref()) {
+            return Err(LinearError::Internal(reason.clone()));
+        }
+        let mut slots: Vec<Option<Result<serde_json::Value, LinearError>>> =
+            Vec::with_capacity(self.entries.len());
+        for chunk in self.chunks() {
+            let (document, variables) = self.document(&chunk);
+            let response = self
+                .client
+                .send_graphql(&document, variables, chunk.kind == OperationKind::Mutation)
+                .await?;
+            let results = split_response(&chunk.indices, response, &self.entries);
+            if chunk.kind == OperationKind::Mutation {
+                for value in results.iter().flatten().flatten() {
+                    self.client.observe_sync_id(value);
+                }
+            }
+            slots.extend(results);
+        }
+        Ok(BatchResults {
+            slots,
+            entries: self
+                .entries
+                .into_iter()
+                .map(|e| (e.kind, e.data_path, e.entity_field, e.with_meta))
+                .collect(),
+        })
+    }
+
+    /// Group entries into requests, preserving order.
+    fn chunks(&self) -> Vec<Chunk> {
+        let mut chunks: Vec<Chunk> = Vec::new();
+        for (index, entry) in self.entries.iter().enumerate() {
+            let cost = estimate_complexity(&entry.selection, &entry.variables);
+            let fits = chunks.last().is_some_and(|c| {
+                c.kind == entry.kind
+                    && c.indices.len() < self.max_operations
+                    && c.complexity.saturating_add(cost) <= self.max_complexity
+            });
+            match chunks.last_mut() {
+                Some(chunk) if fits => {
+                    chunk.indices.push(index);
+                    chunk.complexity += cost;
+                }
+                _ => chunks.push(Chunk {
+                    kind: entry.kind,
+                    indices: vec![index],
+                    complexity: cost,
+                }),
+            }
+        }
+        chunks
+    }
+
+    /// Build the combined document and variables for one request.
+    fn document(&self, chunk: &Chunk) -> (String, serde_json::Value) {
+        let mut defs = Vec::new();
+        let mut fields = Vec::new();
+        let mut variables = serde_json::Map::new();
+        for &index in &chunk.indices {
+            let entry = &self.entries[index];
+            let prefix = alias(index);
+            if !entry.variable_defs.is_empty() {
+                defs.push(prefix_variables(&entry.variable_defs, &prefix));
+            }
+            fields.push(format!(
+                "{}: {}",
+                prefix,
+                prefix_variables(&entry.selection, &prefix)
+            ));
+            for (name, value) in &entry.variables {
+                variables.insert(format!("{}_{}", prefix, name), value.clone());
+            }
+        }
+        let keyword = chunk.kind.keyword();
+        let document = if defs.is_empty() {
+            format!("{} Batch {{ {} }}", keyword, fields.join(" "))
+        } else {
+            format!(
+                "{} Batch({}) {{ {} }}",
+                keyword,
+                defs.join(", "),
+                fields.join(" ")
+            )
+        };
+        (document, serde_json::Value::Object(variables))
+    }
+}
+
+impl Client {
+    /// Start a [`Batch`] of operations to send together.
+    pub fn batch(&self) -> Batch<'_> {
+        Batch::new(self)
+    }
+}
+
+struct Chunk {
+    kind: OperationKind,
+    indices: Vec<usize>,
+    complexity: u32,
+}
+
+/// Results of a sent [`Batch`].
+#[derive(Debug)]
+pub struct BatchResults {
+    slots: Vec<Option<Result<serde_json::Value, LinearError>>>,
+    /// `(kind, data_path, entity_field, with_meta)` per entry, for decoding.
+    entries: Vec<(OperationKind, String, Option<String>, bool)>,
+}
+
+impl BatchResults {
+    /// Take the typed result of the operation identified by `key`.
+    pub fn take<T: DeserializeOwned>(&mut self, key: BatchKey<T>) -> Result<T, LinearError> {
+        let value = self
+            .slots
+            .get_mut(key.index)
+            .and_then(Option::take)
+            .ok_or_else(|| {
+                LinearError::Internal(format!("Batch result {} is not available", key.index))
+            })??;
+        let (kind, data_path, entity_field, with_meta) = &self.entries[key.index];
+        if *kind == OperationKind::Mutation {
+            decode_mutation_payload(value, data_path, entity_field.as_deref(), *with_meta)
+        } else {
+            serde_json::from_value(value).map_err(|e| {
+                LinearError::MissingData(format!("Failed to deserialize '{}': {}", data_path, e))
+            })
+        }
+    }
+}
+
+/// The alias for entry `index`: `b0`, `b1`, ….
+fn alias(index: usize) -> String {
+    format!("b{}", index)
+}
+
+/// Rename every `$variable` in `text` to `$<prefix>_variable`, leaving string
+/// literals untouched.
+fn prefix_variables(text: &str, prefix: &str) -> String {
+    let mut out = String::with_capacity(text.len() + 16);
+    let mut in_string = false;
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        out.push(c);
+        if in_string {
+            match c {
+                '\\' => out.extend(chars.next()),
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+        match c {
+            '"' => in_string = true,
+            '$' => {
+                out.push_str(prefix);
+                out.push('_');
+            }
+            _ => {}
+        }
+    }
+    out
+}
+
+/// Distribute one response over the entries of its chunk. Errors whose path
+/// starts with an entry's alias belong to that entry; errors without a path
+/// belong to every entry of the chunk.
+fn split_response(
+    indices: &[usize],
+    response: crate::client::GraphQLResponse,
+    entries: &[Entry],
+) -> Vec<Option<Result<serde_json::Value, LinearError>>> {
+    let errors = response.errors.unwrap_or_default();
+    let mut data = match response.data {
+        Some(serde_json::Value::Object(map)) => map,
+        _ => serde_json::Map::new(),
+    };
+
+    indices
+        .iter()
+        .map(|&index| {
+            let alias = alias(index);
+            let entry_errors: Vec<GraphQLError> = errors
+                .iter()
+                .filter(|e| match e.path.as_deref().and_then(<[_]>::first) {
+                    Some(serde_json::Value::String(root)) => *root == alias,
+                    _ => true,
+                })
+                .cloned()
+                .collect();
+            let result = if !entry_errors.is_empty() {
+                Err(LinearError::from_graphql(
+                    entry_errors,
+                    Some(entries[index].name.clone()),
+                ))
+            } else {
+                data.remove(&alias).ok_or_else(|| {
+                    LinearError::MissingData(format!(
+                        "No '{}' in response data",
+                        entries[index].data_path
+                    ))
+                })
+            };
+            Some(result)
+        })
+        .collect()
+}
+
+/// Rough estimate of Linear's complexity score for a root selection: one point
+/// per object, a tenth of a point per scalar field, multiplied by the page
+/// size for connections (`first`/`last`, or 50 when unset).
+fn estimate_complexity(
+    selection: &str,
+    variables: &serde_json::Map<String, serde_json::Value>,
+) -> u32 {
+    let mut objects = 0u32;
+    let mut scalars = 0u32;
+    let mut depth_in_args = 0u32;
+    let mut in_identifier = false;
+    let mut pending_identifier = false;
+
+    for c in selection.chars() {
+        if depth_in_args > 0 {
+            match c {
+                '(' => depth_in_args += 1,
+                ')' => depth_in_args -= 1,
+                _ => {}
+            }
+            continue;
+        }
+        match c {
+            c if c.is_alphanumeric() || c == '_' => {
+                if !in_identifier && pending_identifier {
+                    scalars += 1;
+                }
+                in_identifier = true;
+                pending_identifier = true;
+            }
+            '{' => {
+                if pending_identifier {
+                    objects += 1;
+                }
+                in_identifier = false;
+                pending_identifier = false;
+            }
+            '(' => {
+                depth_in_args = 1;
+                in_identifier = false;
+            }
+            '}' => {
+                if pending_identifier {
+                    scalars += 1;
+                }
+                in_identifier = false;
+                pending_identifier = false;
+            }
+            _ => in_identifier = false,
+        }
+    }
+    if pending_identifier {
+        scalars += 1;
+    }
+
+    let is_connection = selection.contains("nodes {");
+    let page_size = if is_connection {
+        ["first", "last"]
+            .iter()
+            .find_map(|k| variables.get(*k).and_then(|v| v.as_u64()))
+            .map(|n| n.min(u64::from(u32::MAX)) as u32)
+            .unwrap_or(DEFAULT_PAGE_SIZE)
+    } else {
+        1
+    };
+
+    (objects * 10 + scalars)
+        .div_ceil(10)
+        .saturating_mul(page_size)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{body_string_contains, method};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn team_op(id: &str) -> Operation<serde_json::Value> {
+        Operation::query(
+            "Team",
+            "$id: String!",
+            "team(id: $id) { id name }",
+            serde_json::json!({ "id": id }),
+            "team",
+        )
+    }
+
+    fn viewer_op() -> Operation<serde_json::Value> {
+        Operation::query(
+            "Viewer",
+            "",
+            "viewer { id }",
+            serde_json::json!({}),
+            "viewer",
+        )
+    }
+
+    fn delete_op(id: &str) -> Operation<serde_json::Value> {
+        Operation::mutation(
+            "TeamDelete",
+            "$id: String!",
+            "teamDelete(id: $id) { success }",
+            serde_json::json!({ "id": id }),
+            "teamDelete",
+        )
+    }
+
+    fn client_for(server: &MockServer) -> Client {
+        let mut client = Client::from_token("token").unwrap();
+        client.set_base_url(server.uri());
+        client
+    }
+
+    async fn request_bodies(server: &MockServer) -> Vec<serde_json::Value> {
+        server
+            .received_requests()
+            .await
+            .unwrap()
+            .iter()
+            .map(|r| serde_json::from_slice(&r.body).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn prefix_variables_renames_every_reference() {
+        assert_eq!(
+            prefix_variables("team(id: $id, first: $first) { id }", "b3"),
+            "team(id: $b3_id, first: $b3_first) { id }"
+        );
+        assert_eq!(prefix_variables("viewer { id }", "b0"), "viewer { id }");
+    }
+
+    #[test]
+    fn prefix_variables_skips_string_literals() {
+        assert_eq!(
+            prefix_variables(
+                r#"issues(filter: { title: { eq: "$5 \"$x\"" } }, first: $n) { id }"#,
+                "b1"
+            ),
+            r#"issues(filter: { title: { eq: "$5 \"$x\"" } }, first: $b1_n) { id }"#
+        );
+    }
+
+    #[test]
+    fn document_aliases_fields_and_variables() {
+        let client = Client::from_token("token").unwrap();
+        let mut batch = client.batch();
+        let _ = batch.add(team_op("t1"));
+        let _ = batch.add(viewer_op());
+        let _ = batch.add(team_op("t2"));
+        let chunks = batch.chunks();
+        assert_eq!(chunks.len(), 1);
+
+        let (document, variables) = batch.document(&chunks[0]);
+        assert_eq!(
+            document,
+            "query Batch($b0_id: String!, $b2_id: String!) { \
+             b0: team(id: $b0_id) { id name } \
+             b1: viewer { id } \
+             b2: team(id: $b2_id) { id name } }"
+        );
+        assert_eq!(variables, serde_json::json!({"b0_id": "t1", "b2_id": "t2"}));
+    }
+
+    #[test]
+    fn queries_and_mutations_are_split() {
+        let client = Client::from_token("token").unwrap();
+        let mut batch = client.batch();
+        let _ = batch.add(team_op("t1"));
+        let _ = batch.add(delete_op("t1"));
+        let _ = batch.add(delete_op("t2"));
+        let _ = batch.add(viewer_op());
+        let kinds: Vec<(OperationKind, usize)> = batch
+            .chunks()
+            .iter()
+            .map(|c| (c.kind, c.indices.len()))
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                (OperationKind::Query, 1),
+                (OperationKind::Mutation, 2),
+                (OperationKind::Query, 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn max_operations_splits_batches() {
+        let client = Client::from_token("token").unwrap();
+        let mut batch = client.batch().max_operations(2);
+        for i in 0..5 {
+            let _ = batch.add(team_op(&i.to_string()));
+        }
+        let sizes: Vec<usize> = batch.chunks().iter().map(|c| c.indices.len()).collect();
+        assert_eq!(sizes, vec![2, 2, 1]);
+    }
+
+    #[test]
+    fn max_complexity_splits_batches() {
+        let client = Client::from_token("token").unwrap();
+        let connection = || {
+            Operation::<serde_json::Value>::query(
+                "Teams",
+                "$first: Int",
+                "teams(first: $first) { nodes { id name key } pageInfo { hasNextPage endCursor } }",
+                serde_json::json!({ "first": 100 }),
+                "teams",
+            )
+        };
+        let cost = estimate_complexity(&connection().selection, &connection().variables);
+        let mut batch = client.batch().max_complexity(cost * 2);
+        for _ in 0..5 {
+            let _ = batch.add(connection());
+        }
+        let sizes: Vec<usize> = batch.chunks().iter().map(|c| c.indices.len()).collect();
+        assert_eq!(sizes, vec![2, 2, 1]);
+    }
+
+    #[test]
+    fn complexity_scales_with_page_size() {
+        let sel = "teams(first: $first) { nodes { id } pageInfo { hasNextPage } }";
+        let small = estimate_complexity(sel, &serde_json::Map::new());
+        let mut vars = serde_json::Map::new();
+        vars.insert("first".into(), serde_json::json!(250));
+        let large = estimate_complexity(sel, &vars);
+        assert_eq!(large, small / DEFAULT_PAGE_SIZE * 250);
+        assert_eq!(estimate_complexity("team(id: $id) { id name }", &vars), 2);
+    }
+
+    #[tokio::test]
+    async fn send_returns_each_result() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": {
+                    "b0": {"id": "t1", "name": "One"},
+                    "b1": {"id": "me"}
+                }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let client = client_for(&server);
+
+        let mut batch = client.batch();
+        let team = batch.add(team_op("t1"));
+        let viewer = batch.add(viewer_op());
+        let mut results = batch.send().await.unwrap();
+
+        assert_eq!(results.take(team).unwrap()["name"], "One");
+        assert_eq!(results.take(viewer).unwrap()["id"], "me");
+    }
+
+    #[tokio::test]
+    async fn errors_are_attributed_by_path() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": {"b0": {"id": "t1", "name": "One"}, "b1": null},
+                "errors": [{"message": "Entity not found", "path": ["b1"]}]
+            })))
+            .mount(&server)
+            .await;
+        let client = client_for(&server);
+
+        let mut batch = client.batch();
+        let found = batch.add(team_op("t1"));
+        let missing = batch.add(team_op("nope"));
+        let mut results = batch.send().await.unwrap();
+
+        assert!(results.take(found).is_ok());
+        match results.take(missing) {
+            Err(LinearError::GraphQL { errors, query_name }) => {
+                assert_eq!(errors[0].message, "Entity not found");
+                assert_eq!(query_name.as_deref(), Some("Team"));
+            }
+            other => panic!("Expected GraphQL error, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn errors_without_path_apply_to_all_entries() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "errors": [{"message": "Query too complex"}]
+            })))
+            .mount(&server)
+            .await;
+        let client = client_for(&server);
+
+        let mut batch = client.batch();
+        let a = batch.add(team_op("t1"));
+        let b = batch.add(viewer_op());
+        let mut results = batch.send().await.unwrap();
+
+        assert!(matches!(results.take(a), Err(LinearError::GraphQL { .. })));
+        assert!(matches!(results.take(b), Err(LinearError::GraphQL { .. })));
+    }
+
+    #[tokio::test]
+    async fn mutation_entity_is_extracted() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": {
+                    "b0": {"success": true, "team": {"id": "new"}},
+                    "b1": {"success": false, "team": null}
+                }
+            })))
+            .mount(&server)
+            .await;
+        let client = client_for(&server);
+
+        let create = || {
+            Operation::<serde_json::Value>::mutation(
+                "TeamCreate",
+                "$input: TeamCreateInput!",
+                "teamCreate(input: $input) { success team { id } }",
+                serde_json::json!({"input": {"name": "x"}}),
+                "teamCreate",
+            )
+            .with_entity_field("team")
+        };
+        let mut batch = client.batch();
+        let ok = batch.add(create());
+        let failed = batch.add(create());
+        let mut results = batch.send().await.unwrap();
+
+        let bodies = request_bodies(&server).await;
+        assert!(bodies[0]["query"]
+            .as_str()
+            .unwrap()
+            .starts_with("mutation Batch("));
+        assert_eq!(results.take(ok).unwrap()["id"], "new");
+        assert!(matches!(
+            results.take(failed),
+            Err(LinearError::Internal(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn with_meta_keeps_payload_metadata() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": {
+                    "b0": {"success": true, "lastSyncId": 12.0, "team": {"id": "new"}}
+                }
+            })))
+            .mount(&server)
+            .await;
+        let client = client_for(&server);
+
+        let mut batch = client.batch();
+        let key = batch.add(
+            Operation::<serde_json::Value>::mutation(
+                "TeamCreate",
+                "$input: TeamCreateInput!",
+                "teamCreate(input: $input) { success lastSyncId team { id } }",
+                serde_json::json!({"input": {"name": "x"}}),
+                "teamCreate",
+            )
+            .with_entity_field("team")
+            .with_meta(),
+        );
+        let mut results = batch.send().await.unwrap();
+
+        let result = results.take(key).unwrap();
+        assert!(result.success);
+        assert_eq!(result.last_sync_id, Some(12.0));
+        assert_eq!(result.entity["id"], "new");
+    }
+
+    #[tokio::test]
+    async fn oversized_batches_use_several_requests() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": {
+                    "b0": {"id": "0"}, "b1": {"id": "1"}, "b2": {"id": "2"}
+                }
+            })))
+            .mount(&server)
+            .await;
+        let client = client_for(&server);
+
+        let mut batch = client.batch().max_operations(2);
+        let keys: Vec<_> = (0..3).map(|i| batch.add(team_op(&i.to_string()))).collect();
+        let mut results = batch.send().await.unwrap();
+
+        assert_eq!(request_bodies(&server).await.len(), 2);
+        for (i, key) in keys.into_iter().enumerate() {
+            assert_eq!(results.take(key).unwrap()["id"], i.to_string());
+        }
+    }
+
+    #[tokio::test]
+    async fn rejected_chunks_do_not_discard_sent_ones() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(body_string_contains("b0: teamDelete"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": {"b0": {"success": true, "lastSyncId": 7.0}}
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(body_string_contains("b1: teamDelete"))
+            .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+                "errors": [{
+                    "message": "Argument Validation Error",
+                    "path": ["b1"],
+                    "extensions": {"code": "INVALID_INPUT"}
+                }]
+            })))
+            .mount(&server)
+            .await;
+        let client = client_for(&server);
+
+        let mut batch = client.batch().max_operations(1);
+        let sent = batch.add(delete_op("t1"));
+        let rejected = batch.add(delete_op("t2"));
+        let mut results = batch.send().await.unwrap();
+
+        assert_eq!(request_bodies(&server).await.len(), 2);
+        assert_eq!(results.take(sent).unwrap()["success"], true);
+        assert!(matches!(
+            results.take(rejected),
+            Err(LinearError::InvalidInput { .. })
+        ));
+        assert_eq!(client.last_sync_id(), Some(7.0));
+    }
+
+    #[tokio::test]
+    async fn transport_errors_fail_the_batch() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(401).set_body_string("Unauthorized"))
+            .mount(&server)
+            .await;
+        let client = client_for(&server);
+
+        let mut batch = client.batch();
+        let _ = batch.add(viewer_op());
+        assert!(matches!(
+            batch.send().await,
+            Err(LinearError::Authentication(_))
+        ));
+    }
+}
@@ -0,0 +1,266 @@
+//! Synchronous Linear API client.
+//!
+//! Enabled with the `blocking` cargo feature. [`Client`] exposes the same
+//! generated query and mutation methods as the async [`crate::Client`], but
+//! each call blocks the current thread until the response arrives — no async
+//! runtime is needed in the calling code.
+//!
+//! ```no_run
+//! # fn example() -> Result<(), lineark_sdk::LinearError> {
+//! use lineark_sdk::blocking::Client;
+//! use lineark_sdk::generated::types::{Team, User};
+//!
+//! let client = Client::from_env()?;
+//! let me: User = client.whoami()?;
+//! let teams = client.teams::<Team>().first(10).send()?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Internally the client owns a single-threaded tokio runtime and drives
+//! the async client on it. Like `reqwest::blocking`, it must not be used from
+//! within an async context; calling it from inside a tokio runtime panics.
+
+use crate::batch::{Batch, BatchResults};
+use crate::error::LinearError;
+use crate::field_selection::GraphQLFields;
+use crate::helpers::DownloadResult;
+use crate::ids::IssueIdentifier;
+use crate::pagination::Connection;
+use crate::retry::RetryPolicy;
+use serde::de::DeserializeOwned;
+use std::future::Future;
+use std::path::Path;
+use std::sync::Arc;
+
+pub use crate::generated::blocking_client_impl::*;
+
+/// The blocking Linear API client.
+///
+/// Cheap to clone; clones share the underlying connection pool and runtime.
+#[derive(Debug, Clone)]
+pub struct Client {
+    inner: crate::Client,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl Client {
+    /// Create a client with an explicit API token.
+    pub fn from_token(token: impl Into<String>) -> Result<Self, LinearError> {
+        Self::from_async(crate::Client::from_token(token)?)
+    }
+
+    /// Create a client from the `LINEAR_API_TOKEN` environment variable.
+    pub fn from_env() -> Result<Self, LinearError> {
+        Self::from_async(crate::Client::from_env()?)
+    }
+
+    /// Create a client from a token file at the given path.
+    pub fn from_token_file(path: &Path) -> Result<Self, LinearError> {
+        Self::from_async(crate::Client::from_token_file(path)?)
+    }
+
+    /// Wrap an async client, e.g. one configured through
+    /// [`Client::builder`](crate::Client::builder).
+    pub fn from_async(client: crate::Client) -> Result<Self, LinearError> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(|e| LinearError::Internal(format!("Failed to create tokio runtime: {}", e)))?;
+        Ok(Self {
+            inner: client,
+            runtime: Arc::new(runtime),
+        })
+    }
+
+    /// Set the [`RetryPolicy`] used for every request made by this client.
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.inner = self.inner.with_retry_policy(policy);
+        self
+    }
+
+    /// The async client this client drives.
+    pub fn as_async(&self) -> &crate::Client {
+        &self.inner
+    }
+
+    /// Execute a typed query using the type's [`GraphQLFields`] implementation.
+    /// See [`crate::Client::query`].
+    pub fn query<T: DeserializeOwned + GraphQLFields>(
+        &self,
+        field: &str,
+    ) -> Result<T, LinearError> {
+        self.block_on(self.inner.query::<T>(field))
+    }
+
+    /// Execute a typed connection query. See [`crate::Client::query_connection`].
+    pub fn query_connection<T: DeserializeOwned + GraphQLFields>(
+        &self,
+        field: &str,
+    ) -> Result<Connection<T>, LinearError> {
+        self.block_on(self.inner.query_connection::<T>(field))
+    }
+
+    /// Execute a raw GraphQL query. See [`crate::Client::execute`].
+    pub fn execute<T: DeserializeOwned>(
+        &self,
+        query: &str,
+        variables: serde_json::Value,
+        data_path: &str,
+    ) -> Result<T, LinearError> {
+        self.block_on(self.inner.execute::<T>(query, variables, data_path))
+    }
+
+    /// Start a batch of operations. Send it with [`Client::send_batch`].
+    pub fn batch(&self) -> Batch<'_> {
+        self.inner.batch()
+    }
+
+    /// Send a batch created with [`Client::batch`]. See [`Batch::send`].
+    pub fn send_batch(&self, batch: Batch<'_>) -> Result<BatchResults, LinearError> {
+        self.block_on(batch.send())
+    }
+
+    /// Download a file. See [`crate::Client::download_url`].
+    pub fn download_url(&self, url: &str) -> Result<DownloadResult, LinearError> {
+        self.block_on(self.inner.download_url(url))
+    }
+
+    /// Fetch an issue by identifier. See [`crate::Client::issue_by_identifier`].
+    pub fn issue_by_identifier<
+        T: DeserializeOwned + GraphQLFields<FullType = crate::generated::types::Issue>,
+    >(
+        &self,
+        identifier: &IssueIdentifier,
+    ) -> Result<Option<T>, LinearError> {
+        self.block_on(self.inner.issue_by_identifier(identifier))
+    }
+
+    /// Drive `future` to completion on this client's runtime.
+    pub(crate) fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generated::types::Team;
+    use wiremock::matchers::method;
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    /// Start a mock server on a throwaway runtime — the blocking client must
+    /// not be called from inside one.
+    fn mock_server(body: serde_json::Value) -> (tokio::runtime::Runtime, MockServer) {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        let server = rt.block_on(async {
+            let server = MockServer::start().await;
+            Mock::given(method("POST"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(body))
+                .mount(&server)
+                .await;
+            server
+        });
+        (rt, server)
+    }
+
+    fn client_for(server: &MockServer) -> Client {
+        let mut inner = crate::Client::from_token("token").unwrap();
+        inner.set_base_url(server.uri());
+        Client::from_async(inner).unwrap()
+    }
+
+    #[test]
+    fn from_token_empty_fails() {
+        assert!(matches!(
+            Client::from_token(""),
+            Err(LinearError::AuthConfig(_))
+        ));
+    }
+
+    #[test]
+    fn builder_query_sends_blocking() {
+        let (_rt, server) = mock_server(serde_json::json!({
+            "data": {"teams": {
+                "nodes": [{"id": "t1"}],
+                "pageInfo": {"hasNextPage": false, "endCursor": null}
+            }}
+        }));
+        let client = client_for(&server);
+
+        let conn = client.teams::<Team>().first(5).send().unwrap();
+        assert_eq!(conn.nodes.len(), 1);
+        assert_eq!(conn.nodes[0].id.as_deref(), Some("t1"));
+    }
+
+    #[test]
+    fn builder_query_all_follows_pages() {
+        let (_rt, server) = mock_server(serde_json::json!({
+            "data": {"teams": {
+                "nodes": [{"id": "t1"}, {"id": "t2"}],
+                "pageInfo": {"hasNextPage": false, "endCursor": "c"}
+            }}
+        }));
+        let client = client_for(&server);
+
+        let teams = client.teams::<Team>().all().unwrap();
+        assert_eq!(teams.len(), 2);
+    }
+
+    #[test]
+    fn direct_query_returns_plain_result() {
+        let (_rt, server) = mock_server(serde_json::json!({
+            "data": {"team": {"id": "t1", "key": "ENG"}}
+        }));
+        let client = client_for(&server);
+
+        let team: Team = client.team("t1".to_string()).unwrap();
+        assert_eq!(team.key.as_deref(), Some("ENG"));
+    }
+
+    #[test]
+    fn mutation_returns_plain_result() {
+        let (_rt, server) = mock_server(serde_json::json!({
+            "data": {"teamDelete": {"success": true}}
+        }));
+        let client = client_for(&server);
+
+        let result = client.team_delete("t1".to_string()).unwrap();
+        assert_eq!(result.success, Some(true));
+    }
+
+    #[test]
+    fn batch_sends_blocking() {
+        let (_rt, server) = mock_server(serde_json::json!({
+            "data": {"b0": {"id": "t1"}, "b1": {"id": "t2"}}
+        }));
+        let client = client_for(&server);
+
+        let mut batch = client.batch();
+        let first = batch.add(crate::generated::operations::team::<Team>("t1"));
+        let second = batch.add(crate::generated::operations::team::<Team>("t2"));
+        let mut results = client.send_batch(batch).unwrap();
+        assert_eq!(results.take(first).unwrap().id.as_deref(), Some("t1"));
+        assert_eq!(results.take(second).unwrap().id.as_deref(), Some("t2"));
+    }
+
+    #[test]
+    fn errors_are_returned_not_panicked() {
+        let (_rt, server) = mock_server(serde_json::json!({
+            "errors": [{"message": "boom"}]
+        }));
+        let client = client_for(&server);
+
+        assert!(matches!(
+            client.teams::<Team>().send(),
+            Err(LinearError::GraphQL { .. })
+        ));
+    }
+
+    #[test]
+    fn clones_share_runtime() {
+        let client = Client::from_token("token").unwrap();
+        let clone = client.clone();
+        assert!(Arc::ptr_eq(&client.runtime, &clone.runtime));
+    }
+}
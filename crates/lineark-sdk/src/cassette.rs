@@ -0,0 +1,653 @@
+//! Record/replay of HTTP interactions.
+//!
+//! A [`Cassette`] is a [`Middleware`] that either records every response the
+//! client receives into a JSON Lines file, or replays responses from that file
+//! without touching the network. Tests recorded once against a real workspace
+//! can then run offline, deterministically, and without a token.
+//!
+//! ```no_run
+//! # fn example() -> Result<(), lineark_sdk::LinearError> {
+//! use lineark_sdk::cassette::Cassette;
+//! use lineark_sdk::Client;
+//!
+//! // Replays `tests/cassettes/teams.jsonl` if it exists, records it otherwise.
+//! let cassette = Cassette::auto("tests/cassettes/teams.jsonl")?;
+//! let client = Client::from_env()?.with_middleware(cassette);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Interactions are matched by operation name and variables. When no recorded
+//! interaction has exactly the same variables — typically because the test
+//! generates unique names — the next unused interaction for the same
+//! operation is replayed, in recording order. Record and replay with the same
+//! request order (e.g. `--test-threads=1`) for suites that rely on this.
+//!
+//! Unique names a test compares against responses must be the same when
+//! replaying as when recording. Make them with [`Cassette::generated`], which
+//! stores each value while recording and hands them back in the same order.
+//!
+//! Each interaction is appended to the file as one line as soon as it is
+//! recorded, so a test that panics later still leaves a usable cassette, and
+//! processes recording into the same cassette (e.g. a test and the CLI
+//! binaries it spawns) add to it rather than overwrite each other.
+//!
+//! Subscriptions are not recorded: their handshakes go to the network when
+//! recording and fail when replaying.
+//!
+//! Only the operation name, variables and response are stored; request
+//! headers, including `Authorization`, never are. Response bodies are stored
+//! verbatim, so review cassettes before committing them.
+
+use crate::error::LinearError;
+use crate::middleware::{BoxFuture, Middleware, Next, Request, RequestKind, Response};
+use base64::Engine;
+use reqwest::header::{HeaderMap, HeaderValue, CONTENT_TYPE};
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+/// Environment variable naming the cassette file for [`Cassette::from_env`].
+pub const CASSETTE_ENV: &str = "LINEARK_CASSETTE";
+
+/// Environment variable selecting the [`CassetteMode`] for
+/// [`Cassette::from_env`]: `record`, `replay` or `auto` (the default).
+pub const CASSETTE_MODE_ENV: &str = "LINEARK_CASSETTE_MODE";
+
+const FORMAT_VERSION: u32 = 3;
+
+/// Oldest cassette format still read. Version 3 added generated values.
+const MIN_FORMAT_VERSION: u32 = 2;
+
+/// Whether a [`Cassette`] talks to the network.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CassetteMode {
+    /// Send requests and append the responses to the cassette file.
+    Record,
+    /// Answer requests from the cassette file; never send them.
+    Replay,
+}
+
+/// One recorded request/response pair.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Interaction {
+    operation: String,
+    #[serde(default, skip_serializing_if = "serde_json::Value::is_null")]
+    variables: serde_json::Value,
+    status: u16,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    content_type: Option<String>,
+    /// The response body, when it is JSON.
+    #[serde(default, skip_serializing_if = "serde_json::Value::is_null")]
+    body: serde_json::Value,
+    /// The response body, when it is not JSON (e.g. file downloads).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    body_base64: Option<String>,
+}
+
+/// A value made by [`Cassette::generated`].
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Generated {
+    generated: String,
+}
+
+/// The first line of a cassette file.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Header {
+    version: u32,
+}
+
+/// A line of a cassette file. The header normally comes first, but another
+/// process may append its first interaction before this one writes it.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Line {
+    Header(Header),
+    Generated(Generated),
+    Interaction(Interaction),
+}
+
+/// What a cassette file holds.
+#[derive(Debug, Default)]
+struct Contents {
+    interactions: Vec<Interaction>,
+    generated: Vec<String>,
+}
+
+#[derive(Debug)]
+struct State {
+    interactions: Vec<Interaction>,
+    used: Vec<bool>,
+    generated: Vec<String>,
+    /// How many generated values have been handed out while replaying.
+    replayed: usize,
+}
+
+/// A recording or replaying [`Middleware`]. Clones share their state, so one
+/// cassette can be attached to several clients.
+#[derive(Debug, Clone)]
+pub struct Cassette {
+    path: PathBuf,
+    mode: CassetteMode,
+    state: Arc<Mutex<State>>,
+}
+
+impl Cassette {
+    /// Record into `path`, appending to any interactions already there.
+    pub fn record(path: impl Into<PathBuf>) -> Result<Self, LinearError> {
+        let path = path.into();
+        let contents = if path.exists() {
+            load(&path)?
+        } else {
+            Contents::default()
+        };
+        Ok(Self::new(path, CassetteMode::Record, contents))
+    }
+
+    /// Replay from `path`, which must exist.
+    pub fn replay(path: impl Into<PathBuf>) -> Result<Self, LinearError> {
+        let path = path.into();
+        let contents = load(&path)?;
+        Ok(Self::new(path, CassetteMode::Replay, contents))
+    }
+
+    /// Replay from `path` if it exists, otherwise record into it.
+    pub fn auto(path: impl Into<PathBuf>) -> Result<Self, LinearError> {
+        let path = path.into();
+        if path.exists() {
+            Self::replay(path)
+        } else {
+            Self::record(path)
+        }
+    }
+
+    /// A cassette configured by [`CASSETTE_ENV`] and [`CASSETTE_MODE_ENV`], or
+    /// `None` when [`CASSETTE_ENV`] is unset.
+    pub fn from_env() -> Result<Option<Self>, LinearError> {
+        let Some(path) = std::env::var_os(CASSETTE_ENV).filter(|p| !p.is_empty()) else {
+            return Ok(None);
+        };
+        let mode = std::env::var(CASSETTE_MODE_ENV).unwrap_or_default();
+        let cassette = match mode.trim().to_ascii_lowercase().as_str() {
+            "" | "auto" => Self::auto(path)?,
+            "record" => Self::record(path)?,
+            "replay" => Self::replay(path)?,
+            other => {
+                return Err(LinearError::Internal(format!(
+                    "Invalid {}: '{}' (expected record, replay or auto)",
+                    CASSETTE_MODE_ENV, other
+                )))
+            }
+        };
+        Ok(Some(cassette))
+    }
+
+    fn new(path: PathBuf, mode: CassetteMode, contents: Contents) -> Self {
+        let used = vec![false; contents.interactions.len()];
+        let state = State {
+            interactions: contents.interactions,
+            used,
+            generated: contents.generated,
+            replayed: 0,
+        };
+        Self {
+            path,
+            mode,
+            state: Arc::new(Mutex::new(state)),
+        }
+    }
+
+    /// Whether this cassette records or replays.
+    pub fn mode(&self) -> CassetteMode {
+        self.mode
+    }
+
+    /// The cassette file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Number of interactions in the cassette.
+    pub fn len(&self) -> usize {
+        self.lock().interactions.len()
+    }
+
+    /// Whether the cassette holds no interactions.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// A value the caller would otherwise make up, such as a unique name for
+    /// a test entity. Recording calls `generate` and stores the result;
+    /// replaying returns the stored values in the order they were recorded,
+    /// so responses that echo them still match.
+    pub fn generated(&self, generate: impl FnOnce() -> String) -> Result<String, LinearError> {
+        match self.mode {
+            CassetteMode::Record => {
+                let mut state = self.lock();
+                let value = generate();
+                self.append(&Generated {
+                    generated: value.clone(),
+                })?;
+                state.generated.push(value.clone());
+                Ok(value)
+            }
+            CassetteMode::Replay => {
+                let mut state = self.lock();
+                let value = state
+                    .generated
+                    .get(state.replayed)
+                    .cloned()
+                    .ok_or_else(|| {
+                        LinearError::Internal(format!(
+                            "No more generated values in cassette {}",
+                            self.path.display()
+                        ))
+                    })?;
+                state.replayed += 1;
+                Ok(value)
+            }
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Store a response and append it to the file.
+    fn store(&self, interaction: Interaction) -> Result<(), LinearError> {
+        let mut state = self.lock();
+        self.append(&interaction)?;
+        state.interactions.push(interaction);
+        state.used.push(true);
+        Ok(())
+    }
+
+    /// Append one line to the file, with the state locked. A new file starts
+    /// with a header line, written together with the first entry.
+    fn append(&self, entry: &impl Serialize) -> Result<(), LinearError> {
+        let mut line = serde_json::to_string(entry)
+            .map_err(|e| LinearError::Internal(format!("Failed to encode cassette: {}", e)))?;
+        line.push('\n');
+        if let Some(dir) = self.path.parent().filter(|d| !d.as_os_str().is_empty()) {
+            std::fs::create_dir_all(dir).map_err(|e| self.io_error(e))?;
+        }
+        let created = std::fs::OpenOptions::new()
+            .append(true)
+            .create_new(true)
+            .open(&self.path);
+        let (mut file, line) = match created {
+            Ok(file) => {
+                let header = serde_json::to_string(&Header {
+                    version: FORMAT_VERSION,
+                })
+                .map_err(|e| LinearError::Internal(format!("Failed to encode cassette: {}", e)))?;
+                (file, format!("{}\n{}", header, line))
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => {
+                let file = std::fs::OpenOptions::new()
+                    .append(true)
+                    .open(&self.path)
+                    .map_err(|e| self.io_error(e))?;
+                (file, line)
+            }
+            Err(e) => return Err(self.io_error(e)),
+        };
+        file.write_all(line.as_bytes())
+            .map_err(|e| self.io_error(e))
+    }
+
+    /// Find the interaction to replay for a request and mark it used.
+    fn take(&self, operation: &str, variables: &serde_json::Value) -> Option<Interaction> {
+        let mut state = self.lock();
+        let unused = |state: &State, exact: bool| {
+            state.interactions.iter().enumerate().position(|(i, rec)| {
+                !state.used[i]
+                    && rec.operation == operation
+                    && (!exact || rec.variables == *variables)
+            })
+        };
+        let index = unused(&state, true).or_else(|| unused(&state, false))?;
+        state.used[index] = true;
+        Some(state.interactions[index].clone())
+    }
+
+    fn io_error(&self, e: std::io::Error) -> LinearError {
+        LinearError::Internal(format!("Cassette {}: {}", self.path.display(), e))
+    }
+}
+
+fn load(path: &Path) -> Result<Contents, LinearError> {
+    let raw = std::fs::read_to_string(path)
+        .map_err(|e| LinearError::Internal(format!("Cassette {}: {}", path.display(), e)))?;
+    let mut contents = Contents::default();
+    for (number, line) in raw.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let line: Line = serde_json::from_str(line).map_err(|e| {
+            LinearError::Internal(format!(
+                "Invalid cassette {} line {}: {}",
+                path.display(),
+                number + 1,
+                e
+            ))
+        })?;
+        match line {
+            Line::Header(header)
+                if !(MIN_FORMAT_VERSION..=FORMAT_VERSION).contains(&header.version) =>
+            {
+                return Err(LinearError::Internal(format!(
+                    "Cassette {} has unsupported version {}",
+                    path.display(),
+                    header.version
+                )));
+            }
+            Line::Header(_) => {}
+            Line::Generated(generated) => contents.generated.push(generated.generated),
+            Line::Interaction(interaction) => contents.interactions.push(interaction),
+        }
+    }
+    Ok(contents)
+}
+
+/// The variables identifying a request: the GraphQL variables, or the URL
+/// without its (typically signed, expiring) query string for file requests.
+fn request_variables(request: &Request) -> serde_json::Value {
+    match request.kind {
+        RequestKind::Query | RequestKind::Mutation => {
+            serde_json::from_slice::<serde_json::Value>(&request.body)
+                .ok()
+                .and_then(|body| body.get("variables").cloned())
+                .unwrap_or_default()
+        }
+        RequestKind::Download | RequestKind::Upload => {
+            let url = request.url.split('?').next().unwrap_or_default();
+            serde_json::json!({ "url": url })
+        }
+        RequestKind::Subscribe => serde_json::Value::Null,
+    }
+}
+
+fn to_interaction(
+    operation: String,
+    variables: serde_json::Value,
+    response: &Response,
+) -> Interaction {
+    let content_type = response
+        .headers
+        .get(CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .map(|s| s.to_string());
+    let (body, body_base64) = match serde_json::from_slice::<serde_json::Value>(&response.body) {
+        Ok(json) if !response.body.is_empty() => (json, None),
+        _ if response.body.is_empty() => (serde_json::Value::Null, None),
+        _ => (
+            serde_json::Value::Null,
+            Some(base64::engine::general_purpose::STANDARD.encode(&response.body)),
+        ),
+    };
+    Interaction {
+        operation,
+        variables,
+        status: response.status.as_u16(),
+        content_type,
+        body,
+        body_base64,
+    }
+}
+
+fn to_response(interaction: Interaction) -> Result<Response, LinearError> {
+    let status = StatusCode::from_u16(interaction.status)
+        .map_err(|e| LinearError::Internal(format!("Invalid status in cassette: {}", e)))?;
+    let mut headers = HeaderMap::new();
+    if let Some(value) = interaction
+        .content_type
+        .as_deref()
+        .and_then(|ct| HeaderValue::from_str(ct).ok())
+    {
+        headers.insert(CONTENT_TYPE, value);
+    }
+    let body = match (&interaction.body_base64, &interaction.body) {
+        (Some(encoded), _) => base64::engine::general_purpose::STANDARD
+            .decode(encoded)
+            .map_err(|e| LinearError::Internal(format!("Invalid body in cassette: {}", e)))?,
+        (None, serde_json::Value::Null) => Vec::new(),
+        (None, json) => serde_json::to_vec(json)
+            .map_err(|e| LinearError::Internal(format!("Invalid body in cassette: {}", e)))?,
+    };
+    Ok(Response {
+        status,
+        headers,
+        body,
+    })
+}
+
+impl Middleware for Cassette {
+    fn handle<'a>(
+        &'a self,
+        request: Request,
+        next: Next<'a>,
+    ) -> BoxFuture<'a, Result<Response, LinearError>> {
+        Box::pin(async move {
+            // A live connection can't be recorded.
+            if request.kind == RequestKind::Subscribe {
+                return match self.mode {
+                    CassetteMode::Replay => Err(LinearError::Internal(format!(
+                        "Cassette {} cannot replay subscription {}",
+                        self.path.display(),
+                        request.operation
+                    ))),
+                    CassetteMode::Record => next.run(request).await,
+                };
+            }
+            let operation = request.operation.clone();
+            let variables = request_variables(&request);
+            match self.mode {
+                CassetteMode::Replay => {
+                    let interaction = self.take(&operation, &variables).ok_or_else(|| {
+                        LinearError::Internal(format!(
+                            "No recorded response for {} {} in cassette {}",
+                            operation,
+                            variables,
+                            self.path.display()
+                        ))
+                    })?;
+                    to_response(interaction)
+                }
+                CassetteMode::Record => {
+                    let response = next.run(request).await?;
+                    self.store(to_interaction(operation, variables, &response))?;
+                    Ok(response)
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Client;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn client(server_uri: &str, cassette: &Cassette) -> Client {
+        Client::builder()
+            .token("secret-token")
+            .base_url(server_uri)
+            .middleware(cassette.clone())
+            .build()
+            .unwrap()
+    }
+
+    async fn team(client: &Client, id: &str) -> Result<serde_json::Value, LinearError> {
+        client
+            .execute::<serde_json::Value>(
+                "query Team($id: String!) { team(id: $id) { id name } }",
+                serde_json::json!({ "id": id }),
+                "team",
+            )
+            .await
+    }
+
+    async fn record_two_teams(file: &Path) {
+        record_two_teams_with(&Cassette::record(file).unwrap()).await;
+    }
+
+    async fn record_two_teams_with(cassette: &Cassette) {
+        let server = MockServer::start().await;
+        for (id, name) in [("t1", "One"), ("t2", "Two")] {
+            Mock::given(method("POST"))
+                .and(wiremock::matchers::body_partial_json(
+                    serde_json::json!({"variables": {"id": id}}),
+                ))
+                .respond_with(
+                    ResponseTemplate::new(200).set_body_json(
+                        serde_json::json!({"data": {"team": {"id": id, "name": name}}}),
+                    ),
+                )
+                .mount(&server)
+                .await;
+        }
+        let client = client(&server.uri(), cassette);
+        assert_eq!(team(&client, "t1").await.unwrap()["name"], "One");
+        assert_eq!(team(&client, "t2").await.unwrap()["name"], "Two");
+    }
+
+    #[tokio::test]
+    async fn records_then_replays_offline() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("cassettes/teams.jsonl");
+        record_two_teams(&file).await;
+
+        let raw = std::fs::read_to_string(&file).unwrap();
+        assert!(!raw.contains("secret-token"), "{raw}");
+
+        let cassette = Cassette::replay(&file).unwrap();
+        assert_eq!(cassette.len(), 2);
+        // Nothing listens here; replay must not send anything.
+        let client = client("http://127.0.0.1:9", &cassette);
+        // Matched by variables, not order.
+        assert_eq!(team(&client, "t2").await.unwrap()["name"], "Two");
+        assert_eq!(team(&client, "t1").await.unwrap()["name"], "One");
+    }
+
+    #[tokio::test]
+    async fn replay_falls_back_to_operation_order() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("teams.jsonl");
+        record_two_teams(&file).await;
+
+        let cassette = Cassette::replay(&file).unwrap();
+        let client = client("http://127.0.0.1:9", &cassette);
+        assert_eq!(team(&client, "other-a").await.unwrap()["name"], "One");
+        assert_eq!(team(&client, "other-b").await.unwrap()["name"], "Two");
+        assert!(matches!(
+            team(&client, "t1").await,
+            Err(LinearError::Internal(msg)) if msg.contains("No recorded response for Team")
+        ));
+    }
+
+    #[tokio::test]
+    async fn recorders_sharing_a_file_append_to_it() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("shared.jsonl");
+        // Two recorders opened before either writes, as two processes would.
+        let first = Cassette::record(&file).unwrap();
+        let second = Cassette::record(&file).unwrap();
+        record_two_teams_with(&first).await;
+        record_two_teams_with(&second).await;
+
+        let raw = std::fs::read_to_string(&file).unwrap();
+        assert_eq!(raw.lines().count(), 5, "{raw}");
+        assert_eq!(Cassette::replay(&file).unwrap().len(), 4);
+    }
+
+    #[tokio::test]
+    async fn replays_errors_and_binary_bodies() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "errors": [{"message": "Entity not found: Team", "extensions": {"code": "INPUT_ERROR"}}]
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/file.png"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_bytes(vec![0xff, 0x00, 0x7f])
+                    .insert_header("content-type", "image/png"),
+            )
+            .mount(&server)
+            .await;
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("mixed.jsonl");
+        let url = format!("{}/file.png?signature=abc", server.uri());
+
+        let recorder = Cassette::record(&file).unwrap();
+        let recording = client(&server.uri(), &recorder);
+        assert!(team(&recording, "nope").await.is_err());
+        recording.download_url(&url).await.unwrap();
+
+        let replaying = client("http://127.0.0.1:9", &Cassette::replay(&file).unwrap());
+        assert!(matches!(
+            team(&replaying, "nope").await,
+            Err(LinearError::NotFound(_))
+        ));
+        let download = replaying
+            .download_url(&format!("{}/file.png?signature=later", server.uri()))
+            .await
+            .unwrap();
+        assert_eq!(download.bytes, vec![0xff, 0x00, 0x7f]);
+        assert_eq!(download.content_type.as_deref(), Some("image/png"));
+    }
+
+    #[tokio::test]
+    async fn generated_values_replay_in_order() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("generated.jsonl");
+        let recorder = Cassette::record(&file).unwrap();
+        assert_eq!(recorder.generated(|| "a".into()).unwrap(), "a");
+        record_two_teams_with(&recorder).await;
+        assert_eq!(recorder.generated(|| "b".into()).unwrap(), "b");
+
+        let cassette = Cassette::replay(&file).unwrap();
+        assert_eq!(cassette.len(), 2);
+        let unused = || panic!("replay must not generate");
+        assert_eq!(cassette.generated(unused).unwrap(), "a");
+        assert_eq!(cassette.generated(unused).unwrap(), "b");
+        assert!(matches!(
+            cassette.generated(unused),
+            Err(LinearError::Internal(msg)) if msg.contains("No more generated values")
+        ));
+    }
+
+    #[test]
+    fn reads_the_previous_format_version() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("v2.jsonl");
+        std::fs::write(
+            &file,
+            "{\"version\":2}\n{\"operation\":\"Team\",\"status\":200}\n",
+        )
+        .unwrap();
+        assert_eq!(Cassette::replay(&file).unwrap().len(), 1);
+        std::fs::write(&file, "{\"version\":1}\n").unwrap();
+        assert!(Cassette::replay(&file).is_err());
+    }
+
+    #[test]
+    fn replay_requires_existing_file() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(Cassette::replay(dir.path().join("missing.jsonl")).is_err());
+        let auto = Cassette::auto(dir.path().join("missing.jsonl")).unwrap();
+        assert_eq!(auto.mode(), CassetteMode::Record);
+        assert!(auto.is_empty());
+    }
+}
@@ -3,13 +3,26 @@
 //! The primary entry point for interacting with Linear's GraphQL API.
 //! Construct a [`Client`] via [`Client::from_token`], [`Client::from_env`],
 //! or [`Client::from_token_file`], then call generated query and mutation
-//! methods.
+//! methods. Use [`Client::builder`] when the transport needs tuning — timeouts,
+//! a proxy, extra root certificates, a custom User-Agent, or a pre-built
+//! [`reqwest::Client`].
 
 use crate::auth;
-use crate::error::{GraphQLError, LinearError};
+use crate::auth::oauth::{OAuthApp, OAuthSession, OAuthToken, Renewal, TokenCallback};
+use crate::error::{GraphQLError, LinearError, RateLimitKind};
+use crate::middleware::{Middleware, Pipeline, Request, RequestKind};
+use crate::operation::Operation;
 use crate::pagination::Connection;
+use crate::rate_limit::RateLimiter;
+use crate::retry::RetryPolicy;
+use crate::subscription::websocket::{self, WebSocket};
 use serde::de::DeserializeOwned;
+use std::collections::VecDeque;
+use std::fmt;
 use std::path::Path;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
 const LINEAR_API_URL: &str = "https://api.linear.app/graphql";
 
@@ -17,29 +30,365 @@ const LINEAR_API_URL: &str = "https://api.linear.app/graphql";
 #[derive(Debug, Clone)]
 pub struct Client {
     http: reqwest::Client,
-    token: String,
+    credentials: Credentials,
     base_url: String,
+    subscription_url: Option<String>,
+    retry: RetryPolicy,
+    user_agent: String,
+    timeout: Option<Duration>,
+    middleware: Pipeline,
+    rate_limiter: Option<RateLimiter>,
+    idempotent_creates: bool,
+    /// Highest `lastSyncId` returned by a mutation, as `f64` bits; shared
+    /// between clones.
+    last_sync_id: Arc<AtomicU64>,
+    /// The most recent mutations, for
+    /// [`SyncEngine::wait_for`](crate::sync::SyncEngine::wait_for); shared
+    /// between clones.
+    writes: Arc<Mutex<VecDeque<Write>>>,
+}
+
+/// How many recent mutations a [`Client`] remembers.
+const MAX_WRITES: usize = 256;
+
+/// A mutation the client observed: the `lastSyncId` it was recorded at and
+/// the entities its payload returned.
+#[derive(Debug, Clone)]
+pub(crate) struct Write {
+    pub(crate) sync_id: f64,
+    pub(crate) entities: Vec<WrittenEntity>,
+}
+
+/// An entity returned by a mutation payload.
+#[derive(Debug, Clone)]
+pub(crate) struct WrittenEntity {
+    /// The payload field holding it, e.g. `"issue"`.
+    pub(crate) field: String,
+    pub(crate) id: String,
+    /// Its `updatedAt`, when the selection included it.
+    pub(crate) updated_at: Option<String>,
+}
+
+/// How requests are authenticated.
+#[derive(Clone)]
+enum Credentials {
+    /// A personal API key, sent verbatim.
+    ApiKey(String),
+    /// A static OAuth access token, sent as `Bearer`.
+    Bearer(String),
+    /// An OAuth token that is renewed when it expires or is rejected.
+    OAuth(Arc<OAuthSession>),
+}
+
+impl fmt::Debug for Credentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ApiKey(_) => f.write_str("ApiKey(<redacted>)"),
+            Self::Bearer(_) => f.write_str("Bearer(<redacted>)"),
+            Self::OAuth(session) => f.debug_tuple("OAuth").field(session).finish(),
+        }
+    }
+}
+
+/// Credentials as configured on a [`ClientBuilder`], before the OAuth session
+/// is assembled.
+enum CredentialsConfig {
+    ApiKey(String),
+    Bearer(String),
+    OAuth(OAuthApp, OAuthToken),
+    ClientCredentials(OAuthApp, Vec<String>),
+}
+
+impl fmt::Debug for CredentialsConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ApiKey(_) => f.write_str("ApiKey(<redacted>)"),
+            Self::Bearer(_) => f.write_str("Bearer(<redacted>)"),
+            Self::OAuth(app, token) => f.debug_tuple("OAuth").field(app).field(token).finish(),
+            Self::ClientCredentials(app, scopes) => f
+                .debug_tuple("ClientCredentials")
+                .field(app)
+                .field(scopes)
+                .finish(),
+        }
+    }
+}
+
+/// Builder for a [`Client`] with a customized transport.
+///
+/// ```no_run
+/// # fn example() -> Result<(), lineark_sdk::LinearError> {
+/// use lineark_sdk::{reqwest, Client};
+/// use std::time::Duration;
+///
+/// let client = Client::builder()
+///     .token("lin_api_...")
+///     .timeout(Duration::from_secs(30))
+///     .proxy(reqwest::Proxy::https("http://proxy.corp.example:3128")?)
+///     .user_agent_suffix("triage-bot/1.2")
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// Proxy, root certificates, and connect timeout configure the underlying
+/// connection pool, so they cannot be combined with
+/// [`http_client`](Self::http_client); [`build`](Self::build) rejects that
+/// combination. The request timeout and User-Agent are applied per request and
+/// work with either.
+#[derive(Debug, Default)]
+pub struct ClientBuilder {
+    credentials: Option<CredentialsConfig>,
+    on_token_refresh: Option<TokenCallback>,
+    base_url: Option<String>,
+    subscription_url: Option<String>,
+    retry: Option<RetryPolicy>,
+    user_agent_suffix: Option<String>,
+    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    proxies: Vec<reqwest::Proxy>,
+    root_certificates: Vec<reqwest::Certificate>,
+    http: Option<reqwest::Client>,
+    middleware: Pipeline,
+    rate_limiter: Option<RateLimiter>,
+    idempotent_creates: bool,
+}
+
+impl ClientBuilder {
+    /// Authenticate with a personal API key, sent verbatim in the
+    /// `Authorization` header.
+    pub fn token(mut self, token: impl Into<String>) -> Self {
+        self.credentials = Some(CredentialsConfig::ApiKey(token.into()));
+        self
+    }
+
+    /// Authenticate with an OAuth access token that is never refreshed, sent
+    /// as `Authorization: Bearer <token>`.
+    pub fn bearer_token(mut self, access_token: impl Into<String>) -> Self {
+        self.credentials = Some(CredentialsConfig::Bearer(access_token.into()));
+        self
+    }
+
+    /// Authenticate as an OAuth application with a token obtained from
+    /// [`OAuthApp::exchange_code`] (or restored from storage).
+    ///
+    /// The token is refreshed with its refresh token when it expires or the
+    /// API answers 401, and the failed request is sent again once.
+    pub fn oauth(mut self, app: OAuthApp, token: OAuthToken) -> Self {
+        self.credentials = Some(CredentialsConfig::OAuth(app, token));
+        self
+    }
+
+    /// Authenticate as an OAuth application using app-actor tokens from the
+    /// client-credentials grant. The first token is requested lazily with the
+    /// first API call and re-requested whenever it expires or is rejected.
+    pub fn oauth_client_credentials<I, S>(mut self, app: OAuthApp, scopes: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let scopes = scopes.into_iter().map(Into::into).collect();
+        self.credentials = Some(CredentialsConfig::ClientCredentials(app, scopes));
+        self
+    }
+
+    /// Called with every token the client obtains on its own, so rotated
+    /// refresh tokens can be persisted.
+    pub fn on_token_refresh(
+        mut self,
+        callback: impl Fn(&OAuthToken) + Send + Sync + 'static,
+    ) -> Self {
+        self.on_token_refresh = Some(TokenCallback(Arc::new(callback)));
+        self
+    }
+
+    /// The GraphQL endpoint. Defaults to `https://api.linear.app/graphql`.
+    pub fn base_url(mut self, url: impl Into<String>) -> Self {
+        self.base_url = Some(url.into());
+        self
+    }
+
+    /// The WebSocket endpoint for [subscriptions](crate::subscription), as a
+    /// `ws(s)://` or `http(s)://` URL. Defaults to the GraphQL endpoint.
+    pub fn subscription_url(mut self, url: impl Into<String>) -> Self {
+        self.subscription_url = Some(url.into());
+        self
+    }
+
+    /// The [`RetryPolicy`] applied to every request. Retries are disabled by
+    /// default.
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry = Some(policy);
+        self
+    }
+
+    /// Give every create mutation a client-generated ID, so it can be retried
+    /// safely. See [`idempotency`](crate::idempotency). Off by default.
+    pub fn idempotent_creates(mut self, enabled: bool) -> Self {
+        self.idempotent_creates = enabled;
+        self
+    }
+
+    /// Text appended to the default `lineark-sdk/<version>` User-Agent.
+    pub fn user_agent_suffix(mut self, suffix: impl Into<String>) -> Self {
+        self.user_agent_suffix = Some(suffix.into());
+        self
+    }
+
+    /// Total time allowed for each HTTP request, from connecting until the
+    /// response body has been read. A timed-out request counts as a transient
+    /// failure for the [`RetryPolicy`].
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Time allowed for establishing a TCP/TLS connection.
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Route requests through a proxy. May be called multiple times.
+    pub fn proxy(mut self, proxy: reqwest::Proxy) -> Self {
+        self.proxies.push(proxy);
+        self
+    }
+
+    /// Trust an additional root certificate, e.g. a corporate CA. May be
+    /// called multiple times.
+    pub fn add_root_certificate(mut self, cert: reqwest::Certificate) -> Self {
+        self.root_certificates.push(cert);
+        self
+    }
+
+    /// Use a pre-built [`reqwest::Client`] instead of constructing one, to
+    /// share a connection pool or apply settings this builder doesn't expose.
+    pub fn http_client(mut self, http: reqwest::Client) -> Self {
+        self.http = Some(http);
+        self
+    }
+
+    /// Wrap every request in `middleware`. May be called multiple times; the
+    /// first middleware added runs outermost. See [`crate::middleware`].
+    pub fn middleware(mut self, middleware: impl Middleware) -> Self {
+        self.middleware.push(Arc::new(middleware));
+        self
+    }
+
+    /// Pace requests with a [`RateLimiter`], which runs inside every
+    /// middleware added with [`middleware`](Self::middleware). Keep a clone
+    /// of `limiter` to read its [`status`](RateLimiter::status). See
+    /// [`crate::rate_limit`].
+    pub fn rate_limiter(mut self, limiter: RateLimiter) -> Self {
+        self.rate_limiter = Some(limiter);
+        self
+    }
+
+    /// Build the [`Client`].
+    ///
+    /// Fails with [`LinearError::AuthConfig`] when no credentials were set, with
+    /// [`LinearError::Internal`] when transport options conflict with an
+    /// injected [`http_client`](Self::http_client), and with
+    /// [`LinearError::Network`] when the HTTP client cannot be constructed.
+    pub fn build(self) -> Result<Client, LinearError> {
+        let has_transport_options = self.connect_timeout.is_some()
+            || !self.proxies.is_empty()
+            || !self.root_certificates.is_empty();
+        let http = match self.http {
+            Some(_) if has_transport_options => {
+                return Err(LinearError::Internal(
+                    "proxy, root certificates and connect timeout cannot be combined with a custom http_client".to_string(),
+                ));
+            }
+            Some(http) => http,
+            None if !has_transport_options => reqwest::Client::new(),
+            None => {
+                let mut builder = reqwest::Client::builder();
+                if let Some(timeout) = self.connect_timeout {
+                    builder = builder.connect_timeout(timeout);
+                }
+                for proxy in self.proxies {
+                    builder = builder.proxy(proxy);
+                }
+                for cert in self.root_certificates {
+                    builder = builder.add_root_certificate(cert);
+                }
+                builder.build()?
+            }
+        };
+
+        let credentials = match self.credentials {
+            None => return Err(LinearError::AuthConfig("No token provided".to_string())),
+            Some(CredentialsConfig::ApiKey(token) | CredentialsConfig::Bearer(token))
+                if token.is_empty() =>
+            {
+                return Err(LinearError::AuthConfig("Token cannot be empty".to_string()));
+            }
+            Some(CredentialsConfig::ApiKey(token)) => Credentials::ApiKey(token),
+            Some(CredentialsConfig::Bearer(token)) => Credentials::Bearer(token),
+            Some(CredentialsConfig::OAuth(app, token)) => {
+                Credentials::OAuth(Arc::new(OAuthSession::new(
+                    app.with_transport(&http, self.timeout),
+                    Renewal::RefreshToken,
+                    Some(token),
+                    self.on_token_refresh,
+                )))
+            }
+            Some(CredentialsConfig::ClientCredentials(app, scopes)) => {
+                Credentials::OAuth(Arc::new(OAuthSession::new(
+                    app.with_transport(&http, self.timeout),
+                    Renewal::ClientCredentials(scopes),
+                    None,
+                    self.on_token_refresh,
+                )))
+            }
+        };
+
+        let mut user_agent = format!("lineark-sdk/{}", env!("CARGO_PKG_VERSION"));
+        if let Some(suffix) = self.user_agent_suffix.filter(|s| !s.is_empty()) {
+            user_agent.push(' ');
+            user_agent.push_str(&suffix);
+        }
+
+        let mut middleware = self.middleware;
+        if let Some(limiter) = &self.rate_limiter {
+            middleware.set_rate_limiter(Arc::new(limiter.clone()));
+        }
+
+        Ok(Client {
+            http,
+            credentials,
+            base_url: self.base_url.unwrap_or_else(|| LINEAR_API_URL.to_string()),
+            subscription_url: self.subscription_url,
+            retry: self.retry.unwrap_or_else(RetryPolicy::disabled),
+            user_agent,
+            timeout: self.timeout,
+            middleware,
+            rate_limiter: self.rate_limiter,
+            idempotent_creates: self.idempotent_creates,
+            last_sync_id: Arc::default(),
+            writes: Arc::default(),
+        })
+    }
 }
 
 /// Raw GraphQL response shape.
 #[derive(serde::Deserialize)]
-struct GraphQLResponse {
-    data: Option<serde_json::Value>,
-    errors: Option<Vec<GraphQLError>>,
+pub(crate) struct GraphQLResponse {
+    pub(crate) data: Option<serde_json::Value>,
+    pub(crate) errors: Option<Vec<GraphQLError>>,
 }
 
 impl Client {
+    /// Start building a client with custom transport settings.
+    pub fn builder() -> ClientBuilder {
+        ClientBuilder::default()
+    }
+
     /// Create a client with an explicit API token.
     pub fn from_token(token: impl Into<String>) -> Result<Self, LinearError> {
-        let token = token.into();
-        if token.is_empty() {
-            return Err(LinearError::AuthConfig("Token cannot be empty".to_string()));
-        }
-        Ok(Self {
-            http: reqwest::Client::new(),
-            token,
-            base_url: LINEAR_API_URL.to_string(),
-        })
+        Self::builder().token(token).build()
     }
 
     /// Create a client from the `LINEAR_API_TOKEN` environment variable.
@@ -52,34 +401,231 @@ impl Client {
         Self::from_token(auth::token_from_file(path)?)
     }
 
+    /// Set the [`RetryPolicy`] used for every request made by this client.
+    ///
+    /// Retries are disabled by default.
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry = policy;
+        self
+    }
+
+    /// Add a [`Middleware`] around every request, inside any middleware
+    /// already configured and outside the rate limiter. See
+    /// [`ClientBuilder::middleware`].
+    pub fn with_middleware(mut self, middleware: impl Middleware) -> Self {
+        self.middleware.push(Arc::new(middleware));
+        self
+    }
+
+    /// Pace requests with a [`RateLimiter`], replacing any previous one. It
+    /// runs inside every middleware, as with [`ClientBuilder::rate_limiter`].
+    pub fn with_rate_limiter(mut self, limiter: RateLimiter) -> Self {
+        self.middleware.set_rate_limiter(Arc::new(limiter.clone()));
+        self.rate_limiter = Some(limiter);
+        self
+    }
+
+    /// The [`RateLimiter`] pacing this client's requests, if any.
+    pub fn rate_limiter(&self) -> Option<&RateLimiter> {
+        self.rate_limiter.as_ref()
+    }
+
+    /// Give every create mutation a client-generated ID. See
+    /// [`ClientBuilder::idempotent_creates`].
+    pub fn with_idempotent_creates(mut self, enabled: bool) -> Self {
+        self.idempotent_creates = enabled;
+        self
+    }
+
+    /// Whether create mutations get client-generated IDs.
+    pub fn idempotent_creates(&self) -> bool {
+        self.idempotent_creates
+    }
+
+    /// The [`RetryPolicy`] this client applies to requests.
+    pub fn retry_policy(&self) -> &RetryPolicy {
+        &self.retry
+    }
+
+    /// The highest `lastSyncId` returned by a mutation made through this
+    /// client or any of its clones, or `None` before the first one. Pass it to
+    /// [`SyncEngine::wait_for`](crate::sync::SyncEngine::wait_for) to read
+    /// your own writes from a local mirror.
+    pub fn last_sync_id(&self) -> Option<f64> {
+        let bits = self.last_sync_id.load(Ordering::Acquire);
+        (bits != 0).then(|| f64::from_bits(bits))
+    }
+
+    /// Record the `lastSyncId` of a mutation payload, if it has one, along
+    /// with the entities the payload returned.
+    pub(crate) fn observe_sync_id(&self, payload: &serde_json::Value) {
+        let Some(id) = payload.get("lastSyncId").and_then(|v| v.as_f64()) else {
+            return;
+        };
+        let _ = self
+            .last_sync_id
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |bits| {
+                (bits == 0 || f64::from_bits(bits) < id).then(|| id.to_bits())
+            });
+
+        let entities = payload
+            .as_object()
+            .into_iter()
+            .flatten()
+            .filter_map(|(field, value)| {
+                Some(WrittenEntity {
+                    field: field.clone(),
+                    id: value.get("id")?.as_str()?.to_string(),
+                    updated_at: value
+                        .get("updatedAt")
+                        .and_then(|v| v.as_str())
+                        .map(str::to_string),
+                })
+            })
+            .collect();
+        let mut writes = self.writes.lock().unwrap_or_else(|e| e.into_inner());
+        if writes.len() == MAX_WRITES {
+            writes.pop_front();
+        }
+        writes.push_back(Write {
+            sync_id: id,
+            entities,
+        });
+    }
+
+    /// The remembered mutations recorded after `after` and up to `up_to`,
+    /// in sync ID order.
+    pub(crate) fn writes_between(&self, after: Option<f64>, up_to: f64) -> Vec<Write> {
+        let writes = self.writes.lock().unwrap_or_else(|e| e.into_inner());
+        let mut writes: Vec<Write> = writes
+            .iter()
+            .filter(|w| after.is_none_or(|after| w.sync_id > after) && w.sync_id <= up_to)
+            .cloned()
+            .collect();
+        writes.sort_by(|a, b| a.sync_id.total_cmp(&b.sync_id));
+        writes
+    }
+
+    /// The OAuth token currently in use, including any refreshed since the
+    /// client was built. `None` for API-key and static bearer clients, or
+    /// before a client-credentials client has fetched its first token.
+    pub async fn oauth_token(&self) -> Option<OAuthToken> {
+        match &self.credentials {
+            Credentials::OAuth(session) => session.current().await,
+            _ => None,
+        }
+    }
+
     /// Execute a GraphQL query and extract a single object from the response.
+    /// An empty `data_path` deserializes the whole `data` object instead.
+    ///
+    /// Transient failures (rate limits, 5xx responses, connection errors) are
+    /// retried according to the client's [`RetryPolicy`]. Mutations are only
+    /// retried when the policy opts in via [`RetryPolicy::retry_mutations`].
+    /// OAuth clients renew their token and resend once when the API answers 401.
     pub async fn execute<T: DeserializeOwned>(
         &self,
         query: &str,
         variables: serde_json::Value,
         data_path: &str,
     ) -> Result<T, LinearError> {
-        let body = serde_json::json!({
+        let is_mutation = query.trim_start().starts_with("mutation");
+        let gql_response = self.send_graphql(query, variables, is_mutation).await?;
+
+        // Check for GraphQL-level errors.
+        if let Some(errors) = gql_response.errors {
+            if !errors.is_empty() {
+                return Err(LinearError::from_graphql(errors, operation_name(query)));
+            }
+        }
+
+        let data = gql_response
+            .data
+            .ok_or_else(|| LinearError::MissingData("No data in response".to_string()))?;
+
+        let value = if data_path.is_empty() {
+            if is_mutation {
+                for payload in data.as_object().into_iter().flat_map(|o| o.values()) {
+                    self.observe_sync_id(payload);
+                }
+            }
+            data
+        } else {
+            let value = data
+                .get(data_path)
+                .ok_or_else(|| {
+                    LinearError::MissingData(format!("No '{}' in response data", data_path))
+                })?
+                .clone();
+            if is_mutation {
+                self.observe_sync_id(&value);
+            }
+            value
+        };
+
+        serde_json::from_value(value).map_err(|e| {
+            LinearError::MissingData(format!("Failed to deserialize '{}': {}", data_path, e))
+        })
+    }
+
+    /// Send a GraphQL document and return the raw response, applying the
+    /// retry policy and OAuth re-authentication. GraphQL-level errors are left
+    /// in the response for the caller to interpret.
+    pub(crate) async fn send_graphql(
+        &self,
+        query: &str,
+        variables: serde_json::Value,
+        is_mutation: bool,
+    ) -> Result<GraphQLResponse, LinearError> {
+        let body = serde_json::to_vec(&serde_json::json!({
             "query": query,
             "variables": variables,
-        });
+        }))
+        .map_err(|e| LinearError::Internal(format!("Failed to encode request: {}", e)))?;
+        let kind = if is_mutation {
+            RequestKind::Mutation
+        } else {
+            RequestKind::Query
+        };
+        let name = operation_name(query).unwrap_or_default();
+
+        let mut attempt = 1;
+        let mut reauthenticated = false;
+        loop {
+            let authorization = self.authorization().await?;
+            let mut request = self.request(kind, &name, reqwest::Method::POST, &self.base_url);
+            request.headers.insert(
+                reqwest::header::AUTHORIZATION,
+                header_value(&authorization)?,
+            );
+            request.headers.insert(
+                reqwest::header::CONTENT_TYPE,
+                reqwest::header::HeaderValue::from_static("application/json"),
+            );
+            request.body = body.clone();
+            match self.send_request(request).await {
+                Ok(response) => return Ok(response),
+                Err(LinearError::Authentication(_)) if !reauthenticated && self.is_oauth() => {
+                    self.reauthenticate(&authorization).await?;
+                    reauthenticated = true;
+                }
+                Err(err) if self.retry.should_retry(&err, attempt, is_mutation) => {
+                    tokio::time::sleep(self.retry.delay(attempt, &err)).await;
+                    attempt += 1;
+                }
+                Err(err) => return Err(err),
+            }
+        }
+    }
 
-        let response = self
-            .http
-            .post(&self.base_url)
-            .header("Authorization", &self.token)
-            .header("Content-Type", "application/json")
-            .header(
-                "User-Agent",
-                format!("lineark-sdk/{}", env!("CARGO_PKG_VERSION")),
-            )
-            .json(&body)
-            .send()
-            .await?;
+    /// Perform a single HTTP round trip and map non-2xx statuses to errors.
+    async fn send_request(&self, request: Request) -> Result<GraphQLResponse, LinearError> {
+        let operation = request.operation.clone();
+        let response = self.send(request).await?;
 
-        let status = response.status();
+        let status = response.status;
         if status == 401 || status == 403 {
-            let text = response.text().await.unwrap_or_default();
+            let text = response.text();
             if status == 401 {
                 return Err(LinearError::Authentication(text));
             }
@@ -87,59 +633,97 @@ impl Client {
         }
         if status == 429 {
             let retry_after = response
-                .headers()
+                .headers
                 .get("retry-after")
                 .and_then(|v| v.to_str().ok())
                 .and_then(|v| v.parse::<f64>().ok());
-            let text = response.text().await.unwrap_or_default();
             return Err(LinearError::RateLimited {
                 retry_after,
-                message: text,
-            });
-        }
-        if !status.is_success() {
-            let body = response.text().await.unwrap_or_default();
-            return Err(LinearError::HttpError {
-                status: status.as_u16(),
-                body,
+                kind: RateLimitKind::from_headers(&response.headers),
+                message: response.text(),
             });
         }
-
-        let gql_response: GraphQLResponse = response.json().await?;
-
-        // Check for GraphQL-level errors.
-        if let Some(errors) = gql_response.errors {
-            if !errors.is_empty() {
-                // Check for specific error types.
-                let first_msg = errors[0].message.to_lowercase();
-                if first_msg.contains("authentication") || first_msg.contains("unauthorized") {
-                    return Err(LinearError::Authentication(errors[0].message.clone()));
+        let response: GraphQLResponse = if status.is_success() {
+            serde_json::from_slice(&response.body)
+                .map_err(|e| LinearError::MissingData(format!("Failed to parse response: {}", e)))?
+        } else {
+            // Linear answers 400 for rejected operations (e.g. invalid input or
+            // complexity limits) with the usual GraphQL error body, which is
+            // handed back like a 200 so batches can attribute it per operation.
+            match serde_json::from_slice::<GraphQLResponse>(&response.body) {
+                Ok(parsed)
+                    if status.is_client_error()
+                        && parsed.errors.as_ref().is_some_and(|e| !e.is_empty()) =>
+                {
+                    parsed
                 }
-                // Extract operation name from query string (e.g. "query Viewer { ... }" → "Viewer").
-                let query_name = query
-                    .strip_prefix("query ")
-                    .or_else(|| query.strip_prefix("mutation "))
-                    .and_then(|rest| rest.split(['(', ' ', '{']).next())
-                    .filter(|s| !s.is_empty())
-                    .map(|s| s.to_string());
-                return Err(LinearError::GraphQL { errors, query_name });
+                _ => {
+                    return Err(LinearError::HttpError {
+                        status: status.as_u16(),
+                        body: response.text(),
+                    })
+                }
+            }
+        };
+        // Rate limits and rejected credentials reported in the body become
+        // errors here, so the retry policy and OAuth renewal see them.
+        if let Some(errors) = response.errors.as_ref().filter(|e| !e.is_empty()) {
+            let err = LinearError::from_graphql(errors.clone(), Some(operation));
+            if matches!(
+                err,
+                LinearError::RateLimited { .. } | LinearError::Authentication(_)
+            ) {
+                return Err(err);
             }
         }
+        Ok(response)
+    }
 
-        let data = gql_response
-            .data
-            .ok_or_else(|| LinearError::MissingData("No data in response".to_string()))?;
+    /// A request carrying the client's User-Agent and timeout, ready for
+    /// [`send`](Self::send).
+    pub(crate) fn request(
+        &self,
+        kind: RequestKind,
+        operation: &str,
+        method: reqwest::Method,
+        url: &str,
+    ) -> Request {
+        let mut request = Request::new(kind, operation, method, url);
+        if let Ok(user_agent) = reqwest::header::HeaderValue::from_str(&self.user_agent) {
+            request
+                .headers
+                .insert(reqwest::header::USER_AGENT, user_agent);
+        }
+        request.timeout = self.timeout;
+        request
+    }
 
-        let value = data
-            .get(data_path)
-            .ok_or_else(|| {
-                LinearError::MissingData(format!("No '{}' in response data", data_path))
-            })?
-            .clone();
+    /// Send a request through the middleware chain.
+    pub(crate) async fn send(
+        &self,
+        request: Request,
+    ) -> Result<crate::middleware::Response, LinearError> {
+        self.middleware.run(&self.http, request).await
+    }
 
-        serde_json::from_value(value).map_err(|e| {
-            LinearError::MissingData(format!("Failed to deserialize '{}': {}", data_path, e))
-        })
+    /// Open a WebSocket speaking `protocol` to the subscription endpoint for
+    /// `operation`, sending the handshake with `authorization` through the
+    /// middleware chain.
+    pub(crate) async fn connect_websocket(
+        &self,
+        operation: &str,
+        authorization: &str,
+        protocol: &'static str,
+    ) -> Result<WebSocket<reqwest::Upgraded>, LinearError> {
+        let url = self.subscription_url.as_deref().unwrap_or(&self.base_url);
+        let mut request =
+            self.request(RequestKind::Subscribe, operation, reqwest::Method::GET, url);
+        request
+            .headers
+            .insert(reqwest::header::AUTHORIZATION, header_value(authorization)?);
+        let key = websocket::handshake(&mut request, protocol);
+        let (response, upgrade) = self.middleware.upgrade(&self.http, request).await?;
+        websocket::accept(response, upgrade, &key, protocol).await
     }
 
     /// Execute a GraphQL query and extract a Connection from the response.
@@ -168,89 +752,95 @@ impl Client {
     ///
     /// let me: MyViewer = client.query::<MyViewer>("viewer").await?;
     /// ```
+    ///
+    /// Variables declared by nested field arguments are hoisted onto the
+    /// operation, as for [`Operation::query`](crate::Operation::query), and
+    /// sent as null.
     pub async fn query<T: DeserializeOwned + crate::GraphQLFields>(
         &self,
         field: &str,
     ) -> Result<T, LinearError> {
-        let selection = T::selection();
-        let query = format!("query {{ {} {{ {} }} }}", field, selection);
-        self.execute::<T>(&query, serde_json::json!({}), field)
-            .await
+        let selection = format!("{} {{ {} }}", field, T::selection());
+        Operation::<T>::query(
+            field_operation_name(field),
+            "",
+            selection,
+            serde_json::json!({}),
+            field,
+        )
+        .execute(self)
+        .await
     }
 
     /// Execute a typed connection query using the node type's
     /// [`GraphQLFields`](crate::GraphQLFields) implementation.
     ///
-    /// Builds `{ field { nodes { <T::selection()> } pageInfo { ... } } }`.
+    /// Builds `{ field { nodes { <T::selection()> } pageInfo { ... } } }`,
+    /// hoisting nested field variables like [`query`](Self::query).
     pub async fn query_connection<T: DeserializeOwned + crate::GraphQLFields>(
         &self,
         field: &str,
     ) -> Result<Connection<T>, LinearError> {
-        let selection = T::selection();
-        let query = format!(
-            "query {{ {} {{ nodes {{ {} }} pageInfo {{ hasNextPage endCursor }} }} }}",
-            field, selection
+        let selection = format!(
+            "{} {{ nodes {{ {} }} pageInfo {{ hasNextPage endCursor }} }}",
+            field,
+            T::selection()
         );
-        self.execute_connection::<T>(&query, serde_json::json!({}), field)
-            .await
+        Operation::<Connection<T>>::query(
+            field_operation_name(field),
+            "",
+            selection,
+            serde_json::json!({}),
+            field,
+        )
+        .execute(self)
+        .await
     }
 
     /// Execute a mutation, check `success`, and extract the entity field.
     ///
     /// Many Linear mutations return a payload shaped like
-    /// `{ success: Boolean, entityField: { ... } }`. This helper:
+    /// `{ success: Boolean, lastSyncId: Float, entityField: { ... } }`. This helper:
     /// 1. Executes the query and extracts the payload at `data_path`
-    /// 2. Checks the `success` field — returns an error if false
-    /// 3. Extracts and deserializes `payload[entity_field]` as `T`
+    /// 2. Checks the `success` field — returns an error if false, or if it
+    ///    is missing when an entity field or metadata is requested
+    /// 3. Extracts and deserializes `payload[entity_field]` (or the whole
+    ///    payload without an entity field) as `T`, wrapped in a
+    ///    [`MutationResult`](crate::MutationResult) when `with_meta` is set
     pub(crate) async fn execute_mutation<T: DeserializeOwned>(
         &self,
         query: &str,
         variables: serde_json::Value,
         data_path: &str,
-        entity_field: &str,
+        entity_field: Option<&str>,
+        with_meta: bool,
     ) -> Result<T, LinearError> {
         let payload = self
             .execute::<serde_json::Value>(query, variables, data_path)
             .await?;
+        decode_mutation_payload(payload, data_path, entity_field, with_meta)
+    }
 
-        // Check success field.
-        if payload.get("success").and_then(|v| v.as_bool()) != Some(true) {
-            return Err(LinearError::Internal(format!(
-                "Mutation '{}' failed: {}",
-                data_path,
-                serde_json::to_string_pretty(&payload).unwrap_or_default()
-            )));
+    /// The `Authorization` header value, renewing an expired OAuth token first.
+    pub(crate) async fn authorization(&self) -> Result<String, LinearError> {
+        match &self.credentials {
+            Credentials::ApiKey(token) => Ok(token.clone()),
+            Credentials::Bearer(token) => Ok(format!("Bearer {}", token)),
+            Credentials::OAuth(session) => session.authorization().await,
         }
-
-        // Extract and deserialize the entity.
-        let entity = payload
-            .get(entity_field)
-            .ok_or_else(|| {
-                LinearError::MissingData(format!(
-                    "No '{}' field in '{}' payload",
-                    entity_field, data_path
-                ))
-            })?
-            .clone();
-
-        serde_json::from_value(entity).map_err(|e| {
-            LinearError::MissingData(format!(
-                "Failed to deserialize '{}' from '{}': {}",
-                entity_field, data_path, e
-            ))
-        })
     }
 
-    /// Access the underlying HTTP client.
-    ///
-    /// Used internally by [`helpers`](crate::helpers) for file download/upload
-    /// operations that go outside the GraphQL API.
-    pub(crate) fn http(&self) -> &reqwest::Client {
-        &self.http
+    /// Renew the OAuth token after the API rejected `rejected`. Does nothing
+    /// for other credentials.
+    pub(crate) async fn reauthenticate(&self, rejected: &str) -> Result<(), LinearError> {
+        match &self.credentials {
+            Credentials::OAuth(session) => session.reauthenticate(rejected).await,
+            _ => Ok(()),
+        }
     }
 
-    pub(crate) fn token(&self) -> &str {
-        &self.token
+    pub(crate) fn is_oauth(&self) -> bool {
+        matches!(self.credentials, Credentials::OAuth(_))
     }
 
     /// Override the base URL (for testing against mock servers).
@@ -267,19 +857,118 @@ impl Client {
     }
 }
 
+/// Extract the operation name from a document (e.g. "query Viewer { ... }" → "Viewer").
+fn operation_name(query: &str) -> Option<String> {
+    query
+        .strip_prefix("query ")
+        .or_else(|| query.strip_prefix("mutation "))
+        .and_then(|rest| rest.split(['(', ' ', '{']).next())
+        .filter(|s| !s.is_empty())
+        .map(|s| s.to_string())
+}
+
+/// Operation name for a root field (`viewer` → `Viewer`).
+fn field_operation_name(field: &str) -> String {
+    let mut chars = field.chars();
+    chars
+        .next()
+        .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+        .unwrap_or_default()
+}
+
+/// An `Authorization` header value, rejecting tokens that can't be sent.
+pub(crate) fn header_value(
+    authorization: &str,
+) -> Result<reqwest::header::HeaderValue, LinearError> {
+    let mut value = reqwest::header::HeaderValue::from_str(authorization).map_err(|_| {
+        LinearError::AuthConfig("Token contains characters not allowed in a header".to_string())
+    })?;
+    value.set_sensitive(true);
+    Ok(value)
+}
+
+/// Check a mutation payload's `success` flag and deserialize its entity
+/// field — or the whole payload when there is none. With `with_meta`, the
+/// entity is deserialized as the `entity` of a
+/// [`MutationResult`](crate::MutationResult) next to `success` and `lastSyncId`.
+///
+/// `success: false` is always an error. A payload that doesn't select
+/// `success` is only accepted when neither an entity nor metadata is wanted.
+pub(crate) fn decode_mutation_payload<T: DeserializeOwned>(
+    payload: serde_json::Value,
+    data_path: &str,
+    entity_field: Option<&str>,
+    with_meta: bool,
+) -> Result<T, LinearError> {
+    // Check success field.
+    let success = payload.get("success").and_then(|v| v.as_bool());
+    let required = entity_field.is_some() || with_meta;
+    if success == Some(false) || (required && success != Some(true)) {
+        return Err(LinearError::Internal(format!(
+            "Mutation '{}' failed: {}",
+            data_path,
+            serde_json::to_string_pretty(&payload).unwrap_or_default()
+        )));
+    }
+
+    // Extract the entity.
+    let entity = match entity_field {
+        Some(entity_field) => payload
+            .get(entity_field)
+            .ok_or_else(|| {
+                LinearError::MissingData(format!(
+                    "No '{}' field in '{}' payload",
+                    entity_field, data_path
+                ))
+            })?
+            .clone(),
+        None => payload.clone(),
+    };
+    let value = if with_meta {
+        serde_json::json!({
+            "success": true,
+            "lastSyncId": payload.get("lastSyncId"),
+            "entity": entity,
+        })
+    } else {
+        entity
+    };
+
+    serde_json::from_value(value).map_err(|e| {
+        LinearError::MissingData(format!(
+            "Failed to deserialize '{}' from '{}': {}",
+            entity_field.unwrap_or("payload"),
+            data_path,
+            e
+        ))
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use wiremock::matchers::{header, method};
+    use wiremock::matchers::{header, method, path};
     use wiremock::{Mock, MockServer, ResponseTemplate};
 
     #[test]
     fn from_token_valid() {
         let client = Client::from_token("lin_api_test123").unwrap();
-        assert_eq!(client.token, "lin_api_test123");
+        assert!(matches!(&client.credentials, Credentials::ApiKey(t) if t == "lin_api_test123"));
         assert_eq!(client.base_url, LINEAR_API_URL);
     }
 
+    #[test]
+    fn debug_redacts_credentials() {
+        let client = Client::from_token("lin_api_secret").unwrap();
+        let debug = format!("{:?}", client);
+        assert!(!debug.contains("lin_api_secret"), "{debug}");
+        assert!(debug.contains("ApiKey(<redacted>)"), "{debug}");
+        let builder = Client::builder().bearer_token("bearer-secret");
+        let debug = format!("{:?}", builder);
+        assert!(!debug.contains("bearer-secret"), "{debug}");
+        assert!(debug.contains("Bearer(<redacted>)"), "{debug}");
+    }
+
     #[test]
     fn from_token_empty_fails() {
         let err = Client::from_token("").unwrap_err();
@@ -360,9 +1049,11 @@ mod tests {
         match result {
             Err(LinearError::RateLimited {
                 retry_after,
+                kind,
                 message,
             }) => {
                 assert_eq!(retry_after, Some(30.0));
+                assert_eq!(kind, RateLimitKind::Unknown);
                 assert_eq!(message, "Too Many Requests");
             }
             other => panic!("Expected RateLimited, got {:?}", other),
@@ -370,58 +1061,158 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn execute_returns_500_as_http_error() {
+    async fn execute_429_reports_exhausted_budget() {
         let server = MockServer::start().await;
         Mock::given(method("POST"))
-            .respond_with(ResponseTemplate::new(500).set_body_string("Internal Server Error"))
+            .respond_with(
+                ResponseTemplate::new(429)
+                    .append_header("x-ratelimit-requests-remaining", "0")
+                    .append_header("x-ratelimit-complexity-remaining", "120000"),
+            )
             .mount(&server)
             .await;
-
         let client = Client::from_token("token")
             .unwrap()
             .with_base_url(server.uri());
 
-        let result = client
-            .execute::<serde_json::Value>(
-                "query { viewer { id } }",
-                serde_json::json!({}),
-                "viewer",
-            )
-            .await;
-
-        match result {
-            Err(LinearError::HttpError { status, body }) => {
-                assert_eq!(status, 500);
-                assert_eq!(body, "Internal Server Error");
-            }
-            other => panic!("Expected HttpError, got {:?}", other),
-        }
+        assert!(matches!(
+            viewer_id(&client).await,
+            Err(LinearError::RateLimited {
+                kind: RateLimitKind::Requests,
+                ..
+            })
+        ));
     }
 
     #[tokio::test]
-    async fn execute_returns_graphql_errors() {
+    async fn execute_maps_400_graphql_errors_to_typed_variants() {
         let server = MockServer::start().await;
         Mock::given(method("POST"))
-            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
-                "data": null,
-                "errors": [{"message": "Field 'foo' not found"}]
+            .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+                "errors": [{
+                    "message": "Argument Validation Error",
+                    "extensions": {
+                        "type": "invalid input",
+                        "code": "INPUT_ERROR",
+                        "userError": true,
+                        "userPresentableMessage": "Title is required.",
+                        "argumentPath": ["input", "title"]
+                    }
+                }]
             })))
             .mount(&server)
             .await;
-
         let client = Client::from_token("token")
             .unwrap()
             .with_base_url(server.uri());
 
-        let result = client
-            .execute::<serde_json::Value>("query { foo }", serde_json::json!({}), "foo")
-            .await;
-
-        assert!(matches!(result, Err(LinearError::GraphQL { .. })));
+        match viewer_id(&client).await {
+            Err(LinearError::InvalidInput { field, message }) => {
+                assert_eq!(field.as_deref(), Some("input.title"));
+                assert_eq!(message, "Title is required.");
+            }
+            other => panic!("Expected InvalidInput, got {:?}", other),
+        }
     }
 
     #[tokio::test]
-    async fn execute_graphql_auth_error_detected() {
+    async fn execute_maps_200_graphql_errors_to_typed_variants() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": null,
+                "errors": [{
+                    "message": "Entity not found: Issue",
+                    "extensions": {"type": "invalid input", "code": "INPUT_ERROR"}
+                }]
+            })))
+            .mount(&server)
+            .await;
+        let client = Client::from_token("token")
+            .unwrap()
+            .with_base_url(server.uri());
+
+        assert!(matches!(
+            viewer_id(&client).await,
+            Err(LinearError::NotFound(m)) if m == "Entity not found: Issue"
+        ));
+    }
+
+    #[tokio::test]
+    async fn execute_retries_graphql_rate_limit_errors() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+                "errors": [{
+                    "message": "Rate limit exceeded",
+                    "extensions": {"code": "RATELIMITED"}
+                }]
+            })))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        mount_viewer(&server).await;
+        let client = Client::from_token("token")
+            .unwrap()
+            .with_base_url(server.uri())
+            .with_retry_policy(fast_retry());
+
+        assert_eq!(viewer_id(&client).await.unwrap()["id"], "1");
+    }
+
+    #[tokio::test]
+    async fn execute_returns_500_as_http_error() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(500).set_body_string("Internal Server Error"))
+            .mount(&server)
+            .await;
+
+        let client = Client::from_token("token")
+            .unwrap()
+            .with_base_url(server.uri());
+
+        let result = client
+            .execute::<serde_json::Value>(
+                "query { viewer { id } }",
+                serde_json::json!({}),
+                "viewer",
+            )
+            .await;
+
+        match result {
+            Err(LinearError::HttpError { status, body }) => {
+                assert_eq!(status, 500);
+                assert_eq!(body, "Internal Server Error");
+            }
+            other => panic!("Expected HttpError, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn execute_returns_graphql_errors() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": null,
+                "errors": [{"message": "Field 'foo' not found"}]
+            })))
+            .mount(&server)
+            .await;
+
+        let client = Client::from_token("token")
+            .unwrap()
+            .with_base_url(server.uri());
+
+        let result = client
+            .execute::<serde_json::Value>("query { foo }", serde_json::json!({}), "foo")
+            .await;
+
+        assert!(matches!(result, Err(LinearError::GraphQL { .. })));
+    }
+
+    #[tokio::test]
+    async fn execute_graphql_auth_error_detected() {
         let server = MockServer::start().await;
         Mock::given(method("POST"))
             .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
@@ -593,4 +1384,565 @@ mod tests {
 
         assert_eq!(result["id"], "1");
     }
+
+    // ── retry policy ────────────────────────────────────────────────────────
+
+    fn fast_retry() -> RetryPolicy {
+        RetryPolicy::default()
+            .jitter(false)
+            .initial_backoff(std::time::Duration::from_millis(20))
+    }
+
+    async fn mount_failures(server: &MockServer, template: ResponseTemplate, times: u64) {
+        Mock::given(method("POST"))
+            .respond_with(template)
+            .up_to_n_times(times)
+            .mount(server)
+            .await;
+    }
+
+    async fn mount_viewer(server: &MockServer) {
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": {"viewer": {"id": "1"}}
+            })))
+            .mount(server)
+            .await;
+    }
+
+    #[test]
+    fn default_client_does_not_retry() {
+        let client = Client::from_token("token").unwrap();
+        assert_eq!(client.retry_policy(), &RetryPolicy::disabled());
+    }
+
+    #[tokio::test]
+    async fn execute_retries_5xx_until_success() {
+        let server = MockServer::start().await;
+        mount_failures(&server, ResponseTemplate::new(503), 2).await;
+        mount_viewer(&server).await;
+
+        let client = Client::from_token("token")
+            .unwrap()
+            .with_base_url(server.uri())
+            .with_retry_policy(fast_retry());
+
+        let result: serde_json::Value = client
+            .execute("query { viewer { id } }", serde_json::json!({}), "viewer")
+            .await
+            .unwrap();
+
+        assert_eq!(result["id"], "1");
+        assert_eq!(server.received_requests().await.unwrap().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn execute_gives_up_after_max_attempts() {
+        let server = MockServer::start().await;
+        mount_failures(&server, ResponseTemplate::new(500), 10).await;
+
+        let client = Client::from_token("token")
+            .unwrap()
+            .with_base_url(server.uri())
+            .with_retry_policy(fast_retry().max_attempts(4));
+
+        let result = client
+            .execute::<serde_json::Value>(
+                "query { viewer { id } }",
+                serde_json::json!({}),
+                "viewer",
+            )
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(LinearError::HttpError { status: 500, .. })
+        ));
+        assert_eq!(server.received_requests().await.unwrap().len(), 4);
+    }
+
+    #[tokio::test]
+    async fn execute_follows_exponential_schedule() {
+        let server = MockServer::start().await;
+        mount_failures(&server, ResponseTemplate::new(502), 3).await;
+        mount_viewer(&server).await;
+
+        // Retries wait 50 ms, 100 ms, 200 ms → at least 350 ms in total.
+        let client = Client::from_token("token")
+            .unwrap()
+            .with_base_url(server.uri())
+            .with_retry_policy(
+                fast_retry()
+                    .max_attempts(4)
+                    .initial_backoff(std::time::Duration::from_millis(50)),
+            );
+
+        let started = std::time::Instant::now();
+        client
+            .execute::<serde_json::Value>(
+                "query { viewer { id } }",
+                serde_json::json!({}),
+                "viewer",
+            )
+            .await
+            .unwrap();
+        let elapsed = started.elapsed();
+
+        assert!(
+            elapsed >= std::time::Duration::from_millis(350),
+            "{elapsed:?}"
+        );
+        assert!(elapsed < std::time::Duration::from_secs(5), "{elapsed:?}");
+        assert_eq!(server.received_requests().await.unwrap().len(), 4);
+    }
+
+    #[tokio::test]
+    async fn execute_honors_retry_after() {
+        let server = MockServer::start().await;
+        mount_failures(
+            &server,
+            ResponseTemplate::new(429).append_header("retry-after", "0.3"),
+            1,
+        )
+        .await;
+        mount_viewer(&server).await;
+
+        // Backoff alone would be 20 ms; the server asks for 300 ms.
+        let client = Client::from_token("token")
+            .unwrap()
+            .with_base_url(server.uri())
+            .with_retry_policy(fast_retry());
+
+        let started = std::time::Instant::now();
+        client
+            .execute::<serde_json::Value>(
+                "query { viewer { id } }",
+                serde_json::json!({}),
+                "viewer",
+            )
+            .await
+            .unwrap();
+
+        assert!(started.elapsed() >= std::time::Duration::from_millis(300));
+        assert_eq!(server.received_requests().await.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn execute_does_not_retry_non_transient_errors() {
+        let server = MockServer::start().await;
+        mount_failures(&server, ResponseTemplate::new(400), 10).await;
+
+        let client = Client::from_token("token")
+            .unwrap()
+            .with_base_url(server.uri())
+            .with_retry_policy(fast_retry());
+
+        let result = client
+            .execute::<serde_json::Value>(
+                "query { viewer { id } }",
+                serde_json::json!({}),
+                "viewer",
+            )
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(LinearError::HttpError { status: 400, .. })
+        ));
+        assert_eq!(server.received_requests().await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn execute_does_not_retry_mutations_by_default() {
+        let server = MockServer::start().await;
+        mount_failures(&server, ResponseTemplate::new(503), 10).await;
+
+        let client = Client::from_token("token")
+            .unwrap()
+            .with_base_url(server.uri())
+            .with_retry_policy(fast_retry());
+
+        let result = client
+            .execute::<serde_json::Value>(
+                "mutation IssueDelete($id: String!) { issueDelete(id: $id) { success } }",
+                serde_json::json!({"id": "x"}),
+                "issueDelete",
+            )
+            .await;
+
+        assert!(result.is_err());
+        assert_eq!(server.received_requests().await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn execute_retries_mutations_when_opted_in() {
+        let server = MockServer::start().await;
+        mount_failures(&server, ResponseTemplate::new(503), 1).await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": {"issueDelete": {"success": true}}
+            })))
+            .mount(&server)
+            .await;
+
+        let client = Client::from_token("token")
+            .unwrap()
+            .with_base_url(server.uri())
+            .with_retry_policy(fast_retry().retry_mutations(true));
+
+        let result: serde_json::Value = client
+            .execute(
+                "mutation IssueDelete($id: String!) { issueDelete(id: $id) { success } }",
+                serde_json::json!({"id": "x"}),
+                "issueDelete",
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result["success"], true);
+        assert_eq!(server.received_requests().await.unwrap().len(), 2);
+    }
+
+    // ── Builder ─────────────────────────────────────────────────────────────
+
+    async fn viewer_id(client: &Client) -> Result<serde_json::Value, LinearError> {
+        client
+            .execute::<serde_json::Value>(
+                "query { viewer { id } }",
+                serde_json::json!({}),
+                "viewer",
+            )
+            .await
+    }
+
+    #[test]
+    fn builder_without_token_fails() {
+        let err = Client::builder().build().unwrap_err();
+        assert!(matches!(err, LinearError::AuthConfig(_)));
+    }
+
+    #[test]
+    fn builder_defaults_match_from_token() {
+        let client = Client::builder().token("tok").build().unwrap();
+        assert_eq!(client.base_url, LINEAR_API_URL);
+        assert_eq!(client.retry_policy(), &RetryPolicy::disabled());
+        assert_eq!(
+            client.user_agent,
+            format!("lineark-sdk/{}", env!("CARGO_PKG_VERSION"))
+        );
+        assert_eq!(client.timeout, None);
+    }
+
+    #[tokio::test]
+    async fn builder_sets_base_url_and_retry_policy() {
+        let server = MockServer::start().await;
+        mount_failures(&server, ResponseTemplate::new(503), 1).await;
+        mount_viewer(&server).await;
+
+        let client = Client::builder()
+            .token("token")
+            .base_url(server.uri())
+            .retry_policy(fast_retry())
+            .build()
+            .unwrap();
+
+        assert_eq!(viewer_id(&client).await.unwrap()["id"], "1");
+        assert_eq!(server.received_requests().await.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn builder_appends_user_agent_suffix() {
+        let server = MockServer::start().await;
+        let expected = format!("lineark-sdk/{} triage-bot/1.2", env!("CARGO_PKG_VERSION"));
+        Mock::given(method("POST"))
+            .and(header("User-Agent", expected.as_str()))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": {"viewer": {"id": "1"}}
+            })))
+            .mount(&server)
+            .await;
+
+        let client = Client::builder()
+            .token("token")
+            .base_url(server.uri())
+            .user_agent_suffix("triage-bot/1.2")
+            .build()
+            .unwrap();
+
+        assert!(viewer_id(&client).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn builder_timeout_applies_per_request() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({"data": {"viewer": {"id": "1"}}}))
+                    .set_delay(std::time::Duration::from_secs(2)),
+            )
+            .mount(&server)
+            .await;
+
+        let client = Client::builder()
+            .token("token")
+            .base_url(server.uri())
+            .timeout(std::time::Duration::from_millis(50))
+            .build()
+            .unwrap();
+
+        match viewer_id(&client).await {
+            Err(LinearError::Network(e)) => assert!(e.is_timeout()),
+            other => panic!("Expected timeout, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn builder_uses_injected_http_client() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(header("x-injected", "yes"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": {"viewer": {"id": "1"}}
+            })))
+            .mount(&server)
+            .await;
+
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert("x-injected", "yes".parse().unwrap());
+        let http = reqwest::Client::builder()
+            .default_headers(headers)
+            .build()
+            .unwrap();
+
+        let client = Client::builder()
+            .token("token")
+            .base_url(server.uri())
+            .http_client(http)
+            .build()
+            .unwrap();
+
+        assert!(viewer_id(&client).await.is_ok());
+    }
+
+    #[test]
+    fn builder_rejects_transport_options_with_injected_client() {
+        let err = Client::builder()
+            .token("token")
+            .http_client(reqwest::Client::new())
+            .connect_timeout(std::time::Duration::from_secs(1))
+            .build()
+            .unwrap_err();
+        assert!(matches!(err, LinearError::Internal(_)));
+    }
+
+    #[tokio::test]
+    async fn builder_routes_through_proxy() {
+        let proxy = MockServer::start().await;
+        mount_viewer(&proxy).await;
+
+        let client = Client::builder()
+            .token("token")
+            .base_url("http://linear.invalid/graphql")
+            .proxy(reqwest::Proxy::http(proxy.uri()).unwrap())
+            .build()
+            .unwrap();
+
+        assert_eq!(viewer_id(&client).await.unwrap()["id"], "1");
+        assert_eq!(proxy.received_requests().await.unwrap().len(), 1);
+    }
+
+    // ── OAuth ───────────────────────────────────────────────────────────────
+
+    fn oauth_app(server: &MockServer) -> OAuthApp {
+        OAuthApp::new("cid")
+            .client_secret("secret")
+            .token_url(format!("{}/oauth/token", server.uri()))
+    }
+
+    async fn mount_token_endpoint(server: &MockServer, access_token: &str, refresh_token: &str) {
+        Mock::given(method("POST"))
+            .and(path("/oauth/token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "access_token": access_token,
+                "refresh_token": refresh_token,
+                "expires_in": 86400
+            })))
+            .mount(server)
+            .await;
+    }
+
+    async fn mount_graphql_for(server: &MockServer, authorization: &str, status: u16) {
+        Mock::given(method("POST"))
+            .and(path("/graphql"))
+            .and(header("Authorization", authorization))
+            .respond_with(
+                ResponseTemplate::new(status).set_body_json(serde_json::json!({
+                    "data": {"viewer": {"id": "1"}}
+                })),
+            )
+            .mount(server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn bearer_token_is_sent_with_prefix() {
+        let server = MockServer::start().await;
+        mount_graphql_for(&server, "Bearer access", 200).await;
+
+        let client = Client::builder()
+            .bearer_token("access")
+            .base_url(format!("{}/graphql", server.uri()))
+            .build()
+            .unwrap();
+
+        assert!(viewer_id(&client).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn oauth_refreshes_on_401_and_resends() {
+        let server = MockServer::start().await;
+        mount_graphql_for(&server, "Bearer stale", 401).await;
+        mount_graphql_for(&server, "Bearer fresh", 200).await;
+        mount_token_endpoint(&server, "fresh", "refresh-2").await;
+
+        let refreshed = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink = refreshed.clone();
+        let client = Client::builder()
+            .oauth(
+                oauth_app(&server),
+                OAuthToken::new("stale").with_refresh_token("refresh-1"),
+            )
+            .on_token_refresh(move |t| sink.lock().unwrap().push(t.clone()))
+            .base_url(format!("{}/graphql", server.uri()))
+            .build()
+            .unwrap();
+
+        assert_eq!(viewer_id(&client).await.unwrap()["id"], "1");
+        let token = client.oauth_token().await.unwrap();
+        assert_eq!(token.access_token, "fresh");
+        assert_eq!(token.refresh_token.as_deref(), Some("refresh-2"));
+        assert_eq!(refreshed.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn oauth_refreshes_expired_token_before_sending() {
+        let server = MockServer::start().await;
+        mount_graphql_for(&server, "Bearer fresh", 200).await;
+        mount_token_endpoint(&server, "fresh", "refresh-2").await;
+
+        let mut token = OAuthToken::new("expired").with_refresh_token("refresh-1");
+        token.expires_at = Some(chrono::Utc::now() - chrono::Duration::hours(1));
+        let client = Client::builder()
+            .oauth(oauth_app(&server), token)
+            .base_url(format!("{}/graphql", server.uri()))
+            .build()
+            .unwrap();
+
+        assert!(viewer_id(&client).await.is_ok());
+        let graphql_calls = server
+            .received_requests()
+            .await
+            .unwrap()
+            .iter()
+            .filter(|r| r.url.path() == "/graphql")
+            .count();
+        assert_eq!(graphql_calls, 1);
+    }
+
+    #[tokio::test]
+    async fn oauth_refresh_uses_the_client_transport() {
+        let server = MockServer::start().await;
+        mount_graphql_for(&server, "Bearer fresh", 200).await;
+        Mock::given(method("POST"))
+            .and(path("/oauth/token"))
+            .and(header("x-injected", "yes"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "access_token": "fresh",
+                "expires_in": 86400
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert("x-injected", "yes".parse().unwrap());
+        let http = reqwest::Client::builder()
+            .default_headers(headers)
+            .build()
+            .unwrap();
+        let mut token = OAuthToken::new("expired").with_refresh_token("refresh-1");
+        token.expires_at = Some(chrono::Utc::now() - chrono::Duration::hours(1));
+        let client = Client::builder()
+            .oauth(oauth_app(&server), token)
+            .base_url(format!("{}/graphql", server.uri()))
+            .http_client(http)
+            .build()
+            .unwrap();
+
+        assert!(viewer_id(&client).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn oauth_gives_up_after_one_reauthentication() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/graphql"))
+            .respond_with(ResponseTemplate::new(401).set_body_string("Unauthorized"))
+            .expect(2)
+            .mount(&server)
+            .await;
+        mount_token_endpoint(&server, "also-rejected", "refresh-2").await;
+
+        let client = Client::builder()
+            .oauth(
+                oauth_app(&server),
+                OAuthToken::new("stale").with_refresh_token("refresh-1"),
+            )
+            .base_url(format!("{}/graphql", server.uri()))
+            .build()
+            .unwrap();
+
+        assert!(matches!(
+            viewer_id(&client).await,
+            Err(LinearError::Authentication(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn oauth_without_refresh_token_surfaces_401() {
+        let server = MockServer::start().await;
+        mount_graphql_for(&server, "Bearer stale", 401).await;
+
+        let client = Client::builder()
+            .oauth(oauth_app(&server), OAuthToken::new("stale"))
+            .base_url(format!("{}/graphql", server.uri()))
+            .build()
+            .unwrap();
+
+        assert!(matches!(
+            viewer_id(&client).await,
+            Err(LinearError::Authentication(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn oauth_client_credentials_fetches_token_lazily() {
+        let server = MockServer::start().await;
+        mount_graphql_for(&server, "Bearer app-token", 200).await;
+        mount_token_endpoint(&server, "app-token", "unused").await;
+
+        let client = Client::builder()
+            .oauth_client_credentials(oauth_app(&server), ["read"])
+            .base_url(format!("{}/graphql", server.uri()))
+            .build()
+            .unwrap();
+
+        assert!(client.oauth_token().await.is_none());
+        assert!(viewer_id(&client).await.is_ok());
+        assert_eq!(
+            client.oauth_token().await.unwrap().access_token,
+            "app-token"
+        );
+    }
 }
@@ -0,0 +1,47 @@
+//! Operations generated from hand-written `.graphql` documents.
+//!
+//! `lineark-codegen documents --out <file.rs> <file.graphql>...` validates
+//! each named query or mutation against Linear's schema and emits a module
+//! per operation with typed `Variables`, a typed `ResponseData`, and an
+//! `Operation` marker implementing [`GraphQLDocument`]. Run it with
+//! [`Client::execute_document`]:
+//!
+//! ```ignore
+//! let data = client
+//!     .execute_document::<documents::my_issues::Operation>(&documents::my_issues::Variables {
+//!         first: Some(10),
+//!     })
+//!     .await?;
+//! ```
+//!
+//! The generated code refers to `lineark_sdk` and `serde`, so the crate
+//! including it must depend on both.
+
+use crate::client::Client;
+use crate::error::LinearError;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// A GraphQL operation generated from a `.graphql` document.
+pub trait GraphQLDocument {
+    /// The operation's variables.
+    type Variables: Serialize;
+    /// The operation's `data`, shaped after its selection set.
+    type ResponseData: DeserializeOwned;
+    /// The operation name.
+    const OPERATION_NAME: &'static str;
+    /// The operation and the fragments it spreads.
+    const DOCUMENT: &'static str;
+}
+
+impl Client {
+    /// Execute a generated document operation and return its whole `data`.
+    pub async fn execute_document<D: GraphQLDocument>(
+        &self,
+        variables: &D::Variables,
+    ) -> Result<D::ResponseData, LinearError> {
+        let variables = serde_json::to_value(variables)
+            .map_err(|e| LinearError::Internal(format!("Failed to encode variables: {}", e)))?;
+        self.execute(D::DOCUMENT, variables, "").await
+    }
+}
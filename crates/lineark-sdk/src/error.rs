@@ -1,7 +1,11 @@
 //! Error types for the Linear SDK.
 //!
 //! [`LinearError`] covers authentication failures, HTTP transport errors,
-//! GraphQL-level errors, rate limiting, and more.
+//! GraphQL-level errors, rate limiting, and more. GraphQL errors carrying a
+//! recognized `extensions.type` or `extensions.code` are mapped to dedicated
+//! variants ([`LinearError::InvalidInput`], [`LinearError::NotFound`], …) so
+//! callers can branch on the kind of failure; anything else is reported as
+//! [`LinearError::GraphQL`] with the raw errors.
 
 use serde::{Deserialize, Serialize};
 use std::fmt;
@@ -16,6 +20,122 @@ pub struct GraphQLError {
     pub path: Option<Vec<serde_json::Value>>,
 }
 
+impl GraphQLError {
+    /// Linear's error type from `extensions.type`, e.g. `"invalid input"`.
+    pub fn error_type(&self) -> Option<&str> {
+        self.extension_str("type")
+    }
+
+    /// The error code from `extensions.code`, e.g. `"INPUT_ERROR"`.
+    pub fn code(&self) -> Option<&str> {
+        self.extension_str("code")
+    }
+
+    /// A message suitable for showing to end users, from
+    /// `extensions.userPresentableMessage`.
+    pub fn user_presentable_message(&self) -> Option<&str> {
+        self.extension_str("userPresentableMessage")
+    }
+
+    /// Whether Linear flagged the error as caused by the user
+    /// (`extensions.userError`) rather than by the request or the server.
+    pub fn is_user_error(&self) -> bool {
+        self.extensions
+            .as_ref()
+            .and_then(|e| e.get("userError"))
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false)
+    }
+
+    /// The input field a validation error refers to, from
+    /// `extensions.argumentPath` (joined with `.`) or the first entry of
+    /// `extensions.validationErrors`.
+    pub fn field(&self) -> Option<String> {
+        let ext = self.extensions.as_ref()?;
+        if let Some(path) = ext.get("argumentPath").and_then(|v| v.as_array()) {
+            let parts: Vec<String> = path
+                .iter()
+                .map(|p| match p {
+                    serde_json::Value::String(s) => s.clone(),
+                    other => other.to_string(),
+                })
+                .collect();
+            if !parts.is_empty() {
+                return Some(parts.join("."));
+            }
+        }
+        ext.get("validationErrors")
+            .and_then(|v| v.get(0))
+            .and_then(|v| v.get("property"))
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string())
+    }
+
+    /// The user-presentable message if there is one, else the raw message.
+    fn best_message(&self) -> String {
+        self.user_presentable_message()
+            .unwrap_or(&self.message)
+            .to_string()
+    }
+
+    fn extension_str(&self, key: &str) -> Option<&str> {
+        self.extensions.as_ref()?.get(key)?.as_str()
+    }
+}
+
+/// Which budget an API rate limit was exceeded for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RateLimitKind {
+    /// Too many requests in the current window.
+    Requests,
+    /// Too much query complexity in the current window, or a single query
+    /// above the complexity limit.
+    Complexity,
+    /// Linear didn't say which.
+    Unknown,
+}
+
+impl RateLimitKind {
+    /// Classify from the `X-RateLimit-*-Remaining` response headers.
+    pub(crate) fn from_headers(headers: &reqwest::header::HeaderMap) -> Self {
+        let exhausted = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| v.trim().parse::<i64>().ok())
+                .is_some_and(|n| n <= 0)
+        };
+        if exhausted("x-ratelimit-complexity-remaining") {
+            Self::Complexity
+        } else if exhausted("x-ratelimit-requests-remaining") {
+            Self::Requests
+        } else {
+            Self::Unknown
+        }
+    }
+
+    /// Classify from a GraphQL error's message and extensions.
+    fn from_graphql(error: &GraphQLError) -> Self {
+        let text = format!(
+            "{} {}",
+            error.message,
+            error
+                .extensions
+                .as_ref()
+                .map(|e| e.to_string())
+                .unwrap_or_default()
+        )
+        .to_lowercase();
+        if text.contains("complexity") {
+            Self::Complexity
+        } else if text.contains("request") {
+            Self::Requests
+        } else {
+            Self::Unknown
+        }
+    }
+}
+
 /// Errors that can occur when interacting with the Linear API.
 #[derive(Debug)]
 pub enum LinearError {
@@ -23,15 +143,32 @@ pub enum LinearError {
     Authentication(String),
     /// Request was rate-limited.
     RateLimited {
+        /// Seconds to wait, from the `Retry-After` header.
         retry_after: Option<f64>,
+        kind: RateLimitKind,
         message: String,
     },
     /// Invalid input (bad arguments to a mutation).
-    InvalidInput(String),
+    InvalidInput {
+        /// The offending input field, when Linear reports it.
+        field: Option<String>,
+        message: String,
+    },
     /// Forbidden (insufficient permissions).
     Forbidden(String),
+    /// A referenced entity does not exist or is not visible to the caller.
+    NotFound(String),
+    /// Linear refused the request for a reason meant to be shown to the user,
+    /// e.g. a workspace limit or a conflicting state.
+    UserError(String),
+    /// An insert was rejected because its ID is taken. Linear occasionally
+    /// reports this for IDs nothing was created with, too.
+    Conflict(String),
     /// Network or HTTP transport error.
     Network(reqwest::Error),
+    /// A subscription's WebSocket failed: the connection dropped or the
+    /// server broke the protocol.
+    WebSocket(String),
     /// GraphQL errors returned by the API.
     GraphQL {
         errors: Vec<GraphQLError>,
@@ -52,9 +189,20 @@ impl fmt::Display for LinearError {
         match self {
             Self::Authentication(msg) => write!(f, "Authentication error: {}", msg),
             Self::RateLimited { message, .. } => write!(f, "Rate limited: {}", message),
-            Self::InvalidInput(msg) => write!(f, "Invalid input: {}", msg),
+            Self::InvalidInput {
+                field: Some(field),
+                message,
+            } => write!(f, "Invalid input for {}: {}", field, message),
+            Self::InvalidInput {
+                field: None,
+                message,
+            } => write!(f, "Invalid input: {}", message),
             Self::Forbidden(msg) => write!(f, "Forbidden: {}", msg),
+            Self::NotFound(msg) => write!(f, "Not found: {}", msg),
+            Self::UserError(msg) => write!(f, "{}", msg),
+            Self::Conflict(msg) => write!(f, "Conflict: {}", msg),
             Self::Network(e) => write!(f, "Network error: {}", e),
+            Self::WebSocket(msg) => write!(f, "WebSocket error: {}", msg),
             Self::GraphQL { errors, query_name } => {
                 let msgs: Vec<String> = errors
                     .iter()
@@ -87,6 +235,77 @@ impl fmt::Display for LinearError {
     }
 }
 
+impl LinearError {
+    /// Map GraphQL errors from a response to the most specific variant, based
+    /// on the first error's `extensions.type` / `extensions.code`. Falls back
+    /// to [`LinearError::GraphQL`] with all errors.
+    pub(crate) fn from_graphql(errors: Vec<GraphQLError>, query_name: Option<String>) -> Self {
+        let Some(first) = errors.first() else {
+            return Self::GraphQL { errors, query_name };
+        };
+        let normalize = |s: &str| s.to_ascii_lowercase().replace([' ', '_', '-'], "");
+        let kind = first
+            .error_type()
+            .map(normalize)
+            .into_iter()
+            .chain(first.code().map(normalize))
+            .find(|k| {
+                matches!(
+                    k.as_str(),
+                    "authenticationerror"
+                        | "forbidden"
+                        | "ratelimited"
+                        | "entitynotfound"
+                        | "notfound"
+                        | "invalidinput"
+                        | "inputerror"
+                        | "baduserinput"
+                        | "usererror"
+                        | "conflict"
+                )
+            });
+
+        // Linear reports taken IDs as "conflict on insert" under varying codes.
+        if kind.as_deref() == Some("conflict")
+            || first
+                .message
+                .to_lowercase()
+                .starts_with("conflict on insert")
+        {
+            return Self::Conflict(first.best_message());
+        }
+
+        match kind.as_deref() {
+            Some("authenticationerror") => Self::Authentication(first.message.clone()),
+            Some("forbidden") => Self::Forbidden(first.best_message()),
+            Some("ratelimited") => Self::RateLimited {
+                retry_after: None,
+                kind: RateLimitKind::from_graphql(first),
+                message: first.message.clone(),
+            },
+            Some("entitynotfound" | "notfound") => Self::NotFound(first.best_message()),
+            // Linear reports missing entities as invalid input.
+            Some(_) if first.message.starts_with("Entity not found") => {
+                Self::NotFound(first.best_message())
+            }
+            Some("invalidinput" | "inputerror" | "baduserinput") => Self::InvalidInput {
+                field: first.field(),
+                message: first.best_message(),
+            },
+            Some(_) => Self::UserError(first.best_message()),
+            None if first.is_user_error() => Self::UserError(first.best_message()),
+            None => {
+                let msg = first.message.to_lowercase();
+                if msg.contains("authentication") || msg.contains("unauthorized") {
+                    Self::Authentication(first.message.clone())
+                } else {
+                    Self::GraphQL { errors, query_name }
+                }
+            }
+        }
+    }
+}
+
 impl std::error::Error for LinearError {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
@@ -102,6 +321,57 @@ impl From<reqwest::Error> for LinearError {
     }
 }
 
+/// Error parsing a string that is not a known value of a generated enum.
+///
+/// Returned by the enums' `FromStr` impls. Deserialization never fails this
+/// way: unknown values from the API become the enum's `Unknown(String)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseEnumError {
+    type_name: &'static str,
+    value: String,
+    expected: Vec<&'static str>,
+}
+
+impl ParseEnumError {
+    #[doc(hidden)]
+    pub fn new(type_name: &'static str, value: &str, expected: Vec<&'static str>) -> Self {
+        Self {
+            type_name,
+            value: value.to_string(),
+            expected,
+        }
+    }
+
+    /// The enum type that failed to parse, e.g. `"IssueRelationType"`.
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// The rejected input.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// The values the enum accepts.
+    pub fn expected(&self) -> &[&'static str] {
+        &self.expected
+    }
+}
+
+impl fmt::Display for ParseEnumError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid {} \"{}\" (expected one of: {})",
+            self.type_name,
+            self.value,
+            self.expected.join(", ")
+        )
+    }
+}
+
+impl std::error::Error for ParseEnumError {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -116,6 +386,7 @@ mod tests {
     fn display_rate_limited_error() {
         let err = LinearError::RateLimited {
             retry_after: Some(30.0),
+            kind: RateLimitKind::Requests,
             message: "Too many requests".to_string(),
         };
         assert_eq!(err.to_string(), "Rate limited: Too many requests");
@@ -123,8 +394,19 @@ mod tests {
 
     #[test]
     fn display_invalid_input_error() {
-        let err = LinearError::InvalidInput("bad field".to_string());
+        let err = LinearError::InvalidInput {
+            field: None,
+            message: "bad field".to_string(),
+        };
         assert_eq!(err.to_string(), "Invalid input: bad field");
+        let err = LinearError::InvalidInput {
+            field: Some("input.title".to_string()),
+            message: "must not be empty".to_string(),
+        };
+        assert_eq!(
+            err.to_string(),
+            "Invalid input for input.title: must not be empty"
+        );
     }
 
     #[test]
@@ -275,6 +557,184 @@ mod tests {
         );
     }
 
+    fn gql(message: &str, extensions: serde_json::Value) -> Vec<GraphQLError> {
+        vec![GraphQLError {
+            message: message.to_string(),
+            extensions: Some(extensions),
+            path: None,
+        }]
+    }
+
+    #[test]
+    fn classifies_invalid_input_with_field() {
+        let err = LinearError::from_graphql(
+            gql(
+                "Argument Validation Error",
+                serde_json::json!({
+                    "type": "invalid input",
+                    "code": "INPUT_ERROR",
+                    "userPresentableMessage": "Title must not be empty.",
+                    "argumentPath": ["input", "title"],
+                }),
+            ),
+            None,
+        );
+        match err {
+            LinearError::InvalidInput { field, message } => {
+                assert_eq!(field.as_deref(), Some("input.title"));
+                assert_eq!(message, "Title must not be empty.");
+            }
+            other => panic!("Expected InvalidInput, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn classifies_validation_errors_property() {
+        let err = LinearError::from_graphql(
+            gql(
+                "Argument Validation Error",
+                serde_json::json!({
+                    "code": "INPUT_ERROR",
+                    "validationErrors": [{"property": "priority", "constraints": {}}],
+                }),
+            ),
+            None,
+        );
+        assert!(matches!(
+            err,
+            LinearError::InvalidInput { field: Some(f), .. } if f == "priority"
+        ));
+    }
+
+    #[test]
+    fn classifies_entity_not_found() {
+        let err = LinearError::from_graphql(
+            gql(
+                "Entity not found: Issue",
+                serde_json::json!({
+                    "type": "invalid input",
+                    "userPresentableMessage": "Could not find referenced Issue.",
+                }),
+            ),
+            None,
+        );
+        match err {
+            LinearError::NotFound(msg) => assert_eq!(msg, "Could not find referenced Issue."),
+            other => panic!("Expected NotFound, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn classifies_forbidden_and_authentication() {
+        let err =
+            LinearError::from_graphql(gql("nope", serde_json::json!({"type": "forbidden"})), None);
+        assert!(matches!(err, LinearError::Forbidden(_)));
+        let err = LinearError::from_graphql(
+            gql(
+                "bad key",
+                serde_json::json!({"code": "AUTHENTICATION_ERROR"}),
+            ),
+            None,
+        );
+        assert!(matches!(err, LinearError::Authentication(m) if m == "bad key"));
+    }
+
+    #[test]
+    fn classifies_rate_limit_kind() {
+        let err = LinearError::from_graphql(
+            gql(
+                "Query too complex",
+                serde_json::json!({"code": "RATELIMITED", "meta": {"complexity": 12000}}),
+            ),
+            None,
+        );
+        assert!(matches!(
+            err,
+            LinearError::RateLimited {
+                kind: RateLimitKind::Complexity,
+                ..
+            }
+        ));
+        let err = LinearError::from_graphql(
+            gql(
+                "Too many requests",
+                serde_json::json!({"type": "ratelimited"}),
+            ),
+            None,
+        );
+        assert!(matches!(
+            err,
+            LinearError::RateLimited {
+                kind: RateLimitKind::Requests,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn rate_limit_kind_from_headers() {
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert("x-ratelimit-requests-remaining", "12".parse().unwrap());
+        assert_eq!(
+            RateLimitKind::from_headers(&headers),
+            RateLimitKind::Unknown
+        );
+        headers.insert("x-ratelimit-complexity-remaining", "0".parse().unwrap());
+        assert_eq!(
+            RateLimitKind::from_headers(&headers),
+            RateLimitKind::Complexity
+        );
+    }
+
+    #[test]
+    fn classifies_user_error() {
+        let err = LinearError::from_graphql(
+            gql(
+                "Limit reached",
+                serde_json::json!({
+                    "userError": true,
+                    "userPresentableMessage": "You have reached the free plan limit.",
+                }),
+            ),
+            None,
+        );
+        assert!(
+            matches!(err, LinearError::UserError(m) if m == "You have reached the free plan limit.")
+        );
+    }
+
+    #[test]
+    fn classifies_conflict() {
+        let err = LinearError::from_graphql(
+            gql(
+                "conflict on insert",
+                serde_json::json!({"code": "INTERNAL_SERVER_ERROR"}),
+            ),
+            None,
+        );
+        assert!(matches!(err, LinearError::Conflict(_)));
+        let err = LinearError::from_graphql(
+            gql("Duplicate", serde_json::json!({"type": "conflict"})),
+            None,
+        );
+        assert!(matches!(err, LinearError::Conflict(m) if m == "Duplicate"));
+    }
+
+    #[test]
+    fn unrecognized_errors_stay_graphql() {
+        let err = LinearError::from_graphql(
+            gql(
+                "Cannot query field",
+                serde_json::json!({"code": "GRAPHQL_VALIDATION_FAILED"}),
+            ),
+            Some("Viewer".to_string()),
+        );
+        assert!(matches!(
+            err,
+            LinearError::GraphQL { query_name: Some(n), .. } if n == "Viewer"
+        ));
+    }
+
     #[test]
     fn network_error_has_source() {
         // We can't easily construct a reqwest::Error directly, but we can verify
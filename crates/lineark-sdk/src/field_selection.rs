@@ -65,6 +65,139 @@ impl<T: GraphQLFields> GraphQLFields for Vec<T> {
     }
 }
 
+/// Build the selection of an interface or union: `__typename` plus an inline
+/// fragment per `(type name, selection)` pair. Types with nothing to select
+/// are left out, since an empty fragment is invalid GraphQL.
+///
+/// ```
+/// use lineark_sdk::field_selection::inline_fragments;
+///
+/// assert_eq!(
+///     inline_fragments(&[("Issue", "id title".into()), ("Team", String::new())]),
+///     "__typename ... on Issue { id title }"
+/// );
+/// ```
+pub fn inline_fragments(fragments: &[(&str, String)]) -> String {
+    let mut selection = String::from("__typename");
+    for (type_name, fields) in fragments {
+        if !fields.is_empty() {
+            selection.push_str(&format!(" ... on {} {{ {} }}", type_name, fields));
+        }
+    }
+    selection
+}
+
+/// Split variable declarations out of a selection.
+///
+/// Nested field arguments may declare the variable they read inline, as
+/// `$name: Type` (see `#[graphql(args(...))]` on the derive). GraphQL only
+/// allows declarations on the operation, so this returns the selection with
+/// each `$name: Type` reduced to `$name`, along with each distinct
+/// declaration, in order of first appearance.
+pub(crate) fn hoist_variables(selection: &str) -> (String, Vec<String>) {
+    let chars: Vec<char> = selection.chars().collect();
+    let mut out = String::with_capacity(selection.len());
+    let mut defs: Vec<String> = Vec::new();
+    let mut in_string = false;
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        out.push(c);
+        i += 1;
+        if in_string {
+            match c {
+                '\\' if i < chars.len() => {
+                    out.push(chars[i]);
+                    i += 1;
+                }
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+        if c == '"' {
+            in_string = true;
+            continue;
+        }
+        if c != '$' {
+            continue;
+        }
+        let name_end = (i..chars.len())
+            .find(|&j| !(chars[j].is_alphanumeric() || chars[j] == '_'))
+            .unwrap_or(chars.len());
+        let name: String = chars[i..name_end].iter().collect();
+        out.push_str(&name);
+        i = name_end;
+        // A `:` after a variable reference can only start its type.
+        let colon = (i..chars.len()).find(|&j| !chars[j].is_whitespace());
+        if let Some(colon) = colon.filter(|&j| chars[j] == ':') {
+            let type_start = (colon + 1..chars.len())
+                .find(|&j| !chars[j].is_whitespace())
+                .unwrap_or(chars.len());
+            let type_end = (type_start..chars.len())
+                .find(|&j| {
+                    !(chars[j].is_alphanumeric() || matches!(chars[j], '_' | '!' | '[' | ']'))
+                })
+                .unwrap_or(chars.len());
+            let ty: String = chars[type_start..type_end].iter().collect();
+            let def = format!("${}: {}", name, ty);
+            if !defs.contains(&def) {
+                defs.push(def);
+            }
+            i = type_end;
+        }
+    }
+    (out, defs)
+}
+
+/// The variable definitions of an operation declaring `declared` whose
+/// `selection` may declare more inline, and the selection with those inline
+/// declarations removed (see [`hoist_variables`]).
+///
+/// Fails when a nested field declares a variable the operation already
+/// declares, or when two nested fields declare one with different types:
+/// either would define the same variable twice.
+pub(crate) fn declare_variables(
+    declared: &str,
+    selection: &str,
+) -> Result<(String, String), String> {
+    let (selection, hoisted) = hoist_variables(selection);
+    let declared_names: Vec<&str> = declared
+        .split('$')
+        .skip(1)
+        .map(|rest| {
+            let end = rest
+                .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+                .unwrap_or(rest.len());
+            &rest[..end]
+        })
+        .collect();
+    let mut hoisted_names: Vec<(&str, &str)> = Vec::new();
+    for def in &hoisted {
+        let (name, ty) = def[1..].split_once(": ").unwrap_or((&def[1..], ""));
+        if declared_names.contains(&name) {
+            return Err(format!(
+                "variable `${}` is declared by the operation and by a nested field",
+                name
+            ));
+        }
+        // `hoist_variables` already merged identical declarations.
+        if let Some((_, other)) = hoisted_names.iter().find(|(n, _)| *n == name) {
+            return Err(format!(
+                "variable `${}` is declared by nested fields as both `{}` and `{}`",
+                name, other, ty
+            ));
+        }
+        hoisted_names.push((name, ty));
+    }
+    let variable_defs = std::iter::once(declared)
+        .chain(hoisted.iter().map(String::as_str))
+        .filter(|d| !d.is_empty())
+        .collect::<Vec<_>>()
+        .join(", ");
+    Ok((selection, variable_defs))
+}
+
 /// Marker trait for compile-time field type compatibility.
 ///
 /// Validates that a full type's field type `Self` is compatible with a custom
@@ -119,6 +252,42 @@ mod tests {
         );
     }
 
+    #[test]
+    fn hoists_inline_variable_declarations() {
+        let (selection, defs) = hoist_variables(
+            r#"id comments(first: $n: Int!, filter: $f: CommentFilter) { id } children(first: $n: Int!, filter: { title: { eq: "$x: Int" } }) { id }"#,
+        );
+        assert_eq!(
+            selection,
+            r#"id comments(first: $n, filter: $f) { id } children(first: $n, filter: { title: { eq: "$x: Int" } }) { id }"#
+        );
+        assert_eq!(defs, ["$n: Int!", "$f: CommentFilter"]);
+
+        let plain = "issue(id: $id) { id }";
+        assert_eq!(hoist_variables(plain), (plain.to_string(), Vec::new()));
+    }
+
+    #[test]
+    fn declared_variables_must_not_clash() {
+        assert_eq!(
+            declare_variables(
+                "$id: String!",
+                "issue(id: $id) { comments(first: $n: Int) { id } }"
+            ),
+            Ok((
+                "issue(id: $id) { comments(first: $n) { id } }".to_string(),
+                "$id: String!, $n: Int".to_string()
+            ))
+        );
+        let err = declare_variables(
+            "$filter: IssueFilter, $first: Int",
+            "issues(filter: $filter, first: $first) { nodes { comments(filter: $filter: CommentFilter) { nodes { id } } } }",
+        )
+        .unwrap_err();
+        assert!(err.contains("`$filter`"), "{err}");
+        assert!(declare_variables("", "a(x: $x: Int) { id } b(x: $x: String) { id }").is_err());
+    }
+
     #[test]
     fn option_preserves_full_type() {
         // Compile-time proof: Option/Vec<FakeIssue>::FullType == FakeFullType
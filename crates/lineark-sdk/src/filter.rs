@@ -0,0 +1,198 @@
+//! Composing filters.
+//!
+//! Codegen gives every generated `*Filter` and `*Comparator` input a
+//! constructor per field, so filters are built with compile-time checking
+//! instead of JSON:
+//!
+//! ```
+//! use lineark_sdk::filter::ago;
+//! use lineark_sdk::generated::inputs::*;
+//!
+//! let filter = IssueFilter::team(TeamFilter::key(StringComparator::eq("ENG")))
+//!     .and(IssueFilter::state(WorkflowStateFilter::type_(StringComparator::nin([
+//!         "completed",
+//!         "canceled",
+//!     ]))))
+//!     .and(IssueFilter::updated_at(DateComparator::gte(ago(chrono::Duration::weeks(2)))))
+//!     .and(IssueFilter::assignee(NullableUserFilter::id(IDComparator::neq("me"))));
+//! ```
+//!
+//! Filters with `and`/`or` fields also get `all(..)` and `any(..)` to combine
+//! several at once without nesting. With no filters, `all` gives the empty
+//! filter, which matches everything, and `any` gives `None`, since nothing
+//! should match.
+//!
+//! There is no `not` over a whole filter: none of the schema's filter inputs
+//! has a `not` field, so a negated filter could not be sent. Negation is
+//! available on comparators instead. Those whose every constraint has a
+//! negated counterpart get `negate()` (`eq` ↔ `neq`, `in` ↔ `nin`, `lt` ↔
+//! `gte`, …), which returns `None` unless exactly one constraint is set:
+//!
+//! ```
+//! use lineark_sdk::generated::inputs::*;
+//!
+//! let not_mine = IDComparator::eq("me").negate().unwrap();
+//! let filter = IssueFilter::assignee(NullableUserFilter::id(not_mine));
+//! ```
+//!
+//! Date comparators accept ISO 8601 durations relative to now, built by
+//! [`ago`] and [`from_now`].
+
+use crate::field_update::MaybeUndefined;
+use crate::generated::inputs::{DateComparator, NullableDateComparator};
+
+/// A point in time `duration` before now, for date comparators.
+pub fn ago(duration: chrono::Duration) -> serde_json::Value {
+    iso8601_duration(-duration).into()
+}
+
+/// A point in time `duration` after now, for date comparators.
+pub fn from_now(duration: chrono::Duration) -> serde_json::Value {
+    iso8601_duration(duration).into()
+}
+
+/// `P14D`, `-PT90S`, …: whole days when possible, seconds otherwise.
+fn iso8601_duration(duration: chrono::Duration) -> String {
+    let sign = if duration < chrono::Duration::zero() {
+        "-"
+    } else {
+        ""
+    };
+    let seconds = duration.num_seconds().unsigned_abs();
+    if seconds.is_multiple_of(86_400) {
+        format!("{}P{}D", sign, seconds / 86_400)
+    } else {
+        format!("{}PT{}S", sign, seconds)
+    }
+}
+
+macro_rules! date_helpers {
+    ($($comparator:ident),*) => {
+        $(impl $comparator {
+            /// Dates within the last `duration`.
+            pub fn within_last(duration: chrono::Duration) -> Self {
+                Self::gte(ago(duration))
+            }
+
+            /// Dates more than `duration` ago.
+            pub fn older_than(duration: chrono::Duration) -> Self {
+                Self::lt(ago(duration))
+            }
+
+            /// Dates from `start` to `end`, inclusive.
+            pub fn between(
+                start: impl Into<serde_json::Value>,
+                end: impl Into<serde_json::Value>,
+            ) -> Self {
+                Self {
+                    gte: MaybeUndefined::Value(start.into()),
+                    lte: MaybeUndefined::Value(end.into()),
+                    ..Default::default()
+                }
+            }
+        })*
+    };
+}
+
+date_helpers!(DateComparator, NullableDateComparator);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generated::inputs::*;
+    use chrono::Duration;
+
+    #[test]
+    fn durations_are_iso8601() {
+        assert_eq!(iso8601_duration(Duration::weeks(2)), "P14D");
+        assert_eq!(iso8601_duration(-Duration::days(1)), "-P1D");
+        assert_eq!(iso8601_duration(Duration::minutes(90)), "PT5400S");
+        assert_eq!(ago(Duration::hours(1)), "-PT3600S");
+    }
+
+    #[test]
+    fn composed_filter_serializes_like_hand_written_json() {
+        let filter = IssueFilter::team(TeamFilter::id(IDComparator::eq("t1")))
+            .and(IssueFilter::state(WorkflowStateFilter::type_(
+                StringComparator::nin(["completed", "canceled"]),
+            )))
+            .or(IssueFilter::assignee(NullableUserFilter::null(true)));
+        assert_eq!(
+            serde_json::to_value(&filter).unwrap(),
+            serde_json::json!({ "or": [
+                { "and": [
+                    { "team": { "id": { "eq": "t1" } } },
+                    { "state": { "type": { "nin": ["completed", "canceled"] } } },
+                ]},
+                { "assignee": { "null": true } },
+            ]})
+        );
+    }
+
+    #[test]
+    fn all_and_any_do_not_nest_single_filters() {
+        let only = IssueFilter::all([IssueFilter::number(NumberComparator::eq(7))]);
+        assert_eq!(
+            serde_json::to_value(&only).unwrap(),
+            serde_json::json!({ "number": { "eq": 7.0 } })
+        );
+        let any = IssueFilter::any([
+            IssueFilter::number(NumberComparator::lt(3)),
+            IssueFilter::number(NumberComparator::gt(9)),
+        ])
+        .unwrap();
+        assert_eq!(
+            serde_json::to_value(&any).unwrap(),
+            serde_json::json!({ "or": [{ "number": { "lt": 3.0 } }, { "number": { "gt": 9.0 } }] })
+        );
+    }
+
+    #[test]
+    fn all_of_nothing_matches_everything_and_any_of_nothing_is_none() {
+        let none: [IssueFilter; 0] = [];
+        assert_eq!(
+            serde_json::to_value(IssueFilter::all(none.clone())).unwrap(),
+            serde_json::json!({})
+        );
+        assert!(IssueFilter::any(none).is_none());
+    }
+
+    #[test]
+    fn negation_swaps_constraints() {
+        let not_done = IDComparator::in_(["completed", "canceled"]).negate();
+        assert_eq!(
+            serde_json::to_value(&not_done).unwrap(),
+            serde_json::json!({ "nin": ["completed", "canceled"] })
+        );
+        let not_null = NullableDateComparator::null(true).negate();
+        assert_eq!(
+            serde_json::to_value(&not_null).unwrap(),
+            serde_json::json!({ "null": false })
+        );
+        let recent = DateComparator::older_than(Duration::days(3)).negate();
+        assert_eq!(
+            serde_json::to_value(&recent).unwrap(),
+            serde_json::json!({ "gte": "-P3D" })
+        );
+    }
+
+    #[test]
+    fn negation_needs_exactly_one_constraint() {
+        assert!(DateComparator::between("2024-01-01", "2024-02-01")
+            .negate()
+            .is_none());
+        assert!(IDComparator::default().negate().is_none());
+    }
+
+    #[test]
+    fn between_sets_both_bounds() {
+        assert_eq!(
+            serde_json::to_value(NullableDateComparator::between(
+                "2024-01-01",
+                from_now(Duration::days(7))
+            ))
+            .unwrap(),
+            serde_json::json!({ "gte": "2024-01-01", "lte": "P7D" })
+        );
+    }
+}
@@ -0,0 +1,13005 @@
+//! Generated `impl blocking::Client` methods and blocking query builders.
+//!
+//! Each method drives the corresponding async operation to completion
+//! on the blocking client's runtime. Only compiled with the `blocking`
+//! feature.
+//!
+//! Generated by lineark-codegen — do not edit.
+#![allow(clippy::too_many_arguments)]
+use super::enums::*;
+use super::inputs::*;
+use crate::blocking::Client;
+use crate::error::LinearError;
+use crate::field_selection::GraphQLFields;
+use crate::operation::Operation;
+use crate::pagination::Connection;
+use serde::de::DeserializeOwned;
+/// Blocking variant of [`WorkflowStatesQueryBuilder`](crate::generated::queries::WorkflowStatesQueryBuilder).
+#[must_use]
+pub struct WorkflowStatesQueryBuilder<'a, T> {
+    inner: crate::generated::queries::WorkflowStatesQueryBuilder<'a, T>,
+    client: &'a Client,
+}
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::WorkflowState>>
+    WorkflowStatesQueryBuilder<'a, T>
+{
+    pub fn filter(self, value: WorkflowStateFilter) -> Self {
+        Self {
+            inner: self.inner.filter(value),
+            client: self.client,
+        }
+    }
+    pub fn before(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.before(value),
+            client: self.client,
+        }
+    }
+    pub fn after(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.after(value),
+            client: self.client,
+        }
+    }
+    pub fn first(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.first(value),
+            client: self.client,
+        }
+    }
+    pub fn last(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.last(value),
+            client: self.client,
+        }
+    }
+    pub fn include_archived(self, value: bool) -> Self {
+        Self {
+            inner: self.inner.include_archived(value),
+            client: self.client,
+        }
+    }
+    pub fn order_by(self, value: PaginationOrderBy) -> Self {
+        Self {
+            inner: self.inner.order_by(value),
+            client: self.client,
+        }
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        Self {
+            inner: self.inner.variable(name, value),
+            client: self.client,
+        }
+    }
+    /// Build the query without sending it.
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        self.inner.into_operation()
+    }
+    pub fn send(self) -> Result<Connection<T>, LinearError> {
+        self.client.block_on(self.inner.send())
+    }
+    /// Fetch every page and collect all nodes into a `Vec`.
+    pub fn all(self) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.all())
+    }
+    /// Fetch pages until `max` nodes have been collected.
+    pub fn take_all(self, max: usize) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.take_all(max))
+    }
+}
+/// Blocking variant of [`WebhooksQueryBuilder`](crate::generated::queries::WebhooksQueryBuilder).
+///
+/// Requires the `webhooks` feature.
+#[cfg(feature = "webhooks")]
+#[must_use]
+pub struct WebhooksQueryBuilder<'a, T> {
+    inner: crate::generated::queries::WebhooksQueryBuilder<'a, T>,
+    client: &'a Client,
+}
+#[cfg(feature = "webhooks")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Webhook>>
+    WebhooksQueryBuilder<'a, T>
+{
+    pub fn before(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.before(value),
+            client: self.client,
+        }
+    }
+    pub fn after(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.after(value),
+            client: self.client,
+        }
+    }
+    pub fn first(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.first(value),
+            client: self.client,
+        }
+    }
+    pub fn last(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.last(value),
+            client: self.client,
+        }
+    }
+    pub fn include_archived(self, value: bool) -> Self {
+        Self {
+            inner: self.inner.include_archived(value),
+            client: self.client,
+        }
+    }
+    pub fn order_by(self, value: PaginationOrderBy) -> Self {
+        Self {
+            inner: self.inner.order_by(value),
+            client: self.client,
+        }
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        Self {
+            inner: self.inner.variable(name, value),
+            client: self.client,
+        }
+    }
+    /// Build the query without sending it.
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        self.inner.into_operation()
+    }
+    pub fn send(self) -> Result<Connection<T>, LinearError> {
+        self.client.block_on(self.inner.send())
+    }
+    /// Fetch every page and collect all nodes into a `Vec`.
+    pub fn all(self) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.all())
+    }
+    /// Fetch pages until `max` nodes have been collected.
+    pub fn take_all(self, max: usize) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.take_all(max))
+    }
+}
+/// Blocking variant of [`UsersQueryBuilder`](crate::generated::queries::UsersQueryBuilder).
+#[must_use]
+pub struct UsersQueryBuilder<'a, T> {
+    inner: crate::generated::queries::UsersQueryBuilder<'a, T>,
+    client: &'a Client,
+}
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::User>>
+    UsersQueryBuilder<'a, T>
+{
+    pub fn filter(self, value: UserFilter) -> Self {
+        Self {
+            inner: self.inner.filter(value),
+            client: self.client,
+        }
+    }
+    pub fn include_disabled(self, value: bool) -> Self {
+        Self {
+            inner: self.inner.include_disabled(value),
+            client: self.client,
+        }
+    }
+    pub fn before(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.before(value),
+            client: self.client,
+        }
+    }
+    pub fn after(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.after(value),
+            client: self.client,
+        }
+    }
+    pub fn first(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.first(value),
+            client: self.client,
+        }
+    }
+    pub fn last(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.last(value),
+            client: self.client,
+        }
+    }
+    pub fn include_archived(self, value: bool) -> Self {
+        Self {
+            inner: self.inner.include_archived(value),
+            client: self.client,
+        }
+    }
+    pub fn order_by(self, value: PaginationOrderBy) -> Self {
+        Self {
+            inner: self.inner.order_by(value),
+            client: self.client,
+        }
+    }
+    pub fn sort(self, value: UserSortInput) -> Self {
+        Self {
+            inner: self.inner.sort(value),
+            client: self.client,
+        }
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        Self {
+            inner: self.inner.variable(name, value),
+            client: self.client,
+        }
+    }
+    /// Build the query without sending it.
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        self.inner.into_operation()
+    }
+    pub fn send(self) -> Result<Connection<T>, LinearError> {
+        self.client.block_on(self.inner.send())
+    }
+    /// Fetch every page and collect all nodes into a `Vec`.
+    pub fn all(self) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.all())
+    }
+    /// Fetch pages until `max` nodes have been collected.
+    pub fn take_all(self, max: usize) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.take_all(max))
+    }
+}
+/// Blocking variant of [`TriageResponsibilitiesQueryBuilder`](crate::generated::queries::TriageResponsibilitiesQueryBuilder).
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+#[must_use]
+pub struct TriageResponsibilitiesQueryBuilder<'a, T> {
+    inner: crate::generated::queries::TriageResponsibilitiesQueryBuilder<'a, T>,
+    client: &'a Client,
+}
+#[cfg(feature = "teams")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::TriageResponsibility>>
+    TriageResponsibilitiesQueryBuilder<'a, T>
+{
+    pub fn before(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.before(value),
+            client: self.client,
+        }
+    }
+    pub fn after(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.after(value),
+            client: self.client,
+        }
+    }
+    pub fn first(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.first(value),
+            client: self.client,
+        }
+    }
+    pub fn last(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.last(value),
+            client: self.client,
+        }
+    }
+    pub fn include_archived(self, value: bool) -> Self {
+        Self {
+            inner: self.inner.include_archived(value),
+            client: self.client,
+        }
+    }
+    pub fn order_by(self, value: PaginationOrderBy) -> Self {
+        Self {
+            inner: self.inner.order_by(value),
+            client: self.client,
+        }
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        Self {
+            inner: self.inner.variable(name, value),
+            client: self.client,
+        }
+    }
+    /// Build the query without sending it.
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        self.inner.into_operation()
+    }
+    pub fn send(self) -> Result<Connection<T>, LinearError> {
+        self.client.block_on(self.inner.send())
+    }
+    /// Fetch every page and collect all nodes into a `Vec`.
+    pub fn all(self) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.all())
+    }
+    /// Fetch pages until `max` nodes have been collected.
+    pub fn take_all(self, max: usize) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.take_all(max))
+    }
+}
+/// Blocking variant of [`TimeSchedulesQueryBuilder`](crate::generated::queries::TimeSchedulesQueryBuilder).
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+#[must_use]
+pub struct TimeSchedulesQueryBuilder<'a, T> {
+    inner: crate::generated::queries::TimeSchedulesQueryBuilder<'a, T>,
+    client: &'a Client,
+}
+#[cfg(feature = "teams")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::TimeSchedule>>
+    TimeSchedulesQueryBuilder<'a, T>
+{
+    pub fn before(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.before(value),
+            client: self.client,
+        }
+    }
+    pub fn after(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.after(value),
+            client: self.client,
+        }
+    }
+    pub fn first(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.first(value),
+            client: self.client,
+        }
+    }
+    pub fn last(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.last(value),
+            client: self.client,
+        }
+    }
+    pub fn include_archived(self, value: bool) -> Self {
+        Self {
+            inner: self.inner.include_archived(value),
+            client: self.client,
+        }
+    }
+    pub fn order_by(self, value: PaginationOrderBy) -> Self {
+        Self {
+            inner: self.inner.order_by(value),
+            client: self.client,
+        }
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        Self {
+            inner: self.inner.variable(name, value),
+            client: self.client,
+        }
+    }
+    /// Build the query without sending it.
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        self.inner.into_operation()
+    }
+    pub fn send(self) -> Result<Connection<T>, LinearError> {
+        self.client.block_on(self.inner.send())
+    }
+    /// Fetch every page and collect all nodes into a `Vec`.
+    pub fn all(self) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.all())
+    }
+    /// Fetch pages until `max` nodes have been collected.
+    pub fn take_all(self, max: usize) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.take_all(max))
+    }
+}
+/// Blocking variant of [`ProjectsQueryBuilder`](crate::generated::queries::ProjectsQueryBuilder).
+#[must_use]
+pub struct ProjectsQueryBuilder<'a, T> {
+    inner: crate::generated::queries::ProjectsQueryBuilder<'a, T>,
+    client: &'a Client,
+}
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Project>>
+    ProjectsQueryBuilder<'a, T>
+{
+    pub fn filter(self, value: ProjectFilter) -> Self {
+        Self {
+            inner: self.inner.filter(value),
+            client: self.client,
+        }
+    }
+    pub fn before(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.before(value),
+            client: self.client,
+        }
+    }
+    pub fn after(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.after(value),
+            client: self.client,
+        }
+    }
+    pub fn first(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.first(value),
+            client: self.client,
+        }
+    }
+    pub fn last(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.last(value),
+            client: self.client,
+        }
+    }
+    pub fn include_archived(self, value: bool) -> Self {
+        Self {
+            inner: self.inner.include_archived(value),
+            client: self.client,
+        }
+    }
+    pub fn order_by(self, value: PaginationOrderBy) -> Self {
+        Self {
+            inner: self.inner.order_by(value),
+            client: self.client,
+        }
+    }
+    pub fn sort(self, value: ProjectSortInput) -> Self {
+        Self {
+            inner: self.inner.sort(value),
+            client: self.client,
+        }
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        Self {
+            inner: self.inner.variable(name, value),
+            client: self.client,
+        }
+    }
+    /// Build the query without sending it.
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        self.inner.into_operation()
+    }
+    pub fn send(self) -> Result<Connection<T>, LinearError> {
+        self.client.block_on(self.inner.send())
+    }
+    /// Fetch every page and collect all nodes into a `Vec`.
+    pub fn all(self) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.all())
+    }
+    /// Fetch pages until `max` nodes have been collected.
+    pub fn take_all(self, max: usize) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.take_all(max))
+    }
+}
+/// Blocking variant of [`ProjectFilterSuggestionQueryBuilder`](crate::generated::queries::ProjectFilterSuggestionQueryBuilder).
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+#[must_use]
+pub struct ProjectFilterSuggestionQueryBuilder<'a, T> {
+    inner: crate::generated::queries::ProjectFilterSuggestionQueryBuilder<'a, T>,
+    client: &'a Client,
+}
+#[cfg(feature = "projects")]
+impl<
+        'a,
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::ProjectFilterSuggestionPayload>,
+    > ProjectFilterSuggestionQueryBuilder<'a, T>
+{
+    pub fn team_id(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.team_id(value),
+            client: self.client,
+        }
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        Self {
+            inner: self.inner.variable(name, value),
+            client: self.client,
+        }
+    }
+    /// Build the query without sending it.
+    pub fn into_operation(self) -> Operation<T> {
+        self.inner.into_operation()
+    }
+    pub fn send(self) -> Result<T, LinearError> {
+        self.client.block_on(self.inner.send())
+    }
+}
+/// Blocking variant of [`TeamsQueryBuilder`](crate::generated::queries::TeamsQueryBuilder).
+#[must_use]
+pub struct TeamsQueryBuilder<'a, T> {
+    inner: crate::generated::queries::TeamsQueryBuilder<'a, T>,
+    client: &'a Client,
+}
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Team>>
+    TeamsQueryBuilder<'a, T>
+{
+    pub fn filter(self, value: TeamFilter) -> Self {
+        Self {
+            inner: self.inner.filter(value),
+            client: self.client,
+        }
+    }
+    pub fn before(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.before(value),
+            client: self.client,
+        }
+    }
+    pub fn after(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.after(value),
+            client: self.client,
+        }
+    }
+    pub fn first(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.first(value),
+            client: self.client,
+        }
+    }
+    pub fn last(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.last(value),
+            client: self.client,
+        }
+    }
+    pub fn include_archived(self, value: bool) -> Self {
+        Self {
+            inner: self.inner.include_archived(value),
+            client: self.client,
+        }
+    }
+    pub fn order_by(self, value: PaginationOrderBy) -> Self {
+        Self {
+            inner: self.inner.order_by(value),
+            client: self.client,
+        }
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        Self {
+            inner: self.inner.variable(name, value),
+            client: self.client,
+        }
+    }
+    /// Build the query without sending it.
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        self.inner.into_operation()
+    }
+    pub fn send(self) -> Result<Connection<T>, LinearError> {
+        self.client.block_on(self.inner.send())
+    }
+    /// Fetch every page and collect all nodes into a `Vec`.
+    pub fn all(self) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.all())
+    }
+    /// Fetch pages until `max` nodes have been collected.
+    pub fn take_all(self, max: usize) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.take_all(max))
+    }
+}
+/// Blocking variant of [`AdministrableTeamsQueryBuilder`](crate::generated::queries::AdministrableTeamsQueryBuilder).
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+#[must_use]
+pub struct AdministrableTeamsQueryBuilder<'a, T> {
+    inner: crate::generated::queries::AdministrableTeamsQueryBuilder<'a, T>,
+    client: &'a Client,
+}
+#[cfg(feature = "teams")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Team>>
+    AdministrableTeamsQueryBuilder<'a, T>
+{
+    pub fn filter(self, value: TeamFilter) -> Self {
+        Self {
+            inner: self.inner.filter(value),
+            client: self.client,
+        }
+    }
+    pub fn before(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.before(value),
+            client: self.client,
+        }
+    }
+    pub fn after(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.after(value),
+            client: self.client,
+        }
+    }
+    pub fn first(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.first(value),
+            client: self.client,
+        }
+    }
+    pub fn last(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.last(value),
+            client: self.client,
+        }
+    }
+    pub fn include_archived(self, value: bool) -> Self {
+        Self {
+            inner: self.inner.include_archived(value),
+            client: self.client,
+        }
+    }
+    pub fn order_by(self, value: PaginationOrderBy) -> Self {
+        Self {
+            inner: self.inner.order_by(value),
+            client: self.client,
+        }
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        Self {
+            inner: self.inner.variable(name, value),
+            client: self.client,
+        }
+    }
+    /// Build the query without sending it.
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        self.inner.into_operation()
+    }
+    pub fn send(self) -> Result<Connection<T>, LinearError> {
+        self.client.block_on(self.inner.send())
+    }
+    /// Fetch every page and collect all nodes into a `Vec`.
+    pub fn all(self) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.all())
+    }
+    /// Fetch pages until `max` nodes have been collected.
+    pub fn take_all(self, max: usize) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.take_all(max))
+    }
+}
+/// Blocking variant of [`TeamMembershipsQueryBuilder`](crate::generated::queries::TeamMembershipsQueryBuilder).
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+#[must_use]
+pub struct TeamMembershipsQueryBuilder<'a, T> {
+    inner: crate::generated::queries::TeamMembershipsQueryBuilder<'a, T>,
+    client: &'a Client,
+}
+#[cfg(feature = "teams")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::TeamMembership>>
+    TeamMembershipsQueryBuilder<'a, T>
+{
+    pub fn before(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.before(value),
+            client: self.client,
+        }
+    }
+    pub fn after(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.after(value),
+            client: self.client,
+        }
+    }
+    pub fn first(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.first(value),
+            client: self.client,
+        }
+    }
+    pub fn last(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.last(value),
+            client: self.client,
+        }
+    }
+    pub fn include_archived(self, value: bool) -> Self {
+        Self {
+            inner: self.inner.include_archived(value),
+            client: self.client,
+        }
+    }
+    pub fn order_by(self, value: PaginationOrderBy) -> Self {
+        Self {
+            inner: self.inner.order_by(value),
+            client: self.client,
+        }
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        Self {
+            inner: self.inner.variable(name, value),
+            client: self.client,
+        }
+    }
+    /// Build the query without sending it.
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        self.inner.into_operation()
+    }
+    pub fn send(self) -> Result<Connection<T>, LinearError> {
+        self.client.block_on(self.inner.send())
+    }
+    /// Fetch every page and collect all nodes into a `Vec`.
+    pub fn all(self) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.all())
+    }
+    /// Fetch pages until `max` nodes have been collected.
+    pub fn take_all(self, max: usize) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.take_all(max))
+    }
+}
+/// Blocking variant of [`SemanticSearchQueryBuilder`](crate::generated::queries::SemanticSearchQueryBuilder).
+///
+/// Requires the `agents` feature.
+#[cfg(feature = "agents")]
+#[must_use]
+pub struct SemanticSearchQueryBuilder<'a, T> {
+    inner: crate::generated::queries::SemanticSearchQueryBuilder<'a, T>,
+    client: &'a Client,
+}
+#[cfg(feature = "agents")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::SemanticSearchPayload>>
+    SemanticSearchQueryBuilder<'a, T>
+{
+    pub fn types(self, value: SemanticSearchResultType) -> Self {
+        Self {
+            inner: self.inner.types(value),
+            client: self.client,
+        }
+    }
+    pub fn max_results(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.max_results(value),
+            client: self.client,
+        }
+    }
+    pub fn include_archived(self, value: bool) -> Self {
+        Self {
+            inner: self.inner.include_archived(value),
+            client: self.client,
+        }
+    }
+    pub fn filters(self, value: SemanticSearchFilters) -> Self {
+        Self {
+            inner: self.inner.filters(value),
+            client: self.client,
+        }
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        Self {
+            inner: self.inner.variable(name, value),
+            client: self.client,
+        }
+    }
+    /// Build the query without sending it.
+    pub fn into_operation(self) -> Operation<T> {
+        self.inner.into_operation()
+    }
+    pub fn send(self) -> Result<T, LinearError> {
+        self.client.block_on(self.inner.send())
+    }
+}
+/// Blocking variant of [`SearchDocumentsQueryBuilder`](crate::generated::queries::SearchDocumentsQueryBuilder).
+///
+/// Requires the `documents` feature.
+#[cfg(feature = "documents")]
+#[must_use]
+pub struct SearchDocumentsQueryBuilder<'a, T> {
+    inner: crate::generated::queries::SearchDocumentsQueryBuilder<'a, T>,
+    client: &'a Client,
+}
+#[cfg(feature = "documents")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::DocumentSearchResult>>
+    SearchDocumentsQueryBuilder<'a, T>
+{
+    pub fn before(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.before(value),
+            client: self.client,
+        }
+    }
+    pub fn after(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.after(value),
+            client: self.client,
+        }
+    }
+    pub fn first(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.first(value),
+            client: self.client,
+        }
+    }
+    pub fn last(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.last(value),
+            client: self.client,
+        }
+    }
+    pub fn include_archived(self, value: bool) -> Self {
+        Self {
+            inner: self.inner.include_archived(value),
+            client: self.client,
+        }
+    }
+    pub fn order_by(self, value: PaginationOrderBy) -> Self {
+        Self {
+            inner: self.inner.order_by(value),
+            client: self.client,
+        }
+    }
+    pub fn include_comments(self, value: bool) -> Self {
+        Self {
+            inner: self.inner.include_comments(value),
+            client: self.client,
+        }
+    }
+    pub fn team_id(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.team_id(value),
+            client: self.client,
+        }
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        Self {
+            inner: self.inner.variable(name, value),
+            client: self.client,
+        }
+    }
+    /// Build the query without sending it.
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        self.inner.into_operation()
+    }
+    pub fn send(self) -> Result<Connection<T>, LinearError> {
+        self.client.block_on(self.inner.send())
+    }
+    /// Fetch every page and collect all nodes into a `Vec`.
+    pub fn all(self) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.all())
+    }
+    /// Fetch pages until `max` nodes have been collected.
+    pub fn take_all(self, max: usize) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.take_all(max))
+    }
+}
+/// Blocking variant of [`SearchProjectsQueryBuilder`](crate::generated::queries::SearchProjectsQueryBuilder).
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+#[must_use]
+pub struct SearchProjectsQueryBuilder<'a, T> {
+    inner: crate::generated::queries::SearchProjectsQueryBuilder<'a, T>,
+    client: &'a Client,
+}
+#[cfg(feature = "projects")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::ProjectSearchResult>>
+    SearchProjectsQueryBuilder<'a, T>
+{
+    pub fn before(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.before(value),
+            client: self.client,
+        }
+    }
+    pub fn after(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.after(value),
+            client: self.client,
+        }
+    }
+    pub fn first(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.first(value),
+            client: self.client,
+        }
+    }
+    pub fn last(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.last(value),
+            client: self.client,
+        }
+    }
+    pub fn include_archived(self, value: bool) -> Self {
+        Self {
+            inner: self.inner.include_archived(value),
+            client: self.client,
+        }
+    }
+    pub fn order_by(self, value: PaginationOrderBy) -> Self {
+        Self {
+            inner: self.inner.order_by(value),
+            client: self.client,
+        }
+    }
+    pub fn include_comments(self, value: bool) -> Self {
+        Self {
+            inner: self.inner.include_comments(value),
+            client: self.client,
+        }
+    }
+    pub fn team_id(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.team_id(value),
+            client: self.client,
+        }
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        Self {
+            inner: self.inner.variable(name, value),
+            client: self.client,
+        }
+    }
+    /// Build the query without sending it.
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        self.inner.into_operation()
+    }
+    pub fn send(self) -> Result<Connection<T>, LinearError> {
+        self.client.block_on(self.inner.send())
+    }
+    /// Fetch every page and collect all nodes into a `Vec`.
+    pub fn all(self) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.all())
+    }
+    /// Fetch pages until `max` nodes have been collected.
+    pub fn take_all(self, max: usize) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.take_all(max))
+    }
+}
+/// Blocking variant of [`SearchIssuesQueryBuilder`](crate::generated::queries::SearchIssuesQueryBuilder).
+#[must_use]
+pub struct SearchIssuesQueryBuilder<'a, T> {
+    inner: crate::generated::queries::SearchIssuesQueryBuilder<'a, T>,
+    client: &'a Client,
+}
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::IssueSearchResult>>
+    SearchIssuesQueryBuilder<'a, T>
+{
+    pub fn filter(self, value: IssueFilter) -> Self {
+        Self {
+            inner: self.inner.filter(value),
+            client: self.client,
+        }
+    }
+    pub fn before(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.before(value),
+            client: self.client,
+        }
+    }
+    pub fn after(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.after(value),
+            client: self.client,
+        }
+    }
+    pub fn first(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.first(value),
+            client: self.client,
+        }
+    }
+    pub fn last(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.last(value),
+            client: self.client,
+        }
+    }
+    pub fn include_archived(self, value: bool) -> Self {
+        Self {
+            inner: self.inner.include_archived(value),
+            client: self.client,
+        }
+    }
+    pub fn order_by(self, value: PaginationOrderBy) -> Self {
+        Self {
+            inner: self.inner.order_by(value),
+            client: self.client,
+        }
+    }
+    pub fn include_comments(self, value: bool) -> Self {
+        Self {
+            inner: self.inner.include_comments(value),
+            client: self.client,
+        }
+    }
+    pub fn team_id(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.team_id(value),
+            client: self.client,
+        }
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        Self {
+            inner: self.inner.variable(name, value),
+            client: self.client,
+        }
+    }
+    /// Build the query without sending it.
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        self.inner.into_operation()
+    }
+    pub fn send(self) -> Result<Connection<T>, LinearError> {
+        self.client.block_on(self.inner.send())
+    }
+    /// Fetch every page and collect all nodes into a `Vec`.
+    pub fn all(self) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.all())
+    }
+    /// Fetch pages until `max` nodes have been collected.
+    pub fn take_all(self, max: usize) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.take_all(max))
+    }
+}
+/// Blocking variant of [`RoadmapToProjectsQueryBuilder`](crate::generated::queries::RoadmapToProjectsQueryBuilder).
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+#[must_use]
+pub struct RoadmapToProjectsQueryBuilder<'a, T> {
+    inner: crate::generated::queries::RoadmapToProjectsQueryBuilder<'a, T>,
+    client: &'a Client,
+}
+#[cfg(feature = "projects")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::RoadmapToProject>>
+    RoadmapToProjectsQueryBuilder<'a, T>
+{
+    pub fn before(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.before(value),
+            client: self.client,
+        }
+    }
+    pub fn after(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.after(value),
+            client: self.client,
+        }
+    }
+    pub fn first(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.first(value),
+            client: self.client,
+        }
+    }
+    pub fn last(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.last(value),
+            client: self.client,
+        }
+    }
+    pub fn include_archived(self, value: bool) -> Self {
+        Self {
+            inner: self.inner.include_archived(value),
+            client: self.client,
+        }
+    }
+    pub fn order_by(self, value: PaginationOrderBy) -> Self {
+        Self {
+            inner: self.inner.order_by(value),
+            client: self.client,
+        }
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        Self {
+            inner: self.inner.variable(name, value),
+            client: self.client,
+        }
+    }
+    /// Build the query without sending it.
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        self.inner.into_operation()
+    }
+    pub fn send(self) -> Result<Connection<T>, LinearError> {
+        self.client.block_on(self.inner.send())
+    }
+    /// Fetch every page and collect all nodes into a `Vec`.
+    pub fn all(self) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.all())
+    }
+    /// Fetch pages until `max` nodes have been collected.
+    pub fn take_all(self, max: usize) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.take_all(max))
+    }
+}
+/// Blocking variant of [`RoadmapsQueryBuilder`](crate::generated::queries::RoadmapsQueryBuilder).
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+#[must_use]
+pub struct RoadmapsQueryBuilder<'a, T> {
+    inner: crate::generated::queries::RoadmapsQueryBuilder<'a, T>,
+    client: &'a Client,
+}
+#[cfg(feature = "projects")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Roadmap>>
+    RoadmapsQueryBuilder<'a, T>
+{
+    pub fn before(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.before(value),
+            client: self.client,
+        }
+    }
+    pub fn after(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.after(value),
+            client: self.client,
+        }
+    }
+    pub fn first(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.first(value),
+            client: self.client,
+        }
+    }
+    pub fn last(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.last(value),
+            client: self.client,
+        }
+    }
+    pub fn include_archived(self, value: bool) -> Self {
+        Self {
+            inner: self.inner.include_archived(value),
+            client: self.client,
+        }
+    }
+    pub fn order_by(self, value: PaginationOrderBy) -> Self {
+        Self {
+            inner: self.inner.order_by(value),
+            client: self.client,
+        }
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        Self {
+            inner: self.inner.variable(name, value),
+            client: self.client,
+        }
+    }
+    /// Build the query without sending it.
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        self.inner.into_operation()
+    }
+    pub fn send(self) -> Result<Connection<T>, LinearError> {
+        self.client.block_on(self.inner.send())
+    }
+    /// Fetch every page and collect all nodes into a `Vec`.
+    pub fn all(self) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.all())
+    }
+    /// Fetch pages until `max` nodes have been collected.
+    pub fn take_all(self, max: usize) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.take_all(max))
+    }
+}
+/// Blocking variant of [`ReleaseStagesQueryBuilder`](crate::generated::queries::ReleaseStagesQueryBuilder).
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+#[must_use]
+pub struct ReleaseStagesQueryBuilder<'a, T> {
+    inner: crate::generated::queries::ReleaseStagesQueryBuilder<'a, T>,
+    client: &'a Client,
+}
+#[cfg(feature = "releases")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::ReleaseStage>>
+    ReleaseStagesQueryBuilder<'a, T>
+{
+    pub fn filter(self, value: ReleaseStageFilter) -> Self {
+        Self {
+            inner: self.inner.filter(value),
+            client: self.client,
+        }
+    }
+    pub fn before(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.before(value),
+            client: self.client,
+        }
+    }
+    pub fn after(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.after(value),
+            client: self.client,
+        }
+    }
+    pub fn first(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.first(value),
+            client: self.client,
+        }
+    }
+    pub fn last(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.last(value),
+            client: self.client,
+        }
+    }
+    pub fn include_archived(self, value: bool) -> Self {
+        Self {
+            inner: self.inner.include_archived(value),
+            client: self.client,
+        }
+    }
+    pub fn order_by(self, value: PaginationOrderBy) -> Self {
+        Self {
+            inner: self.inner.order_by(value),
+            client: self.client,
+        }
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        Self {
+            inner: self.inner.variable(name, value),
+            client: self.client,
+        }
+    }
+    /// Build the query without sending it.
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        self.inner.into_operation()
+    }
+    pub fn send(self) -> Result<Connection<T>, LinearError> {
+        self.client.block_on(self.inner.send())
+    }
+    /// Fetch every page and collect all nodes into a `Vec`.
+    pub fn all(self) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.all())
+    }
+    /// Fetch pages until `max` nodes have been collected.
+    pub fn take_all(self, max: usize) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.take_all(max))
+    }
+}
+/// Blocking variant of [`ReleasePipelinesQueryBuilder`](crate::generated::queries::ReleasePipelinesQueryBuilder).
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+#[must_use]
+pub struct ReleasePipelinesQueryBuilder<'a, T> {
+    inner: crate::generated::queries::ReleasePipelinesQueryBuilder<'a, T>,
+    client: &'a Client,
+}
+#[cfg(feature = "releases")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::ReleasePipeline>>
+    ReleasePipelinesQueryBuilder<'a, T>
+{
+    pub fn filter(self, value: ReleasePipelineFilter) -> Self {
+        Self {
+            inner: self.inner.filter(value),
+            client: self.client,
+        }
+    }
+    pub fn before(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.before(value),
+            client: self.client,
+        }
+    }
+    pub fn after(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.after(value),
+            client: self.client,
+        }
+    }
+    pub fn first(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.first(value),
+            client: self.client,
+        }
+    }
+    pub fn last(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.last(value),
+            client: self.client,
+        }
+    }
+    pub fn include_archived(self, value: bool) -> Self {
+        Self {
+            inner: self.inner.include_archived(value),
+            client: self.client,
+        }
+    }
+    pub fn order_by(self, value: PaginationOrderBy) -> Self {
+        Self {
+            inner: self.inner.order_by(value),
+            client: self.client,
+        }
+    }
+    pub fn sort(self, value: ReleasePipelineSortInput) -> Self {
+        Self {
+            inner: self.inner.sort(value),
+            client: self.client,
+        }
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        Self {
+            inner: self.inner.variable(name, value),
+            client: self.client,
+        }
+    }
+    /// Build the query without sending it.
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        self.inner.into_operation()
+    }
+    pub fn send(self) -> Result<Connection<T>, LinearError> {
+        self.client.block_on(self.inner.send())
+    }
+    /// Fetch every page and collect all nodes into a `Vec`.
+    pub fn all(self) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.all())
+    }
+    /// Fetch pages until `max` nodes have been collected.
+    pub fn take_all(self, max: usize) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.take_all(max))
+    }
+}
+/// Blocking variant of [`ReleasesQueryBuilder`](crate::generated::queries::ReleasesQueryBuilder).
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+#[must_use]
+pub struct ReleasesQueryBuilder<'a, T> {
+    inner: crate::generated::queries::ReleasesQueryBuilder<'a, T>,
+    client: &'a Client,
+}
+#[cfg(feature = "releases")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Release>>
+    ReleasesQueryBuilder<'a, T>
+{
+    pub fn filter(self, value: ReleaseFilter) -> Self {
+        Self {
+            inner: self.inner.filter(value),
+            client: self.client,
+        }
+    }
+    pub fn before(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.before(value),
+            client: self.client,
+        }
+    }
+    pub fn after(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.after(value),
+            client: self.client,
+        }
+    }
+    pub fn first(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.first(value),
+            client: self.client,
+        }
+    }
+    pub fn last(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.last(value),
+            client: self.client,
+        }
+    }
+    pub fn include_archived(self, value: bool) -> Self {
+        Self {
+            inner: self.inner.include_archived(value),
+            client: self.client,
+        }
+    }
+    pub fn order_by(self, value: PaginationOrderBy) -> Self {
+        Self {
+            inner: self.inner.order_by(value),
+            client: self.client,
+        }
+    }
+    pub fn sort(self, value: ReleaseSortInput) -> Self {
+        Self {
+            inner: self.inner.sort(value),
+            client: self.client,
+        }
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        Self {
+            inner: self.inner.variable(name, value),
+            client: self.client,
+        }
+    }
+    /// Build the query without sending it.
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        self.inner.into_operation()
+    }
+    pub fn send(self) -> Result<Connection<T>, LinearError> {
+        self.client.block_on(self.inner.send())
+    }
+    /// Fetch every page and collect all nodes into a `Vec`.
+    pub fn all(self) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.all())
+    }
+    /// Fetch pages until `max` nodes have been collected.
+    pub fn take_all(self, max: usize) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.take_all(max))
+    }
+}
+/// Blocking variant of [`ReleaseSearchQueryBuilder`](crate::generated::queries::ReleaseSearchQueryBuilder).
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+#[must_use]
+pub struct ReleaseSearchQueryBuilder<'a, T> {
+    inner: crate::generated::queries::ReleaseSearchQueryBuilder<'a, T>,
+    client: &'a Client,
+}
+#[cfg(feature = "releases")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Release>>
+    ReleaseSearchQueryBuilder<'a, T>
+{
+    pub fn filter(self, value: ReleaseFilter) -> Self {
+        Self {
+            inner: self.inner.filter(value),
+            client: self.client,
+        }
+    }
+    pub fn first(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.first(value),
+            client: self.client,
+        }
+    }
+    pub fn term(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.term(value),
+            client: self.client,
+        }
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        Self {
+            inner: self.inner.variable(name, value),
+            client: self.client,
+        }
+    }
+    /// Build the query without sending it.
+    pub fn into_operation(self) -> Operation<Vec<T>> {
+        self.inner.into_operation()
+    }
+    pub fn send(self) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.send())
+    }
+}
+/// Blocking variant of [`ReleaseNotesQueryBuilder`](crate::generated::queries::ReleaseNotesQueryBuilder).
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+#[must_use]
+pub struct ReleaseNotesQueryBuilder<'a, T> {
+    inner: crate::generated::queries::ReleaseNotesQueryBuilder<'a, T>,
+    client: &'a Client,
+}
+#[cfg(feature = "releases")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::ReleaseNote>>
+    ReleaseNotesQueryBuilder<'a, T>
+{
+    pub fn before(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.before(value),
+            client: self.client,
+        }
+    }
+    pub fn after(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.after(value),
+            client: self.client,
+        }
+    }
+    pub fn first(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.first(value),
+            client: self.client,
+        }
+    }
+    pub fn last(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.last(value),
+            client: self.client,
+        }
+    }
+    pub fn include_archived(self, value: bool) -> Self {
+        Self {
+            inner: self.inner.include_archived(value),
+            client: self.client,
+        }
+    }
+    pub fn order_by(self, value: PaginationOrderBy) -> Self {
+        Self {
+            inner: self.inner.order_by(value),
+            client: self.client,
+        }
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        Self {
+            inner: self.inner.variable(name, value),
+            client: self.client,
+        }
+    }
+    /// Build the query without sending it.
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        self.inner.into_operation()
+    }
+    pub fn send(self) -> Result<Connection<T>, LinearError> {
+        self.client.block_on(self.inner.send())
+    }
+    /// Fetch every page and collect all nodes into a `Vec`.
+    pub fn all(self) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.all())
+    }
+    /// Fetch pages until `max` nodes have been collected.
+    pub fn take_all(self, max: usize) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.take_all(max))
+    }
+}
+/// Blocking variant of [`PushSubscriptionTestQueryBuilder`](crate::generated::queries::PushSubscriptionTestQueryBuilder).
+///
+/// Requires the `notifications` feature.
+#[cfg(feature = "notifications")]
+#[must_use]
+pub struct PushSubscriptionTestQueryBuilder<'a, T> {
+    inner: crate::generated::queries::PushSubscriptionTestQueryBuilder<'a, T>,
+    client: &'a Client,
+}
+#[cfg(feature = "notifications")]
+impl<
+        'a,
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::PushSubscriptionTestPayload>,
+    > PushSubscriptionTestQueryBuilder<'a, T>
+{
+    pub fn target_mobile(self, value: bool) -> Self {
+        Self {
+            inner: self.inner.target_mobile(value),
+            client: self.client,
+        }
+    }
+    pub fn send_strategy(self, value: SendStrategy) -> Self {
+        Self {
+            inner: self.inner.send_strategy(value),
+            client: self.client,
+        }
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        Self {
+            inner: self.inner.variable(name, value),
+            client: self.client,
+        }
+    }
+    /// Build the query without sending it.
+    pub fn into_operation(self) -> Operation<T> {
+        self.inner.into_operation()
+    }
+    pub fn send(self) -> Result<T, LinearError> {
+        self.client.block_on(self.inner.send())
+    }
+}
+/// Blocking variant of [`ProjectUpdatesQueryBuilder`](crate::generated::queries::ProjectUpdatesQueryBuilder).
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+#[must_use]
+pub struct ProjectUpdatesQueryBuilder<'a, T> {
+    inner: crate::generated::queries::ProjectUpdatesQueryBuilder<'a, T>,
+    client: &'a Client,
+}
+#[cfg(feature = "projects")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::ProjectUpdate>>
+    ProjectUpdatesQueryBuilder<'a, T>
+{
+    pub fn filter(self, value: ProjectUpdateFilter) -> Self {
+        Self {
+            inner: self.inner.filter(value),
+            client: self.client,
+        }
+    }
+    pub fn before(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.before(value),
+            client: self.client,
+        }
+    }
+    pub fn after(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.after(value),
+            client: self.client,
+        }
+    }
+    pub fn first(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.first(value),
+            client: self.client,
+        }
+    }
+    pub fn last(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.last(value),
+            client: self.client,
+        }
+    }
+    pub fn include_archived(self, value: bool) -> Self {
+        Self {
+            inner: self.inner.include_archived(value),
+            client: self.client,
+        }
+    }
+    pub fn order_by(self, value: PaginationOrderBy) -> Self {
+        Self {
+            inner: self.inner.order_by(value),
+            client: self.client,
+        }
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        Self {
+            inner: self.inner.variable(name, value),
+            client: self.client,
+        }
+    }
+    /// Build the query without sending it.
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        self.inner.into_operation()
+    }
+    pub fn send(self) -> Result<Connection<T>, LinearError> {
+        self.client.block_on(self.inner.send())
+    }
+    /// Fetch every page and collect all nodes into a `Vec`.
+    pub fn all(self) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.all())
+    }
+    /// Fetch pages until `max` nodes have been collected.
+    pub fn take_all(self, max: usize) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.take_all(max))
+    }
+}
+/// Blocking variant of [`ProjectStatusesQueryBuilder`](crate::generated::queries::ProjectStatusesQueryBuilder).
+#[must_use]
+pub struct ProjectStatusesQueryBuilder<'a, T> {
+    inner: crate::generated::queries::ProjectStatusesQueryBuilder<'a, T>,
+    client: &'a Client,
+}
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::ProjectStatus>>
+    ProjectStatusesQueryBuilder<'a, T>
+{
+    pub fn before(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.before(value),
+            client: self.client,
+        }
+    }
+    pub fn after(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.after(value),
+            client: self.client,
+        }
+    }
+    pub fn first(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.first(value),
+            client: self.client,
+        }
+    }
+    pub fn last(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.last(value),
+            client: self.client,
+        }
+    }
+    pub fn include_archived(self, value: bool) -> Self {
+        Self {
+            inner: self.inner.include_archived(value),
+            client: self.client,
+        }
+    }
+    pub fn order_by(self, value: PaginationOrderBy) -> Self {
+        Self {
+            inner: self.inner.order_by(value),
+            client: self.client,
+        }
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        Self {
+            inner: self.inner.variable(name, value),
+            client: self.client,
+        }
+    }
+    /// Build the query without sending it.
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        self.inner.into_operation()
+    }
+    pub fn send(self) -> Result<Connection<T>, LinearError> {
+        self.client.block_on(self.inner.send())
+    }
+    /// Fetch every page and collect all nodes into a `Vec`.
+    pub fn all(self) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.all())
+    }
+    /// Fetch pages until `max` nodes have been collected.
+    pub fn take_all(self, max: usize) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.take_all(max))
+    }
+}
+/// Blocking variant of [`ProjectRelationsQueryBuilder`](crate::generated::queries::ProjectRelationsQueryBuilder).
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+#[must_use]
+pub struct ProjectRelationsQueryBuilder<'a, T> {
+    inner: crate::generated::queries::ProjectRelationsQueryBuilder<'a, T>,
+    client: &'a Client,
+}
+#[cfg(feature = "projects")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::ProjectRelation>>
+    ProjectRelationsQueryBuilder<'a, T>
+{
+    pub fn before(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.before(value),
+            client: self.client,
+        }
+    }
+    pub fn after(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.after(value),
+            client: self.client,
+        }
+    }
+    pub fn first(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.first(value),
+            client: self.client,
+        }
+    }
+    pub fn last(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.last(value),
+            client: self.client,
+        }
+    }
+    pub fn include_archived(self, value: bool) -> Self {
+        Self {
+            inner: self.inner.include_archived(value),
+            client: self.client,
+        }
+    }
+    pub fn order_by(self, value: PaginationOrderBy) -> Self {
+        Self {
+            inner: self.inner.order_by(value),
+            client: self.client,
+        }
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        Self {
+            inner: self.inner.variable(name, value),
+            client: self.client,
+        }
+    }
+    /// Build the query without sending it.
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        self.inner.into_operation()
+    }
+    pub fn send(self) -> Result<Connection<T>, LinearError> {
+        self.client.block_on(self.inner.send())
+    }
+    /// Fetch every page and collect all nodes into a `Vec`.
+    pub fn all(self) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.all())
+    }
+    /// Fetch pages until `max` nodes have been collected.
+    pub fn take_all(self, max: usize) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.take_all(max))
+    }
+}
+/// Blocking variant of [`ProjectMilestonesQueryBuilder`](crate::generated::queries::ProjectMilestonesQueryBuilder).
+#[must_use]
+pub struct ProjectMilestonesQueryBuilder<'a, T> {
+    inner: crate::generated::queries::ProjectMilestonesQueryBuilder<'a, T>,
+    client: &'a Client,
+}
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::ProjectMilestone>>
+    ProjectMilestonesQueryBuilder<'a, T>
+{
+    pub fn filter(self, value: ProjectMilestoneFilter) -> Self {
+        Self {
+            inner: self.inner.filter(value),
+            client: self.client,
+        }
+    }
+    pub fn before(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.before(value),
+            client: self.client,
+        }
+    }
+    pub fn after(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.after(value),
+            client: self.client,
+        }
+    }
+    pub fn first(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.first(value),
+            client: self.client,
+        }
+    }
+    pub fn last(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.last(value),
+            client: self.client,
+        }
+    }
+    pub fn include_archived(self, value: bool) -> Self {
+        Self {
+            inner: self.inner.include_archived(value),
+            client: self.client,
+        }
+    }
+    pub fn order_by(self, value: PaginationOrderBy) -> Self {
+        Self {
+            inner: self.inner.order_by(value),
+            client: self.client,
+        }
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        Self {
+            inner: self.inner.variable(name, value),
+            client: self.client,
+        }
+    }
+    /// Build the query without sending it.
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        self.inner.into_operation()
+    }
+    pub fn send(self) -> Result<Connection<T>, LinearError> {
+        self.client.block_on(self.inner.send())
+    }
+    /// Fetch every page and collect all nodes into a `Vec`.
+    pub fn all(self) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.all())
+    }
+    /// Fetch pages until `max` nodes have been collected.
+    pub fn take_all(self, max: usize) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.take_all(max))
+    }
+}
+/// Blocking variant of [`ProjectLabelsQueryBuilder`](crate::generated::queries::ProjectLabelsQueryBuilder).
+#[must_use]
+pub struct ProjectLabelsQueryBuilder<'a, T> {
+    inner: crate::generated::queries::ProjectLabelsQueryBuilder<'a, T>,
+    client: &'a Client,
+}
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::ProjectLabel>>
+    ProjectLabelsQueryBuilder<'a, T>
+{
+    pub fn filter(self, value: ProjectLabelFilter) -> Self {
+        Self {
+            inner: self.inner.filter(value),
+            client: self.client,
+        }
+    }
+    pub fn before(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.before(value),
+            client: self.client,
+        }
+    }
+    pub fn after(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.after(value),
+            client: self.client,
+        }
+    }
+    pub fn first(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.first(value),
+            client: self.client,
+        }
+    }
+    pub fn last(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.last(value),
+            client: self.client,
+        }
+    }
+    pub fn include_archived(self, value: bool) -> Self {
+        Self {
+            inner: self.inner.include_archived(value),
+            client: self.client,
+        }
+    }
+    pub fn order_by(self, value: PaginationOrderBy) -> Self {
+        Self {
+            inner: self.inner.order_by(value),
+            client: self.client,
+        }
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        Self {
+            inner: self.inner.variable(name, value),
+            client: self.client,
+        }
+    }
+    /// Build the query without sending it.
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        self.inner.into_operation()
+    }
+    pub fn send(self) -> Result<Connection<T>, LinearError> {
+        self.client.block_on(self.inner.send())
+    }
+    /// Fetch every page and collect all nodes into a `Vec`.
+    pub fn all(self) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.all())
+    }
+    /// Fetch pages until `max` nodes have been collected.
+    pub fn take_all(self, max: usize) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.take_all(max))
+    }
+}
+/// Blocking variant of [`OrganizationInvitesQueryBuilder`](crate::generated::queries::OrganizationInvitesQueryBuilder).
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+#[must_use]
+pub struct OrganizationInvitesQueryBuilder<'a, T> {
+    inner: crate::generated::queries::OrganizationInvitesQueryBuilder<'a, T>,
+    client: &'a Client,
+}
+#[cfg(feature = "admin")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::OrganizationInvite>>
+    OrganizationInvitesQueryBuilder<'a, T>
+{
+    pub fn before(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.before(value),
+            client: self.client,
+        }
+    }
+    pub fn after(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.after(value),
+            client: self.client,
+        }
+    }
+    pub fn first(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.first(value),
+            client: self.client,
+        }
+    }
+    pub fn last(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.last(value),
+            client: self.client,
+        }
+    }
+    pub fn include_archived(self, value: bool) -> Self {
+        Self {
+            inner: self.inner.include_archived(value),
+            client: self.client,
+        }
+    }
+    pub fn order_by(self, value: PaginationOrderBy) -> Self {
+        Self {
+            inner: self.inner.order_by(value),
+            client: self.client,
+        }
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        Self {
+            inner: self.inner.variable(name, value),
+            client: self.client,
+        }
+    }
+    /// Build the query without sending it.
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        self.inner.into_operation()
+    }
+    pub fn send(self) -> Result<Connection<T>, LinearError> {
+        self.client.block_on(self.inner.send())
+    }
+    /// Fetch every page and collect all nodes into a `Vec`.
+    pub fn all(self) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.all())
+    }
+    /// Fetch pages until `max` nodes have been collected.
+    pub fn take_all(self, max: usize) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.take_all(max))
+    }
+}
+/// Blocking variant of [`NotificationSubscriptionsQueryBuilder`](crate::generated::queries::NotificationSubscriptionsQueryBuilder).
+///
+/// Requires the `notifications` feature.
+#[cfg(feature = "notifications")]
+#[must_use]
+pub struct NotificationSubscriptionsQueryBuilder<'a, T> {
+    inner: crate::generated::queries::NotificationSubscriptionsQueryBuilder<'a, T>,
+    client: &'a Client,
+}
+#[cfg(feature = "notifications")]
+impl<
+        'a,
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::NotificationSubscription>,
+    > NotificationSubscriptionsQueryBuilder<'a, T>
+{
+    pub fn before(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.before(value),
+            client: self.client,
+        }
+    }
+    pub fn after(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.after(value),
+            client: self.client,
+        }
+    }
+    pub fn first(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.first(value),
+            client: self.client,
+        }
+    }
+    pub fn last(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.last(value),
+            client: self.client,
+        }
+    }
+    pub fn include_archived(self, value: bool) -> Self {
+        Self {
+            inner: self.inner.include_archived(value),
+            client: self.client,
+        }
+    }
+    pub fn order_by(self, value: PaginationOrderBy) -> Self {
+        Self {
+            inner: self.inner.order_by(value),
+            client: self.client,
+        }
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        Self {
+            inner: self.inner.variable(name, value),
+            client: self.client,
+        }
+    }
+    /// Build the query without sending it.
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        self.inner.into_operation()
+    }
+    pub fn send(self) -> Result<Connection<T>, LinearError> {
+        self.client.block_on(self.inner.send())
+    }
+    /// Fetch every page and collect all nodes into a `Vec`.
+    pub fn all(self) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.all())
+    }
+    /// Fetch pages until `max` nodes have been collected.
+    pub fn take_all(self, max: usize) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.take_all(max))
+    }
+}
+/// Blocking variant of [`NotificationsQueryBuilder`](crate::generated::queries::NotificationsQueryBuilder).
+#[must_use]
+pub struct NotificationsQueryBuilder<'a, T> {
+    inner: crate::generated::queries::NotificationsQueryBuilder<'a, T>,
+    client: &'a Client,
+}
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Notification>>
+    NotificationsQueryBuilder<'a, T>
+{
+    pub fn filter(self, value: NotificationFilter) -> Self {
+        Self {
+            inner: self.inner.filter(value),
+            client: self.client,
+        }
+    }
+    pub fn before(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.before(value),
+            client: self.client,
+        }
+    }
+    pub fn after(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.after(value),
+            client: self.client,
+        }
+    }
+    pub fn first(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.first(value),
+            client: self.client,
+        }
+    }
+    pub fn last(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.last(value),
+            client: self.client,
+        }
+    }
+    pub fn include_archived(self, value: bool) -> Self {
+        Self {
+            inner: self.inner.include_archived(value),
+            client: self.client,
+        }
+    }
+    pub fn order_by(self, value: PaginationOrderBy) -> Self {
+        Self {
+            inner: self.inner.order_by(value),
+            client: self.client,
+        }
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        Self {
+            inner: self.inner.variable(name, value),
+            client: self.client,
+        }
+    }
+    /// Build the query without sending it.
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        self.inner.into_operation()
+    }
+    pub fn send(self) -> Result<Connection<T>, LinearError> {
+        self.client.block_on(self.inner.send())
+    }
+    /// Fetch every page and collect all nodes into a `Vec`.
+    pub fn all(self) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.all())
+    }
+    /// Fetch pages until `max` nodes have been collected.
+    pub fn take_all(self, max: usize) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.take_all(max))
+    }
+}
+/// Blocking variant of [`IssueToReleasesQueryBuilder`](crate::generated::queries::IssueToReleasesQueryBuilder).
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+#[must_use]
+pub struct IssueToReleasesQueryBuilder<'a, T> {
+    inner: crate::generated::queries::IssueToReleasesQueryBuilder<'a, T>,
+    client: &'a Client,
+}
+#[cfg(feature = "releases")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::IssueToRelease>>
+    IssueToReleasesQueryBuilder<'a, T>
+{
+    pub fn before(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.before(value),
+            client: self.client,
+        }
+    }
+    pub fn after(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.after(value),
+            client: self.client,
+        }
+    }
+    pub fn first(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.first(value),
+            client: self.client,
+        }
+    }
+    pub fn last(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.last(value),
+            client: self.client,
+        }
+    }
+    pub fn include_archived(self, value: bool) -> Self {
+        Self {
+            inner: self.inner.include_archived(value),
+            client: self.client,
+        }
+    }
+    pub fn order_by(self, value: PaginationOrderBy) -> Self {
+        Self {
+            inner: self.inner.order_by(value),
+            client: self.client,
+        }
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        Self {
+            inner: self.inner.variable(name, value),
+            client: self.client,
+        }
+    }
+    /// Build the query without sending it.
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        self.inner.into_operation()
+    }
+    pub fn send(self) -> Result<Connection<T>, LinearError> {
+        self.client.block_on(self.inner.send())
+    }
+    /// Fetch every page and collect all nodes into a `Vec`.
+    pub fn all(self) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.all())
+    }
+    /// Fetch pages until `max` nodes have been collected.
+    pub fn take_all(self, max: usize) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.take_all(max))
+    }
+}
+/// Blocking variant of [`IssuesQueryBuilder`](crate::generated::queries::IssuesQueryBuilder).
+#[must_use]
+pub struct IssuesQueryBuilder<'a, T> {
+    inner: crate::generated::queries::IssuesQueryBuilder<'a, T>,
+    client: &'a Client,
+}
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Issue>>
+    IssuesQueryBuilder<'a, T>
+{
+    pub fn filter(self, value: IssueFilter) -> Self {
+        Self {
+            inner: self.inner.filter(value),
+            client: self.client,
+        }
+    }
+    pub fn before(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.before(value),
+            client: self.client,
+        }
+    }
+    pub fn after(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.after(value),
+            client: self.client,
+        }
+    }
+    pub fn first(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.first(value),
+            client: self.client,
+        }
+    }
+    pub fn last(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.last(value),
+            client: self.client,
+        }
+    }
+    pub fn include_archived(self, value: bool) -> Self {
+        Self {
+            inner: self.inner.include_archived(value),
+            client: self.client,
+        }
+    }
+    pub fn order_by(self, value: PaginationOrderBy) -> Self {
+        Self {
+            inner: self.inner.order_by(value),
+            client: self.client,
+        }
+    }
+    pub fn sort(self, value: IssueSortInput) -> Self {
+        Self {
+            inner: self.inner.sort(value),
+            client: self.client,
+        }
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        Self {
+            inner: self.inner.variable(name, value),
+            client: self.client,
+        }
+    }
+    /// Build the query without sending it.
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        self.inner.into_operation()
+    }
+    pub fn send(self) -> Result<Connection<T>, LinearError> {
+        self.client.block_on(self.inner.send())
+    }
+    /// Fetch every page and collect all nodes into a `Vec`.
+    pub fn all(self) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.all())
+    }
+    /// Fetch pages until `max` nodes have been collected.
+    pub fn take_all(self, max: usize) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.take_all(max))
+    }
+}
+/// Blocking variant of [`IssueSearchQueryBuilder`](crate::generated::queries::IssueSearchQueryBuilder).
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+#[must_use]
+pub struct IssueSearchQueryBuilder<'a, T> {
+    inner: crate::generated::queries::IssueSearchQueryBuilder<'a, T>,
+    client: &'a Client,
+}
+#[cfg(feature = "issues")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Issue>>
+    IssueSearchQueryBuilder<'a, T>
+{
+    pub fn filter(self, value: IssueFilter) -> Self {
+        Self {
+            inner: self.inner.filter(value),
+            client: self.client,
+        }
+    }
+    pub fn query(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.query(value),
+            client: self.client,
+        }
+    }
+    pub fn before(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.before(value),
+            client: self.client,
+        }
+    }
+    pub fn after(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.after(value),
+            client: self.client,
+        }
+    }
+    pub fn first(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.first(value),
+            client: self.client,
+        }
+    }
+    pub fn last(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.last(value),
+            client: self.client,
+        }
+    }
+    pub fn include_archived(self, value: bool) -> Self {
+        Self {
+            inner: self.inner.include_archived(value),
+            client: self.client,
+        }
+    }
+    pub fn order_by(self, value: PaginationOrderBy) -> Self {
+        Self {
+            inner: self.inner.order_by(value),
+            client: self.client,
+        }
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        Self {
+            inner: self.inner.variable(name, value),
+            client: self.client,
+        }
+    }
+    /// Build the query without sending it.
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        self.inner.into_operation()
+    }
+    pub fn send(self) -> Result<Connection<T>, LinearError> {
+        self.client.block_on(self.inner.send())
+    }
+    /// Fetch every page and collect all nodes into a `Vec`.
+    pub fn all(self) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.all())
+    }
+    /// Fetch pages until `max` nodes have been collected.
+    pub fn take_all(self, max: usize) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.take_all(max))
+    }
+}
+/// Blocking variant of [`IssueFigmaFileKeySearchQueryBuilder`](crate::generated::queries::IssueFigmaFileKeySearchQueryBuilder).
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+#[must_use]
+pub struct IssueFigmaFileKeySearchQueryBuilder<'a, T> {
+    inner: crate::generated::queries::IssueFigmaFileKeySearchQueryBuilder<'a, T>,
+    client: &'a Client,
+}
+#[cfg(feature = "issues")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Issue>>
+    IssueFigmaFileKeySearchQueryBuilder<'a, T>
+{
+    pub fn before(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.before(value),
+            client: self.client,
+        }
+    }
+    pub fn after(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.after(value),
+            client: self.client,
+        }
+    }
+    pub fn first(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.first(value),
+            client: self.client,
+        }
+    }
+    pub fn last(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.last(value),
+            client: self.client,
+        }
+    }
+    pub fn include_archived(self, value: bool) -> Self {
+        Self {
+            inner: self.inner.include_archived(value),
+            client: self.client,
+        }
+    }
+    pub fn order_by(self, value: PaginationOrderBy) -> Self {
+        Self {
+            inner: self.inner.order_by(value),
+            client: self.client,
+        }
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        Self {
+            inner: self.inner.variable(name, value),
+            client: self.client,
+        }
+    }
+    /// Build the query without sending it.
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        self.inner.into_operation()
+    }
+    pub fn send(self) -> Result<Connection<T>, LinearError> {
+        self.client.block_on(self.inner.send())
+    }
+    /// Fetch every page and collect all nodes into a `Vec`.
+    pub fn all(self) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.all())
+    }
+    /// Fetch pages until `max` nodes have been collected.
+    pub fn take_all(self, max: usize) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.take_all(max))
+    }
+}
+/// Blocking variant of [`IssueFilterSuggestionQueryBuilder`](crate::generated::queries::IssueFilterSuggestionQueryBuilder).
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+#[must_use]
+pub struct IssueFilterSuggestionQueryBuilder<'a, T> {
+    inner: crate::generated::queries::IssueFilterSuggestionQueryBuilder<'a, T>,
+    client: &'a Client,
+}
+#[cfg(feature = "issues")]
+impl<
+        'a,
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::IssueFilterSuggestionPayload>,
+    > IssueFilterSuggestionQueryBuilder<'a, T>
+{
+    pub fn team_id(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.team_id(value),
+            client: self.client,
+        }
+    }
+    pub fn project_id(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.project_id(value),
+            client: self.client,
+        }
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        Self {
+            inner: self.inner.variable(name, value),
+            client: self.client,
+        }
+    }
+    /// Build the query without sending it.
+    pub fn into_operation(self) -> Operation<T> {
+        self.inner.into_operation()
+    }
+    pub fn send(self) -> Result<T, LinearError> {
+        self.client.block_on(self.inner.send())
+    }
+}
+/// Blocking variant of [`IssueRepositorySuggestionsQueryBuilder`](crate::generated::queries::IssueRepositorySuggestionsQueryBuilder).
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+#[must_use]
+pub struct IssueRepositorySuggestionsQueryBuilder<'a, T> {
+    inner: crate::generated::queries::IssueRepositorySuggestionsQueryBuilder<'a, T>,
+    client: &'a Client,
+}
+#[cfg(feature = "issues")]
+impl<
+        'a,
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::RepositorySuggestionsPayload>,
+    > IssueRepositorySuggestionsQueryBuilder<'a, T>
+{
+    pub fn agent_session_id(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.agent_session_id(value),
+            client: self.client,
+        }
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        Self {
+            inner: self.inner.variable(name, value),
+            client: self.client,
+        }
+    }
+    /// Build the query without sending it.
+    pub fn into_operation(self) -> Operation<T> {
+        self.inner.into_operation()
+    }
+    pub fn send(self) -> Result<T, LinearError> {
+        self.client.block_on(self.inner.send())
+    }
+}
+/// Blocking variant of [`IssueRelationsQueryBuilder`](crate::generated::queries::IssueRelationsQueryBuilder).
+#[must_use]
+pub struct IssueRelationsQueryBuilder<'a, T> {
+    inner: crate::generated::queries::IssueRelationsQueryBuilder<'a, T>,
+    client: &'a Client,
+}
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::IssueRelation>>
+    IssueRelationsQueryBuilder<'a, T>
+{
+    pub fn before(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.before(value),
+            client: self.client,
+        }
+    }
+    pub fn after(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.after(value),
+            client: self.client,
+        }
+    }
+    pub fn first(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.first(value),
+            client: self.client,
+        }
+    }
+    pub fn last(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.last(value),
+            client: self.client,
+        }
+    }
+    pub fn include_archived(self, value: bool) -> Self {
+        Self {
+            inner: self.inner.include_archived(value),
+            client: self.client,
+        }
+    }
+    pub fn order_by(self, value: PaginationOrderBy) -> Self {
+        Self {
+            inner: self.inner.order_by(value),
+            client: self.client,
+        }
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        Self {
+            inner: self.inner.variable(name, value),
+            client: self.client,
+        }
+    }
+    /// Build the query without sending it.
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        self.inner.into_operation()
+    }
+    pub fn send(self) -> Result<Connection<T>, LinearError> {
+        self.client.block_on(self.inner.send())
+    }
+    /// Fetch every page and collect all nodes into a `Vec`.
+    pub fn all(self) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.all())
+    }
+    /// Fetch pages until `max` nodes have been collected.
+    pub fn take_all(self, max: usize) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.take_all(max))
+    }
+}
+/// Blocking variant of [`IssueLabelsQueryBuilder`](crate::generated::queries::IssueLabelsQueryBuilder).
+#[must_use]
+pub struct IssueLabelsQueryBuilder<'a, T> {
+    inner: crate::generated::queries::IssueLabelsQueryBuilder<'a, T>,
+    client: &'a Client,
+}
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::IssueLabel>>
+    IssueLabelsQueryBuilder<'a, T>
+{
+    pub fn filter(self, value: IssueLabelFilter) -> Self {
+        Self {
+            inner: self.inner.filter(value),
+            client: self.client,
+        }
+    }
+    pub fn before(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.before(value),
+            client: self.client,
+        }
+    }
+    pub fn after(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.after(value),
+            client: self.client,
+        }
+    }
+    pub fn first(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.first(value),
+            client: self.client,
+        }
+    }
+    pub fn last(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.last(value),
+            client: self.client,
+        }
+    }
+    pub fn include_archived(self, value: bool) -> Self {
+        Self {
+            inner: self.inner.include_archived(value),
+            client: self.client,
+        }
+    }
+    pub fn order_by(self, value: PaginationOrderBy) -> Self {
+        Self {
+            inner: self.inner.order_by(value),
+            client: self.client,
+        }
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        Self {
+            inner: self.inner.variable(name, value),
+            client: self.client,
+        }
+    }
+    /// Build the query without sending it.
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        self.inner.into_operation()
+    }
+    pub fn send(self) -> Result<Connection<T>, LinearError> {
+        self.client.block_on(self.inner.send())
+    }
+    /// Fetch every page and collect all nodes into a `Vec`.
+    pub fn all(self) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.all())
+    }
+    /// Fetch pages until `max` nodes have been collected.
+    pub fn take_all(self, max: usize) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.take_all(max))
+    }
+}
+/// Blocking variant of [`IntegrationTemplatesQueryBuilder`](crate::generated::queries::IntegrationTemplatesQueryBuilder).
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+#[must_use]
+pub struct IntegrationTemplatesQueryBuilder<'a, T> {
+    inner: crate::generated::queries::IntegrationTemplatesQueryBuilder<'a, T>,
+    client: &'a Client,
+}
+#[cfg(feature = "integrations")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::IntegrationTemplate>>
+    IntegrationTemplatesQueryBuilder<'a, T>
+{
+    pub fn before(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.before(value),
+            client: self.client,
+        }
+    }
+    pub fn after(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.after(value),
+            client: self.client,
+        }
+    }
+    pub fn first(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.first(value),
+            client: self.client,
+        }
+    }
+    pub fn last(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.last(value),
+            client: self.client,
+        }
+    }
+    pub fn include_archived(self, value: bool) -> Self {
+        Self {
+            inner: self.inner.include_archived(value),
+            client: self.client,
+        }
+    }
+    pub fn order_by(self, value: PaginationOrderBy) -> Self {
+        Self {
+            inner: self.inner.order_by(value),
+            client: self.client,
+        }
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        Self {
+            inner: self.inner.variable(name, value),
+            client: self.client,
+        }
+    }
+    /// Build the query without sending it.
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        self.inner.into_operation()
+    }
+    pub fn send(self) -> Result<Connection<T>, LinearError> {
+        self.client.block_on(self.inner.send())
+    }
+    /// Fetch every page and collect all nodes into a `Vec`.
+    pub fn all(self) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.all())
+    }
+    /// Fetch pages until `max` nodes have been collected.
+    pub fn take_all(self, max: usize) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.take_all(max))
+    }
+}
+/// Blocking variant of [`IntegrationsQueryBuilder`](crate::generated::queries::IntegrationsQueryBuilder).
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+#[must_use]
+pub struct IntegrationsQueryBuilder<'a, T> {
+    inner: crate::generated::queries::IntegrationsQueryBuilder<'a, T>,
+    client: &'a Client,
+}
+#[cfg(feature = "integrations")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Integration>>
+    IntegrationsQueryBuilder<'a, T>
+{
+    pub fn before(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.before(value),
+            client: self.client,
+        }
+    }
+    pub fn after(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.after(value),
+            client: self.client,
+        }
+    }
+    pub fn first(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.first(value),
+            client: self.client,
+        }
+    }
+    pub fn last(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.last(value),
+            client: self.client,
+        }
+    }
+    pub fn include_archived(self, value: bool) -> Self {
+        Self {
+            inner: self.inner.include_archived(value),
+            client: self.client,
+        }
+    }
+    pub fn order_by(self, value: PaginationOrderBy) -> Self {
+        Self {
+            inner: self.inner.order_by(value),
+            client: self.client,
+        }
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        Self {
+            inner: self.inner.variable(name, value),
+            client: self.client,
+        }
+    }
+    /// Build the query without sending it.
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        self.inner.into_operation()
+    }
+    pub fn send(self) -> Result<Connection<T>, LinearError> {
+        self.client.block_on(self.inner.send())
+    }
+    /// Fetch every page and collect all nodes into a `Vec`.
+    pub fn all(self) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.all())
+    }
+    /// Fetch pages until `max` nodes have been collected.
+    pub fn take_all(self, max: usize) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.take_all(max))
+    }
+}
+/// Blocking variant of [`InitiativeUpdatesQueryBuilder`](crate::generated::queries::InitiativeUpdatesQueryBuilder).
+///
+/// Requires the `initiatives` feature.
+#[cfg(feature = "initiatives")]
+#[must_use]
+pub struct InitiativeUpdatesQueryBuilder<'a, T> {
+    inner: crate::generated::queries::InitiativeUpdatesQueryBuilder<'a, T>,
+    client: &'a Client,
+}
+#[cfg(feature = "initiatives")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::InitiativeUpdate>>
+    InitiativeUpdatesQueryBuilder<'a, T>
+{
+    pub fn filter(self, value: InitiativeUpdateFilter) -> Self {
+        Self {
+            inner: self.inner.filter(value),
+            client: self.client,
+        }
+    }
+    pub fn before(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.before(value),
+            client: self.client,
+        }
+    }
+    pub fn after(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.after(value),
+            client: self.client,
+        }
+    }
+    pub fn first(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.first(value),
+            client: self.client,
+        }
+    }
+    pub fn last(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.last(value),
+            client: self.client,
+        }
+    }
+    pub fn include_archived(self, value: bool) -> Self {
+        Self {
+            inner: self.inner.include_archived(value),
+            client: self.client,
+        }
+    }
+    pub fn order_by(self, value: PaginationOrderBy) -> Self {
+        Self {
+            inner: self.inner.order_by(value),
+            client: self.client,
+        }
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        Self {
+            inner: self.inner.variable(name, value),
+            client: self.client,
+        }
+    }
+    /// Build the query without sending it.
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        self.inner.into_operation()
+    }
+    pub fn send(self) -> Result<Connection<T>, LinearError> {
+        self.client.block_on(self.inner.send())
+    }
+    /// Fetch every page and collect all nodes into a `Vec`.
+    pub fn all(self) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.all())
+    }
+    /// Fetch pages until `max` nodes have been collected.
+    pub fn take_all(self, max: usize) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.take_all(max))
+    }
+}
+/// Blocking variant of [`InitiativeToProjectsQueryBuilder`](crate::generated::queries::InitiativeToProjectsQueryBuilder).
+///
+/// Requires the `initiatives` feature.
+#[cfg(feature = "initiatives")]
+#[must_use]
+pub struct InitiativeToProjectsQueryBuilder<'a, T> {
+    inner: crate::generated::queries::InitiativeToProjectsQueryBuilder<'a, T>,
+    client: &'a Client,
+}
+#[cfg(feature = "initiatives")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::InitiativeToProject>>
+    InitiativeToProjectsQueryBuilder<'a, T>
+{
+    pub fn before(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.before(value),
+            client: self.client,
+        }
+    }
+    pub fn after(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.after(value),
+            client: self.client,
+        }
+    }
+    pub fn first(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.first(value),
+            client: self.client,
+        }
+    }
+    pub fn last(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.last(value),
+            client: self.client,
+        }
+    }
+    pub fn include_archived(self, value: bool) -> Self {
+        Self {
+            inner: self.inner.include_archived(value),
+            client: self.client,
+        }
+    }
+    pub fn order_by(self, value: PaginationOrderBy) -> Self {
+        Self {
+            inner: self.inner.order_by(value),
+            client: self.client,
+        }
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        Self {
+            inner: self.inner.variable(name, value),
+            client: self.client,
+        }
+    }
+    /// Build the query without sending it.
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        self.inner.into_operation()
+    }
+    pub fn send(self) -> Result<Connection<T>, LinearError> {
+        self.client.block_on(self.inner.send())
+    }
+    /// Fetch every page and collect all nodes into a `Vec`.
+    pub fn all(self) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.all())
+    }
+    /// Fetch pages until `max` nodes have been collected.
+    pub fn take_all(self, max: usize) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.take_all(max))
+    }
+}
+/// Blocking variant of [`InitiativesQueryBuilder`](crate::generated::queries::InitiativesQueryBuilder).
+///
+/// Requires the `initiatives` feature.
+#[cfg(feature = "initiatives")]
+#[must_use]
+pub struct InitiativesQueryBuilder<'a, T> {
+    inner: crate::generated::queries::InitiativesQueryBuilder<'a, T>,
+    client: &'a Client,
+}
+#[cfg(feature = "initiatives")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Initiative>>
+    InitiativesQueryBuilder<'a, T>
+{
+    pub fn filter(self, value: InitiativeFilter) -> Self {
+        Self {
+            inner: self.inner.filter(value),
+            client: self.client,
+        }
+    }
+    pub fn before(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.before(value),
+            client: self.client,
+        }
+    }
+    pub fn after(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.after(value),
+            client: self.client,
+        }
+    }
+    pub fn first(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.first(value),
+            client: self.client,
+        }
+    }
+    pub fn last(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.last(value),
+            client: self.client,
+        }
+    }
+    pub fn include_archived(self, value: bool) -> Self {
+        Self {
+            inner: self.inner.include_archived(value),
+            client: self.client,
+        }
+    }
+    pub fn order_by(self, value: PaginationOrderBy) -> Self {
+        Self {
+            inner: self.inner.order_by(value),
+            client: self.client,
+        }
+    }
+    pub fn sort(self, value: InitiativeSortInput) -> Self {
+        Self {
+            inner: self.inner.sort(value),
+            client: self.client,
+        }
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        Self {
+            inner: self.inner.variable(name, value),
+            client: self.client,
+        }
+    }
+    /// Build the query without sending it.
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        self.inner.into_operation()
+    }
+    pub fn send(self) -> Result<Connection<T>, LinearError> {
+        self.client.block_on(self.inner.send())
+    }
+    /// Fetch every page and collect all nodes into a `Vec`.
+    pub fn all(self) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.all())
+    }
+    /// Fetch pages until `max` nodes have been collected.
+    pub fn take_all(self, max: usize) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.take_all(max))
+    }
+}
+/// Blocking variant of [`InitiativeRelationsQueryBuilder`](crate::generated::queries::InitiativeRelationsQueryBuilder).
+///
+/// Requires the `initiatives` feature.
+#[cfg(feature = "initiatives")]
+#[must_use]
+pub struct InitiativeRelationsQueryBuilder<'a, T> {
+    inner: crate::generated::queries::InitiativeRelationsQueryBuilder<'a, T>,
+    client: &'a Client,
+}
+#[cfg(feature = "initiatives")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::InitiativeRelation>>
+    InitiativeRelationsQueryBuilder<'a, T>
+{
+    pub fn before(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.before(value),
+            client: self.client,
+        }
+    }
+    pub fn after(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.after(value),
+            client: self.client,
+        }
+    }
+    pub fn first(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.first(value),
+            client: self.client,
+        }
+    }
+    pub fn last(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.last(value),
+            client: self.client,
+        }
+    }
+    pub fn include_archived(self, value: bool) -> Self {
+        Self {
+            inner: self.inner.include_archived(value),
+            client: self.client,
+        }
+    }
+    pub fn order_by(self, value: PaginationOrderBy) -> Self {
+        Self {
+            inner: self.inner.order_by(value),
+            client: self.client,
+        }
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        Self {
+            inner: self.inner.variable(name, value),
+            client: self.client,
+        }
+    }
+    /// Build the query without sending it.
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        self.inner.into_operation()
+    }
+    pub fn send(self) -> Result<Connection<T>, LinearError> {
+        self.client.block_on(self.inner.send())
+    }
+    /// Fetch every page and collect all nodes into a `Vec`.
+    pub fn all(self) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.all())
+    }
+    /// Fetch pages until `max` nodes have been collected.
+    pub fn take_all(self, max: usize) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.take_all(max))
+    }
+}
+/// Blocking variant of [`FavoritesQueryBuilder`](crate::generated::queries::FavoritesQueryBuilder).
+///
+/// Requires the `views` feature.
+#[cfg(feature = "views")]
+#[must_use]
+pub struct FavoritesQueryBuilder<'a, T> {
+    inner: crate::generated::queries::FavoritesQueryBuilder<'a, T>,
+    client: &'a Client,
+}
+#[cfg(feature = "views")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Favorite>>
+    FavoritesQueryBuilder<'a, T>
+{
+    pub fn before(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.before(value),
+            client: self.client,
+        }
+    }
+    pub fn after(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.after(value),
+            client: self.client,
+        }
+    }
+    pub fn first(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.first(value),
+            client: self.client,
+        }
+    }
+    pub fn last(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.last(value),
+            client: self.client,
+        }
+    }
+    pub fn include_archived(self, value: bool) -> Self {
+        Self {
+            inner: self.inner.include_archived(value),
+            client: self.client,
+        }
+    }
+    pub fn order_by(self, value: PaginationOrderBy) -> Self {
+        Self {
+            inner: self.inner.order_by(value),
+            client: self.client,
+        }
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        Self {
+            inner: self.inner.variable(name, value),
+            client: self.client,
+        }
+    }
+    /// Build the query without sending it.
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        self.inner.into_operation()
+    }
+    pub fn send(self) -> Result<Connection<T>, LinearError> {
+        self.client.block_on(self.inner.send())
+    }
+    /// Fetch every page and collect all nodes into a `Vec`.
+    pub fn all(self) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.all())
+    }
+    /// Fetch pages until `max` nodes have been collected.
+    pub fn take_all(self, max: usize) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.take_all(max))
+    }
+}
+/// Blocking variant of [`ExternalUsersQueryBuilder`](crate::generated::queries::ExternalUsersQueryBuilder).
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+#[must_use]
+pub struct ExternalUsersQueryBuilder<'a, T> {
+    inner: crate::generated::queries::ExternalUsersQueryBuilder<'a, T>,
+    client: &'a Client,
+}
+#[cfg(feature = "integrations")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::ExternalUser>>
+    ExternalUsersQueryBuilder<'a, T>
+{
+    pub fn before(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.before(value),
+            client: self.client,
+        }
+    }
+    pub fn after(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.after(value),
+            client: self.client,
+        }
+    }
+    pub fn first(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.first(value),
+            client: self.client,
+        }
+    }
+    pub fn last(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.last(value),
+            client: self.client,
+        }
+    }
+    pub fn include_archived(self, value: bool) -> Self {
+        Self {
+            inner: self.inner.include_archived(value),
+            client: self.client,
+        }
+    }
+    pub fn order_by(self, value: PaginationOrderBy) -> Self {
+        Self {
+            inner: self.inner.order_by(value),
+            client: self.client,
+        }
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        Self {
+            inner: self.inner.variable(name, value),
+            client: self.client,
+        }
+    }
+    /// Build the query without sending it.
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        self.inner.into_operation()
+    }
+    pub fn send(self) -> Result<Connection<T>, LinearError> {
+        self.client.block_on(self.inner.send())
+    }
+    /// Fetch every page and collect all nodes into a `Vec`.
+    pub fn all(self) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.all())
+    }
+    /// Fetch pages until `max` nodes have been collected.
+    pub fn take_all(self, max: usize) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.take_all(max))
+    }
+}
+/// Blocking variant of [`EmojisQueryBuilder`](crate::generated::queries::EmojisQueryBuilder).
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+#[must_use]
+pub struct EmojisQueryBuilder<'a, T> {
+    inner: crate::generated::queries::EmojisQueryBuilder<'a, T>,
+    client: &'a Client,
+}
+#[cfg(feature = "admin")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Emoji>>
+    EmojisQueryBuilder<'a, T>
+{
+    pub fn before(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.before(value),
+            client: self.client,
+        }
+    }
+    pub fn after(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.after(value),
+            client: self.client,
+        }
+    }
+    pub fn first(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.first(value),
+            client: self.client,
+        }
+    }
+    pub fn last(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.last(value),
+            client: self.client,
+        }
+    }
+    pub fn include_archived(self, value: bool) -> Self {
+        Self {
+            inner: self.inner.include_archived(value),
+            client: self.client,
+        }
+    }
+    pub fn order_by(self, value: PaginationOrderBy) -> Self {
+        Self {
+            inner: self.inner.order_by(value),
+            client: self.client,
+        }
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        Self {
+            inner: self.inner.variable(name, value),
+            client: self.client,
+        }
+    }
+    /// Build the query without sending it.
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        self.inner.into_operation()
+    }
+    pub fn send(self) -> Result<Connection<T>, LinearError> {
+        self.client.block_on(self.inner.send())
+    }
+    /// Fetch every page and collect all nodes into a `Vec`.
+    pub fn all(self) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.all())
+    }
+    /// Fetch pages until `max` nodes have been collected.
+    pub fn take_all(self, max: usize) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.take_all(max))
+    }
+}
+/// Blocking variant of [`DocumentsQueryBuilder`](crate::generated::queries::DocumentsQueryBuilder).
+#[must_use]
+pub struct DocumentsQueryBuilder<'a, T> {
+    inner: crate::generated::queries::DocumentsQueryBuilder<'a, T>,
+    client: &'a Client,
+}
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Document>>
+    DocumentsQueryBuilder<'a, T>
+{
+    pub fn filter(self, value: DocumentFilter) -> Self {
+        Self {
+            inner: self.inner.filter(value),
+            client: self.client,
+        }
+    }
+    pub fn before(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.before(value),
+            client: self.client,
+        }
+    }
+    pub fn after(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.after(value),
+            client: self.client,
+        }
+    }
+    pub fn first(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.first(value),
+            client: self.client,
+        }
+    }
+    pub fn last(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.last(value),
+            client: self.client,
+        }
+    }
+    pub fn include_archived(self, value: bool) -> Self {
+        Self {
+            inner: self.inner.include_archived(value),
+            client: self.client,
+        }
+    }
+    pub fn order_by(self, value: PaginationOrderBy) -> Self {
+        Self {
+            inner: self.inner.order_by(value),
+            client: self.client,
+        }
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        Self {
+            inner: self.inner.variable(name, value),
+            client: self.client,
+        }
+    }
+    /// Build the query without sending it.
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        self.inner.into_operation()
+    }
+    pub fn send(self) -> Result<Connection<T>, LinearError> {
+        self.client.block_on(self.inner.send())
+    }
+    /// Fetch every page and collect all nodes into a `Vec`.
+    pub fn all(self) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.all())
+    }
+    /// Fetch pages until `max` nodes have been collected.
+    pub fn take_all(self, max: usize) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.take_all(max))
+    }
+}
+/// Blocking variant of [`CyclesQueryBuilder`](crate::generated::queries::CyclesQueryBuilder).
+#[must_use]
+pub struct CyclesQueryBuilder<'a, T> {
+    inner: crate::generated::queries::CyclesQueryBuilder<'a, T>,
+    client: &'a Client,
+}
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Cycle>>
+    CyclesQueryBuilder<'a, T>
+{
+    pub fn filter(self, value: CycleFilter) -> Self {
+        Self {
+            inner: self.inner.filter(value),
+            client: self.client,
+        }
+    }
+    pub fn before(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.before(value),
+            client: self.client,
+        }
+    }
+    pub fn after(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.after(value),
+            client: self.client,
+        }
+    }
+    pub fn first(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.first(value),
+            client: self.client,
+        }
+    }
+    pub fn last(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.last(value),
+            client: self.client,
+        }
+    }
+    pub fn include_archived(self, value: bool) -> Self {
+        Self {
+            inner: self.inner.include_archived(value),
+            client: self.client,
+        }
+    }
+    pub fn order_by(self, value: PaginationOrderBy) -> Self {
+        Self {
+            inner: self.inner.order_by(value),
+            client: self.client,
+        }
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        Self {
+            inner: self.inner.variable(name, value),
+            client: self.client,
+        }
+    }
+    /// Build the query without sending it.
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        self.inner.into_operation()
+    }
+    pub fn send(self) -> Result<Connection<T>, LinearError> {
+        self.client.block_on(self.inner.send())
+    }
+    /// Fetch every page and collect all nodes into a `Vec`.
+    pub fn all(self) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.all())
+    }
+    /// Fetch pages until `max` nodes have been collected.
+    pub fn take_all(self, max: usize) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.take_all(max))
+    }
+}
+/// Blocking variant of [`CustomerTiersQueryBuilder`](crate::generated::queries::CustomerTiersQueryBuilder).
+///
+/// Requires the `customers` feature.
+#[cfg(feature = "customers")]
+#[must_use]
+pub struct CustomerTiersQueryBuilder<'a, T> {
+    inner: crate::generated::queries::CustomerTiersQueryBuilder<'a, T>,
+    client: &'a Client,
+}
+#[cfg(feature = "customers")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::CustomerTier>>
+    CustomerTiersQueryBuilder<'a, T>
+{
+    pub fn before(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.before(value),
+            client: self.client,
+        }
+    }
+    pub fn after(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.after(value),
+            client: self.client,
+        }
+    }
+    pub fn first(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.first(value),
+            client: self.client,
+        }
+    }
+    pub fn last(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.last(value),
+            client: self.client,
+        }
+    }
+    pub fn include_archived(self, value: bool) -> Self {
+        Self {
+            inner: self.inner.include_archived(value),
+            client: self.client,
+        }
+    }
+    pub fn order_by(self, value: PaginationOrderBy) -> Self {
+        Self {
+            inner: self.inner.order_by(value),
+            client: self.client,
+        }
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        Self {
+            inner: self.inner.variable(name, value),
+            client: self.client,
+        }
+    }
+    /// Build the query without sending it.
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        self.inner.into_operation()
+    }
+    pub fn send(self) -> Result<Connection<T>, LinearError> {
+        self.client.block_on(self.inner.send())
+    }
+    /// Fetch every page and collect all nodes into a `Vec`.
+    pub fn all(self) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.all())
+    }
+    /// Fetch pages until `max` nodes have been collected.
+    pub fn take_all(self, max: usize) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.take_all(max))
+    }
+}
+/// Blocking variant of [`CustomerStatusesQueryBuilder`](crate::generated::queries::CustomerStatusesQueryBuilder).
+///
+/// Requires the `customers` feature.
+#[cfg(feature = "customers")]
+#[must_use]
+pub struct CustomerStatusesQueryBuilder<'a, T> {
+    inner: crate::generated::queries::CustomerStatusesQueryBuilder<'a, T>,
+    client: &'a Client,
+}
+#[cfg(feature = "customers")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::CustomerStatus>>
+    CustomerStatusesQueryBuilder<'a, T>
+{
+    pub fn before(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.before(value),
+            client: self.client,
+        }
+    }
+    pub fn after(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.after(value),
+            client: self.client,
+        }
+    }
+    pub fn first(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.first(value),
+            client: self.client,
+        }
+    }
+    pub fn last(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.last(value),
+            client: self.client,
+        }
+    }
+    pub fn include_archived(self, value: bool) -> Self {
+        Self {
+            inner: self.inner.include_archived(value),
+            client: self.client,
+        }
+    }
+    pub fn order_by(self, value: PaginationOrderBy) -> Self {
+        Self {
+            inner: self.inner.order_by(value),
+            client: self.client,
+        }
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        Self {
+            inner: self.inner.variable(name, value),
+            client: self.client,
+        }
+    }
+    /// Build the query without sending it.
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        self.inner.into_operation()
+    }
+    pub fn send(self) -> Result<Connection<T>, LinearError> {
+        self.client.block_on(self.inner.send())
+    }
+    /// Fetch every page and collect all nodes into a `Vec`.
+    pub fn all(self) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.all())
+    }
+    /// Fetch pages until `max` nodes have been collected.
+    pub fn take_all(self, max: usize) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.take_all(max))
+    }
+}
+/// Blocking variant of [`CustomersQueryBuilder`](crate::generated::queries::CustomersQueryBuilder).
+///
+/// Requires the `customers` feature.
+#[cfg(feature = "customers")]
+#[must_use]
+pub struct CustomersQueryBuilder<'a, T> {
+    inner: crate::generated::queries::CustomersQueryBuilder<'a, T>,
+    client: &'a Client,
+}
+#[cfg(feature = "customers")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Customer>>
+    CustomersQueryBuilder<'a, T>
+{
+    pub fn filter(self, value: CustomerFilter) -> Self {
+        Self {
+            inner: self.inner.filter(value),
+            client: self.client,
+        }
+    }
+    pub fn before(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.before(value),
+            client: self.client,
+        }
+    }
+    pub fn after(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.after(value),
+            client: self.client,
+        }
+    }
+    pub fn first(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.first(value),
+            client: self.client,
+        }
+    }
+    pub fn last(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.last(value),
+            client: self.client,
+        }
+    }
+    pub fn include_archived(self, value: bool) -> Self {
+        Self {
+            inner: self.inner.include_archived(value),
+            client: self.client,
+        }
+    }
+    pub fn order_by(self, value: PaginationOrderBy) -> Self {
+        Self {
+            inner: self.inner.order_by(value),
+            client: self.client,
+        }
+    }
+    pub fn sorts(self, value: CustomerSortInput) -> Self {
+        Self {
+            inner: self.inner.sorts(value),
+            client: self.client,
+        }
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        Self {
+            inner: self.inner.variable(name, value),
+            client: self.client,
+        }
+    }
+    /// Build the query without sending it.
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        self.inner.into_operation()
+    }
+    pub fn send(self) -> Result<Connection<T>, LinearError> {
+        self.client.block_on(self.inner.send())
+    }
+    /// Fetch every page and collect all nodes into a `Vec`.
+    pub fn all(self) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.all())
+    }
+    /// Fetch pages until `max` nodes have been collected.
+    pub fn take_all(self, max: usize) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.take_all(max))
+    }
+}
+/// Blocking variant of [`CustomerNeedsQueryBuilder`](crate::generated::queries::CustomerNeedsQueryBuilder).
+///
+/// Requires the `customers` feature.
+#[cfg(feature = "customers")]
+#[must_use]
+pub struct CustomerNeedsQueryBuilder<'a, T> {
+    inner: crate::generated::queries::CustomerNeedsQueryBuilder<'a, T>,
+    client: &'a Client,
+}
+#[cfg(feature = "customers")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::CustomerNeed>>
+    CustomerNeedsQueryBuilder<'a, T>
+{
+    pub fn filter(self, value: CustomerNeedFilter) -> Self {
+        Self {
+            inner: self.inner.filter(value),
+            client: self.client,
+        }
+    }
+    pub fn before(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.before(value),
+            client: self.client,
+        }
+    }
+    pub fn after(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.after(value),
+            client: self.client,
+        }
+    }
+    pub fn first(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.first(value),
+            client: self.client,
+        }
+    }
+    pub fn last(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.last(value),
+            client: self.client,
+        }
+    }
+    pub fn include_archived(self, value: bool) -> Self {
+        Self {
+            inner: self.inner.include_archived(value),
+            client: self.client,
+        }
+    }
+    pub fn order_by(self, value: PaginationOrderBy) -> Self {
+        Self {
+            inner: self.inner.order_by(value),
+            client: self.client,
+        }
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        Self {
+            inner: self.inner.variable(name, value),
+            client: self.client,
+        }
+    }
+    /// Build the query without sending it.
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        self.inner.into_operation()
+    }
+    pub fn send(self) -> Result<Connection<T>, LinearError> {
+        self.client.block_on(self.inner.send())
+    }
+    /// Fetch every page and collect all nodes into a `Vec`.
+    pub fn all(self) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.all())
+    }
+    /// Fetch pages until `max` nodes have been collected.
+    pub fn take_all(self, max: usize) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.take_all(max))
+    }
+}
+/// Blocking variant of [`CustomerNeedQueryBuilder`](crate::generated::queries::CustomerNeedQueryBuilder).
+///
+/// Requires the `customers` feature.
+#[cfg(feature = "customers")]
+#[must_use]
+pub struct CustomerNeedQueryBuilder<'a, T> {
+    inner: crate::generated::queries::CustomerNeedQueryBuilder<'a, T>,
+    client: &'a Client,
+}
+#[cfg(feature = "customers")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::CustomerNeed>>
+    CustomerNeedQueryBuilder<'a, T>
+{
+    pub fn id(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.id(value),
+            client: self.client,
+        }
+    }
+    pub fn hash(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.hash(value),
+            client: self.client,
+        }
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        Self {
+            inner: self.inner.variable(name, value),
+            client: self.client,
+        }
+    }
+    /// Build the query without sending it.
+    pub fn into_operation(self) -> Operation<T> {
+        self.inner.into_operation()
+    }
+    pub fn send(self) -> Result<T, LinearError> {
+        self.client.block_on(self.inner.send())
+    }
+}
+/// Blocking variant of [`CustomViewsQueryBuilder`](crate::generated::queries::CustomViewsQueryBuilder).
+///
+/// Requires the `views` feature.
+#[cfg(feature = "views")]
+#[must_use]
+pub struct CustomViewsQueryBuilder<'a, T> {
+    inner: crate::generated::queries::CustomViewsQueryBuilder<'a, T>,
+    client: &'a Client,
+}
+#[cfg(feature = "views")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::CustomView>>
+    CustomViewsQueryBuilder<'a, T>
+{
+    pub fn filter(self, value: CustomViewFilter) -> Self {
+        Self {
+            inner: self.inner.filter(value),
+            client: self.client,
+        }
+    }
+    pub fn before(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.before(value),
+            client: self.client,
+        }
+    }
+    pub fn after(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.after(value),
+            client: self.client,
+        }
+    }
+    pub fn first(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.first(value),
+            client: self.client,
+        }
+    }
+    pub fn last(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.last(value),
+            client: self.client,
+        }
+    }
+    pub fn include_archived(self, value: bool) -> Self {
+        Self {
+            inner: self.inner.include_archived(value),
+            client: self.client,
+        }
+    }
+    pub fn order_by(self, value: PaginationOrderBy) -> Self {
+        Self {
+            inner: self.inner.order_by(value),
+            client: self.client,
+        }
+    }
+    pub fn sort(self, value: CustomViewSortInput) -> Self {
+        Self {
+            inner: self.inner.sort(value),
+            client: self.client,
+        }
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        Self {
+            inner: self.inner.variable(name, value),
+            client: self.client,
+        }
+    }
+    /// Build the query without sending it.
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        self.inner.into_operation()
+    }
+    pub fn send(self) -> Result<Connection<T>, LinearError> {
+        self.client.block_on(self.inner.send())
+    }
+    /// Fetch every page and collect all nodes into a `Vec`.
+    pub fn all(self) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.all())
+    }
+    /// Fetch pages until `max` nodes have been collected.
+    pub fn take_all(self, max: usize) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.take_all(max))
+    }
+}
+/// Blocking variant of [`CustomViewDetailsSuggestionQueryBuilder`](crate::generated::queries::CustomViewDetailsSuggestionQueryBuilder).
+///
+/// Requires the `views` feature.
+#[cfg(feature = "views")]
+#[must_use]
+pub struct CustomViewDetailsSuggestionQueryBuilder<'a, T> {
+    inner: crate::generated::queries::CustomViewDetailsSuggestionQueryBuilder<'a, T>,
+    client: &'a Client,
+}
+#[cfg(feature = "views")]
+impl<
+        'a,
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::CustomViewSuggestionPayload>,
+    > CustomViewDetailsSuggestionQueryBuilder<'a, T>
+{
+    pub fn model_name(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.model_name(value),
+            client: self.client,
+        }
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        Self {
+            inner: self.inner.variable(name, value),
+            client: self.client,
+        }
+    }
+    /// Build the query without sending it.
+    pub fn into_operation(self) -> Operation<T> {
+        self.inner.into_operation()
+    }
+    pub fn send(self) -> Result<T, LinearError> {
+        self.client.block_on(self.inner.send())
+    }
+}
+/// Blocking variant of [`CommentsQueryBuilder`](crate::generated::queries::CommentsQueryBuilder).
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+#[must_use]
+pub struct CommentsQueryBuilder<'a, T> {
+    inner: crate::generated::queries::CommentsQueryBuilder<'a, T>,
+    client: &'a Client,
+}
+#[cfg(feature = "issues")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Comment>>
+    CommentsQueryBuilder<'a, T>
+{
+    pub fn filter(self, value: CommentFilter) -> Self {
+        Self {
+            inner: self.inner.filter(value),
+            client: self.client,
+        }
+    }
+    pub fn before(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.before(value),
+            client: self.client,
+        }
+    }
+    pub fn after(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.after(value),
+            client: self.client,
+        }
+    }
+    pub fn first(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.first(value),
+            client: self.client,
+        }
+    }
+    pub fn last(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.last(value),
+            client: self.client,
+        }
+    }
+    pub fn include_archived(self, value: bool) -> Self {
+        Self {
+            inner: self.inner.include_archived(value),
+            client: self.client,
+        }
+    }
+    pub fn order_by(self, value: PaginationOrderBy) -> Self {
+        Self {
+            inner: self.inner.order_by(value),
+            client: self.client,
+        }
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        Self {
+            inner: self.inner.variable(name, value),
+            client: self.client,
+        }
+    }
+    /// Build the query without sending it.
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        self.inner.into_operation()
+    }
+    pub fn send(self) -> Result<Connection<T>, LinearError> {
+        self.client.block_on(self.inner.send())
+    }
+    /// Fetch every page and collect all nodes into a `Vec`.
+    pub fn all(self) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.all())
+    }
+    /// Fetch pages until `max` nodes have been collected.
+    pub fn take_all(self, max: usize) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.take_all(max))
+    }
+}
+/// Blocking variant of [`CommentQueryBuilder`](crate::generated::queries::CommentQueryBuilder).
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+#[must_use]
+pub struct CommentQueryBuilder<'a, T> {
+    inner: crate::generated::queries::CommentQueryBuilder<'a, T>,
+    client: &'a Client,
+}
+#[cfg(feature = "issues")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Comment>>
+    CommentQueryBuilder<'a, T>
+{
+    pub fn id(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.id(value),
+            client: self.client,
+        }
+    }
+    pub fn hash(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.hash(value),
+            client: self.client,
+        }
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        Self {
+            inner: self.inner.variable(name, value),
+            client: self.client,
+        }
+    }
+    /// Build the query without sending it.
+    pub fn into_operation(self) -> Operation<T> {
+        self.inner.into_operation()
+    }
+    pub fn send(self) -> Result<T, LinearError> {
+        self.client.block_on(self.inner.send())
+    }
+}
+/// Blocking variant of [`SsoUrlFromEmailQueryBuilder`](crate::generated::queries::SsoUrlFromEmailQueryBuilder).
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+#[must_use]
+pub struct SsoUrlFromEmailQueryBuilder<'a, T> {
+    inner: crate::generated::queries::SsoUrlFromEmailQueryBuilder<'a, T>,
+    client: &'a Client,
+}
+#[cfg(feature = "admin")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::SsoUrlFromEmailResponse>>
+    SsoUrlFromEmailQueryBuilder<'a, T>
+{
+    pub fn is_desktop(self, value: bool) -> Self {
+        Self {
+            inner: self.inner.is_desktop(value),
+            client: self.client,
+        }
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        Self {
+            inner: self.inner.variable(name, value),
+            client: self.client,
+        }
+    }
+    /// Build the query without sending it.
+    pub fn into_operation(self) -> Operation<T> {
+        self.inner.into_operation()
+    }
+    pub fn send(self) -> Result<T, LinearError> {
+        self.client.block_on(self.inner.send())
+    }
+}
+/// Blocking variant of [`AuditEntriesQueryBuilder`](crate::generated::queries::AuditEntriesQueryBuilder).
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+#[must_use]
+pub struct AuditEntriesQueryBuilder<'a, T> {
+    inner: crate::generated::queries::AuditEntriesQueryBuilder<'a, T>,
+    client: &'a Client,
+}
+#[cfg(feature = "admin")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::AuditEntry>>
+    AuditEntriesQueryBuilder<'a, T>
+{
+    pub fn filter(self, value: AuditEntryFilter) -> Self {
+        Self {
+            inner: self.inner.filter(value),
+            client: self.client,
+        }
+    }
+    pub fn before(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.before(value),
+            client: self.client,
+        }
+    }
+    pub fn after(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.after(value),
+            client: self.client,
+        }
+    }
+    pub fn first(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.first(value),
+            client: self.client,
+        }
+    }
+    pub fn last(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.last(value),
+            client: self.client,
+        }
+    }
+    pub fn include_archived(self, value: bool) -> Self {
+        Self {
+            inner: self.inner.include_archived(value),
+            client: self.client,
+        }
+    }
+    pub fn order_by(self, value: PaginationOrderBy) -> Self {
+        Self {
+            inner: self.inner.order_by(value),
+            client: self.client,
+        }
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        Self {
+            inner: self.inner.variable(name, value),
+            client: self.client,
+        }
+    }
+    /// Build the query without sending it.
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        self.inner.into_operation()
+    }
+    pub fn send(self) -> Result<Connection<T>, LinearError> {
+        self.client.block_on(self.inner.send())
+    }
+    /// Fetch every page and collect all nodes into a `Vec`.
+    pub fn all(self) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.all())
+    }
+    /// Fetch pages until `max` nodes have been collected.
+    pub fn take_all(self, max: usize) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.take_all(max))
+    }
+}
+/// Blocking variant of [`AttachmentsQueryBuilder`](crate::generated::queries::AttachmentsQueryBuilder).
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+#[must_use]
+pub struct AttachmentsQueryBuilder<'a, T> {
+    inner: crate::generated::queries::AttachmentsQueryBuilder<'a, T>,
+    client: &'a Client,
+}
+#[cfg(feature = "issues")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Attachment>>
+    AttachmentsQueryBuilder<'a, T>
+{
+    pub fn filter(self, value: AttachmentFilter) -> Self {
+        Self {
+            inner: self.inner.filter(value),
+            client: self.client,
+        }
+    }
+    pub fn before(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.before(value),
+            client: self.client,
+        }
+    }
+    pub fn after(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.after(value),
+            client: self.client,
+        }
+    }
+    pub fn first(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.first(value),
+            client: self.client,
+        }
+    }
+    pub fn last(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.last(value),
+            client: self.client,
+        }
+    }
+    pub fn include_archived(self, value: bool) -> Self {
+        Self {
+            inner: self.inner.include_archived(value),
+            client: self.client,
+        }
+    }
+    pub fn order_by(self, value: PaginationOrderBy) -> Self {
+        Self {
+            inner: self.inner.order_by(value),
+            client: self.client,
+        }
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        Self {
+            inner: self.inner.variable(name, value),
+            client: self.client,
+        }
+    }
+    /// Build the query without sending it.
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        self.inner.into_operation()
+    }
+    pub fn send(self) -> Result<Connection<T>, LinearError> {
+        self.client.block_on(self.inner.send())
+    }
+    /// Fetch every page and collect all nodes into a `Vec`.
+    pub fn all(self) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.all())
+    }
+    /// Fetch pages until `max` nodes have been collected.
+    pub fn take_all(self, max: usize) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.take_all(max))
+    }
+}
+/// Blocking variant of [`AttachmentsForUrlQueryBuilder`](crate::generated::queries::AttachmentsForUrlQueryBuilder).
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+#[must_use]
+pub struct AttachmentsForUrlQueryBuilder<'a, T> {
+    inner: crate::generated::queries::AttachmentsForUrlQueryBuilder<'a, T>,
+    client: &'a Client,
+}
+#[cfg(feature = "issues")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Attachment>>
+    AttachmentsForUrlQueryBuilder<'a, T>
+{
+    pub fn before(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.before(value),
+            client: self.client,
+        }
+    }
+    pub fn after(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.after(value),
+            client: self.client,
+        }
+    }
+    pub fn first(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.first(value),
+            client: self.client,
+        }
+    }
+    pub fn last(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.last(value),
+            client: self.client,
+        }
+    }
+    pub fn include_archived(self, value: bool) -> Self {
+        Self {
+            inner: self.inner.include_archived(value),
+            client: self.client,
+        }
+    }
+    pub fn order_by(self, value: PaginationOrderBy) -> Self {
+        Self {
+            inner: self.inner.order_by(value),
+            client: self.client,
+        }
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        Self {
+            inner: self.inner.variable(name, value),
+            client: self.client,
+        }
+    }
+    /// Build the query without sending it.
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        self.inner.into_operation()
+    }
+    pub fn send(self) -> Result<Connection<T>, LinearError> {
+        self.client.block_on(self.inner.send())
+    }
+    /// Fetch every page and collect all nodes into a `Vec`.
+    pub fn all(self) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.all())
+    }
+    /// Fetch pages until `max` nodes have been collected.
+    pub fn take_all(self, max: usize) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.take_all(max))
+    }
+}
+/// Blocking variant of [`AttachmentSourcesQueryBuilder`](crate::generated::queries::AttachmentSourcesQueryBuilder).
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+#[must_use]
+pub struct AttachmentSourcesQueryBuilder<'a, T> {
+    inner: crate::generated::queries::AttachmentSourcesQueryBuilder<'a, T>,
+    client: &'a Client,
+}
+#[cfg(feature = "issues")]
+impl<
+        'a,
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::AttachmentSourcesPayload>,
+    > AttachmentSourcesQueryBuilder<'a, T>
+{
+    pub fn team_id(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.team_id(value),
+            client: self.client,
+        }
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        Self {
+            inner: self.inner.variable(name, value),
+            client: self.client,
+        }
+    }
+    /// Build the query without sending it.
+    pub fn into_operation(self) -> Operation<T> {
+        self.inner.into_operation()
+    }
+    pub fn send(self) -> Result<T, LinearError> {
+        self.client.block_on(self.inner.send())
+    }
+}
+/// Blocking variant of [`AgentSessionsQueryBuilder`](crate::generated::queries::AgentSessionsQueryBuilder).
+///
+/// Requires the `agents` feature.
+#[cfg(feature = "agents")]
+#[must_use]
+pub struct AgentSessionsQueryBuilder<'a, T> {
+    inner: crate::generated::queries::AgentSessionsQueryBuilder<'a, T>,
+    client: &'a Client,
+}
+#[cfg(feature = "agents")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::AgentSession>>
+    AgentSessionsQueryBuilder<'a, T>
+{
+    pub fn before(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.before(value),
+            client: self.client,
+        }
+    }
+    pub fn after(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.after(value),
+            client: self.client,
+        }
+    }
+    pub fn first(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.first(value),
+            client: self.client,
+        }
+    }
+    pub fn last(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.last(value),
+            client: self.client,
+        }
+    }
+    pub fn include_archived(self, value: bool) -> Self {
+        Self {
+            inner: self.inner.include_archived(value),
+            client: self.client,
+        }
+    }
+    pub fn order_by(self, value: PaginationOrderBy) -> Self {
+        Self {
+            inner: self.inner.order_by(value),
+            client: self.client,
+        }
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        Self {
+            inner: self.inner.variable(name, value),
+            client: self.client,
+        }
+    }
+    /// Build the query without sending it.
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        self.inner.into_operation()
+    }
+    pub fn send(self) -> Result<Connection<T>, LinearError> {
+        self.client.block_on(self.inner.send())
+    }
+    /// Fetch every page and collect all nodes into a `Vec`.
+    pub fn all(self) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.all())
+    }
+    /// Fetch pages until `max` nodes have been collected.
+    pub fn take_all(self, max: usize) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.take_all(max))
+    }
+}
+/// Blocking variant of [`AgentActivitiesQueryBuilder`](crate::generated::queries::AgentActivitiesQueryBuilder).
+///
+/// Requires the `agents` feature.
+#[cfg(feature = "agents")]
+#[must_use]
+pub struct AgentActivitiesQueryBuilder<'a, T> {
+    inner: crate::generated::queries::AgentActivitiesQueryBuilder<'a, T>,
+    client: &'a Client,
+}
+#[cfg(feature = "agents")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::AgentActivity>>
+    AgentActivitiesQueryBuilder<'a, T>
+{
+    pub fn filter(self, value: AgentActivityFilter) -> Self {
+        Self {
+            inner: self.inner.filter(value),
+            client: self.client,
+        }
+    }
+    pub fn before(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.before(value),
+            client: self.client,
+        }
+    }
+    pub fn after(self, value: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.after(value),
+            client: self.client,
+        }
+    }
+    pub fn first(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.first(value),
+            client: self.client,
+        }
+    }
+    pub fn last(self, value: i64) -> Self {
+        Self {
+            inner: self.inner.last(value),
+            client: self.client,
+        }
+    }
+    pub fn include_archived(self, value: bool) -> Self {
+        Self {
+            inner: self.inner.include_archived(value),
+            client: self.client,
+        }
+    }
+    pub fn order_by(self, value: PaginationOrderBy) -> Self {
+        Self {
+            inner: self.inner.order_by(value),
+            client: self.client,
+        }
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        Self {
+            inner: self.inner.variable(name, value),
+            client: self.client,
+        }
+    }
+    /// Build the query without sending it.
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        self.inner.into_operation()
+    }
+    pub fn send(self) -> Result<Connection<T>, LinearError> {
+        self.client.block_on(self.inner.send())
+    }
+    /// Fetch every page and collect all nodes into a `Vec`.
+    pub fn all(self) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.all())
+    }
+    /// Fetch pages until `max` nodes have been collected.
+    pub fn take_all(self, max: usize) -> Result<Vec<T>, LinearError> {
+        self.client.block_on(self.inner.take_all(max))
+    }
+}
+impl Client {
+    /// All issue workflow states (issue statuses). Returns a paginated list of workflow states visible to the authenticated user, across all teams they have access to.
+    ///
+    /// Full type: [`WorkflowState`](super::types::WorkflowState)
+    pub fn workflow_states<T>(&self) -> WorkflowStatesQueryBuilder<'_, T> {
+        WorkflowStatesQueryBuilder {
+            inner: crate::generated::queries::workflow_states(self.as_async()),
+            client: self,
+        }
+    }
+    /// One specific workflow state (issue status), looked up by its unique identifier.
+    ///
+    /// Full type: [`WorkflowState`](super::types::WorkflowState)
+    ///
+    /// Requires the `teams` feature.
+    #[cfg(feature = "teams")]
+    pub fn workflow_state<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::WorkflowState>,
+    >(
+        &self,
+        id: impl Into<super::ids::WorkflowStateId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::queries::workflow_state::<T>(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// All webhooks for the current workspace.
+    ///
+    /// Full type: [`Webhook`](super::types::Webhook)
+    ///
+    /// Requires the `webhooks` feature.
+    #[cfg(feature = "webhooks")]
+    pub fn webhooks<T>(&self) -> WebhooksQueryBuilder<'_, T> {
+        WebhooksQueryBuilder {
+            inner: crate::generated::queries::webhooks(self.as_async()),
+            client: self,
+        }
+    }
+    /// Retrieves a single webhook by its identifier.
+    ///
+    /// Full type: [`Webhook`](super::types::Webhook)
+    ///
+    /// Requires the `webhooks` feature.
+    #[cfg(feature = "webhooks")]
+    pub fn webhook<T: DeserializeOwned + GraphQLFields<FullType = super::types::Webhook>>(
+        &self,
+        id: impl Into<super::ids::WebhookId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::queries::webhook::<T>(self.as_async(), id))
+    }
+    /// `INTERNAL` Webhook failure events for webhooks that belong to an OAuth application. (last 50)
+    ///
+    /// Full type: [`WebhookFailureEvent`](super::types::WebhookFailureEvent)
+    ///
+    /// Requires the `webhooks` feature.
+    #[cfg(feature = "webhooks")]
+    pub fn failures_for_oauth_webhooks<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::WebhookFailureEvent>,
+    >(
+        &self,
+        oauth_client_id: String,
+    ) -> Result<Vec<T>, LinearError> {
+        self.block_on(crate::generated::queries::failures_for_oauth_webhooks::<T>(
+            self.as_async(),
+            oauth_client_id,
+        ))
+    }
+    /// The authenticated user's notification and UI settings.
+    ///
+    /// Full type: [`UserSettings`](super::types::UserSettings)
+    ///
+    /// Requires the `users` feature.
+    #[cfg(feature = "users")]
+    pub fn user_settings<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::UserSettings>,
+    >(
+        &self,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::queries::user_settings::<T>(
+            self.as_async(),
+        ))
+    }
+    /// All users in the workspace. Supports filtering, sorting, and pagination.
+    ///
+    /// Full type: [`User`](super::types::User)
+    pub fn users<T>(&self) -> UsersQueryBuilder<'_, T> {
+        UsersQueryBuilder {
+            inner: crate::generated::queries::users(self.as_async()),
+            client: self,
+        }
+    }
+    /// Fetches a specific user by their ID.
+    ///
+    /// Full type: [`User`](super::types::User)
+    ///
+    /// Requires the `users` feature.
+    #[cfg(feature = "users")]
+    pub fn user<T: DeserializeOwned + GraphQLFields<FullType = super::types::User>>(
+        &self,
+        id: impl Into<super::ids::UserId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::queries::user::<T>(self.as_async(), id))
+    }
+    /// The currently authenticated user making the API request.
+    ///
+    /// Full type: [`User`](super::types::User)
+    pub fn whoami<T: DeserializeOwned + GraphQLFields<FullType = super::types::User>>(
+        &self,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::queries::whoami::<T>(self.as_async()))
+    }
+    /// Lists all active authentication sessions for a user. Can only be called by a workspace admin or owner.
+    ///
+    /// Full type: [`AuthenticationSessionResponse`](super::types::AuthenticationSessionResponse)
+    ///
+    /// Requires the `users` feature.
+    #[cfg(feature = "users")]
+    pub fn user_sessions<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::AuthenticationSessionResponse>,
+    >(
+        &self,
+        id: impl Into<super::ids::AuthenticationSessionResponseId>,
+    ) -> Result<Vec<T>, LinearError> {
+        self.block_on(crate::generated::queries::user_sessions::<T>(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// All triage responsibilities.
+    ///
+    /// Full type: [`TriageResponsibility`](super::types::TriageResponsibility)
+    ///
+    /// Requires the `teams` feature.
+    #[cfg(feature = "teams")]
+    pub fn triage_responsibilities<T>(&self) -> TriageResponsibilitiesQueryBuilder<'_, T> {
+        TriageResponsibilitiesQueryBuilder {
+            inner: crate::generated::queries::triage_responsibilities(self.as_async()),
+            client: self,
+        }
+    }
+    /// A specific triage responsibility.
+    ///
+    /// Full type: [`TriageResponsibility`](super::types::TriageResponsibility)
+    ///
+    /// Requires the `teams` feature.
+    #[cfg(feature = "teams")]
+    pub fn triage_responsibility<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::TriageResponsibility>,
+    >(
+        &self,
+        id: impl Into<super::ids::TriageResponsibilityId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::queries::triage_responsibility::<T>(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// All time schedules.
+    ///
+    /// Full type: [`TimeSchedule`](super::types::TimeSchedule)
+    ///
+    /// Requires the `teams` feature.
+    #[cfg(feature = "teams")]
+    pub fn time_schedules<T>(&self) -> TimeSchedulesQueryBuilder<'_, T> {
+        TimeSchedulesQueryBuilder {
+            inner: crate::generated::queries::time_schedules(self.as_async()),
+            client: self,
+        }
+    }
+    /// A specific time schedule.
+    ///
+    /// Full type: [`TimeSchedule`](super::types::TimeSchedule)
+    ///
+    /// Requires the `teams` feature.
+    #[cfg(feature = "teams")]
+    pub fn time_schedule<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::TimeSchedule>,
+    >(
+        &self,
+        id: impl Into<super::ids::TimeScheduleId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::queries::time_schedule::<T>(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// All templates in the workspace, including both team-scoped and workspace-level templates.
+    ///
+    /// Full type: [`Template`](super::types::Template)
+    ///
+    /// Requires the `teams` feature.
+    #[cfg(feature = "teams")]
+    pub fn templates<T: DeserializeOwned + GraphQLFields<FullType = super::types::Template>>(
+        &self,
+    ) -> Result<Vec<T>, LinearError> {
+        self.block_on(crate::generated::queries::templates::<T>(self.as_async()))
+    }
+    /// A specific template.
+    ///
+    /// Full type: [`Template`](super::types::Template)
+    ///
+    /// Requires the `teams` feature.
+    #[cfg(feature = "teams")]
+    pub fn template<T: DeserializeOwned + GraphQLFields<FullType = super::types::Template>>(
+        &self,
+        id: impl Into<super::ids::TemplateId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::queries::template::<T>(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// Returns all templates that are associated with the integration type.
+    ///
+    /// Full type: [`Template`](super::types::Template)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn templates_for_integration<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::Template>,
+    >(
+        &self,
+        integration_type: String,
+    ) -> Result<Vec<T>, LinearError> {
+        self.block_on(crate::generated::queries::templates_for_integration::<T>(
+            self.as_async(),
+            integration_type,
+        ))
+    }
+    /// Returns all projects in the workspace, with optional filtering and sorting.
+    ///
+    /// Full type: [`Project`](super::types::Project)
+    pub fn projects<T>(&self) -> ProjectsQueryBuilder<'_, T> {
+        ProjectsQueryBuilder {
+            inner: crate::generated::queries::projects(self.as_async()),
+            client: self,
+        }
+    }
+    /// Returns a single project by its identifier or URL slug.
+    ///
+    /// Full type: [`Project`](super::types::Project)
+    pub fn project<T: DeserializeOwned + GraphQLFields<FullType = super::types::Project>>(
+        &self,
+        id: impl Into<super::ids::ProjectId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::queries::project::<T>(self.as_async(), id))
+    }
+    /// Suggests filters for a project view based on a text prompt.
+    ///
+    /// Full type: [`ProjectFilterSuggestionPayload`](super::types::ProjectFilterSuggestionPayload)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub fn project_filter_suggestion<T>(
+        &self,
+        prompt: impl Into<String>,
+    ) -> ProjectFilterSuggestionQueryBuilder<'_, T> {
+        ProjectFilterSuggestionQueryBuilder {
+            inner: crate::generated::queries::project_filter_suggestion(self.as_async(), prompt),
+            client: self,
+        }
+    }
+    /// All teams whose issues the user can access. This includes public teams and private teams the user is a member of. This may differ from `administrableTeams`, which returns teams whose settings the user can change but whose issues they don't necessarily have access to.
+    ///
+    /// Full type: [`Team`](super::types::Team)
+    pub fn teams<T>(&self) -> TeamsQueryBuilder<'_, T> {
+        TeamsQueryBuilder {
+            inner: crate::generated::queries::teams(self.as_async()),
+            client: self,
+        }
+    }
+    /// All teams whose settings the user can administer. This includes teams the user can change settings for, but whose issues they may not have direct access to. This differs from the `teams` query which returns teams based on issue access.
+    ///
+    /// Full type: [`Team`](super::types::Team)
+    ///
+    /// Requires the `teams` feature.
+    #[cfg(feature = "teams")]
+    pub fn administrable_teams<T>(&self) -> AdministrableTeamsQueryBuilder<'_, T> {
+        AdministrableTeamsQueryBuilder {
+            inner: crate::generated::queries::administrable_teams(self.as_async()),
+            client: self,
+        }
+    }
+    /// Fetches a specific team by its ID.
+    ///
+    /// Full type: [`Team`](super::types::Team)
+    pub fn team<T: DeserializeOwned + GraphQLFields<FullType = super::types::Team>>(
+        &self,
+        id: impl Into<super::ids::TeamId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::queries::team::<T>(self.as_async(), id))
+    }
+    /// All team memberships in the workspace.
+    ///
+    /// Full type: [`TeamMembership`](super::types::TeamMembership)
+    ///
+    /// Requires the `teams` feature.
+    #[cfg(feature = "teams")]
+    pub fn team_memberships<T>(&self) -> TeamMembershipsQueryBuilder<'_, T> {
+        TeamMembershipsQueryBuilder {
+            inner: crate::generated::queries::team_memberships(self.as_async()),
+            client: self,
+        }
+    }
+    /// Fetches a specific team membership by its ID.
+    ///
+    /// Full type: [`TeamMembership`](super::types::TeamMembership)
+    ///
+    /// Requires the `teams` feature.
+    #[cfg(feature = "teams")]
+    pub fn team_membership<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::TeamMembership>,
+    >(
+        &self,
+        id: impl Into<super::ids::TeamMembershipId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::queries::team_membership::<T>(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// Search for issues, projects, initiatives, and documents using natural language. Uses vector-based semantic search with optional full-text search and reranking. Results can be filtered by type and by entity-specific filters. Rate-limited to 30 requests per minute.
+    ///
+    /// Full type: [`SemanticSearchPayload`](super::types::SemanticSearchPayload)
+    ///
+    /// Requires the `agents` feature.
+    #[cfg(feature = "agents")]
+    pub fn semantic_search<T>(
+        &self,
+        query: impl Into<String>,
+    ) -> SemanticSearchQueryBuilder<'_, T> {
+        SemanticSearchQueryBuilder {
+            inner: crate::generated::queries::semantic_search(self.as_async(), query),
+            client: self,
+        }
+    }
+    /// Search documents by text query using full-text and vector search. Results are ranked by relevance unless an orderBy parameter is specified. Rate-limited to 30 requests per minute.
+    ///
+    /// Full type: [`DocumentSearchResult`](super::types::DocumentSearchResult)
+    ///
+    /// Requires the `documents` feature.
+    #[cfg(feature = "documents")]
+    pub fn search_documents<T>(
+        &self,
+        term: impl Into<String>,
+    ) -> SearchDocumentsQueryBuilder<'_, T> {
+        SearchDocumentsQueryBuilder {
+            inner: crate::generated::queries::search_documents(self.as_async(), term),
+            client: self,
+        }
+    }
+    /// Search projects by text query using full-text and vector search. Results are ranked by relevance unless an orderBy parameter is specified. Rate-limited to 30 requests per minute.
+    ///
+    /// Full type: [`ProjectSearchResult`](super::types::ProjectSearchResult)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub fn search_projects<T>(&self, term: impl Into<String>) -> SearchProjectsQueryBuilder<'_, T> {
+        SearchProjectsQueryBuilder {
+            inner: crate::generated::queries::search_projects(self.as_async(), term),
+            client: self,
+        }
+    }
+    /// Search issues by text query using full-text and vector search. Results are ranked by relevance unless an orderBy parameter is specified. Supports optional issue filters and comment inclusion. Rate-limited to 30 requests per minute.
+    ///
+    /// Full type: [`IssueSearchResult`](super::types::IssueSearchResult)
+    pub fn search_issues<T>(&self, term: impl Into<String>) -> SearchIssuesQueryBuilder<'_, T> {
+        SearchIssuesQueryBuilder {
+            inner: crate::generated::queries::search_issues(self.as_async(), term),
+            client: self,
+        }
+    }
+    /// `Deprecated` Returns all roadmap-to-project associations. Use InitiativeToProject instead.
+    ///
+    /// Full type: [`RoadmapToProject`](super::types::RoadmapToProject)
+    #[deprecated(note = "RoadmapToProject is deprecated, use InitiativeToProject instead.")]
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub fn roadmap_to_projects<T>(&self) -> RoadmapToProjectsQueryBuilder<'_, T> {
+        RoadmapToProjectsQueryBuilder {
+            inner: crate::generated::queries::roadmap_to_projects(self.as_async()),
+            client: self,
+        }
+    }
+    /// `Deprecated` Returns a single roadmap-to-project association. Use InitiativeToProject instead.
+    ///
+    /// Full type: [`RoadmapToProject`](super::types::RoadmapToProject)
+    #[deprecated(note = "RoadmapToProject is deprecated, use InitiativeToProject instead.")]
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub fn roadmap_to_project<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::RoadmapToProject>,
+    >(
+        &self,
+        id: impl Into<super::ids::RoadmapToProjectId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::queries::roadmap_to_project::<T>(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// `Deprecated` Returns all roadmaps in the workspace. Use initiatives instead.
+    ///
+    /// Full type: [`Roadmap`](super::types::Roadmap)
+    #[deprecated(note = "Roadmaps are deprecated, use initiatives instead.")]
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub fn roadmaps<T>(&self) -> RoadmapsQueryBuilder<'_, T> {
+        RoadmapsQueryBuilder {
+            inner: crate::generated::queries::roadmaps(self.as_async()),
+            client: self,
+        }
+    }
+    /// `Deprecated` Returns a single roadmap by its identifier. Use initiatives instead.
+    ///
+    /// Full type: [`Roadmap`](super::types::Roadmap)
+    #[deprecated(note = "Roadmaps are deprecated, use initiatives instead.")]
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub fn roadmap<T: DeserializeOwned + GraphQLFields<FullType = super::types::Roadmap>>(
+        &self,
+        id: impl Into<super::ids::RoadmapId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::queries::roadmap::<T>(self.as_async(), id))
+    }
+    /// `ALPHA` All release stages in the workspace, with optional filtering.
+    ///
+    /// Full type: [`ReleaseStage`](super::types::ReleaseStage)
+    ///
+    /// Requires the `releases` feature.
+    #[cfg(feature = "releases")]
+    pub fn release_stages<T>(&self) -> ReleaseStagesQueryBuilder<'_, T> {
+        ReleaseStagesQueryBuilder {
+            inner: crate::generated::queries::release_stages(self.as_async()),
+            client: self,
+        }
+    }
+    /// `ALPHA` Fetch a single release stage by its UUID.
+    ///
+    /// Full type: [`ReleaseStage`](super::types::ReleaseStage)
+    ///
+    /// Requires the `releases` feature.
+    #[cfg(feature = "releases")]
+    pub fn release_stage<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::ReleaseStage>,
+    >(
+        &self,
+        id: impl Into<super::ids::ReleaseStageId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::queries::release_stage::<T>(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// `ALPHA` All release pipelines in the workspace, with optional filtering and sorting.
+    ///
+    /// Full type: [`ReleasePipeline`](super::types::ReleasePipeline)
+    ///
+    /// Requires the `releases` feature.
+    #[cfg(feature = "releases")]
+    pub fn release_pipelines<T>(&self) -> ReleasePipelinesQueryBuilder<'_, T> {
+        ReleasePipelinesQueryBuilder {
+            inner: crate::generated::queries::release_pipelines(self.as_async()),
+            client: self,
+        }
+    }
+    /// `ALPHA` Fetch a single release pipeline by its UUID or slug identifier.
+    ///
+    /// Full type: [`ReleasePipeline`](super::types::ReleasePipeline)
+    ///
+    /// Requires the `releases` feature.
+    #[cfg(feature = "releases")]
+    pub fn release_pipeline<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::ReleasePipeline>,
+    >(
+        &self,
+        id: impl Into<super::ids::ReleasePipelineId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::queries::release_pipeline::<T>(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// `ALPHA` Returns a release pipeline by ID. Requires the access key to have access to the pipeline.
+    ///
+    /// Full type: [`ReleasePipeline`](super::types::ReleasePipeline)
+    ///
+    /// Requires the `releases` feature.
+    #[cfg(feature = "releases")]
+    pub fn release_pipeline_by_access_key<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::ReleasePipeline>,
+    >(
+        &self,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::queries::release_pipeline_by_access_key::<T>(self.as_async()),
+        )
+    }
+    /// `ALPHA` Returns the latest release for the pipeline associated with the access key.
+    ///
+    /// Full type: [`Release`](super::types::Release)
+    ///
+    /// Requires the `releases` feature.
+    #[cfg(feature = "releases")]
+    pub fn latest_release_by_access_key<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::Release>,
+    >(
+        &self,
+    ) -> Result<Option<T>, LinearError> {
+        self.block_on(crate::generated::queries::latest_release_by_access_key::<T>(self.as_async()))
+    }
+    /// `ALPHA` All releases in the workspace, with optional filtering and sorting.
+    ///
+    /// Full type: [`Release`](super::types::Release)
+    ///
+    /// Requires the `releases` feature.
+    #[cfg(feature = "releases")]
+    pub fn releases<T>(&self) -> ReleasesQueryBuilder<'_, T> {
+        ReleasesQueryBuilder {
+            inner: crate::generated::queries::releases(self.as_async()),
+            client: self,
+        }
+    }
+    /// `ALPHA` Fetch a single release by its UUID or slug identifier.
+    ///
+    /// Full type: [`Release`](super::types::Release)
+    ///
+    /// Requires the `releases` feature.
+    #[cfg(feature = "releases")]
+    pub fn release<T: DeserializeOwned + GraphQLFields<FullType = super::types::Release>>(
+        &self,
+        id: impl Into<super::ids::ReleaseId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::queries::release::<T>(self.as_async(), id))
+    }
+    /// `ALPHA` Search releases with optional text matching against name, version, and pipeline name. When no search term is provided, returns releases ordered by stage priority (started > planned > completed > canceled).
+    ///
+    /// Full type: [`Release`](super::types::Release)
+    ///
+    /// Requires the `releases` feature.
+    #[cfg(feature = "releases")]
+    pub fn release_search<T>(&self) -> ReleaseSearchQueryBuilder<'_, T> {
+        ReleaseSearchQueryBuilder {
+            inner: crate::generated::queries::release_search(self.as_async()),
+            client: self,
+        }
+    }
+    /// `ALPHA` Release notes in the workspace.
+    ///
+    /// Full type: [`ReleaseNote`](super::types::ReleaseNote)
+    ///
+    /// Requires the `releases` feature.
+    #[cfg(feature = "releases")]
+    pub fn release_notes<T>(&self) -> ReleaseNotesQueryBuilder<'_, T> {
+        ReleaseNotesQueryBuilder {
+            inner: crate::generated::queries::release_notes(self.as_async()),
+            client: self,
+        }
+    }
+    /// `ALPHA` Fetch a release note by its identifier.
+    ///
+    /// Full type: [`ReleaseNote`](super::types::ReleaseNote)
+    ///
+    /// Requires the `releases` feature.
+    #[cfg(feature = "releases")]
+    pub fn release_note<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::ReleaseNote>,
+    >(
+        &self,
+        id: impl Into<super::ids::ReleaseNoteId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::queries::release_note::<T>(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// The current rate limit status for the authenticated client, including remaining quota and reset timing for each limit type.
+    ///
+    /// Full type: [`RateLimitPayload`](super::types::RateLimitPayload)
+    pub fn rate_limit_status<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::RateLimitPayload>,
+    >(
+        &self,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::queries::rate_limit_status::<T>(
+            self.as_async(),
+        ))
+    }
+    /// Sends a test push notification to the authenticated user's registered devices. Useful for verifying that push notification delivery is working correctly.
+    ///
+    /// Full type: [`PushSubscriptionTestPayload`](super::types::PushSubscriptionTestPayload)
+    ///
+    /// Requires the `notifications` feature.
+    #[cfg(feature = "notifications")]
+    pub fn push_subscription_test<T>(&self) -> PushSubscriptionTestQueryBuilder<'_, T> {
+        PushSubscriptionTestQueryBuilder {
+            inner: crate::generated::queries::push_subscription_test(self.as_async()),
+            client: self,
+        }
+    }
+    /// Returns all project status updates in the workspace, with optional filtering.
+    ///
+    /// Full type: [`ProjectUpdate`](super::types::ProjectUpdate)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub fn project_updates<T>(&self) -> ProjectUpdatesQueryBuilder<'_, T> {
+        ProjectUpdatesQueryBuilder {
+            inner: crate::generated::queries::project_updates(self.as_async()),
+            client: self,
+        }
+    }
+    /// Returns a single project update by its identifier.
+    ///
+    /// Full type: [`ProjectUpdate`](super::types::ProjectUpdate)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub fn project_update_post<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::ProjectUpdate>,
+    >(
+        &self,
+        id: impl Into<super::ids::ProjectUpdateId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::queries::project_update_post::<T>(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// Returns all project statuses in the workspace.
+    ///
+    /// Full type: [`ProjectStatus`](super::types::ProjectStatus)
+    pub fn project_statuses<T>(&self) -> ProjectStatusesQueryBuilder<'_, T> {
+        ProjectStatusesQueryBuilder {
+            inner: crate::generated::queries::project_statuses(self.as_async()),
+            client: self,
+        }
+    }
+    /// `INTERNAL` Count of projects using this project status across the workspace.
+    ///
+    /// Full type: [`ProjectStatusCountPayload`](super::types::ProjectStatusCountPayload)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub fn project_status_project_count<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::ProjectStatusCountPayload>,
+    >(
+        &self,
+        id: impl Into<super::ids::ProjectStatusId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::queries::project_status_project_count::<T>(self.as_async(), id),
+        )
+    }
+    /// Returns a single project status by its identifier.
+    ///
+    /// Full type: [`ProjectStatus`](super::types::ProjectStatus)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub fn project_status<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::ProjectStatus>,
+    >(
+        &self,
+        id: impl Into<super::ids::ProjectStatusId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::queries::project_status::<T>(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// Returns all project dependency relations in the workspace.
+    ///
+    /// Full type: [`ProjectRelation`](super::types::ProjectRelation)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub fn project_relations<T>(&self) -> ProjectRelationsQueryBuilder<'_, T> {
+        ProjectRelationsQueryBuilder {
+            inner: crate::generated::queries::project_relations(self.as_async()),
+            client: self,
+        }
+    }
+    /// Returns a single project relation by its identifier.
+    ///
+    /// Full type: [`ProjectRelation`](super::types::ProjectRelation)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub fn project_relation<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::ProjectRelation>,
+    >(
+        &self,
+        id: impl Into<super::ids::ProjectRelationId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::queries::project_relation::<T>(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// Returns all project milestones in the workspace, with optional filtering.
+    ///
+    /// Full type: [`ProjectMilestone`](super::types::ProjectMilestone)
+    pub fn project_milestones<T>(&self) -> ProjectMilestonesQueryBuilder<'_, T> {
+        ProjectMilestonesQueryBuilder {
+            inner: crate::generated::queries::project_milestones(self.as_async()),
+            client: self,
+        }
+    }
+    /// Returns a single project milestone by its identifier.
+    ///
+    /// Full type: [`ProjectMilestone`](super::types::ProjectMilestone)
+    pub fn project_milestone<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::ProjectMilestone>,
+    >(
+        &self,
+        id: impl Into<super::ids::ProjectMilestoneId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::queries::project_milestone::<T>(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// Returns all project labels in the workspace, with optional filtering.
+    ///
+    /// Full type: [`ProjectLabel`](super::types::ProjectLabel)
+    pub fn project_labels<T>(&self) -> ProjectLabelsQueryBuilder<'_, T> {
+        ProjectLabelsQueryBuilder {
+            inner: crate::generated::queries::project_labels(self.as_async()),
+            client: self,
+        }
+    }
+    /// Returns a single project label by its identifier.
+    ///
+    /// Full type: [`ProjectLabel`](super::types::ProjectLabel)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub fn project_label<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::ProjectLabel>,
+    >(
+        &self,
+        id: impl Into<super::ids::ProjectLabelId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::queries::project_label::<T>(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// The authenticated user's workspace.
+    ///
+    /// Full type: [`Organization`](super::types::Organization)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub fn organization<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::Organization>,
+    >(
+        &self,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::queries::organization::<T>(
+            self.as_async(),
+        ))
+    }
+    /// Checks whether a workspace with the given URL key exists.
+    ///
+    /// Full type: [`OrganizationExistsPayload`](super::types::OrganizationExistsPayload)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub fn organization_exists<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::OrganizationExistsPayload>,
+    >(
+        &self,
+        url_key: String,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::queries::organization_exists::<T>(
+            self.as_async(),
+            url_key,
+        ))
+    }
+    /// `Internal` All archived teams of the workspace.
+    ///
+    /// Full type: [`Team`](super::types::Team)
+    ///
+    /// Requires the `teams` feature.
+    #[cfg(feature = "teams")]
+    pub fn archived_teams<T: DeserializeOwned + GraphQLFields<FullType = super::types::Team>>(
+        &self,
+    ) -> Result<Vec<T>, LinearError> {
+        self.block_on(crate::generated::queries::archived_teams::<T>(
+            self.as_async(),
+        ))
+    }
+    /// `INTERNAL` Get workspace metadata by URL key or workspace ID.
+    ///
+    /// Full type: [`OrganizationMeta`](super::types::OrganizationMeta)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub fn organization_meta<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::OrganizationMeta>,
+    >(
+        &self,
+        url_key: String,
+    ) -> Result<Option<T>, LinearError> {
+        self.block_on(crate::generated::queries::organization_meta::<T>(
+            self.as_async(),
+            url_key,
+        ))
+    }
+    /// All pending and accepted invites for the workspace.
+    ///
+    /// Full type: [`OrganizationInvite`](super::types::OrganizationInvite)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub fn organization_invites<T>(&self) -> OrganizationInvitesQueryBuilder<'_, T> {
+        OrganizationInvitesQueryBuilder {
+            inner: crate::generated::queries::organization_invites(self.as_async()),
+            client: self,
+        }
+    }
+    /// Fetches a specific workspace invite by its ID.
+    ///
+    /// Full type: [`OrganizationInvite`](super::types::OrganizationInvite)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub fn organization_invite<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::OrganizationInvite>,
+    >(
+        &self,
+        id: impl Into<super::ids::OrganizationInviteId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::queries::organization_invite::<T>(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// Fetches public details of a specific workspace invite by its ID. This query does not require authentication and is used for invite acceptance flows.
+    ///
+    /// Full type: [`OrganizationInviteDetailsPayload`](super::types::OrganizationInviteDetailsPayload)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub fn organization_invite_details<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::OrganizationInviteDetailsPayload>,
+    >(
+        &self,
+        id: impl Into<super::ids::OrganizationInviteId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::queries::organization_invite_details::<T>(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// `INTERNAL` Checks whether the domain can be claimed.
+    ///
+    /// Full type: [`OrganizationDomainClaimPayload`](super::types::OrganizationDomainClaimPayload)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub fn organization_domain_claim_request<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::OrganizationDomainClaimPayload>,
+    >(
+        &self,
+        id: impl Into<super::ids::OrganizationDomainId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::queries::organization_domain_claim_request::<T>(self.as_async(), id),
+        )
+    }
+    /// The authenticated user's notification subscriptions. These subscriptions control which notifications the user receives for specific entities such as teams, projects, cycles, labels, custom views, initiatives, customers, and users.
+    ///
+    /// Full type: [`NotificationSubscription`](super::types::NotificationSubscription)
+    ///
+    /// Requires the `notifications` feature.
+    #[cfg(feature = "notifications")]
+    pub fn notification_subscriptions<T>(&self) -> NotificationSubscriptionsQueryBuilder<'_, T> {
+        NotificationSubscriptionsQueryBuilder {
+            inner: crate::generated::queries::notification_subscriptions(self.as_async()),
+            client: self,
+        }
+    }
+    /// A specific notification subscription by ID.
+    ///
+    /// Full type: [`NotificationSubscription`](super::types::NotificationSubscription)
+    ///
+    /// Requires the `notifications` feature.
+    #[cfg(feature = "notifications")]
+    pub fn notification_subscription<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::NotificationSubscription>,
+    >(
+        &self,
+        id: String,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::queries::notification_subscription::<T>(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// The authenticated user's notifications.
+    ///
+    /// Full type: [`Notification`](super::types::Notification)
+    pub fn notifications<T>(&self) -> NotificationsQueryBuilder<'_, T> {
+        NotificationsQueryBuilder {
+            inner: crate::generated::queries::notifications(self.as_async()),
+            client: self,
+        }
+    }
+    /// A specific notification by ID.
+    ///
+    /// Full type: [`Notification`](super::types::Notification)
+    pub fn notification<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::Notification>,
+    >(
+        &self,
+        id: String,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::queries::notification::<T>(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// `ALPHA` All issue-to-release associations. Returns a paginated list of all issue-to-release links visible to the authenticated user.
+    ///
+    /// Full type: [`IssueToRelease`](super::types::IssueToRelease)
+    ///
+    /// Requires the `releases` feature.
+    #[cfg(feature = "releases")]
+    pub fn issue_to_releases<T>(&self) -> IssueToReleasesQueryBuilder<'_, T> {
+        IssueToReleasesQueryBuilder {
+            inner: crate::generated::queries::issue_to_releases(self.as_async()),
+            client: self,
+        }
+    }
+    /// `ALPHA` One specific issue-to-release association, looked up by its unique identifier.
+    ///
+    /// Full type: [`IssueToRelease`](super::types::IssueToRelease)
+    ///
+    /// Requires the `releases` feature.
+    #[cfg(feature = "releases")]
+    pub fn issue_to_release<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::IssueToRelease>,
+    >(
+        &self,
+        id: impl Into<super::ids::IssueToReleaseId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::queries::issue_to_release::<T>(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// All issues. Returns a paginated list of issues visible to the authenticated user. Can be filtered by various criteria including team, assignee, state, labels, project, and cycle.
+    ///
+    /// Full type: [`Issue`](super::types::Issue)
+    pub fn issues<T>(&self) -> IssuesQueryBuilder<'_, T> {
+        IssuesQueryBuilder {
+            inner: crate::generated::queries::issues(self.as_async()),
+            client: self,
+        }
+    }
+    /// One specific issue, looked up by its unique identifier.
+    ///
+    /// Full type: [`Issue`](super::types::Issue)
+    pub fn issue<T: DeserializeOwned + GraphQLFields<FullType = super::types::Issue>>(
+        &self,
+        id: impl Into<super::ids::IssueId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::queries::issue::<T>(self.as_async(), id))
+    }
+    /// `DEPRECATED` Search issues. This endpoint is deprecated and will be removed in the future – use `searchIssues` instead.
+    ///
+    /// Full type: [`Issue`](super::types::Issue)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub fn issue_search<T>(&self) -> IssueSearchQueryBuilder<'_, T> {
+        IssueSearchQueryBuilder {
+            inner: crate::generated::queries::issue_search(self.as_async()),
+            client: self,
+        }
+    }
+    /// Find issue based on the VCS branch name.
+    ///
+    /// Full type: [`Issue`](super::types::Issue)
+    pub fn issue_vcs_branch_search<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::Issue>,
+    >(
+        &self,
+        branch_name: String,
+    ) -> Result<Option<T>, LinearError> {
+        self.block_on(crate::generated::queries::issue_vcs_branch_search::<T>(
+            self.as_async(),
+            branch_name,
+        ))
+    }
+    /// Find issues that are related to a given Figma file key.
+    ///
+    /// Full type: [`Issue`](super::types::Issue)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub fn issue_figma_file_key_search<T>(
+        &self,
+        file_key: impl Into<String>,
+    ) -> IssueFigmaFileKeySearchQueryBuilder<'_, T> {
+        IssueFigmaFileKeySearchQueryBuilder {
+            inner: crate::generated::queries::issue_figma_file_key_search(
+                self.as_async(),
+                file_key,
+            ),
+            client: self,
+        }
+    }
+    /// Issue priority values and corresponding labels.
+    ///
+    /// Full type: [`IssuePriorityValue`](super::types::IssuePriorityValue)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub fn issue_priority_values<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::IssuePriorityValue>,
+    >(
+        &self,
+    ) -> Result<Vec<T>, LinearError> {
+        self.block_on(crate::generated::queries::issue_priority_values::<T>(
+            self.as_async(),
+        ))
+    }
+    /// Suggests filters for an issue view based on a text prompt.
+    ///
+    /// Full type: [`IssueFilterSuggestionPayload`](super::types::IssueFilterSuggestionPayload)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub fn issue_filter_suggestion<T>(
+        &self,
+        prompt: impl Into<String>,
+    ) -> IssueFilterSuggestionQueryBuilder<'_, T> {
+        IssueFilterSuggestionQueryBuilder {
+            inner: crate::generated::queries::issue_filter_suggestion(self.as_async(), prompt),
+            client: self,
+        }
+    }
+    /// Returns code repositories that are most likely to be relevant for implementing an issue.
+    ///
+    /// Full type: [`RepositorySuggestionsPayload`](super::types::RepositorySuggestionsPayload)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub fn issue_repository_suggestions<T>(
+        &self,
+        candidate_repositories: CandidateRepository,
+        issue_id: impl Into<String>,
+    ) -> IssueRepositorySuggestionsQueryBuilder<'_, T> {
+        IssueRepositorySuggestionsQueryBuilder {
+            inner: crate::generated::queries::issue_repository_suggestions(
+                self.as_async(),
+                candidate_repositories,
+                issue_id,
+            ),
+            client: self,
+        }
+    }
+    /// All issue relations. Returns a paginated list of all issue relations (blocks, blocked by, relates to, duplicates) visible to the authenticated user.
+    ///
+    /// Full type: [`IssueRelation`](super::types::IssueRelation)
+    pub fn issue_relations<T>(&self) -> IssueRelationsQueryBuilder<'_, T> {
+        IssueRelationsQueryBuilder {
+            inner: crate::generated::queries::issue_relations(self.as_async()),
+            client: self,
+        }
+    }
+    /// One specific issue relation, looked up by its unique identifier.
+    ///
+    /// Full type: [`IssueRelation`](super::types::IssueRelation)
+    pub fn issue_relation<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::IssueRelation>,
+    >(
+        &self,
+        id: impl Into<super::ids::IssueRelationId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::queries::issue_relation::<T>(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// All issue labels. Returns a paginated list of labels visible to the authenticated user, including both workspace-level and team-scoped labels.
+    ///
+    /// Full type: [`IssueLabel`](super::types::IssueLabel)
+    pub fn issue_labels<T>(&self) -> IssueLabelsQueryBuilder<'_, T> {
+        IssueLabelsQueryBuilder {
+            inner: crate::generated::queries::issue_labels(self.as_async()),
+            client: self,
+        }
+    }
+    /// One specific label, looked up by its unique identifier.
+    ///
+    /// Full type: [`IssueLabel`](super::types::IssueLabel)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub fn issue_label<T: DeserializeOwned + GraphQLFields<FullType = super::types::IssueLabel>>(
+        &self,
+        id: impl Into<super::ids::IssueLabelId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::queries::issue_label::<T>(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// Checks a CSV file validity against a specific import service.
+    ///
+    /// Full type: [`IssueImportCheckPayload`](super::types::IssueImportCheckPayload)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub fn issue_import_check_csv<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::IssueImportCheckPayload>,
+    >(
+        &self,
+        csv_url: String,
+        service: String,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::queries::issue_import_check_csv::<T>(
+            self.as_async(),
+            csv_url,
+            service,
+        ))
+    }
+    /// Checks whether it will be possible to set up sync for this project or repository at the end of import.
+    ///
+    /// Full type: [`IssueImportSyncCheckPayload`](super::types::IssueImportSyncCheckPayload)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub fn issue_import_check_sync<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::IssueImportSyncCheckPayload>,
+    >(
+        &self,
+        issue_import_id: String,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::queries::issue_import_check_sync::<T>(
+            self.as_async(),
+            issue_import_id,
+        ))
+    }
+    /// Checks whether a custom JQL query is valid and can be used to filter issues of a Jira import.
+    ///
+    /// Full type: [`IssueImportJqlCheckPayload`](super::types::IssueImportJqlCheckPayload)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub fn issue_import_jql_check<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::IssueImportJqlCheckPayload>,
+    >(
+        &self,
+        jira_hostname: String,
+        jira_token: String,
+        jira_email: String,
+        jira_project: String,
+        jql: String,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::queries::issue_import_jql_check::<T>(
+            self.as_async(),
+            jira_hostname,
+            jira_token,
+            jira_email,
+            jira_project,
+            jql,
+        ))
+    }
+    /// Retrieves a specific integration settings configuration by its identifier.
+    ///
+    /// Full type: [`IntegrationsSettings`](super::types::IntegrationsSettings)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn integrations_settings<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::IntegrationsSettings>,
+    >(
+        &self,
+        id: impl Into<super::ids::IntegrationsSettingsId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::queries::integrations_settings::<T>(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// All integration template connections for the workspace, linking templates to integrations.
+    ///
+    /// Full type: [`IntegrationTemplate`](super::types::IntegrationTemplate)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn integration_templates<T>(&self) -> IntegrationTemplatesQueryBuilder<'_, T> {
+        IntegrationTemplatesQueryBuilder {
+            inner: crate::generated::queries::integration_templates(self.as_async()),
+            client: self,
+        }
+    }
+    /// Retrieves a single integration template connection by its identifier.
+    ///
+    /// Full type: [`IntegrationTemplate`](super::types::IntegrationTemplate)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn integration_template<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::IntegrationTemplate>,
+    >(
+        &self,
+        id: impl Into<super::ids::IntegrationTemplateId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::queries::integration_template::<T>(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// All integrations for the workspace.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn integrations<T>(&self) -> IntegrationsQueryBuilder<'_, T> {
+        IntegrationsQueryBuilder {
+            inner: crate::generated::queries::integrations(self.as_async()),
+            client: self,
+        }
+    }
+    /// Retrieves a single integration by its identifier.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn integration<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        id: impl Into<super::ids::IntegrationId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::queries::integration::<T>(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// Verify that we received the correct response from the GitHub Enterprise Server.
+    ///
+    /// Full type: [`GitHubEnterpriseServerInstallVerificationPayload`](super::types::GitHubEnterpriseServerInstallVerificationPayload)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn verify_git_hub_enterprise_server_installation<
+        T: DeserializeOwned
+            + GraphQLFields<FullType = super::types::GitHubEnterpriseServerInstallVerificationPayload>,
+    >(
+        &self,
+        integration_id: String,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::queries::verify_git_hub_enterprise_server_installation::<T>(
+                self.as_async(),
+                integration_id,
+            ),
+        )
+    }
+    /// Checks if the integration has all required scopes.
+    ///
+    /// Full type: [`IntegrationHasScopesPayload`](super::types::IntegrationHasScopesPayload)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn integration_has_scopes<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::IntegrationHasScopesPayload>,
+    >(
+        &self,
+        scopes: Vec<String>,
+        integration_id: String,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::queries::integration_has_scopes::<T>(
+            self.as_async(),
+            scopes,
+            integration_id,
+        ))
+    }
+    /// `Internal` Lists Microsoft Teams teams and channels accessible to the connecting user. Uses the user's microsoftPersonal integration for auth. Used by the project channel picker dialog to populate the team/channel dropdowns.
+    ///
+    /// Full type: [`MicrosoftTeamsChannelsPayload`](super::types::MicrosoftTeamsChannelsPayload)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn microsoft_teams_channels<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::MicrosoftTeamsChannelsPayload>,
+    >(
+        &self,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::queries::microsoft_teams_channels::<T>(
+            self.as_async(),
+        ))
+    }
+    /// Returns all initiative status updates in the workspace, with optional filtering.
+    ///
+    /// Full type: [`InitiativeUpdate`](super::types::InitiativeUpdate)
+    ///
+    /// Requires the `initiatives` feature.
+    #[cfg(feature = "initiatives")]
+    pub fn initiative_updates<T>(&self) -> InitiativeUpdatesQueryBuilder<'_, T> {
+        InitiativeUpdatesQueryBuilder {
+            inner: crate::generated::queries::initiative_updates(self.as_async()),
+            client: self,
+        }
+    }
+    /// Returns a single initiative update by its identifier.
+    ///
+    /// Full type: [`InitiativeUpdate`](super::types::InitiativeUpdate)
+    ///
+    /// Requires the `initiatives` feature.
+    #[cfg(feature = "initiatives")]
+    pub fn initiative_update_post<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::InitiativeUpdate>,
+    >(
+        &self,
+        id: impl Into<super::ids::InitiativeUpdateId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::queries::initiative_update_post::<T>(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// Returns all initiative-to-project associations in the workspace.
+    ///
+    /// Full type: [`InitiativeToProject`](super::types::InitiativeToProject)
+    ///
+    /// Requires the `initiatives` feature.
+    #[cfg(feature = "initiatives")]
+    pub fn initiative_to_projects<T>(&self) -> InitiativeToProjectsQueryBuilder<'_, T> {
+        InitiativeToProjectsQueryBuilder {
+            inner: crate::generated::queries::initiative_to_projects(self.as_async()),
+            client: self,
+        }
+    }
+    /// Returns a single initiative-to-project association by its identifier.
+    ///
+    /// Full type: [`InitiativeToProject`](super::types::InitiativeToProject)
+    ///
+    /// Requires the `initiatives` feature.
+    #[cfg(feature = "initiatives")]
+    pub fn initiative_to_project<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::InitiativeToProject>,
+    >(
+        &self,
+        id: impl Into<super::ids::InitiativeToProjectId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::queries::initiative_to_project::<T>(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// Returns all initiatives in the workspace, with optional filtering and sorting.
+    ///
+    /// Full type: [`Initiative`](super::types::Initiative)
+    ///
+    /// Requires the `initiatives` feature.
+    #[cfg(feature = "initiatives")]
+    pub fn initiatives<T>(&self) -> InitiativesQueryBuilder<'_, T> {
+        InitiativesQueryBuilder {
+            inner: crate::generated::queries::initiatives(self.as_async()),
+            client: self,
+        }
+    }
+    /// Returns a single initiative by its identifier or URL slug.
+    ///
+    /// Full type: [`Initiative`](super::types::Initiative)
+    ///
+    /// Requires the `initiatives` feature.
+    #[cfg(feature = "initiatives")]
+    pub fn initiative<T: DeserializeOwned + GraphQLFields<FullType = super::types::Initiative>>(
+        &self,
+        id: impl Into<super::ids::InitiativeId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::queries::initiative::<T>(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// Returns all initiative parent-child relations in the workspace.
+    ///
+    /// Full type: [`InitiativeRelation`](super::types::InitiativeRelation)
+    ///
+    /// Requires the `initiatives` feature.
+    #[cfg(feature = "initiatives")]
+    pub fn initiative_relations<T>(&self) -> InitiativeRelationsQueryBuilder<'_, T> {
+        InitiativeRelationsQueryBuilder {
+            inner: crate::generated::queries::initiative_relations(self.as_async()),
+            client: self,
+        }
+    }
+    /// Returns a single initiative relation by its identifier.
+    ///
+    /// Full type: [`InitiativeRelation`](super::types::InitiativeRelation)
+    ///
+    /// Requires the `initiatives` feature.
+    #[cfg(feature = "initiatives")]
+    pub fn initiative_relation<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::InitiativeRelation>,
+    >(
+        &self,
+        id: impl Into<super::ids::InitiativeRelationId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::queries::initiative_relation::<T>(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// `Internal` Fetch an arbitrary set of data using natural language query. Be specific about what you want including properties for each entity, sort order, filters, limit and properties.
+    ///
+    /// Full type: [`FetchDataPayload`](super::types::FetchDataPayload)
+    ///
+    /// Requires the `agents` feature.
+    #[cfg(feature = "agents")]
+    pub fn fetch_data<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::FetchDataPayload>,
+    >(
+        &self,
+        query: String,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::queries::fetch_data::<T>(
+            self.as_async(),
+            query,
+        ))
+    }
+    /// The authenticated user's favorites. Returns all bookmarked items that appear in the user's sidebar.
+    ///
+    /// Full type: [`Favorite`](super::types::Favorite)
+    ///
+    /// Requires the `views` feature.
+    #[cfg(feature = "views")]
+    pub fn favorites<T>(&self) -> FavoritesQueryBuilder<'_, T> {
+        FavoritesQueryBuilder {
+            inner: crate::generated::queries::favorites(self.as_async()),
+            client: self,
+        }
+    }
+    /// A specific favorite by ID.
+    ///
+    /// Full type: [`Favorite`](super::types::Favorite)
+    ///
+    /// Requires the `views` feature.
+    #[cfg(feature = "views")]
+    pub fn favorite<T: DeserializeOwned + GraphQLFields<FullType = super::types::Favorite>>(
+        &self,
+        id: impl Into<super::ids::FavoriteId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::queries::favorite::<T>(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// All external users for the organization. External users are people who interact with Linear through integrated services (Slack, Jira, GitHub, etc.) without having a Linear account.
+    ///
+    /// Full type: [`ExternalUser`](super::types::ExternalUser)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn external_users<T>(&self) -> ExternalUsersQueryBuilder<'_, T> {
+        ExternalUsersQueryBuilder {
+            inner: crate::generated::queries::external_users(self.as_async()),
+            client: self,
+        }
+    }
+    /// Retrieves a single external user by their identifier.
+    ///
+    /// Full type: [`ExternalUser`](super::types::ExternalUser)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn external_user<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::ExternalUser>,
+    >(
+        &self,
+        id: impl Into<super::ids::ExternalUserId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::queries::external_user::<T>(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// Retrieves a single entity external link by its identifier.
+    ///
+    /// Full type: [`EntityExternalLink`](super::types::EntityExternalLink)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub fn entity_external_link<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::EntityExternalLink>,
+    >(
+        &self,
+        id: impl Into<super::ids::EntityExternalLinkId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::queries::entity_external_link::<T>(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// All custom emojis in the workspace.
+    ///
+    /// Full type: [`Emoji`](super::types::Emoji)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub fn emojis<T>(&self) -> EmojisQueryBuilder<'_, T> {
+        EmojisQueryBuilder {
+            inner: crate::generated::queries::emojis(self.as_async()),
+            client: self,
+        }
+    }
+    /// A specific custom emoji by ID or name.
+    ///
+    /// Full type: [`Emoji`](super::types::Emoji)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub fn emoji<T: DeserializeOwned + GraphQLFields<FullType = super::types::Emoji>>(
+        &self,
+        id: impl Into<super::ids::EmojiId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::queries::emoji::<T>(self.as_async(), id))
+    }
+    /// One specific email intake address.
+    ///
+    /// Full type: [`EmailIntakeAddress`](super::types::EmailIntakeAddress)
+    ///
+    /// Requires the `teams` feature.
+    #[cfg(feature = "teams")]
+    pub fn email_intake_address<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::EmailIntakeAddress>,
+    >(
+        &self,
+        id: impl Into<super::ids::EmailIntakeAddressId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::queries::email_intake_address::<T>(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// All documents the user has access to in the workspace.
+    ///
+    /// Full type: [`Document`](super::types::Document)
+    pub fn documents<T>(&self) -> DocumentsQueryBuilder<'_, T> {
+        DocumentsQueryBuilder {
+            inner: crate::generated::queries::documents(self.as_async()),
+            client: self,
+        }
+    }
+    /// A specific document by ID or slug.
+    ///
+    /// Full type: [`Document`](super::types::Document)
+    pub fn document<T: DeserializeOwned + GraphQLFields<FullType = super::types::Document>>(
+        &self,
+        id: impl Into<super::ids::DocumentId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::queries::document::<T>(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// A collection of document content history entries.
+    ///
+    /// Full type: [`DocumentContentHistoryPayload`](super::types::DocumentContentHistoryPayload)
+    ///
+    /// Requires the `documents` feature.
+    #[cfg(feature = "documents")]
+    pub fn document_content_history<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::DocumentContentHistoryPayload>,
+    >(
+        &self,
+        id: impl Into<super::ids::DocumentContentHistoryTypeId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::queries::document_content_history::<T>(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// `Internal` Fetches document content history entries by their IDs, including content data.
+    ///
+    /// Full type: [`DocumentContentHistoryPayload`](super::types::DocumentContentHistoryPayload)
+    ///
+    /// Requires the `documents` feature.
+    #[cfg(feature = "documents")]
+    pub fn document_content_history_entries<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::DocumentContentHistoryPayload>,
+    >(
+        &self,
+        entry_ids: Vec<String>,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::queries::document_content_history_entries::<T>(
+                self.as_async(),
+                entry_ids,
+            ),
+        )
+    }
+    /// All cycles accessible to the user.
+    ///
+    /// Full type: [`Cycle`](super::types::Cycle)
+    pub fn cycles<T>(&self) -> CyclesQueryBuilder<'_, T> {
+        CyclesQueryBuilder {
+            inner: crate::generated::queries::cycles(self.as_async()),
+            client: self,
+        }
+    }
+    /// One specific cycle, looked up by ID or slug.
+    ///
+    /// Full type: [`Cycle`](super::types::Cycle)
+    pub fn cycle<T: DeserializeOwned + GraphQLFields<FullType = super::types::Cycle>>(
+        &self,
+        id: impl Into<super::ids::CycleId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::queries::cycle::<T>(self.as_async(), id))
+    }
+    /// All customer tiers defined in the workspace.
+    ///
+    /// Full type: [`CustomerTier`](super::types::CustomerTier)
+    ///
+    /// Requires the `customers` feature.
+    #[cfg(feature = "customers")]
+    pub fn customer_tiers<T>(&self) -> CustomerTiersQueryBuilder<'_, T> {
+        CustomerTiersQueryBuilder {
+            inner: crate::generated::queries::customer_tiers(self.as_async()),
+            client: self,
+        }
+    }
+    /// One specific customer tier.
+    ///
+    /// Full type: [`CustomerTier`](super::types::CustomerTier)
+    ///
+    /// Requires the `customers` feature.
+    #[cfg(feature = "customers")]
+    pub fn customer_tier<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::CustomerTier>,
+    >(
+        &self,
+        id: impl Into<super::ids::CustomerTierId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::queries::customer_tier::<T>(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// All customer statuses defined in the workspace.
+    ///
+    /// Full type: [`CustomerStatus`](super::types::CustomerStatus)
+    ///
+    /// Requires the `customers` feature.
+    #[cfg(feature = "customers")]
+    pub fn customer_statuses<T>(&self) -> CustomerStatusesQueryBuilder<'_, T> {
+        CustomerStatusesQueryBuilder {
+            inner: crate::generated::queries::customer_statuses(self.as_async()),
+            client: self,
+        }
+    }
+    /// One specific customer status.
+    ///
+    /// Full type: [`CustomerStatus`](super::types::CustomerStatus)
+    ///
+    /// Requires the `customers` feature.
+    #[cfg(feature = "customers")]
+    pub fn customer_status<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::CustomerStatus>,
+    >(
+        &self,
+        id: impl Into<super::ids::CustomerStatusId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::queries::customer_status::<T>(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// All customers in the workspace, with optional filtering and sorting.
+    ///
+    /// Full type: [`Customer`](super::types::Customer)
+    ///
+    /// Requires the `customers` feature.
+    #[cfg(feature = "customers")]
+    pub fn customers<T>(&self) -> CustomersQueryBuilder<'_, T> {
+        CustomersQueryBuilder {
+            inner: crate::generated::queries::customers(self.as_async()),
+            client: self,
+        }
+    }
+    /// Retrieves a single customer by ID or slug.
+    ///
+    /// Full type: [`Customer`](super::types::Customer)
+    ///
+    /// Requires the `customers` feature.
+    #[cfg(feature = "customers")]
+    pub fn customer<T: DeserializeOwned + GraphQLFields<FullType = super::types::Customer>>(
+        &self,
+        id: impl Into<super::ids::CustomerId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::queries::customer::<T>(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// All customer needs in the workspace, with optional filtering.
+    ///
+    /// Full type: [`CustomerNeed`](super::types::CustomerNeed)
+    ///
+    /// Requires the `customers` feature.
+    #[cfg(feature = "customers")]
+    pub fn customer_needs<T>(&self) -> CustomerNeedsQueryBuilder<'_, T> {
+        CustomerNeedsQueryBuilder {
+            inner: crate::generated::queries::customer_needs(self.as_async()),
+            client: self,
+        }
+    }
+    /// Retrieves a single customer need by ID or hash.
+    ///
+    /// Full type: [`CustomerNeed`](super::types::CustomerNeed)
+    ///
+    /// Requires the `customers` feature.
+    #[cfg(feature = "customers")]
+    pub fn customer_need<T>(&self) -> CustomerNeedQueryBuilder<'_, T> {
+        CustomerNeedQueryBuilder {
+            inner: crate::generated::queries::customer_need(self.as_async()),
+            client: self,
+        }
+    }
+    /// Suggests an issue title based on a customer request message using AI summarization.
+    ///
+    /// Full type: [`IssueTitleSuggestionFromCustomerRequestPayload`](super::types::IssueTitleSuggestionFromCustomerRequestPayload)
+    ///
+    /// Requires the `customers` feature.
+    #[cfg(feature = "customers")]
+    pub fn issue_title_suggestion_from_customer_request<
+        T: DeserializeOwned
+            + GraphQLFields<FullType = super::types::IssueTitleSuggestionFromCustomerRequestPayload>,
+    >(
+        &self,
+        request: String,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::queries::issue_title_suggestion_from_customer_request::<T>(
+                self.as_async(),
+                request,
+            ),
+        )
+    }
+    /// All custom views accessible to the user, including personal views and shared workspace views. Excludes views scoped to a specific project or initiative.
+    ///
+    /// Full type: [`CustomView`](super::types::CustomView)
+    ///
+    /// Requires the `views` feature.
+    #[cfg(feature = "views")]
+    pub fn custom_views<T>(&self) -> CustomViewsQueryBuilder<'_, T> {
+        CustomViewsQueryBuilder {
+            inner: crate::generated::queries::custom_views(self.as_async()),
+            client: self,
+        }
+    }
+    /// One specific custom view, looked up by ID or slug.
+    ///
+    /// Full type: [`CustomView`](super::types::CustomView)
+    ///
+    /// Requires the `views` feature.
+    #[cfg(feature = "views")]
+    pub fn custom_view<T: DeserializeOwned + GraphQLFields<FullType = super::types::CustomView>>(
+        &self,
+        id: impl Into<super::ids::CustomViewId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::queries::custom_view::<T>(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// `INTERNAL` Suggests metadata (name, description, icon) for a view based on its filters using AI. Rate-limited to 30 requests per minute.
+    ///
+    /// Full type: [`CustomViewSuggestionPayload`](super::types::CustomViewSuggestionPayload)
+    ///
+    /// Requires the `views` feature.
+    #[cfg(feature = "views")]
+    pub fn custom_view_details_suggestion<T>(
+        &self,
+        filter: serde_json::Value,
+    ) -> CustomViewDetailsSuggestionQueryBuilder<'_, T> {
+        CustomViewDetailsSuggestionQueryBuilder {
+            inner: crate::generated::queries::custom_view_details_suggestion(
+                self.as_async(),
+                filter,
+            ),
+            client: self,
+        }
+    }
+    /// Whether a custom view has active notification subscribers other than the current user. Useful for warning before deleting a shared view.
+    ///
+    /// Full type: [`CustomViewHasSubscribersPayload`](super::types::CustomViewHasSubscribersPayload)
+    ///
+    /// Requires the `views` feature.
+    #[cfg(feature = "views")]
+    pub fn custom_view_has_subscribers<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::CustomViewHasSubscribersPayload>,
+    >(
+        &self,
+        id: impl Into<super::ids::CustomViewId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::queries::custom_view_has_subscribers::<T>(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// All comments the user has access to in the workspace.
+    ///
+    /// Full type: [`Comment`](super::types::Comment)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub fn comments<T>(&self) -> CommentsQueryBuilder<'_, T> {
+        CommentsQueryBuilder {
+            inner: crate::generated::queries::comments(self.as_async()),
+            client: self,
+        }
+    }
+    /// A specific comment.
+    ///
+    /// Full type: [`Comment`](super::types::Comment)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub fn comment<T>(&self) -> CommentQueryBuilder<'_, T> {
+        CommentQueryBuilder {
+            inner: crate::generated::queries::comment(self.as_async()),
+            client: self,
+        }
+    }
+    /// Fetch users belonging to this user account.
+    ///
+    /// Full type: [`AuthResolverResponse`](super::types::AuthResolverResponse)
+    ///
+    /// Requires the `users` feature.
+    #[cfg(feature = "users")]
+    pub fn available_users<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::AuthResolverResponse>,
+    >(
+        &self,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::queries::available_users::<T>(
+            self.as_async(),
+        ))
+    }
+    /// User's active sessions.
+    ///
+    /// Full type: [`AuthenticationSessionResponse`](super::types::AuthenticationSessionResponse)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub fn authentication_sessions<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::AuthenticationSessionResponse>,
+    >(
+        &self,
+    ) -> Result<Vec<T>, LinearError> {
+        self.block_on(crate::generated::queries::authentication_sessions::<T>(
+            self.as_async(),
+        ))
+    }
+    /// Fetch SSO login URL for the email provided.
+    ///
+    /// Full type: [`SsoUrlFromEmailResponse`](super::types::SsoUrlFromEmailResponse)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub fn sso_url_from_email<T>(
+        &self,
+        r#type: IdentityProviderType,
+        email: impl Into<String>,
+    ) -> SsoUrlFromEmailQueryBuilder<'_, T> {
+        SsoUrlFromEmailQueryBuilder {
+            inner: crate::generated::queries::sso_url_from_email(self.as_async(), r#type, email),
+            client: self,
+        }
+    }
+    /// List of audit entry types.
+    ///
+    /// Full type: [`AuditEntryType`](super::types::AuditEntryType)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub fn audit_entry_types<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::AuditEntryType>,
+    >(
+        &self,
+    ) -> Result<Vec<T>, LinearError> {
+        self.block_on(crate::generated::queries::audit_entry_types::<T>(
+            self.as_async(),
+        ))
+    }
+    /// All audit log entries.
+    ///
+    /// Full type: [`AuditEntry`](super::types::AuditEntry)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub fn audit_entries<T>(&self) -> AuditEntriesQueryBuilder<'_, T> {
+        AuditEntriesQueryBuilder {
+            inner: crate::generated::queries::audit_entries(self.as_async()),
+            client: self,
+        }
+    }
+    /// All issue attachments.
+    ///
+    /// To get attachments for a given URL, use `attachmentsForURL` query.
+    ///
+    /// Full type: [`Attachment`](super::types::Attachment)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub fn attachments<T>(&self) -> AttachmentsQueryBuilder<'_, T> {
+        AttachmentsQueryBuilder {
+            inner: crate::generated::queries::attachments(self.as_async()),
+            client: self,
+        }
+    }
+    /// One specific issue attachment.
+    /// `Deprecated` 'url' can no longer be used as the 'id' parameter. Use 'attachmentsForUrl' instead
+    ///
+    /// Full type: [`Attachment`](super::types::Attachment)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub fn attachment<T: DeserializeOwned + GraphQLFields<FullType = super::types::Attachment>>(
+        &self,
+        id: impl Into<super::ids::AttachmentId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::queries::attachment::<T>(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// Returns issue attachments for a given `url`.
+    ///
+    /// Full type: [`Attachment`](super::types::Attachment)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub fn attachments_for_url<T>(
+        &self,
+        url: impl Into<String>,
+    ) -> AttachmentsForUrlQueryBuilder<'_, T> {
+        AttachmentsForUrlQueryBuilder {
+            inner: crate::generated::queries::attachments_for_url(self.as_async(), url),
+            client: self,
+        }
+    }
+    /// Query an issue by its associated attachment, and its id.
+    ///
+    /// Full type: [`Issue`](super::types::Issue)
+    #[deprecated(
+        note = "Will be removed in near future, please use `attachmentsForURL` to get attachments and their issues instead."
+    )]
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub fn attachment_issue<T: DeserializeOwned + GraphQLFields<FullType = super::types::Issue>>(
+        &self,
+        id: impl Into<super::ids::IssueId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::queries::attachment_issue::<T>(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// `Internal` Get a list of all unique attachment sources in the workspace.
+    ///
+    /// Full type: [`AttachmentSourcesPayload`](super::types::AttachmentSourcesPayload)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub fn attachment_sources<T>(&self) -> AttachmentSourcesQueryBuilder<'_, T> {
+        AttachmentSourcesQueryBuilder {
+            inner: crate::generated::queries::attachment_sources(self.as_async()),
+            client: self,
+        }
+    }
+    /// Retrieves public information about an OAuth application by its client ID. Used during the authorization flow to display application details to the user.
+    ///
+    /// Full type: [`Application`](super::types::Application)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub fn application_info<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::Application>,
+    >(
+        &self,
+        client_id: String,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::queries::application_info::<T>(
+            self.as_async(),
+            client_id,
+        ))
+    }
+    /// All agent sessions.
+    ///
+    /// Full type: [`AgentSession`](super::types::AgentSession)
+    ///
+    /// Requires the `agents` feature.
+    #[cfg(feature = "agents")]
+    pub fn agent_sessions<T>(&self) -> AgentSessionsQueryBuilder<'_, T> {
+        AgentSessionsQueryBuilder {
+            inner: crate::generated::queries::agent_sessions(self.as_async()),
+            client: self,
+        }
+    }
+    /// A specific agent session.
+    ///
+    /// Full type: [`AgentSession`](super::types::AgentSession)
+    ///
+    /// Requires the `agents` feature.
+    #[cfg(feature = "agents")]
+    pub fn agent_session<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::AgentSession>,
+    >(
+        &self,
+        id: impl Into<super::ids::AgentSessionId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::queries::agent_session::<T>(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// `Internal` Retrieves coding agent sandbox details for a given agent session ID.
+    ///
+    /// Full type: [`CodingAgentSandboxPayload`](super::types::CodingAgentSandboxPayload)
+    ///
+    /// Requires the `agents` feature.
+    #[cfg(feature = "agents")]
+    pub fn agent_session_sandbox<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::CodingAgentSandboxPayload>,
+    >(
+        &self,
+        agent_session_id: String,
+    ) -> Result<Option<T>, LinearError> {
+        self.block_on(crate::generated::queries::agent_session_sandbox::<T>(
+            self.as_async(),
+            agent_session_id,
+        ))
+    }
+    /// All agent activities.
+    ///
+    /// Full type: [`AgentActivity`](super::types::AgentActivity)
+    ///
+    /// Requires the `agents` feature.
+    #[cfg(feature = "agents")]
+    pub fn agent_activities<T>(&self) -> AgentActivitiesQueryBuilder<'_, T> {
+        AgentActivitiesQueryBuilder {
+            inner: crate::generated::queries::agent_activities(self.as_async()),
+            client: self,
+        }
+    }
+    /// A specific agent activity.
+    ///
+    /// Full type: [`AgentActivity`](super::types::AgentActivity)
+    ///
+    /// Requires the `agents` feature.
+    #[cfg(feature = "agents")]
+    pub fn agent_activity<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::AgentActivity>,
+    >(
+        &self,
+        id: impl Into<super::ids::AgentActivityId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::queries::agent_activity::<T>(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// XHR request payload to upload an images, video and other attachments directly to Linear's cloud storage.
+    pub fn file_upload(
+        &self,
+        meta_data: Option<serde_json::Value>,
+        make_public: Option<bool>,
+        size: i64,
+        content_type: String,
+        filename: String,
+    ) -> Result<serde_json::Value, LinearError> {
+        self.block_on(crate::generated::mutations::file_upload(
+            self.as_async(),
+            meta_data,
+            make_public,
+            size,
+            content_type,
+            filename,
+        ))
+    }
+    /// XHR request payload to upload a file for import, directly to Linear's cloud storage.
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub fn import_file_upload(
+        &self,
+        meta_data: Option<serde_json::Value>,
+        size: i64,
+        content_type: String,
+        filename: String,
+    ) -> Result<serde_json::Value, LinearError> {
+        self.block_on(crate::generated::mutations::import_file_upload(
+            self.as_async(),
+            meta_data,
+            size,
+            content_type,
+            filename,
+        ))
+    }
+    /// Upload an image from an URL to Linear.
+    pub fn image_upload_from_url(
+        &self,
+        url: String,
+    ) -> Result<super::types::ImageUploadFromUrlPayload, LinearError> {
+        self.block_on(crate::generated::mutations::image_upload_from_url(
+            self.as_async(),
+            url,
+        ))
+    }
+    /// `INTERNAL` Permanently delete an uploaded file by asset URL. This should be used as a last resort and will break comments and documents that reference the asset.
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub fn file_upload_dangerously_delete(
+        &self,
+        asset_url: String,
+    ) -> Result<super::types::FileUploadDeletePayload, LinearError> {
+        self.block_on(crate::generated::mutations::file_upload_dangerously_delete(
+            self.as_async(),
+            asset_url,
+        ))
+    }
+    /// Creates a new state, adding it to the workflow of a team.
+    ///
+    /// Full type: [`WorkflowState`](super::types::WorkflowState)
+    ///
+    /// Requires the `teams` feature.
+    #[cfg(feature = "teams")]
+    pub fn workflow_state_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::WorkflowState>,
+    >(
+        &self,
+        input: WorkflowStateCreateInput,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::workflow_state_create::<T>(
+            self.as_async(),
+            input,
+        ))
+    }
+    /// Updates a state.
+    ///
+    /// Full type: [`WorkflowState`](super::types::WorkflowState)
+    ///
+    /// Requires the `teams` feature.
+    #[cfg(feature = "teams")]
+    pub fn workflow_state_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::WorkflowState>,
+    >(
+        &self,
+        input: WorkflowStateUpdateInput,
+        id: impl Into<super::ids::WorkflowStateId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::workflow_state_update::<T>(
+            self.as_async(),
+            input,
+            id,
+        ))
+    }
+    /// Archives a state. Only states with issues that have all been archived can be archived.
+    ///
+    /// Full type: [`WorkflowState`](super::types::WorkflowState)
+    ///
+    /// Requires the `teams` feature.
+    #[cfg(feature = "teams")]
+    pub fn workflow_state_archive<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::WorkflowState>,
+    >(
+        &self,
+        id: impl Into<super::ids::WorkflowStateId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::workflow_state_archive::<T>(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// Creates a new webhook subscription for the workspace. Requires specifying a URL, resource types to subscribe to, and either a specific team or all public teams.
+    ///
+    /// Full type: [`Webhook`](super::types::Webhook)
+    ///
+    /// Requires the `webhooks` feature.
+    #[cfg(feature = "webhooks")]
+    pub fn webhook_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Webhook>,
+    >(
+        &self,
+        input: WebhookCreateInput,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::webhook_create::<T>(
+            self.as_async(),
+            input,
+        ))
+    }
+    /// Updates an existing Webhook.
+    ///
+    /// Full type: [`Webhook`](super::types::Webhook)
+    ///
+    /// Requires the `webhooks` feature.
+    #[cfg(feature = "webhooks")]
+    pub fn webhook_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Webhook>,
+    >(
+        &self,
+        input: WebhookUpdateInput,
+        id: impl Into<super::ids::WebhookId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::webhook_update::<T>(
+            self.as_async(),
+            input,
+            id,
+        ))
+    }
+    /// Deletes a Webhook.
+    ///
+    /// Requires the `webhooks` feature.
+    #[cfg(feature = "webhooks")]
+    pub fn webhook_delete(
+        &self,
+        id: impl Into<super::ids::WebhookId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        self.block_on(crate::generated::mutations::webhook_delete(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// Rotates the signing secret for a webhook, generating a new HMAC-SHA256 key and returning it. The old secret is immediately invalidated.
+    ///
+    /// Requires the `webhooks` feature.
+    #[cfg(feature = "webhooks")]
+    pub fn webhook_rotate_secret(
+        &self,
+        id: impl Into<super::ids::WebhookId>,
+    ) -> Result<super::types::WebhookRotateSecretPayload, LinearError> {
+        self.block_on(crate::generated::mutations::webhook_rotate_secret(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// Creates a new view preferences object. If conflicting preferences already exist for the same view type and scope, the existing preferences are replaced.
+    ///
+    /// Full type: [`ViewPreferences`](super::types::ViewPreferences)
+    ///
+    /// Requires the `views` feature.
+    #[cfg(feature = "views")]
+    pub fn view_preferences_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::ViewPreferences>,
+    >(
+        &self,
+        input: ViewPreferencesCreateInput,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::view_preferences_create::<T>(
+            self.as_async(),
+            input,
+        ))
+    }
+    /// Updates an existing view preferences object. For user-type preferences, only the owning user can update them.
+    ///
+    /// Full type: [`ViewPreferences`](super::types::ViewPreferences)
+    ///
+    /// Requires the `views` feature.
+    #[cfg(feature = "views")]
+    pub fn view_preferences_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::ViewPreferences>,
+    >(
+        &self,
+        input: ViewPreferencesUpdateInput,
+        id: impl Into<super::ids::ViewPreferencesId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::view_preferences_update::<T>(
+            self.as_async(),
+            input,
+            id,
+        ))
+    }
+    /// Deletes a view preferences object. If the preferences do not exist, the operation is treated as a successful idempotent deletion.
+    ///
+    /// Requires the `views` feature.
+    #[cfg(feature = "views")]
+    pub fn view_preferences_delete(
+        &self,
+        id: impl Into<super::ids::ViewPreferencesId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        self.block_on(crate::generated::mutations::view_preferences_delete(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// Updates the authenticated user's settings, including notification preferences, email subscriptions, theme, and other UI preferences.
+    ///
+    /// Full type: [`UserSettings`](super::types::UserSettings)
+    ///
+    /// Requires the `users` feature.
+    #[cfg(feature = "users")]
+    pub fn user_settings_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::UserSettings>,
+    >(
+        &self,
+        input: UserSettingsUpdateInput,
+        id: impl Into<super::ids::UserSettingsId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::user_settings_update::<T>(
+            self.as_async(),
+            input,
+            id,
+        ))
+    }
+    /// Resets one or more of the user's setting flags. If no specific flags are provided, all flags are reset.
+    ///
+    /// Requires the `users` feature.
+    #[cfg(feature = "users")]
+    pub fn user_settings_flags_reset(
+        &self,
+        flags: Option<Vec<UserFlagType>>,
+    ) -> Result<super::types::UserSettingsFlagsResetPayload, LinearError> {
+        self.block_on(crate::generated::mutations::user_settings_flags_reset(
+            self.as_async(),
+            flags,
+        ))
+    }
+    /// Updates a specific user settings flag by performing an operation (e.g., increment or clear) on it.
+    ///
+    /// Requires the `users` feature.
+    #[cfg(feature = "users")]
+    pub fn user_flag_update(
+        &self,
+        operation: UserFlagUpdateOperation,
+        flag: UserFlagType,
+    ) -> Result<super::types::UserSettingsFlagPayload, LinearError> {
+        self.block_on(crate::generated::mutations::user_flag_update(
+            self.as_async(),
+            operation,
+            flag,
+        ))
+    }
+    /// Subscribes to or unsubscribes from a specific notification category for a given notification channel. For example, subscribe to 'issueAssignment' notifications via the 'email' channel.
+    ///
+    /// Full type: [`UserSettings`](super::types::UserSettings)
+    ///
+    /// Requires the `notifications` feature.
+    #[cfg(feature = "notifications")]
+    pub fn notification_category_channel_subscription_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::UserSettings>,
+    >(
+        &self,
+        channel: NotificationChannel,
+        category: NotificationCategory,
+        subscribe: bool,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::notification_category_channel_subscription_update::<T>(
+                self.as_async(),
+                channel,
+                category,
+                subscribe,
+            ),
+        )
+    }
+    /// Updates a user's profile information. Users can update their own profile; workspace admins can update any user's profile. SCIM-managed users may have restricted name changes.
+    ///
+    /// Full type: [`User`](super::types::User)
+    ///
+    /// Requires the `users` feature.
+    #[cfg(feature = "users")]
+    pub fn user_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::User>,
+    >(
+        &self,
+        input: UserUpdateInput,
+        id: impl Into<super::ids::UserId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::user_update::<T>(
+            self.as_async(),
+            input,
+            id,
+        ))
+    }
+    /// Connects the Discord user to this Linear account via OAuth2.
+    ///
+    /// Full type: [`User`](super::types::User)
+    ///
+    /// Requires the `users` feature.
+    #[cfg(feature = "users")]
+    pub fn user_discord_connect<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::User>,
+    >(
+        &self,
+        redirect_uri: String,
+        code: String,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::user_discord_connect::<T>(
+            self.as_async(),
+            redirect_uri,
+            code,
+        ))
+    }
+    /// Disconnects the external user from this Linear account.
+    ///
+    /// Full type: [`User`](super::types::User)
+    ///
+    /// Requires the `users` feature.
+    #[cfg(feature = "users")]
+    pub fn user_external_user_disconnect<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::User>,
+    >(
+        &self,
+        service: String,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::user_external_user_disconnect::<T>(
+                self.as_async(),
+                service,
+            ),
+        )
+    }
+    /// Changes the workspace role of a user. The requesting user must have a role equal to or higher than the target role. Requires workspace admin permissions.
+    ///
+    /// Requires the `users` feature.
+    #[cfg(feature = "users")]
+    pub fn user_change_role(
+        &self,
+        role: UserRoleType,
+        id: impl Into<super::ids::UserId>,
+    ) -> Result<super::types::UserAdminPayload, LinearError> {
+        self.block_on(crate::generated::mutations::user_change_role(
+            self.as_async(),
+            role,
+            id,
+        ))
+    }
+    /// Suspends a user, deactivating their account and revoking access to the workspace. The suspended user's sessions are invalidated. Can only be called by a workspace admin or owner.
+    ///
+    /// Requires the `users` feature.
+    #[cfg(feature = "users")]
+    pub fn user_suspend(
+        &self,
+        force_bypass_scim_restrictions: Option<bool>,
+        id: impl Into<super::ids::UserId>,
+    ) -> Result<super::types::UserAdminPayload, LinearError> {
+        self.block_on(crate::generated::mutations::user_suspend(
+            self.as_async(),
+            force_bypass_scim_restrictions,
+            id,
+        ))
+    }
+    /// Revokes all active sessions for a user, forcing them to re-authenticate. Can only be called by a workspace admin or owner.
+    ///
+    /// Requires the `users` feature.
+    #[cfg(feature = "users")]
+    pub fn user_revoke_all_sessions(
+        &self,
+        id: impl Into<super::ids::UserId>,
+    ) -> Result<super::types::UserAdminPayload, LinearError> {
+        self.block_on(crate::generated::mutations::user_revoke_all_sessions(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// Revokes a specific authentication session for a user. The admin cannot revoke their own sessions with this mutation. Can only be called by a workspace admin or owner.
+    ///
+    /// Requires the `users` feature.
+    #[cfg(feature = "users")]
+    pub fn user_revoke_session(
+        &self,
+        session_id: String,
+        id: impl Into<super::ids::UserId>,
+    ) -> Result<super::types::UserAdminPayload, LinearError> {
+        self.block_on(crate::generated::mutations::user_revoke_session(
+            self.as_async(),
+            session_id,
+            id,
+        ))
+    }
+    /// Re-activates a suspended user, restoring their access to the workspace. Can only be called by a workspace admin or owner.
+    ///
+    /// Requires the `users` feature.
+    #[cfg(feature = "users")]
+    pub fn user_unsuspend(
+        &self,
+        force_bypass_scim_restrictions: Option<bool>,
+        id: impl Into<super::ids::UserId>,
+    ) -> Result<super::types::UserAdminPayload, LinearError> {
+        self.block_on(crate::generated::mutations::user_unsuspend(
+            self.as_async(),
+            force_bypass_scim_restrictions,
+            id,
+        ))
+    }
+    /// Unlinks a guest user from their identity provider. Can only be called by an admin when SCIM is enabled.
+    ///
+    /// Requires the `users` feature.
+    #[cfg(feature = "users")]
+    pub fn user_unlink_from_identity_provider(
+        &self,
+        id: impl Into<super::ids::UserId>,
+    ) -> Result<super::types::UserAdminPayload, LinearError> {
+        self.block_on(
+            crate::generated::mutations::user_unlink_from_identity_provider(self.as_async(), id),
+        )
+    }
+    /// Creates a new triage responsibility.
+    ///
+    /// Full type: [`TriageResponsibility`](super::types::TriageResponsibility)
+    ///
+    /// Requires the `teams` feature.
+    #[cfg(feature = "teams")]
+    pub fn triage_responsibility_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::TriageResponsibility>,
+    >(
+        &self,
+        input: TriageResponsibilityCreateInput,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::triage_responsibility_create::<T>(self.as_async(), input),
+        )
+    }
+    /// Updates an existing triage responsibility.
+    ///
+    /// Full type: [`TriageResponsibility`](super::types::TriageResponsibility)
+    ///
+    /// Requires the `teams` feature.
+    #[cfg(feature = "teams")]
+    pub fn triage_responsibility_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::TriageResponsibility>,
+    >(
+        &self,
+        input: TriageResponsibilityUpdateInput,
+        id: impl Into<super::ids::TriageResponsibilityId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::triage_responsibility_update::<T>(
+                self.as_async(),
+                input,
+                id,
+            ),
+        )
+    }
+    /// Deletes a triage responsibility.
+    ///
+    /// Requires the `teams` feature.
+    #[cfg(feature = "teams")]
+    pub fn triage_responsibility_delete(
+        &self,
+        id: impl Into<super::ids::TriageResponsibilityId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        self.block_on(crate::generated::mutations::triage_responsibility_delete(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// Creates a new time schedule.
+    ///
+    /// Full type: [`TimeSchedule`](super::types::TimeSchedule)
+    ///
+    /// Requires the `teams` feature.
+    #[cfg(feature = "teams")]
+    pub fn time_schedule_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::TimeSchedule>,
+    >(
+        &self,
+        input: TimeScheduleCreateInput,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::time_schedule_create::<T>(
+            self.as_async(),
+            input,
+        ))
+    }
+    /// Updates a time schedule.
+    ///
+    /// Full type: [`TimeSchedule`](super::types::TimeSchedule)
+    ///
+    /// Requires the `teams` feature.
+    #[cfg(feature = "teams")]
+    pub fn time_schedule_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::TimeSchedule>,
+    >(
+        &self,
+        input: TimeScheduleUpdateInput,
+        id: impl Into<super::ids::TimeScheduleId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::time_schedule_update::<T>(
+            self.as_async(),
+            input,
+            id,
+        ))
+    }
+    /// Upsert an external time schedule.
+    ///
+    /// Full type: [`TimeSchedule`](super::types::TimeSchedule)
+    ///
+    /// Requires the `teams` feature.
+    #[cfg(feature = "teams")]
+    pub fn time_schedule_upsert_external<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::TimeSchedule>,
+    >(
+        &self,
+        input: TimeScheduleUpdateInput,
+        external_id: String,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::time_schedule_upsert_external::<T>(
+                self.as_async(),
+                input,
+                external_id,
+            ),
+        )
+    }
+    /// Deletes a time schedule.
+    ///
+    /// Requires the `teams` feature.
+    #[cfg(feature = "teams")]
+    pub fn time_schedule_delete(
+        &self,
+        id: impl Into<super::ids::TimeScheduleId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        self.block_on(crate::generated::mutations::time_schedule_delete(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// Refresh the integration schedule information.
+    ///
+    /// Full type: [`TimeSchedule`](super::types::TimeSchedule)
+    ///
+    /// Requires the `teams` feature.
+    #[cfg(feature = "teams")]
+    pub fn time_schedule_refresh_integration_schedule<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::TimeSchedule>,
+    >(
+        &self,
+        id: impl Into<super::ids::TimeScheduleId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::time_schedule_refresh_integration_schedule::<T>(
+                self.as_async(),
+                id,
+            ),
+        )
+    }
+    /// Creates a new template.
+    ///
+    /// Full type: [`Template`](super::types::Template)
+    ///
+    /// Requires the `teams` feature.
+    #[cfg(feature = "teams")]
+    pub fn template_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Template>,
+    >(
+        &self,
+        input: TemplateCreateInput,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::template_create::<T>(
+            self.as_async(),
+            input,
+        ))
+    }
+    /// Updates an existing template.
+    ///
+    /// Full type: [`Template`](super::types::Template)
+    ///
+    /// Requires the `teams` feature.
+    #[cfg(feature = "teams")]
+    pub fn template_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Template>,
+    >(
+        &self,
+        input: TemplateUpdateInput,
+        id: impl Into<super::ids::TemplateId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::template_update::<T>(
+            self.as_async(),
+            input,
+            id,
+        ))
+    }
+    /// Deletes a template.
+    ///
+    /// Requires the `teams` feature.
+    #[cfg(feature = "teams")]
+    pub fn template_delete(
+        &self,
+        id: impl Into<super::ids::TemplateId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        self.block_on(crate::generated::mutations::template_delete(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// Creates a new project.
+    ///
+    /// Full type: [`Project`](super::types::Project)
+    pub fn project_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Project>,
+    >(
+        &self,
+        slack_channel_name: Option<String>,
+        input: ProjectCreateInput,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::project_create::<T>(
+            self.as_async(),
+            slack_channel_name,
+            input,
+        ))
+    }
+    /// Updates a project.
+    ///
+    /// Full type: [`Project`](super::types::Project)
+    pub fn project_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Project>,
+    >(
+        &self,
+        input: ProjectUpdateInput,
+        id: impl Into<super::ids::ProjectId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::project_update::<T>(
+            self.as_async(),
+            input,
+            id,
+        ))
+    }
+    /// `Internal` Creates a Slack channel for an existing project.
+    ///
+    /// Full type: [`Project`](super::types::Project)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub fn project_create_slack_channel<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Project>,
+    >(
+        &self,
+        integration_id: Option<String>,
+        slack_channel_name: String,
+        id: impl Into<super::ids::ProjectId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::project_create_slack_channel::<T>(
+                self.as_async(),
+                integration_id,
+                slack_channel_name,
+                id,
+            ),
+        )
+    }
+    /// `INTERNAL` Reassigns all projects from one project status to another. Used when archiving or deleting a project status.
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub fn project_reassign_status(
+        &self,
+        new_project_status_id: String,
+        original_project_status_id: String,
+    ) -> Result<super::types::SuccessPayload, LinearError> {
+        self.block_on(crate::generated::mutations::project_reassign_status(
+            self.as_async(),
+            new_project_status_id,
+            original_project_status_id,
+        ))
+    }
+    /// Deletes (trashes) a project. The project can be restored later with projectUnarchive.
+    ///
+    /// Full type: [`Project`](super::types::Project)
+    pub fn project_delete<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Project>,
+    >(
+        &self,
+        id: impl Into<super::ids::ProjectId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::project_delete::<T>(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// Archives a project.
+    #[deprecated(note = "Deprecated in favor of projectDelete.")]
+    ///
+    /// Full type: [`Project`](super::types::Project)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub fn project_archive<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Project>,
+    >(
+        &self,
+        trash: Option<bool>,
+        id: impl Into<super::ids::ProjectId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::project_archive::<T>(
+            self.as_async(),
+            trash,
+            id,
+        ))
+    }
+    /// Restores a previously trashed or archived project.
+    ///
+    /// Full type: [`Project`](super::types::Project)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub fn project_unarchive<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Project>,
+    >(
+        &self,
+        id: impl Into<super::ids::ProjectId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::project_unarchive::<T>(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// Adds a label to a project.
+    ///
+    /// Full type: [`Project`](super::types::Project)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub fn project_add_label<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Project>,
+    >(
+        &self,
+        label_id: String,
+        id: impl Into<super::ids::ProjectId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::project_add_label::<T>(
+            self.as_async(),
+            label_id,
+            id,
+        ))
+    }
+    /// Removes a label from a project.
+    ///
+    /// Full type: [`Project`](super::types::Project)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub fn project_remove_label<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Project>,
+    >(
+        &self,
+        label_id: String,
+        id: impl Into<super::ids::ProjectId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::project_remove_label::<T>(
+            self.as_async(),
+            label_id,
+            id,
+        ))
+    }
+    /// Disables external sync on a project.
+    ///
+    /// Full type: [`Project`](super::types::Project)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub fn project_external_sync_disable<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Project>,
+    >(
+        &self,
+        sync_source: ExternalSyncService,
+        project_id: String,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::project_external_sync_disable::<T>(
+                self.as_async(),
+                sync_source,
+                project_id,
+            ),
+        )
+    }
+    /// Creates a new team. The user who creates the team will automatically be added as a member and owner of the newly created team. Default workflow states, labels, and other team resources are created alongside the team.
+    ///
+    /// Full type: [`Team`](super::types::Team)
+    pub fn team_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Team>,
+    >(
+        &self,
+        copy_settings_from_team_id: Option<String>,
+        input: TeamCreateInput,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::team_create::<T>(
+            self.as_async(),
+            copy_settings_from_team_id,
+            input,
+        ))
+    }
+    /// Updates a team's settings, properties, or configuration. Requires team owner or workspace admin permissions for most changes.
+    ///
+    /// Full type: [`Team`](super::types::Team)
+    pub fn team_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Team>,
+    >(
+        &self,
+        mapping: Option<InheritanceEntityMapping>,
+        input: TeamUpdateInput,
+        id: impl Into<super::ids::TeamId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::team_update::<T>(
+            self.as_async(),
+            mapping,
+            input,
+            id,
+        ))
+    }
+    /// Archives a team and schedules its data for deletion. Requires team owner or workspace admin permissions.
+    pub fn team_delete(
+        &self,
+        id: impl Into<super::ids::TeamId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        self.block_on(crate::generated::mutations::team_delete(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// Unarchives a team and cancels deletion.
+    ///
+    /// Full type: [`Team`](super::types::Team)
+    ///
+    /// Requires the `teams` feature.
+    #[cfg(feature = "teams")]
+    pub fn team_unarchive<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Team>,
+    >(
+        &self,
+        id: impl Into<super::ids::TeamId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::team_unarchive::<T>(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// Deletes all cycle data for a team, disabling the cycles feature. This removes all cycles and their issue associations.
+    ///
+    /// Full type: [`Team`](super::types::Team)
+    ///
+    /// Requires the `teams` feature.
+    #[cfg(feature = "teams")]
+    pub fn team_cycles_delete<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Team>,
+    >(
+        &self,
+        id: impl Into<super::ids::TeamId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::team_cycles_delete::<T>(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// Creates a new team membership, adding a user to a team. Validates that the user is not already a member, the team is not archived or retired, and the requesting user has permission to add members.
+    ///
+    /// Full type: [`TeamMembership`](super::types::TeamMembership)
+    pub fn team_membership_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::TeamMembership>,
+    >(
+        &self,
+        input: TeamMembershipCreateInput,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::team_membership_create::<T>(
+            self.as_async(),
+            input,
+        ))
+    }
+    /// Updates a team membership, such as changing ownership status or sort order.
+    ///
+    /// Full type: [`TeamMembership`](super::types::TeamMembership)
+    ///
+    /// Requires the `teams` feature.
+    #[cfg(feature = "teams")]
+    pub fn team_membership_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::TeamMembership>,
+    >(
+        &self,
+        input: TeamMembershipUpdateInput,
+        id: impl Into<super::ids::TeamMembershipId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::team_membership_update::<T>(
+            self.as_async(),
+            input,
+            id,
+        ))
+    }
+    /// Deletes a team membership, removing the user from the team. Users can remove their own membership, or team owners and workspace admins can remove other members.
+    pub fn team_membership_delete(
+        &self,
+        also_leave_parent_teams: Option<bool>,
+        id: impl Into<super::ids::TeamMembershipId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        self.block_on(crate::generated::mutations::team_membership_delete(
+            self.as_async(),
+            also_leave_parent_teams,
+            id,
+        ))
+    }
+    /// Deletes a previously used team key. The active team key (the team's current key) cannot be deleted.
+    ///
+    /// Requires the `teams` feature.
+    #[cfg(feature = "teams")]
+    pub fn team_key_delete(
+        &self,
+        id: impl Into<super::ids::TeamId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        self.block_on(crate::generated::mutations::team_key_delete(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// `Deprecated` Creates a new roadmap-to-project association. Use InitiativeToProject instead.
+    ///
+    /// Full type: [`RoadmapToProject`](super::types::RoadmapToProject)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub fn roadmap_to_project_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::RoadmapToProject>,
+    >(
+        &self,
+        input: RoadmapToProjectCreateInput,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::roadmap_to_project_create::<T>(
+            self.as_async(),
+            input,
+        ))
+    }
+    /// `Deprecated` Updates a roadmap-to-project association. Use InitiativeToProject instead.
+    ///
+    /// Full type: [`RoadmapToProject`](super::types::RoadmapToProject)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub fn roadmap_to_project_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::RoadmapToProject>,
+    >(
+        &self,
+        input: RoadmapToProjectUpdateInput,
+        id: impl Into<super::ids::RoadmapToProjectId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::roadmap_to_project_update::<T>(
+            self.as_async(),
+            input,
+            id,
+        ))
+    }
+    /// `Deprecated` Deletes a roadmap-to-project association. Use InitiativeToProject instead.
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub fn roadmap_to_project_delete(
+        &self,
+        id: impl Into<super::ids::RoadmapToProjectId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        self.block_on(crate::generated::mutations::roadmap_to_project_delete(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// Creates a new roadmap.
+    #[deprecated(note = "Roadmaps are deprecated, use initiatives instead.")]
+    ///
+    /// Full type: [`Roadmap`](super::types::Roadmap)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub fn roadmap_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Roadmap>,
+    >(
+        &self,
+        input: RoadmapCreateInput,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::roadmap_create::<T>(
+            self.as_async(),
+            input,
+        ))
+    }
+    /// Updates a roadmap.
+    #[deprecated(note = "Roadmaps are deprecated, use initiatives instead.")]
+    ///
+    /// Full type: [`Roadmap`](super::types::Roadmap)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub fn roadmap_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Roadmap>,
+    >(
+        &self,
+        input: RoadmapUpdateInput,
+        id: impl Into<super::ids::RoadmapId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::roadmap_update::<T>(
+            self.as_async(),
+            input,
+            id,
+        ))
+    }
+    /// Archives a roadmap.
+    #[deprecated(note = "Roadmaps are deprecated, use initiatives instead.")]
+    ///
+    /// Full type: [`Roadmap`](super::types::Roadmap)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub fn roadmap_archive<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Roadmap>,
+    >(
+        &self,
+        id: impl Into<super::ids::RoadmapId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::roadmap_archive::<T>(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// Unarchives a roadmap.
+    #[deprecated(note = "Roadmaps are deprecated, use initiatives instead.")]
+    ///
+    /// Full type: [`Roadmap`](super::types::Roadmap)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub fn roadmap_unarchive<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Roadmap>,
+    >(
+        &self,
+        id: impl Into<super::ids::RoadmapId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::roadmap_unarchive::<T>(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// Deletes a roadmap.
+    #[deprecated(note = "Roadmaps are deprecated, use initiatives instead.")]
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub fn roadmap_delete(
+        &self,
+        id: impl Into<super::ids::RoadmapId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        self.block_on(crate::generated::mutations::roadmap_delete(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// Creates a CSV export report for the workspace. The report is generated asynchronously and delivered via email. Requires workspace admin export permission.
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub fn create_csv_export_report(
+        &self,
+        include_private_team_ids: Option<Vec<String>>,
+    ) -> Result<super::types::CreateCsvExportReportPayload, LinearError> {
+        self.block_on(crate::generated::mutations::create_csv_export_report(
+            self.as_async(),
+            include_private_team_ids,
+        ))
+    }
+    /// `ALPHA` Creates a new release stage in a pipeline. Non-started stages must use default names and colors, and only one stage of each non-started type is allowed per pipeline. Started stages can optionally be frozen, but at least one non-frozen started stage must remain.
+    ///
+    /// Full type: [`ReleaseStage`](super::types::ReleaseStage)
+    ///
+    /// Requires the `releases` feature.
+    #[cfg(feature = "releases")]
+    pub fn release_stage_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::ReleaseStage>,
+    >(
+        &self,
+        input: ReleaseStageCreateInput,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::release_stage_create::<T>(
+            self.as_async(),
+            input,
+        ))
+    }
+    /// `ALPHA` Updates an existing release stage. Only started-type stages can be edited. Supports updating name, color, position, and frozen status.
+    ///
+    /// Full type: [`ReleaseStage`](super::types::ReleaseStage)
+    ///
+    /// Requires the `releases` feature.
+    #[cfg(feature = "releases")]
+    pub fn release_stage_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::ReleaseStage>,
+    >(
+        &self,
+        input: ReleaseStageUpdateInput,
+        id: impl Into<super::ids::ReleaseStageId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::release_stage_update::<T>(
+            self.as_async(),
+            input,
+            id,
+        ))
+    }
+    /// `ALPHA` Archives a release stage. Only started-type stages can be archived, and only if they have no active releases and at least one other stage of the same type remains. Cannot archive the last non-frozen started stage.
+    ///
+    /// Full type: [`ReleaseStage`](super::types::ReleaseStage)
+    ///
+    /// Requires the `releases` feature.
+    #[cfg(feature = "releases")]
+    pub fn release_stage_archive<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::ReleaseStage>,
+    >(
+        &self,
+        id: impl Into<super::ids::ReleaseStageId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::release_stage_archive::<T>(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// `ALPHA` Unarchives a release stage.
+    ///
+    /// Full type: [`ReleaseStage`](super::types::ReleaseStage)
+    ///
+    /// Requires the `releases` feature.
+    #[cfg(feature = "releases")]
+    pub fn release_stage_unarchive<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::ReleaseStage>,
+    >(
+        &self,
+        id: impl Into<super::ids::ReleaseStageId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::release_stage_unarchive::<T>(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// `ALPHA` Creates a new release pipeline with default stages. Subject to plan entitlement and quota limits.
+    ///
+    /// Full type: [`ReleasePipeline`](super::types::ReleasePipeline)
+    ///
+    /// Requires the `releases` feature.
+    #[cfg(feature = "releases")]
+    pub fn release_pipeline_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::ReleasePipeline>,
+    >(
+        &self,
+        input: ReleasePipelineCreateInput,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::release_pipeline_create::<T>(
+            self.as_async(),
+            input,
+        ))
+    }
+    /// `ALPHA` Updates an existing release pipeline. Supports updating name, slug, type, production flag, path patterns, and team associations. Private teams that the current user cannot access are preserved in the team list.
+    ///
+    /// Full type: [`ReleasePipeline`](super::types::ReleasePipeline)
+    ///
+    /// Requires the `releases` feature.
+    #[cfg(feature = "releases")]
+    pub fn release_pipeline_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::ReleasePipeline>,
+    >(
+        &self,
+        input: ReleasePipelineUpdateInput,
+        id: impl Into<super::ids::ReleasePipelineId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::release_pipeline_update::<T>(
+            self.as_async(),
+            input,
+            id,
+        ))
+    }
+    /// `ALPHA` Archives a release pipeline.
+    ///
+    /// Full type: [`ReleasePipeline`](super::types::ReleasePipeline)
+    ///
+    /// Requires the `releases` feature.
+    #[cfg(feature = "releases")]
+    pub fn release_pipeline_archive<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::ReleasePipeline>,
+    >(
+        &self,
+        id: impl Into<super::ids::ReleasePipelineId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::release_pipeline_archive::<T>(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// `ALPHA` Unarchives a release pipeline.
+    ///
+    /// Full type: [`ReleasePipeline`](super::types::ReleasePipeline)
+    ///
+    /// Requires the `releases` feature.
+    #[cfg(feature = "releases")]
+    pub fn release_pipeline_unarchive<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::ReleasePipeline>,
+    >(
+        &self,
+        id: impl Into<super::ids::ReleasePipelineId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::release_pipeline_unarchive::<T>(self.as_async(), id),
+        )
+    }
+    /// `ALPHA` Permanently deletes a release pipeline and all associated stages and releases.
+    ///
+    /// Requires the `releases` feature.
+    #[cfg(feature = "releases")]
+    pub fn release_pipeline_delete(
+        &self,
+        id: impl Into<super::ids::ReleasePipelineId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        self.block_on(crate::generated::mutations::release_pipeline_delete(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// `ALPHA` Syncs release data by resolving issue and pull request references and associating them with a release. For continuous pipelines, creates a new completed release. For scheduled pipelines, finds or creates a started release and accumulates issues into it.
+    ///
+    /// Full type: [`Release`](super::types::Release)
+    ///
+    /// Requires the `releases` feature.
+    #[cfg(feature = "releases")]
+    pub fn release_sync<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Release>,
+    >(
+        &self,
+        input: ReleaseSyncInput,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::release_sync::<T>(
+            self.as_async(),
+            input,
+        ))
+    }
+    /// `ALPHA` Creates a new release in a pipeline. If no stage is specified, defaults to the first completed stage for continuous pipelines or the first started stage for scheduled pipelines.
+    ///
+    /// Full type: [`Release`](super::types::Release)
+    ///
+    /// Requires the `releases` feature.
+    #[cfg(feature = "releases")]
+    pub fn release_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Release>,
+    >(
+        &self,
+        input: ReleaseCreateInput,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::release_create::<T>(
+            self.as_async(),
+            input,
+        ))
+    }
+    /// `ALPHA` Updates an existing release by ID. Supports updating name, description, version, commit SHA, pipeline, stage, and dates.
+    ///
+    /// Full type: [`Release`](super::types::Release)
+    ///
+    /// Requires the `releases` feature.
+    #[cfg(feature = "releases")]
+    pub fn release_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Release>,
+    >(
+        &self,
+        input: ReleaseUpdateInput,
+        id: impl Into<super::ids::ReleaseId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::release_update::<T>(
+            self.as_async(),
+            input,
+            id,
+        ))
+    }
+    /// `ALPHA` Marks a release as completed. If version is provided, completes that specific release; otherwise completes the most recent started release.
+    ///
+    /// Full type: [`Release`](super::types::Release)
+    ///
+    /// Requires the `releases` feature.
+    #[cfg(feature = "releases")]
+    pub fn release_complete<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Release>,
+    >(
+        &self,
+        input: ReleaseCompleteInput,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::release_complete::<T>(
+            self.as_async(),
+            input,
+        ))
+    }
+    /// `ALPHA` Updates a release by pipeline identifier. Finds the release by version or latest started/planned release, and optionally transitions it to a new stage by name.
+    ///
+    /// Full type: [`Release`](super::types::Release)
+    ///
+    /// Requires the `releases` feature.
+    #[cfg(feature = "releases")]
+    pub fn release_update_by_pipeline<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Release>,
+    >(
+        &self,
+        input: ReleaseUpdateByPipelineInput,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::release_update_by_pipeline::<T>(self.as_async(), input),
+        )
+    }
+    /// `ALPHA` Moves a release to the trash bin. Trashed releases are archived and will be permanently deleted after a retention period. If the release is already archived, it is marked as trashed with a fresh archive timestamp.
+    ///
+    /// Full type: [`Release`](super::types::Release)
+    ///
+    /// Requires the `releases` feature.
+    #[cfg(feature = "releases")]
+    pub fn release_delete<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Release>,
+    >(
+        &self,
+        id: impl Into<super::ids::ReleaseId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::release_delete::<T>(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// `ALPHA` Archives a release.
+    ///
+    /// Full type: [`Release`](super::types::Release)
+    ///
+    /// Requires the `releases` feature.
+    #[cfg(feature = "releases")]
+    pub fn release_archive<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Release>,
+    >(
+        &self,
+        id: impl Into<super::ids::ReleaseId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::release_archive::<T>(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// `ALPHA` Unarchives a release.
+    ///
+    /// Full type: [`Release`](super::types::Release)
+    ///
+    /// Requires the `releases` feature.
+    #[cfg(feature = "releases")]
+    pub fn release_unarchive<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Release>,
+    >(
+        &self,
+        id: impl Into<super::ids::ReleaseId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::release_unarchive::<T>(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// `ALPHA` Syncs release data using an access key for CI/CD integration. The pipeline is automatically inferred from the access key's configured resources, so no pipeline ID is needed in the input.
+    ///
+    /// Full type: [`Release`](super::types::Release)
+    ///
+    /// Requires the `releases` feature.
+    #[cfg(feature = "releases")]
+    pub fn release_sync_by_access_key<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Release>,
+    >(
+        &self,
+        input: ReleaseSyncInputBase,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::release_sync_by_access_key::<T>(self.as_async(), input),
+        )
+    }
+    /// `ALPHA` Marks a release as completed using an access key. If version is provided, completes that specific release; otherwise completes the most recent started release. The pipeline is inferred from the access key.
+    ///
+    /// Full type: [`Release`](super::types::Release)
+    ///
+    /// Requires the `releases` feature.
+    #[cfg(feature = "releases")]
+    pub fn release_complete_by_access_key<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Release>,
+    >(
+        &self,
+        input: ReleaseCompleteInputBase,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::release_complete_by_access_key::<T>(
+                self.as_async(),
+                input,
+            ),
+        )
+    }
+    /// `ALPHA` Updates a release by pipeline using an access key.
+    ///
+    /// Full type: [`Release`](super::types::Release)
+    ///
+    /// Requires the `releases` feature.
+    #[cfg(feature = "releases")]
+    pub fn release_update_by_pipeline_by_access_key<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Release>,
+    >(
+        &self,
+        input: ReleaseUpdateByPipelineInputBase,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::release_update_by_pipeline_by_access_key::<T>(
+                self.as_async(),
+                input,
+            ),
+        )
+    }
+    /// `ALPHA` Creates a release note.
+    ///
+    /// Full type: [`ReleaseNote`](super::types::ReleaseNote)
+    ///
+    /// Requires the `releases` feature.
+    #[cfg(feature = "releases")]
+    pub fn release_note_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::ReleaseNote>,
+    >(
+        &self,
+        input: ReleaseNoteCreateInput,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::release_note_create::<T>(
+            self.as_async(),
+            input,
+        ))
+    }
+    /// `ALPHA` Updates a release note.
+    ///
+    /// Full type: [`ReleaseNote`](super::types::ReleaseNote)
+    ///
+    /// Requires the `releases` feature.
+    #[cfg(feature = "releases")]
+    pub fn release_note_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::ReleaseNote>,
+    >(
+        &self,
+        input: ReleaseNoteUpdateInput,
+        id: impl Into<super::ids::ReleaseNoteId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::release_note_update::<T>(
+            self.as_async(),
+            input,
+            id,
+        ))
+    }
+    /// `ALPHA` Deletes a release note.
+    ///
+    /// Requires the `releases` feature.
+    #[cfg(feature = "releases")]
+    pub fn release_note_delete(
+        &self,
+        id: impl Into<super::ids::ReleaseNoteId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        self.block_on(crate::generated::mutations::release_note_delete(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// Creates a new reaction.
+    ///
+    /// Full type: [`Reaction`](super::types::Reaction)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub fn reaction_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Reaction>,
+    >(
+        &self,
+        input: ReactionCreateInput,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::reaction_create::<T>(
+            self.as_async(),
+            input,
+        ))
+    }
+    /// Deletes a reaction.
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub fn reaction_delete(
+        &self,
+        id: impl Into<super::ids::ReactionId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        self.block_on(crate::generated::mutations::reaction_delete(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// Creates a push subscription for the authenticated user's current device or browser. If a subscription already exists for the same session, the old one is replaced.
+    ///
+    /// Full type: [`PushSubscription`](super::types::PushSubscription)
+    ///
+    /// Requires the `notifications` feature.
+    #[cfg(feature = "notifications")]
+    pub fn push_subscription_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::PushSubscription>,
+    >(
+        &self,
+        input: PushSubscriptionCreateInput,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::push_subscription_create::<T>(
+            self.as_async(),
+            input,
+        ))
+    }
+    /// Deletes a push subscription, unregistering the device from receiving push notifications.
+    ///
+    /// Full type: [`PushSubscription`](super::types::PushSubscription)
+    ///
+    /// Requires the `notifications` feature.
+    #[cfg(feature = "notifications")]
+    pub fn push_subscription_delete<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::PushSubscription>,
+    >(
+        &self,
+        id: impl Into<super::ids::PushSubscriptionId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::push_subscription_delete::<T>(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// Creates a new project update.
+    ///
+    /// Full type: [`ProjectUpdate`](super::types::ProjectUpdate)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub fn project_update_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::ProjectUpdate>,
+    >(
+        &self,
+        input: ProjectUpdateCreateInput,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::project_update_create::<T>(
+            self.as_async(),
+            input,
+        ))
+    }
+    /// Updates a project update.
+    ///
+    /// Full type: [`ProjectUpdate`](super::types::ProjectUpdate)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub fn project_update_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::ProjectUpdate>,
+    >(
+        &self,
+        input: ProjectUpdateUpdateInput,
+        id: impl Into<super::ids::ProjectUpdateId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::project_update_update::<T>(
+            self.as_async(),
+            input,
+            id,
+        ))
+    }
+    /// Archives a project update.
+    ///
+    /// Full type: [`ProjectUpdate`](super::types::ProjectUpdate)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub fn project_update_archive<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::ProjectUpdate>,
+    >(
+        &self,
+        id: impl Into<super::ids::ProjectUpdateId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::project_update_archive::<T>(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// Unarchives a project update.
+    ///
+    /// Full type: [`ProjectUpdate`](super::types::ProjectUpdate)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub fn project_update_unarchive<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::ProjectUpdate>,
+    >(
+        &self,
+        id: impl Into<super::ids::ProjectUpdateId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::project_update_unarchive::<T>(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// Deletes a project update.
+    #[deprecated(note = "Use `projectUpdateArchive` instead.")]
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub fn project_update_delete(
+        &self,
+        id: impl Into<super::ids::ProjectUpdateId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        self.block_on(crate::generated::mutations::project_update_delete(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// Create a notification to remind a user about a project update.
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub fn create_project_update_reminder(
+        &self,
+        user_id: Option<String>,
+        project_id: String,
+    ) -> Result<super::types::ProjectUpdateReminderPayload, LinearError> {
+        self.block_on(crate::generated::mutations::create_project_update_reminder(
+            self.as_async(),
+            user_id,
+            project_id,
+        ))
+    }
+    /// Creates a new project status.
+    ///
+    /// Full type: [`ProjectStatus`](super::types::ProjectStatus)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub fn project_status_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::ProjectStatus>,
+    >(
+        &self,
+        input: ProjectStatusCreateInput,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::project_status_create::<T>(
+            self.as_async(),
+            input,
+        ))
+    }
+    /// Updates a project status.
+    ///
+    /// Full type: [`ProjectStatus`](super::types::ProjectStatus)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub fn project_status_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::ProjectStatus>,
+    >(
+        &self,
+        input: ProjectStatusUpdateInput,
+        id: impl Into<super::ids::ProjectStatusId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::project_status_update::<T>(
+            self.as_async(),
+            input,
+            id,
+        ))
+    }
+    /// Archives a project status. The status must not have any active projects assigned to it and must not be the last status of its type.
+    ///
+    /// Full type: [`ProjectStatus`](super::types::ProjectStatus)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub fn project_status_archive<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::ProjectStatus>,
+    >(
+        &self,
+        id: impl Into<super::ids::ProjectStatusId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::project_status_archive::<T>(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// Unarchives a project status.
+    ///
+    /// Full type: [`ProjectStatus`](super::types::ProjectStatus)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub fn project_status_unarchive<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::ProjectStatus>,
+    >(
+        &self,
+        id: impl Into<super::ids::ProjectStatusId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::project_status_unarchive::<T>(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// Creates a new project relation.
+    ///
+    /// Full type: [`ProjectRelation`](super::types::ProjectRelation)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub fn project_relation_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::ProjectRelation>,
+    >(
+        &self,
+        input: ProjectRelationCreateInput,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::project_relation_create::<T>(
+            self.as_async(),
+            input,
+        ))
+    }
+    /// Updates a project relation.
+    ///
+    /// Full type: [`ProjectRelation`](super::types::ProjectRelation)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub fn project_relation_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::ProjectRelation>,
+    >(
+        &self,
+        input: ProjectRelationUpdateInput,
+        id: impl Into<super::ids::ProjectRelationId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::project_relation_update::<T>(
+            self.as_async(),
+            input,
+            id,
+        ))
+    }
+    /// Deletes a project relation.
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub fn project_relation_delete(
+        &self,
+        id: impl Into<super::ids::ProjectRelationId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        self.block_on(crate::generated::mutations::project_relation_delete(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// Creates a new project milestone.
+    ///
+    /// Full type: [`ProjectMilestone`](super::types::ProjectMilestone)
+    pub fn project_milestone_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::ProjectMilestone>,
+    >(
+        &self,
+        input: ProjectMilestoneCreateInput,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::project_milestone_create::<T>(
+            self.as_async(),
+            input,
+        ))
+    }
+    /// Updates a project milestone.
+    ///
+    /// Full type: [`ProjectMilestone`](super::types::ProjectMilestone)
+    pub fn project_milestone_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::ProjectMilestone>,
+    >(
+        &self,
+        input: ProjectMilestoneUpdateInput,
+        id: impl Into<super::ids::ProjectMilestoneId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::project_milestone_update::<T>(
+            self.as_async(),
+            input,
+            id,
+        ))
+    }
+    /// Deletes a project milestone.
+    pub fn project_milestone_delete(
+        &self,
+        id: impl Into<super::ids::ProjectMilestoneId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        self.block_on(crate::generated::mutations::project_milestone_delete(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// `Internal` Moves a project milestone to another project, can be called to undo a prior move.
+    ///
+    /// Full type: [`ProjectMilestone`](super::types::ProjectMilestone)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub fn project_milestone_move<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::ProjectMilestone>,
+    >(
+        &self,
+        input: ProjectMilestoneMoveInput,
+        id: impl Into<super::ids::ProjectMilestoneId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::project_milestone_move::<T>(
+            self.as_async(),
+            input,
+            id,
+        ))
+    }
+    /// Creates a new project label.
+    ///
+    /// Full type: [`ProjectLabel`](super::types::ProjectLabel)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub fn project_label_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::ProjectLabel>,
+    >(
+        &self,
+        input: ProjectLabelCreateInput,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::project_label_create::<T>(
+            self.as_async(),
+            input,
+        ))
+    }
+    /// Updates a project label.
+    ///
+    /// Full type: [`ProjectLabel`](super::types::ProjectLabel)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub fn project_label_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::ProjectLabel>,
+    >(
+        &self,
+        input: ProjectLabelUpdateInput,
+        id: impl Into<super::ids::ProjectLabelId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::project_label_update::<T>(
+            self.as_async(),
+            input,
+            id,
+        ))
+    }
+    /// Deletes a project label.
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub fn project_label_delete(
+        &self,
+        id: impl Into<super::ids::ProjectLabelId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        self.block_on(crate::generated::mutations::project_label_delete(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// Retires a project label. Retired labels remain on existing projects but cannot be applied to new ones.
+    ///
+    /// Full type: [`ProjectLabel`](super::types::ProjectLabel)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub fn project_label_retire<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::ProjectLabel>,
+    >(
+        &self,
+        id: impl Into<super::ids::ProjectLabelId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::project_label_retire::<T>(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// Restores a previously retired project label, making it available for use on new projects.
+    ///
+    /// Full type: [`ProjectLabel`](super::types::ProjectLabel)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub fn project_label_restore<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::ProjectLabel>,
+    >(
+        &self,
+        id: impl Into<super::ids::ProjectLabelId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::project_label_restore::<T>(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// Updates the user's workspace settings. Different settings require different permission levels; most require the workspaceSettings admin permission.
+    ///
+    /// Full type: [`Organization`](super::types::Organization)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub fn organization_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Organization>,
+    >(
+        &self,
+        input: OrganizationUpdateInput,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::organization_update::<T>(
+            self.as_async(),
+            input,
+        ))
+    }
+    /// Requests a deletion confirmation code for the workspace. The code is sent to the requesting user's email and must be used with the organizationDelete mutation to confirm deletion.
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub fn organization_delete_challenge(
+        &self,
+    ) -> Result<super::types::OrganizationDeletePayload, LinearError> {
+        self.block_on(crate::generated::mutations::organization_delete_challenge(
+            self.as_async(),
+        ))
+    }
+    /// Permanently deletes the workspace and all its data. Requires a valid deletion code obtained from organizationDeleteChallenge. This action is irreversible.
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub fn organization_delete(
+        &self,
+        input: DeleteOrganizationInput,
+    ) -> Result<super::types::OrganizationDeletePayload, LinearError> {
+        self.block_on(crate::generated::mutations::organization_delete(
+            self.as_async(),
+            input,
+        ))
+    }
+    /// Cancels a previously requested workspace deletion, if the workspace has not yet been fully deleted.
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub fn organization_cancel_delete(
+        &self,
+    ) -> Result<super::types::OrganizationCancelDeletePayload, LinearError> {
+        self.block_on(crate::generated::mutations::organization_cancel_delete(
+            self.as_async(),
+        ))
+    }
+    /// Starts a trial for the workspace on the specified plan type. The workspace must not already be on a paid plan or in an active trial.
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub fn organization_start_trial_for_plan(
+        &self,
+        input: OrganizationStartTrialInput,
+    ) -> Result<super::types::OrganizationStartTrialPayload, LinearError> {
+        self.block_on(
+            crate::generated::mutations::organization_start_trial_for_plan(self.as_async(), input),
+        )
+    }
+    /// `DEPRECATED` Starts a trial for the workspace.
+    #[deprecated(note = "Use organizationStartTrialForPlan")]
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub fn organization_start_trial(
+        &self,
+    ) -> Result<super::types::OrganizationStartTrialPayload, LinearError> {
+        self.block_on(crate::generated::mutations::organization_start_trial(
+            self.as_async(),
+        ))
+    }
+    /// Creates a new workspace invite and sends an invitation email to the specified address. The invite includes a role assignment and optional team memberships.
+    ///
+    /// Full type: [`OrganizationInvite`](super::types::OrganizationInvite)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub fn organization_invite_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::OrganizationInvite>,
+    >(
+        &self,
+        input: OrganizationInviteCreateInput,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::organization_invite_create::<T>(self.as_async(), input),
+        )
+    }
+    /// Updates an existing workspace invite, such as changing the teams the invitee will be added to.
+    ///
+    /// Full type: [`OrganizationInvite`](super::types::OrganizationInvite)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub fn organization_invite_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::OrganizationInvite>,
+    >(
+        &self,
+        input: OrganizationInviteUpdateInput,
+        id: impl Into<super::ids::OrganizationInviteId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::organization_invite_update::<T>(
+                self.as_async(),
+                input,
+                id,
+            ),
+        )
+    }
+    /// Re-sends a workspace invitation email for the specified invite ID.
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub fn resend_organization_invite(
+        &self,
+        id: String,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        self.block_on(crate::generated::mutations::resend_organization_invite(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// Re-sends a workspace invitation email to the specified email address, if an outstanding invite exists for that address.
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub fn resend_organization_invite_by_email(
+        &self,
+        email: String,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        self.block_on(
+            crate::generated::mutations::resend_organization_invite_by_email(
+                self.as_async(),
+                email,
+            ),
+        )
+    }
+    /// Deletes (archives) a workspace invite, preventing it from being accepted.
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub fn organization_invite_delete(
+        &self,
+        id: impl Into<super::ids::OrganizationInviteId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        self.block_on(crate::generated::mutations::organization_invite_delete(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// `INTERNAL` Verifies a domain claim.
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub fn organization_domain_claim(
+        &self,
+        id: impl Into<super::ids::OrganizationDomainId>,
+    ) -> Result<super::types::OrganizationDomainSimplePayload, LinearError> {
+        self.block_on(crate::generated::mutations::organization_domain_claim(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// `INTERNAL` Verifies a domain to be added to a workspace.
+    ///
+    /// Full type: [`OrganizationDomain`](super::types::OrganizationDomain)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub fn organization_domain_verify<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::OrganizationDomain>,
+    >(
+        &self,
+        input: OrganizationDomainVerificationInput,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::organization_domain_verify::<T>(self.as_async(), input),
+        )
+    }
+    /// `INTERNAL` Adds a domain to be allowed for a workspace.
+    ///
+    /// Full type: [`OrganizationDomain`](super::types::OrganizationDomain)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub fn organization_domain_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::OrganizationDomain>,
+    >(
+        &self,
+        trigger_email_verification: Option<bool>,
+        input: OrganizationDomainCreateInput,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::organization_domain_create::<T>(
+                self.as_async(),
+                trigger_email_verification,
+                input,
+            ),
+        )
+    }
+    /// `INTERNAL` Updates a workspace domain's settings.
+    ///
+    /// Full type: [`OrganizationDomain`](super::types::OrganizationDomain)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub fn organization_domain_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::OrganizationDomain>,
+    >(
+        &self,
+        input: OrganizationDomainUpdateInput,
+        id: impl Into<super::ids::OrganizationDomainId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::organization_domain_update::<T>(
+                self.as_async(),
+                input,
+                id,
+            ),
+        )
+    }
+    /// Deletes a domain.
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub fn organization_domain_delete(
+        &self,
+        id: impl Into<super::ids::OrganizationDomainId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        self.block_on(crate::generated::mutations::organization_domain_delete(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// Creates a new notification subscription for a specific entity. The subscription determines which notification types the authenticated user will receive for the target entity. Exactly one target entity (customer, custom view, cycle, initiative, label, project, team, or user) must be specified.
+    ///
+    /// Requires the `notifications` feature.
+    #[cfg(feature = "notifications")]
+    pub fn notification_subscription_create(
+        &self,
+        input: NotificationSubscriptionCreateInput,
+    ) -> Result<serde_json::Value, LinearError> {
+        self.block_on(
+            crate::generated::mutations::notification_subscription_create(self.as_async(), input),
+        )
+    }
+    /// Updates a notification subscription.
+    ///
+    /// Requires the `notifications` feature.
+    #[cfg(feature = "notifications")]
+    pub fn notification_subscription_update(
+        &self,
+        input: NotificationSubscriptionUpdateInput,
+        id: String,
+    ) -> Result<serde_json::Value, LinearError> {
+        self.block_on(
+            crate::generated::mutations::notification_subscription_update(
+                self.as_async(),
+                input,
+                id,
+            ),
+        )
+    }
+    /// Deletes a notification subscription reference.
+    #[deprecated(note = "Update `notificationSubscription.active` to `false` instead.")]
+    ///
+    /// Requires the `notifications` feature.
+    #[cfg(feature = "notifications")]
+    pub fn notification_subscription_delete(
+        &self,
+        id: String,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        self.block_on(
+            crate::generated::mutations::notification_subscription_delete(self.as_async(), id),
+        )
+    }
+    /// Updates a notification.
+    ///
+    /// Requires the `notifications` feature.
+    #[cfg(feature = "notifications")]
+    pub fn notification_update(
+        &self,
+        input: NotificationUpdateInput,
+        id: String,
+    ) -> Result<serde_json::Value, LinearError> {
+        self.block_on(crate::generated::mutations::notification_update(
+            self.as_async(),
+            input,
+            id,
+        ))
+    }
+    /// Marks notification and all related notifications as read.
+    ///
+    /// Requires the `notifications` feature.
+    #[cfg(feature = "notifications")]
+    pub fn notification_mark_read_all(
+        &self,
+        read_at: serde_json::Value,
+        input: NotificationEntityInput,
+    ) -> Result<serde_json::Value, LinearError> {
+        self.block_on(crate::generated::mutations::notification_mark_read_all(
+            self.as_async(),
+            read_at,
+            input,
+        ))
+    }
+    /// Marks notification and all related notifications as unread.
+    ///
+    /// Requires the `notifications` feature.
+    #[cfg(feature = "notifications")]
+    pub fn notification_mark_unread_all(
+        &self,
+        input: NotificationEntityInput,
+    ) -> Result<serde_json::Value, LinearError> {
+        self.block_on(crate::generated::mutations::notification_mark_unread_all(
+            self.as_async(),
+            input,
+        ))
+    }
+    /// Snoozes a notification and all related notifications.
+    ///
+    /// Requires the `notifications` feature.
+    #[cfg(feature = "notifications")]
+    pub fn notification_snooze_all(
+        &self,
+        snoozed_until_at: serde_json::Value,
+        input: NotificationEntityInput,
+    ) -> Result<serde_json::Value, LinearError> {
+        self.block_on(crate::generated::mutations::notification_snooze_all(
+            self.as_async(),
+            snoozed_until_at,
+            input,
+        ))
+    }
+    /// Unsnoozes a notification and all related notifications.
+    ///
+    /// Requires the `notifications` feature.
+    #[cfg(feature = "notifications")]
+    pub fn notification_unsnooze_all(
+        &self,
+        unsnoozed_at: serde_json::Value,
+        input: NotificationEntityInput,
+    ) -> Result<serde_json::Value, LinearError> {
+        self.block_on(crate::generated::mutations::notification_unsnooze_all(
+            self.as_async(),
+            unsnoozed_at,
+            input,
+        ))
+    }
+    /// Archives a notification.
+    ///
+    /// Requires the `notifications` feature.
+    #[cfg(feature = "notifications")]
+    pub fn notification_archive(&self, id: String) -> Result<serde_json::Value, LinearError> {
+        self.block_on(crate::generated::mutations::notification_archive(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// Archives a notification and all related notifications.
+    ///
+    /// Requires the `notifications` feature.
+    #[cfg(feature = "notifications")]
+    pub fn notification_archive_all(
+        &self,
+        input: NotificationEntityInput,
+    ) -> Result<serde_json::Value, LinearError> {
+        self.block_on(crate::generated::mutations::notification_archive_all(
+            self.as_async(),
+            input,
+        ))
+    }
+    /// Unarchives a notification.
+    ///
+    /// Requires the `notifications` feature.
+    #[cfg(feature = "notifications")]
+    pub fn notification_unarchive(&self, id: String) -> Result<serde_json::Value, LinearError> {
+        self.block_on(crate::generated::mutations::notification_unarchive(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// `ALPHA` Creates a new association between an issue and a release, linking the issue to the release for tracking purposes.
+    ///
+    /// Full type: [`IssueToRelease`](super::types::IssueToRelease)
+    ///
+    /// Requires the `releases` feature.
+    #[cfg(feature = "releases")]
+    pub fn issue_to_release_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::IssueToRelease>,
+    >(
+        &self,
+        input: IssueToReleaseCreateInput,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::issue_to_release_create::<T>(
+            self.as_async(),
+            input,
+        ))
+    }
+    /// `ALPHA` Deletes an issue-to-release association by looking up the issue and release identifiers, removing the issue from the release.
+    ///
+    /// Requires the `releases` feature.
+    #[cfg(feature = "releases")]
+    pub fn issue_to_release_delete_by_issue_and_release(
+        &self,
+        release_id: String,
+        issue_id: String,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        self.block_on(
+            crate::generated::mutations::issue_to_release_delete_by_issue_and_release(
+                self.as_async(),
+                release_id,
+                issue_id,
+            ),
+        )
+    }
+    /// `ALPHA` Deletes an issue-to-release association by its identifier, removing the issue from the release.
+    ///
+    /// Requires the `releases` feature.
+    #[cfg(feature = "releases")]
+    pub fn issue_to_release_delete(
+        &self,
+        id: impl Into<super::ids::IssueToReleaseId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        self.block_on(crate::generated::mutations::issue_to_release_delete(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// Creates a new issue.
+    ///
+    /// Full type: [`Issue`](super::types::Issue)
+    pub fn issue_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Issue>,
+    >(
+        &self,
+        input: IssueCreateInput,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::issue_create::<T>(
+            self.as_async(),
+            input,
+        ))
+    }
+    /// Creates a list of issues in one transaction.
+    ///
+    /// Full type: [`Issue`](super::types::Issue)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub fn issue_batch_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Issue>,
+    >(
+        &self,
+        input: IssueBatchCreateInput,
+    ) -> Result<Vec<T>, LinearError> {
+        self.block_on(crate::generated::mutations::issue_batch_create::<T>(
+            self.as_async(),
+            input,
+        ))
+    }
+    /// Updates an issue.
+    ///
+    /// Full type: [`Issue`](super::types::Issue)
+    pub fn issue_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Issue>,
+    >(
+        &self,
+        input: IssueUpdateInput,
+        id: impl Into<super::ids::IssueId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::issue_update::<T>(
+            self.as_async(),
+            input,
+            id,
+        ))
+    }
+    /// Updates multiple issues at once.
+    ///
+    /// Full type: [`Issue`](super::types::Issue)
+    pub fn issue_batch_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Issue>,
+    >(
+        &self,
+        input: IssueUpdateInput,
+        ids: Vec<String>,
+    ) -> Result<Vec<T>, LinearError> {
+        self.block_on(crate::generated::mutations::issue_batch_update::<T>(
+            self.as_async(),
+            input,
+            ids,
+        ))
+    }
+    /// Archives an issue.
+    ///
+    /// Full type: [`Issue`](super::types::Issue)
+    pub fn issue_archive<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Issue>,
+    >(
+        &self,
+        trash: Option<bool>,
+        id: impl Into<super::ids::IssueId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::issue_archive::<T>(
+            self.as_async(),
+            trash,
+            id,
+        ))
+    }
+    /// Unarchives an issue.
+    ///
+    /// Full type: [`Issue`](super::types::Issue)
+    pub fn issue_unarchive<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Issue>,
+    >(
+        &self,
+        id: impl Into<super::ids::IssueId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::issue_unarchive::<T>(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// Deletes (trashes) an issue.
+    ///
+    /// Full type: [`Issue`](super::types::Issue)
+    pub fn issue_delete<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Issue>,
+    >(
+        &self,
+        permanently_delete: Option<bool>,
+        id: impl Into<super::ids::IssueId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::issue_delete::<T>(
+            self.as_async(),
+            permanently_delete,
+            id,
+        ))
+    }
+    /// Adds a label to an issue.
+    ///
+    /// Full type: [`Issue`](super::types::Issue)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub fn issue_add_label<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Issue>,
+    >(
+        &self,
+        label_id: String,
+        id: impl Into<super::ids::IssueId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::issue_add_label::<T>(
+            self.as_async(),
+            label_id,
+            id,
+        ))
+    }
+    /// Removes a label from an issue.
+    ///
+    /// Full type: [`Issue`](super::types::Issue)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub fn issue_remove_label<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Issue>,
+    >(
+        &self,
+        label_id: String,
+        id: impl Into<super::ids::IssueId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::issue_remove_label::<T>(
+            self.as_async(),
+            label_id,
+            id,
+        ))
+    }
+    /// Adds an issue reminder. Will cause a notification to be sent when the issue reminder time is reached.
+    ///
+    /// Full type: [`Issue`](super::types::Issue)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub fn issue_reminder<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Issue>,
+    >(
+        &self,
+        reminder_at: serde_json::Value,
+        id: impl Into<super::ids::IssueId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::issue_reminder::<T>(
+            self.as_async(),
+            reminder_at,
+            id,
+        ))
+    }
+    /// Subscribes a user to an issue.
+    ///
+    /// Full type: [`Issue`](super::types::Issue)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub fn issue_subscribe<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Issue>,
+    >(
+        &self,
+        user_email: Option<String>,
+        user_id: Option<String>,
+        id: impl Into<super::ids::IssueId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::issue_subscribe::<T>(
+            self.as_async(),
+            user_email,
+            user_id,
+            id,
+        ))
+    }
+    /// Unsubscribes a user from an issue.
+    ///
+    /// Full type: [`Issue`](super::types::Issue)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub fn issue_unsubscribe<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Issue>,
+    >(
+        &self,
+        user_email: Option<String>,
+        user_id: Option<String>,
+        id: impl Into<super::ids::IssueId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::issue_unsubscribe::<T>(
+            self.as_async(),
+            user_email,
+            user_id,
+            id,
+        ))
+    }
+    /// `INTERNAL` Updates an issue description from the Front app to handle Front attachments correctly.
+    ///
+    /// Full type: [`Issue`](super::types::Issue)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub fn issue_description_update_from_front<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Issue>,
+    >(
+        &self,
+        description: String,
+        id: impl Into<super::ids::IssueId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::issue_description_update_from_front::<T>(
+                self.as_async(),
+                description,
+                id,
+            ),
+        )
+    }
+    /// Disables external sync on an issue.
+    ///
+    /// Full type: [`Issue`](super::types::Issue)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub fn issue_external_sync_disable<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Issue>,
+    >(
+        &self,
+        attachment_id: String,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::issue_external_sync_disable::<T>(
+                self.as_async(),
+                attachment_id,
+            ),
+        )
+    }
+    /// Creates a new issue relation.
+    ///
+    /// Full type: [`IssueRelation`](super::types::IssueRelation)
+    pub fn issue_relation_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::IssueRelation>,
+    >(
+        &self,
+        override_created_at: Option<serde_json::Value>,
+        input: IssueRelationCreateInput,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::issue_relation_create::<T>(
+            self.as_async(),
+            override_created_at,
+            input,
+        ))
+    }
+    /// Updates an issue relation.
+    ///
+    /// Full type: [`IssueRelation`](super::types::IssueRelation)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub fn issue_relation_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::IssueRelation>,
+    >(
+        &self,
+        input: IssueRelationUpdateInput,
+        id: impl Into<super::ids::IssueRelationId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::issue_relation_update::<T>(
+            self.as_async(),
+            input,
+            id,
+        ))
+    }
+    /// Deletes an issue relation.
+    pub fn issue_relation_delete(
+        &self,
+        id: impl Into<super::ids::IssueRelationId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        self.block_on(crate::generated::mutations::issue_relation_delete(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// Creates a new label.
+    ///
+    /// Full type: [`IssueLabel`](super::types::IssueLabel)
+    pub fn issue_label_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::IssueLabel>,
+    >(
+        &self,
+        replace_team_labels: Option<bool>,
+        input: IssueLabelCreateInput,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::issue_label_create::<T>(
+            self.as_async(),
+            replace_team_labels,
+            input,
+        ))
+    }
+    /// Updates a label.
+    ///
+    /// Full type: [`IssueLabel`](super::types::IssueLabel)
+    pub fn issue_label_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::IssueLabel>,
+    >(
+        &self,
+        replace_team_labels: Option<bool>,
+        input: IssueLabelUpdateInput,
+        id: impl Into<super::ids::IssueLabelId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::issue_label_update::<T>(
+            self.as_async(),
+            replace_team_labels,
+            input,
+            id,
+        ))
+    }
+    /// Deletes an issue label.
+    pub fn issue_label_delete(
+        &self,
+        id: impl Into<super::ids::IssueLabelId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        self.block_on(crate::generated::mutations::issue_label_delete(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// Retires a label. Retired labels are still visible but cannot be applied to new issues. Existing issues with the label are not affected.
+    ///
+    /// Full type: [`IssueLabel`](super::types::IssueLabel)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub fn issue_label_retire<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::IssueLabel>,
+    >(
+        &self,
+        id: impl Into<super::ids::IssueLabelId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::issue_label_retire::<T>(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// Restores a previously retired label, making it available for use again.
+    ///
+    /// Full type: [`IssueLabel`](super::types::IssueLabel)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub fn issue_label_restore<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::IssueLabel>,
+    >(
+        &self,
+        id: impl Into<super::ids::IssueLabelId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::issue_label_restore::<T>(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// Kicks off a GitHub import job.
+    ///
+    /// Full type: [`IssueImport`](super::types::IssueImport)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub fn issue_import_create_github<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::IssueImport>,
+    >(
+        &self,
+        team_id: Option<String>,
+        team_name: Option<String>,
+        github_repo_ids: Option<Vec<i64>>,
+        github_labels: Option<Vec<String>>,
+        instant_process: Option<bool>,
+        include_closed_issues: Option<bool>,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::issue_import_create_github::<T>(
+                self.as_async(),
+                team_id,
+                team_name,
+                github_repo_ids,
+                github_labels,
+                instant_process,
+                include_closed_issues,
+            ),
+        )
+    }
+    /// Kicks off a Jira import job.
+    ///
+    /// Full type: [`IssueImport`](super::types::IssueImport)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub fn issue_import_create_jira<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::IssueImport>,
+    >(
+        &self,
+        team_id: Option<String>,
+        team_name: Option<String>,
+        jira_token: String,
+        jira_project: String,
+        jira_email: String,
+        jira_hostname: String,
+        jql: Option<String>,
+        instant_process: Option<bool>,
+        include_closed_issues: Option<bool>,
+        id: Option<String>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::issue_import_create_jira::<T>(
+            self.as_async(),
+            team_id,
+            team_name,
+            jira_token,
+            jira_project,
+            jira_email,
+            jira_hostname,
+            jql,
+            instant_process,
+            include_closed_issues,
+            id,
+        ))
+    }
+    /// Kicks off a Jira import job from a CSV.
+    ///
+    /// Full type: [`IssueImport`](super::types::IssueImport)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub fn issue_import_create_csv_jira<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::IssueImport>,
+    >(
+        &self,
+        team_id: Option<String>,
+        team_name: Option<String>,
+        csv_url: String,
+        jira_hostname: Option<String>,
+        jira_token: Option<String>,
+        jira_email: Option<String>,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::issue_import_create_csv_jira::<T>(
+                self.as_async(),
+                team_id,
+                team_name,
+                csv_url,
+                jira_hostname,
+                jira_token,
+                jira_email,
+            ),
+        )
+    }
+    /// Kicks off a Shortcut (formerly Clubhouse) import job.
+    ///
+    /// Full type: [`IssueImport`](super::types::IssueImport)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub fn issue_import_create_clubhouse<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::IssueImport>,
+    >(
+        &self,
+        team_id: Option<String>,
+        team_name: Option<String>,
+        clubhouse_token: String,
+        clubhouse_group_name: String,
+        instant_process: Option<bool>,
+        include_closed_issues: Option<bool>,
+        id: Option<String>,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::issue_import_create_clubhouse::<T>(
+                self.as_async(),
+                team_id,
+                team_name,
+                clubhouse_token,
+                clubhouse_group_name,
+                instant_process,
+                include_closed_issues,
+                id,
+            ),
+        )
+    }
+    /// Kicks off an Asana import job.
+    ///
+    /// Full type: [`IssueImport`](super::types::IssueImport)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub fn issue_import_create_asana<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::IssueImport>,
+    >(
+        &self,
+        team_id: Option<String>,
+        team_name: Option<String>,
+        asana_token: String,
+        asana_team_name: String,
+        instant_process: Option<bool>,
+        include_closed_issues: Option<bool>,
+        id: Option<String>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::issue_import_create_asana::<T>(
+            self.as_async(),
+            team_id,
+            team_name,
+            asana_token,
+            asana_team_name,
+            instant_process,
+            include_closed_issues,
+            id,
+        ))
+    }
+    /// `INTERNAL` Kicks off a Linear to Linear import job.
+    ///
+    /// Full type: [`IssueImport`](super::types::IssueImport)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub fn issue_import_create_linear_v2<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::IssueImport>,
+    >(
+        &self,
+        linear_source_organization_id: String,
+        id: Option<String>,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::issue_import_create_linear_v2::<T>(
+                self.as_async(),
+                linear_source_organization_id,
+                id,
+            ),
+        )
+    }
+    /// Deletes an import job.
+    ///
+    /// Full type: [`IssueImport`](super::types::IssueImport)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub fn issue_import_delete<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::IssueImport>,
+    >(
+        &self,
+        issue_import_id: String,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::issue_import_delete::<T>(
+            self.as_async(),
+            issue_import_id,
+        ))
+    }
+    /// Kicks off import processing.
+    ///
+    /// Full type: [`IssueImport`](super::types::IssueImport)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub fn issue_import_process<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::IssueImport>,
+    >(
+        &self,
+        mapping: serde_json::Value,
+        issue_import_id: String,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::issue_import_process::<T>(
+            self.as_async(),
+            mapping,
+            issue_import_id,
+        ))
+    }
+    /// Updates the mapping for the issue import.
+    ///
+    /// Full type: [`IssueImport`](super::types::IssueImport)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub fn issue_import_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::IssueImport>,
+    >(
+        &self,
+        input: IssueImportUpdateInput,
+        id: impl Into<super::ids::IssueImportId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::issue_import_update::<T>(
+            self.as_async(),
+            input,
+            id,
+        ))
+    }
+    /// Creates new Slack notification settings for a team, project, initiative, or custom view.
+    ///
+    /// Full type: [`IntegrationsSettings`](super::types::IntegrationsSettings)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn integrations_settings_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::IntegrationsSettings>,
+    >(
+        &self,
+        input: IntegrationsSettingsCreateInput,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::integrations_settings_create::<T>(self.as_async(), input),
+        )
+    }
+    /// Updates Slack notification settings for a team, project, initiative, or custom view.
+    ///
+    /// Full type: [`IntegrationsSettings`](super::types::IntegrationsSettings)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn integrations_settings_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::IntegrationsSettings>,
+    >(
+        &self,
+        input: IntegrationsSettingsUpdateInput,
+        id: impl Into<super::ids::IntegrationsSettingsId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::integrations_settings_update::<T>(
+                self.as_async(),
+                input,
+                id,
+            ),
+        )
+    }
+    /// Creates a new connection between a template and an integration, optionally scoped to a specific external resource such as a Slack channel.
+    ///
+    /// Full type: [`IntegrationTemplate`](super::types::IntegrationTemplate)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn integration_template_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::IntegrationTemplate>,
+    >(
+        &self,
+        input: IntegrationTemplateCreateInput,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::integration_template_create::<T>(self.as_async(), input),
+        )
+    }
+    /// Deletes an integration template connection, removing the link between a template and an integration.
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn integration_template_delete(
+        &self,
+        id: impl Into<super::ids::IntegrationTemplateId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        self.block_on(crate::generated::mutations::integration_template_delete(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// `INTERNAL` Updates the integration.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn integration_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        input: IntegrationUpdateInput,
+        id: impl Into<super::ids::IntegrationId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::integration_update::<T>(
+            self.as_async(),
+            input,
+            id,
+        ))
+    }
+    /// `INTERNAL` Updates the integration settings.
+    #[deprecated(note = "Use integrationUpdate instead.")]
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn integration_settings_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        input: IntegrationSettingsInput,
+        id: impl Into<super::ids::IntegrationId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::integration_settings_update::<T>(
+                self.as_async(),
+                input,
+                id,
+            ),
+        )
+    }
+    /// Generates a webhook for the GitHub commit integration.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn integration_github_commit_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::integration_github_commit_create::<T>(self.as_async()),
+        )
+    }
+    /// Connects the workspace with the GitHub App.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn integration_github_connect<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        github_host: Option<String>,
+        code_access: Option<bool>,
+        code: String,
+        installation_id: String,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::integration_github_connect::<T>(
+                self.as_async(),
+                github_host,
+                code_access,
+                code,
+                installation_id,
+            ),
+        )
+    }
+    /// Connects the workspace with the GitHub Import App.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn integration_github_import_connect<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        code: String,
+        installation_id: String,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::integration_github_import_connect::<T>(
+                self.as_async(),
+                code,
+                installation_id,
+            ),
+        )
+    }
+    /// Refreshes the data for a GitHub import integration.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn integration_github_import_refresh<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        id: impl Into<super::ids::IntegrationId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::integration_github_import_refresh::<T>(
+                self.as_async(),
+                id,
+            ),
+        )
+    }
+    /// Connects the workspace with a GitHub Enterprise Server.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn integration_git_hub_enterprise_server_connect<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        organization_name: String,
+        github_url: String,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::integration_git_hub_enterprise_server_connect::<T>(
+                self.as_async(),
+                organization_name,
+                github_url,
+            ),
+        )
+    }
+    /// Connects the workspace with a GitLab Access Token.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn integration_gitlab_connect<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        gitlab_url: String,
+        access_token: String,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::integration_gitlab_connect::<T>(
+                self.as_async(),
+                gitlab_url,
+                access_token,
+            ),
+        )
+    }
+    /// Tests connectivity to a self-hosted GitLab instance and clears auth errors if successful.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn integration_gitlab_test_connection<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        integration_id: String,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::integration_gitlab_test_connection::<T>(
+                self.as_async(),
+                integration_id,
+            ),
+        )
+    }
+    /// Creates an integration api key for Airbyte to connect with Linear.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn airbyte_integration_connect<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        input: AirbyteConfigurationInput,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::airbyte_integration_connect::<T>(self.as_async(), input),
+        )
+    }
+    /// `Internal` Connects the Google Calendar to the user to this Linear account via OAuth2.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn integration_google_calendar_personal_connect<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        code: String,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::integration_google_calendar_personal_connect::<T>(
+                self.as_async(),
+                code,
+            ),
+        )
+    }
+    /// `INTERNAL` Integrates the workspace with LaunchDarkly.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn integration_launch_darkly_connect<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        code: String,
+        project_key: String,
+        environment: String,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::integration_launch_darkly_connect::<T>(
+                self.as_async(),
+                code,
+                project_key,
+                environment,
+            ),
+        )
+    }
+    /// `INTERNAL` Integrates your personal account with LaunchDarkly.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn integration_launch_darkly_personal_connect<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        code: String,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::integration_launch_darkly_personal_connect::<T>(
+                self.as_async(),
+                code,
+            ),
+        )
+    }
+    /// `INTERNAL` Connects the workspace with a Jira Personal Access Token.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn jira_integration_connect<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        input: JiraConfigurationInput,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::jira_integration_connect::<T>(
+            self.as_async(),
+            input,
+        ))
+    }
+    /// `INTERNAL` Updates a Jira Integration.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn integration_jira_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        input: JiraUpdateInput,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::integration_jira_update::<T>(
+            self.as_async(),
+            input,
+        ))
+    }
+    /// `INTERNAL` Fetches Jira project statuses and stores them in integration settings.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn integration_jira_fetch_project_statuses<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        input: JiraFetchProjectStatusesInput,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::integration_jira_fetch_project_statuses::<T>(
+                self.as_async(),
+                input,
+            ),
+        )
+    }
+    /// Connect your Jira account to Linear.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn integration_jira_personal<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        code: Option<String>,
+        access_token: Option<String>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::integration_jira_personal::<T>(
+            self.as_async(),
+            code,
+            access_token,
+        ))
+    }
+    /// Connect your GitHub account to Linear.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn integration_git_hub_personal<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        enterprise_url: Option<String>,
+        code_access: Option<bool>,
+        code: String,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::integration_git_hub_personal::<T>(
+                self.as_async(),
+                enterprise_url,
+                code_access,
+                code,
+            ),
+        )
+    }
+    /// Integrates the workspace with Intercom.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn integration_intercom<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        domain_url: Option<String>,
+        redirect_uri: String,
+        code: String,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::integration_intercom::<T>(
+            self.as_async(),
+            domain_url,
+            redirect_uri,
+            code,
+        ))
+    }
+    /// Disconnects the workspace from Intercom.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn integration_intercom_delete<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::integration_intercom_delete::<T>(self.as_async()),
+        )
+    }
+    /// `INTERNAL` Refreshes the customer data attributes from the specified integration service.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn integration_customer_data_attributes_refresh<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        input: IntegrationCustomerDataAttributesRefreshInput,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::integration_customer_data_attributes_refresh::<T>(
+                self.as_async(),
+                input,
+            ),
+        )
+    }
+    /// `DEPRECATED` Updates settings on the Intercom integration.
+    #[deprecated(
+        note = "This mutation is deprecated, please use `integrationSettingsUpdate` instead"
+    )]
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn integration_intercom_settings_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        input: IntercomSettingsInput,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::integration_intercom_settings_update::<T>(
+                self.as_async(),
+                input,
+            ),
+        )
+    }
+    /// Integrates the workspace with Discord.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn integration_discord<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        redirect_uri: String,
+        code: String,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::integration_discord::<T>(
+            self.as_async(),
+            redirect_uri,
+            code,
+        ))
+    }
+    /// `INTERNAL` Integrates the workspace with Opsgenie.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn integration_opsgenie_connect<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        api_key: String,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::integration_opsgenie_connect::<T>(
+                self.as_async(),
+                api_key,
+            ),
+        )
+    }
+    /// `INTERNAL` Refresh Opsgenie schedule mappings.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn integration_opsgenie_refresh_schedule_mappings<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::integration_opsgenie_refresh_schedule_mappings::<T>(
+                self.as_async(),
+            ),
+        )
+    }
+    /// `INTERNAL` Integrates the workspace with PagerDuty.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn integration_pager_duty_connect<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        code: String,
+        redirect_uri: String,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::integration_pager_duty_connect::<T>(
+                self.as_async(),
+                code,
+                redirect_uri,
+            ),
+        )
+    }
+    /// `INTERNAL` Refresh PagerDuty schedule mappings.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn integration_pager_duty_refresh_schedule_mappings<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::integration_pager_duty_refresh_schedule_mappings::<T>(
+                self.as_async(),
+            ),
+        )
+    }
+    /// `Internal` Updates existing Slack and Asks integration scopes.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn update_integration_slack_scopes<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        integration_id: String,
+        redirect_uri: String,
+        code: String,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::update_integration_slack_scopes::<T>(
+                self.as_async(),
+                integration_id,
+                redirect_uri,
+                code,
+            ),
+        )
+    }
+    /// Integrates the workspace with Slack.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn integration_slack<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        should_use_v2_auth: Option<bool>,
+        redirect_uri: String,
+        code: String,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::integration_slack::<T>(
+            self.as_async(),
+            should_use_v2_auth,
+            redirect_uri,
+            code,
+        ))
+    }
+    /// Integrates the workspace with the Slack Asks app.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn integration_slack_asks<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        redirect_uri: String,
+        code: String,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::integration_slack_asks::<T>(
+            self.as_async(),
+            redirect_uri,
+            code,
+        ))
+    }
+    /// Updates the Slack team's name in Linear for an existing Slack or Asks integration.
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn integration_slack_or_asks_update_slack_team_name(
+        &self,
+        integration_id: String,
+    ) -> Result<super::types::IntegrationSlackWorkspaceNamePayload, LinearError> {
+        self.block_on(
+            crate::generated::mutations::integration_slack_or_asks_update_slack_team_name(
+                self.as_async(),
+                integration_id,
+            ),
+        )
+    }
+    /// Integrates your personal notifications with Slack.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn integration_slack_personal<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        redirect_uri: String,
+        code: String,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::integration_slack_personal::<T>(
+                self.as_async(),
+                redirect_uri,
+                code,
+            ),
+        )
+    }
+    /// Connect a Slack channel to Asks.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn integration_asks_connect_channel<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        redirect_uri: String,
+        code: String,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::integration_asks_connect_channel::<T>(
+                self.as_async(),
+                redirect_uri,
+                code,
+            ),
+        )
+    }
+    /// Slack integration for team notifications.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn integration_slack_post<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        should_use_v2_auth: Option<bool>,
+        redirect_uri: String,
+        team_id: String,
+        code: String,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::integration_slack_post::<T>(
+            self.as_async(),
+            should_use_v2_auth,
+            redirect_uri,
+            team_id,
+            code,
+        ))
+    }
+    /// Slack integration for project notifications.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn integration_slack_project_post<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        service: String,
+        redirect_uri: String,
+        project_id: String,
+        code: String,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::integration_slack_project_post::<T>(
+                self.as_async(),
+                service,
+                redirect_uri,
+                project_id,
+                code,
+            ),
+        )
+    }
+    /// `Internal` Slack integration for initiative notifications.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn integration_slack_initiative_post<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        redirect_uri: String,
+        initiative_id: String,
+        code: String,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::integration_slack_initiative_post::<T>(
+                self.as_async(),
+                redirect_uri,
+                initiative_id,
+                code,
+            ),
+        )
+    }
+    /// Slack integration for custom view notifications.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn integration_slack_custom_view_notifications<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        redirect_uri: String,
+        custom_view_id: String,
+        code: String,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::integration_slack_custom_view_notifications::<T>(
+                self.as_async(),
+                redirect_uri,
+                custom_view_id,
+                code,
+            ),
+        )
+    }
+    /// Integrates a Slack Asks channel with a Customer.
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn integration_slack_customer_channel_link(
+        &self,
+        redirect_uri: String,
+        customer_id: String,
+        code: String,
+    ) -> Result<super::types::SuccessPayload, LinearError> {
+        self.block_on(
+            crate::generated::mutations::integration_slack_customer_channel_link(
+                self.as_async(),
+                redirect_uri,
+                customer_id,
+                code,
+            ),
+        )
+    }
+    /// Slack integration for workspace-level project update notifications.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn integration_slack_org_project_updates_post<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        redirect_uri: String,
+        code: String,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::integration_slack_org_project_updates_post::<T>(
+                self.as_async(),
+                redirect_uri,
+                code,
+            ),
+        )
+    }
+    /// `Internal` Slack integration for workspace-level initiative update notifications.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn integration_slack_org_initiative_updates_post<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        redirect_uri: String,
+        code: String,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::integration_slack_org_initiative_updates_post::<T>(
+                self.as_async(),
+                redirect_uri,
+                code,
+            ),
+        )
+    }
+    /// Imports custom emojis from your Slack workspace.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn integration_slack_import_emojis<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        redirect_uri: String,
+        code: String,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::integration_slack_import_emojis::<T>(
+                self.as_async(),
+                redirect_uri,
+                code,
+            ),
+        )
+    }
+    /// Integrates the workspace with Figma.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn integration_figma<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        redirect_uri: String,
+        code: String,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::integration_figma::<T>(
+            self.as_async(),
+            redirect_uri,
+            code,
+        ))
+    }
+    /// Integrates the workspace with Gong.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn integration_gong<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        redirect_uri: String,
+        code: String,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::integration_gong::<T>(
+            self.as_async(),
+            redirect_uri,
+            code,
+        ))
+    }
+    /// Integrates the workspace with Microsoft Teams.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn integration_microsoft_teams<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        redirect_uri: String,
+        code: String,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::integration_microsoft_teams::<T>(
+                self.as_async(),
+                redirect_uri,
+                code,
+            ),
+        )
+    }
+    /// Connects the user's personal Microsoft account to Linear.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn integration_microsoft_personal_connect<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        redirect_uri: String,
+        code: String,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::integration_microsoft_personal_connect::<T>(
+                self.as_async(),
+                redirect_uri,
+                code,
+            ),
+        )
+    }
+    /// `Internal` Connect a project to a Microsoft Teams channel. Find-or-update semantics: creates a microsoftTeamsProjectPost integration row if none exists for the project, or overwrites the existing one's team/channel selection. Requires the connecting user to have linked their personal Microsoft account.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn integration_microsoft_teams_project_post<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        membership_type: String,
+        channel_name: String,
+        channel_id: String,
+        team_name: String,
+        team_id: String,
+        project_id: String,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::integration_microsoft_teams_project_post::<T>(
+                self.as_async(),
+                membership_type,
+                channel_name,
+                channel_id,
+                team_name,
+                team_id,
+                project_id,
+            ),
+        )
+    }
+    /// Integrates the workspace with Google Sheets.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn integration_google_sheets<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        code: String,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::integration_google_sheets::<T>(
+            self.as_async(),
+            code,
+        ))
+    }
+    /// Manually update Google Sheets data.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn refresh_google_sheets_data<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        r#type: Option<String>,
+        id: impl Into<super::ids::IntegrationId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::refresh_google_sheets_data::<T>(
+                self.as_async(),
+                r#type,
+                id,
+            ),
+        )
+    }
+    /// Integrates the workspace with Sentry.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn integration_sentry_connect<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        organization_slug: String,
+        code: String,
+        installation_id: String,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::integration_sentry_connect::<T>(
+                self.as_async(),
+                organization_slug,
+                code,
+                installation_id,
+            ),
+        )
+    }
+    /// Integrates the workspace with Front.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn integration_front<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        redirect_uri: String,
+        code: String,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::integration_front::<T>(
+            self.as_async(),
+            redirect_uri,
+            code,
+        ))
+    }
+    /// Integrates the workspace with Zendesk.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn integration_zendesk<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        subdomain: String,
+        code: String,
+        scope: String,
+        redirect_uri: String,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::integration_zendesk::<T>(
+            self.as_async(),
+            subdomain,
+            code,
+            scope,
+            redirect_uri,
+        ))
+    }
+    /// Enables Loom integration for the workspace.
+    #[deprecated(note = "Not available.")]
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn integration_loom<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::integration_loom::<T>(
+            self.as_async(),
+        ))
+    }
+    /// Integrates the workspace with Salesforce.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn integration_salesforce<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        code: String,
+        subdomain: String,
+        redirect_uri: String,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::integration_salesforce::<T>(
+            self.as_async(),
+            code,
+            subdomain,
+            redirect_uri,
+        ))
+    }
+    /// `INTERNAL` Refreshes the Salesforce integration metadata.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn integration_salesforce_metadata_refresh<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        id: impl Into<super::ids::IntegrationId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::integration_salesforce_metadata_refresh::<T>(
+                self.as_async(),
+                id,
+            ),
+        )
+    }
+    /// `INTERNAL` Connects the user's personal account with an MCP server.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn integration_mcp_server_personal_connect<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        custom_headers: Option<Vec<McpServerCustomHeaderInput>>,
+        server_url: String,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::integration_mcp_server_personal_connect::<T>(
+                self.as_async(),
+                custom_headers,
+                server_url,
+            ),
+        )
+    }
+    /// `INTERNAL` Connects the workspace with an MCP server.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn integration_mcp_server_connect<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        custom_headers: Option<Vec<McpServerCustomHeaderInput>>,
+        workflow_definition_id: Option<String>,
+        team_id: Option<String>,
+        server_url: String,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::integration_mcp_server_connect::<T>(
+                self.as_async(),
+                custom_headers,
+                workflow_definition_id,
+                team_id,
+                server_url,
+            ),
+        )
+    }
+    /// Deletes an integration.
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn integration_delete(
+        &self,
+        skip_installation_deletion: Option<bool>,
+        id: impl Into<super::ids::IntegrationId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        self.block_on(crate::generated::mutations::integration_delete(
+            self.as_async(),
+            skip_installation_deletion,
+            id,
+        ))
+    }
+    /// Archives an integration.
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn integration_archive(
+        &self,
+        id: impl Into<super::ids::IntegrationId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        self.block_on(crate::generated::mutations::integration_archive(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// `Internal` Enables Linear Agent Slack workflow access for a Slack or Slack Asks integration.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn integration_slack_workflow_access_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        enabled: bool,
+        integration_id: String,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::integration_slack_workflow_access_update::<T>(
+                self.as_async(),
+                enabled,
+                integration_id,
+            ),
+        )
+    }
+    /// Requests a currently unavailable integration.
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn integration_request(
+        &self,
+        input: IntegrationRequestInput,
+    ) -> Result<super::types::IntegrationRequestPayload, LinearError> {
+        self.block_on(crate::generated::mutations::integration_request(
+            self.as_async(),
+            input,
+        ))
+    }
+    /// Creates an initiative update.
+    ///
+    /// Full type: [`InitiativeUpdate`](super::types::InitiativeUpdate)
+    ///
+    /// Requires the `initiatives` feature.
+    #[cfg(feature = "initiatives")]
+    pub fn initiative_update_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::InitiativeUpdate>,
+    >(
+        &self,
+        input: InitiativeUpdateCreateInput,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::initiative_update_create::<T>(
+            self.as_async(),
+            input,
+        ))
+    }
+    /// Updates an initiative update.
+    ///
+    /// Full type: [`InitiativeUpdate`](super::types::InitiativeUpdate)
+    ///
+    /// Requires the `initiatives` feature.
+    #[cfg(feature = "initiatives")]
+    pub fn initiative_update_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::InitiativeUpdate>,
+    >(
+        &self,
+        input: InitiativeUpdateUpdateInput,
+        id: impl Into<super::ids::InitiativeUpdateId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::initiative_update_update::<T>(
+            self.as_async(),
+            input,
+            id,
+        ))
+    }
+    /// Archives an initiative update.
+    ///
+    /// Full type: [`InitiativeUpdate`](super::types::InitiativeUpdate)
+    ///
+    /// Requires the `initiatives` feature.
+    #[cfg(feature = "initiatives")]
+    pub fn initiative_update_archive<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::InitiativeUpdate>,
+    >(
+        &self,
+        id: impl Into<super::ids::InitiativeUpdateId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::initiative_update_archive::<T>(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// Create a notification to remind a user about an initiative update.
+    ///
+    /// Requires the `initiatives` feature.
+    #[cfg(feature = "initiatives")]
+    pub fn create_initiative_update_reminder(
+        &self,
+        user_id: Option<String>,
+        initiative_id: String,
+    ) -> Result<super::types::InitiativeUpdateReminderPayload, LinearError> {
+        self.block_on(
+            crate::generated::mutations::create_initiative_update_reminder(
+                self.as_async(),
+                user_id,
+                initiative_id,
+            ),
+        )
+    }
+    /// Unarchives an initiative update.
+    ///
+    /// Full type: [`InitiativeUpdate`](super::types::InitiativeUpdate)
+    ///
+    /// Requires the `initiatives` feature.
+    #[cfg(feature = "initiatives")]
+    pub fn initiative_update_unarchive<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::InitiativeUpdate>,
+    >(
+        &self,
+        id: impl Into<super::ids::InitiativeUpdateId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::initiative_update_unarchive::<T>(self.as_async(), id),
+        )
+    }
+    /// Associates a project with an initiative. A project can only appear once in an initiative hierarchy.
+    ///
+    /// Full type: [`InitiativeToProject`](super::types::InitiativeToProject)
+    ///
+    /// Requires the `initiatives` feature.
+    #[cfg(feature = "initiatives")]
+    pub fn initiative_to_project_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::InitiativeToProject>,
+    >(
+        &self,
+        input: InitiativeToProjectCreateInput,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::initiative_to_project_create::<T>(self.as_async(), input),
+        )
+    }
+    /// Updates an initiative-to-project association, such as its sort order.
+    ///
+    /// Full type: [`InitiativeToProject`](super::types::InitiativeToProject)
+    ///
+    /// Requires the `initiatives` feature.
+    #[cfg(feature = "initiatives")]
+    pub fn initiative_to_project_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::InitiativeToProject>,
+    >(
+        &self,
+        input: InitiativeToProjectUpdateInput,
+        id: impl Into<super::ids::InitiativeToProjectId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::initiative_to_project_update::<T>(
+                self.as_async(),
+                input,
+                id,
+            ),
+        )
+    }
+    /// Removes a project from an initiative.
+    ///
+    /// Requires the `initiatives` feature.
+    #[cfg(feature = "initiatives")]
+    pub fn initiative_to_project_delete(
+        &self,
+        id: impl Into<super::ids::InitiativeToProjectId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        self.block_on(crate::generated::mutations::initiative_to_project_delete(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// Creates a new initiative.
+    ///
+    /// Full type: [`Initiative`](super::types::Initiative)
+    ///
+    /// Requires the `initiatives` feature.
+    #[cfg(feature = "initiatives")]
+    pub fn initiative_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Initiative>,
+    >(
+        &self,
+        input: InitiativeCreateInput,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::initiative_create::<T>(
+            self.as_async(),
+            input,
+        ))
+    }
+    /// Updates an initiative.
+    ///
+    /// Full type: [`Initiative`](super::types::Initiative)
+    ///
+    /// Requires the `initiatives` feature.
+    #[cfg(feature = "initiatives")]
+    pub fn initiative_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Initiative>,
+    >(
+        &self,
+        input: InitiativeUpdateInput,
+        id: impl Into<super::ids::InitiativeId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::initiative_update::<T>(
+            self.as_async(),
+            input,
+            id,
+        ))
+    }
+    /// Archives an initiative.
+    ///
+    /// Full type: [`Initiative`](super::types::Initiative)
+    ///
+    /// Requires the `initiatives` feature.
+    #[cfg(feature = "initiatives")]
+    pub fn initiative_archive<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Initiative>,
+    >(
+        &self,
+        id: impl Into<super::ids::InitiativeId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::initiative_archive::<T>(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// Unarchives an initiative.
+    ///
+    /// Full type: [`Initiative`](super::types::Initiative)
+    ///
+    /// Requires the `initiatives` feature.
+    #[cfg(feature = "initiatives")]
+    pub fn initiative_unarchive<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Initiative>,
+    >(
+        &self,
+        id: impl Into<super::ids::InitiativeId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::initiative_unarchive::<T>(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// Deletes (trashes) an initiative.
+    ///
+    /// Requires the `initiatives` feature.
+    #[cfg(feature = "initiatives")]
+    pub fn initiative_delete(
+        &self,
+        id: impl Into<super::ids::InitiativeId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        self.block_on(crate::generated::mutations::initiative_delete(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// Creates a new parent-child relation between two initiatives. The relation cannot create cycles or exceed maximum nesting depth.
+    ///
+    /// Full type: [`InitiativeRelation`](super::types::InitiativeRelation)
+    ///
+    /// Requires the `initiatives` feature.
+    #[cfg(feature = "initiatives")]
+    pub fn initiative_relation_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::InitiativeRelation>,
+    >(
+        &self,
+        input: InitiativeRelationCreateInput,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::initiative_relation_create::<T>(self.as_async(), input),
+        )
+    }
+    /// Updates an initiative relation.
+    ///
+    /// Full type: [`InitiativeRelation`](super::types::InitiativeRelation)
+    ///
+    /// Requires the `initiatives` feature.
+    #[cfg(feature = "initiatives")]
+    pub fn initiative_relation_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::InitiativeRelation>,
+    >(
+        &self,
+        input: InitiativeRelationUpdateInput,
+        id: impl Into<super::ids::InitiativeRelationId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::initiative_relation_update::<T>(
+                self.as_async(),
+                input,
+                id,
+            ),
+        )
+    }
+    /// Deletes an initiative relation.
+    ///
+    /// Requires the `initiatives` feature.
+    #[cfg(feature = "initiatives")]
+    pub fn initiative_relation_delete(
+        &self,
+        id: impl Into<super::ids::InitiativeRelationId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        self.block_on(crate::generated::mutations::initiative_relation_delete(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// Creates a new Git target branch definition that scopes automation rules to pull requests targeting a specific branch pattern.
+    ///
+    /// Full type: [`GitAutomationTargetBranch`](super::types::GitAutomationTargetBranch)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn git_automation_target_branch_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<
+                FullType = super::types::GitAutomationTargetBranch,
+            >,
+    >(
+        &self,
+        input: GitAutomationTargetBranchCreateInput,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::git_automation_target_branch_create::<T>(
+                self.as_async(),
+                input,
+            ),
+        )
+    }
+    /// Updates an existing Git target branch definition, including its branch pattern and regex flag.
+    ///
+    /// Full type: [`GitAutomationTargetBranch`](super::types::GitAutomationTargetBranch)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn git_automation_target_branch_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<
+                FullType = super::types::GitAutomationTargetBranch,
+            >,
+    >(
+        &self,
+        input: GitAutomationTargetBranchUpdateInput,
+        id: impl Into<super::ids::GitAutomationTargetBranchId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::git_automation_target_branch_update::<T>(
+                self.as_async(),
+                input,
+                id,
+            ),
+        )
+    }
+    /// Deletes a Git target branch definition and its associated automation rules.
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn git_automation_target_branch_delete(
+        &self,
+        id: impl Into<super::ids::GitAutomationTargetBranchId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        self.block_on(
+            crate::generated::mutations::git_automation_target_branch_delete(self.as_async(), id),
+        )
+    }
+    /// Creates a new Git automation rule that maps a Git event to a workflow state transition for a team.
+    ///
+    /// Full type: [`GitAutomationState`](super::types::GitAutomationState)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn git_automation_state_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::GitAutomationState>,
+    >(
+        &self,
+        input: GitAutomationStateCreateInput,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::git_automation_state_create::<T>(self.as_async(), input),
+        )
+    }
+    /// Updates an existing Git automation rule, including its workflow state, target branch, and triggering event.
+    ///
+    /// Full type: [`GitAutomationState`](super::types::GitAutomationState)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn git_automation_state_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::GitAutomationState>,
+    >(
+        &self,
+        input: GitAutomationStateUpdateInput,
+        id: impl Into<super::ids::GitAutomationStateId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::git_automation_state_update::<T>(
+                self.as_async(),
+                input,
+                id,
+            ),
+        )
+    }
+    /// Deletes a Git automation rule.
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn git_automation_state_delete(
+        &self,
+        id: impl Into<super::ids::GitAutomationStateId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        self.block_on(crate::generated::mutations::git_automation_state_delete(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// Creates a new favorite for the authenticated user. Exactly one target entity must be specified. If a favorite for the same entity already exists, the existing favorite is returned (upsert behavior).
+    ///
+    /// Full type: [`Favorite`](super::types::Favorite)
+    ///
+    /// Requires the `views` feature.
+    #[cfg(feature = "views")]
+    pub fn favorite_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Favorite>,
+    >(
+        &self,
+        input: FavoriteCreateInput,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::favorite_create::<T>(
+            self.as_async(),
+            input,
+        ))
+    }
+    /// Updates a favorite's position, parent folder, or folder name.
+    ///
+    /// Full type: [`Favorite`](super::types::Favorite)
+    ///
+    /// Requires the `views` feature.
+    #[cfg(feature = "views")]
+    pub fn favorite_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Favorite>,
+    >(
+        &self,
+        input: FavoriteUpdateInput,
+        id: impl Into<super::ids::FavoriteId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::favorite_update::<T>(
+            self.as_async(),
+            input,
+            id,
+        ))
+    }
+    /// Deletes a favorite, removing it from the user's sidebar. This is an idempotent operation -- deleting a non-existent favorite succeeds silently.
+    ///
+    /// Requires the `views` feature.
+    #[cfg(feature = "views")]
+    pub fn favorite_delete(
+        &self,
+        id: impl Into<super::ids::FavoriteId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        self.block_on(crate::generated::mutations::favorite_delete(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// Track an anonymous analytics event.
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub fn track_anonymous_event(
+        &self,
+        input: EventTrackingInput,
+    ) -> Result<super::types::EventTrackingPayload, LinearError> {
+        self.block_on(crate::generated::mutations::track_anonymous_event(
+            self.as_async(),
+            input,
+        ))
+    }
+    /// Creates a new external link on an initiative, project, team, release, or cycle.
+    ///
+    /// Full type: [`EntityExternalLink`](super::types::EntityExternalLink)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub fn entity_external_link_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::EntityExternalLink>,
+    >(
+        &self,
+        input: EntityExternalLinkCreateInput,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::entity_external_link_create::<T>(self.as_async(), input),
+        )
+    }
+    /// Updates an existing entity external link's URL, label, or sort order.
+    ///
+    /// Full type: [`EntityExternalLink`](super::types::EntityExternalLink)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub fn entity_external_link_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::EntityExternalLink>,
+    >(
+        &self,
+        input: EntityExternalLinkUpdateInput,
+        id: impl Into<super::ids::EntityExternalLinkId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::entity_external_link_update::<T>(
+                self.as_async(),
+                input,
+                id,
+            ),
+        )
+    }
+    /// Deletes an entity external link.
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub fn entity_external_link_delete(
+        &self,
+        id: impl Into<super::ids::EntityExternalLinkId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        self.block_on(crate::generated::mutations::entity_external_link_delete(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// Creates a custom emoji.
+    ///
+    /// Full type: [`Emoji`](super::types::Emoji)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub fn emoji_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Emoji>,
+    >(
+        &self,
+        input: EmojiCreateInput,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::emoji_create::<T>(
+            self.as_async(),
+            input,
+        ))
+    }
+    /// Deletes an emoji.
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub fn emoji_delete(
+        &self,
+        id: impl Into<super::ids::EmojiId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        self.block_on(crate::generated::mutations::emoji_delete(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// Unsubscribes the user from one type of email.
+    ///
+    /// Requires the `notifications` feature.
+    #[cfg(feature = "notifications")]
+    pub fn email_unsubscribe(
+        &self,
+        input: EmailUnsubscribeInput,
+    ) -> Result<super::types::EmailUnsubscribePayload, LinearError> {
+        self.block_on(crate::generated::mutations::email_unsubscribe(
+            self.as_async(),
+            input,
+        ))
+    }
+    /// Creates a new email intake address.
+    ///
+    /// Full type: [`EmailIntakeAddress`](super::types::EmailIntakeAddress)
+    ///
+    /// Requires the `teams` feature.
+    #[cfg(feature = "teams")]
+    pub fn email_intake_address_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::EmailIntakeAddress>,
+    >(
+        &self,
+        input: EmailIntakeAddressCreateInput,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::email_intake_address_create::<T>(self.as_async(), input),
+        )
+    }
+    /// Rotates an existing email intake address.
+    ///
+    /// Full type: [`EmailIntakeAddress`](super::types::EmailIntakeAddress)
+    ///
+    /// Requires the `teams` feature.
+    #[cfg(feature = "teams")]
+    pub fn email_intake_address_rotate<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::EmailIntakeAddress>,
+    >(
+        &self,
+        id: impl Into<super::ids::EmailIntakeAddressId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::email_intake_address_rotate::<T>(self.as_async(), id),
+        )
+    }
+    /// Updates an existing email intake address.
+    ///
+    /// Full type: [`EmailIntakeAddress`](super::types::EmailIntakeAddress)
+    ///
+    /// Requires the `teams` feature.
+    #[cfg(feature = "teams")]
+    pub fn email_intake_address_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::EmailIntakeAddress>,
+    >(
+        &self,
+        input: EmailIntakeAddressUpdateInput,
+        id: impl Into<super::ids::EmailIntakeAddressId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::email_intake_address_update::<T>(
+                self.as_async(),
+                input,
+                id,
+            ),
+        )
+    }
+    /// Deletes an email intake address object.
+    ///
+    /// Requires the `teams` feature.
+    #[cfg(feature = "teams")]
+    pub fn email_intake_address_delete(
+        &self,
+        id: impl Into<super::ids::EmailIntakeAddressId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        self.block_on(crate::generated::mutations::email_intake_address_delete(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// Creates a new document.
+    ///
+    /// Full type: [`Document`](super::types::Document)
+    pub fn document_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Document>,
+    >(
+        &self,
+        input: DocumentCreateInput,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::document_create::<T>(
+            self.as_async(),
+            input,
+        ))
+    }
+    /// Updates a document.
+    ///
+    /// Full type: [`Document`](super::types::Document)
+    pub fn document_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Document>,
+    >(
+        &self,
+        input: DocumentUpdateInput,
+        id: impl Into<super::ids::DocumentId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::document_update::<T>(
+            self.as_async(),
+            input,
+            id,
+        ))
+    }
+    /// Deletes (trashes) a document. The document is marked as trashed and archived, but not permanently removed.
+    ///
+    /// Full type: [`Document`](super::types::Document)
+    pub fn document_delete<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Document>,
+    >(
+        &self,
+        id: impl Into<super::ids::DocumentId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::document_delete::<T>(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// Restores a previously trashed document by unarchiving it.
+    ///
+    /// Full type: [`Document`](super::types::Document)
+    ///
+    /// Requires the `documents` feature.
+    #[cfg(feature = "documents")]
+    pub fn document_unarchive<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Document>,
+    >(
+        &self,
+        id: impl Into<super::ids::DocumentId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::document_unarchive::<T>(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// Creates a new cycle.
+    ///
+    /// Full type: [`Cycle`](super::types::Cycle)
+    ///
+    /// Requires the `cycles` feature.
+    #[cfg(feature = "cycles")]
+    pub fn cycle_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Cycle>,
+    >(
+        &self,
+        input: CycleCreateInput,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::cycle_create::<T>(
+            self.as_async(),
+            input,
+        ))
+    }
+    /// Updates a cycle.
+    ///
+    /// Full type: [`Cycle`](super::types::Cycle)
+    ///
+    /// Requires the `cycles` feature.
+    #[cfg(feature = "cycles")]
+    pub fn cycle_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Cycle>,
+    >(
+        &self,
+        input: CycleUpdateInput,
+        id: impl Into<super::ids::CycleId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::cycle_update::<T>(
+            self.as_async(),
+            input,
+            id,
+        ))
+    }
+    /// Archives a cycle. All issues currently assigned to the cycle are unlinked from it before archiving.
+    ///
+    /// Full type: [`Cycle`](super::types::Cycle)
+    ///
+    /// Requires the `cycles` feature.
+    #[cfg(feature = "cycles")]
+    pub fn cycle_archive<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Cycle>,
+    >(
+        &self,
+        id: impl Into<super::ids::CycleId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::cycle_archive::<T>(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// Shifts all cycles starts and ends by a certain number of days, starting from the provided cycle onwards.
+    ///
+    /// Full type: [`Cycle`](super::types::Cycle)
+    ///
+    /// Requires the `cycles` feature.
+    #[cfg(feature = "cycles")]
+    pub fn cycle_shift_all<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Cycle>,
+    >(
+        &self,
+        input: CycleShiftAllInput,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::cycle_shift_all::<T>(
+            self.as_async(),
+            input,
+        ))
+    }
+    /// Starts the upcoming cycle as of midnight today. Completes the previous cycle if it has not yet ended. Only the next upcoming (not yet started) cycle for the team can be started.
+    ///
+    /// Full type: [`Cycle`](super::types::Cycle)
+    ///
+    /// Requires the `cycles` feature.
+    #[cfg(feature = "cycles")]
+    pub fn cycle_start_upcoming_cycle_today<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Cycle>,
+    >(
+        &self,
+        id: impl Into<super::ids::CycleId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::cycle_start_upcoming_cycle_today::<T>(self.as_async(), id),
+        )
+    }
+    /// Creates a new customer tier.
+    ///
+    /// Full type: [`CustomerTier`](super::types::CustomerTier)
+    ///
+    /// Requires the `customers` feature.
+    #[cfg(feature = "customers")]
+    pub fn customer_tier_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::CustomerTier>,
+    >(
+        &self,
+        input: CustomerTierCreateInput,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::customer_tier_create::<T>(
+            self.as_async(),
+            input,
+        ))
+    }
+    /// Updates a customer tier.
+    ///
+    /// Full type: [`CustomerTier`](super::types::CustomerTier)
+    ///
+    /// Requires the `customers` feature.
+    #[cfg(feature = "customers")]
+    pub fn customer_tier_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::CustomerTier>,
+    >(
+        &self,
+        input: CustomerTierUpdateInput,
+        id: impl Into<super::ids::CustomerTierId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::customer_tier_update::<T>(
+            self.as_async(),
+            input,
+            id,
+        ))
+    }
+    /// Deletes a customer tier. The tier must not be in use by any customers.
+    ///
+    /// Requires the `customers` feature.
+    #[cfg(feature = "customers")]
+    pub fn customer_tier_delete(
+        &self,
+        id: impl Into<super::ids::CustomerTierId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        self.block_on(crate::generated::mutations::customer_tier_delete(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// Creates a new customer status.
+    ///
+    /// Full type: [`CustomerStatus`](super::types::CustomerStatus)
+    ///
+    /// Requires the `customers` feature.
+    #[cfg(feature = "customers")]
+    pub fn customer_status_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::CustomerStatus>,
+    >(
+        &self,
+        input: CustomerStatusCreateInput,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::customer_status_create::<T>(
+            self.as_async(),
+            input,
+        ))
+    }
+    /// Updates a customer status.
+    ///
+    /// Full type: [`CustomerStatus`](super::types::CustomerStatus)
+    ///
+    /// Requires the `customers` feature.
+    #[cfg(feature = "customers")]
+    pub fn customer_status_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::CustomerStatus>,
+    >(
+        &self,
+        input: CustomerStatusUpdateInput,
+        id: impl Into<super::ids::CustomerStatusId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::customer_status_update::<T>(
+            self.as_async(),
+            input,
+            id,
+        ))
+    }
+    /// Deletes a customer status. Cannot delete the last remaining status in a workspace, and the status must not be in use by any customers.
+    ///
+    /// Requires the `customers` feature.
+    #[cfg(feature = "customers")]
+    pub fn customer_status_delete(
+        &self,
+        id: impl Into<super::ids::CustomerStatusId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        self.block_on(crate::generated::mutations::customer_status_delete(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// Creates a new customer.
+    ///
+    /// Full type: [`Customer`](super::types::Customer)
+    ///
+    /// Requires the `customers` feature.
+    #[cfg(feature = "customers")]
+    pub fn customer_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Customer>,
+    >(
+        &self,
+        input: CustomerCreateInput,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::customer_create::<T>(
+            self.as_async(),
+            input,
+        ))
+    }
+    /// Updates an existing customer.
+    ///
+    /// Full type: [`Customer`](super::types::Customer)
+    ///
+    /// Requires the `customers` feature.
+    #[cfg(feature = "customers")]
+    pub fn customer_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Customer>,
+    >(
+        &self,
+        input: CustomerUpdateInput,
+        id: impl Into<super::ids::CustomerId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::customer_update::<T>(
+            self.as_async(),
+            input,
+            id,
+        ))
+    }
+    /// Deletes a customer.
+    ///
+    /// Requires the `customers` feature.
+    #[cfg(feature = "customers")]
+    pub fn customer_delete(
+        &self,
+        id: impl Into<super::ids::CustomerId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        self.block_on(crate::generated::mutations::customer_delete(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// Merges two customers by transferring all needs from the source customer to the target customer. The source customer is archived after the merge. Domains, external IDs, and metadata are combined on the target customer.
+    ///
+    /// Full type: [`Customer`](super::types::Customer)
+    ///
+    /// Requires the `customers` feature.
+    #[cfg(feature = "customers")]
+    pub fn customer_merge<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Customer>,
+    >(
+        &self,
+        source_customer_id: String,
+        target_customer_id: String,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::customer_merge::<T>(
+            self.as_async(),
+            source_customer_id,
+            target_customer_id,
+        ))
+    }
+    /// Upserts a customer, creating it if no match is found, or updating it otherwise. Matches against existing customers using `id`, `externalId`, `slackChannelId`, or `domains`.
+    ///
+    /// Full type: [`Customer`](super::types::Customer)
+    ///
+    /// Requires the `customers` feature.
+    #[cfg(feature = "customers")]
+    pub fn customer_upsert<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Customer>,
+    >(
+        &self,
+        input: CustomerUpsertInput,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::customer_upsert::<T>(
+            self.as_async(),
+            input,
+        ))
+    }
+    /// Unsyncs a managed customer from its current data source integration. External IDs mapping to the external source will be cleared, and the customer will no longer be updated by the integration.
+    ///
+    /// Full type: [`Customer`](super::types::Customer)
+    ///
+    /// Requires the `customers` feature.
+    #[cfg(feature = "customers")]
+    pub fn customer_unsync<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Customer>,
+    >(
+        &self,
+        id: impl Into<super::ids::CustomerId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::customer_unsync::<T>(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// Creates a new customer need.
+    ///
+    /// Full type: [`CustomerNeed`](super::types::CustomerNeed)
+    ///
+    /// Requires the `customers` feature.
+    #[cfg(feature = "customers")]
+    pub fn customer_need_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::CustomerNeed>,
+    >(
+        &self,
+        input: CustomerNeedCreateInput,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::customer_need_create::<T>(
+            self.as_async(),
+            input,
+        ))
+    }
+    /// Creates a new customer need from an existing issue attachment. If the attachment already has an archived need, it will be unarchived instead of creating a duplicate.
+    ///
+    /// Full type: [`CustomerNeed`](super::types::CustomerNeed)
+    ///
+    /// Requires the `customers` feature.
+    #[cfg(feature = "customers")]
+    pub fn customer_need_create_from_attachment<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::CustomerNeed>,
+    >(
+        &self,
+        input: CustomerNeedCreateFromAttachmentInput,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::customer_need_create_from_attachment::<T>(
+                self.as_async(),
+                input,
+            ),
+        )
+    }
+    /// Updates an existing customer need. Supports moving the need to a different issue or project, changing priority, updating the body content, and managing the attached source URL.
+    ///
+    /// Full type: [`CustomerNeed`](super::types::CustomerNeed)
+    ///
+    /// Requires the `customers` feature.
+    #[cfg(feature = "customers")]
+    pub fn customer_need_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::CustomerNeed>,
+    >(
+        &self,
+        clear_attachment: Option<bool>,
+        input: CustomerNeedUpdateInput,
+        id: impl Into<super::ids::CustomerNeedId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::customer_need_update::<T>(
+            self.as_async(),
+            clear_attachment,
+            input,
+            id,
+        ))
+    }
+    /// Deletes a customer need.
+    ///
+    /// Requires the `customers` feature.
+    #[cfg(feature = "customers")]
+    pub fn customer_need_delete(
+        &self,
+        keep_attachment: Option<bool>,
+        id: impl Into<super::ids::CustomerNeedId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        self.block_on(crate::generated::mutations::customer_need_delete(
+            self.as_async(),
+            keep_attachment,
+            id,
+        ))
+    }
+    /// Archives a customer need.
+    ///
+    /// Full type: [`CustomerNeed`](super::types::CustomerNeed)
+    ///
+    /// Requires the `customers` feature.
+    #[cfg(feature = "customers")]
+    pub fn customer_need_archive<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::CustomerNeed>,
+    >(
+        &self,
+        id: impl Into<super::ids::CustomerNeedId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::customer_need_archive::<T>(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// Unarchives a customer need.
+    ///
+    /// Full type: [`CustomerNeed`](super::types::CustomerNeed)
+    ///
+    /// Requires the `customers` feature.
+    #[cfg(feature = "customers")]
+    pub fn customer_need_unarchive<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::CustomerNeed>,
+    >(
+        &self,
+        id: impl Into<super::ids::CustomerNeedId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::customer_need_unarchive::<T>(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// Creates a new custom view.
+    ///
+    /// Full type: [`CustomView`](super::types::CustomView)
+    ///
+    /// Requires the `views` feature.
+    #[cfg(feature = "views")]
+    pub fn custom_view_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::CustomView>,
+    >(
+        &self,
+        input: CustomViewCreateInput,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::custom_view_create::<T>(
+            self.as_async(),
+            input,
+        ))
+    }
+    /// Updates a custom view.
+    ///
+    /// Full type: [`CustomView`](super::types::CustomView)
+    ///
+    /// Requires the `views` feature.
+    #[cfg(feature = "views")]
+    pub fn custom_view_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::CustomView>,
+    >(
+        &self,
+        input: CustomViewUpdateInput,
+        id: impl Into<super::ids::CustomViewId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::custom_view_update::<T>(
+            self.as_async(),
+            input,
+            id,
+        ))
+    }
+    /// Deletes a custom view.
+    ///
+    /// Requires the `views` feature.
+    #[cfg(feature = "views")]
+    pub fn custom_view_delete(
+        &self,
+        id: impl Into<super::ids::CustomViewId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        self.block_on(crate::generated::mutations::custom_view_delete(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// Creates a support contact message from an authenticated user. The message is saved and forwarded to Intercom for support handling.
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub fn contact_create(
+        &self,
+        input: ContactCreateInput,
+    ) -> Result<super::types::ContactPayload, LinearError> {
+        self.block_on(crate::generated::mutations::contact_create(
+            self.as_async(),
+            input,
+        ))
+    }
+    /// `INTERNAL` Submits a sales pricing inquiry from the website. Small companies (1-19 employees) are routed to Intercom, while larger companies are routed to HubSpot.
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub fn contact_sales_create(
+        &self,
+        input: ContactSalesCreateInput,
+    ) -> Result<super::types::ContactPayload, LinearError> {
+        self.block_on(crate::generated::mutations::contact_sales_create(
+            self.as_async(),
+            input,
+        ))
+    }
+    /// Creates a new comment.
+    ///
+    /// Full type: [`Comment`](super::types::Comment)
+    pub fn comment_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Comment>,
+    >(
+        &self,
+        input: CommentCreateInput,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::comment_create::<T>(
+            self.as_async(),
+            input,
+        ))
+    }
+    /// Updates a comment.
+    ///
+    /// Full type: [`Comment`](super::types::Comment)
+    pub fn comment_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Comment>,
+    >(
+        &self,
+        skip_edited_at: Option<bool>,
+        input: CommentUpdateInput,
+        id: impl Into<super::ids::CommentId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::comment_update::<T>(
+            self.as_async(),
+            skip_edited_at,
+            input,
+            id,
+        ))
+    }
+    /// Deletes a comment.
+    pub fn comment_delete(
+        &self,
+        id: impl Into<super::ids::CommentId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        self.block_on(crate::generated::mutations::comment_delete(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// Resolves a comment thread. Marks the root comment as resolved by the current user.
+    ///
+    /// Full type: [`Comment`](super::types::Comment)
+    pub fn comment_resolve<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Comment>,
+    >(
+        &self,
+        resolving_comment_id: Option<String>,
+        id: impl Into<super::ids::CommentId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::comment_resolve::<T>(
+            self.as_async(),
+            resolving_comment_id,
+            id,
+        ))
+    }
+    /// Unresolves a previously resolved comment thread. Clears the resolved state on the root comment.
+    ///
+    /// Full type: [`Comment`](super::types::Comment)
+    pub fn comment_unresolve<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Comment>,
+    >(
+        &self,
+        id: impl Into<super::ids::CommentId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::comment_unresolve::<T>(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// Finds or creates a new user account by email and sends an email with token.
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub fn email_user_account_auth_challenge(
+        &self,
+        input: EmailUserAccountAuthChallengeInput,
+    ) -> Result<super::types::EmailUserAccountAuthChallengeResponse, LinearError> {
+        self.block_on(
+            crate::generated::mutations::email_user_account_auth_challenge(self.as_async(), input),
+        )
+    }
+    /// Authenticates a user account via email and authentication token.
+    ///
+    /// Full type: [`AuthUser`](super::types::AuthUser)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub fn email_token_user_account_auth<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::AuthUser>,
+    >(
+        &self,
+        input: TokenUserAccountAuthInput,
+    ) -> Result<Vec<T>, LinearError> {
+        self.block_on(
+            crate::generated::mutations::email_token_user_account_auth::<T>(self.as_async(), input),
+        )
+    }
+    /// Authenticates a user account via email and authentication token for SAML.
+    ///
+    /// Full type: [`AuthUser`](super::types::AuthUser)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub fn saml_token_user_account_auth<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::AuthUser>,
+    >(
+        &self,
+        input: TokenUserAccountAuthInput,
+    ) -> Result<Vec<T>, LinearError> {
+        self.block_on(
+            crate::generated::mutations::saml_token_user_account_auth::<T>(self.as_async(), input),
+        )
+    }
+    /// Authenticate user account through Google OAuth. This is the 2nd step of OAuth flow.
+    ///
+    /// Full type: [`AuthUser`](super::types::AuthUser)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub fn google_user_account_auth<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::AuthUser>,
+    >(
+        &self,
+        input: GoogleUserAccountAuthInput,
+    ) -> Result<Vec<T>, LinearError> {
+        self.block_on(crate::generated::mutations::google_user_account_auth::<T>(
+            self.as_async(),
+            input,
+        ))
+    }
+    /// `INTERNAL` Starts passkey login process.
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub fn passkey_login_start(
+        &self,
+        auth_id: String,
+    ) -> Result<super::types::PasskeyLoginStartResponse, LinearError> {
+        self.block_on(crate::generated::mutations::passkey_login_start(
+            self.as_async(),
+            auth_id,
+        ))
+    }
+    /// `INTERNAL` Finish passkey login process.
+    ///
+    /// Full type: [`AuthUser`](super::types::AuthUser)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub fn passkey_login_finish<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::AuthUser>,
+    >(
+        &self,
+        response: serde_json::Value,
+        auth_id: String,
+    ) -> Result<Vec<T>, LinearError> {
+        self.block_on(crate::generated::mutations::passkey_login_finish::<T>(
+            self.as_async(),
+            response,
+            auth_id,
+        ))
+    }
+    /// Creates a new workspace from onboarding.
+    ///
+    /// Full type: [`AuthOrganization`](super::types::AuthOrganization)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub fn create_organization_from_onboarding<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::AuthOrganization>,
+    >(
+        &self,
+        session_id: Option<String>,
+        survey: Option<OnboardingCustomerSurvey>,
+        input: CreateOrganizationInput,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::create_organization_from_onboarding::<T>(
+                self.as_async(),
+                session_id,
+                survey,
+                input,
+            ),
+        )
+    }
+    /// Join a workspace from onboarding.
+    ///
+    /// Full type: [`AuthOrganization`](super::types::AuthOrganization)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub fn join_organization_from_onboarding<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::AuthOrganization>,
+    >(
+        &self,
+        input: JoinOrganizationInput,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::join_organization_from_onboarding::<T>(
+                self.as_async(),
+                input,
+            ),
+        )
+    }
+    /// Leave a workspace.
+    ///
+    /// Full type: [`AuthOrganization`](super::types::AuthOrganization)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub fn leave_organization<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::AuthOrganization>,
+    >(
+        &self,
+        organization_id: String,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::leave_organization::<T>(
+            self.as_async(),
+            organization_id,
+        ))
+    }
+    /// Logout the client.
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub fn logout(
+        &self,
+        reason: Option<String>,
+    ) -> Result<super::types::LogoutResponse, LinearError> {
+        self.block_on(crate::generated::mutations::logout(self.as_async(), reason))
+    }
+    /// Logout an individual session with its ID.
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub fn logout_session(
+        &self,
+        session_id: String,
+    ) -> Result<super::types::LogoutResponse, LinearError> {
+        self.block_on(crate::generated::mutations::logout_session(
+            self.as_async(),
+            session_id,
+        ))
+    }
+    /// Logout all of user's sessions including the active one.
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub fn logout_all_sessions(
+        &self,
+        reason: Option<String>,
+    ) -> Result<super::types::LogoutResponse, LinearError> {
+        self.block_on(crate::generated::mutations::logout_all_sessions(
+            self.as_async(),
+            reason,
+        ))
+    }
+    /// Logout all of user's sessions excluding the current one.
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub fn logout_other_sessions(
+        &self,
+        reason: Option<String>,
+    ) -> Result<super::types::LogoutResponse, LinearError> {
+        self.block_on(crate::generated::mutations::logout_other_sessions(
+            self.as_async(),
+            reason,
+        ))
+    }
+    /// Creates a new attachment, or updates existing if the same `url` and `issueId` is used. To create an integration-aware attachment, use the integration-specific mutations such as `attachmentLinkZendesk`, `attachmentLinkSlack`, or `attachmentLinkURL` instead.
+    ///
+    /// Full type: [`Attachment`](super::types::Attachment)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub fn attachment_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Attachment>,
+    >(
+        &self,
+        input: AttachmentCreateInput,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::attachment_create::<T>(
+            self.as_async(),
+            input,
+        ))
+    }
+    /// Updates an existing issue attachment.
+    ///
+    /// Full type: [`Attachment`](super::types::Attachment)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub fn attachment_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Attachment>,
+    >(
+        &self,
+        input: AttachmentUpdateInput,
+        id: impl Into<super::ids::AttachmentId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::attachment_update::<T>(
+            self.as_async(),
+            input,
+            id,
+        ))
+    }
+    /// Link any URL to an issue. If the workspace has a matching integration configured and the URL is recognized (e.g., Zendesk, GitHub, Slack), a rich attachment will be created that enables features like automated status updates. Otherwise, a basic attachment is created.
+    ///
+    /// Full type: [`Attachment`](super::types::Attachment)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub fn attachment_link_url<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Attachment>,
+    >(
+        &self,
+        create_as_user: Option<String>,
+        display_icon_url: Option<String>,
+        title: Option<String>,
+        url: String,
+        issue_id: String,
+        id: Option<String>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::attachment_link_url::<T>(
+            self.as_async(),
+            create_as_user,
+            display_icon_url,
+            title,
+            url,
+            issue_id,
+            id,
+        ))
+    }
+    /// Link an existing GitLab MR to an issue. This creates a rich attachment using the workspace's GitLab integration, enabling features like automated status syncing.
+    ///
+    /// Full type: [`Attachment`](super::types::Attachment)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub fn attachment_link_git_lab_mr<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Attachment>,
+    >(
+        &self,
+        create_as_user: Option<String>,
+        display_icon_url: Option<String>,
+        title: Option<String>,
+        issue_id: String,
+        id: Option<String>,
+        url: String,
+        project_path_with_namespace: String,
+        number: f64,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::attachment_link_git_lab_mr::<T>(
+                self.as_async(),
+                create_as_user,
+                display_icon_url,
+                title,
+                issue_id,
+                id,
+                url,
+                project_path_with_namespace,
+                number,
+            ),
+        )
+    }
+    /// Link a GitHub issue to a Linear issue. This creates a rich attachment using the workspace's GitHub integration, enabling features like automated status syncing.
+    ///
+    /// Full type: [`Attachment`](super::types::Attachment)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub fn attachment_link_git_hub_issue<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Attachment>,
+    >(
+        &self,
+        create_as_user: Option<String>,
+        display_icon_url: Option<String>,
+        title: Option<String>,
+        issue_id: String,
+        id: Option<String>,
+        url: String,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::attachment_link_git_hub_issue::<T>(
+                self.as_async(),
+                create_as_user,
+                display_icon_url,
+                title,
+                issue_id,
+                id,
+                url,
+            ),
+        )
+    }
+    /// Link a GitHub pull request to an issue. This creates a rich attachment using the workspace's GitHub integration, enabling features like automated status syncing.
+    ///
+    /// Full type: [`Attachment`](super::types::Attachment)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub fn attachment_link_git_hub_pr<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Attachment>,
+    >(
+        &self,
+        create_as_user: Option<String>,
+        display_icon_url: Option<String>,
+        title: Option<String>,
+        issue_id: String,
+        id: Option<String>,
+        url: String,
+        link_kind: Option<GitLinkKind>,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::attachment_link_git_hub_pr::<T>(
+                self.as_async(),
+                create_as_user,
+                display_icon_url,
+                title,
+                issue_id,
+                id,
+                url,
+                link_kind,
+            ),
+        )
+    }
+    /// Link an existing Zendesk ticket to an issue. This creates a rich attachment using the workspace's Zendesk integration, enabling features like automated ticket reopening when the Linear issue is completed.
+    ///
+    /// Full type: [`Attachment`](super::types::Attachment)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub fn attachment_link_zendesk<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Attachment>,
+    >(
+        &self,
+        create_as_user: Option<String>,
+        display_icon_url: Option<String>,
+        title: Option<String>,
+        ticket_id: String,
+        issue_id: String,
+        id: Option<String>,
+        url: Option<String>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::attachment_link_zendesk::<T>(
+            self.as_async(),
+            create_as_user,
+            display_icon_url,
+            title,
+            ticket_id,
+            issue_id,
+            id,
+            url,
+        ))
+    }
+    /// Link an existing Discord message to an issue. This creates a rich attachment using the workspace's Discord integration.
+    ///
+    /// Full type: [`Attachment`](super::types::Attachment)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub fn attachment_link_discord<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Attachment>,
+    >(
+        &self,
+        create_as_user: Option<String>,
+        display_icon_url: Option<String>,
+        title: Option<String>,
+        issue_id: String,
+        id: Option<String>,
+        channel_id: String,
+        message_id: String,
+        url: String,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::attachment_link_discord::<T>(
+            self.as_async(),
+            create_as_user,
+            display_icon_url,
+            title,
+            issue_id,
+            id,
+            channel_id,
+            message_id,
+            url,
+        ))
+    }
+    /// Begin syncing the thread for an existing Slack message attachment with a comment thread on its issue.
+    ///
+    /// Full type: [`Attachment`](super::types::Attachment)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub fn attachment_sync_to_slack<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Attachment>,
+    >(
+        &self,
+        id: impl Into<super::ids::AttachmentId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::attachment_sync_to_slack::<T>(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// Link an existing Slack message to an issue. This creates a rich attachment using the workspace's Slack integration.
+    ///
+    /// Full type: [`Attachment`](super::types::Attachment)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub fn attachment_link_slack<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Attachment>,
+    >(
+        &self,
+        create_as_user: Option<String>,
+        display_icon_url: Option<String>,
+        title: Option<String>,
+        issue_id: String,
+        url: String,
+        id: Option<String>,
+        sync_to_comment_thread: Option<bool>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::attachment_link_slack::<T>(
+            self.as_async(),
+            create_as_user,
+            display_icon_url,
+            title,
+            issue_id,
+            url,
+            id,
+            sync_to_comment_thread,
+        ))
+    }
+    /// Link an existing Front conversation to an issue. This creates a rich attachment using the workspace's Front integration, enabling features like automated conversation updates.
+    ///
+    /// Full type: [`Attachment`](super::types::Attachment)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub fn attachment_link_front<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Attachment>,
+    >(
+        &self,
+        create_as_user: Option<String>,
+        display_icon_url: Option<String>,
+        title: Option<String>,
+        conversation_id: String,
+        issue_id: String,
+        id: Option<String>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::attachment_link_front::<T>(
+            self.as_async(),
+            create_as_user,
+            display_icon_url,
+            title,
+            conversation_id,
+            issue_id,
+            id,
+        ))
+    }
+    /// Link an existing Intercom conversation to an issue. This creates a rich attachment using the workspace's Intercom integration, enabling features like automated conversation updates.
+    ///
+    /// Full type: [`Attachment`](super::types::Attachment)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub fn attachment_link_intercom<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Attachment>,
+    >(
+        &self,
+        create_as_user: Option<String>,
+        display_icon_url: Option<String>,
+        title: Option<String>,
+        conversation_id: String,
+        part_id: Option<String>,
+        id: Option<String>,
+        issue_id: String,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::attachment_link_intercom::<T>(
+            self.as_async(),
+            create_as_user,
+            display_icon_url,
+            title,
+            conversation_id,
+            part_id,
+            id,
+            issue_id,
+        ))
+    }
+    /// Link an existing Jira issue to an issue. This creates a rich attachment using the workspace's Jira integration, enabling features like automated status syncing.
+    ///
+    /// Full type: [`Attachment`](super::types::Attachment)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub fn attachment_link_jira_issue<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Attachment>,
+    >(
+        &self,
+        create_as_user: Option<String>,
+        display_icon_url: Option<String>,
+        title: Option<String>,
+        issue_id: String,
+        jira_issue_id: String,
+        id: Option<String>,
+        url: Option<String>,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::attachment_link_jira_issue::<T>(
+                self.as_async(),
+                create_as_user,
+                display_icon_url,
+                title,
+                issue_id,
+                jira_issue_id,
+                id,
+                url,
+            ),
+        )
+    }
+    /// Link an existing Salesforce case to an issue. This creates a rich attachment using the workspace's Salesforce integration.
+    ///
+    /// Full type: [`Attachment`](super::types::Attachment)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub fn attachment_link_salesforce<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Attachment>,
+    >(
+        &self,
+        create_as_user: Option<String>,
+        display_icon_url: Option<String>,
+        title: Option<String>,
+        issue_id: String,
+        id: Option<String>,
+        url: String,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::attachment_link_salesforce::<T>(
+                self.as_async(),
+                create_as_user,
+                display_icon_url,
+                title,
+                issue_id,
+                id,
+                url,
+            ),
+        )
+    }
+    /// Deletes an issue attachment.
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub fn attachment_delete(
+        &self,
+        id: impl Into<super::ids::AttachmentId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        self.block_on(crate::generated::mutations::attachment_delete(
+            self.as_async(),
+            id,
+        ))
+    }
+    /// Creates a new agent session on a root comment.
+    ///
+    /// Full type: [`AgentSession`](super::types::AgentSession)
+    ///
+    /// Requires the `agents` feature.
+    #[cfg(feature = "agents")]
+    pub fn agent_session_create_on_comment<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::AgentSession>,
+    >(
+        &self,
+        input: AgentSessionCreateOnComment,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::agent_session_create_on_comment::<T>(
+                self.as_async(),
+                input,
+            ),
+        )
+    }
+    /// Creates a new agent session on an issue.
+    ///
+    /// Full type: [`AgentSession`](super::types::AgentSession)
+    ///
+    /// Requires the `agents` feature.
+    #[cfg(feature = "agents")]
+    pub fn agent_session_create_on_issue<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::AgentSession>,
+    >(
+        &self,
+        input: AgentSessionCreateOnIssue,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::agent_session_create_on_issue::<T>(self.as_async(), input),
+        )
+    }
+    /// `Internal` Creates a new agent session on behalf of the current user
+    ///
+    /// Full type: [`AgentSession`](super::types::AgentSession)
+    ///
+    /// Requires the `agents` feature.
+    #[cfg(feature = "agents")]
+    pub fn agent_session_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::AgentSession>,
+    >(
+        &self,
+        pull_request_id: Option<String>,
+        input: AgentSessionCreateInput,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::agent_session_create::<T>(
+            self.as_async(),
+            pull_request_id,
+            input,
+        ))
+    }
+    /// Updates the externalUrl of an agent session, which is an agent-hosted page associated with this session.
+    ///
+    /// Full type: [`AgentSession`](super::types::AgentSession)
+    ///
+    /// Requires the `agents` feature.
+    #[cfg(feature = "agents")]
+    pub fn agent_session_update_external_url<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::AgentSession>,
+    >(
+        &self,
+        input: AgentSessionUpdateExternalUrlInput,
+        id: impl Into<super::ids::AgentSessionId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::agent_session_update_external_url::<T>(
+                self.as_async(),
+                input,
+                id,
+            ),
+        )
+    }
+    /// Updates an agent session.
+    ///
+    /// Full type: [`AgentSession`](super::types::AgentSession)
+    ///
+    /// Requires the `agents` feature.
+    #[cfg(feature = "agents")]
+    pub fn agent_session_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::AgentSession>,
+    >(
+        &self,
+        input: AgentSessionUpdateInput,
+        id: impl Into<super::ids::AgentSessionId>,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::agent_session_update::<T>(
+            self.as_async(),
+            input,
+            id,
+        ))
+    }
+    /// Creates an agent activity.
+    ///
+    /// Full type: [`AgentActivity`](super::types::AgentActivity)
+    ///
+    /// Requires the `agents` feature.
+    #[cfg(feature = "agents")]
+    pub fn agent_activity_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::AgentActivity>,
+    >(
+        &self,
+        input: AgentActivityCreateInput,
+    ) -> Result<T, LinearError> {
+        self.block_on(crate::generated::mutations::agent_activity_create::<T>(
+            self.as_async(),
+            input,
+        ))
+    }
+    /// `Internal` Creates a prompt agent activity from Linear user input.
+    ///
+    /// Full type: [`AgentActivity`](super::types::AgentActivity)
+    ///
+    /// Requires the `agents` feature.
+    #[cfg(feature = "agents")]
+    pub fn agent_activity_create_prompt<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::AgentActivity>,
+    >(
+        &self,
+        input: AgentActivityCreatePromptInput,
+    ) -> Result<T, LinearError> {
+        self.block_on(
+            crate::generated::mutations::agent_activity_create_prompt::<T>(self.as_async(), input),
+        )
+    }
+}
@@ -1,11 +1,13 @@
 //! Generated `impl Client` delegation methods.
 //!
 //! This module provides thin wrapper methods on [`Client`] that
-//! delegate to the standalone functions in [`queries`](super::queries) and
-//! [`mutations`](super::mutations).
+//! delegate to the standalone functions in [`queries`](super::queries),
+//! [`mutations`](super::mutations) and [`subscriptions`](super::subscriptions).
 //!
 //! Generated by lineark-codegen — do not edit.
 #![allow(clippy::too_many_arguments)]
+#[allow(unused_imports)]
+use super::enums::*;
 use super::inputs::*;
 use super::queries::*;
 use crate::client::Client;
@@ -19,12 +21,86 @@ impl Client {
     pub fn workflow_states<T>(&self) -> WorkflowStatesQueryBuilder<'_, T> {
         crate::generated::queries::workflow_states(self)
     }
+    /// One specific workflow state (issue status), looked up by its unique identifier.
+    ///
+    /// Full type: [`WorkflowState`](super::types::WorkflowState)
+    ///
+    /// Requires the `teams` feature.
+    #[cfg(feature = "teams")]
+    pub async fn workflow_state<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::WorkflowState>,
+    >(
+        &self,
+        id: impl Into<super::ids::WorkflowStateId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::queries::workflow_state::<T>(self, id).await
+    }
+    /// All webhooks for the current workspace.
+    ///
+    /// Full type: [`Webhook`](super::types::Webhook)
+    ///
+    /// Requires the `webhooks` feature.
+    #[cfg(feature = "webhooks")]
+    pub fn webhooks<T>(&self) -> WebhooksQueryBuilder<'_, T> {
+        crate::generated::queries::webhooks(self)
+    }
+    /// Retrieves a single webhook by its identifier.
+    ///
+    /// Full type: [`Webhook`](super::types::Webhook)
+    ///
+    /// Requires the `webhooks` feature.
+    #[cfg(feature = "webhooks")]
+    pub async fn webhook<T: DeserializeOwned + GraphQLFields<FullType = super::types::Webhook>>(
+        &self,
+        id: impl Into<super::ids::WebhookId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::queries::webhook::<T>(self, id).await
+    }
+    /// `INTERNAL` Webhook failure events for webhooks that belong to an OAuth application. (last 50)
+    ///
+    /// Full type: [`WebhookFailureEvent`](super::types::WebhookFailureEvent)
+    ///
+    /// Requires the `webhooks` feature.
+    #[cfg(feature = "webhooks")]
+    pub async fn failures_for_oauth_webhooks<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::WebhookFailureEvent>,
+    >(
+        &self,
+        oauth_client_id: String,
+    ) -> Result<Vec<T>, LinearError> {
+        crate::generated::queries::failures_for_oauth_webhooks::<T>(self, oauth_client_id).await
+    }
+    /// The authenticated user's notification and UI settings.
+    ///
+    /// Full type: [`UserSettings`](super::types::UserSettings)
+    ///
+    /// Requires the `users` feature.
+    #[cfg(feature = "users")]
+    pub async fn user_settings<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::UserSettings>,
+    >(
+        &self,
+    ) -> Result<T, LinearError> {
+        crate::generated::queries::user_settings::<T>(self).await
+    }
     /// All users in the workspace. Supports filtering, sorting, and pagination.
     ///
     /// Full type: [`User`](super::types::User)
     pub fn users<T>(&self) -> UsersQueryBuilder<'_, T> {
         crate::generated::queries::users(self)
     }
+    /// Fetches a specific user by their ID.
+    ///
+    /// Full type: [`User`](super::types::User)
+    ///
+    /// Requires the `users` feature.
+    #[cfg(feature = "users")]
+    pub async fn user<T: DeserializeOwned + GraphQLFields<FullType = super::types::User>>(
+        &self,
+        id: impl Into<super::ids::UserId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::queries::user::<T>(self, id).await
+    }
     /// The currently authenticated user making the API request.
     ///
     /// Full type: [`User`](super::types::User)
@@ -33,6 +109,107 @@ impl Client {
     ) -> Result<T, LinearError> {
         crate::generated::queries::whoami::<T>(self).await
     }
+    /// Lists all active authentication sessions for a user. Can only be called by a workspace admin or owner.
+    ///
+    /// Full type: [`AuthenticationSessionResponse`](super::types::AuthenticationSessionResponse)
+    ///
+    /// Requires the `users` feature.
+    #[cfg(feature = "users")]
+    pub async fn user_sessions<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::AuthenticationSessionResponse>,
+    >(
+        &self,
+        id: impl Into<super::ids::AuthenticationSessionResponseId>,
+    ) -> Result<Vec<T>, LinearError> {
+        crate::generated::queries::user_sessions::<T>(self, id).await
+    }
+    /// All triage responsibilities.
+    ///
+    /// Full type: [`TriageResponsibility`](super::types::TriageResponsibility)
+    ///
+    /// Requires the `teams` feature.
+    #[cfg(feature = "teams")]
+    pub fn triage_responsibilities<T>(&self) -> TriageResponsibilitiesQueryBuilder<'_, T> {
+        crate::generated::queries::triage_responsibilities(self)
+    }
+    /// A specific triage responsibility.
+    ///
+    /// Full type: [`TriageResponsibility`](super::types::TriageResponsibility)
+    ///
+    /// Requires the `teams` feature.
+    #[cfg(feature = "teams")]
+    pub async fn triage_responsibility<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::TriageResponsibility>,
+    >(
+        &self,
+        id: impl Into<super::ids::TriageResponsibilityId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::queries::triage_responsibility::<T>(self, id).await
+    }
+    /// All time schedules.
+    ///
+    /// Full type: [`TimeSchedule`](super::types::TimeSchedule)
+    ///
+    /// Requires the `teams` feature.
+    #[cfg(feature = "teams")]
+    pub fn time_schedules<T>(&self) -> TimeSchedulesQueryBuilder<'_, T> {
+        crate::generated::queries::time_schedules(self)
+    }
+    /// A specific time schedule.
+    ///
+    /// Full type: [`TimeSchedule`](super::types::TimeSchedule)
+    ///
+    /// Requires the `teams` feature.
+    #[cfg(feature = "teams")]
+    pub async fn time_schedule<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::TimeSchedule>,
+    >(
+        &self,
+        id: impl Into<super::ids::TimeScheduleId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::queries::time_schedule::<T>(self, id).await
+    }
+    /// All templates in the workspace, including both team-scoped and workspace-level templates.
+    ///
+    /// Full type: [`Template`](super::types::Template)
+    ///
+    /// Requires the `teams` feature.
+    #[cfg(feature = "teams")]
+    pub async fn templates<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::Template>,
+    >(
+        &self,
+    ) -> Result<Vec<T>, LinearError> {
+        crate::generated::queries::templates::<T>(self).await
+    }
+    /// A specific template.
+    ///
+    /// Full type: [`Template`](super::types::Template)
+    ///
+    /// Requires the `teams` feature.
+    #[cfg(feature = "teams")]
+    pub async fn template<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::Template>,
+    >(
+        &self,
+        id: impl Into<super::ids::TemplateId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::queries::template::<T>(self, id).await
+    }
+    /// Returns all templates that are associated with the integration type.
+    ///
+    /// Full type: [`Template`](super::types::Template)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub async fn templates_for_integration<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::Template>,
+    >(
+        &self,
+        integration_type: String,
+    ) -> Result<Vec<T>, LinearError> {
+        crate::generated::queries::templates_for_integration::<T>(self, integration_type).await
+    }
     /// Returns all projects in the workspace, with optional filtering and sorting.
     ///
     /// Full type: [`Project`](super::types::Project)
@@ -44,507 +221,7622 @@ impl Client {
     /// Full type: [`Project`](super::types::Project)
     pub async fn project<T: DeserializeOwned + GraphQLFields<FullType = super::types::Project>>(
         &self,
-        id: String,
+        id: impl Into<super::ids::ProjectId>,
     ) -> Result<T, LinearError> {
         crate::generated::queries::project::<T>(self, id).await
     }
+    /// Suggests filters for a project view based on a text prompt.
+    ///
+    /// Full type: [`ProjectFilterSuggestionPayload`](super::types::ProjectFilterSuggestionPayload)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub fn project_filter_suggestion<T>(
+        &self,
+        prompt: impl Into<String>,
+    ) -> ProjectFilterSuggestionQueryBuilder<'_, T> {
+        crate::generated::queries::project_filter_suggestion(self, prompt)
+    }
     /// All teams whose issues the user can access. This includes public teams and private teams the user is a member of. This may differ from `administrableTeams`, which returns teams whose settings the user can change but whose issues they don't necessarily have access to.
     ///
     /// Full type: [`Team`](super::types::Team)
     pub fn teams<T>(&self) -> TeamsQueryBuilder<'_, T> {
         crate::generated::queries::teams(self)
     }
+    /// All teams whose settings the user can administer. This includes teams the user can change settings for, but whose issues they may not have direct access to. This differs from the `teams` query which returns teams based on issue access.
+    ///
+    /// Full type: [`Team`](super::types::Team)
+    ///
+    /// Requires the `teams` feature.
+    #[cfg(feature = "teams")]
+    pub fn administrable_teams<T>(&self) -> AdministrableTeamsQueryBuilder<'_, T> {
+        crate::generated::queries::administrable_teams(self)
+    }
     /// Fetches a specific team by its ID.
     ///
     /// Full type: [`Team`](super::types::Team)
     pub async fn team<T: DeserializeOwned + GraphQLFields<FullType = super::types::Team>>(
         &self,
-        id: String,
+        id: impl Into<super::ids::TeamId>,
     ) -> Result<T, LinearError> {
         crate::generated::queries::team::<T>(self, id).await
     }
+    /// All team memberships in the workspace.
+    ///
+    /// Full type: [`TeamMembership`](super::types::TeamMembership)
+    ///
+    /// Requires the `teams` feature.
+    #[cfg(feature = "teams")]
+    pub fn team_memberships<T>(&self) -> TeamMembershipsQueryBuilder<'_, T> {
+        crate::generated::queries::team_memberships(self)
+    }
+    /// Fetches a specific team membership by its ID.
+    ///
+    /// Full type: [`TeamMembership`](super::types::TeamMembership)
+    ///
+    /// Requires the `teams` feature.
+    #[cfg(feature = "teams")]
+    pub async fn team_membership<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::TeamMembership>,
+    >(
+        &self,
+        id: impl Into<super::ids::TeamMembershipId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::queries::team_membership::<T>(self, id).await
+    }
+    /// Search for issues, projects, initiatives, and documents using natural language. Uses vector-based semantic search with optional full-text search and reranking. Results can be filtered by type and by entity-specific filters. Rate-limited to 30 requests per minute.
+    ///
+    /// Full type: [`SemanticSearchPayload`](super::types::SemanticSearchPayload)
+    ///
+    /// Requires the `agents` feature.
+    #[cfg(feature = "agents")]
+    pub fn semantic_search<T>(
+        &self,
+        query: impl Into<String>,
+    ) -> SemanticSearchQueryBuilder<'_, T> {
+        crate::generated::queries::semantic_search(self, query)
+    }
+    /// Search documents by text query using full-text and vector search. Results are ranked by relevance unless an orderBy parameter is specified. Rate-limited to 30 requests per minute.
+    ///
+    /// Full type: [`DocumentSearchResult`](super::types::DocumentSearchResult)
+    ///
+    /// Requires the `documents` feature.
+    #[cfg(feature = "documents")]
+    pub fn search_documents<T>(
+        &self,
+        term: impl Into<String>,
+    ) -> SearchDocumentsQueryBuilder<'_, T> {
+        crate::generated::queries::search_documents(self, term)
+    }
+    /// Search projects by text query using full-text and vector search. Results are ranked by relevance unless an orderBy parameter is specified. Rate-limited to 30 requests per minute.
+    ///
+    /// Full type: [`ProjectSearchResult`](super::types::ProjectSearchResult)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub fn search_projects<T>(&self, term: impl Into<String>) -> SearchProjectsQueryBuilder<'_, T> {
+        crate::generated::queries::search_projects(self, term)
+    }
     /// Search issues by text query using full-text and vector search. Results are ranked by relevance unless an orderBy parameter is specified. Supports optional issue filters and comment inclusion. Rate-limited to 30 requests per minute.
     ///
     /// Full type: [`IssueSearchResult`](super::types::IssueSearchResult)
     pub fn search_issues<T>(&self, term: impl Into<String>) -> SearchIssuesQueryBuilder<'_, T> {
         crate::generated::queries::search_issues(self, term)
     }
-    /// Returns all project statuses in the workspace.
+    /// `Deprecated` Returns all roadmap-to-project associations. Use InitiativeToProject instead.
     ///
-    /// Full type: [`ProjectStatus`](super::types::ProjectStatus)
-    pub fn project_statuses<T>(&self) -> ProjectStatusesQueryBuilder<'_, T> {
-        crate::generated::queries::project_statuses(self)
-    }
-    /// Returns all project milestones in the workspace, with optional filtering.
+    /// Full type: [`RoadmapToProject`](super::types::RoadmapToProject)
+    #[deprecated(note = "RoadmapToProject is deprecated, use InitiativeToProject instead.")]
     ///
-    /// Full type: [`ProjectMilestone`](super::types::ProjectMilestone)
-    pub fn project_milestones<T>(&self) -> ProjectMilestonesQueryBuilder<'_, T> {
-        crate::generated::queries::project_milestones(self)
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub fn roadmap_to_projects<T>(&self) -> RoadmapToProjectsQueryBuilder<'_, T> {
+        crate::generated::queries::roadmap_to_projects(self)
     }
-    /// Returns a single project milestone by its identifier.
+    /// `Deprecated` Returns a single roadmap-to-project association. Use InitiativeToProject instead.
     ///
-    /// Full type: [`ProjectMilestone`](super::types::ProjectMilestone)
-    pub async fn project_milestone<
-        T: DeserializeOwned + GraphQLFields<FullType = super::types::ProjectMilestone>,
+    /// Full type: [`RoadmapToProject`](super::types::RoadmapToProject)
+    #[deprecated(note = "RoadmapToProject is deprecated, use InitiativeToProject instead.")]
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub async fn roadmap_to_project<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::RoadmapToProject>,
     >(
         &self,
-        id: String,
+        id: impl Into<super::ids::RoadmapToProjectId>,
     ) -> Result<T, LinearError> {
-        crate::generated::queries::project_milestone::<T>(self, id).await
+        crate::generated::queries::roadmap_to_project::<T>(self, id).await
     }
-    /// Returns all project labels in the workspace, with optional filtering.
+    /// `Deprecated` Returns all roadmaps in the workspace. Use initiatives instead.
     ///
-    /// Full type: [`ProjectLabel`](super::types::ProjectLabel)
-    pub fn project_labels<T>(&self) -> ProjectLabelsQueryBuilder<'_, T> {
-        crate::generated::queries::project_labels(self)
-    }
-    /// All issues. Returns a paginated list of issues visible to the authenticated user. Can be filtered by various criteria including team, assignee, state, labels, project, and cycle.
+    /// Full type: [`Roadmap`](super::types::Roadmap)
+    #[deprecated(note = "Roadmaps are deprecated, use initiatives instead.")]
     ///
-    /// Full type: [`Issue`](super::types::Issue)
-    pub fn issues<T>(&self) -> IssuesQueryBuilder<'_, T> {
-        crate::generated::queries::issues(self)
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub fn roadmaps<T>(&self) -> RoadmapsQueryBuilder<'_, T> {
+        crate::generated::queries::roadmaps(self)
     }
-    /// One specific issue, looked up by its unique identifier.
+    /// `Deprecated` Returns a single roadmap by its identifier. Use initiatives instead.
     ///
-    /// Full type: [`Issue`](super::types::Issue)
-    pub async fn issue<T: DeserializeOwned + GraphQLFields<FullType = super::types::Issue>>(
+    /// Full type: [`Roadmap`](super::types::Roadmap)
+    #[deprecated(note = "Roadmaps are deprecated, use initiatives instead.")]
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub async fn roadmap<T: DeserializeOwned + GraphQLFields<FullType = super::types::Roadmap>>(
         &self,
-        id: String,
+        id: impl Into<super::ids::RoadmapId>,
     ) -> Result<T, LinearError> {
-        crate::generated::queries::issue::<T>(self, id).await
+        crate::generated::queries::roadmap::<T>(self, id).await
     }
-    /// Find issue based on the VCS branch name.
+    /// `ALPHA` All release stages in the workspace, with optional filtering.
     ///
-    /// Full type: [`Issue`](super::types::Issue)
-    pub async fn issue_vcs_branch_search<
-        T: DeserializeOwned + GraphQLFields<FullType = super::types::Issue>,
+    /// Full type: [`ReleaseStage`](super::types::ReleaseStage)
+    ///
+    /// Requires the `releases` feature.
+    #[cfg(feature = "releases")]
+    pub fn release_stages<T>(&self) -> ReleaseStagesQueryBuilder<'_, T> {
+        crate::generated::queries::release_stages(self)
+    }
+    /// `ALPHA` Fetch a single release stage by its UUID.
+    ///
+    /// Full type: [`ReleaseStage`](super::types::ReleaseStage)
+    ///
+    /// Requires the `releases` feature.
+    #[cfg(feature = "releases")]
+    pub async fn release_stage<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::ReleaseStage>,
     >(
         &self,
-        branch_name: String,
-    ) -> Result<Option<T>, LinearError> {
-        crate::generated::queries::issue_vcs_branch_search::<T>(self, branch_name).await
+        id: impl Into<super::ids::ReleaseStageId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::queries::release_stage::<T>(self, id).await
     }
-    /// All issue relations. Returns a paginated list of all issue relations (blocks, blocked by, relates to, duplicates) visible to the authenticated user.
+    /// `ALPHA` All release pipelines in the workspace, with optional filtering and sorting.
     ///
-    /// Full type: [`IssueRelation`](super::types::IssueRelation)
-    pub fn issue_relations<T>(&self) -> IssueRelationsQueryBuilder<'_, T> {
-        crate::generated::queries::issue_relations(self)
+    /// Full type: [`ReleasePipeline`](super::types::ReleasePipeline)
+    ///
+    /// Requires the `releases` feature.
+    #[cfg(feature = "releases")]
+    pub fn release_pipelines<T>(&self) -> ReleasePipelinesQueryBuilder<'_, T> {
+        crate::generated::queries::release_pipelines(self)
     }
-    /// One specific issue relation, looked up by its unique identifier.
+    /// `ALPHA` Fetch a single release pipeline by its UUID or slug identifier.
     ///
-    /// Full type: [`IssueRelation`](super::types::IssueRelation)
-    pub async fn issue_relation<
-        T: DeserializeOwned + GraphQLFields<FullType = super::types::IssueRelation>,
+    /// Full type: [`ReleasePipeline`](super::types::ReleasePipeline)
+    ///
+    /// Requires the `releases` feature.
+    #[cfg(feature = "releases")]
+    pub async fn release_pipeline<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::ReleasePipeline>,
     >(
         &self,
-        id: String,
+        id: impl Into<super::ids::ReleasePipelineId>,
     ) -> Result<T, LinearError> {
-        crate::generated::queries::issue_relation::<T>(self, id).await
+        crate::generated::queries::release_pipeline::<T>(self, id).await
     }
-    /// All issue labels. Returns a paginated list of labels visible to the authenticated user, including both workspace-level and team-scoped labels.
+    /// `ALPHA` Returns a release pipeline by ID. Requires the access key to have access to the pipeline.
     ///
-    /// Full type: [`IssueLabel`](super::types::IssueLabel)
-    pub fn issue_labels<T>(&self) -> IssueLabelsQueryBuilder<'_, T> {
-        crate::generated::queries::issue_labels(self)
-    }
-    /// All documents the user has access to in the workspace.
+    /// Full type: [`ReleasePipeline`](super::types::ReleasePipeline)
     ///
-    /// Full type: [`Document`](super::types::Document)
-    pub fn documents<T>(&self) -> DocumentsQueryBuilder<'_, T> {
-        crate::generated::queries::documents(self)
+    /// Requires the `releases` feature.
+    #[cfg(feature = "releases")]
+    pub async fn release_pipeline_by_access_key<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::ReleasePipeline>,
+    >(
+        &self,
+    ) -> Result<T, LinearError> {
+        crate::generated::queries::release_pipeline_by_access_key::<T>(self).await
     }
-    /// A specific document by ID or slug.
+    /// `ALPHA` Returns the latest release for the pipeline associated with the access key.
     ///
-    /// Full type: [`Document`](super::types::Document)
-    pub async fn document<
-        T: DeserializeOwned + GraphQLFields<FullType = super::types::Document>,
+    /// Full type: [`Release`](super::types::Release)
+    ///
+    /// Requires the `releases` feature.
+    #[cfg(feature = "releases")]
+    pub async fn latest_release_by_access_key<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::Release>,
     >(
         &self,
-        id: String,
-    ) -> Result<T, LinearError> {
-        crate::generated::queries::document::<T>(self, id).await
+    ) -> Result<Option<T>, LinearError> {
+        crate::generated::queries::latest_release_by_access_key::<T>(self).await
     }
-    /// All cycles accessible to the user.
+    /// `ALPHA` All releases in the workspace, with optional filtering and sorting.
     ///
-    /// Full type: [`Cycle`](super::types::Cycle)
-    pub fn cycles<T>(&self) -> CyclesQueryBuilder<'_, T> {
-        crate::generated::queries::cycles(self)
+    /// Full type: [`Release`](super::types::Release)
+    ///
+    /// Requires the `releases` feature.
+    #[cfg(feature = "releases")]
+    pub fn releases<T>(&self) -> ReleasesQueryBuilder<'_, T> {
+        crate::generated::queries::releases(self)
     }
-    /// One specific cycle, looked up by ID or slug.
+    /// `ALPHA` Fetch a single release by its UUID or slug identifier.
     ///
-    /// Full type: [`Cycle`](super::types::Cycle)
-    pub async fn cycle<T: DeserializeOwned + GraphQLFields<FullType = super::types::Cycle>>(
+    /// Full type: [`Release`](super::types::Release)
+    ///
+    /// Requires the `releases` feature.
+    #[cfg(feature = "releases")]
+    pub async fn release<T: DeserializeOwned + GraphQLFields<FullType = super::types::Release>>(
         &self,
-        id: String,
+        id: impl Into<super::ids::ReleaseId>,
     ) -> Result<T, LinearError> {
-        crate::generated::queries::cycle::<T>(self, id).await
+        crate::generated::queries::release::<T>(self, id).await
     }
-    /// XHR request payload to upload an images, video and other attachments directly to Linear's cloud storage.
-    pub async fn file_upload(
-        &self,
-        meta_data: Option<serde_json::Value>,
-        make_public: Option<bool>,
-        size: i64,
-        content_type: String,
-        filename: String,
-    ) -> Result<serde_json::Value, LinearError> {
-        crate::generated::mutations::file_upload(
-            self,
-            meta_data,
-            make_public,
-            size,
-            content_type,
-            filename,
-        )
-        .await
+    /// `ALPHA` Search releases with optional text matching against name, version, and pipeline name. When no search term is provided, returns releases ordered by stage priority (started > planned > completed > canceled).
+    ///
+    /// Full type: [`Release`](super::types::Release)
+    ///
+    /// Requires the `releases` feature.
+    #[cfg(feature = "releases")]
+    pub fn release_search<T>(&self) -> ReleaseSearchQueryBuilder<'_, T> {
+        crate::generated::queries::release_search(self)
     }
-    /// Upload an image from an URL to Linear.
-    pub async fn image_upload_from_url(
-        &self,
-        url: String,
-    ) -> Result<serde_json::Value, LinearError> {
-        crate::generated::mutations::image_upload_from_url(self, url).await
+    /// `ALPHA` Release notes in the workspace.
+    ///
+    /// Full type: [`ReleaseNote`](super::types::ReleaseNote)
+    ///
+    /// Requires the `releases` feature.
+    #[cfg(feature = "releases")]
+    pub fn release_notes<T>(&self) -> ReleaseNotesQueryBuilder<'_, T> {
+        crate::generated::queries::release_notes(self)
     }
-    /// Creates a new project.
+    /// `ALPHA` Fetch a release note by its identifier.
     ///
-    /// Full type: [`Project`](super::types::Project)
-    pub async fn project_create<
-        T: serde::de::DeserializeOwned
-            + crate::field_selection::GraphQLFields<FullType = super::types::Project>,
+    /// Full type: [`ReleaseNote`](super::types::ReleaseNote)
+    ///
+    /// Requires the `releases` feature.
+    #[cfg(feature = "releases")]
+    pub async fn release_note<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::ReleaseNote>,
     >(
         &self,
-        slack_channel_name: Option<String>,
-        input: ProjectCreateInput,
+        id: impl Into<super::ids::ReleaseNoteId>,
     ) -> Result<T, LinearError> {
-        crate::generated::mutations::project_create::<T>(self, slack_channel_name, input).await
+        crate::generated::queries::release_note::<T>(self, id).await
     }
-    /// Updates a project.
+    /// The current rate limit status for the authenticated client, including remaining quota and reset timing for each limit type.
     ///
-    /// Full type: [`Project`](super::types::Project)
-    pub async fn project_update<
-        T: serde::de::DeserializeOwned
-            + crate::field_selection::GraphQLFields<FullType = super::types::Project>,
+    /// Full type: [`RateLimitPayload`](super::types::RateLimitPayload)
+    pub async fn rate_limit_status<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::RateLimitPayload>,
     >(
         &self,
-        input: ProjectUpdateInput,
-        id: String,
     ) -> Result<T, LinearError> {
-        crate::generated::mutations::project_update::<T>(self, input, id).await
+        crate::generated::queries::rate_limit_status::<T>(self).await
     }
-    /// Deletes (trashes) a project. The project can be restored later with projectUnarchive.
+    /// Sends a test push notification to the authenticated user's registered devices. Useful for verifying that push notification delivery is working correctly.
     ///
-    /// Full type: [`Project`](super::types::Project)
-    pub async fn project_delete<
-        T: serde::de::DeserializeOwned
-            + crate::field_selection::GraphQLFields<FullType = super::types::Project>,
+    /// Full type: [`PushSubscriptionTestPayload`](super::types::PushSubscriptionTestPayload)
+    ///
+    /// Requires the `notifications` feature.
+    #[cfg(feature = "notifications")]
+    pub fn push_subscription_test<T>(&self) -> PushSubscriptionTestQueryBuilder<'_, T> {
+        crate::generated::queries::push_subscription_test(self)
+    }
+    /// Returns all project status updates in the workspace, with optional filtering.
+    ///
+    /// Full type: [`ProjectUpdate`](super::types::ProjectUpdate)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub fn project_updates<T>(&self) -> ProjectUpdatesQueryBuilder<'_, T> {
+        crate::generated::queries::project_updates(self)
+    }
+    /// Returns a single project update by its identifier.
+    ///
+    /// Full type: [`ProjectUpdate`](super::types::ProjectUpdate)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub async fn project_update_post<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::ProjectUpdate>,
     >(
         &self,
-        id: String,
+        id: impl Into<super::ids::ProjectUpdateId>,
     ) -> Result<T, LinearError> {
-        crate::generated::mutations::project_delete::<T>(self, id).await
+        crate::generated::queries::project_update_post::<T>(self, id).await
     }
-    /// Creates a new team. The user who creates the team will automatically be added as a member and owner of the newly created team. Default workflow states, labels, and other team resources are created alongside the team.
+    /// Returns all project statuses in the workspace.
     ///
-    /// Full type: [`Team`](super::types::Team)
-    pub async fn team_create<
-        T: serde::de::DeserializeOwned
-            + crate::field_selection::GraphQLFields<FullType = super::types::Team>,
+    /// Full type: [`ProjectStatus`](super::types::ProjectStatus)
+    pub fn project_statuses<T>(&self) -> ProjectStatusesQueryBuilder<'_, T> {
+        crate::generated::queries::project_statuses(self)
+    }
+    /// `INTERNAL` Count of projects using this project status across the workspace.
+    ///
+    /// Full type: [`ProjectStatusCountPayload`](super::types::ProjectStatusCountPayload)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub async fn project_status_project_count<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::ProjectStatusCountPayload>,
     >(
         &self,
-        copy_settings_from_team_id: Option<String>,
-        input: TeamCreateInput,
+        id: impl Into<super::ids::ProjectStatusId>,
     ) -> Result<T, LinearError> {
-        crate::generated::mutations::team_create::<T>(self, copy_settings_from_team_id, input).await
+        crate::generated::queries::project_status_project_count::<T>(self, id).await
     }
-    /// Updates a team's settings, properties, or configuration. Requires team owner or workspace admin permissions for most changes.
+    /// Returns a single project status by its identifier.
     ///
-    /// Full type: [`Team`](super::types::Team)
-    pub async fn team_update<
-        T: serde::de::DeserializeOwned
-            + crate::field_selection::GraphQLFields<FullType = super::types::Team>,
+    /// Full type: [`ProjectStatus`](super::types::ProjectStatus)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub async fn project_status<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::ProjectStatus>,
     >(
         &self,
-        mapping: Option<InheritanceEntityMapping>,
-        input: TeamUpdateInput,
-        id: String,
+        id: impl Into<super::ids::ProjectStatusId>,
     ) -> Result<T, LinearError> {
-        crate::generated::mutations::team_update::<T>(self, mapping, input, id).await
+        crate::generated::queries::project_status::<T>(self, id).await
     }
-    /// Archives a team and schedules its data for deletion. Requires team owner or workspace admin permissions.
-    pub async fn team_delete(&self, id: String) -> Result<serde_json::Value, LinearError> {
-        crate::generated::mutations::team_delete(self, id).await
+    /// Returns all project dependency relations in the workspace.
+    ///
+    /// Full type: [`ProjectRelation`](super::types::ProjectRelation)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub fn project_relations<T>(&self) -> ProjectRelationsQueryBuilder<'_, T> {
+        crate::generated::queries::project_relations(self)
     }
-    /// Creates a new team membership, adding a user to a team. Validates that the user is not already a member, the team is not archived or retired, and the requesting user has permission to add members.
+    /// Returns a single project relation by its identifier.
     ///
-    /// Full type: [`TeamMembership`](super::types::TeamMembership)
-    pub async fn team_membership_create<
-        T: serde::de::DeserializeOwned
-            + crate::field_selection::GraphQLFields<FullType = super::types::TeamMembership>,
+    /// Full type: [`ProjectRelation`](super::types::ProjectRelation)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub async fn project_relation<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::ProjectRelation>,
     >(
         &self,
-        input: TeamMembershipCreateInput,
+        id: impl Into<super::ids::ProjectRelationId>,
     ) -> Result<T, LinearError> {
-        crate::generated::mutations::team_membership_create::<T>(self, input).await
+        crate::generated::queries::project_relation::<T>(self, id).await
     }
-    /// Deletes a team membership, removing the user from the team. Users can remove their own membership, or team owners and workspace admins can remove other members.
-    pub async fn team_membership_delete(
-        &self,
-        also_leave_parent_teams: Option<bool>,
-        id: String,
-    ) -> Result<serde_json::Value, LinearError> {
-        crate::generated::mutations::team_membership_delete(self, also_leave_parent_teams, id).await
+    /// Returns all project milestones in the workspace, with optional filtering.
+    ///
+    /// Full type: [`ProjectMilestone`](super::types::ProjectMilestone)
+    pub fn project_milestones<T>(&self) -> ProjectMilestonesQueryBuilder<'_, T> {
+        crate::generated::queries::project_milestones(self)
     }
-    /// Creates a new project milestone.
+    /// Returns a single project milestone by its identifier.
     ///
     /// Full type: [`ProjectMilestone`](super::types::ProjectMilestone)
-    pub async fn project_milestone_create<
-        T: serde::de::DeserializeOwned
-            + crate::field_selection::GraphQLFields<FullType = super::types::ProjectMilestone>,
+    pub async fn project_milestone<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::ProjectMilestone>,
     >(
         &self,
-        input: ProjectMilestoneCreateInput,
+        id: impl Into<super::ids::ProjectMilestoneId>,
     ) -> Result<T, LinearError> {
-        crate::generated::mutations::project_milestone_create::<T>(self, input).await
+        crate::generated::queries::project_milestone::<T>(self, id).await
     }
-    /// Updates a project milestone.
+    /// Returns all project labels in the workspace, with optional filtering.
     ///
-    /// Full type: [`ProjectMilestone`](super::types::ProjectMilestone)
-    pub async fn project_milestone_update<
-        T: serde::de::DeserializeOwned
-            + crate::field_selection::GraphQLFields<FullType = super::types::ProjectMilestone>,
+    /// Full type: [`ProjectLabel`](super::types::ProjectLabel)
+    pub fn project_labels<T>(&self) -> ProjectLabelsQueryBuilder<'_, T> {
+        crate::generated::queries::project_labels(self)
+    }
+    /// Returns a single project label by its identifier.
+    ///
+    /// Full type: [`ProjectLabel`](super::types::ProjectLabel)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub async fn project_label<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::ProjectLabel>,
     >(
         &self,
-        input: ProjectMilestoneUpdateInput,
-        id: String,
+        id: impl Into<super::ids::ProjectLabelId>,
     ) -> Result<T, LinearError> {
-        crate::generated::mutations::project_milestone_update::<T>(self, input, id).await
-    }
-    /// Deletes a project milestone.
-    pub async fn project_milestone_delete(
-        &self,
-        id: String,
-    ) -> Result<serde_json::Value, LinearError> {
-        crate::generated::mutations::project_milestone_delete(self, id).await
+        crate::generated::queries::project_label::<T>(self, id).await
     }
-    /// Creates a new issue.
+    /// The authenticated user's workspace.
     ///
-    /// Full type: [`Issue`](super::types::Issue)
-    pub async fn issue_create<
-        T: serde::de::DeserializeOwned
-            + crate::field_selection::GraphQLFields<FullType = super::types::Issue>,
+    /// Full type: [`Organization`](super::types::Organization)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub async fn organization<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::Organization>,
     >(
         &self,
-        input: IssueCreateInput,
     ) -> Result<T, LinearError> {
-        crate::generated::mutations::issue_create::<T>(self, input).await
+        crate::generated::queries::organization::<T>(self).await
     }
-    /// Updates an issue.
+    /// Checks whether a workspace with the given URL key exists.
     ///
-    /// Full type: [`Issue`](super::types::Issue)
-    pub async fn issue_update<
-        T: serde::de::DeserializeOwned
-            + crate::field_selection::GraphQLFields<FullType = super::types::Issue>,
+    /// Full type: [`OrganizationExistsPayload`](super::types::OrganizationExistsPayload)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub async fn organization_exists<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::OrganizationExistsPayload>,
     >(
         &self,
-        input: IssueUpdateInput,
-        id: String,
+        url_key: String,
     ) -> Result<T, LinearError> {
-        crate::generated::mutations::issue_update::<T>(self, input, id).await
+        crate::generated::queries::organization_exists::<T>(self, url_key).await
     }
-    /// Updates multiple issues at once.
+    /// `Internal` All archived teams of the workspace.
     ///
-    /// Full type: [`Issue`](super::types::Issue)
-    pub async fn issue_batch_update<
-        T: serde::de::DeserializeOwned
-            + crate::field_selection::GraphQLFields<FullType = super::types::Issue>,
+    /// Full type: [`Team`](super::types::Team)
+    ///
+    /// Requires the `teams` feature.
+    #[cfg(feature = "teams")]
+    pub async fn archived_teams<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::Team>,
     >(
         &self,
-        input: IssueUpdateInput,
-        ids: Vec<String>,
     ) -> Result<Vec<T>, LinearError> {
-        crate::generated::mutations::issue_batch_update::<T>(self, input, ids).await
+        crate::generated::queries::archived_teams::<T>(self).await
     }
-    /// Archives an issue.
+    /// `INTERNAL` Get workspace metadata by URL key or workspace ID.
     ///
-    /// Full type: [`Issue`](super::types::Issue)
-    pub async fn issue_archive<
-        T: serde::de::DeserializeOwned
-            + crate::field_selection::GraphQLFields<FullType = super::types::Issue>,
+    /// Full type: [`OrganizationMeta`](super::types::OrganizationMeta)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub async fn organization_meta<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::OrganizationMeta>,
     >(
         &self,
-        trash: Option<bool>,
-        id: String,
-    ) -> Result<T, LinearError> {
-        crate::generated::mutations::issue_archive::<T>(self, trash, id).await
+        url_key: String,
+    ) -> Result<Option<T>, LinearError> {
+        crate::generated::queries::organization_meta::<T>(self, url_key).await
     }
-    /// Unarchives an issue.
+    /// All pending and accepted invites for the workspace.
     ///
-    /// Full type: [`Issue`](super::types::Issue)
-    pub async fn issue_unarchive<
-        T: serde::de::DeserializeOwned
-            + crate::field_selection::GraphQLFields<FullType = super::types::Issue>,
+    /// Full type: [`OrganizationInvite`](super::types::OrganizationInvite)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub fn organization_invites<T>(&self) -> OrganizationInvitesQueryBuilder<'_, T> {
+        crate::generated::queries::organization_invites(self)
+    }
+    /// Fetches a specific workspace invite by its ID.
+    ///
+    /// Full type: [`OrganizationInvite`](super::types::OrganizationInvite)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub async fn organization_invite<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::OrganizationInvite>,
     >(
         &self,
-        id: String,
+        id: impl Into<super::ids::OrganizationInviteId>,
     ) -> Result<T, LinearError> {
-        crate::generated::mutations::issue_unarchive::<T>(self, id).await
+        crate::generated::queries::organization_invite::<T>(self, id).await
     }
-    /// Deletes (trashes) an issue.
+    /// Fetches public details of a specific workspace invite by its ID. This query does not require authentication and is used for invite acceptance flows.
     ///
-    /// Full type: [`Issue`](super::types::Issue)
-    pub async fn issue_delete<
-        T: serde::de::DeserializeOwned
-            + crate::field_selection::GraphQLFields<FullType = super::types::Issue>,
+    /// Full type: [`OrganizationInviteDetailsPayload`](super::types::OrganizationInviteDetailsPayload)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub async fn organization_invite_details<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::OrganizationInviteDetailsPayload>,
     >(
         &self,
-        permanently_delete: Option<bool>,
-        id: String,
+        id: impl Into<super::ids::OrganizationInviteId>,
     ) -> Result<T, LinearError> {
-        crate::generated::mutations::issue_delete::<T>(self, permanently_delete, id).await
+        crate::generated::queries::organization_invite_details::<T>(self, id).await
     }
-    /// Creates a new issue relation.
+    /// `INTERNAL` Checks whether the domain can be claimed.
     ///
-    /// Full type: [`IssueRelation`](super::types::IssueRelation)
-    pub async fn issue_relation_create<
-        T: serde::de::DeserializeOwned
-            + crate::field_selection::GraphQLFields<FullType = super::types::IssueRelation>,
+    /// Full type: [`OrganizationDomainClaimPayload`](super::types::OrganizationDomainClaimPayload)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub async fn organization_domain_claim_request<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::OrganizationDomainClaimPayload>,
     >(
         &self,
-        override_created_at: Option<serde_json::Value>,
-        input: IssueRelationCreateInput,
+        id: impl Into<super::ids::OrganizationDomainId>,
     ) -> Result<T, LinearError> {
-        crate::generated::mutations::issue_relation_create::<T>(self, override_created_at, input)
-            .await
+        crate::generated::queries::organization_domain_claim_request::<T>(self, id).await
     }
-    /// Deletes an issue relation.
-    pub async fn issue_relation_delete(
-        &self,
-        id: String,
-    ) -> Result<serde_json::Value, LinearError> {
-        crate::generated::mutations::issue_relation_delete(self, id).await
+    /// The authenticated user's notification subscriptions. These subscriptions control which notifications the user receives for specific entities such as teams, projects, cycles, labels, custom views, initiatives, customers, and users.
+    ///
+    /// Full type: [`NotificationSubscription`](super::types::NotificationSubscription)
+    ///
+    /// Requires the `notifications` feature.
+    #[cfg(feature = "notifications")]
+    pub fn notification_subscriptions<T>(&self) -> NotificationSubscriptionsQueryBuilder<'_, T> {
+        crate::generated::queries::notification_subscriptions(self)
     }
-    /// Creates a new label.
+    /// A specific notification subscription by ID.
     ///
-    /// Full type: [`IssueLabel`](super::types::IssueLabel)
-    pub async fn issue_label_create<
-        T: serde::de::DeserializeOwned
-            + crate::field_selection::GraphQLFields<FullType = super::types::IssueLabel>,
+    /// Full type: [`NotificationSubscription`](super::types::NotificationSubscription)
+    ///
+    /// Requires the `notifications` feature.
+    #[cfg(feature = "notifications")]
+    pub async fn notification_subscription<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::NotificationSubscription>,
     >(
         &self,
-        replace_team_labels: Option<bool>,
-        input: IssueLabelCreateInput,
+        id: String,
     ) -> Result<T, LinearError> {
-        crate::generated::mutations::issue_label_create::<T>(self, replace_team_labels, input).await
+        crate::generated::queries::notification_subscription::<T>(self, id).await
     }
-    /// Updates a label.
+    /// The authenticated user's notifications.
     ///
-    /// Full type: [`IssueLabel`](super::types::IssueLabel)
-    pub async fn issue_label_update<
-        T: serde::de::DeserializeOwned
-            + crate::field_selection::GraphQLFields<FullType = super::types::IssueLabel>,
+    /// Full type: [`Notification`](super::types::Notification)
+    pub fn notifications<T>(&self) -> NotificationsQueryBuilder<'_, T> {
+        crate::generated::queries::notifications(self)
+    }
+    /// A specific notification by ID.
+    ///
+    /// Full type: [`Notification`](super::types::Notification)
+    pub async fn notification<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::Notification>,
     >(
         &self,
-        replace_team_labels: Option<bool>,
-        input: IssueLabelUpdateInput,
         id: String,
     ) -> Result<T, LinearError> {
-        crate::generated::mutations::issue_label_update::<T>(self, replace_team_labels, input, id)
-            .await
+        crate::generated::queries::notification::<T>(self, id).await
     }
-    /// Deletes an issue label.
-    pub async fn issue_label_delete(&self, id: String) -> Result<serde_json::Value, LinearError> {
-        crate::generated::mutations::issue_label_delete(self, id).await
+    /// `ALPHA` All issue-to-release associations. Returns a paginated list of all issue-to-release links visible to the authenticated user.
+    ///
+    /// Full type: [`IssueToRelease`](super::types::IssueToRelease)
+    ///
+    /// Requires the `releases` feature.
+    #[cfg(feature = "releases")]
+    pub fn issue_to_releases<T>(&self) -> IssueToReleasesQueryBuilder<'_, T> {
+        crate::generated::queries::issue_to_releases(self)
     }
-    /// Creates a new document.
+    /// `ALPHA` One specific issue-to-release association, looked up by its unique identifier.
     ///
-    /// Full type: [`Document`](super::types::Document)
-    pub async fn document_create<
-        T: serde::de::DeserializeOwned
-            + crate::field_selection::GraphQLFields<FullType = super::types::Document>,
+    /// Full type: [`IssueToRelease`](super::types::IssueToRelease)
+    ///
+    /// Requires the `releases` feature.
+    #[cfg(feature = "releases")]
+    pub async fn issue_to_release<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::IssueToRelease>,
     >(
         &self,
-        input: DocumentCreateInput,
+        id: impl Into<super::ids::IssueToReleaseId>,
     ) -> Result<T, LinearError> {
-        crate::generated::mutations::document_create::<T>(self, input).await
+        crate::generated::queries::issue_to_release::<T>(self, id).await
     }
-    /// Updates a document.
+    /// All issues. Returns a paginated list of issues visible to the authenticated user. Can be filtered by various criteria including team, assignee, state, labels, project, and cycle.
     ///
-    /// Full type: [`Document`](super::types::Document)
-    pub async fn document_update<
-        T: serde::de::DeserializeOwned
-            + crate::field_selection::GraphQLFields<FullType = super::types::Document>,
-    >(
+    /// Full type: [`Issue`](super::types::Issue)
+    pub fn issues<T>(&self) -> IssuesQueryBuilder<'_, T> {
+        crate::generated::queries::issues(self)
+    }
+    /// One specific issue, looked up by its unique identifier.
+    ///
+    /// Full type: [`Issue`](super::types::Issue)
+    pub async fn issue<T: DeserializeOwned + GraphQLFields<FullType = super::types::Issue>>(
         &self,
-        input: DocumentUpdateInput,
-        id: String,
+        id: impl Into<super::ids::IssueId>,
     ) -> Result<T, LinearError> {
-        crate::generated::mutations::document_update::<T>(self, input, id).await
+        crate::generated::queries::issue::<T>(self, id).await
     }
-    /// Deletes (trashes) a document. The document is marked as trashed and archived, but not permanently removed.
+    /// `DEPRECATED` Search issues. This endpoint is deprecated and will be removed in the future – use `searchIssues` instead.
     ///
-    /// Full type: [`Document`](super::types::Document)
-    pub async fn document_delete<
-        T: serde::de::DeserializeOwned
-            + crate::field_selection::GraphQLFields<FullType = super::types::Document>,
+    /// Full type: [`Issue`](super::types::Issue)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub fn issue_search<T>(&self) -> IssueSearchQueryBuilder<'_, T> {
+        crate::generated::queries::issue_search(self)
+    }
+    /// Find issue based on the VCS branch name.
+    ///
+    /// Full type: [`Issue`](super::types::Issue)
+    pub async fn issue_vcs_branch_search<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::Issue>,
     >(
         &self,
-        id: String,
-    ) -> Result<T, LinearError> {
-        crate::generated::mutations::document_delete::<T>(self, id).await
+        branch_name: String,
+    ) -> Result<Option<T>, LinearError> {
+        crate::generated::queries::issue_vcs_branch_search::<T>(self, branch_name).await
     }
-    /// Creates a new comment.
+    /// Find issues that are related to a given Figma file key.
     ///
-    /// Full type: [`Comment`](super::types::Comment)
-    pub async fn comment_create<
-        T: serde::de::DeserializeOwned
-            + crate::field_selection::GraphQLFields<FullType = super::types::Comment>,
-    >(
+    /// Full type: [`Issue`](super::types::Issue)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub fn issue_figma_file_key_search<T>(
         &self,
-        input: CommentCreateInput,
-    ) -> Result<T, LinearError> {
-        crate::generated::mutations::comment_create::<T>(self, input).await
+        file_key: impl Into<String>,
+    ) -> IssueFigmaFileKeySearchQueryBuilder<'_, T> {
+        crate::generated::queries::issue_figma_file_key_search(self, file_key)
     }
-    /// Updates a comment.
+    /// Issue priority values and corresponding labels.
     ///
-    /// Full type: [`Comment`](super::types::Comment)
-    pub async fn comment_update<
-        T: serde::de::DeserializeOwned
-            + crate::field_selection::GraphQLFields<FullType = super::types::Comment>,
+    /// Full type: [`IssuePriorityValue`](super::types::IssuePriorityValue)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub async fn issue_priority_values<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::IssuePriorityValue>,
     >(
         &self,
-        skip_edited_at: Option<bool>,
-        input: CommentUpdateInput,
-        id: String,
-    ) -> Result<T, LinearError> {
-        crate::generated::mutations::comment_update::<T>(self, skip_edited_at, input, id).await
+    ) -> Result<Vec<T>, LinearError> {
+        crate::generated::queries::issue_priority_values::<T>(self).await
     }
-    /// Deletes a comment.
-    pub async fn comment_delete(&self, id: String) -> Result<serde_json::Value, LinearError> {
-        crate::generated::mutations::comment_delete(self, id).await
+    /// Suggests filters for an issue view based on a text prompt.
+    ///
+    /// Full type: [`IssueFilterSuggestionPayload`](super::types::IssueFilterSuggestionPayload)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub fn issue_filter_suggestion<T>(
+        &self,
+        prompt: impl Into<String>,
+    ) -> IssueFilterSuggestionQueryBuilder<'_, T> {
+        crate::generated::queries::issue_filter_suggestion(self, prompt)
     }
-    /// Resolves a comment thread. Marks the root comment as resolved by the current user.
+    /// Returns code repositories that are most likely to be relevant for implementing an issue.
     ///
-    /// Full type: [`Comment`](super::types::Comment)
-    pub async fn comment_resolve<
-        T: serde::de::DeserializeOwned
-            + crate::field_selection::GraphQLFields<FullType = super::types::Comment>,
+    /// Full type: [`RepositorySuggestionsPayload`](super::types::RepositorySuggestionsPayload)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub fn issue_repository_suggestions<T>(
+        &self,
+        candidate_repositories: CandidateRepository,
+        issue_id: impl Into<String>,
+    ) -> IssueRepositorySuggestionsQueryBuilder<'_, T> {
+        crate::generated::queries::issue_repository_suggestions(
+            self,
+            candidate_repositories,
+            issue_id,
+        )
+    }
+    /// All issue relations. Returns a paginated list of all issue relations (blocks, blocked by, relates to, duplicates) visible to the authenticated user.
+    ///
+    /// Full type: [`IssueRelation`](super::types::IssueRelation)
+    pub fn issue_relations<T>(&self) -> IssueRelationsQueryBuilder<'_, T> {
+        crate::generated::queries::issue_relations(self)
+    }
+    /// One specific issue relation, looked up by its unique identifier.
+    ///
+    /// Full type: [`IssueRelation`](super::types::IssueRelation)
+    pub async fn issue_relation<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::IssueRelation>,
     >(
         &self,
-        resolving_comment_id: Option<String>,
-        id: String,
+        id: impl Into<super::ids::IssueRelationId>,
     ) -> Result<T, LinearError> {
-        crate::generated::mutations::comment_resolve::<T>(self, resolving_comment_id, id).await
+        crate::generated::queries::issue_relation::<T>(self, id).await
     }
-    /// Unresolves a previously resolved comment thread. Clears the resolved state on the root comment.
+    /// All issue labels. Returns a paginated list of labels visible to the authenticated user, including both workspace-level and team-scoped labels.
     ///
-    /// Full type: [`Comment`](super::types::Comment)
-    pub async fn comment_unresolve<
-        T: serde::de::DeserializeOwned
-            + crate::field_selection::GraphQLFields<FullType = super::types::Comment>,
+    /// Full type: [`IssueLabel`](super::types::IssueLabel)
+    pub fn issue_labels<T>(&self) -> IssueLabelsQueryBuilder<'_, T> {
+        crate::generated::queries::issue_labels(self)
+    }
+    /// One specific label, looked up by its unique identifier.
+    ///
+    /// Full type: [`IssueLabel`](super::types::IssueLabel)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub async fn issue_label<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::IssueLabel>,
     >(
         &self,
-        id: String,
+        id: impl Into<super::ids::IssueLabelId>,
     ) -> Result<T, LinearError> {
-        crate::generated::mutations::comment_unresolve::<T>(self, id).await
+        crate::generated::queries::issue_label::<T>(self, id).await
+    }
+    /// Checks a CSV file validity against a specific import service.
+    ///
+    /// Full type: [`IssueImportCheckPayload`](super::types::IssueImportCheckPayload)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub async fn issue_import_check_csv<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::IssueImportCheckPayload>,
+    >(
+        &self,
+        csv_url: String,
+        service: String,
+    ) -> Result<T, LinearError> {
+        crate::generated::queries::issue_import_check_csv::<T>(self, csv_url, service).await
+    }
+    /// Checks whether it will be possible to set up sync for this project or repository at the end of import.
+    ///
+    /// Full type: [`IssueImportSyncCheckPayload`](super::types::IssueImportSyncCheckPayload)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub async fn issue_import_check_sync<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::IssueImportSyncCheckPayload>,
+    >(
+        &self,
+        issue_import_id: String,
+    ) -> Result<T, LinearError> {
+        crate::generated::queries::issue_import_check_sync::<T>(self, issue_import_id).await
+    }
+    /// Checks whether a custom JQL query is valid and can be used to filter issues of a Jira import.
+    ///
+    /// Full type: [`IssueImportJqlCheckPayload`](super::types::IssueImportJqlCheckPayload)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub async fn issue_import_jql_check<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::IssueImportJqlCheckPayload>,
+    >(
+        &self,
+        jira_hostname: String,
+        jira_token: String,
+        jira_email: String,
+        jira_project: String,
+        jql: String,
+    ) -> Result<T, LinearError> {
+        crate::generated::queries::issue_import_jql_check::<T>(
+            self,
+            jira_hostname,
+            jira_token,
+            jira_email,
+            jira_project,
+            jql,
+        )
+        .await
+    }
+    /// Retrieves a specific integration settings configuration by its identifier.
+    ///
+    /// Full type: [`IntegrationsSettings`](super::types::IntegrationsSettings)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub async fn integrations_settings<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::IntegrationsSettings>,
+    >(
+        &self,
+        id: impl Into<super::ids::IntegrationsSettingsId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::queries::integrations_settings::<T>(self, id).await
+    }
+    /// All integration template connections for the workspace, linking templates to integrations.
+    ///
+    /// Full type: [`IntegrationTemplate`](super::types::IntegrationTemplate)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn integration_templates<T>(&self) -> IntegrationTemplatesQueryBuilder<'_, T> {
+        crate::generated::queries::integration_templates(self)
+    }
+    /// Retrieves a single integration template connection by its identifier.
+    ///
+    /// Full type: [`IntegrationTemplate`](super::types::IntegrationTemplate)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub async fn integration_template<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::IntegrationTemplate>,
+    >(
+        &self,
+        id: impl Into<super::ids::IntegrationTemplateId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::queries::integration_template::<T>(self, id).await
+    }
+    /// All integrations for the workspace.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn integrations<T>(&self) -> IntegrationsQueryBuilder<'_, T> {
+        crate::generated::queries::integrations(self)
+    }
+    /// Retrieves a single integration by its identifier.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub async fn integration<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        id: impl Into<super::ids::IntegrationId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::queries::integration::<T>(self, id).await
+    }
+    /// Verify that we received the correct response from the GitHub Enterprise Server.
+    ///
+    /// Full type: [`GitHubEnterpriseServerInstallVerificationPayload`](super::types::GitHubEnterpriseServerInstallVerificationPayload)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub async fn verify_git_hub_enterprise_server_installation<
+        T: DeserializeOwned
+            + GraphQLFields<FullType = super::types::GitHubEnterpriseServerInstallVerificationPayload>,
+    >(
+        &self,
+        integration_id: String,
+    ) -> Result<T, LinearError> {
+        crate::generated::queries::verify_git_hub_enterprise_server_installation::<T>(
+            self,
+            integration_id,
+        )
+        .await
+    }
+    /// Checks if the integration has all required scopes.
+    ///
+    /// Full type: [`IntegrationHasScopesPayload`](super::types::IntegrationHasScopesPayload)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub async fn integration_has_scopes<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::IntegrationHasScopesPayload>,
+    >(
+        &self,
+        scopes: Vec<String>,
+        integration_id: String,
+    ) -> Result<T, LinearError> {
+        crate::generated::queries::integration_has_scopes::<T>(self, scopes, integration_id).await
+    }
+    /// `Internal` Lists Microsoft Teams teams and channels accessible to the connecting user. Uses the user's microsoftPersonal integration for auth. Used by the project channel picker dialog to populate the team/channel dropdowns.
+    ///
+    /// Full type: [`MicrosoftTeamsChannelsPayload`](super::types::MicrosoftTeamsChannelsPayload)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub async fn microsoft_teams_channels<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::MicrosoftTeamsChannelsPayload>,
+    >(
+        &self,
+    ) -> Result<T, LinearError> {
+        crate::generated::queries::microsoft_teams_channels::<T>(self).await
+    }
+    /// Returns all initiative status updates in the workspace, with optional filtering.
+    ///
+    /// Full type: [`InitiativeUpdate`](super::types::InitiativeUpdate)
+    ///
+    /// Requires the `initiatives` feature.
+    #[cfg(feature = "initiatives")]
+    pub fn initiative_updates<T>(&self) -> InitiativeUpdatesQueryBuilder<'_, T> {
+        crate::generated::queries::initiative_updates(self)
+    }
+    /// Returns a single initiative update by its identifier.
+    ///
+    /// Full type: [`InitiativeUpdate`](super::types::InitiativeUpdate)
+    ///
+    /// Requires the `initiatives` feature.
+    #[cfg(feature = "initiatives")]
+    pub async fn initiative_update_post<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::InitiativeUpdate>,
+    >(
+        &self,
+        id: impl Into<super::ids::InitiativeUpdateId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::queries::initiative_update_post::<T>(self, id).await
+    }
+    /// Returns all initiative-to-project associations in the workspace.
+    ///
+    /// Full type: [`InitiativeToProject`](super::types::InitiativeToProject)
+    ///
+    /// Requires the `initiatives` feature.
+    #[cfg(feature = "initiatives")]
+    pub fn initiative_to_projects<T>(&self) -> InitiativeToProjectsQueryBuilder<'_, T> {
+        crate::generated::queries::initiative_to_projects(self)
+    }
+    /// Returns a single initiative-to-project association by its identifier.
+    ///
+    /// Full type: [`InitiativeToProject`](super::types::InitiativeToProject)
+    ///
+    /// Requires the `initiatives` feature.
+    #[cfg(feature = "initiatives")]
+    pub async fn initiative_to_project<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::InitiativeToProject>,
+    >(
+        &self,
+        id: impl Into<super::ids::InitiativeToProjectId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::queries::initiative_to_project::<T>(self, id).await
+    }
+    /// Returns all initiatives in the workspace, with optional filtering and sorting.
+    ///
+    /// Full type: [`Initiative`](super::types::Initiative)
+    ///
+    /// Requires the `initiatives` feature.
+    #[cfg(feature = "initiatives")]
+    pub fn initiatives<T>(&self) -> InitiativesQueryBuilder<'_, T> {
+        crate::generated::queries::initiatives(self)
+    }
+    /// Returns a single initiative by its identifier or URL slug.
+    ///
+    /// Full type: [`Initiative`](super::types::Initiative)
+    ///
+    /// Requires the `initiatives` feature.
+    #[cfg(feature = "initiatives")]
+    pub async fn initiative<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::Initiative>,
+    >(
+        &self,
+        id: impl Into<super::ids::InitiativeId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::queries::initiative::<T>(self, id).await
+    }
+    /// Returns all initiative parent-child relations in the workspace.
+    ///
+    /// Full type: [`InitiativeRelation`](super::types::InitiativeRelation)
+    ///
+    /// Requires the `initiatives` feature.
+    #[cfg(feature = "initiatives")]
+    pub fn initiative_relations<T>(&self) -> InitiativeRelationsQueryBuilder<'_, T> {
+        crate::generated::queries::initiative_relations(self)
+    }
+    /// Returns a single initiative relation by its identifier.
+    ///
+    /// Full type: [`InitiativeRelation`](super::types::InitiativeRelation)
+    ///
+    /// Requires the `initiatives` feature.
+    #[cfg(feature = "initiatives")]
+    pub async fn initiative_relation<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::InitiativeRelation>,
+    >(
+        &self,
+        id: impl Into<super::ids::InitiativeRelationId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::queries::initiative_relation::<T>(self, id).await
+    }
+    /// `Internal` Fetch an arbitrary set of data using natural language query. Be specific about what you want including properties for each entity, sort order, filters, limit and properties.
+    ///
+    /// Full type: [`FetchDataPayload`](super::types::FetchDataPayload)
+    ///
+    /// Requires the `agents` feature.
+    #[cfg(feature = "agents")]
+    pub async fn fetch_data<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::FetchDataPayload>,
+    >(
+        &self,
+        query: String,
+    ) -> Result<T, LinearError> {
+        crate::generated::queries::fetch_data::<T>(self, query).await
+    }
+    /// The authenticated user's favorites. Returns all bookmarked items that appear in the user's sidebar.
+    ///
+    /// Full type: [`Favorite`](super::types::Favorite)
+    ///
+    /// Requires the `views` feature.
+    #[cfg(feature = "views")]
+    pub fn favorites<T>(&self) -> FavoritesQueryBuilder<'_, T> {
+        crate::generated::queries::favorites(self)
+    }
+    /// A specific favorite by ID.
+    ///
+    /// Full type: [`Favorite`](super::types::Favorite)
+    ///
+    /// Requires the `views` feature.
+    #[cfg(feature = "views")]
+    pub async fn favorite<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::Favorite>,
+    >(
+        &self,
+        id: impl Into<super::ids::FavoriteId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::queries::favorite::<T>(self, id).await
+    }
+    /// All external users for the organization. External users are people who interact with Linear through integrated services (Slack, Jira, GitHub, etc.) without having a Linear account.
+    ///
+    /// Full type: [`ExternalUser`](super::types::ExternalUser)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub fn external_users<T>(&self) -> ExternalUsersQueryBuilder<'_, T> {
+        crate::generated::queries::external_users(self)
+    }
+    /// Retrieves a single external user by their identifier.
+    ///
+    /// Full type: [`ExternalUser`](super::types::ExternalUser)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub async fn external_user<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::ExternalUser>,
+    >(
+        &self,
+        id: impl Into<super::ids::ExternalUserId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::queries::external_user::<T>(self, id).await
+    }
+    /// Retrieves a single entity external link by its identifier.
+    ///
+    /// Full type: [`EntityExternalLink`](super::types::EntityExternalLink)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub async fn entity_external_link<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::EntityExternalLink>,
+    >(
+        &self,
+        id: impl Into<super::ids::EntityExternalLinkId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::queries::entity_external_link::<T>(self, id).await
+    }
+    /// All custom emojis in the workspace.
+    ///
+    /// Full type: [`Emoji`](super::types::Emoji)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub fn emojis<T>(&self) -> EmojisQueryBuilder<'_, T> {
+        crate::generated::queries::emojis(self)
+    }
+    /// A specific custom emoji by ID or name.
+    ///
+    /// Full type: [`Emoji`](super::types::Emoji)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub async fn emoji<T: DeserializeOwned + GraphQLFields<FullType = super::types::Emoji>>(
+        &self,
+        id: impl Into<super::ids::EmojiId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::queries::emoji::<T>(self, id).await
+    }
+    /// One specific email intake address.
+    ///
+    /// Full type: [`EmailIntakeAddress`](super::types::EmailIntakeAddress)
+    ///
+    /// Requires the `teams` feature.
+    #[cfg(feature = "teams")]
+    pub async fn email_intake_address<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::EmailIntakeAddress>,
+    >(
+        &self,
+        id: impl Into<super::ids::EmailIntakeAddressId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::queries::email_intake_address::<T>(self, id).await
+    }
+    /// All documents the user has access to in the workspace.
+    ///
+    /// Full type: [`Document`](super::types::Document)
+    pub fn documents<T>(&self) -> DocumentsQueryBuilder<'_, T> {
+        crate::generated::queries::documents(self)
+    }
+    /// A specific document by ID or slug.
+    ///
+    /// Full type: [`Document`](super::types::Document)
+    pub async fn document<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::Document>,
+    >(
+        &self,
+        id: impl Into<super::ids::DocumentId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::queries::document::<T>(self, id).await
+    }
+    /// A collection of document content history entries.
+    ///
+    /// Full type: [`DocumentContentHistoryPayload`](super::types::DocumentContentHistoryPayload)
+    ///
+    /// Requires the `documents` feature.
+    #[cfg(feature = "documents")]
+    pub async fn document_content_history<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::DocumentContentHistoryPayload>,
+    >(
+        &self,
+        id: impl Into<super::ids::DocumentContentHistoryTypeId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::queries::document_content_history::<T>(self, id).await
+    }
+    /// `Internal` Fetches document content history entries by their IDs, including content data.
+    ///
+    /// Full type: [`DocumentContentHistoryPayload`](super::types::DocumentContentHistoryPayload)
+    ///
+    /// Requires the `documents` feature.
+    #[cfg(feature = "documents")]
+    pub async fn document_content_history_entries<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::DocumentContentHistoryPayload>,
+    >(
+        &self,
+        entry_ids: Vec<String>,
+    ) -> Result<T, LinearError> {
+        crate::generated::queries::document_content_history_entries::<T>(self, entry_ids).await
+    }
+    /// All cycles accessible to the user.
+    ///
+    /// Full type: [`Cycle`](super::types::Cycle)
+    pub fn cycles<T>(&self) -> CyclesQueryBuilder<'_, T> {
+        crate::generated::queries::cycles(self)
+    }
+    /// One specific cycle, looked up by ID or slug.
+    ///
+    /// Full type: [`Cycle`](super::types::Cycle)
+    pub async fn cycle<T: DeserializeOwned + GraphQLFields<FullType = super::types::Cycle>>(
+        &self,
+        id: impl Into<super::ids::CycleId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::queries::cycle::<T>(self, id).await
+    }
+    /// All customer tiers defined in the workspace.
+    ///
+    /// Full type: [`CustomerTier`](super::types::CustomerTier)
+    ///
+    /// Requires the `customers` feature.
+    #[cfg(feature = "customers")]
+    pub fn customer_tiers<T>(&self) -> CustomerTiersQueryBuilder<'_, T> {
+        crate::generated::queries::customer_tiers(self)
+    }
+    /// One specific customer tier.
+    ///
+    /// Full type: [`CustomerTier`](super::types::CustomerTier)
+    ///
+    /// Requires the `customers` feature.
+    #[cfg(feature = "customers")]
+    pub async fn customer_tier<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::CustomerTier>,
+    >(
+        &self,
+        id: impl Into<super::ids::CustomerTierId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::queries::customer_tier::<T>(self, id).await
+    }
+    /// All customer statuses defined in the workspace.
+    ///
+    /// Full type: [`CustomerStatus`](super::types::CustomerStatus)
+    ///
+    /// Requires the `customers` feature.
+    #[cfg(feature = "customers")]
+    pub fn customer_statuses<T>(&self) -> CustomerStatusesQueryBuilder<'_, T> {
+        crate::generated::queries::customer_statuses(self)
+    }
+    /// One specific customer status.
+    ///
+    /// Full type: [`CustomerStatus`](super::types::CustomerStatus)
+    ///
+    /// Requires the `customers` feature.
+    #[cfg(feature = "customers")]
+    pub async fn customer_status<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::CustomerStatus>,
+    >(
+        &self,
+        id: impl Into<super::ids::CustomerStatusId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::queries::customer_status::<T>(self, id).await
+    }
+    /// All customers in the workspace, with optional filtering and sorting.
+    ///
+    /// Full type: [`Customer`](super::types::Customer)
+    ///
+    /// Requires the `customers` feature.
+    #[cfg(feature = "customers")]
+    pub fn customers<T>(&self) -> CustomersQueryBuilder<'_, T> {
+        crate::generated::queries::customers(self)
+    }
+    /// Retrieves a single customer by ID or slug.
+    ///
+    /// Full type: [`Customer`](super::types::Customer)
+    ///
+    /// Requires the `customers` feature.
+    #[cfg(feature = "customers")]
+    pub async fn customer<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::Customer>,
+    >(
+        &self,
+        id: impl Into<super::ids::CustomerId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::queries::customer::<T>(self, id).await
+    }
+    /// All customer needs in the workspace, with optional filtering.
+    ///
+    /// Full type: [`CustomerNeed`](super::types::CustomerNeed)
+    ///
+    /// Requires the `customers` feature.
+    #[cfg(feature = "customers")]
+    pub fn customer_needs<T>(&self) -> CustomerNeedsQueryBuilder<'_, T> {
+        crate::generated::queries::customer_needs(self)
+    }
+    /// Retrieves a single customer need by ID or hash.
+    ///
+    /// Full type: [`CustomerNeed`](super::types::CustomerNeed)
+    ///
+    /// Requires the `customers` feature.
+    #[cfg(feature = "customers")]
+    pub fn customer_need<T>(&self) -> CustomerNeedQueryBuilder<'_, T> {
+        crate::generated::queries::customer_need(self)
+    }
+    /// Suggests an issue title based on a customer request message using AI summarization.
+    ///
+    /// Full type: [`IssueTitleSuggestionFromCustomerRequestPayload`](super::types::IssueTitleSuggestionFromCustomerRequestPayload)
+    ///
+    /// Requires the `customers` feature.
+    #[cfg(feature = "customers")]
+    pub async fn issue_title_suggestion_from_customer_request<
+        T: DeserializeOwned
+            + GraphQLFields<FullType = super::types::IssueTitleSuggestionFromCustomerRequestPayload>,
+    >(
+        &self,
+        request: String,
+    ) -> Result<T, LinearError> {
+        crate::generated::queries::issue_title_suggestion_from_customer_request::<T>(self, request)
+            .await
+    }
+    /// All custom views accessible to the user, including personal views and shared workspace views. Excludes views scoped to a specific project or initiative.
+    ///
+    /// Full type: [`CustomView`](super::types::CustomView)
+    ///
+    /// Requires the `views` feature.
+    #[cfg(feature = "views")]
+    pub fn custom_views<T>(&self) -> CustomViewsQueryBuilder<'_, T> {
+        crate::generated::queries::custom_views(self)
+    }
+    /// One specific custom view, looked up by ID or slug.
+    ///
+    /// Full type: [`CustomView`](super::types::CustomView)
+    ///
+    /// Requires the `views` feature.
+    #[cfg(feature = "views")]
+    pub async fn custom_view<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::CustomView>,
+    >(
+        &self,
+        id: impl Into<super::ids::CustomViewId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::queries::custom_view::<T>(self, id).await
+    }
+    /// `INTERNAL` Suggests metadata (name, description, icon) for a view based on its filters using AI. Rate-limited to 30 requests per minute.
+    ///
+    /// Full type: [`CustomViewSuggestionPayload`](super::types::CustomViewSuggestionPayload)
+    ///
+    /// Requires the `views` feature.
+    #[cfg(feature = "views")]
+    pub fn custom_view_details_suggestion<T>(
+        &self,
+        filter: serde_json::Value,
+    ) -> CustomViewDetailsSuggestionQueryBuilder<'_, T> {
+        crate::generated::queries::custom_view_details_suggestion(self, filter)
+    }
+    /// Whether a custom view has active notification subscribers other than the current user. Useful for warning before deleting a shared view.
+    ///
+    /// Full type: [`CustomViewHasSubscribersPayload`](super::types::CustomViewHasSubscribersPayload)
+    ///
+    /// Requires the `views` feature.
+    #[cfg(feature = "views")]
+    pub async fn custom_view_has_subscribers<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::CustomViewHasSubscribersPayload>,
+    >(
+        &self,
+        id: impl Into<super::ids::CustomViewId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::queries::custom_view_has_subscribers::<T>(self, id).await
+    }
+    /// All comments the user has access to in the workspace.
+    ///
+    /// Full type: [`Comment`](super::types::Comment)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub fn comments<T>(&self) -> CommentsQueryBuilder<'_, T> {
+        crate::generated::queries::comments(self)
+    }
+    /// A specific comment.
+    ///
+    /// Full type: [`Comment`](super::types::Comment)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub fn comment<T>(&self) -> CommentQueryBuilder<'_, T> {
+        crate::generated::queries::comment(self)
+    }
+    /// Fetch users belonging to this user account.
+    ///
+    /// Full type: [`AuthResolverResponse`](super::types::AuthResolverResponse)
+    ///
+    /// Requires the `users` feature.
+    #[cfg(feature = "users")]
+    pub async fn available_users<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::AuthResolverResponse>,
+    >(
+        &self,
+    ) -> Result<T, LinearError> {
+        crate::generated::queries::available_users::<T>(self).await
+    }
+    /// User's active sessions.
+    ///
+    /// Full type: [`AuthenticationSessionResponse`](super::types::AuthenticationSessionResponse)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub async fn authentication_sessions<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::AuthenticationSessionResponse>,
+    >(
+        &self,
+    ) -> Result<Vec<T>, LinearError> {
+        crate::generated::queries::authentication_sessions::<T>(self).await
+    }
+    /// Fetch SSO login URL for the email provided.
+    ///
+    /// Full type: [`SsoUrlFromEmailResponse`](super::types::SsoUrlFromEmailResponse)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub fn sso_url_from_email<T>(
+        &self,
+        r#type: IdentityProviderType,
+        email: impl Into<String>,
+    ) -> SsoUrlFromEmailQueryBuilder<'_, T> {
+        crate::generated::queries::sso_url_from_email(self, r#type, email)
+    }
+    /// List of audit entry types.
+    ///
+    /// Full type: [`AuditEntryType`](super::types::AuditEntryType)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub async fn audit_entry_types<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::AuditEntryType>,
+    >(
+        &self,
+    ) -> Result<Vec<T>, LinearError> {
+        crate::generated::queries::audit_entry_types::<T>(self).await
+    }
+    /// All audit log entries.
+    ///
+    /// Full type: [`AuditEntry`](super::types::AuditEntry)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub fn audit_entries<T>(&self) -> AuditEntriesQueryBuilder<'_, T> {
+        crate::generated::queries::audit_entries(self)
+    }
+    /// All issue attachments.
+    ///
+    /// To get attachments for a given URL, use `attachmentsForURL` query.
+    ///
+    /// Full type: [`Attachment`](super::types::Attachment)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub fn attachments<T>(&self) -> AttachmentsQueryBuilder<'_, T> {
+        crate::generated::queries::attachments(self)
+    }
+    /// One specific issue attachment.
+    /// `Deprecated` 'url' can no longer be used as the 'id' parameter. Use 'attachmentsForUrl' instead
+    ///
+    /// Full type: [`Attachment`](super::types::Attachment)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub async fn attachment<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::Attachment>,
+    >(
+        &self,
+        id: impl Into<super::ids::AttachmentId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::queries::attachment::<T>(self, id).await
+    }
+    /// Returns issue attachments for a given `url`.
+    ///
+    /// Full type: [`Attachment`](super::types::Attachment)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub fn attachments_for_url<T>(
+        &self,
+        url: impl Into<String>,
+    ) -> AttachmentsForUrlQueryBuilder<'_, T> {
+        crate::generated::queries::attachments_for_url(self, url)
+    }
+    /// Query an issue by its associated attachment, and its id.
+    ///
+    /// Full type: [`Issue`](super::types::Issue)
+    #[deprecated(
+        note = "Will be removed in near future, please use `attachmentsForURL` to get attachments and their issues instead."
+    )]
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub async fn attachment_issue<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::Issue>,
+    >(
+        &self,
+        id: impl Into<super::ids::IssueId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::queries::attachment_issue::<T>(self, id).await
+    }
+    /// `Internal` Get a list of all unique attachment sources in the workspace.
+    ///
+    /// Full type: [`AttachmentSourcesPayload`](super::types::AttachmentSourcesPayload)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub fn attachment_sources<T>(&self) -> AttachmentSourcesQueryBuilder<'_, T> {
+        crate::generated::queries::attachment_sources(self)
+    }
+    /// Retrieves public information about an OAuth application by its client ID. Used during the authorization flow to display application details to the user.
+    ///
+    /// Full type: [`Application`](super::types::Application)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub async fn application_info<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::Application>,
+    >(
+        &self,
+        client_id: String,
+    ) -> Result<T, LinearError> {
+        crate::generated::queries::application_info::<T>(self, client_id).await
+    }
+    /// All agent sessions.
+    ///
+    /// Full type: [`AgentSession`](super::types::AgentSession)
+    ///
+    /// Requires the `agents` feature.
+    #[cfg(feature = "agents")]
+    pub fn agent_sessions<T>(&self) -> AgentSessionsQueryBuilder<'_, T> {
+        crate::generated::queries::agent_sessions(self)
+    }
+    /// A specific agent session.
+    ///
+    /// Full type: [`AgentSession`](super::types::AgentSession)
+    ///
+    /// Requires the `agents` feature.
+    #[cfg(feature = "agents")]
+    pub async fn agent_session<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::AgentSession>,
+    >(
+        &self,
+        id: impl Into<super::ids::AgentSessionId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::queries::agent_session::<T>(self, id).await
+    }
+    /// `Internal` Retrieves coding agent sandbox details for a given agent session ID.
+    ///
+    /// Full type: [`CodingAgentSandboxPayload`](super::types::CodingAgentSandboxPayload)
+    ///
+    /// Requires the `agents` feature.
+    #[cfg(feature = "agents")]
+    pub async fn agent_session_sandbox<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::CodingAgentSandboxPayload>,
+    >(
+        &self,
+        agent_session_id: String,
+    ) -> Result<Option<T>, LinearError> {
+        crate::generated::queries::agent_session_sandbox::<T>(self, agent_session_id).await
+    }
+    /// All agent activities.
+    ///
+    /// Full type: [`AgentActivity`](super::types::AgentActivity)
+    ///
+    /// Requires the `agents` feature.
+    #[cfg(feature = "agents")]
+    pub fn agent_activities<T>(&self) -> AgentActivitiesQueryBuilder<'_, T> {
+        crate::generated::queries::agent_activities(self)
+    }
+    /// A specific agent activity.
+    ///
+    /// Full type: [`AgentActivity`](super::types::AgentActivity)
+    ///
+    /// Requires the `agents` feature.
+    #[cfg(feature = "agents")]
+    pub async fn agent_activity<
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::AgentActivity>,
+    >(
+        &self,
+        id: impl Into<super::ids::AgentActivityId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::queries::agent_activity::<T>(self, id).await
+    }
+    /// XHR request payload to upload an images, video and other attachments directly to Linear's cloud storage.
+    pub async fn file_upload(
+        &self,
+        meta_data: Option<serde_json::Value>,
+        make_public: Option<bool>,
+        size: i64,
+        content_type: String,
+        filename: String,
+    ) -> Result<serde_json::Value, LinearError> {
+        crate::generated::mutations::file_upload(
+            self,
+            meta_data,
+            make_public,
+            size,
+            content_type,
+            filename,
+        )
+        .await
+    }
+    /// XHR request payload to upload a file for import, directly to Linear's cloud storage.
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub async fn import_file_upload(
+        &self,
+        meta_data: Option<serde_json::Value>,
+        size: i64,
+        content_type: String,
+        filename: String,
+    ) -> Result<serde_json::Value, LinearError> {
+        crate::generated::mutations::import_file_upload(
+            self,
+            meta_data,
+            size,
+            content_type,
+            filename,
+        )
+        .await
+    }
+    /// Upload an image from an URL to Linear.
+    pub async fn image_upload_from_url(
+        &self,
+        url: String,
+    ) -> Result<super::types::ImageUploadFromUrlPayload, LinearError> {
+        crate::generated::mutations::image_upload_from_url(self, url).await
+    }
+    /// `INTERNAL` Permanently delete an uploaded file by asset URL. This should be used as a last resort and will break comments and documents that reference the asset.
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub async fn file_upload_dangerously_delete(
+        &self,
+        asset_url: String,
+    ) -> Result<super::types::FileUploadDeletePayload, LinearError> {
+        crate::generated::mutations::file_upload_dangerously_delete(self, asset_url).await
+    }
+    /// Creates a new state, adding it to the workflow of a team.
+    ///
+    /// Full type: [`WorkflowState`](super::types::WorkflowState)
+    ///
+    /// Requires the `teams` feature.
+    #[cfg(feature = "teams")]
+    pub async fn workflow_state_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::WorkflowState>,
+    >(
+        &self,
+        input: WorkflowStateCreateInput,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::workflow_state_create::<T>(self, input).await
+    }
+    /// Updates a state.
+    ///
+    /// Full type: [`WorkflowState`](super::types::WorkflowState)
+    ///
+    /// Requires the `teams` feature.
+    #[cfg(feature = "teams")]
+    pub async fn workflow_state_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::WorkflowState>,
+    >(
+        &self,
+        input: WorkflowStateUpdateInput,
+        id: impl Into<super::ids::WorkflowStateId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::workflow_state_update::<T>(self, input, id).await
+    }
+    /// Archives a state. Only states with issues that have all been archived can be archived.
+    ///
+    /// Full type: [`WorkflowState`](super::types::WorkflowState)
+    ///
+    /// Requires the `teams` feature.
+    #[cfg(feature = "teams")]
+    pub async fn workflow_state_archive<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::WorkflowState>,
+    >(
+        &self,
+        id: impl Into<super::ids::WorkflowStateId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::workflow_state_archive::<T>(self, id).await
+    }
+    /// Creates a new webhook subscription for the workspace. Requires specifying a URL, resource types to subscribe to, and either a specific team or all public teams.
+    ///
+    /// Full type: [`Webhook`](super::types::Webhook)
+    ///
+    /// Requires the `webhooks` feature.
+    #[cfg(feature = "webhooks")]
+    pub async fn webhook_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Webhook>,
+    >(
+        &self,
+        input: WebhookCreateInput,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::webhook_create::<T>(self, input).await
+    }
+    /// Updates an existing Webhook.
+    ///
+    /// Full type: [`Webhook`](super::types::Webhook)
+    ///
+    /// Requires the `webhooks` feature.
+    #[cfg(feature = "webhooks")]
+    pub async fn webhook_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Webhook>,
+    >(
+        &self,
+        input: WebhookUpdateInput,
+        id: impl Into<super::ids::WebhookId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::webhook_update::<T>(self, input, id).await
+    }
+    /// Deletes a Webhook.
+    ///
+    /// Requires the `webhooks` feature.
+    #[cfg(feature = "webhooks")]
+    pub async fn webhook_delete(
+        &self,
+        id: impl Into<super::ids::WebhookId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        crate::generated::mutations::webhook_delete(self, id).await
+    }
+    /// Rotates the signing secret for a webhook, generating a new HMAC-SHA256 key and returning it. The old secret is immediately invalidated.
+    ///
+    /// Requires the `webhooks` feature.
+    #[cfg(feature = "webhooks")]
+    pub async fn webhook_rotate_secret(
+        &self,
+        id: impl Into<super::ids::WebhookId>,
+    ) -> Result<super::types::WebhookRotateSecretPayload, LinearError> {
+        crate::generated::mutations::webhook_rotate_secret(self, id).await
+    }
+    /// Creates a new view preferences object. If conflicting preferences already exist for the same view type and scope, the existing preferences are replaced.
+    ///
+    /// Full type: [`ViewPreferences`](super::types::ViewPreferences)
+    ///
+    /// Requires the `views` feature.
+    #[cfg(feature = "views")]
+    pub async fn view_preferences_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::ViewPreferences>,
+    >(
+        &self,
+        input: ViewPreferencesCreateInput,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::view_preferences_create::<T>(self, input).await
+    }
+    /// Updates an existing view preferences object. For user-type preferences, only the owning user can update them.
+    ///
+    /// Full type: [`ViewPreferences`](super::types::ViewPreferences)
+    ///
+    /// Requires the `views` feature.
+    #[cfg(feature = "views")]
+    pub async fn view_preferences_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::ViewPreferences>,
+    >(
+        &self,
+        input: ViewPreferencesUpdateInput,
+        id: impl Into<super::ids::ViewPreferencesId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::view_preferences_update::<T>(self, input, id).await
+    }
+    /// Deletes a view preferences object. If the preferences do not exist, the operation is treated as a successful idempotent deletion.
+    ///
+    /// Requires the `views` feature.
+    #[cfg(feature = "views")]
+    pub async fn view_preferences_delete(
+        &self,
+        id: impl Into<super::ids::ViewPreferencesId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        crate::generated::mutations::view_preferences_delete(self, id).await
+    }
+    /// Updates the authenticated user's settings, including notification preferences, email subscriptions, theme, and other UI preferences.
+    ///
+    /// Full type: [`UserSettings`](super::types::UserSettings)
+    ///
+    /// Requires the `users` feature.
+    #[cfg(feature = "users")]
+    pub async fn user_settings_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::UserSettings>,
+    >(
+        &self,
+        input: UserSettingsUpdateInput,
+        id: impl Into<super::ids::UserSettingsId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::user_settings_update::<T>(self, input, id).await
+    }
+    /// Resets one or more of the user's setting flags. If no specific flags are provided, all flags are reset.
+    ///
+    /// Requires the `users` feature.
+    #[cfg(feature = "users")]
+    pub async fn user_settings_flags_reset(
+        &self,
+        flags: Option<Vec<UserFlagType>>,
+    ) -> Result<super::types::UserSettingsFlagsResetPayload, LinearError> {
+        crate::generated::mutations::user_settings_flags_reset(self, flags).await
+    }
+    /// Updates a specific user settings flag by performing an operation (e.g., increment or clear) on it.
+    ///
+    /// Requires the `users` feature.
+    #[cfg(feature = "users")]
+    pub async fn user_flag_update(
+        &self,
+        operation: UserFlagUpdateOperation,
+        flag: UserFlagType,
+    ) -> Result<super::types::UserSettingsFlagPayload, LinearError> {
+        crate::generated::mutations::user_flag_update(self, operation, flag).await
+    }
+    /// Subscribes to or unsubscribes from a specific notification category for a given notification channel. For example, subscribe to 'issueAssignment' notifications via the 'email' channel.
+    ///
+    /// Full type: [`UserSettings`](super::types::UserSettings)
+    ///
+    /// Requires the `notifications` feature.
+    #[cfg(feature = "notifications")]
+    pub async fn notification_category_channel_subscription_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::UserSettings>,
+    >(
+        &self,
+        channel: NotificationChannel,
+        category: NotificationCategory,
+        subscribe: bool,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::notification_category_channel_subscription_update::<T>(
+            self, channel, category, subscribe,
+        )
+        .await
+    }
+    /// Updates a user's profile information. Users can update their own profile; workspace admins can update any user's profile. SCIM-managed users may have restricted name changes.
+    ///
+    /// Full type: [`User`](super::types::User)
+    ///
+    /// Requires the `users` feature.
+    #[cfg(feature = "users")]
+    pub async fn user_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::User>,
+    >(
+        &self,
+        input: UserUpdateInput,
+        id: impl Into<super::ids::UserId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::user_update::<T>(self, input, id).await
+    }
+    /// Connects the Discord user to this Linear account via OAuth2.
+    ///
+    /// Full type: [`User`](super::types::User)
+    ///
+    /// Requires the `users` feature.
+    #[cfg(feature = "users")]
+    pub async fn user_discord_connect<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::User>,
+    >(
+        &self,
+        redirect_uri: String,
+        code: String,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::user_discord_connect::<T>(self, redirect_uri, code).await
+    }
+    /// Disconnects the external user from this Linear account.
+    ///
+    /// Full type: [`User`](super::types::User)
+    ///
+    /// Requires the `users` feature.
+    #[cfg(feature = "users")]
+    pub async fn user_external_user_disconnect<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::User>,
+    >(
+        &self,
+        service: String,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::user_external_user_disconnect::<T>(self, service).await
+    }
+    /// Changes the workspace role of a user. The requesting user must have a role equal to or higher than the target role. Requires workspace admin permissions.
+    ///
+    /// Requires the `users` feature.
+    #[cfg(feature = "users")]
+    pub async fn user_change_role(
+        &self,
+        role: UserRoleType,
+        id: impl Into<super::ids::UserId>,
+    ) -> Result<super::types::UserAdminPayload, LinearError> {
+        crate::generated::mutations::user_change_role(self, role, id).await
+    }
+    /// Suspends a user, deactivating their account and revoking access to the workspace. The suspended user's sessions are invalidated. Can only be called by a workspace admin or owner.
+    ///
+    /// Requires the `users` feature.
+    #[cfg(feature = "users")]
+    pub async fn user_suspend(
+        &self,
+        force_bypass_scim_restrictions: Option<bool>,
+        id: impl Into<super::ids::UserId>,
+    ) -> Result<super::types::UserAdminPayload, LinearError> {
+        crate::generated::mutations::user_suspend(self, force_bypass_scim_restrictions, id).await
+    }
+    /// Revokes all active sessions for a user, forcing them to re-authenticate. Can only be called by a workspace admin or owner.
+    ///
+    /// Requires the `users` feature.
+    #[cfg(feature = "users")]
+    pub async fn user_revoke_all_sessions(
+        &self,
+        id: impl Into<super::ids::UserId>,
+    ) -> Result<super::types::UserAdminPayload, LinearError> {
+        crate::generated::mutations::user_revoke_all_sessions(self, id).await
+    }
+    /// Revokes a specific authentication session for a user. The admin cannot revoke their own sessions with this mutation. Can only be called by a workspace admin or owner.
+    ///
+    /// Requires the `users` feature.
+    #[cfg(feature = "users")]
+    pub async fn user_revoke_session(
+        &self,
+        session_id: String,
+        id: impl Into<super::ids::UserId>,
+    ) -> Result<super::types::UserAdminPayload, LinearError> {
+        crate::generated::mutations::user_revoke_session(self, session_id, id).await
+    }
+    /// Re-activates a suspended user, restoring their access to the workspace. Can only be called by a workspace admin or owner.
+    ///
+    /// Requires the `users` feature.
+    #[cfg(feature = "users")]
+    pub async fn user_unsuspend(
+        &self,
+        force_bypass_scim_restrictions: Option<bool>,
+        id: impl Into<super::ids::UserId>,
+    ) -> Result<super::types::UserAdminPayload, LinearError> {
+        crate::generated::mutations::user_unsuspend(self, force_bypass_scim_restrictions, id).await
+    }
+    /// Unlinks a guest user from their identity provider. Can only be called by an admin when SCIM is enabled.
+    ///
+    /// Requires the `users` feature.
+    #[cfg(feature = "users")]
+    pub async fn user_unlink_from_identity_provider(
+        &self,
+        id: impl Into<super::ids::UserId>,
+    ) -> Result<super::types::UserAdminPayload, LinearError> {
+        crate::generated::mutations::user_unlink_from_identity_provider(self, id).await
+    }
+    /// Creates a new triage responsibility.
+    ///
+    /// Full type: [`TriageResponsibility`](super::types::TriageResponsibility)
+    ///
+    /// Requires the `teams` feature.
+    #[cfg(feature = "teams")]
+    pub async fn triage_responsibility_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::TriageResponsibility>,
+    >(
+        &self,
+        input: TriageResponsibilityCreateInput,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::triage_responsibility_create::<T>(self, input).await
+    }
+    /// Updates an existing triage responsibility.
+    ///
+    /// Full type: [`TriageResponsibility`](super::types::TriageResponsibility)
+    ///
+    /// Requires the `teams` feature.
+    #[cfg(feature = "teams")]
+    pub async fn triage_responsibility_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::TriageResponsibility>,
+    >(
+        &self,
+        input: TriageResponsibilityUpdateInput,
+        id: impl Into<super::ids::TriageResponsibilityId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::triage_responsibility_update::<T>(self, input, id).await
+    }
+    /// Deletes a triage responsibility.
+    ///
+    /// Requires the `teams` feature.
+    #[cfg(feature = "teams")]
+    pub async fn triage_responsibility_delete(
+        &self,
+        id: impl Into<super::ids::TriageResponsibilityId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        crate::generated::mutations::triage_responsibility_delete(self, id).await
+    }
+    /// Creates a new time schedule.
+    ///
+    /// Full type: [`TimeSchedule`](super::types::TimeSchedule)
+    ///
+    /// Requires the `teams` feature.
+    #[cfg(feature = "teams")]
+    pub async fn time_schedule_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::TimeSchedule>,
+    >(
+        &self,
+        input: TimeScheduleCreateInput,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::time_schedule_create::<T>(self, input).await
+    }
+    /// Updates a time schedule.
+    ///
+    /// Full type: [`TimeSchedule`](super::types::TimeSchedule)
+    ///
+    /// Requires the `teams` feature.
+    #[cfg(feature = "teams")]
+    pub async fn time_schedule_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::TimeSchedule>,
+    >(
+        &self,
+        input: TimeScheduleUpdateInput,
+        id: impl Into<super::ids::TimeScheduleId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::time_schedule_update::<T>(self, input, id).await
+    }
+    /// Upsert an external time schedule.
+    ///
+    /// Full type: [`TimeSchedule`](super::types::TimeSchedule)
+    ///
+    /// Requires the `teams` feature.
+    #[cfg(feature = "teams")]
+    pub async fn time_schedule_upsert_external<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::TimeSchedule>,
+    >(
+        &self,
+        input: TimeScheduleUpdateInput,
+        external_id: String,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::time_schedule_upsert_external::<T>(self, input, external_id)
+            .await
+    }
+    /// Deletes a time schedule.
+    ///
+    /// Requires the `teams` feature.
+    #[cfg(feature = "teams")]
+    pub async fn time_schedule_delete(
+        &self,
+        id: impl Into<super::ids::TimeScheduleId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        crate::generated::mutations::time_schedule_delete(self, id).await
+    }
+    /// Refresh the integration schedule information.
+    ///
+    /// Full type: [`TimeSchedule`](super::types::TimeSchedule)
+    ///
+    /// Requires the `teams` feature.
+    #[cfg(feature = "teams")]
+    pub async fn time_schedule_refresh_integration_schedule<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::TimeSchedule>,
+    >(
+        &self,
+        id: impl Into<super::ids::TimeScheduleId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::time_schedule_refresh_integration_schedule::<T>(self, id).await
+    }
+    /// Creates a new template.
+    ///
+    /// Full type: [`Template`](super::types::Template)
+    ///
+    /// Requires the `teams` feature.
+    #[cfg(feature = "teams")]
+    pub async fn template_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Template>,
+    >(
+        &self,
+        input: TemplateCreateInput,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::template_create::<T>(self, input).await
+    }
+    /// Updates an existing template.
+    ///
+    /// Full type: [`Template`](super::types::Template)
+    ///
+    /// Requires the `teams` feature.
+    #[cfg(feature = "teams")]
+    pub async fn template_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Template>,
+    >(
+        &self,
+        input: TemplateUpdateInput,
+        id: impl Into<super::ids::TemplateId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::template_update::<T>(self, input, id).await
+    }
+    /// Deletes a template.
+    ///
+    /// Requires the `teams` feature.
+    #[cfg(feature = "teams")]
+    pub async fn template_delete(
+        &self,
+        id: impl Into<super::ids::TemplateId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        crate::generated::mutations::template_delete(self, id).await
+    }
+    /// Creates a new project.
+    ///
+    /// Full type: [`Project`](super::types::Project)
+    pub async fn project_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Project>,
+    >(
+        &self,
+        slack_channel_name: Option<String>,
+        input: ProjectCreateInput,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::project_create::<T>(self, slack_channel_name, input).await
+    }
+    /// Updates a project.
+    ///
+    /// Full type: [`Project`](super::types::Project)
+    pub async fn project_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Project>,
+    >(
+        &self,
+        input: ProjectUpdateInput,
+        id: impl Into<super::ids::ProjectId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::project_update::<T>(self, input, id).await
+    }
+    /// `Internal` Creates a Slack channel for an existing project.
+    ///
+    /// Full type: [`Project`](super::types::Project)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub async fn project_create_slack_channel<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Project>,
+    >(
+        &self,
+        integration_id: Option<String>,
+        slack_channel_name: String,
+        id: impl Into<super::ids::ProjectId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::project_create_slack_channel::<T>(
+            self,
+            integration_id,
+            slack_channel_name,
+            id,
+        )
+        .await
+    }
+    /// `INTERNAL` Reassigns all projects from one project status to another. Used when archiving or deleting a project status.
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub async fn project_reassign_status(
+        &self,
+        new_project_status_id: String,
+        original_project_status_id: String,
+    ) -> Result<super::types::SuccessPayload, LinearError> {
+        crate::generated::mutations::project_reassign_status(
+            self,
+            new_project_status_id,
+            original_project_status_id,
+        )
+        .await
+    }
+    /// Deletes (trashes) a project. The project can be restored later with projectUnarchive.
+    ///
+    /// Full type: [`Project`](super::types::Project)
+    pub async fn project_delete<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Project>,
+    >(
+        &self,
+        id: impl Into<super::ids::ProjectId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::project_delete::<T>(self, id).await
+    }
+    /// Archives a project.
+    #[deprecated(note = "Deprecated in favor of projectDelete.")]
+    ///
+    /// Full type: [`Project`](super::types::Project)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub async fn project_archive<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Project>,
+    >(
+        &self,
+        trash: Option<bool>,
+        id: impl Into<super::ids::ProjectId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::project_archive::<T>(self, trash, id).await
+    }
+    /// Restores a previously trashed or archived project.
+    ///
+    /// Full type: [`Project`](super::types::Project)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub async fn project_unarchive<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Project>,
+    >(
+        &self,
+        id: impl Into<super::ids::ProjectId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::project_unarchive::<T>(self, id).await
+    }
+    /// Adds a label to a project.
+    ///
+    /// Full type: [`Project`](super::types::Project)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub async fn project_add_label<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Project>,
+    >(
+        &self,
+        label_id: String,
+        id: impl Into<super::ids::ProjectId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::project_add_label::<T>(self, label_id, id).await
+    }
+    /// Removes a label from a project.
+    ///
+    /// Full type: [`Project`](super::types::Project)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub async fn project_remove_label<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Project>,
+    >(
+        &self,
+        label_id: String,
+        id: impl Into<super::ids::ProjectId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::project_remove_label::<T>(self, label_id, id).await
+    }
+    /// Disables external sync on a project.
+    ///
+    /// Full type: [`Project`](super::types::Project)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub async fn project_external_sync_disable<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Project>,
+    >(
+        &self,
+        sync_source: ExternalSyncService,
+        project_id: String,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::project_external_sync_disable::<T>(
+            self,
+            sync_source,
+            project_id,
+        )
+        .await
+    }
+    /// Creates a new team. The user who creates the team will automatically be added as a member and owner of the newly created team. Default workflow states, labels, and other team resources are created alongside the team.
+    ///
+    /// Full type: [`Team`](super::types::Team)
+    pub async fn team_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Team>,
+    >(
+        &self,
+        copy_settings_from_team_id: Option<String>,
+        input: TeamCreateInput,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::team_create::<T>(self, copy_settings_from_team_id, input).await
+    }
+    /// Updates a team's settings, properties, or configuration. Requires team owner or workspace admin permissions for most changes.
+    ///
+    /// Full type: [`Team`](super::types::Team)
+    pub async fn team_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Team>,
+    >(
+        &self,
+        mapping: Option<InheritanceEntityMapping>,
+        input: TeamUpdateInput,
+        id: impl Into<super::ids::TeamId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::team_update::<T>(self, mapping, input, id).await
+    }
+    /// Archives a team and schedules its data for deletion. Requires team owner or workspace admin permissions.
+    pub async fn team_delete(
+        &self,
+        id: impl Into<super::ids::TeamId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        crate::generated::mutations::team_delete(self, id).await
+    }
+    /// Unarchives a team and cancels deletion.
+    ///
+    /// Full type: [`Team`](super::types::Team)
+    ///
+    /// Requires the `teams` feature.
+    #[cfg(feature = "teams")]
+    pub async fn team_unarchive<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Team>,
+    >(
+        &self,
+        id: impl Into<super::ids::TeamId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::team_unarchive::<T>(self, id).await
+    }
+    /// Deletes all cycle data for a team, disabling the cycles feature. This removes all cycles and their issue associations.
+    ///
+    /// Full type: [`Team`](super::types::Team)
+    ///
+    /// Requires the `teams` feature.
+    #[cfg(feature = "teams")]
+    pub async fn team_cycles_delete<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Team>,
+    >(
+        &self,
+        id: impl Into<super::ids::TeamId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::team_cycles_delete::<T>(self, id).await
+    }
+    /// Creates a new team membership, adding a user to a team. Validates that the user is not already a member, the team is not archived or retired, and the requesting user has permission to add members.
+    ///
+    /// Full type: [`TeamMembership`](super::types::TeamMembership)
+    pub async fn team_membership_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::TeamMembership>,
+    >(
+        &self,
+        input: TeamMembershipCreateInput,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::team_membership_create::<T>(self, input).await
+    }
+    /// Updates a team membership, such as changing ownership status or sort order.
+    ///
+    /// Full type: [`TeamMembership`](super::types::TeamMembership)
+    ///
+    /// Requires the `teams` feature.
+    #[cfg(feature = "teams")]
+    pub async fn team_membership_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::TeamMembership>,
+    >(
+        &self,
+        input: TeamMembershipUpdateInput,
+        id: impl Into<super::ids::TeamMembershipId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::team_membership_update::<T>(self, input, id).await
+    }
+    /// Deletes a team membership, removing the user from the team. Users can remove their own membership, or team owners and workspace admins can remove other members.
+    pub async fn team_membership_delete(
+        &self,
+        also_leave_parent_teams: Option<bool>,
+        id: impl Into<super::ids::TeamMembershipId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        crate::generated::mutations::team_membership_delete(self, also_leave_parent_teams, id).await
+    }
+    /// Deletes a previously used team key. The active team key (the team's current key) cannot be deleted.
+    ///
+    /// Requires the `teams` feature.
+    #[cfg(feature = "teams")]
+    pub async fn team_key_delete(
+        &self,
+        id: impl Into<super::ids::TeamId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        crate::generated::mutations::team_key_delete(self, id).await
+    }
+    /// `Deprecated` Creates a new roadmap-to-project association. Use InitiativeToProject instead.
+    ///
+    /// Full type: [`RoadmapToProject`](super::types::RoadmapToProject)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub async fn roadmap_to_project_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::RoadmapToProject>,
+    >(
+        &self,
+        input: RoadmapToProjectCreateInput,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::roadmap_to_project_create::<T>(self, input).await
+    }
+    /// `Deprecated` Updates a roadmap-to-project association. Use InitiativeToProject instead.
+    ///
+    /// Full type: [`RoadmapToProject`](super::types::RoadmapToProject)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub async fn roadmap_to_project_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::RoadmapToProject>,
+    >(
+        &self,
+        input: RoadmapToProjectUpdateInput,
+        id: impl Into<super::ids::RoadmapToProjectId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::roadmap_to_project_update::<T>(self, input, id).await
+    }
+    /// `Deprecated` Deletes a roadmap-to-project association. Use InitiativeToProject instead.
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub async fn roadmap_to_project_delete(
+        &self,
+        id: impl Into<super::ids::RoadmapToProjectId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        crate::generated::mutations::roadmap_to_project_delete(self, id).await
+    }
+    /// Creates a new roadmap.
+    #[deprecated(note = "Roadmaps are deprecated, use initiatives instead.")]
+    ///
+    /// Full type: [`Roadmap`](super::types::Roadmap)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub async fn roadmap_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Roadmap>,
+    >(
+        &self,
+        input: RoadmapCreateInput,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::roadmap_create::<T>(self, input).await
+    }
+    /// Updates a roadmap.
+    #[deprecated(note = "Roadmaps are deprecated, use initiatives instead.")]
+    ///
+    /// Full type: [`Roadmap`](super::types::Roadmap)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub async fn roadmap_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Roadmap>,
+    >(
+        &self,
+        input: RoadmapUpdateInput,
+        id: impl Into<super::ids::RoadmapId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::roadmap_update::<T>(self, input, id).await
+    }
+    /// Archives a roadmap.
+    #[deprecated(note = "Roadmaps are deprecated, use initiatives instead.")]
+    ///
+    /// Full type: [`Roadmap`](super::types::Roadmap)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub async fn roadmap_archive<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Roadmap>,
+    >(
+        &self,
+        id: impl Into<super::ids::RoadmapId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::roadmap_archive::<T>(self, id).await
+    }
+    /// Unarchives a roadmap.
+    #[deprecated(note = "Roadmaps are deprecated, use initiatives instead.")]
+    ///
+    /// Full type: [`Roadmap`](super::types::Roadmap)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub async fn roadmap_unarchive<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Roadmap>,
+    >(
+        &self,
+        id: impl Into<super::ids::RoadmapId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::roadmap_unarchive::<T>(self, id).await
+    }
+    /// Deletes a roadmap.
+    #[deprecated(note = "Roadmaps are deprecated, use initiatives instead.")]
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub async fn roadmap_delete(
+        &self,
+        id: impl Into<super::ids::RoadmapId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        crate::generated::mutations::roadmap_delete(self, id).await
+    }
+    /// Creates a CSV export report for the workspace. The report is generated asynchronously and delivered via email. Requires workspace admin export permission.
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub async fn create_csv_export_report(
+        &self,
+        include_private_team_ids: Option<Vec<String>>,
+    ) -> Result<super::types::CreateCsvExportReportPayload, LinearError> {
+        crate::generated::mutations::create_csv_export_report(self, include_private_team_ids).await
+    }
+    /// `ALPHA` Creates a new release stage in a pipeline. Non-started stages must use default names and colors, and only one stage of each non-started type is allowed per pipeline. Started stages can optionally be frozen, but at least one non-frozen started stage must remain.
+    ///
+    /// Full type: [`ReleaseStage`](super::types::ReleaseStage)
+    ///
+    /// Requires the `releases` feature.
+    #[cfg(feature = "releases")]
+    pub async fn release_stage_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::ReleaseStage>,
+    >(
+        &self,
+        input: ReleaseStageCreateInput,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::release_stage_create::<T>(self, input).await
+    }
+    /// `ALPHA` Updates an existing release stage. Only started-type stages can be edited. Supports updating name, color, position, and frozen status.
+    ///
+    /// Full type: [`ReleaseStage`](super::types::ReleaseStage)
+    ///
+    /// Requires the `releases` feature.
+    #[cfg(feature = "releases")]
+    pub async fn release_stage_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::ReleaseStage>,
+    >(
+        &self,
+        input: ReleaseStageUpdateInput,
+        id: impl Into<super::ids::ReleaseStageId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::release_stage_update::<T>(self, input, id).await
+    }
+    /// `ALPHA` Archives a release stage. Only started-type stages can be archived, and only if they have no active releases and at least one other stage of the same type remains. Cannot archive the last non-frozen started stage.
+    ///
+    /// Full type: [`ReleaseStage`](super::types::ReleaseStage)
+    ///
+    /// Requires the `releases` feature.
+    #[cfg(feature = "releases")]
+    pub async fn release_stage_archive<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::ReleaseStage>,
+    >(
+        &self,
+        id: impl Into<super::ids::ReleaseStageId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::release_stage_archive::<T>(self, id).await
+    }
+    /// `ALPHA` Unarchives a release stage.
+    ///
+    /// Full type: [`ReleaseStage`](super::types::ReleaseStage)
+    ///
+    /// Requires the `releases` feature.
+    #[cfg(feature = "releases")]
+    pub async fn release_stage_unarchive<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::ReleaseStage>,
+    >(
+        &self,
+        id: impl Into<super::ids::ReleaseStageId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::release_stage_unarchive::<T>(self, id).await
+    }
+    /// `ALPHA` Creates a new release pipeline with default stages. Subject to plan entitlement and quota limits.
+    ///
+    /// Full type: [`ReleasePipeline`](super::types::ReleasePipeline)
+    ///
+    /// Requires the `releases` feature.
+    #[cfg(feature = "releases")]
+    pub async fn release_pipeline_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::ReleasePipeline>,
+    >(
+        &self,
+        input: ReleasePipelineCreateInput,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::release_pipeline_create::<T>(self, input).await
+    }
+    /// `ALPHA` Updates an existing release pipeline. Supports updating name, slug, type, production flag, path patterns, and team associations. Private teams that the current user cannot access are preserved in the team list.
+    ///
+    /// Full type: [`ReleasePipeline`](super::types::ReleasePipeline)
+    ///
+    /// Requires the `releases` feature.
+    #[cfg(feature = "releases")]
+    pub async fn release_pipeline_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::ReleasePipeline>,
+    >(
+        &self,
+        input: ReleasePipelineUpdateInput,
+        id: impl Into<super::ids::ReleasePipelineId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::release_pipeline_update::<T>(self, input, id).await
+    }
+    /// `ALPHA` Archives a release pipeline.
+    ///
+    /// Full type: [`ReleasePipeline`](super::types::ReleasePipeline)
+    ///
+    /// Requires the `releases` feature.
+    #[cfg(feature = "releases")]
+    pub async fn release_pipeline_archive<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::ReleasePipeline>,
+    >(
+        &self,
+        id: impl Into<super::ids::ReleasePipelineId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::release_pipeline_archive::<T>(self, id).await
+    }
+    /// `ALPHA` Unarchives a release pipeline.
+    ///
+    /// Full type: [`ReleasePipeline`](super::types::ReleasePipeline)
+    ///
+    /// Requires the `releases` feature.
+    #[cfg(feature = "releases")]
+    pub async fn release_pipeline_unarchive<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::ReleasePipeline>,
+    >(
+        &self,
+        id: impl Into<super::ids::ReleasePipelineId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::release_pipeline_unarchive::<T>(self, id).await
+    }
+    /// `ALPHA` Permanently deletes a release pipeline and all associated stages and releases.
+    ///
+    /// Requires the `releases` feature.
+    #[cfg(feature = "releases")]
+    pub async fn release_pipeline_delete(
+        &self,
+        id: impl Into<super::ids::ReleasePipelineId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        crate::generated::mutations::release_pipeline_delete(self, id).await
+    }
+    /// `ALPHA` Syncs release data by resolving issue and pull request references and associating them with a release. For continuous pipelines, creates a new completed release. For scheduled pipelines, finds or creates a started release and accumulates issues into it.
+    ///
+    /// Full type: [`Release`](super::types::Release)
+    ///
+    /// Requires the `releases` feature.
+    #[cfg(feature = "releases")]
+    pub async fn release_sync<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Release>,
+    >(
+        &self,
+        input: ReleaseSyncInput,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::release_sync::<T>(self, input).await
+    }
+    /// `ALPHA` Creates a new release in a pipeline. If no stage is specified, defaults to the first completed stage for continuous pipelines or the first started stage for scheduled pipelines.
+    ///
+    /// Full type: [`Release`](super::types::Release)
+    ///
+    /// Requires the `releases` feature.
+    #[cfg(feature = "releases")]
+    pub async fn release_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Release>,
+    >(
+        &self,
+        input: ReleaseCreateInput,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::release_create::<T>(self, input).await
+    }
+    /// `ALPHA` Updates an existing release by ID. Supports updating name, description, version, commit SHA, pipeline, stage, and dates.
+    ///
+    /// Full type: [`Release`](super::types::Release)
+    ///
+    /// Requires the `releases` feature.
+    #[cfg(feature = "releases")]
+    pub async fn release_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Release>,
+    >(
+        &self,
+        input: ReleaseUpdateInput,
+        id: impl Into<super::ids::ReleaseId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::release_update::<T>(self, input, id).await
+    }
+    /// `ALPHA` Marks a release as completed. If version is provided, completes that specific release; otherwise completes the most recent started release.
+    ///
+    /// Full type: [`Release`](super::types::Release)
+    ///
+    /// Requires the `releases` feature.
+    #[cfg(feature = "releases")]
+    pub async fn release_complete<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Release>,
+    >(
+        &self,
+        input: ReleaseCompleteInput,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::release_complete::<T>(self, input).await
+    }
+    /// `ALPHA` Updates a release by pipeline identifier. Finds the release by version or latest started/planned release, and optionally transitions it to a new stage by name.
+    ///
+    /// Full type: [`Release`](super::types::Release)
+    ///
+    /// Requires the `releases` feature.
+    #[cfg(feature = "releases")]
+    pub async fn release_update_by_pipeline<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Release>,
+    >(
+        &self,
+        input: ReleaseUpdateByPipelineInput,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::release_update_by_pipeline::<T>(self, input).await
+    }
+    /// `ALPHA` Moves a release to the trash bin. Trashed releases are archived and will be permanently deleted after a retention period. If the release is already archived, it is marked as trashed with a fresh archive timestamp.
+    ///
+    /// Full type: [`Release`](super::types::Release)
+    ///
+    /// Requires the `releases` feature.
+    #[cfg(feature = "releases")]
+    pub async fn release_delete<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Release>,
+    >(
+        &self,
+        id: impl Into<super::ids::ReleaseId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::release_delete::<T>(self, id).await
+    }
+    /// `ALPHA` Archives a release.
+    ///
+    /// Full type: [`Release`](super::types::Release)
+    ///
+    /// Requires the `releases` feature.
+    #[cfg(feature = "releases")]
+    pub async fn release_archive<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Release>,
+    >(
+        &self,
+        id: impl Into<super::ids::ReleaseId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::release_archive::<T>(self, id).await
+    }
+    /// `ALPHA` Unarchives a release.
+    ///
+    /// Full type: [`Release`](super::types::Release)
+    ///
+    /// Requires the `releases` feature.
+    #[cfg(feature = "releases")]
+    pub async fn release_unarchive<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Release>,
+    >(
+        &self,
+        id: impl Into<super::ids::ReleaseId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::release_unarchive::<T>(self, id).await
+    }
+    /// `ALPHA` Syncs release data using an access key for CI/CD integration. The pipeline is automatically inferred from the access key's configured resources, so no pipeline ID is needed in the input.
+    ///
+    /// Full type: [`Release`](super::types::Release)
+    ///
+    /// Requires the `releases` feature.
+    #[cfg(feature = "releases")]
+    pub async fn release_sync_by_access_key<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Release>,
+    >(
+        &self,
+        input: ReleaseSyncInputBase,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::release_sync_by_access_key::<T>(self, input).await
+    }
+    /// `ALPHA` Marks a release as completed using an access key. If version is provided, completes that specific release; otherwise completes the most recent started release. The pipeline is inferred from the access key.
+    ///
+    /// Full type: [`Release`](super::types::Release)
+    ///
+    /// Requires the `releases` feature.
+    #[cfg(feature = "releases")]
+    pub async fn release_complete_by_access_key<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Release>,
+    >(
+        &self,
+        input: ReleaseCompleteInputBase,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::release_complete_by_access_key::<T>(self, input).await
+    }
+    /// `ALPHA` Updates a release by pipeline using an access key.
+    ///
+    /// Full type: [`Release`](super::types::Release)
+    ///
+    /// Requires the `releases` feature.
+    #[cfg(feature = "releases")]
+    pub async fn release_update_by_pipeline_by_access_key<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Release>,
+    >(
+        &self,
+        input: ReleaseUpdateByPipelineInputBase,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::release_update_by_pipeline_by_access_key::<T>(self, input)
+            .await
+    }
+    /// `ALPHA` Creates a release note.
+    ///
+    /// Full type: [`ReleaseNote`](super::types::ReleaseNote)
+    ///
+    /// Requires the `releases` feature.
+    #[cfg(feature = "releases")]
+    pub async fn release_note_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::ReleaseNote>,
+    >(
+        &self,
+        input: ReleaseNoteCreateInput,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::release_note_create::<T>(self, input).await
+    }
+    /// `ALPHA` Updates a release note.
+    ///
+    /// Full type: [`ReleaseNote`](super::types::ReleaseNote)
+    ///
+    /// Requires the `releases` feature.
+    #[cfg(feature = "releases")]
+    pub async fn release_note_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::ReleaseNote>,
+    >(
+        &self,
+        input: ReleaseNoteUpdateInput,
+        id: impl Into<super::ids::ReleaseNoteId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::release_note_update::<T>(self, input, id).await
+    }
+    /// `ALPHA` Deletes a release note.
+    ///
+    /// Requires the `releases` feature.
+    #[cfg(feature = "releases")]
+    pub async fn release_note_delete(
+        &self,
+        id: impl Into<super::ids::ReleaseNoteId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        crate::generated::mutations::release_note_delete(self, id).await
+    }
+    /// Creates a new reaction.
+    ///
+    /// Full type: [`Reaction`](super::types::Reaction)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub async fn reaction_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Reaction>,
+    >(
+        &self,
+        input: ReactionCreateInput,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::reaction_create::<T>(self, input).await
+    }
+    /// Deletes a reaction.
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub async fn reaction_delete(
+        &self,
+        id: impl Into<super::ids::ReactionId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        crate::generated::mutations::reaction_delete(self, id).await
+    }
+    /// Creates a push subscription for the authenticated user's current device or browser. If a subscription already exists for the same session, the old one is replaced.
+    ///
+    /// Full type: [`PushSubscription`](super::types::PushSubscription)
+    ///
+    /// Requires the `notifications` feature.
+    #[cfg(feature = "notifications")]
+    pub async fn push_subscription_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::PushSubscription>,
+    >(
+        &self,
+        input: PushSubscriptionCreateInput,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::push_subscription_create::<T>(self, input).await
+    }
+    /// Deletes a push subscription, unregistering the device from receiving push notifications.
+    ///
+    /// Full type: [`PushSubscription`](super::types::PushSubscription)
+    ///
+    /// Requires the `notifications` feature.
+    #[cfg(feature = "notifications")]
+    pub async fn push_subscription_delete<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::PushSubscription>,
+    >(
+        &self,
+        id: impl Into<super::ids::PushSubscriptionId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::push_subscription_delete::<T>(self, id).await
+    }
+    /// Creates a new project update.
+    ///
+    /// Full type: [`ProjectUpdate`](super::types::ProjectUpdate)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub async fn project_update_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::ProjectUpdate>,
+    >(
+        &self,
+        input: ProjectUpdateCreateInput,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::project_update_create::<T>(self, input).await
+    }
+    /// Updates a project update.
+    ///
+    /// Full type: [`ProjectUpdate`](super::types::ProjectUpdate)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub async fn project_update_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::ProjectUpdate>,
+    >(
+        &self,
+        input: ProjectUpdateUpdateInput,
+        id: impl Into<super::ids::ProjectUpdateId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::project_update_update::<T>(self, input, id).await
+    }
+    /// Archives a project update.
+    ///
+    /// Full type: [`ProjectUpdate`](super::types::ProjectUpdate)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub async fn project_update_archive<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::ProjectUpdate>,
+    >(
+        &self,
+        id: impl Into<super::ids::ProjectUpdateId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::project_update_archive::<T>(self, id).await
+    }
+    /// Unarchives a project update.
+    ///
+    /// Full type: [`ProjectUpdate`](super::types::ProjectUpdate)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub async fn project_update_unarchive<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::ProjectUpdate>,
+    >(
+        &self,
+        id: impl Into<super::ids::ProjectUpdateId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::project_update_unarchive::<T>(self, id).await
+    }
+    /// Deletes a project update.
+    #[deprecated(note = "Use `projectUpdateArchive` instead.")]
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub async fn project_update_delete(
+        &self,
+        id: impl Into<super::ids::ProjectUpdateId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        crate::generated::mutations::project_update_delete(self, id).await
+    }
+    /// Create a notification to remind a user about a project update.
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub async fn create_project_update_reminder(
+        &self,
+        user_id: Option<String>,
+        project_id: String,
+    ) -> Result<super::types::ProjectUpdateReminderPayload, LinearError> {
+        crate::generated::mutations::create_project_update_reminder(self, user_id, project_id).await
+    }
+    /// Creates a new project status.
+    ///
+    /// Full type: [`ProjectStatus`](super::types::ProjectStatus)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub async fn project_status_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::ProjectStatus>,
+    >(
+        &self,
+        input: ProjectStatusCreateInput,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::project_status_create::<T>(self, input).await
+    }
+    /// Updates a project status.
+    ///
+    /// Full type: [`ProjectStatus`](super::types::ProjectStatus)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub async fn project_status_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::ProjectStatus>,
+    >(
+        &self,
+        input: ProjectStatusUpdateInput,
+        id: impl Into<super::ids::ProjectStatusId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::project_status_update::<T>(self, input, id).await
+    }
+    /// Archives a project status. The status must not have any active projects assigned to it and must not be the last status of its type.
+    ///
+    /// Full type: [`ProjectStatus`](super::types::ProjectStatus)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub async fn project_status_archive<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::ProjectStatus>,
+    >(
+        &self,
+        id: impl Into<super::ids::ProjectStatusId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::project_status_archive::<T>(self, id).await
+    }
+    /// Unarchives a project status.
+    ///
+    /// Full type: [`ProjectStatus`](super::types::ProjectStatus)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub async fn project_status_unarchive<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::ProjectStatus>,
+    >(
+        &self,
+        id: impl Into<super::ids::ProjectStatusId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::project_status_unarchive::<T>(self, id).await
+    }
+    /// Creates a new project relation.
+    ///
+    /// Full type: [`ProjectRelation`](super::types::ProjectRelation)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub async fn project_relation_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::ProjectRelation>,
+    >(
+        &self,
+        input: ProjectRelationCreateInput,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::project_relation_create::<T>(self, input).await
+    }
+    /// Updates a project relation.
+    ///
+    /// Full type: [`ProjectRelation`](super::types::ProjectRelation)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub async fn project_relation_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::ProjectRelation>,
+    >(
+        &self,
+        input: ProjectRelationUpdateInput,
+        id: impl Into<super::ids::ProjectRelationId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::project_relation_update::<T>(self, input, id).await
+    }
+    /// Deletes a project relation.
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub async fn project_relation_delete(
+        &self,
+        id: impl Into<super::ids::ProjectRelationId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        crate::generated::mutations::project_relation_delete(self, id).await
+    }
+    /// Creates a new project milestone.
+    ///
+    /// Full type: [`ProjectMilestone`](super::types::ProjectMilestone)
+    pub async fn project_milestone_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::ProjectMilestone>,
+    >(
+        &self,
+        input: ProjectMilestoneCreateInput,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::project_milestone_create::<T>(self, input).await
+    }
+    /// Updates a project milestone.
+    ///
+    /// Full type: [`ProjectMilestone`](super::types::ProjectMilestone)
+    pub async fn project_milestone_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::ProjectMilestone>,
+    >(
+        &self,
+        input: ProjectMilestoneUpdateInput,
+        id: impl Into<super::ids::ProjectMilestoneId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::project_milestone_update::<T>(self, input, id).await
+    }
+    /// Deletes a project milestone.
+    pub async fn project_milestone_delete(
+        &self,
+        id: impl Into<super::ids::ProjectMilestoneId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        crate::generated::mutations::project_milestone_delete(self, id).await
+    }
+    /// `Internal` Moves a project milestone to another project, can be called to undo a prior move.
+    ///
+    /// Full type: [`ProjectMilestone`](super::types::ProjectMilestone)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub async fn project_milestone_move<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::ProjectMilestone>,
+    >(
+        &self,
+        input: ProjectMilestoneMoveInput,
+        id: impl Into<super::ids::ProjectMilestoneId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::project_milestone_move::<T>(self, input, id).await
+    }
+    /// Creates a new project label.
+    ///
+    /// Full type: [`ProjectLabel`](super::types::ProjectLabel)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub async fn project_label_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::ProjectLabel>,
+    >(
+        &self,
+        input: ProjectLabelCreateInput,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::project_label_create::<T>(self, input).await
+    }
+    /// Updates a project label.
+    ///
+    /// Full type: [`ProjectLabel`](super::types::ProjectLabel)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub async fn project_label_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::ProjectLabel>,
+    >(
+        &self,
+        input: ProjectLabelUpdateInput,
+        id: impl Into<super::ids::ProjectLabelId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::project_label_update::<T>(self, input, id).await
+    }
+    /// Deletes a project label.
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub async fn project_label_delete(
+        &self,
+        id: impl Into<super::ids::ProjectLabelId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        crate::generated::mutations::project_label_delete(self, id).await
+    }
+    /// Retires a project label. Retired labels remain on existing projects but cannot be applied to new ones.
+    ///
+    /// Full type: [`ProjectLabel`](super::types::ProjectLabel)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub async fn project_label_retire<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::ProjectLabel>,
+    >(
+        &self,
+        id: impl Into<super::ids::ProjectLabelId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::project_label_retire::<T>(self, id).await
+    }
+    /// Restores a previously retired project label, making it available for use on new projects.
+    ///
+    /// Full type: [`ProjectLabel`](super::types::ProjectLabel)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub async fn project_label_restore<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::ProjectLabel>,
+    >(
+        &self,
+        id: impl Into<super::ids::ProjectLabelId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::project_label_restore::<T>(self, id).await
+    }
+    /// Updates the user's workspace settings. Different settings require different permission levels; most require the workspaceSettings admin permission.
+    ///
+    /// Full type: [`Organization`](super::types::Organization)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub async fn organization_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Organization>,
+    >(
+        &self,
+        input: OrganizationUpdateInput,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::organization_update::<T>(self, input).await
+    }
+    /// Requests a deletion confirmation code for the workspace. The code is sent to the requesting user's email and must be used with the organizationDelete mutation to confirm deletion.
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub async fn organization_delete_challenge(
+        &self,
+    ) -> Result<super::types::OrganizationDeletePayload, LinearError> {
+        crate::generated::mutations::organization_delete_challenge(self).await
+    }
+    /// Permanently deletes the workspace and all its data. Requires a valid deletion code obtained from organizationDeleteChallenge. This action is irreversible.
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub async fn organization_delete(
+        &self,
+        input: DeleteOrganizationInput,
+    ) -> Result<super::types::OrganizationDeletePayload, LinearError> {
+        crate::generated::mutations::organization_delete(self, input).await
+    }
+    /// Cancels a previously requested workspace deletion, if the workspace has not yet been fully deleted.
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub async fn organization_cancel_delete(
+        &self,
+    ) -> Result<super::types::OrganizationCancelDeletePayload, LinearError> {
+        crate::generated::mutations::organization_cancel_delete(self).await
+    }
+    /// Starts a trial for the workspace on the specified plan type. The workspace must not already be on a paid plan or in an active trial.
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub async fn organization_start_trial_for_plan(
+        &self,
+        input: OrganizationStartTrialInput,
+    ) -> Result<super::types::OrganizationStartTrialPayload, LinearError> {
+        crate::generated::mutations::organization_start_trial_for_plan(self, input).await
+    }
+    /// `DEPRECATED` Starts a trial for the workspace.
+    #[deprecated(note = "Use organizationStartTrialForPlan")]
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub async fn organization_start_trial(
+        &self,
+    ) -> Result<super::types::OrganizationStartTrialPayload, LinearError> {
+        crate::generated::mutations::organization_start_trial(self).await
+    }
+    /// Creates a new workspace invite and sends an invitation email to the specified address. The invite includes a role assignment and optional team memberships.
+    ///
+    /// Full type: [`OrganizationInvite`](super::types::OrganizationInvite)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub async fn organization_invite_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::OrganizationInvite>,
+    >(
+        &self,
+        input: OrganizationInviteCreateInput,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::organization_invite_create::<T>(self, input).await
+    }
+    /// Updates an existing workspace invite, such as changing the teams the invitee will be added to.
+    ///
+    /// Full type: [`OrganizationInvite`](super::types::OrganizationInvite)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub async fn organization_invite_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::OrganizationInvite>,
+    >(
+        &self,
+        input: OrganizationInviteUpdateInput,
+        id: impl Into<super::ids::OrganizationInviteId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::organization_invite_update::<T>(self, input, id).await
+    }
+    /// Re-sends a workspace invitation email for the specified invite ID.
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub async fn resend_organization_invite(
+        &self,
+        id: String,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        crate::generated::mutations::resend_organization_invite(self, id).await
+    }
+    /// Re-sends a workspace invitation email to the specified email address, if an outstanding invite exists for that address.
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub async fn resend_organization_invite_by_email(
+        &self,
+        email: String,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        crate::generated::mutations::resend_organization_invite_by_email(self, email).await
+    }
+    /// Deletes (archives) a workspace invite, preventing it from being accepted.
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub async fn organization_invite_delete(
+        &self,
+        id: impl Into<super::ids::OrganizationInviteId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        crate::generated::mutations::organization_invite_delete(self, id).await
+    }
+    /// `INTERNAL` Verifies a domain claim.
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub async fn organization_domain_claim(
+        &self,
+        id: impl Into<super::ids::OrganizationDomainId>,
+    ) -> Result<super::types::OrganizationDomainSimplePayload, LinearError> {
+        crate::generated::mutations::organization_domain_claim(self, id).await
+    }
+    /// `INTERNAL` Verifies a domain to be added to a workspace.
+    ///
+    /// Full type: [`OrganizationDomain`](super::types::OrganizationDomain)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub async fn organization_domain_verify<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::OrganizationDomain>,
+    >(
+        &self,
+        input: OrganizationDomainVerificationInput,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::organization_domain_verify::<T>(self, input).await
+    }
+    /// `INTERNAL` Adds a domain to be allowed for a workspace.
+    ///
+    /// Full type: [`OrganizationDomain`](super::types::OrganizationDomain)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub async fn organization_domain_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::OrganizationDomain>,
+    >(
+        &self,
+        trigger_email_verification: Option<bool>,
+        input: OrganizationDomainCreateInput,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::organization_domain_create::<T>(
+            self,
+            trigger_email_verification,
+            input,
+        )
+        .await
+    }
+    /// `INTERNAL` Updates a workspace domain's settings.
+    ///
+    /// Full type: [`OrganizationDomain`](super::types::OrganizationDomain)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub async fn organization_domain_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::OrganizationDomain>,
+    >(
+        &self,
+        input: OrganizationDomainUpdateInput,
+        id: impl Into<super::ids::OrganizationDomainId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::organization_domain_update::<T>(self, input, id).await
+    }
+    /// Deletes a domain.
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub async fn organization_domain_delete(
+        &self,
+        id: impl Into<super::ids::OrganizationDomainId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        crate::generated::mutations::organization_domain_delete(self, id).await
+    }
+    /// Creates a new notification subscription for a specific entity. The subscription determines which notification types the authenticated user will receive for the target entity. Exactly one target entity (customer, custom view, cycle, initiative, label, project, team, or user) must be specified.
+    ///
+    /// Requires the `notifications` feature.
+    #[cfg(feature = "notifications")]
+    pub async fn notification_subscription_create(
+        &self,
+        input: NotificationSubscriptionCreateInput,
+    ) -> Result<serde_json::Value, LinearError> {
+        crate::generated::mutations::notification_subscription_create(self, input).await
+    }
+    /// Updates a notification subscription.
+    ///
+    /// Requires the `notifications` feature.
+    #[cfg(feature = "notifications")]
+    pub async fn notification_subscription_update(
+        &self,
+        input: NotificationSubscriptionUpdateInput,
+        id: String,
+    ) -> Result<serde_json::Value, LinearError> {
+        crate::generated::mutations::notification_subscription_update(self, input, id).await
+    }
+    /// Deletes a notification subscription reference.
+    #[deprecated(note = "Update `notificationSubscription.active` to `false` instead.")]
+    ///
+    /// Requires the `notifications` feature.
+    #[cfg(feature = "notifications")]
+    pub async fn notification_subscription_delete(
+        &self,
+        id: String,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        crate::generated::mutations::notification_subscription_delete(self, id).await
+    }
+    /// Updates a notification.
+    ///
+    /// Requires the `notifications` feature.
+    #[cfg(feature = "notifications")]
+    pub async fn notification_update(
+        &self,
+        input: NotificationUpdateInput,
+        id: String,
+    ) -> Result<serde_json::Value, LinearError> {
+        crate::generated::mutations::notification_update(self, input, id).await
+    }
+    /// Marks notification and all related notifications as read.
+    ///
+    /// Requires the `notifications` feature.
+    #[cfg(feature = "notifications")]
+    pub async fn notification_mark_read_all(
+        &self,
+        read_at: serde_json::Value,
+        input: NotificationEntityInput,
+    ) -> Result<serde_json::Value, LinearError> {
+        crate::generated::mutations::notification_mark_read_all(self, read_at, input).await
+    }
+    /// Marks notification and all related notifications as unread.
+    ///
+    /// Requires the `notifications` feature.
+    #[cfg(feature = "notifications")]
+    pub async fn notification_mark_unread_all(
+        &self,
+        input: NotificationEntityInput,
+    ) -> Result<serde_json::Value, LinearError> {
+        crate::generated::mutations::notification_mark_unread_all(self, input).await
+    }
+    /// Snoozes a notification and all related notifications.
+    ///
+    /// Requires the `notifications` feature.
+    #[cfg(feature = "notifications")]
+    pub async fn notification_snooze_all(
+        &self,
+        snoozed_until_at: serde_json::Value,
+        input: NotificationEntityInput,
+    ) -> Result<serde_json::Value, LinearError> {
+        crate::generated::mutations::notification_snooze_all(self, snoozed_until_at, input).await
+    }
+    /// Unsnoozes a notification and all related notifications.
+    ///
+    /// Requires the `notifications` feature.
+    #[cfg(feature = "notifications")]
+    pub async fn notification_unsnooze_all(
+        &self,
+        unsnoozed_at: serde_json::Value,
+        input: NotificationEntityInput,
+    ) -> Result<serde_json::Value, LinearError> {
+        crate::generated::mutations::notification_unsnooze_all(self, unsnoozed_at, input).await
+    }
+    /// Archives a notification.
+    ///
+    /// Requires the `notifications` feature.
+    #[cfg(feature = "notifications")]
+    pub async fn notification_archive(&self, id: String) -> Result<serde_json::Value, LinearError> {
+        crate::generated::mutations::notification_archive(self, id).await
+    }
+    /// Archives a notification and all related notifications.
+    ///
+    /// Requires the `notifications` feature.
+    #[cfg(feature = "notifications")]
+    pub async fn notification_archive_all(
+        &self,
+        input: NotificationEntityInput,
+    ) -> Result<serde_json::Value, LinearError> {
+        crate::generated::mutations::notification_archive_all(self, input).await
+    }
+    /// Unarchives a notification.
+    ///
+    /// Requires the `notifications` feature.
+    #[cfg(feature = "notifications")]
+    pub async fn notification_unarchive(
+        &self,
+        id: String,
+    ) -> Result<serde_json::Value, LinearError> {
+        crate::generated::mutations::notification_unarchive(self, id).await
+    }
+    /// `ALPHA` Creates a new association between an issue and a release, linking the issue to the release for tracking purposes.
+    ///
+    /// Full type: [`IssueToRelease`](super::types::IssueToRelease)
+    ///
+    /// Requires the `releases` feature.
+    #[cfg(feature = "releases")]
+    pub async fn issue_to_release_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::IssueToRelease>,
+    >(
+        &self,
+        input: IssueToReleaseCreateInput,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::issue_to_release_create::<T>(self, input).await
+    }
+    /// `ALPHA` Deletes an issue-to-release association by looking up the issue and release identifiers, removing the issue from the release.
+    ///
+    /// Requires the `releases` feature.
+    #[cfg(feature = "releases")]
+    pub async fn issue_to_release_delete_by_issue_and_release(
+        &self,
+        release_id: String,
+        issue_id: String,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        crate::generated::mutations::issue_to_release_delete_by_issue_and_release(
+            self, release_id, issue_id,
+        )
+        .await
+    }
+    /// `ALPHA` Deletes an issue-to-release association by its identifier, removing the issue from the release.
+    ///
+    /// Requires the `releases` feature.
+    #[cfg(feature = "releases")]
+    pub async fn issue_to_release_delete(
+        &self,
+        id: impl Into<super::ids::IssueToReleaseId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        crate::generated::mutations::issue_to_release_delete(self, id).await
+    }
+    /// Creates a new issue.
+    ///
+    /// Full type: [`Issue`](super::types::Issue)
+    pub async fn issue_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Issue>,
+    >(
+        &self,
+        input: IssueCreateInput,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::issue_create::<T>(self, input).await
+    }
+    /// Creates a list of issues in one transaction.
+    ///
+    /// Full type: [`Issue`](super::types::Issue)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub async fn issue_batch_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Issue>,
+    >(
+        &self,
+        input: IssueBatchCreateInput,
+    ) -> Result<Vec<T>, LinearError> {
+        crate::generated::mutations::issue_batch_create::<T>(self, input).await
+    }
+    /// Updates an issue.
+    ///
+    /// Full type: [`Issue`](super::types::Issue)
+    pub async fn issue_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Issue>,
+    >(
+        &self,
+        input: IssueUpdateInput,
+        id: impl Into<super::ids::IssueId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::issue_update::<T>(self, input, id).await
+    }
+    /// Updates multiple issues at once.
+    ///
+    /// Full type: [`Issue`](super::types::Issue)
+    pub async fn issue_batch_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Issue>,
+    >(
+        &self,
+        input: IssueUpdateInput,
+        ids: Vec<String>,
+    ) -> Result<Vec<T>, LinearError> {
+        crate::generated::mutations::issue_batch_update::<T>(self, input, ids).await
+    }
+    /// Archives an issue.
+    ///
+    /// Full type: [`Issue`](super::types::Issue)
+    pub async fn issue_archive<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Issue>,
+    >(
+        &self,
+        trash: Option<bool>,
+        id: impl Into<super::ids::IssueId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::issue_archive::<T>(self, trash, id).await
+    }
+    /// Unarchives an issue.
+    ///
+    /// Full type: [`Issue`](super::types::Issue)
+    pub async fn issue_unarchive<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Issue>,
+    >(
+        &self,
+        id: impl Into<super::ids::IssueId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::issue_unarchive::<T>(self, id).await
+    }
+    /// Deletes (trashes) an issue.
+    ///
+    /// Full type: [`Issue`](super::types::Issue)
+    pub async fn issue_delete<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Issue>,
+    >(
+        &self,
+        permanently_delete: Option<bool>,
+        id: impl Into<super::ids::IssueId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::issue_delete::<T>(self, permanently_delete, id).await
+    }
+    /// Adds a label to an issue.
+    ///
+    /// Full type: [`Issue`](super::types::Issue)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub async fn issue_add_label<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Issue>,
+    >(
+        &self,
+        label_id: String,
+        id: impl Into<super::ids::IssueId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::issue_add_label::<T>(self, label_id, id).await
+    }
+    /// Removes a label from an issue.
+    ///
+    /// Full type: [`Issue`](super::types::Issue)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub async fn issue_remove_label<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Issue>,
+    >(
+        &self,
+        label_id: String,
+        id: impl Into<super::ids::IssueId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::issue_remove_label::<T>(self, label_id, id).await
+    }
+    /// Adds an issue reminder. Will cause a notification to be sent when the issue reminder time is reached.
+    ///
+    /// Full type: [`Issue`](super::types::Issue)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub async fn issue_reminder<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Issue>,
+    >(
+        &self,
+        reminder_at: serde_json::Value,
+        id: impl Into<super::ids::IssueId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::issue_reminder::<T>(self, reminder_at, id).await
+    }
+    /// Subscribes a user to an issue.
+    ///
+    /// Full type: [`Issue`](super::types::Issue)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub async fn issue_subscribe<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Issue>,
+    >(
+        &self,
+        user_email: Option<String>,
+        user_id: Option<String>,
+        id: impl Into<super::ids::IssueId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::issue_subscribe::<T>(self, user_email, user_id, id).await
+    }
+    /// Unsubscribes a user from an issue.
+    ///
+    /// Full type: [`Issue`](super::types::Issue)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub async fn issue_unsubscribe<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Issue>,
+    >(
+        &self,
+        user_email: Option<String>,
+        user_id: Option<String>,
+        id: impl Into<super::ids::IssueId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::issue_unsubscribe::<T>(self, user_email, user_id, id).await
+    }
+    /// `INTERNAL` Updates an issue description from the Front app to handle Front attachments correctly.
+    ///
+    /// Full type: [`Issue`](super::types::Issue)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub async fn issue_description_update_from_front<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Issue>,
+    >(
+        &self,
+        description: String,
+        id: impl Into<super::ids::IssueId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::issue_description_update_from_front::<T>(self, description, id)
+            .await
+    }
+    /// Disables external sync on an issue.
+    ///
+    /// Full type: [`Issue`](super::types::Issue)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub async fn issue_external_sync_disable<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Issue>,
+    >(
+        &self,
+        attachment_id: String,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::issue_external_sync_disable::<T>(self, attachment_id).await
+    }
+    /// Creates a new issue relation.
+    ///
+    /// Full type: [`IssueRelation`](super::types::IssueRelation)
+    pub async fn issue_relation_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::IssueRelation>,
+    >(
+        &self,
+        override_created_at: Option<serde_json::Value>,
+        input: IssueRelationCreateInput,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::issue_relation_create::<T>(self, override_created_at, input)
+            .await
+    }
+    /// Updates an issue relation.
+    ///
+    /// Full type: [`IssueRelation`](super::types::IssueRelation)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub async fn issue_relation_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::IssueRelation>,
+    >(
+        &self,
+        input: IssueRelationUpdateInput,
+        id: impl Into<super::ids::IssueRelationId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::issue_relation_update::<T>(self, input, id).await
+    }
+    /// Deletes an issue relation.
+    pub async fn issue_relation_delete(
+        &self,
+        id: impl Into<super::ids::IssueRelationId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        crate::generated::mutations::issue_relation_delete(self, id).await
+    }
+    /// Creates a new label.
+    ///
+    /// Full type: [`IssueLabel`](super::types::IssueLabel)
+    pub async fn issue_label_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::IssueLabel>,
+    >(
+        &self,
+        replace_team_labels: Option<bool>,
+        input: IssueLabelCreateInput,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::issue_label_create::<T>(self, replace_team_labels, input).await
+    }
+    /// Updates a label.
+    ///
+    /// Full type: [`IssueLabel`](super::types::IssueLabel)
+    pub async fn issue_label_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::IssueLabel>,
+    >(
+        &self,
+        replace_team_labels: Option<bool>,
+        input: IssueLabelUpdateInput,
+        id: impl Into<super::ids::IssueLabelId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::issue_label_update::<T>(self, replace_team_labels, input, id)
+            .await
+    }
+    /// Deletes an issue label.
+    pub async fn issue_label_delete(
+        &self,
+        id: impl Into<super::ids::IssueLabelId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        crate::generated::mutations::issue_label_delete(self, id).await
+    }
+    /// Retires a label. Retired labels are still visible but cannot be applied to new issues. Existing issues with the label are not affected.
+    ///
+    /// Full type: [`IssueLabel`](super::types::IssueLabel)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub async fn issue_label_retire<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::IssueLabel>,
+    >(
+        &self,
+        id: impl Into<super::ids::IssueLabelId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::issue_label_retire::<T>(self, id).await
+    }
+    /// Restores a previously retired label, making it available for use again.
+    ///
+    /// Full type: [`IssueLabel`](super::types::IssueLabel)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub async fn issue_label_restore<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::IssueLabel>,
+    >(
+        &self,
+        id: impl Into<super::ids::IssueLabelId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::issue_label_restore::<T>(self, id).await
+    }
+    /// Kicks off a GitHub import job.
+    ///
+    /// Full type: [`IssueImport`](super::types::IssueImport)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub async fn issue_import_create_github<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::IssueImport>,
+    >(
+        &self,
+        team_id: Option<String>,
+        team_name: Option<String>,
+        github_repo_ids: Option<Vec<i64>>,
+        github_labels: Option<Vec<String>>,
+        instant_process: Option<bool>,
+        include_closed_issues: Option<bool>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::issue_import_create_github::<T>(
+            self,
+            team_id,
+            team_name,
+            github_repo_ids,
+            github_labels,
+            instant_process,
+            include_closed_issues,
+        )
+        .await
+    }
+    /// Kicks off a Jira import job.
+    ///
+    /// Full type: [`IssueImport`](super::types::IssueImport)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub async fn issue_import_create_jira<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::IssueImport>,
+    >(
+        &self,
+        team_id: Option<String>,
+        team_name: Option<String>,
+        jira_token: String,
+        jira_project: String,
+        jira_email: String,
+        jira_hostname: String,
+        jql: Option<String>,
+        instant_process: Option<bool>,
+        include_closed_issues: Option<bool>,
+        id: Option<String>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::issue_import_create_jira::<T>(
+            self,
+            team_id,
+            team_name,
+            jira_token,
+            jira_project,
+            jira_email,
+            jira_hostname,
+            jql,
+            instant_process,
+            include_closed_issues,
+            id,
+        )
+        .await
+    }
+    /// Kicks off a Jira import job from a CSV.
+    ///
+    /// Full type: [`IssueImport`](super::types::IssueImport)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub async fn issue_import_create_csv_jira<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::IssueImport>,
+    >(
+        &self,
+        team_id: Option<String>,
+        team_name: Option<String>,
+        csv_url: String,
+        jira_hostname: Option<String>,
+        jira_token: Option<String>,
+        jira_email: Option<String>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::issue_import_create_csv_jira::<T>(
+            self,
+            team_id,
+            team_name,
+            csv_url,
+            jira_hostname,
+            jira_token,
+            jira_email,
+        )
+        .await
+    }
+    /// Kicks off a Shortcut (formerly Clubhouse) import job.
+    ///
+    /// Full type: [`IssueImport`](super::types::IssueImport)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub async fn issue_import_create_clubhouse<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::IssueImport>,
+    >(
+        &self,
+        team_id: Option<String>,
+        team_name: Option<String>,
+        clubhouse_token: String,
+        clubhouse_group_name: String,
+        instant_process: Option<bool>,
+        include_closed_issues: Option<bool>,
+        id: Option<String>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::issue_import_create_clubhouse::<T>(
+            self,
+            team_id,
+            team_name,
+            clubhouse_token,
+            clubhouse_group_name,
+            instant_process,
+            include_closed_issues,
+            id,
+        )
+        .await
+    }
+    /// Kicks off an Asana import job.
+    ///
+    /// Full type: [`IssueImport`](super::types::IssueImport)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub async fn issue_import_create_asana<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::IssueImport>,
+    >(
+        &self,
+        team_id: Option<String>,
+        team_name: Option<String>,
+        asana_token: String,
+        asana_team_name: String,
+        instant_process: Option<bool>,
+        include_closed_issues: Option<bool>,
+        id: Option<String>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::issue_import_create_asana::<T>(
+            self,
+            team_id,
+            team_name,
+            asana_token,
+            asana_team_name,
+            instant_process,
+            include_closed_issues,
+            id,
+        )
+        .await
+    }
+    /// `INTERNAL` Kicks off a Linear to Linear import job.
+    ///
+    /// Full type: [`IssueImport`](super::types::IssueImport)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub async fn issue_import_create_linear_v2<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::IssueImport>,
+    >(
+        &self,
+        linear_source_organization_id: String,
+        id: Option<String>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::issue_import_create_linear_v2::<T>(
+            self,
+            linear_source_organization_id,
+            id,
+        )
+        .await
+    }
+    /// Deletes an import job.
+    ///
+    /// Full type: [`IssueImport`](super::types::IssueImport)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub async fn issue_import_delete<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::IssueImport>,
+    >(
+        &self,
+        issue_import_id: String,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::issue_import_delete::<T>(self, issue_import_id).await
+    }
+    /// Kicks off import processing.
+    ///
+    /// Full type: [`IssueImport`](super::types::IssueImport)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub async fn issue_import_process<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::IssueImport>,
+    >(
+        &self,
+        mapping: serde_json::Value,
+        issue_import_id: String,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::issue_import_process::<T>(self, mapping, issue_import_id).await
+    }
+    /// Updates the mapping for the issue import.
+    ///
+    /// Full type: [`IssueImport`](super::types::IssueImport)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub async fn issue_import_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::IssueImport>,
+    >(
+        &self,
+        input: IssueImportUpdateInput,
+        id: impl Into<super::ids::IssueImportId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::issue_import_update::<T>(self, input, id).await
+    }
+    /// Creates new Slack notification settings for a team, project, initiative, or custom view.
+    ///
+    /// Full type: [`IntegrationsSettings`](super::types::IntegrationsSettings)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub async fn integrations_settings_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::IntegrationsSettings>,
+    >(
+        &self,
+        input: IntegrationsSettingsCreateInput,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::integrations_settings_create::<T>(self, input).await
+    }
+    /// Updates Slack notification settings for a team, project, initiative, or custom view.
+    ///
+    /// Full type: [`IntegrationsSettings`](super::types::IntegrationsSettings)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub async fn integrations_settings_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::IntegrationsSettings>,
+    >(
+        &self,
+        input: IntegrationsSettingsUpdateInput,
+        id: impl Into<super::ids::IntegrationsSettingsId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::integrations_settings_update::<T>(self, input, id).await
+    }
+    /// Creates a new connection between a template and an integration, optionally scoped to a specific external resource such as a Slack channel.
+    ///
+    /// Full type: [`IntegrationTemplate`](super::types::IntegrationTemplate)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub async fn integration_template_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::IntegrationTemplate>,
+    >(
+        &self,
+        input: IntegrationTemplateCreateInput,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::integration_template_create::<T>(self, input).await
+    }
+    /// Deletes an integration template connection, removing the link between a template and an integration.
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub async fn integration_template_delete(
+        &self,
+        id: impl Into<super::ids::IntegrationTemplateId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        crate::generated::mutations::integration_template_delete(self, id).await
+    }
+    /// `INTERNAL` Updates the integration.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub async fn integration_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        input: IntegrationUpdateInput,
+        id: impl Into<super::ids::IntegrationId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::integration_update::<T>(self, input, id).await
+    }
+    /// `INTERNAL` Updates the integration settings.
+    #[deprecated(note = "Use integrationUpdate instead.")]
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub async fn integration_settings_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        input: IntegrationSettingsInput,
+        id: impl Into<super::ids::IntegrationId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::integration_settings_update::<T>(self, input, id).await
+    }
+    /// Generates a webhook for the GitHub commit integration.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub async fn integration_github_commit_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::integration_github_commit_create::<T>(self).await
+    }
+    /// Connects the workspace with the GitHub App.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub async fn integration_github_connect<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        github_host: Option<String>,
+        code_access: Option<bool>,
+        code: String,
+        installation_id: String,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::integration_github_connect::<T>(
+            self,
+            github_host,
+            code_access,
+            code,
+            installation_id,
+        )
+        .await
+    }
+    /// Connects the workspace with the GitHub Import App.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub async fn integration_github_import_connect<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        code: String,
+        installation_id: String,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::integration_github_import_connect::<T>(
+            self,
+            code,
+            installation_id,
+        )
+        .await
+    }
+    /// Refreshes the data for a GitHub import integration.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub async fn integration_github_import_refresh<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        id: impl Into<super::ids::IntegrationId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::integration_github_import_refresh::<T>(self, id).await
+    }
+    /// Connects the workspace with a GitHub Enterprise Server.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub async fn integration_git_hub_enterprise_server_connect<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        organization_name: String,
+        github_url: String,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::integration_git_hub_enterprise_server_connect::<T>(
+            self,
+            organization_name,
+            github_url,
+        )
+        .await
+    }
+    /// Connects the workspace with a GitLab Access Token.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub async fn integration_gitlab_connect<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        gitlab_url: String,
+        access_token: String,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::integration_gitlab_connect::<T>(self, gitlab_url, access_token)
+            .await
+    }
+    /// Tests connectivity to a self-hosted GitLab instance and clears auth errors if successful.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub async fn integration_gitlab_test_connection<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        integration_id: String,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::integration_gitlab_test_connection::<T>(self, integration_id)
+            .await
+    }
+    /// Creates an integration api key for Airbyte to connect with Linear.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub async fn airbyte_integration_connect<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        input: AirbyteConfigurationInput,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::airbyte_integration_connect::<T>(self, input).await
+    }
+    /// `Internal` Connects the Google Calendar to the user to this Linear account via OAuth2.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub async fn integration_google_calendar_personal_connect<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        code: String,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::integration_google_calendar_personal_connect::<T>(self, code)
+            .await
+    }
+    /// `INTERNAL` Integrates the workspace with LaunchDarkly.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub async fn integration_launch_darkly_connect<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        code: String,
+        project_key: String,
+        environment: String,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::integration_launch_darkly_connect::<T>(
+            self,
+            code,
+            project_key,
+            environment,
+        )
+        .await
+    }
+    /// `INTERNAL` Integrates your personal account with LaunchDarkly.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub async fn integration_launch_darkly_personal_connect<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        code: String,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::integration_launch_darkly_personal_connect::<T>(self, code)
+            .await
+    }
+    /// `INTERNAL` Connects the workspace with a Jira Personal Access Token.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub async fn jira_integration_connect<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        input: JiraConfigurationInput,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::jira_integration_connect::<T>(self, input).await
+    }
+    /// `INTERNAL` Updates a Jira Integration.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub async fn integration_jira_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        input: JiraUpdateInput,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::integration_jira_update::<T>(self, input).await
+    }
+    /// `INTERNAL` Fetches Jira project statuses and stores them in integration settings.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub async fn integration_jira_fetch_project_statuses<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        input: JiraFetchProjectStatusesInput,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::integration_jira_fetch_project_statuses::<T>(self, input).await
+    }
+    /// Connect your Jira account to Linear.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub async fn integration_jira_personal<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        code: Option<String>,
+        access_token: Option<String>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::integration_jira_personal::<T>(self, code, access_token).await
+    }
+    /// Connect your GitHub account to Linear.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub async fn integration_git_hub_personal<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        enterprise_url: Option<String>,
+        code_access: Option<bool>,
+        code: String,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::integration_git_hub_personal::<T>(
+            self,
+            enterprise_url,
+            code_access,
+            code,
+        )
+        .await
+    }
+    /// Integrates the workspace with Intercom.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub async fn integration_intercom<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        domain_url: Option<String>,
+        redirect_uri: String,
+        code: String,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::integration_intercom::<T>(self, domain_url, redirect_uri, code)
+            .await
+    }
+    /// Disconnects the workspace from Intercom.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub async fn integration_intercom_delete<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::integration_intercom_delete::<T>(self).await
+    }
+    /// `INTERNAL` Refreshes the customer data attributes from the specified integration service.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub async fn integration_customer_data_attributes_refresh<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        input: IntegrationCustomerDataAttributesRefreshInput,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::integration_customer_data_attributes_refresh::<T>(self, input)
+            .await
+    }
+    /// `DEPRECATED` Updates settings on the Intercom integration.
+    #[deprecated(
+        note = "This mutation is deprecated, please use `integrationSettingsUpdate` instead"
+    )]
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub async fn integration_intercom_settings_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        input: IntercomSettingsInput,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::integration_intercom_settings_update::<T>(self, input).await
+    }
+    /// Integrates the workspace with Discord.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub async fn integration_discord<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        redirect_uri: String,
+        code: String,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::integration_discord::<T>(self, redirect_uri, code).await
+    }
+    /// `INTERNAL` Integrates the workspace with Opsgenie.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub async fn integration_opsgenie_connect<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        api_key: String,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::integration_opsgenie_connect::<T>(self, api_key).await
+    }
+    /// `INTERNAL` Refresh Opsgenie schedule mappings.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub async fn integration_opsgenie_refresh_schedule_mappings<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::integration_opsgenie_refresh_schedule_mappings::<T>(self).await
+    }
+    /// `INTERNAL` Integrates the workspace with PagerDuty.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub async fn integration_pager_duty_connect<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        code: String,
+        redirect_uri: String,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::integration_pager_duty_connect::<T>(self, code, redirect_uri)
+            .await
+    }
+    /// `INTERNAL` Refresh PagerDuty schedule mappings.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub async fn integration_pager_duty_refresh_schedule_mappings<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::integration_pager_duty_refresh_schedule_mappings::<T>(self)
+            .await
+    }
+    /// `Internal` Updates existing Slack and Asks integration scopes.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub async fn update_integration_slack_scopes<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        integration_id: String,
+        redirect_uri: String,
+        code: String,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::update_integration_slack_scopes::<T>(
+            self,
+            integration_id,
+            redirect_uri,
+            code,
+        )
+        .await
+    }
+    /// Integrates the workspace with Slack.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub async fn integration_slack<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        should_use_v2_auth: Option<bool>,
+        redirect_uri: String,
+        code: String,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::integration_slack::<T>(
+            self,
+            should_use_v2_auth,
+            redirect_uri,
+            code,
+        )
+        .await
+    }
+    /// Integrates the workspace with the Slack Asks app.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub async fn integration_slack_asks<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        redirect_uri: String,
+        code: String,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::integration_slack_asks::<T>(self, redirect_uri, code).await
+    }
+    /// Updates the Slack team's name in Linear for an existing Slack or Asks integration.
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub async fn integration_slack_or_asks_update_slack_team_name(
+        &self,
+        integration_id: String,
+    ) -> Result<super::types::IntegrationSlackWorkspaceNamePayload, LinearError> {
+        crate::generated::mutations::integration_slack_or_asks_update_slack_team_name(
+            self,
+            integration_id,
+        )
+        .await
+    }
+    /// Integrates your personal notifications with Slack.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub async fn integration_slack_personal<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        redirect_uri: String,
+        code: String,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::integration_slack_personal::<T>(self, redirect_uri, code).await
+    }
+    /// Connect a Slack channel to Asks.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub async fn integration_asks_connect_channel<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        redirect_uri: String,
+        code: String,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::integration_asks_connect_channel::<T>(self, redirect_uri, code)
+            .await
+    }
+    /// Slack integration for team notifications.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub async fn integration_slack_post<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        should_use_v2_auth: Option<bool>,
+        redirect_uri: String,
+        team_id: String,
+        code: String,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::integration_slack_post::<T>(
+            self,
+            should_use_v2_auth,
+            redirect_uri,
+            team_id,
+            code,
+        )
+        .await
+    }
+    /// Slack integration for project notifications.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub async fn integration_slack_project_post<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        service: String,
+        redirect_uri: String,
+        project_id: String,
+        code: String,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::integration_slack_project_post::<T>(
+            self,
+            service,
+            redirect_uri,
+            project_id,
+            code,
+        )
+        .await
+    }
+    /// `Internal` Slack integration for initiative notifications.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub async fn integration_slack_initiative_post<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        redirect_uri: String,
+        initiative_id: String,
+        code: String,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::integration_slack_initiative_post::<T>(
+            self,
+            redirect_uri,
+            initiative_id,
+            code,
+        )
+        .await
+    }
+    /// Slack integration for custom view notifications.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub async fn integration_slack_custom_view_notifications<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        redirect_uri: String,
+        custom_view_id: String,
+        code: String,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::integration_slack_custom_view_notifications::<T>(
+            self,
+            redirect_uri,
+            custom_view_id,
+            code,
+        )
+        .await
+    }
+    /// Integrates a Slack Asks channel with a Customer.
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub async fn integration_slack_customer_channel_link(
+        &self,
+        redirect_uri: String,
+        customer_id: String,
+        code: String,
+    ) -> Result<super::types::SuccessPayload, LinearError> {
+        crate::generated::mutations::integration_slack_customer_channel_link(
+            self,
+            redirect_uri,
+            customer_id,
+            code,
+        )
+        .await
+    }
+    /// Slack integration for workspace-level project update notifications.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub async fn integration_slack_org_project_updates_post<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        redirect_uri: String,
+        code: String,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::integration_slack_org_project_updates_post::<T>(
+            self,
+            redirect_uri,
+            code,
+        )
+        .await
+    }
+    /// `Internal` Slack integration for workspace-level initiative update notifications.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub async fn integration_slack_org_initiative_updates_post<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        redirect_uri: String,
+        code: String,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::integration_slack_org_initiative_updates_post::<T>(
+            self,
+            redirect_uri,
+            code,
+        )
+        .await
+    }
+    /// Imports custom emojis from your Slack workspace.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub async fn integration_slack_import_emojis<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        redirect_uri: String,
+        code: String,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::integration_slack_import_emojis::<T>(self, redirect_uri, code)
+            .await
+    }
+    /// Integrates the workspace with Figma.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub async fn integration_figma<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        redirect_uri: String,
+        code: String,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::integration_figma::<T>(self, redirect_uri, code).await
+    }
+    /// Integrates the workspace with Gong.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub async fn integration_gong<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        redirect_uri: String,
+        code: String,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::integration_gong::<T>(self, redirect_uri, code).await
+    }
+    /// Integrates the workspace with Microsoft Teams.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub async fn integration_microsoft_teams<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        redirect_uri: String,
+        code: String,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::integration_microsoft_teams::<T>(self, redirect_uri, code)
+            .await
+    }
+    /// Connects the user's personal Microsoft account to Linear.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub async fn integration_microsoft_personal_connect<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        redirect_uri: String,
+        code: String,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::integration_microsoft_personal_connect::<T>(
+            self,
+            redirect_uri,
+            code,
+        )
+        .await
+    }
+    /// `Internal` Connect a project to a Microsoft Teams channel. Find-or-update semantics: creates a microsoftTeamsProjectPost integration row if none exists for the project, or overwrites the existing one's team/channel selection. Requires the connecting user to have linked their personal Microsoft account.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub async fn integration_microsoft_teams_project_post<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        membership_type: String,
+        channel_name: String,
+        channel_id: String,
+        team_name: String,
+        team_id: String,
+        project_id: String,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::integration_microsoft_teams_project_post::<T>(
+            self,
+            membership_type,
+            channel_name,
+            channel_id,
+            team_name,
+            team_id,
+            project_id,
+        )
+        .await
+    }
+    /// Integrates the workspace with Google Sheets.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub async fn integration_google_sheets<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        code: String,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::integration_google_sheets::<T>(self, code).await
+    }
+    /// Manually update Google Sheets data.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub async fn refresh_google_sheets_data<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        r#type: Option<String>,
+        id: impl Into<super::ids::IntegrationId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::refresh_google_sheets_data::<T>(self, r#type, id).await
+    }
+    /// Integrates the workspace with Sentry.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub async fn integration_sentry_connect<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        organization_slug: String,
+        code: String,
+        installation_id: String,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::integration_sentry_connect::<T>(
+            self,
+            organization_slug,
+            code,
+            installation_id,
+        )
+        .await
+    }
+    /// Integrates the workspace with Front.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub async fn integration_front<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        redirect_uri: String,
+        code: String,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::integration_front::<T>(self, redirect_uri, code).await
+    }
+    /// Integrates the workspace with Zendesk.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub async fn integration_zendesk<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        subdomain: String,
+        code: String,
+        scope: String,
+        redirect_uri: String,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::integration_zendesk::<T>(
+            self,
+            subdomain,
+            code,
+            scope,
+            redirect_uri,
+        )
+        .await
+    }
+    /// Enables Loom integration for the workspace.
+    #[deprecated(note = "Not available.")]
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub async fn integration_loom<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::integration_loom::<T>(self).await
+    }
+    /// Integrates the workspace with Salesforce.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub async fn integration_salesforce<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        code: String,
+        subdomain: String,
+        redirect_uri: String,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::integration_salesforce::<T>(
+            self,
+            code,
+            subdomain,
+            redirect_uri,
+        )
+        .await
+    }
+    /// `INTERNAL` Refreshes the Salesforce integration metadata.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub async fn integration_salesforce_metadata_refresh<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        id: impl Into<super::ids::IntegrationId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::integration_salesforce_metadata_refresh::<T>(self, id).await
+    }
+    /// `INTERNAL` Connects the user's personal account with an MCP server.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub async fn integration_mcp_server_personal_connect<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        custom_headers: Option<Vec<McpServerCustomHeaderInput>>,
+        server_url: String,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::integration_mcp_server_personal_connect::<T>(
+            self,
+            custom_headers,
+            server_url,
+        )
+        .await
+    }
+    /// `INTERNAL` Connects the workspace with an MCP server.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub async fn integration_mcp_server_connect<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        custom_headers: Option<Vec<McpServerCustomHeaderInput>>,
+        workflow_definition_id: Option<String>,
+        team_id: Option<String>,
+        server_url: String,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::integration_mcp_server_connect::<T>(
+            self,
+            custom_headers,
+            workflow_definition_id,
+            team_id,
+            server_url,
+        )
+        .await
+    }
+    /// Deletes an integration.
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub async fn integration_delete(
+        &self,
+        skip_installation_deletion: Option<bool>,
+        id: impl Into<super::ids::IntegrationId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        crate::generated::mutations::integration_delete(self, skip_installation_deletion, id).await
+    }
+    /// Archives an integration.
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub async fn integration_archive(
+        &self,
+        id: impl Into<super::ids::IntegrationId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        crate::generated::mutations::integration_archive(self, id).await
+    }
+    /// `Internal` Enables Linear Agent Slack workflow access for a Slack or Slack Asks integration.
+    ///
+    /// Full type: [`Integration`](super::types::Integration)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub async fn integration_slack_workflow_access_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+    >(
+        &self,
+        enabled: bool,
+        integration_id: String,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::integration_slack_workflow_access_update::<T>(
+            self,
+            enabled,
+            integration_id,
+        )
+        .await
+    }
+    /// Requests a currently unavailable integration.
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub async fn integration_request(
+        &self,
+        input: IntegrationRequestInput,
+    ) -> Result<super::types::IntegrationRequestPayload, LinearError> {
+        crate::generated::mutations::integration_request(self, input).await
+    }
+    /// Creates an initiative update.
+    ///
+    /// Full type: [`InitiativeUpdate`](super::types::InitiativeUpdate)
+    ///
+    /// Requires the `initiatives` feature.
+    #[cfg(feature = "initiatives")]
+    pub async fn initiative_update_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::InitiativeUpdate>,
+    >(
+        &self,
+        input: InitiativeUpdateCreateInput,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::initiative_update_create::<T>(self, input).await
+    }
+    /// Updates an initiative update.
+    ///
+    /// Full type: [`InitiativeUpdate`](super::types::InitiativeUpdate)
+    ///
+    /// Requires the `initiatives` feature.
+    #[cfg(feature = "initiatives")]
+    pub async fn initiative_update_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::InitiativeUpdate>,
+    >(
+        &self,
+        input: InitiativeUpdateUpdateInput,
+        id: impl Into<super::ids::InitiativeUpdateId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::initiative_update_update::<T>(self, input, id).await
+    }
+    /// Archives an initiative update.
+    ///
+    /// Full type: [`InitiativeUpdate`](super::types::InitiativeUpdate)
+    ///
+    /// Requires the `initiatives` feature.
+    #[cfg(feature = "initiatives")]
+    pub async fn initiative_update_archive<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::InitiativeUpdate>,
+    >(
+        &self,
+        id: impl Into<super::ids::InitiativeUpdateId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::initiative_update_archive::<T>(self, id).await
+    }
+    /// Create a notification to remind a user about an initiative update.
+    ///
+    /// Requires the `initiatives` feature.
+    #[cfg(feature = "initiatives")]
+    pub async fn create_initiative_update_reminder(
+        &self,
+        user_id: Option<String>,
+        initiative_id: String,
+    ) -> Result<super::types::InitiativeUpdateReminderPayload, LinearError> {
+        crate::generated::mutations::create_initiative_update_reminder(self, user_id, initiative_id)
+            .await
+    }
+    /// Unarchives an initiative update.
+    ///
+    /// Full type: [`InitiativeUpdate`](super::types::InitiativeUpdate)
+    ///
+    /// Requires the `initiatives` feature.
+    #[cfg(feature = "initiatives")]
+    pub async fn initiative_update_unarchive<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::InitiativeUpdate>,
+    >(
+        &self,
+        id: impl Into<super::ids::InitiativeUpdateId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::initiative_update_unarchive::<T>(self, id).await
+    }
+    /// Associates a project with an initiative. A project can only appear once in an initiative hierarchy.
+    ///
+    /// Full type: [`InitiativeToProject`](super::types::InitiativeToProject)
+    ///
+    /// Requires the `initiatives` feature.
+    #[cfg(feature = "initiatives")]
+    pub async fn initiative_to_project_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::InitiativeToProject>,
+    >(
+        &self,
+        input: InitiativeToProjectCreateInput,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::initiative_to_project_create::<T>(self, input).await
+    }
+    /// Updates an initiative-to-project association, such as its sort order.
+    ///
+    /// Full type: [`InitiativeToProject`](super::types::InitiativeToProject)
+    ///
+    /// Requires the `initiatives` feature.
+    #[cfg(feature = "initiatives")]
+    pub async fn initiative_to_project_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::InitiativeToProject>,
+    >(
+        &self,
+        input: InitiativeToProjectUpdateInput,
+        id: impl Into<super::ids::InitiativeToProjectId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::initiative_to_project_update::<T>(self, input, id).await
+    }
+    /// Removes a project from an initiative.
+    ///
+    /// Requires the `initiatives` feature.
+    #[cfg(feature = "initiatives")]
+    pub async fn initiative_to_project_delete(
+        &self,
+        id: impl Into<super::ids::InitiativeToProjectId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        crate::generated::mutations::initiative_to_project_delete(self, id).await
+    }
+    /// Creates a new initiative.
+    ///
+    /// Full type: [`Initiative`](super::types::Initiative)
+    ///
+    /// Requires the `initiatives` feature.
+    #[cfg(feature = "initiatives")]
+    pub async fn initiative_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Initiative>,
+    >(
+        &self,
+        input: InitiativeCreateInput,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::initiative_create::<T>(self, input).await
+    }
+    /// Updates an initiative.
+    ///
+    /// Full type: [`Initiative`](super::types::Initiative)
+    ///
+    /// Requires the `initiatives` feature.
+    #[cfg(feature = "initiatives")]
+    pub async fn initiative_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Initiative>,
+    >(
+        &self,
+        input: InitiativeUpdateInput,
+        id: impl Into<super::ids::InitiativeId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::initiative_update::<T>(self, input, id).await
+    }
+    /// Archives an initiative.
+    ///
+    /// Full type: [`Initiative`](super::types::Initiative)
+    ///
+    /// Requires the `initiatives` feature.
+    #[cfg(feature = "initiatives")]
+    pub async fn initiative_archive<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Initiative>,
+    >(
+        &self,
+        id: impl Into<super::ids::InitiativeId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::initiative_archive::<T>(self, id).await
+    }
+    /// Unarchives an initiative.
+    ///
+    /// Full type: [`Initiative`](super::types::Initiative)
+    ///
+    /// Requires the `initiatives` feature.
+    #[cfg(feature = "initiatives")]
+    pub async fn initiative_unarchive<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Initiative>,
+    >(
+        &self,
+        id: impl Into<super::ids::InitiativeId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::initiative_unarchive::<T>(self, id).await
+    }
+    /// Deletes (trashes) an initiative.
+    ///
+    /// Requires the `initiatives` feature.
+    #[cfg(feature = "initiatives")]
+    pub async fn initiative_delete(
+        &self,
+        id: impl Into<super::ids::InitiativeId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        crate::generated::mutations::initiative_delete(self, id).await
+    }
+    /// Creates a new parent-child relation between two initiatives. The relation cannot create cycles or exceed maximum nesting depth.
+    ///
+    /// Full type: [`InitiativeRelation`](super::types::InitiativeRelation)
+    ///
+    /// Requires the `initiatives` feature.
+    #[cfg(feature = "initiatives")]
+    pub async fn initiative_relation_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::InitiativeRelation>,
+    >(
+        &self,
+        input: InitiativeRelationCreateInput,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::initiative_relation_create::<T>(self, input).await
+    }
+    /// Updates an initiative relation.
+    ///
+    /// Full type: [`InitiativeRelation`](super::types::InitiativeRelation)
+    ///
+    /// Requires the `initiatives` feature.
+    #[cfg(feature = "initiatives")]
+    pub async fn initiative_relation_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::InitiativeRelation>,
+    >(
+        &self,
+        input: InitiativeRelationUpdateInput,
+        id: impl Into<super::ids::InitiativeRelationId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::initiative_relation_update::<T>(self, input, id).await
+    }
+    /// Deletes an initiative relation.
+    ///
+    /// Requires the `initiatives` feature.
+    #[cfg(feature = "initiatives")]
+    pub async fn initiative_relation_delete(
+        &self,
+        id: impl Into<super::ids::InitiativeRelationId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        crate::generated::mutations::initiative_relation_delete(self, id).await
+    }
+    /// Creates a new Git target branch definition that scopes automation rules to pull requests targeting a specific branch pattern.
+    ///
+    /// Full type: [`GitAutomationTargetBranch`](super::types::GitAutomationTargetBranch)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub async fn git_automation_target_branch_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<
+                FullType = super::types::GitAutomationTargetBranch,
+            >,
+    >(
+        &self,
+        input: GitAutomationTargetBranchCreateInput,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::git_automation_target_branch_create::<T>(self, input).await
+    }
+    /// Updates an existing Git target branch definition, including its branch pattern and regex flag.
+    ///
+    /// Full type: [`GitAutomationTargetBranch`](super::types::GitAutomationTargetBranch)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub async fn git_automation_target_branch_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<
+                FullType = super::types::GitAutomationTargetBranch,
+            >,
+    >(
+        &self,
+        input: GitAutomationTargetBranchUpdateInput,
+        id: impl Into<super::ids::GitAutomationTargetBranchId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::git_automation_target_branch_update::<T>(self, input, id).await
+    }
+    /// Deletes a Git target branch definition and its associated automation rules.
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub async fn git_automation_target_branch_delete(
+        &self,
+        id: impl Into<super::ids::GitAutomationTargetBranchId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        crate::generated::mutations::git_automation_target_branch_delete(self, id).await
+    }
+    /// Creates a new Git automation rule that maps a Git event to a workflow state transition for a team.
+    ///
+    /// Full type: [`GitAutomationState`](super::types::GitAutomationState)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub async fn git_automation_state_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::GitAutomationState>,
+    >(
+        &self,
+        input: GitAutomationStateCreateInput,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::git_automation_state_create::<T>(self, input).await
+    }
+    /// Updates an existing Git automation rule, including its workflow state, target branch, and triggering event.
+    ///
+    /// Full type: [`GitAutomationState`](super::types::GitAutomationState)
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub async fn git_automation_state_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::GitAutomationState>,
+    >(
+        &self,
+        input: GitAutomationStateUpdateInput,
+        id: impl Into<super::ids::GitAutomationStateId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::git_automation_state_update::<T>(self, input, id).await
+    }
+    /// Deletes a Git automation rule.
+    ///
+    /// Requires the `integrations` feature.
+    #[cfg(feature = "integrations")]
+    pub async fn git_automation_state_delete(
+        &self,
+        id: impl Into<super::ids::GitAutomationStateId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        crate::generated::mutations::git_automation_state_delete(self, id).await
+    }
+    /// Creates a new favorite for the authenticated user. Exactly one target entity must be specified. If a favorite for the same entity already exists, the existing favorite is returned (upsert behavior).
+    ///
+    /// Full type: [`Favorite`](super::types::Favorite)
+    ///
+    /// Requires the `views` feature.
+    #[cfg(feature = "views")]
+    pub async fn favorite_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Favorite>,
+    >(
+        &self,
+        input: FavoriteCreateInput,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::favorite_create::<T>(self, input).await
+    }
+    /// Updates a favorite's position, parent folder, or folder name.
+    ///
+    /// Full type: [`Favorite`](super::types::Favorite)
+    ///
+    /// Requires the `views` feature.
+    #[cfg(feature = "views")]
+    pub async fn favorite_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Favorite>,
+    >(
+        &self,
+        input: FavoriteUpdateInput,
+        id: impl Into<super::ids::FavoriteId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::favorite_update::<T>(self, input, id).await
+    }
+    /// Deletes a favorite, removing it from the user's sidebar. This is an idempotent operation -- deleting a non-existent favorite succeeds silently.
+    ///
+    /// Requires the `views` feature.
+    #[cfg(feature = "views")]
+    pub async fn favorite_delete(
+        &self,
+        id: impl Into<super::ids::FavoriteId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        crate::generated::mutations::favorite_delete(self, id).await
+    }
+    /// Track an anonymous analytics event.
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub async fn track_anonymous_event(
+        &self,
+        input: EventTrackingInput,
+    ) -> Result<super::types::EventTrackingPayload, LinearError> {
+        crate::generated::mutations::track_anonymous_event(self, input).await
+    }
+    /// Creates a new external link on an initiative, project, team, release, or cycle.
+    ///
+    /// Full type: [`EntityExternalLink`](super::types::EntityExternalLink)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub async fn entity_external_link_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::EntityExternalLink>,
+    >(
+        &self,
+        input: EntityExternalLinkCreateInput,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::entity_external_link_create::<T>(self, input).await
+    }
+    /// Updates an existing entity external link's URL, label, or sort order.
+    ///
+    /// Full type: [`EntityExternalLink`](super::types::EntityExternalLink)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub async fn entity_external_link_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::EntityExternalLink>,
+    >(
+        &self,
+        input: EntityExternalLinkUpdateInput,
+        id: impl Into<super::ids::EntityExternalLinkId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::entity_external_link_update::<T>(self, input, id).await
+    }
+    /// Deletes an entity external link.
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub async fn entity_external_link_delete(
+        &self,
+        id: impl Into<super::ids::EntityExternalLinkId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        crate::generated::mutations::entity_external_link_delete(self, id).await
+    }
+    /// Creates a custom emoji.
+    ///
+    /// Full type: [`Emoji`](super::types::Emoji)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub async fn emoji_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Emoji>,
+    >(
+        &self,
+        input: EmojiCreateInput,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::emoji_create::<T>(self, input).await
+    }
+    /// Deletes an emoji.
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub async fn emoji_delete(
+        &self,
+        id: impl Into<super::ids::EmojiId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        crate::generated::mutations::emoji_delete(self, id).await
+    }
+    /// Unsubscribes the user from one type of email.
+    ///
+    /// Requires the `notifications` feature.
+    #[cfg(feature = "notifications")]
+    pub async fn email_unsubscribe(
+        &self,
+        input: EmailUnsubscribeInput,
+    ) -> Result<super::types::EmailUnsubscribePayload, LinearError> {
+        crate::generated::mutations::email_unsubscribe(self, input).await
+    }
+    /// Creates a new email intake address.
+    ///
+    /// Full type: [`EmailIntakeAddress`](super::types::EmailIntakeAddress)
+    ///
+    /// Requires the `teams` feature.
+    #[cfg(feature = "teams")]
+    pub async fn email_intake_address_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::EmailIntakeAddress>,
+    >(
+        &self,
+        input: EmailIntakeAddressCreateInput,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::email_intake_address_create::<T>(self, input).await
+    }
+    /// Rotates an existing email intake address.
+    ///
+    /// Full type: [`EmailIntakeAddress`](super::types::EmailIntakeAddress)
+    ///
+    /// Requires the `teams` feature.
+    #[cfg(feature = "teams")]
+    pub async fn email_intake_address_rotate<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::EmailIntakeAddress>,
+    >(
+        &self,
+        id: impl Into<super::ids::EmailIntakeAddressId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::email_intake_address_rotate::<T>(self, id).await
+    }
+    /// Updates an existing email intake address.
+    ///
+    /// Full type: [`EmailIntakeAddress`](super::types::EmailIntakeAddress)
+    ///
+    /// Requires the `teams` feature.
+    #[cfg(feature = "teams")]
+    pub async fn email_intake_address_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::EmailIntakeAddress>,
+    >(
+        &self,
+        input: EmailIntakeAddressUpdateInput,
+        id: impl Into<super::ids::EmailIntakeAddressId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::email_intake_address_update::<T>(self, input, id).await
+    }
+    /// Deletes an email intake address object.
+    ///
+    /// Requires the `teams` feature.
+    #[cfg(feature = "teams")]
+    pub async fn email_intake_address_delete(
+        &self,
+        id: impl Into<super::ids::EmailIntakeAddressId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        crate::generated::mutations::email_intake_address_delete(self, id).await
+    }
+    /// Creates a new document.
+    ///
+    /// Full type: [`Document`](super::types::Document)
+    pub async fn document_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Document>,
+    >(
+        &self,
+        input: DocumentCreateInput,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::document_create::<T>(self, input).await
+    }
+    /// Updates a document.
+    ///
+    /// Full type: [`Document`](super::types::Document)
+    pub async fn document_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Document>,
+    >(
+        &self,
+        input: DocumentUpdateInput,
+        id: impl Into<super::ids::DocumentId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::document_update::<T>(self, input, id).await
+    }
+    /// Deletes (trashes) a document. The document is marked as trashed and archived, but not permanently removed.
+    ///
+    /// Full type: [`Document`](super::types::Document)
+    pub async fn document_delete<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Document>,
+    >(
+        &self,
+        id: impl Into<super::ids::DocumentId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::document_delete::<T>(self, id).await
+    }
+    /// Restores a previously trashed document by unarchiving it.
+    ///
+    /// Full type: [`Document`](super::types::Document)
+    ///
+    /// Requires the `documents` feature.
+    #[cfg(feature = "documents")]
+    pub async fn document_unarchive<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Document>,
+    >(
+        &self,
+        id: impl Into<super::ids::DocumentId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::document_unarchive::<T>(self, id).await
+    }
+    /// Creates a new cycle.
+    ///
+    /// Full type: [`Cycle`](super::types::Cycle)
+    ///
+    /// Requires the `cycles` feature.
+    #[cfg(feature = "cycles")]
+    pub async fn cycle_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Cycle>,
+    >(
+        &self,
+        input: CycleCreateInput,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::cycle_create::<T>(self, input).await
+    }
+    /// Updates a cycle.
+    ///
+    /// Full type: [`Cycle`](super::types::Cycle)
+    ///
+    /// Requires the `cycles` feature.
+    #[cfg(feature = "cycles")]
+    pub async fn cycle_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Cycle>,
+    >(
+        &self,
+        input: CycleUpdateInput,
+        id: impl Into<super::ids::CycleId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::cycle_update::<T>(self, input, id).await
+    }
+    /// Archives a cycle. All issues currently assigned to the cycle are unlinked from it before archiving.
+    ///
+    /// Full type: [`Cycle`](super::types::Cycle)
+    ///
+    /// Requires the `cycles` feature.
+    #[cfg(feature = "cycles")]
+    pub async fn cycle_archive<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Cycle>,
+    >(
+        &self,
+        id: impl Into<super::ids::CycleId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::cycle_archive::<T>(self, id).await
+    }
+    /// Shifts all cycles starts and ends by a certain number of days, starting from the provided cycle onwards.
+    ///
+    /// Full type: [`Cycle`](super::types::Cycle)
+    ///
+    /// Requires the `cycles` feature.
+    #[cfg(feature = "cycles")]
+    pub async fn cycle_shift_all<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Cycle>,
+    >(
+        &self,
+        input: CycleShiftAllInput,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::cycle_shift_all::<T>(self, input).await
+    }
+    /// Starts the upcoming cycle as of midnight today. Completes the previous cycle if it has not yet ended. Only the next upcoming (not yet started) cycle for the team can be started.
+    ///
+    /// Full type: [`Cycle`](super::types::Cycle)
+    ///
+    /// Requires the `cycles` feature.
+    #[cfg(feature = "cycles")]
+    pub async fn cycle_start_upcoming_cycle_today<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Cycle>,
+    >(
+        &self,
+        id: impl Into<super::ids::CycleId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::cycle_start_upcoming_cycle_today::<T>(self, id).await
+    }
+    /// Creates a new customer tier.
+    ///
+    /// Full type: [`CustomerTier`](super::types::CustomerTier)
+    ///
+    /// Requires the `customers` feature.
+    #[cfg(feature = "customers")]
+    pub async fn customer_tier_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::CustomerTier>,
+    >(
+        &self,
+        input: CustomerTierCreateInput,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::customer_tier_create::<T>(self, input).await
+    }
+    /// Updates a customer tier.
+    ///
+    /// Full type: [`CustomerTier`](super::types::CustomerTier)
+    ///
+    /// Requires the `customers` feature.
+    #[cfg(feature = "customers")]
+    pub async fn customer_tier_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::CustomerTier>,
+    >(
+        &self,
+        input: CustomerTierUpdateInput,
+        id: impl Into<super::ids::CustomerTierId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::customer_tier_update::<T>(self, input, id).await
+    }
+    /// Deletes a customer tier. The tier must not be in use by any customers.
+    ///
+    /// Requires the `customers` feature.
+    #[cfg(feature = "customers")]
+    pub async fn customer_tier_delete(
+        &self,
+        id: impl Into<super::ids::CustomerTierId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        crate::generated::mutations::customer_tier_delete(self, id).await
+    }
+    /// Creates a new customer status.
+    ///
+    /// Full type: [`CustomerStatus`](super::types::CustomerStatus)
+    ///
+    /// Requires the `customers` feature.
+    #[cfg(feature = "customers")]
+    pub async fn customer_status_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::CustomerStatus>,
+    >(
+        &self,
+        input: CustomerStatusCreateInput,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::customer_status_create::<T>(self, input).await
+    }
+    /// Updates a customer status.
+    ///
+    /// Full type: [`CustomerStatus`](super::types::CustomerStatus)
+    ///
+    /// Requires the `customers` feature.
+    #[cfg(feature = "customers")]
+    pub async fn customer_status_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::CustomerStatus>,
+    >(
+        &self,
+        input: CustomerStatusUpdateInput,
+        id: impl Into<super::ids::CustomerStatusId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::customer_status_update::<T>(self, input, id).await
+    }
+    /// Deletes a customer status. Cannot delete the last remaining status in a workspace, and the status must not be in use by any customers.
+    ///
+    /// Requires the `customers` feature.
+    #[cfg(feature = "customers")]
+    pub async fn customer_status_delete(
+        &self,
+        id: impl Into<super::ids::CustomerStatusId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        crate::generated::mutations::customer_status_delete(self, id).await
+    }
+    /// Creates a new customer.
+    ///
+    /// Full type: [`Customer`](super::types::Customer)
+    ///
+    /// Requires the `customers` feature.
+    #[cfg(feature = "customers")]
+    pub async fn customer_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Customer>,
+    >(
+        &self,
+        input: CustomerCreateInput,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::customer_create::<T>(self, input).await
+    }
+    /// Updates an existing customer.
+    ///
+    /// Full type: [`Customer`](super::types::Customer)
+    ///
+    /// Requires the `customers` feature.
+    #[cfg(feature = "customers")]
+    pub async fn customer_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Customer>,
+    >(
+        &self,
+        input: CustomerUpdateInput,
+        id: impl Into<super::ids::CustomerId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::customer_update::<T>(self, input, id).await
+    }
+    /// Deletes a customer.
+    ///
+    /// Requires the `customers` feature.
+    #[cfg(feature = "customers")]
+    pub async fn customer_delete(
+        &self,
+        id: impl Into<super::ids::CustomerId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        crate::generated::mutations::customer_delete(self, id).await
+    }
+    /// Merges two customers by transferring all needs from the source customer to the target customer. The source customer is archived after the merge. Domains, external IDs, and metadata are combined on the target customer.
+    ///
+    /// Full type: [`Customer`](super::types::Customer)
+    ///
+    /// Requires the `customers` feature.
+    #[cfg(feature = "customers")]
+    pub async fn customer_merge<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Customer>,
+    >(
+        &self,
+        source_customer_id: String,
+        target_customer_id: String,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::customer_merge::<T>(
+            self,
+            source_customer_id,
+            target_customer_id,
+        )
+        .await
+    }
+    /// Upserts a customer, creating it if no match is found, or updating it otherwise. Matches against existing customers using `id`, `externalId`, `slackChannelId`, or `domains`.
+    ///
+    /// Full type: [`Customer`](super::types::Customer)
+    ///
+    /// Requires the `customers` feature.
+    #[cfg(feature = "customers")]
+    pub async fn customer_upsert<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Customer>,
+    >(
+        &self,
+        input: CustomerUpsertInput,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::customer_upsert::<T>(self, input).await
+    }
+    /// Unsyncs a managed customer from its current data source integration. External IDs mapping to the external source will be cleared, and the customer will no longer be updated by the integration.
+    ///
+    /// Full type: [`Customer`](super::types::Customer)
+    ///
+    /// Requires the `customers` feature.
+    #[cfg(feature = "customers")]
+    pub async fn customer_unsync<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Customer>,
+    >(
+        &self,
+        id: impl Into<super::ids::CustomerId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::customer_unsync::<T>(self, id).await
+    }
+    /// Creates a new customer need.
+    ///
+    /// Full type: [`CustomerNeed`](super::types::CustomerNeed)
+    ///
+    /// Requires the `customers` feature.
+    #[cfg(feature = "customers")]
+    pub async fn customer_need_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::CustomerNeed>,
+    >(
+        &self,
+        input: CustomerNeedCreateInput,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::customer_need_create::<T>(self, input).await
+    }
+    /// Creates a new customer need from an existing issue attachment. If the attachment already has an archived need, it will be unarchived instead of creating a duplicate.
+    ///
+    /// Full type: [`CustomerNeed`](super::types::CustomerNeed)
+    ///
+    /// Requires the `customers` feature.
+    #[cfg(feature = "customers")]
+    pub async fn customer_need_create_from_attachment<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::CustomerNeed>,
+    >(
+        &self,
+        input: CustomerNeedCreateFromAttachmentInput,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::customer_need_create_from_attachment::<T>(self, input).await
+    }
+    /// Updates an existing customer need. Supports moving the need to a different issue or project, changing priority, updating the body content, and managing the attached source URL.
+    ///
+    /// Full type: [`CustomerNeed`](super::types::CustomerNeed)
+    ///
+    /// Requires the `customers` feature.
+    #[cfg(feature = "customers")]
+    pub async fn customer_need_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::CustomerNeed>,
+    >(
+        &self,
+        clear_attachment: Option<bool>,
+        input: CustomerNeedUpdateInput,
+        id: impl Into<super::ids::CustomerNeedId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::customer_need_update::<T>(self, clear_attachment, input, id)
+            .await
+    }
+    /// Deletes a customer need.
+    ///
+    /// Requires the `customers` feature.
+    #[cfg(feature = "customers")]
+    pub async fn customer_need_delete(
+        &self,
+        keep_attachment: Option<bool>,
+        id: impl Into<super::ids::CustomerNeedId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        crate::generated::mutations::customer_need_delete(self, keep_attachment, id).await
+    }
+    /// Archives a customer need.
+    ///
+    /// Full type: [`CustomerNeed`](super::types::CustomerNeed)
+    ///
+    /// Requires the `customers` feature.
+    #[cfg(feature = "customers")]
+    pub async fn customer_need_archive<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::CustomerNeed>,
+    >(
+        &self,
+        id: impl Into<super::ids::CustomerNeedId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::customer_need_archive::<T>(self, id).await
+    }
+    /// Unarchives a customer need.
+    ///
+    /// Full type: [`CustomerNeed`](super::types::CustomerNeed)
+    ///
+    /// Requires the `customers` feature.
+    #[cfg(feature = "customers")]
+    pub async fn customer_need_unarchive<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::CustomerNeed>,
+    >(
+        &self,
+        id: impl Into<super::ids::CustomerNeedId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::customer_need_unarchive::<T>(self, id).await
+    }
+    /// Creates a new custom view.
+    ///
+    /// Full type: [`CustomView`](super::types::CustomView)
+    ///
+    /// Requires the `views` feature.
+    #[cfg(feature = "views")]
+    pub async fn custom_view_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::CustomView>,
+    >(
+        &self,
+        input: CustomViewCreateInput,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::custom_view_create::<T>(self, input).await
+    }
+    /// Updates a custom view.
+    ///
+    /// Full type: [`CustomView`](super::types::CustomView)
+    ///
+    /// Requires the `views` feature.
+    #[cfg(feature = "views")]
+    pub async fn custom_view_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::CustomView>,
+    >(
+        &self,
+        input: CustomViewUpdateInput,
+        id: impl Into<super::ids::CustomViewId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::custom_view_update::<T>(self, input, id).await
+    }
+    /// Deletes a custom view.
+    ///
+    /// Requires the `views` feature.
+    #[cfg(feature = "views")]
+    pub async fn custom_view_delete(
+        &self,
+        id: impl Into<super::ids::CustomViewId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        crate::generated::mutations::custom_view_delete(self, id).await
+    }
+    /// Creates a support contact message from an authenticated user. The message is saved and forwarded to Intercom for support handling.
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub async fn contact_create(
+        &self,
+        input: ContactCreateInput,
+    ) -> Result<super::types::ContactPayload, LinearError> {
+        crate::generated::mutations::contact_create(self, input).await
+    }
+    /// `INTERNAL` Submits a sales pricing inquiry from the website. Small companies (1-19 employees) are routed to Intercom, while larger companies are routed to HubSpot.
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub async fn contact_sales_create(
+        &self,
+        input: ContactSalesCreateInput,
+    ) -> Result<super::types::ContactPayload, LinearError> {
+        crate::generated::mutations::contact_sales_create(self, input).await
+    }
+    /// Creates a new comment.
+    ///
+    /// Full type: [`Comment`](super::types::Comment)
+    pub async fn comment_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Comment>,
+    >(
+        &self,
+        input: CommentCreateInput,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::comment_create::<T>(self, input).await
+    }
+    /// Updates a comment.
+    ///
+    /// Full type: [`Comment`](super::types::Comment)
+    pub async fn comment_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Comment>,
+    >(
+        &self,
+        skip_edited_at: Option<bool>,
+        input: CommentUpdateInput,
+        id: impl Into<super::ids::CommentId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::comment_update::<T>(self, skip_edited_at, input, id).await
+    }
+    /// Deletes a comment.
+    pub async fn comment_delete(
+        &self,
+        id: impl Into<super::ids::CommentId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        crate::generated::mutations::comment_delete(self, id).await
+    }
+    /// Resolves a comment thread. Marks the root comment as resolved by the current user.
+    ///
+    /// Full type: [`Comment`](super::types::Comment)
+    pub async fn comment_resolve<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Comment>,
+    >(
+        &self,
+        resolving_comment_id: Option<String>,
+        id: impl Into<super::ids::CommentId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::comment_resolve::<T>(self, resolving_comment_id, id).await
+    }
+    /// Unresolves a previously resolved comment thread. Clears the resolved state on the root comment.
+    ///
+    /// Full type: [`Comment`](super::types::Comment)
+    pub async fn comment_unresolve<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Comment>,
+    >(
+        &self,
+        id: impl Into<super::ids::CommentId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::comment_unresolve::<T>(self, id).await
+    }
+    /// Finds or creates a new user account by email and sends an email with token.
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub async fn email_user_account_auth_challenge(
+        &self,
+        input: EmailUserAccountAuthChallengeInput,
+    ) -> Result<super::types::EmailUserAccountAuthChallengeResponse, LinearError> {
+        crate::generated::mutations::email_user_account_auth_challenge(self, input).await
+    }
+    /// Authenticates a user account via email and authentication token.
+    ///
+    /// Full type: [`AuthUser`](super::types::AuthUser)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub async fn email_token_user_account_auth<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::AuthUser>,
+    >(
+        &self,
+        input: TokenUserAccountAuthInput,
+    ) -> Result<Vec<T>, LinearError> {
+        crate::generated::mutations::email_token_user_account_auth::<T>(self, input).await
+    }
+    /// Authenticates a user account via email and authentication token for SAML.
+    ///
+    /// Full type: [`AuthUser`](super::types::AuthUser)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub async fn saml_token_user_account_auth<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::AuthUser>,
+    >(
+        &self,
+        input: TokenUserAccountAuthInput,
+    ) -> Result<Vec<T>, LinearError> {
+        crate::generated::mutations::saml_token_user_account_auth::<T>(self, input).await
+    }
+    /// Authenticate user account through Google OAuth. This is the 2nd step of OAuth flow.
+    ///
+    /// Full type: [`AuthUser`](super::types::AuthUser)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub async fn google_user_account_auth<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::AuthUser>,
+    >(
+        &self,
+        input: GoogleUserAccountAuthInput,
+    ) -> Result<Vec<T>, LinearError> {
+        crate::generated::mutations::google_user_account_auth::<T>(self, input).await
+    }
+    /// `INTERNAL` Starts passkey login process.
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub async fn passkey_login_start(
+        &self,
+        auth_id: String,
+    ) -> Result<super::types::PasskeyLoginStartResponse, LinearError> {
+        crate::generated::mutations::passkey_login_start(self, auth_id).await
+    }
+    /// `INTERNAL` Finish passkey login process.
+    ///
+    /// Full type: [`AuthUser`](super::types::AuthUser)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub async fn passkey_login_finish<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::AuthUser>,
+    >(
+        &self,
+        response: serde_json::Value,
+        auth_id: String,
+    ) -> Result<Vec<T>, LinearError> {
+        crate::generated::mutations::passkey_login_finish::<T>(self, response, auth_id).await
+    }
+    /// Creates a new workspace from onboarding.
+    ///
+    /// Full type: [`AuthOrganization`](super::types::AuthOrganization)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub async fn create_organization_from_onboarding<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::AuthOrganization>,
+    >(
+        &self,
+        session_id: Option<String>,
+        survey: Option<OnboardingCustomerSurvey>,
+        input: CreateOrganizationInput,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::create_organization_from_onboarding::<T>(
+            self, session_id, survey, input,
+        )
+        .await
+    }
+    /// Join a workspace from onboarding.
+    ///
+    /// Full type: [`AuthOrganization`](super::types::AuthOrganization)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub async fn join_organization_from_onboarding<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::AuthOrganization>,
+    >(
+        &self,
+        input: JoinOrganizationInput,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::join_organization_from_onboarding::<T>(self, input).await
+    }
+    /// Leave a workspace.
+    ///
+    /// Full type: [`AuthOrganization`](super::types::AuthOrganization)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub async fn leave_organization<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::AuthOrganization>,
+    >(
+        &self,
+        organization_id: String,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::leave_organization::<T>(self, organization_id).await
+    }
+    /// Logout the client.
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub async fn logout(
+        &self,
+        reason: Option<String>,
+    ) -> Result<super::types::LogoutResponse, LinearError> {
+        crate::generated::mutations::logout(self, reason).await
+    }
+    /// Logout an individual session with its ID.
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub async fn logout_session(
+        &self,
+        session_id: String,
+    ) -> Result<super::types::LogoutResponse, LinearError> {
+        crate::generated::mutations::logout_session(self, session_id).await
+    }
+    /// Logout all of user's sessions including the active one.
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub async fn logout_all_sessions(
+        &self,
+        reason: Option<String>,
+    ) -> Result<super::types::LogoutResponse, LinearError> {
+        crate::generated::mutations::logout_all_sessions(self, reason).await
+    }
+    /// Logout all of user's sessions excluding the current one.
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub async fn logout_other_sessions(
+        &self,
+        reason: Option<String>,
+    ) -> Result<super::types::LogoutResponse, LinearError> {
+        crate::generated::mutations::logout_other_sessions(self, reason).await
+    }
+    /// Creates a new attachment, or updates existing if the same `url` and `issueId` is used. To create an integration-aware attachment, use the integration-specific mutations such as `attachmentLinkZendesk`, `attachmentLinkSlack`, or `attachmentLinkURL` instead.
+    ///
+    /// Full type: [`Attachment`](super::types::Attachment)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub async fn attachment_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Attachment>,
+    >(
+        &self,
+        input: AttachmentCreateInput,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::attachment_create::<T>(self, input).await
+    }
+    /// Updates an existing issue attachment.
+    ///
+    /// Full type: [`Attachment`](super::types::Attachment)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub async fn attachment_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Attachment>,
+    >(
+        &self,
+        input: AttachmentUpdateInput,
+        id: impl Into<super::ids::AttachmentId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::attachment_update::<T>(self, input, id).await
+    }
+    /// Link any URL to an issue. If the workspace has a matching integration configured and the URL is recognized (e.g., Zendesk, GitHub, Slack), a rich attachment will be created that enables features like automated status updates. Otherwise, a basic attachment is created.
+    ///
+    /// Full type: [`Attachment`](super::types::Attachment)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub async fn attachment_link_url<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Attachment>,
+    >(
+        &self,
+        create_as_user: Option<String>,
+        display_icon_url: Option<String>,
+        title: Option<String>,
+        url: String,
+        issue_id: String,
+        id: Option<String>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::attachment_link_url::<T>(
+            self,
+            create_as_user,
+            display_icon_url,
+            title,
+            url,
+            issue_id,
+            id,
+        )
+        .await
+    }
+    /// Link an existing GitLab MR to an issue. This creates a rich attachment using the workspace's GitLab integration, enabling features like automated status syncing.
+    ///
+    /// Full type: [`Attachment`](super::types::Attachment)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub async fn attachment_link_git_lab_mr<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Attachment>,
+    >(
+        &self,
+        create_as_user: Option<String>,
+        display_icon_url: Option<String>,
+        title: Option<String>,
+        issue_id: String,
+        id: Option<String>,
+        url: String,
+        project_path_with_namespace: String,
+        number: f64,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::attachment_link_git_lab_mr::<T>(
+            self,
+            create_as_user,
+            display_icon_url,
+            title,
+            issue_id,
+            id,
+            url,
+            project_path_with_namespace,
+            number,
+        )
+        .await
+    }
+    /// Link a GitHub issue to a Linear issue. This creates a rich attachment using the workspace's GitHub integration, enabling features like automated status syncing.
+    ///
+    /// Full type: [`Attachment`](super::types::Attachment)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub async fn attachment_link_git_hub_issue<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Attachment>,
+    >(
+        &self,
+        create_as_user: Option<String>,
+        display_icon_url: Option<String>,
+        title: Option<String>,
+        issue_id: String,
+        id: Option<String>,
+        url: String,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::attachment_link_git_hub_issue::<T>(
+            self,
+            create_as_user,
+            display_icon_url,
+            title,
+            issue_id,
+            id,
+            url,
+        )
+        .await
+    }
+    /// Link a GitHub pull request to an issue. This creates a rich attachment using the workspace's GitHub integration, enabling features like automated status syncing.
+    ///
+    /// Full type: [`Attachment`](super::types::Attachment)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub async fn attachment_link_git_hub_pr<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Attachment>,
+    >(
+        &self,
+        create_as_user: Option<String>,
+        display_icon_url: Option<String>,
+        title: Option<String>,
+        issue_id: String,
+        id: Option<String>,
+        url: String,
+        link_kind: Option<GitLinkKind>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::attachment_link_git_hub_pr::<T>(
+            self,
+            create_as_user,
+            display_icon_url,
+            title,
+            issue_id,
+            id,
+            url,
+            link_kind,
+        )
+        .await
+    }
+    /// Link an existing Zendesk ticket to an issue. This creates a rich attachment using the workspace's Zendesk integration, enabling features like automated ticket reopening when the Linear issue is completed.
+    ///
+    /// Full type: [`Attachment`](super::types::Attachment)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub async fn attachment_link_zendesk<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Attachment>,
+    >(
+        &self,
+        create_as_user: Option<String>,
+        display_icon_url: Option<String>,
+        title: Option<String>,
+        ticket_id: String,
+        issue_id: String,
+        id: Option<String>,
+        url: Option<String>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::attachment_link_zendesk::<T>(
+            self,
+            create_as_user,
+            display_icon_url,
+            title,
+            ticket_id,
+            issue_id,
+            id,
+            url,
+        )
+        .await
+    }
+    /// Link an existing Discord message to an issue. This creates a rich attachment using the workspace's Discord integration.
+    ///
+    /// Full type: [`Attachment`](super::types::Attachment)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub async fn attachment_link_discord<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Attachment>,
+    >(
+        &self,
+        create_as_user: Option<String>,
+        display_icon_url: Option<String>,
+        title: Option<String>,
+        issue_id: String,
+        id: Option<String>,
+        channel_id: String,
+        message_id: String,
+        url: String,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::attachment_link_discord::<T>(
+            self,
+            create_as_user,
+            display_icon_url,
+            title,
+            issue_id,
+            id,
+            channel_id,
+            message_id,
+            url,
+        )
+        .await
+    }
+    /// Begin syncing the thread for an existing Slack message attachment with a comment thread on its issue.
+    ///
+    /// Full type: [`Attachment`](super::types::Attachment)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub async fn attachment_sync_to_slack<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Attachment>,
+    >(
+        &self,
+        id: impl Into<super::ids::AttachmentId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::attachment_sync_to_slack::<T>(self, id).await
+    }
+    /// Link an existing Slack message to an issue. This creates a rich attachment using the workspace's Slack integration.
+    ///
+    /// Full type: [`Attachment`](super::types::Attachment)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub async fn attachment_link_slack<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Attachment>,
+    >(
+        &self,
+        create_as_user: Option<String>,
+        display_icon_url: Option<String>,
+        title: Option<String>,
+        issue_id: String,
+        url: String,
+        id: Option<String>,
+        sync_to_comment_thread: Option<bool>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::attachment_link_slack::<T>(
+            self,
+            create_as_user,
+            display_icon_url,
+            title,
+            issue_id,
+            url,
+            id,
+            sync_to_comment_thread,
+        )
+        .await
+    }
+    /// Link an existing Front conversation to an issue. This creates a rich attachment using the workspace's Front integration, enabling features like automated conversation updates.
+    ///
+    /// Full type: [`Attachment`](super::types::Attachment)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub async fn attachment_link_front<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Attachment>,
+    >(
+        &self,
+        create_as_user: Option<String>,
+        display_icon_url: Option<String>,
+        title: Option<String>,
+        conversation_id: String,
+        issue_id: String,
+        id: Option<String>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::attachment_link_front::<T>(
+            self,
+            create_as_user,
+            display_icon_url,
+            title,
+            conversation_id,
+            issue_id,
+            id,
+        )
+        .await
+    }
+    /// Link an existing Intercom conversation to an issue. This creates a rich attachment using the workspace's Intercom integration, enabling features like automated conversation updates.
+    ///
+    /// Full type: [`Attachment`](super::types::Attachment)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub async fn attachment_link_intercom<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Attachment>,
+    >(
+        &self,
+        create_as_user: Option<String>,
+        display_icon_url: Option<String>,
+        title: Option<String>,
+        conversation_id: String,
+        part_id: Option<String>,
+        id: Option<String>,
+        issue_id: String,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::attachment_link_intercom::<T>(
+            self,
+            create_as_user,
+            display_icon_url,
+            title,
+            conversation_id,
+            part_id,
+            id,
+            issue_id,
+        )
+        .await
+    }
+    /// Link an existing Jira issue to an issue. This creates a rich attachment using the workspace's Jira integration, enabling features like automated status syncing.
+    ///
+    /// Full type: [`Attachment`](super::types::Attachment)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub async fn attachment_link_jira_issue<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Attachment>,
+    >(
+        &self,
+        create_as_user: Option<String>,
+        display_icon_url: Option<String>,
+        title: Option<String>,
+        issue_id: String,
+        jira_issue_id: String,
+        id: Option<String>,
+        url: Option<String>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::attachment_link_jira_issue::<T>(
+            self,
+            create_as_user,
+            display_icon_url,
+            title,
+            issue_id,
+            jira_issue_id,
+            id,
+            url,
+        )
+        .await
+    }
+    /// Link an existing Salesforce case to an issue. This creates a rich attachment using the workspace's Salesforce integration.
+    ///
+    /// Full type: [`Attachment`](super::types::Attachment)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub async fn attachment_link_salesforce<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::Attachment>,
+    >(
+        &self,
+        create_as_user: Option<String>,
+        display_icon_url: Option<String>,
+        title: Option<String>,
+        issue_id: String,
+        id: Option<String>,
+        url: String,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::attachment_link_salesforce::<T>(
+            self,
+            create_as_user,
+            display_icon_url,
+            title,
+            issue_id,
+            id,
+            url,
+        )
+        .await
+    }
+    /// Deletes an issue attachment.
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub async fn attachment_delete(
+        &self,
+        id: impl Into<super::ids::AttachmentId>,
+    ) -> Result<super::types::DeletePayload, LinearError> {
+        crate::generated::mutations::attachment_delete(self, id).await
+    }
+    /// Creates a new agent session on a root comment.
+    ///
+    /// Full type: [`AgentSession`](super::types::AgentSession)
+    ///
+    /// Requires the `agents` feature.
+    #[cfg(feature = "agents")]
+    pub async fn agent_session_create_on_comment<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::AgentSession>,
+    >(
+        &self,
+        input: AgentSessionCreateOnComment,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::agent_session_create_on_comment::<T>(self, input).await
+    }
+    /// Creates a new agent session on an issue.
+    ///
+    /// Full type: [`AgentSession`](super::types::AgentSession)
+    ///
+    /// Requires the `agents` feature.
+    #[cfg(feature = "agents")]
+    pub async fn agent_session_create_on_issue<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::AgentSession>,
+    >(
+        &self,
+        input: AgentSessionCreateOnIssue,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::agent_session_create_on_issue::<T>(self, input).await
+    }
+    /// `Internal` Creates a new agent session on behalf of the current user
+    ///
+    /// Full type: [`AgentSession`](super::types::AgentSession)
+    ///
+    /// Requires the `agents` feature.
+    #[cfg(feature = "agents")]
+    pub async fn agent_session_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::AgentSession>,
+    >(
+        &self,
+        pull_request_id: Option<String>,
+        input: AgentSessionCreateInput,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::agent_session_create::<T>(self, pull_request_id, input).await
+    }
+    /// Updates the externalUrl of an agent session, which is an agent-hosted page associated with this session.
+    ///
+    /// Full type: [`AgentSession`](super::types::AgentSession)
+    ///
+    /// Requires the `agents` feature.
+    #[cfg(feature = "agents")]
+    pub async fn agent_session_update_external_url<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::AgentSession>,
+    >(
+        &self,
+        input: AgentSessionUpdateExternalUrlInput,
+        id: impl Into<super::ids::AgentSessionId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::agent_session_update_external_url::<T>(self, input, id).await
+    }
+    /// Updates an agent session.
+    ///
+    /// Full type: [`AgentSession`](super::types::AgentSession)
+    ///
+    /// Requires the `agents` feature.
+    #[cfg(feature = "agents")]
+    pub async fn agent_session_update<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::AgentSession>,
+    >(
+        &self,
+        input: AgentSessionUpdateInput,
+        id: impl Into<super::ids::AgentSessionId>,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::agent_session_update::<T>(self, input, id).await
+    }
+    /// Creates an agent activity.
+    ///
+    /// Full type: [`AgentActivity`](super::types::AgentActivity)
+    ///
+    /// Requires the `agents` feature.
+    #[cfg(feature = "agents")]
+    pub async fn agent_activity_create<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::AgentActivity>,
+    >(
+        &self,
+        input: AgentActivityCreateInput,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::agent_activity_create::<T>(self, input).await
+    }
+    /// `Internal` Creates a prompt agent activity from Linear user input.
+    ///
+    /// Full type: [`AgentActivity`](super::types::AgentActivity)
+    ///
+    /// Requires the `agents` feature.
+    #[cfg(feature = "agents")]
+    pub async fn agent_activity_create_prompt<
+        T: serde::de::DeserializeOwned
+            + crate::field_selection::GraphQLFields<FullType = super::types::AgentActivity>,
+    >(
+        &self,
+        input: AgentActivityCreatePromptInput,
+    ) -> Result<T, LinearError> {
+        crate::generated::mutations::agent_activity_create_prompt::<T>(self, input).await
+    }
+    /// Triggered when a comment is created
+    ///
+    /// Event type: [`Comment`](super::types::Comment)
+    pub fn comment_created<T>(
+        &self,
+    ) -> crate::generated::subscriptions::CommentCreatedSubscriptionBuilder<'_, T> {
+        crate::generated::subscriptions::comment_created(self)
+    }
+    /// Triggered when a comment is updated
+    ///
+    /// Event type: [`Comment`](super::types::Comment)
+    pub fn comment_updated<T>(
+        &self,
+    ) -> crate::generated::subscriptions::CommentUpdatedSubscriptionBuilder<'_, T> {
+        crate::generated::subscriptions::comment_updated(self)
+    }
+    /// Triggered when a comment is archived
+    ///
+    /// Event type: [`Comment`](super::types::Comment)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub fn comment_archived<T>(
+        &self,
+    ) -> crate::generated::subscriptions::CommentArchivedSubscriptionBuilder<'_, T> {
+        crate::generated::subscriptions::comment_archived(self)
+    }
+    /// Triggered when a a comment is unarchived
+    ///
+    /// Event type: [`Comment`](super::types::Comment)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub fn comment_unarchived<T>(
+        &self,
+    ) -> crate::generated::subscriptions::CommentUnarchivedSubscriptionBuilder<'_, T> {
+        crate::generated::subscriptions::comment_unarchived(self)
+    }
+    /// Triggered when a comment is deleted
+    ///
+    /// Event type: [`Comment`](super::types::Comment)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub fn comment_deleted<T>(
+        &self,
+    ) -> crate::generated::subscriptions::CommentDeletedSubscriptionBuilder<'_, T> {
+        crate::generated::subscriptions::comment_deleted(self)
+    }
+    /// Triggered when a cycle is created
+    ///
+    /// Event type: [`Cycle`](super::types::Cycle)
+    ///
+    /// Requires the `cycles` feature.
+    #[cfg(feature = "cycles")]
+    pub fn cycle_created<T>(
+        &self,
+    ) -> crate::generated::subscriptions::CycleCreatedSubscriptionBuilder<'_, T> {
+        crate::generated::subscriptions::cycle_created(self)
+    }
+    /// Triggered when a cycle is updated
+    ///
+    /// Event type: [`Cycle`](super::types::Cycle)
+    ///
+    /// Requires the `cycles` feature.
+    #[cfg(feature = "cycles")]
+    pub fn cycle_updated<T>(
+        &self,
+    ) -> crate::generated::subscriptions::CycleUpdatedSubscriptionBuilder<'_, T> {
+        crate::generated::subscriptions::cycle_updated(self)
+    }
+    /// Triggered when a cycle is archived
+    ///
+    /// Event type: [`Cycle`](super::types::Cycle)
+    ///
+    /// Requires the `cycles` feature.
+    #[cfg(feature = "cycles")]
+    pub fn cycle_archived<T>(
+        &self,
+    ) -> crate::generated::subscriptions::CycleArchivedSubscriptionBuilder<'_, T> {
+        crate::generated::subscriptions::cycle_archived(self)
+    }
+    /// Triggered when a document is created
+    ///
+    /// Event type: [`Document`](super::types::Document)
+    ///
+    /// Requires the `documents` feature.
+    #[cfg(feature = "documents")]
+    pub fn document_created<T>(
+        &self,
+    ) -> crate::generated::subscriptions::DocumentCreatedSubscriptionBuilder<'_, T> {
+        crate::generated::subscriptions::document_created(self)
+    }
+    /// Triggered when a document is updated
+    ///
+    /// Event type: [`Document`](super::types::Document)
+    ///
+    /// Requires the `documents` feature.
+    #[cfg(feature = "documents")]
+    pub fn document_updated<T>(
+        &self,
+    ) -> crate::generated::subscriptions::DocumentUpdatedSubscriptionBuilder<'_, T> {
+        crate::generated::subscriptions::document_updated(self)
+    }
+    /// Triggered when a document is archived
+    ///
+    /// Event type: [`Document`](super::types::Document)
+    ///
+    /// Requires the `documents` feature.
+    #[cfg(feature = "documents")]
+    pub fn document_archived<T>(
+        &self,
+    ) -> crate::generated::subscriptions::DocumentArchivedSubscriptionBuilder<'_, T> {
+        crate::generated::subscriptions::document_archived(self)
+    }
+    /// Triggered when a a document is unarchived
+    ///
+    /// Event type: [`Document`](super::types::Document)
+    ///
+    /// Requires the `documents` feature.
+    #[cfg(feature = "documents")]
+    pub fn document_unarchived<T>(
+        &self,
+    ) -> crate::generated::subscriptions::DocumentUnarchivedSubscriptionBuilder<'_, T> {
+        crate::generated::subscriptions::document_unarchived(self)
+    }
+    /// Triggered when a document content is created
+    ///
+    /// Event type: [`DocumentContent`](super::types::DocumentContent)
+    ///
+    /// Requires the `documents` feature.
+    #[cfg(feature = "documents")]
+    pub fn document_content_created<T>(
+        &self,
+    ) -> crate::generated::subscriptions::DocumentContentCreatedSubscriptionBuilder<'_, T> {
+        crate::generated::subscriptions::document_content_created(self)
+    }
+    /// Triggered when a document content is updated
+    ///
+    /// Event type: [`DocumentContent`](super::types::DocumentContent)
+    pub fn document_content_updated<T>(
+        &self,
+    ) -> crate::generated::subscriptions::DocumentContentUpdatedSubscriptionBuilder<'_, T> {
+        crate::generated::subscriptions::document_content_updated(self)
+    }
+    /// Triggered when a document content draft is created
+    ///
+    /// Event type: [`DocumentContentDraft`](super::types::DocumentContentDraft)
+    ///
+    /// Requires the `documents` feature.
+    #[cfg(feature = "documents")]
+    pub fn document_content_draft_created<T>(
+        &self,
+    ) -> crate::generated::subscriptions::DocumentContentDraftCreatedSubscriptionBuilder<'_, T>
+    {
+        crate::generated::subscriptions::document_content_draft_created(self)
+    }
+    /// Triggered when a document content draft is updated
+    ///
+    /// Event type: [`DocumentContentDraft`](super::types::DocumentContentDraft)
+    ///
+    /// Requires the `documents` feature.
+    #[cfg(feature = "documents")]
+    pub fn document_content_draft_updated<T>(
+        &self,
+    ) -> crate::generated::subscriptions::DocumentContentDraftUpdatedSubscriptionBuilder<'_, T>
+    {
+        crate::generated::subscriptions::document_content_draft_updated(self)
+    }
+    /// Triggered when a document content draft is deleted
+    ///
+    /// Event type: [`DocumentContentDraft`](super::types::DocumentContentDraft)
+    ///
+    /// Requires the `documents` feature.
+    #[cfg(feature = "documents")]
+    pub fn document_content_draft_deleted<T>(
+        &self,
+    ) -> crate::generated::subscriptions::DocumentContentDraftDeletedSubscriptionBuilder<'_, T>
+    {
+        crate::generated::subscriptions::document_content_draft_deleted(self)
+    }
+    /// Triggered when a draft is created
+    ///
+    /// Event type: [`Draft`](super::types::Draft)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub fn draft_created<T>(
+        &self,
+    ) -> crate::generated::subscriptions::DraftCreatedSubscriptionBuilder<'_, T> {
+        crate::generated::subscriptions::draft_created(self)
+    }
+    /// Triggered when a draft is updated
+    ///
+    /// Event type: [`Draft`](super::types::Draft)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub fn draft_updated<T>(
+        &self,
+    ) -> crate::generated::subscriptions::DraftUpdatedSubscriptionBuilder<'_, T> {
+        crate::generated::subscriptions::draft_updated(self)
+    }
+    /// Triggered when a draft is deleted
+    ///
+    /// Event type: [`Draft`](super::types::Draft)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub fn draft_deleted<T>(
+        &self,
+    ) -> crate::generated::subscriptions::DraftDeletedSubscriptionBuilder<'_, T> {
+        crate::generated::subscriptions::draft_deleted(self)
+    }
+    /// Triggered when a favorite is created
+    ///
+    /// Event type: [`Favorite`](super::types::Favorite)
+    ///
+    /// Requires the `views` feature.
+    #[cfg(feature = "views")]
+    pub fn favorite_created<T>(
+        &self,
+    ) -> crate::generated::subscriptions::FavoriteCreatedSubscriptionBuilder<'_, T> {
+        crate::generated::subscriptions::favorite_created(self)
+    }
+    /// Triggered when a favorite is updated
+    ///
+    /// Event type: [`Favorite`](super::types::Favorite)
+    ///
+    /// Requires the `views` feature.
+    #[cfg(feature = "views")]
+    pub fn favorite_updated<T>(
+        &self,
+    ) -> crate::generated::subscriptions::FavoriteUpdatedSubscriptionBuilder<'_, T> {
+        crate::generated::subscriptions::favorite_updated(self)
+    }
+    /// Triggered when a favorite is deleted
+    ///
+    /// Event type: [`Favorite`](super::types::Favorite)
+    ///
+    /// Requires the `views` feature.
+    #[cfg(feature = "views")]
+    pub fn favorite_deleted<T>(
+        &self,
+    ) -> crate::generated::subscriptions::FavoriteDeletedSubscriptionBuilder<'_, T> {
+        crate::generated::subscriptions::favorite_deleted(self)
+    }
+    /// Triggered when an issue is created
+    ///
+    /// Event type: [`Issue`](super::types::Issue)
+    pub fn issue_created<T>(
+        &self,
+    ) -> crate::generated::subscriptions::IssueCreatedSubscriptionBuilder<'_, T> {
+        crate::generated::subscriptions::issue_created(self)
+    }
+    /// Triggered when an issue is updated
+    ///
+    /// Event type: [`Issue`](super::types::Issue)
+    pub fn issue_updated<T>(
+        &self,
+    ) -> crate::generated::subscriptions::IssueUpdatedSubscriptionBuilder<'_, T> {
+        crate::generated::subscriptions::issue_updated(self)
+    }
+    /// Triggered when an issue is archived
+    ///
+    /// Event type: [`Issue`](super::types::Issue)
+    pub fn issue_archived<T>(
+        &self,
+    ) -> crate::generated::subscriptions::IssueArchivedSubscriptionBuilder<'_, T> {
+        crate::generated::subscriptions::issue_archived(self)
+    }
+    /// Triggered when a an issue is unarchived
+    ///
+    /// Event type: [`Issue`](super::types::Issue)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub fn issue_unarchived<T>(
+        &self,
+    ) -> crate::generated::subscriptions::IssueUnarchivedSubscriptionBuilder<'_, T> {
+        crate::generated::subscriptions::issue_unarchived(self)
+    }
+    /// Triggered when an issue history is created
+    ///
+    /// Event type: [`IssueHistory`](super::types::IssueHistory)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub fn issue_history_created<T>(
+        &self,
+    ) -> crate::generated::subscriptions::IssueHistoryCreatedSubscriptionBuilder<'_, T> {
+        crate::generated::subscriptions::issue_history_created(self)
+    }
+    /// Triggered when an issue history is updated
+    ///
+    /// Event type: [`IssueHistory`](super::types::IssueHistory)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub fn issue_history_updated<T>(
+        &self,
+    ) -> crate::generated::subscriptions::IssueHistoryUpdatedSubscriptionBuilder<'_, T> {
+        crate::generated::subscriptions::issue_history_updated(self)
+    }
+    /// Triggered when an issue draft is created
+    ///
+    /// Event type: [`IssueDraft`](super::types::IssueDraft)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub fn issue_draft_created<T>(
+        &self,
+    ) -> crate::generated::subscriptions::IssueDraftCreatedSubscriptionBuilder<'_, T> {
+        crate::generated::subscriptions::issue_draft_created(self)
+    }
+    /// Triggered when an issue draft is updated
+    ///
+    /// Event type: [`IssueDraft`](super::types::IssueDraft)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub fn issue_draft_updated<T>(
+        &self,
+    ) -> crate::generated::subscriptions::IssueDraftUpdatedSubscriptionBuilder<'_, T> {
+        crate::generated::subscriptions::issue_draft_updated(self)
+    }
+    /// Triggered when an issue draft is deleted
+    ///
+    /// Event type: [`IssueDraft`](super::types::IssueDraft)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub fn issue_draft_deleted<T>(
+        &self,
+    ) -> crate::generated::subscriptions::IssueDraftDeletedSubscriptionBuilder<'_, T> {
+        crate::generated::subscriptions::issue_draft_deleted(self)
+    }
+    /// Triggered when an issue label is created
+    ///
+    /// Event type: [`IssueLabel`](super::types::IssueLabel)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub fn issue_label_created<T>(
+        &self,
+    ) -> crate::generated::subscriptions::IssueLabelCreatedSubscriptionBuilder<'_, T> {
+        crate::generated::subscriptions::issue_label_created(self)
+    }
+    /// Triggered when an issue label is updated
+    ///
+    /// Event type: [`IssueLabel`](super::types::IssueLabel)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub fn issue_label_updated<T>(
+        &self,
+    ) -> crate::generated::subscriptions::IssueLabelUpdatedSubscriptionBuilder<'_, T> {
+        crate::generated::subscriptions::issue_label_updated(self)
+    }
+    /// Triggered when an issue label is deleted
+    ///
+    /// Event type: [`IssueLabel`](super::types::IssueLabel)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub fn issue_label_deleted<T>(
+        &self,
+    ) -> crate::generated::subscriptions::IssueLabelDeletedSubscriptionBuilder<'_, T> {
+        crate::generated::subscriptions::issue_label_deleted(self)
+    }
+    /// Triggered when an issue relation is created
+    ///
+    /// Event type: [`IssueRelation`](super::types::IssueRelation)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub fn issue_relation_created<T>(
+        &self,
+    ) -> crate::generated::subscriptions::IssueRelationCreatedSubscriptionBuilder<'_, T> {
+        crate::generated::subscriptions::issue_relation_created(self)
+    }
+    /// Triggered when an issue relation is updated
+    ///
+    /// Event type: [`IssueRelation`](super::types::IssueRelation)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub fn issue_relation_updated<T>(
+        &self,
+    ) -> crate::generated::subscriptions::IssueRelationUpdatedSubscriptionBuilder<'_, T> {
+        crate::generated::subscriptions::issue_relation_updated(self)
+    }
+    /// Triggered when an issue relation is deleted
+    ///
+    /// Event type: [`IssueRelation`](super::types::IssueRelation)
+    ///
+    /// Requires the `issues` feature.
+    #[cfg(feature = "issues")]
+    pub fn issue_relation_deleted<T>(
+        &self,
+    ) -> crate::generated::subscriptions::IssueRelationDeletedSubscriptionBuilder<'_, T> {
+        crate::generated::subscriptions::issue_relation_deleted(self)
+    }
+    /// Triggered when a notification is created
+    ///
+    /// Event type: [`Notification`](super::types::Notification)
+    pub fn notification_created<T>(
+        &self,
+    ) -> crate::generated::subscriptions::NotificationCreatedSubscriptionBuilder<'_, T> {
+        crate::generated::subscriptions::notification_created(self)
+    }
+    /// Triggered when a notification is updated
+    ///
+    /// Event type: [`Notification`](super::types::Notification)
+    ///
+    /// Requires the `notifications` feature.
+    #[cfg(feature = "notifications")]
+    pub fn notification_updated<T>(
+        &self,
+    ) -> crate::generated::subscriptions::NotificationUpdatedSubscriptionBuilder<'_, T> {
+        crate::generated::subscriptions::notification_updated(self)
+    }
+    /// Triggered when a notification is archived
+    ///
+    /// Event type: [`Notification`](super::types::Notification)
+    ///
+    /// Requires the `notifications` feature.
+    #[cfg(feature = "notifications")]
+    pub fn notification_archived<T>(
+        &self,
+    ) -> crate::generated::subscriptions::NotificationArchivedSubscriptionBuilder<'_, T> {
+        crate::generated::subscriptions::notification_archived(self)
+    }
+    /// Triggered when a a notification is unarchived
+    ///
+    /// Event type: [`Notification`](super::types::Notification)
+    ///
+    /// Requires the `notifications` feature.
+    #[cfg(feature = "notifications")]
+    pub fn notification_unarchived<T>(
+        &self,
+    ) -> crate::generated::subscriptions::NotificationUnarchivedSubscriptionBuilder<'_, T> {
+        crate::generated::subscriptions::notification_unarchived(self)
+    }
+    /// Triggered when a notification is deleted
+    ///
+    /// Event type: [`Notification`](super::types::Notification)
+    ///
+    /// Requires the `notifications` feature.
+    #[cfg(feature = "notifications")]
+    pub fn notification_deleted<T>(
+        &self,
+    ) -> crate::generated::subscriptions::NotificationDeletedSubscriptionBuilder<'_, T> {
+        crate::generated::subscriptions::notification_deleted(self)
+    }
+    /// Triggered when an organization is updated
+    ///
+    /// Event type: [`Organization`](super::types::Organization)
+    ///
+    /// Requires the `admin` feature.
+    #[cfg(feature = "admin")]
+    pub fn organization_updated<T>(
+        &self,
+    ) -> crate::generated::subscriptions::OrganizationUpdatedSubscriptionBuilder<'_, T> {
+        crate::generated::subscriptions::organization_updated(self)
+    }
+    /// Triggered when a project is created
+    ///
+    /// Event type: [`Project`](super::types::Project)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub fn project_created<T>(
+        &self,
+    ) -> crate::generated::subscriptions::ProjectCreatedSubscriptionBuilder<'_, T> {
+        crate::generated::subscriptions::project_created(self)
+    }
+    /// Triggered when a project is updated
+    ///
+    /// Event type: [`Project`](super::types::Project)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub fn project_updated<T>(
+        &self,
+    ) -> crate::generated::subscriptions::ProjectUpdatedSubscriptionBuilder<'_, T> {
+        crate::generated::subscriptions::project_updated(self)
+    }
+    /// Triggered when a project is archived
+    ///
+    /// Event type: [`Project`](super::types::Project)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub fn project_archived<T>(
+        &self,
+    ) -> crate::generated::subscriptions::ProjectArchivedSubscriptionBuilder<'_, T> {
+        crate::generated::subscriptions::project_archived(self)
+    }
+    /// Triggered when a a project is unarchived
+    ///
+    /// Event type: [`Project`](super::types::Project)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub fn project_unarchived<T>(
+        &self,
+    ) -> crate::generated::subscriptions::ProjectUnarchivedSubscriptionBuilder<'_, T> {
+        crate::generated::subscriptions::project_unarchived(self)
+    }
+    /// Triggered when a project update is created
+    ///
+    /// Event type: [`ProjectUpdate`](super::types::ProjectUpdate)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub fn project_update_created<T>(
+        &self,
+    ) -> crate::generated::subscriptions::ProjectUpdateCreatedSubscriptionBuilder<'_, T> {
+        crate::generated::subscriptions::project_update_created(self)
+    }
+    /// Triggered when a project update is updated
+    ///
+    /// Event type: [`ProjectUpdate`](super::types::ProjectUpdate)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub fn project_update_updated<T>(
+        &self,
+    ) -> crate::generated::subscriptions::ProjectUpdateUpdatedSubscriptionBuilder<'_, T> {
+        crate::generated::subscriptions::project_update_updated(self)
+    }
+    /// Triggered when a project update is deleted
+    ///
+    /// Event type: [`ProjectUpdate`](super::types::ProjectUpdate)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub fn project_update_deleted<T>(
+        &self,
+    ) -> crate::generated::subscriptions::ProjectUpdateDeletedSubscriptionBuilder<'_, T> {
+        crate::generated::subscriptions::project_update_deleted(self)
+    }
+    /// Triggered when a roadmap is created
+    ///
+    /// Event type: [`Roadmap`](super::types::Roadmap)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub fn roadmap_created<T>(
+        &self,
+    ) -> crate::generated::subscriptions::RoadmapCreatedSubscriptionBuilder<'_, T> {
+        crate::generated::subscriptions::roadmap_created(self)
+    }
+    /// Triggered when a roadmap is updated
+    ///
+    /// Event type: [`Roadmap`](super::types::Roadmap)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub fn roadmap_updated<T>(
+        &self,
+    ) -> crate::generated::subscriptions::RoadmapUpdatedSubscriptionBuilder<'_, T> {
+        crate::generated::subscriptions::roadmap_updated(self)
+    }
+    /// Triggered when a roadmap is deleted
+    ///
+    /// Event type: [`Roadmap`](super::types::Roadmap)
+    ///
+    /// Requires the `projects` feature.
+    #[cfg(feature = "projects")]
+    pub fn roadmap_deleted<T>(
+        &self,
+    ) -> crate::generated::subscriptions::RoadmapDeletedSubscriptionBuilder<'_, T> {
+        crate::generated::subscriptions::roadmap_deleted(self)
+    }
+    /// Triggered when an initiative is created
+    ///
+    /// Event type: [`Initiative`](super::types::Initiative)
+    ///
+    /// Requires the `initiatives` feature.
+    #[cfg(feature = "initiatives")]
+    pub fn initiative_created<T>(
+        &self,
+    ) -> crate::generated::subscriptions::InitiativeCreatedSubscriptionBuilder<'_, T> {
+        crate::generated::subscriptions::initiative_created(self)
+    }
+    /// Triggered when an initiative is updated
+    ///
+    /// Event type: [`Initiative`](super::types::Initiative)
+    ///
+    /// Requires the `initiatives` feature.
+    #[cfg(feature = "initiatives")]
+    pub fn initiative_updated<T>(
+        &self,
+    ) -> crate::generated::subscriptions::InitiativeUpdatedSubscriptionBuilder<'_, T> {
+        crate::generated::subscriptions::initiative_updated(self)
+    }
+    /// Triggered when an initiative is deleted
+    ///
+    /// Event type: [`Initiative`](super::types::Initiative)
+    ///
+    /// Requires the `initiatives` feature.
+    #[cfg(feature = "initiatives")]
+    pub fn initiative_deleted<T>(
+        &self,
+    ) -> crate::generated::subscriptions::InitiativeDeletedSubscriptionBuilder<'_, T> {
+        crate::generated::subscriptions::initiative_deleted(self)
+    }
+    /// Triggered when an agent session is created
+    ///
+    /// Event type: [`AgentSession`](super::types::AgentSession)
+    ///
+    /// Requires the `agents` feature.
+    #[cfg(feature = "agents")]
+    pub fn agent_session_created<T>(
+        &self,
+    ) -> crate::generated::subscriptions::AgentSessionCreatedSubscriptionBuilder<'_, T> {
+        crate::generated::subscriptions::agent_session_created(self)
+    }
+    /// Triggered when an agent session is updated
+    ///
+    /// Event type: [`AgentSession`](super::types::AgentSession)
+    ///
+    /// Requires the `agents` feature.
+    #[cfg(feature = "agents")]
+    pub fn agent_session_updated<T>(
+        &self,
+    ) -> crate::generated::subscriptions::AgentSessionUpdatedSubscriptionBuilder<'_, T> {
+        crate::generated::subscriptions::agent_session_updated(self)
+    }
+    /// Triggered when an agent activity is created
+    ///
+    /// Event type: [`AgentActivity`](super::types::AgentActivity)
+    ///
+    /// Requires the `agents` feature.
+    #[cfg(feature = "agents")]
+    pub fn agent_activity_created<T>(
+        &self,
+    ) -> crate::generated::subscriptions::AgentActivityCreatedSubscriptionBuilder<'_, T> {
+        crate::generated::subscriptions::agent_activity_created(self)
+    }
+    /// Triggered when an agent activity is updated
+    ///
+    /// Event type: [`AgentActivity`](super::types::AgentActivity)
+    ///
+    /// Requires the `agents` feature.
+    #[cfg(feature = "agents")]
+    pub fn agent_activity_updated<T>(
+        &self,
+    ) -> crate::generated::subscriptions::AgentActivityUpdatedSubscriptionBuilder<'_, T> {
+        crate::generated::subscriptions::agent_activity_updated(self)
+    }
+    /// Triggered when an ai conversation is updated
+    ///
+    /// Event type: [`AiConversation`](super::types::AiConversation)
+    ///
+    /// Requires the `agents` feature.
+    #[cfg(feature = "agents")]
+    pub fn ai_conversation_updated<T>(
+        &self,
+    ) -> crate::generated::subscriptions::AiConversationUpdatedSubscriptionBuilder<'_, T> {
+        crate::generated::subscriptions::ai_conversation_updated(self)
+    }
+    /// Triggered when an ai prompt progress is created
+    ///
+    /// Event type: [`AiPromptProgress`](super::types::AiPromptProgress)
+    ///
+    /// Requires the `agents` feature.
+    #[cfg(feature = "agents")]
+    pub fn ai_prompt_progress_created<T>(
+        &self,
+    ) -> crate::generated::subscriptions::AiPromptProgressCreatedSubscriptionBuilder<'_, T> {
+        crate::generated::subscriptions::ai_prompt_progress_created(self)
+    }
+    /// Triggered when an ai prompt progress is updated
+    ///
+    /// Event type: [`AiPromptProgress`](super::types::AiPromptProgress)
+    ///
+    /// Requires the `agents` feature.
+    #[cfg(feature = "agents")]
+    pub fn ai_prompt_progress_updated<T>(
+        &self,
+    ) -> crate::generated::subscriptions::AiPromptProgressUpdatedSubscriptionBuilder<'_, T> {
+        crate::generated::subscriptions::ai_prompt_progress_updated(self)
+    }
+    /// Triggered when a team is created
+    ///
+    /// Event type: [`Team`](super::types::Team)
+    ///
+    /// Requires the `teams` feature.
+    #[cfg(feature = "teams")]
+    pub fn team_created<T>(
+        &self,
+    ) -> crate::generated::subscriptions::TeamCreatedSubscriptionBuilder<'_, T> {
+        crate::generated::subscriptions::team_created(self)
+    }
+    /// Triggered when a team is updated
+    ///
+    /// Event type: [`Team`](super::types::Team)
+    ///
+    /// Requires the `teams` feature.
+    #[cfg(feature = "teams")]
+    pub fn team_updated<T>(
+        &self,
+    ) -> crate::generated::subscriptions::TeamUpdatedSubscriptionBuilder<'_, T> {
+        crate::generated::subscriptions::team_updated(self)
+    }
+    /// Triggered when a team is deleted
+    ///
+    /// Event type: [`Team`](super::types::Team)
+    ///
+    /// Requires the `teams` feature.
+    #[cfg(feature = "teams")]
+    pub fn team_deleted<T>(
+        &self,
+    ) -> crate::generated::subscriptions::TeamDeletedSubscriptionBuilder<'_, T> {
+        crate::generated::subscriptions::team_deleted(self)
+    }
+    /// Triggered when a team membership is created
+    ///
+    /// Event type: [`TeamMembership`](super::types::TeamMembership)
+    ///
+    /// Requires the `teams` feature.
+    #[cfg(feature = "teams")]
+    pub fn team_membership_created<T>(
+        &self,
+    ) -> crate::generated::subscriptions::TeamMembershipCreatedSubscriptionBuilder<'_, T> {
+        crate::generated::subscriptions::team_membership_created(self)
+    }
+    /// Triggered when a team membership is updated
+    ///
+    /// Event type: [`TeamMembership`](super::types::TeamMembership)
+    ///
+    /// Requires the `teams` feature.
+    #[cfg(feature = "teams")]
+    pub fn team_membership_updated<T>(
+        &self,
+    ) -> crate::generated::subscriptions::TeamMembershipUpdatedSubscriptionBuilder<'_, T> {
+        crate::generated::subscriptions::team_membership_updated(self)
+    }
+    /// Triggered when a team membership is deleted
+    ///
+    /// Event type: [`TeamMembership`](super::types::TeamMembership)
+    ///
+    /// Requires the `teams` feature.
+    #[cfg(feature = "teams")]
+    pub fn team_membership_deleted<T>(
+        &self,
+    ) -> crate::generated::subscriptions::TeamMembershipDeletedSubscriptionBuilder<'_, T> {
+        crate::generated::subscriptions::team_membership_deleted(self)
+    }
+    /// Triggered when an user is created
+    ///
+    /// Event type: [`User`](super::types::User)
+    ///
+    /// Requires the `users` feature.
+    #[cfg(feature = "users")]
+    pub fn user_created<T>(
+        &self,
+    ) -> crate::generated::subscriptions::UserCreatedSubscriptionBuilder<'_, T> {
+        crate::generated::subscriptions::user_created(self)
+    }
+    /// Triggered when an user is updated
+    ///
+    /// Event type: [`User`](super::types::User)
+    ///
+    /// Requires the `users` feature.
+    #[cfg(feature = "users")]
+    pub fn user_updated<T>(
+        &self,
+    ) -> crate::generated::subscriptions::UserUpdatedSubscriptionBuilder<'_, T> {
+        crate::generated::subscriptions::user_updated(self)
+    }
+    /// Triggered when a workflow state is created
+    ///
+    /// Event type: [`WorkflowState`](super::types::WorkflowState)
+    ///
+    /// Requires the `teams` feature.
+    #[cfg(feature = "teams")]
+    pub fn workflow_state_created<T>(
+        &self,
+    ) -> crate::generated::subscriptions::WorkflowStateCreatedSubscriptionBuilder<'_, T> {
+        crate::generated::subscriptions::workflow_state_created(self)
+    }
+    /// Triggered when a workflow state is updated
+    ///
+    /// Event type: [`WorkflowState`](super::types::WorkflowState)
+    ///
+    /// Requires the `teams` feature.
+    #[cfg(feature = "teams")]
+    pub fn workflow_state_updated<T>(
+        &self,
+    ) -> crate::generated::subscriptions::WorkflowStateUpdatedSubscriptionBuilder<'_, T> {
+        crate::generated::subscriptions::workflow_state_updated(self)
+    }
+    /// Triggered when a workflow state is archived
+    ///
+    /// Event type: [`WorkflowState`](super::types::WorkflowState)
+    ///
+    /// Requires the `teams` feature.
+    #[cfg(feature = "teams")]
+    pub fn workflow_state_archived<T>(
+        &self,
+    ) -> crate::generated::subscriptions::WorkflowStateArchivedSubscriptionBuilder<'_, T> {
+        crate::generated::subscriptions::workflow_state_archived(self)
     }
 }
@@ -1,82 +1,344 @@
 //! GraphQL enum types.
 //!
 //! Generated by lineark-codegen — do not edit.
-use serde::{Deserialize, Serialize};
+use crate::error::ParseEnumError;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
 /// A modifier that provides additional instructions on how the activity should be interpreted.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum AgentActivitySignal {
-    #[serde(rename = "stop")]
     Stop,
-    #[serde(rename = "continue")]
     Continue,
-    #[serde(rename = "auth")]
     Auth,
-    #[serde(rename = "select")]
     Select,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl AgentActivitySignal {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[Self::Stop, Self::Continue, Self::Auth, Self::Select];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Stop => "stop",
+            Self::Continue => "continue",
+            Self::Auth => "auth",
+            Self::Select => "select",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "stop" => Some(Self::Stop),
+            "continue" => Some(Self::Continue),
+            "auth" => Some(Self::Auth),
+            "select" => Some(Self::Select),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for AgentActivitySignal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for AgentActivitySignal {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "AgentActivitySignal",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for AgentActivitySignal {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for AgentActivitySignal {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
 }
 /// The type of an agent activity.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum AgentActivityType {
-    #[serde(rename = "thought")]
     Thought,
-    #[serde(rename = "action")]
     Action,
-    #[serde(rename = "response")]
     Response,
-    #[serde(rename = "elicitation")]
     Elicitation,
-    #[serde(rename = "error")]
     Error,
-    #[serde(rename = "prompt")]
     Prompt,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl AgentActivityType {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[
+        Self::Thought,
+        Self::Action,
+        Self::Response,
+        Self::Elicitation,
+        Self::Error,
+        Self::Prompt,
+    ];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Thought => "thought",
+            Self::Action => "action",
+            Self::Response => "response",
+            Self::Elicitation => "elicitation",
+            Self::Error => "error",
+            Self::Prompt => "prompt",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "thought" => Some(Self::Thought),
+            "action" => Some(Self::Action),
+            "response" => Some(Self::Response),
+            "elicitation" => Some(Self::Elicitation),
+            "error" => Some(Self::Error),
+            "prompt" => Some(Self::Prompt),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for AgentActivityType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for AgentActivityType {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "AgentActivityType",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for AgentActivityType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for AgentActivityType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
 }
 /// The status of an agent session.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum AgentSessionStatus {
-    #[serde(rename = "pending")]
     Pending,
-    #[serde(rename = "active")]
     Active,
-    #[serde(rename = "complete")]
     Complete,
-    #[serde(rename = "awaitingInput")]
     AwaitingInput,
-    #[serde(rename = "error")]
     Error,
-    #[serde(rename = "stale")]
     Stale,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl AgentSessionStatus {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[
+        Self::Pending,
+        Self::Active,
+        Self::Complete,
+        Self::AwaitingInput,
+        Self::Error,
+        Self::Stale,
+    ];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Pending => "pending",
+            Self::Active => "active",
+            Self::Complete => "complete",
+            Self::AwaitingInput => "awaitingInput",
+            Self::Error => "error",
+            Self::Stale => "stale",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "pending" => Some(Self::Pending),
+            "active" => Some(Self::Active),
+            "complete" => Some(Self::Complete),
+            "awaitingInput" => Some(Self::AwaitingInput),
+            "error" => Some(Self::Error),
+            "stale" => Some(Self::Stale),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for AgentSessionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for AgentSessionStatus {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "AgentSessionStatus",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for AgentSessionStatus {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for AgentSessionStatus {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
 }
 /// `DEPRECATED` The type of an agent session.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum AgentSessionType {
-    #[serde(rename = "commentThread")]
     CommentThread,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl AgentSessionType {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[Self::CommentThread];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::CommentThread => "commentThread",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "commentThread" => Some(Self::CommentThread),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for AgentSessionType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for AgentSessionType {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "AgentSessionType",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for AgentSessionType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for AgentSessionType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
 }
 /// The action performed on the entity (leave empty if just found)
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum AiConversationEntityCardWidgetArgsAction {
-    #[serde(rename = "created")]
     Created,
-    #[serde(rename = "updated")]
     Updated,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl AiConversationEntityCardWidgetArgsAction {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[Self::Created, Self::Updated];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Created => "created",
+            Self::Updated => "updated",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "created" => Some(Self::Created),
+            "updated" => Some(Self::Updated),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for AiConversationEntityCardWidgetArgsAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for AiConversationEntityCardWidgetArgsAction {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "AiConversationEntityCardWidgetArgsAction",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for AiConversationEntityCardWidgetArgsAction {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for AiConversationEntityCardWidgetArgsAction {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
 }
 /// `Internal` The entity type
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum AiConversationEntityCardWidgetArgsType {
     Issue,
     Project,
@@ -95,23 +357,167 @@ pub enum AiConversationEntityCardWidgetArgsType {
     Release,
     ReleasePipeline,
     AiPrompt,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl AiConversationEntityCardWidgetArgsType {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[
+        Self::Issue,
+        Self::Project,
+        Self::Initiative,
+        Self::InitiativeUpdate,
+        Self::ProjectUpdate,
+        Self::WorkflowDefinition,
+        Self::Team,
+        Self::Template,
+        Self::Customer,
+        Self::CustomerNeed,
+        Self::Document,
+        Self::CustomView,
+        Self::Dashboard,
+        Self::PullRequest,
+        Self::Release,
+        Self::ReleasePipeline,
+        Self::AiPrompt,
+    ];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Issue => "Issue",
+            Self::Project => "Project",
+            Self::Initiative => "Initiative",
+            Self::InitiativeUpdate => "InitiativeUpdate",
+            Self::ProjectUpdate => "ProjectUpdate",
+            Self::WorkflowDefinition => "WorkflowDefinition",
+            Self::Team => "Team",
+            Self::Template => "Template",
+            Self::Customer => "Customer",
+            Self::CustomerNeed => "CustomerNeed",
+            Self::Document => "Document",
+            Self::CustomView => "CustomView",
+            Self::Dashboard => "Dashboard",
+            Self::PullRequest => "PullRequest",
+            Self::Release => "Release",
+            Self::ReleasePipeline => "ReleasePipeline",
+            Self::AiPrompt => "AiPrompt",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "Issue" => Some(Self::Issue),
+            "Project" => Some(Self::Project),
+            "Initiative" => Some(Self::Initiative),
+            "InitiativeUpdate" => Some(Self::InitiativeUpdate),
+            "ProjectUpdate" => Some(Self::ProjectUpdate),
+            "WorkflowDefinition" => Some(Self::WorkflowDefinition),
+            "Team" => Some(Self::Team),
+            "Template" => Some(Self::Template),
+            "Customer" => Some(Self::Customer),
+            "CustomerNeed" => Some(Self::CustomerNeed),
+            "Document" => Some(Self::Document),
+            "CustomView" => Some(Self::CustomView),
+            "Dashboard" => Some(Self::Dashboard),
+            "PullRequest" => Some(Self::PullRequest),
+            "Release" => Some(Self::Release),
+            "ReleasePipeline" => Some(Self::ReleasePipeline),
+            "AiPrompt" => Some(Self::AiPrompt),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for AiConversationEntityCardWidgetArgsType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for AiConversationEntityCardWidgetArgsType {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "AiConversationEntityCardWidgetArgsType",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for AiConversationEntityCardWidgetArgsType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for AiConversationEntityCardWidgetArgsType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
 }
 /// The action performed on the entities (leave empty if just found)
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum AiConversationEntityListWidgetArgsAction {
-    #[serde(rename = "created")]
     Created,
-    #[serde(rename = "updated")]
     Updated,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl AiConversationEntityListWidgetArgsAction {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[Self::Created, Self::Updated];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Created => "created",
+            Self::Updated => "updated",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "created" => Some(Self::Created),
+            "updated" => Some(Self::Updated),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for AiConversationEntityListWidgetArgsAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for AiConversationEntityListWidgetArgsAction {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "AiConversationEntityListWidgetArgsAction",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for AiConversationEntityListWidgetArgsAction {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for AiConversationEntityListWidgetArgsAction {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
 }
 /// `Internal` The entity type
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum AiConversationEntityListWidgetArgsEntitiesType {
     Issue,
     Project,
@@ -130,98 +536,512 @@ pub enum AiConversationEntityListWidgetArgsEntitiesType {
     Release,
     ReleasePipeline,
     AiPrompt,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl AiConversationEntityListWidgetArgsEntitiesType {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[
+        Self::Issue,
+        Self::Project,
+        Self::Initiative,
+        Self::InitiativeUpdate,
+        Self::ProjectUpdate,
+        Self::WorkflowDefinition,
+        Self::Team,
+        Self::Template,
+        Self::Customer,
+        Self::CustomerNeed,
+        Self::Document,
+        Self::CustomView,
+        Self::Dashboard,
+        Self::PullRequest,
+        Self::Release,
+        Self::ReleasePipeline,
+        Self::AiPrompt,
+    ];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Issue => "Issue",
+            Self::Project => "Project",
+            Self::Initiative => "Initiative",
+            Self::InitiativeUpdate => "InitiativeUpdate",
+            Self::ProjectUpdate => "ProjectUpdate",
+            Self::WorkflowDefinition => "WorkflowDefinition",
+            Self::Team => "Team",
+            Self::Template => "Template",
+            Self::Customer => "Customer",
+            Self::CustomerNeed => "CustomerNeed",
+            Self::Document => "Document",
+            Self::CustomView => "CustomView",
+            Self::Dashboard => "Dashboard",
+            Self::PullRequest => "PullRequest",
+            Self::Release => "Release",
+            Self::ReleasePipeline => "ReleasePipeline",
+            Self::AiPrompt => "AiPrompt",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "Issue" => Some(Self::Issue),
+            "Project" => Some(Self::Project),
+            "Initiative" => Some(Self::Initiative),
+            "InitiativeUpdate" => Some(Self::InitiativeUpdate),
+            "ProjectUpdate" => Some(Self::ProjectUpdate),
+            "WorkflowDefinition" => Some(Self::WorkflowDefinition),
+            "Team" => Some(Self::Team),
+            "Template" => Some(Self::Template),
+            "Customer" => Some(Self::Customer),
+            "CustomerNeed" => Some(Self::CustomerNeed),
+            "Document" => Some(Self::Document),
+            "CustomView" => Some(Self::CustomView),
+            "Dashboard" => Some(Self::Dashboard),
+            "PullRequest" => Some(Self::PullRequest),
+            "Release" => Some(Self::Release),
+            "ReleasePipeline" => Some(Self::ReleasePipeline),
+            "AiPrompt" => Some(Self::AiPrompt),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for AiConversationEntityListWidgetArgsEntitiesType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for AiConversationEntityListWidgetArgsEntitiesType {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "AiConversationEntityListWidgetArgsEntitiesType",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for AiConversationEntityListWidgetArgsEntitiesType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for AiConversationEntityListWidgetArgsEntitiesType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
 }
 /// The initial source of an AI conversation.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum AiConversationInitialSource {
-    #[serde(rename = "slack")]
     Slack,
-    #[serde(rename = "microsoftTeams")]
     MicrosoftTeams,
-    #[serde(rename = "mcp")]
     Mcp,
-    #[serde(rename = "directChat")]
     DirectChat,
-    #[serde(rename = "comment")]
     Comment,
-    #[serde(rename = "pullRequestComment")]
     PullRequestComment,
-    #[serde(rename = "workflow")]
     Workflow,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl AiConversationInitialSource {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[
+        Self::Slack,
+        Self::MicrosoftTeams,
+        Self::Mcp,
+        Self::DirectChat,
+        Self::Comment,
+        Self::PullRequestComment,
+        Self::Workflow,
+    ];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Slack => "slack",
+            Self::MicrosoftTeams => "microsoftTeams",
+            Self::Mcp => "mcp",
+            Self::DirectChat => "directChat",
+            Self::Comment => "comment",
+            Self::PullRequestComment => "pullRequestComment",
+            Self::Workflow => "workflow",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "slack" => Some(Self::Slack),
+            "microsoftTeams" => Some(Self::MicrosoftTeams),
+            "mcp" => Some(Self::Mcp),
+            "directChat" => Some(Self::DirectChat),
+            "comment" => Some(Self::Comment),
+            "pullRequestComment" => Some(Self::PullRequestComment),
+            "workflow" => Some(Self::Workflow),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for AiConversationInitialSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for AiConversationInitialSource {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "AiConversationInitialSource",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for AiConversationInitialSource {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for AiConversationInitialSource {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
 }
 /// The phase during which a conversation part was generated.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum AiConversationPartPhase {
-    #[serde(rename = "commentary")]
     Commentary,
-    #[serde(rename = "answer")]
     Answer,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl AiConversationPartPhase {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[Self::Commentary, Self::Answer];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Commentary => "commentary",
+            Self::Answer => "answer",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "commentary" => Some(Self::Commentary),
+            "answer" => Some(Self::Answer),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for AiConversationPartPhase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for AiConversationPartPhase {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "AiConversationPartPhase",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for AiConversationPartPhase {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for AiConversationPartPhase {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
 }
 /// The type of a part in an AI conversation.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum AiConversationPartType {
-    #[serde(rename = "prompt")]
     Prompt,
-    #[serde(rename = "toolCall")]
     ToolCall,
-    #[serde(rename = "reasoning")]
     Reasoning,
-    #[serde(rename = "text")]
     Text,
-    #[serde(rename = "widget")]
     Widget,
-    #[serde(rename = "widgetPlaceholder")]
     WidgetPlaceholder,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl AiConversationPartType {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[
+        Self::Prompt,
+        Self::ToolCall,
+        Self::Reasoning,
+        Self::Text,
+        Self::Widget,
+        Self::WidgetPlaceholder,
+    ];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Prompt => "prompt",
+            Self::ToolCall => "toolCall",
+            Self::Reasoning => "reasoning",
+            Self::Text => "text",
+            Self::Widget => "widget",
+            Self::WidgetPlaceholder => "widgetPlaceholder",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "prompt" => Some(Self::Prompt),
+            "toolCall" => Some(Self::ToolCall),
+            "reasoning" => Some(Self::Reasoning),
+            "text" => Some(Self::Text),
+            "widget" => Some(Self::Widget),
+            "widgetPlaceholder" => Some(Self::WidgetPlaceholder),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for AiConversationPartType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for AiConversationPartType {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "AiConversationPartType",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for AiConversationPartType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for AiConversationPartType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
 }
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum AiConversationQueryUpdatesToolCallArgsUpdateType {
     ProjectUpdate,
     InitiativeUpdate,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
 }
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+impl AiConversationQueryUpdatesToolCallArgsUpdateType {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[Self::ProjectUpdate, Self::InitiativeUpdate];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::ProjectUpdate => "ProjectUpdate",
+            Self::InitiativeUpdate => "InitiativeUpdate",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "ProjectUpdate" => Some(Self::ProjectUpdate),
+            "InitiativeUpdate" => Some(Self::InitiativeUpdate),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for AiConversationQueryUpdatesToolCallArgsUpdateType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for AiConversationQueryUpdatesToolCallArgsUpdateType {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "AiConversationQueryUpdatesToolCallArgsUpdateType",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for AiConversationQueryUpdatesToolCallArgsUpdateType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for AiConversationQueryUpdatesToolCallArgsUpdateType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum AiConversationQueryViewToolCallArgsMode {
-    #[serde(rename = "list")]
     List,
-    #[serde(rename = "insight")]
     Insight,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl AiConversationQueryViewToolCallArgsMode {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[Self::List, Self::Insight];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::List => "list",
+            Self::Insight => "insight",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "list" => Some(Self::List),
+            "insight" => Some(Self::Insight),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for AiConversationQueryViewToolCallArgsMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for AiConversationQueryViewToolCallArgsMode {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "AiConversationQueryViewToolCallArgsMode",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for AiConversationQueryViewToolCallArgsMode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for AiConversationQueryViewToolCallArgsMode {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
 }
 /// The status of an AI conversation.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum AiConversationStatus {
-    #[serde(rename = "active")]
     Active,
-    #[serde(rename = "complete")]
     Complete,
-    #[serde(rename = "awaitingInput")]
     AwaitingInput,
-    #[serde(rename = "error")]
     Error,
-    #[serde(rename = "pending")]
     Pending,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl AiConversationStatus {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[
+        Self::Active,
+        Self::Complete,
+        Self::AwaitingInput,
+        Self::Error,
+        Self::Pending,
+    ];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Active => "active",
+            Self::Complete => "complete",
+            Self::AwaitingInput => "awaitingInput",
+            Self::Error => "error",
+            Self::Pending => "pending",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "active" => Some(Self::Active),
+            "complete" => Some(Self::Complete),
+            "awaitingInput" => Some(Self::AwaitingInput),
+            "error" => Some(Self::Error),
+            "pending" => Some(Self::Pending),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for AiConversationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for AiConversationStatus {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "AiConversationStatus",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for AiConversationStatus {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for AiConversationStatus {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
 }
 /// The name of a tool that was called in an AI conversation.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum AiConversationTool {
     SearchEntities,
     RetrieveEntities,
@@ -246,147 +1066,743 @@ pub enum AiConversationTool {
     GetMicrosoftTeamsConversationHistory,
     NavigateToPage,
     InvokeMcpTool,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl AiConversationTool {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[
+        Self::SearchEntities,
+        Self::RetrieveEntities,
+        Self::QueryView,
+        Self::QueryActivity,
+        Self::QueryUpdates,
+        Self::SuggestValues,
+        Self::CreateEntity,
+        Self::UpdateEntity,
+        Self::DeleteEntity,
+        Self::RestoreEntity,
+        Self::Research,
+        Self::CodeIntelligence,
+        Self::GetPullRequestDiff,
+        Self::GetPullRequestFile,
+        Self::HandoffToCodingSession,
+        Self::TranscribeVideo,
+        Self::TranscribeMedia,
+        Self::SearchDocumentation,
+        Self::WebSearch,
+        Self::GetSlackConversationHistory,
+        Self::GetMicrosoftTeamsConversationHistory,
+        Self::NavigateToPage,
+        Self::InvokeMcpTool,
+    ];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::SearchEntities => "SearchEntities",
+            Self::RetrieveEntities => "RetrieveEntities",
+            Self::QueryView => "QueryView",
+            Self::QueryActivity => "QueryActivity",
+            Self::QueryUpdates => "QueryUpdates",
+            Self::SuggestValues => "SuggestValues",
+            Self::CreateEntity => "CreateEntity",
+            Self::UpdateEntity => "UpdateEntity",
+            Self::DeleteEntity => "DeleteEntity",
+            Self::RestoreEntity => "RestoreEntity",
+            Self::Research => "Research",
+            Self::CodeIntelligence => "CodeIntelligence",
+            Self::GetPullRequestDiff => "GetPullRequestDiff",
+            Self::GetPullRequestFile => "GetPullRequestFile",
+            Self::HandoffToCodingSession => "HandoffToCodingSession",
+            Self::TranscribeVideo => "TranscribeVideo",
+            Self::TranscribeMedia => "TranscribeMedia",
+            Self::SearchDocumentation => "SearchDocumentation",
+            Self::WebSearch => "WebSearch",
+            Self::GetSlackConversationHistory => "GetSlackConversationHistory",
+            Self::GetMicrosoftTeamsConversationHistory => "GetMicrosoftTeamsConversationHistory",
+            Self::NavigateToPage => "NavigateToPage",
+            Self::InvokeMcpTool => "InvokeMcpTool",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "SearchEntities" => Some(Self::SearchEntities),
+            "RetrieveEntities" => Some(Self::RetrieveEntities),
+            "QueryView" => Some(Self::QueryView),
+            "QueryActivity" => Some(Self::QueryActivity),
+            "QueryUpdates" => Some(Self::QueryUpdates),
+            "SuggestValues" => Some(Self::SuggestValues),
+            "CreateEntity" => Some(Self::CreateEntity),
+            "UpdateEntity" => Some(Self::UpdateEntity),
+            "DeleteEntity" => Some(Self::DeleteEntity),
+            "RestoreEntity" => Some(Self::RestoreEntity),
+            "Research" => Some(Self::Research),
+            "CodeIntelligence" => Some(Self::CodeIntelligence),
+            "GetPullRequestDiff" => Some(Self::GetPullRequestDiff),
+            "GetPullRequestFile" => Some(Self::GetPullRequestFile),
+            "HandoffToCodingSession" => Some(Self::HandoffToCodingSession),
+            "TranscribeVideo" => Some(Self::TranscribeVideo),
+            "TranscribeMedia" => Some(Self::TranscribeMedia),
+            "SearchDocumentation" => Some(Self::SearchDocumentation),
+            "WebSearch" => Some(Self::WebSearch),
+            "GetSlackConversationHistory" => Some(Self::GetSlackConversationHistory),
+            "GetMicrosoftTeamsConversationHistory" => {
+                Some(Self::GetMicrosoftTeamsConversationHistory)
+            }
+            "NavigateToPage" => Some(Self::NavigateToPage),
+            "InvokeMcpTool" => Some(Self::InvokeMcpTool),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for AiConversationTool {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for AiConversationTool {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "AiConversationTool",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for AiConversationTool {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for AiConversationTool {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
 }
 /// The name of a widget in an AI conversation.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum AiConversationWidgetName {
     EntityCard,
     EntityList,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl AiConversationWidgetName {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[Self::EntityCard, Self::EntityList];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::EntityCard => "EntityCard",
+            Self::EntityList => "EntityList",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "EntityCard" => Some(Self::EntityCard),
+            "EntityList" => Some(Self::EntityList),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for AiConversationWidgetName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for AiConversationWidgetName {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "AiConversationWidgetName",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for AiConversationWidgetName {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for AiConversationWidgetName {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
 }
 /// `Internal` The status of a prompt workflow.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum AiPromptProgressStatus {
-    #[serde(rename = "created")]
     Created,
-    #[serde(rename = "inProgress")]
     InProgress,
-    #[serde(rename = "finished")]
     Finished,
-    #[serde(rename = "failed")]
     Failed,
-    #[serde(rename = "canceled")]
     Canceled,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl AiPromptProgressStatus {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[
+        Self::Created,
+        Self::InProgress,
+        Self::Finished,
+        Self::Failed,
+        Self::Canceled,
+    ];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Created => "created",
+            Self::InProgress => "inProgress",
+            Self::Finished => "finished",
+            Self::Failed => "failed",
+            Self::Canceled => "canceled",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "created" => Some(Self::Created),
+            "inProgress" => Some(Self::InProgress),
+            "finished" => Some(Self::Finished),
+            "failed" => Some(Self::Failed),
+            "canceled" => Some(Self::Canceled),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for AiPromptProgressStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for AiPromptProgressStatus {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "AiPromptProgressStatus",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for AiPromptProgressStatus {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for AiPromptProgressStatus {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
 }
 /// The type of AI prompt workflow.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum AiPromptType {
-    #[serde(rename = "productIntelligence")]
     ProductIntelligence,
-    #[serde(rename = "internalResearch")]
     InternalResearch,
-    #[serde(rename = "projectUpdates")]
     ProjectUpdates,
-    #[serde(rename = "initiativeUpdates")]
     InitiativeUpdates,
-    #[serde(rename = "agentGuidance")]
     AgentGuidance,
-    #[serde(rename = "codeIntelligence")]
     CodeIntelligence,
-    #[serde(rename = "aiConversation")]
     AiConversation,
-    #[serde(rename = "slackIssueIntake")]
     SlackIssueIntake,
-    #[serde(rename = "intercomIssueIntake")]
     IntercomIssueIntake,
-    #[serde(rename = "gongIssueIntake")]
     GongIssueIntake,
-    #[serde(rename = "zendeskIssueIntake")]
     ZendeskIssueIntake,
-    #[serde(rename = "microsoftTeamsIssueIntake")]
     MicrosoftTeamsIssueIntake,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl AiPromptType {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[
+        Self::ProductIntelligence,
+        Self::InternalResearch,
+        Self::ProjectUpdates,
+        Self::InitiativeUpdates,
+        Self::AgentGuidance,
+        Self::CodeIntelligence,
+        Self::AiConversation,
+        Self::SlackIssueIntake,
+        Self::IntercomIssueIntake,
+        Self::GongIssueIntake,
+        Self::ZendeskIssueIntake,
+        Self::MicrosoftTeamsIssueIntake,
+    ];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::ProductIntelligence => "productIntelligence",
+            Self::InternalResearch => "internalResearch",
+            Self::ProjectUpdates => "projectUpdates",
+            Self::InitiativeUpdates => "initiativeUpdates",
+            Self::AgentGuidance => "agentGuidance",
+            Self::CodeIntelligence => "codeIntelligence",
+            Self::AiConversation => "aiConversation",
+            Self::SlackIssueIntake => "slackIssueIntake",
+            Self::IntercomIssueIntake => "intercomIssueIntake",
+            Self::GongIssueIntake => "gongIssueIntake",
+            Self::ZendeskIssueIntake => "zendeskIssueIntake",
+            Self::MicrosoftTeamsIssueIntake => "microsoftTeamsIssueIntake",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "productIntelligence" => Some(Self::ProductIntelligence),
+            "internalResearch" => Some(Self::InternalResearch),
+            "projectUpdates" => Some(Self::ProjectUpdates),
+            "initiativeUpdates" => Some(Self::InitiativeUpdates),
+            "agentGuidance" => Some(Self::AgentGuidance),
+            "codeIntelligence" => Some(Self::CodeIntelligence),
+            "aiConversation" => Some(Self::AiConversation),
+            "slackIssueIntake" => Some(Self::SlackIssueIntake),
+            "intercomIssueIntake" => Some(Self::IntercomIssueIntake),
+            "gongIssueIntake" => Some(Self::GongIssueIntake),
+            "zendeskIssueIntake" => Some(Self::ZendeskIssueIntake),
+            "microsoftTeamsIssueIntake" => Some(Self::MicrosoftTeamsIssueIntake),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for AiPromptType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for AiPromptType {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "AiPromptType",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for AiPromptType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for AiPromptType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
 }
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum AuthenticationSessionType {
-    #[serde(rename = "web")]
     Web,
-    #[serde(rename = "desktop")]
     Desktop,
-    #[serde(rename = "ios")]
     Ios,
-    #[serde(rename = "android")]
     Android,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
 }
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+impl AuthenticationSessionType {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[Self::Web, Self::Desktop, Self::Ios, Self::Android];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Web => "web",
+            Self::Desktop => "desktop",
+            Self::Ios => "ios",
+            Self::Android => "android",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "web" => Some(Self::Web),
+            "desktop" => Some(Self::Desktop),
+            "ios" => Some(Self::Ios),
+            "android" => Some(Self::Android),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for AuthenticationSessionType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for AuthenticationSessionType {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "AuthenticationSessionType",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for AuthenticationSessionType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for AuthenticationSessionType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum ContextViewType {
-    #[serde(rename = "activeIssues")]
     ActiveIssues,
-    #[serde(rename = "activeCycle")]
     ActiveCycle,
-    #[serde(rename = "upcomingCycle")]
     UpcomingCycle,
-    #[serde(rename = "backlog")]
     Backlog,
-    #[serde(rename = "triage")]
     Triage,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl ContextViewType {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[
+        Self::ActiveIssues,
+        Self::ActiveCycle,
+        Self::UpcomingCycle,
+        Self::Backlog,
+        Self::Triage,
+    ];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::ActiveIssues => "activeIssues",
+            Self::ActiveCycle => "activeCycle",
+            Self::UpcomingCycle => "upcomingCycle",
+            Self::Backlog => "backlog",
+            Self::Triage => "triage",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "activeIssues" => Some(Self::ActiveIssues),
+            "activeCycle" => Some(Self::ActiveCycle),
+            "upcomingCycle" => Some(Self::UpcomingCycle),
+            "backlog" => Some(Self::Backlog),
+            "triage" => Some(Self::Triage),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for ContextViewType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for ContextViewType {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "ContextViewType",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for ContextViewType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for ContextViewType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
 }
 /// `DEPRECATED` A type of customer status.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum CustomerStatusType {
-    #[serde(rename = "active")]
     Active,
-    #[serde(rename = "inactive")]
     Inactive,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl CustomerStatusType {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[Self::Active, Self::Inactive];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Active => "active",
+            Self::Inactive => "inactive",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "active" => Some(Self::Active),
+            "inactive" => Some(Self::Inactive),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for CustomerStatusType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for CustomerStatusType {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "CustomerStatusType",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for CustomerStatusType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for CustomerStatusType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
 }
 /// Mode that controls who can see and set Customers in Slack Asks.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum CustomerVisibilityMode {
     LinearOnly,
     SlackMembers,
     SlackMembersAndGuests,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl CustomerVisibilityMode {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[
+        Self::LinearOnly,
+        Self::SlackMembers,
+        Self::SlackMembersAndGuests,
+    ];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::LinearOnly => "LinearOnly",
+            Self::SlackMembers => "SlackMembers",
+            Self::SlackMembersAndGuests => "SlackMembersAndGuests",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "LinearOnly" => Some(Self::LinearOnly),
+            "SlackMembers" => Some(Self::SlackMembers),
+            "SlackMembersAndGuests" => Some(Self::SlackMembersAndGuests),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for CustomerVisibilityMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for CustomerVisibilityMode {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "CustomerVisibilityMode",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for CustomerVisibilityMode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for CustomerVisibilityMode {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
 }
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum CyclePeriod {
-    #[serde(rename = "before")]
     Before,
-    #[serde(rename = "during")]
     During,
-    #[serde(rename = "after")]
     After,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl CyclePeriod {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[Self::Before, Self::During, Self::After];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Before => "before",
+            Self::During => "during",
+            Self::After => "after",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "before" => Some(Self::Before),
+            "during" => Some(Self::During),
+            "after" => Some(Self::After),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for CyclePeriod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for CyclePeriod {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "CyclePeriod",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for CyclePeriod {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for CyclePeriod {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
 }
 /// By which resolution is a date defined.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum DateResolutionType {
-    #[serde(rename = "month")]
     Month,
-    #[serde(rename = "quarter")]
     Quarter,
-    #[serde(rename = "halfYear")]
     HalfYear,
-    #[serde(rename = "year")]
     Year,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl DateResolutionType {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[Self::Month, Self::Quarter, Self::HalfYear, Self::Year];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Month => "month",
+            Self::Quarter => "quarter",
+            Self::HalfYear => "halfYear",
+            Self::Year => "year",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "month" => Some(Self::Month),
+            "quarter" => Some(Self::Quarter),
+            "halfYear" => Some(Self::HalfYear),
+            "year" => Some(Self::Year),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for DateResolutionType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for DateResolutionType {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "DateResolutionType",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for DateResolutionType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for DateResolutionType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
 }
 /// The day of the week.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum Day {
     Sunday,
     Monday,
@@ -395,713 +1811,3090 @@ pub enum Day {
     Thursday,
     Friday,
     Saturday,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl Day {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[
+        Self::Sunday,
+        Self::Monday,
+        Self::Tuesday,
+        Self::Wednesday,
+        Self::Thursday,
+        Self::Friday,
+        Self::Saturday,
+    ];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Sunday => "Sunday",
+            Self::Monday => "Monday",
+            Self::Tuesday => "Tuesday",
+            Self::Wednesday => "Wednesday",
+            Self::Thursday => "Thursday",
+            Self::Friday => "Friday",
+            Self::Saturday => "Saturday",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "Sunday" => Some(Self::Sunday),
+            "Monday" => Some(Self::Monday),
+            "Tuesday" => Some(Self::Tuesday),
+            "Wednesday" => Some(Self::Wednesday),
+            "Thursday" => Some(Self::Thursday),
+            "Friday" => Some(Self::Friday),
+            "Saturday" => Some(Self::Saturday),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for Day {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for Day {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new("Day", value, Self::ALL.iter().map(|v| v.as_str()).collect())
+        })
+    }
+}
+impl Serialize for Day {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for Day {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
 }
 /// The type of the email address.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum EmailIntakeAddressType {
-    #[serde(rename = "team")]
     Team,
-    #[serde(rename = "template")]
     Template,
-    #[serde(rename = "asks")]
     Asks,
-    #[serde(rename = "asksWeb")]
     AsksWeb,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl EmailIntakeAddressType {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[Self::Team, Self::Template, Self::Asks, Self::AsksWeb];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Team => "team",
+            Self::Template => "template",
+            Self::Asks => "asks",
+            Self::AsksWeb => "asksWeb",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "team" => Some(Self::Team),
+            "template" => Some(Self::Template),
+            "asks" => Some(Self::Asks),
+            "asksWeb" => Some(Self::AsksWeb),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for EmailIntakeAddressType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for EmailIntakeAddressType {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "EmailIntakeAddressType",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for EmailIntakeAddressType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for EmailIntakeAddressType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
 }
 /// The service that syncs an external entity to Linear.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum ExternalSyncService {
-    #[serde(rename = "jira")]
     Jira,
-    #[serde(rename = "github")]
     Github,
-    #[serde(rename = "slack")]
     Slack,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl ExternalSyncService {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[Self::Jira, Self::Github, Self::Slack];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Jira => "jira",
+            Self::Github => "github",
+            Self::Slack => "slack",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "jira" => Some(Self::Jira),
+            "github" => Some(Self::Github),
+            "slack" => Some(Self::Slack),
+            _ => None,
+        }
+    }
 }
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+impl fmt::Display for ExternalSyncService {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for ExternalSyncService {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "ExternalSyncService",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for ExternalSyncService {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for ExternalSyncService {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum FacetPageSource {
-    #[serde(rename = "projects")]
     Projects,
-    #[serde(rename = "teamIssues")]
     TeamIssues,
-    #[serde(rename = "feed")]
     Feed,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl FacetPageSource {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[Self::Projects, Self::TeamIssues, Self::Feed];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Projects => "projects",
+            Self::TeamIssues => "teamIssues",
+            Self::Feed => "feed",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "projects" => Some(Self::Projects),
+            "teamIssues" => Some(Self::TeamIssues),
+            "feed" => Some(Self::Feed),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for FacetPageSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for FacetPageSource {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "FacetPageSource",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for FacetPageSource {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for FacetPageSource {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
 }
 /// Cadence to generate feed summary
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum FeedSummarySchedule {
-    #[serde(rename = "daily")]
     Daily,
-    #[serde(rename = "weekly")]
     Weekly,
-    #[serde(rename = "never")]
     Never,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl FeedSummarySchedule {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[Self::Daily, Self::Weekly, Self::Never];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Daily => "daily",
+            Self::Weekly => "weekly",
+            Self::Never => "never",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "daily" => Some(Self::Daily),
+            "weekly" => Some(Self::Weekly),
+            "never" => Some(Self::Never),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for FeedSummarySchedule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for FeedSummarySchedule {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "FeedSummarySchedule",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for FeedSummarySchedule {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for FeedSummarySchedule {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
 }
 /// By which resolution is frequency defined.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum FrequencyResolutionType {
-    #[serde(rename = "daily")]
     Daily,
-    #[serde(rename = "weekly")]
     Weekly,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl FrequencyResolutionType {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[Self::Daily, Self::Weekly];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Daily => "daily",
+            Self::Weekly => "weekly",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "daily" => Some(Self::Daily),
+            "weekly" => Some(Self::Weekly),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for FrequencyResolutionType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for FrequencyResolutionType {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "FrequencyResolutionType",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for FrequencyResolutionType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for FrequencyResolutionType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
 }
 /// The Git events that can trigger an automation rule. Each value corresponds to a pull/merge request lifecycle event (e.g., branch created, PR opened for review, PR merged).
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum GitAutomationStates {
-    #[serde(rename = "draft")]
     Draft,
-    #[serde(rename = "start")]
     Start,
-    #[serde(rename = "review")]
     Review,
-    #[serde(rename = "mergeable")]
     Mergeable,
-    #[serde(rename = "merge")]
     Merge,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl GitAutomationStates {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[
+        Self::Draft,
+        Self::Start,
+        Self::Review,
+        Self::Mergeable,
+        Self::Merge,
+    ];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Draft => "draft",
+            Self::Start => "start",
+            Self::Review => "review",
+            Self::Mergeable => "mergeable",
+            Self::Merge => "merge",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "draft" => Some(Self::Draft),
+            "start" => Some(Self::Start),
+            "review" => Some(Self::Review),
+            "mergeable" => Some(Self::Mergeable),
+            "merge" => Some(Self::Merge),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for GitAutomationStates {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for GitAutomationStates {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "GitAutomationStates",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for GitAutomationStates {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for GitAutomationStates {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
 }
 /// `Internal` The kind of link between an issue and a pull request.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum GitLinkKind {
-    #[serde(rename = "closes")]
     Closes,
-    #[serde(rename = "contributes")]
     Contributes,
-    #[serde(rename = "links")]
     Links,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl GitLinkKind {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[Self::Closes, Self::Contributes, Self::Links];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Closes => "closes",
+            Self::Contributes => "contributes",
+            Self::Links => "links",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "closes" => Some(Self::Closes),
+            "contributes" => Some(Self::Contributes),
+            "links" => Some(Self::Links),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for GitLinkKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for GitLinkKind {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "GitLinkKind",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for GitLinkKind {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
 }
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+impl<'de> Deserialize<'de> for GitLinkKind {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum GithubOrgType {
-    #[serde(rename = "user")]
     User,
-    #[serde(rename = "organization")]
     Organization,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl GithubOrgType {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[Self::User, Self::Organization];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::User => "user",
+            Self::Organization => "organization",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "user" => Some(Self::User),
+            "organization" => Some(Self::Organization),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for GithubOrgType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for GithubOrgType {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "GithubOrgType",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for GithubOrgType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for GithubOrgType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
 }
 /// The type of identity provider.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum IdentityProviderType {
-    #[serde(rename = "general")]
     General,
-    #[serde(rename = "webForms")]
     WebForms,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
 }
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+impl IdentityProviderType {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[Self::General, Self::WebForms];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::General => "general",
+            Self::WebForms => "webForms",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "general" => Some(Self::General),
+            "webForms" => Some(Self::WebForms),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for IdentityProviderType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for IdentityProviderType {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "IdentityProviderType",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for IdentityProviderType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for IdentityProviderType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum InitiativeStatus {
     Planned,
     Active,
     Completed,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl InitiativeStatus {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[Self::Planned, Self::Active, Self::Completed];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Planned => "Planned",
+            Self::Active => "Active",
+            Self::Completed => "Completed",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "Planned" => Some(Self::Planned),
+            "Active" => Some(Self::Active),
+            "Completed" => Some(Self::Completed),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for InitiativeStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for InitiativeStatus {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "InitiativeStatus",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for InitiativeStatus {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for InitiativeStatus {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
 }
 /// Different tabs available inside an initiative.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum InitiativeTab {
-    #[serde(rename = "overview")]
     Overview,
-    #[serde(rename = "projects")]
     Projects,
-    #[serde(rename = "updates")]
     Updates,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl InitiativeTab {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[Self::Overview, Self::Projects, Self::Updates];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Overview => "overview",
+            Self::Projects => "projects",
+            Self::Updates => "updates",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "overview" => Some(Self::Overview),
+            "projects" => Some(Self::Projects),
+            "updates" => Some(Self::Updates),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for InitiativeTab {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for InitiativeTab {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "InitiativeTab",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for InitiativeTab {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for InitiativeTab {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
 }
 /// The health type when the update is created.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum InitiativeUpdateHealthType {
-    #[serde(rename = "onTrack")]
     OnTrack,
-    #[serde(rename = "atRisk")]
     AtRisk,
-    #[serde(rename = "offTrack")]
     OffTrack,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl InitiativeUpdateHealthType {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[Self::OnTrack, Self::AtRisk, Self::OffTrack];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::OnTrack => "onTrack",
+            Self::AtRisk => "atRisk",
+            Self::OffTrack => "offTrack",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "onTrack" => Some(Self::OnTrack),
+            "atRisk" => Some(Self::AtRisk),
+            "offTrack" => Some(Self::OffTrack),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for InitiativeUpdateHealthType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for InitiativeUpdateHealthType {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "InitiativeUpdateHealthType",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for InitiativeUpdateHealthType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for InitiativeUpdateHealthType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
 }
 /// Linear supported integration services.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum IntegrationService {
-    #[serde(rename = "airbyte")]
     Airbyte,
-    #[serde(rename = "discord")]
     Discord,
-    #[serde(rename = "figma")]
     Figma,
-    #[serde(rename = "figmaPlugin")]
     FigmaPlugin,
-    #[serde(rename = "front")]
     Front,
-    #[serde(rename = "github")]
     Github,
-    #[serde(rename = "gong")]
     Gong,
-    #[serde(rename = "githubEnterpriseServer")]
     GithubEnterpriseServer,
-    #[serde(rename = "githubCommit")]
     GithubCommit,
-    #[serde(rename = "githubImport")]
     GithubImport,
-    #[serde(rename = "githubPersonal")]
     GithubPersonal,
-    #[serde(rename = "githubCodeAccessPersonal")]
     GithubCodeAccessPersonal,
-    #[serde(rename = "gitlab")]
     Gitlab,
-    #[serde(rename = "googleCalendarPersonal")]
     GoogleCalendarPersonal,
-    #[serde(rename = "googleSheets")]
     GoogleSheets,
-    #[serde(rename = "intercom")]
     Intercom,
-    #[serde(rename = "jira")]
     Jira,
-    #[serde(rename = "jiraPersonal")]
     JiraPersonal,
-    #[serde(rename = "launchDarkly")]
     LaunchDarkly,
-    #[serde(rename = "launchDarklyPersonal")]
     LaunchDarklyPersonal,
-    #[serde(rename = "loom")]
     Loom,
-    #[serde(rename = "notion")]
     Notion,
-    #[serde(rename = "opsgenie")]
     Opsgenie,
-    #[serde(rename = "pagerDuty")]
     PagerDuty,
-    #[serde(rename = "salesforce")]
     Salesforce,
-    #[serde(rename = "slack")]
     Slack,
-    #[serde(rename = "slackAsks")]
     SlackAsks,
-    #[serde(rename = "asksWeb")]
     AsksWeb,
-    #[serde(rename = "slackCustomViewNotifications")]
     SlackCustomViewNotifications,
-    #[serde(rename = "slackOrgProjectUpdatesPost")]
     SlackOrgProjectUpdatesPost,
-    #[serde(rename = "slackOrgInitiativeUpdatesPost")]
     SlackOrgInitiativeUpdatesPost,
-    #[serde(rename = "slackPersonal")]
     SlackPersonal,
-    #[serde(rename = "slackPost")]
     SlackPost,
-    #[serde(rename = "slackProjectPost")]
     SlackProjectPost,
-    #[serde(rename = "slackProjectUpdatesPost")]
     SlackProjectUpdatesPost,
-    #[serde(rename = "slackInitiativePost")]
     SlackInitiativePost,
-    #[serde(rename = "sentry")]
     Sentry,
-    #[serde(rename = "zendesk")]
     Zendesk,
-    #[serde(rename = "email")]
     Email,
-    #[serde(rename = "mcpServerPersonal")]
     McpServerPersonal,
-    #[serde(rename = "mcpServer")]
     McpServer,
-    #[serde(rename = "microsoftTeams")]
     MicrosoftTeams,
-    #[serde(rename = "microsoftPersonal")]
     MicrosoftPersonal,
-    #[serde(rename = "microsoftTeamsProjectPost")]
     MicrosoftTeamsProjectPost,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl IntegrationService {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[
+        Self::Airbyte,
+        Self::Discord,
+        Self::Figma,
+        Self::FigmaPlugin,
+        Self::Front,
+        Self::Github,
+        Self::Gong,
+        Self::GithubEnterpriseServer,
+        Self::GithubCommit,
+        Self::GithubImport,
+        Self::GithubPersonal,
+        Self::GithubCodeAccessPersonal,
+        Self::Gitlab,
+        Self::GoogleCalendarPersonal,
+        Self::GoogleSheets,
+        Self::Intercom,
+        Self::Jira,
+        Self::JiraPersonal,
+        Self::LaunchDarkly,
+        Self::LaunchDarklyPersonal,
+        Self::Loom,
+        Self::Notion,
+        Self::Opsgenie,
+        Self::PagerDuty,
+        Self::Salesforce,
+        Self::Slack,
+        Self::SlackAsks,
+        Self::AsksWeb,
+        Self::SlackCustomViewNotifications,
+        Self::SlackOrgProjectUpdatesPost,
+        Self::SlackOrgInitiativeUpdatesPost,
+        Self::SlackPersonal,
+        Self::SlackPost,
+        Self::SlackProjectPost,
+        Self::SlackProjectUpdatesPost,
+        Self::SlackInitiativePost,
+        Self::Sentry,
+        Self::Zendesk,
+        Self::Email,
+        Self::McpServerPersonal,
+        Self::McpServer,
+        Self::MicrosoftTeams,
+        Self::MicrosoftPersonal,
+        Self::MicrosoftTeamsProjectPost,
+    ];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Airbyte => "airbyte",
+            Self::Discord => "discord",
+            Self::Figma => "figma",
+            Self::FigmaPlugin => "figmaPlugin",
+            Self::Front => "front",
+            Self::Github => "github",
+            Self::Gong => "gong",
+            Self::GithubEnterpriseServer => "githubEnterpriseServer",
+            Self::GithubCommit => "githubCommit",
+            Self::GithubImport => "githubImport",
+            Self::GithubPersonal => "githubPersonal",
+            Self::GithubCodeAccessPersonal => "githubCodeAccessPersonal",
+            Self::Gitlab => "gitlab",
+            Self::GoogleCalendarPersonal => "googleCalendarPersonal",
+            Self::GoogleSheets => "googleSheets",
+            Self::Intercom => "intercom",
+            Self::Jira => "jira",
+            Self::JiraPersonal => "jiraPersonal",
+            Self::LaunchDarkly => "launchDarkly",
+            Self::LaunchDarklyPersonal => "launchDarklyPersonal",
+            Self::Loom => "loom",
+            Self::Notion => "notion",
+            Self::Opsgenie => "opsgenie",
+            Self::PagerDuty => "pagerDuty",
+            Self::Salesforce => "salesforce",
+            Self::Slack => "slack",
+            Self::SlackAsks => "slackAsks",
+            Self::AsksWeb => "asksWeb",
+            Self::SlackCustomViewNotifications => "slackCustomViewNotifications",
+            Self::SlackOrgProjectUpdatesPost => "slackOrgProjectUpdatesPost",
+            Self::SlackOrgInitiativeUpdatesPost => "slackOrgInitiativeUpdatesPost",
+            Self::SlackPersonal => "slackPersonal",
+            Self::SlackPost => "slackPost",
+            Self::SlackProjectPost => "slackProjectPost",
+            Self::SlackProjectUpdatesPost => "slackProjectUpdatesPost",
+            Self::SlackInitiativePost => "slackInitiativePost",
+            Self::Sentry => "sentry",
+            Self::Zendesk => "zendesk",
+            Self::Email => "email",
+            Self::McpServerPersonal => "mcpServerPersonal",
+            Self::McpServer => "mcpServer",
+            Self::MicrosoftTeams => "microsoftTeams",
+            Self::MicrosoftPersonal => "microsoftPersonal",
+            Self::MicrosoftTeamsProjectPost => "microsoftTeamsProjectPost",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "airbyte" => Some(Self::Airbyte),
+            "discord" => Some(Self::Discord),
+            "figma" => Some(Self::Figma),
+            "figmaPlugin" => Some(Self::FigmaPlugin),
+            "front" => Some(Self::Front),
+            "github" => Some(Self::Github),
+            "gong" => Some(Self::Gong),
+            "githubEnterpriseServer" => Some(Self::GithubEnterpriseServer),
+            "githubCommit" => Some(Self::GithubCommit),
+            "githubImport" => Some(Self::GithubImport),
+            "githubPersonal" => Some(Self::GithubPersonal),
+            "githubCodeAccessPersonal" => Some(Self::GithubCodeAccessPersonal),
+            "gitlab" => Some(Self::Gitlab),
+            "googleCalendarPersonal" => Some(Self::GoogleCalendarPersonal),
+            "googleSheets" => Some(Self::GoogleSheets),
+            "intercom" => Some(Self::Intercom),
+            "jira" => Some(Self::Jira),
+            "jiraPersonal" => Some(Self::JiraPersonal),
+            "launchDarkly" => Some(Self::LaunchDarkly),
+            "launchDarklyPersonal" => Some(Self::LaunchDarklyPersonal),
+            "loom" => Some(Self::Loom),
+            "notion" => Some(Self::Notion),
+            "opsgenie" => Some(Self::Opsgenie),
+            "pagerDuty" => Some(Self::PagerDuty),
+            "salesforce" => Some(Self::Salesforce),
+            "slack" => Some(Self::Slack),
+            "slackAsks" => Some(Self::SlackAsks),
+            "asksWeb" => Some(Self::AsksWeb),
+            "slackCustomViewNotifications" => Some(Self::SlackCustomViewNotifications),
+            "slackOrgProjectUpdatesPost" => Some(Self::SlackOrgProjectUpdatesPost),
+            "slackOrgInitiativeUpdatesPost" => Some(Self::SlackOrgInitiativeUpdatesPost),
+            "slackPersonal" => Some(Self::SlackPersonal),
+            "slackPost" => Some(Self::SlackPost),
+            "slackProjectPost" => Some(Self::SlackProjectPost),
+            "slackProjectUpdatesPost" => Some(Self::SlackProjectUpdatesPost),
+            "slackInitiativePost" => Some(Self::SlackInitiativePost),
+            "sentry" => Some(Self::Sentry),
+            "zendesk" => Some(Self::Zendesk),
+            "email" => Some(Self::Email),
+            "mcpServerPersonal" => Some(Self::McpServerPersonal),
+            "mcpServer" => Some(Self::McpServer),
+            "microsoftTeams" => Some(Self::MicrosoftTeams),
+            "microsoftPersonal" => Some(Self::MicrosoftPersonal),
+            "microsoftTeamsProjectPost" => Some(Self::MicrosoftTeamsProjectPost),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for IntegrationService {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for IntegrationService {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "IntegrationService",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for IntegrationService {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for IntegrationService {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
 }
 /// The type of the issue relation.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum IssueRelationType {
-    #[serde(rename = "blocks")]
     Blocks,
-    #[serde(rename = "duplicate")]
     Duplicate,
-    #[serde(rename = "related")]
     Related,
-    #[serde(rename = "similar")]
     Similar,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl IssueRelationType {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[Self::Blocks, Self::Duplicate, Self::Related, Self::Similar];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Blocks => "blocks",
+            Self::Duplicate => "duplicate",
+            Self::Related => "related",
+            Self::Similar => "similar",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "blocks" => Some(Self::Blocks),
+            "duplicate" => Some(Self::Duplicate),
+            "related" => Some(Self::Related),
+            "similar" => Some(Self::Similar),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for IssueRelationType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for IssueRelationType {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "IssueRelationType",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for IssueRelationType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for IssueRelationType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
 }
 /// Issue update fields that are disallowed for users with only shared access.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum IssueSharedAccessDisallowedField {
-    #[serde(rename = "projectId")]
     ProjectId,
-    #[serde(rename = "teamId")]
     TeamId,
-    #[serde(rename = "cycleId")]
     CycleId,
-    #[serde(rename = "projectMilestoneId")]
     ProjectMilestoneId,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl IssueSharedAccessDisallowedField {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[
+        Self::ProjectId,
+        Self::TeamId,
+        Self::CycleId,
+        Self::ProjectMilestoneId,
+    ];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::ProjectId => "projectId",
+            Self::TeamId => "teamId",
+            Self::CycleId => "cycleId",
+            Self::ProjectMilestoneId => "projectMilestoneId",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "projectId" => Some(Self::ProjectId),
+            "teamId" => Some(Self::TeamId),
+            "cycleId" => Some(Self::CycleId),
+            "projectMilestoneId" => Some(Self::ProjectMilestoneId),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for IssueSharedAccessDisallowedField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for IssueSharedAccessDisallowedField {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "IssueSharedAccessDisallowedField",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for IssueSharedAccessDisallowedField {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for IssueSharedAccessDisallowedField {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
 }
 /// Policy controlling whether and by whom issues in a team can be shared with non-team-members.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum IssueSharingPolicy {
-    #[serde(rename = "disabled")]
     Disabled,
-    #[serde(rename = "adminsOnly")]
     AdminsOnly,
-    #[serde(rename = "allMembers")]
     AllMembers,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl IssueSharingPolicy {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[Self::Disabled, Self::AdminsOnly, Self::AllMembers];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Disabled => "disabled",
+            Self::AdminsOnly => "adminsOnly",
+            Self::AllMembers => "allMembers",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "disabled" => Some(Self::Disabled),
+            "adminsOnly" => Some(Self::AdminsOnly),
+            "allMembers" => Some(Self::AllMembers),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for IssueSharingPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for IssueSharingPolicy {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "IssueSharingPolicy",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for IssueSharingPolicy {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for IssueSharingPolicy {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
 }
 /// The state of an issue suggestion, indicating whether it is active, accepted, or dismissed.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum IssueSuggestionState {
-    #[serde(rename = "active")]
     Active,
-    #[serde(rename = "stale")]
     Stale,
-    #[serde(rename = "accepted")]
     Accepted,
-    #[serde(rename = "dismissed")]
     Dismissed,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl IssueSuggestionState {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[Self::Active, Self::Stale, Self::Accepted, Self::Dismissed];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Active => "active",
+            Self::Stale => "stale",
+            Self::Accepted => "accepted",
+            Self::Dismissed => "dismissed",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "active" => Some(Self::Active),
+            "stale" => Some(Self::Stale),
+            "accepted" => Some(Self::Accepted),
+            "dismissed" => Some(Self::Dismissed),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for IssueSuggestionState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for IssueSuggestionState {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "IssueSuggestionState",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for IssueSuggestionState {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for IssueSuggestionState {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
 }
 /// The type of an issue suggestion, indicating what kind of entity is being suggested (e.g., similar issue, assignee, label, team, project).
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum IssueSuggestionType {
-    #[serde(rename = "team")]
     Team,
-    #[serde(rename = "project")]
     Project,
-    #[serde(rename = "assignee")]
     Assignee,
-    #[serde(rename = "label")]
     Label,
-    #[serde(rename = "similarIssue")]
     SimilarIssue,
-    #[serde(rename = "relatedIssue")]
     RelatedIssue,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl IssueSuggestionType {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[
+        Self::Team,
+        Self::Project,
+        Self::Assignee,
+        Self::Label,
+        Self::SimilarIssue,
+        Self::RelatedIssue,
+    ];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Team => "team",
+            Self::Project => "project",
+            Self::Assignee => "assignee",
+            Self::Label => "label",
+            Self::SimilarIssue => "similarIssue",
+            Self::RelatedIssue => "relatedIssue",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "team" => Some(Self::Team),
+            "project" => Some(Self::Project),
+            "assignee" => Some(Self::Assignee),
+            "label" => Some(Self::Label),
+            "similarIssue" => Some(Self::SimilarIssue),
+            "relatedIssue" => Some(Self::RelatedIssue),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for IssueSuggestionType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for IssueSuggestionType {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "IssueSuggestionType",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for IssueSuggestionType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for IssueSuggestionType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
 }
 /// The categories of notifications a user can subscribe to.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum NotificationCategory {
-    #[serde(rename = "assignments")]
     Assignments,
-    #[serde(rename = "statusChanges")]
     StatusChanges,
-    #[serde(rename = "commentsAndReplies")]
     CommentsAndReplies,
-    #[serde(rename = "mentions")]
     Mentions,
-    #[serde(rename = "reactions")]
     Reactions,
-    #[serde(rename = "subscriptions")]
     Subscriptions,
-    #[serde(rename = "documentChanges")]
     DocumentChanges,
-    #[serde(rename = "postsAndUpdates")]
     PostsAndUpdates,
-    #[serde(rename = "reminders")]
     Reminders,
-    #[serde(rename = "reviews")]
     Reviews,
-    #[serde(rename = "appsAndIntegrations")]
     AppsAndIntegrations,
-    #[serde(rename = "triage")]
     Triage,
-    #[serde(rename = "customers")]
     Customers,
-    #[serde(rename = "feed")]
     Feed,
-    #[serde(rename = "system")]
     System,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl NotificationCategory {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[
+        Self::Assignments,
+        Self::StatusChanges,
+        Self::CommentsAndReplies,
+        Self::Mentions,
+        Self::Reactions,
+        Self::Subscriptions,
+        Self::DocumentChanges,
+        Self::PostsAndUpdates,
+        Self::Reminders,
+        Self::Reviews,
+        Self::AppsAndIntegrations,
+        Self::Triage,
+        Self::Customers,
+        Self::Feed,
+        Self::System,
+    ];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Assignments => "assignments",
+            Self::StatusChanges => "statusChanges",
+            Self::CommentsAndReplies => "commentsAndReplies",
+            Self::Mentions => "mentions",
+            Self::Reactions => "reactions",
+            Self::Subscriptions => "subscriptions",
+            Self::DocumentChanges => "documentChanges",
+            Self::PostsAndUpdates => "postsAndUpdates",
+            Self::Reminders => "reminders",
+            Self::Reviews => "reviews",
+            Self::AppsAndIntegrations => "appsAndIntegrations",
+            Self::Triage => "triage",
+            Self::Customers => "customers",
+            Self::Feed => "feed",
+            Self::System => "system",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "assignments" => Some(Self::Assignments),
+            "statusChanges" => Some(Self::StatusChanges),
+            "commentsAndReplies" => Some(Self::CommentsAndReplies),
+            "mentions" => Some(Self::Mentions),
+            "reactions" => Some(Self::Reactions),
+            "subscriptions" => Some(Self::Subscriptions),
+            "documentChanges" => Some(Self::DocumentChanges),
+            "postsAndUpdates" => Some(Self::PostsAndUpdates),
+            "reminders" => Some(Self::Reminders),
+            "reviews" => Some(Self::Reviews),
+            "appsAndIntegrations" => Some(Self::AppsAndIntegrations),
+            "triage" => Some(Self::Triage),
+            "customers" => Some(Self::Customers),
+            "feed" => Some(Self::Feed),
+            "system" => Some(Self::System),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for NotificationCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for NotificationCategory {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "NotificationCategory",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for NotificationCategory {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for NotificationCategory {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
 }
 /// The delivery channels a user can receive notifications in.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum NotificationChannel {
-    #[serde(rename = "desktop")]
     Desktop,
-    #[serde(rename = "mobile")]
     Mobile,
-    #[serde(rename = "email")]
     Email,
-    #[serde(rename = "slack")]
     Slack,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl NotificationChannel {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[Self::Desktop, Self::Mobile, Self::Email, Self::Slack];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Desktop => "desktop",
+            Self::Mobile => "mobile",
+            Self::Email => "email",
+            Self::Slack => "slack",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "desktop" => Some(Self::Desktop),
+            "mobile" => Some(Self::Mobile),
+            "email" => Some(Self::Email),
+            "slack" => Some(Self::Slack),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for NotificationChannel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for NotificationChannel {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "NotificationChannel",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for NotificationChannel {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for NotificationChannel {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
 }
 /// The different requests statuses possible for an OAuth client approval request.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum OAuthClientApprovalStatus {
-    #[serde(rename = "requested")]
     Requested,
-    #[serde(rename = "approved")]
     Approved,
-    #[serde(rename = "denied")]
     Denied,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl OAuthClientApprovalStatus {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[Self::Requested, Self::Approved, Self::Denied];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Requested => "requested",
+            Self::Approved => "approved",
+            Self::Denied => "denied",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "requested" => Some(Self::Requested),
+            "approved" => Some(Self::Approved),
+            "denied" => Some(Self::Denied),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for OAuthClientApprovalStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for OAuthClientApprovalStatus {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "OAuthClientApprovalStatus",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for OAuthClientApprovalStatus {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for OAuthClientApprovalStatus {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
 }
 /// What type of auth is the domain used for.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum OrganizationDomainAuthType {
-    #[serde(rename = "saml")]
     Saml,
-    #[serde(rename = "general")]
     General,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl OrganizationDomainAuthType {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[Self::Saml, Self::General];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Saml => "saml",
+            Self::General => "general",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "saml" => Some(Self::Saml),
+            "general" => Some(Self::General),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for OrganizationDomainAuthType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for OrganizationDomainAuthType {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "OrganizationDomainAuthType",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for OrganizationDomainAuthType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for OrganizationDomainAuthType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
 }
 /// The different statuses possible for an organization invite.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum OrganizationInviteStatus {
-    #[serde(rename = "pending")]
     Pending,
-    #[serde(rename = "accepted")]
     Accepted,
-    #[serde(rename = "expired")]
     Expired,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl OrganizationInviteStatus {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[Self::Pending, Self::Accepted, Self::Expired];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Pending => "pending",
+            Self::Accepted => "accepted",
+            Self::Expired => "expired",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "pending" => Some(Self::Pending),
+            "accepted" => Some(Self::Accepted),
+            "expired" => Some(Self::Expired),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for OrganizationInviteStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for OrganizationInviteStatus {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "OrganizationInviteStatus",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for OrganizationInviteStatus {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for OrganizationInviteStatus {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
 }
 /// How to treat NULL values, whether they should appear first or last
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum PaginationNulls {
-    #[serde(rename = "first")]
     First,
-    #[serde(rename = "last")]
     Last,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl PaginationNulls {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[Self::First, Self::Last];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::First => "first",
+            Self::Last => "last",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "first" => Some(Self::First),
+            "last" => Some(Self::Last),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for PaginationNulls {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for PaginationNulls {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "PaginationNulls",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for PaginationNulls {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for PaginationNulls {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
 }
 /// By which field should the pagination order by
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum PaginationOrderBy {
-    #[serde(rename = "createdAt")]
     CreatedAt,
-    #[serde(rename = "updatedAt")]
     UpdatedAt,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl PaginationOrderBy {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[Self::CreatedAt, Self::UpdatedAt];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::CreatedAt => "createdAt",
+            Self::UpdatedAt => "updatedAt",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "createdAt" => Some(Self::CreatedAt),
+            "updatedAt" => Some(Self::UpdatedAt),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for PaginationOrderBy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for PaginationOrderBy {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "PaginationOrderBy",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for PaginationOrderBy {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for PaginationOrderBy {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
 }
 /// Whether to sort in ascending or descending order
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum PaginationSortOrder {
     Ascending,
     Descending,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl PaginationSortOrder {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[Self::Ascending, Self::Descending];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Ascending => "Ascending",
+            Self::Descending => "Descending",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "Ascending" => Some(Self::Ascending),
+            "Descending" => Some(Self::Descending),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for PaginationSortOrder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for PaginationSortOrder {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "PaginationSortOrder",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for PaginationSortOrder {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for PaginationSortOrder {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
 }
 /// Type of Post
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum PostType {
-    #[serde(rename = "summary")]
     Summary,
-    #[serde(rename = "update")]
     Update,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl PostType {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[Self::Summary, Self::Update];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Summary => "summary",
+            Self::Update => "update",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "summary" => Some(Self::Summary),
+            "update" => Some(Self::Update),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for PostType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for PostType {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "PostType",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for PostType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for PostType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
 }
 /// `Internal` The scope of product intelligence suggestion data for a team.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum ProductIntelligenceScope {
-    #[serde(rename = "workspace")]
     Workspace,
-    #[serde(rename = "teamHierarchy")]
     TeamHierarchy,
-    #[serde(rename = "team")]
     Team,
-    #[serde(rename = "none")]
     None,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl ProductIntelligenceScope {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] =
+        &[Self::Workspace, Self::TeamHierarchy, Self::Team, Self::None];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Workspace => "workspace",
+            Self::TeamHierarchy => "teamHierarchy",
+            Self::Team => "team",
+            Self::None => "none",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "workspace" => Some(Self::Workspace),
+            "teamHierarchy" => Some(Self::TeamHierarchy),
+            "team" => Some(Self::Team),
+            "none" => Some(Self::None),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for ProductIntelligenceScope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for ProductIntelligenceScope {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "ProductIntelligenceScope",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for ProductIntelligenceScope {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for ProductIntelligenceScope {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
 }
 /// The status of a project milestone.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum ProjectMilestoneStatus {
-    #[serde(rename = "unstarted")]
     Unstarted,
-    #[serde(rename = "next")]
     Next,
-    #[serde(rename = "overdue")]
     Overdue,
-    #[serde(rename = "done")]
     Done,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl ProjectMilestoneStatus {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[Self::Unstarted, Self::Next, Self::Overdue, Self::Done];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Unstarted => "unstarted",
+            Self::Next => "next",
+            Self::Overdue => "overdue",
+            Self::Done => "done",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "unstarted" => Some(Self::Unstarted),
+            "next" => Some(Self::Next),
+            "overdue" => Some(Self::Overdue),
+            "done" => Some(Self::Done),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for ProjectMilestoneStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for ProjectMilestoneStatus {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "ProjectMilestoneStatus",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for ProjectMilestoneStatus {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for ProjectMilestoneStatus {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
 }
 /// A type of project status.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum ProjectStatusType {
-    #[serde(rename = "backlog")]
     Backlog,
-    #[serde(rename = "planned")]
     Planned,
-    #[serde(rename = "started")]
     Started,
-    #[serde(rename = "paused")]
     Paused,
-    #[serde(rename = "completed")]
     Completed,
-    #[serde(rename = "canceled")]
     Canceled,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl ProjectStatusType {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[
+        Self::Backlog,
+        Self::Planned,
+        Self::Started,
+        Self::Paused,
+        Self::Completed,
+        Self::Canceled,
+    ];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Backlog => "backlog",
+            Self::Planned => "planned",
+            Self::Started => "started",
+            Self::Paused => "paused",
+            Self::Completed => "completed",
+            Self::Canceled => "canceled",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "backlog" => Some(Self::Backlog),
+            "planned" => Some(Self::Planned),
+            "started" => Some(Self::Started),
+            "paused" => Some(Self::Paused),
+            "completed" => Some(Self::Completed),
+            "canceled" => Some(Self::Canceled),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for ProjectStatusType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for ProjectStatusType {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "ProjectStatusType",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for ProjectStatusType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for ProjectStatusType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
 }
 /// Different tabs available inside a project.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum ProjectTab {
-    #[serde(rename = "customers")]
     Customers,
-    #[serde(rename = "documents")]
     Documents,
-    #[serde(rename = "issues")]
     Issues,
-    #[serde(rename = "updates")]
     Updates,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl ProjectTab {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[
+        Self::Customers,
+        Self::Documents,
+        Self::Issues,
+        Self::Updates,
+    ];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Customers => "customers",
+            Self::Documents => "documents",
+            Self::Issues => "issues",
+            Self::Updates => "updates",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "customers" => Some(Self::Customers),
+            "documents" => Some(Self::Documents),
+            "issues" => Some(Self::Issues),
+            "updates" => Some(Self::Updates),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for ProjectTab {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for ProjectTab {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "ProjectTab",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for ProjectTab {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for ProjectTab {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
 }
 /// The health type when the project update is created.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum ProjectUpdateHealthType {
-    #[serde(rename = "onTrack")]
     OnTrack,
-    #[serde(rename = "atRisk")]
     AtRisk,
-    #[serde(rename = "offTrack")]
     OffTrack,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl ProjectUpdateHealthType {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[Self::OnTrack, Self::AtRisk, Self::OffTrack];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::OnTrack => "onTrack",
+            Self::AtRisk => "atRisk",
+            Self::OffTrack => "offTrack",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "onTrack" => Some(Self::OnTrack),
+            "atRisk" => Some(Self::AtRisk),
+            "offTrack" => Some(Self::OffTrack),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for ProjectUpdateHealthType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for ProjectUpdateHealthType {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "ProjectUpdateHealthType",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for ProjectUpdateHealthType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for ProjectUpdateHealthType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
 }
 /// The frequency at which to send project update reminders.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum ProjectUpdateReminderFrequency {
-    #[serde(rename = "week")]
     Week,
-    #[serde(rename = "twoWeeks")]
     TwoWeeks,
-    #[serde(rename = "month")]
     Month,
-    #[serde(rename = "never")]
     Never,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl ProjectUpdateReminderFrequency {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[Self::Week, Self::TwoWeeks, Self::Month, Self::Never];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Week => "week",
+            Self::TwoWeeks => "twoWeeks",
+            Self::Month => "month",
+            Self::Never => "never",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "week" => Some(Self::Week),
+            "twoWeeks" => Some(Self::TwoWeeks),
+            "month" => Some(Self::Month),
+            "never" => Some(Self::Never),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for ProjectUpdateReminderFrequency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for ProjectUpdateReminderFrequency {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "ProjectUpdateReminderFrequency",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for ProjectUpdateReminderFrequency {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for ProjectUpdateReminderFrequency {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
 }
 /// `ALPHA` How a pull request check should be opened in the client.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum PullRequestCheckPresentation {
-    #[serde(rename = "jobLogs")]
     JobLogs,
-    #[serde(rename = "runLogs")]
     RunLogs,
-    #[serde(rename = "markdown")]
     Markdown,
-    #[serde(rename = "externalOnly")]
     ExternalOnly,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl PullRequestCheckPresentation {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[
+        Self::JobLogs,
+        Self::RunLogs,
+        Self::Markdown,
+        Self::ExternalOnly,
+    ];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::JobLogs => "jobLogs",
+            Self::RunLogs => "runLogs",
+            Self::Markdown => "markdown",
+            Self::ExternalOnly => "externalOnly",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "jobLogs" => Some(Self::JobLogs),
+            "runLogs" => Some(Self::RunLogs),
+            "markdown" => Some(Self::Markdown),
+            "externalOnly" => Some(Self::ExternalOnly),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for PullRequestCheckPresentation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for PullRequestCheckPresentation {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "PullRequestCheckPresentation",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for PullRequestCheckPresentation {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for PullRequestCheckPresentation {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
 }
 /// The method used to merge a pull request.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum PullRequestMergeMethod {
-    #[serde(rename = "MERGE")]
     Merge,
-    #[serde(rename = "REBASE")]
     Rebase,
-    #[serde(rename = "SQUASH")]
     Squash,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl PullRequestMergeMethod {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[Self::Merge, Self::Rebase, Self::Squash];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Merge => "MERGE",
+            Self::Rebase => "REBASE",
+            Self::Squash => "SQUASH",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "MERGE" => Some(Self::Merge),
+            "REBASE" => Some(Self::Rebase),
+            "SQUASH" => Some(Self::Squash),
+            _ => None,
+        }
+    }
 }
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+impl fmt::Display for PullRequestMergeMethod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for PullRequestMergeMethod {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "PullRequestMergeMethod",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for PullRequestMergeMethod {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for PullRequestMergeMethod {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum PullRequestReviewTool {
-    #[serde(rename = "source")]
     Source,
-    #[serde(rename = "graphite")]
     Graphite,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl PullRequestReviewTool {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[Self::Source, Self::Graphite];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Source => "source",
+            Self::Graphite => "graphite",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "source" => Some(Self::Source),
+            "graphite" => Some(Self::Graphite),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for PullRequestReviewTool {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for PullRequestReviewTool {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "PullRequestReviewTool",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for PullRequestReviewTool {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for PullRequestReviewTool {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
 }
 /// The status of a pull request.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum PullRequestStatus {
-    #[serde(rename = "draft")]
     Draft,
-    #[serde(rename = "open")]
     Open,
-    #[serde(rename = "inReview")]
     InReview,
-    #[serde(rename = "approved")]
     Approved,
-    #[serde(rename = "merged")]
     Merged,
-    #[serde(rename = "closed")]
     Closed,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl PullRequestStatus {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[
+        Self::Draft,
+        Self::Open,
+        Self::InReview,
+        Self::Approved,
+        Self::Merged,
+        Self::Closed,
+    ];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Draft => "draft",
+            Self::Open => "open",
+            Self::InReview => "inReview",
+            Self::Approved => "approved",
+            Self::Merged => "merged",
+            Self::Closed => "closed",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "draft" => Some(Self::Draft),
+            "open" => Some(Self::Open),
+            "inReview" => Some(Self::InReview),
+            "approved" => Some(Self::Approved),
+            "merged" => Some(Self::Merged),
+            "closed" => Some(Self::Closed),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for PullRequestStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for PullRequestStatus {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "PullRequestStatus",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for PullRequestStatus {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for PullRequestStatus {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
 }
 /// The different push subscription types.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum PushSubscriptionType {
-    #[serde(rename = "web")]
     Web,
-    #[serde(rename = "apple")]
     Apple,
-    #[serde(rename = "appleDevelopment")]
     AppleDevelopment,
-    #[serde(rename = "firebase")]
     Firebase,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl PushSubscriptionType {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[
+        Self::Web,
+        Self::Apple,
+        Self::AppleDevelopment,
+        Self::Firebase,
+    ];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Web => "web",
+            Self::Apple => "apple",
+            Self::AppleDevelopment => "appleDevelopment",
+            Self::Firebase => "firebase",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "web" => Some(Self::Web),
+            "apple" => Some(Self::Apple),
+            "appleDevelopment" => Some(Self::AppleDevelopment),
+            "firebase" => Some(Self::Firebase),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for PushSubscriptionType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for PushSubscriptionType {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "PushSubscriptionType",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for PushSubscriptionType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for PushSubscriptionType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
 }
 /// Features release channel.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum ReleaseChannel {
-    #[serde(rename = "development")]
     Development,
-    #[serde(rename = "internal")]
     Internal,
-    #[serde(rename = "privateBeta")]
     PrivateBeta,
-    #[serde(rename = "beta")]
     Beta,
-    #[serde(rename = "preRelease")]
     PreRelease,
-    #[serde(rename = "public")]
     Public,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl ReleaseChannel {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[
+        Self::Development,
+        Self::Internal,
+        Self::PrivateBeta,
+        Self::Beta,
+        Self::PreRelease,
+        Self::Public,
+    ];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Development => "development",
+            Self::Internal => "internal",
+            Self::PrivateBeta => "privateBeta",
+            Self::Beta => "beta",
+            Self::PreRelease => "preRelease",
+            Self::Public => "public",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "development" => Some(Self::Development),
+            "internal" => Some(Self::Internal),
+            "privateBeta" => Some(Self::PrivateBeta),
+            "beta" => Some(Self::Beta),
+            "preRelease" => Some(Self::PreRelease),
+            "public" => Some(Self::Public),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for ReleaseChannel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for ReleaseChannel {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "ReleaseChannel",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for ReleaseChannel {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for ReleaseChannel {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
 }
 /// The type of a release pipeline, which determines how releases are created and managed. Continuous pipelines create a new completed release for each sync. Scheduled pipelines accumulate issues into a started release that is explicitly completed.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum ReleasePipelineType {
-    #[serde(rename = "continuous")]
     Continuous,
-    #[serde(rename = "scheduled")]
     Scheduled,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl ReleasePipelineType {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[Self::Continuous, Self::Scheduled];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Continuous => "continuous",
+            Self::Scheduled => "scheduled",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "continuous" => Some(Self::Continuous),
+            "scheduled" => Some(Self::Scheduled),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for ReleasePipelineType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for ReleasePipelineType {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "ReleasePipelineType",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for ReleasePipelineType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for ReleasePipelineType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
 }
 /// The type of a release stage, which determines the release's lifecycle state. Types include planned, started, completed, and canceled. Each pipeline must have at least one stage of each type, though only started stages may have multiple instances.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum ReleaseStageType {
-    #[serde(rename = "planned")]
     Planned,
-    #[serde(rename = "started")]
     Started,
-    #[serde(rename = "completed")]
     Completed,
-    #[serde(rename = "canceled")]
     Canceled,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl ReleaseStageType {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[
+        Self::Planned,
+        Self::Started,
+        Self::Completed,
+        Self::Canceled,
+    ];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Planned => "planned",
+            Self::Started => "started",
+            Self::Completed => "completed",
+            Self::Canceled => "canceled",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "planned" => Some(Self::Planned),
+            "started" => Some(Self::Started),
+            "completed" => Some(Self::Completed),
+            "canceled" => Some(Self::Canceled),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for ReleaseStageType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for ReleaseStageType {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "ReleaseStageType",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for ReleaseStageType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for ReleaseStageType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
 }
 /// Which day count to use for SLA calculations.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum SLADayCountType {
-    #[serde(rename = "all")]
     All,
-    #[serde(rename = "onlyBusinessDays")]
     OnlyBusinessDays,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl SLADayCountType {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[Self::All, Self::OnlyBusinessDays];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::All => "all",
+            Self::OnlyBusinessDays => "onlyBusinessDays",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "all" => Some(Self::All),
+            "onlyBusinessDays" => Some(Self::OnlyBusinessDays),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for SLADayCountType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for SLADayCountType {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "SLADayCountType",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for SLADayCountType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for SLADayCountType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
 }
 /// The type of the semantic search result.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum SemanticSearchResultType {
-    #[serde(rename = "issue")]
     Issue,
-    #[serde(rename = "project")]
     Project,
-    #[serde(rename = "initiative")]
     Initiative,
-    #[serde(rename = "document")]
     Document,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl SemanticSearchResultType {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] =
+        &[Self::Issue, Self::Project, Self::Initiative, Self::Document];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Issue => "issue",
+            Self::Project => "project",
+            Self::Initiative => "initiative",
+            Self::Document => "document",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "issue" => Some(Self::Issue),
+            "project" => Some(Self::Project),
+            "initiative" => Some(Self::Initiative),
+            "document" => Some(Self::Document),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for SemanticSearchResultType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+impl FromStr for SemanticSearchResultType {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "SemanticSearchResultType",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for SemanticSearchResultType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for SemanticSearchResultType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum SendStrategy {
-    #[serde(rename = "desktopThenPush")]
     DesktopThenPush,
-    #[serde(rename = "desktopAndPush")]
     DesktopAndPush,
-    #[serde(rename = "desktop")]
     Desktop,
-    #[serde(rename = "push")]
     Push,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl SendStrategy {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[
+        Self::DesktopThenPush,
+        Self::DesktopAndPush,
+        Self::Desktop,
+        Self::Push,
+    ];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::DesktopThenPush => "desktopThenPush",
+            Self::DesktopAndPush => "desktopAndPush",
+            Self::Desktop => "desktop",
+            Self::Push => "push",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "desktopThenPush" => Some(Self::DesktopThenPush),
+            "desktopAndPush" => Some(Self::DesktopAndPush),
+            "desktop" => Some(Self::Desktop),
+            "push" => Some(Self::Push),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for SendStrategy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for SendStrategy {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "SendStrategy",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for SendStrategy {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
 }
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+impl<'de> Deserialize<'de> for SendStrategy {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum SlaStatus {
     Breached,
     HighRisk,
@@ -1109,465 +4902,1675 @@ pub enum SlaStatus {
     LowRisk,
     Completed,
     Failed,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl SlaStatus {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[
+        Self::Breached,
+        Self::HighRisk,
+        Self::MediumRisk,
+        Self::LowRisk,
+        Self::Completed,
+        Self::Failed,
+    ];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Breached => "Breached",
+            Self::HighRisk => "HighRisk",
+            Self::MediumRisk => "MediumRisk",
+            Self::LowRisk => "LowRisk",
+            Self::Completed => "Completed",
+            Self::Failed => "Failed",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "Breached" => Some(Self::Breached),
+            "HighRisk" => Some(Self::HighRisk),
+            "MediumRisk" => Some(Self::MediumRisk),
+            "LowRisk" => Some(Self::LowRisk),
+            "Completed" => Some(Self::Completed),
+            "Failed" => Some(Self::Failed),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for SlaStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for SlaStatus {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "SlaStatus",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
 }
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+impl Serialize for SlaStatus {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for SlaStatus {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum SlackChannelType {
     DirectMessage,
     MultiPersonDirectMessage,
     Private,
     PrivateGroup,
     Public,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl SlackChannelType {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[
+        Self::DirectMessage,
+        Self::MultiPersonDirectMessage,
+        Self::Private,
+        Self::PrivateGroup,
+        Self::Public,
+    ];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::DirectMessage => "DirectMessage",
+            Self::MultiPersonDirectMessage => "MultiPersonDirectMessage",
+            Self::Private => "Private",
+            Self::PrivateGroup => "PrivateGroup",
+            Self::Public => "Public",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "DirectMessage" => Some(Self::DirectMessage),
+            "MultiPersonDirectMessage" => Some(Self::MultiPersonDirectMessage),
+            "Private" => Some(Self::Private),
+            "PrivateGroup" => Some(Self::PrivateGroup),
+            "Public" => Some(Self::Public),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for SlackChannelType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for SlackChannelType {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "SlackChannelType",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for SlackChannelType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for SlackChannelType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
 }
 /// The generation status of a summary.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum SummaryGenerationStatus {
-    #[serde(rename = "pending")]
     Pending,
-    #[serde(rename = "completed")]
     Completed,
-    #[serde(rename = "failed")]
     Failed,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl SummaryGenerationStatus {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[Self::Pending, Self::Completed, Self::Failed];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Pending => "pending",
+            Self::Completed => "completed",
+            Self::Failed => "failed",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "pending" => Some(Self::Pending),
+            "completed" => Some(Self::Completed),
+            "failed" => Some(Self::Failed),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for SummaryGenerationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for SummaryGenerationStatus {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "SummaryGenerationStatus",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for SummaryGenerationStatus {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for SummaryGenerationStatus {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
 }
 /// `Internal` How to handle sub-teams when retiring a parent team.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum TeamRetirementSubTeamHandling {
-    #[serde(rename = "unnest")]
     Unnest,
-    #[serde(rename = "retire")]
     Retire,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl TeamRetirementSubTeamHandling {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[Self::Unnest, Self::Retire];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Unnest => "unnest",
+            Self::Retire => "retire",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "unnest" => Some(Self::Unnest),
+            "retire" => Some(Self::Retire),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for TeamRetirementSubTeamHandling {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for TeamRetirementSubTeamHandling {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "TeamRetirementSubTeamHandling",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for TeamRetirementSubTeamHandling {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for TeamRetirementSubTeamHandling {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
 }
 /// All possible roles within a team in terms of access to team settings and operations.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum TeamRoleType {
-    #[serde(rename = "owner")]
     Owner,
-    #[serde(rename = "member")]
     Member,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl TeamRoleType {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[Self::Owner, Self::Member];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Owner => "owner",
+            Self::Member => "member",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "owner" => Some(Self::Owner),
+            "member" => Some(Self::Member),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for TeamRoleType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for TeamRoleType {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "TeamRoleType",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for TeamRoleType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for TeamRoleType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
 }
 /// Which action should be taken after an issue is added to triage.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum TriageResponsibilityAction {
-    #[serde(rename = "assign")]
     Assign,
-    #[serde(rename = "notify")]
     Notify,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl TriageResponsibilityAction {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[Self::Assign, Self::Notify];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Assign => "assign",
+            Self::Notify => "notify",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "assign" => Some(Self::Assign),
+            "notify" => Some(Self::Notify),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for TriageResponsibilityAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for TriageResponsibilityAction {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "TriageResponsibilityAction",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for TriageResponsibilityAction {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for TriageResponsibilityAction {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
 }
 /// The type of error that occurred during triage rule execution.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum TriageRuleErrorType {
-    #[serde(rename = "cycle")]
     Cycle,
-    #[serde(rename = "default")]
     Default,
-    #[serde(rename = "labelGroupConflict")]
     LabelGroupConflict,
-    #[serde(rename = "codingAgentQuotaExceeded")]
     CodingAgentQuotaExceeded,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl TriageRuleErrorType {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[
+        Self::Cycle,
+        Self::Default,
+        Self::LabelGroupConflict,
+        Self::CodingAgentQuotaExceeded,
+    ];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Cycle => "cycle",
+            Self::Default => "default",
+            Self::LabelGroupConflict => "labelGroupConflict",
+            Self::CodingAgentQuotaExceeded => "codingAgentQuotaExceeded",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "cycle" => Some(Self::Cycle),
+            "default" => Some(Self::Default),
+            "labelGroupConflict" => Some(Self::LabelGroupConflict),
+            "codingAgentQuotaExceeded" => Some(Self::CodingAgentQuotaExceeded),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for TriageRuleErrorType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for TriageRuleErrorType {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "TriageRuleErrorType",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for TriageRuleErrorType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
 }
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+impl<'de> Deserialize<'de> for TriageRuleErrorType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum UserContextViewType {
-    #[serde(rename = "assigned")]
     Assigned,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl UserContextViewType {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[Self::Assigned];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Assigned => "assigned",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "assigned" => Some(Self::Assigned),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for UserContextViewType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for UserContextViewType {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "UserContextViewType",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for UserContextViewType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for UserContextViewType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
 }
 /// The types of flags that the user can have.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum UserFlagType {
-    #[serde(rename = "updatedSlackThreadSyncIntegration")]
     UpdatedSlackThreadSyncIntegration,
-    #[serde(rename = "completedOnboarding")]
     CompletedOnboarding,
-    #[serde(rename = "desktopInstalled")]
     DesktopInstalled,
-    #[serde(rename = "teamsPageIntroductionDismissed")]
     TeamsPageIntroductionDismissed,
-    #[serde(rename = "joinTeamIntroductionDismissed")]
     JoinTeamIntroductionDismissed,
-    #[serde(rename = "desktopDownloadToastDismissed")]
     DesktopDownloadToastDismissed,
-    #[serde(rename = "emptyBacklogDismissed")]
     EmptyBacklogDismissed,
-    #[serde(rename = "emptyCustomViewsDismissed")]
     EmptyCustomViewsDismissed,
-    #[serde(rename = "emptyActiveIssuesDismissed")]
     EmptyActiveIssuesDismissed,
-    #[serde(rename = "emptyMyIssuesDismissed")]
     EmptyMyIssuesDismissed,
-    #[serde(rename = "triageWelcomeDismissed")]
     TriageWelcomeDismissed,
-    #[serde(rename = "cycleWelcomeDismissed")]
     CycleWelcomeDismissed,
-    #[serde(rename = "projectWelcomeDismissed")]
     ProjectWelcomeDismissed,
-    #[serde(rename = "projectBacklogWelcomeDismissed")]
     ProjectBacklogWelcomeDismissed,
-    #[serde(rename = "projectUpdatesWelcomeDismissed")]
     ProjectUpdatesWelcomeDismissed,
-    #[serde(rename = "pulseWelcomeDismissed")]
     PulseWelcomeDismissed,
-    #[serde(rename = "analyticsWelcomeDismissed")]
     AnalyticsWelcomeDismissed,
-    #[serde(rename = "insightsWelcomeDismissed")]
     InsightsWelcomeDismissed,
-    #[serde(rename = "insightsHelpDismissed")]
     InsightsHelpDismissed,
-    #[serde(rename = "figmaPromptDismissed")]
     FigmaPromptDismissed,
-    #[serde(rename = "issueMovePromptCompleted")]
     IssueMovePromptCompleted,
-    #[serde(rename = "migrateThemePreference")]
     MigrateThemePreference,
-    #[serde(rename = "listSelectionTip")]
     ListSelectionTip,
-    #[serde(rename = "emptyParagraphSlashCommandTip")]
     EmptyParagraphSlashCommandTip,
-    #[serde(rename = "editorSlashCommandUsed")]
     EditorSlashCommandUsed,
-    #[serde(rename = "canPlaySnake")]
     CanPlaySnake,
-    #[serde(rename = "canPlayTetris")]
     CanPlayTetris,
-    #[serde(rename = "importBannerDismissed")]
     ImportBannerDismissed,
-    #[serde(rename = "tryInvitePeopleDismissed")]
     TryInvitePeopleDismissed,
-    #[serde(rename = "tryRoadmapsDismissed")]
     TryRoadmapsDismissed,
-    #[serde(rename = "tryCyclesDismissed")]
     TryCyclesDismissed,
-    #[serde(rename = "tryTriageDismissed")]
     TryTriageDismissed,
-    #[serde(rename = "tryGithubDismissed")]
     TryGithubDismissed,
-    #[serde(rename = "tryCursorDismissed")]
     TryCursorDismissed,
-    #[serde(rename = "tryCodexDismissed")]
     TryCodexDismissed,
-    #[serde(rename = "rewindBannerDismissed")]
     RewindBannerDismissed,
-    #[serde(rename = "helpIslandFeatureInsightsDismissed")]
     HelpIslandFeatureInsightsDismissed,
-    #[serde(rename = "dueDateShortcutMigration")]
     DueDateShortcutMigration,
-    #[serde(rename = "slackCommentReactionTipShown")]
     SlackCommentReactionTipShown,
-    #[serde(rename = "issueLabelSuggestionUsed")]
     IssueLabelSuggestionUsed,
-    #[serde(rename = "threadedCommentsNudgeIsSeen")]
     ThreadedCommentsNudgeIsSeen,
-    #[serde(rename = "desktopTabsOnboardingDismissed")]
     DesktopTabsOnboardingDismissed,
-    #[serde(rename = "milestoneOnboardingIsSeenAndDismissed")]
     MilestoneOnboardingIsSeenAndDismissed,
-    #[serde(rename = "projectBoardOnboardingIsSeenAndDismissed")]
     ProjectBoardOnboardingIsSeenAndDismissed,
-    #[serde(rename = "figmaPluginBannerDismissed")]
     FigmaPluginBannerDismissed,
-    #[serde(rename = "initiativesBannerDismissed")]
     InitiativesBannerDismissed,
-    #[serde(rename = "commandMenuClearShortcutTip")]
     CommandMenuClearShortcutTip,
-    #[serde(rename = "slackBotWelcomeMessageShown")]
     SlackBotWelcomeMessageShown,
-    #[serde(rename = "teamsBotWelcomeMessageShown")]
     TeamsBotWelcomeMessageShown,
-    #[serde(rename = "slackAgentPromoFromCreateNewIssueShown")]
     SlackAgentPromoFromCreateNewIssueShown,
-    #[serde(rename = "agentExamplesDismissed")]
     AgentExamplesDismissed,
-    #[serde(rename = "agentHomePageNotice")]
     AgentHomePageNotice,
-    #[serde(rename = "agentHomeHeadlineSeen")]
     AgentHomeHeadlineSeen,
-    #[serde(rename = "all")]
     All,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl UserFlagType {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[
+        Self::UpdatedSlackThreadSyncIntegration,
+        Self::CompletedOnboarding,
+        Self::DesktopInstalled,
+        Self::TeamsPageIntroductionDismissed,
+        Self::JoinTeamIntroductionDismissed,
+        Self::DesktopDownloadToastDismissed,
+        Self::EmptyBacklogDismissed,
+        Self::EmptyCustomViewsDismissed,
+        Self::EmptyActiveIssuesDismissed,
+        Self::EmptyMyIssuesDismissed,
+        Self::TriageWelcomeDismissed,
+        Self::CycleWelcomeDismissed,
+        Self::ProjectWelcomeDismissed,
+        Self::ProjectBacklogWelcomeDismissed,
+        Self::ProjectUpdatesWelcomeDismissed,
+        Self::PulseWelcomeDismissed,
+        Self::AnalyticsWelcomeDismissed,
+        Self::InsightsWelcomeDismissed,
+        Self::InsightsHelpDismissed,
+        Self::FigmaPromptDismissed,
+        Self::IssueMovePromptCompleted,
+        Self::MigrateThemePreference,
+        Self::ListSelectionTip,
+        Self::EmptyParagraphSlashCommandTip,
+        Self::EditorSlashCommandUsed,
+        Self::CanPlaySnake,
+        Self::CanPlayTetris,
+        Self::ImportBannerDismissed,
+        Self::TryInvitePeopleDismissed,
+        Self::TryRoadmapsDismissed,
+        Self::TryCyclesDismissed,
+        Self::TryTriageDismissed,
+        Self::TryGithubDismissed,
+        Self::TryCursorDismissed,
+        Self::TryCodexDismissed,
+        Self::RewindBannerDismissed,
+        Self::HelpIslandFeatureInsightsDismissed,
+        Self::DueDateShortcutMigration,
+        Self::SlackCommentReactionTipShown,
+        Self::IssueLabelSuggestionUsed,
+        Self::ThreadedCommentsNudgeIsSeen,
+        Self::DesktopTabsOnboardingDismissed,
+        Self::MilestoneOnboardingIsSeenAndDismissed,
+        Self::ProjectBoardOnboardingIsSeenAndDismissed,
+        Self::FigmaPluginBannerDismissed,
+        Self::InitiativesBannerDismissed,
+        Self::CommandMenuClearShortcutTip,
+        Self::SlackBotWelcomeMessageShown,
+        Self::TeamsBotWelcomeMessageShown,
+        Self::SlackAgentPromoFromCreateNewIssueShown,
+        Self::AgentExamplesDismissed,
+        Self::AgentHomePageNotice,
+        Self::AgentHomeHeadlineSeen,
+        Self::All,
+    ];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::UpdatedSlackThreadSyncIntegration => "updatedSlackThreadSyncIntegration",
+            Self::CompletedOnboarding => "completedOnboarding",
+            Self::DesktopInstalled => "desktopInstalled",
+            Self::TeamsPageIntroductionDismissed => "teamsPageIntroductionDismissed",
+            Self::JoinTeamIntroductionDismissed => "joinTeamIntroductionDismissed",
+            Self::DesktopDownloadToastDismissed => "desktopDownloadToastDismissed",
+            Self::EmptyBacklogDismissed => "emptyBacklogDismissed",
+            Self::EmptyCustomViewsDismissed => "emptyCustomViewsDismissed",
+            Self::EmptyActiveIssuesDismissed => "emptyActiveIssuesDismissed",
+            Self::EmptyMyIssuesDismissed => "emptyMyIssuesDismissed",
+            Self::TriageWelcomeDismissed => "triageWelcomeDismissed",
+            Self::CycleWelcomeDismissed => "cycleWelcomeDismissed",
+            Self::ProjectWelcomeDismissed => "projectWelcomeDismissed",
+            Self::ProjectBacklogWelcomeDismissed => "projectBacklogWelcomeDismissed",
+            Self::ProjectUpdatesWelcomeDismissed => "projectUpdatesWelcomeDismissed",
+            Self::PulseWelcomeDismissed => "pulseWelcomeDismissed",
+            Self::AnalyticsWelcomeDismissed => "analyticsWelcomeDismissed",
+            Self::InsightsWelcomeDismissed => "insightsWelcomeDismissed",
+            Self::InsightsHelpDismissed => "insightsHelpDismissed",
+            Self::FigmaPromptDismissed => "figmaPromptDismissed",
+            Self::IssueMovePromptCompleted => "issueMovePromptCompleted",
+            Self::MigrateThemePreference => "migrateThemePreference",
+            Self::ListSelectionTip => "listSelectionTip",
+            Self::EmptyParagraphSlashCommandTip => "emptyParagraphSlashCommandTip",
+            Self::EditorSlashCommandUsed => "editorSlashCommandUsed",
+            Self::CanPlaySnake => "canPlaySnake",
+            Self::CanPlayTetris => "canPlayTetris",
+            Self::ImportBannerDismissed => "importBannerDismissed",
+            Self::TryInvitePeopleDismissed => "tryInvitePeopleDismissed",
+            Self::TryRoadmapsDismissed => "tryRoadmapsDismissed",
+            Self::TryCyclesDismissed => "tryCyclesDismissed",
+            Self::TryTriageDismissed => "tryTriageDismissed",
+            Self::TryGithubDismissed => "tryGithubDismissed",
+            Self::TryCursorDismissed => "tryCursorDismissed",
+            Self::TryCodexDismissed => "tryCodexDismissed",
+            Self::RewindBannerDismissed => "rewindBannerDismissed",
+            Self::HelpIslandFeatureInsightsDismissed => "helpIslandFeatureInsightsDismissed",
+            Self::DueDateShortcutMigration => "dueDateShortcutMigration",
+            Self::SlackCommentReactionTipShown => "slackCommentReactionTipShown",
+            Self::IssueLabelSuggestionUsed => "issueLabelSuggestionUsed",
+            Self::ThreadedCommentsNudgeIsSeen => "threadedCommentsNudgeIsSeen",
+            Self::DesktopTabsOnboardingDismissed => "desktopTabsOnboardingDismissed",
+            Self::MilestoneOnboardingIsSeenAndDismissed => "milestoneOnboardingIsSeenAndDismissed",
+            Self::ProjectBoardOnboardingIsSeenAndDismissed => {
+                "projectBoardOnboardingIsSeenAndDismissed"
+            }
+            Self::FigmaPluginBannerDismissed => "figmaPluginBannerDismissed",
+            Self::InitiativesBannerDismissed => "initiativesBannerDismissed",
+            Self::CommandMenuClearShortcutTip => "commandMenuClearShortcutTip",
+            Self::SlackBotWelcomeMessageShown => "slackBotWelcomeMessageShown",
+            Self::TeamsBotWelcomeMessageShown => "teamsBotWelcomeMessageShown",
+            Self::SlackAgentPromoFromCreateNewIssueShown => {
+                "slackAgentPromoFromCreateNewIssueShown"
+            }
+            Self::AgentExamplesDismissed => "agentExamplesDismissed",
+            Self::AgentHomePageNotice => "agentHomePageNotice",
+            Self::AgentHomeHeadlineSeen => "agentHomeHeadlineSeen",
+            Self::All => "all",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "updatedSlackThreadSyncIntegration" => Some(Self::UpdatedSlackThreadSyncIntegration),
+            "completedOnboarding" => Some(Self::CompletedOnboarding),
+            "desktopInstalled" => Some(Self::DesktopInstalled),
+            "teamsPageIntroductionDismissed" => Some(Self::TeamsPageIntroductionDismissed),
+            "joinTeamIntroductionDismissed" => Some(Self::JoinTeamIntroductionDismissed),
+            "desktopDownloadToastDismissed" => Some(Self::DesktopDownloadToastDismissed),
+            "emptyBacklogDismissed" => Some(Self::EmptyBacklogDismissed),
+            "emptyCustomViewsDismissed" => Some(Self::EmptyCustomViewsDismissed),
+            "emptyActiveIssuesDismissed" => Some(Self::EmptyActiveIssuesDismissed),
+            "emptyMyIssuesDismissed" => Some(Self::EmptyMyIssuesDismissed),
+            "triageWelcomeDismissed" => Some(Self::TriageWelcomeDismissed),
+            "cycleWelcomeDismissed" => Some(Self::CycleWelcomeDismissed),
+            "projectWelcomeDismissed" => Some(Self::ProjectWelcomeDismissed),
+            "projectBacklogWelcomeDismissed" => Some(Self::ProjectBacklogWelcomeDismissed),
+            "projectUpdatesWelcomeDismissed" => Some(Self::ProjectUpdatesWelcomeDismissed),
+            "pulseWelcomeDismissed" => Some(Self::PulseWelcomeDismissed),
+            "analyticsWelcomeDismissed" => Some(Self::AnalyticsWelcomeDismissed),
+            "insightsWelcomeDismissed" => Some(Self::InsightsWelcomeDismissed),
+            "insightsHelpDismissed" => Some(Self::InsightsHelpDismissed),
+            "figmaPromptDismissed" => Some(Self::FigmaPromptDismissed),
+            "issueMovePromptCompleted" => Some(Self::IssueMovePromptCompleted),
+            "migrateThemePreference" => Some(Self::MigrateThemePreference),
+            "listSelectionTip" => Some(Self::ListSelectionTip),
+            "emptyParagraphSlashCommandTip" => Some(Self::EmptyParagraphSlashCommandTip),
+            "editorSlashCommandUsed" => Some(Self::EditorSlashCommandUsed),
+            "canPlaySnake" => Some(Self::CanPlaySnake),
+            "canPlayTetris" => Some(Self::CanPlayTetris),
+            "importBannerDismissed" => Some(Self::ImportBannerDismissed),
+            "tryInvitePeopleDismissed" => Some(Self::TryInvitePeopleDismissed),
+            "tryRoadmapsDismissed" => Some(Self::TryRoadmapsDismissed),
+            "tryCyclesDismissed" => Some(Self::TryCyclesDismissed),
+            "tryTriageDismissed" => Some(Self::TryTriageDismissed),
+            "tryGithubDismissed" => Some(Self::TryGithubDismissed),
+            "tryCursorDismissed" => Some(Self::TryCursorDismissed),
+            "tryCodexDismissed" => Some(Self::TryCodexDismissed),
+            "rewindBannerDismissed" => Some(Self::RewindBannerDismissed),
+            "helpIslandFeatureInsightsDismissed" => Some(Self::HelpIslandFeatureInsightsDismissed),
+            "dueDateShortcutMigration" => Some(Self::DueDateShortcutMigration),
+            "slackCommentReactionTipShown" => Some(Self::SlackCommentReactionTipShown),
+            "issueLabelSuggestionUsed" => Some(Self::IssueLabelSuggestionUsed),
+            "threadedCommentsNudgeIsSeen" => Some(Self::ThreadedCommentsNudgeIsSeen),
+            "desktopTabsOnboardingDismissed" => Some(Self::DesktopTabsOnboardingDismissed),
+            "milestoneOnboardingIsSeenAndDismissed" => {
+                Some(Self::MilestoneOnboardingIsSeenAndDismissed)
+            }
+            "projectBoardOnboardingIsSeenAndDismissed" => {
+                Some(Self::ProjectBoardOnboardingIsSeenAndDismissed)
+            }
+            "figmaPluginBannerDismissed" => Some(Self::FigmaPluginBannerDismissed),
+            "initiativesBannerDismissed" => Some(Self::InitiativesBannerDismissed),
+            "commandMenuClearShortcutTip" => Some(Self::CommandMenuClearShortcutTip),
+            "slackBotWelcomeMessageShown" => Some(Self::SlackBotWelcomeMessageShown),
+            "teamsBotWelcomeMessageShown" => Some(Self::TeamsBotWelcomeMessageShown),
+            "slackAgentPromoFromCreateNewIssueShown" => {
+                Some(Self::SlackAgentPromoFromCreateNewIssueShown)
+            }
+            "agentExamplesDismissed" => Some(Self::AgentExamplesDismissed),
+            "agentHomePageNotice" => Some(Self::AgentHomePageNotice),
+            "agentHomeHeadlineSeen" => Some(Self::AgentHomeHeadlineSeen),
+            "all" => Some(Self::All),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for UserFlagType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for UserFlagType {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "UserFlagType",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for UserFlagType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for UserFlagType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
 }
 /// Operations that can be applied to UserFlagType.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum UserFlagUpdateOperation {
-    #[serde(rename = "incr")]
     Incr,
-    #[serde(rename = "decr")]
     Decr,
-    #[serde(rename = "clear")]
     Clear,
-    #[serde(rename = "lock")]
     Lock,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl UserFlagUpdateOperation {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[Self::Incr, Self::Decr, Self::Clear, Self::Lock];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Incr => "incr",
+            Self::Decr => "decr",
+            Self::Clear => "clear",
+            Self::Lock => "lock",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "incr" => Some(Self::Incr),
+            "decr" => Some(Self::Decr),
+            "clear" => Some(Self::Clear),
+            "lock" => Some(Self::Lock),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for UserFlagUpdateOperation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for UserFlagUpdateOperation {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "UserFlagUpdateOperation",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for UserFlagUpdateOperation {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for UserFlagUpdateOperation {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
 }
 /// The different permission roles available to users in a workspace.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum UserRoleType {
-    #[serde(rename = "owner")]
     Owner,
-    #[serde(rename = "admin")]
     Admin,
-    #[serde(rename = "guest")]
     Guest,
-    #[serde(rename = "user")]
     User,
-    #[serde(rename = "app")]
     App,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl UserRoleType {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] =
+        &[Self::Owner, Self::Admin, Self::Guest, Self::User, Self::App];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Owner => "owner",
+            Self::Admin => "admin",
+            Self::Guest => "guest",
+            Self::User => "user",
+            Self::App => "app",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "owner" => Some(Self::Owner),
+            "admin" => Some(Self::Admin),
+            "guest" => Some(Self::Guest),
+            "user" => Some(Self::User),
+            "app" => Some(Self::App),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for UserRoleType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for UserRoleType {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "UserRoleType",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for UserRoleType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for UserRoleType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
 }
 /// Device type for theme
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum UserSettingsThemeDeviceType {
-    #[serde(rename = "desktop")]
     Desktop,
-    #[serde(rename = "mobileWeb")]
     MobileWeb,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl UserSettingsThemeDeviceType {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[Self::Desktop, Self::MobileWeb];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Desktop => "desktop",
+            Self::MobileWeb => "mobileWeb",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "desktop" => Some(Self::Desktop),
+            "mobileWeb" => Some(Self::MobileWeb),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for UserSettingsThemeDeviceType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for UserSettingsThemeDeviceType {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "UserSettingsThemeDeviceType",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for UserSettingsThemeDeviceType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for UserSettingsThemeDeviceType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
 }
 /// Theme color mode
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum UserSettingsThemeMode {
-    #[serde(rename = "light")]
     Light,
-    #[serde(rename = "dark")]
     Dark,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl UserSettingsThemeMode {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[Self::Light, Self::Dark];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Light => "light",
+            Self::Dark => "dark",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "light" => Some(Self::Light),
+            "dark" => Some(Self::Dark),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for UserSettingsThemeMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for UserSettingsThemeMode {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "UserSettingsThemeMode",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for UserSettingsThemeMode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for UserSettingsThemeMode {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
 }
 /// Theme preset options
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum UserSettingsThemePreset {
-    #[serde(rename = "system")]
     System,
-    #[serde(rename = "light")]
     Light,
-    #[serde(rename = "pureLight")]
     PureLight,
-    #[serde(rename = "dark")]
     Dark,
-    #[serde(rename = "magicBlue")]
     MagicBlue,
-    #[serde(rename = "classicDark")]
     ClassicDark,
-    #[serde(rename = "custom")]
     Custom,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl UserSettingsThemePreset {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[
+        Self::System,
+        Self::Light,
+        Self::PureLight,
+        Self::Dark,
+        Self::MagicBlue,
+        Self::ClassicDark,
+        Self::Custom,
+    ];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::System => "system",
+            Self::Light => "light",
+            Self::PureLight => "pureLight",
+            Self::Dark => "dark",
+            Self::MagicBlue => "magicBlue",
+            Self::ClassicDark => "classicDark",
+            Self::Custom => "custom",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "system" => Some(Self::System),
+            "light" => Some(Self::Light),
+            "pureLight" => Some(Self::PureLight),
+            "dark" => Some(Self::Dark),
+            "magicBlue" => Some(Self::MagicBlue),
+            "classicDark" => Some(Self::ClassicDark),
+            "custom" => Some(Self::Custom),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for UserSettingsThemePreset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for UserSettingsThemePreset {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "UserSettingsThemePreset",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for UserSettingsThemePreset {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for UserSettingsThemePreset {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
 }
 /// The type of view preferences (either user or workspace level preferences).
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum ViewPreferencesType {
-    #[serde(rename = "organization")]
     Organization,
-    #[serde(rename = "user")]
     User,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl ViewPreferencesType {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[Self::Organization, Self::User];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Organization => "organization",
+            Self::User => "user",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "organization" => Some(Self::Organization),
+            "user" => Some(Self::User),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for ViewPreferencesType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for ViewPreferencesType {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "ViewPreferencesType",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for ViewPreferencesType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for ViewPreferencesType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
 }
 /// The client view this custom view is targeting.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum ViewType {
-    #[serde(rename = "inbox")]
     Inbox,
-    #[serde(rename = "myIssues")]
     MyIssues,
-    #[serde(rename = "myIssuesCreatedByMe")]
     MyIssuesCreatedByMe,
-    #[serde(rename = "myIssuesSubscribedTo")]
     MyIssuesSubscribedTo,
-    #[serde(rename = "myIssuesActivity")]
     MyIssuesActivity,
-    #[serde(rename = "myIssuesSharedWithMe")]
     MyIssuesSharedWithMe,
-    #[serde(rename = "userProfile")]
     UserProfile,
-    #[serde(rename = "userProfileCreatedByUser")]
     UserProfileCreatedByUser,
-    #[serde(rename = "board")]
     Board,
-    #[serde(rename = "completedCycle")]
     CompletedCycle,
-    #[serde(rename = "cycle")]
     Cycle,
-    #[serde(rename = "release")]
     Release,
-    #[serde(rename = "continuousPipelineReleases")]
     ContinuousPipelineReleases,
-    #[serde(rename = "scheduledPipelineReleases")]
     ScheduledPipelineReleases,
-    #[serde(rename = "project")]
     Project,
-    #[serde(rename = "projectDocuments")]
     ProjectDocuments,
-    #[serde(rename = "label")]
     Label,
-    #[serde(rename = "triage")]
     Triage,
-    #[serde(rename = "activeIssues")]
     ActiveIssues,
-    #[serde(rename = "backlog")]
     Backlog,
-    #[serde(rename = "subIssues")]
     SubIssues,
-    #[serde(rename = "allIssues")]
     AllIssues,
-    #[serde(rename = "dashboards")]
     Dashboards,
-    #[serde(rename = "customView")]
     CustomView,
-    #[serde(rename = "customViews")]
     CustomViews,
-    #[serde(rename = "initiative")]
     Initiative,
-    #[serde(rename = "initiativeOverview")]
     InitiativeOverview,
-    #[serde(rename = "initiativeOverviewSubInitiatives")]
     InitiativeOverviewSubInitiatives,
-    #[serde(rename = "initiatives")]
     Initiatives,
-    #[serde(rename = "initiativesPlanned")]
     InitiativesPlanned,
-    #[serde(rename = "initiativesCompleted")]
     InitiativesCompleted,
-    #[serde(rename = "projects")]
     Projects,
-    #[serde(rename = "projectsAll")]
     ProjectsAll,
-    #[serde(rename = "projectsBacklog")]
     ProjectsBacklog,
-    #[serde(rename = "projectsClosed")]
     ProjectsClosed,
-    #[serde(rename = "projectLabel")]
     ProjectLabel,
-    #[serde(rename = "search")]
     Search,
-    #[serde(rename = "splitSearch")]
     SplitSearch,
-    #[serde(rename = "teams")]
     Teams,
-    #[serde(rename = "archive")]
     Archive,
-    #[serde(rename = "quickView")]
     QuickView,
-    #[serde(rename = "issueIdentifiers")]
     IssueIdentifiers,
-    #[serde(rename = "customers")]
     Customers,
-    #[serde(rename = "customer")]
     Customer,
-    #[serde(rename = "embeddedCustomerNeeds")]
     EmbeddedCustomerNeeds,
-    #[serde(rename = "projectCustomerNeeds")]
     ProjectCustomerNeeds,
-    #[serde(rename = "reviews")]
     Reviews,
-    #[serde(rename = "myReviews")]
     MyReviews,
-    #[serde(rename = "createdReviews")]
     CreatedReviews,
-    #[serde(rename = "agents")]
     Agents,
-    #[serde(rename = "focus")]
     Focus,
-    #[serde(rename = "feedAll")]
     FeedAll,
-    #[serde(rename = "feedCreated")]
     FeedCreated,
-    #[serde(rename = "feedFollowing")]
     FeedFollowing,
-    #[serde(rename = "feedPopular")]
     FeedPopular,
-    #[serde(rename = "workspaceMembers")]
     WorkspaceMembers,
-    #[serde(rename = "releasePipelines")]
     ReleasePipelines,
-    #[serde(rename = "roadmapAll")]
     RoadmapAll,
-    #[serde(rename = "roadmap")]
     Roadmap,
-    #[serde(rename = "roadmaps")]
     Roadmaps,
-    #[serde(rename = "roadmapClosed")]
     RoadmapClosed,
-    #[serde(rename = "roadmapBacklog")]
     RoadmapBacklog,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
 }
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+impl ViewType {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[
+        Self::Inbox,
+        Self::MyIssues,
+        Self::MyIssuesCreatedByMe,
+        Self::MyIssuesSubscribedTo,
+        Self::MyIssuesActivity,
+        Self::MyIssuesSharedWithMe,
+        Self::UserProfile,
+        Self::UserProfileCreatedByUser,
+        Self::Board,
+        Self::CompletedCycle,
+        Self::Cycle,
+        Self::Release,
+        Self::ContinuousPipelineReleases,
+        Self::ScheduledPipelineReleases,
+        Self::Project,
+        Self::ProjectDocuments,
+        Self::Label,
+        Self::Triage,
+        Self::ActiveIssues,
+        Self::Backlog,
+        Self::SubIssues,
+        Self::AllIssues,
+        Self::Dashboards,
+        Self::CustomView,
+        Self::CustomViews,
+        Self::Initiative,
+        Self::InitiativeOverview,
+        Self::InitiativeOverviewSubInitiatives,
+        Self::Initiatives,
+        Self::InitiativesPlanned,
+        Self::InitiativesCompleted,
+        Self::Projects,
+        Self::ProjectsAll,
+        Self::ProjectsBacklog,
+        Self::ProjectsClosed,
+        Self::ProjectLabel,
+        Self::Search,
+        Self::SplitSearch,
+        Self::Teams,
+        Self::Archive,
+        Self::QuickView,
+        Self::IssueIdentifiers,
+        Self::Customers,
+        Self::Customer,
+        Self::EmbeddedCustomerNeeds,
+        Self::ProjectCustomerNeeds,
+        Self::Reviews,
+        Self::MyReviews,
+        Self::CreatedReviews,
+        Self::Agents,
+        Self::Focus,
+        Self::FeedAll,
+        Self::FeedCreated,
+        Self::FeedFollowing,
+        Self::FeedPopular,
+        Self::WorkspaceMembers,
+        Self::ReleasePipelines,
+        Self::RoadmapAll,
+        Self::Roadmap,
+        Self::Roadmaps,
+        Self::RoadmapClosed,
+        Self::RoadmapBacklog,
+    ];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Inbox => "inbox",
+            Self::MyIssues => "myIssues",
+            Self::MyIssuesCreatedByMe => "myIssuesCreatedByMe",
+            Self::MyIssuesSubscribedTo => "myIssuesSubscribedTo",
+            Self::MyIssuesActivity => "myIssuesActivity",
+            Self::MyIssuesSharedWithMe => "myIssuesSharedWithMe",
+            Self::UserProfile => "userProfile",
+            Self::UserProfileCreatedByUser => "userProfileCreatedByUser",
+            Self::Board => "board",
+            Self::CompletedCycle => "completedCycle",
+            Self::Cycle => "cycle",
+            Self::Release => "release",
+            Self::ContinuousPipelineReleases => "continuousPipelineReleases",
+            Self::ScheduledPipelineReleases => "scheduledPipelineReleases",
+            Self::Project => "project",
+            Self::ProjectDocuments => "projectDocuments",
+            Self::Label => "label",
+            Self::Triage => "triage",
+            Self::ActiveIssues => "activeIssues",
+            Self::Backlog => "backlog",
+            Self::SubIssues => "subIssues",
+            Self::AllIssues => "allIssues",
+            Self::Dashboards => "dashboards",
+            Self::CustomView => "customView",
+            Self::CustomViews => "customViews",
+            Self::Initiative => "initiative",
+            Self::InitiativeOverview => "initiativeOverview",
+            Self::InitiativeOverviewSubInitiatives => "initiativeOverviewSubInitiatives",
+            Self::Initiatives => "initiatives",
+            Self::InitiativesPlanned => "initiativesPlanned",
+            Self::InitiativesCompleted => "initiativesCompleted",
+            Self::Projects => "projects",
+            Self::ProjectsAll => "projectsAll",
+            Self::ProjectsBacklog => "projectsBacklog",
+            Self::ProjectsClosed => "projectsClosed",
+            Self::ProjectLabel => "projectLabel",
+            Self::Search => "search",
+            Self::SplitSearch => "splitSearch",
+            Self::Teams => "teams",
+            Self::Archive => "archive",
+            Self::QuickView => "quickView",
+            Self::IssueIdentifiers => "issueIdentifiers",
+            Self::Customers => "customers",
+            Self::Customer => "customer",
+            Self::EmbeddedCustomerNeeds => "embeddedCustomerNeeds",
+            Self::ProjectCustomerNeeds => "projectCustomerNeeds",
+            Self::Reviews => "reviews",
+            Self::MyReviews => "myReviews",
+            Self::CreatedReviews => "createdReviews",
+            Self::Agents => "agents",
+            Self::Focus => "focus",
+            Self::FeedAll => "feedAll",
+            Self::FeedCreated => "feedCreated",
+            Self::FeedFollowing => "feedFollowing",
+            Self::FeedPopular => "feedPopular",
+            Self::WorkspaceMembers => "workspaceMembers",
+            Self::ReleasePipelines => "releasePipelines",
+            Self::RoadmapAll => "roadmapAll",
+            Self::Roadmap => "roadmap",
+            Self::Roadmaps => "roadmaps",
+            Self::RoadmapClosed => "roadmapClosed",
+            Self::RoadmapBacklog => "roadmapBacklog",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "inbox" => Some(Self::Inbox),
+            "myIssues" => Some(Self::MyIssues),
+            "myIssuesCreatedByMe" => Some(Self::MyIssuesCreatedByMe),
+            "myIssuesSubscribedTo" => Some(Self::MyIssuesSubscribedTo),
+            "myIssuesActivity" => Some(Self::MyIssuesActivity),
+            "myIssuesSharedWithMe" => Some(Self::MyIssuesSharedWithMe),
+            "userProfile" => Some(Self::UserProfile),
+            "userProfileCreatedByUser" => Some(Self::UserProfileCreatedByUser),
+            "board" => Some(Self::Board),
+            "completedCycle" => Some(Self::CompletedCycle),
+            "cycle" => Some(Self::Cycle),
+            "release" => Some(Self::Release),
+            "continuousPipelineReleases" => Some(Self::ContinuousPipelineReleases),
+            "scheduledPipelineReleases" => Some(Self::ScheduledPipelineReleases),
+            "project" => Some(Self::Project),
+            "projectDocuments" => Some(Self::ProjectDocuments),
+            "label" => Some(Self::Label),
+            "triage" => Some(Self::Triage),
+            "activeIssues" => Some(Self::ActiveIssues),
+            "backlog" => Some(Self::Backlog),
+            "subIssues" => Some(Self::SubIssues),
+            "allIssues" => Some(Self::AllIssues),
+            "dashboards" => Some(Self::Dashboards),
+            "customView" => Some(Self::CustomView),
+            "customViews" => Some(Self::CustomViews),
+            "initiative" => Some(Self::Initiative),
+            "initiativeOverview" => Some(Self::InitiativeOverview),
+            "initiativeOverviewSubInitiatives" => Some(Self::InitiativeOverviewSubInitiatives),
+            "initiatives" => Some(Self::Initiatives),
+            "initiativesPlanned" => Some(Self::InitiativesPlanned),
+            "initiativesCompleted" => Some(Self::InitiativesCompleted),
+            "projects" => Some(Self::Projects),
+            "projectsAll" => Some(Self::ProjectsAll),
+            "projectsBacklog" => Some(Self::ProjectsBacklog),
+            "projectsClosed" => Some(Self::ProjectsClosed),
+            "projectLabel" => Some(Self::ProjectLabel),
+            "search" => Some(Self::Search),
+            "splitSearch" => Some(Self::SplitSearch),
+            "teams" => Some(Self::Teams),
+            "archive" => Some(Self::Archive),
+            "quickView" => Some(Self::QuickView),
+            "issueIdentifiers" => Some(Self::IssueIdentifiers),
+            "customers" => Some(Self::Customers),
+            "customer" => Some(Self::Customer),
+            "embeddedCustomerNeeds" => Some(Self::EmbeddedCustomerNeeds),
+            "projectCustomerNeeds" => Some(Self::ProjectCustomerNeeds),
+            "reviews" => Some(Self::Reviews),
+            "myReviews" => Some(Self::MyReviews),
+            "createdReviews" => Some(Self::CreatedReviews),
+            "agents" => Some(Self::Agents),
+            "focus" => Some(Self::Focus),
+            "feedAll" => Some(Self::FeedAll),
+            "feedCreated" => Some(Self::FeedCreated),
+            "feedFollowing" => Some(Self::FeedFollowing),
+            "feedPopular" => Some(Self::FeedPopular),
+            "workspaceMembers" => Some(Self::WorkspaceMembers),
+            "releasePipelines" => Some(Self::ReleasePipelines),
+            "roadmapAll" => Some(Self::RoadmapAll),
+            "roadmap" => Some(Self::Roadmap),
+            "roadmaps" => Some(Self::Roadmaps),
+            "roadmapClosed" => Some(Self::RoadmapClosed),
+            "roadmapBacklog" => Some(Self::RoadmapBacklog),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for ViewType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for ViewType {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "ViewType",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for ViewType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for ViewType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum WorkflowTrigger {
-    #[serde(rename = "entityCreated")]
     EntityCreated,
-    #[serde(rename = "entityUpdated")]
     EntityUpdated,
-    #[serde(rename = "entityCreatedOrUpdated")]
     EntityCreatedOrUpdated,
-    #[serde(rename = "entityRemoved")]
     EntityRemoved,
-    #[serde(rename = "entityUnarchived")]
     EntityUnarchived,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl WorkflowTrigger {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[
+        Self::EntityCreated,
+        Self::EntityUpdated,
+        Self::EntityCreatedOrUpdated,
+        Self::EntityRemoved,
+        Self::EntityUnarchived,
+    ];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::EntityCreated => "entityCreated",
+            Self::EntityUpdated => "entityUpdated",
+            Self::EntityCreatedOrUpdated => "entityCreatedOrUpdated",
+            Self::EntityRemoved => "entityRemoved",
+            Self::EntityUnarchived => "entityUnarchived",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "entityCreated" => Some(Self::EntityCreated),
+            "entityUpdated" => Some(Self::EntityUpdated),
+            "entityCreatedOrUpdated" => Some(Self::EntityCreatedOrUpdated),
+            "entityRemoved" => Some(Self::EntityRemoved),
+            "entityUnarchived" => Some(Self::EntityUnarchived),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for WorkflowTrigger {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+impl FromStr for WorkflowTrigger {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "WorkflowTrigger",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for WorkflowTrigger {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for WorkflowTrigger {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum WorkflowTriggerType {
-    #[serde(rename = "issue")]
     Issue,
-    #[serde(rename = "project")]
     Project,
-    #[serde(rename = "release")]
     Release,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl WorkflowTriggerType {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[Self::Issue, Self::Project, Self::Release];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Issue => "issue",
+            Self::Project => "project",
+            Self::Release => "release",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "issue" => Some(Self::Issue),
+            "project" => Some(Self::Project),
+            "release" => Some(Self::Release),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for WorkflowTriggerType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for WorkflowTriggerType {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "WorkflowTriggerType",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for WorkflowTriggerType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
 }
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+impl<'de> Deserialize<'de> for WorkflowTriggerType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum WorkflowType {
-    #[serde(rename = "sla")]
     Sla,
-    #[serde(rename = "automation")]
     Automation,
-    #[serde(rename = "viewSubscription")]
     ViewSubscription,
-    #[serde(rename = "triage")]
     Triage,
-    #[serde(rename = "triageAutomation")]
     TriageAutomation,
-    #[serde(rename = "release")]
     Release,
-    /// Catch-all for unknown/new variants added by Linear.
-    #[serde(other)]
-    Unknown,
+    /// A value added by Linear after this SDK was generated, kept
+    /// verbatim so it round-trips.
+    Unknown(String),
+}
+impl WorkflowType {
+    /// Every known value, in schema order.
+    pub const ALL: &'static [Self] = &[
+        Self::Sla,
+        Self::Automation,
+        Self::ViewSubscription,
+        Self::Triage,
+        Self::TriageAutomation,
+        Self::Release,
+    ];
+    /// The value as it appears in the GraphQL schema.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Sla => "sla",
+            Self::Automation => "automation",
+            Self::ViewSubscription => "viewSubscription",
+            Self::Triage => "triage",
+            Self::TriageAutomation => "triageAutomation",
+            Self::Release => "release",
+            Self::Unknown(value) => value,
+        }
+    }
+    fn from_known(value: &str) -> Option<Self> {
+        match value {
+            "sla" => Some(Self::Sla),
+            "automation" => Some(Self::Automation),
+            "viewSubscription" => Some(Self::ViewSubscription),
+            "triage" => Some(Self::Triage),
+            "triageAutomation" => Some(Self::TriageAutomation),
+            "release" => Some(Self::Release),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for WorkflowType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+impl FromStr for WorkflowType {
+    type Err = ParseEnumError;
+    /// Parse a known value; unknown values are rejected.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from_known(value).ok_or_else(|| {
+            ParseEnumError::new(
+                "WorkflowType",
+                value,
+                Self::ALL.iter().map(|v| v.as_str()).collect(),
+            )
+        })
+    }
+}
+impl Serialize for WorkflowType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for WorkflowType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::from_known(&value).unwrap_or(Self::Unknown(value)))
+    }
 }
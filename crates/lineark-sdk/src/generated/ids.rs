@@ -0,0 +1,3430 @@
+//! Generated entity ID newtypes.
+//!
+//! Operations that take an entity's `id` accept `impl Into<…Id>`, so a
+//! team ID can't be passed where an issue ID is expected once callers
+//! opt into the newtypes.
+//!
+//! Generated by lineark-codegen — do not edit.
+use serde::{Deserialize, Serialize};
+/// Identifier of a [`AgentActivity`](super::types::AgentActivity).
+///
+/// Accepted by every operation that takes this entity's `id`.
+/// Plain strings convert with `From`, so the newtype is opt-in.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct AgentActivityId(String);
+impl AgentActivityId {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self(id.into())
+    }
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+impl From<String> for AgentActivityId {
+    fn from(id: String) -> Self {
+        Self(id)
+    }
+}
+impl From<&str> for AgentActivityId {
+    fn from(id: &str) -> Self {
+        Self(id.to_string())
+    }
+}
+impl From<&String> for AgentActivityId {
+    fn from(id: &String) -> Self {
+        Self(id.clone())
+    }
+}
+impl From<&AgentActivityId> for AgentActivityId {
+    fn from(id: &AgentActivityId) -> Self {
+        id.clone()
+    }
+}
+impl From<AgentActivityId> for String {
+    fn from(id: AgentActivityId) -> Self {
+        id.0
+    }
+}
+impl AsRef<str> for AgentActivityId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+impl std::fmt::Display for AgentActivityId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl super::types::AgentActivity {
+    /// This entity's ID as an [`AgentActivityId`], if selected.
+    pub fn typed_id(&self) -> Option<AgentActivityId> {
+        self.id.clone().map(AgentActivityId)
+    }
+}
+/// Identifier of a [`AgentSession`](super::types::AgentSession).
+///
+/// Accepted by every operation that takes this entity's `id`.
+/// Plain strings convert with `From`, so the newtype is opt-in.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct AgentSessionId(String);
+impl AgentSessionId {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self(id.into())
+    }
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+impl From<String> for AgentSessionId {
+    fn from(id: String) -> Self {
+        Self(id)
+    }
+}
+impl From<&str> for AgentSessionId {
+    fn from(id: &str) -> Self {
+        Self(id.to_string())
+    }
+}
+impl From<&String> for AgentSessionId {
+    fn from(id: &String) -> Self {
+        Self(id.clone())
+    }
+}
+impl From<&AgentSessionId> for AgentSessionId {
+    fn from(id: &AgentSessionId) -> Self {
+        id.clone()
+    }
+}
+impl From<AgentSessionId> for String {
+    fn from(id: AgentSessionId) -> Self {
+        id.0
+    }
+}
+impl AsRef<str> for AgentSessionId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+impl std::fmt::Display for AgentSessionId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl super::types::AgentSession {
+    /// This entity's ID as an [`AgentSessionId`], if selected.
+    pub fn typed_id(&self) -> Option<AgentSessionId> {
+        self.id.clone().map(AgentSessionId)
+    }
+}
+/// Identifier of a [`Attachment`](super::types::Attachment).
+///
+/// Accepted by every operation that takes this entity's `id`.
+/// Plain strings convert with `From`, so the newtype is opt-in.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct AttachmentId(String);
+impl AttachmentId {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self(id.into())
+    }
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+impl From<String> for AttachmentId {
+    fn from(id: String) -> Self {
+        Self(id)
+    }
+}
+impl From<&str> for AttachmentId {
+    fn from(id: &str) -> Self {
+        Self(id.to_string())
+    }
+}
+impl From<&String> for AttachmentId {
+    fn from(id: &String) -> Self {
+        Self(id.clone())
+    }
+}
+impl From<&AttachmentId> for AttachmentId {
+    fn from(id: &AttachmentId) -> Self {
+        id.clone()
+    }
+}
+impl From<AttachmentId> for String {
+    fn from(id: AttachmentId) -> Self {
+        id.0
+    }
+}
+impl AsRef<str> for AttachmentId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+impl std::fmt::Display for AttachmentId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl super::types::Attachment {
+    /// This entity's ID as an [`AttachmentId`], if selected.
+    pub fn typed_id(&self) -> Option<AttachmentId> {
+        self.id.clone().map(AttachmentId)
+    }
+}
+/// Identifier of a [`AuthenticationSessionResponse`](super::types::AuthenticationSessionResponse).
+///
+/// Accepted by every operation that takes this entity's `id`.
+/// Plain strings convert with `From`, so the newtype is opt-in.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct AuthenticationSessionResponseId(String);
+impl AuthenticationSessionResponseId {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self(id.into())
+    }
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+impl From<String> for AuthenticationSessionResponseId {
+    fn from(id: String) -> Self {
+        Self(id)
+    }
+}
+impl From<&str> for AuthenticationSessionResponseId {
+    fn from(id: &str) -> Self {
+        Self(id.to_string())
+    }
+}
+impl From<&String> for AuthenticationSessionResponseId {
+    fn from(id: &String) -> Self {
+        Self(id.clone())
+    }
+}
+impl From<&AuthenticationSessionResponseId> for AuthenticationSessionResponseId {
+    fn from(id: &AuthenticationSessionResponseId) -> Self {
+        id.clone()
+    }
+}
+impl From<AuthenticationSessionResponseId> for String {
+    fn from(id: AuthenticationSessionResponseId) -> Self {
+        id.0
+    }
+}
+impl AsRef<str> for AuthenticationSessionResponseId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+impl std::fmt::Display for AuthenticationSessionResponseId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl super::types::AuthenticationSessionResponse {
+    /// This entity's ID as an [`AuthenticationSessionResponseId`], if selected.
+    pub fn typed_id(&self) -> Option<AuthenticationSessionResponseId> {
+        self.id.clone().map(AuthenticationSessionResponseId)
+    }
+}
+/// Identifier of a [`Comment`](super::types::Comment).
+///
+/// Accepted by every operation that takes this entity's `id`.
+/// Plain strings convert with `From`, so the newtype is opt-in.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct CommentId(String);
+impl CommentId {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self(id.into())
+    }
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+impl From<String> for CommentId {
+    fn from(id: String) -> Self {
+        Self(id)
+    }
+}
+impl From<&str> for CommentId {
+    fn from(id: &str) -> Self {
+        Self(id.to_string())
+    }
+}
+impl From<&String> for CommentId {
+    fn from(id: &String) -> Self {
+        Self(id.clone())
+    }
+}
+impl From<&CommentId> for CommentId {
+    fn from(id: &CommentId) -> Self {
+        id.clone()
+    }
+}
+impl From<CommentId> for String {
+    fn from(id: CommentId) -> Self {
+        id.0
+    }
+}
+impl AsRef<str> for CommentId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+impl std::fmt::Display for CommentId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl super::types::Comment {
+    /// This entity's ID as an [`CommentId`], if selected.
+    pub fn typed_id(&self) -> Option<CommentId> {
+        self.id.clone().map(CommentId)
+    }
+}
+/// Identifier of a [`CustomView`](super::types::CustomView).
+///
+/// Accepted by every operation that takes this entity's `id`.
+/// Plain strings convert with `From`, so the newtype is opt-in.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct CustomViewId(String);
+impl CustomViewId {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self(id.into())
+    }
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+impl From<String> for CustomViewId {
+    fn from(id: String) -> Self {
+        Self(id)
+    }
+}
+impl From<&str> for CustomViewId {
+    fn from(id: &str) -> Self {
+        Self(id.to_string())
+    }
+}
+impl From<&String> for CustomViewId {
+    fn from(id: &String) -> Self {
+        Self(id.clone())
+    }
+}
+impl From<&CustomViewId> for CustomViewId {
+    fn from(id: &CustomViewId) -> Self {
+        id.clone()
+    }
+}
+impl From<CustomViewId> for String {
+    fn from(id: CustomViewId) -> Self {
+        id.0
+    }
+}
+impl AsRef<str> for CustomViewId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+impl std::fmt::Display for CustomViewId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl super::types::CustomView {
+    /// This entity's ID as an [`CustomViewId`], if selected.
+    pub fn typed_id(&self) -> Option<CustomViewId> {
+        self.id.clone().map(CustomViewId)
+    }
+}
+/// Identifier of a [`Customer`](super::types::Customer).
+///
+/// Accepted by every operation that takes this entity's `id`.
+/// Plain strings convert with `From`, so the newtype is opt-in.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct CustomerId(String);
+impl CustomerId {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self(id.into())
+    }
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+impl From<String> for CustomerId {
+    fn from(id: String) -> Self {
+        Self(id)
+    }
+}
+impl From<&str> for CustomerId {
+    fn from(id: &str) -> Self {
+        Self(id.to_string())
+    }
+}
+impl From<&String> for CustomerId {
+    fn from(id: &String) -> Self {
+        Self(id.clone())
+    }
+}
+impl From<&CustomerId> for CustomerId {
+    fn from(id: &CustomerId) -> Self {
+        id.clone()
+    }
+}
+impl From<CustomerId> for String {
+    fn from(id: CustomerId) -> Self {
+        id.0
+    }
+}
+impl AsRef<str> for CustomerId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+impl std::fmt::Display for CustomerId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl super::types::Customer {
+    /// This entity's ID as an [`CustomerId`], if selected.
+    pub fn typed_id(&self) -> Option<CustomerId> {
+        self.id.clone().map(CustomerId)
+    }
+}
+/// Identifier of a [`CustomerNeed`](super::types::CustomerNeed).
+///
+/// Accepted by every operation that takes this entity's `id`.
+/// Plain strings convert with `From`, so the newtype is opt-in.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct CustomerNeedId(String);
+impl CustomerNeedId {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self(id.into())
+    }
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+impl From<String> for CustomerNeedId {
+    fn from(id: String) -> Self {
+        Self(id)
+    }
+}
+impl From<&str> for CustomerNeedId {
+    fn from(id: &str) -> Self {
+        Self(id.to_string())
+    }
+}
+impl From<&String> for CustomerNeedId {
+    fn from(id: &String) -> Self {
+        Self(id.clone())
+    }
+}
+impl From<&CustomerNeedId> for CustomerNeedId {
+    fn from(id: &CustomerNeedId) -> Self {
+        id.clone()
+    }
+}
+impl From<CustomerNeedId> for String {
+    fn from(id: CustomerNeedId) -> Self {
+        id.0
+    }
+}
+impl AsRef<str> for CustomerNeedId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+impl std::fmt::Display for CustomerNeedId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl super::types::CustomerNeed {
+    /// This entity's ID as an [`CustomerNeedId`], if selected.
+    pub fn typed_id(&self) -> Option<CustomerNeedId> {
+        self.id.clone().map(CustomerNeedId)
+    }
+}
+/// Identifier of a [`CustomerStatus`](super::types::CustomerStatus).
+///
+/// Accepted by every operation that takes this entity's `id`.
+/// Plain strings convert with `From`, so the newtype is opt-in.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct CustomerStatusId(String);
+impl CustomerStatusId {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self(id.into())
+    }
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+impl From<String> for CustomerStatusId {
+    fn from(id: String) -> Self {
+        Self(id)
+    }
+}
+impl From<&str> for CustomerStatusId {
+    fn from(id: &str) -> Self {
+        Self(id.to_string())
+    }
+}
+impl From<&String> for CustomerStatusId {
+    fn from(id: &String) -> Self {
+        Self(id.clone())
+    }
+}
+impl From<&CustomerStatusId> for CustomerStatusId {
+    fn from(id: &CustomerStatusId) -> Self {
+        id.clone()
+    }
+}
+impl From<CustomerStatusId> for String {
+    fn from(id: CustomerStatusId) -> Self {
+        id.0
+    }
+}
+impl AsRef<str> for CustomerStatusId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+impl std::fmt::Display for CustomerStatusId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl super::types::CustomerStatus {
+    /// This entity's ID as an [`CustomerStatusId`], if selected.
+    pub fn typed_id(&self) -> Option<CustomerStatusId> {
+        self.id.clone().map(CustomerStatusId)
+    }
+}
+/// Identifier of a [`CustomerTier`](super::types::CustomerTier).
+///
+/// Accepted by every operation that takes this entity's `id`.
+/// Plain strings convert with `From`, so the newtype is opt-in.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct CustomerTierId(String);
+impl CustomerTierId {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self(id.into())
+    }
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+impl From<String> for CustomerTierId {
+    fn from(id: String) -> Self {
+        Self(id)
+    }
+}
+impl From<&str> for CustomerTierId {
+    fn from(id: &str) -> Self {
+        Self(id.to_string())
+    }
+}
+impl From<&String> for CustomerTierId {
+    fn from(id: &String) -> Self {
+        Self(id.clone())
+    }
+}
+impl From<&CustomerTierId> for CustomerTierId {
+    fn from(id: &CustomerTierId) -> Self {
+        id.clone()
+    }
+}
+impl From<CustomerTierId> for String {
+    fn from(id: CustomerTierId) -> Self {
+        id.0
+    }
+}
+impl AsRef<str> for CustomerTierId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+impl std::fmt::Display for CustomerTierId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl super::types::CustomerTier {
+    /// This entity's ID as an [`CustomerTierId`], if selected.
+    pub fn typed_id(&self) -> Option<CustomerTierId> {
+        self.id.clone().map(CustomerTierId)
+    }
+}
+/// Identifier of a [`Cycle`](super::types::Cycle).
+///
+/// Accepted by every operation that takes this entity's `id`.
+/// Plain strings convert with `From`, so the newtype is opt-in.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct CycleId(String);
+impl CycleId {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self(id.into())
+    }
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+impl From<String> for CycleId {
+    fn from(id: String) -> Self {
+        Self(id)
+    }
+}
+impl From<&str> for CycleId {
+    fn from(id: &str) -> Self {
+        Self(id.to_string())
+    }
+}
+impl From<&String> for CycleId {
+    fn from(id: &String) -> Self {
+        Self(id.clone())
+    }
+}
+impl From<&CycleId> for CycleId {
+    fn from(id: &CycleId) -> Self {
+        id.clone()
+    }
+}
+impl From<CycleId> for String {
+    fn from(id: CycleId) -> Self {
+        id.0
+    }
+}
+impl AsRef<str> for CycleId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+impl std::fmt::Display for CycleId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl super::types::Cycle {
+    /// This entity's ID as an [`CycleId`], if selected.
+    pub fn typed_id(&self) -> Option<CycleId> {
+        self.id.clone().map(CycleId)
+    }
+}
+/// Identifier of a [`Document`](super::types::Document).
+///
+/// Accepted by every operation that takes this entity's `id`.
+/// Plain strings convert with `From`, so the newtype is opt-in.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct DocumentId(String);
+impl DocumentId {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self(id.into())
+    }
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+impl From<String> for DocumentId {
+    fn from(id: String) -> Self {
+        Self(id)
+    }
+}
+impl From<&str> for DocumentId {
+    fn from(id: &str) -> Self {
+        Self(id.to_string())
+    }
+}
+impl From<&String> for DocumentId {
+    fn from(id: &String) -> Self {
+        Self(id.clone())
+    }
+}
+impl From<&DocumentId> for DocumentId {
+    fn from(id: &DocumentId) -> Self {
+        id.clone()
+    }
+}
+impl From<DocumentId> for String {
+    fn from(id: DocumentId) -> Self {
+        id.0
+    }
+}
+impl AsRef<str> for DocumentId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+impl std::fmt::Display for DocumentId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl super::types::Document {
+    /// This entity's ID as an [`DocumentId`], if selected.
+    pub fn typed_id(&self) -> Option<DocumentId> {
+        self.id.clone().map(DocumentId)
+    }
+}
+/// Identifier of a [`DocumentContentHistoryType`](super::types::DocumentContentHistoryType).
+///
+/// Accepted by every operation that takes this entity's `id`.
+/// Plain strings convert with `From`, so the newtype is opt-in.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct DocumentContentHistoryTypeId(String);
+impl DocumentContentHistoryTypeId {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self(id.into())
+    }
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+impl From<String> for DocumentContentHistoryTypeId {
+    fn from(id: String) -> Self {
+        Self(id)
+    }
+}
+impl From<&str> for DocumentContentHistoryTypeId {
+    fn from(id: &str) -> Self {
+        Self(id.to_string())
+    }
+}
+impl From<&String> for DocumentContentHistoryTypeId {
+    fn from(id: &String) -> Self {
+        Self(id.clone())
+    }
+}
+impl From<&DocumentContentHistoryTypeId> for DocumentContentHistoryTypeId {
+    fn from(id: &DocumentContentHistoryTypeId) -> Self {
+        id.clone()
+    }
+}
+impl From<DocumentContentHistoryTypeId> for String {
+    fn from(id: DocumentContentHistoryTypeId) -> Self {
+        id.0
+    }
+}
+impl AsRef<str> for DocumentContentHistoryTypeId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+impl std::fmt::Display for DocumentContentHistoryTypeId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl super::types::DocumentContentHistoryType {
+    /// This entity's ID as an [`DocumentContentHistoryTypeId`], if selected.
+    pub fn typed_id(&self) -> Option<DocumentContentHistoryTypeId> {
+        self.id.clone().map(DocumentContentHistoryTypeId)
+    }
+}
+/// Identifier of a [`EmailIntakeAddress`](super::types::EmailIntakeAddress).
+///
+/// Accepted by every operation that takes this entity's `id`.
+/// Plain strings convert with `From`, so the newtype is opt-in.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct EmailIntakeAddressId(String);
+impl EmailIntakeAddressId {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self(id.into())
+    }
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+impl From<String> for EmailIntakeAddressId {
+    fn from(id: String) -> Self {
+        Self(id)
+    }
+}
+impl From<&str> for EmailIntakeAddressId {
+    fn from(id: &str) -> Self {
+        Self(id.to_string())
+    }
+}
+impl From<&String> for EmailIntakeAddressId {
+    fn from(id: &String) -> Self {
+        Self(id.clone())
+    }
+}
+impl From<&EmailIntakeAddressId> for EmailIntakeAddressId {
+    fn from(id: &EmailIntakeAddressId) -> Self {
+        id.clone()
+    }
+}
+impl From<EmailIntakeAddressId> for String {
+    fn from(id: EmailIntakeAddressId) -> Self {
+        id.0
+    }
+}
+impl AsRef<str> for EmailIntakeAddressId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+impl std::fmt::Display for EmailIntakeAddressId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl super::types::EmailIntakeAddress {
+    /// This entity's ID as an [`EmailIntakeAddressId`], if selected.
+    pub fn typed_id(&self) -> Option<EmailIntakeAddressId> {
+        self.id.clone().map(EmailIntakeAddressId)
+    }
+}
+/// Identifier of a [`Emoji`](super::types::Emoji).
+///
+/// Accepted by every operation that takes this entity's `id`.
+/// Plain strings convert with `From`, so the newtype is opt-in.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct EmojiId(String);
+impl EmojiId {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self(id.into())
+    }
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+impl From<String> for EmojiId {
+    fn from(id: String) -> Self {
+        Self(id)
+    }
+}
+impl From<&str> for EmojiId {
+    fn from(id: &str) -> Self {
+        Self(id.to_string())
+    }
+}
+impl From<&String> for EmojiId {
+    fn from(id: &String) -> Self {
+        Self(id.clone())
+    }
+}
+impl From<&EmojiId> for EmojiId {
+    fn from(id: &EmojiId) -> Self {
+        id.clone()
+    }
+}
+impl From<EmojiId> for String {
+    fn from(id: EmojiId) -> Self {
+        id.0
+    }
+}
+impl AsRef<str> for EmojiId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+impl std::fmt::Display for EmojiId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl super::types::Emoji {
+    /// This entity's ID as an [`EmojiId`], if selected.
+    pub fn typed_id(&self) -> Option<EmojiId> {
+        self.id.clone().map(EmojiId)
+    }
+}
+/// Identifier of a [`EntityExternalLink`](super::types::EntityExternalLink).
+///
+/// Accepted by every operation that takes this entity's `id`.
+/// Plain strings convert with `From`, so the newtype is opt-in.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct EntityExternalLinkId(String);
+impl EntityExternalLinkId {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self(id.into())
+    }
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+impl From<String> for EntityExternalLinkId {
+    fn from(id: String) -> Self {
+        Self(id)
+    }
+}
+impl From<&str> for EntityExternalLinkId {
+    fn from(id: &str) -> Self {
+        Self(id.to_string())
+    }
+}
+impl From<&String> for EntityExternalLinkId {
+    fn from(id: &String) -> Self {
+        Self(id.clone())
+    }
+}
+impl From<&EntityExternalLinkId> for EntityExternalLinkId {
+    fn from(id: &EntityExternalLinkId) -> Self {
+        id.clone()
+    }
+}
+impl From<EntityExternalLinkId> for String {
+    fn from(id: EntityExternalLinkId) -> Self {
+        id.0
+    }
+}
+impl AsRef<str> for EntityExternalLinkId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+impl std::fmt::Display for EntityExternalLinkId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl super::types::EntityExternalLink {
+    /// This entity's ID as an [`EntityExternalLinkId`], if selected.
+    pub fn typed_id(&self) -> Option<EntityExternalLinkId> {
+        self.id.clone().map(EntityExternalLinkId)
+    }
+}
+/// Identifier of a [`ExternalUser`](super::types::ExternalUser).
+///
+/// Accepted by every operation that takes this entity's `id`.
+/// Plain strings convert with `From`, so the newtype is opt-in.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ExternalUserId(String);
+impl ExternalUserId {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self(id.into())
+    }
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+impl From<String> for ExternalUserId {
+    fn from(id: String) -> Self {
+        Self(id)
+    }
+}
+impl From<&str> for ExternalUserId {
+    fn from(id: &str) -> Self {
+        Self(id.to_string())
+    }
+}
+impl From<&String> for ExternalUserId {
+    fn from(id: &String) -> Self {
+        Self(id.clone())
+    }
+}
+impl From<&ExternalUserId> for ExternalUserId {
+    fn from(id: &ExternalUserId) -> Self {
+        id.clone()
+    }
+}
+impl From<ExternalUserId> for String {
+    fn from(id: ExternalUserId) -> Self {
+        id.0
+    }
+}
+impl AsRef<str> for ExternalUserId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+impl std::fmt::Display for ExternalUserId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl super::types::ExternalUser {
+    /// This entity's ID as an [`ExternalUserId`], if selected.
+    pub fn typed_id(&self) -> Option<ExternalUserId> {
+        self.id.clone().map(ExternalUserId)
+    }
+}
+/// Identifier of a [`Favorite`](super::types::Favorite).
+///
+/// Accepted by every operation that takes this entity's `id`.
+/// Plain strings convert with `From`, so the newtype is opt-in.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct FavoriteId(String);
+impl FavoriteId {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self(id.into())
+    }
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+impl From<String> for FavoriteId {
+    fn from(id: String) -> Self {
+        Self(id)
+    }
+}
+impl From<&str> for FavoriteId {
+    fn from(id: &str) -> Self {
+        Self(id.to_string())
+    }
+}
+impl From<&String> for FavoriteId {
+    fn from(id: &String) -> Self {
+        Self(id.clone())
+    }
+}
+impl From<&FavoriteId> for FavoriteId {
+    fn from(id: &FavoriteId) -> Self {
+        id.clone()
+    }
+}
+impl From<FavoriteId> for String {
+    fn from(id: FavoriteId) -> Self {
+        id.0
+    }
+}
+impl AsRef<str> for FavoriteId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+impl std::fmt::Display for FavoriteId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl super::types::Favorite {
+    /// This entity's ID as an [`FavoriteId`], if selected.
+    pub fn typed_id(&self) -> Option<FavoriteId> {
+        self.id.clone().map(FavoriteId)
+    }
+}
+/// Identifier of a [`GitAutomationState`](super::types::GitAutomationState).
+///
+/// Accepted by every operation that takes this entity's `id`.
+/// Plain strings convert with `From`, so the newtype is opt-in.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct GitAutomationStateId(String);
+impl GitAutomationStateId {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self(id.into())
+    }
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+impl From<String> for GitAutomationStateId {
+    fn from(id: String) -> Self {
+        Self(id)
+    }
+}
+impl From<&str> for GitAutomationStateId {
+    fn from(id: &str) -> Self {
+        Self(id.to_string())
+    }
+}
+impl From<&String> for GitAutomationStateId {
+    fn from(id: &String) -> Self {
+        Self(id.clone())
+    }
+}
+impl From<&GitAutomationStateId> for GitAutomationStateId {
+    fn from(id: &GitAutomationStateId) -> Self {
+        id.clone()
+    }
+}
+impl From<GitAutomationStateId> for String {
+    fn from(id: GitAutomationStateId) -> Self {
+        id.0
+    }
+}
+impl AsRef<str> for GitAutomationStateId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+impl std::fmt::Display for GitAutomationStateId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl super::types::GitAutomationState {
+    /// This entity's ID as an [`GitAutomationStateId`], if selected.
+    pub fn typed_id(&self) -> Option<GitAutomationStateId> {
+        self.id.clone().map(GitAutomationStateId)
+    }
+}
+/// Identifier of a [`GitAutomationTargetBranch`](super::types::GitAutomationTargetBranch).
+///
+/// Accepted by every operation that takes this entity's `id`.
+/// Plain strings convert with `From`, so the newtype is opt-in.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct GitAutomationTargetBranchId(String);
+impl GitAutomationTargetBranchId {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self(id.into())
+    }
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+impl From<String> for GitAutomationTargetBranchId {
+    fn from(id: String) -> Self {
+        Self(id)
+    }
+}
+impl From<&str> for GitAutomationTargetBranchId {
+    fn from(id: &str) -> Self {
+        Self(id.to_string())
+    }
+}
+impl From<&String> for GitAutomationTargetBranchId {
+    fn from(id: &String) -> Self {
+        Self(id.clone())
+    }
+}
+impl From<&GitAutomationTargetBranchId> for GitAutomationTargetBranchId {
+    fn from(id: &GitAutomationTargetBranchId) -> Self {
+        id.clone()
+    }
+}
+impl From<GitAutomationTargetBranchId> for String {
+    fn from(id: GitAutomationTargetBranchId) -> Self {
+        id.0
+    }
+}
+impl AsRef<str> for GitAutomationTargetBranchId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+impl std::fmt::Display for GitAutomationTargetBranchId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl super::types::GitAutomationTargetBranch {
+    /// This entity's ID as an [`GitAutomationTargetBranchId`], if selected.
+    pub fn typed_id(&self) -> Option<GitAutomationTargetBranchId> {
+        self.id.clone().map(GitAutomationTargetBranchId)
+    }
+}
+/// Identifier of a [`Initiative`](super::types::Initiative).
+///
+/// Accepted by every operation that takes this entity's `id`.
+/// Plain strings convert with `From`, so the newtype is opt-in.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct InitiativeId(String);
+impl InitiativeId {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self(id.into())
+    }
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+impl From<String> for InitiativeId {
+    fn from(id: String) -> Self {
+        Self(id)
+    }
+}
+impl From<&str> for InitiativeId {
+    fn from(id: &str) -> Self {
+        Self(id.to_string())
+    }
+}
+impl From<&String> for InitiativeId {
+    fn from(id: &String) -> Self {
+        Self(id.clone())
+    }
+}
+impl From<&InitiativeId> for InitiativeId {
+    fn from(id: &InitiativeId) -> Self {
+        id.clone()
+    }
+}
+impl From<InitiativeId> for String {
+    fn from(id: InitiativeId) -> Self {
+        id.0
+    }
+}
+impl AsRef<str> for InitiativeId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+impl std::fmt::Display for InitiativeId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl super::types::Initiative {
+    /// This entity's ID as an [`InitiativeId`], if selected.
+    pub fn typed_id(&self) -> Option<InitiativeId> {
+        self.id.clone().map(InitiativeId)
+    }
+}
+/// Identifier of a [`InitiativeRelation`](super::types::InitiativeRelation).
+///
+/// Accepted by every operation that takes this entity's `id`.
+/// Plain strings convert with `From`, so the newtype is opt-in.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct InitiativeRelationId(String);
+impl InitiativeRelationId {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self(id.into())
+    }
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+impl From<String> for InitiativeRelationId {
+    fn from(id: String) -> Self {
+        Self(id)
+    }
+}
+impl From<&str> for InitiativeRelationId {
+    fn from(id: &str) -> Self {
+        Self(id.to_string())
+    }
+}
+impl From<&String> for InitiativeRelationId {
+    fn from(id: &String) -> Self {
+        Self(id.clone())
+    }
+}
+impl From<&InitiativeRelationId> for InitiativeRelationId {
+    fn from(id: &InitiativeRelationId) -> Self {
+        id.clone()
+    }
+}
+impl From<InitiativeRelationId> for String {
+    fn from(id: InitiativeRelationId) -> Self {
+        id.0
+    }
+}
+impl AsRef<str> for InitiativeRelationId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+impl std::fmt::Display for InitiativeRelationId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl super::types::InitiativeRelation {
+    /// This entity's ID as an [`InitiativeRelationId`], if selected.
+    pub fn typed_id(&self) -> Option<InitiativeRelationId> {
+        self.id.clone().map(InitiativeRelationId)
+    }
+}
+/// Identifier of a [`InitiativeToProject`](super::types::InitiativeToProject).
+///
+/// Accepted by every operation that takes this entity's `id`.
+/// Plain strings convert with `From`, so the newtype is opt-in.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct InitiativeToProjectId(String);
+impl InitiativeToProjectId {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self(id.into())
+    }
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+impl From<String> for InitiativeToProjectId {
+    fn from(id: String) -> Self {
+        Self(id)
+    }
+}
+impl From<&str> for InitiativeToProjectId {
+    fn from(id: &str) -> Self {
+        Self(id.to_string())
+    }
+}
+impl From<&String> for InitiativeToProjectId {
+    fn from(id: &String) -> Self {
+        Self(id.clone())
+    }
+}
+impl From<&InitiativeToProjectId> for InitiativeToProjectId {
+    fn from(id: &InitiativeToProjectId) -> Self {
+        id.clone()
+    }
+}
+impl From<InitiativeToProjectId> for String {
+    fn from(id: InitiativeToProjectId) -> Self {
+        id.0
+    }
+}
+impl AsRef<str> for InitiativeToProjectId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+impl std::fmt::Display for InitiativeToProjectId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl super::types::InitiativeToProject {
+    /// This entity's ID as an [`InitiativeToProjectId`], if selected.
+    pub fn typed_id(&self) -> Option<InitiativeToProjectId> {
+        self.id.clone().map(InitiativeToProjectId)
+    }
+}
+/// Identifier of a [`InitiativeUpdate`](super::types::InitiativeUpdate).
+///
+/// Accepted by every operation that takes this entity's `id`.
+/// Plain strings convert with `From`, so the newtype is opt-in.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct InitiativeUpdateId(String);
+impl InitiativeUpdateId {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self(id.into())
+    }
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+impl From<String> for InitiativeUpdateId {
+    fn from(id: String) -> Self {
+        Self(id)
+    }
+}
+impl From<&str> for InitiativeUpdateId {
+    fn from(id: &str) -> Self {
+        Self(id.to_string())
+    }
+}
+impl From<&String> for InitiativeUpdateId {
+    fn from(id: &String) -> Self {
+        Self(id.clone())
+    }
+}
+impl From<&InitiativeUpdateId> for InitiativeUpdateId {
+    fn from(id: &InitiativeUpdateId) -> Self {
+        id.clone()
+    }
+}
+impl From<InitiativeUpdateId> for String {
+    fn from(id: InitiativeUpdateId) -> Self {
+        id.0
+    }
+}
+impl AsRef<str> for InitiativeUpdateId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+impl std::fmt::Display for InitiativeUpdateId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl super::types::InitiativeUpdate {
+    /// This entity's ID as an [`InitiativeUpdateId`], if selected.
+    pub fn typed_id(&self) -> Option<InitiativeUpdateId> {
+        self.id.clone().map(InitiativeUpdateId)
+    }
+}
+/// Identifier of a [`Integration`](super::types::Integration).
+///
+/// Accepted by every operation that takes this entity's `id`.
+/// Plain strings convert with `From`, so the newtype is opt-in.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct IntegrationId(String);
+impl IntegrationId {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self(id.into())
+    }
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+impl From<String> for IntegrationId {
+    fn from(id: String) -> Self {
+        Self(id)
+    }
+}
+impl From<&str> for IntegrationId {
+    fn from(id: &str) -> Self {
+        Self(id.to_string())
+    }
+}
+impl From<&String> for IntegrationId {
+    fn from(id: &String) -> Self {
+        Self(id.clone())
+    }
+}
+impl From<&IntegrationId> for IntegrationId {
+    fn from(id: &IntegrationId) -> Self {
+        id.clone()
+    }
+}
+impl From<IntegrationId> for String {
+    fn from(id: IntegrationId) -> Self {
+        id.0
+    }
+}
+impl AsRef<str> for IntegrationId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+impl std::fmt::Display for IntegrationId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl super::types::Integration {
+    /// This entity's ID as an [`IntegrationId`], if selected.
+    pub fn typed_id(&self) -> Option<IntegrationId> {
+        self.id.clone().map(IntegrationId)
+    }
+}
+/// Identifier of a [`IntegrationTemplate`](super::types::IntegrationTemplate).
+///
+/// Accepted by every operation that takes this entity's `id`.
+/// Plain strings convert with `From`, so the newtype is opt-in.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct IntegrationTemplateId(String);
+impl IntegrationTemplateId {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self(id.into())
+    }
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+impl From<String> for IntegrationTemplateId {
+    fn from(id: String) -> Self {
+        Self(id)
+    }
+}
+impl From<&str> for IntegrationTemplateId {
+    fn from(id: &str) -> Self {
+        Self(id.to_string())
+    }
+}
+impl From<&String> for IntegrationTemplateId {
+    fn from(id: &String) -> Self {
+        Self(id.clone())
+    }
+}
+impl From<&IntegrationTemplateId> for IntegrationTemplateId {
+    fn from(id: &IntegrationTemplateId) -> Self {
+        id.clone()
+    }
+}
+impl From<IntegrationTemplateId> for String {
+    fn from(id: IntegrationTemplateId) -> Self {
+        id.0
+    }
+}
+impl AsRef<str> for IntegrationTemplateId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+impl std::fmt::Display for IntegrationTemplateId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl super::types::IntegrationTemplate {
+    /// This entity's ID as an [`IntegrationTemplateId`], if selected.
+    pub fn typed_id(&self) -> Option<IntegrationTemplateId> {
+        self.id.clone().map(IntegrationTemplateId)
+    }
+}
+/// Identifier of a [`IntegrationsSettings`](super::types::IntegrationsSettings).
+///
+/// Accepted by every operation that takes this entity's `id`.
+/// Plain strings convert with `From`, so the newtype is opt-in.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct IntegrationsSettingsId(String);
+impl IntegrationsSettingsId {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self(id.into())
+    }
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+impl From<String> for IntegrationsSettingsId {
+    fn from(id: String) -> Self {
+        Self(id)
+    }
+}
+impl From<&str> for IntegrationsSettingsId {
+    fn from(id: &str) -> Self {
+        Self(id.to_string())
+    }
+}
+impl From<&String> for IntegrationsSettingsId {
+    fn from(id: &String) -> Self {
+        Self(id.clone())
+    }
+}
+impl From<&IntegrationsSettingsId> for IntegrationsSettingsId {
+    fn from(id: &IntegrationsSettingsId) -> Self {
+        id.clone()
+    }
+}
+impl From<IntegrationsSettingsId> for String {
+    fn from(id: IntegrationsSettingsId) -> Self {
+        id.0
+    }
+}
+impl AsRef<str> for IntegrationsSettingsId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+impl std::fmt::Display for IntegrationsSettingsId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl super::types::IntegrationsSettings {
+    /// This entity's ID as an [`IntegrationsSettingsId`], if selected.
+    pub fn typed_id(&self) -> Option<IntegrationsSettingsId> {
+        self.id.clone().map(IntegrationsSettingsId)
+    }
+}
+/// Identifier of a [`Issue`](super::types::Issue).
+///
+/// Accepted by every operation that takes this entity's `id`.
+/// Plain strings convert with `From`, so the newtype is opt-in.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct IssueId(String);
+impl IssueId {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self(id.into())
+    }
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+impl From<String> for IssueId {
+    fn from(id: String) -> Self {
+        Self(id)
+    }
+}
+impl From<&str> for IssueId {
+    fn from(id: &str) -> Self {
+        Self(id.to_string())
+    }
+}
+impl From<&String> for IssueId {
+    fn from(id: &String) -> Self {
+        Self(id.clone())
+    }
+}
+impl From<&IssueId> for IssueId {
+    fn from(id: &IssueId) -> Self {
+        id.clone()
+    }
+}
+impl From<IssueId> for String {
+    fn from(id: IssueId) -> Self {
+        id.0
+    }
+}
+impl AsRef<str> for IssueId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+impl std::fmt::Display for IssueId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl super::types::Issue {
+    /// This entity's ID as an [`IssueId`], if selected.
+    pub fn typed_id(&self) -> Option<IssueId> {
+        self.id.clone().map(IssueId)
+    }
+}
+/// Identifier of a [`IssueImport`](super::types::IssueImport).
+///
+/// Accepted by every operation that takes this entity's `id`.
+/// Plain strings convert with `From`, so the newtype is opt-in.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct IssueImportId(String);
+impl IssueImportId {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self(id.into())
+    }
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+impl From<String> for IssueImportId {
+    fn from(id: String) -> Self {
+        Self(id)
+    }
+}
+impl From<&str> for IssueImportId {
+    fn from(id: &str) -> Self {
+        Self(id.to_string())
+    }
+}
+impl From<&String> for IssueImportId {
+    fn from(id: &String) -> Self {
+        Self(id.clone())
+    }
+}
+impl From<&IssueImportId> for IssueImportId {
+    fn from(id: &IssueImportId) -> Self {
+        id.clone()
+    }
+}
+impl From<IssueImportId> for String {
+    fn from(id: IssueImportId) -> Self {
+        id.0
+    }
+}
+impl AsRef<str> for IssueImportId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+impl std::fmt::Display for IssueImportId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl super::types::IssueImport {
+    /// This entity's ID as an [`IssueImportId`], if selected.
+    pub fn typed_id(&self) -> Option<IssueImportId> {
+        self.id.clone().map(IssueImportId)
+    }
+}
+/// Identifier of a [`IssueLabel`](super::types::IssueLabel).
+///
+/// Accepted by every operation that takes this entity's `id`.
+/// Plain strings convert with `From`, so the newtype is opt-in.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct IssueLabelId(String);
+impl IssueLabelId {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self(id.into())
+    }
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+impl From<String> for IssueLabelId {
+    fn from(id: String) -> Self {
+        Self(id)
+    }
+}
+impl From<&str> for IssueLabelId {
+    fn from(id: &str) -> Self {
+        Self(id.to_string())
+    }
+}
+impl From<&String> for IssueLabelId {
+    fn from(id: &String) -> Self {
+        Self(id.clone())
+    }
+}
+impl From<&IssueLabelId> for IssueLabelId {
+    fn from(id: &IssueLabelId) -> Self {
+        id.clone()
+    }
+}
+impl From<IssueLabelId> for String {
+    fn from(id: IssueLabelId) -> Self {
+        id.0
+    }
+}
+impl AsRef<str> for IssueLabelId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+impl std::fmt::Display for IssueLabelId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl super::types::IssueLabel {
+    /// This entity's ID as an [`IssueLabelId`], if selected.
+    pub fn typed_id(&self) -> Option<IssueLabelId> {
+        self.id.clone().map(IssueLabelId)
+    }
+}
+/// Identifier of a [`IssueRelation`](super::types::IssueRelation).
+///
+/// Accepted by every operation that takes this entity's `id`.
+/// Plain strings convert with `From`, so the newtype is opt-in.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct IssueRelationId(String);
+impl IssueRelationId {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self(id.into())
+    }
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+impl From<String> for IssueRelationId {
+    fn from(id: String) -> Self {
+        Self(id)
+    }
+}
+impl From<&str> for IssueRelationId {
+    fn from(id: &str) -> Self {
+        Self(id.to_string())
+    }
+}
+impl From<&String> for IssueRelationId {
+    fn from(id: &String) -> Self {
+        Self(id.clone())
+    }
+}
+impl From<&IssueRelationId> for IssueRelationId {
+    fn from(id: &IssueRelationId) -> Self {
+        id.clone()
+    }
+}
+impl From<IssueRelationId> for String {
+    fn from(id: IssueRelationId) -> Self {
+        id.0
+    }
+}
+impl AsRef<str> for IssueRelationId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+impl std::fmt::Display for IssueRelationId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl super::types::IssueRelation {
+    /// This entity's ID as an [`IssueRelationId`], if selected.
+    pub fn typed_id(&self) -> Option<IssueRelationId> {
+        self.id.clone().map(IssueRelationId)
+    }
+}
+/// Identifier of a [`IssueToRelease`](super::types::IssueToRelease).
+///
+/// Accepted by every operation that takes this entity's `id`.
+/// Plain strings convert with `From`, so the newtype is opt-in.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct IssueToReleaseId(String);
+impl IssueToReleaseId {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self(id.into())
+    }
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+impl From<String> for IssueToReleaseId {
+    fn from(id: String) -> Self {
+        Self(id)
+    }
+}
+impl From<&str> for IssueToReleaseId {
+    fn from(id: &str) -> Self {
+        Self(id.to_string())
+    }
+}
+impl From<&String> for IssueToReleaseId {
+    fn from(id: &String) -> Self {
+        Self(id.clone())
+    }
+}
+impl From<&IssueToReleaseId> for IssueToReleaseId {
+    fn from(id: &IssueToReleaseId) -> Self {
+        id.clone()
+    }
+}
+impl From<IssueToReleaseId> for String {
+    fn from(id: IssueToReleaseId) -> Self {
+        id.0
+    }
+}
+impl AsRef<str> for IssueToReleaseId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+impl std::fmt::Display for IssueToReleaseId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl super::types::IssueToRelease {
+    /// This entity's ID as an [`IssueToReleaseId`], if selected.
+    pub fn typed_id(&self) -> Option<IssueToReleaseId> {
+        self.id.clone().map(IssueToReleaseId)
+    }
+}
+/// Identifier of a [`OrganizationDomain`](super::types::OrganizationDomain).
+///
+/// Accepted by every operation that takes this entity's `id`.
+/// Plain strings convert with `From`, so the newtype is opt-in.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct OrganizationDomainId(String);
+impl OrganizationDomainId {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self(id.into())
+    }
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+impl From<String> for OrganizationDomainId {
+    fn from(id: String) -> Self {
+        Self(id)
+    }
+}
+impl From<&str> for OrganizationDomainId {
+    fn from(id: &str) -> Self {
+        Self(id.to_string())
+    }
+}
+impl From<&String> for OrganizationDomainId {
+    fn from(id: &String) -> Self {
+        Self(id.clone())
+    }
+}
+impl From<&OrganizationDomainId> for OrganizationDomainId {
+    fn from(id: &OrganizationDomainId) -> Self {
+        id.clone()
+    }
+}
+impl From<OrganizationDomainId> for String {
+    fn from(id: OrganizationDomainId) -> Self {
+        id.0
+    }
+}
+impl AsRef<str> for OrganizationDomainId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+impl std::fmt::Display for OrganizationDomainId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl super::types::OrganizationDomain {
+    /// This entity's ID as an [`OrganizationDomainId`], if selected.
+    pub fn typed_id(&self) -> Option<OrganizationDomainId> {
+        self.id.clone().map(OrganizationDomainId)
+    }
+}
+/// Identifier of a [`OrganizationInvite`](super::types::OrganizationInvite).
+///
+/// Accepted by every operation that takes this entity's `id`.
+/// Plain strings convert with `From`, so the newtype is opt-in.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct OrganizationInviteId(String);
+impl OrganizationInviteId {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self(id.into())
+    }
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+impl From<String> for OrganizationInviteId {
+    fn from(id: String) -> Self {
+        Self(id)
+    }
+}
+impl From<&str> for OrganizationInviteId {
+    fn from(id: &str) -> Self {
+        Self(id.to_string())
+    }
+}
+impl From<&String> for OrganizationInviteId {
+    fn from(id: &String) -> Self {
+        Self(id.clone())
+    }
+}
+impl From<&OrganizationInviteId> for OrganizationInviteId {
+    fn from(id: &OrganizationInviteId) -> Self {
+        id.clone()
+    }
+}
+impl From<OrganizationInviteId> for String {
+    fn from(id: OrganizationInviteId) -> Self {
+        id.0
+    }
+}
+impl AsRef<str> for OrganizationInviteId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+impl std::fmt::Display for OrganizationInviteId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl super::types::OrganizationInvite {
+    /// This entity's ID as an [`OrganizationInviteId`], if selected.
+    pub fn typed_id(&self) -> Option<OrganizationInviteId> {
+        self.id.clone().map(OrganizationInviteId)
+    }
+}
+/// Identifier of a [`Project`](super::types::Project).
+///
+/// Accepted by every operation that takes this entity's `id`.
+/// Plain strings convert with `From`, so the newtype is opt-in.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ProjectId(String);
+impl ProjectId {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self(id.into())
+    }
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+impl From<String> for ProjectId {
+    fn from(id: String) -> Self {
+        Self(id)
+    }
+}
+impl From<&str> for ProjectId {
+    fn from(id: &str) -> Self {
+        Self(id.to_string())
+    }
+}
+impl From<&String> for ProjectId {
+    fn from(id: &String) -> Self {
+        Self(id.clone())
+    }
+}
+impl From<&ProjectId> for ProjectId {
+    fn from(id: &ProjectId) -> Self {
+        id.clone()
+    }
+}
+impl From<ProjectId> for String {
+    fn from(id: ProjectId) -> Self {
+        id.0
+    }
+}
+impl AsRef<str> for ProjectId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+impl std::fmt::Display for ProjectId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl super::types::Project {
+    /// This entity's ID as an [`ProjectId`], if selected.
+    pub fn typed_id(&self) -> Option<ProjectId> {
+        self.id.clone().map(ProjectId)
+    }
+}
+/// Identifier of a [`ProjectLabel`](super::types::ProjectLabel).
+///
+/// Accepted by every operation that takes this entity's `id`.
+/// Plain strings convert with `From`, so the newtype is opt-in.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ProjectLabelId(String);
+impl ProjectLabelId {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self(id.into())
+    }
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+impl From<String> for ProjectLabelId {
+    fn from(id: String) -> Self {
+        Self(id)
+    }
+}
+impl From<&str> for ProjectLabelId {
+    fn from(id: &str) -> Self {
+        Self(id.to_string())
+    }
+}
+impl From<&String> for ProjectLabelId {
+    fn from(id: &String) -> Self {
+        Self(id.clone())
+    }
+}
+impl From<&ProjectLabelId> for ProjectLabelId {
+    fn from(id: &ProjectLabelId) -> Self {
+        id.clone()
+    }
+}
+impl From<ProjectLabelId> for String {
+    fn from(id: ProjectLabelId) -> Self {
+        id.0
+    }
+}
+impl AsRef<str> for ProjectLabelId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+impl std::fmt::Display for ProjectLabelId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl super::types::ProjectLabel {
+    /// This entity's ID as an [`ProjectLabelId`], if selected.
+    pub fn typed_id(&self) -> Option<ProjectLabelId> {
+        self.id.clone().map(ProjectLabelId)
+    }
+}
+/// Identifier of a [`ProjectMilestone`](super::types::ProjectMilestone).
+///
+/// Accepted by every operation that takes this entity's `id`.
+/// Plain strings convert with `From`, so the newtype is opt-in.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ProjectMilestoneId(String);
+impl ProjectMilestoneId {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self(id.into())
+    }
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+impl From<String> for ProjectMilestoneId {
+    fn from(id: String) -> Self {
+        Self(id)
+    }
+}
+impl From<&str> for ProjectMilestoneId {
+    fn from(id: &str) -> Self {
+        Self(id.to_string())
+    }
+}
+impl From<&String> for ProjectMilestoneId {
+    fn from(id: &String) -> Self {
+        Self(id.clone())
+    }
+}
+impl From<&ProjectMilestoneId> for ProjectMilestoneId {
+    fn from(id: &ProjectMilestoneId) -> Self {
+        id.clone()
+    }
+}
+impl From<ProjectMilestoneId> for String {
+    fn from(id: ProjectMilestoneId) -> Self {
+        id.0
+    }
+}
+impl AsRef<str> for ProjectMilestoneId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+impl std::fmt::Display for ProjectMilestoneId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl super::types::ProjectMilestone {
+    /// This entity's ID as an [`ProjectMilestoneId`], if selected.
+    pub fn typed_id(&self) -> Option<ProjectMilestoneId> {
+        self.id.clone().map(ProjectMilestoneId)
+    }
+}
+/// Identifier of a [`ProjectRelation`](super::types::ProjectRelation).
+///
+/// Accepted by every operation that takes this entity's `id`.
+/// Plain strings convert with `From`, so the newtype is opt-in.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ProjectRelationId(String);
+impl ProjectRelationId {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self(id.into())
+    }
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+impl From<String> for ProjectRelationId {
+    fn from(id: String) -> Self {
+        Self(id)
+    }
+}
+impl From<&str> for ProjectRelationId {
+    fn from(id: &str) -> Self {
+        Self(id.to_string())
+    }
+}
+impl From<&String> for ProjectRelationId {
+    fn from(id: &String) -> Self {
+        Self(id.clone())
+    }
+}
+impl From<&ProjectRelationId> for ProjectRelationId {
+    fn from(id: &ProjectRelationId) -> Self {
+        id.clone()
+    }
+}
+impl From<ProjectRelationId> for String {
+    fn from(id: ProjectRelationId) -> Self {
+        id.0
+    }
+}
+impl AsRef<str> for ProjectRelationId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+impl std::fmt::Display for ProjectRelationId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl super::types::ProjectRelation {
+    /// This entity's ID as an [`ProjectRelationId`], if selected.
+    pub fn typed_id(&self) -> Option<ProjectRelationId> {
+        self.id.clone().map(ProjectRelationId)
+    }
+}
+/// Identifier of a [`ProjectStatus`](super::types::ProjectStatus).
+///
+/// Accepted by every operation that takes this entity's `id`.
+/// Plain strings convert with `From`, so the newtype is opt-in.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ProjectStatusId(String);
+impl ProjectStatusId {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self(id.into())
+    }
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+impl From<String> for ProjectStatusId {
+    fn from(id: String) -> Self {
+        Self(id)
+    }
+}
+impl From<&str> for ProjectStatusId {
+    fn from(id: &str) -> Self {
+        Self(id.to_string())
+    }
+}
+impl From<&String> for ProjectStatusId {
+    fn from(id: &String) -> Self {
+        Self(id.clone())
+    }
+}
+impl From<&ProjectStatusId> for ProjectStatusId {
+    fn from(id: &ProjectStatusId) -> Self {
+        id.clone()
+    }
+}
+impl From<ProjectStatusId> for String {
+    fn from(id: ProjectStatusId) -> Self {
+        id.0
+    }
+}
+impl AsRef<str> for ProjectStatusId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+impl std::fmt::Display for ProjectStatusId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl super::types::ProjectStatus {
+    /// This entity's ID as an [`ProjectStatusId`], if selected.
+    pub fn typed_id(&self) -> Option<ProjectStatusId> {
+        self.id.clone().map(ProjectStatusId)
+    }
+}
+/// Identifier of a [`ProjectUpdate`](super::types::ProjectUpdate).
+///
+/// Accepted by every operation that takes this entity's `id`.
+/// Plain strings convert with `From`, so the newtype is opt-in.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ProjectUpdateId(String);
+impl ProjectUpdateId {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self(id.into())
+    }
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+impl From<String> for ProjectUpdateId {
+    fn from(id: String) -> Self {
+        Self(id)
+    }
+}
+impl From<&str> for ProjectUpdateId {
+    fn from(id: &str) -> Self {
+        Self(id.to_string())
+    }
+}
+impl From<&String> for ProjectUpdateId {
+    fn from(id: &String) -> Self {
+        Self(id.clone())
+    }
+}
+impl From<&ProjectUpdateId> for ProjectUpdateId {
+    fn from(id: &ProjectUpdateId) -> Self {
+        id.clone()
+    }
+}
+impl From<ProjectUpdateId> for String {
+    fn from(id: ProjectUpdateId) -> Self {
+        id.0
+    }
+}
+impl AsRef<str> for ProjectUpdateId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+impl std::fmt::Display for ProjectUpdateId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl super::types::ProjectUpdate {
+    /// This entity's ID as an [`ProjectUpdateId`], if selected.
+    pub fn typed_id(&self) -> Option<ProjectUpdateId> {
+        self.id.clone().map(ProjectUpdateId)
+    }
+}
+/// Identifier of a [`PushSubscription`](super::types::PushSubscription).
+///
+/// Accepted by every operation that takes this entity's `id`.
+/// Plain strings convert with `From`, so the newtype is opt-in.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct PushSubscriptionId(String);
+impl PushSubscriptionId {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self(id.into())
+    }
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+impl From<String> for PushSubscriptionId {
+    fn from(id: String) -> Self {
+        Self(id)
+    }
+}
+impl From<&str> for PushSubscriptionId {
+    fn from(id: &str) -> Self {
+        Self(id.to_string())
+    }
+}
+impl From<&String> for PushSubscriptionId {
+    fn from(id: &String) -> Self {
+        Self(id.clone())
+    }
+}
+impl From<&PushSubscriptionId> for PushSubscriptionId {
+    fn from(id: &PushSubscriptionId) -> Self {
+        id.clone()
+    }
+}
+impl From<PushSubscriptionId> for String {
+    fn from(id: PushSubscriptionId) -> Self {
+        id.0
+    }
+}
+impl AsRef<str> for PushSubscriptionId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+impl std::fmt::Display for PushSubscriptionId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl super::types::PushSubscription {
+    /// This entity's ID as an [`PushSubscriptionId`], if selected.
+    pub fn typed_id(&self) -> Option<PushSubscriptionId> {
+        self.id.clone().map(PushSubscriptionId)
+    }
+}
+/// Identifier of a [`Reaction`](super::types::Reaction).
+///
+/// Accepted by every operation that takes this entity's `id`.
+/// Plain strings convert with `From`, so the newtype is opt-in.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ReactionId(String);
+impl ReactionId {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self(id.into())
+    }
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+impl From<String> for ReactionId {
+    fn from(id: String) -> Self {
+        Self(id)
+    }
+}
+impl From<&str> for ReactionId {
+    fn from(id: &str) -> Self {
+        Self(id.to_string())
+    }
+}
+impl From<&String> for ReactionId {
+    fn from(id: &String) -> Self {
+        Self(id.clone())
+    }
+}
+impl From<&ReactionId> for ReactionId {
+    fn from(id: &ReactionId) -> Self {
+        id.clone()
+    }
+}
+impl From<ReactionId> for String {
+    fn from(id: ReactionId) -> Self {
+        id.0
+    }
+}
+impl AsRef<str> for ReactionId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+impl std::fmt::Display for ReactionId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl super::types::Reaction {
+    /// This entity's ID as an [`ReactionId`], if selected.
+    pub fn typed_id(&self) -> Option<ReactionId> {
+        self.id.clone().map(ReactionId)
+    }
+}
+/// Identifier of a [`Release`](super::types::Release).
+///
+/// Accepted by every operation that takes this entity's `id`.
+/// Plain strings convert with `From`, so the newtype is opt-in.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ReleaseId(String);
+impl ReleaseId {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self(id.into())
+    }
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+impl From<String> for ReleaseId {
+    fn from(id: String) -> Self {
+        Self(id)
+    }
+}
+impl From<&str> for ReleaseId {
+    fn from(id: &str) -> Self {
+        Self(id.to_string())
+    }
+}
+impl From<&String> for ReleaseId {
+    fn from(id: &String) -> Self {
+        Self(id.clone())
+    }
+}
+impl From<&ReleaseId> for ReleaseId {
+    fn from(id: &ReleaseId) -> Self {
+        id.clone()
+    }
+}
+impl From<ReleaseId> for String {
+    fn from(id: ReleaseId) -> Self {
+        id.0
+    }
+}
+impl AsRef<str> for ReleaseId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+impl std::fmt::Display for ReleaseId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl super::types::Release {
+    /// This entity's ID as an [`ReleaseId`], if selected.
+    pub fn typed_id(&self) -> Option<ReleaseId> {
+        self.id.clone().map(ReleaseId)
+    }
+}
+/// Identifier of a [`ReleaseNote`](super::types::ReleaseNote).
+///
+/// Accepted by every operation that takes this entity's `id`.
+/// Plain strings convert with `From`, so the newtype is opt-in.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ReleaseNoteId(String);
+impl ReleaseNoteId {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self(id.into())
+    }
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+impl From<String> for ReleaseNoteId {
+    fn from(id: String) -> Self {
+        Self(id)
+    }
+}
+impl From<&str> for ReleaseNoteId {
+    fn from(id: &str) -> Self {
+        Self(id.to_string())
+    }
+}
+impl From<&String> for ReleaseNoteId {
+    fn from(id: &String) -> Self {
+        Self(id.clone())
+    }
+}
+impl From<&ReleaseNoteId> for ReleaseNoteId {
+    fn from(id: &ReleaseNoteId) -> Self {
+        id.clone()
+    }
+}
+impl From<ReleaseNoteId> for String {
+    fn from(id: ReleaseNoteId) -> Self {
+        id.0
+    }
+}
+impl AsRef<str> for ReleaseNoteId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+impl std::fmt::Display for ReleaseNoteId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl super::types::ReleaseNote {
+    /// This entity's ID as an [`ReleaseNoteId`], if selected.
+    pub fn typed_id(&self) -> Option<ReleaseNoteId> {
+        self.id.clone().map(ReleaseNoteId)
+    }
+}
+/// Identifier of a [`ReleasePipeline`](super::types::ReleasePipeline).
+///
+/// Accepted by every operation that takes this entity's `id`.
+/// Plain strings convert with `From`, so the newtype is opt-in.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ReleasePipelineId(String);
+impl ReleasePipelineId {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self(id.into())
+    }
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+impl From<String> for ReleasePipelineId {
+    fn from(id: String) -> Self {
+        Self(id)
+    }
+}
+impl From<&str> for ReleasePipelineId {
+    fn from(id: &str) -> Self {
+        Self(id.to_string())
+    }
+}
+impl From<&String> for ReleasePipelineId {
+    fn from(id: &String) -> Self {
+        Self(id.clone())
+    }
+}
+impl From<&ReleasePipelineId> for ReleasePipelineId {
+    fn from(id: &ReleasePipelineId) -> Self {
+        id.clone()
+    }
+}
+impl From<ReleasePipelineId> for String {
+    fn from(id: ReleasePipelineId) -> Self {
+        id.0
+    }
+}
+impl AsRef<str> for ReleasePipelineId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+impl std::fmt::Display for ReleasePipelineId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl super::types::ReleasePipeline {
+    /// This entity's ID as an [`ReleasePipelineId`], if selected.
+    pub fn typed_id(&self) -> Option<ReleasePipelineId> {
+        self.id.clone().map(ReleasePipelineId)
+    }
+}
+/// Identifier of a [`ReleaseStage`](super::types::ReleaseStage).
+///
+/// Accepted by every operation that takes this entity's `id`.
+/// Plain strings convert with `From`, so the newtype is opt-in.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ReleaseStageId(String);
+impl ReleaseStageId {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self(id.into())
+    }
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+impl From<String> for ReleaseStageId {
+    fn from(id: String) -> Self {
+        Self(id)
+    }
+}
+impl From<&str> for ReleaseStageId {
+    fn from(id: &str) -> Self {
+        Self(id.to_string())
+    }
+}
+impl From<&String> for ReleaseStageId {
+    fn from(id: &String) -> Self {
+        Self(id.clone())
+    }
+}
+impl From<&ReleaseStageId> for ReleaseStageId {
+    fn from(id: &ReleaseStageId) -> Self {
+        id.clone()
+    }
+}
+impl From<ReleaseStageId> for String {
+    fn from(id: ReleaseStageId) -> Self {
+        id.0
+    }
+}
+impl AsRef<str> for ReleaseStageId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+impl std::fmt::Display for ReleaseStageId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl super::types::ReleaseStage {
+    /// This entity's ID as an [`ReleaseStageId`], if selected.
+    pub fn typed_id(&self) -> Option<ReleaseStageId> {
+        self.id.clone().map(ReleaseStageId)
+    }
+}
+/// Identifier of a [`Roadmap`](super::types::Roadmap).
+///
+/// Accepted by every operation that takes this entity's `id`.
+/// Plain strings convert with `From`, so the newtype is opt-in.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct RoadmapId(String);
+impl RoadmapId {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self(id.into())
+    }
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+impl From<String> for RoadmapId {
+    fn from(id: String) -> Self {
+        Self(id)
+    }
+}
+impl From<&str> for RoadmapId {
+    fn from(id: &str) -> Self {
+        Self(id.to_string())
+    }
+}
+impl From<&String> for RoadmapId {
+    fn from(id: &String) -> Self {
+        Self(id.clone())
+    }
+}
+impl From<&RoadmapId> for RoadmapId {
+    fn from(id: &RoadmapId) -> Self {
+        id.clone()
+    }
+}
+impl From<RoadmapId> for String {
+    fn from(id: RoadmapId) -> Self {
+        id.0
+    }
+}
+impl AsRef<str> for RoadmapId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+impl std::fmt::Display for RoadmapId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl super::types::Roadmap {
+    /// This entity's ID as an [`RoadmapId`], if selected.
+    pub fn typed_id(&self) -> Option<RoadmapId> {
+        self.id.clone().map(RoadmapId)
+    }
+}
+/// Identifier of a [`RoadmapToProject`](super::types::RoadmapToProject).
+///
+/// Accepted by every operation that takes this entity's `id`.
+/// Plain strings convert with `From`, so the newtype is opt-in.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct RoadmapToProjectId(String);
+impl RoadmapToProjectId {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self(id.into())
+    }
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+impl From<String> for RoadmapToProjectId {
+    fn from(id: String) -> Self {
+        Self(id)
+    }
+}
+impl From<&str> for RoadmapToProjectId {
+    fn from(id: &str) -> Self {
+        Self(id.to_string())
+    }
+}
+impl From<&String> for RoadmapToProjectId {
+    fn from(id: &String) -> Self {
+        Self(id.clone())
+    }
+}
+impl From<&RoadmapToProjectId> for RoadmapToProjectId {
+    fn from(id: &RoadmapToProjectId) -> Self {
+        id.clone()
+    }
+}
+impl From<RoadmapToProjectId> for String {
+    fn from(id: RoadmapToProjectId) -> Self {
+        id.0
+    }
+}
+impl AsRef<str> for RoadmapToProjectId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+impl std::fmt::Display for RoadmapToProjectId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl super::types::RoadmapToProject {
+    /// This entity's ID as an [`RoadmapToProjectId`], if selected.
+    pub fn typed_id(&self) -> Option<RoadmapToProjectId> {
+        self.id.clone().map(RoadmapToProjectId)
+    }
+}
+/// Identifier of a [`Team`](super::types::Team).
+///
+/// Accepted by every operation that takes this entity's `id`.
+/// Plain strings convert with `From`, so the newtype is opt-in.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct TeamId(String);
+impl TeamId {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self(id.into())
+    }
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+impl From<String> for TeamId {
+    fn from(id: String) -> Self {
+        Self(id)
+    }
+}
+impl From<&str> for TeamId {
+    fn from(id: &str) -> Self {
+        Self(id.to_string())
+    }
+}
+impl From<&String> for TeamId {
+    fn from(id: &String) -> Self {
+        Self(id.clone())
+    }
+}
+impl From<&TeamId> for TeamId {
+    fn from(id: &TeamId) -> Self {
+        id.clone()
+    }
+}
+impl From<TeamId> for String {
+    fn from(id: TeamId) -> Self {
+        id.0
+    }
+}
+impl AsRef<str> for TeamId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+impl std::fmt::Display for TeamId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl super::types::Team {
+    /// This entity's ID as an [`TeamId`], if selected.
+    pub fn typed_id(&self) -> Option<TeamId> {
+        self.id.clone().map(TeamId)
+    }
+}
+/// Identifier of a [`TeamMembership`](super::types::TeamMembership).
+///
+/// Accepted by every operation that takes this entity's `id`.
+/// Plain strings convert with `From`, so the newtype is opt-in.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct TeamMembershipId(String);
+impl TeamMembershipId {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self(id.into())
+    }
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+impl From<String> for TeamMembershipId {
+    fn from(id: String) -> Self {
+        Self(id)
+    }
+}
+impl From<&str> for TeamMembershipId {
+    fn from(id: &str) -> Self {
+        Self(id.to_string())
+    }
+}
+impl From<&String> for TeamMembershipId {
+    fn from(id: &String) -> Self {
+        Self(id.clone())
+    }
+}
+impl From<&TeamMembershipId> for TeamMembershipId {
+    fn from(id: &TeamMembershipId) -> Self {
+        id.clone()
+    }
+}
+impl From<TeamMembershipId> for String {
+    fn from(id: TeamMembershipId) -> Self {
+        id.0
+    }
+}
+impl AsRef<str> for TeamMembershipId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+impl std::fmt::Display for TeamMembershipId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl super::types::TeamMembership {
+    /// This entity's ID as an [`TeamMembershipId`], if selected.
+    pub fn typed_id(&self) -> Option<TeamMembershipId> {
+        self.id.clone().map(TeamMembershipId)
+    }
+}
+/// Identifier of a [`Template`](super::types::Template).
+///
+/// Accepted by every operation that takes this entity's `id`.
+/// Plain strings convert with `From`, so the newtype is opt-in.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct TemplateId(String);
+impl TemplateId {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self(id.into())
+    }
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+impl From<String> for TemplateId {
+    fn from(id: String) -> Self {
+        Self(id)
+    }
+}
+impl From<&str> for TemplateId {
+    fn from(id: &str) -> Self {
+        Self(id.to_string())
+    }
+}
+impl From<&String> for TemplateId {
+    fn from(id: &String) -> Self {
+        Self(id.clone())
+    }
+}
+impl From<&TemplateId> for TemplateId {
+    fn from(id: &TemplateId) -> Self {
+        id.clone()
+    }
+}
+impl From<TemplateId> for String {
+    fn from(id: TemplateId) -> Self {
+        id.0
+    }
+}
+impl AsRef<str> for TemplateId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+impl std::fmt::Display for TemplateId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl super::types::Template {
+    /// This entity's ID as an [`TemplateId`], if selected.
+    pub fn typed_id(&self) -> Option<TemplateId> {
+        self.id.clone().map(TemplateId)
+    }
+}
+/// Identifier of a [`TimeSchedule`](super::types::TimeSchedule).
+///
+/// Accepted by every operation that takes this entity's `id`.
+/// Plain strings convert with `From`, so the newtype is opt-in.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct TimeScheduleId(String);
+impl TimeScheduleId {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self(id.into())
+    }
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+impl From<String> for TimeScheduleId {
+    fn from(id: String) -> Self {
+        Self(id)
+    }
+}
+impl From<&str> for TimeScheduleId {
+    fn from(id: &str) -> Self {
+        Self(id.to_string())
+    }
+}
+impl From<&String> for TimeScheduleId {
+    fn from(id: &String) -> Self {
+        Self(id.clone())
+    }
+}
+impl From<&TimeScheduleId> for TimeScheduleId {
+    fn from(id: &TimeScheduleId) -> Self {
+        id.clone()
+    }
+}
+impl From<TimeScheduleId> for String {
+    fn from(id: TimeScheduleId) -> Self {
+        id.0
+    }
+}
+impl AsRef<str> for TimeScheduleId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+impl std::fmt::Display for TimeScheduleId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl super::types::TimeSchedule {
+    /// This entity's ID as an [`TimeScheduleId`], if selected.
+    pub fn typed_id(&self) -> Option<TimeScheduleId> {
+        self.id.clone().map(TimeScheduleId)
+    }
+}
+/// Identifier of a [`TriageResponsibility`](super::types::TriageResponsibility).
+///
+/// Accepted by every operation that takes this entity's `id`.
+/// Plain strings convert with `From`, so the newtype is opt-in.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct TriageResponsibilityId(String);
+impl TriageResponsibilityId {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self(id.into())
+    }
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+impl From<String> for TriageResponsibilityId {
+    fn from(id: String) -> Self {
+        Self(id)
+    }
+}
+impl From<&str> for TriageResponsibilityId {
+    fn from(id: &str) -> Self {
+        Self(id.to_string())
+    }
+}
+impl From<&String> for TriageResponsibilityId {
+    fn from(id: &String) -> Self {
+        Self(id.clone())
+    }
+}
+impl From<&TriageResponsibilityId> for TriageResponsibilityId {
+    fn from(id: &TriageResponsibilityId) -> Self {
+        id.clone()
+    }
+}
+impl From<TriageResponsibilityId> for String {
+    fn from(id: TriageResponsibilityId) -> Self {
+        id.0
+    }
+}
+impl AsRef<str> for TriageResponsibilityId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+impl std::fmt::Display for TriageResponsibilityId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl super::types::TriageResponsibility {
+    /// This entity's ID as an [`TriageResponsibilityId`], if selected.
+    pub fn typed_id(&self) -> Option<TriageResponsibilityId> {
+        self.id.clone().map(TriageResponsibilityId)
+    }
+}
+/// Identifier of a [`User`](super::types::User).
+///
+/// Accepted by every operation that takes this entity's `id`.
+/// Plain strings convert with `From`, so the newtype is opt-in.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct UserId(String);
+impl UserId {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self(id.into())
+    }
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+impl From<String> for UserId {
+    fn from(id: String) -> Self {
+        Self(id)
+    }
+}
+impl From<&str> for UserId {
+    fn from(id: &str) -> Self {
+        Self(id.to_string())
+    }
+}
+impl From<&String> for UserId {
+    fn from(id: &String) -> Self {
+        Self(id.clone())
+    }
+}
+impl From<&UserId> for UserId {
+    fn from(id: &UserId) -> Self {
+        id.clone()
+    }
+}
+impl From<UserId> for String {
+    fn from(id: UserId) -> Self {
+        id.0
+    }
+}
+impl AsRef<str> for UserId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+impl std::fmt::Display for UserId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl super::types::User {
+    /// This entity's ID as an [`UserId`], if selected.
+    pub fn typed_id(&self) -> Option<UserId> {
+        self.id.clone().map(UserId)
+    }
+}
+/// Identifier of a [`UserSettings`](super::types::UserSettings).
+///
+/// Accepted by every operation that takes this entity's `id`.
+/// Plain strings convert with `From`, so the newtype is opt-in.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct UserSettingsId(String);
+impl UserSettingsId {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self(id.into())
+    }
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+impl From<String> for UserSettingsId {
+    fn from(id: String) -> Self {
+        Self(id)
+    }
+}
+impl From<&str> for UserSettingsId {
+    fn from(id: &str) -> Self {
+        Self(id.to_string())
+    }
+}
+impl From<&String> for UserSettingsId {
+    fn from(id: &String) -> Self {
+        Self(id.clone())
+    }
+}
+impl From<&UserSettingsId> for UserSettingsId {
+    fn from(id: &UserSettingsId) -> Self {
+        id.clone()
+    }
+}
+impl From<UserSettingsId> for String {
+    fn from(id: UserSettingsId) -> Self {
+        id.0
+    }
+}
+impl AsRef<str> for UserSettingsId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+impl std::fmt::Display for UserSettingsId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl super::types::UserSettings {
+    /// This entity's ID as an [`UserSettingsId`], if selected.
+    pub fn typed_id(&self) -> Option<UserSettingsId> {
+        self.id.clone().map(UserSettingsId)
+    }
+}
+/// Identifier of a [`ViewPreferences`](super::types::ViewPreferences).
+///
+/// Accepted by every operation that takes this entity's `id`.
+/// Plain strings convert with `From`, so the newtype is opt-in.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ViewPreferencesId(String);
+impl ViewPreferencesId {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self(id.into())
+    }
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+impl From<String> for ViewPreferencesId {
+    fn from(id: String) -> Self {
+        Self(id)
+    }
+}
+impl From<&str> for ViewPreferencesId {
+    fn from(id: &str) -> Self {
+        Self(id.to_string())
+    }
+}
+impl From<&String> for ViewPreferencesId {
+    fn from(id: &String) -> Self {
+        Self(id.clone())
+    }
+}
+impl From<&ViewPreferencesId> for ViewPreferencesId {
+    fn from(id: &ViewPreferencesId) -> Self {
+        id.clone()
+    }
+}
+impl From<ViewPreferencesId> for String {
+    fn from(id: ViewPreferencesId) -> Self {
+        id.0
+    }
+}
+impl AsRef<str> for ViewPreferencesId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+impl std::fmt::Display for ViewPreferencesId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl super::types::ViewPreferences {
+    /// This entity's ID as an [`ViewPreferencesId`], if selected.
+    pub fn typed_id(&self) -> Option<ViewPreferencesId> {
+        self.id.clone().map(ViewPreferencesId)
+    }
+}
+/// Identifier of a [`Webhook`](super::types::Webhook).
+///
+/// Accepted by every operation that takes this entity's `id`.
+/// Plain strings convert with `From`, so the newtype is opt-in.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct WebhookId(String);
+impl WebhookId {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self(id.into())
+    }
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+impl From<String> for WebhookId {
+    fn from(id: String) -> Self {
+        Self(id)
+    }
+}
+impl From<&str> for WebhookId {
+    fn from(id: &str) -> Self {
+        Self(id.to_string())
+    }
+}
+impl From<&String> for WebhookId {
+    fn from(id: &String) -> Self {
+        Self(id.clone())
+    }
+}
+impl From<&WebhookId> for WebhookId {
+    fn from(id: &WebhookId) -> Self {
+        id.clone()
+    }
+}
+impl From<WebhookId> for String {
+    fn from(id: WebhookId) -> Self {
+        id.0
+    }
+}
+impl AsRef<str> for WebhookId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+impl std::fmt::Display for WebhookId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl super::types::Webhook {
+    /// This entity's ID as an [`WebhookId`], if selected.
+    pub fn typed_id(&self) -> Option<WebhookId> {
+        self.id.clone().map(WebhookId)
+    }
+}
+/// Identifier of a [`WorkflowState`](super::types::WorkflowState).
+///
+/// Accepted by every operation that takes this entity's `id`.
+/// Plain strings convert with `From`, so the newtype is opt-in.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct WorkflowStateId(String);
+impl WorkflowStateId {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self(id.into())
+    }
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+impl From<String> for WorkflowStateId {
+    fn from(id: String) -> Self {
+        Self(id)
+    }
+}
+impl From<&str> for WorkflowStateId {
+    fn from(id: &str) -> Self {
+        Self(id.to_string())
+    }
+}
+impl From<&String> for WorkflowStateId {
+    fn from(id: &String) -> Self {
+        Self(id.clone())
+    }
+}
+impl From<&WorkflowStateId> for WorkflowStateId {
+    fn from(id: &WorkflowStateId) -> Self {
+        id.clone()
+    }
+}
+impl From<WorkflowStateId> for String {
+    fn from(id: WorkflowStateId) -> Self {
+        id.0
+    }
+}
+impl AsRef<str> for WorkflowStateId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+impl std::fmt::Display for WorkflowStateId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+impl super::types::WorkflowState {
+    /// This entity's ID as an [`WorkflowStateId`], if selected.
+    pub fn typed_id(&self) -> Option<WorkflowStateId> {
+        self.id.clone().map(WorkflowStateId)
+    }
+}
@@ -36,6 +36,95 @@ pub struct ActivityCollectionFilter {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub length: MaybeUndefined<NumberComparator>,
 }
+impl ActivityCollectionFilter {
+    /// Comparator for the identifier.
+    pub fn id(value: IDComparator) -> Self {
+        Self {
+            id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the created at date.
+    pub fn created_at(value: DateComparator) -> Self {
+        Self {
+            created_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the updated at date.
+    pub fn updated_at(value: DateComparator) -> Self {
+        Self {
+            updated_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the activity's user must satisfy.
+    pub fn user(value: UserFilter) -> Self {
+        Self {
+            user: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that needs to be matched by some activities.
+    pub fn some(value: ActivityFilter) -> Self {
+        Self {
+            some: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that needs to be matched by all activities.
+    pub fn every(value: ActivityFilter) -> Self {
+        Self {
+            every: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the collection length.
+    pub fn length(value: NumberComparator) -> Self {
+        Self {
+            length: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Match records matching every filter. A single filter is returned
+    /// as is, and no filters give the empty filter, which matches everything.
+    pub fn all(filters: impl IntoIterator<Item = Self>) -> Self {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => Self::default(),
+            1 => filters.remove(0),
+            _ => Self {
+                and: MaybeUndefined::Value(filters),
+                ..Default::default()
+            },
+        }
+    }
+    /// Match records matching any of the filters. A single filter is
+    /// returned as is. No filters give `None`: a disjunction of nothing
+    /// matches nothing, which no filter expresses.
+    pub fn any(filters: impl IntoIterator<Item = Self>) -> Option<Self> {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => None,
+            1 => Some(filters.remove(0)),
+            _ => Some(Self {
+                or: MaybeUndefined::Value(filters),
+                ..Default::default()
+            }),
+        }
+    }
+    /// Match records matching both this filter and `other`.
+    pub fn and(self, other: Self) -> Self {
+        Self::all([self, other])
+    }
+    /// Match records matching this filter or `other`.
+    pub fn or(self, other: Self) -> Self {
+        Self {
+            or: MaybeUndefined::Value(vec![self, other]),
+            ..Default::default()
+        }
+    }
+}
 /// Activity filtering options.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -59,6 +148,74 @@ pub struct ActivityFilter {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub or: MaybeUndefined<Vec<ActivityFilter>>,
 }
+impl ActivityFilter {
+    /// Comparator for the identifier.
+    pub fn id(value: IDComparator) -> Self {
+        Self {
+            id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the created at date.
+    pub fn created_at(value: DateComparator) -> Self {
+        Self {
+            created_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the updated at date.
+    pub fn updated_at(value: DateComparator) -> Self {
+        Self {
+            updated_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the activity's user must satisfy.
+    pub fn user(value: UserFilter) -> Self {
+        Self {
+            user: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Match records matching every filter. A single filter is returned
+    /// as is, and no filters give the empty filter, which matches everything.
+    pub fn all(filters: impl IntoIterator<Item = Self>) -> Self {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => Self::default(),
+            1 => filters.remove(0),
+            _ => Self {
+                and: MaybeUndefined::Value(filters),
+                ..Default::default()
+            },
+        }
+    }
+    /// Match records matching any of the filters. A single filter is
+    /// returned as is. No filters give `None`: a disjunction of nothing
+    /// matches nothing, which no filter expresses.
+    pub fn any(filters: impl IntoIterator<Item = Self>) -> Option<Self> {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => None,
+            1 => Some(filters.remove(0)),
+            _ => Some(Self {
+                or: MaybeUndefined::Value(filters),
+                ..Default::default()
+            }),
+        }
+    }
+    /// Match records matching both this filter and `other`.
+    pub fn and(self, other: Self) -> Self {
+        Self::all([self, other])
+    }
+    /// Match records matching this filter or `other`.
+    pub fn or(self, other: Self) -> Self {
+        Self {
+            or: MaybeUndefined::Value(vec![self, other]),
+            ..Default::default()
+        }
+    }
+}
 /// Input for creating an agent activity.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -137,6 +294,88 @@ pub struct AgentActivityFilter {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub or: MaybeUndefined<Vec<AgentActivityFilter>>,
 }
+impl AgentActivityFilter {
+    /// Comparator for the identifier.
+    pub fn id(value: IDComparator) -> Self {
+        Self {
+            id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the created at date.
+    pub fn created_at(value: DateComparator) -> Self {
+        Self {
+            created_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the updated at date.
+    pub fn updated_at(value: DateComparator) -> Self {
+        Self {
+            updated_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the agent session ID.
+    pub fn agent_session_id(value: StringComparator) -> Self {
+        Self {
+            agent_session_id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the agent activity's content type.
+    pub fn type_(value: StringComparator) -> Self {
+        Self {
+            r#type: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the source comment must satisfy.
+    pub fn source_comment(value: NullableCommentFilter) -> Self {
+        Self {
+            source_comment: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Match records matching every filter. A single filter is returned
+    /// as is, and no filters give the empty filter, which matches everything.
+    pub fn all(filters: impl IntoIterator<Item = Self>) -> Self {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => Self::default(),
+            1 => filters.remove(0),
+            _ => Self {
+                and: MaybeUndefined::Value(filters),
+                ..Default::default()
+            },
+        }
+    }
+    /// Match records matching any of the filters. A single filter is
+    /// returned as is. No filters give `None`: a disjunction of nothing
+    /// matches nothing, which no filter expresses.
+    pub fn any(filters: impl IntoIterator<Item = Self>) -> Option<Self> {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => None,
+            1 => Some(filters.remove(0)),
+            _ => Some(Self {
+                or: MaybeUndefined::Value(filters),
+                ..Default::default()
+            }),
+        }
+    }
+    /// Match records matching both this filter and `other`.
+    pub fn and(self, other: Self) -> Self {
+        Self::all([self, other])
+    }
+    /// Match records matching this filter or `other`.
+    pub fn or(self, other: Self) -> Self {
+        Self {
+            or: MaybeUndefined::Value(vec![self, other]),
+            ..Default::default()
+        }
+    }
+}
 /// `Internal` Input for creating prompt-type agent activities (created by users).
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -279,6 +518,81 @@ pub struct AiPromptProgressFilter {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub or: MaybeUndefined<Vec<AiPromptProgressFilter>>,
 }
+impl AiPromptProgressFilter {
+    /// Comparator for the identifier.
+    pub fn id(value: IDComparator) -> Self {
+        Self {
+            id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the created at date.
+    pub fn created_at(value: DateComparator) -> Self {
+        Self {
+            created_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the updated at date.
+    pub fn updated_at(value: DateComparator) -> Self {
+        Self {
+            updated_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// `Internal` Comparator for the AI prompt workflow type.
+    pub fn type_(value: AiPromptTypeComparator) -> Self {
+        Self {
+            r#type: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// `Internal` Comparator for the AI prompt workflow status.
+    pub fn status(value: AiPromptProgressStatusComparator) -> Self {
+        Self {
+            status: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Match records matching every filter. A single filter is returned
+    /// as is, and no filters give the empty filter, which matches everything.
+    pub fn all(filters: impl IntoIterator<Item = Self>) -> Self {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => Self::default(),
+            1 => filters.remove(0),
+            _ => Self {
+                and: MaybeUndefined::Value(filters),
+                ..Default::default()
+            },
+        }
+    }
+    /// Match records matching any of the filters. A single filter is
+    /// returned as is. No filters give `None`: a disjunction of nothing
+    /// matches nothing, which no filter expresses.
+    pub fn any(filters: impl IntoIterator<Item = Self>) -> Option<Self> {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => None,
+            1 => Some(filters.remove(0)),
+            _ => Some(Self {
+                or: MaybeUndefined::Value(filters),
+                ..Default::default()
+            }),
+        }
+    }
+    /// Match records matching both this filter and `other`.
+    pub fn and(self, other: Self) -> Self {
+        Self::all([self, other])
+    }
+    /// Match records matching this filter or `other`.
+    pub fn or(self, other: Self) -> Self {
+        Self {
+            or: MaybeUndefined::Value(vec![self, other]),
+            ..Default::default()
+        }
+    }
+}
 /// `Internal` Comparator for the AI prompt workflow status.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -299,6 +613,69 @@ pub struct AiPromptProgressStatusComparator {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub null: MaybeUndefined<bool>,
 }
+impl AiPromptProgressStatusComparator {
+    /// Equals constraint.
+    pub fn eq(value: impl Into<AiPromptProgressStatus>) -> Self {
+        Self {
+            eq: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Not-equals constraint.
+    pub fn neq(value: impl Into<AiPromptProgressStatus>) -> Self {
+        Self {
+            neq: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// In-array constraint.
+    pub fn in_(values: impl IntoIterator<Item = impl Into<AiPromptProgressStatus>>) -> Self {
+        Self {
+            r#in: MaybeUndefined::Value(values.into_iter().map(Into::into).collect()),
+            ..Default::default()
+        }
+    }
+    /// Not-in-array constraint.
+    pub fn nin(values: impl IntoIterator<Item = impl Into<AiPromptProgressStatus>>) -> Self {
+        Self {
+            nin: MaybeUndefined::Value(values.into_iter().map(Into::into).collect()),
+            ..Default::default()
+        }
+    }
+    /// Null constraint. Matches any non-null values if the given value is false, otherwise it matches null values.
+    pub fn null(value: impl Into<bool>) -> Self {
+        Self {
+            null: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+}
+impl AiPromptProgressStatusComparator {
+    /// The negated comparator (`eq` ↔ `neq`, `lt` ↔ `gte`, …), or
+    /// `None` unless exactly one constraint is set: negating several
+    /// would need an `or`, which comparators don't have.
+    pub fn negate(self) -> Option<Self> {
+        if usize::from(!self.eq.is_undefined())
+            + usize::from(!self.neq.is_undefined())
+            + usize::from(!self.r#in.is_undefined())
+            + usize::from(!self.nin.is_undefined())
+            + usize::from(!self.null.is_undefined())
+            != 1
+        {
+            return None;
+        }
+        Some(Self {
+            eq: self.neq,
+            neq: self.eq,
+            r#in: self.nin,
+            nin: self.r#in,
+            null: match self.null {
+                MaybeUndefined::Value(is_null) => MaybeUndefined::Value(!is_null),
+                other => other,
+            },
+        })
+    }
+}
 /// `Internal` Filter for AI prompt progress subscription events.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -319,6 +696,43 @@ pub struct AiPromptProgressSubscriptionFilter {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub status: MaybeUndefined<AiPromptProgressStatusComparator>,
 }
+impl AiPromptProgressSubscriptionFilter {
+    /// `Internal` Filter by issue ID.
+    pub fn issue_id(value: IDComparator) -> Self {
+        Self {
+            issue_id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// `Internal` Filter by comment ID.
+    pub fn comment_id(value: IDComparator) -> Self {
+        Self {
+            comment_id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// `Internal` Filter by pull request comment ID.
+    pub fn pull_request_comment_id(value: IDComparator) -> Self {
+        Self {
+            pull_request_comment_id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// `Internal` Filter by prompt workflow type.
+    pub fn type_(value: AiPromptTypeComparator) -> Self {
+        Self {
+            r#type: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// `Internal` Filter by prompt workflow status.
+    pub fn status(value: AiPromptProgressStatusComparator) -> Self {
+        Self {
+            status: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+}
 /// `Internal` Comparator for the AI prompt workflow type.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -339,6 +753,69 @@ pub struct AiPromptTypeComparator {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub null: MaybeUndefined<bool>,
 }
+impl AiPromptTypeComparator {
+    /// Equals constraint.
+    pub fn eq(value: impl Into<AiPromptType>) -> Self {
+        Self {
+            eq: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Not-equals constraint.
+    pub fn neq(value: impl Into<AiPromptType>) -> Self {
+        Self {
+            neq: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// In-array constraint.
+    pub fn in_(values: impl IntoIterator<Item = impl Into<AiPromptType>>) -> Self {
+        Self {
+            r#in: MaybeUndefined::Value(values.into_iter().map(Into::into).collect()),
+            ..Default::default()
+        }
+    }
+    /// Not-in-array constraint.
+    pub fn nin(values: impl IntoIterator<Item = impl Into<AiPromptType>>) -> Self {
+        Self {
+            nin: MaybeUndefined::Value(values.into_iter().map(Into::into).collect()),
+            ..Default::default()
+        }
+    }
+    /// Null constraint. Matches any non-null values if the given value is false, otherwise it matches null values.
+    pub fn null(value: impl Into<bool>) -> Self {
+        Self {
+            null: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+}
+impl AiPromptTypeComparator {
+    /// The negated comparator (`eq` ↔ `neq`, `lt` ↔ `gte`, …), or
+    /// `None` unless exactly one constraint is set: negating several
+    /// would need an `or`, which comparators don't have.
+    pub fn negate(self) -> Option<Self> {
+        if usize::from(!self.eq.is_undefined())
+            + usize::from(!self.neq.is_undefined())
+            + usize::from(!self.r#in.is_undefined())
+            + usize::from(!self.nin.is_undefined())
+            + usize::from(!self.null.is_undefined())
+            != 1
+        {
+            return None;
+        }
+        Some(Self {
+            eq: self.neq,
+            neq: self.eq,
+            r#in: self.nin,
+            nin: self.r#in,
+            null: match self.null {
+                MaybeUndefined::Value(is_null) => MaybeUndefined::Value(!is_null),
+                other => other,
+            },
+        })
+    }
+}
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AirbyteConfigurationInput {
@@ -411,6 +888,123 @@ pub struct AttachmentCollectionFilter {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub length: MaybeUndefined<NumberComparator>,
 }
+impl AttachmentCollectionFilter {
+    /// Comparator for the identifier.
+    pub fn id(value: IDComparator) -> Self {
+        Self {
+            id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the created at date.
+    pub fn created_at(value: DateComparator) -> Self {
+        Self {
+            created_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the updated at date.
+    pub fn updated_at(value: DateComparator) -> Self {
+        Self {
+            updated_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the title.
+    pub fn title(value: StringComparator) -> Self {
+        Self {
+            title: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the subtitle.
+    pub fn subtitle(value: NullableStringComparator) -> Self {
+        Self {
+            subtitle: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the url.
+    pub fn url(value: StringComparator) -> Self {
+        Self {
+            url: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the attachments creator must satisfy.
+    pub fn creator(value: NullableUserFilter) -> Self {
+        Self {
+            creator: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the source type.
+    pub fn source_type(value: SourceTypeComparator) -> Self {
+        Self {
+            source_type: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that needs to be matched by some attachments.
+    pub fn some(value: AttachmentFilter) -> Self {
+        Self {
+            some: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that needs to be matched by all attachments.
+    pub fn every(value: AttachmentFilter) -> Self {
+        Self {
+            every: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the collection length.
+    pub fn length(value: NumberComparator) -> Self {
+        Self {
+            length: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Match records matching every filter. A single filter is returned
+    /// as is, and no filters give the empty filter, which matches everything.
+    pub fn all(filters: impl IntoIterator<Item = Self>) -> Self {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => Self::default(),
+            1 => filters.remove(0),
+            _ => Self {
+                and: MaybeUndefined::Value(filters),
+                ..Default::default()
+            },
+        }
+    }
+    /// Match records matching any of the filters. A single filter is
+    /// returned as is. No filters give `None`: a disjunction of nothing
+    /// matches nothing, which no filter expresses.
+    pub fn any(filters: impl IntoIterator<Item = Self>) -> Option<Self> {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => None,
+            1 => Some(filters.remove(0)),
+            _ => Some(Self {
+                or: MaybeUndefined::Value(filters),
+                ..Default::default()
+            }),
+        }
+    }
+    /// Match records matching both this filter and `other`.
+    pub fn and(self, other: Self) -> Self {
+        Self::all([self, other])
+    }
+    /// Match records matching this filter or `other`.
+    pub fn or(self, other: Self) -> Self {
+        Self {
+            or: MaybeUndefined::Value(vec![self, other]),
+            ..Default::default()
+        }
+    }
+}
 /// Input for creating a new issue attachment.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -481,6 +1075,102 @@ pub struct AttachmentFilter {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub or: MaybeUndefined<Vec<AttachmentFilter>>,
 }
+impl AttachmentFilter {
+    /// Comparator for the identifier.
+    pub fn id(value: IDComparator) -> Self {
+        Self {
+            id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the created at date.
+    pub fn created_at(value: DateComparator) -> Self {
+        Self {
+            created_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the updated at date.
+    pub fn updated_at(value: DateComparator) -> Self {
+        Self {
+            updated_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the title.
+    pub fn title(value: StringComparator) -> Self {
+        Self {
+            title: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the subtitle.
+    pub fn subtitle(value: NullableStringComparator) -> Self {
+        Self {
+            subtitle: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the url.
+    pub fn url(value: StringComparator) -> Self {
+        Self {
+            url: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the attachments creator must satisfy.
+    pub fn creator(value: NullableUserFilter) -> Self {
+        Self {
+            creator: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the source type.
+    pub fn source_type(value: SourceTypeComparator) -> Self {
+        Self {
+            source_type: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Match records matching every filter. A single filter is returned
+    /// as is, and no filters give the empty filter, which matches everything.
+    pub fn all(filters: impl IntoIterator<Item = Self>) -> Self {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => Self::default(),
+            1 => filters.remove(0),
+            _ => Self {
+                and: MaybeUndefined::Value(filters),
+                ..Default::default()
+            },
+        }
+    }
+    /// Match records matching any of the filters. A single filter is
+    /// returned as is. No filters give `None`: a disjunction of nothing
+    /// matches nothing, which no filter expresses.
+    pub fn any(filters: impl IntoIterator<Item = Self>) -> Option<Self> {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => None,
+            1 => Some(filters.remove(0)),
+            _ => Some(Self {
+                or: MaybeUndefined::Value(filters),
+                ..Default::default()
+            }),
+        }
+    }
+    /// Match records matching both this filter and `other`.
+    pub fn and(self, other: Self) -> Self {
+        Self::all([self, other])
+    }
+    /// Match records matching this filter or `other`.
+    pub fn or(self, other: Self) -> Self {
+        Self {
+            or: MaybeUndefined::Value(vec![self, other]),
+            ..Default::default()
+        }
+    }
+}
 /// Input for updating an existing issue attachment.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -529,6 +1219,95 @@ pub struct AuditEntryFilter {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub or: MaybeUndefined<Vec<AuditEntryFilter>>,
 }
+impl AuditEntryFilter {
+    /// Comparator for the identifier.
+    pub fn id(value: IDComparator) -> Self {
+        Self {
+            id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the created at date.
+    pub fn created_at(value: DateComparator) -> Self {
+        Self {
+            created_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the updated at date.
+    pub fn updated_at(value: DateComparator) -> Self {
+        Self {
+            updated_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the type.
+    pub fn type_(value: StringComparator) -> Self {
+        Self {
+            r#type: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the IP address.
+    pub fn ip(value: StringComparator) -> Self {
+        Self {
+            ip: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the country code.
+    pub fn country_code(value: StringComparator) -> Self {
+        Self {
+            country_code: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the audit entry actor must satisfy.
+    pub fn actor(value: NullableUserFilter) -> Self {
+        Self {
+            actor: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Match records matching every filter. A single filter is returned
+    /// as is, and no filters give the empty filter, which matches everything.
+    pub fn all(filters: impl IntoIterator<Item = Self>) -> Self {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => Self::default(),
+            1 => filters.remove(0),
+            _ => Self {
+                and: MaybeUndefined::Value(filters),
+                ..Default::default()
+            },
+        }
+    }
+    /// Match records matching any of the filters. A single filter is
+    /// returned as is. No filters give `None`: a disjunction of nothing
+    /// matches nothing, which no filter expresses.
+    pub fn any(filters: impl IntoIterator<Item = Self>) -> Option<Self> {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => None,
+            1 => Some(filters.remove(0)),
+            _ => Some(Self {
+                or: MaybeUndefined::Value(filters),
+                ..Default::default()
+            }),
+        }
+    }
+    /// Match records matching both this filter and `other`.
+    pub fn and(self, other: Self) -> Self {
+        Self::all([self, other])
+    }
+    /// Match records matching this filter or `other`.
+    pub fn or(self, other: Self) -> Self {
+        Self {
+            or: MaybeUndefined::Value(vec![self, other]),
+            ..Default::default()
+        }
+    }
+}
 /// Comparator for booleans.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -540,6 +1319,36 @@ pub struct BooleanComparator {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub neq: MaybeUndefined<bool>,
 }
+impl BooleanComparator {
+    /// Equals constraint.
+    pub fn eq(value: impl Into<bool>) -> Self {
+        Self {
+            eq: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Not equals constraint.
+    pub fn neq(value: impl Into<bool>) -> Self {
+        Self {
+            neq: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+}
+impl BooleanComparator {
+    /// The negated comparator (`eq` ↔ `neq`, `lt` ↔ `gte`, …), or
+    /// `None` unless exactly one constraint is set: negating several
+    /// would need an `or`, which comparators don't have.
+    pub fn negate(self) -> Option<Self> {
+        if usize::from(!self.eq.is_undefined()) + usize::from(!self.neq.is_undefined()) != 1 {
+            return None;
+        }
+        Some(Self {
+            eq: self.neq,
+            neq: self.eq,
+        })
+    }
+}
 /// A candidate code repository to consider when generating repository suggestions for an issue.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -608,6 +1417,158 @@ pub struct CommentCollectionFilter {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub length: MaybeUndefined<NumberComparator>,
 }
+impl CommentCollectionFilter {
+    /// Comparator for the identifier.
+    pub fn id(value: IDComparator) -> Self {
+        Self {
+            id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the created at date.
+    pub fn created_at(value: DateComparator) -> Self {
+        Self {
+            created_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the updated at date.
+    pub fn updated_at(value: DateComparator) -> Self {
+        Self {
+            updated_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the comment's body.
+    pub fn body(value: StringComparator) -> Self {
+        Self {
+            body: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the comment's creator must satisfy.
+    pub fn user(value: UserFilter) -> Self {
+        Self {
+            user: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the comment's issue must satisfy.
+    pub fn issue(value: NullableIssueFilter) -> Self {
+        Self {
+            issue: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the comment's project update must satisfy.
+    pub fn project_update(value: NullableProjectUpdateFilter) -> Self {
+        Self {
+            project_update: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the comment parent must satisfy.
+    pub fn parent(value: NullableCommentFilter) -> Self {
+        Self {
+            parent: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the comment's document content must satisfy.
+    pub fn document_content(value: NullableDocumentContentFilter) -> Self {
+        Self {
+            document_content: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// `Internal` Filters that the comment's project must satisfy.
+    pub fn project(value: NullableProjectFilter) -> Self {
+        Self {
+            project: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// `Internal` Filters that the comment's initiative must satisfy.
+    pub fn initiative(value: NullableInitiativeFilter) -> Self {
+        Self {
+            initiative: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the comment's reactions must satisfy.
+    pub fn reactions(value: ReactionCollectionFilter) -> Self {
+        Self {
+            reactions: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the comment's customer needs must satisfy.
+    pub fn needs(value: CustomerNeedCollectionFilter) -> Self {
+        Self {
+            needs: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that needs to be matched by some comments.
+    pub fn some(value: CommentFilter) -> Self {
+        Self {
+            some: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that needs to be matched by all comments.
+    pub fn every(value: CommentFilter) -> Self {
+        Self {
+            every: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the collection length.
+    pub fn length(value: NumberComparator) -> Self {
+        Self {
+            length: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Match records matching every filter. A single filter is returned
+    /// as is, and no filters give the empty filter, which matches everything.
+    pub fn all(filters: impl IntoIterator<Item = Self>) -> Self {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => Self::default(),
+            1 => filters.remove(0),
+            _ => Self {
+                and: MaybeUndefined::Value(filters),
+                ..Default::default()
+            },
+        }
+    }
+    /// Match records matching any of the filters. A single filter is
+    /// returned as is. No filters give `None`: a disjunction of nothing
+    /// matches nothing, which no filter expresses.
+    pub fn any(filters: impl IntoIterator<Item = Self>) -> Option<Self> {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => None,
+            1 => Some(filters.remove(0)),
+            _ => Some(Self {
+                or: MaybeUndefined::Value(filters),
+                ..Default::default()
+            }),
+        }
+    }
+    /// Match records matching both this filter and `other`.
+    pub fn and(self, other: Self) -> Self {
+        Self::all([self, other])
+    }
+    /// Match records matching this filter or `other`.
+    pub fn or(self, other: Self) -> Self {
+        Self {
+            or: MaybeUndefined::Value(vec![self, other]),
+            ..Default::default()
+        }
+    }
+}
 /// Input for creating a new comment.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -717,6 +1678,137 @@ pub struct CommentFilter {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub or: MaybeUndefined<Vec<CommentFilter>>,
 }
+impl CommentFilter {
+    /// Comparator for the identifier.
+    pub fn id(value: IDComparator) -> Self {
+        Self {
+            id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the created at date.
+    pub fn created_at(value: DateComparator) -> Self {
+        Self {
+            created_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the updated at date.
+    pub fn updated_at(value: DateComparator) -> Self {
+        Self {
+            updated_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the comment's body.
+    pub fn body(value: StringComparator) -> Self {
+        Self {
+            body: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the comment's creator must satisfy.
+    pub fn user(value: UserFilter) -> Self {
+        Self {
+            user: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the comment's issue must satisfy.
+    pub fn issue(value: NullableIssueFilter) -> Self {
+        Self {
+            issue: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the comment's project update must satisfy.
+    pub fn project_update(value: NullableProjectUpdateFilter) -> Self {
+        Self {
+            project_update: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the comment parent must satisfy.
+    pub fn parent(value: NullableCommentFilter) -> Self {
+        Self {
+            parent: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the comment's document content must satisfy.
+    pub fn document_content(value: NullableDocumentContentFilter) -> Self {
+        Self {
+            document_content: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// `Internal` Filters that the comment's project must satisfy.
+    pub fn project(value: NullableProjectFilter) -> Self {
+        Self {
+            project: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// `Internal` Filters that the comment's initiative must satisfy.
+    pub fn initiative(value: NullableInitiativeFilter) -> Self {
+        Self {
+            initiative: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the comment's reactions must satisfy.
+    pub fn reactions(value: ReactionCollectionFilter) -> Self {
+        Self {
+            reactions: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the comment's customer needs must satisfy.
+    pub fn needs(value: CustomerNeedCollectionFilter) -> Self {
+        Self {
+            needs: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Match records matching every filter. A single filter is returned
+    /// as is, and no filters give the empty filter, which matches everything.
+    pub fn all(filters: impl IntoIterator<Item = Self>) -> Self {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => Self::default(),
+            1 => filters.remove(0),
+            _ => Self {
+                and: MaybeUndefined::Value(filters),
+                ..Default::default()
+            },
+        }
+    }
+    /// Match records matching any of the filters. A single filter is
+    /// returned as is. No filters give `None`: a disjunction of nothing
+    /// matches nothing, which no filter expresses.
+    pub fn any(filters: impl IntoIterator<Item = Self>) -> Option<Self> {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => None,
+            1 => Some(filters.remove(0)),
+            _ => Some(Self {
+                or: MaybeUndefined::Value(filters),
+                ..Default::default()
+            }),
+        }
+    }
+    /// Match records matching both this filter and `other`.
+    pub fn and(self, other: Self) -> Self {
+        Self::all([self, other])
+    }
+    /// Match records matching this filter or `other`.
+    pub fn or(self, other: Self) -> Self {
+        Self {
+            or: MaybeUndefined::Value(vec![self, other]),
+            ..Default::default()
+        }
+    }
+}
 /// Input for updating an existing comment.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -813,6 +1905,39 @@ pub struct ContentComparator {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub not_contains: MaybeUndefined<String>,
 }
+impl ContentComparator {
+    /// `Internal` Contains constraint.
+    pub fn contains(value: impl Into<String>) -> Self {
+        Self {
+            contains: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// `Internal` Not-contains constraint.
+    pub fn not_contains(value: impl Into<String>) -> Self {
+        Self {
+            not_contains: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+}
+impl ContentComparator {
+    /// The negated comparator (`eq` ↔ `neq`, `lt` ↔ `gte`, …), or
+    /// `None` unless exactly one constraint is set: negating several
+    /// would need an `or`, which comparators don't have.
+    pub fn negate(self) -> Option<Self> {
+        if usize::from(!self.contains.is_undefined())
+            + usize::from(!self.not_contains.is_undefined())
+            != 1
+        {
+            return None;
+        }
+        Some(Self {
+            contains: self.not_contains,
+            not_contains: self.contains,
+        })
+    }
+}
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CreateOrganizationInput {
@@ -936,6 +2061,109 @@ pub struct CustomViewFilter {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub or: MaybeUndefined<Vec<CustomViewFilter>>,
 }
+impl CustomViewFilter {
+    /// Comparator for the identifier.
+    pub fn id(value: IDComparator) -> Self {
+        Self {
+            id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the created at date.
+    pub fn created_at(value: DateComparator) -> Self {
+        Self {
+            created_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the updated at date.
+    pub fn updated_at(value: DateComparator) -> Self {
+        Self {
+            updated_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the custom view name.
+    pub fn name(value: StringComparator) -> Self {
+        Self {
+            name: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the custom view model name.
+    pub fn model_name(value: StringComparator) -> Self {
+        Self {
+            model_name: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the custom view's team must satisfy.
+    pub fn team(value: NullableTeamFilter) -> Self {
+        Self {
+            team: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the custom view creator must satisfy.
+    pub fn creator(value: UserFilter) -> Self {
+        Self {
+            creator: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for whether the custom view is shared.
+    pub fn shared(value: BooleanComparator) -> Self {
+        Self {
+            shared: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// `INTERNAL` Filter based on whether the custom view has a facet.
+    pub fn has_facet(value: impl Into<bool>) -> Self {
+        Self {
+            has_facet: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Match records matching every filter. A single filter is returned
+    /// as is, and no filters give the empty filter, which matches everything.
+    pub fn all(filters: impl IntoIterator<Item = Self>) -> Self {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => Self::default(),
+            1 => filters.remove(0),
+            _ => Self {
+                and: MaybeUndefined::Value(filters),
+                ..Default::default()
+            },
+        }
+    }
+    /// Match records matching any of the filters. A single filter is
+    /// returned as is. No filters give `None`: a disjunction of nothing
+    /// matches nothing, which no filter expresses.
+    pub fn any(filters: impl IntoIterator<Item = Self>) -> Option<Self> {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => None,
+            1 => Some(filters.remove(0)),
+            _ => Some(Self {
+                or: MaybeUndefined::Value(filters),
+                ..Default::default()
+            }),
+        }
+    }
+    /// Match records matching both this filter and `other`.
+    pub fn and(self, other: Self) -> Self {
+        Self::all([self, other])
+    }
+    /// Match records matching this filter or `other`.
+    pub fn or(self, other: Self) -> Self {
+        Self {
+            or: MaybeUndefined::Value(vec![self, other]),
+            ..Default::default()
+        }
+    }
+}
 /// Custom view name sorting options.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -1141,6 +2369,137 @@ pub struct CustomerFilter {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub or: MaybeUndefined<Vec<CustomerFilter>>,
 }
+impl CustomerFilter {
+    /// Comparator for the identifier.
+    pub fn id(value: IDComparator) -> Self {
+        Self {
+            id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the created at date.
+    pub fn created_at(value: DateComparator) -> Self {
+        Self {
+            created_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the updated at date.
+    pub fn updated_at(value: DateComparator) -> Self {
+        Self {
+            updated_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the customer name.
+    pub fn name(value: StringComparator) -> Self {
+        Self {
+            name: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the customer slack channel ID.
+    pub fn slack_channel_id(value: StringComparator) -> Self {
+        Self {
+            slack_channel_id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the customer's domains.
+    pub fn domains(value: StringArrayComparator) -> Self {
+        Self {
+            domains: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the customer's external IDs.
+    pub fn external_ids(value: StringArrayComparator) -> Self {
+        Self {
+            external_ids: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the customer owner must satisfy.
+    pub fn owner(value: NullableUserFilter) -> Self {
+        Self {
+            owner: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the customer's needs must satisfy.
+    pub fn needs(value: CustomerNeedCollectionFilter) -> Self {
+        Self {
+            needs: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the customer generated revenue.
+    pub fn revenue(value: NumberComparator) -> Self {
+        Self {
+            revenue: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the customer size.
+    pub fn size(value: NumberComparator) -> Self {
+        Self {
+            size: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the customer's status must satisfy.
+    pub fn status(value: CustomerStatusFilter) -> Self {
+        Self {
+            status: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the customer's tier must satisfy.
+    pub fn tier(value: CustomerTierFilter) -> Self {
+        Self {
+            tier: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Match records matching every filter. A single filter is returned
+    /// as is, and no filters give the empty filter, which matches everything.
+    pub fn all(filters: impl IntoIterator<Item = Self>) -> Self {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => Self::default(),
+            1 => filters.remove(0),
+            _ => Self {
+                and: MaybeUndefined::Value(filters),
+                ..Default::default()
+            },
+        }
+    }
+    /// Match records matching any of the filters. A single filter is
+    /// returned as is. No filters give `None`: a disjunction of nothing
+    /// matches nothing, which no filter expresses.
+    pub fn any(filters: impl IntoIterator<Item = Self>) -> Option<Self> {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => None,
+            1 => Some(filters.remove(0)),
+            _ => Some(Self {
+                or: MaybeUndefined::Value(filters),
+                ..Default::default()
+            }),
+        }
+    }
+    /// Match records matching both this filter and `other`.
+    pub fn and(self, other: Self) -> Self {
+        Self::all([self, other])
+    }
+    /// Match records matching this filter or `other`.
+    pub fn or(self, other: Self) -> Self {
+        Self {
+            or: MaybeUndefined::Value(vec![self, other]),
+            ..Default::default()
+        }
+    }
+}
 /// Issue customer important count sorting options.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -1196,6 +2555,123 @@ pub struct CustomerNeedCollectionFilter {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub length: MaybeUndefined<NumberComparator>,
 }
+impl CustomerNeedCollectionFilter {
+    /// Comparator for the identifier.
+    pub fn id(value: IDComparator) -> Self {
+        Self {
+            id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the created at date.
+    pub fn created_at(value: DateComparator) -> Self {
+        Self {
+            created_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the updated at date.
+    pub fn updated_at(value: DateComparator) -> Self {
+        Self {
+            updated_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the customer need priority.
+    pub fn priority(value: NumberComparator) -> Self {
+        Self {
+            priority: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the need's project must satisfy.
+    pub fn project(value: NullableProjectFilter) -> Self {
+        Self {
+            project: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the need's issue must satisfy.
+    pub fn issue(value: NullableIssueFilter) -> Self {
+        Self {
+            issue: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the need's comment must satisfy.
+    pub fn comment(value: NullableCommentFilter) -> Self {
+        Self {
+            comment: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the need's customer must satisfy.
+    pub fn customer(value: NullableCustomerFilter) -> Self {
+        Self {
+            customer: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that needs to be matched by some customer needs.
+    pub fn some(value: CustomerNeedFilter) -> Self {
+        Self {
+            some: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that needs to be matched by all customer needs.
+    pub fn every(value: CustomerNeedFilter) -> Self {
+        Self {
+            every: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the collection length.
+    pub fn length(value: NumberComparator) -> Self {
+        Self {
+            length: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Match records matching every filter. A single filter is returned
+    /// as is, and no filters give the empty filter, which matches everything.
+    pub fn all(filters: impl IntoIterator<Item = Self>) -> Self {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => Self::default(),
+            1 => filters.remove(0),
+            _ => Self {
+                and: MaybeUndefined::Value(filters),
+                ..Default::default()
+            },
+        }
+    }
+    /// Match records matching any of the filters. A single filter is
+    /// returned as is. No filters give `None`: a disjunction of nothing
+    /// matches nothing, which no filter expresses.
+    pub fn any(filters: impl IntoIterator<Item = Self>) -> Option<Self> {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => None,
+            1 => Some(filters.remove(0)),
+            _ => Some(Self {
+                or: MaybeUndefined::Value(filters),
+                ..Default::default()
+            }),
+        }
+    }
+    /// Match records matching both this filter and `other`.
+    pub fn and(self, other: Self) -> Self {
+        Self::all([self, other])
+    }
+    /// Match records matching this filter or `other`.
+    pub fn or(self, other: Self) -> Self {
+        Self {
+            or: MaybeUndefined::Value(vec![self, other]),
+            ..Default::default()
+        }
+    }
+}
 /// Input for creating a customer need from an existing issue attachment. If the attachment already has an archived need, it will be unarchived instead.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -1282,6 +2758,102 @@ pub struct CustomerNeedFilter {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub or: MaybeUndefined<Vec<CustomerNeedFilter>>,
 }
+impl CustomerNeedFilter {
+    /// Comparator for the identifier.
+    pub fn id(value: IDComparator) -> Self {
+        Self {
+            id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the created at date.
+    pub fn created_at(value: DateComparator) -> Self {
+        Self {
+            created_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the updated at date.
+    pub fn updated_at(value: DateComparator) -> Self {
+        Self {
+            updated_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the customer need priority.
+    pub fn priority(value: NumberComparator) -> Self {
+        Self {
+            priority: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the need's project must satisfy.
+    pub fn project(value: NullableProjectFilter) -> Self {
+        Self {
+            project: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the need's issue must satisfy.
+    pub fn issue(value: NullableIssueFilter) -> Self {
+        Self {
+            issue: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the need's comment must satisfy.
+    pub fn comment(value: NullableCommentFilter) -> Self {
+        Self {
+            comment: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the need's customer must satisfy.
+    pub fn customer(value: NullableCustomerFilter) -> Self {
+        Self {
+            customer: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Match records matching every filter. A single filter is returned
+    /// as is, and no filters give the empty filter, which matches everything.
+    pub fn all(filters: impl IntoIterator<Item = Self>) -> Self {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => Self::default(),
+            1 => filters.remove(0),
+            _ => Self {
+                and: MaybeUndefined::Value(filters),
+                ..Default::default()
+            },
+        }
+    }
+    /// Match records matching any of the filters. A single filter is
+    /// returned as is. No filters give `None`: a disjunction of nothing
+    /// matches nothing, which no filter expresses.
+    pub fn any(filters: impl IntoIterator<Item = Self>) -> Option<Self> {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => None,
+            1 => Some(filters.remove(0)),
+            _ => Some(Self {
+                or: MaybeUndefined::Value(filters),
+                ..Default::default()
+            }),
+        }
+    }
+    /// Match records matching both this filter and `other`.
+    pub fn and(self, other: Self) -> Self {
+        Self::all([self, other])
+    }
+    /// Match records matching this filter or `other`.
+    pub fn or(self, other: Self) -> Self {
+        Self {
+            or: MaybeUndefined::Value(vec![self, other]),
+            ..Default::default()
+        }
+    }
+}
 /// Input for updating a customer need. Supports reassigning the customer, moving to a different issue or project, changing priority, and updating body content.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -1425,6 +2997,102 @@ pub struct CustomerStatusFilter {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub or: MaybeUndefined<Vec<CustomerStatusFilter>>,
 }
+impl CustomerStatusFilter {
+    /// Comparator for the identifier.
+    pub fn id(value: IDComparator) -> Self {
+        Self {
+            id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the created at date.
+    pub fn created_at(value: DateComparator) -> Self {
+        Self {
+            created_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the updated at date.
+    pub fn updated_at(value: DateComparator) -> Self {
+        Self {
+            updated_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the customer status name.
+    pub fn name(value: StringComparator) -> Self {
+        Self {
+            name: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the customer status description.
+    pub fn description(value: StringComparator) -> Self {
+        Self {
+            description: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the customer status position.
+    pub fn position(value: NumberComparator) -> Self {
+        Self {
+            position: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the customer status type.
+    pub fn type_(value: StringComparator) -> Self {
+        Self {
+            r#type: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the customer status color.
+    pub fn color(value: StringComparator) -> Self {
+        Self {
+            color: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Match records matching every filter. A single filter is returned
+    /// as is, and no filters give the empty filter, which matches everything.
+    pub fn all(filters: impl IntoIterator<Item = Self>) -> Self {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => Self::default(),
+            1 => filters.remove(0),
+            _ => Self {
+                and: MaybeUndefined::Value(filters),
+                ..Default::default()
+            },
+        }
+    }
+    /// Match records matching any of the filters. A single filter is
+    /// returned as is. No filters give `None`: a disjunction of nothing
+    /// matches nothing, which no filter expresses.
+    pub fn any(filters: impl IntoIterator<Item = Self>) -> Option<Self> {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => None,
+            1 => Some(filters.remove(0)),
+            _ => Some(Self {
+                or: MaybeUndefined::Value(filters),
+                ..Default::default()
+            }),
+        }
+    }
+    /// Match records matching both this filter and `other`.
+    pub fn and(self, other: Self) -> Self {
+        Self::all([self, other])
+    }
+    /// Match records matching this filter or `other`.
+    pub fn or(self, other: Self) -> Self {
+        Self {
+            or: MaybeUndefined::Value(vec![self, other]),
+            ..Default::default()
+        }
+    }
+}
 /// Customer status sorting options.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -1510,6 +3178,95 @@ pub struct CustomerTierFilter {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub or: MaybeUndefined<Vec<CustomerTierFilter>>,
 }
+impl CustomerTierFilter {
+    /// Comparator for the identifier.
+    pub fn id(value: IDComparator) -> Self {
+        Self {
+            id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the created at date.
+    pub fn created_at(value: DateComparator) -> Self {
+        Self {
+            created_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the updated at date.
+    pub fn updated_at(value: DateComparator) -> Self {
+        Self {
+            updated_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the customer tier display name.
+    pub fn display_name(value: StringComparator) -> Self {
+        Self {
+            display_name: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the customer tier description.
+    pub fn description(value: StringComparator) -> Self {
+        Self {
+            description: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the customer tier position.
+    pub fn position(value: NumberComparator) -> Self {
+        Self {
+            position: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the customer tier color.
+    pub fn color(value: StringComparator) -> Self {
+        Self {
+            color: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Match records matching every filter. A single filter is returned
+    /// as is, and no filters give the empty filter, which matches everything.
+    pub fn all(filters: impl IntoIterator<Item = Self>) -> Self {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => Self::default(),
+            1 => filters.remove(0),
+            _ => Self {
+                and: MaybeUndefined::Value(filters),
+                ..Default::default()
+            },
+        }
+    }
+    /// Match records matching any of the filters. A single filter is
+    /// returned as is. No filters give `None`: a disjunction of nothing
+    /// matches nothing, which no filter expresses.
+    pub fn any(filters: impl IntoIterator<Item = Self>) -> Option<Self> {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => None,
+            1 => Some(filters.remove(0)),
+            _ => Some(Self {
+                or: MaybeUndefined::Value(filters),
+                ..Default::default()
+            }),
+        }
+    }
+    /// Match records matching both this filter and `other`.
+    pub fn and(self, other: Self) -> Self {
+        Self::all([self, other])
+    }
+    /// Match records matching this filter or `other`.
+    pub fn or(self, other: Self) -> Self {
+        Self {
+            or: MaybeUndefined::Value(vec![self, other]),
+            ..Default::default()
+        }
+    }
+}
 /// Input for updating an existing customer tier.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -1694,6 +3451,165 @@ pub struct CycleFilter {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub or: MaybeUndefined<Vec<CycleFilter>>,
 }
+impl CycleFilter {
+    /// Comparator for the identifier.
+    pub fn id(value: IDComparator) -> Self {
+        Self {
+            id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the created at date.
+    pub fn created_at(value: DateComparator) -> Self {
+        Self {
+            created_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the updated at date.
+    pub fn updated_at(value: DateComparator) -> Self {
+        Self {
+            updated_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the cycle number.
+    pub fn number(value: NumberComparator) -> Self {
+        Self {
+            number: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the cycle name.
+    pub fn name(value: StringComparator) -> Self {
+        Self {
+            name: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the cycle start date.
+    pub fn starts_at(value: DateComparator) -> Self {
+        Self {
+            starts_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the cycle ends at date.
+    pub fn ends_at(value: DateComparator) -> Self {
+        Self {
+            ends_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the cycle completed at date.
+    pub fn completed_at(value: DateComparator) -> Self {
+        Self {
+            completed_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the filtering active cycle.
+    pub fn is_active(value: BooleanComparator) -> Self {
+        Self {
+            is_active: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for filtering for whether the cycle is currently in cooldown.
+    pub fn is_in_cooldown(value: BooleanComparator) -> Self {
+        Self {
+            is_in_cooldown: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the filtering next cycle.
+    pub fn is_next(value: BooleanComparator) -> Self {
+        Self {
+            is_next: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the filtering previous cycle.
+    pub fn is_previous(value: BooleanComparator) -> Self {
+        Self {
+            is_previous: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the filtering future cycles.
+    pub fn is_future(value: BooleanComparator) -> Self {
+        Self {
+            is_future: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the filtering past cycles.
+    pub fn is_past(value: BooleanComparator) -> Self {
+        Self {
+            is_past: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the cycles team must satisfy.
+    pub fn team(value: TeamFilter) -> Self {
+        Self {
+            team: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the cycles issues must satisfy.
+    pub fn issues(value: IssueCollectionFilter) -> Self {
+        Self {
+            issues: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the inherited cycle ID.
+    pub fn inherited_from_id(value: IDComparator) -> Self {
+        Self {
+            inherited_from_id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Match records matching every filter. A single filter is returned
+    /// as is, and no filters give the empty filter, which matches everything.
+    pub fn all(filters: impl IntoIterator<Item = Self>) -> Self {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => Self::default(),
+            1 => filters.remove(0),
+            _ => Self {
+                and: MaybeUndefined::Value(filters),
+                ..Default::default()
+            },
+        }
+    }
+    /// Match records matching any of the filters. A single filter is
+    /// returned as is. No filters give `None`: a disjunction of nothing
+    /// matches nothing, which no filter expresses.
+    pub fn any(filters: impl IntoIterator<Item = Self>) -> Option<Self> {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => None,
+            1 => Some(filters.remove(0)),
+            _ => Some(Self {
+                or: MaybeUndefined::Value(filters),
+                ..Default::default()
+            }),
+        }
+    }
+    /// Match records matching both this filter and `other`.
+    pub fn and(self, other: Self) -> Self {
+        Self::all([self, other])
+    }
+    /// Match records matching this filter or `other`.
+    pub fn or(self, other: Self) -> Self {
+        Self {
+            or: MaybeUndefined::Value(vec![self, other]),
+            ..Default::default()
+        }
+    }
+}
 /// Comparator for period when issue was added to a cycle.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -1714,6 +3630,69 @@ pub struct CyclePeriodComparator {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub null: MaybeUndefined<bool>,
 }
+impl CyclePeriodComparator {
+    /// Equals constraint.
+    pub fn eq(value: impl Into<CyclePeriod>) -> Self {
+        Self {
+            eq: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Not-equals constraint.
+    pub fn neq(value: impl Into<CyclePeriod>) -> Self {
+        Self {
+            neq: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// In-array constraint.
+    pub fn in_(values: impl IntoIterator<Item = impl Into<CyclePeriod>>) -> Self {
+        Self {
+            r#in: MaybeUndefined::Value(values.into_iter().map(Into::into).collect()),
+            ..Default::default()
+        }
+    }
+    /// Not-in-array constraint.
+    pub fn nin(values: impl IntoIterator<Item = impl Into<CyclePeriod>>) -> Self {
+        Self {
+            nin: MaybeUndefined::Value(values.into_iter().map(Into::into).collect()),
+            ..Default::default()
+        }
+    }
+    /// Null constraint. Matches any non-null values if the given value is false, otherwise it matches null values.
+    pub fn null(value: impl Into<bool>) -> Self {
+        Self {
+            null: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+}
+impl CyclePeriodComparator {
+    /// The negated comparator (`eq` ↔ `neq`, `lt` ↔ `gte`, …), or
+    /// `None` unless exactly one constraint is set: negating several
+    /// would need an `or`, which comparators don't have.
+    pub fn negate(self) -> Option<Self> {
+        if usize::from(!self.eq.is_undefined())
+            + usize::from(!self.neq.is_undefined())
+            + usize::from(!self.r#in.is_undefined())
+            + usize::from(!self.nin.is_undefined())
+            + usize::from(!self.null.is_undefined())
+            != 1
+        {
+            return None;
+        }
+        Some(Self {
+            eq: self.neq,
+            neq: self.eq,
+            r#in: self.nin,
+            nin: self.r#in,
+            null: match self.null {
+                MaybeUndefined::Value(is_null) => MaybeUndefined::Value(!is_null),
+                other => other,
+            },
+        })
+    }
+}
 /// Input for shifting all cycles from a certain cycle onwards by a certain number of days.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -1786,6 +3765,93 @@ pub struct DateComparator {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub gte: MaybeUndefined<serde_json::Value>,
 }
+impl DateComparator {
+    /// Equals constraint.
+    pub fn eq(value: impl Into<serde_json::Value>) -> Self {
+        Self {
+            eq: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Not-equals constraint.
+    pub fn neq(value: impl Into<serde_json::Value>) -> Self {
+        Self {
+            neq: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// In-array constraint.
+    pub fn in_(values: impl IntoIterator<Item = impl Into<serde_json::Value>>) -> Self {
+        Self {
+            r#in: MaybeUndefined::Value(values.into_iter().map(Into::into).collect()),
+            ..Default::default()
+        }
+    }
+    /// Not-in-array constraint.
+    pub fn nin(values: impl IntoIterator<Item = impl Into<serde_json::Value>>) -> Self {
+        Self {
+            nin: MaybeUndefined::Value(values.into_iter().map(Into::into).collect()),
+            ..Default::default()
+        }
+    }
+    /// Less-than constraint. Matches any values that are less than the given value.
+    pub fn lt(value: impl Into<serde_json::Value>) -> Self {
+        Self {
+            lt: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Less-than-or-equal constraint. Matches any values that are less than or equal to the given value.
+    pub fn lte(value: impl Into<serde_json::Value>) -> Self {
+        Self {
+            lte: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Greater-than constraint. Matches any values that are greater than the given value.
+    pub fn gt(value: impl Into<serde_json::Value>) -> Self {
+        Self {
+            gt: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Greater-than-or-equal constraint. Matches any values that are greater than or equal to the given value.
+    pub fn gte(value: impl Into<serde_json::Value>) -> Self {
+        Self {
+            gte: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+}
+impl DateComparator {
+    /// The negated comparator (`eq` ↔ `neq`, `lt` ↔ `gte`, …), or
+    /// `None` unless exactly one constraint is set: negating several
+    /// would need an `or`, which comparators don't have.
+    pub fn negate(self) -> Option<Self> {
+        if usize::from(!self.eq.is_undefined())
+            + usize::from(!self.neq.is_undefined())
+            + usize::from(!self.r#in.is_undefined())
+            + usize::from(!self.nin.is_undefined())
+            + usize::from(!self.lt.is_undefined())
+            + usize::from(!self.lte.is_undefined())
+            + usize::from(!self.gt.is_undefined())
+            + usize::from(!self.gte.is_undefined())
+            != 1
+        {
+            return None;
+        }
+        Some(Self {
+            eq: self.neq,
+            neq: self.eq,
+            r#in: self.nin,
+            nin: self.r#in,
+            lt: self.gte,
+            lte: self.gt,
+            gt: self.lte,
+            gte: self.lt,
+        })
+    }
+}
 /// Issue delegate sorting options.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -1897,6 +3963,123 @@ pub struct DocumentFilter {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub or: MaybeUndefined<Vec<DocumentFilter>>,
 }
+impl DocumentFilter {
+    /// Comparator for the identifier.
+    pub fn id(value: IDComparator) -> Self {
+        Self {
+            id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the created at date.
+    pub fn created_at(value: DateComparator) -> Self {
+        Self {
+            created_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the updated at date.
+    pub fn updated_at(value: DateComparator) -> Self {
+        Self {
+            updated_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the document title.
+    pub fn title(value: StringComparator) -> Self {
+        Self {
+            title: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the document slug ID.
+    pub fn slug_id(value: StringComparator) -> Self {
+        Self {
+            slug_id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the document's creator must satisfy.
+    pub fn creator(value: UserFilter) -> Self {
+        Self {
+            creator: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the document's project must satisfy.
+    pub fn project(value: ProjectFilter) -> Self {
+        Self {
+            project: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the document's issue must satisfy.
+    pub fn issue(value: IssueFilter) -> Self {
+        Self {
+            issue: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the document's initiative must satisfy.
+    pub fn initiative(value: InitiativeFilter) -> Self {
+        Self {
+            initiative: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the document's cycle must satisfy.
+    pub fn cycle(value: CycleFilter) -> Self {
+        Self {
+            cycle: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the document's release must satisfy.
+    pub fn release(value: ReleaseFilter) -> Self {
+        Self {
+            release: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Match records matching every filter. A single filter is returned
+    /// as is, and no filters give the empty filter, which matches everything.
+    pub fn all(filters: impl IntoIterator<Item = Self>) -> Self {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => Self::default(),
+            1 => filters.remove(0),
+            _ => Self {
+                and: MaybeUndefined::Value(filters),
+                ..Default::default()
+            },
+        }
+    }
+    /// Match records matching any of the filters. A single filter is
+    /// returned as is. No filters give `None`: a disjunction of nothing
+    /// matches nothing, which no filter expresses.
+    pub fn any(filters: impl IntoIterator<Item = Self>) -> Option<Self> {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => None,
+            1 => Some(filters.remove(0)),
+            _ => Some(Self {
+                or: MaybeUndefined::Value(filters),
+                ..Default::default()
+            }),
+        }
+    }
+    /// Match records matching both this filter and `other`.
+    pub fn and(self, other: Self) -> Self {
+        Self::all([self, other])
+    }
+    /// Match records matching this filter or `other`.
+    pub fn or(self, other: Self) -> Self {
+        Self {
+            or: MaybeUndefined::Value(vec![self, other]),
+            ..Default::default()
+        }
+    }
+}
 /// Input for updating an existing document.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -2199,6 +4382,85 @@ pub struct EstimateComparator {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub and: MaybeUndefined<Vec<NullableNumberComparator>>,
 }
+impl EstimateComparator {
+    /// Equals constraint.
+    pub fn eq(value: impl Into<f64>) -> Self {
+        Self {
+            eq: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Not-equals constraint.
+    pub fn neq(value: impl Into<f64>) -> Self {
+        Self {
+            neq: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// In-array constraint.
+    pub fn in_(values: impl IntoIterator<Item = impl Into<f64>>) -> Self {
+        Self {
+            r#in: MaybeUndefined::Value(values.into_iter().map(Into::into).collect()),
+            ..Default::default()
+        }
+    }
+    /// Not-in-array constraint.
+    pub fn nin(values: impl IntoIterator<Item = impl Into<f64>>) -> Self {
+        Self {
+            nin: MaybeUndefined::Value(values.into_iter().map(Into::into).collect()),
+            ..Default::default()
+        }
+    }
+    /// Null constraint. Matches any non-null values if the given value is false, otherwise it matches null values.
+    pub fn null(value: impl Into<bool>) -> Self {
+        Self {
+            null: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Less-than constraint. Matches any values that are less than the given value.
+    pub fn lt(value: impl Into<f64>) -> Self {
+        Self {
+            lt: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Less-than-or-equal constraint. Matches any values that are less than or equal to the given value.
+    pub fn lte(value: impl Into<f64>) -> Self {
+        Self {
+            lte: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Greater-than constraint. Matches any values that are greater than the given value.
+    pub fn gt(value: impl Into<f64>) -> Self {
+        Self {
+            gt: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Greater-than-or-equal constraint. Matches any values that are greater than or equal to the given value.
+    pub fn gte(value: impl Into<f64>) -> Self {
+        Self {
+            gte: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Compound filters, all of which need to be matched by the estimate.
+    pub fn or(values: impl IntoIterator<Item = impl Into<NullableNumberComparator>>) -> Self {
+        Self {
+            or: MaybeUndefined::Value(values.into_iter().map(Into::into).collect()),
+            ..Default::default()
+        }
+    }
+    /// Compound filters, one of which need to be matched by the estimate.
+    pub fn and(values: impl IntoIterator<Item = impl Into<NullableNumberComparator>>) -> Self {
+        Self {
+            and: MaybeUndefined::Value(values.into_iter().map(Into::into).collect()),
+            ..Default::default()
+        }
+    }
+}
 /// Issue estimate sorting options.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -2349,6 +4611,109 @@ pub struct FeedItemFilter {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub or: MaybeUndefined<Vec<FeedItemFilter>>,
 }
+impl FeedItemFilter {
+    /// Comparator for the identifier.
+    pub fn id(value: IDComparator) -> Self {
+        Self {
+            id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the created at date.
+    pub fn created_at(value: DateComparator) -> Self {
+        Self {
+            created_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the updated at date.
+    pub fn updated_at(value: DateComparator) -> Self {
+        Self {
+            updated_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the feed item author must satisfy.
+    pub fn author(value: UserFilter) -> Self {
+        Self {
+            author: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the update type: initiative, project, team
+    pub fn update_type(value: StringComparator) -> Self {
+        Self {
+            update_type: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the project or initiative update health: onTrack, atRisk, offTrack
+    pub fn update_health(value: StringComparator) -> Self {
+        Self {
+            update_health: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the feed item's project update must satisfy.
+    pub fn project_update(value: ProjectUpdateFilter) -> Self {
+        Self {
+            project_update: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the related feed item initiatives must satisfy.
+    pub fn related_initiatives(value: InitiativeCollectionFilter) -> Self {
+        Self {
+            related_initiatives: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the related feed item team must satisfy.
+    pub fn related_teams(value: TeamCollectionFilter) -> Self {
+        Self {
+            related_teams: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Match records matching every filter. A single filter is returned
+    /// as is, and no filters give the empty filter, which matches everything.
+    pub fn all(filters: impl IntoIterator<Item = Self>) -> Self {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => Self::default(),
+            1 => filters.remove(0),
+            _ => Self {
+                and: MaybeUndefined::Value(filters),
+                ..Default::default()
+            },
+        }
+    }
+    /// Match records matching any of the filters. A single filter is
+    /// returned as is. No filters give `None`: a disjunction of nothing
+    /// matches nothing, which no filter expresses.
+    pub fn any(filters: impl IntoIterator<Item = Self>) -> Option<Self> {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => None,
+            1 => Some(filters.remove(0)),
+            _ => Some(Self {
+                or: MaybeUndefined::Value(filters),
+                ..Default::default()
+            }),
+        }
+    }
+    /// Match records matching both this filter and `other`.
+    pub fn and(self, other: Self) -> Self {
+        Self::all([self, other])
+    }
+    /// Match records matching this filter or `other`.
+    pub fn or(self, other: Self) -> Self {
+        Self {
+            or: MaybeUndefined::Value(vec![self, other]),
+            ..Default::default()
+        }
+    }
+}
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct FrontSettingsInput {
@@ -2632,6 +4997,57 @@ pub struct IDComparator {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub nin: MaybeUndefined<Vec<String>>,
 }
+impl IDComparator {
+    /// Equals constraint.
+    pub fn eq(value: impl Into<String>) -> Self {
+        Self {
+            eq: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Not-equals constraint.
+    pub fn neq(value: impl Into<String>) -> Self {
+        Self {
+            neq: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// In-array constraint.
+    pub fn in_(values: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self {
+            r#in: MaybeUndefined::Value(values.into_iter().map(Into::into).collect()),
+            ..Default::default()
+        }
+    }
+    /// Not-in-array constraint.
+    pub fn nin(values: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self {
+            nin: MaybeUndefined::Value(values.into_iter().map(Into::into).collect()),
+            ..Default::default()
+        }
+    }
+}
+impl IDComparator {
+    /// The negated comparator (`eq` ↔ `neq`, `lt` ↔ `gte`, …), or
+    /// `None` unless exactly one constraint is set: negating several
+    /// would need an `or`, which comparators don't have.
+    pub fn negate(self) -> Option<Self> {
+        if usize::from(!self.eq.is_undefined())
+            + usize::from(!self.neq.is_undefined())
+            + usize::from(!self.r#in.is_undefined())
+            + usize::from(!self.nin.is_undefined())
+            != 1
+        {
+            return None;
+        }
+        Some(Self {
+            eq: self.neq,
+            neq: self.eq,
+            r#in: self.nin,
+            nin: self.r#in,
+        })
+    }
+}
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct InheritanceEntityMapping {
@@ -2712,18 +5128,198 @@ pub struct InitiativeCollectionFilter {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub length: MaybeUndefined<NumberComparator>,
 }
-/// The properties of the initiative to create.
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct InitiativeCreateInput {
-    /// The identifier in UUID v4 format. If none is provided, the backend will generate one.
-    #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
-    pub id: MaybeUndefined<String>,
-    /// The name of the initiative.
-    pub name: String,
-    /// The description of the initiative.
-    #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
-    pub description: MaybeUndefined<String>,
+impl InitiativeCollectionFilter {
+    /// Comparator for the identifier.
+    pub fn id(value: IDComparator) -> Self {
+        Self {
+            id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the created at date.
+    pub fn created_at(value: DateComparator) -> Self {
+        Self {
+            created_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the updated at date.
+    pub fn updated_at(value: DateComparator) -> Self {
+        Self {
+            updated_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the initiative name.
+    pub fn name(value: StringComparator) -> Self {
+        Self {
+            name: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the initiative slug ID.
+    pub fn slug_id(value: StringComparator) -> Self {
+        Self {
+            slug_id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the initiative creator must satisfy.
+    pub fn creator(value: NullableUserFilter) -> Self {
+        Self {
+            creator: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the initiative status: Planned, Active, Completed
+    pub fn status(value: StringComparator) -> Self {
+        Self {
+            status: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the initiative teams must satisfy.
+    pub fn teams(value: TeamCollectionFilter) -> Self {
+        Self {
+            teams: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the initiative owner must satisfy.
+    pub fn owner(value: NullableUserFilter) -> Self {
+        Self {
+            owner: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the initiative target date.
+    pub fn target_date(value: NullableDateComparator) -> Self {
+        Self {
+            target_date: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the initiative started at date.
+    pub fn started_at(value: NullableDateComparator) -> Self {
+        Self {
+            started_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the initiative completed at date.
+    pub fn completed_at(value: NullableDateComparator) -> Self {
+        Self {
+            completed_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the initiative health: onTrack, atRisk, offTrack
+    pub fn health(value: StringComparator) -> Self {
+        Self {
+            health: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the initiative health (with age): onTrack, atRisk, offTrack, outdated, noUpdate
+    pub fn health_with_age(value: StringComparator) -> Self {
+        Self {
+            health_with_age: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the initiative activity type.
+    pub fn activity_type(value: StringComparator) -> Self {
+        Self {
+            activity_type: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the initiative must be an ancestor of.
+    pub fn ancestors(value: InitiativeCollectionFilter) -> Self {
+        Self {
+            ancestors: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the initiative updates must satisfy.
+    pub fn initiative_updates(value: InitiativeUpdatesCollectionFilter) -> Self {
+        Self {
+            initiative_updates: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that needs to be matched by some initiatives.
+    pub fn some(value: InitiativeFilter) -> Self {
+        Self {
+            some: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that needs to be matched by all initiatives.
+    pub fn every(value: InitiativeFilter) -> Self {
+        Self {
+            every: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the collection length.
+    pub fn length(value: NumberComparator) -> Self {
+        Self {
+            length: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Match records matching every filter. A single filter is returned
+    /// as is, and no filters give the empty filter, which matches everything.
+    pub fn all(filters: impl IntoIterator<Item = Self>) -> Self {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => Self::default(),
+            1 => filters.remove(0),
+            _ => Self {
+                and: MaybeUndefined::Value(filters),
+                ..Default::default()
+            },
+        }
+    }
+    /// Match records matching any of the filters. A single filter is
+    /// returned as is. No filters give `None`: a disjunction of nothing
+    /// matches nothing, which no filter expresses.
+    pub fn any(filters: impl IntoIterator<Item = Self>) -> Option<Self> {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => None,
+            1 => Some(filters.remove(0)),
+            _ => Some(Self {
+                or: MaybeUndefined::Value(filters),
+                ..Default::default()
+            }),
+        }
+    }
+    /// Match records matching both this filter and `other`.
+    pub fn and(self, other: Self) -> Self {
+        Self::all([self, other])
+    }
+    /// Match records matching this filter or `other`.
+    pub fn or(self, other: Self) -> Self {
+        Self {
+            or: MaybeUndefined::Value(vec![self, other]),
+            ..Default::default()
+        }
+    }
+}
+/// The properties of the initiative to create.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InitiativeCreateInput {
+    /// The identifier in UUID v4 format. If none is provided, the backend will generate one.
+    #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
+    pub id: MaybeUndefined<String>,
+    /// The name of the initiative.
+    pub name: String,
+    /// The description of the initiative.
+    #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
+    pub description: MaybeUndefined<String>,
     /// The owner of the initiative.
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub owner_id: MaybeUndefined<String>,
@@ -2822,6 +5418,165 @@ pub struct InitiativeFilter {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub or: MaybeUndefined<Vec<InitiativeFilter>>,
 }
+impl InitiativeFilter {
+    /// Comparator for the identifier.
+    pub fn id(value: IDComparator) -> Self {
+        Self {
+            id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the created at date.
+    pub fn created_at(value: DateComparator) -> Self {
+        Self {
+            created_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the updated at date.
+    pub fn updated_at(value: DateComparator) -> Self {
+        Self {
+            updated_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the initiative name.
+    pub fn name(value: StringComparator) -> Self {
+        Self {
+            name: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the initiative slug ID.
+    pub fn slug_id(value: StringComparator) -> Self {
+        Self {
+            slug_id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the initiative creator must satisfy.
+    pub fn creator(value: NullableUserFilter) -> Self {
+        Self {
+            creator: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the initiative status: Planned, Active, Completed
+    pub fn status(value: StringComparator) -> Self {
+        Self {
+            status: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the initiative teams must satisfy.
+    pub fn teams(value: TeamCollectionFilter) -> Self {
+        Self {
+            teams: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the initiative owner must satisfy.
+    pub fn owner(value: NullableUserFilter) -> Self {
+        Self {
+            owner: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the initiative target date.
+    pub fn target_date(value: NullableDateComparator) -> Self {
+        Self {
+            target_date: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the initiative started at date.
+    pub fn started_at(value: NullableDateComparator) -> Self {
+        Self {
+            started_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the initiative completed at date.
+    pub fn completed_at(value: NullableDateComparator) -> Self {
+        Self {
+            completed_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the initiative health: onTrack, atRisk, offTrack
+    pub fn health(value: StringComparator) -> Self {
+        Self {
+            health: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the initiative health (with age): onTrack, atRisk, offTrack, outdated, noUpdate
+    pub fn health_with_age(value: StringComparator) -> Self {
+        Self {
+            health_with_age: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the initiative activity type.
+    pub fn activity_type(value: StringComparator) -> Self {
+        Self {
+            activity_type: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the initiative must be an ancestor of.
+    pub fn ancestors(value: InitiativeCollectionFilter) -> Self {
+        Self {
+            ancestors: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the initiative updates must satisfy.
+    pub fn initiative_updates(value: InitiativeUpdatesCollectionFilter) -> Self {
+        Self {
+            initiative_updates: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Match records matching every filter. A single filter is returned
+    /// as is, and no filters give the empty filter, which matches everything.
+    pub fn all(filters: impl IntoIterator<Item = Self>) -> Self {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => Self::default(),
+            1 => filters.remove(0),
+            _ => Self {
+                and: MaybeUndefined::Value(filters),
+                ..Default::default()
+            },
+        }
+    }
+    /// Match records matching any of the filters. A single filter is
+    /// returned as is. No filters give `None`: a disjunction of nothing
+    /// matches nothing, which no filter expresses.
+    pub fn any(filters: impl IntoIterator<Item = Self>) -> Option<Self> {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => None,
+            1 => Some(filters.remove(0)),
+            _ => Some(Self {
+                or: MaybeUndefined::Value(filters),
+                ..Default::default()
+            }),
+        }
+    }
+    /// Match records matching both this filter and `other`.
+    pub fn and(self, other: Self) -> Self {
+        Self::all([self, other])
+    }
+    /// Match records matching this filter or `other`.
+    pub fn or(self, other: Self) -> Self {
+        Self {
+            or: MaybeUndefined::Value(vec![self, other]),
+            ..Default::default()
+        }
+    }
+}
 /// Initiative health sorting options.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -3014,6 +5769,88 @@ pub struct InitiativeUpdateFilter {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub or: MaybeUndefined<Vec<InitiativeUpdateFilter>>,
 }
+impl InitiativeUpdateFilter {
+    /// Comparator for the identifier.
+    pub fn id(value: IDComparator) -> Self {
+        Self {
+            id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the created at date.
+    pub fn created_at(value: DateComparator) -> Self {
+        Self {
+            created_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the updated at date.
+    pub fn updated_at(value: DateComparator) -> Self {
+        Self {
+            updated_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the initiative update creator must satisfy.
+    pub fn user(value: UserFilter) -> Self {
+        Self {
+            user: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the initiative update initiative must satisfy.
+    pub fn initiative(value: InitiativeFilter) -> Self {
+        Self {
+            initiative: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the initiative updates reactions must satisfy.
+    pub fn reactions(value: ReactionCollectionFilter) -> Self {
+        Self {
+            reactions: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Match records matching every filter. A single filter is returned
+    /// as is, and no filters give the empty filter, which matches everything.
+    pub fn all(filters: impl IntoIterator<Item = Self>) -> Self {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => Self::default(),
+            1 => filters.remove(0),
+            _ => Self {
+                and: MaybeUndefined::Value(filters),
+                ..Default::default()
+            },
+        }
+    }
+    /// Match records matching any of the filters. A single filter is
+    /// returned as is. No filters give `None`: a disjunction of nothing
+    /// matches nothing, which no filter expresses.
+    pub fn any(filters: impl IntoIterator<Item = Self>) -> Option<Self> {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => None,
+            1 => Some(filters.remove(0)),
+            _ => Some(Self {
+                or: MaybeUndefined::Value(filters),
+                ..Default::default()
+            }),
+        }
+    }
+    /// Match records matching both this filter and `other`.
+    pub fn and(self, other: Self) -> Self {
+        Self::all([self, other])
+    }
+    /// Match records matching this filter or `other`.
+    pub fn or(self, other: Self) -> Self {
+        Self {
+            or: MaybeUndefined::Value(vec![self, other]),
+            ..Default::default()
+        }
+    }
+}
 /// The properties of the initiative to update.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -3124,6 +5961,88 @@ pub struct InitiativeUpdatesCollectionFilter {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub length: MaybeUndefined<NumberComparator>,
 }
+impl InitiativeUpdatesCollectionFilter {
+    /// Comparator for the identifier.
+    pub fn id(value: IDComparator) -> Self {
+        Self {
+            id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the created at date.
+    pub fn created_at(value: DateComparator) -> Self {
+        Self {
+            created_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the updated at date.
+    pub fn updated_at(value: DateComparator) -> Self {
+        Self {
+            updated_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that needs to be matched by some initiative updates.
+    pub fn some(value: InitiativeUpdatesFilter) -> Self {
+        Self {
+            some: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that needs to be matched by all initiative updates.
+    pub fn every(value: InitiativeUpdatesFilter) -> Self {
+        Self {
+            every: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the collection length.
+    pub fn length(value: NumberComparator) -> Self {
+        Self {
+            length: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Match records matching every filter. A single filter is returned
+    /// as is, and no filters give the empty filter, which matches everything.
+    pub fn all(filters: impl IntoIterator<Item = Self>) -> Self {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => Self::default(),
+            1 => filters.remove(0),
+            _ => Self {
+                and: MaybeUndefined::Value(filters),
+                ..Default::default()
+            },
+        }
+    }
+    /// Match records matching any of the filters. A single filter is
+    /// returned as is. No filters give `None`: a disjunction of nothing
+    /// matches nothing, which no filter expresses.
+    pub fn any(filters: impl IntoIterator<Item = Self>) -> Option<Self> {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => None,
+            1 => Some(filters.remove(0)),
+            _ => Some(Self {
+                or: MaybeUndefined::Value(filters),
+                ..Default::default()
+            }),
+        }
+    }
+    /// Match records matching both this filter and `other`.
+    pub fn and(self, other: Self) -> Self {
+        Self::all([self, other])
+    }
+    /// Match records matching this filter or `other`.
+    pub fn or(self, other: Self) -> Self {
+        Self {
+            or: MaybeUndefined::Value(vec![self, other]),
+            ..Default::default()
+        }
+    }
+}
 /// Options for filtering initiatives by initiative updates.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -3144,6 +6063,67 @@ pub struct InitiativeUpdatesFilter {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub or: MaybeUndefined<Vec<InitiativeUpdatesFilter>>,
 }
+impl InitiativeUpdatesFilter {
+    /// Comparator for the identifier.
+    pub fn id(value: IDComparator) -> Self {
+        Self {
+            id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the created at date.
+    pub fn created_at(value: DateComparator) -> Self {
+        Self {
+            created_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the updated at date.
+    pub fn updated_at(value: DateComparator) -> Self {
+        Self {
+            updated_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Match records matching every filter. A single filter is returned
+    /// as is, and no filters give the empty filter, which matches everything.
+    pub fn all(filters: impl IntoIterator<Item = Self>) -> Self {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => Self::default(),
+            1 => filters.remove(0),
+            _ => Self {
+                and: MaybeUndefined::Value(filters),
+                ..Default::default()
+            },
+        }
+    }
+    /// Match records matching any of the filters. A single filter is
+    /// returned as is. No filters give `None`: a disjunction of nothing
+    /// matches nothing, which no filter expresses.
+    pub fn any(filters: impl IntoIterator<Item = Self>) -> Option<Self> {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => None,
+            1 => Some(filters.remove(0)),
+            _ => Some(Self {
+                or: MaybeUndefined::Value(filters),
+                ..Default::default()
+            }),
+        }
+    }
+    /// Match records matching both this filter and `other`.
+    pub fn and(self, other: Self) -> Self {
+        Self::all([self, other])
+    }
+    /// Match records matching this filter or `other`.
+    pub fn or(self, other: Self) -> Self {
+        Self {
+            or: MaybeUndefined::Value(vec![self, other]),
+            ..Default::default()
+        }
+    }
+}
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct IntegrationCustomerDataAttributesRefreshInput {
@@ -3594,6 +6574,515 @@ pub struct IssueCollectionFilter {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub length: MaybeUndefined<NumberComparator>,
 }
+impl IssueCollectionFilter {
+    /// Comparator for the identifier.
+    pub fn id(value: IssueIDComparator) -> Self {
+        Self {
+            id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the created at date.
+    pub fn created_at(value: DateComparator) -> Self {
+        Self {
+            created_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the updated at date.
+    pub fn updated_at(value: DateComparator) -> Self {
+        Self {
+            updated_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the issues number.
+    pub fn number(value: NumberComparator) -> Self {
+        Self {
+            number: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the issues title.
+    pub fn title(value: StringComparator) -> Self {
+        Self {
+            title: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the issues description.
+    pub fn description(value: NullableStringComparator) -> Self {
+        Self {
+            description: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the issues priority. 0 = No priority, 1 = Urgent, 2 = High, 3 = Medium, 4 = Low.
+    pub fn priority(value: NullableNumberComparator) -> Self {
+        Self {
+            priority: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the issues estimate.
+    pub fn estimate(value: EstimateComparator) -> Self {
+        Self {
+            estimate: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the issues started at date.
+    pub fn started_at(value: NullableDateComparator) -> Self {
+        Self {
+            started_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the issues triaged at date.
+    pub fn triaged_at(value: NullableDateComparator) -> Self {
+        Self {
+            triaged_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the issues completed at date.
+    pub fn completed_at(value: NullableDateComparator) -> Self {
+        Self {
+            completed_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the issues canceled at date.
+    pub fn canceled_at(value: NullableDateComparator) -> Self {
+        Self {
+            canceled_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the issue's SLA breach date.
+    pub fn sla_breaches_at(value: NullableDateComparator) -> Self {
+        Self {
+            sla_breaches_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the issues archived at date.
+    pub fn archived_at(value: NullableDateComparator) -> Self {
+        Self {
+            archived_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the issues auto closed at date.
+    pub fn auto_closed_at(value: NullableDateComparator) -> Self {
+        Self {
+            auto_closed_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the issues auto archived at date.
+    pub fn auto_archived_at(value: NullableDateComparator) -> Self {
+        Self {
+            auto_archived_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the issues added to cycle at date.
+    pub fn added_to_cycle_at(value: NullableDateComparator) -> Self {
+        Self {
+            added_to_cycle_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the period when issue was added to a cycle.
+    pub fn added_to_cycle_period(value: CyclePeriodComparator) -> Self {
+        Self {
+            added_to_cycle_period: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the issues due date.
+    pub fn due_date(value: NullableTimelessDateComparator) -> Self {
+        Self {
+            due_date: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// `Internal` Comparator for the issue's accumulatedStateUpdatedAt date.
+    pub fn accumulated_state_updated_at(value: NullableDateComparator) -> Self {
+        Self {
+            accumulated_state_updated_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the issues snoozed until date.
+    pub fn snoozed_until_at(value: NullableDateComparator) -> Self {
+        Self {
+            snoozed_until_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the issues assignee must satisfy.
+    pub fn assignee(value: NullableUserFilter) -> Self {
+        Self {
+            assignee: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the issue's delegated agent must satisfy.
+    pub fn delegate(value: NullableUserFilter) -> Self {
+        Self {
+            delegate: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the last applied template must satisfy.
+    pub fn last_applied_template(value: NullableTemplateFilter) -> Self {
+        Self {
+            last_applied_template: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// `ALPHA` Filters that the recurring issue template must satisfy.
+    pub fn recurring_issue_template(value: NullableTemplateFilter) -> Self {
+        Self {
+            recurring_issue_template: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the source must satisfy.
+    pub fn source_metadata(value: SourceMetadataComparator) -> Self {
+        Self {
+            source_metadata: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the issues creator must satisfy.
+    pub fn creator(value: NullableUserFilter) -> Self {
+        Self {
+            creator: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the issue parent must satisfy.
+    pub fn parent(value: NullableIssueFilter) -> Self {
+        Self {
+            parent: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the issues snoozer must satisfy.
+    pub fn snoozed_by(value: NullableUserFilter) -> Self {
+        Self {
+            snoozed_by: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that issue labels must satisfy.
+    pub fn labels(value: IssueLabelCollectionFilter) -> Self {
+        Self {
+            labels: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that issue subscribers must satisfy.
+    pub fn subscribers(value: UserCollectionFilter) -> Self {
+        Self {
+            subscribers: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Comparator for filtering issues which have been shared with users outside of the team.
+    pub fn has_shared_users(value: RelationExistsComparator) -> Self {
+        Self {
+            has_shared_users: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that users the issue has been shared with must satisfy.
+    pub fn shared_with(value: UserCollectionFilter) -> Self {
+        Self {
+            shared_with: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the issues team must satisfy.
+    pub fn team(value: TeamFilter) -> Self {
+        Self {
+            team: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the issues project milestone must satisfy.
+    pub fn project_milestone(value: NullableProjectMilestoneFilter) -> Self {
+        Self {
+            project_milestone: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the issues comments must satisfy.
+    pub fn comments(value: CommentCollectionFilter) -> Self {
+        Self {
+            comments: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the issue's activities must satisfy.
+    pub fn activity(value: ActivityCollectionFilter) -> Self {
+        Self {
+            activity: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// `Internal` Filters that the issue's suggestions must satisfy.
+    pub fn suggestions(value: IssueSuggestionCollectionFilter) -> Self {
+        Self {
+            suggestions: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the issues cycle must satisfy.
+    pub fn cycle(value: NullableCycleFilter) -> Self {
+        Self {
+            cycle: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the issues project must satisfy.
+    pub fn project(value: NullableProjectFilter) -> Self {
+        Self {
+            project: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the issues state must satisfy.
+    pub fn state(value: WorkflowStateFilter) -> Self {
+        Self {
+            state: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the child issues must satisfy.
+    pub fn children(value: IssueCollectionFilter) -> Self {
+        Self {
+            children: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the issues attachments must satisfy.
+    pub fn attachments(value: AttachmentCollectionFilter) -> Self {
+        Self {
+            attachments: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// `Internal` Comparator for the issues content.
+    pub fn searchable_content(value: ContentComparator) -> Self {
+        Self {
+            searchable_content: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for filtering issues with relations.
+    pub fn has_related_relations(value: RelationExistsComparator) -> Self {
+        Self {
+            has_related_relations: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for filtering issues which are duplicates.
+    pub fn has_duplicate_relations(value: RelationExistsComparator) -> Self {
+        Self {
+            has_duplicate_relations: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for filtering issues which are blocked.
+    pub fn has_blocked_by_relations(value: RelationExistsComparator) -> Self {
+        Self {
+            has_blocked_by_relations: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for filtering issues which are blocking.
+    pub fn has_blocking_relations(value: RelationExistsComparator) -> Self {
+        Self {
+            has_blocking_relations: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// `Internal` Comparator for filtering issues which have suggested related issues.
+    pub fn has_suggested_related_issues(value: RelationExistsComparator) -> Self {
+        Self {
+            has_suggested_related_issues: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// `Internal` Comparator for filtering issues which have suggested similar issues.
+    pub fn has_suggested_similar_issues(value: RelationExistsComparator) -> Self {
+        Self {
+            has_suggested_similar_issues: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// `Internal` Comparator for filtering issues which have suggested assignees.
+    pub fn has_suggested_assignees(value: RelationExistsComparator) -> Self {
+        Self {
+            has_suggested_assignees: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// `Internal` Comparator for filtering issues which have suggested projects.
+    pub fn has_suggested_projects(value: RelationExistsComparator) -> Self {
+        Self {
+            has_suggested_projects: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// `Internal` Comparator for filtering issues which have suggested labels.
+    pub fn has_suggested_labels(value: RelationExistsComparator) -> Self {
+        Self {
+            has_suggested_labels: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// `Internal` Comparator for filtering issues which have suggested teams.
+    pub fn has_suggested_teams(value: RelationExistsComparator) -> Self {
+        Self {
+            has_suggested_teams: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the issues sla status.
+    pub fn sla_status(value: SlaStatusComparator) -> Self {
+        Self {
+            sla_status: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the issues reactions must satisfy.
+    pub fn reactions(value: ReactionCollectionFilter) -> Self {
+        Self {
+            reactions: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the issue's customer needs must satisfy.
+    pub fn needs(value: CustomerNeedCollectionFilter) -> Self {
+        Self {
+            needs: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// `ALPHA` Filters that the issue's releases must satisfy.
+    pub fn releases(value: ReleaseCollectionFilter) -> Self {
+        Self {
+            releases: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Count of customers
+    pub fn customer_count(value: NumberComparator) -> Self {
+        Self {
+            customer_count: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Count of important customers
+    pub fn customer_important_count(value: NumberComparator) -> Self {
+        Self {
+            customer_important_count: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// `Internal` Lead time (created -> completed) comparator.
+    pub fn lead_time(value: NullableDurationComparator) -> Self {
+        Self {
+            lead_time: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// `Internal` Cycle time (started -> completed) comparator.
+    pub fn cycle_time(value: NullableDurationComparator) -> Self {
+        Self {
+            cycle_time: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// `Internal` Age (created -> now) comparator, defined if the issue is still open.
+    pub fn age_time(value: NullableDurationComparator) -> Self {
+        Self {
+            age_time: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// `Internal` Triage time (entered triaged -> triaged) comparator.
+    pub fn triage_time(value: NullableDurationComparator) -> Self {
+        Self {
+            triage_time: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that needs to be matched by some issues.
+    pub fn some(value: IssueFilter) -> Self {
+        Self {
+            some: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that needs to be matched by all issues.
+    pub fn every(value: IssueFilter) -> Self {
+        Self {
+            every: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the collection length.
+    pub fn length(value: NumberComparator) -> Self {
+        Self {
+            length: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Match records matching every filter. A single filter is returned
+    /// as is, and no filters give the empty filter, which matches everything.
+    pub fn all(filters: impl IntoIterator<Item = Self>) -> Self {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => Self::default(),
+            1 => filters.remove(0),
+            _ => Self {
+                and: MaybeUndefined::Value(filters),
+                ..Default::default()
+            },
+        }
+    }
+    /// Match records matching any of the filters. A single filter is
+    /// returned as is. No filters give `None`: a disjunction of nothing
+    /// matches nothing, which no filter expresses.
+    pub fn any(filters: impl IntoIterator<Item = Self>) -> Option<Self> {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => None,
+            1 => Some(filters.remove(0)),
+            _ => Some(Self {
+                or: MaybeUndefined::Value(filters),
+                ..Default::default()
+            }),
+        }
+    }
+    /// Match records matching both this filter and `other`.
+    pub fn and(self, other: Self) -> Self {
+        Self::all([self, other])
+    }
+    /// Match records matching this filter or `other`.
+    pub fn or(self, other: Self) -> Self {
+        Self {
+            or: MaybeUndefined::Value(vec![self, other]),
+            ..Default::default()
+        }
+    }
+}
 /// Input for creating a new issue. At minimum, a team must be specified. A title is required unless a template is provided. All other fields are optional and will use defaults from the team or template if not specified.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -3909,6 +7398,494 @@ pub struct IssueFilter {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub or: MaybeUndefined<Vec<IssueFilter>>,
 }
+impl IssueFilter {
+    /// Comparator for the identifier.
+    pub fn id(value: IssueIDComparator) -> Self {
+        Self {
+            id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the created at date.
+    pub fn created_at(value: DateComparator) -> Self {
+        Self {
+            created_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the updated at date.
+    pub fn updated_at(value: DateComparator) -> Self {
+        Self {
+            updated_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the issues number.
+    pub fn number(value: NumberComparator) -> Self {
+        Self {
+            number: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the issues title.
+    pub fn title(value: StringComparator) -> Self {
+        Self {
+            title: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the issues description.
+    pub fn description(value: NullableStringComparator) -> Self {
+        Self {
+            description: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the issues priority. 0 = No priority, 1 = Urgent, 2 = High, 3 = Medium, 4 = Low.
+    pub fn priority(value: NullableNumberComparator) -> Self {
+        Self {
+            priority: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the issues estimate.
+    pub fn estimate(value: EstimateComparator) -> Self {
+        Self {
+            estimate: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the issues started at date.
+    pub fn started_at(value: NullableDateComparator) -> Self {
+        Self {
+            started_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the issues triaged at date.
+    pub fn triaged_at(value: NullableDateComparator) -> Self {
+        Self {
+            triaged_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the issues completed at date.
+    pub fn completed_at(value: NullableDateComparator) -> Self {
+        Self {
+            completed_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the issues canceled at date.
+    pub fn canceled_at(value: NullableDateComparator) -> Self {
+        Self {
+            canceled_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the issue's SLA breach date.
+    pub fn sla_breaches_at(value: NullableDateComparator) -> Self {
+        Self {
+            sla_breaches_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the issues archived at date.
+    pub fn archived_at(value: NullableDateComparator) -> Self {
+        Self {
+            archived_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the issues auto closed at date.
+    pub fn auto_closed_at(value: NullableDateComparator) -> Self {
+        Self {
+            auto_closed_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the issues auto archived at date.
+    pub fn auto_archived_at(value: NullableDateComparator) -> Self {
+        Self {
+            auto_archived_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the issues added to cycle at date.
+    pub fn added_to_cycle_at(value: NullableDateComparator) -> Self {
+        Self {
+            added_to_cycle_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the period when issue was added to a cycle.
+    pub fn added_to_cycle_period(value: CyclePeriodComparator) -> Self {
+        Self {
+            added_to_cycle_period: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the issues due date.
+    pub fn due_date(value: NullableTimelessDateComparator) -> Self {
+        Self {
+            due_date: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// `Internal` Comparator for the issue's accumulatedStateUpdatedAt date.
+    pub fn accumulated_state_updated_at(value: NullableDateComparator) -> Self {
+        Self {
+            accumulated_state_updated_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the issues snoozed until date.
+    pub fn snoozed_until_at(value: NullableDateComparator) -> Self {
+        Self {
+            snoozed_until_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the issues assignee must satisfy.
+    pub fn assignee(value: NullableUserFilter) -> Self {
+        Self {
+            assignee: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the issue's delegated agent must satisfy.
+    pub fn delegate(value: NullableUserFilter) -> Self {
+        Self {
+            delegate: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the last applied template must satisfy.
+    pub fn last_applied_template(value: NullableTemplateFilter) -> Self {
+        Self {
+            last_applied_template: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// `ALPHA` Filters that the recurring issue template must satisfy.
+    pub fn recurring_issue_template(value: NullableTemplateFilter) -> Self {
+        Self {
+            recurring_issue_template: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the source must satisfy.
+    pub fn source_metadata(value: SourceMetadataComparator) -> Self {
+        Self {
+            source_metadata: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the issues creator must satisfy.
+    pub fn creator(value: NullableUserFilter) -> Self {
+        Self {
+            creator: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the issue parent must satisfy.
+    pub fn parent(value: NullableIssueFilter) -> Self {
+        Self {
+            parent: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the issues snoozer must satisfy.
+    pub fn snoozed_by(value: NullableUserFilter) -> Self {
+        Self {
+            snoozed_by: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that issue labels must satisfy.
+    pub fn labels(value: IssueLabelCollectionFilter) -> Self {
+        Self {
+            labels: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that issue subscribers must satisfy.
+    pub fn subscribers(value: UserCollectionFilter) -> Self {
+        Self {
+            subscribers: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Comparator for filtering issues which have been shared with users outside of the team.
+    pub fn has_shared_users(value: RelationExistsComparator) -> Self {
+        Self {
+            has_shared_users: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that users the issue has been shared with must satisfy.
+    pub fn shared_with(value: UserCollectionFilter) -> Self {
+        Self {
+            shared_with: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the issues team must satisfy.
+    pub fn team(value: TeamFilter) -> Self {
+        Self {
+            team: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the issues project milestone must satisfy.
+    pub fn project_milestone(value: NullableProjectMilestoneFilter) -> Self {
+        Self {
+            project_milestone: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the issues comments must satisfy.
+    pub fn comments(value: CommentCollectionFilter) -> Self {
+        Self {
+            comments: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the issue's activities must satisfy.
+    pub fn activity(value: ActivityCollectionFilter) -> Self {
+        Self {
+            activity: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// `Internal` Filters that the issue's suggestions must satisfy.
+    pub fn suggestions(value: IssueSuggestionCollectionFilter) -> Self {
+        Self {
+            suggestions: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the issues cycle must satisfy.
+    pub fn cycle(value: NullableCycleFilter) -> Self {
+        Self {
+            cycle: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the issues project must satisfy.
+    pub fn project(value: NullableProjectFilter) -> Self {
+        Self {
+            project: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the issues state must satisfy.
+    pub fn state(value: WorkflowStateFilter) -> Self {
+        Self {
+            state: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the child issues must satisfy.
+    pub fn children(value: IssueCollectionFilter) -> Self {
+        Self {
+            children: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the issues attachments must satisfy.
+    pub fn attachments(value: AttachmentCollectionFilter) -> Self {
+        Self {
+            attachments: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// `Internal` Comparator for the issues content.
+    pub fn searchable_content(value: ContentComparator) -> Self {
+        Self {
+            searchable_content: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for filtering issues with relations.
+    pub fn has_related_relations(value: RelationExistsComparator) -> Self {
+        Self {
+            has_related_relations: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for filtering issues which are duplicates.
+    pub fn has_duplicate_relations(value: RelationExistsComparator) -> Self {
+        Self {
+            has_duplicate_relations: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for filtering issues which are blocked.
+    pub fn has_blocked_by_relations(value: RelationExistsComparator) -> Self {
+        Self {
+            has_blocked_by_relations: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for filtering issues which are blocking.
+    pub fn has_blocking_relations(value: RelationExistsComparator) -> Self {
+        Self {
+            has_blocking_relations: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// `Internal` Comparator for filtering issues which have suggested related issues.
+    pub fn has_suggested_related_issues(value: RelationExistsComparator) -> Self {
+        Self {
+            has_suggested_related_issues: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// `Internal` Comparator for filtering issues which have suggested similar issues.
+    pub fn has_suggested_similar_issues(value: RelationExistsComparator) -> Self {
+        Self {
+            has_suggested_similar_issues: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// `Internal` Comparator for filtering issues which have suggested assignees.
+    pub fn has_suggested_assignees(value: RelationExistsComparator) -> Self {
+        Self {
+            has_suggested_assignees: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// `Internal` Comparator for filtering issues which have suggested projects.
+    pub fn has_suggested_projects(value: RelationExistsComparator) -> Self {
+        Self {
+            has_suggested_projects: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// `Internal` Comparator for filtering issues which have suggested labels.
+    pub fn has_suggested_labels(value: RelationExistsComparator) -> Self {
+        Self {
+            has_suggested_labels: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// `Internal` Comparator for filtering issues which have suggested teams.
+    pub fn has_suggested_teams(value: RelationExistsComparator) -> Self {
+        Self {
+            has_suggested_teams: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the issues sla status.
+    pub fn sla_status(value: SlaStatusComparator) -> Self {
+        Self {
+            sla_status: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the issues reactions must satisfy.
+    pub fn reactions(value: ReactionCollectionFilter) -> Self {
+        Self {
+            reactions: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the issue's customer needs must satisfy.
+    pub fn needs(value: CustomerNeedCollectionFilter) -> Self {
+        Self {
+            needs: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// `ALPHA` Filters that the issue's releases must satisfy.
+    pub fn releases(value: ReleaseCollectionFilter) -> Self {
+        Self {
+            releases: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Count of customers
+    pub fn customer_count(value: NumberComparator) -> Self {
+        Self {
+            customer_count: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Count of important customers
+    pub fn customer_important_count(value: NumberComparator) -> Self {
+        Self {
+            customer_important_count: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// `Internal` Lead time (created -> completed) comparator.
+    pub fn lead_time(value: NullableDurationComparator) -> Self {
+        Self {
+            lead_time: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// `Internal` Cycle time (started -> completed) comparator.
+    pub fn cycle_time(value: NullableDurationComparator) -> Self {
+        Self {
+            cycle_time: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// `Internal` Age (created -> now) comparator, defined if the issue is still open.
+    pub fn age_time(value: NullableDurationComparator) -> Self {
+        Self {
+            age_time: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// `Internal` Triage time (entered triaged -> triaged) comparator.
+    pub fn triage_time(value: NullableDurationComparator) -> Self {
+        Self {
+            triage_time: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Match records matching every filter. A single filter is returned
+    /// as is, and no filters give the empty filter, which matches everything.
+    pub fn all(filters: impl IntoIterator<Item = Self>) -> Self {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => Self::default(),
+            1 => filters.remove(0),
+            _ => Self {
+                and: MaybeUndefined::Value(filters),
+                ..Default::default()
+            },
+        }
+    }
+    /// Match records matching any of the filters. A single filter is
+    /// returned as is. No filters give `None`: a disjunction of nothing
+    /// matches nothing, which no filter expresses.
+    pub fn any(filters: impl IntoIterator<Item = Self>) -> Option<Self> {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => None,
+            1 => Some(filters.remove(0)),
+            _ => Some(Self {
+                or: MaybeUndefined::Value(filters),
+                ..Default::default()
+            }),
+        }
+    }
+    /// Match records matching both this filter and `other`.
+    pub fn and(self, other: Self) -> Self {
+        Self::all([self, other])
+    }
+    /// Match records matching this filter or `other`.
+    pub fn or(self, other: Self) -> Self {
+        Self {
+            or: MaybeUndefined::Value(vec![self, other]),
+            ..Default::default()
+        }
+    }
+}
 /// Comparator for issue identifiers.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -3926,6 +7903,57 @@ pub struct IssueIDComparator {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub nin: MaybeUndefined<Vec<String>>,
 }
+impl IssueIDComparator {
+    /// Equals constraint.
+    pub fn eq(value: impl Into<String>) -> Self {
+        Self {
+            eq: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Not-equals constraint.
+    pub fn neq(value: impl Into<String>) -> Self {
+        Self {
+            neq: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// In-array constraint.
+    pub fn in_(values: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self {
+            r#in: MaybeUndefined::Value(values.into_iter().map(Into::into).collect()),
+            ..Default::default()
+        }
+    }
+    /// Not-in-array constraint.
+    pub fn nin(values: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self {
+            nin: MaybeUndefined::Value(values.into_iter().map(Into::into).collect()),
+            ..Default::default()
+        }
+    }
+}
+impl IssueIDComparator {
+    /// The negated comparator (`eq` ↔ `neq`, `lt` ↔ `gte`, …), or
+    /// `None` unless exactly one constraint is set: negating several
+    /// would need an `or`, which comparators don't have.
+    pub fn negate(self) -> Option<Self> {
+        if usize::from(!self.eq.is_undefined())
+            + usize::from(!self.neq.is_undefined())
+            + usize::from(!self.r#in.is_undefined())
+            + usize::from(!self.nin.is_undefined())
+            != 1
+        {
+            return None;
+        }
+        Some(Self {
+            eq: self.neq,
+            neq: self.eq,
+            r#in: self.nin,
+            nin: self.r#in,
+        })
+    }
+}
 /// Input for updating an import job's mapping configuration, such as user and workflow state mappings between the source service and Linear.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -3980,6 +8008,130 @@ pub struct IssueLabelCollectionFilter {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub length: MaybeUndefined<NumberComparator>,
 }
+impl IssueLabelCollectionFilter {
+    /// Comparator for the identifier.
+    pub fn id(value: IDComparator) -> Self {
+        Self {
+            id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the created at date.
+    pub fn created_at(value: DateComparator) -> Self {
+        Self {
+            created_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the updated at date.
+    pub fn updated_at(value: DateComparator) -> Self {
+        Self {
+            updated_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the name.
+    pub fn name(value: StringComparator) -> Self {
+        Self {
+            name: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for whether the label is a group label.
+    pub fn is_group(value: BooleanComparator) -> Self {
+        Self {
+            is_group: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the issue labels creator must satisfy.
+    pub fn creator(value: NullableUserFilter) -> Self {
+        Self {
+            creator: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the issue labels team must satisfy.
+    pub fn team(value: NullableTeamFilter) -> Self {
+        Self {
+            team: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the issue label's parent label must satisfy.
+    pub fn parent(value: IssueLabelFilter) -> Self {
+        Self {
+            parent: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filter based on the existence of the relation.
+    pub fn null(value: impl Into<bool>) -> Self {
+        Self {
+            null: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Filters that needs to be matched by some issue labels.
+    pub fn some(value: IssueLabelFilter) -> Self {
+        Self {
+            some: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that needs to be matched by all issue labels.
+    pub fn every(value: IssueLabelFilter) -> Self {
+        Self {
+            every: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the collection length.
+    pub fn length(value: NumberComparator) -> Self {
+        Self {
+            length: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Match records matching every filter. A single filter is returned
+    /// as is, and no filters give the empty filter, which matches everything.
+    pub fn all(filters: impl IntoIterator<Item = Self>) -> Self {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => Self::default(),
+            1 => filters.remove(0),
+            _ => Self {
+                and: MaybeUndefined::Value(filters),
+                ..Default::default()
+            },
+        }
+    }
+    /// Match records matching any of the filters. A single filter is
+    /// returned as is. No filters give `None`: a disjunction of nothing
+    /// matches nothing, which no filter expresses.
+    pub fn any(filters: impl IntoIterator<Item = Self>) -> Option<Self> {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => None,
+            1 => Some(filters.remove(0)),
+            _ => Some(Self {
+                or: MaybeUndefined::Value(filters),
+                ..Default::default()
+            }),
+        }
+    }
+    /// Match records matching both this filter and `other`.
+    pub fn and(self, other: Self) -> Self {
+        Self::all([self, other])
+    }
+    /// Match records matching this filter or `other`.
+    pub fn or(self, other: Self) -> Self {
+        Self {
+            or: MaybeUndefined::Value(vec![self, other]),
+            ..Default::default()
+        }
+    }
+}
 /// Input for creating a new label. A name is required. If no team is specified, the label is created as a workspace-level label available to all teams.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -4043,6 +8195,102 @@ pub struct IssueLabelFilter {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub or: MaybeUndefined<Vec<IssueLabelFilter>>,
 }
+impl IssueLabelFilter {
+    /// Comparator for the identifier.
+    pub fn id(value: IDComparator) -> Self {
+        Self {
+            id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the created at date.
+    pub fn created_at(value: DateComparator) -> Self {
+        Self {
+            created_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the updated at date.
+    pub fn updated_at(value: DateComparator) -> Self {
+        Self {
+            updated_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the name.
+    pub fn name(value: StringComparator) -> Self {
+        Self {
+            name: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for whether the label is a group label.
+    pub fn is_group(value: BooleanComparator) -> Self {
+        Self {
+            is_group: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the issue labels creator must satisfy.
+    pub fn creator(value: NullableUserFilter) -> Self {
+        Self {
+            creator: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the issue labels team must satisfy.
+    pub fn team(value: NullableTeamFilter) -> Self {
+        Self {
+            team: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the issue label's parent label must satisfy.
+    pub fn parent(value: IssueLabelFilter) -> Self {
+        Self {
+            parent: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Match records matching every filter. A single filter is returned
+    /// as is, and no filters give the empty filter, which matches everything.
+    pub fn all(filters: impl IntoIterator<Item = Self>) -> Self {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => Self::default(),
+            1 => filters.remove(0),
+            _ => Self {
+                and: MaybeUndefined::Value(filters),
+                ..Default::default()
+            },
+        }
+    }
+    /// Match records matching any of the filters. A single filter is
+    /// returned as is. No filters give `None`: a disjunction of nothing
+    /// matches nothing, which no filter expresses.
+    pub fn any(filters: impl IntoIterator<Item = Self>) -> Option<Self> {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => None,
+            1 => Some(filters.remove(0)),
+            _ => Some(Self {
+                or: MaybeUndefined::Value(filters),
+                ..Default::default()
+            }),
+        }
+    }
+    /// Match records matching both this filter and `other`.
+    pub fn and(self, other: Self) -> Self {
+        Self::all([self, other])
+    }
+    /// Match records matching this filter or `other`.
+    pub fn or(self, other: Self) -> Self {
+        Self {
+            or: MaybeUndefined::Value(vec![self, other]),
+            ..Default::default()
+        }
+    }
+}
 /// Input for updating an existing label. All fields are optional; only provided fields will be updated.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -4206,6 +8454,43 @@ pub struct IssueSubscriptionFilter {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub parent_id: MaybeUndefined<IDComparator>,
 }
+impl IssueSubscriptionFilter {
+    /// Filter by team ID.
+    pub fn team_id(value: IDComparator) -> Self {
+        Self {
+            team_id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filter by project ID.
+    pub fn project_id(value: IDComparator) -> Self {
+        Self {
+            project_id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filter by assignee ID.
+    pub fn assignee_id(value: IDComparator) -> Self {
+        Self {
+            assignee_id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filter by workflow state ID.
+    pub fn state_id(value: IDComparator) -> Self {
+        Self {
+            state_id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filter by parent issue ID.
+    pub fn parent_id(value: IDComparator) -> Self {
+        Self {
+            parent_id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+}
 /// IssueSuggestion collection filtering options.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -4253,6 +8538,130 @@ pub struct IssueSuggestionCollectionFilter {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub length: MaybeUndefined<NumberComparator>,
 }
+impl IssueSuggestionCollectionFilter {
+    /// Comparator for the identifier.
+    pub fn id(value: IDComparator) -> Self {
+        Self {
+            id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the created at date.
+    pub fn created_at(value: DateComparator) -> Self {
+        Self {
+            created_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the updated at date.
+    pub fn updated_at(value: DateComparator) -> Self {
+        Self {
+            updated_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the suggestion type.
+    pub fn type_(value: StringComparator) -> Self {
+        Self {
+            r#type: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the suggestion state.
+    pub fn state(value: StringComparator) -> Self {
+        Self {
+            state: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the suggested user must satisfy.
+    pub fn suggested_user(value: NullableUserFilter) -> Self {
+        Self {
+            suggested_user: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the suggested project must satisfy.
+    pub fn suggested_project(value: NullableProjectFilter) -> Self {
+        Self {
+            suggested_project: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the suggested team must satisfy.
+    pub fn suggested_team(value: NullableTeamFilter) -> Self {
+        Self {
+            suggested_team: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the suggested label must satisfy.
+    pub fn suggested_label(value: IssueLabelFilter) -> Self {
+        Self {
+            suggested_label: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that needs to be matched by some suggestions.
+    pub fn some(value: IssueSuggestionFilter) -> Self {
+        Self {
+            some: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that needs to be matched by all suggestions.
+    pub fn every(value: IssueSuggestionFilter) -> Self {
+        Self {
+            every: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the collection length.
+    pub fn length(value: NumberComparator) -> Self {
+        Self {
+            length: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Match records matching every filter. A single filter is returned
+    /// as is, and no filters give the empty filter, which matches everything.
+    pub fn all(filters: impl IntoIterator<Item = Self>) -> Self {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => Self::default(),
+            1 => filters.remove(0),
+            _ => Self {
+                and: MaybeUndefined::Value(filters),
+                ..Default::default()
+            },
+        }
+    }
+    /// Match records matching any of the filters. A single filter is
+    /// returned as is. No filters give `None`: a disjunction of nothing
+    /// matches nothing, which no filter expresses.
+    pub fn any(filters: impl IntoIterator<Item = Self>) -> Option<Self> {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => None,
+            1 => Some(filters.remove(0)),
+            _ => Some(Self {
+                or: MaybeUndefined::Value(filters),
+                ..Default::default()
+            }),
+        }
+    }
+    /// Match records matching both this filter and `other`.
+    pub fn and(self, other: Self) -> Self {
+        Self::all([self, other])
+    }
+    /// Match records matching this filter or `other`.
+    pub fn or(self, other: Self) -> Self {
+        Self {
+            or: MaybeUndefined::Value(vec![self, other]),
+            ..Default::default()
+        }
+    }
+}
 /// IssueSuggestion filtering options.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -4291,14 +8700,117 @@ pub struct IssueSuggestionFilter {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub or: MaybeUndefined<Vec<IssueSuggestionFilter>>,
 }
-/// `ALPHA` Input for creating a new association between an issue and a release. Both an issue identifier and a release identifier must be provided.
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct IssueToReleaseCreateInput {
-    /// The identifier in UUID v4 format. If none is provided, the backend will generate one.
-    #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
-    pub id: MaybeUndefined<String>,
-    /// The identifier of the issue. Can be a UUID or issue identifier (e.g., 'LIN-123').
+impl IssueSuggestionFilter {
+    /// Comparator for the identifier.
+    pub fn id(value: IDComparator) -> Self {
+        Self {
+            id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the created at date.
+    pub fn created_at(value: DateComparator) -> Self {
+        Self {
+            created_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the updated at date.
+    pub fn updated_at(value: DateComparator) -> Self {
+        Self {
+            updated_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the suggestion type.
+    pub fn type_(value: StringComparator) -> Self {
+        Self {
+            r#type: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the suggestion state.
+    pub fn state(value: StringComparator) -> Self {
+        Self {
+            state: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the suggested user must satisfy.
+    pub fn suggested_user(value: NullableUserFilter) -> Self {
+        Self {
+            suggested_user: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the suggested project must satisfy.
+    pub fn suggested_project(value: NullableProjectFilter) -> Self {
+        Self {
+            suggested_project: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the suggested team must satisfy.
+    pub fn suggested_team(value: NullableTeamFilter) -> Self {
+        Self {
+            suggested_team: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the suggested label must satisfy.
+    pub fn suggested_label(value: IssueLabelFilter) -> Self {
+        Self {
+            suggested_label: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Match records matching every filter. A single filter is returned
+    /// as is, and no filters give the empty filter, which matches everything.
+    pub fn all(filters: impl IntoIterator<Item = Self>) -> Self {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => Self::default(),
+            1 => filters.remove(0),
+            _ => Self {
+                and: MaybeUndefined::Value(filters),
+                ..Default::default()
+            },
+        }
+    }
+    /// Match records matching any of the filters. A single filter is
+    /// returned as is. No filters give `None`: a disjunction of nothing
+    /// matches nothing, which no filter expresses.
+    pub fn any(filters: impl IntoIterator<Item = Self>) -> Option<Self> {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => None,
+            1 => Some(filters.remove(0)),
+            _ => Some(Self {
+                or: MaybeUndefined::Value(filters),
+                ..Default::default()
+            }),
+        }
+    }
+    /// Match records matching both this filter and `other`.
+    pub fn and(self, other: Self) -> Self {
+        Self::all([self, other])
+    }
+    /// Match records matching this filter or `other`.
+    pub fn or(self, other: Self) -> Self {
+        Self {
+            or: MaybeUndefined::Value(vec![self, other]),
+            ..Default::default()
+        }
+    }
+}
+/// `ALPHA` Input for creating a new association between an issue and a release. Both an issue identifier and a release identifier must be provided.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueToReleaseCreateInput {
+    /// The identifier in UUID v4 format. If none is provided, the backend will generate one.
+    #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
+    pub id: MaybeUndefined<String>,
+    /// The identifier of the issue. Can be a UUID or issue identifier (e.g., 'LIN-123').
     pub issue_id: String,
     /// The identifier of the release.
     pub release_id: String,
@@ -4785,6 +9297,81 @@ pub struct NotificationFilter {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub or: MaybeUndefined<Vec<NotificationFilter>>,
 }
+impl NotificationFilter {
+    /// Comparator for the identifier.
+    pub fn id(value: IDComparator) -> Self {
+        Self {
+            id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the created at date.
+    pub fn created_at(value: DateComparator) -> Self {
+        Self {
+            created_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the updated at date.
+    pub fn updated_at(value: DateComparator) -> Self {
+        Self {
+            updated_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the notification type.
+    pub fn type_(value: StringComparator) -> Self {
+        Self {
+            r#type: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the archived at date.
+    pub fn archived_at(value: DateComparator) -> Self {
+        Self {
+            archived_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Match records matching every filter. A single filter is returned
+    /// as is, and no filters give the empty filter, which matches everything.
+    pub fn all(filters: impl IntoIterator<Item = Self>) -> Self {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => Self::default(),
+            1 => filters.remove(0),
+            _ => Self {
+                and: MaybeUndefined::Value(filters),
+                ..Default::default()
+            },
+        }
+    }
+    /// Match records matching any of the filters. A single filter is
+    /// returned as is. No filters give `None`: a disjunction of nothing
+    /// matches nothing, which no filter expresses.
+    pub fn any(filters: impl IntoIterator<Item = Self>) -> Option<Self> {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => None,
+            1 => Some(filters.remove(0)),
+            _ => Some(Self {
+                or: MaybeUndefined::Value(filters),
+                ..Default::default()
+            }),
+        }
+    }
+    /// Match records matching both this filter and `other`.
+    pub fn and(self, other: Self) -> Self {
+        Self::all([self, other])
+    }
+    /// Match records matching this filter or `other`.
+    pub fn or(self, other: Self) -> Self {
+        Self {
+            or: MaybeUndefined::Value(vec![self, other]),
+            ..Default::default()
+        }
+    }
+}
 /// Input for creating a notification subscription. Exactly one target entity (customer, custom view, cycle, initiative, label, project, team, or user) must be specified along with the notification types to subscribe to.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -4918,6 +9505,144 @@ pub struct NullableCommentFilter {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub or: MaybeUndefined<Vec<NullableCommentFilter>>,
 }
+impl NullableCommentFilter {
+    /// Comparator for the identifier.
+    pub fn id(value: IDComparator) -> Self {
+        Self {
+            id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the created at date.
+    pub fn created_at(value: DateComparator) -> Self {
+        Self {
+            created_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the updated at date.
+    pub fn updated_at(value: DateComparator) -> Self {
+        Self {
+            updated_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the comment's body.
+    pub fn body(value: StringComparator) -> Self {
+        Self {
+            body: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the comment's creator must satisfy.
+    pub fn user(value: UserFilter) -> Self {
+        Self {
+            user: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the comment's issue must satisfy.
+    pub fn issue(value: NullableIssueFilter) -> Self {
+        Self {
+            issue: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the comment's project update must satisfy.
+    pub fn project_update(value: NullableProjectUpdateFilter) -> Self {
+        Self {
+            project_update: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the comment parent must satisfy.
+    pub fn parent(value: NullableCommentFilter) -> Self {
+        Self {
+            parent: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the comment's document content must satisfy.
+    pub fn document_content(value: NullableDocumentContentFilter) -> Self {
+        Self {
+            document_content: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// `Internal` Filters that the comment's project must satisfy.
+    pub fn project(value: NullableProjectFilter) -> Self {
+        Self {
+            project: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// `Internal` Filters that the comment's initiative must satisfy.
+    pub fn initiative(value: NullableInitiativeFilter) -> Self {
+        Self {
+            initiative: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the comment's reactions must satisfy.
+    pub fn reactions(value: ReactionCollectionFilter) -> Self {
+        Self {
+            reactions: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the comment's customer needs must satisfy.
+    pub fn needs(value: CustomerNeedCollectionFilter) -> Self {
+        Self {
+            needs: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filter based on the existence of the relation.
+    pub fn null(value: impl Into<bool>) -> Self {
+        Self {
+            null: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Match records matching every filter. A single filter is returned
+    /// as is, and no filters give the empty filter, which matches everything.
+    pub fn all(filters: impl IntoIterator<Item = Self>) -> Self {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => Self::default(),
+            1 => filters.remove(0),
+            _ => Self {
+                and: MaybeUndefined::Value(filters),
+                ..Default::default()
+            },
+        }
+    }
+    /// Match records matching any of the filters. A single filter is
+    /// returned as is. No filters give `None`: a disjunction of nothing
+    /// matches nothing, which no filter expresses.
+    pub fn any(filters: impl IntoIterator<Item = Self>) -> Option<Self> {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => None,
+            1 => Some(filters.remove(0)),
+            _ => Some(Self {
+                or: MaybeUndefined::Value(filters),
+                ..Default::default()
+            }),
+        }
+    }
+    /// Match records matching both this filter and `other`.
+    pub fn and(self, other: Self) -> Self {
+        Self::all([self, other])
+    }
+    /// Match records matching this filter or `other`.
+    pub fn or(self, other: Self) -> Self {
+        Self {
+            or: MaybeUndefined::Value(vec![self, other]),
+            ..Default::default()
+        }
+    }
+}
 /// Customer filtering options.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -4971,6 +9696,144 @@ pub struct NullableCustomerFilter {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub or: MaybeUndefined<Vec<NullableCustomerFilter>>,
 }
+impl NullableCustomerFilter {
+    /// Comparator for the identifier.
+    pub fn id(value: IDComparator) -> Self {
+        Self {
+            id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the created at date.
+    pub fn created_at(value: DateComparator) -> Self {
+        Self {
+            created_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the updated at date.
+    pub fn updated_at(value: DateComparator) -> Self {
+        Self {
+            updated_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the customer name.
+    pub fn name(value: StringComparator) -> Self {
+        Self {
+            name: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the customer slack channel ID.
+    pub fn slack_channel_id(value: StringComparator) -> Self {
+        Self {
+            slack_channel_id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the customer's domains.
+    pub fn domains(value: StringArrayComparator) -> Self {
+        Self {
+            domains: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the customer's external IDs.
+    pub fn external_ids(value: StringArrayComparator) -> Self {
+        Self {
+            external_ids: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the customer owner must satisfy.
+    pub fn owner(value: NullableUserFilter) -> Self {
+        Self {
+            owner: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the customer's needs must satisfy.
+    pub fn needs(value: CustomerNeedCollectionFilter) -> Self {
+        Self {
+            needs: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the customer generated revenue.
+    pub fn revenue(value: NumberComparator) -> Self {
+        Self {
+            revenue: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the customer size.
+    pub fn size(value: NumberComparator) -> Self {
+        Self {
+            size: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the customer's status must satisfy.
+    pub fn status(value: CustomerStatusFilter) -> Self {
+        Self {
+            status: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the customer's tier must satisfy.
+    pub fn tier(value: CustomerTierFilter) -> Self {
+        Self {
+            tier: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filter based on the existence of the relation.
+    pub fn null(value: impl Into<bool>) -> Self {
+        Self {
+            null: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Match records matching every filter. A single filter is returned
+    /// as is, and no filters give the empty filter, which matches everything.
+    pub fn all(filters: impl IntoIterator<Item = Self>) -> Self {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => Self::default(),
+            1 => filters.remove(0),
+            _ => Self {
+                and: MaybeUndefined::Value(filters),
+                ..Default::default()
+            },
+        }
+    }
+    /// Match records matching any of the filters. A single filter is
+    /// returned as is. No filters give `None`: a disjunction of nothing
+    /// matches nothing, which no filter expresses.
+    pub fn any(filters: impl IntoIterator<Item = Self>) -> Option<Self> {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => None,
+            1 => Some(filters.remove(0)),
+            _ => Some(Self {
+                or: MaybeUndefined::Value(filters),
+                ..Default::default()
+            }),
+        }
+    }
+    /// Match records matching both this filter and `other`.
+    pub fn and(self, other: Self) -> Self {
+        Self::all([self, other])
+    }
+    /// Match records matching this filter or `other`.
+    pub fn or(self, other: Self) -> Self {
+        Self {
+            or: MaybeUndefined::Value(vec![self, other]),
+            ..Default::default()
+        }
+    }
+}
 /// Cycle filtering options.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -5036,6 +9899,172 @@ pub struct NullableCycleFilter {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub or: MaybeUndefined<Vec<NullableCycleFilter>>,
 }
+impl NullableCycleFilter {
+    /// Comparator for the identifier.
+    pub fn id(value: IDComparator) -> Self {
+        Self {
+            id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the created at date.
+    pub fn created_at(value: DateComparator) -> Self {
+        Self {
+            created_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the updated at date.
+    pub fn updated_at(value: DateComparator) -> Self {
+        Self {
+            updated_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the cycle number.
+    pub fn number(value: NumberComparator) -> Self {
+        Self {
+            number: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the cycle name.
+    pub fn name(value: StringComparator) -> Self {
+        Self {
+            name: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the cycle start date.
+    pub fn starts_at(value: DateComparator) -> Self {
+        Self {
+            starts_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the cycle ends at date.
+    pub fn ends_at(value: DateComparator) -> Self {
+        Self {
+            ends_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the cycle completed at date.
+    pub fn completed_at(value: DateComparator) -> Self {
+        Self {
+            completed_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the filtering active cycle.
+    pub fn is_active(value: BooleanComparator) -> Self {
+        Self {
+            is_active: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for filtering for whether the cycle is currently in cooldown.
+    pub fn is_in_cooldown(value: BooleanComparator) -> Self {
+        Self {
+            is_in_cooldown: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the filtering next cycle.
+    pub fn is_next(value: BooleanComparator) -> Self {
+        Self {
+            is_next: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the filtering previous cycle.
+    pub fn is_previous(value: BooleanComparator) -> Self {
+        Self {
+            is_previous: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the filtering future cycles.
+    pub fn is_future(value: BooleanComparator) -> Self {
+        Self {
+            is_future: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the filtering past cycles.
+    pub fn is_past(value: BooleanComparator) -> Self {
+        Self {
+            is_past: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the cycles team must satisfy.
+    pub fn team(value: TeamFilter) -> Self {
+        Self {
+            team: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the cycles issues must satisfy.
+    pub fn issues(value: IssueCollectionFilter) -> Self {
+        Self {
+            issues: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the inherited cycle ID.
+    pub fn inherited_from_id(value: IDComparator) -> Self {
+        Self {
+            inherited_from_id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filter based on the existence of the relation.
+    pub fn null(value: impl Into<bool>) -> Self {
+        Self {
+            null: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Match records matching every filter. A single filter is returned
+    /// as is, and no filters give the empty filter, which matches everything.
+    pub fn all(filters: impl IntoIterator<Item = Self>) -> Self {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => Self::default(),
+            1 => filters.remove(0),
+            _ => Self {
+                and: MaybeUndefined::Value(filters),
+                ..Default::default()
+            },
+        }
+    }
+    /// Match records matching any of the filters. A single filter is
+    /// returned as is. No filters give `None`: a disjunction of nothing
+    /// matches nothing, which no filter expresses.
+    pub fn any(filters: impl IntoIterator<Item = Self>) -> Option<Self> {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => None,
+            1 => Some(filters.remove(0)),
+            _ => Some(Self {
+                or: MaybeUndefined::Value(filters),
+                ..Default::default()
+            }),
+        }
+    }
+    /// Match records matching both this filter and `other`.
+    pub fn and(self, other: Self) -> Self {
+        Self::all([self, other])
+    }
+    /// Match records matching this filter or `other`.
+    pub fn or(self, other: Self) -> Self {
+        Self {
+            or: MaybeUndefined::Value(vec![self, other]),
+            ..Default::default()
+        }
+    }
+}
 /// Comparator for optional dates.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -5068,6 +10097,105 @@ pub struct NullableDateComparator {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub gte: MaybeUndefined<serde_json::Value>,
 }
+impl NullableDateComparator {
+    /// Equals constraint.
+    pub fn eq(value: impl Into<serde_json::Value>) -> Self {
+        Self {
+            eq: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Not-equals constraint.
+    pub fn neq(value: impl Into<serde_json::Value>) -> Self {
+        Self {
+            neq: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// In-array constraint.
+    pub fn in_(values: impl IntoIterator<Item = impl Into<serde_json::Value>>) -> Self {
+        Self {
+            r#in: MaybeUndefined::Value(values.into_iter().map(Into::into).collect()),
+            ..Default::default()
+        }
+    }
+    /// Not-in-array constraint.
+    pub fn nin(values: impl IntoIterator<Item = impl Into<serde_json::Value>>) -> Self {
+        Self {
+            nin: MaybeUndefined::Value(values.into_iter().map(Into::into).collect()),
+            ..Default::default()
+        }
+    }
+    /// Null constraint. Matches any non-null values if the given value is false, otherwise it matches null values.
+    pub fn null(value: impl Into<bool>) -> Self {
+        Self {
+            null: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Less-than constraint. Matches any values that are less than the given value.
+    pub fn lt(value: impl Into<serde_json::Value>) -> Self {
+        Self {
+            lt: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Less-than-or-equal constraint. Matches any values that are less than or equal to the given value.
+    pub fn lte(value: impl Into<serde_json::Value>) -> Self {
+        Self {
+            lte: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Greater-than constraint. Matches any values that are greater than the given value.
+    pub fn gt(value: impl Into<serde_json::Value>) -> Self {
+        Self {
+            gt: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Greater-than-or-equal constraint. Matches any values that are greater than or equal to the given value.
+    pub fn gte(value: impl Into<serde_json::Value>) -> Self {
+        Self {
+            gte: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+}
+impl NullableDateComparator {
+    /// The negated comparator (`eq` ↔ `neq`, `lt` ↔ `gte`, …), or
+    /// `None` unless exactly one constraint is set: negating several
+    /// would need an `or`, which comparators don't have.
+    pub fn negate(self) -> Option<Self> {
+        if usize::from(!self.eq.is_undefined())
+            + usize::from(!self.neq.is_undefined())
+            + usize::from(!self.r#in.is_undefined())
+            + usize::from(!self.nin.is_undefined())
+            + usize::from(!self.null.is_undefined())
+            + usize::from(!self.lt.is_undefined())
+            + usize::from(!self.lte.is_undefined())
+            + usize::from(!self.gt.is_undefined())
+            + usize::from(!self.gte.is_undefined())
+            != 1
+        {
+            return None;
+        }
+        Some(Self {
+            eq: self.neq,
+            neq: self.eq,
+            r#in: self.nin,
+            nin: self.r#in,
+            null: match self.null {
+                MaybeUndefined::Value(is_null) => MaybeUndefined::Value(!is_null),
+                other => other,
+            },
+            lt: self.gte,
+            lte: self.gt,
+            gt: self.lte,
+            gte: self.lt,
+        })
+    }
+}
 /// Document content filtering options.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -5100,6 +10228,95 @@ pub struct NullableDocumentContentFilter {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub or: MaybeUndefined<Vec<NullableDocumentContentFilter>>,
 }
+impl NullableDocumentContentFilter {
+    /// Comparator for the identifier.
+    pub fn id(value: IDComparator) -> Self {
+        Self {
+            id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the created at date.
+    pub fn created_at(value: DateComparator) -> Self {
+        Self {
+            created_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the updated at date.
+    pub fn updated_at(value: DateComparator) -> Self {
+        Self {
+            updated_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the document content.
+    pub fn content(value: NullableStringComparator) -> Self {
+        Self {
+            content: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the document content project must satisfy.
+    pub fn project(value: ProjectFilter) -> Self {
+        Self {
+            project: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the document content document must satisfy.
+    pub fn document(value: DocumentFilter) -> Self {
+        Self {
+            document: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filter based on the existence of the relation.
+    pub fn null(value: impl Into<bool>) -> Self {
+        Self {
+            null: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Match records matching every filter. A single filter is returned
+    /// as is, and no filters give the empty filter, which matches everything.
+    pub fn all(filters: impl IntoIterator<Item = Self>) -> Self {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => Self::default(),
+            1 => filters.remove(0),
+            _ => Self {
+                and: MaybeUndefined::Value(filters),
+                ..Default::default()
+            },
+        }
+    }
+    /// Match records matching any of the filters. A single filter is
+    /// returned as is. No filters give `None`: a disjunction of nothing
+    /// matches nothing, which no filter expresses.
+    pub fn any(filters: impl IntoIterator<Item = Self>) -> Option<Self> {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => None,
+            1 => Some(filters.remove(0)),
+            _ => Some(Self {
+                or: MaybeUndefined::Value(filters),
+                ..Default::default()
+            }),
+        }
+    }
+    /// Match records matching both this filter and `other`.
+    pub fn and(self, other: Self) -> Self {
+        Self::all([self, other])
+    }
+    /// Match records matching this filter or `other`.
+    pub fn or(self, other: Self) -> Self {
+        Self {
+            or: MaybeUndefined::Value(vec![self, other]),
+            ..Default::default()
+        }
+    }
+}
 /// Nullable comparator for optional durations.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -5132,6 +10349,105 @@ pub struct NullableDurationComparator {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub gte: MaybeUndefined<serde_json::Value>,
 }
+impl NullableDurationComparator {
+    /// Equals constraint.
+    pub fn eq(value: impl Into<serde_json::Value>) -> Self {
+        Self {
+            eq: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Not-equals constraint.
+    pub fn neq(value: impl Into<serde_json::Value>) -> Self {
+        Self {
+            neq: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// In-array constraint.
+    pub fn in_(values: impl IntoIterator<Item = impl Into<serde_json::Value>>) -> Self {
+        Self {
+            r#in: MaybeUndefined::Value(values.into_iter().map(Into::into).collect()),
+            ..Default::default()
+        }
+    }
+    /// Not-in-array constraint.
+    pub fn nin(values: impl IntoIterator<Item = impl Into<serde_json::Value>>) -> Self {
+        Self {
+            nin: MaybeUndefined::Value(values.into_iter().map(Into::into).collect()),
+            ..Default::default()
+        }
+    }
+    /// Null constraint. Matches any non-null values if the given value is false, otherwise it matches null values.
+    pub fn null(value: impl Into<bool>) -> Self {
+        Self {
+            null: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Less-than constraint. Matches any values that are less than the given value.
+    pub fn lt(value: impl Into<serde_json::Value>) -> Self {
+        Self {
+            lt: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Less-than-or-equal constraint. Matches any values that are less than or equal to the given value.
+    pub fn lte(value: impl Into<serde_json::Value>) -> Self {
+        Self {
+            lte: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Greater-than constraint. Matches any values that are greater than the given value.
+    pub fn gt(value: impl Into<serde_json::Value>) -> Self {
+        Self {
+            gt: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Greater-than-or-equal constraint. Matches any values that are greater than or equal to the given value.
+    pub fn gte(value: impl Into<serde_json::Value>) -> Self {
+        Self {
+            gte: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+}
+impl NullableDurationComparator {
+    /// The negated comparator (`eq` ↔ `neq`, `lt` ↔ `gte`, …), or
+    /// `None` unless exactly one constraint is set: negating several
+    /// would need an `or`, which comparators don't have.
+    pub fn negate(self) -> Option<Self> {
+        if usize::from(!self.eq.is_undefined())
+            + usize::from(!self.neq.is_undefined())
+            + usize::from(!self.r#in.is_undefined())
+            + usize::from(!self.nin.is_undefined())
+            + usize::from(!self.null.is_undefined())
+            + usize::from(!self.lt.is_undefined())
+            + usize::from(!self.lte.is_undefined())
+            + usize::from(!self.gt.is_undefined())
+            + usize::from(!self.gte.is_undefined())
+            != 1
+        {
+            return None;
+        }
+        Some(Self {
+            eq: self.neq,
+            neq: self.eq,
+            r#in: self.nin,
+            nin: self.r#in,
+            null: match self.null {
+                MaybeUndefined::Value(is_null) => MaybeUndefined::Value(!is_null),
+                other => other,
+            },
+            lt: self.gte,
+            lte: self.gt,
+            gt: self.lte,
+            gte: self.lt,
+        })
+    }
+}
 /// Initiative filtering options.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -5197,6 +10513,172 @@ pub struct NullableInitiativeFilter {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub or: MaybeUndefined<Vec<NullableInitiativeFilter>>,
 }
+impl NullableInitiativeFilter {
+    /// Comparator for the identifier.
+    pub fn id(value: IDComparator) -> Self {
+        Self {
+            id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the created at date.
+    pub fn created_at(value: DateComparator) -> Self {
+        Self {
+            created_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the updated at date.
+    pub fn updated_at(value: DateComparator) -> Self {
+        Self {
+            updated_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the initiative name.
+    pub fn name(value: StringComparator) -> Self {
+        Self {
+            name: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the initiative slug ID.
+    pub fn slug_id(value: StringComparator) -> Self {
+        Self {
+            slug_id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the initiative creator must satisfy.
+    pub fn creator(value: NullableUserFilter) -> Self {
+        Self {
+            creator: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the initiative status: Planned, Active, Completed
+    pub fn status(value: StringComparator) -> Self {
+        Self {
+            status: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the initiative teams must satisfy.
+    pub fn teams(value: TeamCollectionFilter) -> Self {
+        Self {
+            teams: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the initiative owner must satisfy.
+    pub fn owner(value: NullableUserFilter) -> Self {
+        Self {
+            owner: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the initiative target date.
+    pub fn target_date(value: NullableDateComparator) -> Self {
+        Self {
+            target_date: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the initiative started at date.
+    pub fn started_at(value: NullableDateComparator) -> Self {
+        Self {
+            started_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the initiative completed at date.
+    pub fn completed_at(value: NullableDateComparator) -> Self {
+        Self {
+            completed_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the initiative health: onTrack, atRisk, offTrack
+    pub fn health(value: StringComparator) -> Self {
+        Self {
+            health: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the initiative health (with age): onTrack, atRisk, offTrack, outdated, noUpdate
+    pub fn health_with_age(value: StringComparator) -> Self {
+        Self {
+            health_with_age: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the initiative activity type.
+    pub fn activity_type(value: StringComparator) -> Self {
+        Self {
+            activity_type: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the initiative must be an ancestor of.
+    pub fn ancestors(value: InitiativeCollectionFilter) -> Self {
+        Self {
+            ancestors: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the initiative updates must satisfy.
+    pub fn initiative_updates(value: InitiativeUpdatesCollectionFilter) -> Self {
+        Self {
+            initiative_updates: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filter based on the existence of the relation.
+    pub fn null(value: impl Into<bool>) -> Self {
+        Self {
+            null: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Match records matching every filter. A single filter is returned
+    /// as is, and no filters give the empty filter, which matches everything.
+    pub fn all(filters: impl IntoIterator<Item = Self>) -> Self {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => Self::default(),
+            1 => filters.remove(0),
+            _ => Self {
+                and: MaybeUndefined::Value(filters),
+                ..Default::default()
+            },
+        }
+    }
+    /// Match records matching any of the filters. A single filter is
+    /// returned as is. No filters give `None`: a disjunction of nothing
+    /// matches nothing, which no filter expresses.
+    pub fn any(filters: impl IntoIterator<Item = Self>) -> Option<Self> {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => None,
+            1 => Some(filters.remove(0)),
+            _ => Some(Self {
+                or: MaybeUndefined::Value(filters),
+                ..Default::default()
+            }),
+        }
+    }
+    /// Match records matching both this filter and `other`.
+    pub fn and(self, other: Self) -> Self {
+        Self::all([self, other])
+    }
+    /// Match records matching this filter or `other`.
+    pub fn or(self, other: Self) -> Self {
+        Self {
+            or: MaybeUndefined::Value(vec![self, other]),
+            ..Default::default()
+        }
+    }
+}
 /// Issue filtering options.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -5403,6 +10885,501 @@ pub struct NullableIssueFilter {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub or: MaybeUndefined<Vec<NullableIssueFilter>>,
 }
+impl NullableIssueFilter {
+    /// Comparator for the identifier.
+    pub fn id(value: IssueIDComparator) -> Self {
+        Self {
+            id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the created at date.
+    pub fn created_at(value: DateComparator) -> Self {
+        Self {
+            created_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the updated at date.
+    pub fn updated_at(value: DateComparator) -> Self {
+        Self {
+            updated_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the issues number.
+    pub fn number(value: NumberComparator) -> Self {
+        Self {
+            number: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the issues title.
+    pub fn title(value: StringComparator) -> Self {
+        Self {
+            title: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the issues description.
+    pub fn description(value: NullableStringComparator) -> Self {
+        Self {
+            description: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the issues priority. 0 = No priority, 1 = Urgent, 2 = High, 3 = Medium, 4 = Low.
+    pub fn priority(value: NullableNumberComparator) -> Self {
+        Self {
+            priority: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the issues estimate.
+    pub fn estimate(value: EstimateComparator) -> Self {
+        Self {
+            estimate: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the issues started at date.
+    pub fn started_at(value: NullableDateComparator) -> Self {
+        Self {
+            started_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the issues triaged at date.
+    pub fn triaged_at(value: NullableDateComparator) -> Self {
+        Self {
+            triaged_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the issues completed at date.
+    pub fn completed_at(value: NullableDateComparator) -> Self {
+        Self {
+            completed_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the issues canceled at date.
+    pub fn canceled_at(value: NullableDateComparator) -> Self {
+        Self {
+            canceled_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the issue's SLA breach date.
+    pub fn sla_breaches_at(value: NullableDateComparator) -> Self {
+        Self {
+            sla_breaches_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the issues archived at date.
+    pub fn archived_at(value: NullableDateComparator) -> Self {
+        Self {
+            archived_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the issues auto closed at date.
+    pub fn auto_closed_at(value: NullableDateComparator) -> Self {
+        Self {
+            auto_closed_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the issues auto archived at date.
+    pub fn auto_archived_at(value: NullableDateComparator) -> Self {
+        Self {
+            auto_archived_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the issues added to cycle at date.
+    pub fn added_to_cycle_at(value: NullableDateComparator) -> Self {
+        Self {
+            added_to_cycle_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the period when issue was added to a cycle.
+    pub fn added_to_cycle_period(value: CyclePeriodComparator) -> Self {
+        Self {
+            added_to_cycle_period: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the issues due date.
+    pub fn due_date(value: NullableTimelessDateComparator) -> Self {
+        Self {
+            due_date: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// `Internal` Comparator for the issue's accumulatedStateUpdatedAt date.
+    pub fn accumulated_state_updated_at(value: NullableDateComparator) -> Self {
+        Self {
+            accumulated_state_updated_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the issues snoozed until date.
+    pub fn snoozed_until_at(value: NullableDateComparator) -> Self {
+        Self {
+            snoozed_until_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the issues assignee must satisfy.
+    pub fn assignee(value: NullableUserFilter) -> Self {
+        Self {
+            assignee: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the issue's delegated agent must satisfy.
+    pub fn delegate(value: NullableUserFilter) -> Self {
+        Self {
+            delegate: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the last applied template must satisfy.
+    pub fn last_applied_template(value: NullableTemplateFilter) -> Self {
+        Self {
+            last_applied_template: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// `ALPHA` Filters that the recurring issue template must satisfy.
+    pub fn recurring_issue_template(value: NullableTemplateFilter) -> Self {
+        Self {
+            recurring_issue_template: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the source must satisfy.
+    pub fn source_metadata(value: SourceMetadataComparator) -> Self {
+        Self {
+            source_metadata: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the issues creator must satisfy.
+    pub fn creator(value: NullableUserFilter) -> Self {
+        Self {
+            creator: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the issue parent must satisfy.
+    pub fn parent(value: NullableIssueFilter) -> Self {
+        Self {
+            parent: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the issues snoozer must satisfy.
+    pub fn snoozed_by(value: NullableUserFilter) -> Self {
+        Self {
+            snoozed_by: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that issue labels must satisfy.
+    pub fn labels(value: IssueLabelCollectionFilter) -> Self {
+        Self {
+            labels: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that issue subscribers must satisfy.
+    pub fn subscribers(value: UserCollectionFilter) -> Self {
+        Self {
+            subscribers: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Comparator for filtering issues which have been shared with users outside of the team.
+    pub fn has_shared_users(value: RelationExistsComparator) -> Self {
+        Self {
+            has_shared_users: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that users the issue has been shared with must satisfy.
+    pub fn shared_with(value: UserCollectionFilter) -> Self {
+        Self {
+            shared_with: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the issues team must satisfy.
+    pub fn team(value: TeamFilter) -> Self {
+        Self {
+            team: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the issues project milestone must satisfy.
+    pub fn project_milestone(value: NullableProjectMilestoneFilter) -> Self {
+        Self {
+            project_milestone: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the issues comments must satisfy.
+    pub fn comments(value: CommentCollectionFilter) -> Self {
+        Self {
+            comments: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the issue's activities must satisfy.
+    pub fn activity(value: ActivityCollectionFilter) -> Self {
+        Self {
+            activity: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// `Internal` Filters that the issue's suggestions must satisfy.
+    pub fn suggestions(value: IssueSuggestionCollectionFilter) -> Self {
+        Self {
+            suggestions: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the issues cycle must satisfy.
+    pub fn cycle(value: NullableCycleFilter) -> Self {
+        Self {
+            cycle: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the issues project must satisfy.
+    pub fn project(value: NullableProjectFilter) -> Self {
+        Self {
+            project: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the issues state must satisfy.
+    pub fn state(value: WorkflowStateFilter) -> Self {
+        Self {
+            state: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the child issues must satisfy.
+    pub fn children(value: IssueCollectionFilter) -> Self {
+        Self {
+            children: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the issues attachments must satisfy.
+    pub fn attachments(value: AttachmentCollectionFilter) -> Self {
+        Self {
+            attachments: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// `Internal` Comparator for the issues content.
+    pub fn searchable_content(value: ContentComparator) -> Self {
+        Self {
+            searchable_content: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for filtering issues with relations.
+    pub fn has_related_relations(value: RelationExistsComparator) -> Self {
+        Self {
+            has_related_relations: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for filtering issues which are duplicates.
+    pub fn has_duplicate_relations(value: RelationExistsComparator) -> Self {
+        Self {
+            has_duplicate_relations: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for filtering issues which are blocked.
+    pub fn has_blocked_by_relations(value: RelationExistsComparator) -> Self {
+        Self {
+            has_blocked_by_relations: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for filtering issues which are blocking.
+    pub fn has_blocking_relations(value: RelationExistsComparator) -> Self {
+        Self {
+            has_blocking_relations: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// `Internal` Comparator for filtering issues which have suggested related issues.
+    pub fn has_suggested_related_issues(value: RelationExistsComparator) -> Self {
+        Self {
+            has_suggested_related_issues: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// `Internal` Comparator for filtering issues which have suggested similar issues.
+    pub fn has_suggested_similar_issues(value: RelationExistsComparator) -> Self {
+        Self {
+            has_suggested_similar_issues: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// `Internal` Comparator for filtering issues which have suggested assignees.
+    pub fn has_suggested_assignees(value: RelationExistsComparator) -> Self {
+        Self {
+            has_suggested_assignees: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// `Internal` Comparator for filtering issues which have suggested projects.
+    pub fn has_suggested_projects(value: RelationExistsComparator) -> Self {
+        Self {
+            has_suggested_projects: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// `Internal` Comparator for filtering issues which have suggested labels.
+    pub fn has_suggested_labels(value: RelationExistsComparator) -> Self {
+        Self {
+            has_suggested_labels: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// `Internal` Comparator for filtering issues which have suggested teams.
+    pub fn has_suggested_teams(value: RelationExistsComparator) -> Self {
+        Self {
+            has_suggested_teams: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the issues sla status.
+    pub fn sla_status(value: SlaStatusComparator) -> Self {
+        Self {
+            sla_status: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the issues reactions must satisfy.
+    pub fn reactions(value: ReactionCollectionFilter) -> Self {
+        Self {
+            reactions: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the issue's customer needs must satisfy.
+    pub fn needs(value: CustomerNeedCollectionFilter) -> Self {
+        Self {
+            needs: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// `ALPHA` Filters that the issue's releases must satisfy.
+    pub fn releases(value: ReleaseCollectionFilter) -> Self {
+        Self {
+            releases: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Count of customers
+    pub fn customer_count(value: NumberComparator) -> Self {
+        Self {
+            customer_count: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Count of important customers
+    pub fn customer_important_count(value: NumberComparator) -> Self {
+        Self {
+            customer_important_count: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// `Internal` Lead time (created -> completed) comparator.
+    pub fn lead_time(value: NullableDurationComparator) -> Self {
+        Self {
+            lead_time: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// `Internal` Cycle time (started -> completed) comparator.
+    pub fn cycle_time(value: NullableDurationComparator) -> Self {
+        Self {
+            cycle_time: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// `Internal` Age (created -> now) comparator, defined if the issue is still open.
+    pub fn age_time(value: NullableDurationComparator) -> Self {
+        Self {
+            age_time: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// `Internal` Triage time (entered triaged -> triaged) comparator.
+    pub fn triage_time(value: NullableDurationComparator) -> Self {
+        Self {
+            triage_time: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filter based on the existence of the relation.
+    pub fn null(value: impl Into<bool>) -> Self {
+        Self {
+            null: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Match records matching every filter. A single filter is returned
+    /// as is, and no filters give the empty filter, which matches everything.
+    pub fn all(filters: impl IntoIterator<Item = Self>) -> Self {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => Self::default(),
+            1 => filters.remove(0),
+            _ => Self {
+                and: MaybeUndefined::Value(filters),
+                ..Default::default()
+            },
+        }
+    }
+    /// Match records matching any of the filters. A single filter is
+    /// returned as is. No filters give `None`: a disjunction of nothing
+    /// matches nothing, which no filter expresses.
+    pub fn any(filters: impl IntoIterator<Item = Self>) -> Option<Self> {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => None,
+            1 => Some(filters.remove(0)),
+            _ => Some(Self {
+                or: MaybeUndefined::Value(filters),
+                ..Default::default()
+            }),
+        }
+    }
+    /// Match records matching both this filter and `other`.
+    pub fn and(self, other: Self) -> Self {
+        Self::all([self, other])
+    }
+    /// Match records matching this filter or `other`.
+    pub fn or(self, other: Self) -> Self {
+        Self {
+            or: MaybeUndefined::Value(vec![self, other]),
+            ..Default::default()
+        }
+    }
+}
 /// Comparator for optional numbers.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -5435,6 +11412,105 @@ pub struct NullableNumberComparator {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub gte: MaybeUndefined<f64>,
 }
+impl NullableNumberComparator {
+    /// Equals constraint.
+    pub fn eq(value: impl Into<f64>) -> Self {
+        Self {
+            eq: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Not-equals constraint.
+    pub fn neq(value: impl Into<f64>) -> Self {
+        Self {
+            neq: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// In-array constraint.
+    pub fn in_(values: impl IntoIterator<Item = impl Into<f64>>) -> Self {
+        Self {
+            r#in: MaybeUndefined::Value(values.into_iter().map(Into::into).collect()),
+            ..Default::default()
+        }
+    }
+    /// Not-in-array constraint.
+    pub fn nin(values: impl IntoIterator<Item = impl Into<f64>>) -> Self {
+        Self {
+            nin: MaybeUndefined::Value(values.into_iter().map(Into::into).collect()),
+            ..Default::default()
+        }
+    }
+    /// Null constraint. Matches any non-null values if the given value is false, otherwise it matches null values.
+    pub fn null(value: impl Into<bool>) -> Self {
+        Self {
+            null: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Less-than constraint. Matches any values that are less than the given value.
+    pub fn lt(value: impl Into<f64>) -> Self {
+        Self {
+            lt: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Less-than-or-equal constraint. Matches any values that are less than or equal to the given value.
+    pub fn lte(value: impl Into<f64>) -> Self {
+        Self {
+            lte: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Greater-than constraint. Matches any values that are greater than the given value.
+    pub fn gt(value: impl Into<f64>) -> Self {
+        Self {
+            gt: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Greater-than-or-equal constraint. Matches any values that are greater than or equal to the given value.
+    pub fn gte(value: impl Into<f64>) -> Self {
+        Self {
+            gte: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+}
+impl NullableNumberComparator {
+    /// The negated comparator (`eq` ↔ `neq`, `lt` ↔ `gte`, …), or
+    /// `None` unless exactly one constraint is set: negating several
+    /// would need an `or`, which comparators don't have.
+    pub fn negate(self) -> Option<Self> {
+        if usize::from(!self.eq.is_undefined())
+            + usize::from(!self.neq.is_undefined())
+            + usize::from(!self.r#in.is_undefined())
+            + usize::from(!self.nin.is_undefined())
+            + usize::from(!self.null.is_undefined())
+            + usize::from(!self.lt.is_undefined())
+            + usize::from(!self.lte.is_undefined())
+            + usize::from(!self.gt.is_undefined())
+            + usize::from(!self.gte.is_undefined())
+            != 1
+        {
+            return None;
+        }
+        Some(Self {
+            eq: self.neq,
+            neq: self.eq,
+            r#in: self.nin,
+            nin: self.r#in,
+            null: match self.null {
+                MaybeUndefined::Value(is_null) => MaybeUndefined::Value(!is_null),
+                other => other,
+            },
+            lt: self.gte,
+            lte: self.gt,
+            gt: self.lte,
+            gte: self.lt,
+        })
+    }
+}
 /// Project filtering options.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -5566,6 +11642,326 @@ pub struct NullableProjectFilter {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub or: MaybeUndefined<Vec<NullableProjectFilter>>,
 }
+impl NullableProjectFilter {
+    /// Comparator for the identifier.
+    pub fn id(value: IDComparator) -> Self {
+        Self {
+            id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the created at date.
+    pub fn created_at(value: DateComparator) -> Self {
+        Self {
+            created_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the updated at date.
+    pub fn updated_at(value: DateComparator) -> Self {
+        Self {
+            updated_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the project name.
+    pub fn name(value: StringComparator) -> Self {
+        Self {
+            name: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the project slug ID.
+    pub fn slug_id(value: StringComparator) -> Self {
+        Self {
+            slug_id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// `DEPRECATED` Comparator for the project state.
+    pub fn state(value: StringComparator) -> Self {
+        Self {
+            state: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the project's status must satisfy.
+    pub fn status(value: ProjectStatusFilter) -> Self {
+        Self {
+            status: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the projects priority.
+    pub fn priority(value: NullableNumberComparator) -> Self {
+        Self {
+            priority: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that project labels must satisfy.
+    pub fn labels(value: ProjectLabelCollectionFilter) -> Self {
+        Self {
+            labels: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// `Internal` Comparator for the project's content.
+    pub fn searchable_content(value: ContentComparator) -> Self {
+        Self {
+            searchable_content: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the project started date (when it was moved to an "In Progress" status).
+    pub fn started_at(value: NullableDateComparator) -> Self {
+        Self {
+            started_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the project completion date.
+    pub fn completed_at(value: NullableDateComparator) -> Self {
+        Self {
+            completed_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the project cancelation date.
+    pub fn canceled_at(value: NullableDateComparator) -> Self {
+        Self {
+            canceled_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the project start date.
+    pub fn start_date(value: NullableDateComparator) -> Self {
+        Self {
+            start_date: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the project target date.
+    pub fn target_date(value: NullableDateComparator) -> Self {
+        Self {
+            target_date: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the project health: onTrack, atRisk, offTrack
+    pub fn health(value: StringComparator) -> Self {
+        Self {
+            health: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the project health (with age): onTrack, atRisk, offTrack, outdated, noUpdate
+    pub fn health_with_age(value: StringComparator) -> Self {
+        Self {
+            health_with_age: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// `ALPHA` Comparator for the project activity type: buzzin, active, some, none
+    pub fn activity_type(value: StringComparator) -> Self {
+        Self {
+            activity_type: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for filtering projects with relations.
+    pub fn has_related_relations(value: RelationExistsComparator) -> Self {
+        Self {
+            has_related_relations: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// `Deprecated` Comparator for filtering projects which this is depended on by.
+    pub fn has_depended_on_by_relations(value: RelationExistsComparator) -> Self {
+        Self {
+            has_depended_on_by_relations: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// `Deprecated`Comparator for filtering projects which this depends on.
+    pub fn has_depends_on_relations(value: RelationExistsComparator) -> Self {
+        Self {
+            has_depends_on_relations: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for filtering projects which are blocked.
+    pub fn has_blocked_by_relations(value: RelationExistsComparator) -> Self {
+        Self {
+            has_blocked_by_relations: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for filtering projects which are blocking.
+    pub fn has_blocking_relations(value: RelationExistsComparator) -> Self {
+        Self {
+            has_blocking_relations: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for filtering projects with violated dependencies.
+    pub fn has_violated_relations(value: RelationExistsComparator) -> Self {
+        Self {
+            has_violated_relations: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the project updates.
+    pub fn project_updates(value: ProjectUpdatesCollectionFilter) -> Self {
+        Self {
+            project_updates: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the projects creator must satisfy.
+    pub fn creator(value: UserFilter) -> Self {
+        Self {
+            creator: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the projects lead must satisfy.
+    pub fn lead(value: NullableUserFilter) -> Self {
+        Self {
+            lead: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the projects members must satisfy.
+    pub fn members(value: UserCollectionFilter) -> Self {
+        Self {
+            members: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the projects issues must satisfy.
+    pub fn issues(value: IssueCollectionFilter) -> Self {
+        Self {
+            issues: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the projects roadmaps must satisfy.
+    pub fn roadmaps(value: RoadmapCollectionFilter) -> Self {
+        Self {
+            roadmaps: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the projects initiatives must satisfy.
+    pub fn initiatives(value: InitiativeCollectionFilter) -> Self {
+        Self {
+            initiatives: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the project's milestones must satisfy.
+    pub fn project_milestones(value: ProjectMilestoneCollectionFilter) -> Self {
+        Self {
+            project_milestones: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the project's completed milestones must satisfy.
+    pub fn completed_project_milestones(value: ProjectMilestoneCollectionFilter) -> Self {
+        Self {
+            completed_project_milestones: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the project's next milestone must satisfy.
+    pub fn next_project_milestone(value: ProjectMilestoneFilter) -> Self {
+        Self {
+            next_project_milestone: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the project's team must satisfy.
+    pub fn accessible_teams(value: TeamCollectionFilter) -> Self {
+        Self {
+            accessible_teams: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the last applied template must satisfy.
+    pub fn last_applied_template(value: NullableTemplateFilter) -> Self {
+        Self {
+            last_applied_template: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the project's customer needs must satisfy.
+    pub fn needs(value: CustomerNeedCollectionFilter) -> Self {
+        Self {
+            needs: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Count of customers
+    pub fn customer_count(value: NumberComparator) -> Self {
+        Self {
+            customer_count: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Count of important customers
+    pub fn customer_important_count(value: NumberComparator) -> Self {
+        Self {
+            customer_important_count: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filter based on the existence of the relation.
+    pub fn null(value: impl Into<bool>) -> Self {
+        Self {
+            null: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Match records matching every filter. A single filter is returned
+    /// as is, and no filters give the empty filter, which matches everything.
+    pub fn all(filters: impl IntoIterator<Item = Self>) -> Self {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => Self::default(),
+            1 => filters.remove(0),
+            _ => Self {
+                and: MaybeUndefined::Value(filters),
+                ..Default::default()
+            },
+        }
+    }
+    /// Match records matching any of the filters. A single filter is
+    /// returned as is. No filters give `None`: a disjunction of nothing
+    /// matches nothing, which no filter expresses.
+    pub fn any(filters: impl IntoIterator<Item = Self>) -> Option<Self> {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => None,
+            1 => Some(filters.remove(0)),
+            _ => Some(Self {
+                or: MaybeUndefined::Value(filters),
+                ..Default::default()
+            }),
+        }
+    }
+    /// Match records matching both this filter and `other`.
+    pub fn and(self, other: Self) -> Self {
+        Self::all([self, other])
+    }
+    /// Match records matching this filter or `other`.
+    pub fn or(self, other: Self) -> Self {
+        Self {
+            or: MaybeUndefined::Value(vec![self, other]),
+            ..Default::default()
+        }
+    }
+}
 /// Project milestone filtering options.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -5598,6 +11994,95 @@ pub struct NullableProjectMilestoneFilter {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub or: MaybeUndefined<Vec<NullableProjectMilestoneFilter>>,
 }
+impl NullableProjectMilestoneFilter {
+    /// Comparator for the identifier.
+    pub fn id(value: IDComparator) -> Self {
+        Self {
+            id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the created at date.
+    pub fn created_at(value: DateComparator) -> Self {
+        Self {
+            created_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the updated at date.
+    pub fn updated_at(value: DateComparator) -> Self {
+        Self {
+            updated_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the project milestone name.
+    pub fn name(value: NullableStringComparator) -> Self {
+        Self {
+            name: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the project milestone target date.
+    pub fn target_date(value: NullableDateComparator) -> Self {
+        Self {
+            target_date: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the project milestone's project must satisfy.
+    pub fn project(value: NullableProjectFilter) -> Self {
+        Self {
+            project: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filter based on the existence of the relation.
+    pub fn null(value: impl Into<bool>) -> Self {
+        Self {
+            null: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Match records matching every filter. A single filter is returned
+    /// as is, and no filters give the empty filter, which matches everything.
+    pub fn all(filters: impl IntoIterator<Item = Self>) -> Self {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => Self::default(),
+            1 => filters.remove(0),
+            _ => Self {
+                and: MaybeUndefined::Value(filters),
+                ..Default::default()
+            },
+        }
+    }
+    /// Match records matching any of the filters. A single filter is
+    /// returned as is. No filters give `None`: a disjunction of nothing
+    /// matches nothing, which no filter expresses.
+    pub fn any(filters: impl IntoIterator<Item = Self>) -> Option<Self> {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => None,
+            1 => Some(filters.remove(0)),
+            _ => Some(Self {
+                or: MaybeUndefined::Value(filters),
+                ..Default::default()
+            }),
+        }
+    }
+    /// Match records matching both this filter and `other`.
+    pub fn and(self, other: Self) -> Self {
+        Self::all([self, other])
+    }
+    /// Match records matching this filter or `other`.
+    pub fn or(self, other: Self) -> Self {
+        Self {
+            or: MaybeUndefined::Value(vec![self, other]),
+            ..Default::default()
+        }
+    }
+}
 /// Nullable project update filtering options.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -5630,6 +12115,95 @@ pub struct NullableProjectUpdateFilter {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub or: MaybeUndefined<Vec<NullableProjectUpdateFilter>>,
 }
+impl NullableProjectUpdateFilter {
+    /// Comparator for the identifier.
+    pub fn id(value: IDComparator) -> Self {
+        Self {
+            id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the created at date.
+    pub fn created_at(value: DateComparator) -> Self {
+        Self {
+            created_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the updated at date.
+    pub fn updated_at(value: DateComparator) -> Self {
+        Self {
+            updated_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the project update creator must satisfy.
+    pub fn user(value: UserFilter) -> Self {
+        Self {
+            user: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the project update project must satisfy.
+    pub fn project(value: ProjectFilter) -> Self {
+        Self {
+            project: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the project updates reactions must satisfy.
+    pub fn reactions(value: ReactionCollectionFilter) -> Self {
+        Self {
+            reactions: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filter based on the existence of the relation.
+    pub fn null(value: impl Into<bool>) -> Self {
+        Self {
+            null: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Match records matching every filter. A single filter is returned
+    /// as is, and no filters give the empty filter, which matches everything.
+    pub fn all(filters: impl IntoIterator<Item = Self>) -> Self {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => Self::default(),
+            1 => filters.remove(0),
+            _ => Self {
+                and: MaybeUndefined::Value(filters),
+                ..Default::default()
+            },
+        }
+    }
+    /// Match records matching any of the filters. A single filter is
+    /// returned as is. No filters give `None`: a disjunction of nothing
+    /// matches nothing, which no filter expresses.
+    pub fn any(filters: impl IntoIterator<Item = Self>) -> Option<Self> {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => None,
+            1 => Some(filters.remove(0)),
+            _ => Some(Self {
+                or: MaybeUndefined::Value(filters),
+                ..Default::default()
+            }),
+        }
+    }
+    /// Match records matching both this filter and `other`.
+    pub fn and(self, other: Self) -> Self {
+        Self::all([self, other])
+    }
+    /// Match records matching this filter or `other`.
+    pub fn or(self, other: Self) -> Self {
+        Self {
+            or: MaybeUndefined::Value(vec![self, other]),
+            ..Default::default()
+        }
+    }
+}
 /// Comparator for optional strings.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -5686,6 +12260,127 @@ pub struct NullableStringComparator {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub contains_ignore_case_and_accent: MaybeUndefined<String>,
 }
+impl NullableStringComparator {
+    /// Equals constraint.
+    pub fn eq(value: impl Into<String>) -> Self {
+        Self {
+            eq: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Not-equals constraint.
+    pub fn neq(value: impl Into<String>) -> Self {
+        Self {
+            neq: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// In-array constraint.
+    pub fn in_(values: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self {
+            r#in: MaybeUndefined::Value(values.into_iter().map(Into::into).collect()),
+            ..Default::default()
+        }
+    }
+    /// Not-in-array constraint.
+    pub fn nin(values: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self {
+            nin: MaybeUndefined::Value(values.into_iter().map(Into::into).collect()),
+            ..Default::default()
+        }
+    }
+    /// Null constraint. Matches any non-null values if the given value is false, otherwise it matches null values.
+    pub fn null(value: impl Into<bool>) -> Self {
+        Self {
+            null: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Equals case insensitive. Matches any values that matches the given string case insensitive.
+    pub fn eq_ignore_case(value: impl Into<String>) -> Self {
+        Self {
+            eq_ignore_case: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Not-equals case insensitive. Matches any values that don't match the given string case insensitive.
+    pub fn neq_ignore_case(value: impl Into<String>) -> Self {
+        Self {
+            neq_ignore_case: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Starts with constraint. Matches any values that start with the given string.
+    pub fn starts_with(value: impl Into<String>) -> Self {
+        Self {
+            starts_with: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Starts with case insensitive constraint. Matches any values that start with the given string.
+    pub fn starts_with_ignore_case(value: impl Into<String>) -> Self {
+        Self {
+            starts_with_ignore_case: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Doesn't start with constraint. Matches any values that don't start with the given string.
+    pub fn not_starts_with(value: impl Into<String>) -> Self {
+        Self {
+            not_starts_with: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Ends with constraint. Matches any values that end with the given string.
+    pub fn ends_with(value: impl Into<String>) -> Self {
+        Self {
+            ends_with: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Doesn't end with constraint. Matches any values that don't end with the given string.
+    pub fn not_ends_with(value: impl Into<String>) -> Self {
+        Self {
+            not_ends_with: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Contains constraint. Matches any values that contain the given string.
+    pub fn contains(value: impl Into<String>) -> Self {
+        Self {
+            contains: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Contains case insensitive constraint. Matches any values that contain the given string case insensitive.
+    pub fn contains_ignore_case(value: impl Into<String>) -> Self {
+        Self {
+            contains_ignore_case: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Doesn't contain constraint. Matches any values that don't contain the given string.
+    pub fn not_contains(value: impl Into<String>) -> Self {
+        Self {
+            not_contains: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Doesn't contain case insensitive constraint. Matches any values that don't contain the given string case insensitive.
+    pub fn not_contains_ignore_case(value: impl Into<String>) -> Self {
+        Self {
+            not_contains_ignore_case: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Contains case and accent insensitive constraint. Matches any values that contain the given string case and accent insensitive.
+    pub fn contains_ignore_case_and_accent(value: impl Into<String>) -> Self {
+        Self {
+            contains_ignore_case_and_accent: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+}
 /// Team filtering options.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -5736,6 +12431,137 @@ pub struct NullableTeamFilter {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub or: MaybeUndefined<Vec<NullableTeamFilter>>,
 }
+impl NullableTeamFilter {
+    /// Comparator for the identifier.
+    pub fn id(value: IDComparator) -> Self {
+        Self {
+            id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the created at date.
+    pub fn created_at(value: DateComparator) -> Self {
+        Self {
+            created_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the updated at date.
+    pub fn updated_at(value: DateComparator) -> Self {
+        Self {
+            updated_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the team name.
+    pub fn name(value: StringComparator) -> Self {
+        Self {
+            name: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the team key.
+    pub fn key(value: StringComparator) -> Self {
+        Self {
+            key: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the team description.
+    pub fn description(value: NullableStringComparator) -> Self {
+        Self {
+            description: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the team privacy.
+    pub fn private(value: BooleanComparator) -> Self {
+        Self {
+            private: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the time at which the team was retired.
+    pub fn retired_at(value: NullableDateComparator) -> Self {
+        Self {
+            retired_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the teams issues must satisfy.
+    pub fn issues(value: IssueCollectionFilter) -> Self {
+        Self {
+            issues: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the teams parent must satisfy.
+    pub fn parent(value: NullableTeamFilter) -> Self {
+        Self {
+            parent: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the team's ancestors must satisfy.
+    pub fn ancestors(value: TeamCollectionFilter) -> Self {
+        Self {
+            ancestors: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// `ALPHA` Filters that the team's release pipelines must satisfy.
+    pub fn release_pipelines(value: ReleasePipelineCollectionFilter) -> Self {
+        Self {
+            release_pipelines: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filter based on the existence of the relation.
+    pub fn null(value: impl Into<bool>) -> Self {
+        Self {
+            null: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Match records matching every filter. A single filter is returned
+    /// as is, and no filters give the empty filter, which matches everything.
+    pub fn all(filters: impl IntoIterator<Item = Self>) -> Self {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => Self::default(),
+            1 => filters.remove(0),
+            _ => Self {
+                and: MaybeUndefined::Value(filters),
+                ..Default::default()
+            },
+        }
+    }
+    /// Match records matching any of the filters. A single filter is
+    /// returned as is. No filters give `None`: a disjunction of nothing
+    /// matches nothing, which no filter expresses.
+    pub fn any(filters: impl IntoIterator<Item = Self>) -> Option<Self> {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => None,
+            1 => Some(filters.remove(0)),
+            _ => Some(Self {
+                or: MaybeUndefined::Value(filters),
+                ..Default::default()
+            }),
+        }
+    }
+    /// Match records matching both this filter and `other`.
+    pub fn and(self, other: Self) -> Self {
+        Self::all([self, other])
+    }
+    /// Match records matching this filter or `other`.
+    pub fn or(self, other: Self) -> Self {
+        Self {
+            or: MaybeUndefined::Value(vec![self, other]),
+            ..Default::default()
+        }
+    }
+}
 /// Template filtering options.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -5768,6 +12594,95 @@ pub struct NullableTemplateFilter {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub or: MaybeUndefined<Vec<NullableTemplateFilter>>,
 }
+impl NullableTemplateFilter {
+    /// Comparator for the identifier.
+    pub fn id(value: IDComparator) -> Self {
+        Self {
+            id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the created at date.
+    pub fn created_at(value: DateComparator) -> Self {
+        Self {
+            created_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the updated at date.
+    pub fn updated_at(value: DateComparator) -> Self {
+        Self {
+            updated_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the template's name.
+    pub fn name(value: StringComparator) -> Self {
+        Self {
+            name: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the template's type.
+    pub fn type_(value: StringComparator) -> Self {
+        Self {
+            r#type: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the inherited template's ID.
+    pub fn inherited_from_id(value: IDComparator) -> Self {
+        Self {
+            inherited_from_id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filter based on the existence of the relation.
+    pub fn null(value: impl Into<bool>) -> Self {
+        Self {
+            null: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Match records matching every filter. A single filter is returned
+    /// as is, and no filters give the empty filter, which matches everything.
+    pub fn all(filters: impl IntoIterator<Item = Self>) -> Self {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => Self::default(),
+            1 => filters.remove(0),
+            _ => Self {
+                and: MaybeUndefined::Value(filters),
+                ..Default::default()
+            },
+        }
+    }
+    /// Match records matching any of the filters. A single filter is
+    /// returned as is. No filters give `None`: a disjunction of nothing
+    /// matches nothing, which no filter expresses.
+    pub fn any(filters: impl IntoIterator<Item = Self>) -> Option<Self> {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => None,
+            1 => Some(filters.remove(0)),
+            _ => Some(Self {
+                or: MaybeUndefined::Value(filters),
+                ..Default::default()
+            }),
+        }
+    }
+    /// Match records matching both this filter and `other`.
+    pub fn and(self, other: Self) -> Self {
+        Self::all([self, other])
+    }
+    /// Match records matching this filter or `other`.
+    pub fn or(self, other: Self) -> Self {
+        Self {
+            or: MaybeUndefined::Value(vec![self, other]),
+            ..Default::default()
+        }
+    }
+}
 /// Comparator for optional timeless dates.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -5800,6 +12715,105 @@ pub struct NullableTimelessDateComparator {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub gte: MaybeUndefined<serde_json::Value>,
 }
+impl NullableTimelessDateComparator {
+    /// Equals constraint.
+    pub fn eq(value: impl Into<serde_json::Value>) -> Self {
+        Self {
+            eq: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Not-equals constraint.
+    pub fn neq(value: impl Into<serde_json::Value>) -> Self {
+        Self {
+            neq: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// In-array constraint.
+    pub fn in_(values: impl IntoIterator<Item = impl Into<serde_json::Value>>) -> Self {
+        Self {
+            r#in: MaybeUndefined::Value(values.into_iter().map(Into::into).collect()),
+            ..Default::default()
+        }
+    }
+    /// Not-in-array constraint.
+    pub fn nin(values: impl IntoIterator<Item = impl Into<serde_json::Value>>) -> Self {
+        Self {
+            nin: MaybeUndefined::Value(values.into_iter().map(Into::into).collect()),
+            ..Default::default()
+        }
+    }
+    /// Null constraint. Matches any non-null values if the given value is false, otherwise it matches null values.
+    pub fn null(value: impl Into<bool>) -> Self {
+        Self {
+            null: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Less-than constraint. Matches any values that are less than the given value.
+    pub fn lt(value: impl Into<serde_json::Value>) -> Self {
+        Self {
+            lt: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Less-than-or-equal constraint. Matches any values that are less than or equal to the given value.
+    pub fn lte(value: impl Into<serde_json::Value>) -> Self {
+        Self {
+            lte: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Greater-than constraint. Matches any values that are greater than the given value.
+    pub fn gt(value: impl Into<serde_json::Value>) -> Self {
+        Self {
+            gt: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Greater-than-or-equal constraint. Matches any values that are greater than or equal to the given value.
+    pub fn gte(value: impl Into<serde_json::Value>) -> Self {
+        Self {
+            gte: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+}
+impl NullableTimelessDateComparator {
+    /// The negated comparator (`eq` ↔ `neq`, `lt` ↔ `gte`, …), or
+    /// `None` unless exactly one constraint is set: negating several
+    /// would need an `or`, which comparators don't have.
+    pub fn negate(self) -> Option<Self> {
+        if usize::from(!self.eq.is_undefined())
+            + usize::from(!self.neq.is_undefined())
+            + usize::from(!self.r#in.is_undefined())
+            + usize::from(!self.nin.is_undefined())
+            + usize::from(!self.null.is_undefined())
+            + usize::from(!self.lt.is_undefined())
+            + usize::from(!self.lte.is_undefined())
+            + usize::from(!self.gt.is_undefined())
+            + usize::from(!self.gte.is_undefined())
+            != 1
+        {
+            return None;
+        }
+        Some(Self {
+            eq: self.neq,
+            neq: self.eq,
+            r#in: self.nin,
+            nin: self.r#in,
+            null: match self.null {
+                MaybeUndefined::Value(is_null) => MaybeUndefined::Value(!is_null),
+                other => other,
+            },
+            lt: self.gte,
+            lte: self.gt,
+            gt: self.lte,
+            gte: self.lt,
+        })
+    }
+}
 /// User filtering options.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -5856,6 +12870,151 @@ pub struct NullableUserFilter {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub or: MaybeUndefined<Vec<NullableUserFilter>>,
 }
+impl NullableUserFilter {
+    /// Comparator for the identifier.
+    pub fn id(value: IDComparator) -> Self {
+        Self {
+            id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the created at date.
+    pub fn created_at(value: DateComparator) -> Self {
+        Self {
+            created_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the updated at date.
+    pub fn updated_at(value: DateComparator) -> Self {
+        Self {
+            updated_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the user's name.
+    pub fn name(value: StringComparator) -> Self {
+        Self {
+            name: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the user's display name.
+    pub fn display_name(value: StringComparator) -> Self {
+        Self {
+            display_name: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the user's email.
+    pub fn email(value: StringComparator) -> Self {
+        Self {
+            email: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the user's activity status.
+    pub fn active(value: BooleanComparator) -> Self {
+        Self {
+            active: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the users assigned issues must satisfy.
+    pub fn assigned_issues(value: IssueCollectionFilter) -> Self {
+        Self {
+            assigned_issues: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the user's admin status.
+    pub fn admin(value: BooleanComparator) -> Self {
+        Self {
+            admin: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the user's owner status.
+    pub fn owner(value: BooleanComparator) -> Self {
+        Self {
+            owner: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the user's invited status.
+    pub fn invited(value: BooleanComparator) -> Self {
+        Self {
+            invited: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the user's invited status.
+    pub fn is_invited(value: BooleanComparator) -> Self {
+        Self {
+            is_invited: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the user's app status.
+    pub fn app(value: BooleanComparator) -> Self {
+        Self {
+            app: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filter based on the currently authenticated user. Set to true to filter for the authenticated user, false for any other user.
+    pub fn is_me(value: BooleanComparator) -> Self {
+        Self {
+            is_me: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filter based on the existence of the relation.
+    pub fn null(value: impl Into<bool>) -> Self {
+        Self {
+            null: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Match records matching every filter. A single filter is returned
+    /// as is, and no filters give the empty filter, which matches everything.
+    pub fn all(filters: impl IntoIterator<Item = Self>) -> Self {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => Self::default(),
+            1 => filters.remove(0),
+            _ => Self {
+                and: MaybeUndefined::Value(filters),
+                ..Default::default()
+            },
+        }
+    }
+    /// Match records matching any of the filters. A single filter is
+    /// returned as is. No filters give `None`: a disjunction of nothing
+    /// matches nothing, which no filter expresses.
+    pub fn any(filters: impl IntoIterator<Item = Self>) -> Option<Self> {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => None,
+            1 => Some(filters.remove(0)),
+            _ => Some(Self {
+                or: MaybeUndefined::Value(filters),
+                ..Default::default()
+            }),
+        }
+    }
+    /// Match records matching both this filter and `other`.
+    pub fn and(self, other: Self) -> Self {
+        Self::all([self, other])
+    }
+    /// Match records matching this filter or `other`.
+    pub fn or(self, other: Self) -> Self {
+        Self {
+            or: MaybeUndefined::Value(vec![self, other]),
+            ..Default::default()
+        }
+    }
+}
 /// Comparator for numbers.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -5885,6 +13044,93 @@ pub struct NumberComparator {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub gte: MaybeUndefined<f64>,
 }
+impl NumberComparator {
+    /// Equals constraint.
+    pub fn eq(value: impl Into<f64>) -> Self {
+        Self {
+            eq: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Not-equals constraint.
+    pub fn neq(value: impl Into<f64>) -> Self {
+        Self {
+            neq: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// In-array constraint.
+    pub fn in_(values: impl IntoIterator<Item = impl Into<f64>>) -> Self {
+        Self {
+            r#in: MaybeUndefined::Value(values.into_iter().map(Into::into).collect()),
+            ..Default::default()
+        }
+    }
+    /// Not-in-array constraint.
+    pub fn nin(values: impl IntoIterator<Item = impl Into<f64>>) -> Self {
+        Self {
+            nin: MaybeUndefined::Value(values.into_iter().map(Into::into).collect()),
+            ..Default::default()
+        }
+    }
+    /// Less-than constraint. Matches any values that are less than the given value.
+    pub fn lt(value: impl Into<f64>) -> Self {
+        Self {
+            lt: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Less-than-or-equal constraint. Matches any values that are less than or equal to the given value.
+    pub fn lte(value: impl Into<f64>) -> Self {
+        Self {
+            lte: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Greater-than constraint. Matches any values that are greater than the given value.
+    pub fn gt(value: impl Into<f64>) -> Self {
+        Self {
+            gt: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Greater-than-or-equal constraint. Matches any values that are greater than or equal to the given value.
+    pub fn gte(value: impl Into<f64>) -> Self {
+        Self {
+            gte: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+}
+impl NumberComparator {
+    /// The negated comparator (`eq` ↔ `neq`, `lt` ↔ `gte`, …), or
+    /// `None` unless exactly one constraint is set: negating several
+    /// would need an `or`, which comparators don't have.
+    pub fn negate(self) -> Option<Self> {
+        if usize::from(!self.eq.is_undefined())
+            + usize::from(!self.neq.is_undefined())
+            + usize::from(!self.r#in.is_undefined())
+            + usize::from(!self.nin.is_undefined())
+            + usize::from(!self.lt.is_undefined())
+            + usize::from(!self.lte.is_undefined())
+            + usize::from(!self.gt.is_undefined())
+            + usize::from(!self.gte.is_undefined())
+            != 1
+        {
+            return None;
+        }
+        Some(Self {
+            eq: self.neq,
+            neq: self.eq,
+            r#in: self.nin,
+            nin: self.r#in,
+            lt: self.gte,
+            lte: self.gt,
+            gt: self.lte,
+            gte: self.lt,
+        })
+    }
+}
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct OnboardingCustomerSurvey {
@@ -6383,6 +13629,340 @@ pub struct ProjectCollectionFilter {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub length: MaybeUndefined<NumberComparator>,
 }
+impl ProjectCollectionFilter {
+    /// Comparator for the identifier.
+    pub fn id(value: IDComparator) -> Self {
+        Self {
+            id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the created at date.
+    pub fn created_at(value: DateComparator) -> Self {
+        Self {
+            created_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the updated at date.
+    pub fn updated_at(value: DateComparator) -> Self {
+        Self {
+            updated_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the project name.
+    pub fn name(value: StringComparator) -> Self {
+        Self {
+            name: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the project slug ID.
+    pub fn slug_id(value: StringComparator) -> Self {
+        Self {
+            slug_id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// `DEPRECATED` Comparator for the project state.
+    pub fn state(value: StringComparator) -> Self {
+        Self {
+            state: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the project's status must satisfy.
+    pub fn status(value: ProjectStatusFilter) -> Self {
+        Self {
+            status: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the projects priority.
+    pub fn priority(value: NullableNumberComparator) -> Self {
+        Self {
+            priority: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that project labels must satisfy.
+    pub fn labels(value: ProjectLabelCollectionFilter) -> Self {
+        Self {
+            labels: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// `Internal` Comparator for the project's content.
+    pub fn searchable_content(value: ContentComparator) -> Self {
+        Self {
+            searchable_content: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the project started date (when it was moved to an "In Progress" status).
+    pub fn started_at(value: NullableDateComparator) -> Self {
+        Self {
+            started_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the project completion date.
+    pub fn completed_at(value: NullableDateComparator) -> Self {
+        Self {
+            completed_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the project cancelation date.
+    pub fn canceled_at(value: NullableDateComparator) -> Self {
+        Self {
+            canceled_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the project start date.
+    pub fn start_date(value: NullableDateComparator) -> Self {
+        Self {
+            start_date: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the project target date.
+    pub fn target_date(value: NullableDateComparator) -> Self {
+        Self {
+            target_date: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the project health: onTrack, atRisk, offTrack
+    pub fn health(value: StringComparator) -> Self {
+        Self {
+            health: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the project health (with age): onTrack, atRisk, offTrack, outdated, noUpdate
+    pub fn health_with_age(value: StringComparator) -> Self {
+        Self {
+            health_with_age: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// `ALPHA` Comparator for the project activity type: buzzin, active, some, none
+    pub fn activity_type(value: StringComparator) -> Self {
+        Self {
+            activity_type: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for filtering projects with relations.
+    pub fn has_related_relations(value: RelationExistsComparator) -> Self {
+        Self {
+            has_related_relations: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// `Deprecated` Comparator for filtering projects which this is depended on by.
+    pub fn has_depended_on_by_relations(value: RelationExistsComparator) -> Self {
+        Self {
+            has_depended_on_by_relations: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// `Deprecated`Comparator for filtering projects which this depends on.
+    pub fn has_depends_on_relations(value: RelationExistsComparator) -> Self {
+        Self {
+            has_depends_on_relations: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for filtering projects which are blocked.
+    pub fn has_blocked_by_relations(value: RelationExistsComparator) -> Self {
+        Self {
+            has_blocked_by_relations: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for filtering projects which are blocking.
+    pub fn has_blocking_relations(value: RelationExistsComparator) -> Self {
+        Self {
+            has_blocking_relations: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for filtering projects with violated dependencies.
+    pub fn has_violated_relations(value: RelationExistsComparator) -> Self {
+        Self {
+            has_violated_relations: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the project updates.
+    pub fn project_updates(value: ProjectUpdatesCollectionFilter) -> Self {
+        Self {
+            project_updates: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the projects creator must satisfy.
+    pub fn creator(value: UserFilter) -> Self {
+        Self {
+            creator: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the projects lead must satisfy.
+    pub fn lead(value: NullableUserFilter) -> Self {
+        Self {
+            lead: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the projects members must satisfy.
+    pub fn members(value: UserCollectionFilter) -> Self {
+        Self {
+            members: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the projects issues must satisfy.
+    pub fn issues(value: IssueCollectionFilter) -> Self {
+        Self {
+            issues: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the projects roadmaps must satisfy.
+    pub fn roadmaps(value: RoadmapCollectionFilter) -> Self {
+        Self {
+            roadmaps: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the projects initiatives must satisfy.
+    pub fn initiatives(value: InitiativeCollectionFilter) -> Self {
+        Self {
+            initiatives: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the project's milestones must satisfy.
+    pub fn project_milestones(value: ProjectMilestoneCollectionFilter) -> Self {
+        Self {
+            project_milestones: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the project's completed milestones must satisfy.
+    pub fn completed_project_milestones(value: ProjectMilestoneCollectionFilter) -> Self {
+        Self {
+            completed_project_milestones: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the project's next milestone must satisfy.
+    pub fn next_project_milestone(value: ProjectMilestoneFilter) -> Self {
+        Self {
+            next_project_milestone: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the project's team must satisfy.
+    pub fn accessible_teams(value: TeamCollectionFilter) -> Self {
+        Self {
+            accessible_teams: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the last applied template must satisfy.
+    pub fn last_applied_template(value: NullableTemplateFilter) -> Self {
+        Self {
+            last_applied_template: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the project's customer needs must satisfy.
+    pub fn needs(value: CustomerNeedCollectionFilter) -> Self {
+        Self {
+            needs: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Count of customers
+    pub fn customer_count(value: NumberComparator) -> Self {
+        Self {
+            customer_count: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Count of important customers
+    pub fn customer_important_count(value: NumberComparator) -> Self {
+        Self {
+            customer_important_count: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that needs to be matched by some projects.
+    pub fn some(value: ProjectFilter) -> Self {
+        Self {
+            some: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that needs to be matched by all projects.
+    pub fn every(value: ProjectFilter) -> Self {
+        Self {
+            every: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the collection length.
+    pub fn length(value: NumberComparator) -> Self {
+        Self {
+            length: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Match records matching every filter. A single filter is returned
+    /// as is, and no filters give the empty filter, which matches everything.
+    pub fn all(filters: impl IntoIterator<Item = Self>) -> Self {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => Self::default(),
+            1 => filters.remove(0),
+            _ => Self {
+                and: MaybeUndefined::Value(filters),
+                ..Default::default()
+            },
+        }
+    }
+    /// Match records matching any of the filters. A single filter is
+    /// returned as is. No filters give `None`: a disjunction of nothing
+    /// matches nothing, which no filter expresses.
+    pub fn any(filters: impl IntoIterator<Item = Self>) -> Option<Self> {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => None,
+            1 => Some(filters.remove(0)),
+            _ => Some(Self {
+                or: MaybeUndefined::Value(filters),
+                ..Default::default()
+            }),
+        }
+    }
+    /// Match records matching both this filter and `other`.
+    pub fn and(self, other: Self) -> Self {
+        Self::all([self, other])
+    }
+    /// Match records matching this filter or `other`.
+    pub fn or(self, other: Self) -> Self {
+        Self {
+            or: MaybeUndefined::Value(vec![self, other]),
+            ..Default::default()
+        }
+    }
+}
 /// Input for creating a new project. A name and at least one team are required. All other fields are optional and will use defaults if not specified.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -6591,6 +14171,319 @@ pub struct ProjectFilter {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub or: MaybeUndefined<Vec<ProjectFilter>>,
 }
+impl ProjectFilter {
+    /// Comparator for the identifier.
+    pub fn id(value: IDComparator) -> Self {
+        Self {
+            id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the created at date.
+    pub fn created_at(value: DateComparator) -> Self {
+        Self {
+            created_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the updated at date.
+    pub fn updated_at(value: DateComparator) -> Self {
+        Self {
+            updated_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the project name.
+    pub fn name(value: StringComparator) -> Self {
+        Self {
+            name: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the project slug ID.
+    pub fn slug_id(value: StringComparator) -> Self {
+        Self {
+            slug_id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// `DEPRECATED` Comparator for the project state.
+    pub fn state(value: StringComparator) -> Self {
+        Self {
+            state: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the project's status must satisfy.
+    pub fn status(value: ProjectStatusFilter) -> Self {
+        Self {
+            status: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the projects priority.
+    pub fn priority(value: NullableNumberComparator) -> Self {
+        Self {
+            priority: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that project labels must satisfy.
+    pub fn labels(value: ProjectLabelCollectionFilter) -> Self {
+        Self {
+            labels: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// `Internal` Comparator for the project's content.
+    pub fn searchable_content(value: ContentComparator) -> Self {
+        Self {
+            searchable_content: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the project started date (when it was moved to an "In Progress" status).
+    pub fn started_at(value: NullableDateComparator) -> Self {
+        Self {
+            started_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the project completion date.
+    pub fn completed_at(value: NullableDateComparator) -> Self {
+        Self {
+            completed_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the project cancelation date.
+    pub fn canceled_at(value: NullableDateComparator) -> Self {
+        Self {
+            canceled_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the project start date.
+    pub fn start_date(value: NullableDateComparator) -> Self {
+        Self {
+            start_date: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the project target date.
+    pub fn target_date(value: NullableDateComparator) -> Self {
+        Self {
+            target_date: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the project health: onTrack, atRisk, offTrack
+    pub fn health(value: StringComparator) -> Self {
+        Self {
+            health: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the project health (with age): onTrack, atRisk, offTrack, outdated, noUpdate
+    pub fn health_with_age(value: StringComparator) -> Self {
+        Self {
+            health_with_age: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// `ALPHA` Comparator for the project activity type: buzzin, active, some, none
+    pub fn activity_type(value: StringComparator) -> Self {
+        Self {
+            activity_type: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for filtering projects with relations.
+    pub fn has_related_relations(value: RelationExistsComparator) -> Self {
+        Self {
+            has_related_relations: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// `Deprecated` Comparator for filtering projects which this is depended on by.
+    pub fn has_depended_on_by_relations(value: RelationExistsComparator) -> Self {
+        Self {
+            has_depended_on_by_relations: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// `Deprecated`Comparator for filtering projects which this depends on.
+    pub fn has_depends_on_relations(value: RelationExistsComparator) -> Self {
+        Self {
+            has_depends_on_relations: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for filtering projects which are blocked.
+    pub fn has_blocked_by_relations(value: RelationExistsComparator) -> Self {
+        Self {
+            has_blocked_by_relations: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for filtering projects which are blocking.
+    pub fn has_blocking_relations(value: RelationExistsComparator) -> Self {
+        Self {
+            has_blocking_relations: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for filtering projects with violated dependencies.
+    pub fn has_violated_relations(value: RelationExistsComparator) -> Self {
+        Self {
+            has_violated_relations: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the project updates.
+    pub fn project_updates(value: ProjectUpdatesCollectionFilter) -> Self {
+        Self {
+            project_updates: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the projects creator must satisfy.
+    pub fn creator(value: UserFilter) -> Self {
+        Self {
+            creator: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the projects lead must satisfy.
+    pub fn lead(value: NullableUserFilter) -> Self {
+        Self {
+            lead: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the projects members must satisfy.
+    pub fn members(value: UserCollectionFilter) -> Self {
+        Self {
+            members: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the projects issues must satisfy.
+    pub fn issues(value: IssueCollectionFilter) -> Self {
+        Self {
+            issues: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the projects roadmaps must satisfy.
+    pub fn roadmaps(value: RoadmapCollectionFilter) -> Self {
+        Self {
+            roadmaps: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the projects initiatives must satisfy.
+    pub fn initiatives(value: InitiativeCollectionFilter) -> Self {
+        Self {
+            initiatives: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the project's milestones must satisfy.
+    pub fn project_milestones(value: ProjectMilestoneCollectionFilter) -> Self {
+        Self {
+            project_milestones: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the project's completed milestones must satisfy.
+    pub fn completed_project_milestones(value: ProjectMilestoneCollectionFilter) -> Self {
+        Self {
+            completed_project_milestones: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the project's next milestone must satisfy.
+    pub fn next_project_milestone(value: ProjectMilestoneFilter) -> Self {
+        Self {
+            next_project_milestone: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the project's team must satisfy.
+    pub fn accessible_teams(value: TeamCollectionFilter) -> Self {
+        Self {
+            accessible_teams: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the last applied template must satisfy.
+    pub fn last_applied_template(value: NullableTemplateFilter) -> Self {
+        Self {
+            last_applied_template: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the project's customer needs must satisfy.
+    pub fn needs(value: CustomerNeedCollectionFilter) -> Self {
+        Self {
+            needs: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Count of customers
+    pub fn customer_count(value: NumberComparator) -> Self {
+        Self {
+            customer_count: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Count of important customers
+    pub fn customer_important_count(value: NumberComparator) -> Self {
+        Self {
+            customer_important_count: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Match records matching every filter. A single filter is returned
+    /// as is, and no filters give the empty filter, which matches everything.
+    pub fn all(filters: impl IntoIterator<Item = Self>) -> Self {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => Self::default(),
+            1 => filters.remove(0),
+            _ => Self {
+                and: MaybeUndefined::Value(filters),
+                ..Default::default()
+            },
+        }
+    }
+    /// Match records matching any of the filters. A single filter is
+    /// returned as is. No filters give `None`: a disjunction of nothing
+    /// matches nothing, which no filter expresses.
+    pub fn any(filters: impl IntoIterator<Item = Self>) -> Option<Self> {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => None,
+            1 => Some(filters.remove(0)),
+            _ => Some(Self {
+                or: MaybeUndefined::Value(filters),
+                ..Default::default()
+            }),
+        }
+    }
+    /// Match records matching both this filter and `other`.
+    pub fn and(self, other: Self) -> Self {
+        Self::all([self, other])
+    }
+    /// Match records matching this filter or `other`.
+    pub fn or(self, other: Self) -> Self {
+        Self {
+            or: MaybeUndefined::Value(vec![self, other]),
+            ..Default::default()
+        }
+    }
+}
 /// Project health sorting options.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -6646,6 +14539,123 @@ pub struct ProjectLabelCollectionFilter {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub length: MaybeUndefined<NumberComparator>,
 }
+impl ProjectLabelCollectionFilter {
+    /// Comparator for the identifier.
+    pub fn id(value: IDComparator) -> Self {
+        Self {
+            id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the created at date.
+    pub fn created_at(value: DateComparator) -> Self {
+        Self {
+            created_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the updated at date.
+    pub fn updated_at(value: DateComparator) -> Self {
+        Self {
+            updated_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the name.
+    pub fn name(value: StringComparator) -> Self {
+        Self {
+            name: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for whether the label is a group label.
+    pub fn is_group(value: BooleanComparator) -> Self {
+        Self {
+            is_group: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the project labels creator must satisfy.
+    pub fn creator(value: NullableUserFilter) -> Self {
+        Self {
+            creator: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the project label's parent label must satisfy.
+    pub fn parent(value: ProjectLabelFilter) -> Self {
+        Self {
+            parent: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filter based on the existence of the relation.
+    pub fn null(value: impl Into<bool>) -> Self {
+        Self {
+            null: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Filters that needs to be matched by some project labels.
+    pub fn some(value: ProjectLabelCollectionFilter) -> Self {
+        Self {
+            some: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that needs to be matched by all project labels.
+    pub fn every(value: ProjectLabelFilter) -> Self {
+        Self {
+            every: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the collection length.
+    pub fn length(value: NumberComparator) -> Self {
+        Self {
+            length: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Match records matching every filter. A single filter is returned
+    /// as is, and no filters give the empty filter, which matches everything.
+    pub fn all(filters: impl IntoIterator<Item = Self>) -> Self {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => Self::default(),
+            1 => filters.remove(0),
+            _ => Self {
+                and: MaybeUndefined::Value(filters),
+                ..Default::default()
+            },
+        }
+    }
+    /// Match records matching any of the filters. A single filter is
+    /// returned as is. No filters give `None`: a disjunction of nothing
+    /// matches nothing, which no filter expresses.
+    pub fn any(filters: impl IntoIterator<Item = Self>) -> Option<Self> {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => None,
+            1 => Some(filters.remove(0)),
+            _ => Some(Self {
+                or: MaybeUndefined::Value(filters),
+                ..Default::default()
+            }),
+        }
+    }
+    /// Match records matching both this filter and `other`.
+    pub fn and(self, other: Self) -> Self {
+        Self::all([self, other])
+    }
+    /// Match records matching this filter or `other`.
+    pub fn or(self, other: Self) -> Self {
+        Self {
+            or: MaybeUndefined::Value(vec![self, other]),
+            ..Default::default()
+        }
+    }
+}
 /// Input for creating a new project label. A name is required. The label is created as a workspace-level label available to all projects.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -6703,6 +14713,95 @@ pub struct ProjectLabelFilter {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub or: MaybeUndefined<Vec<ProjectLabelFilter>>,
 }
+impl ProjectLabelFilter {
+    /// Comparator for the identifier.
+    pub fn id(value: IDComparator) -> Self {
+        Self {
+            id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the created at date.
+    pub fn created_at(value: DateComparator) -> Self {
+        Self {
+            created_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the updated at date.
+    pub fn updated_at(value: DateComparator) -> Self {
+        Self {
+            updated_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the name.
+    pub fn name(value: StringComparator) -> Self {
+        Self {
+            name: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for whether the label is a group label.
+    pub fn is_group(value: BooleanComparator) -> Self {
+        Self {
+            is_group: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the project labels creator must satisfy.
+    pub fn creator(value: NullableUserFilter) -> Self {
+        Self {
+            creator: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the project label's parent label must satisfy.
+    pub fn parent(value: ProjectLabelFilter) -> Self {
+        Self {
+            parent: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Match records matching every filter. A single filter is returned
+    /// as is, and no filters give the empty filter, which matches everything.
+    pub fn all(filters: impl IntoIterator<Item = Self>) -> Self {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => Self::default(),
+            1 => filters.remove(0),
+            _ => Self {
+                and: MaybeUndefined::Value(filters),
+                ..Default::default()
+            },
+        }
+    }
+    /// Match records matching any of the filters. A single filter is
+    /// returned as is. No filters give `None`: a disjunction of nothing
+    /// matches nothing, which no filter expresses.
+    pub fn any(filters: impl IntoIterator<Item = Self>) -> Option<Self> {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => None,
+            1 => Some(filters.remove(0)),
+            _ => Some(Self {
+                or: MaybeUndefined::Value(filters),
+                ..Default::default()
+            }),
+        }
+    }
+    /// Match records matching both this filter and `other`.
+    pub fn and(self, other: Self) -> Self {
+        Self::all([self, other])
+    }
+    /// Match records matching this filter or `other`.
+    pub fn or(self, other: Self) -> Self {
+        Self {
+            or: MaybeUndefined::Value(vec![self, other]),
+            ..Default::default()
+        }
+    }
+}
 /// Input for updating an existing project label. All fields are optional; only provided fields will be updated.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -6786,6 +14885,109 @@ pub struct ProjectMilestoneCollectionFilter {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub length: MaybeUndefined<NumberComparator>,
 }
+impl ProjectMilestoneCollectionFilter {
+    /// Comparator for the identifier.
+    pub fn id(value: IDComparator) -> Self {
+        Self {
+            id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the created at date.
+    pub fn created_at(value: DateComparator) -> Self {
+        Self {
+            created_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the updated at date.
+    pub fn updated_at(value: DateComparator) -> Self {
+        Self {
+            updated_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the project milestone name.
+    pub fn name(value: NullableStringComparator) -> Self {
+        Self {
+            name: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the project milestone target date.
+    pub fn target_date(value: NullableDateComparator) -> Self {
+        Self {
+            target_date: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the project milestone's project must satisfy.
+    pub fn project(value: NullableProjectFilter) -> Self {
+        Self {
+            project: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that needs to be matched by some milestones.
+    pub fn some(value: ProjectMilestoneFilter) -> Self {
+        Self {
+            some: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that needs to be matched by all milestones.
+    pub fn every(value: ProjectMilestoneFilter) -> Self {
+        Self {
+            every: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the collection length.
+    pub fn length(value: NumberComparator) -> Self {
+        Self {
+            length: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Match records matching every filter. A single filter is returned
+    /// as is, and no filters give the empty filter, which matches everything.
+    pub fn all(filters: impl IntoIterator<Item = Self>) -> Self {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => Self::default(),
+            1 => filters.remove(0),
+            _ => Self {
+                and: MaybeUndefined::Value(filters),
+                ..Default::default()
+            },
+        }
+    }
+    /// Match records matching any of the filters. A single filter is
+    /// returned as is. No filters give `None`: a disjunction of nothing
+    /// matches nothing, which no filter expresses.
+    pub fn any(filters: impl IntoIterator<Item = Self>) -> Option<Self> {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => None,
+            1 => Some(filters.remove(0)),
+            _ => Some(Self {
+                or: MaybeUndefined::Value(filters),
+                ..Default::default()
+            }),
+        }
+    }
+    /// Match records matching both this filter and `other`.
+    pub fn and(self, other: Self) -> Self {
+        Self::all([self, other])
+    }
+    /// Match records matching this filter or `other`.
+    pub fn or(self, other: Self) -> Self {
+        Self {
+            or: MaybeUndefined::Value(vec![self, other]),
+            ..Default::default()
+        }
+    }
+}
 /// Input for creating a new project milestone.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -6839,6 +15041,88 @@ pub struct ProjectMilestoneFilter {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub or: MaybeUndefined<Vec<ProjectMilestoneFilter>>,
 }
+impl ProjectMilestoneFilter {
+    /// Comparator for the identifier.
+    pub fn id(value: IDComparator) -> Self {
+        Self {
+            id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the created at date.
+    pub fn created_at(value: DateComparator) -> Self {
+        Self {
+            created_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the updated at date.
+    pub fn updated_at(value: DateComparator) -> Self {
+        Self {
+            updated_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the project milestone name.
+    pub fn name(value: NullableStringComparator) -> Self {
+        Self {
+            name: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the project milestone target date.
+    pub fn target_date(value: NullableDateComparator) -> Self {
+        Self {
+            target_date: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the project milestone's project must satisfy.
+    pub fn project(value: NullableProjectFilter) -> Self {
+        Self {
+            project: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Match records matching every filter. A single filter is returned
+    /// as is, and no filters give the empty filter, which matches everything.
+    pub fn all(filters: impl IntoIterator<Item = Self>) -> Self {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => Self::default(),
+            1 => filters.remove(0),
+            _ => Self {
+                and: MaybeUndefined::Value(filters),
+                ..Default::default()
+            },
+        }
+    }
+    /// Match records matching any of the filters. A single filter is
+    /// returned as is. No filters give `None`: a disjunction of nothing
+    /// matches nothing, which no filter expresses.
+    pub fn any(filters: impl IntoIterator<Item = Self>) -> Option<Self> {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => None,
+            1 => Some(filters.remove(0)),
+            _ => Some(Self {
+                or: MaybeUndefined::Value(filters),
+                ..Default::default()
+            }),
+        }
+    }
+    /// Match records matching both this filter and `other`.
+    pub fn and(self, other: Self) -> Self {
+        Self::all([self, other])
+    }
+    /// Match records matching this filter or `other`.
+    pub fn or(self, other: Self) -> Self {
+        Self {
+            or: MaybeUndefined::Value(vec![self, other]),
+            ..Default::default()
+        }
+    }
+}
 /// `Internal` Input for moving a project milestone to another project.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -7077,6 +15361,102 @@ pub struct ProjectStatusFilter {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub or: MaybeUndefined<Vec<ProjectStatusFilter>>,
 }
+impl ProjectStatusFilter {
+    /// Comparator for the identifier.
+    pub fn id(value: IDComparator) -> Self {
+        Self {
+            id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the created at date.
+    pub fn created_at(value: DateComparator) -> Self {
+        Self {
+            created_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the updated at date.
+    pub fn updated_at(value: DateComparator) -> Self {
+        Self {
+            updated_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the project status name.
+    pub fn name(value: StringComparator) -> Self {
+        Self {
+            name: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the project status description.
+    pub fn description(value: StringComparator) -> Self {
+        Self {
+            description: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the project status position.
+    pub fn position(value: NumberComparator) -> Self {
+        Self {
+            position: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the project status type.
+    pub fn type_(value: StringComparator) -> Self {
+        Self {
+            r#type: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the project status projects must satisfy.
+    pub fn projects(value: ProjectCollectionFilter) -> Self {
+        Self {
+            projects: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Match records matching every filter. A single filter is returned
+    /// as is, and no filters give the empty filter, which matches everything.
+    pub fn all(filters: impl IntoIterator<Item = Self>) -> Self {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => Self::default(),
+            1 => filters.remove(0),
+            _ => Self {
+                and: MaybeUndefined::Value(filters),
+                ..Default::default()
+            },
+        }
+    }
+    /// Match records matching any of the filters. A single filter is
+    /// returned as is. No filters give `None`: a disjunction of nothing
+    /// matches nothing, which no filter expresses.
+    pub fn any(filters: impl IntoIterator<Item = Self>) -> Option<Self> {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => None,
+            1 => Some(filters.remove(0)),
+            _ => Some(Self {
+                or: MaybeUndefined::Value(filters),
+                ..Default::default()
+            }),
+        }
+    }
+    /// Match records matching both this filter and `other`.
+    pub fn and(self, other: Self) -> Self {
+        Self::all([self, other])
+    }
+    /// Match records matching this filter or `other`.
+    pub fn or(self, other: Self) -> Self {
+        Self {
+            or: MaybeUndefined::Value(vec![self, other]),
+            ..Default::default()
+        }
+    }
+}
 /// Project status sorting options.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -7162,6 +15542,88 @@ pub struct ProjectUpdateFilter {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub or: MaybeUndefined<Vec<ProjectUpdateFilter>>,
 }
+impl ProjectUpdateFilter {
+    /// Comparator for the identifier.
+    pub fn id(value: IDComparator) -> Self {
+        Self {
+            id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the created at date.
+    pub fn created_at(value: DateComparator) -> Self {
+        Self {
+            created_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the updated at date.
+    pub fn updated_at(value: DateComparator) -> Self {
+        Self {
+            updated_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the project update creator must satisfy.
+    pub fn user(value: UserFilter) -> Self {
+        Self {
+            user: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the project update project must satisfy.
+    pub fn project(value: ProjectFilter) -> Self {
+        Self {
+            project: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the project updates reactions must satisfy.
+    pub fn reactions(value: ReactionCollectionFilter) -> Self {
+        Self {
+            reactions: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Match records matching every filter. A single filter is returned
+    /// as is, and no filters give the empty filter, which matches everything.
+    pub fn all(filters: impl IntoIterator<Item = Self>) -> Self {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => Self::default(),
+            1 => filters.remove(0),
+            _ => Self {
+                and: MaybeUndefined::Value(filters),
+                ..Default::default()
+            },
+        }
+    }
+    /// Match records matching any of the filters. A single filter is
+    /// returned as is. No filters give `None`: a disjunction of nothing
+    /// matches nothing, which no filter expresses.
+    pub fn any(filters: impl IntoIterator<Item = Self>) -> Option<Self> {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => None,
+            1 => Some(filters.remove(0)),
+            _ => Some(Self {
+                or: MaybeUndefined::Value(filters),
+                ..Default::default()
+            }),
+        }
+    }
+    /// Match records matching both this filter and `other`.
+    pub fn and(self, other: Self) -> Self {
+        Self::all([self, other])
+    }
+    /// Match records matching this filter or `other`.
+    pub fn or(self, other: Self) -> Self {
+        Self {
+            or: MaybeUndefined::Value(vec![self, other]),
+            ..Default::default()
+        }
+    }
+}
 /// Input for updating an existing project. All fields are optional; only provided fields will be updated. Setting a field to null (where supported) will clear the value.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -7320,6 +15782,95 @@ pub struct ProjectUpdatesCollectionFilter {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub length: MaybeUndefined<NumberComparator>,
 }
+impl ProjectUpdatesCollectionFilter {
+    /// Comparator for the identifier.
+    pub fn id(value: IDComparator) -> Self {
+        Self {
+            id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the created at date.
+    pub fn created_at(value: DateComparator) -> Self {
+        Self {
+            created_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the updated at date.
+    pub fn updated_at(value: DateComparator) -> Self {
+        Self {
+            updated_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the project update health.
+    pub fn health(value: StringComparator) -> Self {
+        Self {
+            health: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that needs to be matched by some updates.
+    pub fn some(value: ProjectUpdatesFilter) -> Self {
+        Self {
+            some: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that needs to be matched by all updates.
+    pub fn every(value: ProjectUpdatesFilter) -> Self {
+        Self {
+            every: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the collection length.
+    pub fn length(value: NumberComparator) -> Self {
+        Self {
+            length: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Match records matching every filter. A single filter is returned
+    /// as is, and no filters give the empty filter, which matches everything.
+    pub fn all(filters: impl IntoIterator<Item = Self>) -> Self {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => Self::default(),
+            1 => filters.remove(0),
+            _ => Self {
+                and: MaybeUndefined::Value(filters),
+                ..Default::default()
+            },
+        }
+    }
+    /// Match records matching any of the filters. A single filter is
+    /// returned as is. No filters give `None`: a disjunction of nothing
+    /// matches nothing, which no filter expresses.
+    pub fn any(filters: impl IntoIterator<Item = Self>) -> Option<Self> {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => None,
+            1 => Some(filters.remove(0)),
+            _ => Some(Self {
+                or: MaybeUndefined::Value(filters),
+                ..Default::default()
+            }),
+        }
+    }
+    /// Match records matching both this filter and `other`.
+    pub fn and(self, other: Self) -> Self {
+        Self::all([self, other])
+    }
+    /// Match records matching this filter or `other`.
+    pub fn or(self, other: Self) -> Self {
+        Self {
+            or: MaybeUndefined::Value(vec![self, other]),
+            ..Default::default()
+        }
+    }
+}
 /// Options for filtering projects by project updates.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -7343,6 +15894,74 @@ pub struct ProjectUpdatesFilter {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub or: MaybeUndefined<Vec<ProjectUpdatesFilter>>,
 }
+impl ProjectUpdatesFilter {
+    /// Comparator for the identifier.
+    pub fn id(value: IDComparator) -> Self {
+        Self {
+            id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the created at date.
+    pub fn created_at(value: DateComparator) -> Self {
+        Self {
+            created_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the updated at date.
+    pub fn updated_at(value: DateComparator) -> Self {
+        Self {
+            updated_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the project update health.
+    pub fn health(value: StringComparator) -> Self {
+        Self {
+            health: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Match records matching every filter. A single filter is returned
+    /// as is, and no filters give the empty filter, which matches everything.
+    pub fn all(filters: impl IntoIterator<Item = Self>) -> Self {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => Self::default(),
+            1 => filters.remove(0),
+            _ => Self {
+                and: MaybeUndefined::Value(filters),
+                ..Default::default()
+            },
+        }
+    }
+    /// Match records matching any of the filters. A single filter is
+    /// returned as is. No filters give `None`: a disjunction of nothing
+    /// matches nothing, which no filter expresses.
+    pub fn any(filters: impl IntoIterator<Item = Self>) -> Option<Self> {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => None,
+            1 => Some(filters.remove(0)),
+            _ => Some(Self {
+                or: MaybeUndefined::Value(filters),
+                ..Default::default()
+            }),
+        }
+    }
+    /// Match records matching both this filter and `other`.
+    pub fn and(self, other: Self) -> Self {
+        Self::all([self, other])
+    }
+    /// Match records matching this filter or `other`.
+    pub fn or(self, other: Self) -> Self {
+        Self {
+            or: MaybeUndefined::Value(vec![self, other]),
+            ..Default::default()
+        }
+    }
+}
 /// A reference to a pull request by its repository owner, name, and pull request number. Used during release sync to look up pull requests and associate their linked issues with the release.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -7402,6 +16021,102 @@ pub struct ReactionCollectionFilter {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub length: MaybeUndefined<NumberComparator>,
 }
+impl ReactionCollectionFilter {
+    /// Comparator for the identifier.
+    pub fn id(value: IDComparator) -> Self {
+        Self {
+            id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the created at date.
+    pub fn created_at(value: DateComparator) -> Self {
+        Self {
+            created_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the updated at date.
+    pub fn updated_at(value: DateComparator) -> Self {
+        Self {
+            updated_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the reactions emoji.
+    pub fn emoji(value: StringComparator) -> Self {
+        Self {
+            emoji: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the reactions custom emoji.
+    pub fn custom_emoji_id(value: IDComparator) -> Self {
+        Self {
+            custom_emoji_id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that needs to be matched by some reactions.
+    pub fn some(value: ReactionFilter) -> Self {
+        Self {
+            some: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that needs to be matched by all reactions.
+    pub fn every(value: ReactionFilter) -> Self {
+        Self {
+            every: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the collection length.
+    pub fn length(value: NumberComparator) -> Self {
+        Self {
+            length: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Match records matching every filter. A single filter is returned
+    /// as is, and no filters give the empty filter, which matches everything.
+    pub fn all(filters: impl IntoIterator<Item = Self>) -> Self {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => Self::default(),
+            1 => filters.remove(0),
+            _ => Self {
+                and: MaybeUndefined::Value(filters),
+                ..Default::default()
+            },
+        }
+    }
+    /// Match records matching any of the filters. A single filter is
+    /// returned as is. No filters give `None`: a disjunction of nothing
+    /// matches nothing, which no filter expresses.
+    pub fn any(filters: impl IntoIterator<Item = Self>) -> Option<Self> {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => None,
+            1 => Some(filters.remove(0)),
+            _ => Some(Self {
+                or: MaybeUndefined::Value(filters),
+                ..Default::default()
+            }),
+        }
+    }
+    /// Match records matching both this filter and `other`.
+    pub fn and(self, other: Self) -> Self {
+        Self::all([self, other])
+    }
+    /// Match records matching this filter or `other`.
+    pub fn or(self, other: Self) -> Self {
+        Self {
+            or: MaybeUndefined::Value(vec![self, other]),
+            ..Default::default()
+        }
+    }
+}
 /// Input for creating a new reaction.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -7459,6 +16174,81 @@ pub struct ReactionFilter {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub or: MaybeUndefined<Vec<ReactionFilter>>,
 }
+impl ReactionFilter {
+    /// Comparator for the identifier.
+    pub fn id(value: IDComparator) -> Self {
+        Self {
+            id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the created at date.
+    pub fn created_at(value: DateComparator) -> Self {
+        Self {
+            created_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the updated at date.
+    pub fn updated_at(value: DateComparator) -> Self {
+        Self {
+            updated_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the reactions emoji.
+    pub fn emoji(value: StringComparator) -> Self {
+        Self {
+            emoji: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the reactions custom emoji.
+    pub fn custom_emoji_id(value: IDComparator) -> Self {
+        Self {
+            custom_emoji_id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Match records matching every filter. A single filter is returned
+    /// as is, and no filters give the empty filter, which matches everything.
+    pub fn all(filters: impl IntoIterator<Item = Self>) -> Self {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => Self::default(),
+            1 => filters.remove(0),
+            _ => Self {
+                and: MaybeUndefined::Value(filters),
+                ..Default::default()
+            },
+        }
+    }
+    /// Match records matching any of the filters. A single filter is
+    /// returned as is. No filters give `None`: a disjunction of nothing
+    /// matches nothing, which no filter expresses.
+    pub fn any(filters: impl IntoIterator<Item = Self>) -> Option<Self> {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => None,
+            1 => Some(filters.remove(0)),
+            _ => Some(Self {
+                or: MaybeUndefined::Value(filters),
+                ..Default::default()
+            }),
+        }
+    }
+    /// Match records matching both this filter and `other`.
+    pub fn and(self, other: Self) -> Self {
+        Self::all([self, other])
+    }
+    /// Match records matching this filter or `other`.
+    pub fn or(self, other: Self) -> Self {
+        Self {
+            or: MaybeUndefined::Value(vec![self, other]),
+            ..Default::default()
+        }
+    }
+}
 /// Comparator for relation existence.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -7470,6 +16260,36 @@ pub struct RelationExistsComparator {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub neq: MaybeUndefined<bool>,
 }
+impl RelationExistsComparator {
+    /// Equals constraint.
+    pub fn eq(value: impl Into<bool>) -> Self {
+        Self {
+            eq: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Not equals constraint.
+    pub fn neq(value: impl Into<bool>) -> Self {
+        Self {
+            neq: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+}
+impl RelationExistsComparator {
+    /// The negated comparator (`eq` ↔ `neq`, `lt` ↔ `gte`, …), or
+    /// `None` unless exactly one constraint is set: negating several
+    /// would need an `or`, which comparators don't have.
+    pub fn negate(self) -> Option<Self> {
+        if usize::from(!self.eq.is_undefined()) + usize::from(!self.neq.is_undefined()) != 1 {
+            return None;
+        }
+        Some(Self {
+            eq: self.neq,
+            neq: self.eq,
+        })
+    }
+}
 /// `ALPHA` Release collection filtering options.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -7514,6 +16334,123 @@ pub struct ReleaseCollectionFilter {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub length: MaybeUndefined<NumberComparator>,
 }
+impl ReleaseCollectionFilter {
+    /// Comparator for the identifier.
+    pub fn id(value: IDComparator) -> Self {
+        Self {
+            id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the created at date.
+    pub fn created_at(value: DateComparator) -> Self {
+        Self {
+            created_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the updated at date.
+    pub fn updated_at(value: DateComparator) -> Self {
+        Self {
+            updated_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the release name.
+    pub fn name(value: StringComparator) -> Self {
+        Self {
+            name: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the release version.
+    pub fn version(value: StringComparator) -> Self {
+        Self {
+            version: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the release's pipeline must satisfy.
+    pub fn pipeline(value: ReleasePipelineFilter) -> Self {
+        Self {
+            pipeline: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the release's stage must satisfy.
+    pub fn stage(value: ReleaseStageFilter) -> Self {
+        Self {
+            stage: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the release completion date.
+    pub fn completed_at(value: NullableDateComparator) -> Self {
+        Self {
+            completed_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that needs to be matched by some releases.
+    pub fn some(value: ReleaseFilter) -> Self {
+        Self {
+            some: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that needs to be matched by all releases.
+    pub fn every(value: ReleaseFilter) -> Self {
+        Self {
+            every: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the collection length.
+    pub fn length(value: NumberComparator) -> Self {
+        Self {
+            length: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Match records matching every filter. A single filter is returned
+    /// as is, and no filters give the empty filter, which matches everything.
+    pub fn all(filters: impl IntoIterator<Item = Self>) -> Self {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => Self::default(),
+            1 => filters.remove(0),
+            _ => Self {
+                and: MaybeUndefined::Value(filters),
+                ..Default::default()
+            },
+        }
+    }
+    /// Match records matching any of the filters. A single filter is
+    /// returned as is. No filters give `None`: a disjunction of nothing
+    /// matches nothing, which no filter expresses.
+    pub fn any(filters: impl IntoIterator<Item = Self>) -> Option<Self> {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => None,
+            1 => Some(filters.remove(0)),
+            _ => Some(Self {
+                or: MaybeUndefined::Value(filters),
+                ..Default::default()
+            }),
+        }
+    }
+    /// Match records matching both this filter and `other`.
+    pub fn and(self, other: Self) -> Self {
+        Self::all([self, other])
+    }
+    /// Match records matching this filter or `other`.
+    pub fn or(self, other: Self) -> Self {
+        Self {
+            or: MaybeUndefined::Value(vec![self, other]),
+            ..Default::default()
+        }
+    }
+}
 /// Input for completing a release in a specific pipeline.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -7620,6 +16557,102 @@ pub struct ReleaseFilter {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub or: MaybeUndefined<Vec<ReleaseFilter>>,
 }
+impl ReleaseFilter {
+    /// Comparator for the identifier.
+    pub fn id(value: IDComparator) -> Self {
+        Self {
+            id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the created at date.
+    pub fn created_at(value: DateComparator) -> Self {
+        Self {
+            created_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the updated at date.
+    pub fn updated_at(value: DateComparator) -> Self {
+        Self {
+            updated_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the release name.
+    pub fn name(value: StringComparator) -> Self {
+        Self {
+            name: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the release version.
+    pub fn version(value: StringComparator) -> Self {
+        Self {
+            version: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the release's pipeline must satisfy.
+    pub fn pipeline(value: ReleasePipelineFilter) -> Self {
+        Self {
+            pipeline: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the release's stage must satisfy.
+    pub fn stage(value: ReleaseStageFilter) -> Self {
+        Self {
+            stage: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the release completion date.
+    pub fn completed_at(value: NullableDateComparator) -> Self {
+        Self {
+            completed_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Match records matching every filter. A single filter is returned
+    /// as is, and no filters give the empty filter, which matches everything.
+    pub fn all(filters: impl IntoIterator<Item = Self>) -> Self {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => Self::default(),
+            1 => filters.remove(0),
+            _ => Self {
+                and: MaybeUndefined::Value(filters),
+                ..Default::default()
+            },
+        }
+    }
+    /// Match records matching any of the filters. A single filter is
+    /// returned as is. No filters give `None`: a disjunction of nothing
+    /// matches nothing, which no filter expresses.
+    pub fn any(filters: impl IntoIterator<Item = Self>) -> Option<Self> {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => None,
+            1 => Some(filters.remove(0)),
+            _ => Some(Self {
+                or: MaybeUndefined::Value(filters),
+                ..Default::default()
+            }),
+        }
+    }
+    /// Match records matching both this filter and `other`.
+    pub fn and(self, other: Self) -> Self {
+        Self::all([self, other])
+    }
+    /// Match records matching this filter or `other`.
+    pub fn or(self, other: Self) -> Self {
+        Self {
+            or: MaybeUndefined::Value(vec![self, other]),
+            ..Default::default()
+        }
+    }
+}
 /// `ALPHA` Input for creating a release note.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -7678,6 +16711,109 @@ pub struct ReleasePipelineCollectionFilter {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub length: MaybeUndefined<NumberComparator>,
 }
+impl ReleasePipelineCollectionFilter {
+    /// Comparator for the identifier.
+    pub fn id(value: IDComparator) -> Self {
+        Self {
+            id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the created at date.
+    pub fn created_at(value: DateComparator) -> Self {
+        Self {
+            created_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the updated at date.
+    pub fn updated_at(value: DateComparator) -> Self {
+        Self {
+            updated_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the pipeline name.
+    pub fn name(value: StringComparator) -> Self {
+        Self {
+            name: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the pipeline production flag.
+    pub fn is_production(value: BooleanComparator) -> Self {
+        Self {
+            is_production: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the release pipeline's teams must satisfy.
+    pub fn teams(value: TeamCollectionFilter) -> Self {
+        Self {
+            teams: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that needs to be matched by some release pipelines.
+    pub fn some(value: ReleasePipelineFilter) -> Self {
+        Self {
+            some: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that needs to be matched by all release pipelines.
+    pub fn every(value: ReleasePipelineFilter) -> Self {
+        Self {
+            every: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the collection length.
+    pub fn length(value: NumberComparator) -> Self {
+        Self {
+            length: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Match records matching every filter. A single filter is returned
+    /// as is, and no filters give the empty filter, which matches everything.
+    pub fn all(filters: impl IntoIterator<Item = Self>) -> Self {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => Self::default(),
+            1 => filters.remove(0),
+            _ => Self {
+                and: MaybeUndefined::Value(filters),
+                ..Default::default()
+            },
+        }
+    }
+    /// Match records matching any of the filters. A single filter is
+    /// returned as is. No filters give `None`: a disjunction of nothing
+    /// matches nothing, which no filter expresses.
+    pub fn any(filters: impl IntoIterator<Item = Self>) -> Option<Self> {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => None,
+            1 => Some(filters.remove(0)),
+            _ => Some(Self {
+                or: MaybeUndefined::Value(filters),
+                ..Default::default()
+            }),
+        }
+    }
+    /// Match records matching both this filter and `other`.
+    pub fn and(self, other: Self) -> Self {
+        Self::all([self, other])
+    }
+    /// Match records matching this filter or `other`.
+    pub fn or(self, other: Self) -> Self {
+        Self {
+            or: MaybeUndefined::Value(vec![self, other]),
+            ..Default::default()
+        }
+    }
+}
 /// Input for creating a new release pipeline.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -7732,6 +16868,88 @@ pub struct ReleasePipelineFilter {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub or: MaybeUndefined<Vec<ReleasePipelineFilter>>,
 }
+impl ReleasePipelineFilter {
+    /// Comparator for the identifier.
+    pub fn id(value: IDComparator) -> Self {
+        Self {
+            id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the created at date.
+    pub fn created_at(value: DateComparator) -> Self {
+        Self {
+            created_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the updated at date.
+    pub fn updated_at(value: DateComparator) -> Self {
+        Self {
+            updated_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the pipeline name.
+    pub fn name(value: StringComparator) -> Self {
+        Self {
+            name: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the pipeline production flag.
+    pub fn is_production(value: BooleanComparator) -> Self {
+        Self {
+            is_production: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the release pipeline's teams must satisfy.
+    pub fn teams(value: TeamCollectionFilter) -> Self {
+        Self {
+            teams: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Match records matching every filter. A single filter is returned
+    /// as is, and no filters give the empty filter, which matches everything.
+    pub fn all(filters: impl IntoIterator<Item = Self>) -> Self {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => Self::default(),
+            1 => filters.remove(0),
+            _ => Self {
+                and: MaybeUndefined::Value(filters),
+                ..Default::default()
+            },
+        }
+    }
+    /// Match records matching any of the filters. A single filter is
+    /// returned as is. No filters give `None`: a disjunction of nothing
+    /// matches nothing, which no filter expresses.
+    pub fn any(filters: impl IntoIterator<Item = Self>) -> Option<Self> {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => None,
+            1 => Some(filters.remove(0)),
+            _ => Some(Self {
+                or: MaybeUndefined::Value(filters),
+                ..Default::default()
+            }),
+        }
+    }
+    /// Match records matching both this filter and `other`.
+    pub fn and(self, other: Self) -> Self {
+        Self::all([self, other])
+    }
+    /// Match records matching this filter or `other`.
+    pub fn or(self, other: Self) -> Self {
+        Self {
+            or: MaybeUndefined::Value(vec![self, other]),
+            ..Default::default()
+        }
+    }
+}
 /// Release pipeline name sorting options.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -7840,6 +17058,81 @@ pub struct ReleaseStageFilter {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub or: MaybeUndefined<Vec<ReleaseStageFilter>>,
 }
+impl ReleaseStageFilter {
+    /// Comparator for the identifier.
+    pub fn id(value: IDComparator) -> Self {
+        Self {
+            id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the created at date.
+    pub fn created_at(value: DateComparator) -> Self {
+        Self {
+            created_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the updated at date.
+    pub fn updated_at(value: DateComparator) -> Self {
+        Self {
+            updated_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the stage type.
+    pub fn type_(value: ReleaseStageTypeComparator) -> Self {
+        Self {
+            r#type: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the stage name.
+    pub fn name(value: StringComparator) -> Self {
+        Self {
+            name: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Match records matching every filter. A single filter is returned
+    /// as is, and no filters give the empty filter, which matches everything.
+    pub fn all(filters: impl IntoIterator<Item = Self>) -> Self {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => Self::default(),
+            1 => filters.remove(0),
+            _ => Self {
+                and: MaybeUndefined::Value(filters),
+                ..Default::default()
+            },
+        }
+    }
+    /// Match records matching any of the filters. A single filter is
+    /// returned as is. No filters give `None`: a disjunction of nothing
+    /// matches nothing, which no filter expresses.
+    pub fn any(filters: impl IntoIterator<Item = Self>) -> Option<Self> {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => None,
+            1 => Some(filters.remove(0)),
+            _ => Some(Self {
+                or: MaybeUndefined::Value(filters),
+                ..Default::default()
+            }),
+        }
+    }
+    /// Match records matching both this filter and `other`.
+    pub fn and(self, other: Self) -> Self {
+        Self::all([self, other])
+    }
+    /// Match records matching this filter or `other`.
+    pub fn or(self, other: Self) -> Self {
+        Self {
+            or: MaybeUndefined::Value(vec![self, other]),
+            ..Default::default()
+        }
+    }
+}
 /// Release stage sorting options.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -7871,6 +17164,69 @@ pub struct ReleaseStageTypeComparator {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub null: MaybeUndefined<bool>,
 }
+impl ReleaseStageTypeComparator {
+    /// Equals constraint.
+    pub fn eq(value: impl Into<ReleaseStageType>) -> Self {
+        Self {
+            eq: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Not-equals constraint.
+    pub fn neq(value: impl Into<ReleaseStageType>) -> Self {
+        Self {
+            neq: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// In-array constraint.
+    pub fn in_(values: impl IntoIterator<Item = impl Into<ReleaseStageType>>) -> Self {
+        Self {
+            r#in: MaybeUndefined::Value(values.into_iter().map(Into::into).collect()),
+            ..Default::default()
+        }
+    }
+    /// Not-in-array constraint.
+    pub fn nin(values: impl IntoIterator<Item = impl Into<ReleaseStageType>>) -> Self {
+        Self {
+            nin: MaybeUndefined::Value(values.into_iter().map(Into::into).collect()),
+            ..Default::default()
+        }
+    }
+    /// Null constraint. Matches any non-null values if the given value is false, otherwise it matches null values.
+    pub fn null(value: impl Into<bool>) -> Self {
+        Self {
+            null: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+}
+impl ReleaseStageTypeComparator {
+    /// The negated comparator (`eq` ↔ `neq`, `lt` ↔ `gte`, …), or
+    /// `None` unless exactly one constraint is set: negating several
+    /// would need an `or`, which comparators don't have.
+    pub fn negate(self) -> Option<Self> {
+        if usize::from(!self.eq.is_undefined())
+            + usize::from(!self.neq.is_undefined())
+            + usize::from(!self.r#in.is_undefined())
+            + usize::from(!self.nin.is_undefined())
+            + usize::from(!self.null.is_undefined())
+            != 1
+        {
+            return None;
+        }
+        Some(Self {
+            eq: self.neq,
+            neq: self.eq,
+            r#in: self.nin,
+            nin: self.r#in,
+            null: match self.null {
+                MaybeUndefined::Value(is_null) => MaybeUndefined::Value(!is_null),
+                other => other,
+            },
+        })
+    }
+}
 /// Input for updating an existing release stage.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -8064,6 +17420,109 @@ pub struct RoadmapCollectionFilter {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub length: MaybeUndefined<NumberComparator>,
 }
+impl RoadmapCollectionFilter {
+    /// Comparator for the identifier.
+    pub fn id(value: IDComparator) -> Self {
+        Self {
+            id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the created at date.
+    pub fn created_at(value: DateComparator) -> Self {
+        Self {
+            created_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the updated at date.
+    pub fn updated_at(value: DateComparator) -> Self {
+        Self {
+            updated_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the roadmap name.
+    pub fn name(value: StringComparator) -> Self {
+        Self {
+            name: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the roadmap slug ID.
+    pub fn slug_id(value: StringComparator) -> Self {
+        Self {
+            slug_id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the roadmap creator must satisfy.
+    pub fn creator(value: UserFilter) -> Self {
+        Self {
+            creator: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that needs to be matched by some roadmaps.
+    pub fn some(value: RoadmapFilter) -> Self {
+        Self {
+            some: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that needs to be matched by all roadmaps.
+    pub fn every(value: RoadmapFilter) -> Self {
+        Self {
+            every: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the collection length.
+    pub fn length(value: NumberComparator) -> Self {
+        Self {
+            length: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Match records matching every filter. A single filter is returned
+    /// as is, and no filters give the empty filter, which matches everything.
+    pub fn all(filters: impl IntoIterator<Item = Self>) -> Self {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => Self::default(),
+            1 => filters.remove(0),
+            _ => Self {
+                and: MaybeUndefined::Value(filters),
+                ..Default::default()
+            },
+        }
+    }
+    /// Match records matching any of the filters. A single filter is
+    /// returned as is. No filters give `None`: a disjunction of nothing
+    /// matches nothing, which no filter expresses.
+    pub fn any(filters: impl IntoIterator<Item = Self>) -> Option<Self> {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => None,
+            1 => Some(filters.remove(0)),
+            _ => Some(Self {
+                or: MaybeUndefined::Value(filters),
+                ..Default::default()
+            }),
+        }
+    }
+    /// Match records matching both this filter and `other`.
+    pub fn and(self, other: Self) -> Self {
+        Self::all([self, other])
+    }
+    /// Match records matching this filter or `other`.
+    pub fn or(self, other: Self) -> Self {
+        Self {
+            or: MaybeUndefined::Value(vec![self, other]),
+            ..Default::default()
+        }
+    }
+}
 /// Input for creating a new roadmap.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -8115,6 +17574,88 @@ pub struct RoadmapFilter {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub or: MaybeUndefined<Vec<RoadmapFilter>>,
 }
+impl RoadmapFilter {
+    /// Comparator for the identifier.
+    pub fn id(value: IDComparator) -> Self {
+        Self {
+            id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the created at date.
+    pub fn created_at(value: DateComparator) -> Self {
+        Self {
+            created_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the updated at date.
+    pub fn updated_at(value: DateComparator) -> Self {
+        Self {
+            updated_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the roadmap name.
+    pub fn name(value: StringComparator) -> Self {
+        Self {
+            name: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the roadmap slug ID.
+    pub fn slug_id(value: StringComparator) -> Self {
+        Self {
+            slug_id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the roadmap creator must satisfy.
+    pub fn creator(value: UserFilter) -> Self {
+        Self {
+            creator: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Match records matching every filter. A single filter is returned
+    /// as is, and no filters give the empty filter, which matches everything.
+    pub fn all(filters: impl IntoIterator<Item = Self>) -> Self {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => Self::default(),
+            1 => filters.remove(0),
+            _ => Self {
+                and: MaybeUndefined::Value(filters),
+                ..Default::default()
+            },
+        }
+    }
+    /// Match records matching any of the filters. A single filter is
+    /// returned as is. No filters give `None`: a disjunction of nothing
+    /// matches nothing, which no filter expresses.
+    pub fn any(filters: impl IntoIterator<Item = Self>) -> Option<Self> {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => None,
+            1 => Some(filters.remove(0)),
+            _ => Some(Self {
+                or: MaybeUndefined::Value(filters),
+                ..Default::default()
+            }),
+        }
+    }
+    /// Match records matching both this filter and `other`.
+    pub fn and(self, other: Self) -> Self {
+        Self::all([self, other])
+    }
+    /// Match records matching this filter or `other`.
+    pub fn or(self, other: Self) -> Self {
+        Self {
+            or: MaybeUndefined::Value(vec![self, other]),
+            ..Default::default()
+        }
+    }
+}
 /// Input for creating a new roadmap-to-project mapping.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -8179,6 +17720,14 @@ pub struct SalesforceMetadataIntegrationComparator {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub case_metadata: MaybeUndefined<serde_json::Value>,
 }
+impl SalesforceMetadataIntegrationComparator {
+    /// Salesforce Case metadata filter
+    pub fn case_metadata(value: impl Into<serde_json::Value>) -> Self {
+        Self {
+            case_metadata: MaybeUndefined::Value(value.into()),
+        }
+    }
+}
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SalesforceSettingsInput {
@@ -8285,6 +17834,69 @@ pub struct SlaStatusComparator {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub null: MaybeUndefined<bool>,
 }
+impl SlaStatusComparator {
+    /// Equals constraint.
+    pub fn eq(value: impl Into<SlaStatus>) -> Self {
+        Self {
+            eq: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Not-equals constraint.
+    pub fn neq(value: impl Into<SlaStatus>) -> Self {
+        Self {
+            neq: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// In-array constraint.
+    pub fn in_(values: impl IntoIterator<Item = impl Into<SlaStatus>>) -> Self {
+        Self {
+            r#in: MaybeUndefined::Value(values.into_iter().map(Into::into).collect()),
+            ..Default::default()
+        }
+    }
+    /// Not-in-array constraint.
+    pub fn nin(values: impl IntoIterator<Item = impl Into<SlaStatus>>) -> Self {
+        Self {
+            nin: MaybeUndefined::Value(values.into_iter().map(Into::into).collect()),
+            ..Default::default()
+        }
+    }
+    /// Null constraint. Matches any non-null values if the given value is false, otherwise it matches null values.
+    pub fn null(value: impl Into<bool>) -> Self {
+        Self {
+            null: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+}
+impl SlaStatusComparator {
+    /// The negated comparator (`eq` ↔ `neq`, `lt` ↔ `gte`, …), or
+    /// `None` unless exactly one constraint is set: negating several
+    /// would need an `or`, which comparators don't have.
+    pub fn negate(self) -> Option<Self> {
+        if usize::from(!self.eq.is_undefined())
+            + usize::from(!self.neq.is_undefined())
+            + usize::from(!self.r#in.is_undefined())
+            + usize::from(!self.nin.is_undefined())
+            + usize::from(!self.null.is_undefined())
+            != 1
+        {
+            return None;
+        }
+        Some(Self {
+            eq: self.neq,
+            neq: self.eq,
+            r#in: self.nin,
+            nin: self.r#in,
+            null: match self.null {
+                MaybeUndefined::Value(is_null) => MaybeUndefined::Value(!is_null),
+                other => other,
+            },
+        })
+    }
+}
 /// Issue SLA status sorting options.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -8452,6 +18064,29 @@ pub struct SourceMetadataComparator {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub salesforce_metadata: MaybeUndefined<SalesforceMetadataIntegrationComparator>,
 }
+impl SourceMetadataComparator {
+    /// Null constraint. Matches any non-null values if the given value is false, otherwise it matches null values.
+    pub fn null(value: impl Into<bool>) -> Self {
+        Self {
+            null: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the sub type.
+    pub fn sub_type(value: SubTypeComparator) -> Self {
+        Self {
+            sub_type: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// `INTERNAL` Comparator for the salesforce metadata.
+    pub fn salesforce_metadata(value: SalesforceMetadataIntegrationComparator) -> Self {
+        Self {
+            salesforce_metadata: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+}
 /// Comparator for `sourceType` field.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -8505,6 +18140,120 @@ pub struct SourceTypeComparator {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub contains_ignore_case_and_accent: MaybeUndefined<String>,
 }
+impl SourceTypeComparator {
+    /// Equals constraint.
+    pub fn eq(value: impl Into<String>) -> Self {
+        Self {
+            eq: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Not-equals constraint.
+    pub fn neq(value: impl Into<String>) -> Self {
+        Self {
+            neq: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// In-array constraint.
+    pub fn in_(values: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self {
+            r#in: MaybeUndefined::Value(values.into_iter().map(Into::into).collect()),
+            ..Default::default()
+        }
+    }
+    /// Not-in-array constraint.
+    pub fn nin(values: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self {
+            nin: MaybeUndefined::Value(values.into_iter().map(Into::into).collect()),
+            ..Default::default()
+        }
+    }
+    /// Equals case insensitive. Matches any values that matches the given string case insensitive.
+    pub fn eq_ignore_case(value: impl Into<String>) -> Self {
+        Self {
+            eq_ignore_case: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Not-equals case insensitive. Matches any values that don't match the given string case insensitive.
+    pub fn neq_ignore_case(value: impl Into<String>) -> Self {
+        Self {
+            neq_ignore_case: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Starts with constraint. Matches any values that start with the given string.
+    pub fn starts_with(value: impl Into<String>) -> Self {
+        Self {
+            starts_with: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Starts with case insensitive constraint. Matches any values that start with the given string.
+    pub fn starts_with_ignore_case(value: impl Into<String>) -> Self {
+        Self {
+            starts_with_ignore_case: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Doesn't start with constraint. Matches any values that don't start with the given string.
+    pub fn not_starts_with(value: impl Into<String>) -> Self {
+        Self {
+            not_starts_with: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Ends with constraint. Matches any values that end with the given string.
+    pub fn ends_with(value: impl Into<String>) -> Self {
+        Self {
+            ends_with: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Doesn't end with constraint. Matches any values that don't end with the given string.
+    pub fn not_ends_with(value: impl Into<String>) -> Self {
+        Self {
+            not_ends_with: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Contains constraint. Matches any values that contain the given string.
+    pub fn contains(value: impl Into<String>) -> Self {
+        Self {
+            contains: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Contains case insensitive constraint. Matches any values that contain the given string case insensitive.
+    pub fn contains_ignore_case(value: impl Into<String>) -> Self {
+        Self {
+            contains_ignore_case: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Doesn't contain constraint. Matches any values that don't contain the given string.
+    pub fn not_contains(value: impl Into<String>) -> Self {
+        Self {
+            not_contains: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Doesn't contain case insensitive constraint. Matches any values that don't contain the given string case insensitive.
+    pub fn not_contains_ignore_case(value: impl Into<String>) -> Self {
+        Self {
+            not_contains_ignore_case: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Contains case and accent insensitive constraint. Matches any values that contain the given string case and accent insensitive.
+    pub fn contains_ignore_case_and_accent(value: impl Into<String>) -> Self {
+        Self {
+            contains_ignore_case_and_accent: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+}
 /// Project start date sorting options.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -8530,6 +18279,29 @@ pub struct StringArrayComparator {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub some: MaybeUndefined<StringItemComparator>,
 }
+impl StringArrayComparator {
+    /// Length of the array. Matches any values that have the given length.
+    pub fn length(value: NumberComparator) -> Self {
+        Self {
+            length: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Compound filters, all of which need to be matched.
+    pub fn every(value: StringItemComparator) -> Self {
+        Self {
+            every: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Compound filters, one of which needs to be matched.
+    pub fn some(value: StringItemComparator) -> Self {
+        Self {
+            some: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+}
 /// Comparator for strings.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -8583,6 +18355,120 @@ pub struct StringComparator {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub contains_ignore_case_and_accent: MaybeUndefined<String>,
 }
+impl StringComparator {
+    /// Equals constraint.
+    pub fn eq(value: impl Into<String>) -> Self {
+        Self {
+            eq: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Not-equals constraint.
+    pub fn neq(value: impl Into<String>) -> Self {
+        Self {
+            neq: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// In-array constraint.
+    pub fn in_(values: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self {
+            r#in: MaybeUndefined::Value(values.into_iter().map(Into::into).collect()),
+            ..Default::default()
+        }
+    }
+    /// Not-in-array constraint.
+    pub fn nin(values: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self {
+            nin: MaybeUndefined::Value(values.into_iter().map(Into::into).collect()),
+            ..Default::default()
+        }
+    }
+    /// Equals case insensitive. Matches any values that matches the given string case insensitive.
+    pub fn eq_ignore_case(value: impl Into<String>) -> Self {
+        Self {
+            eq_ignore_case: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Not-equals case insensitive. Matches any values that don't match the given string case insensitive.
+    pub fn neq_ignore_case(value: impl Into<String>) -> Self {
+        Self {
+            neq_ignore_case: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Starts with constraint. Matches any values that start with the given string.
+    pub fn starts_with(value: impl Into<String>) -> Self {
+        Self {
+            starts_with: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Starts with case insensitive constraint. Matches any values that start with the given string.
+    pub fn starts_with_ignore_case(value: impl Into<String>) -> Self {
+        Self {
+            starts_with_ignore_case: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Doesn't start with constraint. Matches any values that don't start with the given string.
+    pub fn not_starts_with(value: impl Into<String>) -> Self {
+        Self {
+            not_starts_with: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Ends with constraint. Matches any values that end with the given string.
+    pub fn ends_with(value: impl Into<String>) -> Self {
+        Self {
+            ends_with: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Doesn't end with constraint. Matches any values that don't end with the given string.
+    pub fn not_ends_with(value: impl Into<String>) -> Self {
+        Self {
+            not_ends_with: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Contains constraint. Matches any values that contain the given string.
+    pub fn contains(value: impl Into<String>) -> Self {
+        Self {
+            contains: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Contains case insensitive constraint. Matches any values that contain the given string case insensitive.
+    pub fn contains_ignore_case(value: impl Into<String>) -> Self {
+        Self {
+            contains_ignore_case: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Doesn't contain constraint. Matches any values that don't contain the given string.
+    pub fn not_contains(value: impl Into<String>) -> Self {
+        Self {
+            not_contains: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Doesn't contain case insensitive constraint. Matches any values that don't contain the given string case insensitive.
+    pub fn not_contains_ignore_case(value: impl Into<String>) -> Self {
+        Self {
+            not_contains_ignore_case: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Contains case and accent insensitive constraint. Matches any values that contain the given string case and accent insensitive.
+    pub fn contains_ignore_case_and_accent(value: impl Into<String>) -> Self {
+        Self {
+            contains_ignore_case_and_accent: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+}
 /// Comparator for strings in arrays.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -8636,6 +18522,120 @@ pub struct StringItemComparator {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub contains_ignore_case_and_accent: MaybeUndefined<String>,
 }
+impl StringItemComparator {
+    /// Equals constraint.
+    pub fn eq(value: impl Into<String>) -> Self {
+        Self {
+            eq: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Not-equals constraint.
+    pub fn neq(value: impl Into<String>) -> Self {
+        Self {
+            neq: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// In-array constraint.
+    pub fn in_(values: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self {
+            r#in: MaybeUndefined::Value(values.into_iter().map(Into::into).collect()),
+            ..Default::default()
+        }
+    }
+    /// Not-in-array constraint.
+    pub fn nin(values: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self {
+            nin: MaybeUndefined::Value(values.into_iter().map(Into::into).collect()),
+            ..Default::default()
+        }
+    }
+    /// Equals case insensitive. Matches any values that matches the given string case insensitive.
+    pub fn eq_ignore_case(value: impl Into<String>) -> Self {
+        Self {
+            eq_ignore_case: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Not-equals case insensitive. Matches any values that don't match the given string case insensitive.
+    pub fn neq_ignore_case(value: impl Into<String>) -> Self {
+        Self {
+            neq_ignore_case: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Starts with constraint. Matches any values that start with the given string.
+    pub fn starts_with(value: impl Into<String>) -> Self {
+        Self {
+            starts_with: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Starts with case insensitive constraint. Matches any values that start with the given string.
+    pub fn starts_with_ignore_case(value: impl Into<String>) -> Self {
+        Self {
+            starts_with_ignore_case: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Doesn't start with constraint. Matches any values that don't start with the given string.
+    pub fn not_starts_with(value: impl Into<String>) -> Self {
+        Self {
+            not_starts_with: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Ends with constraint. Matches any values that end with the given string.
+    pub fn ends_with(value: impl Into<String>) -> Self {
+        Self {
+            ends_with: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Doesn't end with constraint. Matches any values that don't end with the given string.
+    pub fn not_ends_with(value: impl Into<String>) -> Self {
+        Self {
+            not_ends_with: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Contains constraint. Matches any values that contain the given string.
+    pub fn contains(value: impl Into<String>) -> Self {
+        Self {
+            contains: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Contains case insensitive constraint. Matches any values that contain the given string case insensitive.
+    pub fn contains_ignore_case(value: impl Into<String>) -> Self {
+        Self {
+            contains_ignore_case: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Doesn't contain constraint. Matches any values that don't contain the given string.
+    pub fn not_contains(value: impl Into<String>) -> Self {
+        Self {
+            not_contains: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Doesn't contain case insensitive constraint. Matches any values that don't contain the given string case insensitive.
+    pub fn not_contains_ignore_case(value: impl Into<String>) -> Self {
+        Self {
+            not_contains_ignore_case: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Contains case and accent insensitive constraint. Matches any values that contain the given string case and accent insensitive.
+    pub fn contains_ignore_case_and_accent(value: impl Into<String>) -> Self {
+        Self {
+            contains_ignore_case_and_accent: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+}
 /// Comparator for source type.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -8656,6 +18656,69 @@ pub struct SubTypeComparator {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub null: MaybeUndefined<bool>,
 }
+impl SubTypeComparator {
+    /// Equals constraint.
+    pub fn eq(value: impl Into<String>) -> Self {
+        Self {
+            eq: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// Not-equals constraint.
+    pub fn neq(value: impl Into<String>) -> Self {
+        Self {
+            neq: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+    /// In-array constraint.
+    pub fn in_(values: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self {
+            r#in: MaybeUndefined::Value(values.into_iter().map(Into::into).collect()),
+            ..Default::default()
+        }
+    }
+    /// Not-in-array constraint.
+    pub fn nin(values: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self {
+            nin: MaybeUndefined::Value(values.into_iter().map(Into::into).collect()),
+            ..Default::default()
+        }
+    }
+    /// Null constraint. Matches any non-null values if the given value is false, otherwise it matches null values.
+    pub fn null(value: impl Into<bool>) -> Self {
+        Self {
+            null: MaybeUndefined::Value(value.into()),
+            ..Default::default()
+        }
+    }
+}
+impl SubTypeComparator {
+    /// The negated comparator (`eq` ↔ `neq`, `lt` ↔ `gte`, …), or
+    /// `None` unless exactly one constraint is set: negating several
+    /// would need an `or`, which comparators don't have.
+    pub fn negate(self) -> Option<Self> {
+        if usize::from(!self.eq.is_undefined())
+            + usize::from(!self.neq.is_undefined())
+            + usize::from(!self.r#in.is_undefined())
+            + usize::from(!self.nin.is_undefined())
+            + usize::from(!self.null.is_undefined())
+            != 1
+        {
+            return None;
+        }
+        Some(Self {
+            eq: self.neq,
+            neq: self.eq,
+            r#in: self.nin,
+            nin: self.r#in,
+            null: match self.null {
+                MaybeUndefined::Value(is_null) => MaybeUndefined::Value(!is_null),
+                other => other,
+            },
+        })
+    }
+}
 /// Project target date sorting options.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -8702,6 +18765,102 @@ pub struct TeamCollectionFilter {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub ancestors: MaybeUndefined<Box<TeamCollectionFilter>>,
 }
+impl TeamCollectionFilter {
+    /// Comparator for the identifier.
+    pub fn id(value: IDComparator) -> Self {
+        Self {
+            id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the created at date.
+    pub fn created_at(value: DateComparator) -> Self {
+        Self {
+            created_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the updated at date.
+    pub fn updated_at(value: DateComparator) -> Self {
+        Self {
+            updated_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that needs to be matched by some teams.
+    pub fn some(value: TeamFilter) -> Self {
+        Self {
+            some: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that needs to be matched by all teams.
+    pub fn every(value: TeamFilter) -> Self {
+        Self {
+            every: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the collection length.
+    pub fn length(value: NumberComparator) -> Self {
+        Self {
+            length: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the teams parent must satisfy.
+    pub fn parent(value: NullableTeamFilter) -> Self {
+        Self {
+            parent: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the team's ancestors must satisfy.
+    pub fn ancestors(value: TeamCollectionFilter) -> Self {
+        Self {
+            ancestors: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Match records matching every filter. A single filter is returned
+    /// as is, and no filters give the empty filter, which matches everything.
+    pub fn all(filters: impl IntoIterator<Item = Self>) -> Self {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => Self::default(),
+            1 => filters.remove(0),
+            _ => Self {
+                and: MaybeUndefined::Value(filters),
+                ..Default::default()
+            },
+        }
+    }
+    /// Match records matching any of the filters. A single filter is
+    /// returned as is. No filters give `None`: a disjunction of nothing
+    /// matches nothing, which no filter expresses.
+    pub fn any(filters: impl IntoIterator<Item = Self>) -> Option<Self> {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => None,
+            1 => Some(filters.remove(0)),
+            _ => Some(Self {
+                or: MaybeUndefined::Value(filters),
+                ..Default::default()
+            }),
+        }
+    }
+    /// Match records matching both this filter and `other`.
+    pub fn and(self, other: Self) -> Self {
+        Self::all([self, other])
+    }
+    /// Match records matching this filter or `other`.
+    pub fn or(self, other: Self) -> Self {
+        Self {
+            or: MaybeUndefined::Value(vec![self, other]),
+            ..Default::default()
+        }
+    }
+}
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TeamCreateInput {
@@ -8869,6 +19028,130 @@ pub struct TeamFilter {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub or: MaybeUndefined<Vec<TeamFilter>>,
 }
+impl TeamFilter {
+    /// Comparator for the identifier.
+    pub fn id(value: IDComparator) -> Self {
+        Self {
+            id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the created at date.
+    pub fn created_at(value: DateComparator) -> Self {
+        Self {
+            created_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the updated at date.
+    pub fn updated_at(value: DateComparator) -> Self {
+        Self {
+            updated_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the team name.
+    pub fn name(value: StringComparator) -> Self {
+        Self {
+            name: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the team key.
+    pub fn key(value: StringComparator) -> Self {
+        Self {
+            key: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the team description.
+    pub fn description(value: NullableStringComparator) -> Self {
+        Self {
+            description: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the team privacy.
+    pub fn private(value: BooleanComparator) -> Self {
+        Self {
+            private: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the time at which the team was retired.
+    pub fn retired_at(value: NullableDateComparator) -> Self {
+        Self {
+            retired_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the teams issues must satisfy.
+    pub fn issues(value: IssueCollectionFilter) -> Self {
+        Self {
+            issues: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the teams parent must satisfy.
+    pub fn parent(value: NullableTeamFilter) -> Self {
+        Self {
+            parent: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the team's ancestors must satisfy.
+    pub fn ancestors(value: TeamCollectionFilter) -> Self {
+        Self {
+            ancestors: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// `ALPHA` Filters that the team's release pipelines must satisfy.
+    pub fn release_pipelines(value: ReleasePipelineCollectionFilter) -> Self {
+        Self {
+            release_pipelines: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Match records matching every filter. A single filter is returned
+    /// as is, and no filters give the empty filter, which matches everything.
+    pub fn all(filters: impl IntoIterator<Item = Self>) -> Self {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => Self::default(),
+            1 => filters.remove(0),
+            _ => Self {
+                and: MaybeUndefined::Value(filters),
+                ..Default::default()
+            },
+        }
+    }
+    /// Match records matching any of the filters. A single filter is
+    /// returned as is. No filters give `None`: a disjunction of nothing
+    /// matches nothing, which no filter expresses.
+    pub fn any(filters: impl IntoIterator<Item = Self>) -> Option<Self> {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => None,
+            1 => Some(filters.remove(0)),
+            _ => Some(Self {
+                or: MaybeUndefined::Value(filters),
+                ..Default::default()
+            }),
+        }
+    }
+    /// Match records matching both this filter and `other`.
+    pub fn and(self, other: Self) -> Self {
+        Self::all([self, other])
+    }
+    /// Match records matching this filter or `other`.
+    pub fn or(self, other: Self) -> Self {
+        Self {
+            or: MaybeUndefined::Value(vec![self, other]),
+            ..Default::default()
+        }
+    }
+}
 /// Input for creating a new team membership.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -9359,6 +19642,165 @@ pub struct UserCollectionFilter {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub length: MaybeUndefined<NumberComparator>,
 }
+impl UserCollectionFilter {
+    /// Comparator for the identifier.
+    pub fn id(value: IDComparator) -> Self {
+        Self {
+            id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the created at date.
+    pub fn created_at(value: DateComparator) -> Self {
+        Self {
+            created_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the updated at date.
+    pub fn updated_at(value: DateComparator) -> Self {
+        Self {
+            updated_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the user's name.
+    pub fn name(value: StringComparator) -> Self {
+        Self {
+            name: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the user's display name.
+    pub fn display_name(value: StringComparator) -> Self {
+        Self {
+            display_name: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the user's email.
+    pub fn email(value: StringComparator) -> Self {
+        Self {
+            email: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the user's activity status.
+    pub fn active(value: BooleanComparator) -> Self {
+        Self {
+            active: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the users assigned issues must satisfy.
+    pub fn assigned_issues(value: IssueCollectionFilter) -> Self {
+        Self {
+            assigned_issues: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the user's admin status.
+    pub fn admin(value: BooleanComparator) -> Self {
+        Self {
+            admin: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the user's owner status.
+    pub fn owner(value: BooleanComparator) -> Self {
+        Self {
+            owner: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the user's invited status.
+    pub fn invited(value: BooleanComparator) -> Self {
+        Self {
+            invited: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the user's invited status.
+    pub fn is_invited(value: BooleanComparator) -> Self {
+        Self {
+            is_invited: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the user's app status.
+    pub fn app(value: BooleanComparator) -> Self {
+        Self {
+            app: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filter based on the currently authenticated user. Set to true to filter for the authenticated user, false for any other user.
+    pub fn is_me(value: BooleanComparator) -> Self {
+        Self {
+            is_me: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that needs to be matched by some users.
+    pub fn some(value: UserFilter) -> Self {
+        Self {
+            some: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that needs to be matched by all users.
+    pub fn every(value: UserFilter) -> Self {
+        Self {
+            every: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the collection length.
+    pub fn length(value: NumberComparator) -> Self {
+        Self {
+            length: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Match records matching every filter. A single filter is returned
+    /// as is, and no filters give the empty filter, which matches everything.
+    pub fn all(filters: impl IntoIterator<Item = Self>) -> Self {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => Self::default(),
+            1 => filters.remove(0),
+            _ => Self {
+                and: MaybeUndefined::Value(filters),
+                ..Default::default()
+            },
+        }
+    }
+    /// Match records matching any of the filters. A single filter is
+    /// returned as is. No filters give `None`: a disjunction of nothing
+    /// matches nothing, which no filter expresses.
+    pub fn any(filters: impl IntoIterator<Item = Self>) -> Option<Self> {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => None,
+            1 => Some(filters.remove(0)),
+            _ => Some(Self {
+                or: MaybeUndefined::Value(filters),
+                ..Default::default()
+            }),
+        }
+    }
+    /// Match records matching both this filter and `other`.
+    pub fn and(self, other: Self) -> Self {
+        Self::all([self, other])
+    }
+    /// Match records matching this filter or `other`.
+    pub fn or(self, other: Self) -> Self {
+        Self {
+            or: MaybeUndefined::Value(vec![self, other]),
+            ..Default::default()
+        }
+    }
+}
 /// User display name sorting options.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -9423,6 +19865,144 @@ pub struct UserFilter {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub or: MaybeUndefined<Vec<UserFilter>>,
 }
+impl UserFilter {
+    /// Comparator for the identifier.
+    pub fn id(value: IDComparator) -> Self {
+        Self {
+            id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the created at date.
+    pub fn created_at(value: DateComparator) -> Self {
+        Self {
+            created_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the updated at date.
+    pub fn updated_at(value: DateComparator) -> Self {
+        Self {
+            updated_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the user's name.
+    pub fn name(value: StringComparator) -> Self {
+        Self {
+            name: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the user's display name.
+    pub fn display_name(value: StringComparator) -> Self {
+        Self {
+            display_name: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the user's email.
+    pub fn email(value: StringComparator) -> Self {
+        Self {
+            email: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the user's activity status.
+    pub fn active(value: BooleanComparator) -> Self {
+        Self {
+            active: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the users assigned issues must satisfy.
+    pub fn assigned_issues(value: IssueCollectionFilter) -> Self {
+        Self {
+            assigned_issues: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the user's admin status.
+    pub fn admin(value: BooleanComparator) -> Self {
+        Self {
+            admin: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the user's owner status.
+    pub fn owner(value: BooleanComparator) -> Self {
+        Self {
+            owner: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the user's invited status.
+    pub fn invited(value: BooleanComparator) -> Self {
+        Self {
+            invited: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the user's invited status.
+    pub fn is_invited(value: BooleanComparator) -> Self {
+        Self {
+            is_invited: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the user's app status.
+    pub fn app(value: BooleanComparator) -> Self {
+        Self {
+            app: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filter based on the currently authenticated user. Set to true to filter for the authenticated user, false for any other user.
+    pub fn is_me(value: BooleanComparator) -> Self {
+        Self {
+            is_me: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Match records matching every filter. A single filter is returned
+    /// as is, and no filters give the empty filter, which matches everything.
+    pub fn all(filters: impl IntoIterator<Item = Self>) -> Self {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => Self::default(),
+            1 => filters.remove(0),
+            _ => Self {
+                and: MaybeUndefined::Value(filters),
+                ..Default::default()
+            },
+        }
+    }
+    /// Match records matching any of the filters. A single filter is
+    /// returned as is. No filters give `None`: a disjunction of nothing
+    /// matches nothing, which no filter expresses.
+    pub fn any(filters: impl IntoIterator<Item = Self>) -> Option<Self> {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => None,
+            1 => Some(filters.remove(0)),
+            _ => Some(Self {
+                or: MaybeUndefined::Value(filters),
+                ..Default::default()
+            }),
+        }
+    }
+    /// Match records matching both this filter and `other`.
+    pub fn and(self, other: Self) -> Self {
+        Self::all([self, other])
+    }
+    /// Match records matching this filter or `other`.
+    pub fn or(self, other: Self) -> Self {
+        Self {
+            or: MaybeUndefined::Value(vec![self, other]),
+            ..Default::default()
+        }
+    }
+}
 /// User name sorting options.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -9681,6 +20261,109 @@ pub struct WorkflowStateFilter {
     #[serde(default, skip_serializing_if = "MaybeUndefined::is_undefined")]
     pub or: MaybeUndefined<Vec<WorkflowStateFilter>>,
 }
+impl WorkflowStateFilter {
+    /// Comparator for the identifier.
+    pub fn id(value: IDComparator) -> Self {
+        Self {
+            id: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the created at date.
+    pub fn created_at(value: DateComparator) -> Self {
+        Self {
+            created_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the updated at date.
+    pub fn updated_at(value: DateComparator) -> Self {
+        Self {
+            updated_at: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the workflow state name.
+    pub fn name(value: StringComparator) -> Self {
+        Self {
+            name: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the workflow state description.
+    pub fn description(value: StringComparator) -> Self {
+        Self {
+            description: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the workflow state position.
+    pub fn position(value: NumberComparator) -> Self {
+        Self {
+            position: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Comparator for the workflow state type. Possible values are "triage", "backlog", "unstarted", "started", "completed", "canceled", "duplicate".
+    pub fn type_(value: StringComparator) -> Self {
+        Self {
+            r#type: MaybeUndefined::Value(value),
+            ..Default::default()
+        }
+    }
+    /// Filters that the workflow states team must satisfy.
+    pub fn team(value: TeamFilter) -> Self {
+        Self {
+            team: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Filters that the workflow states issues must satisfy.
+    pub fn issues(value: IssueCollectionFilter) -> Self {
+        Self {
+            issues: MaybeUndefined::Value(Box::new(value)),
+            ..Default::default()
+        }
+    }
+    /// Match records matching every filter. A single filter is returned
+    /// as is, and no filters give the empty filter, which matches everything.
+    pub fn all(filters: impl IntoIterator<Item = Self>) -> Self {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => Self::default(),
+            1 => filters.remove(0),
+            _ => Self {
+                and: MaybeUndefined::Value(filters),
+                ..Default::default()
+            },
+        }
+    }
+    /// Match records matching any of the filters. A single filter is
+    /// returned as is. No filters give `None`: a disjunction of nothing
+    /// matches nothing, which no filter expresses.
+    pub fn any(filters: impl IntoIterator<Item = Self>) -> Option<Self> {
+        let mut filters: Vec<Self> = filters.into_iter().collect();
+        match filters.len() {
+            0 => None,
+            1 => Some(filters.remove(0)),
+            _ => Some(Self {
+                or: MaybeUndefined::Value(filters),
+                ..Default::default()
+            }),
+        }
+    }
+    /// Match records matching both this filter and `other`.
+    pub fn and(self, other: Self) -> Self {
+        Self::all([self, other])
+    }
+    /// Match records matching this filter or `other`.
+    pub fn or(self, other: Self) -> Self {
+        Self {
+            or: MaybeUndefined::Value(vec![self, other]),
+            ..Default::default()
+        }
+    }
+}
 /// Issue workflow state sorting options.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -1,10 +1,16 @@
 //! Generated types and operations for the Linear GraphQL API.
 //!
 //! Generated by lineark-codegen — do not edit.
+#![allow(deprecated)]
+#[cfg(feature = "blocking")]
+pub(crate) mod blocking_client_impl;
 mod client_impl;
 pub mod enums;
+pub mod ids;
 pub mod inputs;
 pub mod mutations;
+pub mod operations;
 pub mod queries;
 pub mod scalars;
+pub mod subscriptions;
 pub mod types;
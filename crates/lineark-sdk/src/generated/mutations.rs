@@ -5,6 +5,8 @@
 //!
 //! Generated by lineark-codegen — do not edit.
 #![allow(clippy::too_many_arguments)]
+#[allow(unused_imports)]
+use super::enums::*;
 use super::inputs::*;
 use crate::client::Client;
 use crate::error::LinearError;
@@ -17,601 +19,5763 @@ pub async fn file_upload(
     content_type: String,
     filename: String,
 ) -> Result<serde_json::Value, LinearError> {
-    let variables = serde_json::json!(
-        { "metaData" : meta_data, "makePublic" : make_public, "size" : size,
-        "contentType" : content_type, "filename" : filename }
-    );
-    let mut response_parts: Vec<String> = vec!["success".to_string()];
-    response_parts.push(format!(
-        "{} {{ {} }}",
-        "uploadFile",
-        <super::types::UploadFile as crate::field_selection::GraphQLFields>::selection()
-    ));
-    let query = String::from(
-        "mutation FileUpload($metaData: JSON, $makePublic: Boolean, $size: Int!, $contentType: String!, $filename: String!) { fileUpload(metaData: $metaData, makePublic: $makePublic, size: $size, contentType: $contentType, filename: $filename) { ",
-    ) + &response_parts.join(" ") + " } }";
-    client
-        .execute::<serde_json::Value>(&query, variables, "fileUpload")
+    super::operations::file_upload(meta_data, make_public, size, content_type, filename)
+        .execute(client)
+        .await
+}
+/// XHR request payload to upload a file for import, directly to Linear's cloud storage.
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub async fn import_file_upload(
+    client: &Client,
+    meta_data: Option<serde_json::Value>,
+    size: i64,
+    content_type: String,
+    filename: String,
+) -> Result<serde_json::Value, LinearError> {
+    super::operations::import_file_upload(meta_data, size, content_type, filename)
+        .execute(client)
         .await
 }
 /// Upload an image from an URL to Linear.
 pub async fn image_upload_from_url(
     client: &Client,
     url: String,
-) -> Result<serde_json::Value, LinearError> {
-    let variables = serde_json::json!({ "url" : url });
-    let response_parts: Vec<String> = vec!["url".to_string(), "success".to_string()];
-    let query = String::from(
-        "mutation ImageUploadFromUrl($url: String!) { imageUploadFromUrl(url: $url) { ",
-    ) + &response_parts.join(" ")
-        + " } }";
-    client
-        .execute::<serde_json::Value>(&query, variables, "imageUploadFromUrl")
+) -> Result<super::types::ImageUploadFromUrlPayload, LinearError> {
+    super::operations::image_upload_from_url(url)
+        .execute(client)
         .await
 }
-/// Creates a new project.
+/// `INTERNAL` Permanently delete an uploaded file by asset URL. This should be used as a last resort and will break comments and documents that reference the asset.
 ///
-/// Full type: [`Project`](super::types::Project)
-pub async fn project_create<
-    T: serde::de::DeserializeOwned
-        + crate::field_selection::GraphQLFields<FullType = super::types::Project>,
->(
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub async fn file_upload_dangerously_delete(
     client: &Client,
-    slack_channel_name: Option<String>,
-    input: ProjectCreateInput,
-) -> Result<T, LinearError> {
-    let variables = serde_json::json!(
-        { "slackChannelName" : slack_channel_name, "input" : input }
-    );
-    let query = String::from(
-        "mutation ProjectCreate($slackChannelName: String, $input: ProjectCreateInput!) { projectCreate(slackChannelName: $slackChannelName, input: $input) { success project { ",
-    ) + &T::selection() + " } } }";
-    client
-        .execute_mutation::<T>(&query, variables, "projectCreate", "project")
+    asset_url: String,
+) -> Result<super::types::FileUploadDeletePayload, LinearError> {
+    super::operations::file_upload_dangerously_delete(asset_url)
+        .execute(client)
         .await
 }
-/// Updates a project.
+/// Creates a new state, adding it to the workflow of a team.
 ///
-/// Full type: [`Project`](super::types::Project)
-pub async fn project_update<
+/// Full type: [`WorkflowState`](super::types::WorkflowState)
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+pub async fn workflow_state_create<
     T: serde::de::DeserializeOwned
-        + crate::field_selection::GraphQLFields<FullType = super::types::Project>,
+        + crate::field_selection::GraphQLFields<FullType = super::types::WorkflowState>,
 >(
     client: &Client,
-    input: ProjectUpdateInput,
-    id: String,
+    input: WorkflowStateCreateInput,
 ) -> Result<T, LinearError> {
-    let variables = serde_json::json!({ "input" : input, "id" : id });
-    let query = String::from(
-        "mutation ProjectUpdate($input: ProjectUpdateInput!, $id: String!) { projectUpdate(input: $input, id: $id) { success project { ",
-    ) + &T::selection() + " } } }";
-    client
-        .execute_mutation::<T>(&query, variables, "projectUpdate", "project")
+    super::operations::workflow_state_create::<T>(input)
+        .execute(client)
         .await
 }
-/// Deletes (trashes) a project. The project can be restored later with projectUnarchive.
+/// Updates a state.
 ///
-/// Full type: [`Project`](super::types::Project)
-pub async fn project_delete<
+/// Full type: [`WorkflowState`](super::types::WorkflowState)
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+pub async fn workflow_state_update<
     T: serde::de::DeserializeOwned
-        + crate::field_selection::GraphQLFields<FullType = super::types::Project>,
+        + crate::field_selection::GraphQLFields<FullType = super::types::WorkflowState>,
 >(
     client: &Client,
-    id: String,
+    input: WorkflowStateUpdateInput,
+    id: impl Into<super::ids::WorkflowStateId>,
 ) -> Result<T, LinearError> {
-    let variables = serde_json::json!({ "id" : id });
-    let query = String::from(
-        "mutation ProjectDelete($id: String!) { projectDelete(id: $id) { success entity { ",
-    ) + &T::selection()
-        + " } } }";
-    client
-        .execute_mutation::<T>(&query, variables, "projectDelete", "entity")
+    super::operations::workflow_state_update::<T>(input, id)
+        .execute(client)
         .await
 }
-/// Creates a new team. The user who creates the team will automatically be added as a member and owner of the newly created team. Default workflow states, labels, and other team resources are created alongside the team.
+/// Archives a state. Only states with issues that have all been archived can be archived.
 ///
-/// Full type: [`Team`](super::types::Team)
-pub async fn team_create<
+/// Full type: [`WorkflowState`](super::types::WorkflowState)
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+pub async fn workflow_state_archive<
     T: serde::de::DeserializeOwned
-        + crate::field_selection::GraphQLFields<FullType = super::types::Team>,
+        + crate::field_selection::GraphQLFields<FullType = super::types::WorkflowState>,
 >(
     client: &Client,
-    copy_settings_from_team_id: Option<String>,
-    input: TeamCreateInput,
+    id: impl Into<super::ids::WorkflowStateId>,
 ) -> Result<T, LinearError> {
-    let variables = serde_json::json!(
-        { "copySettingsFromTeamId" : copy_settings_from_team_id, "input" : input }
-    );
-    let query = String::from(
-        "mutation TeamCreate($copySettingsFromTeamId: String, $input: TeamCreateInput!) { teamCreate(copySettingsFromTeamId: $copySettingsFromTeamId, input: $input) { success team { ",
-    ) + &T::selection() + " } } }";
-    client
-        .execute_mutation::<T>(&query, variables, "teamCreate", "team")
+    super::operations::workflow_state_archive::<T>(id)
+        .execute(client)
         .await
 }
-/// Updates a team's settings, properties, or configuration. Requires team owner or workspace admin permissions for most changes.
+/// Creates a new webhook subscription for the workspace. Requires specifying a URL, resource types to subscribe to, and either a specific team or all public teams.
 ///
-/// Full type: [`Team`](super::types::Team)
-pub async fn team_update<
+/// Full type: [`Webhook`](super::types::Webhook)
+///
+/// Requires the `webhooks` feature.
+#[cfg(feature = "webhooks")]
+pub async fn webhook_create<
     T: serde::de::DeserializeOwned
-        + crate::field_selection::GraphQLFields<FullType = super::types::Team>,
+        + crate::field_selection::GraphQLFields<FullType = super::types::Webhook>,
 >(
     client: &Client,
-    mapping: Option<InheritanceEntityMapping>,
-    input: TeamUpdateInput,
-    id: String,
+    input: WebhookCreateInput,
 ) -> Result<T, LinearError> {
-    let variables = serde_json::json!(
-        { "mapping" : mapping, "input" : input, "id" : id }
-    );
-    let query = String::from(
-        "mutation TeamUpdate($mapping: InheritanceEntityMapping, $input: TeamUpdateInput!, $id: String!) { teamUpdate(mapping: $mapping, input: $input, id: $id) { success team { ",
-    ) + &T::selection() + " } } }";
-    client
-        .execute_mutation::<T>(&query, variables, "teamUpdate", "team")
-        .await
-}
-/// Archives a team and schedules its data for deletion. Requires team owner or workspace admin permissions.
-pub async fn team_delete(client: &Client, id: String) -> Result<serde_json::Value, LinearError> {
-    let variables = serde_json::json!({ "id" : id });
-    let response_parts: Vec<String> = vec!["success".to_string(), "entityId".to_string()];
-    let query = String::from("mutation TeamDelete($id: String!) { teamDelete(id: $id) { ")
-        + &response_parts.join(" ")
-        + " } }";
-    client
-        .execute::<serde_json::Value>(&query, variables, "teamDelete")
+    super::operations::webhook_create::<T>(input)
+        .execute(client)
         .await
 }
-/// Creates a new team membership, adding a user to a team. Validates that the user is not already a member, the team is not archived or retired, and the requesting user has permission to add members.
+/// Updates an existing Webhook.
 ///
-/// Full type: [`TeamMembership`](super::types::TeamMembership)
-pub async fn team_membership_create<
+/// Full type: [`Webhook`](super::types::Webhook)
+///
+/// Requires the `webhooks` feature.
+#[cfg(feature = "webhooks")]
+pub async fn webhook_update<
     T: serde::de::DeserializeOwned
-        + crate::field_selection::GraphQLFields<FullType = super::types::TeamMembership>,
+        + crate::field_selection::GraphQLFields<FullType = super::types::Webhook>,
 >(
     client: &Client,
-    input: TeamMembershipCreateInput,
+    input: WebhookUpdateInput,
+    id: impl Into<super::ids::WebhookId>,
 ) -> Result<T, LinearError> {
-    let variables = serde_json::json!({ "input" : input });
-    let query = String::from(
-        "mutation TeamMembershipCreate($input: TeamMembershipCreateInput!) { teamMembershipCreate(input: $input) { success teamMembership { ",
-    ) + &T::selection() + " } } }";
-    client
-        .execute_mutation::<T>(&query, variables, "teamMembershipCreate", "teamMembership")
+    super::operations::webhook_update::<T>(input, id)
+        .execute(client)
         .await
 }
-/// Deletes a team membership, removing the user from the team. Users can remove their own membership, or team owners and workspace admins can remove other members.
-pub async fn team_membership_delete(
+/// Deletes a Webhook.
+///
+/// Requires the `webhooks` feature.
+#[cfg(feature = "webhooks")]
+pub async fn webhook_delete(
     client: &Client,
-    also_leave_parent_teams: Option<bool>,
-    id: String,
-) -> Result<serde_json::Value, LinearError> {
-    let variables = serde_json::json!(
-        { "alsoLeaveParentTeams" : also_leave_parent_teams, "id" : id }
-    );
-    let response_parts: Vec<String> = vec!["success".to_string(), "entityId".to_string()];
-    let query = String::from(
-        "mutation TeamMembershipDelete($alsoLeaveParentTeams: Boolean, $id: String!) { teamMembershipDelete(alsoLeaveParentTeams: $alsoLeaveParentTeams, id: $id) { ",
-    ) + &response_parts.join(" ") + " } }";
-    client
-        .execute::<serde_json::Value>(&query, variables, "teamMembershipDelete")
+    id: impl Into<super::ids::WebhookId>,
+) -> Result<super::types::DeletePayload, LinearError> {
+    super::operations::webhook_delete(id).execute(client).await
+}
+/// Rotates the signing secret for a webhook, generating a new HMAC-SHA256 key and returning it. The old secret is immediately invalidated.
+///
+/// Requires the `webhooks` feature.
+#[cfg(feature = "webhooks")]
+pub async fn webhook_rotate_secret(
+    client: &Client,
+    id: impl Into<super::ids::WebhookId>,
+) -> Result<super::types::WebhookRotateSecretPayload, LinearError> {
+    super::operations::webhook_rotate_secret(id)
+        .execute(client)
         .await
 }
-/// Creates a new project milestone.
+/// Creates a new view preferences object. If conflicting preferences already exist for the same view type and scope, the existing preferences are replaced.
 ///
-/// Full type: [`ProjectMilestone`](super::types::ProjectMilestone)
-pub async fn project_milestone_create<
+/// Full type: [`ViewPreferences`](super::types::ViewPreferences)
+///
+/// Requires the `views` feature.
+#[cfg(feature = "views")]
+pub async fn view_preferences_create<
     T: serde::de::DeserializeOwned
-        + crate::field_selection::GraphQLFields<FullType = super::types::ProjectMilestone>,
+        + crate::field_selection::GraphQLFields<FullType = super::types::ViewPreferences>,
 >(
     client: &Client,
-    input: ProjectMilestoneCreateInput,
+    input: ViewPreferencesCreateInput,
 ) -> Result<T, LinearError> {
-    let variables = serde_json::json!({ "input" : input });
-    let query = String::from(
-        "mutation ProjectMilestoneCreate($input: ProjectMilestoneCreateInput!) { projectMilestoneCreate(input: $input) { success projectMilestone { ",
-    ) + &T::selection() + " } } }";
-    client
-        .execute_mutation::<T>(
-            &query,
-            variables,
-            "projectMilestoneCreate",
-            "projectMilestone",
-        )
+    super::operations::view_preferences_create::<T>(input)
+        .execute(client)
         .await
 }
-/// Updates a project milestone.
+/// Updates an existing view preferences object. For user-type preferences, only the owning user can update them.
 ///
-/// Full type: [`ProjectMilestone`](super::types::ProjectMilestone)
-pub async fn project_milestone_update<
+/// Full type: [`ViewPreferences`](super::types::ViewPreferences)
+///
+/// Requires the `views` feature.
+#[cfg(feature = "views")]
+pub async fn view_preferences_update<
     T: serde::de::DeserializeOwned
-        + crate::field_selection::GraphQLFields<FullType = super::types::ProjectMilestone>,
+        + crate::field_selection::GraphQLFields<FullType = super::types::ViewPreferences>,
 >(
     client: &Client,
-    input: ProjectMilestoneUpdateInput,
-    id: String,
+    input: ViewPreferencesUpdateInput,
+    id: impl Into<super::ids::ViewPreferencesId>,
 ) -> Result<T, LinearError> {
-    let variables = serde_json::json!({ "input" : input, "id" : id });
-    let query = String::from(
-        "mutation ProjectMilestoneUpdate($input: ProjectMilestoneUpdateInput!, $id: String!) { projectMilestoneUpdate(input: $input, id: $id) { success projectMilestone { ",
-    ) + &T::selection() + " } } }";
-    client
-        .execute_mutation::<T>(
-            &query,
-            variables,
-            "projectMilestoneUpdate",
-            "projectMilestone",
-        )
+    super::operations::view_preferences_update::<T>(input, id)
+        .execute(client)
         .await
 }
-/// Deletes a project milestone.
-pub async fn project_milestone_delete(
+/// Deletes a view preferences object. If the preferences do not exist, the operation is treated as a successful idempotent deletion.
+///
+/// Requires the `views` feature.
+#[cfg(feature = "views")]
+pub async fn view_preferences_delete(
     client: &Client,
-    id: String,
-) -> Result<serde_json::Value, LinearError> {
-    let variables = serde_json::json!({ "id" : id });
-    let response_parts: Vec<String> = vec!["success".to_string(), "entityId".to_string()];
-    let query = String::from(
-        "mutation ProjectMilestoneDelete($id: String!) { projectMilestoneDelete(id: $id) { ",
-    ) + &response_parts.join(" ")
-        + " } }";
-    client
-        .execute::<serde_json::Value>(&query, variables, "projectMilestoneDelete")
+    id: impl Into<super::ids::ViewPreferencesId>,
+) -> Result<super::types::DeletePayload, LinearError> {
+    super::operations::view_preferences_delete(id)
+        .execute(client)
         .await
 }
-/// Creates a new issue.
+/// Updates the authenticated user's settings, including notification preferences, email subscriptions, theme, and other UI preferences.
 ///
-/// Full type: [`Issue`](super::types::Issue)
-pub async fn issue_create<
+/// Full type: [`UserSettings`](super::types::UserSettings)
+///
+/// Requires the `users` feature.
+#[cfg(feature = "users")]
+pub async fn user_settings_update<
     T: serde::de::DeserializeOwned
-        + crate::field_selection::GraphQLFields<FullType = super::types::Issue>,
+        + crate::field_selection::GraphQLFields<FullType = super::types::UserSettings>,
 >(
     client: &Client,
-    input: IssueCreateInput,
+    input: UserSettingsUpdateInput,
+    id: impl Into<super::ids::UserSettingsId>,
 ) -> Result<T, LinearError> {
-    let variables = serde_json::json!({ "input" : input });
-    let query = String::from(
-        "mutation IssueCreate($input: IssueCreateInput!) { issueCreate(input: $input) { success issue { ",
-    ) + &T::selection() + " } } }";
-    client
-        .execute_mutation::<T>(&query, variables, "issueCreate", "issue")
+    super::operations::user_settings_update::<T>(input, id)
+        .execute(client)
         .await
 }
-/// Updates an issue.
+/// Resets one or more of the user's setting flags. If no specific flags are provided, all flags are reset.
 ///
-/// Full type: [`Issue`](super::types::Issue)
-pub async fn issue_update<
-    T: serde::de::DeserializeOwned
-        + crate::field_selection::GraphQLFields<FullType = super::types::Issue>,
->(
+/// Requires the `users` feature.
+#[cfg(feature = "users")]
+pub async fn user_settings_flags_reset(
     client: &Client,
-    input: IssueUpdateInput,
-    id: String,
-) -> Result<T, LinearError> {
-    let variables = serde_json::json!({ "input" : input, "id" : id });
-    let query = String::from(
-        "mutation IssueUpdate($input: IssueUpdateInput!, $id: String!) { issueUpdate(input: $input, id: $id) { success issue { ",
-    ) + &T::selection() + " } } }";
-    client
-        .execute_mutation::<T>(&query, variables, "issueUpdate", "issue")
+    flags: Option<Vec<UserFlagType>>,
+) -> Result<super::types::UserSettingsFlagsResetPayload, LinearError> {
+    super::operations::user_settings_flags_reset(flags)
+        .execute(client)
         .await
 }
-/// Updates multiple issues at once.
+/// Updates a specific user settings flag by performing an operation (e.g., increment or clear) on it.
 ///
-/// Full type: [`Issue`](super::types::Issue)
-pub async fn issue_batch_update<
-    T: serde::de::DeserializeOwned
-        + crate::field_selection::GraphQLFields<FullType = super::types::Issue>,
->(
+/// Requires the `users` feature.
+#[cfg(feature = "users")]
+pub async fn user_flag_update(
     client: &Client,
-    input: IssueUpdateInput,
-    ids: Vec<String>,
-) -> Result<Vec<T>, LinearError> {
-    let variables = serde_json::json!({ "input" : input, "ids" : ids });
-    let query = String::from(
-        "mutation IssueBatchUpdate($input: IssueUpdateInput!, $ids: [UUID!]!) { issueBatchUpdate(input: $input, ids: $ids) { success issues { ",
-    ) + &T::selection() + " } } }";
-    client
-        .execute_mutation::<Vec<T>>(&query, variables, "issueBatchUpdate", "issues")
+    operation: UserFlagUpdateOperation,
+    flag: UserFlagType,
+) -> Result<super::types::UserSettingsFlagPayload, LinearError> {
+    super::operations::user_flag_update(operation, flag)
+        .execute(client)
         .await
 }
-/// Archives an issue.
+/// Subscribes to or unsubscribes from a specific notification category for a given notification channel. For example, subscribe to 'issueAssignment' notifications via the 'email' channel.
 ///
-/// Full type: [`Issue`](super::types::Issue)
-pub async fn issue_archive<
+/// Full type: [`UserSettings`](super::types::UserSettings)
+///
+/// Requires the `notifications` feature.
+#[cfg(feature = "notifications")]
+pub async fn notification_category_channel_subscription_update<
     T: serde::de::DeserializeOwned
-        + crate::field_selection::GraphQLFields<FullType = super::types::Issue>,
+        + crate::field_selection::GraphQLFields<FullType = super::types::UserSettings>,
 >(
     client: &Client,
-    trash: Option<bool>,
-    id: String,
+    channel: NotificationChannel,
+    category: NotificationCategory,
+    subscribe: bool,
 ) -> Result<T, LinearError> {
-    let variables = serde_json::json!({ "trash" : trash, "id" : id });
-    let query = String::from(
-        "mutation IssueArchive($trash: Boolean, $id: String!) { issueArchive(trash: $trash, id: $id) { success entity { ",
-    ) + &T::selection() + " } } }";
-    client
-        .execute_mutation::<T>(&query, variables, "issueArchive", "entity")
-        .await
+    super::operations::notification_category_channel_subscription_update::<T>(
+        channel, category, subscribe,
+    )
+    .execute(client)
+    .await
 }
-/// Unarchives an issue.
+/// Updates a user's profile information. Users can update their own profile; workspace admins can update any user's profile. SCIM-managed users may have restricted name changes.
 ///
-/// Full type: [`Issue`](super::types::Issue)
-pub async fn issue_unarchive<
+/// Full type: [`User`](super::types::User)
+///
+/// Requires the `users` feature.
+#[cfg(feature = "users")]
+pub async fn user_update<
     T: serde::de::DeserializeOwned
-        + crate::field_selection::GraphQLFields<FullType = super::types::Issue>,
+        + crate::field_selection::GraphQLFields<FullType = super::types::User>,
 >(
     client: &Client,
-    id: String,
+    input: UserUpdateInput,
+    id: impl Into<super::ids::UserId>,
 ) -> Result<T, LinearError> {
-    let variables = serde_json::json!({ "id" : id });
-    let query = String::from(
-        "mutation IssueUnarchive($id: String!) { issueUnarchive(id: $id) { success entity { ",
-    ) + &T::selection()
-        + " } } }";
-    client
-        .execute_mutation::<T>(&query, variables, "issueUnarchive", "entity")
+    super::operations::user_update::<T>(input, id)
+        .execute(client)
         .await
 }
-/// Deletes (trashes) an issue.
+/// Connects the Discord user to this Linear account via OAuth2.
 ///
-/// Full type: [`Issue`](super::types::Issue)
-pub async fn issue_delete<
+/// Full type: [`User`](super::types::User)
+///
+/// Requires the `users` feature.
+#[cfg(feature = "users")]
+pub async fn user_discord_connect<
     T: serde::de::DeserializeOwned
-        + crate::field_selection::GraphQLFields<FullType = super::types::Issue>,
+        + crate::field_selection::GraphQLFields<FullType = super::types::User>,
 >(
     client: &Client,
-    permanently_delete: Option<bool>,
-    id: String,
+    redirect_uri: String,
+    code: String,
 ) -> Result<T, LinearError> {
-    let variables = serde_json::json!(
-        { "permanentlyDelete" : permanently_delete, "id" : id }
-    );
-    let query = String::from(
-        "mutation IssueDelete($permanentlyDelete: Boolean, $id: String!) { issueDelete(permanentlyDelete: $permanentlyDelete, id: $id) { success entity { ",
-    ) + &T::selection() + " } } }";
-    client
-        .execute_mutation::<T>(&query, variables, "issueDelete", "entity")
+    super::operations::user_discord_connect::<T>(redirect_uri, code)
+        .execute(client)
         .await
 }
-/// Creates a new issue relation.
+/// Disconnects the external user from this Linear account.
 ///
-/// Full type: [`IssueRelation`](super::types::IssueRelation)
-pub async fn issue_relation_create<
+/// Full type: [`User`](super::types::User)
+///
+/// Requires the `users` feature.
+#[cfg(feature = "users")]
+pub async fn user_external_user_disconnect<
     T: serde::de::DeserializeOwned
-        + crate::field_selection::GraphQLFields<FullType = super::types::IssueRelation>,
+        + crate::field_selection::GraphQLFields<FullType = super::types::User>,
 >(
     client: &Client,
-    override_created_at: Option<serde_json::Value>,
-    input: IssueRelationCreateInput,
+    service: String,
 ) -> Result<T, LinearError> {
-    let variables = serde_json::json!(
-        { "overrideCreatedAt" : override_created_at, "input" : input }
-    );
-    let query = String::from(
-        "mutation IssueRelationCreate($overrideCreatedAt: DateTime, $input: IssueRelationCreateInput!) { issueRelationCreate(overrideCreatedAt: $overrideCreatedAt, input: $input) { success issueRelation { ",
-    ) + &T::selection() + " } } }";
-    client
-        .execute_mutation::<T>(&query, variables, "issueRelationCreate", "issueRelation")
+    super::operations::user_external_user_disconnect::<T>(service)
+        .execute(client)
         .await
 }
-/// Deletes an issue relation.
-pub async fn issue_relation_delete(
+/// Changes the workspace role of a user. The requesting user must have a role equal to or higher than the target role. Requires workspace admin permissions.
+///
+/// Requires the `users` feature.
+#[cfg(feature = "users")]
+pub async fn user_change_role(
     client: &Client,
-    id: String,
-) -> Result<serde_json::Value, LinearError> {
-    let variables = serde_json::json!({ "id" : id });
-    let response_parts: Vec<String> = vec!["success".to_string(), "entityId".to_string()];
-    let query = String::from(
-        "mutation IssueRelationDelete($id: String!) { issueRelationDelete(id: $id) { ",
-    ) + &response_parts.join(" ")
-        + " } }";
-    client
-        .execute::<serde_json::Value>(&query, variables, "issueRelationDelete")
+    role: UserRoleType,
+    id: impl Into<super::ids::UserId>,
+) -> Result<super::types::UserAdminPayload, LinearError> {
+    super::operations::user_change_role(role, id)
+        .execute(client)
         .await
 }
-/// Creates a new label.
+/// Suspends a user, deactivating their account and revoking access to the workspace. The suspended user's sessions are invalidated. Can only be called by a workspace admin or owner.
 ///
-/// Full type: [`IssueLabel`](super::types::IssueLabel)
-pub async fn issue_label_create<
+/// Requires the `users` feature.
+#[cfg(feature = "users")]
+pub async fn user_suspend(
+    client: &Client,
+    force_bypass_scim_restrictions: Option<bool>,
+    id: impl Into<super::ids::UserId>,
+) -> Result<super::types::UserAdminPayload, LinearError> {
+    super::operations::user_suspend(force_bypass_scim_restrictions, id)
+        .execute(client)
+        .await
+}
+/// Revokes all active sessions for a user, forcing them to re-authenticate. Can only be called by a workspace admin or owner.
+///
+/// Requires the `users` feature.
+#[cfg(feature = "users")]
+pub async fn user_revoke_all_sessions(
+    client: &Client,
+    id: impl Into<super::ids::UserId>,
+) -> Result<super::types::UserAdminPayload, LinearError> {
+    super::operations::user_revoke_all_sessions(id)
+        .execute(client)
+        .await
+}
+/// Revokes a specific authentication session for a user. The admin cannot revoke their own sessions with this mutation. Can only be called by a workspace admin or owner.
+///
+/// Requires the `users` feature.
+#[cfg(feature = "users")]
+pub async fn user_revoke_session(
+    client: &Client,
+    session_id: String,
+    id: impl Into<super::ids::UserId>,
+) -> Result<super::types::UserAdminPayload, LinearError> {
+    super::operations::user_revoke_session(session_id, id)
+        .execute(client)
+        .await
+}
+/// Re-activates a suspended user, restoring their access to the workspace. Can only be called by a workspace admin or owner.
+///
+/// Requires the `users` feature.
+#[cfg(feature = "users")]
+pub async fn user_unsuspend(
+    client: &Client,
+    force_bypass_scim_restrictions: Option<bool>,
+    id: impl Into<super::ids::UserId>,
+) -> Result<super::types::UserAdminPayload, LinearError> {
+    super::operations::user_unsuspend(force_bypass_scim_restrictions, id)
+        .execute(client)
+        .await
+}
+/// Unlinks a guest user from their identity provider. Can only be called by an admin when SCIM is enabled.
+///
+/// Requires the `users` feature.
+#[cfg(feature = "users")]
+pub async fn user_unlink_from_identity_provider(
+    client: &Client,
+    id: impl Into<super::ids::UserId>,
+) -> Result<super::types::UserAdminPayload, LinearError> {
+    super::operations::user_unlink_from_identity_provider(id)
+        .execute(client)
+        .await
+}
+/// Creates a new triage responsibility.
+///
+/// Full type: [`TriageResponsibility`](super::types::TriageResponsibility)
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+pub async fn triage_responsibility_create<
     T: serde::de::DeserializeOwned
-        + crate::field_selection::GraphQLFields<FullType = super::types::IssueLabel>,
+        + crate::field_selection::GraphQLFields<FullType = super::types::TriageResponsibility>,
 >(
     client: &Client,
-    replace_team_labels: Option<bool>,
-    input: IssueLabelCreateInput,
+    input: TriageResponsibilityCreateInput,
 ) -> Result<T, LinearError> {
-    let variables = serde_json::json!(
-        { "replaceTeamLabels" : replace_team_labels, "input" : input }
-    );
-    let query = String::from(
-        "mutation IssueLabelCreate($replaceTeamLabels: Boolean, $input: IssueLabelCreateInput!) { issueLabelCreate(replaceTeamLabels: $replaceTeamLabels, input: $input) { success issueLabel { ",
-    ) + &T::selection() + " } } }";
-    client
-        .execute_mutation::<T>(&query, variables, "issueLabelCreate", "issueLabel")
+    super::operations::triage_responsibility_create::<T>(input)
+        .execute(client)
         .await
 }
-/// Updates a label.
+/// Updates an existing triage responsibility.
 ///
-/// Full type: [`IssueLabel`](super::types::IssueLabel)
-pub async fn issue_label_update<
+/// Full type: [`TriageResponsibility`](super::types::TriageResponsibility)
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+pub async fn triage_responsibility_update<
     T: serde::de::DeserializeOwned
-        + crate::field_selection::GraphQLFields<FullType = super::types::IssueLabel>,
+        + crate::field_selection::GraphQLFields<FullType = super::types::TriageResponsibility>,
 >(
     client: &Client,
-    replace_team_labels: Option<bool>,
-    input: IssueLabelUpdateInput,
-    id: String,
+    input: TriageResponsibilityUpdateInput,
+    id: impl Into<super::ids::TriageResponsibilityId>,
 ) -> Result<T, LinearError> {
-    let variables = serde_json::json!(
-        { "replaceTeamLabels" : replace_team_labels, "input" : input, "id" : id }
-    );
-    let query = String::from(
-        "mutation IssueLabelUpdate($replaceTeamLabels: Boolean, $input: IssueLabelUpdateInput!, $id: String!) { issueLabelUpdate(replaceTeamLabels: $replaceTeamLabels, input: $input, id: $id) { success issueLabel { ",
-    ) + &T::selection() + " } } }";
-    client
-        .execute_mutation::<T>(&query, variables, "issueLabelUpdate", "issueLabel")
+    super::operations::triage_responsibility_update::<T>(input, id)
+        .execute(client)
         .await
 }
-/// Deletes an issue label.
-pub async fn issue_label_delete(
+/// Deletes a triage responsibility.
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+pub async fn triage_responsibility_delete(
     client: &Client,
-    id: String,
-) -> Result<serde_json::Value, LinearError> {
-    let variables = serde_json::json!({ "id" : id });
-    let response_parts: Vec<String> = vec!["success".to_string(), "entityId".to_string()];
-    let query =
-        String::from("mutation IssueLabelDelete($id: String!) { issueLabelDelete(id: $id) { ")
-            + &response_parts.join(" ")
-            + " } }";
-    client
-        .execute::<serde_json::Value>(&query, variables, "issueLabelDelete")
+    id: impl Into<super::ids::TriageResponsibilityId>,
+) -> Result<super::types::DeletePayload, LinearError> {
+    super::operations::triage_responsibility_delete(id)
+        .execute(client)
         .await
 }
-/// Creates a new document.
+/// Creates a new time schedule.
 ///
-/// Full type: [`Document`](super::types::Document)
-pub async fn document_create<
+/// Full type: [`TimeSchedule`](super::types::TimeSchedule)
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+pub async fn time_schedule_create<
     T: serde::de::DeserializeOwned
-        + crate::field_selection::GraphQLFields<FullType = super::types::Document>,
+        + crate::field_selection::GraphQLFields<FullType = super::types::TimeSchedule>,
 >(
     client: &Client,
-    input: DocumentCreateInput,
+    input: TimeScheduleCreateInput,
 ) -> Result<T, LinearError> {
-    let variables = serde_json::json!({ "input" : input });
-    let query = String::from(
-        "mutation DocumentCreate($input: DocumentCreateInput!) { documentCreate(input: $input) { success document { ",
-    ) + &T::selection() + " } } }";
-    client
-        .execute_mutation::<T>(&query, variables, "documentCreate", "document")
+    super::operations::time_schedule_create::<T>(input)
+        .execute(client)
         .await
 }
-/// Updates a document.
+/// Updates a time schedule.
 ///
-/// Full type: [`Document`](super::types::Document)
-pub async fn document_update<
+/// Full type: [`TimeSchedule`](super::types::TimeSchedule)
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+pub async fn time_schedule_update<
     T: serde::de::DeserializeOwned
-        + crate::field_selection::GraphQLFields<FullType = super::types::Document>,
+        + crate::field_selection::GraphQLFields<FullType = super::types::TimeSchedule>,
 >(
     client: &Client,
-    input: DocumentUpdateInput,
-    id: String,
+    input: TimeScheduleUpdateInput,
+    id: impl Into<super::ids::TimeScheduleId>,
 ) -> Result<T, LinearError> {
-    let variables = serde_json::json!({ "input" : input, "id" : id });
-    let query = String::from(
-        "mutation DocumentUpdate($input: DocumentUpdateInput!, $id: String!) { documentUpdate(input: $input, id: $id) { success document { ",
-    ) + &T::selection() + " } } }";
-    client
-        .execute_mutation::<T>(&query, variables, "documentUpdate", "document")
+    super::operations::time_schedule_update::<T>(input, id)
+        .execute(client)
         .await
 }
-/// Deletes (trashes) a document. The document is marked as trashed and archived, but not permanently removed.
+/// Upsert an external time schedule.
 ///
-/// Full type: [`Document`](super::types::Document)
-pub async fn document_delete<
+/// Full type: [`TimeSchedule`](super::types::TimeSchedule)
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+pub async fn time_schedule_upsert_external<
     T: serde::de::DeserializeOwned
-        + crate::field_selection::GraphQLFields<FullType = super::types::Document>,
+        + crate::field_selection::GraphQLFields<FullType = super::types::TimeSchedule>,
 >(
     client: &Client,
-    id: String,
+    input: TimeScheduleUpdateInput,
+    external_id: String,
 ) -> Result<T, LinearError> {
-    let variables = serde_json::json!({ "id" : id });
-    let query = String::from(
-        "mutation DocumentDelete($id: String!) { documentDelete(id: $id) { success entity { ",
-    ) + &T::selection()
-        + " } } }";
-    client
-        .execute_mutation::<T>(&query, variables, "documentDelete", "entity")
+    super::operations::time_schedule_upsert_external::<T>(input, external_id)
+        .execute(client)
         .await
 }
-/// Creates a new comment.
+/// Deletes a time schedule.
 ///
-/// Full type: [`Comment`](super::types::Comment)
-pub async fn comment_create<
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+pub async fn time_schedule_delete(
+    client: &Client,
+    id: impl Into<super::ids::TimeScheduleId>,
+) -> Result<super::types::DeletePayload, LinearError> {
+    super::operations::time_schedule_delete(id)
+        .execute(client)
+        .await
+}
+/// Refresh the integration schedule information.
+///
+/// Full type: [`TimeSchedule`](super::types::TimeSchedule)
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+pub async fn time_schedule_refresh_integration_schedule<
     T: serde::de::DeserializeOwned
-        + crate::field_selection::GraphQLFields<FullType = super::types::Comment>,
+        + crate::field_selection::GraphQLFields<FullType = super::types::TimeSchedule>,
 >(
     client: &Client,
-    input: CommentCreateInput,
+    id: impl Into<super::ids::TimeScheduleId>,
 ) -> Result<T, LinearError> {
-    let variables = serde_json::json!({ "input" : input });
-    let query = String::from(
-        "mutation CommentCreate($input: CommentCreateInput!) { commentCreate(input: $input) { success comment { ",
-    ) + &T::selection() + " } } }";
-    client
-        .execute_mutation::<T>(&query, variables, "commentCreate", "comment")
+    super::operations::time_schedule_refresh_integration_schedule::<T>(id)
+        .execute(client)
         .await
 }
-/// Updates a comment.
+/// Creates a new template.
 ///
-/// Full type: [`Comment`](super::types::Comment)
-pub async fn comment_update<
+/// Full type: [`Template`](super::types::Template)
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+pub async fn template_create<
     T: serde::de::DeserializeOwned
-        + crate::field_selection::GraphQLFields<FullType = super::types::Comment>,
+        + crate::field_selection::GraphQLFields<FullType = super::types::Template>,
 >(
     client: &Client,
-    skip_edited_at: Option<bool>,
-    input: CommentUpdateInput,
-    id: String,
+    input: TemplateCreateInput,
 ) -> Result<T, LinearError> {
-    let variables = serde_json::json!(
-        { "skipEditedAt" : skip_edited_at, "input" : input, "id" : id }
-    );
-    let query = String::from(
-        "mutation CommentUpdate($skipEditedAt: Boolean, $input: CommentUpdateInput!, $id: String!) { commentUpdate(skipEditedAt: $skipEditedAt, input: $input, id: $id) { success comment { ",
-    ) + &T::selection() + " } } }";
-    client
-        .execute_mutation::<T>(&query, variables, "commentUpdate", "comment")
+    super::operations::template_create::<T>(input)
+        .execute(client)
         .await
 }
-/// Deletes a comment.
-pub async fn comment_delete(client: &Client, id: String) -> Result<serde_json::Value, LinearError> {
-    let variables = serde_json::json!({ "id" : id });
-    let response_parts: Vec<String> = vec!["success".to_string(), "entityId".to_string()];
-    let query = String::from("mutation CommentDelete($id: String!) { commentDelete(id: $id) { ")
-        + &response_parts.join(" ")
-        + " } }";
-    client
-        .execute::<serde_json::Value>(&query, variables, "commentDelete")
+/// Updates an existing template.
+///
+/// Full type: [`Template`](super::types::Template)
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+pub async fn template_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Template>,
+>(
+    client: &Client,
+    input: TemplateUpdateInput,
+    id: impl Into<super::ids::TemplateId>,
+) -> Result<T, LinearError> {
+    super::operations::template_update::<T>(input, id)
+        .execute(client)
         .await
 }
-/// Resolves a comment thread. Marks the root comment as resolved by the current user.
+/// Deletes a template.
 ///
-/// Full type: [`Comment`](super::types::Comment)
-pub async fn comment_resolve<
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+pub async fn template_delete(
+    client: &Client,
+    id: impl Into<super::ids::TemplateId>,
+) -> Result<super::types::DeletePayload, LinearError> {
+    super::operations::template_delete(id).execute(client).await
+}
+/// Creates a new project.
+///
+/// Full type: [`Project`](super::types::Project)
+pub async fn project_create<
     T: serde::de::DeserializeOwned
-        + crate::field_selection::GraphQLFields<FullType = super::types::Comment>,
+        + crate::field_selection::GraphQLFields<FullType = super::types::Project>,
 >(
     client: &Client,
-    resolving_comment_id: Option<String>,
-    id: String,
+    slack_channel_name: Option<String>,
+    input: ProjectCreateInput,
 ) -> Result<T, LinearError> {
-    let variables = serde_json::json!(
-        { "resolvingCommentId" : resolving_comment_id, "id" : id }
-    );
-    let query = String::from(
-        "mutation CommentResolve($resolvingCommentId: String, $id: String!) { commentResolve(resolvingCommentId: $resolvingCommentId, id: $id) { success comment { ",
-    ) + &T::selection() + " } } }";
-    client
-        .execute_mutation::<T>(&query, variables, "commentResolve", "comment")
+    super::operations::project_create::<T>(slack_channel_name, input)
+        .execute(client)
         .await
 }
-/// Unresolves a previously resolved comment thread. Clears the resolved state on the root comment.
+/// Updates a project.
 ///
-/// Full type: [`Comment`](super::types::Comment)
-pub async fn comment_unresolve<
+/// Full type: [`Project`](super::types::Project)
+pub async fn project_update<
     T: serde::de::DeserializeOwned
-        + crate::field_selection::GraphQLFields<FullType = super::types::Comment>,
+        + crate::field_selection::GraphQLFields<FullType = super::types::Project>,
 >(
     client: &Client,
-    id: String,
+    input: ProjectUpdateInput,
+    id: impl Into<super::ids::ProjectId>,
+) -> Result<T, LinearError> {
+    super::operations::project_update::<T>(input, id)
+        .execute(client)
+        .await
+}
+/// `Internal` Creates a Slack channel for an existing project.
+///
+/// Full type: [`Project`](super::types::Project)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub async fn project_create_slack_channel<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Project>,
+>(
+    client: &Client,
+    integration_id: Option<String>,
+    slack_channel_name: String,
+    id: impl Into<super::ids::ProjectId>,
+) -> Result<T, LinearError> {
+    super::operations::project_create_slack_channel::<T>(integration_id, slack_channel_name, id)
+        .execute(client)
+        .await
+}
+/// `INTERNAL` Reassigns all projects from one project status to another. Used when archiving or deleting a project status.
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub async fn project_reassign_status(
+    client: &Client,
+    new_project_status_id: String,
+    original_project_status_id: String,
+) -> Result<super::types::SuccessPayload, LinearError> {
+    super::operations::project_reassign_status(new_project_status_id, original_project_status_id)
+        .execute(client)
+        .await
+}
+/// Deletes (trashes) a project. The project can be restored later with projectUnarchive.
+///
+/// Full type: [`Project`](super::types::Project)
+pub async fn project_delete<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Project>,
+>(
+    client: &Client,
+    id: impl Into<super::ids::ProjectId>,
+) -> Result<T, LinearError> {
+    super::operations::project_delete::<T>(id)
+        .execute(client)
+        .await
+}
+/// Archives a project.
+#[deprecated(note = "Deprecated in favor of projectDelete.")]
+///
+/// Full type: [`Project`](super::types::Project)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub async fn project_archive<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Project>,
+>(
+    client: &Client,
+    trash: Option<bool>,
+    id: impl Into<super::ids::ProjectId>,
+) -> Result<T, LinearError> {
+    super::operations::project_archive::<T>(trash, id)
+        .execute(client)
+        .await
+}
+/// Restores a previously trashed or archived project.
+///
+/// Full type: [`Project`](super::types::Project)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub async fn project_unarchive<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Project>,
+>(
+    client: &Client,
+    id: impl Into<super::ids::ProjectId>,
+) -> Result<T, LinearError> {
+    super::operations::project_unarchive::<T>(id)
+        .execute(client)
+        .await
+}
+/// Adds a label to a project.
+///
+/// Full type: [`Project`](super::types::Project)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub async fn project_add_label<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Project>,
+>(
+    client: &Client,
+    label_id: String,
+    id: impl Into<super::ids::ProjectId>,
+) -> Result<T, LinearError> {
+    super::operations::project_add_label::<T>(label_id, id)
+        .execute(client)
+        .await
+}
+/// Removes a label from a project.
+///
+/// Full type: [`Project`](super::types::Project)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub async fn project_remove_label<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Project>,
+>(
+    client: &Client,
+    label_id: String,
+    id: impl Into<super::ids::ProjectId>,
+) -> Result<T, LinearError> {
+    super::operations::project_remove_label::<T>(label_id, id)
+        .execute(client)
+        .await
+}
+/// Disables external sync on a project.
+///
+/// Full type: [`Project`](super::types::Project)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub async fn project_external_sync_disable<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Project>,
+>(
+    client: &Client,
+    sync_source: ExternalSyncService,
+    project_id: String,
+) -> Result<T, LinearError> {
+    super::operations::project_external_sync_disable::<T>(sync_source, project_id)
+        .execute(client)
+        .await
+}
+/// Creates a new team. The user who creates the team will automatically be added as a member and owner of the newly created team. Default workflow states, labels, and other team resources are created alongside the team.
+///
+/// Full type: [`Team`](super::types::Team)
+pub async fn team_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Team>,
+>(
+    client: &Client,
+    copy_settings_from_team_id: Option<String>,
+    input: TeamCreateInput,
+) -> Result<T, LinearError> {
+    super::operations::team_create::<T>(copy_settings_from_team_id, input)
+        .execute(client)
+        .await
+}
+/// Updates a team's settings, properties, or configuration. Requires team owner or workspace admin permissions for most changes.
+///
+/// Full type: [`Team`](super::types::Team)
+pub async fn team_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Team>,
+>(
+    client: &Client,
+    mapping: Option<InheritanceEntityMapping>,
+    input: TeamUpdateInput,
+    id: impl Into<super::ids::TeamId>,
+) -> Result<T, LinearError> {
+    super::operations::team_update::<T>(mapping, input, id)
+        .execute(client)
+        .await
+}
+/// Archives a team and schedules its data for deletion. Requires team owner or workspace admin permissions.
+pub async fn team_delete(
+    client: &Client,
+    id: impl Into<super::ids::TeamId>,
+) -> Result<super::types::DeletePayload, LinearError> {
+    super::operations::team_delete(id).execute(client).await
+}
+/// Unarchives a team and cancels deletion.
+///
+/// Full type: [`Team`](super::types::Team)
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+pub async fn team_unarchive<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Team>,
+>(
+    client: &Client,
+    id: impl Into<super::ids::TeamId>,
+) -> Result<T, LinearError> {
+    super::operations::team_unarchive::<T>(id)
+        .execute(client)
+        .await
+}
+/// Deletes all cycle data for a team, disabling the cycles feature. This removes all cycles and their issue associations.
+///
+/// Full type: [`Team`](super::types::Team)
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+pub async fn team_cycles_delete<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Team>,
+>(
+    client: &Client,
+    id: impl Into<super::ids::TeamId>,
+) -> Result<T, LinearError> {
+    super::operations::team_cycles_delete::<T>(id)
+        .execute(client)
+        .await
+}
+/// Creates a new team membership, adding a user to a team. Validates that the user is not already a member, the team is not archived or retired, and the requesting user has permission to add members.
+///
+/// Full type: [`TeamMembership`](super::types::TeamMembership)
+pub async fn team_membership_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::TeamMembership>,
+>(
+    client: &Client,
+    input: TeamMembershipCreateInput,
+) -> Result<T, LinearError> {
+    super::operations::team_membership_create::<T>(input)
+        .execute(client)
+        .await
+}
+/// Updates a team membership, such as changing ownership status or sort order.
+///
+/// Full type: [`TeamMembership`](super::types::TeamMembership)
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+pub async fn team_membership_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::TeamMembership>,
+>(
+    client: &Client,
+    input: TeamMembershipUpdateInput,
+    id: impl Into<super::ids::TeamMembershipId>,
+) -> Result<T, LinearError> {
+    super::operations::team_membership_update::<T>(input, id)
+        .execute(client)
+        .await
+}
+/// Deletes a team membership, removing the user from the team. Users can remove their own membership, or team owners and workspace admins can remove other members.
+pub async fn team_membership_delete(
+    client: &Client,
+    also_leave_parent_teams: Option<bool>,
+    id: impl Into<super::ids::TeamMembershipId>,
+) -> Result<super::types::DeletePayload, LinearError> {
+    super::operations::team_membership_delete(also_leave_parent_teams, id)
+        .execute(client)
+        .await
+}
+/// Deletes a previously used team key. The active team key (the team's current key) cannot be deleted.
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+pub async fn team_key_delete(
+    client: &Client,
+    id: impl Into<super::ids::TeamId>,
+) -> Result<super::types::DeletePayload, LinearError> {
+    super::operations::team_key_delete(id).execute(client).await
+}
+/// `Deprecated` Creates a new roadmap-to-project association. Use InitiativeToProject instead.
+///
+/// Full type: [`RoadmapToProject`](super::types::RoadmapToProject)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub async fn roadmap_to_project_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::RoadmapToProject>,
+>(
+    client: &Client,
+    input: RoadmapToProjectCreateInput,
+) -> Result<T, LinearError> {
+    super::operations::roadmap_to_project_create::<T>(input)
+        .execute(client)
+        .await
+}
+/// `Deprecated` Updates a roadmap-to-project association. Use InitiativeToProject instead.
+///
+/// Full type: [`RoadmapToProject`](super::types::RoadmapToProject)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub async fn roadmap_to_project_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::RoadmapToProject>,
+>(
+    client: &Client,
+    input: RoadmapToProjectUpdateInput,
+    id: impl Into<super::ids::RoadmapToProjectId>,
+) -> Result<T, LinearError> {
+    super::operations::roadmap_to_project_update::<T>(input, id)
+        .execute(client)
+        .await
+}
+/// `Deprecated` Deletes a roadmap-to-project association. Use InitiativeToProject instead.
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub async fn roadmap_to_project_delete(
+    client: &Client,
+    id: impl Into<super::ids::RoadmapToProjectId>,
+) -> Result<super::types::DeletePayload, LinearError> {
+    super::operations::roadmap_to_project_delete(id)
+        .execute(client)
+        .await
+}
+/// Creates a new roadmap.
+#[deprecated(note = "Roadmaps are deprecated, use initiatives instead.")]
+///
+/// Full type: [`Roadmap`](super::types::Roadmap)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub async fn roadmap_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Roadmap>,
+>(
+    client: &Client,
+    input: RoadmapCreateInput,
+) -> Result<T, LinearError> {
+    super::operations::roadmap_create::<T>(input)
+        .execute(client)
+        .await
+}
+/// Updates a roadmap.
+#[deprecated(note = "Roadmaps are deprecated, use initiatives instead.")]
+///
+/// Full type: [`Roadmap`](super::types::Roadmap)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub async fn roadmap_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Roadmap>,
+>(
+    client: &Client,
+    input: RoadmapUpdateInput,
+    id: impl Into<super::ids::RoadmapId>,
+) -> Result<T, LinearError> {
+    super::operations::roadmap_update::<T>(input, id)
+        .execute(client)
+        .await
+}
+/// Archives a roadmap.
+#[deprecated(note = "Roadmaps are deprecated, use initiatives instead.")]
+///
+/// Full type: [`Roadmap`](super::types::Roadmap)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub async fn roadmap_archive<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Roadmap>,
+>(
+    client: &Client,
+    id: impl Into<super::ids::RoadmapId>,
+) -> Result<T, LinearError> {
+    super::operations::roadmap_archive::<T>(id)
+        .execute(client)
+        .await
+}
+/// Unarchives a roadmap.
+#[deprecated(note = "Roadmaps are deprecated, use initiatives instead.")]
+///
+/// Full type: [`Roadmap`](super::types::Roadmap)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub async fn roadmap_unarchive<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Roadmap>,
+>(
+    client: &Client,
+    id: impl Into<super::ids::RoadmapId>,
+) -> Result<T, LinearError> {
+    super::operations::roadmap_unarchive::<T>(id)
+        .execute(client)
+        .await
+}
+/// Deletes a roadmap.
+#[deprecated(note = "Roadmaps are deprecated, use initiatives instead.")]
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub async fn roadmap_delete(
+    client: &Client,
+    id: impl Into<super::ids::RoadmapId>,
+) -> Result<super::types::DeletePayload, LinearError> {
+    super::operations::roadmap_delete(id).execute(client).await
+}
+/// Creates a CSV export report for the workspace. The report is generated asynchronously and delivered via email. Requires workspace admin export permission.
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub async fn create_csv_export_report(
+    client: &Client,
+    include_private_team_ids: Option<Vec<String>>,
+) -> Result<super::types::CreateCsvExportReportPayload, LinearError> {
+    super::operations::create_csv_export_report(include_private_team_ids)
+        .execute(client)
+        .await
+}
+/// `ALPHA` Creates a new release stage in a pipeline. Non-started stages must use default names and colors, and only one stage of each non-started type is allowed per pipeline. Started stages can optionally be frozen, but at least one non-frozen started stage must remain.
+///
+/// Full type: [`ReleaseStage`](super::types::ReleaseStage)
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+pub async fn release_stage_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::ReleaseStage>,
+>(
+    client: &Client,
+    input: ReleaseStageCreateInput,
+) -> Result<T, LinearError> {
+    super::operations::release_stage_create::<T>(input)
+        .execute(client)
+        .await
+}
+/// `ALPHA` Updates an existing release stage. Only started-type stages can be edited. Supports updating name, color, position, and frozen status.
+///
+/// Full type: [`ReleaseStage`](super::types::ReleaseStage)
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+pub async fn release_stage_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::ReleaseStage>,
+>(
+    client: &Client,
+    input: ReleaseStageUpdateInput,
+    id: impl Into<super::ids::ReleaseStageId>,
+) -> Result<T, LinearError> {
+    super::operations::release_stage_update::<T>(input, id)
+        .execute(client)
+        .await
+}
+/// `ALPHA` Archives a release stage. Only started-type stages can be archived, and only if they have no active releases and at least one other stage of the same type remains. Cannot archive the last non-frozen started stage.
+///
+/// Full type: [`ReleaseStage`](super::types::ReleaseStage)
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+pub async fn release_stage_archive<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::ReleaseStage>,
+>(
+    client: &Client,
+    id: impl Into<super::ids::ReleaseStageId>,
+) -> Result<T, LinearError> {
+    super::operations::release_stage_archive::<T>(id)
+        .execute(client)
+        .await
+}
+/// `ALPHA` Unarchives a release stage.
+///
+/// Full type: [`ReleaseStage`](super::types::ReleaseStage)
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+pub async fn release_stage_unarchive<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::ReleaseStage>,
+>(
+    client: &Client,
+    id: impl Into<super::ids::ReleaseStageId>,
+) -> Result<T, LinearError> {
+    super::operations::release_stage_unarchive::<T>(id)
+        .execute(client)
+        .await
+}
+/// `ALPHA` Creates a new release pipeline with default stages. Subject to plan entitlement and quota limits.
+///
+/// Full type: [`ReleasePipeline`](super::types::ReleasePipeline)
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+pub async fn release_pipeline_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::ReleasePipeline>,
+>(
+    client: &Client,
+    input: ReleasePipelineCreateInput,
+) -> Result<T, LinearError> {
+    super::operations::release_pipeline_create::<T>(input)
+        .execute(client)
+        .await
+}
+/// `ALPHA` Updates an existing release pipeline. Supports updating name, slug, type, production flag, path patterns, and team associations. Private teams that the current user cannot access are preserved in the team list.
+///
+/// Full type: [`ReleasePipeline`](super::types::ReleasePipeline)
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+pub async fn release_pipeline_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::ReleasePipeline>,
+>(
+    client: &Client,
+    input: ReleasePipelineUpdateInput,
+    id: impl Into<super::ids::ReleasePipelineId>,
+) -> Result<T, LinearError> {
+    super::operations::release_pipeline_update::<T>(input, id)
+        .execute(client)
+        .await
+}
+/// `ALPHA` Archives a release pipeline.
+///
+/// Full type: [`ReleasePipeline`](super::types::ReleasePipeline)
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+pub async fn release_pipeline_archive<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::ReleasePipeline>,
+>(
+    client: &Client,
+    id: impl Into<super::ids::ReleasePipelineId>,
+) -> Result<T, LinearError> {
+    super::operations::release_pipeline_archive::<T>(id)
+        .execute(client)
+        .await
+}
+/// `ALPHA` Unarchives a release pipeline.
+///
+/// Full type: [`ReleasePipeline`](super::types::ReleasePipeline)
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+pub async fn release_pipeline_unarchive<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::ReleasePipeline>,
+>(
+    client: &Client,
+    id: impl Into<super::ids::ReleasePipelineId>,
+) -> Result<T, LinearError> {
+    super::operations::release_pipeline_unarchive::<T>(id)
+        .execute(client)
+        .await
+}
+/// `ALPHA` Permanently deletes a release pipeline and all associated stages and releases.
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+pub async fn release_pipeline_delete(
+    client: &Client,
+    id: impl Into<super::ids::ReleasePipelineId>,
+) -> Result<super::types::DeletePayload, LinearError> {
+    super::operations::release_pipeline_delete(id)
+        .execute(client)
+        .await
+}
+/// `ALPHA` Syncs release data by resolving issue and pull request references and associating them with a release. For continuous pipelines, creates a new completed release. For scheduled pipelines, finds or creates a started release and accumulates issues into it.
+///
+/// Full type: [`Release`](super::types::Release)
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+pub async fn release_sync<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Release>,
+>(
+    client: &Client,
+    input: ReleaseSyncInput,
+) -> Result<T, LinearError> {
+    super::operations::release_sync::<T>(input)
+        .execute(client)
+        .await
+}
+/// `ALPHA` Creates a new release in a pipeline. If no stage is specified, defaults to the first completed stage for continuous pipelines or the first started stage for scheduled pipelines.
+///
+/// Full type: [`Release`](super::types::Release)
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+pub async fn release_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Release>,
+>(
+    client: &Client,
+    input: ReleaseCreateInput,
+) -> Result<T, LinearError> {
+    super::operations::release_create::<T>(input)
+        .execute(client)
+        .await
+}
+/// `ALPHA` Updates an existing release by ID. Supports updating name, description, version, commit SHA, pipeline, stage, and dates.
+///
+/// Full type: [`Release`](super::types::Release)
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+pub async fn release_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Release>,
+>(
+    client: &Client,
+    input: ReleaseUpdateInput,
+    id: impl Into<super::ids::ReleaseId>,
+) -> Result<T, LinearError> {
+    super::operations::release_update::<T>(input, id)
+        .execute(client)
+        .await
+}
+/// `ALPHA` Marks a release as completed. If version is provided, completes that specific release; otherwise completes the most recent started release.
+///
+/// Full type: [`Release`](super::types::Release)
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+pub async fn release_complete<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Release>,
+>(
+    client: &Client,
+    input: ReleaseCompleteInput,
+) -> Result<T, LinearError> {
+    super::operations::release_complete::<T>(input)
+        .execute(client)
+        .await
+}
+/// `ALPHA` Updates a release by pipeline identifier. Finds the release by version or latest started/planned release, and optionally transitions it to a new stage by name.
+///
+/// Full type: [`Release`](super::types::Release)
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+pub async fn release_update_by_pipeline<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Release>,
+>(
+    client: &Client,
+    input: ReleaseUpdateByPipelineInput,
+) -> Result<T, LinearError> {
+    super::operations::release_update_by_pipeline::<T>(input)
+        .execute(client)
+        .await
+}
+/// `ALPHA` Moves a release to the trash bin. Trashed releases are archived and will be permanently deleted after a retention period. If the release is already archived, it is marked as trashed with a fresh archive timestamp.
+///
+/// Full type: [`Release`](super::types::Release)
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+pub async fn release_delete<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Release>,
+>(
+    client: &Client,
+    id: impl Into<super::ids::ReleaseId>,
+) -> Result<T, LinearError> {
+    super::operations::release_delete::<T>(id)
+        .execute(client)
+        .await
+}
+/// `ALPHA` Archives a release.
+///
+/// Full type: [`Release`](super::types::Release)
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+pub async fn release_archive<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Release>,
+>(
+    client: &Client,
+    id: impl Into<super::ids::ReleaseId>,
+) -> Result<T, LinearError> {
+    super::operations::release_archive::<T>(id)
+        .execute(client)
+        .await
+}
+/// `ALPHA` Unarchives a release.
+///
+/// Full type: [`Release`](super::types::Release)
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+pub async fn release_unarchive<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Release>,
+>(
+    client: &Client,
+    id: impl Into<super::ids::ReleaseId>,
+) -> Result<T, LinearError> {
+    super::operations::release_unarchive::<T>(id)
+        .execute(client)
+        .await
+}
+/// `ALPHA` Syncs release data using an access key for CI/CD integration. The pipeline is automatically inferred from the access key's configured resources, so no pipeline ID is needed in the input.
+///
+/// Full type: [`Release`](super::types::Release)
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+pub async fn release_sync_by_access_key<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Release>,
+>(
+    client: &Client,
+    input: ReleaseSyncInputBase,
+) -> Result<T, LinearError> {
+    super::operations::release_sync_by_access_key::<T>(input)
+        .execute(client)
+        .await
+}
+/// `ALPHA` Marks a release as completed using an access key. If version is provided, completes that specific release; otherwise completes the most recent started release. The pipeline is inferred from the access key.
+///
+/// Full type: [`Release`](super::types::Release)
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+pub async fn release_complete_by_access_key<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Release>,
+>(
+    client: &Client,
+    input: ReleaseCompleteInputBase,
+) -> Result<T, LinearError> {
+    super::operations::release_complete_by_access_key::<T>(input)
+        .execute(client)
+        .await
+}
+/// `ALPHA` Updates a release by pipeline using an access key.
+///
+/// Full type: [`Release`](super::types::Release)
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+pub async fn release_update_by_pipeline_by_access_key<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Release>,
+>(
+    client: &Client,
+    input: ReleaseUpdateByPipelineInputBase,
+) -> Result<T, LinearError> {
+    super::operations::release_update_by_pipeline_by_access_key::<T>(input)
+        .execute(client)
+        .await
+}
+/// `ALPHA` Creates a release note.
+///
+/// Full type: [`ReleaseNote`](super::types::ReleaseNote)
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+pub async fn release_note_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::ReleaseNote>,
+>(
+    client: &Client,
+    input: ReleaseNoteCreateInput,
+) -> Result<T, LinearError> {
+    super::operations::release_note_create::<T>(input)
+        .execute(client)
+        .await
+}
+/// `ALPHA` Updates a release note.
+///
+/// Full type: [`ReleaseNote`](super::types::ReleaseNote)
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+pub async fn release_note_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::ReleaseNote>,
+>(
+    client: &Client,
+    input: ReleaseNoteUpdateInput,
+    id: impl Into<super::ids::ReleaseNoteId>,
+) -> Result<T, LinearError> {
+    super::operations::release_note_update::<T>(input, id)
+        .execute(client)
+        .await
+}
+/// `ALPHA` Deletes a release note.
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+pub async fn release_note_delete(
+    client: &Client,
+    id: impl Into<super::ids::ReleaseNoteId>,
+) -> Result<super::types::DeletePayload, LinearError> {
+    super::operations::release_note_delete(id)
+        .execute(client)
+        .await
+}
+/// Creates a new reaction.
+///
+/// Full type: [`Reaction`](super::types::Reaction)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub async fn reaction_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Reaction>,
+>(
+    client: &Client,
+    input: ReactionCreateInput,
+) -> Result<T, LinearError> {
+    super::operations::reaction_create::<T>(input)
+        .execute(client)
+        .await
+}
+/// Deletes a reaction.
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub async fn reaction_delete(
+    client: &Client,
+    id: impl Into<super::ids::ReactionId>,
+) -> Result<super::types::DeletePayload, LinearError> {
+    super::operations::reaction_delete(id).execute(client).await
+}
+/// Creates a push subscription for the authenticated user's current device or browser. If a subscription already exists for the same session, the old one is replaced.
+///
+/// Full type: [`PushSubscription`](super::types::PushSubscription)
+///
+/// Requires the `notifications` feature.
+#[cfg(feature = "notifications")]
+pub async fn push_subscription_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::PushSubscription>,
+>(
+    client: &Client,
+    input: PushSubscriptionCreateInput,
+) -> Result<T, LinearError> {
+    super::operations::push_subscription_create::<T>(input)
+        .execute(client)
+        .await
+}
+/// Deletes a push subscription, unregistering the device from receiving push notifications.
+///
+/// Full type: [`PushSubscription`](super::types::PushSubscription)
+///
+/// Requires the `notifications` feature.
+#[cfg(feature = "notifications")]
+pub async fn push_subscription_delete<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::PushSubscription>,
+>(
+    client: &Client,
+    id: impl Into<super::ids::PushSubscriptionId>,
+) -> Result<T, LinearError> {
+    super::operations::push_subscription_delete::<T>(id)
+        .execute(client)
+        .await
+}
+/// Creates a new project update.
+///
+/// Full type: [`ProjectUpdate`](super::types::ProjectUpdate)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub async fn project_update_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::ProjectUpdate>,
+>(
+    client: &Client,
+    input: ProjectUpdateCreateInput,
+) -> Result<T, LinearError> {
+    super::operations::project_update_create::<T>(input)
+        .execute(client)
+        .await
+}
+/// Updates a project update.
+///
+/// Full type: [`ProjectUpdate`](super::types::ProjectUpdate)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub async fn project_update_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::ProjectUpdate>,
+>(
+    client: &Client,
+    input: ProjectUpdateUpdateInput,
+    id: impl Into<super::ids::ProjectUpdateId>,
+) -> Result<T, LinearError> {
+    super::operations::project_update_update::<T>(input, id)
+        .execute(client)
+        .await
+}
+/// Archives a project update.
+///
+/// Full type: [`ProjectUpdate`](super::types::ProjectUpdate)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub async fn project_update_archive<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::ProjectUpdate>,
+>(
+    client: &Client,
+    id: impl Into<super::ids::ProjectUpdateId>,
+) -> Result<T, LinearError> {
+    super::operations::project_update_archive::<T>(id)
+        .execute(client)
+        .await
+}
+/// Unarchives a project update.
+///
+/// Full type: [`ProjectUpdate`](super::types::ProjectUpdate)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub async fn project_update_unarchive<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::ProjectUpdate>,
+>(
+    client: &Client,
+    id: impl Into<super::ids::ProjectUpdateId>,
+) -> Result<T, LinearError> {
+    super::operations::project_update_unarchive::<T>(id)
+        .execute(client)
+        .await
+}
+/// Deletes a project update.
+#[deprecated(note = "Use `projectUpdateArchive` instead.")]
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub async fn project_update_delete(
+    client: &Client,
+    id: impl Into<super::ids::ProjectUpdateId>,
+) -> Result<super::types::DeletePayload, LinearError> {
+    super::operations::project_update_delete(id)
+        .execute(client)
+        .await
+}
+/// Create a notification to remind a user about a project update.
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub async fn create_project_update_reminder(
+    client: &Client,
+    user_id: Option<String>,
+    project_id: String,
+) -> Result<super::types::ProjectUpdateReminderPayload, LinearError> {
+    super::operations::create_project_update_reminder(user_id, project_id)
+        .execute(client)
+        .await
+}
+/// Creates a new project status.
+///
+/// Full type: [`ProjectStatus`](super::types::ProjectStatus)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub async fn project_status_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::ProjectStatus>,
+>(
+    client: &Client,
+    input: ProjectStatusCreateInput,
+) -> Result<T, LinearError> {
+    super::operations::project_status_create::<T>(input)
+        .execute(client)
+        .await
+}
+/// Updates a project status.
+///
+/// Full type: [`ProjectStatus`](super::types::ProjectStatus)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub async fn project_status_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::ProjectStatus>,
+>(
+    client: &Client,
+    input: ProjectStatusUpdateInput,
+    id: impl Into<super::ids::ProjectStatusId>,
+) -> Result<T, LinearError> {
+    super::operations::project_status_update::<T>(input, id)
+        .execute(client)
+        .await
+}
+/// Archives a project status. The status must not have any active projects assigned to it and must not be the last status of its type.
+///
+/// Full type: [`ProjectStatus`](super::types::ProjectStatus)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub async fn project_status_archive<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::ProjectStatus>,
+>(
+    client: &Client,
+    id: impl Into<super::ids::ProjectStatusId>,
+) -> Result<T, LinearError> {
+    super::operations::project_status_archive::<T>(id)
+        .execute(client)
+        .await
+}
+/// Unarchives a project status.
+///
+/// Full type: [`ProjectStatus`](super::types::ProjectStatus)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub async fn project_status_unarchive<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::ProjectStatus>,
+>(
+    client: &Client,
+    id: impl Into<super::ids::ProjectStatusId>,
+) -> Result<T, LinearError> {
+    super::operations::project_status_unarchive::<T>(id)
+        .execute(client)
+        .await
+}
+/// Creates a new project relation.
+///
+/// Full type: [`ProjectRelation`](super::types::ProjectRelation)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub async fn project_relation_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::ProjectRelation>,
+>(
+    client: &Client,
+    input: ProjectRelationCreateInput,
+) -> Result<T, LinearError> {
+    super::operations::project_relation_create::<T>(input)
+        .execute(client)
+        .await
+}
+/// Updates a project relation.
+///
+/// Full type: [`ProjectRelation`](super::types::ProjectRelation)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub async fn project_relation_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::ProjectRelation>,
+>(
+    client: &Client,
+    input: ProjectRelationUpdateInput,
+    id: impl Into<super::ids::ProjectRelationId>,
+) -> Result<T, LinearError> {
+    super::operations::project_relation_update::<T>(input, id)
+        .execute(client)
+        .await
+}
+/// Deletes a project relation.
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub async fn project_relation_delete(
+    client: &Client,
+    id: impl Into<super::ids::ProjectRelationId>,
+) -> Result<super::types::DeletePayload, LinearError> {
+    super::operations::project_relation_delete(id)
+        .execute(client)
+        .await
+}
+/// Creates a new project milestone.
+///
+/// Full type: [`ProjectMilestone`](super::types::ProjectMilestone)
+pub async fn project_milestone_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::ProjectMilestone>,
+>(
+    client: &Client,
+    input: ProjectMilestoneCreateInput,
+) -> Result<T, LinearError> {
+    super::operations::project_milestone_create::<T>(input)
+        .execute(client)
+        .await
+}
+/// Updates a project milestone.
+///
+/// Full type: [`ProjectMilestone`](super::types::ProjectMilestone)
+pub async fn project_milestone_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::ProjectMilestone>,
+>(
+    client: &Client,
+    input: ProjectMilestoneUpdateInput,
+    id: impl Into<super::ids::ProjectMilestoneId>,
+) -> Result<T, LinearError> {
+    super::operations::project_milestone_update::<T>(input, id)
+        .execute(client)
+        .await
+}
+/// Deletes a project milestone.
+pub async fn project_milestone_delete(
+    client: &Client,
+    id: impl Into<super::ids::ProjectMilestoneId>,
+) -> Result<super::types::DeletePayload, LinearError> {
+    super::operations::project_milestone_delete(id)
+        .execute(client)
+        .await
+}
+/// `Internal` Moves a project milestone to another project, can be called to undo a prior move.
+///
+/// Full type: [`ProjectMilestone`](super::types::ProjectMilestone)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub async fn project_milestone_move<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::ProjectMilestone>,
+>(
+    client: &Client,
+    input: ProjectMilestoneMoveInput,
+    id: impl Into<super::ids::ProjectMilestoneId>,
+) -> Result<T, LinearError> {
+    super::operations::project_milestone_move::<T>(input, id)
+        .execute(client)
+        .await
+}
+/// Creates a new project label.
+///
+/// Full type: [`ProjectLabel`](super::types::ProjectLabel)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub async fn project_label_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::ProjectLabel>,
+>(
+    client: &Client,
+    input: ProjectLabelCreateInput,
+) -> Result<T, LinearError> {
+    super::operations::project_label_create::<T>(input)
+        .execute(client)
+        .await
+}
+/// Updates a project label.
+///
+/// Full type: [`ProjectLabel`](super::types::ProjectLabel)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub async fn project_label_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::ProjectLabel>,
+>(
+    client: &Client,
+    input: ProjectLabelUpdateInput,
+    id: impl Into<super::ids::ProjectLabelId>,
+) -> Result<T, LinearError> {
+    super::operations::project_label_update::<T>(input, id)
+        .execute(client)
+        .await
+}
+/// Deletes a project label.
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub async fn project_label_delete(
+    client: &Client,
+    id: impl Into<super::ids::ProjectLabelId>,
+) -> Result<super::types::DeletePayload, LinearError> {
+    super::operations::project_label_delete(id)
+        .execute(client)
+        .await
+}
+/// Retires a project label. Retired labels remain on existing projects but cannot be applied to new ones.
+///
+/// Full type: [`ProjectLabel`](super::types::ProjectLabel)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub async fn project_label_retire<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::ProjectLabel>,
+>(
+    client: &Client,
+    id: impl Into<super::ids::ProjectLabelId>,
+) -> Result<T, LinearError> {
+    super::operations::project_label_retire::<T>(id)
+        .execute(client)
+        .await
+}
+/// Restores a previously retired project label, making it available for use on new projects.
+///
+/// Full type: [`ProjectLabel`](super::types::ProjectLabel)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub async fn project_label_restore<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::ProjectLabel>,
+>(
+    client: &Client,
+    id: impl Into<super::ids::ProjectLabelId>,
+) -> Result<T, LinearError> {
+    super::operations::project_label_restore::<T>(id)
+        .execute(client)
+        .await
+}
+/// Updates the user's workspace settings. Different settings require different permission levels; most require the workspaceSettings admin permission.
+///
+/// Full type: [`Organization`](super::types::Organization)
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub async fn organization_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Organization>,
+>(
+    client: &Client,
+    input: OrganizationUpdateInput,
+) -> Result<T, LinearError> {
+    super::operations::organization_update::<T>(input)
+        .execute(client)
+        .await
+}
+/// Requests a deletion confirmation code for the workspace. The code is sent to the requesting user's email and must be used with the organizationDelete mutation to confirm deletion.
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub async fn organization_delete_challenge(
+    client: &Client,
+) -> Result<super::types::OrganizationDeletePayload, LinearError> {
+    super::operations::organization_delete_challenge()
+        .execute(client)
+        .await
+}
+/// Permanently deletes the workspace and all its data. Requires a valid deletion code obtained from organizationDeleteChallenge. This action is irreversible.
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub async fn organization_delete(
+    client: &Client,
+    input: DeleteOrganizationInput,
+) -> Result<super::types::OrganizationDeletePayload, LinearError> {
+    super::operations::organization_delete(input)
+        .execute(client)
+        .await
+}
+/// Cancels a previously requested workspace deletion, if the workspace has not yet been fully deleted.
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub async fn organization_cancel_delete(
+    client: &Client,
+) -> Result<super::types::OrganizationCancelDeletePayload, LinearError> {
+    super::operations::organization_cancel_delete()
+        .execute(client)
+        .await
+}
+/// Starts a trial for the workspace on the specified plan type. The workspace must not already be on a paid plan or in an active trial.
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub async fn organization_start_trial_for_plan(
+    client: &Client,
+    input: OrganizationStartTrialInput,
+) -> Result<super::types::OrganizationStartTrialPayload, LinearError> {
+    super::operations::organization_start_trial_for_plan(input)
+        .execute(client)
+        .await
+}
+/// `DEPRECATED` Starts a trial for the workspace.
+#[deprecated(note = "Use organizationStartTrialForPlan")]
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub async fn organization_start_trial(
+    client: &Client,
+) -> Result<super::types::OrganizationStartTrialPayload, LinearError> {
+    super::operations::organization_start_trial()
+        .execute(client)
+        .await
+}
+/// Creates a new workspace invite and sends an invitation email to the specified address. The invite includes a role assignment and optional team memberships.
+///
+/// Full type: [`OrganizationInvite`](super::types::OrganizationInvite)
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub async fn organization_invite_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::OrganizationInvite>,
+>(
+    client: &Client,
+    input: OrganizationInviteCreateInput,
+) -> Result<T, LinearError> {
+    super::operations::organization_invite_create::<T>(input)
+        .execute(client)
+        .await
+}
+/// Updates an existing workspace invite, such as changing the teams the invitee will be added to.
+///
+/// Full type: [`OrganizationInvite`](super::types::OrganizationInvite)
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub async fn organization_invite_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::OrganizationInvite>,
+>(
+    client: &Client,
+    input: OrganizationInviteUpdateInput,
+    id: impl Into<super::ids::OrganizationInviteId>,
+) -> Result<T, LinearError> {
+    super::operations::organization_invite_update::<T>(input, id)
+        .execute(client)
+        .await
+}
+/// Re-sends a workspace invitation email for the specified invite ID.
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub async fn resend_organization_invite(
+    client: &Client,
+    id: String,
+) -> Result<super::types::DeletePayload, LinearError> {
+    super::operations::resend_organization_invite(id)
+        .execute(client)
+        .await
+}
+/// Re-sends a workspace invitation email to the specified email address, if an outstanding invite exists for that address.
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub async fn resend_organization_invite_by_email(
+    client: &Client,
+    email: String,
+) -> Result<super::types::DeletePayload, LinearError> {
+    super::operations::resend_organization_invite_by_email(email)
+        .execute(client)
+        .await
+}
+/// Deletes (archives) a workspace invite, preventing it from being accepted.
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub async fn organization_invite_delete(
+    client: &Client,
+    id: impl Into<super::ids::OrganizationInviteId>,
+) -> Result<super::types::DeletePayload, LinearError> {
+    super::operations::organization_invite_delete(id)
+        .execute(client)
+        .await
+}
+/// `INTERNAL` Verifies a domain claim.
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub async fn organization_domain_claim(
+    client: &Client,
+    id: impl Into<super::ids::OrganizationDomainId>,
+) -> Result<super::types::OrganizationDomainSimplePayload, LinearError> {
+    super::operations::organization_domain_claim(id)
+        .execute(client)
+        .await
+}
+/// `INTERNAL` Verifies a domain to be added to a workspace.
+///
+/// Full type: [`OrganizationDomain`](super::types::OrganizationDomain)
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub async fn organization_domain_verify<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::OrganizationDomain>,
+>(
+    client: &Client,
+    input: OrganizationDomainVerificationInput,
+) -> Result<T, LinearError> {
+    super::operations::organization_domain_verify::<T>(input)
+        .execute(client)
+        .await
+}
+/// `INTERNAL` Adds a domain to be allowed for a workspace.
+///
+/// Full type: [`OrganizationDomain`](super::types::OrganizationDomain)
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub async fn organization_domain_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::OrganizationDomain>,
+>(
+    client: &Client,
+    trigger_email_verification: Option<bool>,
+    input: OrganizationDomainCreateInput,
+) -> Result<T, LinearError> {
+    super::operations::organization_domain_create::<T>(trigger_email_verification, input)
+        .execute(client)
+        .await
+}
+/// `INTERNAL` Updates a workspace domain's settings.
+///
+/// Full type: [`OrganizationDomain`](super::types::OrganizationDomain)
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub async fn organization_domain_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::OrganizationDomain>,
+>(
+    client: &Client,
+    input: OrganizationDomainUpdateInput,
+    id: impl Into<super::ids::OrganizationDomainId>,
+) -> Result<T, LinearError> {
+    super::operations::organization_domain_update::<T>(input, id)
+        .execute(client)
+        .await
+}
+/// Deletes a domain.
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub async fn organization_domain_delete(
+    client: &Client,
+    id: impl Into<super::ids::OrganizationDomainId>,
+) -> Result<super::types::DeletePayload, LinearError> {
+    super::operations::organization_domain_delete(id)
+        .execute(client)
+        .await
+}
+/// Creates a new notification subscription for a specific entity. The subscription determines which notification types the authenticated user will receive for the target entity. Exactly one target entity (customer, custom view, cycle, initiative, label, project, team, or user) must be specified.
+///
+/// Requires the `notifications` feature.
+#[cfg(feature = "notifications")]
+pub async fn notification_subscription_create(
+    client: &Client,
+    input: NotificationSubscriptionCreateInput,
+) -> Result<serde_json::Value, LinearError> {
+    super::operations::notification_subscription_create(input)
+        .execute(client)
+        .await
+}
+/// Updates a notification subscription.
+///
+/// Requires the `notifications` feature.
+#[cfg(feature = "notifications")]
+pub async fn notification_subscription_update(
+    client: &Client,
+    input: NotificationSubscriptionUpdateInput,
+    id: String,
+) -> Result<serde_json::Value, LinearError> {
+    super::operations::notification_subscription_update(input, id)
+        .execute(client)
+        .await
+}
+/// Deletes a notification subscription reference.
+#[deprecated(note = "Update `notificationSubscription.active` to `false` instead.")]
+///
+/// Requires the `notifications` feature.
+#[cfg(feature = "notifications")]
+pub async fn notification_subscription_delete(
+    client: &Client,
+    id: String,
+) -> Result<super::types::DeletePayload, LinearError> {
+    super::operations::notification_subscription_delete(id)
+        .execute(client)
+        .await
+}
+/// Updates a notification.
+///
+/// Requires the `notifications` feature.
+#[cfg(feature = "notifications")]
+pub async fn notification_update(
+    client: &Client,
+    input: NotificationUpdateInput,
+    id: String,
+) -> Result<serde_json::Value, LinearError> {
+    super::operations::notification_update(input, id)
+        .execute(client)
+        .await
+}
+/// Marks notification and all related notifications as read.
+///
+/// Requires the `notifications` feature.
+#[cfg(feature = "notifications")]
+pub async fn notification_mark_read_all(
+    client: &Client,
+    read_at: serde_json::Value,
+    input: NotificationEntityInput,
+) -> Result<serde_json::Value, LinearError> {
+    super::operations::notification_mark_read_all(read_at, input)
+        .execute(client)
+        .await
+}
+/// Marks notification and all related notifications as unread.
+///
+/// Requires the `notifications` feature.
+#[cfg(feature = "notifications")]
+pub async fn notification_mark_unread_all(
+    client: &Client,
+    input: NotificationEntityInput,
+) -> Result<serde_json::Value, LinearError> {
+    super::operations::notification_mark_unread_all(input)
+        .execute(client)
+        .await
+}
+/// Snoozes a notification and all related notifications.
+///
+/// Requires the `notifications` feature.
+#[cfg(feature = "notifications")]
+pub async fn notification_snooze_all(
+    client: &Client,
+    snoozed_until_at: serde_json::Value,
+    input: NotificationEntityInput,
+) -> Result<serde_json::Value, LinearError> {
+    super::operations::notification_snooze_all(snoozed_until_at, input)
+        .execute(client)
+        .await
+}
+/// Unsnoozes a notification and all related notifications.
+///
+/// Requires the `notifications` feature.
+#[cfg(feature = "notifications")]
+pub async fn notification_unsnooze_all(
+    client: &Client,
+    unsnoozed_at: serde_json::Value,
+    input: NotificationEntityInput,
+) -> Result<serde_json::Value, LinearError> {
+    super::operations::notification_unsnooze_all(unsnoozed_at, input)
+        .execute(client)
+        .await
+}
+/// Archives a notification.
+///
+/// Requires the `notifications` feature.
+#[cfg(feature = "notifications")]
+pub async fn notification_archive(
+    client: &Client,
+    id: String,
+) -> Result<serde_json::Value, LinearError> {
+    super::operations::notification_archive(id)
+        .execute(client)
+        .await
+}
+/// Archives a notification and all related notifications.
+///
+/// Requires the `notifications` feature.
+#[cfg(feature = "notifications")]
+pub async fn notification_archive_all(
+    client: &Client,
+    input: NotificationEntityInput,
+) -> Result<serde_json::Value, LinearError> {
+    super::operations::notification_archive_all(input)
+        .execute(client)
+        .await
+}
+/// Unarchives a notification.
+///
+/// Requires the `notifications` feature.
+#[cfg(feature = "notifications")]
+pub async fn notification_unarchive(
+    client: &Client,
+    id: String,
+) -> Result<serde_json::Value, LinearError> {
+    super::operations::notification_unarchive(id)
+        .execute(client)
+        .await
+}
+/// `ALPHA` Creates a new association between an issue and a release, linking the issue to the release for tracking purposes.
+///
+/// Full type: [`IssueToRelease`](super::types::IssueToRelease)
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+pub async fn issue_to_release_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::IssueToRelease>,
+>(
+    client: &Client,
+    input: IssueToReleaseCreateInput,
+) -> Result<T, LinearError> {
+    super::operations::issue_to_release_create::<T>(input)
+        .execute(client)
+        .await
+}
+/// `ALPHA` Deletes an issue-to-release association by looking up the issue and release identifiers, removing the issue from the release.
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+pub async fn issue_to_release_delete_by_issue_and_release(
+    client: &Client,
+    release_id: String,
+    issue_id: String,
+) -> Result<super::types::DeletePayload, LinearError> {
+    super::operations::issue_to_release_delete_by_issue_and_release(release_id, issue_id)
+        .execute(client)
+        .await
+}
+/// `ALPHA` Deletes an issue-to-release association by its identifier, removing the issue from the release.
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+pub async fn issue_to_release_delete(
+    client: &Client,
+    id: impl Into<super::ids::IssueToReleaseId>,
+) -> Result<super::types::DeletePayload, LinearError> {
+    super::operations::issue_to_release_delete(id)
+        .execute(client)
+        .await
+}
+/// Creates a new issue.
+///
+/// Full type: [`Issue`](super::types::Issue)
+pub async fn issue_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Issue>,
+>(
+    client: &Client,
+    input: IssueCreateInput,
+) -> Result<T, LinearError> {
+    super::operations::issue_create::<T>(input)
+        .execute(client)
+        .await
+}
+/// Creates a list of issues in one transaction.
+///
+/// Full type: [`Issue`](super::types::Issue)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub async fn issue_batch_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Issue>,
+>(
+    client: &Client,
+    input: IssueBatchCreateInput,
+) -> Result<Vec<T>, LinearError> {
+    super::operations::issue_batch_create::<T>(input)
+        .execute(client)
+        .await
+}
+/// Updates an issue.
+///
+/// Full type: [`Issue`](super::types::Issue)
+pub async fn issue_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Issue>,
+>(
+    client: &Client,
+    input: IssueUpdateInput,
+    id: impl Into<super::ids::IssueId>,
+) -> Result<T, LinearError> {
+    super::operations::issue_update::<T>(input, id)
+        .execute(client)
+        .await
+}
+/// Updates multiple issues at once.
+///
+/// Full type: [`Issue`](super::types::Issue)
+pub async fn issue_batch_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Issue>,
+>(
+    client: &Client,
+    input: IssueUpdateInput,
+    ids: Vec<String>,
+) -> Result<Vec<T>, LinearError> {
+    super::operations::issue_batch_update::<T>(input, ids)
+        .execute(client)
+        .await
+}
+/// Archives an issue.
+///
+/// Full type: [`Issue`](super::types::Issue)
+pub async fn issue_archive<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Issue>,
+>(
+    client: &Client,
+    trash: Option<bool>,
+    id: impl Into<super::ids::IssueId>,
+) -> Result<T, LinearError> {
+    super::operations::issue_archive::<T>(trash, id)
+        .execute(client)
+        .await
+}
+/// Unarchives an issue.
+///
+/// Full type: [`Issue`](super::types::Issue)
+pub async fn issue_unarchive<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Issue>,
+>(
+    client: &Client,
+    id: impl Into<super::ids::IssueId>,
+) -> Result<T, LinearError> {
+    super::operations::issue_unarchive::<T>(id)
+        .execute(client)
+        .await
+}
+/// Deletes (trashes) an issue.
+///
+/// Full type: [`Issue`](super::types::Issue)
+pub async fn issue_delete<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Issue>,
+>(
+    client: &Client,
+    permanently_delete: Option<bool>,
+    id: impl Into<super::ids::IssueId>,
+) -> Result<T, LinearError> {
+    super::operations::issue_delete::<T>(permanently_delete, id)
+        .execute(client)
+        .await
+}
+/// Adds a label to an issue.
+///
+/// Full type: [`Issue`](super::types::Issue)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub async fn issue_add_label<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Issue>,
+>(
+    client: &Client,
+    label_id: String,
+    id: impl Into<super::ids::IssueId>,
+) -> Result<T, LinearError> {
+    super::operations::issue_add_label::<T>(label_id, id)
+        .execute(client)
+        .await
+}
+/// Removes a label from an issue.
+///
+/// Full type: [`Issue`](super::types::Issue)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub async fn issue_remove_label<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Issue>,
+>(
+    client: &Client,
+    label_id: String,
+    id: impl Into<super::ids::IssueId>,
+) -> Result<T, LinearError> {
+    super::operations::issue_remove_label::<T>(label_id, id)
+        .execute(client)
+        .await
+}
+/// Adds an issue reminder. Will cause a notification to be sent when the issue reminder time is reached.
+///
+/// Full type: [`Issue`](super::types::Issue)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub async fn issue_reminder<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Issue>,
+>(
+    client: &Client,
+    reminder_at: serde_json::Value,
+    id: impl Into<super::ids::IssueId>,
+) -> Result<T, LinearError> {
+    super::operations::issue_reminder::<T>(reminder_at, id)
+        .execute(client)
+        .await
+}
+/// Subscribes a user to an issue.
+///
+/// Full type: [`Issue`](super::types::Issue)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub async fn issue_subscribe<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Issue>,
+>(
+    client: &Client,
+    user_email: Option<String>,
+    user_id: Option<String>,
+    id: impl Into<super::ids::IssueId>,
+) -> Result<T, LinearError> {
+    super::operations::issue_subscribe::<T>(user_email, user_id, id)
+        .execute(client)
+        .await
+}
+/// Unsubscribes a user from an issue.
+///
+/// Full type: [`Issue`](super::types::Issue)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub async fn issue_unsubscribe<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Issue>,
+>(
+    client: &Client,
+    user_email: Option<String>,
+    user_id: Option<String>,
+    id: impl Into<super::ids::IssueId>,
+) -> Result<T, LinearError> {
+    super::operations::issue_unsubscribe::<T>(user_email, user_id, id)
+        .execute(client)
+        .await
+}
+/// `INTERNAL` Updates an issue description from the Front app to handle Front attachments correctly.
+///
+/// Full type: [`Issue`](super::types::Issue)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub async fn issue_description_update_from_front<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Issue>,
+>(
+    client: &Client,
+    description: String,
+    id: impl Into<super::ids::IssueId>,
+) -> Result<T, LinearError> {
+    super::operations::issue_description_update_from_front::<T>(description, id)
+        .execute(client)
+        .await
+}
+/// Disables external sync on an issue.
+///
+/// Full type: [`Issue`](super::types::Issue)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub async fn issue_external_sync_disable<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Issue>,
+>(
+    client: &Client,
+    attachment_id: String,
+) -> Result<T, LinearError> {
+    super::operations::issue_external_sync_disable::<T>(attachment_id)
+        .execute(client)
+        .await
+}
+/// Creates a new issue relation.
+///
+/// Full type: [`IssueRelation`](super::types::IssueRelation)
+pub async fn issue_relation_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::IssueRelation>,
+>(
+    client: &Client,
+    override_created_at: Option<serde_json::Value>,
+    input: IssueRelationCreateInput,
+) -> Result<T, LinearError> {
+    super::operations::issue_relation_create::<T>(override_created_at, input)
+        .execute(client)
+        .await
+}
+/// Updates an issue relation.
+///
+/// Full type: [`IssueRelation`](super::types::IssueRelation)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub async fn issue_relation_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::IssueRelation>,
+>(
+    client: &Client,
+    input: IssueRelationUpdateInput,
+    id: impl Into<super::ids::IssueRelationId>,
+) -> Result<T, LinearError> {
+    super::operations::issue_relation_update::<T>(input, id)
+        .execute(client)
+        .await
+}
+/// Deletes an issue relation.
+pub async fn issue_relation_delete(
+    client: &Client,
+    id: impl Into<super::ids::IssueRelationId>,
+) -> Result<super::types::DeletePayload, LinearError> {
+    super::operations::issue_relation_delete(id)
+        .execute(client)
+        .await
+}
+/// Creates a new label.
+///
+/// Full type: [`IssueLabel`](super::types::IssueLabel)
+pub async fn issue_label_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::IssueLabel>,
+>(
+    client: &Client,
+    replace_team_labels: Option<bool>,
+    input: IssueLabelCreateInput,
+) -> Result<T, LinearError> {
+    super::operations::issue_label_create::<T>(replace_team_labels, input)
+        .execute(client)
+        .await
+}
+/// Updates a label.
+///
+/// Full type: [`IssueLabel`](super::types::IssueLabel)
+pub async fn issue_label_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::IssueLabel>,
+>(
+    client: &Client,
+    replace_team_labels: Option<bool>,
+    input: IssueLabelUpdateInput,
+    id: impl Into<super::ids::IssueLabelId>,
+) -> Result<T, LinearError> {
+    super::operations::issue_label_update::<T>(replace_team_labels, input, id)
+        .execute(client)
+        .await
+}
+/// Deletes an issue label.
+pub async fn issue_label_delete(
+    client: &Client,
+    id: impl Into<super::ids::IssueLabelId>,
+) -> Result<super::types::DeletePayload, LinearError> {
+    super::operations::issue_label_delete(id)
+        .execute(client)
+        .await
+}
+/// Retires a label. Retired labels are still visible but cannot be applied to new issues. Existing issues with the label are not affected.
+///
+/// Full type: [`IssueLabel`](super::types::IssueLabel)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub async fn issue_label_retire<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::IssueLabel>,
+>(
+    client: &Client,
+    id: impl Into<super::ids::IssueLabelId>,
+) -> Result<T, LinearError> {
+    super::operations::issue_label_retire::<T>(id)
+        .execute(client)
+        .await
+}
+/// Restores a previously retired label, making it available for use again.
+///
+/// Full type: [`IssueLabel`](super::types::IssueLabel)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub async fn issue_label_restore<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::IssueLabel>,
+>(
+    client: &Client,
+    id: impl Into<super::ids::IssueLabelId>,
+) -> Result<T, LinearError> {
+    super::operations::issue_label_restore::<T>(id)
+        .execute(client)
+        .await
+}
+/// Kicks off a GitHub import job.
+///
+/// Full type: [`IssueImport`](super::types::IssueImport)
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub async fn issue_import_create_github<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::IssueImport>,
+>(
+    client: &Client,
+    team_id: Option<String>,
+    team_name: Option<String>,
+    github_repo_ids: Option<Vec<i64>>,
+    github_labels: Option<Vec<String>>,
+    instant_process: Option<bool>,
+    include_closed_issues: Option<bool>,
+) -> Result<T, LinearError> {
+    super::operations::issue_import_create_github::<T>(
+        team_id,
+        team_name,
+        github_repo_ids,
+        github_labels,
+        instant_process,
+        include_closed_issues,
+    )
+    .execute(client)
+    .await
+}
+/// Kicks off a Jira import job.
+///
+/// Full type: [`IssueImport`](super::types::IssueImport)
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub async fn issue_import_create_jira<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::IssueImport>,
+>(
+    client: &Client,
+    team_id: Option<String>,
+    team_name: Option<String>,
+    jira_token: String,
+    jira_project: String,
+    jira_email: String,
+    jira_hostname: String,
+    jql: Option<String>,
+    instant_process: Option<bool>,
+    include_closed_issues: Option<bool>,
+    id: Option<String>,
+) -> Result<T, LinearError> {
+    super::operations::issue_import_create_jira::<T>(
+        team_id,
+        team_name,
+        jira_token,
+        jira_project,
+        jira_email,
+        jira_hostname,
+        jql,
+        instant_process,
+        include_closed_issues,
+        id,
+    )
+    .execute(client)
+    .await
+}
+/// Kicks off a Jira import job from a CSV.
+///
+/// Full type: [`IssueImport`](super::types::IssueImport)
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub async fn issue_import_create_csv_jira<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::IssueImport>,
+>(
+    client: &Client,
+    team_id: Option<String>,
+    team_name: Option<String>,
+    csv_url: String,
+    jira_hostname: Option<String>,
+    jira_token: Option<String>,
+    jira_email: Option<String>,
+) -> Result<T, LinearError> {
+    super::operations::issue_import_create_csv_jira::<T>(
+        team_id,
+        team_name,
+        csv_url,
+        jira_hostname,
+        jira_token,
+        jira_email,
+    )
+    .execute(client)
+    .await
+}
+/// Kicks off a Shortcut (formerly Clubhouse) import job.
+///
+/// Full type: [`IssueImport`](super::types::IssueImport)
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub async fn issue_import_create_clubhouse<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::IssueImport>,
+>(
+    client: &Client,
+    team_id: Option<String>,
+    team_name: Option<String>,
+    clubhouse_token: String,
+    clubhouse_group_name: String,
+    instant_process: Option<bool>,
+    include_closed_issues: Option<bool>,
+    id: Option<String>,
+) -> Result<T, LinearError> {
+    super::operations::issue_import_create_clubhouse::<T>(
+        team_id,
+        team_name,
+        clubhouse_token,
+        clubhouse_group_name,
+        instant_process,
+        include_closed_issues,
+        id,
+    )
+    .execute(client)
+    .await
+}
+/// Kicks off an Asana import job.
+///
+/// Full type: [`IssueImport`](super::types::IssueImport)
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub async fn issue_import_create_asana<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::IssueImport>,
+>(
+    client: &Client,
+    team_id: Option<String>,
+    team_name: Option<String>,
+    asana_token: String,
+    asana_team_name: String,
+    instant_process: Option<bool>,
+    include_closed_issues: Option<bool>,
+    id: Option<String>,
+) -> Result<T, LinearError> {
+    super::operations::issue_import_create_asana::<T>(
+        team_id,
+        team_name,
+        asana_token,
+        asana_team_name,
+        instant_process,
+        include_closed_issues,
+        id,
+    )
+    .execute(client)
+    .await
+}
+/// `INTERNAL` Kicks off a Linear to Linear import job.
+///
+/// Full type: [`IssueImport`](super::types::IssueImport)
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub async fn issue_import_create_linear_v2<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::IssueImport>,
+>(
+    client: &Client,
+    linear_source_organization_id: String,
+    id: Option<String>,
+) -> Result<T, LinearError> {
+    super::operations::issue_import_create_linear_v2::<T>(linear_source_organization_id, id)
+        .execute(client)
+        .await
+}
+/// Deletes an import job.
+///
+/// Full type: [`IssueImport`](super::types::IssueImport)
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub async fn issue_import_delete<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::IssueImport>,
+>(
+    client: &Client,
+    issue_import_id: String,
+) -> Result<T, LinearError> {
+    super::operations::issue_import_delete::<T>(issue_import_id)
+        .execute(client)
+        .await
+}
+/// Kicks off import processing.
+///
+/// Full type: [`IssueImport`](super::types::IssueImport)
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub async fn issue_import_process<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::IssueImport>,
+>(
+    client: &Client,
+    mapping: serde_json::Value,
+    issue_import_id: String,
+) -> Result<T, LinearError> {
+    super::operations::issue_import_process::<T>(mapping, issue_import_id)
+        .execute(client)
+        .await
+}
+/// Updates the mapping for the issue import.
+///
+/// Full type: [`IssueImport`](super::types::IssueImport)
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub async fn issue_import_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::IssueImport>,
+>(
+    client: &Client,
+    input: IssueImportUpdateInput,
+    id: impl Into<super::ids::IssueImportId>,
+) -> Result<T, LinearError> {
+    super::operations::issue_import_update::<T>(input, id)
+        .execute(client)
+        .await
+}
+/// Creates new Slack notification settings for a team, project, initiative, or custom view.
+///
+/// Full type: [`IntegrationsSettings`](super::types::IntegrationsSettings)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub async fn integrations_settings_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::IntegrationsSettings>,
+>(
+    client: &Client,
+    input: IntegrationsSettingsCreateInput,
+) -> Result<T, LinearError> {
+    super::operations::integrations_settings_create::<T>(input)
+        .execute(client)
+        .await
+}
+/// Updates Slack notification settings for a team, project, initiative, or custom view.
+///
+/// Full type: [`IntegrationsSettings`](super::types::IntegrationsSettings)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub async fn integrations_settings_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::IntegrationsSettings>,
+>(
+    client: &Client,
+    input: IntegrationsSettingsUpdateInput,
+    id: impl Into<super::ids::IntegrationsSettingsId>,
+) -> Result<T, LinearError> {
+    super::operations::integrations_settings_update::<T>(input, id)
+        .execute(client)
+        .await
+}
+/// Creates a new connection between a template and an integration, optionally scoped to a specific external resource such as a Slack channel.
+///
+/// Full type: [`IntegrationTemplate`](super::types::IntegrationTemplate)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub async fn integration_template_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::IntegrationTemplate>,
+>(
+    client: &Client,
+    input: IntegrationTemplateCreateInput,
+) -> Result<T, LinearError> {
+    super::operations::integration_template_create::<T>(input)
+        .execute(client)
+        .await
+}
+/// Deletes an integration template connection, removing the link between a template and an integration.
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub async fn integration_template_delete(
+    client: &Client,
+    id: impl Into<super::ids::IntegrationTemplateId>,
+) -> Result<super::types::DeletePayload, LinearError> {
+    super::operations::integration_template_delete(id)
+        .execute(client)
+        .await
+}
+/// `INTERNAL` Updates the integration.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub async fn integration_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    client: &Client,
+    input: IntegrationUpdateInput,
+    id: impl Into<super::ids::IntegrationId>,
+) -> Result<T, LinearError> {
+    super::operations::integration_update::<T>(input, id)
+        .execute(client)
+        .await
+}
+/// `INTERNAL` Updates the integration settings.
+#[deprecated(note = "Use integrationUpdate instead.")]
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub async fn integration_settings_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    client: &Client,
+    input: IntegrationSettingsInput,
+    id: impl Into<super::ids::IntegrationId>,
+) -> Result<T, LinearError> {
+    super::operations::integration_settings_update::<T>(input, id)
+        .execute(client)
+        .await
+}
+/// Generates a webhook for the GitHub commit integration.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub async fn integration_github_commit_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    client: &Client,
+) -> Result<T, LinearError> {
+    super::operations::integration_github_commit_create::<T>()
+        .execute(client)
+        .await
+}
+/// Connects the workspace with the GitHub App.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub async fn integration_github_connect<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    client: &Client,
+    github_host: Option<String>,
+    code_access: Option<bool>,
+    code: String,
+    installation_id: String,
+) -> Result<T, LinearError> {
+    super::operations::integration_github_connect::<T>(
+        github_host,
+        code_access,
+        code,
+        installation_id,
+    )
+    .execute(client)
+    .await
+}
+/// Connects the workspace with the GitHub Import App.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub async fn integration_github_import_connect<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    client: &Client,
+    code: String,
+    installation_id: String,
+) -> Result<T, LinearError> {
+    super::operations::integration_github_import_connect::<T>(code, installation_id)
+        .execute(client)
+        .await
+}
+/// Refreshes the data for a GitHub import integration.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub async fn integration_github_import_refresh<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    client: &Client,
+    id: impl Into<super::ids::IntegrationId>,
+) -> Result<T, LinearError> {
+    super::operations::integration_github_import_refresh::<T>(id)
+        .execute(client)
+        .await
+}
+/// Connects the workspace with a GitHub Enterprise Server.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub async fn integration_git_hub_enterprise_server_connect<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    client: &Client,
+    organization_name: String,
+    github_url: String,
+) -> Result<T, LinearError> {
+    super::operations::integration_git_hub_enterprise_server_connect::<T>(
+        organization_name,
+        github_url,
+    )
+    .execute(client)
+    .await
+}
+/// Connects the workspace with a GitLab Access Token.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub async fn integration_gitlab_connect<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    client: &Client,
+    gitlab_url: String,
+    access_token: String,
+) -> Result<T, LinearError> {
+    super::operations::integration_gitlab_connect::<T>(gitlab_url, access_token)
+        .execute(client)
+        .await
+}
+/// Tests connectivity to a self-hosted GitLab instance and clears auth errors if successful.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub async fn integration_gitlab_test_connection<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    client: &Client,
+    integration_id: String,
+) -> Result<T, LinearError> {
+    super::operations::integration_gitlab_test_connection::<T>(integration_id)
+        .execute(client)
+        .await
+}
+/// Creates an integration api key for Airbyte to connect with Linear.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub async fn airbyte_integration_connect<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    client: &Client,
+    input: AirbyteConfigurationInput,
+) -> Result<T, LinearError> {
+    super::operations::airbyte_integration_connect::<T>(input)
+        .execute(client)
+        .await
+}
+/// `Internal` Connects the Google Calendar to the user to this Linear account via OAuth2.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub async fn integration_google_calendar_personal_connect<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    client: &Client,
+    code: String,
+) -> Result<T, LinearError> {
+    super::operations::integration_google_calendar_personal_connect::<T>(code)
+        .execute(client)
+        .await
+}
+/// `INTERNAL` Integrates the workspace with LaunchDarkly.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub async fn integration_launch_darkly_connect<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    client: &Client,
+    code: String,
+    project_key: String,
+    environment: String,
+) -> Result<T, LinearError> {
+    super::operations::integration_launch_darkly_connect::<T>(code, project_key, environment)
+        .execute(client)
+        .await
+}
+/// `INTERNAL` Integrates your personal account with LaunchDarkly.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub async fn integration_launch_darkly_personal_connect<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    client: &Client,
+    code: String,
+) -> Result<T, LinearError> {
+    super::operations::integration_launch_darkly_personal_connect::<T>(code)
+        .execute(client)
+        .await
+}
+/// `INTERNAL` Connects the workspace with a Jira Personal Access Token.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub async fn jira_integration_connect<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    client: &Client,
+    input: JiraConfigurationInput,
+) -> Result<T, LinearError> {
+    super::operations::jira_integration_connect::<T>(input)
+        .execute(client)
+        .await
+}
+/// `INTERNAL` Updates a Jira Integration.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub async fn integration_jira_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    client: &Client,
+    input: JiraUpdateInput,
+) -> Result<T, LinearError> {
+    super::operations::integration_jira_update::<T>(input)
+        .execute(client)
+        .await
+}
+/// `INTERNAL` Fetches Jira project statuses and stores them in integration settings.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub async fn integration_jira_fetch_project_statuses<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    client: &Client,
+    input: JiraFetchProjectStatusesInput,
+) -> Result<T, LinearError> {
+    super::operations::integration_jira_fetch_project_statuses::<T>(input)
+        .execute(client)
+        .await
+}
+/// Connect your Jira account to Linear.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub async fn integration_jira_personal<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    client: &Client,
+    code: Option<String>,
+    access_token: Option<String>,
+) -> Result<T, LinearError> {
+    super::operations::integration_jira_personal::<T>(code, access_token)
+        .execute(client)
+        .await
+}
+/// Connect your GitHub account to Linear.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub async fn integration_git_hub_personal<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    client: &Client,
+    enterprise_url: Option<String>,
+    code_access: Option<bool>,
+    code: String,
+) -> Result<T, LinearError> {
+    super::operations::integration_git_hub_personal::<T>(enterprise_url, code_access, code)
+        .execute(client)
+        .await
+}
+/// Integrates the workspace with Intercom.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub async fn integration_intercom<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    client: &Client,
+    domain_url: Option<String>,
+    redirect_uri: String,
+    code: String,
+) -> Result<T, LinearError> {
+    super::operations::integration_intercom::<T>(domain_url, redirect_uri, code)
+        .execute(client)
+        .await
+}
+/// Disconnects the workspace from Intercom.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub async fn integration_intercom_delete<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    client: &Client,
+) -> Result<T, LinearError> {
+    super::operations::integration_intercom_delete::<T>()
+        .execute(client)
+        .await
+}
+/// `INTERNAL` Refreshes the customer data attributes from the specified integration service.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub async fn integration_customer_data_attributes_refresh<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    client: &Client,
+    input: IntegrationCustomerDataAttributesRefreshInput,
+) -> Result<T, LinearError> {
+    super::operations::integration_customer_data_attributes_refresh::<T>(input)
+        .execute(client)
+        .await
+}
+/// `DEPRECATED` Updates settings on the Intercom integration.
+#[deprecated(note = "This mutation is deprecated, please use `integrationSettingsUpdate` instead")]
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub async fn integration_intercom_settings_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    client: &Client,
+    input: IntercomSettingsInput,
+) -> Result<T, LinearError> {
+    super::operations::integration_intercom_settings_update::<T>(input)
+        .execute(client)
+        .await
+}
+/// Integrates the workspace with Discord.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub async fn integration_discord<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    client: &Client,
+    redirect_uri: String,
+    code: String,
+) -> Result<T, LinearError> {
+    super::operations::integration_discord::<T>(redirect_uri, code)
+        .execute(client)
+        .await
+}
+/// `INTERNAL` Integrates the workspace with Opsgenie.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub async fn integration_opsgenie_connect<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    client: &Client,
+    api_key: String,
+) -> Result<T, LinearError> {
+    super::operations::integration_opsgenie_connect::<T>(api_key)
+        .execute(client)
+        .await
+}
+/// `INTERNAL` Refresh Opsgenie schedule mappings.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub async fn integration_opsgenie_refresh_schedule_mappings<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    client: &Client,
+) -> Result<T, LinearError> {
+    super::operations::integration_opsgenie_refresh_schedule_mappings::<T>()
+        .execute(client)
+        .await
+}
+/// `INTERNAL` Integrates the workspace with PagerDuty.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub async fn integration_pager_duty_connect<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    client: &Client,
+    code: String,
+    redirect_uri: String,
+) -> Result<T, LinearError> {
+    super::operations::integration_pager_duty_connect::<T>(code, redirect_uri)
+        .execute(client)
+        .await
+}
+/// `INTERNAL` Refresh PagerDuty schedule mappings.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub async fn integration_pager_duty_refresh_schedule_mappings<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    client: &Client,
+) -> Result<T, LinearError> {
+    super::operations::integration_pager_duty_refresh_schedule_mappings::<T>()
+        .execute(client)
+        .await
+}
+/// `Internal` Updates existing Slack and Asks integration scopes.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub async fn update_integration_slack_scopes<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    client: &Client,
+    integration_id: String,
+    redirect_uri: String,
+    code: String,
+) -> Result<T, LinearError> {
+    super::operations::update_integration_slack_scopes::<T>(integration_id, redirect_uri, code)
+        .execute(client)
+        .await
+}
+/// Integrates the workspace with Slack.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub async fn integration_slack<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    client: &Client,
+    should_use_v2_auth: Option<bool>,
+    redirect_uri: String,
+    code: String,
+) -> Result<T, LinearError> {
+    super::operations::integration_slack::<T>(should_use_v2_auth, redirect_uri, code)
+        .execute(client)
+        .await
+}
+/// Integrates the workspace with the Slack Asks app.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub async fn integration_slack_asks<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    client: &Client,
+    redirect_uri: String,
+    code: String,
+) -> Result<T, LinearError> {
+    super::operations::integration_slack_asks::<T>(redirect_uri, code)
+        .execute(client)
+        .await
+}
+/// Updates the Slack team's name in Linear for an existing Slack or Asks integration.
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub async fn integration_slack_or_asks_update_slack_team_name(
+    client: &Client,
+    integration_id: String,
+) -> Result<super::types::IntegrationSlackWorkspaceNamePayload, LinearError> {
+    super::operations::integration_slack_or_asks_update_slack_team_name(integration_id)
+        .execute(client)
+        .await
+}
+/// Integrates your personal notifications with Slack.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub async fn integration_slack_personal<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    client: &Client,
+    redirect_uri: String,
+    code: String,
+) -> Result<T, LinearError> {
+    super::operations::integration_slack_personal::<T>(redirect_uri, code)
+        .execute(client)
+        .await
+}
+/// Connect a Slack channel to Asks.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub async fn integration_asks_connect_channel<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    client: &Client,
+    redirect_uri: String,
+    code: String,
+) -> Result<T, LinearError> {
+    super::operations::integration_asks_connect_channel::<T>(redirect_uri, code)
+        .execute(client)
+        .await
+}
+/// Slack integration for team notifications.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub async fn integration_slack_post<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    client: &Client,
+    should_use_v2_auth: Option<bool>,
+    redirect_uri: String,
+    team_id: String,
+    code: String,
+) -> Result<T, LinearError> {
+    super::operations::integration_slack_post::<T>(should_use_v2_auth, redirect_uri, team_id, code)
+        .execute(client)
+        .await
+}
+/// Slack integration for project notifications.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub async fn integration_slack_project_post<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    client: &Client,
+    service: String,
+    redirect_uri: String,
+    project_id: String,
+    code: String,
+) -> Result<T, LinearError> {
+    super::operations::integration_slack_project_post::<T>(service, redirect_uri, project_id, code)
+        .execute(client)
+        .await
+}
+/// `Internal` Slack integration for initiative notifications.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub async fn integration_slack_initiative_post<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    client: &Client,
+    redirect_uri: String,
+    initiative_id: String,
+    code: String,
+) -> Result<T, LinearError> {
+    super::operations::integration_slack_initiative_post::<T>(redirect_uri, initiative_id, code)
+        .execute(client)
+        .await
+}
+/// Slack integration for custom view notifications.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub async fn integration_slack_custom_view_notifications<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    client: &Client,
+    redirect_uri: String,
+    custom_view_id: String,
+    code: String,
+) -> Result<T, LinearError> {
+    super::operations::integration_slack_custom_view_notifications::<T>(
+        redirect_uri,
+        custom_view_id,
+        code,
+    )
+    .execute(client)
+    .await
+}
+/// Integrates a Slack Asks channel with a Customer.
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub async fn integration_slack_customer_channel_link(
+    client: &Client,
+    redirect_uri: String,
+    customer_id: String,
+    code: String,
+) -> Result<super::types::SuccessPayload, LinearError> {
+    super::operations::integration_slack_customer_channel_link(redirect_uri, customer_id, code)
+        .execute(client)
+        .await
+}
+/// Slack integration for workspace-level project update notifications.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub async fn integration_slack_org_project_updates_post<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    client: &Client,
+    redirect_uri: String,
+    code: String,
+) -> Result<T, LinearError> {
+    super::operations::integration_slack_org_project_updates_post::<T>(redirect_uri, code)
+        .execute(client)
+        .await
+}
+/// `Internal` Slack integration for workspace-level initiative update notifications.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub async fn integration_slack_org_initiative_updates_post<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    client: &Client,
+    redirect_uri: String,
+    code: String,
+) -> Result<T, LinearError> {
+    super::operations::integration_slack_org_initiative_updates_post::<T>(redirect_uri, code)
+        .execute(client)
+        .await
+}
+/// Imports custom emojis from your Slack workspace.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub async fn integration_slack_import_emojis<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    client: &Client,
+    redirect_uri: String,
+    code: String,
+) -> Result<T, LinearError> {
+    super::operations::integration_slack_import_emojis::<T>(redirect_uri, code)
+        .execute(client)
+        .await
+}
+/// Integrates the workspace with Figma.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub async fn integration_figma<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    client: &Client,
+    redirect_uri: String,
+    code: String,
+) -> Result<T, LinearError> {
+    super::operations::integration_figma::<T>(redirect_uri, code)
+        .execute(client)
+        .await
+}
+/// Integrates the workspace with Gong.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub async fn integration_gong<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    client: &Client,
+    redirect_uri: String,
+    code: String,
+) -> Result<T, LinearError> {
+    super::operations::integration_gong::<T>(redirect_uri, code)
+        .execute(client)
+        .await
+}
+/// Integrates the workspace with Microsoft Teams.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub async fn integration_microsoft_teams<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    client: &Client,
+    redirect_uri: String,
+    code: String,
+) -> Result<T, LinearError> {
+    super::operations::integration_microsoft_teams::<T>(redirect_uri, code)
+        .execute(client)
+        .await
+}
+/// Connects the user's personal Microsoft account to Linear.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub async fn integration_microsoft_personal_connect<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    client: &Client,
+    redirect_uri: String,
+    code: String,
+) -> Result<T, LinearError> {
+    super::operations::integration_microsoft_personal_connect::<T>(redirect_uri, code)
+        .execute(client)
+        .await
+}
+/// `Internal` Connect a project to a Microsoft Teams channel. Find-or-update semantics: creates a microsoftTeamsProjectPost integration row if none exists for the project, or overwrites the existing one's team/channel selection. Requires the connecting user to have linked their personal Microsoft account.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub async fn integration_microsoft_teams_project_post<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    client: &Client,
+    membership_type: String,
+    channel_name: String,
+    channel_id: String,
+    team_name: String,
+    team_id: String,
+    project_id: String,
+) -> Result<T, LinearError> {
+    super::operations::integration_microsoft_teams_project_post::<T>(
+        membership_type,
+        channel_name,
+        channel_id,
+        team_name,
+        team_id,
+        project_id,
+    )
+    .execute(client)
+    .await
+}
+/// Integrates the workspace with Google Sheets.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub async fn integration_google_sheets<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    client: &Client,
+    code: String,
+) -> Result<T, LinearError> {
+    super::operations::integration_google_sheets::<T>(code)
+        .execute(client)
+        .await
+}
+/// Manually update Google Sheets data.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub async fn refresh_google_sheets_data<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    client: &Client,
+    r#type: Option<String>,
+    id: impl Into<super::ids::IntegrationId>,
+) -> Result<T, LinearError> {
+    super::operations::refresh_google_sheets_data::<T>(r#type, id)
+        .execute(client)
+        .await
+}
+/// Integrates the workspace with Sentry.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub async fn integration_sentry_connect<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    client: &Client,
+    organization_slug: String,
+    code: String,
+    installation_id: String,
+) -> Result<T, LinearError> {
+    super::operations::integration_sentry_connect::<T>(organization_slug, code, installation_id)
+        .execute(client)
+        .await
+}
+/// Integrates the workspace with Front.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub async fn integration_front<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    client: &Client,
+    redirect_uri: String,
+    code: String,
+) -> Result<T, LinearError> {
+    super::operations::integration_front::<T>(redirect_uri, code)
+        .execute(client)
+        .await
+}
+/// Integrates the workspace with Zendesk.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub async fn integration_zendesk<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    client: &Client,
+    subdomain: String,
+    code: String,
+    scope: String,
+    redirect_uri: String,
+) -> Result<T, LinearError> {
+    super::operations::integration_zendesk::<T>(subdomain, code, scope, redirect_uri)
+        .execute(client)
+        .await
+}
+/// Enables Loom integration for the workspace.
+#[deprecated(note = "Not available.")]
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub async fn integration_loom<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    client: &Client,
+) -> Result<T, LinearError> {
+    super::operations::integration_loom::<T>()
+        .execute(client)
+        .await
+}
+/// Integrates the workspace with Salesforce.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub async fn integration_salesforce<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    client: &Client,
+    code: String,
+    subdomain: String,
+    redirect_uri: String,
+) -> Result<T, LinearError> {
+    super::operations::integration_salesforce::<T>(code, subdomain, redirect_uri)
+        .execute(client)
+        .await
+}
+/// `INTERNAL` Refreshes the Salesforce integration metadata.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub async fn integration_salesforce_metadata_refresh<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    client: &Client,
+    id: impl Into<super::ids::IntegrationId>,
+) -> Result<T, LinearError> {
+    super::operations::integration_salesforce_metadata_refresh::<T>(id)
+        .execute(client)
+        .await
+}
+/// `INTERNAL` Connects the user's personal account with an MCP server.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub async fn integration_mcp_server_personal_connect<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    client: &Client,
+    custom_headers: Option<Vec<McpServerCustomHeaderInput>>,
+    server_url: String,
+) -> Result<T, LinearError> {
+    super::operations::integration_mcp_server_personal_connect::<T>(custom_headers, server_url)
+        .execute(client)
+        .await
+}
+/// `INTERNAL` Connects the workspace with an MCP server.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub async fn integration_mcp_server_connect<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    client: &Client,
+    custom_headers: Option<Vec<McpServerCustomHeaderInput>>,
+    workflow_definition_id: Option<String>,
+    team_id: Option<String>,
+    server_url: String,
+) -> Result<T, LinearError> {
+    super::operations::integration_mcp_server_connect::<T>(
+        custom_headers,
+        workflow_definition_id,
+        team_id,
+        server_url,
+    )
+    .execute(client)
+    .await
+}
+/// Deletes an integration.
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub async fn integration_delete(
+    client: &Client,
+    skip_installation_deletion: Option<bool>,
+    id: impl Into<super::ids::IntegrationId>,
+) -> Result<super::types::DeletePayload, LinearError> {
+    super::operations::integration_delete(skip_installation_deletion, id)
+        .execute(client)
+        .await
+}
+/// Archives an integration.
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub async fn integration_archive(
+    client: &Client,
+    id: impl Into<super::ids::IntegrationId>,
+) -> Result<super::types::DeletePayload, LinearError> {
+    super::operations::integration_archive(id)
+        .execute(client)
+        .await
+}
+/// `Internal` Enables Linear Agent Slack workflow access for a Slack or Slack Asks integration.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub async fn integration_slack_workflow_access_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    client: &Client,
+    enabled: bool,
+    integration_id: String,
+) -> Result<T, LinearError> {
+    super::operations::integration_slack_workflow_access_update::<T>(enabled, integration_id)
+        .execute(client)
+        .await
+}
+/// Requests a currently unavailable integration.
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub async fn integration_request(
+    client: &Client,
+    input: IntegrationRequestInput,
+) -> Result<super::types::IntegrationRequestPayload, LinearError> {
+    super::operations::integration_request(input)
+        .execute(client)
+        .await
+}
+/// Creates an initiative update.
+///
+/// Full type: [`InitiativeUpdate`](super::types::InitiativeUpdate)
+///
+/// Requires the `initiatives` feature.
+#[cfg(feature = "initiatives")]
+pub async fn initiative_update_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::InitiativeUpdate>,
+>(
+    client: &Client,
+    input: InitiativeUpdateCreateInput,
+) -> Result<T, LinearError> {
+    super::operations::initiative_update_create::<T>(input)
+        .execute(client)
+        .await
+}
+/// Updates an initiative update.
+///
+/// Full type: [`InitiativeUpdate`](super::types::InitiativeUpdate)
+///
+/// Requires the `initiatives` feature.
+#[cfg(feature = "initiatives")]
+pub async fn initiative_update_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::InitiativeUpdate>,
+>(
+    client: &Client,
+    input: InitiativeUpdateUpdateInput,
+    id: impl Into<super::ids::InitiativeUpdateId>,
+) -> Result<T, LinearError> {
+    super::operations::initiative_update_update::<T>(input, id)
+        .execute(client)
+        .await
+}
+/// Archives an initiative update.
+///
+/// Full type: [`InitiativeUpdate`](super::types::InitiativeUpdate)
+///
+/// Requires the `initiatives` feature.
+#[cfg(feature = "initiatives")]
+pub async fn initiative_update_archive<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::InitiativeUpdate>,
+>(
+    client: &Client,
+    id: impl Into<super::ids::InitiativeUpdateId>,
+) -> Result<T, LinearError> {
+    super::operations::initiative_update_archive::<T>(id)
+        .execute(client)
+        .await
+}
+/// Create a notification to remind a user about an initiative update.
+///
+/// Requires the `initiatives` feature.
+#[cfg(feature = "initiatives")]
+pub async fn create_initiative_update_reminder(
+    client: &Client,
+    user_id: Option<String>,
+    initiative_id: String,
+) -> Result<super::types::InitiativeUpdateReminderPayload, LinearError> {
+    super::operations::create_initiative_update_reminder(user_id, initiative_id)
+        .execute(client)
+        .await
+}
+/// Unarchives an initiative update.
+///
+/// Full type: [`InitiativeUpdate`](super::types::InitiativeUpdate)
+///
+/// Requires the `initiatives` feature.
+#[cfg(feature = "initiatives")]
+pub async fn initiative_update_unarchive<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::InitiativeUpdate>,
+>(
+    client: &Client,
+    id: impl Into<super::ids::InitiativeUpdateId>,
+) -> Result<T, LinearError> {
+    super::operations::initiative_update_unarchive::<T>(id)
+        .execute(client)
+        .await
+}
+/// Associates a project with an initiative. A project can only appear once in an initiative hierarchy.
+///
+/// Full type: [`InitiativeToProject`](super::types::InitiativeToProject)
+///
+/// Requires the `initiatives` feature.
+#[cfg(feature = "initiatives")]
+pub async fn initiative_to_project_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::InitiativeToProject>,
+>(
+    client: &Client,
+    input: InitiativeToProjectCreateInput,
+) -> Result<T, LinearError> {
+    super::operations::initiative_to_project_create::<T>(input)
+        .execute(client)
+        .await
+}
+/// Updates an initiative-to-project association, such as its sort order.
+///
+/// Full type: [`InitiativeToProject`](super::types::InitiativeToProject)
+///
+/// Requires the `initiatives` feature.
+#[cfg(feature = "initiatives")]
+pub async fn initiative_to_project_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::InitiativeToProject>,
+>(
+    client: &Client,
+    input: InitiativeToProjectUpdateInput,
+    id: impl Into<super::ids::InitiativeToProjectId>,
+) -> Result<T, LinearError> {
+    super::operations::initiative_to_project_update::<T>(input, id)
+        .execute(client)
+        .await
+}
+/// Removes a project from an initiative.
+///
+/// Requires the `initiatives` feature.
+#[cfg(feature = "initiatives")]
+pub async fn initiative_to_project_delete(
+    client: &Client,
+    id: impl Into<super::ids::InitiativeToProjectId>,
+) -> Result<super::types::DeletePayload, LinearError> {
+    super::operations::initiative_to_project_delete(id)
+        .execute(client)
+        .await
+}
+/// Creates a new initiative.
+///
+/// Full type: [`Initiative`](super::types::Initiative)
+///
+/// Requires the `initiatives` feature.
+#[cfg(feature = "initiatives")]
+pub async fn initiative_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Initiative>,
+>(
+    client: &Client,
+    input: InitiativeCreateInput,
+) -> Result<T, LinearError> {
+    super::operations::initiative_create::<T>(input)
+        .execute(client)
+        .await
+}
+/// Updates an initiative.
+///
+/// Full type: [`Initiative`](super::types::Initiative)
+///
+/// Requires the `initiatives` feature.
+#[cfg(feature = "initiatives")]
+pub async fn initiative_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Initiative>,
+>(
+    client: &Client,
+    input: InitiativeUpdateInput,
+    id: impl Into<super::ids::InitiativeId>,
+) -> Result<T, LinearError> {
+    super::operations::initiative_update::<T>(input, id)
+        .execute(client)
+        .await
+}
+/// Archives an initiative.
+///
+/// Full type: [`Initiative`](super::types::Initiative)
+///
+/// Requires the `initiatives` feature.
+#[cfg(feature = "initiatives")]
+pub async fn initiative_archive<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Initiative>,
+>(
+    client: &Client,
+    id: impl Into<super::ids::InitiativeId>,
+) -> Result<T, LinearError> {
+    super::operations::initiative_archive::<T>(id)
+        .execute(client)
+        .await
+}
+/// Unarchives an initiative.
+///
+/// Full type: [`Initiative`](super::types::Initiative)
+///
+/// Requires the `initiatives` feature.
+#[cfg(feature = "initiatives")]
+pub async fn initiative_unarchive<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Initiative>,
+>(
+    client: &Client,
+    id: impl Into<super::ids::InitiativeId>,
+) -> Result<T, LinearError> {
+    super::operations::initiative_unarchive::<T>(id)
+        .execute(client)
+        .await
+}
+/// Deletes (trashes) an initiative.
+///
+/// Requires the `initiatives` feature.
+#[cfg(feature = "initiatives")]
+pub async fn initiative_delete(
+    client: &Client,
+    id: impl Into<super::ids::InitiativeId>,
+) -> Result<super::types::DeletePayload, LinearError> {
+    super::operations::initiative_delete(id)
+        .execute(client)
+        .await
+}
+/// Creates a new parent-child relation between two initiatives. The relation cannot create cycles or exceed maximum nesting depth.
+///
+/// Full type: [`InitiativeRelation`](super::types::InitiativeRelation)
+///
+/// Requires the `initiatives` feature.
+#[cfg(feature = "initiatives")]
+pub async fn initiative_relation_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::InitiativeRelation>,
+>(
+    client: &Client,
+    input: InitiativeRelationCreateInput,
+) -> Result<T, LinearError> {
+    super::operations::initiative_relation_create::<T>(input)
+        .execute(client)
+        .await
+}
+/// Updates an initiative relation.
+///
+/// Full type: [`InitiativeRelation`](super::types::InitiativeRelation)
+///
+/// Requires the `initiatives` feature.
+#[cfg(feature = "initiatives")]
+pub async fn initiative_relation_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::InitiativeRelation>,
+>(
+    client: &Client,
+    input: InitiativeRelationUpdateInput,
+    id: impl Into<super::ids::InitiativeRelationId>,
+) -> Result<T, LinearError> {
+    super::operations::initiative_relation_update::<T>(input, id)
+        .execute(client)
+        .await
+}
+/// Deletes an initiative relation.
+///
+/// Requires the `initiatives` feature.
+#[cfg(feature = "initiatives")]
+pub async fn initiative_relation_delete(
+    client: &Client,
+    id: impl Into<super::ids::InitiativeRelationId>,
+) -> Result<super::types::DeletePayload, LinearError> {
+    super::operations::initiative_relation_delete(id)
+        .execute(client)
+        .await
+}
+/// Creates a new Git target branch definition that scopes automation rules to pull requests targeting a specific branch pattern.
+///
+/// Full type: [`GitAutomationTargetBranch`](super::types::GitAutomationTargetBranch)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub async fn git_automation_target_branch_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::GitAutomationTargetBranch>,
+>(
+    client: &Client,
+    input: GitAutomationTargetBranchCreateInput,
+) -> Result<T, LinearError> {
+    super::operations::git_automation_target_branch_create::<T>(input)
+        .execute(client)
+        .await
+}
+/// Updates an existing Git target branch definition, including its branch pattern and regex flag.
+///
+/// Full type: [`GitAutomationTargetBranch`](super::types::GitAutomationTargetBranch)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub async fn git_automation_target_branch_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::GitAutomationTargetBranch>,
+>(
+    client: &Client,
+    input: GitAutomationTargetBranchUpdateInput,
+    id: impl Into<super::ids::GitAutomationTargetBranchId>,
+) -> Result<T, LinearError> {
+    super::operations::git_automation_target_branch_update::<T>(input, id)
+        .execute(client)
+        .await
+}
+/// Deletes a Git target branch definition and its associated automation rules.
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub async fn git_automation_target_branch_delete(
+    client: &Client,
+    id: impl Into<super::ids::GitAutomationTargetBranchId>,
+) -> Result<super::types::DeletePayload, LinearError> {
+    super::operations::git_automation_target_branch_delete(id)
+        .execute(client)
+        .await
+}
+/// Creates a new Git automation rule that maps a Git event to a workflow state transition for a team.
+///
+/// Full type: [`GitAutomationState`](super::types::GitAutomationState)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub async fn git_automation_state_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::GitAutomationState>,
+>(
+    client: &Client,
+    input: GitAutomationStateCreateInput,
+) -> Result<T, LinearError> {
+    super::operations::git_automation_state_create::<T>(input)
+        .execute(client)
+        .await
+}
+/// Updates an existing Git automation rule, including its workflow state, target branch, and triggering event.
+///
+/// Full type: [`GitAutomationState`](super::types::GitAutomationState)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub async fn git_automation_state_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::GitAutomationState>,
+>(
+    client: &Client,
+    input: GitAutomationStateUpdateInput,
+    id: impl Into<super::ids::GitAutomationStateId>,
+) -> Result<T, LinearError> {
+    super::operations::git_automation_state_update::<T>(input, id)
+        .execute(client)
+        .await
+}
+/// Deletes a Git automation rule.
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub async fn git_automation_state_delete(
+    client: &Client,
+    id: impl Into<super::ids::GitAutomationStateId>,
+) -> Result<super::types::DeletePayload, LinearError> {
+    super::operations::git_automation_state_delete(id)
+        .execute(client)
+        .await
+}
+/// Creates a new favorite for the authenticated user. Exactly one target entity must be specified. If a favorite for the same entity already exists, the existing favorite is returned (upsert behavior).
+///
+/// Full type: [`Favorite`](super::types::Favorite)
+///
+/// Requires the `views` feature.
+#[cfg(feature = "views")]
+pub async fn favorite_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Favorite>,
+>(
+    client: &Client,
+    input: FavoriteCreateInput,
+) -> Result<T, LinearError> {
+    super::operations::favorite_create::<T>(input)
+        .execute(client)
+        .await
+}
+/// Updates a favorite's position, parent folder, or folder name.
+///
+/// Full type: [`Favorite`](super::types::Favorite)
+///
+/// Requires the `views` feature.
+#[cfg(feature = "views")]
+pub async fn favorite_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Favorite>,
+>(
+    client: &Client,
+    input: FavoriteUpdateInput,
+    id: impl Into<super::ids::FavoriteId>,
+) -> Result<T, LinearError> {
+    super::operations::favorite_update::<T>(input, id)
+        .execute(client)
+        .await
+}
+/// Deletes a favorite, removing it from the user's sidebar. This is an idempotent operation -- deleting a non-existent favorite succeeds silently.
+///
+/// Requires the `views` feature.
+#[cfg(feature = "views")]
+pub async fn favorite_delete(
+    client: &Client,
+    id: impl Into<super::ids::FavoriteId>,
+) -> Result<super::types::DeletePayload, LinearError> {
+    super::operations::favorite_delete(id).execute(client).await
+}
+/// Track an anonymous analytics event.
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub async fn track_anonymous_event(
+    client: &Client,
+    input: EventTrackingInput,
+) -> Result<super::types::EventTrackingPayload, LinearError> {
+    super::operations::track_anonymous_event(input)
+        .execute(client)
+        .await
+}
+/// Creates a new external link on an initiative, project, team, release, or cycle.
+///
+/// Full type: [`EntityExternalLink`](super::types::EntityExternalLink)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub async fn entity_external_link_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::EntityExternalLink>,
+>(
+    client: &Client,
+    input: EntityExternalLinkCreateInput,
+) -> Result<T, LinearError> {
+    super::operations::entity_external_link_create::<T>(input)
+        .execute(client)
+        .await
+}
+/// Updates an existing entity external link's URL, label, or sort order.
+///
+/// Full type: [`EntityExternalLink`](super::types::EntityExternalLink)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub async fn entity_external_link_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::EntityExternalLink>,
+>(
+    client: &Client,
+    input: EntityExternalLinkUpdateInput,
+    id: impl Into<super::ids::EntityExternalLinkId>,
+) -> Result<T, LinearError> {
+    super::operations::entity_external_link_update::<T>(input, id)
+        .execute(client)
+        .await
+}
+/// Deletes an entity external link.
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub async fn entity_external_link_delete(
+    client: &Client,
+    id: impl Into<super::ids::EntityExternalLinkId>,
+) -> Result<super::types::DeletePayload, LinearError> {
+    super::operations::entity_external_link_delete(id)
+        .execute(client)
+        .await
+}
+/// Creates a custom emoji.
+///
+/// Full type: [`Emoji`](super::types::Emoji)
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub async fn emoji_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Emoji>,
+>(
+    client: &Client,
+    input: EmojiCreateInput,
+) -> Result<T, LinearError> {
+    super::operations::emoji_create::<T>(input)
+        .execute(client)
+        .await
+}
+/// Deletes an emoji.
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub async fn emoji_delete(
+    client: &Client,
+    id: impl Into<super::ids::EmojiId>,
+) -> Result<super::types::DeletePayload, LinearError> {
+    super::operations::emoji_delete(id).execute(client).await
+}
+/// Unsubscribes the user from one type of email.
+///
+/// Requires the `notifications` feature.
+#[cfg(feature = "notifications")]
+pub async fn email_unsubscribe(
+    client: &Client,
+    input: EmailUnsubscribeInput,
+) -> Result<super::types::EmailUnsubscribePayload, LinearError> {
+    super::operations::email_unsubscribe(input)
+        .execute(client)
+        .await
+}
+/// Creates a new email intake address.
+///
+/// Full type: [`EmailIntakeAddress`](super::types::EmailIntakeAddress)
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+pub async fn email_intake_address_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::EmailIntakeAddress>,
+>(
+    client: &Client,
+    input: EmailIntakeAddressCreateInput,
+) -> Result<T, LinearError> {
+    super::operations::email_intake_address_create::<T>(input)
+        .execute(client)
+        .await
+}
+/// Rotates an existing email intake address.
+///
+/// Full type: [`EmailIntakeAddress`](super::types::EmailIntakeAddress)
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+pub async fn email_intake_address_rotate<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::EmailIntakeAddress>,
+>(
+    client: &Client,
+    id: impl Into<super::ids::EmailIntakeAddressId>,
+) -> Result<T, LinearError> {
+    super::operations::email_intake_address_rotate::<T>(id)
+        .execute(client)
+        .await
+}
+/// Updates an existing email intake address.
+///
+/// Full type: [`EmailIntakeAddress`](super::types::EmailIntakeAddress)
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+pub async fn email_intake_address_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::EmailIntakeAddress>,
+>(
+    client: &Client,
+    input: EmailIntakeAddressUpdateInput,
+    id: impl Into<super::ids::EmailIntakeAddressId>,
+) -> Result<T, LinearError> {
+    super::operations::email_intake_address_update::<T>(input, id)
+        .execute(client)
+        .await
+}
+/// Deletes an email intake address object.
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+pub async fn email_intake_address_delete(
+    client: &Client,
+    id: impl Into<super::ids::EmailIntakeAddressId>,
+) -> Result<super::types::DeletePayload, LinearError> {
+    super::operations::email_intake_address_delete(id)
+        .execute(client)
+        .await
+}
+/// Creates a new document.
+///
+/// Full type: [`Document`](super::types::Document)
+pub async fn document_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Document>,
+>(
+    client: &Client,
+    input: DocumentCreateInput,
+) -> Result<T, LinearError> {
+    super::operations::document_create::<T>(input)
+        .execute(client)
+        .await
+}
+/// Updates a document.
+///
+/// Full type: [`Document`](super::types::Document)
+pub async fn document_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Document>,
+>(
+    client: &Client,
+    input: DocumentUpdateInput,
+    id: impl Into<super::ids::DocumentId>,
+) -> Result<T, LinearError> {
+    super::operations::document_update::<T>(input, id)
+        .execute(client)
+        .await
+}
+/// Deletes (trashes) a document. The document is marked as trashed and archived, but not permanently removed.
+///
+/// Full type: [`Document`](super::types::Document)
+pub async fn document_delete<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Document>,
+>(
+    client: &Client,
+    id: impl Into<super::ids::DocumentId>,
+) -> Result<T, LinearError> {
+    super::operations::document_delete::<T>(id)
+        .execute(client)
+        .await
+}
+/// Restores a previously trashed document by unarchiving it.
+///
+/// Full type: [`Document`](super::types::Document)
+///
+/// Requires the `documents` feature.
+#[cfg(feature = "documents")]
+pub async fn document_unarchive<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Document>,
+>(
+    client: &Client,
+    id: impl Into<super::ids::DocumentId>,
+) -> Result<T, LinearError> {
+    super::operations::document_unarchive::<T>(id)
+        .execute(client)
+        .await
+}
+/// Creates a new cycle.
+///
+/// Full type: [`Cycle`](super::types::Cycle)
+///
+/// Requires the `cycles` feature.
+#[cfg(feature = "cycles")]
+pub async fn cycle_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Cycle>,
+>(
+    client: &Client,
+    input: CycleCreateInput,
+) -> Result<T, LinearError> {
+    super::operations::cycle_create::<T>(input)
+        .execute(client)
+        .await
+}
+/// Updates a cycle.
+///
+/// Full type: [`Cycle`](super::types::Cycle)
+///
+/// Requires the `cycles` feature.
+#[cfg(feature = "cycles")]
+pub async fn cycle_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Cycle>,
+>(
+    client: &Client,
+    input: CycleUpdateInput,
+    id: impl Into<super::ids::CycleId>,
+) -> Result<T, LinearError> {
+    super::operations::cycle_update::<T>(input, id)
+        .execute(client)
+        .await
+}
+/// Archives a cycle. All issues currently assigned to the cycle are unlinked from it before archiving.
+///
+/// Full type: [`Cycle`](super::types::Cycle)
+///
+/// Requires the `cycles` feature.
+#[cfg(feature = "cycles")]
+pub async fn cycle_archive<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Cycle>,
+>(
+    client: &Client,
+    id: impl Into<super::ids::CycleId>,
+) -> Result<T, LinearError> {
+    super::operations::cycle_archive::<T>(id)
+        .execute(client)
+        .await
+}
+/// Shifts all cycles starts and ends by a certain number of days, starting from the provided cycle onwards.
+///
+/// Full type: [`Cycle`](super::types::Cycle)
+///
+/// Requires the `cycles` feature.
+#[cfg(feature = "cycles")]
+pub async fn cycle_shift_all<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Cycle>,
+>(
+    client: &Client,
+    input: CycleShiftAllInput,
+) -> Result<T, LinearError> {
+    super::operations::cycle_shift_all::<T>(input)
+        .execute(client)
+        .await
+}
+/// Starts the upcoming cycle as of midnight today. Completes the previous cycle if it has not yet ended. Only the next upcoming (not yet started) cycle for the team can be started.
+///
+/// Full type: [`Cycle`](super::types::Cycle)
+///
+/// Requires the `cycles` feature.
+#[cfg(feature = "cycles")]
+pub async fn cycle_start_upcoming_cycle_today<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Cycle>,
+>(
+    client: &Client,
+    id: impl Into<super::ids::CycleId>,
+) -> Result<T, LinearError> {
+    super::operations::cycle_start_upcoming_cycle_today::<T>(id)
+        .execute(client)
+        .await
+}
+/// Creates a new customer tier.
+///
+/// Full type: [`CustomerTier`](super::types::CustomerTier)
+///
+/// Requires the `customers` feature.
+#[cfg(feature = "customers")]
+pub async fn customer_tier_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::CustomerTier>,
+>(
+    client: &Client,
+    input: CustomerTierCreateInput,
+) -> Result<T, LinearError> {
+    super::operations::customer_tier_create::<T>(input)
+        .execute(client)
+        .await
+}
+/// Updates a customer tier.
+///
+/// Full type: [`CustomerTier`](super::types::CustomerTier)
+///
+/// Requires the `customers` feature.
+#[cfg(feature = "customers")]
+pub async fn customer_tier_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::CustomerTier>,
+>(
+    client: &Client,
+    input: CustomerTierUpdateInput,
+    id: impl Into<super::ids::CustomerTierId>,
+) -> Result<T, LinearError> {
+    super::operations::customer_tier_update::<T>(input, id)
+        .execute(client)
+        .await
+}
+/// Deletes a customer tier. The tier must not be in use by any customers.
+///
+/// Requires the `customers` feature.
+#[cfg(feature = "customers")]
+pub async fn customer_tier_delete(
+    client: &Client,
+    id: impl Into<super::ids::CustomerTierId>,
+) -> Result<super::types::DeletePayload, LinearError> {
+    super::operations::customer_tier_delete(id)
+        .execute(client)
+        .await
+}
+/// Creates a new customer status.
+///
+/// Full type: [`CustomerStatus`](super::types::CustomerStatus)
+///
+/// Requires the `customers` feature.
+#[cfg(feature = "customers")]
+pub async fn customer_status_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::CustomerStatus>,
+>(
+    client: &Client,
+    input: CustomerStatusCreateInput,
+) -> Result<T, LinearError> {
+    super::operations::customer_status_create::<T>(input)
+        .execute(client)
+        .await
+}
+/// Updates a customer status.
+///
+/// Full type: [`CustomerStatus`](super::types::CustomerStatus)
+///
+/// Requires the `customers` feature.
+#[cfg(feature = "customers")]
+pub async fn customer_status_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::CustomerStatus>,
+>(
+    client: &Client,
+    input: CustomerStatusUpdateInput,
+    id: impl Into<super::ids::CustomerStatusId>,
+) -> Result<T, LinearError> {
+    super::operations::customer_status_update::<T>(input, id)
+        .execute(client)
+        .await
+}
+/// Deletes a customer status. Cannot delete the last remaining status in a workspace, and the status must not be in use by any customers.
+///
+/// Requires the `customers` feature.
+#[cfg(feature = "customers")]
+pub async fn customer_status_delete(
+    client: &Client,
+    id: impl Into<super::ids::CustomerStatusId>,
+) -> Result<super::types::DeletePayload, LinearError> {
+    super::operations::customer_status_delete(id)
+        .execute(client)
+        .await
+}
+/// Creates a new customer.
+///
+/// Full type: [`Customer`](super::types::Customer)
+///
+/// Requires the `customers` feature.
+#[cfg(feature = "customers")]
+pub async fn customer_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Customer>,
+>(
+    client: &Client,
+    input: CustomerCreateInput,
+) -> Result<T, LinearError> {
+    super::operations::customer_create::<T>(input)
+        .execute(client)
+        .await
+}
+/// Updates an existing customer.
+///
+/// Full type: [`Customer`](super::types::Customer)
+///
+/// Requires the `customers` feature.
+#[cfg(feature = "customers")]
+pub async fn customer_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Customer>,
+>(
+    client: &Client,
+    input: CustomerUpdateInput,
+    id: impl Into<super::ids::CustomerId>,
+) -> Result<T, LinearError> {
+    super::operations::customer_update::<T>(input, id)
+        .execute(client)
+        .await
+}
+/// Deletes a customer.
+///
+/// Requires the `customers` feature.
+#[cfg(feature = "customers")]
+pub async fn customer_delete(
+    client: &Client,
+    id: impl Into<super::ids::CustomerId>,
+) -> Result<super::types::DeletePayload, LinearError> {
+    super::operations::customer_delete(id).execute(client).await
+}
+/// Merges two customers by transferring all needs from the source customer to the target customer. The source customer is archived after the merge. Domains, external IDs, and metadata are combined on the target customer.
+///
+/// Full type: [`Customer`](super::types::Customer)
+///
+/// Requires the `customers` feature.
+#[cfg(feature = "customers")]
+pub async fn customer_merge<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Customer>,
+>(
+    client: &Client,
+    source_customer_id: String,
+    target_customer_id: String,
+) -> Result<T, LinearError> {
+    super::operations::customer_merge::<T>(source_customer_id, target_customer_id)
+        .execute(client)
+        .await
+}
+/// Upserts a customer, creating it if no match is found, or updating it otherwise. Matches against existing customers using `id`, `externalId`, `slackChannelId`, or `domains`.
+///
+/// Full type: [`Customer`](super::types::Customer)
+///
+/// Requires the `customers` feature.
+#[cfg(feature = "customers")]
+pub async fn customer_upsert<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Customer>,
+>(
+    client: &Client,
+    input: CustomerUpsertInput,
+) -> Result<T, LinearError> {
+    super::operations::customer_upsert::<T>(input)
+        .execute(client)
+        .await
+}
+/// Unsyncs a managed customer from its current data source integration. External IDs mapping to the external source will be cleared, and the customer will no longer be updated by the integration.
+///
+/// Full type: [`Customer`](super::types::Customer)
+///
+/// Requires the `customers` feature.
+#[cfg(feature = "customers")]
+pub async fn customer_unsync<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Customer>,
+>(
+    client: &Client,
+    id: impl Into<super::ids::CustomerId>,
+) -> Result<T, LinearError> {
+    super::operations::customer_unsync::<T>(id)
+        .execute(client)
+        .await
+}
+/// Creates a new customer need.
+///
+/// Full type: [`CustomerNeed`](super::types::CustomerNeed)
+///
+/// Requires the `customers` feature.
+#[cfg(feature = "customers")]
+pub async fn customer_need_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::CustomerNeed>,
+>(
+    client: &Client,
+    input: CustomerNeedCreateInput,
+) -> Result<T, LinearError> {
+    super::operations::customer_need_create::<T>(input)
+        .execute(client)
+        .await
+}
+/// Creates a new customer need from an existing issue attachment. If the attachment already has an archived need, it will be unarchived instead of creating a duplicate.
+///
+/// Full type: [`CustomerNeed`](super::types::CustomerNeed)
+///
+/// Requires the `customers` feature.
+#[cfg(feature = "customers")]
+pub async fn customer_need_create_from_attachment<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::CustomerNeed>,
+>(
+    client: &Client,
+    input: CustomerNeedCreateFromAttachmentInput,
+) -> Result<T, LinearError> {
+    super::operations::customer_need_create_from_attachment::<T>(input)
+        .execute(client)
+        .await
+}
+/// Updates an existing customer need. Supports moving the need to a different issue or project, changing priority, updating the body content, and managing the attached source URL.
+///
+/// Full type: [`CustomerNeed`](super::types::CustomerNeed)
+///
+/// Requires the `customers` feature.
+#[cfg(feature = "customers")]
+pub async fn customer_need_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::CustomerNeed>,
+>(
+    client: &Client,
+    clear_attachment: Option<bool>,
+    input: CustomerNeedUpdateInput,
+    id: impl Into<super::ids::CustomerNeedId>,
+) -> Result<T, LinearError> {
+    super::operations::customer_need_update::<T>(clear_attachment, input, id)
+        .execute(client)
+        .await
+}
+/// Deletes a customer need.
+///
+/// Requires the `customers` feature.
+#[cfg(feature = "customers")]
+pub async fn customer_need_delete(
+    client: &Client,
+    keep_attachment: Option<bool>,
+    id: impl Into<super::ids::CustomerNeedId>,
+) -> Result<super::types::DeletePayload, LinearError> {
+    super::operations::customer_need_delete(keep_attachment, id)
+        .execute(client)
+        .await
+}
+/// Archives a customer need.
+///
+/// Full type: [`CustomerNeed`](super::types::CustomerNeed)
+///
+/// Requires the `customers` feature.
+#[cfg(feature = "customers")]
+pub async fn customer_need_archive<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::CustomerNeed>,
+>(
+    client: &Client,
+    id: impl Into<super::ids::CustomerNeedId>,
+) -> Result<T, LinearError> {
+    super::operations::customer_need_archive::<T>(id)
+        .execute(client)
+        .await
+}
+/// Unarchives a customer need.
+///
+/// Full type: [`CustomerNeed`](super::types::CustomerNeed)
+///
+/// Requires the `customers` feature.
+#[cfg(feature = "customers")]
+pub async fn customer_need_unarchive<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::CustomerNeed>,
+>(
+    client: &Client,
+    id: impl Into<super::ids::CustomerNeedId>,
+) -> Result<T, LinearError> {
+    super::operations::customer_need_unarchive::<T>(id)
+        .execute(client)
+        .await
+}
+/// Creates a new custom view.
+///
+/// Full type: [`CustomView`](super::types::CustomView)
+///
+/// Requires the `views` feature.
+#[cfg(feature = "views")]
+pub async fn custom_view_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::CustomView>,
+>(
+    client: &Client,
+    input: CustomViewCreateInput,
+) -> Result<T, LinearError> {
+    super::operations::custom_view_create::<T>(input)
+        .execute(client)
+        .await
+}
+/// Updates a custom view.
+///
+/// Full type: [`CustomView`](super::types::CustomView)
+///
+/// Requires the `views` feature.
+#[cfg(feature = "views")]
+pub async fn custom_view_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::CustomView>,
+>(
+    client: &Client,
+    input: CustomViewUpdateInput,
+    id: impl Into<super::ids::CustomViewId>,
+) -> Result<T, LinearError> {
+    super::operations::custom_view_update::<T>(input, id)
+        .execute(client)
+        .await
+}
+/// Deletes a custom view.
+///
+/// Requires the `views` feature.
+#[cfg(feature = "views")]
+pub async fn custom_view_delete(
+    client: &Client,
+    id: impl Into<super::ids::CustomViewId>,
+) -> Result<super::types::DeletePayload, LinearError> {
+    super::operations::custom_view_delete(id)
+        .execute(client)
+        .await
+}
+/// Creates a support contact message from an authenticated user. The message is saved and forwarded to Intercom for support handling.
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub async fn contact_create(
+    client: &Client,
+    input: ContactCreateInput,
+) -> Result<super::types::ContactPayload, LinearError> {
+    super::operations::contact_create(input)
+        .execute(client)
+        .await
+}
+/// `INTERNAL` Submits a sales pricing inquiry from the website. Small companies (1-19 employees) are routed to Intercom, while larger companies are routed to HubSpot.
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub async fn contact_sales_create(
+    client: &Client,
+    input: ContactSalesCreateInput,
+) -> Result<super::types::ContactPayload, LinearError> {
+    super::operations::contact_sales_create(input)
+        .execute(client)
+        .await
+}
+/// Creates a new comment.
+///
+/// Full type: [`Comment`](super::types::Comment)
+pub async fn comment_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Comment>,
+>(
+    client: &Client,
+    input: CommentCreateInput,
+) -> Result<T, LinearError> {
+    super::operations::comment_create::<T>(input)
+        .execute(client)
+        .await
+}
+/// Updates a comment.
+///
+/// Full type: [`Comment`](super::types::Comment)
+pub async fn comment_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Comment>,
+>(
+    client: &Client,
+    skip_edited_at: Option<bool>,
+    input: CommentUpdateInput,
+    id: impl Into<super::ids::CommentId>,
+) -> Result<T, LinearError> {
+    super::operations::comment_update::<T>(skip_edited_at, input, id)
+        .execute(client)
+        .await
+}
+/// Deletes a comment.
+pub async fn comment_delete(
+    client: &Client,
+    id: impl Into<super::ids::CommentId>,
+) -> Result<super::types::DeletePayload, LinearError> {
+    super::operations::comment_delete(id).execute(client).await
+}
+/// Resolves a comment thread. Marks the root comment as resolved by the current user.
+///
+/// Full type: [`Comment`](super::types::Comment)
+pub async fn comment_resolve<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Comment>,
+>(
+    client: &Client,
+    resolving_comment_id: Option<String>,
+    id: impl Into<super::ids::CommentId>,
+) -> Result<T, LinearError> {
+    super::operations::comment_resolve::<T>(resolving_comment_id, id)
+        .execute(client)
+        .await
+}
+/// Unresolves a previously resolved comment thread. Clears the resolved state on the root comment.
+///
+/// Full type: [`Comment`](super::types::Comment)
+pub async fn comment_unresolve<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Comment>,
+>(
+    client: &Client,
+    id: impl Into<super::ids::CommentId>,
+) -> Result<T, LinearError> {
+    super::operations::comment_unresolve::<T>(id)
+        .execute(client)
+        .await
+}
+/// Finds or creates a new user account by email and sends an email with token.
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub async fn email_user_account_auth_challenge(
+    client: &Client,
+    input: EmailUserAccountAuthChallengeInput,
+) -> Result<super::types::EmailUserAccountAuthChallengeResponse, LinearError> {
+    super::operations::email_user_account_auth_challenge(input)
+        .execute(client)
+        .await
+}
+/// Authenticates a user account via email and authentication token.
+///
+/// Full type: [`AuthUser`](super::types::AuthUser)
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub async fn email_token_user_account_auth<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::AuthUser>,
+>(
+    client: &Client,
+    input: TokenUserAccountAuthInput,
+) -> Result<Vec<T>, LinearError> {
+    super::operations::email_token_user_account_auth::<T>(input)
+        .execute(client)
+        .await
+}
+/// Authenticates a user account via email and authentication token for SAML.
+///
+/// Full type: [`AuthUser`](super::types::AuthUser)
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub async fn saml_token_user_account_auth<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::AuthUser>,
+>(
+    client: &Client,
+    input: TokenUserAccountAuthInput,
+) -> Result<Vec<T>, LinearError> {
+    super::operations::saml_token_user_account_auth::<T>(input)
+        .execute(client)
+        .await
+}
+/// Authenticate user account through Google OAuth. This is the 2nd step of OAuth flow.
+///
+/// Full type: [`AuthUser`](super::types::AuthUser)
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub async fn google_user_account_auth<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::AuthUser>,
+>(
+    client: &Client,
+    input: GoogleUserAccountAuthInput,
+) -> Result<Vec<T>, LinearError> {
+    super::operations::google_user_account_auth::<T>(input)
+        .execute(client)
+        .await
+}
+/// `INTERNAL` Starts passkey login process.
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub async fn passkey_login_start(
+    client: &Client,
+    auth_id: String,
+) -> Result<super::types::PasskeyLoginStartResponse, LinearError> {
+    super::operations::passkey_login_start(auth_id)
+        .execute(client)
+        .await
+}
+/// `INTERNAL` Finish passkey login process.
+///
+/// Full type: [`AuthUser`](super::types::AuthUser)
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub async fn passkey_login_finish<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::AuthUser>,
+>(
+    client: &Client,
+    response: serde_json::Value,
+    auth_id: String,
+) -> Result<Vec<T>, LinearError> {
+    super::operations::passkey_login_finish::<T>(response, auth_id)
+        .execute(client)
+        .await
+}
+/// Creates a new workspace from onboarding.
+///
+/// Full type: [`AuthOrganization`](super::types::AuthOrganization)
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub async fn create_organization_from_onboarding<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::AuthOrganization>,
+>(
+    client: &Client,
+    session_id: Option<String>,
+    survey: Option<OnboardingCustomerSurvey>,
+    input: CreateOrganizationInput,
+) -> Result<T, LinearError> {
+    super::operations::create_organization_from_onboarding::<T>(session_id, survey, input)
+        .execute(client)
+        .await
+}
+/// Join a workspace from onboarding.
+///
+/// Full type: [`AuthOrganization`](super::types::AuthOrganization)
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub async fn join_organization_from_onboarding<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::AuthOrganization>,
+>(
+    client: &Client,
+    input: JoinOrganizationInput,
+) -> Result<T, LinearError> {
+    super::operations::join_organization_from_onboarding::<T>(input)
+        .execute(client)
+        .await
+}
+/// Leave a workspace.
+///
+/// Full type: [`AuthOrganization`](super::types::AuthOrganization)
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub async fn leave_organization<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::AuthOrganization>,
+>(
+    client: &Client,
+    organization_id: String,
+) -> Result<T, LinearError> {
+    super::operations::leave_organization::<T>(organization_id)
+        .execute(client)
+        .await
+}
+/// Logout the client.
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub async fn logout(
+    client: &Client,
+    reason: Option<String>,
+) -> Result<super::types::LogoutResponse, LinearError> {
+    super::operations::logout(reason).execute(client).await
+}
+/// Logout an individual session with its ID.
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub async fn logout_session(
+    client: &Client,
+    session_id: String,
+) -> Result<super::types::LogoutResponse, LinearError> {
+    super::operations::logout_session(session_id)
+        .execute(client)
+        .await
+}
+/// Logout all of user's sessions including the active one.
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub async fn logout_all_sessions(
+    client: &Client,
+    reason: Option<String>,
+) -> Result<super::types::LogoutResponse, LinearError> {
+    super::operations::logout_all_sessions(reason)
+        .execute(client)
+        .await
+}
+/// Logout all of user's sessions excluding the current one.
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub async fn logout_other_sessions(
+    client: &Client,
+    reason: Option<String>,
+) -> Result<super::types::LogoutResponse, LinearError> {
+    super::operations::logout_other_sessions(reason)
+        .execute(client)
+        .await
+}
+/// Creates a new attachment, or updates existing if the same `url` and `issueId` is used. To create an integration-aware attachment, use the integration-specific mutations such as `attachmentLinkZendesk`, `attachmentLinkSlack`, or `attachmentLinkURL` instead.
+///
+/// Full type: [`Attachment`](super::types::Attachment)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub async fn attachment_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Attachment>,
+>(
+    client: &Client,
+    input: AttachmentCreateInput,
+) -> Result<T, LinearError> {
+    super::operations::attachment_create::<T>(input)
+        .execute(client)
+        .await
+}
+/// Updates an existing issue attachment.
+///
+/// Full type: [`Attachment`](super::types::Attachment)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub async fn attachment_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Attachment>,
+>(
+    client: &Client,
+    input: AttachmentUpdateInput,
+    id: impl Into<super::ids::AttachmentId>,
+) -> Result<T, LinearError> {
+    super::operations::attachment_update::<T>(input, id)
+        .execute(client)
+        .await
+}
+/// Link any URL to an issue. If the workspace has a matching integration configured and the URL is recognized (e.g., Zendesk, GitHub, Slack), a rich attachment will be created that enables features like automated status updates. Otherwise, a basic attachment is created.
+///
+/// Full type: [`Attachment`](super::types::Attachment)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub async fn attachment_link_url<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Attachment>,
+>(
+    client: &Client,
+    create_as_user: Option<String>,
+    display_icon_url: Option<String>,
+    title: Option<String>,
+    url: String,
+    issue_id: String,
+    id: Option<String>,
+) -> Result<T, LinearError> {
+    super::operations::attachment_link_url::<T>(
+        create_as_user,
+        display_icon_url,
+        title,
+        url,
+        issue_id,
+        id,
+    )
+    .execute(client)
+    .await
+}
+/// Link an existing GitLab MR to an issue. This creates a rich attachment using the workspace's GitLab integration, enabling features like automated status syncing.
+///
+/// Full type: [`Attachment`](super::types::Attachment)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub async fn attachment_link_git_lab_mr<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Attachment>,
+>(
+    client: &Client,
+    create_as_user: Option<String>,
+    display_icon_url: Option<String>,
+    title: Option<String>,
+    issue_id: String,
+    id: Option<String>,
+    url: String,
+    project_path_with_namespace: String,
+    number: f64,
+) -> Result<T, LinearError> {
+    super::operations::attachment_link_git_lab_mr::<T>(
+        create_as_user,
+        display_icon_url,
+        title,
+        issue_id,
+        id,
+        url,
+        project_path_with_namespace,
+        number,
+    )
+    .execute(client)
+    .await
+}
+/// Link a GitHub issue to a Linear issue. This creates a rich attachment using the workspace's GitHub integration, enabling features like automated status syncing.
+///
+/// Full type: [`Attachment`](super::types::Attachment)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub async fn attachment_link_git_hub_issue<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Attachment>,
+>(
+    client: &Client,
+    create_as_user: Option<String>,
+    display_icon_url: Option<String>,
+    title: Option<String>,
+    issue_id: String,
+    id: Option<String>,
+    url: String,
+) -> Result<T, LinearError> {
+    super::operations::attachment_link_git_hub_issue::<T>(
+        create_as_user,
+        display_icon_url,
+        title,
+        issue_id,
+        id,
+        url,
+    )
+    .execute(client)
+    .await
+}
+/// Link a GitHub pull request to an issue. This creates a rich attachment using the workspace's GitHub integration, enabling features like automated status syncing.
+///
+/// Full type: [`Attachment`](super::types::Attachment)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub async fn attachment_link_git_hub_pr<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Attachment>,
+>(
+    client: &Client,
+    create_as_user: Option<String>,
+    display_icon_url: Option<String>,
+    title: Option<String>,
+    issue_id: String,
+    id: Option<String>,
+    url: String,
+    link_kind: Option<GitLinkKind>,
+) -> Result<T, LinearError> {
+    super::operations::attachment_link_git_hub_pr::<T>(
+        create_as_user,
+        display_icon_url,
+        title,
+        issue_id,
+        id,
+        url,
+        link_kind,
+    )
+    .execute(client)
+    .await
+}
+/// Link an existing Zendesk ticket to an issue. This creates a rich attachment using the workspace's Zendesk integration, enabling features like automated ticket reopening when the Linear issue is completed.
+///
+/// Full type: [`Attachment`](super::types::Attachment)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub async fn attachment_link_zendesk<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Attachment>,
+>(
+    client: &Client,
+    create_as_user: Option<String>,
+    display_icon_url: Option<String>,
+    title: Option<String>,
+    ticket_id: String,
+    issue_id: String,
+    id: Option<String>,
+    url: Option<String>,
+) -> Result<T, LinearError> {
+    super::operations::attachment_link_zendesk::<T>(
+        create_as_user,
+        display_icon_url,
+        title,
+        ticket_id,
+        issue_id,
+        id,
+        url,
+    )
+    .execute(client)
+    .await
+}
+/// Link an existing Discord message to an issue. This creates a rich attachment using the workspace's Discord integration.
+///
+/// Full type: [`Attachment`](super::types::Attachment)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub async fn attachment_link_discord<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Attachment>,
+>(
+    client: &Client,
+    create_as_user: Option<String>,
+    display_icon_url: Option<String>,
+    title: Option<String>,
+    issue_id: String,
+    id: Option<String>,
+    channel_id: String,
+    message_id: String,
+    url: String,
+) -> Result<T, LinearError> {
+    super::operations::attachment_link_discord::<T>(
+        create_as_user,
+        display_icon_url,
+        title,
+        issue_id,
+        id,
+        channel_id,
+        message_id,
+        url,
+    )
+    .execute(client)
+    .await
+}
+/// Begin syncing the thread for an existing Slack message attachment with a comment thread on its issue.
+///
+/// Full type: [`Attachment`](super::types::Attachment)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub async fn attachment_sync_to_slack<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Attachment>,
+>(
+    client: &Client,
+    id: impl Into<super::ids::AttachmentId>,
+) -> Result<T, LinearError> {
+    super::operations::attachment_sync_to_slack::<T>(id)
+        .execute(client)
+        .await
+}
+/// Link an existing Slack message to an issue. This creates a rich attachment using the workspace's Slack integration.
+///
+/// Full type: [`Attachment`](super::types::Attachment)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub async fn attachment_link_slack<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Attachment>,
+>(
+    client: &Client,
+    create_as_user: Option<String>,
+    display_icon_url: Option<String>,
+    title: Option<String>,
+    issue_id: String,
+    url: String,
+    id: Option<String>,
+    sync_to_comment_thread: Option<bool>,
+) -> Result<T, LinearError> {
+    super::operations::attachment_link_slack::<T>(
+        create_as_user,
+        display_icon_url,
+        title,
+        issue_id,
+        url,
+        id,
+        sync_to_comment_thread,
+    )
+    .execute(client)
+    .await
+}
+/// Link an existing Front conversation to an issue. This creates a rich attachment using the workspace's Front integration, enabling features like automated conversation updates.
+///
+/// Full type: [`Attachment`](super::types::Attachment)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub async fn attachment_link_front<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Attachment>,
+>(
+    client: &Client,
+    create_as_user: Option<String>,
+    display_icon_url: Option<String>,
+    title: Option<String>,
+    conversation_id: String,
+    issue_id: String,
+    id: Option<String>,
+) -> Result<T, LinearError> {
+    super::operations::attachment_link_front::<T>(
+        create_as_user,
+        display_icon_url,
+        title,
+        conversation_id,
+        issue_id,
+        id,
+    )
+    .execute(client)
+    .await
+}
+/// Link an existing Intercom conversation to an issue. This creates a rich attachment using the workspace's Intercom integration, enabling features like automated conversation updates.
+///
+/// Full type: [`Attachment`](super::types::Attachment)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub async fn attachment_link_intercom<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Attachment>,
+>(
+    client: &Client,
+    create_as_user: Option<String>,
+    display_icon_url: Option<String>,
+    title: Option<String>,
+    conversation_id: String,
+    part_id: Option<String>,
+    id: Option<String>,
+    issue_id: String,
+) -> Result<T, LinearError> {
+    super::operations::attachment_link_intercom::<T>(
+        create_as_user,
+        display_icon_url,
+        title,
+        conversation_id,
+        part_id,
+        id,
+        issue_id,
+    )
+    .execute(client)
+    .await
+}
+/// Link an existing Jira issue to an issue. This creates a rich attachment using the workspace's Jira integration, enabling features like automated status syncing.
+///
+/// Full type: [`Attachment`](super::types::Attachment)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub async fn attachment_link_jira_issue<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Attachment>,
+>(
+    client: &Client,
+    create_as_user: Option<String>,
+    display_icon_url: Option<String>,
+    title: Option<String>,
+    issue_id: String,
+    jira_issue_id: String,
+    id: Option<String>,
+    url: Option<String>,
+) -> Result<T, LinearError> {
+    super::operations::attachment_link_jira_issue::<T>(
+        create_as_user,
+        display_icon_url,
+        title,
+        issue_id,
+        jira_issue_id,
+        id,
+        url,
+    )
+    .execute(client)
+    .await
+}
+/// Link an existing Salesforce case to an issue. This creates a rich attachment using the workspace's Salesforce integration.
+///
+/// Full type: [`Attachment`](super::types::Attachment)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub async fn attachment_link_salesforce<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Attachment>,
+>(
+    client: &Client,
+    create_as_user: Option<String>,
+    display_icon_url: Option<String>,
+    title: Option<String>,
+    issue_id: String,
+    id: Option<String>,
+    url: String,
+) -> Result<T, LinearError> {
+    super::operations::attachment_link_salesforce::<T>(
+        create_as_user,
+        display_icon_url,
+        title,
+        issue_id,
+        id,
+        url,
+    )
+    .execute(client)
+    .await
+}
+/// Deletes an issue attachment.
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub async fn attachment_delete(
+    client: &Client,
+    id: impl Into<super::ids::AttachmentId>,
+) -> Result<super::types::DeletePayload, LinearError> {
+    super::operations::attachment_delete(id)
+        .execute(client)
+        .await
+}
+/// Creates a new agent session on a root comment.
+///
+/// Full type: [`AgentSession`](super::types::AgentSession)
+///
+/// Requires the `agents` feature.
+#[cfg(feature = "agents")]
+pub async fn agent_session_create_on_comment<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::AgentSession>,
+>(
+    client: &Client,
+    input: AgentSessionCreateOnComment,
+) -> Result<T, LinearError> {
+    super::operations::agent_session_create_on_comment::<T>(input)
+        .execute(client)
+        .await
+}
+/// Creates a new agent session on an issue.
+///
+/// Full type: [`AgentSession`](super::types::AgentSession)
+///
+/// Requires the `agents` feature.
+#[cfg(feature = "agents")]
+pub async fn agent_session_create_on_issue<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::AgentSession>,
+>(
+    client: &Client,
+    input: AgentSessionCreateOnIssue,
+) -> Result<T, LinearError> {
+    super::operations::agent_session_create_on_issue::<T>(input)
+        .execute(client)
+        .await
+}
+/// `Internal` Creates a new agent session on behalf of the current user
+///
+/// Full type: [`AgentSession`](super::types::AgentSession)
+///
+/// Requires the `agents` feature.
+#[cfg(feature = "agents")]
+pub async fn agent_session_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::AgentSession>,
+>(
+    client: &Client,
+    pull_request_id: Option<String>,
+    input: AgentSessionCreateInput,
+) -> Result<T, LinearError> {
+    super::operations::agent_session_create::<T>(pull_request_id, input)
+        .execute(client)
+        .await
+}
+/// Updates the externalUrl of an agent session, which is an agent-hosted page associated with this session.
+///
+/// Full type: [`AgentSession`](super::types::AgentSession)
+///
+/// Requires the `agents` feature.
+#[cfg(feature = "agents")]
+pub async fn agent_session_update_external_url<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::AgentSession>,
+>(
+    client: &Client,
+    input: AgentSessionUpdateExternalUrlInput,
+    id: impl Into<super::ids::AgentSessionId>,
+) -> Result<T, LinearError> {
+    super::operations::agent_session_update_external_url::<T>(input, id)
+        .execute(client)
+        .await
+}
+/// Updates an agent session.
+///
+/// Full type: [`AgentSession`](super::types::AgentSession)
+///
+/// Requires the `agents` feature.
+#[cfg(feature = "agents")]
+pub async fn agent_session_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::AgentSession>,
+>(
+    client: &Client,
+    input: AgentSessionUpdateInput,
+    id: impl Into<super::ids::AgentSessionId>,
+) -> Result<T, LinearError> {
+    super::operations::agent_session_update::<T>(input, id)
+        .execute(client)
+        .await
+}
+/// Creates an agent activity.
+///
+/// Full type: [`AgentActivity`](super::types::AgentActivity)
+///
+/// Requires the `agents` feature.
+#[cfg(feature = "agents")]
+pub async fn agent_activity_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::AgentActivity>,
+>(
+    client: &Client,
+    input: AgentActivityCreateInput,
+) -> Result<T, LinearError> {
+    super::operations::agent_activity_create::<T>(input)
+        .execute(client)
+        .await
+}
+/// `Internal` Creates a prompt agent activity from Linear user input.
+///
+/// Full type: [`AgentActivity`](super::types::AgentActivity)
+///
+/// Requires the `agents` feature.
+#[cfg(feature = "agents")]
+pub async fn agent_activity_create_prompt<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::AgentActivity>,
+>(
+    client: &Client,
+    input: AgentActivityCreatePromptInput,
 ) -> Result<T, LinearError> {
-    let variables = serde_json::json!({ "id" : id });
-    let query = String::from(
-        "mutation CommentUnresolve($id: String!) { commentUnresolve(id: $id) { success comment { ",
-    ) + &T::selection()
-        + " } } }";
-    client
-        .execute_mutation::<T>(&query, variables, "commentUnresolve", "comment")
+    super::operations::agent_activity_create_prompt::<T>(input)
+        .execute(client)
         .await
 }
@@ -0,0 +1,10864 @@
+//! Generated operation constructors.
+//!
+//! Each function builds an [`Operation`] for a query without optional
+//! arguments or for a mutation, without sending it. Pass it to
+//! [`Operation::execute`] or add it to a [`Batch`](crate::batch::Batch).
+//! Queries with optional arguments build operations through their
+//! builder's `into_operation()`.
+//!
+//! Generated by lineark-codegen — do not edit.
+#![allow(clippy::too_many_arguments)]
+#[allow(unused_imports)]
+use super::enums::*;
+use super::inputs::*;
+use crate::field_selection::GraphQLFields;
+use crate::operation::Operation;
+use serde::de::DeserializeOwned;
+/// One specific workflow state (issue status), looked up by its unique identifier.
+///
+/// Full type: [`WorkflowState`](super::types::WorkflowState)
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+pub fn workflow_state<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::WorkflowState>,
+>(
+    id: impl Into<super::ids::WorkflowStateId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::WorkflowStateId > ::into(id) }
+    );
+    let selection = format!("{} {{ {} }}", "workflowState(id: $id)", T::selection());
+    Operation::query(
+        "WorkflowState",
+        "$id: String!",
+        selection,
+        variables,
+        "workflowState",
+    )
+}
+/// Retrieves a single webhook by its identifier.
+///
+/// Full type: [`Webhook`](super::types::Webhook)
+///
+/// Requires the `webhooks` feature.
+#[cfg(feature = "webhooks")]
+pub fn webhook<T: DeserializeOwned + GraphQLFields<FullType = super::types::Webhook>>(
+    id: impl Into<super::ids::WebhookId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::WebhookId > ::into(id) }
+    );
+    let selection = format!("{} {{ {} }}", "webhook(id: $id)", T::selection());
+    Operation::query("Webhook", "$id: String!", selection, variables, "webhook")
+}
+/// `INTERNAL` Webhook failure events for webhooks that belong to an OAuth application. (last 50)
+///
+/// Full type: [`WebhookFailureEvent`](super::types::WebhookFailureEvent)
+///
+/// Requires the `webhooks` feature.
+#[cfg(feature = "webhooks")]
+pub fn failures_for_oauth_webhooks<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::WebhookFailureEvent>,
+>(
+    oauth_client_id: String,
+) -> Operation<Vec<T>> {
+    let variables = serde_json::json!({ "oauthClientId" : oauth_client_id });
+    let selection = format!(
+        "{} {{ {} }}",
+        "failuresForOauthWebhooks(oauthClientId: $oauthClientId)",
+        T::selection()
+    );
+    Operation::query(
+        "FailuresForOauthWebhooks",
+        "$oauthClientId: String!",
+        selection,
+        variables,
+        "failuresForOauthWebhooks",
+    )
+}
+/// The authenticated user's notification and UI settings.
+///
+/// Full type: [`UserSettings`](super::types::UserSettings)
+///
+/// Requires the `users` feature.
+#[cfg(feature = "users")]
+pub fn user_settings<T: DeserializeOwned + GraphQLFields<FullType = super::types::UserSettings>>(
+) -> Operation<T> {
+    let variables = serde_json::json!({});
+    let selection = format!("{} {{ {} }}", "userSettings", T::selection());
+    Operation::query("UserSettings", "", selection, variables, "userSettings")
+}
+/// Fetches a specific user by their ID.
+///
+/// Full type: [`User`](super::types::User)
+///
+/// Requires the `users` feature.
+#[cfg(feature = "users")]
+pub fn user<T: DeserializeOwned + GraphQLFields<FullType = super::types::User>>(
+    id: impl Into<super::ids::UserId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::UserId > ::into(id) }
+    );
+    let selection = format!("{} {{ {} }}", "user(id: $id)", T::selection());
+    Operation::query("User", "$id: String!", selection, variables, "user")
+}
+/// The currently authenticated user making the API request.
+///
+/// Full type: [`User`](super::types::User)
+pub fn whoami<T: DeserializeOwned + GraphQLFields<FullType = super::types::User>>() -> Operation<T>
+{
+    let variables = serde_json::json!({});
+    let selection = format!("{} {{ {} }}", "viewer", T::selection());
+    Operation::query("Viewer", "", selection, variables, "viewer")
+}
+/// Lists all active authentication sessions for a user. Can only be called by a workspace admin or owner.
+///
+/// Full type: [`AuthenticationSessionResponse`](super::types::AuthenticationSessionResponse)
+///
+/// Requires the `users` feature.
+#[cfg(feature = "users")]
+pub fn user_sessions<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::AuthenticationSessionResponse>,
+>(
+    id: impl Into<super::ids::AuthenticationSessionResponseId>,
+) -> Operation<Vec<T>> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::AuthenticationSessionResponseId > ::into(id) }
+    );
+    let selection = format!("{} {{ {} }}", "userSessions(id: $id)", T::selection());
+    Operation::query(
+        "UserSessions",
+        "$id: String!",
+        selection,
+        variables,
+        "userSessions",
+    )
+}
+/// A specific triage responsibility.
+///
+/// Full type: [`TriageResponsibility`](super::types::TriageResponsibility)
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+pub fn triage_responsibility<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::TriageResponsibility>,
+>(
+    id: impl Into<super::ids::TriageResponsibilityId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::TriageResponsibilityId > ::into(id) }
+    );
+    let selection = format!(
+        "{} {{ {} }}",
+        "triageResponsibility(id: $id)",
+        T::selection()
+    );
+    Operation::query(
+        "TriageResponsibility",
+        "$id: String!",
+        selection,
+        variables,
+        "triageResponsibility",
+    )
+}
+/// A specific time schedule.
+///
+/// Full type: [`TimeSchedule`](super::types::TimeSchedule)
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+pub fn time_schedule<T: DeserializeOwned + GraphQLFields<FullType = super::types::TimeSchedule>>(
+    id: impl Into<super::ids::TimeScheduleId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::TimeScheduleId > ::into(id) }
+    );
+    let selection = format!("{} {{ {} }}", "timeSchedule(id: $id)", T::selection());
+    Operation::query(
+        "TimeSchedule",
+        "$id: String!",
+        selection,
+        variables,
+        "timeSchedule",
+    )
+}
+/// All templates in the workspace, including both team-scoped and workspace-level templates.
+///
+/// Full type: [`Template`](super::types::Template)
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+pub fn templates<T: DeserializeOwned + GraphQLFields<FullType = super::types::Template>>(
+) -> Operation<Vec<T>> {
+    let variables = serde_json::json!({});
+    let selection = format!("{} {{ {} }}", "templates", T::selection());
+    Operation::query("Templates", "", selection, variables, "templates")
+}
+/// A specific template.
+///
+/// Full type: [`Template`](super::types::Template)
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+pub fn template<T: DeserializeOwned + GraphQLFields<FullType = super::types::Template>>(
+    id: impl Into<super::ids::TemplateId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::TemplateId > ::into(id) }
+    );
+    let selection = format!("{} {{ {} }}", "template(id: $id)", T::selection());
+    Operation::query("Template", "$id: String!", selection, variables, "template")
+}
+/// Returns all templates that are associated with the integration type.
+///
+/// Full type: [`Template`](super::types::Template)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn templates_for_integration<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::Template>,
+>(
+    integration_type: String,
+) -> Operation<Vec<T>> {
+    let variables = serde_json::json!({ "integrationType" : integration_type });
+    let selection = format!(
+        "{} {{ {} }}",
+        "templatesForIntegration(integrationType: $integrationType)",
+        T::selection()
+    );
+    Operation::query(
+        "TemplatesForIntegration",
+        "$integrationType: String!",
+        selection,
+        variables,
+        "templatesForIntegration",
+    )
+}
+/// Returns a single project by its identifier or URL slug.
+///
+/// Full type: [`Project`](super::types::Project)
+pub fn project<T: DeserializeOwned + GraphQLFields<FullType = super::types::Project>>(
+    id: impl Into<super::ids::ProjectId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::ProjectId > ::into(id) }
+    );
+    let selection = format!("{} {{ {} }}", "project(id: $id)", T::selection());
+    Operation::query("Project", "$id: String!", selection, variables, "project")
+}
+/// Fetches a specific team by its ID.
+///
+/// Full type: [`Team`](super::types::Team)
+pub fn team<T: DeserializeOwned + GraphQLFields<FullType = super::types::Team>>(
+    id: impl Into<super::ids::TeamId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::TeamId > ::into(id) }
+    );
+    let selection = format!("{} {{ {} }}", "team(id: $id)", T::selection());
+    Operation::query("Team", "$id: String!", selection, variables, "team")
+}
+/// Fetches a specific team membership by its ID.
+///
+/// Full type: [`TeamMembership`](super::types::TeamMembership)
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+pub fn team_membership<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::TeamMembership>,
+>(
+    id: impl Into<super::ids::TeamMembershipId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::TeamMembershipId > ::into(id) }
+    );
+    let selection = format!("{} {{ {} }}", "teamMembership(id: $id)", T::selection());
+    Operation::query(
+        "TeamMembership",
+        "$id: String!",
+        selection,
+        variables,
+        "teamMembership",
+    )
+}
+/// `Deprecated` Returns a single roadmap-to-project association. Use InitiativeToProject instead.
+///
+/// Full type: [`RoadmapToProject`](super::types::RoadmapToProject)
+#[deprecated(note = "RoadmapToProject is deprecated, use InitiativeToProject instead.")]
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub fn roadmap_to_project<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::RoadmapToProject>,
+>(
+    id: impl Into<super::ids::RoadmapToProjectId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::RoadmapToProjectId > ::into(id) }
+    );
+    let selection = format!("{} {{ {} }}", "roadmapToProject(id: $id)", T::selection());
+    Operation::query(
+        "RoadmapToProject",
+        "$id: String!",
+        selection,
+        variables,
+        "roadmapToProject",
+    )
+}
+/// `Deprecated` Returns a single roadmap by its identifier. Use initiatives instead.
+///
+/// Full type: [`Roadmap`](super::types::Roadmap)
+#[deprecated(note = "Roadmaps are deprecated, use initiatives instead.")]
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub fn roadmap<T: DeserializeOwned + GraphQLFields<FullType = super::types::Roadmap>>(
+    id: impl Into<super::ids::RoadmapId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::RoadmapId > ::into(id) }
+    );
+    let selection = format!("{} {{ {} }}", "roadmap(id: $id)", T::selection());
+    Operation::query("Roadmap", "$id: String!", selection, variables, "roadmap")
+}
+/// `ALPHA` Fetch a single release stage by its UUID.
+///
+/// Full type: [`ReleaseStage`](super::types::ReleaseStage)
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+pub fn release_stage<T: DeserializeOwned + GraphQLFields<FullType = super::types::ReleaseStage>>(
+    id: impl Into<super::ids::ReleaseStageId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::ReleaseStageId > ::into(id) }
+    );
+    let selection = format!("{} {{ {} }}", "releaseStage(id: $id)", T::selection());
+    Operation::query(
+        "ReleaseStage",
+        "$id: String!",
+        selection,
+        variables,
+        "releaseStage",
+    )
+}
+/// `ALPHA` Fetch a single release pipeline by its UUID or slug identifier.
+///
+/// Full type: [`ReleasePipeline`](super::types::ReleasePipeline)
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+pub fn release_pipeline<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::ReleasePipeline>,
+>(
+    id: impl Into<super::ids::ReleasePipelineId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::ReleasePipelineId > ::into(id) }
+    );
+    let selection = format!("{} {{ {} }}", "releasePipeline(id: $id)", T::selection());
+    Operation::query(
+        "ReleasePipeline",
+        "$id: String!",
+        selection,
+        variables,
+        "releasePipeline",
+    )
+}
+/// `ALPHA` Returns a release pipeline by ID. Requires the access key to have access to the pipeline.
+///
+/// Full type: [`ReleasePipeline`](super::types::ReleasePipeline)
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+pub fn release_pipeline_by_access_key<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::ReleasePipeline>,
+>() -> Operation<T> {
+    let variables = serde_json::json!({});
+    let selection = format!("{} {{ {} }}", "releasePipelineByAccessKey", T::selection());
+    Operation::query(
+        "ReleasePipelineByAccessKey",
+        "",
+        selection,
+        variables,
+        "releasePipelineByAccessKey",
+    )
+}
+/// `ALPHA` Returns the latest release for the pipeline associated with the access key.
+///
+/// Full type: [`Release`](super::types::Release)
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+pub fn latest_release_by_access_key<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::Release>,
+>() -> Operation<Option<T>> {
+    let variables = serde_json::json!({});
+    let selection = format!("{} {{ {} }}", "latestReleaseByAccessKey", T::selection());
+    Operation::query(
+        "LatestReleaseByAccessKey",
+        "",
+        selection,
+        variables,
+        "latestReleaseByAccessKey",
+    )
+}
+/// `ALPHA` Fetch a single release by its UUID or slug identifier.
+///
+/// Full type: [`Release`](super::types::Release)
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+pub fn release<T: DeserializeOwned + GraphQLFields<FullType = super::types::Release>>(
+    id: impl Into<super::ids::ReleaseId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::ReleaseId > ::into(id) }
+    );
+    let selection = format!("{} {{ {} }}", "release(id: $id)", T::selection());
+    Operation::query("Release", "$id: String!", selection, variables, "release")
+}
+/// `ALPHA` Fetch a release note by its identifier.
+///
+/// Full type: [`ReleaseNote`](super::types::ReleaseNote)
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+pub fn release_note<T: DeserializeOwned + GraphQLFields<FullType = super::types::ReleaseNote>>(
+    id: impl Into<super::ids::ReleaseNoteId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::ReleaseNoteId > ::into(id) }
+    );
+    let selection = format!("{} {{ {} }}", "releaseNote(id: $id)", T::selection());
+    Operation::query(
+        "ReleaseNote",
+        "$id: String!",
+        selection,
+        variables,
+        "releaseNote",
+    )
+}
+/// The current rate limit status for the authenticated client, including remaining quota and reset timing for each limit type.
+///
+/// Full type: [`RateLimitPayload`](super::types::RateLimitPayload)
+pub fn rate_limit_status<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::RateLimitPayload>,
+>() -> Operation<T> {
+    let variables = serde_json::json!({});
+    let selection = format!("{} {{ {} }}", "rateLimitStatus", T::selection());
+    Operation::query(
+        "RateLimitStatus",
+        "",
+        selection,
+        variables,
+        "rateLimitStatus",
+    )
+}
+/// Returns a single project update by its identifier.
+///
+/// Full type: [`ProjectUpdate`](super::types::ProjectUpdate)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub fn project_update_post<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::ProjectUpdate>,
+>(
+    id: impl Into<super::ids::ProjectUpdateId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::ProjectUpdateId > ::into(id) }
+    );
+    let selection = format!("{} {{ {} }}", "projectUpdate(id: $id)", T::selection());
+    Operation::query(
+        "ProjectUpdate",
+        "$id: String!",
+        selection,
+        variables,
+        "projectUpdate",
+    )
+}
+/// `INTERNAL` Count of projects using this project status across the workspace.
+///
+/// Full type: [`ProjectStatusCountPayload`](super::types::ProjectStatusCountPayload)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub fn project_status_project_count<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::ProjectStatusCountPayload>,
+>(
+    id: impl Into<super::ids::ProjectStatusId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::ProjectStatusId > ::into(id) }
+    );
+    let selection = format!(
+        "{} {{ {} }}",
+        "projectStatusProjectCount(id: $id)",
+        T::selection()
+    );
+    Operation::query(
+        "ProjectStatusProjectCount",
+        "$id: String!",
+        selection,
+        variables,
+        "projectStatusProjectCount",
+    )
+}
+/// Returns a single project status by its identifier.
+///
+/// Full type: [`ProjectStatus`](super::types::ProjectStatus)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub fn project_status<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::ProjectStatus>,
+>(
+    id: impl Into<super::ids::ProjectStatusId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::ProjectStatusId > ::into(id) }
+    );
+    let selection = format!("{} {{ {} }}", "projectStatus(id: $id)", T::selection());
+    Operation::query(
+        "ProjectStatus",
+        "$id: String!",
+        selection,
+        variables,
+        "projectStatus",
+    )
+}
+/// Returns a single project relation by its identifier.
+///
+/// Full type: [`ProjectRelation`](super::types::ProjectRelation)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub fn project_relation<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::ProjectRelation>,
+>(
+    id: impl Into<super::ids::ProjectRelationId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::ProjectRelationId > ::into(id) }
+    );
+    let selection = format!("{} {{ {} }}", "projectRelation(id: $id)", T::selection());
+    Operation::query(
+        "ProjectRelation",
+        "$id: String!",
+        selection,
+        variables,
+        "projectRelation",
+    )
+}
+/// Returns a single project milestone by its identifier.
+///
+/// Full type: [`ProjectMilestone`](super::types::ProjectMilestone)
+pub fn project_milestone<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::ProjectMilestone>,
+>(
+    id: impl Into<super::ids::ProjectMilestoneId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::ProjectMilestoneId > ::into(id) }
+    );
+    let selection = format!("{} {{ {} }}", "projectMilestone(id: $id)", T::selection());
+    Operation::query(
+        "ProjectMilestone",
+        "$id: String!",
+        selection,
+        variables,
+        "projectMilestone",
+    )
+}
+/// Returns a single project label by its identifier.
+///
+/// Full type: [`ProjectLabel`](super::types::ProjectLabel)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub fn project_label<T: DeserializeOwned + GraphQLFields<FullType = super::types::ProjectLabel>>(
+    id: impl Into<super::ids::ProjectLabelId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::ProjectLabelId > ::into(id) }
+    );
+    let selection = format!("{} {{ {} }}", "projectLabel(id: $id)", T::selection());
+    Operation::query(
+        "ProjectLabel",
+        "$id: String!",
+        selection,
+        variables,
+        "projectLabel",
+    )
+}
+/// The authenticated user's workspace.
+///
+/// Full type: [`Organization`](super::types::Organization)
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub fn organization<T: DeserializeOwned + GraphQLFields<FullType = super::types::Organization>>(
+) -> Operation<T> {
+    let variables = serde_json::json!({});
+    let selection = format!("{} {{ {} }}", "organization", T::selection());
+    Operation::query("Organization", "", selection, variables, "organization")
+}
+/// Checks whether a workspace with the given URL key exists.
+///
+/// Full type: [`OrganizationExistsPayload`](super::types::OrganizationExistsPayload)
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub fn organization_exists<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::OrganizationExistsPayload>,
+>(
+    url_key: String,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "urlKey" : url_key });
+    let selection = format!(
+        "{} {{ {} }}",
+        "organizationExists(urlKey: $urlKey)",
+        T::selection()
+    );
+    Operation::query(
+        "OrganizationExists",
+        "$urlKey: String!",
+        selection,
+        variables,
+        "organizationExists",
+    )
+}
+/// `Internal` All archived teams of the workspace.
+///
+/// Full type: [`Team`](super::types::Team)
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+pub fn archived_teams<T: DeserializeOwned + GraphQLFields<FullType = super::types::Team>>(
+) -> Operation<Vec<T>> {
+    let variables = serde_json::json!({});
+    let selection = format!("{} {{ {} }}", "archivedTeams", T::selection());
+    Operation::query("ArchivedTeams", "", selection, variables, "archivedTeams")
+}
+/// `INTERNAL` Get workspace metadata by URL key or workspace ID.
+///
+/// Full type: [`OrganizationMeta`](super::types::OrganizationMeta)
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub fn organization_meta<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::OrganizationMeta>,
+>(
+    url_key: String,
+) -> Operation<Option<T>> {
+    let variables = serde_json::json!({ "urlKey" : url_key });
+    let selection = format!(
+        "{} {{ {} }}",
+        "organizationMeta(urlKey: $urlKey)",
+        T::selection()
+    );
+    Operation::query(
+        "OrganizationMeta",
+        "$urlKey: String!",
+        selection,
+        variables,
+        "organizationMeta",
+    )
+}
+/// Fetches a specific workspace invite by its ID.
+///
+/// Full type: [`OrganizationInvite`](super::types::OrganizationInvite)
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub fn organization_invite<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::OrganizationInvite>,
+>(
+    id: impl Into<super::ids::OrganizationInviteId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::OrganizationInviteId > ::into(id) }
+    );
+    let selection = format!("{} {{ {} }}", "organizationInvite(id: $id)", T::selection());
+    Operation::query(
+        "OrganizationInvite",
+        "$id: String!",
+        selection,
+        variables,
+        "organizationInvite",
+    )
+}
+/// Fetches public details of a specific workspace invite by its ID. This query does not require authentication and is used for invite acceptance flows.
+///
+/// Full type: [`OrganizationInviteDetailsPayload`](super::types::OrganizationInviteDetailsPayload)
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub fn organization_invite_details<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::OrganizationInviteDetailsPayload>,
+>(
+    id: impl Into<super::ids::OrganizationInviteId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::OrganizationInviteId > ::into(id) }
+    );
+    let selection = format!(
+        "{} {{ {} }}",
+        "organizationInviteDetails(id: $id)",
+        T::selection()
+    );
+    Operation::query(
+        "OrganizationInviteDetails",
+        "$id: String!",
+        selection,
+        variables,
+        "organizationInviteDetails",
+    )
+}
+/// `INTERNAL` Checks whether the domain can be claimed.
+///
+/// Full type: [`OrganizationDomainClaimPayload`](super::types::OrganizationDomainClaimPayload)
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub fn organization_domain_claim_request<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::OrganizationDomainClaimPayload>,
+>(
+    id: impl Into<super::ids::OrganizationDomainId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::OrganizationDomainId > ::into(id) }
+    );
+    let selection = format!(
+        "{} {{ {} }}",
+        "organizationDomainClaimRequest(id: $id)",
+        T::selection()
+    );
+    Operation::query(
+        "OrganizationDomainClaimRequest",
+        "$id: String!",
+        selection,
+        variables,
+        "organizationDomainClaimRequest",
+    )
+}
+/// A specific notification subscription by ID.
+///
+/// Full type: [`NotificationSubscription`](super::types::NotificationSubscription)
+///
+/// Requires the `notifications` feature.
+#[cfg(feature = "notifications")]
+pub fn notification_subscription<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::NotificationSubscription>,
+>(
+    id: String,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "id" : id });
+    let selection = format!(
+        "{} {{ {} }}",
+        "notificationSubscription(id: $id)",
+        T::selection()
+    );
+    Operation::query(
+        "NotificationSubscription",
+        "$id: String!",
+        selection,
+        variables,
+        "notificationSubscription",
+    )
+}
+/// A specific notification by ID.
+///
+/// Full type: [`Notification`](super::types::Notification)
+pub fn notification<T: DeserializeOwned + GraphQLFields<FullType = super::types::Notification>>(
+    id: String,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "id" : id });
+    let selection = format!("{} {{ {} }}", "notification(id: $id)", T::selection());
+    Operation::query(
+        "Notification",
+        "$id: String!",
+        selection,
+        variables,
+        "notification",
+    )
+}
+/// `ALPHA` One specific issue-to-release association, looked up by its unique identifier.
+///
+/// Full type: [`IssueToRelease`](super::types::IssueToRelease)
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+pub fn issue_to_release<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::IssueToRelease>,
+>(
+    id: impl Into<super::ids::IssueToReleaseId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::IssueToReleaseId > ::into(id) }
+    );
+    let selection = format!("{} {{ {} }}", "issueToRelease(id: $id)", T::selection());
+    Operation::query(
+        "IssueToRelease",
+        "$id: String!",
+        selection,
+        variables,
+        "issueToRelease",
+    )
+}
+/// One specific issue, looked up by its unique identifier.
+///
+/// Full type: [`Issue`](super::types::Issue)
+pub fn issue<T: DeserializeOwned + GraphQLFields<FullType = super::types::Issue>>(
+    id: impl Into<super::ids::IssueId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::IssueId > ::into(id) }
+    );
+    let selection = format!("{} {{ {} }}", "issue(id: $id)", T::selection());
+    Operation::query("Issue", "$id: String!", selection, variables, "issue")
+}
+/// Find issue based on the VCS branch name.
+///
+/// Full type: [`Issue`](super::types::Issue)
+pub fn issue_vcs_branch_search<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::Issue>,
+>(
+    branch_name: String,
+) -> Operation<Option<T>> {
+    let variables = serde_json::json!({ "branchName" : branch_name });
+    let selection = format!(
+        "{} {{ {} }}",
+        "issueVcsBranchSearch(branchName: $branchName)",
+        T::selection()
+    );
+    Operation::query(
+        "IssueVcsBranchSearch",
+        "$branchName: String!",
+        selection,
+        variables,
+        "issueVcsBranchSearch",
+    )
+}
+/// Issue priority values and corresponding labels.
+///
+/// Full type: [`IssuePriorityValue`](super::types::IssuePriorityValue)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub fn issue_priority_values<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::IssuePriorityValue>,
+>() -> Operation<Vec<T>> {
+    let variables = serde_json::json!({});
+    let selection = format!("{} {{ {} }}", "issuePriorityValues", T::selection());
+    Operation::query(
+        "IssuePriorityValues",
+        "",
+        selection,
+        variables,
+        "issuePriorityValues",
+    )
+}
+/// One specific issue relation, looked up by its unique identifier.
+///
+/// Full type: [`IssueRelation`](super::types::IssueRelation)
+pub fn issue_relation<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::IssueRelation>,
+>(
+    id: impl Into<super::ids::IssueRelationId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::IssueRelationId > ::into(id) }
+    );
+    let selection = format!("{} {{ {} }}", "issueRelation(id: $id)", T::selection());
+    Operation::query(
+        "IssueRelation",
+        "$id: String!",
+        selection,
+        variables,
+        "issueRelation",
+    )
+}
+/// One specific label, looked up by its unique identifier.
+///
+/// Full type: [`IssueLabel`](super::types::IssueLabel)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub fn issue_label<T: DeserializeOwned + GraphQLFields<FullType = super::types::IssueLabel>>(
+    id: impl Into<super::ids::IssueLabelId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::IssueLabelId > ::into(id) }
+    );
+    let selection = format!("{} {{ {} }}", "issueLabel(id: $id)", T::selection());
+    Operation::query(
+        "IssueLabel",
+        "$id: String!",
+        selection,
+        variables,
+        "issueLabel",
+    )
+}
+/// Checks a CSV file validity against a specific import service.
+///
+/// Full type: [`IssueImportCheckPayload`](super::types::IssueImportCheckPayload)
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub fn issue_import_check_csv<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::IssueImportCheckPayload>,
+>(
+    csv_url: String,
+    service: String,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "csvUrl" : csv_url, "service" : service });
+    let selection = format!(
+        "{} {{ {} }}",
+        "issueImportCheckCSV(csvUrl: $csvUrl, service: $service)",
+        T::selection()
+    );
+    Operation::query(
+        "IssueImportCheckCsv",
+        "$csvUrl: String!, $service: String!",
+        selection,
+        variables,
+        "issueImportCheckCSV",
+    )
+}
+/// Checks whether it will be possible to set up sync for this project or repository at the end of import.
+///
+/// Full type: [`IssueImportSyncCheckPayload`](super::types::IssueImportSyncCheckPayload)
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub fn issue_import_check_sync<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::IssueImportSyncCheckPayload>,
+>(
+    issue_import_id: String,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "issueImportId" : issue_import_id });
+    let selection = format!(
+        "{} {{ {} }}",
+        "issueImportCheckSync(issueImportId: $issueImportId)",
+        T::selection()
+    );
+    Operation::query(
+        "IssueImportCheckSync",
+        "$issueImportId: String!",
+        selection,
+        variables,
+        "issueImportCheckSync",
+    )
+}
+/// Checks whether a custom JQL query is valid and can be used to filter issues of a Jira import.
+///
+/// Full type: [`IssueImportJqlCheckPayload`](super::types::IssueImportJqlCheckPayload)
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub fn issue_import_jql_check<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::IssueImportJqlCheckPayload>,
+>(
+    jira_hostname: String,
+    jira_token: String,
+    jira_email: String,
+    jira_project: String,
+    jql: String,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "jiraHostname" : jira_hostname, "jiraToken" : jira_token, "jiraEmail" :
+        jira_email, "jiraProject" : jira_project, "jql" : jql }
+    );
+    let selection = format!(
+        "{} {{ {} }}",
+        "issueImportJqlCheck(jiraHostname: $jiraHostname, jiraToken: $jiraToken, jiraEmail: $jiraEmail, jiraProject: $jiraProject, jql: $jql)",
+        T::selection()
+    );
+    Operation::query(
+        "IssueImportJqlCheck",
+        "$jiraHostname: String!, $jiraToken: String!, $jiraEmail: String!, $jiraProject: String!, $jql: String!",
+        selection,
+        variables,
+        "issueImportJqlCheck",
+    )
+}
+/// Retrieves a specific integration settings configuration by its identifier.
+///
+/// Full type: [`IntegrationsSettings`](super::types::IntegrationsSettings)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn integrations_settings<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::IntegrationsSettings>,
+>(
+    id: impl Into<super::ids::IntegrationsSettingsId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::IntegrationsSettingsId > ::into(id) }
+    );
+    let selection = format!(
+        "{} {{ {} }}",
+        "integrationsSettings(id: $id)",
+        T::selection()
+    );
+    Operation::query(
+        "IntegrationsSettings",
+        "$id: String!",
+        selection,
+        variables,
+        "integrationsSettings",
+    )
+}
+/// Retrieves a single integration template connection by its identifier.
+///
+/// Full type: [`IntegrationTemplate`](super::types::IntegrationTemplate)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn integration_template<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::IntegrationTemplate>,
+>(
+    id: impl Into<super::ids::IntegrationTemplateId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::IntegrationTemplateId > ::into(id) }
+    );
+    let selection = format!(
+        "{} {{ {} }}",
+        "integrationTemplate(id: $id)",
+        T::selection()
+    );
+    Operation::query(
+        "IntegrationTemplate",
+        "$id: String!",
+        selection,
+        variables,
+        "integrationTemplate",
+    )
+}
+/// Retrieves a single integration by its identifier.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn integration<T: DeserializeOwned + GraphQLFields<FullType = super::types::Integration>>(
+    id: impl Into<super::ids::IntegrationId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::IntegrationId > ::into(id) }
+    );
+    let selection = format!("{} {{ {} }}", "integration(id: $id)", T::selection());
+    Operation::query(
+        "Integration",
+        "$id: String!",
+        selection,
+        variables,
+        "integration",
+    )
+}
+/// Verify that we received the correct response from the GitHub Enterprise Server.
+///
+/// Full type: [`GitHubEnterpriseServerInstallVerificationPayload`](super::types::GitHubEnterpriseServerInstallVerificationPayload)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn verify_git_hub_enterprise_server_installation<
+    T: DeserializeOwned
+        + GraphQLFields<FullType = super::types::GitHubEnterpriseServerInstallVerificationPayload>,
+>(
+    integration_id: String,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "integrationId" : integration_id });
+    let selection = format!(
+        "{} {{ {} }}",
+        "verifyGitHubEnterpriseServerInstallation(integrationId: $integrationId)",
+        T::selection()
+    );
+    Operation::query(
+        "VerifyGitHubEnterpriseServerInstallation",
+        "$integrationId: String!",
+        selection,
+        variables,
+        "verifyGitHubEnterpriseServerInstallation",
+    )
+}
+/// Checks if the integration has all required scopes.
+///
+/// Full type: [`IntegrationHasScopesPayload`](super::types::IntegrationHasScopesPayload)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn integration_has_scopes<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::IntegrationHasScopesPayload>,
+>(
+    scopes: Vec<String>,
+    integration_id: String,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "scopes" : scopes, "integrationId" : integration_id }
+    );
+    let selection = format!(
+        "{} {{ {} }}",
+        "integrationHasScopes(scopes: $scopes, integrationId: $integrationId)",
+        T::selection()
+    );
+    Operation::query(
+        "IntegrationHasScopes",
+        "$scopes: [String!]!, $integrationId: String!",
+        selection,
+        variables,
+        "integrationHasScopes",
+    )
+}
+/// `Internal` Lists Microsoft Teams teams and channels accessible to the connecting user. Uses the user's microsoftPersonal integration for auth. Used by the project channel picker dialog to populate the team/channel dropdowns.
+///
+/// Full type: [`MicrosoftTeamsChannelsPayload`](super::types::MicrosoftTeamsChannelsPayload)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn microsoft_teams_channels<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::MicrosoftTeamsChannelsPayload>,
+>() -> Operation<T> {
+    let variables = serde_json::json!({});
+    let selection = format!("{} {{ {} }}", "microsoftTeamsChannels", T::selection());
+    Operation::query(
+        "MicrosoftTeamsChannels",
+        "",
+        selection,
+        variables,
+        "microsoftTeamsChannels",
+    )
+}
+/// Returns a single initiative update by its identifier.
+///
+/// Full type: [`InitiativeUpdate`](super::types::InitiativeUpdate)
+///
+/// Requires the `initiatives` feature.
+#[cfg(feature = "initiatives")]
+pub fn initiative_update_post<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::InitiativeUpdate>,
+>(
+    id: impl Into<super::ids::InitiativeUpdateId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::InitiativeUpdateId > ::into(id) }
+    );
+    let selection = format!("{} {{ {} }}", "initiativeUpdate(id: $id)", T::selection());
+    Operation::query(
+        "InitiativeUpdate",
+        "$id: String!",
+        selection,
+        variables,
+        "initiativeUpdate",
+    )
+}
+/// Returns a single initiative-to-project association by its identifier.
+///
+/// Full type: [`InitiativeToProject`](super::types::InitiativeToProject)
+///
+/// Requires the `initiatives` feature.
+#[cfg(feature = "initiatives")]
+pub fn initiative_to_project<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::InitiativeToProject>,
+>(
+    id: impl Into<super::ids::InitiativeToProjectId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::InitiativeToProjectId > ::into(id) }
+    );
+    let selection = format!(
+        "{} {{ {} }}",
+        "initiativeToProject(id: $id)",
+        T::selection()
+    );
+    Operation::query(
+        "InitiativeToProject",
+        "$id: String!",
+        selection,
+        variables,
+        "initiativeToProject",
+    )
+}
+/// Returns a single initiative by its identifier or URL slug.
+///
+/// Full type: [`Initiative`](super::types::Initiative)
+///
+/// Requires the `initiatives` feature.
+#[cfg(feature = "initiatives")]
+pub fn initiative<T: DeserializeOwned + GraphQLFields<FullType = super::types::Initiative>>(
+    id: impl Into<super::ids::InitiativeId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::InitiativeId > ::into(id) }
+    );
+    let selection = format!("{} {{ {} }}", "initiative(id: $id)", T::selection());
+    Operation::query(
+        "Initiative",
+        "$id: String!",
+        selection,
+        variables,
+        "initiative",
+    )
+}
+/// Returns a single initiative relation by its identifier.
+///
+/// Full type: [`InitiativeRelation`](super::types::InitiativeRelation)
+///
+/// Requires the `initiatives` feature.
+#[cfg(feature = "initiatives")]
+pub fn initiative_relation<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::InitiativeRelation>,
+>(
+    id: impl Into<super::ids::InitiativeRelationId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::InitiativeRelationId > ::into(id) }
+    );
+    let selection = format!("{} {{ {} }}", "initiativeRelation(id: $id)", T::selection());
+    Operation::query(
+        "InitiativeRelation",
+        "$id: String!",
+        selection,
+        variables,
+        "initiativeRelation",
+    )
+}
+/// `Internal` Fetch an arbitrary set of data using natural language query. Be specific about what you want including properties for each entity, sort order, filters, limit and properties.
+///
+/// Full type: [`FetchDataPayload`](super::types::FetchDataPayload)
+///
+/// Requires the `agents` feature.
+#[cfg(feature = "agents")]
+pub fn fetch_data<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::FetchDataPayload>,
+>(
+    query: String,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "query" : query });
+    let selection = format!("{} {{ {} }}", "fetchData(query: $query)", T::selection());
+    Operation::query(
+        "FetchData",
+        "$query: String!",
+        selection,
+        variables,
+        "fetchData",
+    )
+}
+/// A specific favorite by ID.
+///
+/// Full type: [`Favorite`](super::types::Favorite)
+///
+/// Requires the `views` feature.
+#[cfg(feature = "views")]
+pub fn favorite<T: DeserializeOwned + GraphQLFields<FullType = super::types::Favorite>>(
+    id: impl Into<super::ids::FavoriteId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::FavoriteId > ::into(id) }
+    );
+    let selection = format!("{} {{ {} }}", "favorite(id: $id)", T::selection());
+    Operation::query("Favorite", "$id: String!", selection, variables, "favorite")
+}
+/// Retrieves a single external user by their identifier.
+///
+/// Full type: [`ExternalUser`](super::types::ExternalUser)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn external_user<T: DeserializeOwned + GraphQLFields<FullType = super::types::ExternalUser>>(
+    id: impl Into<super::ids::ExternalUserId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::ExternalUserId > ::into(id) }
+    );
+    let selection = format!("{} {{ {} }}", "externalUser(id: $id)", T::selection());
+    Operation::query(
+        "ExternalUser",
+        "$id: String!",
+        selection,
+        variables,
+        "externalUser",
+    )
+}
+/// Retrieves a single entity external link by its identifier.
+///
+/// Full type: [`EntityExternalLink`](super::types::EntityExternalLink)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub fn entity_external_link<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::EntityExternalLink>,
+>(
+    id: impl Into<super::ids::EntityExternalLinkId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::EntityExternalLinkId > ::into(id) }
+    );
+    let selection = format!("{} {{ {} }}", "entityExternalLink(id: $id)", T::selection());
+    Operation::query(
+        "EntityExternalLink",
+        "$id: String!",
+        selection,
+        variables,
+        "entityExternalLink",
+    )
+}
+/// A specific custom emoji by ID or name.
+///
+/// Full type: [`Emoji`](super::types::Emoji)
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub fn emoji<T: DeserializeOwned + GraphQLFields<FullType = super::types::Emoji>>(
+    id: impl Into<super::ids::EmojiId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::EmojiId > ::into(id) }
+    );
+    let selection = format!("{} {{ {} }}", "emoji(id: $id)", T::selection());
+    Operation::query("Emoji", "$id: String!", selection, variables, "emoji")
+}
+/// One specific email intake address.
+///
+/// Full type: [`EmailIntakeAddress`](super::types::EmailIntakeAddress)
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+pub fn email_intake_address<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::EmailIntakeAddress>,
+>(
+    id: impl Into<super::ids::EmailIntakeAddressId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::EmailIntakeAddressId > ::into(id) }
+    );
+    let selection = format!("{} {{ {} }}", "emailIntakeAddress(id: $id)", T::selection());
+    Operation::query(
+        "EmailIntakeAddress",
+        "$id: String!",
+        selection,
+        variables,
+        "emailIntakeAddress",
+    )
+}
+/// A specific document by ID or slug.
+///
+/// Full type: [`Document`](super::types::Document)
+pub fn document<T: DeserializeOwned + GraphQLFields<FullType = super::types::Document>>(
+    id: impl Into<super::ids::DocumentId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::DocumentId > ::into(id) }
+    );
+    let selection = format!("{} {{ {} }}", "document(id: $id)", T::selection());
+    Operation::query("Document", "$id: String!", selection, variables, "document")
+}
+/// A collection of document content history entries.
+///
+/// Full type: [`DocumentContentHistoryPayload`](super::types::DocumentContentHistoryPayload)
+///
+/// Requires the `documents` feature.
+#[cfg(feature = "documents")]
+pub fn document_content_history<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::DocumentContentHistoryPayload>,
+>(
+    id: impl Into<super::ids::DocumentContentHistoryTypeId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::DocumentContentHistoryTypeId > ::into(id) }
+    );
+    let selection = format!(
+        "{} {{ {} }}",
+        "documentContentHistory(id: $id)",
+        T::selection()
+    );
+    Operation::query(
+        "DocumentContentHistory",
+        "$id: String!",
+        selection,
+        variables,
+        "documentContentHistory",
+    )
+}
+/// `Internal` Fetches document content history entries by their IDs, including content data.
+///
+/// Full type: [`DocumentContentHistoryPayload`](super::types::DocumentContentHistoryPayload)
+///
+/// Requires the `documents` feature.
+#[cfg(feature = "documents")]
+pub fn document_content_history_entries<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::DocumentContentHistoryPayload>,
+>(
+    entry_ids: Vec<String>,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "entryIds" : entry_ids });
+    let selection = format!(
+        "{} {{ {} }}",
+        "documentContentHistoryEntries(entryIds: $entryIds)",
+        T::selection()
+    );
+    Operation::query(
+        "DocumentContentHistoryEntries",
+        "$entryIds: [String!]!",
+        selection,
+        variables,
+        "documentContentHistoryEntries",
+    )
+}
+/// One specific cycle, looked up by ID or slug.
+///
+/// Full type: [`Cycle`](super::types::Cycle)
+pub fn cycle<T: DeserializeOwned + GraphQLFields<FullType = super::types::Cycle>>(
+    id: impl Into<super::ids::CycleId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::CycleId > ::into(id) }
+    );
+    let selection = format!("{} {{ {} }}", "cycle(id: $id)", T::selection());
+    Operation::query("Cycle", "$id: String!", selection, variables, "cycle")
+}
+/// One specific customer tier.
+///
+/// Full type: [`CustomerTier`](super::types::CustomerTier)
+///
+/// Requires the `customers` feature.
+#[cfg(feature = "customers")]
+pub fn customer_tier<T: DeserializeOwned + GraphQLFields<FullType = super::types::CustomerTier>>(
+    id: impl Into<super::ids::CustomerTierId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::CustomerTierId > ::into(id) }
+    );
+    let selection = format!("{} {{ {} }}", "customerTier(id: $id)", T::selection());
+    Operation::query(
+        "CustomerTier",
+        "$id: String!",
+        selection,
+        variables,
+        "customerTier",
+    )
+}
+/// One specific customer status.
+///
+/// Full type: [`CustomerStatus`](super::types::CustomerStatus)
+///
+/// Requires the `customers` feature.
+#[cfg(feature = "customers")]
+pub fn customer_status<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::CustomerStatus>,
+>(
+    id: impl Into<super::ids::CustomerStatusId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::CustomerStatusId > ::into(id) }
+    );
+    let selection = format!("{} {{ {} }}", "customerStatus(id: $id)", T::selection());
+    Operation::query(
+        "CustomerStatus",
+        "$id: String!",
+        selection,
+        variables,
+        "customerStatus",
+    )
+}
+/// Retrieves a single customer by ID or slug.
+///
+/// Full type: [`Customer`](super::types::Customer)
+///
+/// Requires the `customers` feature.
+#[cfg(feature = "customers")]
+pub fn customer<T: DeserializeOwned + GraphQLFields<FullType = super::types::Customer>>(
+    id: impl Into<super::ids::CustomerId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::CustomerId > ::into(id) }
+    );
+    let selection = format!("{} {{ {} }}", "customer(id: $id)", T::selection());
+    Operation::query("Customer", "$id: String!", selection, variables, "customer")
+}
+/// Suggests an issue title based on a customer request message using AI summarization.
+///
+/// Full type: [`IssueTitleSuggestionFromCustomerRequestPayload`](super::types::IssueTitleSuggestionFromCustomerRequestPayload)
+///
+/// Requires the `customers` feature.
+#[cfg(feature = "customers")]
+pub fn issue_title_suggestion_from_customer_request<
+    T: DeserializeOwned
+        + GraphQLFields<FullType = super::types::IssueTitleSuggestionFromCustomerRequestPayload>,
+>(
+    request: String,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "request" : request });
+    let selection = format!(
+        "{} {{ {} }}",
+        "issueTitleSuggestionFromCustomerRequest(request: $request)",
+        T::selection()
+    );
+    Operation::query(
+        "IssueTitleSuggestionFromCustomerRequest",
+        "$request: String!",
+        selection,
+        variables,
+        "issueTitleSuggestionFromCustomerRequest",
+    )
+}
+/// One specific custom view, looked up by ID or slug.
+///
+/// Full type: [`CustomView`](super::types::CustomView)
+///
+/// Requires the `views` feature.
+#[cfg(feature = "views")]
+pub fn custom_view<T: DeserializeOwned + GraphQLFields<FullType = super::types::CustomView>>(
+    id: impl Into<super::ids::CustomViewId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::CustomViewId > ::into(id) }
+    );
+    let selection = format!("{} {{ {} }}", "customView(id: $id)", T::selection());
+    Operation::query(
+        "CustomView",
+        "$id: String!",
+        selection,
+        variables,
+        "customView",
+    )
+}
+/// Whether a custom view has active notification subscribers other than the current user. Useful for warning before deleting a shared view.
+///
+/// Full type: [`CustomViewHasSubscribersPayload`](super::types::CustomViewHasSubscribersPayload)
+///
+/// Requires the `views` feature.
+#[cfg(feature = "views")]
+pub fn custom_view_has_subscribers<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::CustomViewHasSubscribersPayload>,
+>(
+    id: impl Into<super::ids::CustomViewId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::CustomViewId > ::into(id) }
+    );
+    let selection = format!(
+        "{} {{ {} }}",
+        "customViewHasSubscribers(id: $id)",
+        T::selection()
+    );
+    Operation::query(
+        "CustomViewHasSubscribers",
+        "$id: String!",
+        selection,
+        variables,
+        "customViewHasSubscribers",
+    )
+}
+/// Fetch users belonging to this user account.
+///
+/// Full type: [`AuthResolverResponse`](super::types::AuthResolverResponse)
+///
+/// Requires the `users` feature.
+#[cfg(feature = "users")]
+pub fn available_users<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::AuthResolverResponse>,
+>() -> Operation<T> {
+    let variables = serde_json::json!({});
+    let selection = format!("{} {{ {} }}", "availableUsers", T::selection());
+    Operation::query("AvailableUsers", "", selection, variables, "availableUsers")
+}
+/// User's active sessions.
+///
+/// Full type: [`AuthenticationSessionResponse`](super::types::AuthenticationSessionResponse)
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub fn authentication_sessions<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::AuthenticationSessionResponse>,
+>() -> Operation<Vec<T>> {
+    let variables = serde_json::json!({});
+    let selection = format!("{} {{ {} }}", "authenticationSessions", T::selection());
+    Operation::query(
+        "AuthenticationSessions",
+        "",
+        selection,
+        variables,
+        "authenticationSessions",
+    )
+}
+/// List of audit entry types.
+///
+/// Full type: [`AuditEntryType`](super::types::AuditEntryType)
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub fn audit_entry_types<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::AuditEntryType>,
+>() -> Operation<Vec<T>> {
+    let variables = serde_json::json!({});
+    let selection = format!("{} {{ {} }}", "auditEntryTypes", T::selection());
+    Operation::query(
+        "AuditEntryTypes",
+        "",
+        selection,
+        variables,
+        "auditEntryTypes",
+    )
+}
+/// One specific issue attachment.
+/// `Deprecated` 'url' can no longer be used as the 'id' parameter. Use 'attachmentsForUrl' instead
+///
+/// Full type: [`Attachment`](super::types::Attachment)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub fn attachment<T: DeserializeOwned + GraphQLFields<FullType = super::types::Attachment>>(
+    id: impl Into<super::ids::AttachmentId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::AttachmentId > ::into(id) }
+    );
+    let selection = format!("{} {{ {} }}", "attachment(id: $id)", T::selection());
+    Operation::query(
+        "Attachment",
+        "$id: String!",
+        selection,
+        variables,
+        "attachment",
+    )
+}
+/// Query an issue by its associated attachment, and its id.
+///
+/// Full type: [`Issue`](super::types::Issue)
+#[deprecated(
+    note = "Will be removed in near future, please use `attachmentsForURL` to get attachments and their issues instead."
+)]
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub fn attachment_issue<T: DeserializeOwned + GraphQLFields<FullType = super::types::Issue>>(
+    id: impl Into<super::ids::IssueId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::IssueId > ::into(id) }
+    );
+    let selection = format!("{} {{ {} }}", "attachmentIssue(id: $id)", T::selection());
+    Operation::query(
+        "AttachmentIssue",
+        "$id: String!",
+        selection,
+        variables,
+        "attachmentIssue",
+    )
+}
+/// Retrieves public information about an OAuth application by its client ID. Used during the authorization flow to display application details to the user.
+///
+/// Full type: [`Application`](super::types::Application)
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub fn application_info<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::Application>,
+>(
+    client_id: String,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "clientId" : client_id });
+    let selection = format!(
+        "{} {{ {} }}",
+        "applicationInfo(clientId: $clientId)",
+        T::selection()
+    );
+    Operation::query(
+        "ApplicationInfo",
+        "$clientId: String!",
+        selection,
+        variables,
+        "applicationInfo",
+    )
+}
+/// A specific agent session.
+///
+/// Full type: [`AgentSession`](super::types::AgentSession)
+///
+/// Requires the `agents` feature.
+#[cfg(feature = "agents")]
+pub fn agent_session<T: DeserializeOwned + GraphQLFields<FullType = super::types::AgentSession>>(
+    id: impl Into<super::ids::AgentSessionId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::AgentSessionId > ::into(id) }
+    );
+    let selection = format!("{} {{ {} }}", "agentSession(id: $id)", T::selection());
+    Operation::query(
+        "AgentSession",
+        "$id: String!",
+        selection,
+        variables,
+        "agentSession",
+    )
+}
+/// `Internal` Retrieves coding agent sandbox details for a given agent session ID.
+///
+/// Full type: [`CodingAgentSandboxPayload`](super::types::CodingAgentSandboxPayload)
+///
+/// Requires the `agents` feature.
+#[cfg(feature = "agents")]
+pub fn agent_session_sandbox<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::CodingAgentSandboxPayload>,
+>(
+    agent_session_id: String,
+) -> Operation<Option<T>> {
+    let variables = serde_json::json!({ "agentSessionId" : agent_session_id });
+    let selection = format!(
+        "{} {{ {} }}",
+        "agentSessionSandbox(agentSessionId: $agentSessionId)",
+        T::selection()
+    );
+    Operation::query(
+        "AgentSessionSandbox",
+        "$agentSessionId: String!",
+        selection,
+        variables,
+        "agentSessionSandbox",
+    )
+}
+/// A specific agent activity.
+///
+/// Full type: [`AgentActivity`](super::types::AgentActivity)
+///
+/// Requires the `agents` feature.
+#[cfg(feature = "agents")]
+pub fn agent_activity<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::AgentActivity>,
+>(
+    id: impl Into<super::ids::AgentActivityId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::AgentActivityId > ::into(id) }
+    );
+    let selection = format!("{} {{ {} }}", "agentActivity(id: $id)", T::selection());
+    Operation::query(
+        "AgentActivity",
+        "$id: String!",
+        selection,
+        variables,
+        "agentActivity",
+    )
+}
+/// XHR request payload to upload an images, video and other attachments directly to Linear's cloud storage.
+pub fn file_upload(
+    meta_data: Option<serde_json::Value>,
+    make_public: Option<bool>,
+    size: i64,
+    content_type: String,
+    filename: String,
+) -> Operation<serde_json::Value> {
+    let variables = serde_json::json!(
+        { "metaData" : meta_data, "makePublic" : make_public, "size" : size,
+        "contentType" : content_type, "filename" : filename }
+    );
+    let mut response_parts: Vec<String> = vec!["lastSyncId".to_string(), "success".to_string()];
+    response_parts.push(format!(
+        "{} {{ {} }}",
+        "uploadFile",
+        <super::types::UploadFile as crate::field_selection::GraphQLFields>::selection()
+    ));
+    let selection = String::from(
+        "fileUpload(metaData: $metaData, makePublic: $makePublic, size: $size, contentType: $contentType, filename: $filename) { ",
+    ) + &response_parts.join(" ") + " }";
+    Operation::mutation(
+        "FileUpload",
+        "$metaData: JSON, $makePublic: Boolean, $size: Int!, $contentType: String!, $filename: String!",
+        selection,
+        variables,
+        "fileUpload",
+    )
+}
+/// XHR request payload to upload a file for import, directly to Linear's cloud storage.
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub fn import_file_upload(
+    meta_data: Option<serde_json::Value>,
+    size: i64,
+    content_type: String,
+    filename: String,
+) -> Operation<serde_json::Value> {
+    let variables = serde_json::json!(
+        { "metaData" : meta_data, "size" : size, "contentType" : content_type, "filename"
+        : filename }
+    );
+    let mut response_parts: Vec<String> = vec!["lastSyncId".to_string(), "success".to_string()];
+    response_parts.push(format!(
+        "{} {{ {} }}",
+        "uploadFile",
+        <super::types::UploadFile as crate::field_selection::GraphQLFields>::selection()
+    ));
+    let selection = String::from(
+        "importFileUpload(metaData: $metaData, size: $size, contentType: $contentType, filename: $filename) { ",
+    ) + &response_parts.join(" ") + " }";
+    Operation::mutation(
+        "ImportFileUpload",
+        "$metaData: JSON, $size: Int!, $contentType: String!, $filename: String!",
+        selection,
+        variables,
+        "importFileUpload",
+    )
+}
+/// Upload an image from an URL to Linear.
+pub fn image_upload_from_url(url: String) -> Operation<super::types::ImageUploadFromUrlPayload> {
+    let variables = serde_json::json!({ "url" : url });
+    let selection = String::from("imageUploadFromUrl(url: $url) { ")
+        + &<super::types::ImageUploadFromUrlPayload as crate::field_selection::GraphQLFields>::selection()
+        + " }";
+    Operation::mutation(
+        "ImageUploadFromUrl",
+        "$url: String!",
+        selection,
+        variables,
+        "imageUploadFromUrl",
+    )
+}
+/// `INTERNAL` Permanently delete an uploaded file by asset URL. This should be used as a last resort and will break comments and documents that reference the asset.
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub fn file_upload_dangerously_delete(
+    asset_url: String,
+) -> Operation<super::types::FileUploadDeletePayload> {
+    let variables = serde_json::json!({ "assetUrl" : asset_url });
+    let selection = String::from("fileUploadDangerouslyDelete(assetUrl: $assetUrl) { ")
+        + &<super::types::FileUploadDeletePayload as crate::field_selection::GraphQLFields>::selection()
+        + " }";
+    Operation::mutation(
+        "FileUploadDangerouslyDelete",
+        "$assetUrl: String!",
+        selection,
+        variables,
+        "fileUploadDangerouslyDelete",
+    )
+}
+/// Creates a new state, adding it to the workflow of a team.
+///
+/// Full type: [`WorkflowState`](super::types::WorkflowState)
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+pub fn workflow_state_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::WorkflowState>,
+>(
+    input: WorkflowStateCreateInput,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "input" : input });
+    let selection =
+        String::from("workflowStateCreate(input: $input) { success lastSyncId workflowState { ")
+            + &T::selection()
+            + " } }";
+    Operation::mutation(
+        "WorkflowStateCreate",
+        "$input: WorkflowStateCreateInput!",
+        selection,
+        variables,
+        "workflowStateCreate",
+    )
+    .with_entity_field("workflowState")
+    .with_client_id(
+        "input",
+        String::from("workflowState(id: $id) { ") + &T::selection() + " }",
+    )
+}
+/// Updates a state.
+///
+/// Full type: [`WorkflowState`](super::types::WorkflowState)
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+pub fn workflow_state_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::WorkflowState>,
+>(
+    input: WorkflowStateUpdateInput,
+    id: impl Into<super::ids::WorkflowStateId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "input" : input, "id" : Into:: < super::ids::WorkflowStateId > ::into(id) }
+    );
+    let selection = String::from(
+        "workflowStateUpdate(input: $input, id: $id) { success lastSyncId workflowState { ",
+    ) + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "WorkflowStateUpdate",
+        "$input: WorkflowStateUpdateInput!, $id: String!",
+        selection,
+        variables,
+        "workflowStateUpdate",
+    )
+    .with_entity_field("workflowState")
+}
+/// Archives a state. Only states with issues that have all been archived can be archived.
+///
+/// Full type: [`WorkflowState`](super::types::WorkflowState)
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+pub fn workflow_state_archive<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::WorkflowState>,
+>(
+    id: impl Into<super::ids::WorkflowStateId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::WorkflowStateId > ::into(id) }
+    );
+    let selection = String::from("workflowStateArchive(id: $id) { success lastSyncId entity { ")
+        + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "WorkflowStateArchive",
+        "$id: String!",
+        selection,
+        variables,
+        "workflowStateArchive",
+    )
+    .with_entity_field("entity")
+}
+/// Creates a new webhook subscription for the workspace. Requires specifying a URL, resource types to subscribe to, and either a specific team or all public teams.
+///
+/// Full type: [`Webhook`](super::types::Webhook)
+///
+/// Requires the `webhooks` feature.
+#[cfg(feature = "webhooks")]
+pub fn webhook_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Webhook>,
+>(
+    input: WebhookCreateInput,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "input" : input });
+    let selection = String::from("webhookCreate(input: $input) { success lastSyncId webhook { ")
+        + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "WebhookCreate",
+        "$input: WebhookCreateInput!",
+        selection,
+        variables,
+        "webhookCreate",
+    )
+    .with_entity_field("webhook")
+    .with_client_id(
+        "input",
+        String::from("webhook(id: $id) { ") + &T::selection() + " }",
+    )
+}
+/// Updates an existing Webhook.
+///
+/// Full type: [`Webhook`](super::types::Webhook)
+///
+/// Requires the `webhooks` feature.
+#[cfg(feature = "webhooks")]
+pub fn webhook_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Webhook>,
+>(
+    input: WebhookUpdateInput,
+    id: impl Into<super::ids::WebhookId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "input" : input, "id" : Into:: < super::ids::WebhookId > ::into(id) }
+    );
+    let selection =
+        String::from("webhookUpdate(input: $input, id: $id) { success lastSyncId webhook { ")
+            + &T::selection()
+            + " } }";
+    Operation::mutation(
+        "WebhookUpdate",
+        "$input: WebhookUpdateInput!, $id: String!",
+        selection,
+        variables,
+        "webhookUpdate",
+    )
+    .with_entity_field("webhook")
+}
+/// Deletes a Webhook.
+///
+/// Requires the `webhooks` feature.
+#[cfg(feature = "webhooks")]
+pub fn webhook_delete(
+    id: impl Into<super::ids::WebhookId>,
+) -> Operation<super::types::DeletePayload> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::WebhookId > ::into(id) }
+    );
+    let selection = String::from("webhookDelete(id: $id) { ")
+        + &<super::types::DeletePayload as crate::field_selection::GraphQLFields>::selection()
+        + " }";
+    Operation::mutation(
+        "WebhookDelete",
+        "$id: String!",
+        selection,
+        variables,
+        "webhookDelete",
+    )
+}
+/// Rotates the signing secret for a webhook, generating a new HMAC-SHA256 key and returning it. The old secret is immediately invalidated.
+///
+/// Requires the `webhooks` feature.
+#[cfg(feature = "webhooks")]
+pub fn webhook_rotate_secret(
+    id: impl Into<super::ids::WebhookId>,
+) -> Operation<super::types::WebhookRotateSecretPayload> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::WebhookId > ::into(id) }
+    );
+    let selection = String::from("webhookRotateSecret(id: $id) { ")
+        + &<super::types::WebhookRotateSecretPayload as crate::field_selection::GraphQLFields>::selection()
+        + " }";
+    Operation::mutation(
+        "WebhookRotateSecret",
+        "$id: String!",
+        selection,
+        variables,
+        "webhookRotateSecret",
+    )
+}
+/// Creates a new view preferences object. If conflicting preferences already exist for the same view type and scope, the existing preferences are replaced.
+///
+/// Full type: [`ViewPreferences`](super::types::ViewPreferences)
+///
+/// Requires the `views` feature.
+#[cfg(feature = "views")]
+pub fn view_preferences_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::ViewPreferences>,
+>(
+    input: ViewPreferencesCreateInput,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "input" : input });
+    let selection = String::from(
+        "viewPreferencesCreate(input: $input) { success lastSyncId viewPreferences { ",
+    ) + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "ViewPreferencesCreate",
+        "$input: ViewPreferencesCreateInput!",
+        selection,
+        variables,
+        "viewPreferencesCreate",
+    )
+    .with_entity_field("viewPreferences")
+}
+/// Updates an existing view preferences object. For user-type preferences, only the owning user can update them.
+///
+/// Full type: [`ViewPreferences`](super::types::ViewPreferences)
+///
+/// Requires the `views` feature.
+#[cfg(feature = "views")]
+pub fn view_preferences_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::ViewPreferences>,
+>(
+    input: ViewPreferencesUpdateInput,
+    id: impl Into<super::ids::ViewPreferencesId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "input" : input, "id" : Into:: < super::ids::ViewPreferencesId > ::into(id) }
+    );
+    let selection = String::from(
+        "viewPreferencesUpdate(input: $input, id: $id) { success lastSyncId viewPreferences { ",
+    ) + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "ViewPreferencesUpdate",
+        "$input: ViewPreferencesUpdateInput!, $id: String!",
+        selection,
+        variables,
+        "viewPreferencesUpdate",
+    )
+    .with_entity_field("viewPreferences")
+}
+/// Deletes a view preferences object. If the preferences do not exist, the operation is treated as a successful idempotent deletion.
+///
+/// Requires the `views` feature.
+#[cfg(feature = "views")]
+pub fn view_preferences_delete(
+    id: impl Into<super::ids::ViewPreferencesId>,
+) -> Operation<super::types::DeletePayload> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::ViewPreferencesId > ::into(id) }
+    );
+    let selection = String::from("viewPreferencesDelete(id: $id) { ")
+        + &<super::types::DeletePayload as crate::field_selection::GraphQLFields>::selection()
+        + " }";
+    Operation::mutation(
+        "ViewPreferencesDelete",
+        "$id: String!",
+        selection,
+        variables,
+        "viewPreferencesDelete",
+    )
+}
+/// Updates the authenticated user's settings, including notification preferences, email subscriptions, theme, and other UI preferences.
+///
+/// Full type: [`UserSettings`](super::types::UserSettings)
+///
+/// Requires the `users` feature.
+#[cfg(feature = "users")]
+pub fn user_settings_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::UserSettings>,
+>(
+    input: UserSettingsUpdateInput,
+    id: impl Into<super::ids::UserSettingsId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "input" : input, "id" : Into:: < super::ids::UserSettingsId > ::into(id) }
+    );
+    let selection = String::from(
+        "userSettingsUpdate(input: $input, id: $id) { success lastSyncId userSettings { ",
+    ) + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "UserSettingsUpdate",
+        "$input: UserSettingsUpdateInput!, $id: String!",
+        selection,
+        variables,
+        "userSettingsUpdate",
+    )
+    .with_entity_field("userSettings")
+}
+/// Resets one or more of the user's setting flags. If no specific flags are provided, all flags are reset.
+///
+/// Requires the `users` feature.
+#[cfg(feature = "users")]
+pub fn user_settings_flags_reset(
+    flags: Option<Vec<UserFlagType>>,
+) -> Operation<super::types::UserSettingsFlagsResetPayload> {
+    let variables = serde_json::json!({ "flags" : flags });
+    let selection = String::from("userSettingsFlagsReset(flags: $flags) { ")
+        + &<super::types::UserSettingsFlagsResetPayload as crate::field_selection::GraphQLFields>::selection()
+        + " }";
+    Operation::mutation(
+        "UserSettingsFlagsReset",
+        "$flags: [UserFlagType!]",
+        selection,
+        variables,
+        "userSettingsFlagsReset",
+    )
+}
+/// Updates a specific user settings flag by performing an operation (e.g., increment or clear) on it.
+///
+/// Requires the `users` feature.
+#[cfg(feature = "users")]
+pub fn user_flag_update(
+    operation: UserFlagUpdateOperation,
+    flag: UserFlagType,
+) -> Operation<super::types::UserSettingsFlagPayload> {
+    let variables = serde_json::json!({ "operation" : operation, "flag" : flag });
+    let selection = String::from("userFlagUpdate(operation: $operation, flag: $flag) { ")
+        + &<super::types::UserSettingsFlagPayload as crate::field_selection::GraphQLFields>::selection()
+        + " }";
+    Operation::mutation(
+        "UserFlagUpdate",
+        "$operation: UserFlagUpdateOperation!, $flag: UserFlagType!",
+        selection,
+        variables,
+        "userFlagUpdate",
+    )
+}
+/// Subscribes to or unsubscribes from a specific notification category for a given notification channel. For example, subscribe to 'issueAssignment' notifications via the 'email' channel.
+///
+/// Full type: [`UserSettings`](super::types::UserSettings)
+///
+/// Requires the `notifications` feature.
+#[cfg(feature = "notifications")]
+pub fn notification_category_channel_subscription_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::UserSettings>,
+>(
+    channel: NotificationChannel,
+    category: NotificationCategory,
+    subscribe: bool,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "channel" : channel, "category" : category, "subscribe" : subscribe }
+    );
+    let selection = String::from(
+        "notificationCategoryChannelSubscriptionUpdate(channel: $channel, category: $category, subscribe: $subscribe) { success lastSyncId userSettings { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+        "NotificationCategoryChannelSubscriptionUpdate",
+        "$channel: NotificationChannel!, $category: NotificationCategory!, $subscribe: Boolean!",
+        selection,
+        variables,
+        "notificationCategoryChannelSubscriptionUpdate",
+    )
+    .with_entity_field("userSettings")
+}
+/// Updates a user's profile information. Users can update their own profile; workspace admins can update any user's profile. SCIM-managed users may have restricted name changes.
+///
+/// Full type: [`User`](super::types::User)
+///
+/// Requires the `users` feature.
+#[cfg(feature = "users")]
+pub fn user_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::User>,
+>(
+    input: UserUpdateInput,
+    id: impl Into<super::ids::UserId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "input" : input, "id" : Into:: < super::ids::UserId > ::into(id) }
+    );
+    let selection = String::from("userUpdate(input: $input, id: $id) { success lastSyncId user { ")
+        + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "UserUpdate",
+        "$input: UserUpdateInput!, $id: String!",
+        selection,
+        variables,
+        "userUpdate",
+    )
+    .with_entity_field("user")
+}
+/// Connects the Discord user to this Linear account via OAuth2.
+///
+/// Full type: [`User`](super::types::User)
+///
+/// Requires the `users` feature.
+#[cfg(feature = "users")]
+pub fn user_discord_connect<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::User>,
+>(
+    redirect_uri: String,
+    code: String,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "redirectUri" : redirect_uri, "code" : code });
+    let selection = String::from(
+        "userDiscordConnect(redirectUri: $redirectUri, code: $code) { success lastSyncId user { ",
+    ) + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "UserDiscordConnect",
+        "$redirectUri: String!, $code: String!",
+        selection,
+        variables,
+        "userDiscordConnect",
+    )
+    .with_entity_field("user")
+}
+/// Disconnects the external user from this Linear account.
+///
+/// Full type: [`User`](super::types::User)
+///
+/// Requires the `users` feature.
+#[cfg(feature = "users")]
+pub fn user_external_user_disconnect<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::User>,
+>(
+    service: String,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "service" : service });
+    let selection =
+        String::from("userExternalUserDisconnect(service: $service) { success lastSyncId user { ")
+            + &T::selection()
+            + " } }";
+    Operation::mutation(
+        "UserExternalUserDisconnect",
+        "$service: String!",
+        selection,
+        variables,
+        "userExternalUserDisconnect",
+    )
+    .with_entity_field("user")
+}
+/// Changes the workspace role of a user. The requesting user must have a role equal to or higher than the target role. Requires workspace admin permissions.
+///
+/// Requires the `users` feature.
+#[cfg(feature = "users")]
+pub fn user_change_role(
+    role: UserRoleType,
+    id: impl Into<super::ids::UserId>,
+) -> Operation<super::types::UserAdminPayload> {
+    let variables = serde_json::json!(
+        { "role" : role, "id" : Into:: < super::ids::UserId > ::into(id) }
+    );
+    let selection = String::from("userChangeRole(role: $role, id: $id) { ")
+        + &<super::types::UserAdminPayload as crate::field_selection::GraphQLFields>::selection()
+        + " }";
+    Operation::mutation(
+        "UserChangeRole",
+        "$role: UserRoleType!, $id: String!",
+        selection,
+        variables,
+        "userChangeRole",
+    )
+}
+/// Suspends a user, deactivating their account and revoking access to the workspace. The suspended user's sessions are invalidated. Can only be called by a workspace admin or owner.
+///
+/// Requires the `users` feature.
+#[cfg(feature = "users")]
+pub fn user_suspend(
+    force_bypass_scim_restrictions: Option<bool>,
+    id: impl Into<super::ids::UserId>,
+) -> Operation<super::types::UserAdminPayload> {
+    let variables = serde_json::json!(
+        { "forceBypassScimRestrictions" : force_bypass_scim_restrictions, "id" : Into:: <
+        super::ids::UserId > ::into(id) }
+    );
+    let selection = String::from(
+        "userSuspend(forceBypassScimRestrictions: $forceBypassScimRestrictions, id: $id) { ",
+    )
+        + &<super::types::UserAdminPayload as crate::field_selection::GraphQLFields>::selection()
+        + " }";
+    Operation::mutation(
+        "UserSuspend",
+        "$forceBypassScimRestrictions: Boolean, $id: String!",
+        selection,
+        variables,
+        "userSuspend",
+    )
+}
+/// Revokes all active sessions for a user, forcing them to re-authenticate. Can only be called by a workspace admin or owner.
+///
+/// Requires the `users` feature.
+#[cfg(feature = "users")]
+pub fn user_revoke_all_sessions(
+    id: impl Into<super::ids::UserId>,
+) -> Operation<super::types::UserAdminPayload> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::UserId > ::into(id) }
+    );
+    let selection = String::from("userRevokeAllSessions(id: $id) { ")
+        + &<super::types::UserAdminPayload as crate::field_selection::GraphQLFields>::selection()
+        + " }";
+    Operation::mutation(
+        "UserRevokeAllSessions",
+        "$id: String!",
+        selection,
+        variables,
+        "userRevokeAllSessions",
+    )
+}
+/// Revokes a specific authentication session for a user. The admin cannot revoke their own sessions with this mutation. Can only be called by a workspace admin or owner.
+///
+/// Requires the `users` feature.
+#[cfg(feature = "users")]
+pub fn user_revoke_session(
+    session_id: String,
+    id: impl Into<super::ids::UserId>,
+) -> Operation<super::types::UserAdminPayload> {
+    let variables = serde_json::json!(
+        { "sessionId" : session_id, "id" : Into:: < super::ids::UserId > ::into(id) }
+    );
+    let selection = String::from("userRevokeSession(sessionId: $sessionId, id: $id) { ")
+        + &<super::types::UserAdminPayload as crate::field_selection::GraphQLFields>::selection()
+        + " }";
+    Operation::mutation(
+        "UserRevokeSession",
+        "$sessionId: String!, $id: String!",
+        selection,
+        variables,
+        "userRevokeSession",
+    )
+}
+/// Re-activates a suspended user, restoring their access to the workspace. Can only be called by a workspace admin or owner.
+///
+/// Requires the `users` feature.
+#[cfg(feature = "users")]
+pub fn user_unsuspend(
+    force_bypass_scim_restrictions: Option<bool>,
+    id: impl Into<super::ids::UserId>,
+) -> Operation<super::types::UserAdminPayload> {
+    let variables = serde_json::json!(
+        { "forceBypassScimRestrictions" : force_bypass_scim_restrictions, "id" : Into:: <
+        super::ids::UserId > ::into(id) }
+    );
+    let selection = String::from(
+        "userUnsuspend(forceBypassScimRestrictions: $forceBypassScimRestrictions, id: $id) { ",
+    )
+        + &<super::types::UserAdminPayload as crate::field_selection::GraphQLFields>::selection()
+        + " }";
+    Operation::mutation(
+        "UserUnsuspend",
+        "$forceBypassScimRestrictions: Boolean, $id: String!",
+        selection,
+        variables,
+        "userUnsuspend",
+    )
+}
+/// Unlinks a guest user from their identity provider. Can only be called by an admin when SCIM is enabled.
+///
+/// Requires the `users` feature.
+#[cfg(feature = "users")]
+pub fn user_unlink_from_identity_provider(
+    id: impl Into<super::ids::UserId>,
+) -> Operation<super::types::UserAdminPayload> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::UserId > ::into(id) }
+    );
+    let selection = String::from("userUnlinkFromIdentityProvider(id: $id) { ")
+        + &<super::types::UserAdminPayload as crate::field_selection::GraphQLFields>::selection()
+        + " }";
+    Operation::mutation(
+        "UserUnlinkFromIdentityProvider",
+        "$id: String!",
+        selection,
+        variables,
+        "userUnlinkFromIdentityProvider",
+    )
+}
+/// Creates a new triage responsibility.
+///
+/// Full type: [`TriageResponsibility`](super::types::TriageResponsibility)
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+pub fn triage_responsibility_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::TriageResponsibility>,
+>(
+    input: TriageResponsibilityCreateInput,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "input" : input });
+    let selection = String::from(
+        "triageResponsibilityCreate(input: $input) { success lastSyncId triageResponsibility { ",
+    ) + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "TriageResponsibilityCreate",
+        "$input: TriageResponsibilityCreateInput!",
+        selection,
+        variables,
+        "triageResponsibilityCreate",
+    )
+    .with_entity_field("triageResponsibility")
+    .with_client_id(
+        "input",
+        String::from("triageResponsibility(id: $id) { ") + &T::selection() + " }",
+    )
+}
+/// Updates an existing triage responsibility.
+///
+/// Full type: [`TriageResponsibility`](super::types::TriageResponsibility)
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+pub fn triage_responsibility_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::TriageResponsibility>,
+>(
+    input: TriageResponsibilityUpdateInput,
+    id: impl Into<super::ids::TriageResponsibilityId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "input" : input, "id" : Into:: < super::ids::TriageResponsibilityId >
+        ::into(id) }
+    );
+    let selection = String::from(
+        "triageResponsibilityUpdate(input: $input, id: $id) { success lastSyncId triageResponsibility { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+        "TriageResponsibilityUpdate",
+        "$input: TriageResponsibilityUpdateInput!, $id: String!",
+        selection,
+        variables,
+        "triageResponsibilityUpdate",
+    )
+    .with_entity_field("triageResponsibility")
+}
+/// Deletes a triage responsibility.
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+pub fn triage_responsibility_delete(
+    id: impl Into<super::ids::TriageResponsibilityId>,
+) -> Operation<super::types::DeletePayload> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::TriageResponsibilityId > ::into(id) }
+    );
+    let selection = String::from("triageResponsibilityDelete(id: $id) { ")
+        + &<super::types::DeletePayload as crate::field_selection::GraphQLFields>::selection()
+        + " }";
+    Operation::mutation(
+        "TriageResponsibilityDelete",
+        "$id: String!",
+        selection,
+        variables,
+        "triageResponsibilityDelete",
+    )
+}
+/// Creates a new time schedule.
+///
+/// Full type: [`TimeSchedule`](super::types::TimeSchedule)
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+pub fn time_schedule_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::TimeSchedule>,
+>(
+    input: TimeScheduleCreateInput,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "input" : input });
+    let selection =
+        String::from("timeScheduleCreate(input: $input) { success lastSyncId timeSchedule { ")
+            + &T::selection()
+            + " } }";
+    Operation::mutation(
+        "TimeScheduleCreate",
+        "$input: TimeScheduleCreateInput!",
+        selection,
+        variables,
+        "timeScheduleCreate",
+    )
+    .with_entity_field("timeSchedule")
+    .with_client_id(
+        "input",
+        String::from("timeSchedule(id: $id) { ") + &T::selection() + " }",
+    )
+}
+/// Updates a time schedule.
+///
+/// Full type: [`TimeSchedule`](super::types::TimeSchedule)
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+pub fn time_schedule_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::TimeSchedule>,
+>(
+    input: TimeScheduleUpdateInput,
+    id: impl Into<super::ids::TimeScheduleId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "input" : input, "id" : Into:: < super::ids::TimeScheduleId > ::into(id) }
+    );
+    let selection = String::from(
+        "timeScheduleUpdate(input: $input, id: $id) { success lastSyncId timeSchedule { ",
+    ) + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "TimeScheduleUpdate",
+        "$input: TimeScheduleUpdateInput!, $id: String!",
+        selection,
+        variables,
+        "timeScheduleUpdate",
+    )
+    .with_entity_field("timeSchedule")
+}
+/// Upsert an external time schedule.
+///
+/// Full type: [`TimeSchedule`](super::types::TimeSchedule)
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+pub fn time_schedule_upsert_external<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::TimeSchedule>,
+>(
+    input: TimeScheduleUpdateInput,
+    external_id: String,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "input" : input, "externalId" : external_id });
+    let selection = String::from(
+        "timeScheduleUpsertExternal(input: $input, externalId: $externalId) { success lastSyncId timeSchedule { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+        "TimeScheduleUpsertExternal",
+        "$input: TimeScheduleUpdateInput!, $externalId: String!",
+        selection,
+        variables,
+        "timeScheduleUpsertExternal",
+    )
+    .with_entity_field("timeSchedule")
+}
+/// Deletes a time schedule.
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+pub fn time_schedule_delete(
+    id: impl Into<super::ids::TimeScheduleId>,
+) -> Operation<super::types::DeletePayload> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::TimeScheduleId > ::into(id) }
+    );
+    let selection = String::from("timeScheduleDelete(id: $id) { ")
+        + &<super::types::DeletePayload as crate::field_selection::GraphQLFields>::selection()
+        + " }";
+    Operation::mutation(
+        "TimeScheduleDelete",
+        "$id: String!",
+        selection,
+        variables,
+        "timeScheduleDelete",
+    )
+}
+/// Refresh the integration schedule information.
+///
+/// Full type: [`TimeSchedule`](super::types::TimeSchedule)
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+pub fn time_schedule_refresh_integration_schedule<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::TimeSchedule>,
+>(
+    id: impl Into<super::ids::TimeScheduleId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::TimeScheduleId > ::into(id) }
+    );
+    let selection = String::from(
+        "timeScheduleRefreshIntegrationSchedule(id: $id) { success lastSyncId timeSchedule { ",
+    ) + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "TimeScheduleRefreshIntegrationSchedule",
+        "$id: String!",
+        selection,
+        variables,
+        "timeScheduleRefreshIntegrationSchedule",
+    )
+    .with_entity_field("timeSchedule")
+}
+/// Creates a new template.
+///
+/// Full type: [`Template`](super::types::Template)
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+pub fn template_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Template>,
+>(
+    input: TemplateCreateInput,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "input" : input });
+    let selection = String::from("templateCreate(input: $input) { success lastSyncId template { ")
+        + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "TemplateCreate",
+        "$input: TemplateCreateInput!",
+        selection,
+        variables,
+        "templateCreate",
+    )
+    .with_entity_field("template")
+    .with_client_id(
+        "input",
+        String::from("template(id: $id) { ") + &T::selection() + " }",
+    )
+}
+/// Updates an existing template.
+///
+/// Full type: [`Template`](super::types::Template)
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+pub fn template_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Template>,
+>(
+    input: TemplateUpdateInput,
+    id: impl Into<super::ids::TemplateId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "input" : input, "id" : Into:: < super::ids::TemplateId > ::into(id) }
+    );
+    let selection =
+        String::from("templateUpdate(input: $input, id: $id) { success lastSyncId template { ")
+            + &T::selection()
+            + " } }";
+    Operation::mutation(
+        "TemplateUpdate",
+        "$input: TemplateUpdateInput!, $id: String!",
+        selection,
+        variables,
+        "templateUpdate",
+    )
+    .with_entity_field("template")
+}
+/// Deletes a template.
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+pub fn template_delete(
+    id: impl Into<super::ids::TemplateId>,
+) -> Operation<super::types::DeletePayload> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::TemplateId > ::into(id) }
+    );
+    let selection = String::from("templateDelete(id: $id) { ")
+        + &<super::types::DeletePayload as crate::field_selection::GraphQLFields>::selection()
+        + " }";
+    Operation::mutation(
+        "TemplateDelete",
+        "$id: String!",
+        selection,
+        variables,
+        "templateDelete",
+    )
+}
+/// Creates a new project.
+///
+/// Full type: [`Project`](super::types::Project)
+pub fn project_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Project>,
+>(
+    slack_channel_name: Option<String>,
+    input: ProjectCreateInput,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "slackChannelName" : slack_channel_name, "input" : input }
+    );
+    let selection = String::from(
+        "projectCreate(slackChannelName: $slackChannelName, input: $input) { success lastSyncId project { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+        "ProjectCreate",
+        "$slackChannelName: String, $input: ProjectCreateInput!",
+        selection,
+        variables,
+        "projectCreate",
+    )
+    .with_entity_field("project")
+    .with_client_id(
+        "input",
+        String::from("project(id: $id) { ") + &T::selection() + " }",
+    )
+}
+/// Updates a project.
+///
+/// Full type: [`Project`](super::types::Project)
+pub fn project_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Project>,
+>(
+    input: ProjectUpdateInput,
+    id: impl Into<super::ids::ProjectId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "input" : input, "id" : Into:: < super::ids::ProjectId > ::into(id) }
+    );
+    let selection =
+        String::from("projectUpdate(input: $input, id: $id) { success lastSyncId project { ")
+            + &T::selection()
+            + " } }";
+    Operation::mutation(
+        "ProjectUpdate",
+        "$input: ProjectUpdateInput!, $id: String!",
+        selection,
+        variables,
+        "projectUpdate",
+    )
+    .with_entity_field("project")
+}
+/// `Internal` Creates a Slack channel for an existing project.
+///
+/// Full type: [`Project`](super::types::Project)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub fn project_create_slack_channel<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Project>,
+>(
+    integration_id: Option<String>,
+    slack_channel_name: String,
+    id: impl Into<super::ids::ProjectId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "integrationId" : integration_id, "slackChannelName" : slack_channel_name, "id"
+        : Into:: < super::ids::ProjectId > ::into(id) }
+    );
+    let selection = String::from(
+        "projectCreateSlackChannel(integrationId: $integrationId, slackChannelName: $slackChannelName, id: $id) { success lastSyncId project { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+        "ProjectCreateSlackChannel",
+        "$integrationId: String, $slackChannelName: String!, $id: String!",
+        selection,
+        variables,
+        "projectCreateSlackChannel",
+    )
+    .with_entity_field("project")
+}
+/// `INTERNAL` Reassigns all projects from one project status to another. Used when archiving or deleting a project status.
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub fn project_reassign_status(
+    new_project_status_id: String,
+    original_project_status_id: String,
+) -> Operation<super::types::SuccessPayload> {
+    let variables = serde_json::json!(
+        { "newProjectStatusId" : new_project_status_id, "originalProjectStatusId" :
+        original_project_status_id }
+    );
+    let selection = String::from(
+        "projectReassignStatus(newProjectStatusId: $newProjectStatusId, originalProjectStatusId: $originalProjectStatusId) { ",
+    )
+        + &<super::types::SuccessPayload as crate::field_selection::GraphQLFields>::selection()
+        + " }";
+    Operation::mutation(
+        "ProjectReassignStatus",
+        "$newProjectStatusId: String!, $originalProjectStatusId: String!",
+        selection,
+        variables,
+        "projectReassignStatus",
+    )
+}
+/// Deletes (trashes) a project. The project can be restored later with projectUnarchive.
+///
+/// Full type: [`Project`](super::types::Project)
+pub fn project_delete<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Project>,
+>(
+    id: impl Into<super::ids::ProjectId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::ProjectId > ::into(id) }
+    );
+    let selection = String::from("projectDelete(id: $id) { success lastSyncId entity { ")
+        + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "ProjectDelete",
+        "$id: String!",
+        selection,
+        variables,
+        "projectDelete",
+    )
+    .with_entity_field("entity")
+}
+/// Archives a project.
+#[deprecated(note = "Deprecated in favor of projectDelete.")]
+///
+/// Full type: [`Project`](super::types::Project)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub fn project_archive<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Project>,
+>(
+    trash: Option<bool>,
+    id: impl Into<super::ids::ProjectId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "trash" : trash, "id" : Into:: < super::ids::ProjectId > ::into(id) }
+    );
+    let selection =
+        String::from("projectArchive(trash: $trash, id: $id) { success lastSyncId entity { ")
+            + &T::selection()
+            + " } }";
+    Operation::mutation(
+        "ProjectArchive",
+        "$trash: Boolean, $id: String!",
+        selection,
+        variables,
+        "projectArchive",
+    )
+    .with_entity_field("entity")
+}
+/// Restores a previously trashed or archived project.
+///
+/// Full type: [`Project`](super::types::Project)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub fn project_unarchive<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Project>,
+>(
+    id: impl Into<super::ids::ProjectId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::ProjectId > ::into(id) }
+    );
+    let selection = String::from("projectUnarchive(id: $id) { success lastSyncId entity { ")
+        + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "ProjectUnarchive",
+        "$id: String!",
+        selection,
+        variables,
+        "projectUnarchive",
+    )
+    .with_entity_field("entity")
+}
+/// Adds a label to a project.
+///
+/// Full type: [`Project`](super::types::Project)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub fn project_add_label<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Project>,
+>(
+    label_id: String,
+    id: impl Into<super::ids::ProjectId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "labelId" : label_id, "id" : Into:: < super::ids::ProjectId > ::into(id) }
+    );
+    let selection =
+        String::from("projectAddLabel(labelId: $labelId, id: $id) { success lastSyncId project { ")
+            + &T::selection()
+            + " } }";
+    Operation::mutation(
+        "ProjectAddLabel",
+        "$labelId: String!, $id: String!",
+        selection,
+        variables,
+        "projectAddLabel",
+    )
+    .with_entity_field("project")
+}
+/// Removes a label from a project.
+///
+/// Full type: [`Project`](super::types::Project)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub fn project_remove_label<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Project>,
+>(
+    label_id: String,
+    id: impl Into<super::ids::ProjectId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "labelId" : label_id, "id" : Into:: < super::ids::ProjectId > ::into(id) }
+    );
+    let selection = String::from(
+        "projectRemoveLabel(labelId: $labelId, id: $id) { success lastSyncId project { ",
+    ) + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "ProjectRemoveLabel",
+        "$labelId: String!, $id: String!",
+        selection,
+        variables,
+        "projectRemoveLabel",
+    )
+    .with_entity_field("project")
+}
+/// Disables external sync on a project.
+///
+/// Full type: [`Project`](super::types::Project)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub fn project_external_sync_disable<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Project>,
+>(
+    sync_source: ExternalSyncService,
+    project_id: String,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "syncSource" : sync_source, "projectId" : project_id }
+    );
+    let selection = String::from(
+        "projectExternalSyncDisable(syncSource: $syncSource, projectId: $projectId) { success lastSyncId project { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+        "ProjectExternalSyncDisable",
+        "$syncSource: ExternalSyncService!, $projectId: String!",
+        selection,
+        variables,
+        "projectExternalSyncDisable",
+    )
+    .with_entity_field("project")
+}
+/// Creates a new team. The user who creates the team will automatically be added as a member and owner of the newly created team. Default workflow states, labels, and other team resources are created alongside the team.
+///
+/// Full type: [`Team`](super::types::Team)
+pub fn team_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Team>,
+>(
+    copy_settings_from_team_id: Option<String>,
+    input: TeamCreateInput,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "copySettingsFromTeamId" : copy_settings_from_team_id, "input" : input }
+    );
+    let selection = String::from(
+        "teamCreate(copySettingsFromTeamId: $copySettingsFromTeamId, input: $input) { success lastSyncId team { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+        "TeamCreate",
+        "$copySettingsFromTeamId: String, $input: TeamCreateInput!",
+        selection,
+        variables,
+        "teamCreate",
+    )
+    .with_entity_field("team")
+    .with_client_id(
+        "input",
+        String::from("team(id: $id) { ") + &T::selection() + " }",
+    )
+}
+/// Updates a team's settings, properties, or configuration. Requires team owner or workspace admin permissions for most changes.
+///
+/// Full type: [`Team`](super::types::Team)
+pub fn team_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Team>,
+>(
+    mapping: Option<InheritanceEntityMapping>,
+    input: TeamUpdateInput,
+    id: impl Into<super::ids::TeamId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "mapping" : mapping, "input" : input, "id" : Into:: < super::ids::TeamId >
+        ::into(id) }
+    );
+    let selection = String::from(
+        "teamUpdate(mapping: $mapping, input: $input, id: $id) { success lastSyncId team { ",
+    ) + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "TeamUpdate",
+        "$mapping: InheritanceEntityMapping, $input: TeamUpdateInput!, $id: String!",
+        selection,
+        variables,
+        "teamUpdate",
+    )
+    .with_entity_field("team")
+}
+/// Archives a team and schedules its data for deletion. Requires team owner or workspace admin permissions.
+pub fn team_delete(id: impl Into<super::ids::TeamId>) -> Operation<super::types::DeletePayload> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::TeamId > ::into(id) }
+    );
+    let selection = String::from("teamDelete(id: $id) { ")
+        + &<super::types::DeletePayload as crate::field_selection::GraphQLFields>::selection()
+        + " }";
+    Operation::mutation(
+        "TeamDelete",
+        "$id: String!",
+        selection,
+        variables,
+        "teamDelete",
+    )
+}
+/// Unarchives a team and cancels deletion.
+///
+/// Full type: [`Team`](super::types::Team)
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+pub fn team_unarchive<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Team>,
+>(
+    id: impl Into<super::ids::TeamId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::TeamId > ::into(id) }
+    );
+    let selection = String::from("teamUnarchive(id: $id) { success lastSyncId entity { ")
+        + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "TeamUnarchive",
+        "$id: String!",
+        selection,
+        variables,
+        "teamUnarchive",
+    )
+    .with_entity_field("entity")
+}
+/// Deletes all cycle data for a team, disabling the cycles feature. This removes all cycles and their issue associations.
+///
+/// Full type: [`Team`](super::types::Team)
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+pub fn team_cycles_delete<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Team>,
+>(
+    id: impl Into<super::ids::TeamId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::TeamId > ::into(id) }
+    );
+    let selection = String::from("teamCyclesDelete(id: $id) { success lastSyncId team { ")
+        + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "TeamCyclesDelete",
+        "$id: String!",
+        selection,
+        variables,
+        "teamCyclesDelete",
+    )
+    .with_entity_field("team")
+}
+/// Creates a new team membership, adding a user to a team. Validates that the user is not already a member, the team is not archived or retired, and the requesting user has permission to add members.
+///
+/// Full type: [`TeamMembership`](super::types::TeamMembership)
+pub fn team_membership_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::TeamMembership>,
+>(
+    input: TeamMembershipCreateInput,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "input" : input });
+    let selection =
+        String::from("teamMembershipCreate(input: $input) { success lastSyncId teamMembership { ")
+            + &T::selection()
+            + " } }";
+    Operation::mutation(
+        "TeamMembershipCreate",
+        "$input: TeamMembershipCreateInput!",
+        selection,
+        variables,
+        "teamMembershipCreate",
+    )
+    .with_entity_field("teamMembership")
+    .with_client_id(
+        "input",
+        String::from("teamMembership(id: $id) { ") + &T::selection() + " }",
+    )
+}
+/// Updates a team membership, such as changing ownership status or sort order.
+///
+/// Full type: [`TeamMembership`](super::types::TeamMembership)
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+pub fn team_membership_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::TeamMembership>,
+>(
+    input: TeamMembershipUpdateInput,
+    id: impl Into<super::ids::TeamMembershipId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "input" : input, "id" : Into:: < super::ids::TeamMembershipId > ::into(id) }
+    );
+    let selection = String::from(
+        "teamMembershipUpdate(input: $input, id: $id) { success lastSyncId teamMembership { ",
+    ) + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "TeamMembershipUpdate",
+        "$input: TeamMembershipUpdateInput!, $id: String!",
+        selection,
+        variables,
+        "teamMembershipUpdate",
+    )
+    .with_entity_field("teamMembership")
+}
+/// Deletes a team membership, removing the user from the team. Users can remove their own membership, or team owners and workspace admins can remove other members.
+pub fn team_membership_delete(
+    also_leave_parent_teams: Option<bool>,
+    id: impl Into<super::ids::TeamMembershipId>,
+) -> Operation<super::types::DeletePayload> {
+    let variables = serde_json::json!(
+        { "alsoLeaveParentTeams" : also_leave_parent_teams, "id" : Into:: <
+        super::ids::TeamMembershipId > ::into(id) }
+    );
+    let selection = String::from(
+        "teamMembershipDelete(alsoLeaveParentTeams: $alsoLeaveParentTeams, id: $id) { ",
+    )
+        + &<super::types::DeletePayload as crate::field_selection::GraphQLFields>::selection()
+        + " }";
+    Operation::mutation(
+        "TeamMembershipDelete",
+        "$alsoLeaveParentTeams: Boolean, $id: String!",
+        selection,
+        variables,
+        "teamMembershipDelete",
+    )
+}
+/// Deletes a previously used team key. The active team key (the team's current key) cannot be deleted.
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+pub fn team_key_delete(
+    id: impl Into<super::ids::TeamId>,
+) -> Operation<super::types::DeletePayload> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::TeamId > ::into(id) }
+    );
+    let selection = String::from("teamKeyDelete(id: $id) { ")
+        + &<super::types::DeletePayload as crate::field_selection::GraphQLFields>::selection()
+        + " }";
+    Operation::mutation(
+        "TeamKeyDelete",
+        "$id: String!",
+        selection,
+        variables,
+        "teamKeyDelete",
+    )
+}
+/// `Deprecated` Creates a new roadmap-to-project association. Use InitiativeToProject instead.
+///
+/// Full type: [`RoadmapToProject`](super::types::RoadmapToProject)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub fn roadmap_to_project_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::RoadmapToProject>,
+>(
+    input: RoadmapToProjectCreateInput,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "input" : input });
+    let selection = String::from(
+        "roadmapToProjectCreate(input: $input) { success lastSyncId roadmapToProject { ",
+    ) + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "RoadmapToProjectCreate",
+        "$input: RoadmapToProjectCreateInput!",
+        selection,
+        variables,
+        "roadmapToProjectCreate",
+    )
+    .with_entity_field("roadmapToProject")
+    .with_client_id(
+        "input",
+        String::from("roadmapToProject(id: $id) { ") + &T::selection() + " }",
+    )
+}
+/// `Deprecated` Updates a roadmap-to-project association. Use InitiativeToProject instead.
+///
+/// Full type: [`RoadmapToProject`](super::types::RoadmapToProject)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub fn roadmap_to_project_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::RoadmapToProject>,
+>(
+    input: RoadmapToProjectUpdateInput,
+    id: impl Into<super::ids::RoadmapToProjectId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "input" : input, "id" : Into:: < super::ids::RoadmapToProjectId > ::into(id) }
+    );
+    let selection = String::from(
+        "roadmapToProjectUpdate(input: $input, id: $id) { success lastSyncId roadmapToProject { ",
+    ) + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "RoadmapToProjectUpdate",
+        "$input: RoadmapToProjectUpdateInput!, $id: String!",
+        selection,
+        variables,
+        "roadmapToProjectUpdate",
+    )
+    .with_entity_field("roadmapToProject")
+}
+/// `Deprecated` Deletes a roadmap-to-project association. Use InitiativeToProject instead.
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub fn roadmap_to_project_delete(
+    id: impl Into<super::ids::RoadmapToProjectId>,
+) -> Operation<super::types::DeletePayload> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::RoadmapToProjectId > ::into(id) }
+    );
+    let selection = String::from("roadmapToProjectDelete(id: $id) { ")
+        + &<super::types::DeletePayload as crate::field_selection::GraphQLFields>::selection()
+        + " }";
+    Operation::mutation(
+        "RoadmapToProjectDelete",
+        "$id: String!",
+        selection,
+        variables,
+        "roadmapToProjectDelete",
+    )
+}
+/// Creates a new roadmap.
+#[deprecated(note = "Roadmaps are deprecated, use initiatives instead.")]
+///
+/// Full type: [`Roadmap`](super::types::Roadmap)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub fn roadmap_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Roadmap>,
+>(
+    input: RoadmapCreateInput,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "input" : input });
+    let selection = String::from("roadmapCreate(input: $input) { success lastSyncId roadmap { ")
+        + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "RoadmapCreate",
+        "$input: RoadmapCreateInput!",
+        selection,
+        variables,
+        "roadmapCreate",
+    )
+    .with_entity_field("roadmap")
+    .with_client_id(
+        "input",
+        String::from("roadmap(id: $id) { ") + &T::selection() + " }",
+    )
+}
+/// Updates a roadmap.
+#[deprecated(note = "Roadmaps are deprecated, use initiatives instead.")]
+///
+/// Full type: [`Roadmap`](super::types::Roadmap)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub fn roadmap_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Roadmap>,
+>(
+    input: RoadmapUpdateInput,
+    id: impl Into<super::ids::RoadmapId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "input" : input, "id" : Into:: < super::ids::RoadmapId > ::into(id) }
+    );
+    let selection =
+        String::from("roadmapUpdate(input: $input, id: $id) { success lastSyncId roadmap { ")
+            + &T::selection()
+            + " } }";
+    Operation::mutation(
+        "RoadmapUpdate",
+        "$input: RoadmapUpdateInput!, $id: String!",
+        selection,
+        variables,
+        "roadmapUpdate",
+    )
+    .with_entity_field("roadmap")
+}
+/// Archives a roadmap.
+#[deprecated(note = "Roadmaps are deprecated, use initiatives instead.")]
+///
+/// Full type: [`Roadmap`](super::types::Roadmap)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub fn roadmap_archive<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Roadmap>,
+>(
+    id: impl Into<super::ids::RoadmapId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::RoadmapId > ::into(id) }
+    );
+    let selection = String::from("roadmapArchive(id: $id) { success lastSyncId entity { ")
+        + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "RoadmapArchive",
+        "$id: String!",
+        selection,
+        variables,
+        "roadmapArchive",
+    )
+    .with_entity_field("entity")
+}
+/// Unarchives a roadmap.
+#[deprecated(note = "Roadmaps are deprecated, use initiatives instead.")]
+///
+/// Full type: [`Roadmap`](super::types::Roadmap)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub fn roadmap_unarchive<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Roadmap>,
+>(
+    id: impl Into<super::ids::RoadmapId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::RoadmapId > ::into(id) }
+    );
+    let selection = String::from("roadmapUnarchive(id: $id) { success lastSyncId entity { ")
+        + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "RoadmapUnarchive",
+        "$id: String!",
+        selection,
+        variables,
+        "roadmapUnarchive",
+    )
+    .with_entity_field("entity")
+}
+/// Deletes a roadmap.
+#[deprecated(note = "Roadmaps are deprecated, use initiatives instead.")]
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub fn roadmap_delete(
+    id: impl Into<super::ids::RoadmapId>,
+) -> Operation<super::types::DeletePayload> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::RoadmapId > ::into(id) }
+    );
+    let selection = String::from("roadmapDelete(id: $id) { ")
+        + &<super::types::DeletePayload as crate::field_selection::GraphQLFields>::selection()
+        + " }";
+    Operation::mutation(
+        "RoadmapDelete",
+        "$id: String!",
+        selection,
+        variables,
+        "roadmapDelete",
+    )
+}
+/// Creates a CSV export report for the workspace. The report is generated asynchronously and delivered via email. Requires workspace admin export permission.
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub fn create_csv_export_report(
+    include_private_team_ids: Option<Vec<String>>,
+) -> Operation<super::types::CreateCsvExportReportPayload> {
+    let variables = serde_json::json!(
+        { "includePrivateTeamIds" : include_private_team_ids }
+    );
+    let selection = String::from(
+        "createCsvExportReport(includePrivateTeamIds: $includePrivateTeamIds) { ",
+    )
+        + &<super::types::CreateCsvExportReportPayload as crate::field_selection::GraphQLFields>::selection()
+        + " }";
+    Operation::mutation(
+        "CreateCsvExportReport",
+        "$includePrivateTeamIds: [String!]",
+        selection,
+        variables,
+        "createCsvExportReport",
+    )
+}
+/// `ALPHA` Creates a new release stage in a pipeline. Non-started stages must use default names and colors, and only one stage of each non-started type is allowed per pipeline. Started stages can optionally be frozen, but at least one non-frozen started stage must remain.
+///
+/// Full type: [`ReleaseStage`](super::types::ReleaseStage)
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+pub fn release_stage_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::ReleaseStage>,
+>(
+    input: ReleaseStageCreateInput,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "input" : input });
+    let selection =
+        String::from("releaseStageCreate(input: $input) { success lastSyncId releaseStage { ")
+            + &T::selection()
+            + " } }";
+    Operation::mutation(
+        "ReleaseStageCreate",
+        "$input: ReleaseStageCreateInput!",
+        selection,
+        variables,
+        "releaseStageCreate",
+    )
+    .with_entity_field("releaseStage")
+    .with_client_id(
+        "input",
+        String::from("releaseStage(id: $id) { ") + &T::selection() + " }",
+    )
+}
+/// `ALPHA` Updates an existing release stage. Only started-type stages can be edited. Supports updating name, color, position, and frozen status.
+///
+/// Full type: [`ReleaseStage`](super::types::ReleaseStage)
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+pub fn release_stage_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::ReleaseStage>,
+>(
+    input: ReleaseStageUpdateInput,
+    id: impl Into<super::ids::ReleaseStageId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "input" : input, "id" : Into:: < super::ids::ReleaseStageId > ::into(id) }
+    );
+    let selection = String::from(
+        "releaseStageUpdate(input: $input, id: $id) { success lastSyncId releaseStage { ",
+    ) + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "ReleaseStageUpdate",
+        "$input: ReleaseStageUpdateInput!, $id: String!",
+        selection,
+        variables,
+        "releaseStageUpdate",
+    )
+    .with_entity_field("releaseStage")
+}
+/// `ALPHA` Archives a release stage. Only started-type stages can be archived, and only if they have no active releases and at least one other stage of the same type remains. Cannot archive the last non-frozen started stage.
+///
+/// Full type: [`ReleaseStage`](super::types::ReleaseStage)
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+pub fn release_stage_archive<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::ReleaseStage>,
+>(
+    id: impl Into<super::ids::ReleaseStageId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::ReleaseStageId > ::into(id) }
+    );
+    let selection = String::from("releaseStageArchive(id: $id) { success lastSyncId entity { ")
+        + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "ReleaseStageArchive",
+        "$id: String!",
+        selection,
+        variables,
+        "releaseStageArchive",
+    )
+    .with_entity_field("entity")
+}
+/// `ALPHA` Unarchives a release stage.
+///
+/// Full type: [`ReleaseStage`](super::types::ReleaseStage)
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+pub fn release_stage_unarchive<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::ReleaseStage>,
+>(
+    id: impl Into<super::ids::ReleaseStageId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::ReleaseStageId > ::into(id) }
+    );
+    let selection = String::from("releaseStageUnarchive(id: $id) { success lastSyncId entity { ")
+        + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "ReleaseStageUnarchive",
+        "$id: String!",
+        selection,
+        variables,
+        "releaseStageUnarchive",
+    )
+    .with_entity_field("entity")
+}
+/// `ALPHA` Creates a new release pipeline with default stages. Subject to plan entitlement and quota limits.
+///
+/// Full type: [`ReleasePipeline`](super::types::ReleasePipeline)
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+pub fn release_pipeline_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::ReleasePipeline>,
+>(
+    input: ReleasePipelineCreateInput,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "input" : input });
+    let selection = String::from(
+        "releasePipelineCreate(input: $input) { success lastSyncId releasePipeline { ",
+    ) + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "ReleasePipelineCreate",
+        "$input: ReleasePipelineCreateInput!",
+        selection,
+        variables,
+        "releasePipelineCreate",
+    )
+    .with_entity_field("releasePipeline")
+    .with_client_id(
+        "input",
+        String::from("releasePipeline(id: $id) { ") + &T::selection() + " }",
+    )
+}
+/// `ALPHA` Updates an existing release pipeline. Supports updating name, slug, type, production flag, path patterns, and team associations. Private teams that the current user cannot access are preserved in the team list.
+///
+/// Full type: [`ReleasePipeline`](super::types::ReleasePipeline)
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+pub fn release_pipeline_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::ReleasePipeline>,
+>(
+    input: ReleasePipelineUpdateInput,
+    id: impl Into<super::ids::ReleasePipelineId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "input" : input, "id" : Into:: < super::ids::ReleasePipelineId > ::into(id) }
+    );
+    let selection = String::from(
+        "releasePipelineUpdate(input: $input, id: $id) { success lastSyncId releasePipeline { ",
+    ) + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "ReleasePipelineUpdate",
+        "$input: ReleasePipelineUpdateInput!, $id: String!",
+        selection,
+        variables,
+        "releasePipelineUpdate",
+    )
+    .with_entity_field("releasePipeline")
+}
+/// `ALPHA` Archives a release pipeline.
+///
+/// Full type: [`ReleasePipeline`](super::types::ReleasePipeline)
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+pub fn release_pipeline_archive<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::ReleasePipeline>,
+>(
+    id: impl Into<super::ids::ReleasePipelineId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::ReleasePipelineId > ::into(id) }
+    );
+    let selection = String::from("releasePipelineArchive(id: $id) { success lastSyncId entity { ")
+        + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "ReleasePipelineArchive",
+        "$id: String!",
+        selection,
+        variables,
+        "releasePipelineArchive",
+    )
+    .with_entity_field("entity")
+}
+/// `ALPHA` Unarchives a release pipeline.
+///
+/// Full type: [`ReleasePipeline`](super::types::ReleasePipeline)
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+pub fn release_pipeline_unarchive<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::ReleasePipeline>,
+>(
+    id: impl Into<super::ids::ReleasePipelineId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::ReleasePipelineId > ::into(id) }
+    );
+    let selection =
+        String::from("releasePipelineUnarchive(id: $id) { success lastSyncId entity { ")
+            + &T::selection()
+            + " } }";
+    Operation::mutation(
+        "ReleasePipelineUnarchive",
+        "$id: String!",
+        selection,
+        variables,
+        "releasePipelineUnarchive",
+    )
+    .with_entity_field("entity")
+}
+/// `ALPHA` Permanently deletes a release pipeline and all associated stages and releases.
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+pub fn release_pipeline_delete(
+    id: impl Into<super::ids::ReleasePipelineId>,
+) -> Operation<super::types::DeletePayload> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::ReleasePipelineId > ::into(id) }
+    );
+    let selection = String::from("releasePipelineDelete(id: $id) { ")
+        + &<super::types::DeletePayload as crate::field_selection::GraphQLFields>::selection()
+        + " }";
+    Operation::mutation(
+        "ReleasePipelineDelete",
+        "$id: String!",
+        selection,
+        variables,
+        "releasePipelineDelete",
+    )
+}
+/// `ALPHA` Syncs release data by resolving issue and pull request references and associating them with a release. For continuous pipelines, creates a new completed release. For scheduled pipelines, finds or creates a started release and accumulates issues into it.
+///
+/// Full type: [`Release`](super::types::Release)
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+pub fn release_sync<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Release>,
+>(
+    input: ReleaseSyncInput,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "input" : input });
+    let selection = String::from("releaseSync(input: $input) { success lastSyncId release { ")
+        + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "ReleaseSync",
+        "$input: ReleaseSyncInput!",
+        selection,
+        variables,
+        "releaseSync",
+    )
+    .with_entity_field("release")
+}
+/// `ALPHA` Creates a new release in a pipeline. If no stage is specified, defaults to the first completed stage for continuous pipelines or the first started stage for scheduled pipelines.
+///
+/// Full type: [`Release`](super::types::Release)
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+pub fn release_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Release>,
+>(
+    input: ReleaseCreateInput,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "input" : input });
+    let selection = String::from("releaseCreate(input: $input) { success lastSyncId release { ")
+        + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "ReleaseCreate",
+        "$input: ReleaseCreateInput!",
+        selection,
+        variables,
+        "releaseCreate",
+    )
+    .with_entity_field("release")
+    .with_client_id(
+        "input",
+        String::from("release(id: $id) { ") + &T::selection() + " }",
+    )
+}
+/// `ALPHA` Updates an existing release by ID. Supports updating name, description, version, commit SHA, pipeline, stage, and dates.
+///
+/// Full type: [`Release`](super::types::Release)
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+pub fn release_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Release>,
+>(
+    input: ReleaseUpdateInput,
+    id: impl Into<super::ids::ReleaseId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "input" : input, "id" : Into:: < super::ids::ReleaseId > ::into(id) }
+    );
+    let selection =
+        String::from("releaseUpdate(input: $input, id: $id) { success lastSyncId release { ")
+            + &T::selection()
+            + " } }";
+    Operation::mutation(
+        "ReleaseUpdate",
+        "$input: ReleaseUpdateInput!, $id: String!",
+        selection,
+        variables,
+        "releaseUpdate",
+    )
+    .with_entity_field("release")
+}
+/// `ALPHA` Marks a release as completed. If version is provided, completes that specific release; otherwise completes the most recent started release.
+///
+/// Full type: [`Release`](super::types::Release)
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+pub fn release_complete<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Release>,
+>(
+    input: ReleaseCompleteInput,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "input" : input });
+    let selection = String::from("releaseComplete(input: $input) { success lastSyncId release { ")
+        + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "ReleaseComplete",
+        "$input: ReleaseCompleteInput!",
+        selection,
+        variables,
+        "releaseComplete",
+    )
+    .with_entity_field("release")
+}
+/// `ALPHA` Updates a release by pipeline identifier. Finds the release by version or latest started/planned release, and optionally transitions it to a new stage by name.
+///
+/// Full type: [`Release`](super::types::Release)
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+pub fn release_update_by_pipeline<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Release>,
+>(
+    input: ReleaseUpdateByPipelineInput,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "input" : input });
+    let selection =
+        String::from("releaseUpdateByPipeline(input: $input) { success lastSyncId release { ")
+            + &T::selection()
+            + " } }";
+    Operation::mutation(
+        "ReleaseUpdateByPipeline",
+        "$input: ReleaseUpdateByPipelineInput!",
+        selection,
+        variables,
+        "releaseUpdateByPipeline",
+    )
+    .with_entity_field("release")
+}
+/// `ALPHA` Moves a release to the trash bin. Trashed releases are archived and will be permanently deleted after a retention period. If the release is already archived, it is marked as trashed with a fresh archive timestamp.
+///
+/// Full type: [`Release`](super::types::Release)
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+pub fn release_delete<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Release>,
+>(
+    id: impl Into<super::ids::ReleaseId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::ReleaseId > ::into(id) }
+    );
+    let selection = String::from("releaseDelete(id: $id) { success lastSyncId entity { ")
+        + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "ReleaseDelete",
+        "$id: String!",
+        selection,
+        variables,
+        "releaseDelete",
+    )
+    .with_entity_field("entity")
+}
+/// `ALPHA` Archives a release.
+///
+/// Full type: [`Release`](super::types::Release)
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+pub fn release_archive<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Release>,
+>(
+    id: impl Into<super::ids::ReleaseId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::ReleaseId > ::into(id) }
+    );
+    let selection = String::from("releaseArchive(id: $id) { success lastSyncId entity { ")
+        + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "ReleaseArchive",
+        "$id: String!",
+        selection,
+        variables,
+        "releaseArchive",
+    )
+    .with_entity_field("entity")
+}
+/// `ALPHA` Unarchives a release.
+///
+/// Full type: [`Release`](super::types::Release)
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+pub fn release_unarchive<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Release>,
+>(
+    id: impl Into<super::ids::ReleaseId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::ReleaseId > ::into(id) }
+    );
+    let selection = String::from("releaseUnarchive(id: $id) { success lastSyncId entity { ")
+        + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "ReleaseUnarchive",
+        "$id: String!",
+        selection,
+        variables,
+        "releaseUnarchive",
+    )
+    .with_entity_field("entity")
+}
+/// `ALPHA` Syncs release data using an access key for CI/CD integration. The pipeline is automatically inferred from the access key's configured resources, so no pipeline ID is needed in the input.
+///
+/// Full type: [`Release`](super::types::Release)
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+pub fn release_sync_by_access_key<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Release>,
+>(
+    input: ReleaseSyncInputBase,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "input" : input });
+    let selection =
+        String::from("releaseSyncByAccessKey(input: $input) { success lastSyncId release { ")
+            + &T::selection()
+            + " } }";
+    Operation::mutation(
+        "ReleaseSyncByAccessKey",
+        "$input: ReleaseSyncInputBase!",
+        selection,
+        variables,
+        "releaseSyncByAccessKey",
+    )
+    .with_entity_field("release")
+}
+/// `ALPHA` Marks a release as completed using an access key. If version is provided, completes that specific release; otherwise completes the most recent started release. The pipeline is inferred from the access key.
+///
+/// Full type: [`Release`](super::types::Release)
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+pub fn release_complete_by_access_key<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Release>,
+>(
+    input: ReleaseCompleteInputBase,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "input" : input });
+    let selection =
+        String::from("releaseCompleteByAccessKey(input: $input) { success lastSyncId release { ")
+            + &T::selection()
+            + " } }";
+    Operation::mutation(
+        "ReleaseCompleteByAccessKey",
+        "$input: ReleaseCompleteInputBase!",
+        selection,
+        variables,
+        "releaseCompleteByAccessKey",
+    )
+    .with_entity_field("release")
+}
+/// `ALPHA` Updates a release by pipeline using an access key.
+///
+/// Full type: [`Release`](super::types::Release)
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+pub fn release_update_by_pipeline_by_access_key<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Release>,
+>(
+    input: ReleaseUpdateByPipelineInputBase,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "input" : input });
+    let selection = String::from(
+        "releaseUpdateByPipelineByAccessKey(input: $input) { success lastSyncId release { ",
+    ) + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "ReleaseUpdateByPipelineByAccessKey",
+        "$input: ReleaseUpdateByPipelineInputBase!",
+        selection,
+        variables,
+        "releaseUpdateByPipelineByAccessKey",
+    )
+    .with_entity_field("release")
+}
+/// `ALPHA` Creates a release note.
+///
+/// Full type: [`ReleaseNote`](super::types::ReleaseNote)
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+pub fn release_note_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::ReleaseNote>,
+>(
+    input: ReleaseNoteCreateInput,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "input" : input });
+    let selection =
+        String::from("releaseNoteCreate(input: $input) { success lastSyncId releaseNote { ")
+            + &T::selection()
+            + " } }";
+    Operation::mutation(
+        "ReleaseNoteCreate",
+        "$input: ReleaseNoteCreateInput!",
+        selection,
+        variables,
+        "releaseNoteCreate",
+    )
+    .with_entity_field("releaseNote")
+    .with_client_id(
+        "input",
+        String::from("releaseNote(id: $id) { ") + &T::selection() + " }",
+    )
+}
+/// `ALPHA` Updates a release note.
+///
+/// Full type: [`ReleaseNote`](super::types::ReleaseNote)
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+pub fn release_note_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::ReleaseNote>,
+>(
+    input: ReleaseNoteUpdateInput,
+    id: impl Into<super::ids::ReleaseNoteId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "input" : input, "id" : Into:: < super::ids::ReleaseNoteId > ::into(id) }
+    );
+    let selection = String::from(
+        "releaseNoteUpdate(input: $input, id: $id) { success lastSyncId releaseNote { ",
+    ) + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "ReleaseNoteUpdate",
+        "$input: ReleaseNoteUpdateInput!, $id: String!",
+        selection,
+        variables,
+        "releaseNoteUpdate",
+    )
+    .with_entity_field("releaseNote")
+}
+/// `ALPHA` Deletes a release note.
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+pub fn release_note_delete(
+    id: impl Into<super::ids::ReleaseNoteId>,
+) -> Operation<super::types::DeletePayload> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::ReleaseNoteId > ::into(id) }
+    );
+    let selection = String::from("releaseNoteDelete(id: $id) { ")
+        + &<super::types::DeletePayload as crate::field_selection::GraphQLFields>::selection()
+        + " }";
+    Operation::mutation(
+        "ReleaseNoteDelete",
+        "$id: String!",
+        selection,
+        variables,
+        "releaseNoteDelete",
+    )
+}
+/// Creates a new reaction.
+///
+/// Full type: [`Reaction`](super::types::Reaction)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub fn reaction_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Reaction>,
+>(
+    input: ReactionCreateInput,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "input" : input });
+    let selection = String::from("reactionCreate(input: $input) { success lastSyncId reaction { ")
+        + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "ReactionCreate",
+        "$input: ReactionCreateInput!",
+        selection,
+        variables,
+        "reactionCreate",
+    )
+    .with_entity_field("reaction")
+}
+/// Deletes a reaction.
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub fn reaction_delete(
+    id: impl Into<super::ids::ReactionId>,
+) -> Operation<super::types::DeletePayload> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::ReactionId > ::into(id) }
+    );
+    let selection = String::from("reactionDelete(id: $id) { ")
+        + &<super::types::DeletePayload as crate::field_selection::GraphQLFields>::selection()
+        + " }";
+    Operation::mutation(
+        "ReactionDelete",
+        "$id: String!",
+        selection,
+        variables,
+        "reactionDelete",
+    )
+}
+/// Creates a push subscription for the authenticated user's current device or browser. If a subscription already exists for the same session, the old one is replaced.
+///
+/// Full type: [`PushSubscription`](super::types::PushSubscription)
+///
+/// Requires the `notifications` feature.
+#[cfg(feature = "notifications")]
+pub fn push_subscription_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::PushSubscription>,
+>(
+    input: PushSubscriptionCreateInput,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "input" : input });
+    let selection =
+        String::from("pushSubscriptionCreate(input: $input) { success lastSyncId entity { ")
+            + &T::selection()
+            + " } }";
+    Operation::mutation(
+        "PushSubscriptionCreate",
+        "$input: PushSubscriptionCreateInput!",
+        selection,
+        variables,
+        "pushSubscriptionCreate",
+    )
+    .with_entity_field("entity")
+}
+/// Deletes a push subscription, unregistering the device from receiving push notifications.
+///
+/// Full type: [`PushSubscription`](super::types::PushSubscription)
+///
+/// Requires the `notifications` feature.
+#[cfg(feature = "notifications")]
+pub fn push_subscription_delete<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::PushSubscription>,
+>(
+    id: impl Into<super::ids::PushSubscriptionId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::PushSubscriptionId > ::into(id) }
+    );
+    let selection = String::from("pushSubscriptionDelete(id: $id) { success lastSyncId entity { ")
+        + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "PushSubscriptionDelete",
+        "$id: String!",
+        selection,
+        variables,
+        "pushSubscriptionDelete",
+    )
+    .with_entity_field("entity")
+}
+/// Creates a new project update.
+///
+/// Full type: [`ProjectUpdate`](super::types::ProjectUpdate)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub fn project_update_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::ProjectUpdate>,
+>(
+    input: ProjectUpdateCreateInput,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "input" : input });
+    let selection =
+        String::from("projectUpdateCreate(input: $input) { success lastSyncId projectUpdate { ")
+            + &T::selection()
+            + " } }";
+    Operation::mutation(
+        "ProjectUpdateCreate",
+        "$input: ProjectUpdateCreateInput!",
+        selection,
+        variables,
+        "projectUpdateCreate",
+    )
+    .with_entity_field("projectUpdate")
+    .with_client_id(
+        "input",
+        String::from("projectUpdate(id: $id) { ") + &T::selection() + " }",
+    )
+}
+/// Updates a project update.
+///
+/// Full type: [`ProjectUpdate`](super::types::ProjectUpdate)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub fn project_update_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::ProjectUpdate>,
+>(
+    input: ProjectUpdateUpdateInput,
+    id: impl Into<super::ids::ProjectUpdateId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "input" : input, "id" : Into:: < super::ids::ProjectUpdateId > ::into(id) }
+    );
+    let selection = String::from(
+        "projectUpdateUpdate(input: $input, id: $id) { success lastSyncId projectUpdate { ",
+    ) + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "ProjectUpdateUpdate",
+        "$input: ProjectUpdateUpdateInput!, $id: String!",
+        selection,
+        variables,
+        "projectUpdateUpdate",
+    )
+    .with_entity_field("projectUpdate")
+}
+/// Archives a project update.
+///
+/// Full type: [`ProjectUpdate`](super::types::ProjectUpdate)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub fn project_update_archive<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::ProjectUpdate>,
+>(
+    id: impl Into<super::ids::ProjectUpdateId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::ProjectUpdateId > ::into(id) }
+    );
+    let selection = String::from("projectUpdateArchive(id: $id) { success lastSyncId entity { ")
+        + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "ProjectUpdateArchive",
+        "$id: String!",
+        selection,
+        variables,
+        "projectUpdateArchive",
+    )
+    .with_entity_field("entity")
+}
+/// Unarchives a project update.
+///
+/// Full type: [`ProjectUpdate`](super::types::ProjectUpdate)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub fn project_update_unarchive<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::ProjectUpdate>,
+>(
+    id: impl Into<super::ids::ProjectUpdateId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::ProjectUpdateId > ::into(id) }
+    );
+    let selection = String::from("projectUpdateUnarchive(id: $id) { success lastSyncId entity { ")
+        + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "ProjectUpdateUnarchive",
+        "$id: String!",
+        selection,
+        variables,
+        "projectUpdateUnarchive",
+    )
+    .with_entity_field("entity")
+}
+/// Deletes a project update.
+#[deprecated(note = "Use `projectUpdateArchive` instead.")]
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub fn project_update_delete(
+    id: impl Into<super::ids::ProjectUpdateId>,
+) -> Operation<super::types::DeletePayload> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::ProjectUpdateId > ::into(id) }
+    );
+    let selection = String::from("projectUpdateDelete(id: $id) { ")
+        + &<super::types::DeletePayload as crate::field_selection::GraphQLFields>::selection()
+        + " }";
+    Operation::mutation(
+        "ProjectUpdateDelete",
+        "$id: String!",
+        selection,
+        variables,
+        "projectUpdateDelete",
+    )
+}
+/// Create a notification to remind a user about a project update.
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub fn create_project_update_reminder(
+    user_id: Option<String>,
+    project_id: String,
+) -> Operation<super::types::ProjectUpdateReminderPayload> {
+    let variables = serde_json::json!({ "userId" : user_id, "projectId" : project_id });
+    let selection = String::from(
+        "createProjectUpdateReminder(userId: $userId, projectId: $projectId) { ",
+    )
+        + &<super::types::ProjectUpdateReminderPayload as crate::field_selection::GraphQLFields>::selection()
+        + " }";
+    Operation::mutation(
+        "CreateProjectUpdateReminder",
+        "$userId: String, $projectId: String!",
+        selection,
+        variables,
+        "createProjectUpdateReminder",
+    )
+}
+/// Creates a new project status.
+///
+/// Full type: [`ProjectStatus`](super::types::ProjectStatus)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub fn project_status_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::ProjectStatus>,
+>(
+    input: ProjectStatusCreateInput,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "input" : input });
+    let selection =
+        String::from("projectStatusCreate(input: $input) { success lastSyncId status { ")
+            + &T::selection()
+            + " } }";
+    Operation::mutation(
+        "ProjectStatusCreate",
+        "$input: ProjectStatusCreateInput!",
+        selection,
+        variables,
+        "projectStatusCreate",
+    )
+    .with_entity_field("status")
+    .with_client_id(
+        "input",
+        String::from("projectStatus(id: $id) { ") + &T::selection() + " }",
+    )
+}
+/// Updates a project status.
+///
+/// Full type: [`ProjectStatus`](super::types::ProjectStatus)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub fn project_status_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::ProjectStatus>,
+>(
+    input: ProjectStatusUpdateInput,
+    id: impl Into<super::ids::ProjectStatusId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "input" : input, "id" : Into:: < super::ids::ProjectStatusId > ::into(id) }
+    );
+    let selection =
+        String::from("projectStatusUpdate(input: $input, id: $id) { success lastSyncId status { ")
+            + &T::selection()
+            + " } }";
+    Operation::mutation(
+        "ProjectStatusUpdate",
+        "$input: ProjectStatusUpdateInput!, $id: String!",
+        selection,
+        variables,
+        "projectStatusUpdate",
+    )
+    .with_entity_field("status")
+}
+/// Archives a project status. The status must not have any active projects assigned to it and must not be the last status of its type.
+///
+/// Full type: [`ProjectStatus`](super::types::ProjectStatus)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub fn project_status_archive<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::ProjectStatus>,
+>(
+    id: impl Into<super::ids::ProjectStatusId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::ProjectStatusId > ::into(id) }
+    );
+    let selection = String::from("projectStatusArchive(id: $id) { success lastSyncId entity { ")
+        + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "ProjectStatusArchive",
+        "$id: String!",
+        selection,
+        variables,
+        "projectStatusArchive",
+    )
+    .with_entity_field("entity")
+}
+/// Unarchives a project status.
+///
+/// Full type: [`ProjectStatus`](super::types::ProjectStatus)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub fn project_status_unarchive<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::ProjectStatus>,
+>(
+    id: impl Into<super::ids::ProjectStatusId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::ProjectStatusId > ::into(id) }
+    );
+    let selection = String::from("projectStatusUnarchive(id: $id) { success lastSyncId entity { ")
+        + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "ProjectStatusUnarchive",
+        "$id: String!",
+        selection,
+        variables,
+        "projectStatusUnarchive",
+    )
+    .with_entity_field("entity")
+}
+/// Creates a new project relation.
+///
+/// Full type: [`ProjectRelation`](super::types::ProjectRelation)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub fn project_relation_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::ProjectRelation>,
+>(
+    input: ProjectRelationCreateInput,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "input" : input });
+    let selection = String::from(
+        "projectRelationCreate(input: $input) { success lastSyncId projectRelation { ",
+    ) + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "ProjectRelationCreate",
+        "$input: ProjectRelationCreateInput!",
+        selection,
+        variables,
+        "projectRelationCreate",
+    )
+    .with_entity_field("projectRelation")
+    .with_client_id(
+        "input",
+        String::from("projectRelation(id: $id) { ") + &T::selection() + " }",
+    )
+}
+/// Updates a project relation.
+///
+/// Full type: [`ProjectRelation`](super::types::ProjectRelation)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub fn project_relation_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::ProjectRelation>,
+>(
+    input: ProjectRelationUpdateInput,
+    id: impl Into<super::ids::ProjectRelationId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "input" : input, "id" : Into:: < super::ids::ProjectRelationId > ::into(id) }
+    );
+    let selection = String::from(
+        "projectRelationUpdate(input: $input, id: $id) { success lastSyncId projectRelation { ",
+    ) + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "ProjectRelationUpdate",
+        "$input: ProjectRelationUpdateInput!, $id: String!",
+        selection,
+        variables,
+        "projectRelationUpdate",
+    )
+    .with_entity_field("projectRelation")
+}
+/// Deletes a project relation.
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub fn project_relation_delete(
+    id: impl Into<super::ids::ProjectRelationId>,
+) -> Operation<super::types::DeletePayload> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::ProjectRelationId > ::into(id) }
+    );
+    let selection = String::from("projectRelationDelete(id: $id) { ")
+        + &<super::types::DeletePayload as crate::field_selection::GraphQLFields>::selection()
+        + " }";
+    Operation::mutation(
+        "ProjectRelationDelete",
+        "$id: String!",
+        selection,
+        variables,
+        "projectRelationDelete",
+    )
+}
+/// Creates a new project milestone.
+///
+/// Full type: [`ProjectMilestone`](super::types::ProjectMilestone)
+pub fn project_milestone_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::ProjectMilestone>,
+>(
+    input: ProjectMilestoneCreateInput,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "input" : input });
+    let selection = String::from(
+        "projectMilestoneCreate(input: $input) { success lastSyncId projectMilestone { ",
+    ) + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "ProjectMilestoneCreate",
+        "$input: ProjectMilestoneCreateInput!",
+        selection,
+        variables,
+        "projectMilestoneCreate",
+    )
+    .with_entity_field("projectMilestone")
+    .with_client_id(
+        "input",
+        String::from("projectMilestone(id: $id) { ") + &T::selection() + " }",
+    )
+}
+/// Updates a project milestone.
+///
+/// Full type: [`ProjectMilestone`](super::types::ProjectMilestone)
+pub fn project_milestone_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::ProjectMilestone>,
+>(
+    input: ProjectMilestoneUpdateInput,
+    id: impl Into<super::ids::ProjectMilestoneId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "input" : input, "id" : Into:: < super::ids::ProjectMilestoneId > ::into(id) }
+    );
+    let selection = String::from(
+        "projectMilestoneUpdate(input: $input, id: $id) { success lastSyncId projectMilestone { ",
+    ) + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "ProjectMilestoneUpdate",
+        "$input: ProjectMilestoneUpdateInput!, $id: String!",
+        selection,
+        variables,
+        "projectMilestoneUpdate",
+    )
+    .with_entity_field("projectMilestone")
+}
+/// Deletes a project milestone.
+pub fn project_milestone_delete(
+    id: impl Into<super::ids::ProjectMilestoneId>,
+) -> Operation<super::types::DeletePayload> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::ProjectMilestoneId > ::into(id) }
+    );
+    let selection = String::from("projectMilestoneDelete(id: $id) { ")
+        + &<super::types::DeletePayload as crate::field_selection::GraphQLFields>::selection()
+        + " }";
+    Operation::mutation(
+        "ProjectMilestoneDelete",
+        "$id: String!",
+        selection,
+        variables,
+        "projectMilestoneDelete",
+    )
+}
+/// `Internal` Moves a project milestone to another project, can be called to undo a prior move.
+///
+/// Full type: [`ProjectMilestone`](super::types::ProjectMilestone)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub fn project_milestone_move<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::ProjectMilestone>,
+>(
+    input: ProjectMilestoneMoveInput,
+    id: impl Into<super::ids::ProjectMilestoneId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "input" : input, "id" : Into:: < super::ids::ProjectMilestoneId > ::into(id) }
+    );
+    let selection = String::from(
+        "projectMilestoneMove(input: $input, id: $id) { success lastSyncId projectMilestone { ",
+    ) + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "ProjectMilestoneMove",
+        "$input: ProjectMilestoneMoveInput!, $id: String!",
+        selection,
+        variables,
+        "projectMilestoneMove",
+    )
+    .with_entity_field("projectMilestone")
+}
+/// Creates a new project label.
+///
+/// Full type: [`ProjectLabel`](super::types::ProjectLabel)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub fn project_label_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::ProjectLabel>,
+>(
+    input: ProjectLabelCreateInput,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "input" : input });
+    let selection =
+        String::from("projectLabelCreate(input: $input) { success lastSyncId projectLabel { ")
+            + &T::selection()
+            + " } }";
+    Operation::mutation(
+        "ProjectLabelCreate",
+        "$input: ProjectLabelCreateInput!",
+        selection,
+        variables,
+        "projectLabelCreate",
+    )
+    .with_entity_field("projectLabel")
+    .with_client_id(
+        "input",
+        String::from("projectLabel(id: $id) { ") + &T::selection() + " }",
+    )
+}
+/// Updates a project label.
+///
+/// Full type: [`ProjectLabel`](super::types::ProjectLabel)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub fn project_label_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::ProjectLabel>,
+>(
+    input: ProjectLabelUpdateInput,
+    id: impl Into<super::ids::ProjectLabelId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "input" : input, "id" : Into:: < super::ids::ProjectLabelId > ::into(id) }
+    );
+    let selection = String::from(
+        "projectLabelUpdate(input: $input, id: $id) { success lastSyncId projectLabel { ",
+    ) + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "ProjectLabelUpdate",
+        "$input: ProjectLabelUpdateInput!, $id: String!",
+        selection,
+        variables,
+        "projectLabelUpdate",
+    )
+    .with_entity_field("projectLabel")
+}
+/// Deletes a project label.
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub fn project_label_delete(
+    id: impl Into<super::ids::ProjectLabelId>,
+) -> Operation<super::types::DeletePayload> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::ProjectLabelId > ::into(id) }
+    );
+    let selection = String::from("projectLabelDelete(id: $id) { ")
+        + &<super::types::DeletePayload as crate::field_selection::GraphQLFields>::selection()
+        + " }";
+    Operation::mutation(
+        "ProjectLabelDelete",
+        "$id: String!",
+        selection,
+        variables,
+        "projectLabelDelete",
+    )
+}
+/// Retires a project label. Retired labels remain on existing projects but cannot be applied to new ones.
+///
+/// Full type: [`ProjectLabel`](super::types::ProjectLabel)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub fn project_label_retire<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::ProjectLabel>,
+>(
+    id: impl Into<super::ids::ProjectLabelId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::ProjectLabelId > ::into(id) }
+    );
+    let selection =
+        String::from("projectLabelRetire(id: $id) { success lastSyncId projectLabel { ")
+            + &T::selection()
+            + " } }";
+    Operation::mutation(
+        "ProjectLabelRetire",
+        "$id: String!",
+        selection,
+        variables,
+        "projectLabelRetire",
+    )
+    .with_entity_field("projectLabel")
+}
+/// Restores a previously retired project label, making it available for use on new projects.
+///
+/// Full type: [`ProjectLabel`](super::types::ProjectLabel)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub fn project_label_restore<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::ProjectLabel>,
+>(
+    id: impl Into<super::ids::ProjectLabelId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::ProjectLabelId > ::into(id) }
+    );
+    let selection =
+        String::from("projectLabelRestore(id: $id) { success lastSyncId projectLabel { ")
+            + &T::selection()
+            + " } }";
+    Operation::mutation(
+        "ProjectLabelRestore",
+        "$id: String!",
+        selection,
+        variables,
+        "projectLabelRestore",
+    )
+    .with_entity_field("projectLabel")
+}
+/// Updates the user's workspace settings. Different settings require different permission levels; most require the workspaceSettings admin permission.
+///
+/// Full type: [`Organization`](super::types::Organization)
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub fn organization_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Organization>,
+>(
+    input: OrganizationUpdateInput,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "input" : input });
+    let selection =
+        String::from("organizationUpdate(input: $input) { success lastSyncId organization { ")
+            + &T::selection()
+            + " } }";
+    Operation::mutation(
+        "OrganizationUpdate",
+        "$input: OrganizationUpdateInput!",
+        selection,
+        variables,
+        "organizationUpdate",
+    )
+    .with_entity_field("organization")
+}
+/// Requests a deletion confirmation code for the workspace. The code is sent to the requesting user's email and must be used with the organizationDelete mutation to confirm deletion.
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub fn organization_delete_challenge() -> Operation<super::types::OrganizationDeletePayload> {
+    let variables = serde_json::json!({});
+    let selection = String::from("organizationDeleteChallenge() { ")
+        + &<super::types::OrganizationDeletePayload as crate::field_selection::GraphQLFields>::selection()
+        + " }";
+    Operation::mutation(
+        "OrganizationDeleteChallenge",
+        "",
+        selection,
+        variables,
+        "organizationDeleteChallenge",
+    )
+}
+/// Permanently deletes the workspace and all its data. Requires a valid deletion code obtained from organizationDeleteChallenge. This action is irreversible.
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub fn organization_delete(
+    input: DeleteOrganizationInput,
+) -> Operation<super::types::OrganizationDeletePayload> {
+    let variables = serde_json::json!({ "input" : input });
+    let selection = String::from("organizationDelete(input: $input) { ")
+        + &<super::types::OrganizationDeletePayload as crate::field_selection::GraphQLFields>::selection()
+        + " }";
+    Operation::mutation(
+        "OrganizationDelete",
+        "$input: DeleteOrganizationInput!",
+        selection,
+        variables,
+        "organizationDelete",
+    )
+}
+/// Cancels a previously requested workspace deletion, if the workspace has not yet been fully deleted.
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub fn organization_cancel_delete() -> Operation<super::types::OrganizationCancelDeletePayload> {
+    let variables = serde_json::json!({});
+    let selection = String::from("organizationCancelDelete() { ")
+        + &<super::types::OrganizationCancelDeletePayload as crate::field_selection::GraphQLFields>::selection()
+        + " }";
+    Operation::mutation(
+        "OrganizationCancelDelete",
+        "",
+        selection,
+        variables,
+        "organizationCancelDelete",
+    )
+}
+/// Starts a trial for the workspace on the specified plan type. The workspace must not already be on a paid plan or in an active trial.
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub fn organization_start_trial_for_plan(
+    input: OrganizationStartTrialInput,
+) -> Operation<super::types::OrganizationStartTrialPayload> {
+    let variables = serde_json::json!({ "input" : input });
+    let selection = String::from("organizationStartTrialForPlan(input: $input) { ")
+        + &<super::types::OrganizationStartTrialPayload as crate::field_selection::GraphQLFields>::selection()
+        + " }";
+    Operation::mutation(
+        "OrganizationStartTrialForPlan",
+        "$input: OrganizationStartTrialInput!",
+        selection,
+        variables,
+        "organizationStartTrialForPlan",
+    )
+}
+/// `DEPRECATED` Starts a trial for the workspace.
+#[deprecated(note = "Use organizationStartTrialForPlan")]
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub fn organization_start_trial() -> Operation<super::types::OrganizationStartTrialPayload> {
+    let variables = serde_json::json!({});
+    let selection = String::from("organizationStartTrial() { ")
+        + &<super::types::OrganizationStartTrialPayload as crate::field_selection::GraphQLFields>::selection()
+        + " }";
+    Operation::mutation(
+        "OrganizationStartTrial",
+        "",
+        selection,
+        variables,
+        "organizationStartTrial",
+    )
+}
+/// Creates a new workspace invite and sends an invitation email to the specified address. The invite includes a role assignment and optional team memberships.
+///
+/// Full type: [`OrganizationInvite`](super::types::OrganizationInvite)
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub fn organization_invite_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::OrganizationInvite>,
+>(
+    input: OrganizationInviteCreateInput,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "input" : input });
+    let selection = String::from(
+        "organizationInviteCreate(input: $input) { success lastSyncId organizationInvite { ",
+    ) + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "OrganizationInviteCreate",
+        "$input: OrganizationInviteCreateInput!",
+        selection,
+        variables,
+        "organizationInviteCreate",
+    )
+    .with_entity_field("organizationInvite")
+    .with_client_id(
+        "input",
+        String::from("organizationInvite(id: $id) { ") + &T::selection() + " }",
+    )
+}
+/// Updates an existing workspace invite, such as changing the teams the invitee will be added to.
+///
+/// Full type: [`OrganizationInvite`](super::types::OrganizationInvite)
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub fn organization_invite_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::OrganizationInvite>,
+>(
+    input: OrganizationInviteUpdateInput,
+    id: impl Into<super::ids::OrganizationInviteId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "input" : input, "id" : Into:: < super::ids::OrganizationInviteId > ::into(id)
+        }
+    );
+    let selection = String::from(
+        "organizationInviteUpdate(input: $input, id: $id) { success lastSyncId organizationInvite { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+        "OrganizationInviteUpdate",
+        "$input: OrganizationInviteUpdateInput!, $id: String!",
+        selection,
+        variables,
+        "organizationInviteUpdate",
+    )
+    .with_entity_field("organizationInvite")
+}
+/// Re-sends a workspace invitation email for the specified invite ID.
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub fn resend_organization_invite(id: String) -> Operation<super::types::DeletePayload> {
+    let variables = serde_json::json!({ "id" : id });
+    let selection = String::from("resendOrganizationInvite(id: $id) { ")
+        + &<super::types::DeletePayload as crate::field_selection::GraphQLFields>::selection()
+        + " }";
+    Operation::mutation(
+        "ResendOrganizationInvite",
+        "$id: String!",
+        selection,
+        variables,
+        "resendOrganizationInvite",
+    )
+}
+/// Re-sends a workspace invitation email to the specified email address, if an outstanding invite exists for that address.
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub fn resend_organization_invite_by_email(
+    email: String,
+) -> Operation<super::types::DeletePayload> {
+    let variables = serde_json::json!({ "email" : email });
+    let selection = String::from("resendOrganizationInviteByEmail(email: $email) { ")
+        + &<super::types::DeletePayload as crate::field_selection::GraphQLFields>::selection()
+        + " }";
+    Operation::mutation(
+        "ResendOrganizationInviteByEmail",
+        "$email: String!",
+        selection,
+        variables,
+        "resendOrganizationInviteByEmail",
+    )
+}
+/// Deletes (archives) a workspace invite, preventing it from being accepted.
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub fn organization_invite_delete(
+    id: impl Into<super::ids::OrganizationInviteId>,
+) -> Operation<super::types::DeletePayload> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::OrganizationInviteId > ::into(id) }
+    );
+    let selection = String::from("organizationInviteDelete(id: $id) { ")
+        + &<super::types::DeletePayload as crate::field_selection::GraphQLFields>::selection()
+        + " }";
+    Operation::mutation(
+        "OrganizationInviteDelete",
+        "$id: String!",
+        selection,
+        variables,
+        "organizationInviteDelete",
+    )
+}
+/// `INTERNAL` Verifies a domain claim.
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub fn organization_domain_claim(
+    id: impl Into<super::ids::OrganizationDomainId>,
+) -> Operation<super::types::OrganizationDomainSimplePayload> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::OrganizationDomainId > ::into(id) }
+    );
+    let selection = String::from("organizationDomainClaim(id: $id) { ")
+        + &<super::types::OrganizationDomainSimplePayload as crate::field_selection::GraphQLFields>::selection()
+        + " }";
+    Operation::mutation(
+        "OrganizationDomainClaim",
+        "$id: String!",
+        selection,
+        variables,
+        "organizationDomainClaim",
+    )
+}
+/// `INTERNAL` Verifies a domain to be added to a workspace.
+///
+/// Full type: [`OrganizationDomain`](super::types::OrganizationDomain)
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub fn organization_domain_verify<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::OrganizationDomain>,
+>(
+    input: OrganizationDomainVerificationInput,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "input" : input });
+    let selection = String::from(
+        "organizationDomainVerify(input: $input) { success lastSyncId organizationDomain { ",
+    ) + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "OrganizationDomainVerify",
+        "$input: OrganizationDomainVerificationInput!",
+        selection,
+        variables,
+        "organizationDomainVerify",
+    )
+    .with_entity_field("organizationDomain")
+}
+/// `INTERNAL` Adds a domain to be allowed for a workspace.
+///
+/// Full type: [`OrganizationDomain`](super::types::OrganizationDomain)
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub fn organization_domain_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::OrganizationDomain>,
+>(
+    trigger_email_verification: Option<bool>,
+    input: OrganizationDomainCreateInput,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "triggerEmailVerification" : trigger_email_verification, "input" : input }
+    );
+    let selection = String::from(
+        "organizationDomainCreate(triggerEmailVerification: $triggerEmailVerification, input: $input) { success lastSyncId organizationDomain { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+        "OrganizationDomainCreate",
+        "$triggerEmailVerification: Boolean, $input: OrganizationDomainCreateInput!",
+        selection,
+        variables,
+        "organizationDomainCreate",
+    )
+    .with_entity_field("organizationDomain")
+}
+/// `INTERNAL` Updates a workspace domain's settings.
+///
+/// Full type: [`OrganizationDomain`](super::types::OrganizationDomain)
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub fn organization_domain_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::OrganizationDomain>,
+>(
+    input: OrganizationDomainUpdateInput,
+    id: impl Into<super::ids::OrganizationDomainId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "input" : input, "id" : Into:: < super::ids::OrganizationDomainId > ::into(id)
+        }
+    );
+    let selection = String::from(
+        "organizationDomainUpdate(input: $input, id: $id) { success lastSyncId organizationDomain { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+        "OrganizationDomainUpdate",
+        "$input: OrganizationDomainUpdateInput!, $id: String!",
+        selection,
+        variables,
+        "organizationDomainUpdate",
+    )
+    .with_entity_field("organizationDomain")
+}
+/// Deletes a domain.
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub fn organization_domain_delete(
+    id: impl Into<super::ids::OrganizationDomainId>,
+) -> Operation<super::types::DeletePayload> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::OrganizationDomainId > ::into(id) }
+    );
+    let selection = String::from("organizationDomainDelete(id: $id) { ")
+        + &<super::types::DeletePayload as crate::field_selection::GraphQLFields>::selection()
+        + " }";
+    Operation::mutation(
+        "OrganizationDomainDelete",
+        "$id: String!",
+        selection,
+        variables,
+        "organizationDomainDelete",
+    )
+}
+/// Creates a new notification subscription for a specific entity. The subscription determines which notification types the authenticated user will receive for the target entity. Exactly one target entity (customer, custom view, cycle, initiative, label, project, team, or user) must be specified.
+///
+/// Requires the `notifications` feature.
+#[cfg(feature = "notifications")]
+pub fn notification_subscription_create(
+    input: NotificationSubscriptionCreateInput,
+) -> Operation<serde_json::Value> {
+    let variables = serde_json::json!({ "input" : input });
+    let response_parts: Vec<String> = vec!["lastSyncId".to_string(), "success".to_string()];
+    let selection = String::from("notificationSubscriptionCreate(input: $input) { ")
+        + &response_parts.join(" ")
+        + " }";
+    Operation::mutation(
+        "NotificationSubscriptionCreate",
+        "$input: NotificationSubscriptionCreateInput!",
+        selection,
+        variables,
+        "notificationSubscriptionCreate",
+    )
+}
+/// Updates a notification subscription.
+///
+/// Requires the `notifications` feature.
+#[cfg(feature = "notifications")]
+pub fn notification_subscription_update(
+    input: NotificationSubscriptionUpdateInput,
+    id: String,
+) -> Operation<serde_json::Value> {
+    let variables = serde_json::json!({ "input" : input, "id" : id });
+    let response_parts: Vec<String> = vec!["lastSyncId".to_string(), "success".to_string()];
+    let selection = String::from("notificationSubscriptionUpdate(input: $input, id: $id) { ")
+        + &response_parts.join(" ")
+        + " }";
+    Operation::mutation(
+        "NotificationSubscriptionUpdate",
+        "$input: NotificationSubscriptionUpdateInput!, $id: String!",
+        selection,
+        variables,
+        "notificationSubscriptionUpdate",
+    )
+}
+/// Deletes a notification subscription reference.
+#[deprecated(note = "Update `notificationSubscription.active` to `false` instead.")]
+///
+/// Requires the `notifications` feature.
+#[cfg(feature = "notifications")]
+pub fn notification_subscription_delete(id: String) -> Operation<super::types::DeletePayload> {
+    let variables = serde_json::json!({ "id" : id });
+    let selection = String::from("notificationSubscriptionDelete(id: $id) { ")
+        + &<super::types::DeletePayload as crate::field_selection::GraphQLFields>::selection()
+        + " }";
+    Operation::mutation(
+        "NotificationSubscriptionDelete",
+        "$id: String!",
+        selection,
+        variables,
+        "notificationSubscriptionDelete",
+    )
+}
+/// Updates a notification.
+///
+/// Requires the `notifications` feature.
+#[cfg(feature = "notifications")]
+pub fn notification_update(
+    input: NotificationUpdateInput,
+    id: String,
+) -> Operation<serde_json::Value> {
+    let variables = serde_json::json!({ "input" : input, "id" : id });
+    let response_parts: Vec<String> = vec!["lastSyncId".to_string(), "success".to_string()];
+    let selection = String::from("notificationUpdate(input: $input, id: $id) { ")
+        + &response_parts.join(" ")
+        + " }";
+    Operation::mutation(
+        "NotificationUpdate",
+        "$input: NotificationUpdateInput!, $id: String!",
+        selection,
+        variables,
+        "notificationUpdate",
+    )
+}
+/// Marks notification and all related notifications as read.
+///
+/// Requires the `notifications` feature.
+#[cfg(feature = "notifications")]
+pub fn notification_mark_read_all(
+    read_at: serde_json::Value,
+    input: NotificationEntityInput,
+) -> Operation<serde_json::Value> {
+    let variables = serde_json::json!({ "readAt" : read_at, "input" : input });
+    let response_parts: Vec<String> = vec!["lastSyncId".to_string(), "success".to_string()];
+    let selection = String::from("notificationMarkReadAll(readAt: $readAt, input: $input) { ")
+        + &response_parts.join(" ")
+        + " }";
+    Operation::mutation(
+        "NotificationMarkReadAll",
+        "$readAt: DateTime!, $input: NotificationEntityInput!",
+        selection,
+        variables,
+        "notificationMarkReadAll",
+    )
+}
+/// Marks notification and all related notifications as unread.
+///
+/// Requires the `notifications` feature.
+#[cfg(feature = "notifications")]
+pub fn notification_mark_unread_all(
+    input: NotificationEntityInput,
+) -> Operation<serde_json::Value> {
+    let variables = serde_json::json!({ "input" : input });
+    let response_parts: Vec<String> = vec!["lastSyncId".to_string(), "success".to_string()];
+    let selection = String::from("notificationMarkUnreadAll(input: $input) { ")
+        + &response_parts.join(" ")
+        + " }";
+    Operation::mutation(
+        "NotificationMarkUnreadAll",
+        "$input: NotificationEntityInput!",
+        selection,
+        variables,
+        "notificationMarkUnreadAll",
+    )
+}
+/// Snoozes a notification and all related notifications.
+///
+/// Requires the `notifications` feature.
+#[cfg(feature = "notifications")]
+pub fn notification_snooze_all(
+    snoozed_until_at: serde_json::Value,
+    input: NotificationEntityInput,
+) -> Operation<serde_json::Value> {
+    let variables = serde_json::json!(
+        { "snoozedUntilAt" : snoozed_until_at, "input" : input }
+    );
+    let response_parts: Vec<String> = vec!["lastSyncId".to_string(), "success".to_string()];
+    let selection =
+        String::from("notificationSnoozeAll(snoozedUntilAt: $snoozedUntilAt, input: $input) { ")
+            + &response_parts.join(" ")
+            + " }";
+    Operation::mutation(
+        "NotificationSnoozeAll",
+        "$snoozedUntilAt: DateTime!, $input: NotificationEntityInput!",
+        selection,
+        variables,
+        "notificationSnoozeAll",
+    )
+}
+/// Unsnoozes a notification and all related notifications.
+///
+/// Requires the `notifications` feature.
+#[cfg(feature = "notifications")]
+pub fn notification_unsnooze_all(
+    unsnoozed_at: serde_json::Value,
+    input: NotificationEntityInput,
+) -> Operation<serde_json::Value> {
+    let variables = serde_json::json!({ "unsnoozedAt" : unsnoozed_at, "input" : input });
+    let response_parts: Vec<String> = vec!["lastSyncId".to_string(), "success".to_string()];
+    let selection =
+        String::from("notificationUnsnoozeAll(unsnoozedAt: $unsnoozedAt, input: $input) { ")
+            + &response_parts.join(" ")
+            + " }";
+    Operation::mutation(
+        "NotificationUnsnoozeAll",
+        "$unsnoozedAt: DateTime!, $input: NotificationEntityInput!",
+        selection,
+        variables,
+        "notificationUnsnoozeAll",
+    )
+}
+/// Archives a notification.
+///
+/// Requires the `notifications` feature.
+#[cfg(feature = "notifications")]
+pub fn notification_archive(id: String) -> Operation<serde_json::Value> {
+    let variables = serde_json::json!({ "id" : id });
+    let response_parts: Vec<String> = vec!["lastSyncId".to_string(), "success".to_string()];
+    let selection =
+        String::from("notificationArchive(id: $id) { ") + &response_parts.join(" ") + " }";
+    Operation::mutation(
+        "NotificationArchive",
+        "$id: String!",
+        selection,
+        variables,
+        "notificationArchive",
+    )
+}
+/// Archives a notification and all related notifications.
+///
+/// Requires the `notifications` feature.
+#[cfg(feature = "notifications")]
+pub fn notification_archive_all(input: NotificationEntityInput) -> Operation<serde_json::Value> {
+    let variables = serde_json::json!({ "input" : input });
+    let response_parts: Vec<String> = vec!["lastSyncId".to_string(), "success".to_string()];
+    let selection =
+        String::from("notificationArchiveAll(input: $input) { ") + &response_parts.join(" ") + " }";
+    Operation::mutation(
+        "NotificationArchiveAll",
+        "$input: NotificationEntityInput!",
+        selection,
+        variables,
+        "notificationArchiveAll",
+    )
+}
+/// Unarchives a notification.
+///
+/// Requires the `notifications` feature.
+#[cfg(feature = "notifications")]
+pub fn notification_unarchive(id: String) -> Operation<serde_json::Value> {
+    let variables = serde_json::json!({ "id" : id });
+    let response_parts: Vec<String> = vec!["lastSyncId".to_string(), "success".to_string()];
+    let selection =
+        String::from("notificationUnarchive(id: $id) { ") + &response_parts.join(" ") + " }";
+    Operation::mutation(
+        "NotificationUnarchive",
+        "$id: String!",
+        selection,
+        variables,
+        "notificationUnarchive",
+    )
+}
+/// `ALPHA` Creates a new association between an issue and a release, linking the issue to the release for tracking purposes.
+///
+/// Full type: [`IssueToRelease`](super::types::IssueToRelease)
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+pub fn issue_to_release_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::IssueToRelease>,
+>(
+    input: IssueToReleaseCreateInput,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "input" : input });
+    let selection =
+        String::from("issueToReleaseCreate(input: $input) { success lastSyncId issueToRelease { ")
+            + &T::selection()
+            + " } }";
+    Operation::mutation(
+        "IssueToReleaseCreate",
+        "$input: IssueToReleaseCreateInput!",
+        selection,
+        variables,
+        "issueToReleaseCreate",
+    )
+    .with_entity_field("issueToRelease")
+    .with_client_id(
+        "input",
+        String::from("issueToRelease(id: $id) { ") + &T::selection() + " }",
+    )
+}
+/// `ALPHA` Deletes an issue-to-release association by looking up the issue and release identifiers, removing the issue from the release.
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+pub fn issue_to_release_delete_by_issue_and_release(
+    release_id: String,
+    issue_id: String,
+) -> Operation<super::types::DeletePayload> {
+    let variables = serde_json::json!(
+        { "releaseId" : release_id, "issueId" : issue_id }
+    );
+    let selection = String::from(
+        "issueToReleaseDeleteByIssueAndRelease(releaseId: $releaseId, issueId: $issueId) { ",
+    )
+        + &<super::types::DeletePayload as crate::field_selection::GraphQLFields>::selection()
+        + " }";
+    Operation::mutation(
+        "IssueToReleaseDeleteByIssueAndRelease",
+        "$releaseId: String!, $issueId: String!",
+        selection,
+        variables,
+        "issueToReleaseDeleteByIssueAndRelease",
+    )
+}
+/// `ALPHA` Deletes an issue-to-release association by its identifier, removing the issue from the release.
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+pub fn issue_to_release_delete(
+    id: impl Into<super::ids::IssueToReleaseId>,
+) -> Operation<super::types::DeletePayload> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::IssueToReleaseId > ::into(id) }
+    );
+    let selection = String::from("issueToReleaseDelete(id: $id) { ")
+        + &<super::types::DeletePayload as crate::field_selection::GraphQLFields>::selection()
+        + " }";
+    Operation::mutation(
+        "IssueToReleaseDelete",
+        "$id: String!",
+        selection,
+        variables,
+        "issueToReleaseDelete",
+    )
+}
+/// Creates a new issue.
+///
+/// Full type: [`Issue`](super::types::Issue)
+pub fn issue_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Issue>,
+>(
+    input: IssueCreateInput,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "input" : input });
+    let selection = String::from("issueCreate(input: $input) { success lastSyncId issue { ")
+        + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "IssueCreate",
+        "$input: IssueCreateInput!",
+        selection,
+        variables,
+        "issueCreate",
+    )
+    .with_entity_field("issue")
+    .with_client_id(
+        "input",
+        String::from("issue(id: $id) { ") + &T::selection() + " }",
+    )
+}
+/// Creates a list of issues in one transaction.
+///
+/// Full type: [`Issue`](super::types::Issue)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub fn issue_batch_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Issue>,
+>(
+    input: IssueBatchCreateInput,
+) -> Operation<Vec<T>> {
+    let variables = serde_json::json!({ "input" : input });
+    let selection = String::from("issueBatchCreate(input: $input) { success lastSyncId issues { ")
+        + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "IssueBatchCreate",
+        "$input: IssueBatchCreateInput!",
+        selection,
+        variables,
+        "issueBatchCreate",
+    )
+    .with_entity_field("issues")
+}
+/// Updates an issue.
+///
+/// Full type: [`Issue`](super::types::Issue)
+pub fn issue_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Issue>,
+>(
+    input: IssueUpdateInput,
+    id: impl Into<super::ids::IssueId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "input" : input, "id" : Into:: < super::ids::IssueId > ::into(id) }
+    );
+    let selection =
+        String::from("issueUpdate(input: $input, id: $id) { success lastSyncId issue { ")
+            + &T::selection()
+            + " } }";
+    Operation::mutation(
+        "IssueUpdate",
+        "$input: IssueUpdateInput!, $id: String!",
+        selection,
+        variables,
+        "issueUpdate",
+    )
+    .with_entity_field("issue")
+}
+/// Updates multiple issues at once.
+///
+/// Full type: [`Issue`](super::types::Issue)
+pub fn issue_batch_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Issue>,
+>(
+    input: IssueUpdateInput,
+    ids: Vec<String>,
+) -> Operation<Vec<T>> {
+    let variables = serde_json::json!({ "input" : input, "ids" : ids });
+    let selection =
+        String::from("issueBatchUpdate(input: $input, ids: $ids) { success lastSyncId issues { ")
+            + &T::selection()
+            + " } }";
+    Operation::mutation(
+        "IssueBatchUpdate",
+        "$input: IssueUpdateInput!, $ids: [UUID!]!",
+        selection,
+        variables,
+        "issueBatchUpdate",
+    )
+    .with_entity_field("issues")
+}
+/// Archives an issue.
+///
+/// Full type: [`Issue`](super::types::Issue)
+pub fn issue_archive<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Issue>,
+>(
+    trash: Option<bool>,
+    id: impl Into<super::ids::IssueId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "trash" : trash, "id" : Into:: < super::ids::IssueId > ::into(id) }
+    );
+    let selection =
+        String::from("issueArchive(trash: $trash, id: $id) { success lastSyncId entity { ")
+            + &T::selection()
+            + " } }";
+    Operation::mutation(
+        "IssueArchive",
+        "$trash: Boolean, $id: String!",
+        selection,
+        variables,
+        "issueArchive",
+    )
+    .with_entity_field("entity")
+}
+/// Unarchives an issue.
+///
+/// Full type: [`Issue`](super::types::Issue)
+pub fn issue_unarchive<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Issue>,
+>(
+    id: impl Into<super::ids::IssueId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::IssueId > ::into(id) }
+    );
+    let selection = String::from("issueUnarchive(id: $id) { success lastSyncId entity { ")
+        + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "IssueUnarchive",
+        "$id: String!",
+        selection,
+        variables,
+        "issueUnarchive",
+    )
+    .with_entity_field("entity")
+}
+/// Deletes (trashes) an issue.
+///
+/// Full type: [`Issue`](super::types::Issue)
+pub fn issue_delete<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Issue>,
+>(
+    permanently_delete: Option<bool>,
+    id: impl Into<super::ids::IssueId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "permanentlyDelete" : permanently_delete, "id" : Into:: < super::ids::IssueId >
+        ::into(id) }
+    );
+    let selection = String::from(
+        "issueDelete(permanentlyDelete: $permanentlyDelete, id: $id) { success lastSyncId entity { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+        "IssueDelete",
+        "$permanentlyDelete: Boolean, $id: String!",
+        selection,
+        variables,
+        "issueDelete",
+    )
+    .with_entity_field("entity")
+}
+/// Adds a label to an issue.
+///
+/// Full type: [`Issue`](super::types::Issue)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub fn issue_add_label<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Issue>,
+>(
+    label_id: String,
+    id: impl Into<super::ids::IssueId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "labelId" : label_id, "id" : Into:: < super::ids::IssueId > ::into(id) }
+    );
+    let selection =
+        String::from("issueAddLabel(labelId: $labelId, id: $id) { success lastSyncId issue { ")
+            + &T::selection()
+            + " } }";
+    Operation::mutation(
+        "IssueAddLabel",
+        "$labelId: String!, $id: String!",
+        selection,
+        variables,
+        "issueAddLabel",
+    )
+    .with_entity_field("issue")
+}
+/// Removes a label from an issue.
+///
+/// Full type: [`Issue`](super::types::Issue)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub fn issue_remove_label<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Issue>,
+>(
+    label_id: String,
+    id: impl Into<super::ids::IssueId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "labelId" : label_id, "id" : Into:: < super::ids::IssueId > ::into(id) }
+    );
+    let selection =
+        String::from("issueRemoveLabel(labelId: $labelId, id: $id) { success lastSyncId issue { ")
+            + &T::selection()
+            + " } }";
+    Operation::mutation(
+        "IssueRemoveLabel",
+        "$labelId: String!, $id: String!",
+        selection,
+        variables,
+        "issueRemoveLabel",
+    )
+    .with_entity_field("issue")
+}
+/// Adds an issue reminder. Will cause a notification to be sent when the issue reminder time is reached.
+///
+/// Full type: [`Issue`](super::types::Issue)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub fn issue_reminder<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Issue>,
+>(
+    reminder_at: serde_json::Value,
+    id: impl Into<super::ids::IssueId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "reminderAt" : reminder_at, "id" : Into:: < super::ids::IssueId > ::into(id) }
+    );
+    let selection = String::from(
+        "issueReminder(reminderAt: $reminderAt, id: $id) { success lastSyncId issue { ",
+    ) + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "IssueReminder",
+        "$reminderAt: DateTime!, $id: String!",
+        selection,
+        variables,
+        "issueReminder",
+    )
+    .with_entity_field("issue")
+}
+/// Subscribes a user to an issue.
+///
+/// Full type: [`Issue`](super::types::Issue)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub fn issue_subscribe<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Issue>,
+>(
+    user_email: Option<String>,
+    user_id: Option<String>,
+    id: impl Into<super::ids::IssueId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "userEmail" : user_email, "userId" : user_id, "id" : Into:: <
+        super::ids::IssueId > ::into(id) }
+    );
+    let selection = String::from(
+        "issueSubscribe(userEmail: $userEmail, userId: $userId, id: $id) { success lastSyncId issue { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+        "IssueSubscribe",
+        "$userEmail: String, $userId: String, $id: String!",
+        selection,
+        variables,
+        "issueSubscribe",
+    )
+    .with_entity_field("issue")
+}
+/// Unsubscribes a user from an issue.
+///
+/// Full type: [`Issue`](super::types::Issue)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub fn issue_unsubscribe<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Issue>,
+>(
+    user_email: Option<String>,
+    user_id: Option<String>,
+    id: impl Into<super::ids::IssueId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "userEmail" : user_email, "userId" : user_id, "id" : Into:: <
+        super::ids::IssueId > ::into(id) }
+    );
+    let selection = String::from(
+        "issueUnsubscribe(userEmail: $userEmail, userId: $userId, id: $id) { success lastSyncId issue { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+        "IssueUnsubscribe",
+        "$userEmail: String, $userId: String, $id: String!",
+        selection,
+        variables,
+        "issueUnsubscribe",
+    )
+    .with_entity_field("issue")
+}
+/// `INTERNAL` Updates an issue description from the Front app to handle Front attachments correctly.
+///
+/// Full type: [`Issue`](super::types::Issue)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub fn issue_description_update_from_front<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Issue>,
+>(
+    description: String,
+    id: impl Into<super::ids::IssueId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "description" : description, "id" : Into:: < super::ids::IssueId > ::into(id) }
+    );
+    let selection = String::from(
+        "issueDescriptionUpdateFromFront(description: $description, id: $id) { success lastSyncId issue { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+        "IssueDescriptionUpdateFromFront",
+        "$description: String!, $id: String!",
+        selection,
+        variables,
+        "issueDescriptionUpdateFromFront",
+    )
+    .with_entity_field("issue")
+}
+/// Disables external sync on an issue.
+///
+/// Full type: [`Issue`](super::types::Issue)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub fn issue_external_sync_disable<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Issue>,
+>(
+    attachment_id: String,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "attachmentId" : attachment_id });
+    let selection = String::from(
+        "issueExternalSyncDisable(attachmentId: $attachmentId) { success lastSyncId issue { ",
+    ) + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "IssueExternalSyncDisable",
+        "$attachmentId: String!",
+        selection,
+        variables,
+        "issueExternalSyncDisable",
+    )
+    .with_entity_field("issue")
+}
+/// Creates a new issue relation.
+///
+/// Full type: [`IssueRelation`](super::types::IssueRelation)
+pub fn issue_relation_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::IssueRelation>,
+>(
+    override_created_at: Option<serde_json::Value>,
+    input: IssueRelationCreateInput,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "overrideCreatedAt" : override_created_at, "input" : input }
+    );
+    let selection = String::from(
+        "issueRelationCreate(overrideCreatedAt: $overrideCreatedAt, input: $input) { success lastSyncId issueRelation { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+        "IssueRelationCreate",
+        "$overrideCreatedAt: DateTime, $input: IssueRelationCreateInput!",
+        selection,
+        variables,
+        "issueRelationCreate",
+    )
+    .with_entity_field("issueRelation")
+    .with_client_id(
+        "input",
+        String::from("issueRelation(id: $id) { ") + &T::selection() + " }",
+    )
+}
+/// Updates an issue relation.
+///
+/// Full type: [`IssueRelation`](super::types::IssueRelation)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub fn issue_relation_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::IssueRelation>,
+>(
+    input: IssueRelationUpdateInput,
+    id: impl Into<super::ids::IssueRelationId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "input" : input, "id" : Into:: < super::ids::IssueRelationId > ::into(id) }
+    );
+    let selection = String::from(
+        "issueRelationUpdate(input: $input, id: $id) { success lastSyncId issueRelation { ",
+    ) + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "IssueRelationUpdate",
+        "$input: IssueRelationUpdateInput!, $id: String!",
+        selection,
+        variables,
+        "issueRelationUpdate",
+    )
+    .with_entity_field("issueRelation")
+}
+/// Deletes an issue relation.
+pub fn issue_relation_delete(
+    id: impl Into<super::ids::IssueRelationId>,
+) -> Operation<super::types::DeletePayload> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::IssueRelationId > ::into(id) }
+    );
+    let selection = String::from("issueRelationDelete(id: $id) { ")
+        + &<super::types::DeletePayload as crate::field_selection::GraphQLFields>::selection()
+        + " }";
+    Operation::mutation(
+        "IssueRelationDelete",
+        "$id: String!",
+        selection,
+        variables,
+        "issueRelationDelete",
+    )
+}
+/// Creates a new label.
+///
+/// Full type: [`IssueLabel`](super::types::IssueLabel)
+pub fn issue_label_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::IssueLabel>,
+>(
+    replace_team_labels: Option<bool>,
+    input: IssueLabelCreateInput,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "replaceTeamLabels" : replace_team_labels, "input" : input }
+    );
+    let selection = String::from(
+        "issueLabelCreate(replaceTeamLabels: $replaceTeamLabels, input: $input) { success lastSyncId issueLabel { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+        "IssueLabelCreate",
+        "$replaceTeamLabels: Boolean, $input: IssueLabelCreateInput!",
+        selection,
+        variables,
+        "issueLabelCreate",
+    )
+    .with_entity_field("issueLabel")
+    .with_client_id(
+        "input",
+        String::from("issueLabel(id: $id) { ") + &T::selection() + " }",
+    )
+}
+/// Updates a label.
+///
+/// Full type: [`IssueLabel`](super::types::IssueLabel)
+pub fn issue_label_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::IssueLabel>,
+>(
+    replace_team_labels: Option<bool>,
+    input: IssueLabelUpdateInput,
+    id: impl Into<super::ids::IssueLabelId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "replaceTeamLabels" : replace_team_labels, "input" : input, "id" : Into:: <
+        super::ids::IssueLabelId > ::into(id) }
+    );
+    let selection = String::from(
+        "issueLabelUpdate(replaceTeamLabels: $replaceTeamLabels, input: $input, id: $id) { success lastSyncId issueLabel { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+        "IssueLabelUpdate",
+        "$replaceTeamLabels: Boolean, $input: IssueLabelUpdateInput!, $id: String!",
+        selection,
+        variables,
+        "issueLabelUpdate",
+    )
+    .with_entity_field("issueLabel")
+}
+/// Deletes an issue label.
+pub fn issue_label_delete(
+    id: impl Into<super::ids::IssueLabelId>,
+) -> Operation<super::types::DeletePayload> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::IssueLabelId > ::into(id) }
+    );
+    let selection = String::from("issueLabelDelete(id: $id) { ")
+        + &<super::types::DeletePayload as crate::field_selection::GraphQLFields>::selection()
+        + " }";
+    Operation::mutation(
+        "IssueLabelDelete",
+        "$id: String!",
+        selection,
+        variables,
+        "issueLabelDelete",
+    )
+}
+/// Retires a label. Retired labels are still visible but cannot be applied to new issues. Existing issues with the label are not affected.
+///
+/// Full type: [`IssueLabel`](super::types::IssueLabel)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub fn issue_label_retire<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::IssueLabel>,
+>(
+    id: impl Into<super::ids::IssueLabelId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::IssueLabelId > ::into(id) }
+    );
+    let selection = String::from("issueLabelRetire(id: $id) { success lastSyncId issueLabel { ")
+        + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "IssueLabelRetire",
+        "$id: String!",
+        selection,
+        variables,
+        "issueLabelRetire",
+    )
+    .with_entity_field("issueLabel")
+}
+/// Restores a previously retired label, making it available for use again.
+///
+/// Full type: [`IssueLabel`](super::types::IssueLabel)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub fn issue_label_restore<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::IssueLabel>,
+>(
+    id: impl Into<super::ids::IssueLabelId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::IssueLabelId > ::into(id) }
+    );
+    let selection = String::from("issueLabelRestore(id: $id) { success lastSyncId issueLabel { ")
+        + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "IssueLabelRestore",
+        "$id: String!",
+        selection,
+        variables,
+        "issueLabelRestore",
+    )
+    .with_entity_field("issueLabel")
+}
+/// Kicks off a GitHub import job.
+///
+/// Full type: [`IssueImport`](super::types::IssueImport)
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub fn issue_import_create_github<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::IssueImport>,
+>(
+    team_id: Option<String>,
+    team_name: Option<String>,
+    github_repo_ids: Option<Vec<i64>>,
+    github_labels: Option<Vec<String>>,
+    instant_process: Option<bool>,
+    include_closed_issues: Option<bool>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "teamId" : team_id, "teamName" : team_name, "githubRepoIds" : github_repo_ids,
+        "githubLabels" : github_labels, "instantProcess" : instant_process,
+        "includeClosedIssues" : include_closed_issues }
+    );
+    let selection = String::from(
+        "issueImportCreateGithub(teamId: $teamId, teamName: $teamName, githubRepoIds: $githubRepoIds, githubLabels: $githubLabels, instantProcess: $instantProcess, includeClosedIssues: $includeClosedIssues) { success lastSyncId issueImport { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+            "IssueImportCreateGithub",
+            "$teamId: String, $teamName: String, $githubRepoIds: [Int!], $githubLabels: [String!], $instantProcess: Boolean, $includeClosedIssues: Boolean",
+            selection,
+            variables,
+            "issueImportCreateGithub",
+        )
+        .with_entity_field("issueImport")
+}
+/// Kicks off a Jira import job.
+///
+/// Full type: [`IssueImport`](super::types::IssueImport)
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub fn issue_import_create_jira<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::IssueImport>,
+>(
+    team_id: Option<String>,
+    team_name: Option<String>,
+    jira_token: String,
+    jira_project: String,
+    jira_email: String,
+    jira_hostname: String,
+    jql: Option<String>,
+    instant_process: Option<bool>,
+    include_closed_issues: Option<bool>,
+    id: Option<String>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "teamId" : team_id, "teamName" : team_name, "jiraToken" : jira_token,
+        "jiraProject" : jira_project, "jiraEmail" : jira_email, "jiraHostname" :
+        jira_hostname, "jql" : jql, "instantProcess" : instant_process,
+        "includeClosedIssues" : include_closed_issues, "id" : id }
+    );
+    let selection = String::from(
+        "issueImportCreateJira(teamId: $teamId, teamName: $teamName, jiraToken: $jiraToken, jiraProject: $jiraProject, jiraEmail: $jiraEmail, jiraHostname: $jiraHostname, jql: $jql, instantProcess: $instantProcess, includeClosedIssues: $includeClosedIssues, id: $id) { success lastSyncId issueImport { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+            "IssueImportCreateJira",
+            "$teamId: String, $teamName: String, $jiraToken: String!, $jiraProject: String!, $jiraEmail: String!, $jiraHostname: String!, $jql: String, $instantProcess: Boolean, $includeClosedIssues: Boolean, $id: String",
+            selection,
+            variables,
+            "issueImportCreateJira",
+        )
+        .with_entity_field("issueImport")
+}
+/// Kicks off a Jira import job from a CSV.
+///
+/// Full type: [`IssueImport`](super::types::IssueImport)
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub fn issue_import_create_csv_jira<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::IssueImport>,
+>(
+    team_id: Option<String>,
+    team_name: Option<String>,
+    csv_url: String,
+    jira_hostname: Option<String>,
+    jira_token: Option<String>,
+    jira_email: Option<String>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "teamId" : team_id, "teamName" : team_name, "csvUrl" : csv_url, "jiraHostname"
+        : jira_hostname, "jiraToken" : jira_token, "jiraEmail" : jira_email }
+    );
+    let selection = String::from(
+        "issueImportCreateCSVJira(teamId: $teamId, teamName: $teamName, csvUrl: $csvUrl, jiraHostname: $jiraHostname, jiraToken: $jiraToken, jiraEmail: $jiraEmail) { success lastSyncId issueImport { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+            "IssueImportCreateCSVJira",
+            "$teamId: String, $teamName: String, $csvUrl: String!, $jiraHostname: String, $jiraToken: String, $jiraEmail: String",
+            selection,
+            variables,
+            "issueImportCreateCSVJira",
+        )
+        .with_entity_field("issueImport")
+}
+/// Kicks off a Shortcut (formerly Clubhouse) import job.
+///
+/// Full type: [`IssueImport`](super::types::IssueImport)
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub fn issue_import_create_clubhouse<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::IssueImport>,
+>(
+    team_id: Option<String>,
+    team_name: Option<String>,
+    clubhouse_token: String,
+    clubhouse_group_name: String,
+    instant_process: Option<bool>,
+    include_closed_issues: Option<bool>,
+    id: Option<String>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "teamId" : team_id, "teamName" : team_name, "clubhouseToken" : clubhouse_token,
+        "clubhouseGroupName" : clubhouse_group_name, "instantProcess" : instant_process,
+        "includeClosedIssues" : include_closed_issues, "id" : id }
+    );
+    let selection = String::from(
+        "issueImportCreateClubhouse(teamId: $teamId, teamName: $teamName, clubhouseToken: $clubhouseToken, clubhouseGroupName: $clubhouseGroupName, instantProcess: $instantProcess, includeClosedIssues: $includeClosedIssues, id: $id) { success lastSyncId issueImport { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+            "IssueImportCreateClubhouse",
+            "$teamId: String, $teamName: String, $clubhouseToken: String!, $clubhouseGroupName: String!, $instantProcess: Boolean, $includeClosedIssues: Boolean, $id: String",
+            selection,
+            variables,
+            "issueImportCreateClubhouse",
+        )
+        .with_entity_field("issueImport")
+}
+/// Kicks off an Asana import job.
+///
+/// Full type: [`IssueImport`](super::types::IssueImport)
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub fn issue_import_create_asana<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::IssueImport>,
+>(
+    team_id: Option<String>,
+    team_name: Option<String>,
+    asana_token: String,
+    asana_team_name: String,
+    instant_process: Option<bool>,
+    include_closed_issues: Option<bool>,
+    id: Option<String>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "teamId" : team_id, "teamName" : team_name, "asanaToken" : asana_token,
+        "asanaTeamName" : asana_team_name, "instantProcess" : instant_process,
+        "includeClosedIssues" : include_closed_issues, "id" : id }
+    );
+    let selection = String::from(
+        "issueImportCreateAsana(teamId: $teamId, teamName: $teamName, asanaToken: $asanaToken, asanaTeamName: $asanaTeamName, instantProcess: $instantProcess, includeClosedIssues: $includeClosedIssues, id: $id) { success lastSyncId issueImport { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+            "IssueImportCreateAsana",
+            "$teamId: String, $teamName: String, $asanaToken: String!, $asanaTeamName: String!, $instantProcess: Boolean, $includeClosedIssues: Boolean, $id: String",
+            selection,
+            variables,
+            "issueImportCreateAsana",
+        )
+        .with_entity_field("issueImport")
+}
+/// `INTERNAL` Kicks off a Linear to Linear import job.
+///
+/// Full type: [`IssueImport`](super::types::IssueImport)
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub fn issue_import_create_linear_v2<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::IssueImport>,
+>(
+    linear_source_organization_id: String,
+    id: Option<String>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "linearSourceOrganizationId" : linear_source_organization_id, "id" : id }
+    );
+    let selection = String::from(
+        "issueImportCreateLinearV2(linearSourceOrganizationId: $linearSourceOrganizationId, id: $id) { success lastSyncId issueImport { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+        "IssueImportCreateLinearV2",
+        "$linearSourceOrganizationId: String!, $id: String",
+        selection,
+        variables,
+        "issueImportCreateLinearV2",
+    )
+    .with_entity_field("issueImport")
+}
+/// Deletes an import job.
+///
+/// Full type: [`IssueImport`](super::types::IssueImport)
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub fn issue_import_delete<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::IssueImport>,
+>(
+    issue_import_id: String,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "issueImportId" : issue_import_id });
+    let selection = String::from(
+        "issueImportDelete(issueImportId: $issueImportId) { success lastSyncId issueImport { ",
+    ) + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "IssueImportDelete",
+        "$issueImportId: String!",
+        selection,
+        variables,
+        "issueImportDelete",
+    )
+    .with_entity_field("issueImport")
+}
+/// Kicks off import processing.
+///
+/// Full type: [`IssueImport`](super::types::IssueImport)
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub fn issue_import_process<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::IssueImport>,
+>(
+    mapping: serde_json::Value,
+    issue_import_id: String,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "mapping" : mapping, "issueImportId" : issue_import_id }
+    );
+    let selection = String::from(
+        "issueImportProcess(mapping: $mapping, issueImportId: $issueImportId) { success lastSyncId issueImport { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+        "IssueImportProcess",
+        "$mapping: JSONObject!, $issueImportId: String!",
+        selection,
+        variables,
+        "issueImportProcess",
+    )
+    .with_entity_field("issueImport")
+}
+/// Updates the mapping for the issue import.
+///
+/// Full type: [`IssueImport`](super::types::IssueImport)
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub fn issue_import_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::IssueImport>,
+>(
+    input: IssueImportUpdateInput,
+    id: impl Into<super::ids::IssueImportId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "input" : input, "id" : Into:: < super::ids::IssueImportId > ::into(id) }
+    );
+    let selection = String::from(
+        "issueImportUpdate(input: $input, id: $id) { success lastSyncId issueImport { ",
+    ) + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "IssueImportUpdate",
+        "$input: IssueImportUpdateInput!, $id: String!",
+        selection,
+        variables,
+        "issueImportUpdate",
+    )
+    .with_entity_field("issueImport")
+}
+/// Creates new Slack notification settings for a team, project, initiative, or custom view.
+///
+/// Full type: [`IntegrationsSettings`](super::types::IntegrationsSettings)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn integrations_settings_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::IntegrationsSettings>,
+>(
+    input: IntegrationsSettingsCreateInput,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "input" : input });
+    let selection = String::from(
+        "integrationsSettingsCreate(input: $input) { success lastSyncId integrationsSettings { ",
+    ) + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "IntegrationsSettingsCreate",
+        "$input: IntegrationsSettingsCreateInput!",
+        selection,
+        variables,
+        "integrationsSettingsCreate",
+    )
+    .with_entity_field("integrationsSettings")
+    .with_client_id(
+        "input",
+        String::from("integrationsSettings(id: $id) { ") + &T::selection() + " }",
+    )
+}
+/// Updates Slack notification settings for a team, project, initiative, or custom view.
+///
+/// Full type: [`IntegrationsSettings`](super::types::IntegrationsSettings)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn integrations_settings_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::IntegrationsSettings>,
+>(
+    input: IntegrationsSettingsUpdateInput,
+    id: impl Into<super::ids::IntegrationsSettingsId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "input" : input, "id" : Into:: < super::ids::IntegrationsSettingsId >
+        ::into(id) }
+    );
+    let selection = String::from(
+        "integrationsSettingsUpdate(input: $input, id: $id) { success lastSyncId integrationsSettings { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+        "IntegrationsSettingsUpdate",
+        "$input: IntegrationsSettingsUpdateInput!, $id: String!",
+        selection,
+        variables,
+        "integrationsSettingsUpdate",
+    )
+    .with_entity_field("integrationsSettings")
+}
+/// Creates a new connection between a template and an integration, optionally scoped to a specific external resource such as a Slack channel.
+///
+/// Full type: [`IntegrationTemplate`](super::types::IntegrationTemplate)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn integration_template_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::IntegrationTemplate>,
+>(
+    input: IntegrationTemplateCreateInput,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "input" : input });
+    let selection = String::from(
+        "integrationTemplateCreate(input: $input) { success lastSyncId integrationTemplate { ",
+    ) + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "IntegrationTemplateCreate",
+        "$input: IntegrationTemplateCreateInput!",
+        selection,
+        variables,
+        "integrationTemplateCreate",
+    )
+    .with_entity_field("integrationTemplate")
+    .with_client_id(
+        "input",
+        String::from("integrationTemplate(id: $id) { ") + &T::selection() + " }",
+    )
+}
+/// Deletes an integration template connection, removing the link between a template and an integration.
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn integration_template_delete(
+    id: impl Into<super::ids::IntegrationTemplateId>,
+) -> Operation<super::types::DeletePayload> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::IntegrationTemplateId > ::into(id) }
+    );
+    let selection = String::from("integrationTemplateDelete(id: $id) { ")
+        + &<super::types::DeletePayload as crate::field_selection::GraphQLFields>::selection()
+        + " }";
+    Operation::mutation(
+        "IntegrationTemplateDelete",
+        "$id: String!",
+        selection,
+        variables,
+        "integrationTemplateDelete",
+    )
+}
+/// `INTERNAL` Updates the integration.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn integration_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    input: IntegrationUpdateInput,
+    id: impl Into<super::ids::IntegrationId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "input" : input, "id" : Into:: < super::ids::IntegrationId > ::into(id) }
+    );
+    let selection = String::from(
+        "integrationUpdate(input: $input, id: $id) { success lastSyncId integration { ",
+    ) + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "IntegrationUpdate",
+        "$input: IntegrationUpdateInput!, $id: String!",
+        selection,
+        variables,
+        "integrationUpdate",
+    )
+    .with_entity_field("integration")
+}
+/// `INTERNAL` Updates the integration settings.
+#[deprecated(note = "Use integrationUpdate instead.")]
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn integration_settings_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    input: IntegrationSettingsInput,
+    id: impl Into<super::ids::IntegrationId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "input" : input, "id" : Into:: < super::ids::IntegrationId > ::into(id) }
+    );
+    let selection = String::from(
+        "integrationSettingsUpdate(input: $input, id: $id) { success lastSyncId integration { ",
+    ) + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "IntegrationSettingsUpdate",
+        "$input: IntegrationSettingsInput!, $id: String!",
+        selection,
+        variables,
+        "integrationSettingsUpdate",
+    )
+    .with_entity_field("integration")
+}
+/// Generates a webhook for the GitHub commit integration.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn integration_github_commit_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>() -> Operation<T> {
+    let variables = serde_json::json!({});
+    let selection =
+        String::from("integrationGithubCommitCreate() { success lastSyncId integration { ")
+            + &T::selection()
+            + " } }";
+    Operation::mutation(
+        "IntegrationGithubCommitCreate",
+        "",
+        selection,
+        variables,
+        "integrationGithubCommitCreate",
+    )
+    .with_entity_field("integration")
+}
+/// Connects the workspace with the GitHub App.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn integration_github_connect<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    github_host: Option<String>,
+    code_access: Option<bool>,
+    code: String,
+    installation_id: String,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "githubHost" : github_host, "codeAccess" : code_access, "code" : code,
+        "installationId" : installation_id }
+    );
+    let selection = String::from(
+        "integrationGithubConnect(githubHost: $githubHost, codeAccess: $codeAccess, code: $code, installationId: $installationId) { success lastSyncId integration { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+        "IntegrationGithubConnect",
+        "$githubHost: String, $codeAccess: Boolean, $code: String!, $installationId: String!",
+        selection,
+        variables,
+        "integrationGithubConnect",
+    )
+    .with_entity_field("integration")
+}
+/// Connects the workspace with the GitHub Import App.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn integration_github_import_connect<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    code: String,
+    installation_id: String,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "code" : code, "installationId" : installation_id }
+    );
+    let selection = String::from(
+        "integrationGithubImportConnect(code: $code, installationId: $installationId) { success lastSyncId integration { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+        "IntegrationGithubImportConnect",
+        "$code: String!, $installationId: String!",
+        selection,
+        variables,
+        "integrationGithubImportConnect",
+    )
+    .with_entity_field("integration")
+}
+/// Refreshes the data for a GitHub import integration.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn integration_github_import_refresh<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    id: impl Into<super::ids::IntegrationId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::IntegrationId > ::into(id) }
+    );
+    let selection =
+        String::from("integrationGithubImportRefresh(id: $id) { success lastSyncId integration { ")
+            + &T::selection()
+            + " } }";
+    Operation::mutation(
+        "IntegrationGithubImportRefresh",
+        "$id: String!",
+        selection,
+        variables,
+        "integrationGithubImportRefresh",
+    )
+    .with_entity_field("integration")
+}
+/// Connects the workspace with a GitHub Enterprise Server.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn integration_git_hub_enterprise_server_connect<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    organization_name: String,
+    github_url: String,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "organizationName" : organization_name, "githubUrl" : github_url }
+    );
+    let selection = String::from(
+        "integrationGitHubEnterpriseServerConnect(organizationName: $organizationName, githubUrl: $githubUrl) { success lastSyncId integration { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+        "IntegrationGitHubEnterpriseServerConnect",
+        "$organizationName: String!, $githubUrl: String!",
+        selection,
+        variables,
+        "integrationGitHubEnterpriseServerConnect",
+    )
+    .with_entity_field("integration")
+}
+/// Connects the workspace with a GitLab Access Token.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn integration_gitlab_connect<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    gitlab_url: String,
+    access_token: String,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "gitlabUrl" : gitlab_url, "accessToken" : access_token }
+    );
+    let selection = String::from(
+        "integrationGitlabConnect(gitlabUrl: $gitlabUrl, accessToken: $accessToken) { success lastSyncId integration { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+        "IntegrationGitlabConnect",
+        "$gitlabUrl: String!, $accessToken: String!",
+        selection,
+        variables,
+        "integrationGitlabConnect",
+    )
+    .with_entity_field("integration")
+}
+/// Tests connectivity to a self-hosted GitLab instance and clears auth errors if successful.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn integration_gitlab_test_connection<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    integration_id: String,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "integrationId" : integration_id });
+    let selection = String::from(
+        "integrationGitlabTestConnection(integrationId: $integrationId) { success lastSyncId integration { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+        "IntegrationGitlabTestConnection",
+        "$integrationId: String!",
+        selection,
+        variables,
+        "integrationGitlabTestConnection",
+    )
+    .with_entity_field("integration")
+}
+/// Creates an integration api key for Airbyte to connect with Linear.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn airbyte_integration_connect<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    input: AirbyteConfigurationInput,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "input" : input });
+    let selection = String::from(
+        "airbyteIntegrationConnect(input: $input) { success lastSyncId integration { ",
+    ) + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "AirbyteIntegrationConnect",
+        "$input: AirbyteConfigurationInput!",
+        selection,
+        variables,
+        "airbyteIntegrationConnect",
+    )
+    .with_entity_field("integration")
+}
+/// `Internal` Connects the Google Calendar to the user to this Linear account via OAuth2.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn integration_google_calendar_personal_connect<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    code: String,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "code" : code });
+    let selection = String::from(
+        "integrationGoogleCalendarPersonalConnect(code: $code) { success lastSyncId integration { ",
+    ) + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "IntegrationGoogleCalendarPersonalConnect",
+        "$code: String!",
+        selection,
+        variables,
+        "integrationGoogleCalendarPersonalConnect",
+    )
+    .with_entity_field("integration")
+}
+/// `INTERNAL` Integrates the workspace with LaunchDarkly.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn integration_launch_darkly_connect<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    code: String,
+    project_key: String,
+    environment: String,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "code" : code, "projectKey" : project_key, "environment" : environment }
+    );
+    let selection = String::from(
+        "integrationLaunchDarklyConnect(code: $code, projectKey: $projectKey, environment: $environment) { success lastSyncId integration { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+        "IntegrationLaunchDarklyConnect",
+        "$code: String!, $projectKey: String!, $environment: String!",
+        selection,
+        variables,
+        "integrationLaunchDarklyConnect",
+    )
+    .with_entity_field("integration")
+}
+/// `INTERNAL` Integrates your personal account with LaunchDarkly.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn integration_launch_darkly_personal_connect<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    code: String,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "code" : code });
+    let selection = String::from(
+        "integrationLaunchDarklyPersonalConnect(code: $code) { success lastSyncId integration { ",
+    ) + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "IntegrationLaunchDarklyPersonalConnect",
+        "$code: String!",
+        selection,
+        variables,
+        "integrationLaunchDarklyPersonalConnect",
+    )
+    .with_entity_field("integration")
+}
+/// `INTERNAL` Connects the workspace with a Jira Personal Access Token.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn jira_integration_connect<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    input: JiraConfigurationInput,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "input" : input });
+    let selection =
+        String::from("jiraIntegrationConnect(input: $input) { success lastSyncId integration { ")
+            + &T::selection()
+            + " } }";
+    Operation::mutation(
+        "JiraIntegrationConnect",
+        "$input: JiraConfigurationInput!",
+        selection,
+        variables,
+        "jiraIntegrationConnect",
+    )
+    .with_entity_field("integration")
+}
+/// `INTERNAL` Updates a Jira Integration.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn integration_jira_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    input: JiraUpdateInput,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "input" : input });
+    let selection =
+        String::from("integrationJiraUpdate(input: $input) { success lastSyncId integration { ")
+            + &T::selection()
+            + " } }";
+    Operation::mutation(
+        "IntegrationJiraUpdate",
+        "$input: JiraUpdateInput!",
+        selection,
+        variables,
+        "integrationJiraUpdate",
+    )
+    .with_entity_field("integration")
+}
+/// `INTERNAL` Fetches Jira project statuses and stores them in integration settings.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn integration_jira_fetch_project_statuses<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    input: JiraFetchProjectStatusesInput,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "input" : input });
+    let selection = String::from(
+        "integrationJiraFetchProjectStatuses(input: $input) { success lastSyncId integration { ",
+    ) + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "IntegrationJiraFetchProjectStatuses",
+        "$input: JiraFetchProjectStatusesInput!",
+        selection,
+        variables,
+        "integrationJiraFetchProjectStatuses",
+    )
+    .with_entity_field("integration")
+}
+/// Connect your Jira account to Linear.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn integration_jira_personal<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    code: Option<String>,
+    access_token: Option<String>,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "code" : code, "accessToken" : access_token });
+    let selection = String::from(
+        "integrationJiraPersonal(code: $code, accessToken: $accessToken) { success lastSyncId integration { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+        "IntegrationJiraPersonal",
+        "$code: String, $accessToken: String",
+        selection,
+        variables,
+        "integrationJiraPersonal",
+    )
+    .with_entity_field("integration")
+}
+/// Connect your GitHub account to Linear.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn integration_git_hub_personal<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    enterprise_url: Option<String>,
+    code_access: Option<bool>,
+    code: String,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "enterpriseUrl" : enterprise_url, "codeAccess" : code_access, "code" : code }
+    );
+    let selection = String::from(
+        "integrationGitHubPersonal(enterpriseUrl: $enterpriseUrl, codeAccess: $codeAccess, code: $code) { success lastSyncId integration { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+        "IntegrationGitHubPersonal",
+        "$enterpriseUrl: String, $codeAccess: Boolean, $code: String!",
+        selection,
+        variables,
+        "integrationGitHubPersonal",
+    )
+    .with_entity_field("integration")
+}
+/// Integrates the workspace with Intercom.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn integration_intercom<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    domain_url: Option<String>,
+    redirect_uri: String,
+    code: String,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "domainUrl" : domain_url, "redirectUri" : redirect_uri, "code" : code }
+    );
+    let selection = String::from(
+        "integrationIntercom(domainUrl: $domainUrl, redirectUri: $redirectUri, code: $code) { success lastSyncId integration { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+        "IntegrationIntercom",
+        "$domainUrl: String, $redirectUri: String!, $code: String!",
+        selection,
+        variables,
+        "integrationIntercom",
+    )
+    .with_entity_field("integration")
+}
+/// Disconnects the workspace from Intercom.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn integration_intercom_delete<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>() -> Operation<T> {
+    let variables = serde_json::json!({});
+    let selection = String::from("integrationIntercomDelete() { success lastSyncId integration { ")
+        + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "IntegrationIntercomDelete",
+        "",
+        selection,
+        variables,
+        "integrationIntercomDelete",
+    )
+    .with_entity_field("integration")
+}
+/// `INTERNAL` Refreshes the customer data attributes from the specified integration service.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn integration_customer_data_attributes_refresh<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    input: IntegrationCustomerDataAttributesRefreshInput,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "input" : input });
+    let selection = String::from(
+        "integrationCustomerDataAttributesRefresh(input: $input) { success lastSyncId integration { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+        "IntegrationCustomerDataAttributesRefresh",
+        "$input: IntegrationCustomerDataAttributesRefreshInput!",
+        selection,
+        variables,
+        "integrationCustomerDataAttributesRefresh",
+    )
+    .with_entity_field("integration")
+}
+/// `DEPRECATED` Updates settings on the Intercom integration.
+#[deprecated(note = "This mutation is deprecated, please use `integrationSettingsUpdate` instead")]
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn integration_intercom_settings_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    input: IntercomSettingsInput,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "input" : input });
+    let selection = String::from(
+        "integrationIntercomSettingsUpdate(input: $input) { success lastSyncId integration { ",
+    ) + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "IntegrationIntercomSettingsUpdate",
+        "$input: IntercomSettingsInput!",
+        selection,
+        variables,
+        "integrationIntercomSettingsUpdate",
+    )
+    .with_entity_field("integration")
+}
+/// Integrates the workspace with Discord.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn integration_discord<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    redirect_uri: String,
+    code: String,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "redirectUri" : redirect_uri, "code" : code });
+    let selection = String::from(
+        "integrationDiscord(redirectUri: $redirectUri, code: $code) { success lastSyncId integration { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+        "IntegrationDiscord",
+        "$redirectUri: String!, $code: String!",
+        selection,
+        variables,
+        "integrationDiscord",
+    )
+    .with_entity_field("integration")
+}
+/// `INTERNAL` Integrates the workspace with Opsgenie.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn integration_opsgenie_connect<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    api_key: String,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "apiKey" : api_key });
+    let selection = String::from(
+        "integrationOpsgenieConnect(apiKey: $apiKey) { success lastSyncId integration { ",
+    ) + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "IntegrationOpsgenieConnect",
+        "$apiKey: String!",
+        selection,
+        variables,
+        "integrationOpsgenieConnect",
+    )
+    .with_entity_field("integration")
+}
+/// `INTERNAL` Refresh Opsgenie schedule mappings.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn integration_opsgenie_refresh_schedule_mappings<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>() -> Operation<T> {
+    let variables = serde_json::json!({});
+    let selection = String::from(
+        "integrationOpsgenieRefreshScheduleMappings() { success lastSyncId integration { ",
+    ) + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "IntegrationOpsgenieRefreshScheduleMappings",
+        "",
+        selection,
+        variables,
+        "integrationOpsgenieRefreshScheduleMappings",
+    )
+    .with_entity_field("integration")
+}
+/// `INTERNAL` Integrates the workspace with PagerDuty.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn integration_pager_duty_connect<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    code: String,
+    redirect_uri: String,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "code" : code, "redirectUri" : redirect_uri });
+    let selection = String::from(
+        "integrationPagerDutyConnect(code: $code, redirectUri: $redirectUri) { success lastSyncId integration { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+        "IntegrationPagerDutyConnect",
+        "$code: String!, $redirectUri: String!",
+        selection,
+        variables,
+        "integrationPagerDutyConnect",
+    )
+    .with_entity_field("integration")
+}
+/// `INTERNAL` Refresh PagerDuty schedule mappings.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn integration_pager_duty_refresh_schedule_mappings<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>() -> Operation<T> {
+    let variables = serde_json::json!({});
+    let selection = String::from(
+        "integrationPagerDutyRefreshScheduleMappings() { success lastSyncId integration { ",
+    ) + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "IntegrationPagerDutyRefreshScheduleMappings",
+        "",
+        selection,
+        variables,
+        "integrationPagerDutyRefreshScheduleMappings",
+    )
+    .with_entity_field("integration")
+}
+/// `Internal` Updates existing Slack and Asks integration scopes.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn update_integration_slack_scopes<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    integration_id: String,
+    redirect_uri: String,
+    code: String,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "integrationId" : integration_id, "redirectUri" : redirect_uri, "code" : code }
+    );
+    let selection = String::from(
+        "updateIntegrationSlackScopes(integrationId: $integrationId, redirectUri: $redirectUri, code: $code) { success lastSyncId integration { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+        "UpdateIntegrationSlackScopes",
+        "$integrationId: String!, $redirectUri: String!, $code: String!",
+        selection,
+        variables,
+        "updateIntegrationSlackScopes",
+    )
+    .with_entity_field("integration")
+}
+/// Integrates the workspace with Slack.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn integration_slack<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    should_use_v2_auth: Option<bool>,
+    redirect_uri: String,
+    code: String,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "shouldUseV2Auth" : should_use_v2_auth, "redirectUri" : redirect_uri, "code" :
+        code }
+    );
+    let selection = String::from(
+        "integrationSlack(shouldUseV2Auth: $shouldUseV2Auth, redirectUri: $redirectUri, code: $code) { success lastSyncId integration { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+        "IntegrationSlack",
+        "$shouldUseV2Auth: Boolean, $redirectUri: String!, $code: String!",
+        selection,
+        variables,
+        "integrationSlack",
+    )
+    .with_entity_field("integration")
+}
+/// Integrates the workspace with the Slack Asks app.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn integration_slack_asks<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    redirect_uri: String,
+    code: String,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "redirectUri" : redirect_uri, "code" : code });
+    let selection = String::from(
+        "integrationSlackAsks(redirectUri: $redirectUri, code: $code) { success lastSyncId integration { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+        "IntegrationSlackAsks",
+        "$redirectUri: String!, $code: String!",
+        selection,
+        variables,
+        "integrationSlackAsks",
+    )
+    .with_entity_field("integration")
+}
+/// Updates the Slack team's name in Linear for an existing Slack or Asks integration.
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn integration_slack_or_asks_update_slack_team_name(
+    integration_id: String,
+) -> Operation<super::types::IntegrationSlackWorkspaceNamePayload> {
+    let variables = serde_json::json!({ "integrationId" : integration_id });
+    let selection = String::from(
+        "integrationSlackOrAsksUpdateSlackTeamName(integrationId: $integrationId) { ",
+    )
+        + &<super::types::IntegrationSlackWorkspaceNamePayload as crate::field_selection::GraphQLFields>::selection()
+        + " }";
+    Operation::mutation(
+        "IntegrationSlackOrAsksUpdateSlackTeamName",
+        "$integrationId: String!",
+        selection,
+        variables,
+        "integrationSlackOrAsksUpdateSlackTeamName",
+    )
+}
+/// Integrates your personal notifications with Slack.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn integration_slack_personal<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    redirect_uri: String,
+    code: String,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "redirectUri" : redirect_uri, "code" : code });
+    let selection = String::from(
+        "integrationSlackPersonal(redirectUri: $redirectUri, code: $code) { success lastSyncId integration { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+        "IntegrationSlackPersonal",
+        "$redirectUri: String!, $code: String!",
+        selection,
+        variables,
+        "integrationSlackPersonal",
+    )
+    .with_entity_field("integration")
+}
+/// Connect a Slack channel to Asks.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn integration_asks_connect_channel<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    redirect_uri: String,
+    code: String,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "redirectUri" : redirect_uri, "code" : code });
+    let selection = String::from(
+        "integrationAsksConnectChannel(redirectUri: $redirectUri, code: $code) { success lastSyncId integration { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+        "IntegrationAsksConnectChannel",
+        "$redirectUri: String!, $code: String!",
+        selection,
+        variables,
+        "integrationAsksConnectChannel",
+    )
+    .with_entity_field("integration")
+}
+/// Slack integration for team notifications.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn integration_slack_post<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    should_use_v2_auth: Option<bool>,
+    redirect_uri: String,
+    team_id: String,
+    code: String,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "shouldUseV2Auth" : should_use_v2_auth, "redirectUri" : redirect_uri, "teamId"
+        : team_id, "code" : code }
+    );
+    let selection = String::from(
+        "integrationSlackPost(shouldUseV2Auth: $shouldUseV2Auth, redirectUri: $redirectUri, teamId: $teamId, code: $code) { success lastSyncId integration { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+        "IntegrationSlackPost",
+        "$shouldUseV2Auth: Boolean, $redirectUri: String!, $teamId: String!, $code: String!",
+        selection,
+        variables,
+        "integrationSlackPost",
+    )
+    .with_entity_field("integration")
+}
+/// Slack integration for project notifications.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn integration_slack_project_post<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    service: String,
+    redirect_uri: String,
+    project_id: String,
+    code: String,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "service" : service, "redirectUri" : redirect_uri, "projectId" : project_id,
+        "code" : code }
+    );
+    let selection = String::from(
+        "integrationSlackProjectPost(service: $service, redirectUri: $redirectUri, projectId: $projectId, code: $code) { success lastSyncId integration { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+        "IntegrationSlackProjectPost",
+        "$service: String!, $redirectUri: String!, $projectId: String!, $code: String!",
+        selection,
+        variables,
+        "integrationSlackProjectPost",
+    )
+    .with_entity_field("integration")
+}
+/// `Internal` Slack integration for initiative notifications.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn integration_slack_initiative_post<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    redirect_uri: String,
+    initiative_id: String,
+    code: String,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "redirectUri" : redirect_uri, "initiativeId" : initiative_id, "code" : code }
+    );
+    let selection = String::from(
+        "integrationSlackInitiativePost(redirectUri: $redirectUri, initiativeId: $initiativeId, code: $code) { success lastSyncId integration { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+        "IntegrationSlackInitiativePost",
+        "$redirectUri: String!, $initiativeId: String!, $code: String!",
+        selection,
+        variables,
+        "integrationSlackInitiativePost",
+    )
+    .with_entity_field("integration")
+}
+/// Slack integration for custom view notifications.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn integration_slack_custom_view_notifications<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    redirect_uri: String,
+    custom_view_id: String,
+    code: String,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "redirectUri" : redirect_uri, "customViewId" : custom_view_id, "code" : code }
+    );
+    let selection = String::from(
+        "integrationSlackCustomViewNotifications(redirectUri: $redirectUri, customViewId: $customViewId, code: $code) { success lastSyncId integration { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+        "IntegrationSlackCustomViewNotifications",
+        "$redirectUri: String!, $customViewId: String!, $code: String!",
+        selection,
+        variables,
+        "integrationSlackCustomViewNotifications",
+    )
+    .with_entity_field("integration")
+}
+/// Integrates a Slack Asks channel with a Customer.
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn integration_slack_customer_channel_link(
+    redirect_uri: String,
+    customer_id: String,
+    code: String,
+) -> Operation<super::types::SuccessPayload> {
+    let variables = serde_json::json!(
+        { "redirectUri" : redirect_uri, "customerId" : customer_id, "code" : code }
+    );
+    let selection = String::from(
+        "integrationSlackCustomerChannelLink(redirectUri: $redirectUri, customerId: $customerId, code: $code) { ",
+    )
+        + &<super::types::SuccessPayload as crate::field_selection::GraphQLFields>::selection()
+        + " }";
+    Operation::mutation(
+        "IntegrationSlackCustomerChannelLink",
+        "$redirectUri: String!, $customerId: String!, $code: String!",
+        selection,
+        variables,
+        "integrationSlackCustomerChannelLink",
+    )
+}
+/// Slack integration for workspace-level project update notifications.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn integration_slack_org_project_updates_post<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    redirect_uri: String,
+    code: String,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "redirectUri" : redirect_uri, "code" : code });
+    let selection = String::from(
+        "integrationSlackOrgProjectUpdatesPost(redirectUri: $redirectUri, code: $code) { success lastSyncId integration { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+        "IntegrationSlackOrgProjectUpdatesPost",
+        "$redirectUri: String!, $code: String!",
+        selection,
+        variables,
+        "integrationSlackOrgProjectUpdatesPost",
+    )
+    .with_entity_field("integration")
+}
+/// `Internal` Slack integration for workspace-level initiative update notifications.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn integration_slack_org_initiative_updates_post<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    redirect_uri: String,
+    code: String,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "redirectUri" : redirect_uri, "code" : code });
+    let selection = String::from(
+        "integrationSlackOrgInitiativeUpdatesPost(redirectUri: $redirectUri, code: $code) { success lastSyncId integration { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+        "IntegrationSlackOrgInitiativeUpdatesPost",
+        "$redirectUri: String!, $code: String!",
+        selection,
+        variables,
+        "integrationSlackOrgInitiativeUpdatesPost",
+    )
+    .with_entity_field("integration")
+}
+/// Imports custom emojis from your Slack workspace.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn integration_slack_import_emojis<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    redirect_uri: String,
+    code: String,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "redirectUri" : redirect_uri, "code" : code });
+    let selection = String::from(
+        "integrationSlackImportEmojis(redirectUri: $redirectUri, code: $code) { success lastSyncId integration { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+        "IntegrationSlackImportEmojis",
+        "$redirectUri: String!, $code: String!",
+        selection,
+        variables,
+        "integrationSlackImportEmojis",
+    )
+    .with_entity_field("integration")
+}
+/// Integrates the workspace with Figma.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn integration_figma<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    redirect_uri: String,
+    code: String,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "redirectUri" : redirect_uri, "code" : code });
+    let selection = String::from(
+        "integrationFigma(redirectUri: $redirectUri, code: $code) { success lastSyncId integration { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+        "IntegrationFigma",
+        "$redirectUri: String!, $code: String!",
+        selection,
+        variables,
+        "integrationFigma",
+    )
+    .with_entity_field("integration")
+}
+/// Integrates the workspace with Gong.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn integration_gong<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    redirect_uri: String,
+    code: String,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "redirectUri" : redirect_uri, "code" : code });
+    let selection = String::from(
+        "integrationGong(redirectUri: $redirectUri, code: $code) { success lastSyncId integration { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+        "IntegrationGong",
+        "$redirectUri: String!, $code: String!",
+        selection,
+        variables,
+        "integrationGong",
+    )
+    .with_entity_field("integration")
+}
+/// Integrates the workspace with Microsoft Teams.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn integration_microsoft_teams<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    redirect_uri: String,
+    code: String,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "redirectUri" : redirect_uri, "code" : code });
+    let selection = String::from(
+        "integrationMicrosoftTeams(redirectUri: $redirectUri, code: $code) { success lastSyncId integration { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+        "IntegrationMicrosoftTeams",
+        "$redirectUri: String!, $code: String!",
+        selection,
+        variables,
+        "integrationMicrosoftTeams",
+    )
+    .with_entity_field("integration")
+}
+/// Connects the user's personal Microsoft account to Linear.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn integration_microsoft_personal_connect<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    redirect_uri: String,
+    code: String,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "redirectUri" : redirect_uri, "code" : code });
+    let selection = String::from(
+        "integrationMicrosoftPersonalConnect(redirectUri: $redirectUri, code: $code) { success lastSyncId integration { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+        "IntegrationMicrosoftPersonalConnect",
+        "$redirectUri: String!, $code: String!",
+        selection,
+        variables,
+        "integrationMicrosoftPersonalConnect",
+    )
+    .with_entity_field("integration")
+}
+/// `Internal` Connect a project to a Microsoft Teams channel. Find-or-update semantics: creates a microsoftTeamsProjectPost integration row if none exists for the project, or overwrites the existing one's team/channel selection. Requires the connecting user to have linked their personal Microsoft account.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn integration_microsoft_teams_project_post<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    membership_type: String,
+    channel_name: String,
+    channel_id: String,
+    team_name: String,
+    team_id: String,
+    project_id: String,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "membershipType" : membership_type, "channelName" : channel_name, "channelId" :
+        channel_id, "teamName" : team_name, "teamId" : team_id, "projectId" : project_id
+        }
+    );
+    let selection = String::from(
+        "integrationMicrosoftTeamsProjectPost(membershipType: $membershipType, channelName: $channelName, channelId: $channelId, teamName: $teamName, teamId: $teamId, projectId: $projectId) { success lastSyncId integration { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+            "IntegrationMicrosoftTeamsProjectPost",
+            "$membershipType: String!, $channelName: String!, $channelId: String!, $teamName: String!, $teamId: String!, $projectId: String!",
+            selection,
+            variables,
+            "integrationMicrosoftTeamsProjectPost",
+        )
+        .with_entity_field("integration")
+}
+/// Integrates the workspace with Google Sheets.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn integration_google_sheets<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    code: String,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "code" : code });
+    let selection =
+        String::from("integrationGoogleSheets(code: $code) { success lastSyncId integration { ")
+            + &T::selection()
+            + " } }";
+    Operation::mutation(
+        "IntegrationGoogleSheets",
+        "$code: String!",
+        selection,
+        variables,
+        "integrationGoogleSheets",
+    )
+    .with_entity_field("integration")
+}
+/// Manually update Google Sheets data.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn refresh_google_sheets_data<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    r#type: Option<String>,
+    id: impl Into<super::ids::IntegrationId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "type" : r#type, "id" : Into:: < super::ids::IntegrationId > ::into(id) }
+    );
+    let selection = String::from(
+        "refreshGoogleSheetsData(type: $type, id: $id) { success lastSyncId integration { ",
+    ) + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "RefreshGoogleSheetsData",
+        "$type: String, $id: String!",
+        selection,
+        variables,
+        "refreshGoogleSheetsData",
+    )
+    .with_entity_field("integration")
+}
+/// Integrates the workspace with Sentry.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn integration_sentry_connect<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    organization_slug: String,
+    code: String,
+    installation_id: String,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "organizationSlug" : organization_slug, "code" : code, "installationId" :
+        installation_id }
+    );
+    let selection = String::from(
+        "integrationSentryConnect(organizationSlug: $organizationSlug, code: $code, installationId: $installationId) { success lastSyncId integration { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+        "IntegrationSentryConnect",
+        "$organizationSlug: String!, $code: String!, $installationId: String!",
+        selection,
+        variables,
+        "integrationSentryConnect",
+    )
+    .with_entity_field("integration")
+}
+/// Integrates the workspace with Front.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn integration_front<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    redirect_uri: String,
+    code: String,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "redirectUri" : redirect_uri, "code" : code });
+    let selection = String::from(
+        "integrationFront(redirectUri: $redirectUri, code: $code) { success lastSyncId integration { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+        "IntegrationFront",
+        "$redirectUri: String!, $code: String!",
+        selection,
+        variables,
+        "integrationFront",
+    )
+    .with_entity_field("integration")
+}
+/// Integrates the workspace with Zendesk.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn integration_zendesk<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    subdomain: String,
+    code: String,
+    scope: String,
+    redirect_uri: String,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "subdomain" : subdomain, "code" : code, "scope" : scope, "redirectUri" :
+        redirect_uri }
+    );
+    let selection = String::from(
+        "integrationZendesk(subdomain: $subdomain, code: $code, scope: $scope, redirectUri: $redirectUri) { success lastSyncId integration { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+        "IntegrationZendesk",
+        "$subdomain: String!, $code: String!, $scope: String!, $redirectUri: String!",
+        selection,
+        variables,
+        "integrationZendesk",
+    )
+    .with_entity_field("integration")
+}
+/// Enables Loom integration for the workspace.
+#[deprecated(note = "Not available.")]
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn integration_loom<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>() -> Operation<T> {
+    let variables = serde_json::json!({});
+    let selection = String::from("integrationLoom() { success lastSyncId integration { ")
+        + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "IntegrationLoom",
+        "",
+        selection,
+        variables,
+        "integrationLoom",
+    )
+    .with_entity_field("integration")
+}
+/// Integrates the workspace with Salesforce.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn integration_salesforce<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    code: String,
+    subdomain: String,
+    redirect_uri: String,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "code" : code, "subdomain" : subdomain, "redirectUri" : redirect_uri }
+    );
+    let selection = String::from(
+        "integrationSalesforce(code: $code, subdomain: $subdomain, redirectUri: $redirectUri) { success lastSyncId integration { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+        "IntegrationSalesforce",
+        "$code: String!, $subdomain: String!, $redirectUri: String!",
+        selection,
+        variables,
+        "integrationSalesforce",
+    )
+    .with_entity_field("integration")
+}
+/// `INTERNAL` Refreshes the Salesforce integration metadata.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn integration_salesforce_metadata_refresh<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    id: impl Into<super::ids::IntegrationId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::IntegrationId > ::into(id) }
+    );
+    let selection = String::from(
+        "integrationSalesforceMetadataRefresh(id: $id) { success lastSyncId integration { ",
+    ) + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "IntegrationSalesforceMetadataRefresh",
+        "$id: String!",
+        selection,
+        variables,
+        "integrationSalesforceMetadataRefresh",
+    )
+    .with_entity_field("integration")
+}
+/// `INTERNAL` Connects the user's personal account with an MCP server.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn integration_mcp_server_personal_connect<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    custom_headers: Option<Vec<McpServerCustomHeaderInput>>,
+    server_url: String,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "customHeaders" : custom_headers, "serverUrl" : server_url }
+    );
+    let selection = String::from(
+        "integrationMcpServerPersonalConnect(customHeaders: $customHeaders, serverUrl: $serverUrl) { success lastSyncId integration { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+        "IntegrationMcpServerPersonalConnect",
+        "$customHeaders: [McpServerCustomHeaderInput!], $serverUrl: String!",
+        selection,
+        variables,
+        "integrationMcpServerPersonalConnect",
+    )
+    .with_entity_field("integration")
+}
+/// `INTERNAL` Connects the workspace with an MCP server.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn integration_mcp_server_connect<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    custom_headers: Option<Vec<McpServerCustomHeaderInput>>,
+    workflow_definition_id: Option<String>,
+    team_id: Option<String>,
+    server_url: String,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "customHeaders" : custom_headers, "workflowDefinitionId" :
+        workflow_definition_id, "teamId" : team_id, "serverUrl" : server_url }
+    );
+    let selection = String::from(
+        "integrationMcpServerConnect(customHeaders: $customHeaders, workflowDefinitionId: $workflowDefinitionId, teamId: $teamId, serverUrl: $serverUrl) { success lastSyncId integration { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+            "IntegrationMcpServerConnect",
+            "$customHeaders: [McpServerCustomHeaderInput!], $workflowDefinitionId: String, $teamId: String, $serverUrl: String!",
+            selection,
+            variables,
+            "integrationMcpServerConnect",
+        )
+        .with_entity_field("integration")
+}
+/// Deletes an integration.
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn integration_delete(
+    skip_installation_deletion: Option<bool>,
+    id: impl Into<super::ids::IntegrationId>,
+) -> Operation<super::types::DeletePayload> {
+    let variables = serde_json::json!(
+        { "skipInstallationDeletion" : skip_installation_deletion, "id" : Into:: <
+        super::ids::IntegrationId > ::into(id) }
+    );
+    let selection = String::from(
+        "integrationDelete(skipInstallationDeletion: $skipInstallationDeletion, id: $id) { ",
+    )
+        + &<super::types::DeletePayload as crate::field_selection::GraphQLFields>::selection()
+        + " }";
+    Operation::mutation(
+        "IntegrationDelete",
+        "$skipInstallationDeletion: Boolean, $id: String!",
+        selection,
+        variables,
+        "integrationDelete",
+    )
+}
+/// Archives an integration.
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn integration_archive(
+    id: impl Into<super::ids::IntegrationId>,
+) -> Operation<super::types::DeletePayload> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::IntegrationId > ::into(id) }
+    );
+    let selection = String::from("integrationArchive(id: $id) { ")
+        + &<super::types::DeletePayload as crate::field_selection::GraphQLFields>::selection()
+        + " }";
+    Operation::mutation(
+        "IntegrationArchive",
+        "$id: String!",
+        selection,
+        variables,
+        "integrationArchive",
+    )
+}
+/// `Internal` Enables Linear Agent Slack workflow access for a Slack or Slack Asks integration.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn integration_slack_workflow_access_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Integration>,
+>(
+    enabled: bool,
+    integration_id: String,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "enabled" : enabled, "integrationId" : integration_id }
+    );
+    let selection = String::from(
+        "integrationSlackWorkflowAccessUpdate(enabled: $enabled, integrationId: $integrationId) { success lastSyncId integration { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+        "IntegrationSlackWorkflowAccessUpdate",
+        "$enabled: Boolean!, $integrationId: String!",
+        selection,
+        variables,
+        "integrationSlackWorkflowAccessUpdate",
+    )
+    .with_entity_field("integration")
+}
+/// Requests a currently unavailable integration.
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn integration_request(
+    input: IntegrationRequestInput,
+) -> Operation<super::types::IntegrationRequestPayload> {
+    let variables = serde_json::json!({ "input" : input });
+    let selection = String::from("integrationRequest(input: $input) { ")
+        + &<super::types::IntegrationRequestPayload as crate::field_selection::GraphQLFields>::selection()
+        + " }";
+    Operation::mutation(
+        "IntegrationRequest",
+        "$input: IntegrationRequestInput!",
+        selection,
+        variables,
+        "integrationRequest",
+    )
+}
+/// Creates an initiative update.
+///
+/// Full type: [`InitiativeUpdate`](super::types::InitiativeUpdate)
+///
+/// Requires the `initiatives` feature.
+#[cfg(feature = "initiatives")]
+pub fn initiative_update_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::InitiativeUpdate>,
+>(
+    input: InitiativeUpdateCreateInput,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "input" : input });
+    let selection = String::from(
+        "initiativeUpdateCreate(input: $input) { success lastSyncId initiativeUpdate { ",
+    ) + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "InitiativeUpdateCreate",
+        "$input: InitiativeUpdateCreateInput!",
+        selection,
+        variables,
+        "initiativeUpdateCreate",
+    )
+    .with_entity_field("initiativeUpdate")
+    .with_client_id(
+        "input",
+        String::from("initiativeUpdate(id: $id) { ") + &T::selection() + " }",
+    )
+}
+/// Updates an initiative update.
+///
+/// Full type: [`InitiativeUpdate`](super::types::InitiativeUpdate)
+///
+/// Requires the `initiatives` feature.
+#[cfg(feature = "initiatives")]
+pub fn initiative_update_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::InitiativeUpdate>,
+>(
+    input: InitiativeUpdateUpdateInput,
+    id: impl Into<super::ids::InitiativeUpdateId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "input" : input, "id" : Into:: < super::ids::InitiativeUpdateId > ::into(id) }
+    );
+    let selection = String::from(
+        "initiativeUpdateUpdate(input: $input, id: $id) { success lastSyncId initiativeUpdate { ",
+    ) + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "InitiativeUpdateUpdate",
+        "$input: InitiativeUpdateUpdateInput!, $id: String!",
+        selection,
+        variables,
+        "initiativeUpdateUpdate",
+    )
+    .with_entity_field("initiativeUpdate")
+}
+/// Archives an initiative update.
+///
+/// Full type: [`InitiativeUpdate`](super::types::InitiativeUpdate)
+///
+/// Requires the `initiatives` feature.
+#[cfg(feature = "initiatives")]
+pub fn initiative_update_archive<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::InitiativeUpdate>,
+>(
+    id: impl Into<super::ids::InitiativeUpdateId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::InitiativeUpdateId > ::into(id) }
+    );
+    let selection = String::from("initiativeUpdateArchive(id: $id) { success lastSyncId entity { ")
+        + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "InitiativeUpdateArchive",
+        "$id: String!",
+        selection,
+        variables,
+        "initiativeUpdateArchive",
+    )
+    .with_entity_field("entity")
+}
+/// Create a notification to remind a user about an initiative update.
+///
+/// Requires the `initiatives` feature.
+#[cfg(feature = "initiatives")]
+pub fn create_initiative_update_reminder(
+    user_id: Option<String>,
+    initiative_id: String,
+) -> Operation<super::types::InitiativeUpdateReminderPayload> {
+    let variables = serde_json::json!(
+        { "userId" : user_id, "initiativeId" : initiative_id }
+    );
+    let selection = String::from(
+        "createInitiativeUpdateReminder(userId: $userId, initiativeId: $initiativeId) { ",
+    )
+        + &<super::types::InitiativeUpdateReminderPayload as crate::field_selection::GraphQLFields>::selection()
+        + " }";
+    Operation::mutation(
+        "CreateInitiativeUpdateReminder",
+        "$userId: String, $initiativeId: String!",
+        selection,
+        variables,
+        "createInitiativeUpdateReminder",
+    )
+}
+/// Unarchives an initiative update.
+///
+/// Full type: [`InitiativeUpdate`](super::types::InitiativeUpdate)
+///
+/// Requires the `initiatives` feature.
+#[cfg(feature = "initiatives")]
+pub fn initiative_update_unarchive<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::InitiativeUpdate>,
+>(
+    id: impl Into<super::ids::InitiativeUpdateId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::InitiativeUpdateId > ::into(id) }
+    );
+    let selection =
+        String::from("initiativeUpdateUnarchive(id: $id) { success lastSyncId entity { ")
+            + &T::selection()
+            + " } }";
+    Operation::mutation(
+        "InitiativeUpdateUnarchive",
+        "$id: String!",
+        selection,
+        variables,
+        "initiativeUpdateUnarchive",
+    )
+    .with_entity_field("entity")
+}
+/// Associates a project with an initiative. A project can only appear once in an initiative hierarchy.
+///
+/// Full type: [`InitiativeToProject`](super::types::InitiativeToProject)
+///
+/// Requires the `initiatives` feature.
+#[cfg(feature = "initiatives")]
+pub fn initiative_to_project_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::InitiativeToProject>,
+>(
+    input: InitiativeToProjectCreateInput,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "input" : input });
+    let selection = String::from(
+        "initiativeToProjectCreate(input: $input) { success lastSyncId initiativeToProject { ",
+    ) + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "InitiativeToProjectCreate",
+        "$input: InitiativeToProjectCreateInput!",
+        selection,
+        variables,
+        "initiativeToProjectCreate",
+    )
+    .with_entity_field("initiativeToProject")
+    .with_client_id(
+        "input",
+        String::from("initiativeToProject(id: $id) { ") + &T::selection() + " }",
+    )
+}
+/// Updates an initiative-to-project association, such as its sort order.
+///
+/// Full type: [`InitiativeToProject`](super::types::InitiativeToProject)
+///
+/// Requires the `initiatives` feature.
+#[cfg(feature = "initiatives")]
+pub fn initiative_to_project_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::InitiativeToProject>,
+>(
+    input: InitiativeToProjectUpdateInput,
+    id: impl Into<super::ids::InitiativeToProjectId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "input" : input, "id" : Into:: < super::ids::InitiativeToProjectId > ::into(id)
+        }
+    );
+    let selection = String::from(
+        "initiativeToProjectUpdate(input: $input, id: $id) { success lastSyncId initiativeToProject { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+        "InitiativeToProjectUpdate",
+        "$input: InitiativeToProjectUpdateInput!, $id: String!",
+        selection,
+        variables,
+        "initiativeToProjectUpdate",
+    )
+    .with_entity_field("initiativeToProject")
+}
+/// Removes a project from an initiative.
+///
+/// Requires the `initiatives` feature.
+#[cfg(feature = "initiatives")]
+pub fn initiative_to_project_delete(
+    id: impl Into<super::ids::InitiativeToProjectId>,
+) -> Operation<super::types::DeletePayload> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::InitiativeToProjectId > ::into(id) }
+    );
+    let selection = String::from("initiativeToProjectDelete(id: $id) { ")
+        + &<super::types::DeletePayload as crate::field_selection::GraphQLFields>::selection()
+        + " }";
+    Operation::mutation(
+        "InitiativeToProjectDelete",
+        "$id: String!",
+        selection,
+        variables,
+        "initiativeToProjectDelete",
+    )
+}
+/// Creates a new initiative.
+///
+/// Full type: [`Initiative`](super::types::Initiative)
+///
+/// Requires the `initiatives` feature.
+#[cfg(feature = "initiatives")]
+pub fn initiative_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Initiative>,
+>(
+    input: InitiativeCreateInput,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "input" : input });
+    let selection =
+        String::from("initiativeCreate(input: $input) { success lastSyncId initiative { ")
+            + &T::selection()
+            + " } }";
+    Operation::mutation(
+        "InitiativeCreate",
+        "$input: InitiativeCreateInput!",
+        selection,
+        variables,
+        "initiativeCreate",
+    )
+    .with_entity_field("initiative")
+    .with_client_id(
+        "input",
+        String::from("initiative(id: $id) { ") + &T::selection() + " }",
+    )
+}
+/// Updates an initiative.
+///
+/// Full type: [`Initiative`](super::types::Initiative)
+///
+/// Requires the `initiatives` feature.
+#[cfg(feature = "initiatives")]
+pub fn initiative_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Initiative>,
+>(
+    input: InitiativeUpdateInput,
+    id: impl Into<super::ids::InitiativeId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "input" : input, "id" : Into:: < super::ids::InitiativeId > ::into(id) }
+    );
+    let selection =
+        String::from("initiativeUpdate(input: $input, id: $id) { success lastSyncId initiative { ")
+            + &T::selection()
+            + " } }";
+    Operation::mutation(
+        "InitiativeUpdate",
+        "$input: InitiativeUpdateInput!, $id: String!",
+        selection,
+        variables,
+        "initiativeUpdate",
+    )
+    .with_entity_field("initiative")
+}
+/// Archives an initiative.
+///
+/// Full type: [`Initiative`](super::types::Initiative)
+///
+/// Requires the `initiatives` feature.
+#[cfg(feature = "initiatives")]
+pub fn initiative_archive<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Initiative>,
+>(
+    id: impl Into<super::ids::InitiativeId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::InitiativeId > ::into(id) }
+    );
+    let selection = String::from("initiativeArchive(id: $id) { success lastSyncId entity { ")
+        + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "InitiativeArchive",
+        "$id: String!",
+        selection,
+        variables,
+        "initiativeArchive",
+    )
+    .with_entity_field("entity")
+}
+/// Unarchives an initiative.
+///
+/// Full type: [`Initiative`](super::types::Initiative)
+///
+/// Requires the `initiatives` feature.
+#[cfg(feature = "initiatives")]
+pub fn initiative_unarchive<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Initiative>,
+>(
+    id: impl Into<super::ids::InitiativeId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::InitiativeId > ::into(id) }
+    );
+    let selection = String::from("initiativeUnarchive(id: $id) { success lastSyncId entity { ")
+        + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "InitiativeUnarchive",
+        "$id: String!",
+        selection,
+        variables,
+        "initiativeUnarchive",
+    )
+    .with_entity_field("entity")
+}
+/// Deletes (trashes) an initiative.
+///
+/// Requires the `initiatives` feature.
+#[cfg(feature = "initiatives")]
+pub fn initiative_delete(
+    id: impl Into<super::ids::InitiativeId>,
+) -> Operation<super::types::DeletePayload> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::InitiativeId > ::into(id) }
+    );
+    let selection = String::from("initiativeDelete(id: $id) { ")
+        + &<super::types::DeletePayload as crate::field_selection::GraphQLFields>::selection()
+        + " }";
+    Operation::mutation(
+        "InitiativeDelete",
+        "$id: String!",
+        selection,
+        variables,
+        "initiativeDelete",
+    )
+}
+/// Creates a new parent-child relation between two initiatives. The relation cannot create cycles or exceed maximum nesting depth.
+///
+/// Full type: [`InitiativeRelation`](super::types::InitiativeRelation)
+///
+/// Requires the `initiatives` feature.
+#[cfg(feature = "initiatives")]
+pub fn initiative_relation_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::InitiativeRelation>,
+>(
+    input: InitiativeRelationCreateInput,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "input" : input });
+    let selection = String::from(
+        "initiativeRelationCreate(input: $input) { success lastSyncId initiativeRelation { ",
+    ) + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "InitiativeRelationCreate",
+        "$input: InitiativeRelationCreateInput!",
+        selection,
+        variables,
+        "initiativeRelationCreate",
+    )
+    .with_entity_field("initiativeRelation")
+    .with_client_id(
+        "input",
+        String::from("initiativeRelation(id: $id) { ") + &T::selection() + " }",
+    )
+}
+/// Updates an initiative relation.
+///
+/// Full type: [`InitiativeRelation`](super::types::InitiativeRelation)
+///
+/// Requires the `initiatives` feature.
+#[cfg(feature = "initiatives")]
+pub fn initiative_relation_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::InitiativeRelation>,
+>(
+    input: InitiativeRelationUpdateInput,
+    id: impl Into<super::ids::InitiativeRelationId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "input" : input, "id" : Into:: < super::ids::InitiativeRelationId > ::into(id)
+        }
+    );
+    let selection = String::from(
+        "initiativeRelationUpdate(input: $input, id: $id) { success lastSyncId initiativeRelation { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+        "InitiativeRelationUpdate",
+        "$input: InitiativeRelationUpdateInput!, $id: String!",
+        selection,
+        variables,
+        "initiativeRelationUpdate",
+    )
+    .with_entity_field("initiativeRelation")
+}
+/// Deletes an initiative relation.
+///
+/// Requires the `initiatives` feature.
+#[cfg(feature = "initiatives")]
+pub fn initiative_relation_delete(
+    id: impl Into<super::ids::InitiativeRelationId>,
+) -> Operation<super::types::DeletePayload> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::InitiativeRelationId > ::into(id) }
+    );
+    let selection = String::from("initiativeRelationDelete(id: $id) { ")
+        + &<super::types::DeletePayload as crate::field_selection::GraphQLFields>::selection()
+        + " }";
+    Operation::mutation(
+        "InitiativeRelationDelete",
+        "$id: String!",
+        selection,
+        variables,
+        "initiativeRelationDelete",
+    )
+}
+/// Creates a new Git target branch definition that scopes automation rules to pull requests targeting a specific branch pattern.
+///
+/// Full type: [`GitAutomationTargetBranch`](super::types::GitAutomationTargetBranch)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn git_automation_target_branch_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::GitAutomationTargetBranch>,
+>(
+    input: GitAutomationTargetBranchCreateInput,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "input" : input });
+    let selection = String::from(
+        "gitAutomationTargetBranchCreate(input: $input) { success lastSyncId targetBranch { ",
+    ) + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "GitAutomationTargetBranchCreate",
+        "$input: GitAutomationTargetBranchCreateInput!",
+        selection,
+        variables,
+        "gitAutomationTargetBranchCreate",
+    )
+    .with_entity_field("targetBranch")
+}
+/// Updates an existing Git target branch definition, including its branch pattern and regex flag.
+///
+/// Full type: [`GitAutomationTargetBranch`](super::types::GitAutomationTargetBranch)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn git_automation_target_branch_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::GitAutomationTargetBranch>,
+>(
+    input: GitAutomationTargetBranchUpdateInput,
+    id: impl Into<super::ids::GitAutomationTargetBranchId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "input" : input, "id" : Into:: < super::ids::GitAutomationTargetBranchId >
+        ::into(id) }
+    );
+    let selection = String::from(
+        "gitAutomationTargetBranchUpdate(input: $input, id: $id) { success lastSyncId targetBranch { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+        "GitAutomationTargetBranchUpdate",
+        "$input: GitAutomationTargetBranchUpdateInput!, $id: String!",
+        selection,
+        variables,
+        "gitAutomationTargetBranchUpdate",
+    )
+    .with_entity_field("targetBranch")
+}
+/// Deletes a Git target branch definition and its associated automation rules.
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn git_automation_target_branch_delete(
+    id: impl Into<super::ids::GitAutomationTargetBranchId>,
+) -> Operation<super::types::DeletePayload> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::GitAutomationTargetBranchId > ::into(id) }
+    );
+    let selection = String::from("gitAutomationTargetBranchDelete(id: $id) { ")
+        + &<super::types::DeletePayload as crate::field_selection::GraphQLFields>::selection()
+        + " }";
+    Operation::mutation(
+        "GitAutomationTargetBranchDelete",
+        "$id: String!",
+        selection,
+        variables,
+        "gitAutomationTargetBranchDelete",
+    )
+}
+/// Creates a new Git automation rule that maps a Git event to a workflow state transition for a team.
+///
+/// Full type: [`GitAutomationState`](super::types::GitAutomationState)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn git_automation_state_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::GitAutomationState>,
+>(
+    input: GitAutomationStateCreateInput,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "input" : input });
+    let selection = String::from(
+        "gitAutomationStateCreate(input: $input) { success lastSyncId gitAutomationState { ",
+    ) + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "GitAutomationStateCreate",
+        "$input: GitAutomationStateCreateInput!",
+        selection,
+        variables,
+        "gitAutomationStateCreate",
+    )
+    .with_entity_field("gitAutomationState")
+}
+/// Updates an existing Git automation rule, including its workflow state, target branch, and triggering event.
+///
+/// Full type: [`GitAutomationState`](super::types::GitAutomationState)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn git_automation_state_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::GitAutomationState>,
+>(
+    input: GitAutomationStateUpdateInput,
+    id: impl Into<super::ids::GitAutomationStateId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "input" : input, "id" : Into:: < super::ids::GitAutomationStateId > ::into(id)
+        }
+    );
+    let selection = String::from(
+        "gitAutomationStateUpdate(input: $input, id: $id) { success lastSyncId gitAutomationState { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+        "GitAutomationStateUpdate",
+        "$input: GitAutomationStateUpdateInput!, $id: String!",
+        selection,
+        variables,
+        "gitAutomationStateUpdate",
+    )
+    .with_entity_field("gitAutomationState")
+}
+/// Deletes a Git automation rule.
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn git_automation_state_delete(
+    id: impl Into<super::ids::GitAutomationStateId>,
+) -> Operation<super::types::DeletePayload> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::GitAutomationStateId > ::into(id) }
+    );
+    let selection = String::from("gitAutomationStateDelete(id: $id) { ")
+        + &<super::types::DeletePayload as crate::field_selection::GraphQLFields>::selection()
+        + " }";
+    Operation::mutation(
+        "GitAutomationStateDelete",
+        "$id: String!",
+        selection,
+        variables,
+        "gitAutomationStateDelete",
+    )
+}
+/// Creates a new favorite for the authenticated user. Exactly one target entity must be specified. If a favorite for the same entity already exists, the existing favorite is returned (upsert behavior).
+///
+/// Full type: [`Favorite`](super::types::Favorite)
+///
+/// Requires the `views` feature.
+#[cfg(feature = "views")]
+pub fn favorite_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Favorite>,
+>(
+    input: FavoriteCreateInput,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "input" : input });
+    let selection = String::from("favoriteCreate(input: $input) { success lastSyncId favorite { ")
+        + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "FavoriteCreate",
+        "$input: FavoriteCreateInput!",
+        selection,
+        variables,
+        "favoriteCreate",
+    )
+    .with_entity_field("favorite")
+    .with_client_id(
+        "input",
+        String::from("favorite(id: $id) { ") + &T::selection() + " }",
+    )
+}
+/// Updates a favorite's position, parent folder, or folder name.
+///
+/// Full type: [`Favorite`](super::types::Favorite)
+///
+/// Requires the `views` feature.
+#[cfg(feature = "views")]
+pub fn favorite_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Favorite>,
+>(
+    input: FavoriteUpdateInput,
+    id: impl Into<super::ids::FavoriteId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "input" : input, "id" : Into:: < super::ids::FavoriteId > ::into(id) }
+    );
+    let selection =
+        String::from("favoriteUpdate(input: $input, id: $id) { success lastSyncId favorite { ")
+            + &T::selection()
+            + " } }";
+    Operation::mutation(
+        "FavoriteUpdate",
+        "$input: FavoriteUpdateInput!, $id: String!",
+        selection,
+        variables,
+        "favoriteUpdate",
+    )
+    .with_entity_field("favorite")
+}
+/// Deletes a favorite, removing it from the user's sidebar. This is an idempotent operation -- deleting a non-existent favorite succeeds silently.
+///
+/// Requires the `views` feature.
+#[cfg(feature = "views")]
+pub fn favorite_delete(
+    id: impl Into<super::ids::FavoriteId>,
+) -> Operation<super::types::DeletePayload> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::FavoriteId > ::into(id) }
+    );
+    let selection = String::from("favoriteDelete(id: $id) { ")
+        + &<super::types::DeletePayload as crate::field_selection::GraphQLFields>::selection()
+        + " }";
+    Operation::mutation(
+        "FavoriteDelete",
+        "$id: String!",
+        selection,
+        variables,
+        "favoriteDelete",
+    )
+}
+/// Track an anonymous analytics event.
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub fn track_anonymous_event(
+    input: EventTrackingInput,
+) -> Operation<super::types::EventTrackingPayload> {
+    let variables = serde_json::json!({ "input" : input });
+    let selection = String::from("trackAnonymousEvent(input: $input) { ")
+        + &<super::types::EventTrackingPayload as crate::field_selection::GraphQLFields>::selection(
+        )
+        + " }";
+    Operation::mutation(
+        "TrackAnonymousEvent",
+        "$input: EventTrackingInput!",
+        selection,
+        variables,
+        "trackAnonymousEvent",
+    )
+}
+/// Creates a new external link on an initiative, project, team, release, or cycle.
+///
+/// Full type: [`EntityExternalLink`](super::types::EntityExternalLink)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub fn entity_external_link_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::EntityExternalLink>,
+>(
+    input: EntityExternalLinkCreateInput,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "input" : input });
+    let selection = String::from(
+        "entityExternalLinkCreate(input: $input) { success lastSyncId entityExternalLink { ",
+    ) + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "EntityExternalLinkCreate",
+        "$input: EntityExternalLinkCreateInput!",
+        selection,
+        variables,
+        "entityExternalLinkCreate",
+    )
+    .with_entity_field("entityExternalLink")
+    .with_client_id(
+        "input",
+        String::from("entityExternalLink(id: $id) { ") + &T::selection() + " }",
+    )
+}
+/// Updates an existing entity external link's URL, label, or sort order.
+///
+/// Full type: [`EntityExternalLink`](super::types::EntityExternalLink)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub fn entity_external_link_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::EntityExternalLink>,
+>(
+    input: EntityExternalLinkUpdateInput,
+    id: impl Into<super::ids::EntityExternalLinkId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "input" : input, "id" : Into:: < super::ids::EntityExternalLinkId > ::into(id)
+        }
+    );
+    let selection = String::from(
+        "entityExternalLinkUpdate(input: $input, id: $id) { success lastSyncId entityExternalLink { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+        "EntityExternalLinkUpdate",
+        "$input: EntityExternalLinkUpdateInput!, $id: String!",
+        selection,
+        variables,
+        "entityExternalLinkUpdate",
+    )
+    .with_entity_field("entityExternalLink")
+}
+/// Deletes an entity external link.
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub fn entity_external_link_delete(
+    id: impl Into<super::ids::EntityExternalLinkId>,
+) -> Operation<super::types::DeletePayload> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::EntityExternalLinkId > ::into(id) }
+    );
+    let selection = String::from("entityExternalLinkDelete(id: $id) { ")
+        + &<super::types::DeletePayload as crate::field_selection::GraphQLFields>::selection()
+        + " }";
+    Operation::mutation(
+        "EntityExternalLinkDelete",
+        "$id: String!",
+        selection,
+        variables,
+        "entityExternalLinkDelete",
+    )
+}
+/// Creates a custom emoji.
+///
+/// Full type: [`Emoji`](super::types::Emoji)
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub fn emoji_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Emoji>,
+>(
+    input: EmojiCreateInput,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "input" : input });
+    let selection = String::from("emojiCreate(input: $input) { success lastSyncId emoji { ")
+        + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "EmojiCreate",
+        "$input: EmojiCreateInput!",
+        selection,
+        variables,
+        "emojiCreate",
+    )
+    .with_entity_field("emoji")
+    .with_client_id(
+        "input",
+        String::from("emoji(id: $id) { ") + &T::selection() + " }",
+    )
+}
+/// Deletes an emoji.
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub fn emoji_delete(id: impl Into<super::ids::EmojiId>) -> Operation<super::types::DeletePayload> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::EmojiId > ::into(id) }
+    );
+    let selection = String::from("emojiDelete(id: $id) { ")
+        + &<super::types::DeletePayload as crate::field_selection::GraphQLFields>::selection()
+        + " }";
+    Operation::mutation(
+        "EmojiDelete",
+        "$id: String!",
+        selection,
+        variables,
+        "emojiDelete",
+    )
+}
+/// Unsubscribes the user from one type of email.
+///
+/// Requires the `notifications` feature.
+#[cfg(feature = "notifications")]
+pub fn email_unsubscribe(
+    input: EmailUnsubscribeInput,
+) -> Operation<super::types::EmailUnsubscribePayload> {
+    let variables = serde_json::json!({ "input" : input });
+    let selection = String::from("emailUnsubscribe(input: $input) { ")
+        + &<super::types::EmailUnsubscribePayload as crate::field_selection::GraphQLFields>::selection()
+        + " }";
+    Operation::mutation(
+        "EmailUnsubscribe",
+        "$input: EmailUnsubscribeInput!",
+        selection,
+        variables,
+        "emailUnsubscribe",
+    )
+}
+/// Creates a new email intake address.
+///
+/// Full type: [`EmailIntakeAddress`](super::types::EmailIntakeAddress)
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+pub fn email_intake_address_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::EmailIntakeAddress>,
+>(
+    input: EmailIntakeAddressCreateInput,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "input" : input });
+    let selection = String::from(
+        "emailIntakeAddressCreate(input: $input) { success lastSyncId emailIntakeAddress { ",
+    ) + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "EmailIntakeAddressCreate",
+        "$input: EmailIntakeAddressCreateInput!",
+        selection,
+        variables,
+        "emailIntakeAddressCreate",
+    )
+    .with_entity_field("emailIntakeAddress")
+    .with_client_id(
+        "input",
+        String::from("emailIntakeAddress(id: $id) { ") + &T::selection() + " }",
+    )
+}
+/// Rotates an existing email intake address.
+///
+/// Full type: [`EmailIntakeAddress`](super::types::EmailIntakeAddress)
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+pub fn email_intake_address_rotate<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::EmailIntakeAddress>,
+>(
+    id: impl Into<super::ids::EmailIntakeAddressId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::EmailIntakeAddressId > ::into(id) }
+    );
+    let selection = String::from(
+        "emailIntakeAddressRotate(id: $id) { success lastSyncId emailIntakeAddress { ",
+    ) + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "EmailIntakeAddressRotate",
+        "$id: String!",
+        selection,
+        variables,
+        "emailIntakeAddressRotate",
+    )
+    .with_entity_field("emailIntakeAddress")
+}
+/// Updates an existing email intake address.
+///
+/// Full type: [`EmailIntakeAddress`](super::types::EmailIntakeAddress)
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+pub fn email_intake_address_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::EmailIntakeAddress>,
+>(
+    input: EmailIntakeAddressUpdateInput,
+    id: impl Into<super::ids::EmailIntakeAddressId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "input" : input, "id" : Into:: < super::ids::EmailIntakeAddressId > ::into(id)
+        }
+    );
+    let selection = String::from(
+        "emailIntakeAddressUpdate(input: $input, id: $id) { success lastSyncId emailIntakeAddress { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+        "EmailIntakeAddressUpdate",
+        "$input: EmailIntakeAddressUpdateInput!, $id: String!",
+        selection,
+        variables,
+        "emailIntakeAddressUpdate",
+    )
+    .with_entity_field("emailIntakeAddress")
+}
+/// Deletes an email intake address object.
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+pub fn email_intake_address_delete(
+    id: impl Into<super::ids::EmailIntakeAddressId>,
+) -> Operation<super::types::DeletePayload> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::EmailIntakeAddressId > ::into(id) }
+    );
+    let selection = String::from("emailIntakeAddressDelete(id: $id) { ")
+        + &<super::types::DeletePayload as crate::field_selection::GraphQLFields>::selection()
+        + " }";
+    Operation::mutation(
+        "EmailIntakeAddressDelete",
+        "$id: String!",
+        selection,
+        variables,
+        "emailIntakeAddressDelete",
+    )
+}
+/// Creates a new document.
+///
+/// Full type: [`Document`](super::types::Document)
+pub fn document_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Document>,
+>(
+    input: DocumentCreateInput,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "input" : input });
+    let selection = String::from("documentCreate(input: $input) { success lastSyncId document { ")
+        + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "DocumentCreate",
+        "$input: DocumentCreateInput!",
+        selection,
+        variables,
+        "documentCreate",
+    )
+    .with_entity_field("document")
+    .with_client_id(
+        "input",
+        String::from("document(id: $id) { ") + &T::selection() + " }",
+    )
+}
+/// Updates a document.
+///
+/// Full type: [`Document`](super::types::Document)
+pub fn document_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Document>,
+>(
+    input: DocumentUpdateInput,
+    id: impl Into<super::ids::DocumentId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "input" : input, "id" : Into:: < super::ids::DocumentId > ::into(id) }
+    );
+    let selection =
+        String::from("documentUpdate(input: $input, id: $id) { success lastSyncId document { ")
+            + &T::selection()
+            + " } }";
+    Operation::mutation(
+        "DocumentUpdate",
+        "$input: DocumentUpdateInput!, $id: String!",
+        selection,
+        variables,
+        "documentUpdate",
+    )
+    .with_entity_field("document")
+}
+/// Deletes (trashes) a document. The document is marked as trashed and archived, but not permanently removed.
+///
+/// Full type: [`Document`](super::types::Document)
+pub fn document_delete<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Document>,
+>(
+    id: impl Into<super::ids::DocumentId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::DocumentId > ::into(id) }
+    );
+    let selection = String::from("documentDelete(id: $id) { success lastSyncId entity { ")
+        + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "DocumentDelete",
+        "$id: String!",
+        selection,
+        variables,
+        "documentDelete",
+    )
+    .with_entity_field("entity")
+}
+/// Restores a previously trashed document by unarchiving it.
+///
+/// Full type: [`Document`](super::types::Document)
+///
+/// Requires the `documents` feature.
+#[cfg(feature = "documents")]
+pub fn document_unarchive<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Document>,
+>(
+    id: impl Into<super::ids::DocumentId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::DocumentId > ::into(id) }
+    );
+    let selection = String::from("documentUnarchive(id: $id) { success lastSyncId entity { ")
+        + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "DocumentUnarchive",
+        "$id: String!",
+        selection,
+        variables,
+        "documentUnarchive",
+    )
+    .with_entity_field("entity")
+}
+/// Creates a new cycle.
+///
+/// Full type: [`Cycle`](super::types::Cycle)
+///
+/// Requires the `cycles` feature.
+#[cfg(feature = "cycles")]
+pub fn cycle_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Cycle>,
+>(
+    input: CycleCreateInput,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "input" : input });
+    let selection = String::from("cycleCreate(input: $input) { success lastSyncId cycle { ")
+        + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "CycleCreate",
+        "$input: CycleCreateInput!",
+        selection,
+        variables,
+        "cycleCreate",
+    )
+    .with_entity_field("cycle")
+    .with_client_id(
+        "input",
+        String::from("cycle(id: $id) { ") + &T::selection() + " }",
+    )
+}
+/// Updates a cycle.
+///
+/// Full type: [`Cycle`](super::types::Cycle)
+///
+/// Requires the `cycles` feature.
+#[cfg(feature = "cycles")]
+pub fn cycle_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Cycle>,
+>(
+    input: CycleUpdateInput,
+    id: impl Into<super::ids::CycleId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "input" : input, "id" : Into:: < super::ids::CycleId > ::into(id) }
+    );
+    let selection =
+        String::from("cycleUpdate(input: $input, id: $id) { success lastSyncId cycle { ")
+            + &T::selection()
+            + " } }";
+    Operation::mutation(
+        "CycleUpdate",
+        "$input: CycleUpdateInput!, $id: String!",
+        selection,
+        variables,
+        "cycleUpdate",
+    )
+    .with_entity_field("cycle")
+}
+/// Archives a cycle. All issues currently assigned to the cycle are unlinked from it before archiving.
+///
+/// Full type: [`Cycle`](super::types::Cycle)
+///
+/// Requires the `cycles` feature.
+#[cfg(feature = "cycles")]
+pub fn cycle_archive<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Cycle>,
+>(
+    id: impl Into<super::ids::CycleId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::CycleId > ::into(id) }
+    );
+    let selection = String::from("cycleArchive(id: $id) { success lastSyncId entity { ")
+        + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "CycleArchive",
+        "$id: String!",
+        selection,
+        variables,
+        "cycleArchive",
+    )
+    .with_entity_field("entity")
+}
+/// Shifts all cycles starts and ends by a certain number of days, starting from the provided cycle onwards.
+///
+/// Full type: [`Cycle`](super::types::Cycle)
+///
+/// Requires the `cycles` feature.
+#[cfg(feature = "cycles")]
+pub fn cycle_shift_all<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Cycle>,
+>(
+    input: CycleShiftAllInput,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "input" : input });
+    let selection = String::from("cycleShiftAll(input: $input) { success lastSyncId cycle { ")
+        + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "CycleShiftAll",
+        "$input: CycleShiftAllInput!",
+        selection,
+        variables,
+        "cycleShiftAll",
+    )
+    .with_entity_field("cycle")
+}
+/// Starts the upcoming cycle as of midnight today. Completes the previous cycle if it has not yet ended. Only the next upcoming (not yet started) cycle for the team can be started.
+///
+/// Full type: [`Cycle`](super::types::Cycle)
+///
+/// Requires the `cycles` feature.
+#[cfg(feature = "cycles")]
+pub fn cycle_start_upcoming_cycle_today<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Cycle>,
+>(
+    id: impl Into<super::ids::CycleId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::CycleId > ::into(id) }
+    );
+    let selection =
+        String::from("cycleStartUpcomingCycleToday(id: $id) { success lastSyncId cycle { ")
+            + &T::selection()
+            + " } }";
+    Operation::mutation(
+        "CycleStartUpcomingCycleToday",
+        "$id: String!",
+        selection,
+        variables,
+        "cycleStartUpcomingCycleToday",
+    )
+    .with_entity_field("cycle")
+}
+/// Creates a new customer tier.
+///
+/// Full type: [`CustomerTier`](super::types::CustomerTier)
+///
+/// Requires the `customers` feature.
+#[cfg(feature = "customers")]
+pub fn customer_tier_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::CustomerTier>,
+>(
+    input: CustomerTierCreateInput,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "input" : input });
+    let selection = String::from("customerTierCreate(input: $input) { success lastSyncId tier { ")
+        + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "CustomerTierCreate",
+        "$input: CustomerTierCreateInput!",
+        selection,
+        variables,
+        "customerTierCreate",
+    )
+    .with_entity_field("tier")
+    .with_client_id(
+        "input",
+        String::from("customerTier(id: $id) { ") + &T::selection() + " }",
+    )
+}
+/// Updates a customer tier.
+///
+/// Full type: [`CustomerTier`](super::types::CustomerTier)
+///
+/// Requires the `customers` feature.
+#[cfg(feature = "customers")]
+pub fn customer_tier_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::CustomerTier>,
+>(
+    input: CustomerTierUpdateInput,
+    id: impl Into<super::ids::CustomerTierId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "input" : input, "id" : Into:: < super::ids::CustomerTierId > ::into(id) }
+    );
+    let selection =
+        String::from("customerTierUpdate(input: $input, id: $id) { success lastSyncId tier { ")
+            + &T::selection()
+            + " } }";
+    Operation::mutation(
+        "CustomerTierUpdate",
+        "$input: CustomerTierUpdateInput!, $id: String!",
+        selection,
+        variables,
+        "customerTierUpdate",
+    )
+    .with_entity_field("tier")
+}
+/// Deletes a customer tier. The tier must not be in use by any customers.
+///
+/// Requires the `customers` feature.
+#[cfg(feature = "customers")]
+pub fn customer_tier_delete(
+    id: impl Into<super::ids::CustomerTierId>,
+) -> Operation<super::types::DeletePayload> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::CustomerTierId > ::into(id) }
+    );
+    let selection = String::from("customerTierDelete(id: $id) { ")
+        + &<super::types::DeletePayload as crate::field_selection::GraphQLFields>::selection()
+        + " }";
+    Operation::mutation(
+        "CustomerTierDelete",
+        "$id: String!",
+        selection,
+        variables,
+        "customerTierDelete",
+    )
+}
+/// Creates a new customer status.
+///
+/// Full type: [`CustomerStatus`](super::types::CustomerStatus)
+///
+/// Requires the `customers` feature.
+#[cfg(feature = "customers")]
+pub fn customer_status_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::CustomerStatus>,
+>(
+    input: CustomerStatusCreateInput,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "input" : input });
+    let selection =
+        String::from("customerStatusCreate(input: $input) { success lastSyncId status { ")
+            + &T::selection()
+            + " } }";
+    Operation::mutation(
+        "CustomerStatusCreate",
+        "$input: CustomerStatusCreateInput!",
+        selection,
+        variables,
+        "customerStatusCreate",
+    )
+    .with_entity_field("status")
+    .with_client_id(
+        "input",
+        String::from("customerStatus(id: $id) { ") + &T::selection() + " }",
+    )
+}
+/// Updates a customer status.
+///
+/// Full type: [`CustomerStatus`](super::types::CustomerStatus)
+///
+/// Requires the `customers` feature.
+#[cfg(feature = "customers")]
+pub fn customer_status_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::CustomerStatus>,
+>(
+    input: CustomerStatusUpdateInput,
+    id: impl Into<super::ids::CustomerStatusId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "input" : input, "id" : Into:: < super::ids::CustomerStatusId > ::into(id) }
+    );
+    let selection =
+        String::from("customerStatusUpdate(input: $input, id: $id) { success lastSyncId status { ")
+            + &T::selection()
+            + " } }";
+    Operation::mutation(
+        "CustomerStatusUpdate",
+        "$input: CustomerStatusUpdateInput!, $id: String!",
+        selection,
+        variables,
+        "customerStatusUpdate",
+    )
+    .with_entity_field("status")
+}
+/// Deletes a customer status. Cannot delete the last remaining status in a workspace, and the status must not be in use by any customers.
+///
+/// Requires the `customers` feature.
+#[cfg(feature = "customers")]
+pub fn customer_status_delete(
+    id: impl Into<super::ids::CustomerStatusId>,
+) -> Operation<super::types::DeletePayload> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::CustomerStatusId > ::into(id) }
+    );
+    let selection = String::from("customerStatusDelete(id: $id) { ")
+        + &<super::types::DeletePayload as crate::field_selection::GraphQLFields>::selection()
+        + " }";
+    Operation::mutation(
+        "CustomerStatusDelete",
+        "$id: String!",
+        selection,
+        variables,
+        "customerStatusDelete",
+    )
+}
+/// Creates a new customer.
+///
+/// Full type: [`Customer`](super::types::Customer)
+///
+/// Requires the `customers` feature.
+#[cfg(feature = "customers")]
+pub fn customer_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Customer>,
+>(
+    input: CustomerCreateInput,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "input" : input });
+    let selection = String::from("customerCreate(input: $input) { success lastSyncId customer { ")
+        + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "CustomerCreate",
+        "$input: CustomerCreateInput!",
+        selection,
+        variables,
+        "customerCreate",
+    )
+    .with_entity_field("customer")
+    .with_client_id(
+        "input",
+        String::from("customer(id: $id) { ") + &T::selection() + " }",
+    )
+}
+/// Updates an existing customer.
+///
+/// Full type: [`Customer`](super::types::Customer)
+///
+/// Requires the `customers` feature.
+#[cfg(feature = "customers")]
+pub fn customer_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Customer>,
+>(
+    input: CustomerUpdateInput,
+    id: impl Into<super::ids::CustomerId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "input" : input, "id" : Into:: < super::ids::CustomerId > ::into(id) }
+    );
+    let selection =
+        String::from("customerUpdate(input: $input, id: $id) { success lastSyncId customer { ")
+            + &T::selection()
+            + " } }";
+    Operation::mutation(
+        "CustomerUpdate",
+        "$input: CustomerUpdateInput!, $id: String!",
+        selection,
+        variables,
+        "customerUpdate",
+    )
+    .with_entity_field("customer")
+}
+/// Deletes a customer.
+///
+/// Requires the `customers` feature.
+#[cfg(feature = "customers")]
+pub fn customer_delete(
+    id: impl Into<super::ids::CustomerId>,
+) -> Operation<super::types::DeletePayload> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::CustomerId > ::into(id) }
+    );
+    let selection = String::from("customerDelete(id: $id) { ")
+        + &<super::types::DeletePayload as crate::field_selection::GraphQLFields>::selection()
+        + " }";
+    Operation::mutation(
+        "CustomerDelete",
+        "$id: String!",
+        selection,
+        variables,
+        "customerDelete",
+    )
+}
+/// Merges two customers by transferring all needs from the source customer to the target customer. The source customer is archived after the merge. Domains, external IDs, and metadata are combined on the target customer.
+///
+/// Full type: [`Customer`](super::types::Customer)
+///
+/// Requires the `customers` feature.
+#[cfg(feature = "customers")]
+pub fn customer_merge<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Customer>,
+>(
+    source_customer_id: String,
+    target_customer_id: String,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "sourceCustomerId" : source_customer_id, "targetCustomerId" :
+        target_customer_id }
+    );
+    let selection = String::from(
+        "customerMerge(sourceCustomerId: $sourceCustomerId, targetCustomerId: $targetCustomerId) { success lastSyncId customer { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+        "CustomerMerge",
+        "$sourceCustomerId: String!, $targetCustomerId: String!",
+        selection,
+        variables,
+        "customerMerge",
+    )
+    .with_entity_field("customer")
+}
+/// Upserts a customer, creating it if no match is found, or updating it otherwise. Matches against existing customers using `id`, `externalId`, `slackChannelId`, or `domains`.
+///
+/// Full type: [`Customer`](super::types::Customer)
+///
+/// Requires the `customers` feature.
+#[cfg(feature = "customers")]
+pub fn customer_upsert<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Customer>,
+>(
+    input: CustomerUpsertInput,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "input" : input });
+    let selection = String::from("customerUpsert(input: $input) { success lastSyncId customer { ")
+        + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "CustomerUpsert",
+        "$input: CustomerUpsertInput!",
+        selection,
+        variables,
+        "customerUpsert",
+    )
+    .with_entity_field("customer")
+}
+/// Unsyncs a managed customer from its current data source integration. External IDs mapping to the external source will be cleared, and the customer will no longer be updated by the integration.
+///
+/// Full type: [`Customer`](super::types::Customer)
+///
+/// Requires the `customers` feature.
+#[cfg(feature = "customers")]
+pub fn customer_unsync<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Customer>,
+>(
+    id: impl Into<super::ids::CustomerId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::CustomerId > ::into(id) }
+    );
+    let selection = String::from("customerUnsync(id: $id) { success lastSyncId customer { ")
+        + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "CustomerUnsync",
+        "$id: String!",
+        selection,
+        variables,
+        "customerUnsync",
+    )
+    .with_entity_field("customer")
+}
+/// Creates a new customer need.
+///
+/// Full type: [`CustomerNeed`](super::types::CustomerNeed)
+///
+/// Requires the `customers` feature.
+#[cfg(feature = "customers")]
+pub fn customer_need_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::CustomerNeed>,
+>(
+    input: CustomerNeedCreateInput,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "input" : input });
+    let selection = String::from("customerNeedCreate(input: $input) { success lastSyncId need { ")
+        + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "CustomerNeedCreate",
+        "$input: CustomerNeedCreateInput!",
+        selection,
+        variables,
+        "customerNeedCreate",
+    )
+    .with_entity_field("need")
+    .with_client_id(
+        "input",
+        String::from("customerNeed(id: $id) { ") + &T::selection() + " }",
+    )
+}
+/// Creates a new customer need from an existing issue attachment. If the attachment already has an archived need, it will be unarchived instead of creating a duplicate.
+///
+/// Full type: [`CustomerNeed`](super::types::CustomerNeed)
+///
+/// Requires the `customers` feature.
+#[cfg(feature = "customers")]
+pub fn customer_need_create_from_attachment<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::CustomerNeed>,
+>(
+    input: CustomerNeedCreateFromAttachmentInput,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "input" : input });
+    let selection = String::from(
+        "customerNeedCreateFromAttachment(input: $input) { success lastSyncId need { ",
+    ) + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "CustomerNeedCreateFromAttachment",
+        "$input: CustomerNeedCreateFromAttachmentInput!",
+        selection,
+        variables,
+        "customerNeedCreateFromAttachment",
+    )
+    .with_entity_field("need")
+}
+/// Updates an existing customer need. Supports moving the need to a different issue or project, changing priority, updating the body content, and managing the attached source URL.
+///
+/// Full type: [`CustomerNeed`](super::types::CustomerNeed)
+///
+/// Requires the `customers` feature.
+#[cfg(feature = "customers")]
+pub fn customer_need_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::CustomerNeed>,
+>(
+    clear_attachment: Option<bool>,
+    input: CustomerNeedUpdateInput,
+    id: impl Into<super::ids::CustomerNeedId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "clearAttachment" : clear_attachment, "input" : input, "id" : Into:: <
+        super::ids::CustomerNeedId > ::into(id) }
+    );
+    let selection = String::from(
+        "customerNeedUpdate(clearAttachment: $clearAttachment, input: $input, id: $id) { success lastSyncId need { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+        "CustomerNeedUpdate",
+        "$clearAttachment: Boolean, $input: CustomerNeedUpdateInput!, $id: String!",
+        selection,
+        variables,
+        "customerNeedUpdate",
+    )
+    .with_entity_field("need")
+}
+/// Deletes a customer need.
+///
+/// Requires the `customers` feature.
+#[cfg(feature = "customers")]
+pub fn customer_need_delete(
+    keep_attachment: Option<bool>,
+    id: impl Into<super::ids::CustomerNeedId>,
+) -> Operation<super::types::DeletePayload> {
+    let variables = serde_json::json!(
+        { "keepAttachment" : keep_attachment, "id" : Into:: < super::ids::CustomerNeedId
+        > ::into(id) }
+    );
+    let selection = String::from("customerNeedDelete(keepAttachment: $keepAttachment, id: $id) { ")
+        + &<super::types::DeletePayload as crate::field_selection::GraphQLFields>::selection()
+        + " }";
+    Operation::mutation(
+        "CustomerNeedDelete",
+        "$keepAttachment: Boolean, $id: String!",
+        selection,
+        variables,
+        "customerNeedDelete",
+    )
+}
+/// Archives a customer need.
+///
+/// Full type: [`CustomerNeed`](super::types::CustomerNeed)
+///
+/// Requires the `customers` feature.
+#[cfg(feature = "customers")]
+pub fn customer_need_archive<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::CustomerNeed>,
+>(
+    id: impl Into<super::ids::CustomerNeedId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::CustomerNeedId > ::into(id) }
+    );
+    let selection = String::from("customerNeedArchive(id: $id) { success lastSyncId entity { ")
+        + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "CustomerNeedArchive",
+        "$id: String!",
+        selection,
+        variables,
+        "customerNeedArchive",
+    )
+    .with_entity_field("entity")
+}
+/// Unarchives a customer need.
+///
+/// Full type: [`CustomerNeed`](super::types::CustomerNeed)
+///
+/// Requires the `customers` feature.
+#[cfg(feature = "customers")]
+pub fn customer_need_unarchive<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::CustomerNeed>,
+>(
+    id: impl Into<super::ids::CustomerNeedId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::CustomerNeedId > ::into(id) }
+    );
+    let selection = String::from("customerNeedUnarchive(id: $id) { success lastSyncId entity { ")
+        + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "CustomerNeedUnarchive",
+        "$id: String!",
+        selection,
+        variables,
+        "customerNeedUnarchive",
+    )
+    .with_entity_field("entity")
+}
+/// Creates a new custom view.
+///
+/// Full type: [`CustomView`](super::types::CustomView)
+///
+/// Requires the `views` feature.
+#[cfg(feature = "views")]
+pub fn custom_view_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::CustomView>,
+>(
+    input: CustomViewCreateInput,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "input" : input });
+    let selection =
+        String::from("customViewCreate(input: $input) { success lastSyncId customView { ")
+            + &T::selection()
+            + " } }";
+    Operation::mutation(
+        "CustomViewCreate",
+        "$input: CustomViewCreateInput!",
+        selection,
+        variables,
+        "customViewCreate",
+    )
+    .with_entity_field("customView")
+    .with_client_id(
+        "input",
+        String::from("customView(id: $id) { ") + &T::selection() + " }",
+    )
+}
+/// Updates a custom view.
+///
+/// Full type: [`CustomView`](super::types::CustomView)
+///
+/// Requires the `views` feature.
+#[cfg(feature = "views")]
+pub fn custom_view_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::CustomView>,
+>(
+    input: CustomViewUpdateInput,
+    id: impl Into<super::ids::CustomViewId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "input" : input, "id" : Into:: < super::ids::CustomViewId > ::into(id) }
+    );
+    let selection =
+        String::from("customViewUpdate(input: $input, id: $id) { success lastSyncId customView { ")
+            + &T::selection()
+            + " } }";
+    Operation::mutation(
+        "CustomViewUpdate",
+        "$input: CustomViewUpdateInput!, $id: String!",
+        selection,
+        variables,
+        "customViewUpdate",
+    )
+    .with_entity_field("customView")
+}
+/// Deletes a custom view.
+///
+/// Requires the `views` feature.
+#[cfg(feature = "views")]
+pub fn custom_view_delete(
+    id: impl Into<super::ids::CustomViewId>,
+) -> Operation<super::types::DeletePayload> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::CustomViewId > ::into(id) }
+    );
+    let selection = String::from("customViewDelete(id: $id) { ")
+        + &<super::types::DeletePayload as crate::field_selection::GraphQLFields>::selection()
+        + " }";
+    Operation::mutation(
+        "CustomViewDelete",
+        "$id: String!",
+        selection,
+        variables,
+        "customViewDelete",
+    )
+}
+/// Creates a support contact message from an authenticated user. The message is saved and forwarded to Intercom for support handling.
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub fn contact_create(input: ContactCreateInput) -> Operation<super::types::ContactPayload> {
+    let variables = serde_json::json!({ "input" : input });
+    let selection = String::from("contactCreate(input: $input) { ")
+        + &<super::types::ContactPayload as crate::field_selection::GraphQLFields>::selection()
+        + " }";
+    Operation::mutation(
+        "ContactCreate",
+        "$input: ContactCreateInput!",
+        selection,
+        variables,
+        "contactCreate",
+    )
+}
+/// `INTERNAL` Submits a sales pricing inquiry from the website. Small companies (1-19 employees) are routed to Intercom, while larger companies are routed to HubSpot.
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub fn contact_sales_create(
+    input: ContactSalesCreateInput,
+) -> Operation<super::types::ContactPayload> {
+    let variables = serde_json::json!({ "input" : input });
+    let selection = String::from("contactSalesCreate(input: $input) { ")
+        + &<super::types::ContactPayload as crate::field_selection::GraphQLFields>::selection()
+        + " }";
+    Operation::mutation(
+        "ContactSalesCreate",
+        "$input: ContactSalesCreateInput!",
+        selection,
+        variables,
+        "contactSalesCreate",
+    )
+}
+/// Creates a new comment.
+///
+/// Full type: [`Comment`](super::types::Comment)
+pub fn comment_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Comment>,
+>(
+    input: CommentCreateInput,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "input" : input });
+    let selection = String::from("commentCreate(input: $input) { success lastSyncId comment { ")
+        + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "CommentCreate",
+        "$input: CommentCreateInput!",
+        selection,
+        variables,
+        "commentCreate",
+    )
+    .with_entity_field("comment")
+    .with_client_id(
+        "input",
+        String::from("comment(id: $id) { ") + &T::selection() + " }",
+    )
+}
+/// Updates a comment.
+///
+/// Full type: [`Comment`](super::types::Comment)
+pub fn comment_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Comment>,
+>(
+    skip_edited_at: Option<bool>,
+    input: CommentUpdateInput,
+    id: impl Into<super::ids::CommentId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "skipEditedAt" : skip_edited_at, "input" : input, "id" : Into:: <
+        super::ids::CommentId > ::into(id) }
+    );
+    let selection = String::from(
+        "commentUpdate(skipEditedAt: $skipEditedAt, input: $input, id: $id) { success lastSyncId comment { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+        "CommentUpdate",
+        "$skipEditedAt: Boolean, $input: CommentUpdateInput!, $id: String!",
+        selection,
+        variables,
+        "commentUpdate",
+    )
+    .with_entity_field("comment")
+}
+/// Deletes a comment.
+pub fn comment_delete(
+    id: impl Into<super::ids::CommentId>,
+) -> Operation<super::types::DeletePayload> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::CommentId > ::into(id) }
+    );
+    let selection = String::from("commentDelete(id: $id) { ")
+        + &<super::types::DeletePayload as crate::field_selection::GraphQLFields>::selection()
+        + " }";
+    Operation::mutation(
+        "CommentDelete",
+        "$id: String!",
+        selection,
+        variables,
+        "commentDelete",
+    )
+}
+/// Resolves a comment thread. Marks the root comment as resolved by the current user.
+///
+/// Full type: [`Comment`](super::types::Comment)
+pub fn comment_resolve<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Comment>,
+>(
+    resolving_comment_id: Option<String>,
+    id: impl Into<super::ids::CommentId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "resolvingCommentId" : resolving_comment_id, "id" : Into:: <
+        super::ids::CommentId > ::into(id) }
+    );
+    let selection = String::from(
+        "commentResolve(resolvingCommentId: $resolvingCommentId, id: $id) { success lastSyncId comment { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+        "CommentResolve",
+        "$resolvingCommentId: String, $id: String!",
+        selection,
+        variables,
+        "commentResolve",
+    )
+    .with_entity_field("comment")
+}
+/// Unresolves a previously resolved comment thread. Clears the resolved state on the root comment.
+///
+/// Full type: [`Comment`](super::types::Comment)
+pub fn comment_unresolve<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Comment>,
+>(
+    id: impl Into<super::ids::CommentId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::CommentId > ::into(id) }
+    );
+    let selection = String::from("commentUnresolve(id: $id) { success lastSyncId comment { ")
+        + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "CommentUnresolve",
+        "$id: String!",
+        selection,
+        variables,
+        "commentUnresolve",
+    )
+    .with_entity_field("comment")
+}
+/// Finds or creates a new user account by email and sends an email with token.
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub fn email_user_account_auth_challenge(
+    input: EmailUserAccountAuthChallengeInput,
+) -> Operation<super::types::EmailUserAccountAuthChallengeResponse> {
+    let variables = serde_json::json!({ "input" : input });
+    let selection = String::from("emailUserAccountAuthChallenge(input: $input) { ")
+        + &<super::types::EmailUserAccountAuthChallengeResponse as crate::field_selection::GraphQLFields>::selection()
+        + " }";
+    Operation::mutation(
+        "EmailUserAccountAuthChallenge",
+        "$input: EmailUserAccountAuthChallengeInput!",
+        selection,
+        variables,
+        "emailUserAccountAuthChallenge",
+    )
+}
+/// Authenticates a user account via email and authentication token.
+///
+/// Full type: [`AuthUser`](super::types::AuthUser)
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub fn email_token_user_account_auth<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::AuthUser>,
+>(
+    input: TokenUserAccountAuthInput,
+) -> Operation<Vec<T>> {
+    let variables = serde_json::json!({ "input" : input });
+    let selection = String::from("emailTokenUserAccountAuth(input: $input) { success users { ")
+        + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "EmailTokenUserAccountAuth",
+        "$input: TokenUserAccountAuthInput!",
+        selection,
+        variables,
+        "emailTokenUserAccountAuth",
+    )
+    .with_entity_field("users")
+}
+/// Authenticates a user account via email and authentication token for SAML.
+///
+/// Full type: [`AuthUser`](super::types::AuthUser)
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub fn saml_token_user_account_auth<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::AuthUser>,
+>(
+    input: TokenUserAccountAuthInput,
+) -> Operation<Vec<T>> {
+    let variables = serde_json::json!({ "input" : input });
+    let selection = String::from("samlTokenUserAccountAuth(input: $input) { success users { ")
+        + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "SamlTokenUserAccountAuth",
+        "$input: TokenUserAccountAuthInput!",
+        selection,
+        variables,
+        "samlTokenUserAccountAuth",
+    )
+    .with_entity_field("users")
+}
+/// Authenticate user account through Google OAuth. This is the 2nd step of OAuth flow.
+///
+/// Full type: [`AuthUser`](super::types::AuthUser)
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub fn google_user_account_auth<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::AuthUser>,
+>(
+    input: GoogleUserAccountAuthInput,
+) -> Operation<Vec<T>> {
+    let variables = serde_json::json!({ "input" : input });
+    let selection = String::from("googleUserAccountAuth(input: $input) { success users { ")
+        + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "GoogleUserAccountAuth",
+        "$input: GoogleUserAccountAuthInput!",
+        selection,
+        variables,
+        "googleUserAccountAuth",
+    )
+    .with_entity_field("users")
+}
+/// `INTERNAL` Starts passkey login process.
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub fn passkey_login_start(auth_id: String) -> Operation<super::types::PasskeyLoginStartResponse> {
+    let variables = serde_json::json!({ "authId" : auth_id });
+    let selection = String::from("passkeyLoginStart(authId: $authId) { ")
+        + &<super::types::PasskeyLoginStartResponse as crate::field_selection::GraphQLFields>::selection()
+        + " }";
+    Operation::mutation(
+        "PasskeyLoginStart",
+        "$authId: String!",
+        selection,
+        variables,
+        "passkeyLoginStart",
+    )
+}
+/// `INTERNAL` Finish passkey login process.
+///
+/// Full type: [`AuthUser`](super::types::AuthUser)
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub fn passkey_login_finish<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::AuthUser>,
+>(
+    response: serde_json::Value,
+    auth_id: String,
+) -> Operation<Vec<T>> {
+    let variables = serde_json::json!({ "response" : response, "authId" : auth_id });
+    let selection =
+        String::from("passkeyLoginFinish(response: $response, authId: $authId) { success users { ")
+            + &T::selection()
+            + " } }";
+    Operation::mutation(
+        "PasskeyLoginFinish",
+        "$response: JSONObject!, $authId: String!",
+        selection,
+        variables,
+        "passkeyLoginFinish",
+    )
+    .with_entity_field("users")
+}
+/// Creates a new workspace from onboarding.
+///
+/// Full type: [`AuthOrganization`](super::types::AuthOrganization)
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub fn create_organization_from_onboarding<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::AuthOrganization>,
+>(
+    session_id: Option<String>,
+    survey: Option<OnboardingCustomerSurvey>,
+    input: CreateOrganizationInput,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "sessionId" : session_id, "survey" : survey, "input" : input }
+    );
+    let selection = String::from(
+        "createOrganizationFromOnboarding(sessionId: $sessionId, survey: $survey, input: $input) { success organization { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+        "CreateOrganizationFromOnboarding",
+        "$sessionId: String, $survey: OnboardingCustomerSurvey, $input: CreateOrganizationInput!",
+        selection,
+        variables,
+        "createOrganizationFromOnboarding",
+    )
+    .with_entity_field("organization")
+}
+/// Join a workspace from onboarding.
+///
+/// Full type: [`AuthOrganization`](super::types::AuthOrganization)
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub fn join_organization_from_onboarding<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::AuthOrganization>,
+>(
+    input: JoinOrganizationInput,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "input" : input });
+    let selection =
+        String::from("joinOrganizationFromOnboarding(input: $input) { success organization { ")
+            + &T::selection()
+            + " } }";
+    Operation::mutation(
+        "JoinOrganizationFromOnboarding",
+        "$input: JoinOrganizationInput!",
+        selection,
+        variables,
+        "joinOrganizationFromOnboarding",
+    )
+    .with_entity_field("organization")
+}
+/// Leave a workspace.
+///
+/// Full type: [`AuthOrganization`](super::types::AuthOrganization)
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub fn leave_organization<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::AuthOrganization>,
+>(
+    organization_id: String,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "organizationId" : organization_id });
+    let selection = String::from(
+        "leaveOrganization(organizationId: $organizationId) { success organization { ",
+    ) + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "LeaveOrganization",
+        "$organizationId: String!",
+        selection,
+        variables,
+        "leaveOrganization",
+    )
+    .with_entity_field("organization")
+}
+/// Logout the client.
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub fn logout(reason: Option<String>) -> Operation<super::types::LogoutResponse> {
+    let variables = serde_json::json!({ "reason" : reason });
+    let selection = String::from("logout(reason: $reason) { ")
+        + &<super::types::LogoutResponse as crate::field_selection::GraphQLFields>::selection()
+        + " }";
+    Operation::mutation("Logout", "$reason: String", selection, variables, "logout")
+}
+/// Logout an individual session with its ID.
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub fn logout_session(session_id: String) -> Operation<super::types::LogoutResponse> {
+    let variables = serde_json::json!({ "sessionId" : session_id });
+    let selection = String::from("logoutSession(sessionId: $sessionId) { ")
+        + &<super::types::LogoutResponse as crate::field_selection::GraphQLFields>::selection()
+        + " }";
+    Operation::mutation(
+        "LogoutSession",
+        "$sessionId: String!",
+        selection,
+        variables,
+        "logoutSession",
+    )
+}
+/// Logout all of user's sessions including the active one.
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub fn logout_all_sessions(reason: Option<String>) -> Operation<super::types::LogoutResponse> {
+    let variables = serde_json::json!({ "reason" : reason });
+    let selection = String::from("logoutAllSessions(reason: $reason) { ")
+        + &<super::types::LogoutResponse as crate::field_selection::GraphQLFields>::selection()
+        + " }";
+    Operation::mutation(
+        "LogoutAllSessions",
+        "$reason: String",
+        selection,
+        variables,
+        "logoutAllSessions",
+    )
+}
+/// Logout all of user's sessions excluding the current one.
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub fn logout_other_sessions(reason: Option<String>) -> Operation<super::types::LogoutResponse> {
+    let variables = serde_json::json!({ "reason" : reason });
+    let selection = String::from("logoutOtherSessions(reason: $reason) { ")
+        + &<super::types::LogoutResponse as crate::field_selection::GraphQLFields>::selection()
+        + " }";
+    Operation::mutation(
+        "LogoutOtherSessions",
+        "$reason: String",
+        selection,
+        variables,
+        "logoutOtherSessions",
+    )
+}
+/// Creates a new attachment, or updates existing if the same `url` and `issueId` is used. To create an integration-aware attachment, use the integration-specific mutations such as `attachmentLinkZendesk`, `attachmentLinkSlack`, or `attachmentLinkURL` instead.
+///
+/// Full type: [`Attachment`](super::types::Attachment)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub fn attachment_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Attachment>,
+>(
+    input: AttachmentCreateInput,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "input" : input });
+    let selection =
+        String::from("attachmentCreate(input: $input) { success lastSyncId attachment { ")
+            + &T::selection()
+            + " } }";
+    Operation::mutation(
+        "AttachmentCreate",
+        "$input: AttachmentCreateInput!",
+        selection,
+        variables,
+        "attachmentCreate",
+    )
+    .with_entity_field("attachment")
+    .with_client_id(
+        "input",
+        String::from("attachment(id: $id) { ") + &T::selection() + " }",
+    )
+}
+/// Updates an existing issue attachment.
+///
+/// Full type: [`Attachment`](super::types::Attachment)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub fn attachment_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Attachment>,
+>(
+    input: AttachmentUpdateInput,
+    id: impl Into<super::ids::AttachmentId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "input" : input, "id" : Into:: < super::ids::AttachmentId > ::into(id) }
+    );
+    let selection =
+        String::from("attachmentUpdate(input: $input, id: $id) { success lastSyncId attachment { ")
+            + &T::selection()
+            + " } }";
+    Operation::mutation(
+        "AttachmentUpdate",
+        "$input: AttachmentUpdateInput!, $id: String!",
+        selection,
+        variables,
+        "attachmentUpdate",
+    )
+    .with_entity_field("attachment")
+}
+/// Link any URL to an issue. If the workspace has a matching integration configured and the URL is recognized (e.g., Zendesk, GitHub, Slack), a rich attachment will be created that enables features like automated status updates. Otherwise, a basic attachment is created.
+///
+/// Full type: [`Attachment`](super::types::Attachment)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub fn attachment_link_url<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Attachment>,
+>(
+    create_as_user: Option<String>,
+    display_icon_url: Option<String>,
+    title: Option<String>,
+    url: String,
+    issue_id: String,
+    id: Option<String>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "createAsUser" : create_as_user, "displayIconUrl" : display_icon_url, "title" :
+        title, "url" : url, "issueId" : issue_id, "id" : id }
+    );
+    let selection = String::from(
+        "attachmentLinkURL(createAsUser: $createAsUser, displayIconUrl: $displayIconUrl, title: $title, url: $url, issueId: $issueId, id: $id) { success lastSyncId attachment { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+            "AttachmentLinkURL",
+            "$createAsUser: String, $displayIconUrl: String, $title: String, $url: String!, $issueId: String!, $id: String",
+            selection,
+            variables,
+            "attachmentLinkURL",
+        )
+        .with_entity_field("attachment")
+}
+/// Link an existing GitLab MR to an issue. This creates a rich attachment using the workspace's GitLab integration, enabling features like automated status syncing.
+///
+/// Full type: [`Attachment`](super::types::Attachment)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub fn attachment_link_git_lab_mr<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Attachment>,
+>(
+    create_as_user: Option<String>,
+    display_icon_url: Option<String>,
+    title: Option<String>,
+    issue_id: String,
+    id: Option<String>,
+    url: String,
+    project_path_with_namespace: String,
+    number: f64,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "createAsUser" : create_as_user, "displayIconUrl" : display_icon_url, "title" :
+        title, "issueId" : issue_id, "id" : id, "url" : url, "projectPathWithNamespace" :
+        project_path_with_namespace, "number" : number }
+    );
+    let selection = String::from(
+        "attachmentLinkGitLabMR(createAsUser: $createAsUser, displayIconUrl: $displayIconUrl, title: $title, issueId: $issueId, id: $id, url: $url, projectPathWithNamespace: $projectPathWithNamespace, number: $number) { success lastSyncId attachment { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+            "AttachmentLinkGitLabMR",
+            "$createAsUser: String, $displayIconUrl: String, $title: String, $issueId: String!, $id: String, $url: String!, $projectPathWithNamespace: String!, $number: Float!",
+            selection,
+            variables,
+            "attachmentLinkGitLabMR",
+        )
+        .with_entity_field("attachment")
+}
+/// Link a GitHub issue to a Linear issue. This creates a rich attachment using the workspace's GitHub integration, enabling features like automated status syncing.
+///
+/// Full type: [`Attachment`](super::types::Attachment)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub fn attachment_link_git_hub_issue<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Attachment>,
+>(
+    create_as_user: Option<String>,
+    display_icon_url: Option<String>,
+    title: Option<String>,
+    issue_id: String,
+    id: Option<String>,
+    url: String,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "createAsUser" : create_as_user, "displayIconUrl" : display_icon_url, "title" :
+        title, "issueId" : issue_id, "id" : id, "url" : url }
+    );
+    let selection = String::from(
+        "attachmentLinkGitHubIssue(createAsUser: $createAsUser, displayIconUrl: $displayIconUrl, title: $title, issueId: $issueId, id: $id, url: $url) { success lastSyncId attachment { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+            "AttachmentLinkGitHubIssue",
+            "$createAsUser: String, $displayIconUrl: String, $title: String, $issueId: String!, $id: String, $url: String!",
+            selection,
+            variables,
+            "attachmentLinkGitHubIssue",
+        )
+        .with_entity_field("attachment")
+}
+/// Link a GitHub pull request to an issue. This creates a rich attachment using the workspace's GitHub integration, enabling features like automated status syncing.
+///
+/// Full type: [`Attachment`](super::types::Attachment)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub fn attachment_link_git_hub_pr<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Attachment>,
+>(
+    create_as_user: Option<String>,
+    display_icon_url: Option<String>,
+    title: Option<String>,
+    issue_id: String,
+    id: Option<String>,
+    url: String,
+    link_kind: Option<GitLinkKind>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "createAsUser" : create_as_user, "displayIconUrl" : display_icon_url, "title" :
+        title, "issueId" : issue_id, "id" : id, "url" : url, "linkKind" : link_kind }
+    );
+    let selection = String::from(
+        "attachmentLinkGitHubPR(createAsUser: $createAsUser, displayIconUrl: $displayIconUrl, title: $title, issueId: $issueId, id: $id, url: $url, linkKind: $linkKind) { success lastSyncId attachment { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+            "AttachmentLinkGitHubPR",
+            "$createAsUser: String, $displayIconUrl: String, $title: String, $issueId: String!, $id: String, $url: String!, $linkKind: GitLinkKind",
+            selection,
+            variables,
+            "attachmentLinkGitHubPR",
+        )
+        .with_entity_field("attachment")
+}
+/// Link an existing Zendesk ticket to an issue. This creates a rich attachment using the workspace's Zendesk integration, enabling features like automated ticket reopening when the Linear issue is completed.
+///
+/// Full type: [`Attachment`](super::types::Attachment)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub fn attachment_link_zendesk<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Attachment>,
+>(
+    create_as_user: Option<String>,
+    display_icon_url: Option<String>,
+    title: Option<String>,
+    ticket_id: String,
+    issue_id: String,
+    id: Option<String>,
+    url: Option<String>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "createAsUser" : create_as_user, "displayIconUrl" : display_icon_url, "title" :
+        title, "ticketId" : ticket_id, "issueId" : issue_id, "id" : id, "url" : url }
+    );
+    let selection = String::from(
+        "attachmentLinkZendesk(createAsUser: $createAsUser, displayIconUrl: $displayIconUrl, title: $title, ticketId: $ticketId, issueId: $issueId, id: $id, url: $url) { success lastSyncId attachment { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+            "AttachmentLinkZendesk",
+            "$createAsUser: String, $displayIconUrl: String, $title: String, $ticketId: String!, $issueId: String!, $id: String, $url: String",
+            selection,
+            variables,
+            "attachmentLinkZendesk",
+        )
+        .with_entity_field("attachment")
+}
+/// Link an existing Discord message to an issue. This creates a rich attachment using the workspace's Discord integration.
+///
+/// Full type: [`Attachment`](super::types::Attachment)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub fn attachment_link_discord<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Attachment>,
+>(
+    create_as_user: Option<String>,
+    display_icon_url: Option<String>,
+    title: Option<String>,
+    issue_id: String,
+    id: Option<String>,
+    channel_id: String,
+    message_id: String,
+    url: String,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "createAsUser" : create_as_user, "displayIconUrl" : display_icon_url, "title" :
+        title, "issueId" : issue_id, "id" : id, "channelId" : channel_id, "messageId" :
+        message_id, "url" : url }
+    );
+    let selection = String::from(
+        "attachmentLinkDiscord(createAsUser: $createAsUser, displayIconUrl: $displayIconUrl, title: $title, issueId: $issueId, id: $id, channelId: $channelId, messageId: $messageId, url: $url) { success lastSyncId attachment { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+            "AttachmentLinkDiscord",
+            "$createAsUser: String, $displayIconUrl: String, $title: String, $issueId: String!, $id: String, $channelId: String!, $messageId: String!, $url: String!",
+            selection,
+            variables,
+            "attachmentLinkDiscord",
+        )
+        .with_entity_field("attachment")
+}
+/// Begin syncing the thread for an existing Slack message attachment with a comment thread on its issue.
+///
+/// Full type: [`Attachment`](super::types::Attachment)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub fn attachment_sync_to_slack<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Attachment>,
+>(
+    id: impl Into<super::ids::AttachmentId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::AttachmentId > ::into(id) }
+    );
+    let selection =
+        String::from("attachmentSyncToSlack(id: $id) { success lastSyncId attachment { ")
+            + &T::selection()
+            + " } }";
+    Operation::mutation(
+        "AttachmentSyncToSlack",
+        "$id: String!",
+        selection,
+        variables,
+        "attachmentSyncToSlack",
+    )
+    .with_entity_field("attachment")
+}
+/// Link an existing Slack message to an issue. This creates a rich attachment using the workspace's Slack integration.
+///
+/// Full type: [`Attachment`](super::types::Attachment)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub fn attachment_link_slack<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Attachment>,
+>(
+    create_as_user: Option<String>,
+    display_icon_url: Option<String>,
+    title: Option<String>,
+    issue_id: String,
+    url: String,
+    id: Option<String>,
+    sync_to_comment_thread: Option<bool>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "createAsUser" : create_as_user, "displayIconUrl" : display_icon_url, "title" :
+        title, "issueId" : issue_id, "url" : url, "id" : id, "syncToCommentThread" :
+        sync_to_comment_thread }
+    );
+    let selection = String::from(
+        "attachmentLinkSlack(createAsUser: $createAsUser, displayIconUrl: $displayIconUrl, title: $title, issueId: $issueId, url: $url, id: $id, syncToCommentThread: $syncToCommentThread) { success lastSyncId attachment { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+            "AttachmentLinkSlack",
+            "$createAsUser: String, $displayIconUrl: String, $title: String, $issueId: String!, $url: String!, $id: String, $syncToCommentThread: Boolean",
+            selection,
+            variables,
+            "attachmentLinkSlack",
+        )
+        .with_entity_field("attachment")
+}
+/// Link an existing Front conversation to an issue. This creates a rich attachment using the workspace's Front integration, enabling features like automated conversation updates.
+///
+/// Full type: [`Attachment`](super::types::Attachment)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub fn attachment_link_front<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Attachment>,
+>(
+    create_as_user: Option<String>,
+    display_icon_url: Option<String>,
+    title: Option<String>,
+    conversation_id: String,
+    issue_id: String,
+    id: Option<String>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "createAsUser" : create_as_user, "displayIconUrl" : display_icon_url, "title" :
+        title, "conversationId" : conversation_id, "issueId" : issue_id, "id" : id }
+    );
+    let selection = String::from(
+        "attachmentLinkFront(createAsUser: $createAsUser, displayIconUrl: $displayIconUrl, title: $title, conversationId: $conversationId, issueId: $issueId, id: $id) { success lastSyncId attachment { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+            "AttachmentLinkFront",
+            "$createAsUser: String, $displayIconUrl: String, $title: String, $conversationId: String!, $issueId: String!, $id: String",
+            selection,
+            variables,
+            "attachmentLinkFront",
+        )
+        .with_entity_field("attachment")
+}
+/// Link an existing Intercom conversation to an issue. This creates a rich attachment using the workspace's Intercom integration, enabling features like automated conversation updates.
+///
+/// Full type: [`Attachment`](super::types::Attachment)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub fn attachment_link_intercom<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Attachment>,
+>(
+    create_as_user: Option<String>,
+    display_icon_url: Option<String>,
+    title: Option<String>,
+    conversation_id: String,
+    part_id: Option<String>,
+    id: Option<String>,
+    issue_id: String,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "createAsUser" : create_as_user, "displayIconUrl" : display_icon_url, "title" :
+        title, "conversationId" : conversation_id, "partId" : part_id, "id" : id,
+        "issueId" : issue_id }
+    );
+    let selection = String::from(
+        "attachmentLinkIntercom(createAsUser: $createAsUser, displayIconUrl: $displayIconUrl, title: $title, conversationId: $conversationId, partId: $partId, id: $id, issueId: $issueId) { success lastSyncId attachment { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+            "AttachmentLinkIntercom",
+            "$createAsUser: String, $displayIconUrl: String, $title: String, $conversationId: String!, $partId: String, $id: String, $issueId: String!",
+            selection,
+            variables,
+            "attachmentLinkIntercom",
+        )
+        .with_entity_field("attachment")
+}
+/// Link an existing Jira issue to an issue. This creates a rich attachment using the workspace's Jira integration, enabling features like automated status syncing.
+///
+/// Full type: [`Attachment`](super::types::Attachment)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub fn attachment_link_jira_issue<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Attachment>,
+>(
+    create_as_user: Option<String>,
+    display_icon_url: Option<String>,
+    title: Option<String>,
+    issue_id: String,
+    jira_issue_id: String,
+    id: Option<String>,
+    url: Option<String>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "createAsUser" : create_as_user, "displayIconUrl" : display_icon_url, "title" :
+        title, "issueId" : issue_id, "jiraIssueId" : jira_issue_id, "id" : id, "url" :
+        url }
+    );
+    let selection = String::from(
+        "attachmentLinkJiraIssue(createAsUser: $createAsUser, displayIconUrl: $displayIconUrl, title: $title, issueId: $issueId, jiraIssueId: $jiraIssueId, id: $id, url: $url) { success lastSyncId attachment { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+            "AttachmentLinkJiraIssue",
+            "$createAsUser: String, $displayIconUrl: String, $title: String, $issueId: String!, $jiraIssueId: String!, $id: String, $url: String",
+            selection,
+            variables,
+            "attachmentLinkJiraIssue",
+        )
+        .with_entity_field("attachment")
+}
+/// Link an existing Salesforce case to an issue. This creates a rich attachment using the workspace's Salesforce integration.
+///
+/// Full type: [`Attachment`](super::types::Attachment)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub fn attachment_link_salesforce<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::Attachment>,
+>(
+    create_as_user: Option<String>,
+    display_icon_url: Option<String>,
+    title: Option<String>,
+    issue_id: String,
+    id: Option<String>,
+    url: String,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "createAsUser" : create_as_user, "displayIconUrl" : display_icon_url, "title" :
+        title, "issueId" : issue_id, "id" : id, "url" : url }
+    );
+    let selection = String::from(
+        "attachmentLinkSalesforce(createAsUser: $createAsUser, displayIconUrl: $displayIconUrl, title: $title, issueId: $issueId, id: $id, url: $url) { success lastSyncId attachment { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+            "AttachmentLinkSalesforce",
+            "$createAsUser: String, $displayIconUrl: String, $title: String, $issueId: String!, $id: String, $url: String!",
+            selection,
+            variables,
+            "attachmentLinkSalesforce",
+        )
+        .with_entity_field("attachment")
+}
+/// Deletes an issue attachment.
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub fn attachment_delete(
+    id: impl Into<super::ids::AttachmentId>,
+) -> Operation<super::types::DeletePayload> {
+    let variables = serde_json::json!(
+        { "id" : Into:: < super::ids::AttachmentId > ::into(id) }
+    );
+    let selection = String::from("attachmentDelete(id: $id) { ")
+        + &<super::types::DeletePayload as crate::field_selection::GraphQLFields>::selection()
+        + " }";
+    Operation::mutation(
+        "AttachmentDelete",
+        "$id: String!",
+        selection,
+        variables,
+        "attachmentDelete",
+    )
+}
+/// Creates a new agent session on a root comment.
+///
+/// Full type: [`AgentSession`](super::types::AgentSession)
+///
+/// Requires the `agents` feature.
+#[cfg(feature = "agents")]
+pub fn agent_session_create_on_comment<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::AgentSession>,
+>(
+    input: AgentSessionCreateOnComment,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "input" : input });
+    let selection = String::from(
+        "agentSessionCreateOnComment(input: $input) { success lastSyncId agentSession { ",
+    ) + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "AgentSessionCreateOnComment",
+        "$input: AgentSessionCreateOnComment!",
+        selection,
+        variables,
+        "agentSessionCreateOnComment",
+    )
+    .with_entity_field("agentSession")
+}
+/// Creates a new agent session on an issue.
+///
+/// Full type: [`AgentSession`](super::types::AgentSession)
+///
+/// Requires the `agents` feature.
+#[cfg(feature = "agents")]
+pub fn agent_session_create_on_issue<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::AgentSession>,
+>(
+    input: AgentSessionCreateOnIssue,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "input" : input });
+    let selection = String::from(
+        "agentSessionCreateOnIssue(input: $input) { success lastSyncId agentSession { ",
+    ) + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "AgentSessionCreateOnIssue",
+        "$input: AgentSessionCreateOnIssue!",
+        selection,
+        variables,
+        "agentSessionCreateOnIssue",
+    )
+    .with_entity_field("agentSession")
+}
+/// `Internal` Creates a new agent session on behalf of the current user
+///
+/// Full type: [`AgentSession`](super::types::AgentSession)
+///
+/// Requires the `agents` feature.
+#[cfg(feature = "agents")]
+pub fn agent_session_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::AgentSession>,
+>(
+    pull_request_id: Option<String>,
+    input: AgentSessionCreateInput,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "pullRequestId" : pull_request_id, "input" : input }
+    );
+    let selection = String::from(
+        "agentSessionCreate(pullRequestId: $pullRequestId, input: $input) { success lastSyncId agentSession { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+        "AgentSessionCreate",
+        "$pullRequestId: String, $input: AgentSessionCreateInput!",
+        selection,
+        variables,
+        "agentSessionCreate",
+    )
+    .with_entity_field("agentSession")
+    .with_client_id(
+        "input",
+        String::from("agentSession(id: $id) { ") + &T::selection() + " }",
+    )
+}
+/// Updates the externalUrl of an agent session, which is an agent-hosted page associated with this session.
+///
+/// Full type: [`AgentSession`](super::types::AgentSession)
+///
+/// Requires the `agents` feature.
+#[cfg(feature = "agents")]
+pub fn agent_session_update_external_url<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::AgentSession>,
+>(
+    input: AgentSessionUpdateExternalUrlInput,
+    id: impl Into<super::ids::AgentSessionId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "input" : input, "id" : Into:: < super::ids::AgentSessionId > ::into(id) }
+    );
+    let selection = String::from(
+        "agentSessionUpdateExternalUrl(input: $input, id: $id) { success lastSyncId agentSession { ",
+    ) + &T::selection() + " } }";
+    Operation::mutation(
+        "AgentSessionUpdateExternalUrl",
+        "$input: AgentSessionUpdateExternalUrlInput!, $id: String!",
+        selection,
+        variables,
+        "agentSessionUpdateExternalUrl",
+    )
+    .with_entity_field("agentSession")
+}
+/// Updates an agent session.
+///
+/// Full type: [`AgentSession`](super::types::AgentSession)
+///
+/// Requires the `agents` feature.
+#[cfg(feature = "agents")]
+pub fn agent_session_update<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::AgentSession>,
+>(
+    input: AgentSessionUpdateInput,
+    id: impl Into<super::ids::AgentSessionId>,
+) -> Operation<T> {
+    let variables = serde_json::json!(
+        { "input" : input, "id" : Into:: < super::ids::AgentSessionId > ::into(id) }
+    );
+    let selection = String::from(
+        "agentSessionUpdate(input: $input, id: $id) { success lastSyncId agentSession { ",
+    ) + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "AgentSessionUpdate",
+        "$input: AgentSessionUpdateInput!, $id: String!",
+        selection,
+        variables,
+        "agentSessionUpdate",
+    )
+    .with_entity_field("agentSession")
+}
+/// Creates an agent activity.
+///
+/// Full type: [`AgentActivity`](super::types::AgentActivity)
+///
+/// Requires the `agents` feature.
+#[cfg(feature = "agents")]
+pub fn agent_activity_create<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::AgentActivity>,
+>(
+    input: AgentActivityCreateInput,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "input" : input });
+    let selection =
+        String::from("agentActivityCreate(input: $input) { success lastSyncId agentActivity { ")
+            + &T::selection()
+            + " } }";
+    Operation::mutation(
+        "AgentActivityCreate",
+        "$input: AgentActivityCreateInput!",
+        selection,
+        variables,
+        "agentActivityCreate",
+    )
+    .with_entity_field("agentActivity")
+    .with_client_id(
+        "input",
+        String::from("agentActivity(id: $id) { ") + &T::selection() + " }",
+    )
+}
+/// `Internal` Creates a prompt agent activity from Linear user input.
+///
+/// Full type: [`AgentActivity`](super::types::AgentActivity)
+///
+/// Requires the `agents` feature.
+#[cfg(feature = "agents")]
+pub fn agent_activity_create_prompt<
+    T: serde::de::DeserializeOwned
+        + crate::field_selection::GraphQLFields<FullType = super::types::AgentActivity>,
+>(
+    input: AgentActivityCreatePromptInput,
+) -> Operation<T> {
+    let variables = serde_json::json!({ "input" : input });
+    let selection = String::from(
+        "agentActivityCreatePrompt(input: $input) { success lastSyncId agentActivity { ",
+    ) + &T::selection()
+        + " } }";
+    Operation::mutation(
+        "AgentActivityCreatePrompt",
+        "$input: AgentActivityCreatePromptInput!",
+        selection,
+        variables,
+        "agentActivityCreatePrompt",
+    )
+    .with_entity_field("agentActivity")
+}
@@ -10,6 +10,7 @@ use super::inputs::*;
 use crate::client::Client;
 use crate::error::LinearError;
 use crate::field_selection::GraphQLFields;
+use crate::operation::Operation;
 use crate::pagination::Connection;
 use serde::de::DeserializeOwned;
 /// Query builder: All issue workflow states (issue statuses). Returns a paginated list of workflow states visible to the authenticated user, across all teams they have access to.
@@ -28,6 +29,7 @@ pub struct WorkflowStatesQueryBuilder<'a, T> {
     last: Option<i64>,
     include_archived: Option<bool>,
     order_by: Option<PaginationOrderBy>,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
     _marker: std::marker::PhantomData<T>,
 }
 impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::WorkflowState>>
@@ -61,8 +63,16 @@ impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::WorkflowSt
         self.order_by = Some(value);
         self
     }
-    pub async fn send(self) -> Result<Connection<T>, LinearError> {
-        let mut map = serde_json::Map::new();
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the query without sending it, e.g. to add it to a
+    /// [`Batch`](crate::batch::Batch).
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        let mut map = self.extra_variables;
         if let Some(ref v) = self.filter {
             map.insert("filter".to_string(), serde_json::json!(v));
         }
@@ -85,18 +95,197 @@ impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::WorkflowSt
             map.insert("orderBy".to_string(), serde_json::json!(v));
         }
         let variables = serde_json::Value::Object(map);
-        let selection = T::selection();
-        let query = format!(
-            "query {}({}) {{ {}({}) {{ nodes {{ {} }} pageInfo {{ hasNextPage endCursor }} }} }}",
+        let selection = format!(
+            "{} {{ nodes {{ {} }} pageInfo {{ hasNextPage endCursor }} }}",
+            "workflowStates(filter: $filter, before: $before, after: $after, first: $first, last: $last, includeArchived: $includeArchived, orderBy: $orderBy)",
+            T::selection()
+        );
+        Operation::query(
             "WorkflowStates",
             "$filter: WorkflowStateFilter, $before: String, $after: String, $first: Int, $last: Int, $includeArchived: Boolean, $orderBy: PaginationOrderBy",
+            selection,
+            variables,
             "workflowStates",
-            "filter: $filter, before: $before, after: $after, first: $first, last: $last, includeArchived: $includeArchived, orderBy: $orderBy",
-            selection
+        )
+    }
+    pub async fn send(self) -> Result<Connection<T>, LinearError> {
+        let client = self.client;
+        self.into_operation().execute(client).await
+    }
+    /// Stream every node across all pages, following `pageInfo.endCursor`.
+    ///
+    /// Starts after the cursor set via `.after()` (or at the beginning) and
+    /// uses `.first()` as the page size. `.before()` / `.last()` are ignored.
+    pub fn stream(self) -> impl futures::Stream<Item = Result<T, LinearError>> + 'a
+    where
+        T: 'a,
+    {
+        let after = self.after.clone();
+        crate::pagination::paginate(after, move |after| {
+            Self {
+                client: self.client,
+                filter: self.filter.clone(),
+                before: None,
+                after,
+                first: self.first,
+                last: None,
+                include_archived: self.include_archived,
+                order_by: self.order_by.clone(),
+                extra_variables: self.extra_variables.clone(),
+                _marker: std::marker::PhantomData,
+            }
+            .send()
+        })
+    }
+    /// Fetch every node across all pages. See [`stream`](Self::stream).
+    pub async fn all(self) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(self.stream()).await
+    }
+    /// Fetch nodes across pages until `max` have been collected. Pages past
+    /// the one containing the `max`-th node are never requested.
+    pub async fn take_all(self, max: usize) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(futures::StreamExt::take(self.stream(), max)).await
+    }
+}
+/// Query builder: All webhooks for the current workspace.
+///
+/// Full type: [`Webhook`](super::types::Webhook)
+///
+/// Use setter methods to configure optional parameters, then call
+/// [`.send()`](Self::send) to execute the query.
+///
+/// Requires the `webhooks` feature.
+#[cfg(feature = "webhooks")]
+#[must_use]
+pub struct WebhooksQueryBuilder<'a, T> {
+    client: &'a Client,
+    before: Option<String>,
+    after: Option<String>,
+    first: Option<i64>,
+    last: Option<i64>,
+    include_archived: Option<bool>,
+    order_by: Option<PaginationOrderBy>,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "webhooks")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Webhook>>
+    WebhooksQueryBuilder<'a, T>
+{
+    pub fn before(mut self, value: impl Into<String>) -> Self {
+        self.before = Some(value.into());
+        self
+    }
+    pub fn after(mut self, value: impl Into<String>) -> Self {
+        self.after = Some(value.into());
+        self
+    }
+    pub fn first(mut self, value: i64) -> Self {
+        self.first = Some(value);
+        self
+    }
+    pub fn last(mut self, value: i64) -> Self {
+        self.last = Some(value);
+        self
+    }
+    pub fn include_archived(mut self, value: bool) -> Self {
+        self.include_archived = Some(value);
+        self
+    }
+    pub fn order_by(mut self, value: PaginationOrderBy) -> Self {
+        self.order_by = Some(value);
+        self
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the query without sending it, e.g. to add it to a
+    /// [`Batch`](crate::batch::Batch).
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        let mut map = self.extra_variables;
+        if let Some(ref v) = self.before {
+            map.insert("before".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.after {
+            map.insert("after".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.first {
+            map.insert("first".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.last {
+            map.insert("last".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.include_archived {
+            map.insert("includeArchived".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.order_by {
+            map.insert("orderBy".to_string(), serde_json::json!(v));
+        }
+        let variables = serde_json::Value::Object(map);
+        let selection = format!(
+            "{} {{ nodes {{ {} }} pageInfo {{ hasNextPage endCursor }} }}",
+            "webhooks(before: $before, after: $after, first: $first, last: $last, includeArchived: $includeArchived, orderBy: $orderBy)",
+            T::selection()
         );
-        self.client
-            .execute_connection::<T>(&query, variables, "workflowStates")
-            .await
+        Operation::query(
+            "Webhooks",
+            "$before: String, $after: String, $first: Int, $last: Int, $includeArchived: Boolean, $orderBy: PaginationOrderBy",
+            selection,
+            variables,
+            "webhooks",
+        )
+    }
+    pub async fn send(self) -> Result<Connection<T>, LinearError> {
+        let client = self.client;
+        self.into_operation().execute(client).await
+    }
+    /// Stream every node across all pages, following `pageInfo.endCursor`.
+    ///
+    /// Starts after the cursor set via `.after()` (or at the beginning) and
+    /// uses `.first()` as the page size. `.before()` / `.last()` are ignored.
+    pub fn stream(self) -> impl futures::Stream<Item = Result<T, LinearError>> + 'a
+    where
+        T: 'a,
+    {
+        let after = self.after.clone();
+        crate::pagination::paginate(after, move |after| {
+            Self {
+                client: self.client,
+                before: None,
+                after,
+                first: self.first,
+                last: None,
+                include_archived: self.include_archived,
+                order_by: self.order_by.clone(),
+                extra_variables: self.extra_variables.clone(),
+                _marker: std::marker::PhantomData,
+            }
+            .send()
+        })
+    }
+    /// Fetch every node across all pages. See [`stream`](Self::stream).
+    pub async fn all(self) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(self.stream()).await
+    }
+    /// Fetch nodes across pages until `max` have been collected. Pages past
+    /// the one containing the `max`-th node are never requested.
+    pub async fn take_all(self, max: usize) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(futures::StreamExt::take(self.stream(), max)).await
     }
 }
 /// Query builder: All users in the workspace. Supports filtering, sorting, and pagination.
@@ -117,6 +306,7 @@ pub struct UsersQueryBuilder<'a, T> {
     include_archived: Option<bool>,
     order_by: Option<PaginationOrderBy>,
     sort: Option<UserSortInput>,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
     _marker: std::marker::PhantomData<T>,
 }
 impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::User>>
@@ -158,8 +348,16 @@ impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::User>>
         self.sort = Some(value);
         self
     }
-    pub async fn send(self) -> Result<Connection<T>, LinearError> {
-        let mut map = serde_json::Map::new();
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the query without sending it, e.g. to add it to a
+    /// [`Batch`](crate::batch::Batch).
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        let mut map = self.extra_variables;
         if let Some(ref v) = self.filter {
             map.insert("filter".to_string(), serde_json::json!(v));
         }
@@ -188,46 +386,91 @@ impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::User>>
             map.insert("sort".to_string(), serde_json::json!(v));
         }
         let variables = serde_json::Value::Object(map);
-        let selection = T::selection();
-        let query = format!(
-            "query {}({}) {{ {}({}) {{ nodes {{ {} }} pageInfo {{ hasNextPage endCursor }} }} }}",
+        let selection = format!(
+            "{} {{ nodes {{ {} }} pageInfo {{ hasNextPage endCursor }} }}",
+            "users(filter: $filter, includeDisabled: $includeDisabled, before: $before, after: $after, first: $first, last: $last, includeArchived: $includeArchived, orderBy: $orderBy, sort: $sort)",
+            T::selection()
+        );
+        Operation::query(
             "Users",
             "$filter: UserFilter, $includeDisabled: Boolean, $before: String, $after: String, $first: Int, $last: Int, $includeArchived: Boolean, $orderBy: PaginationOrderBy, $sort: [UserSortInput!]",
+            selection,
+            variables,
             "users",
-            "filter: $filter, includeDisabled: $includeDisabled, before: $before, after: $after, first: $first, last: $last, includeArchived: $includeArchived, orderBy: $orderBy, sort: $sort",
-            selection
-        );
-        self.client
-            .execute_connection::<T>(&query, variables, "users")
-            .await
+        )
+    }
+    pub async fn send(self) -> Result<Connection<T>, LinearError> {
+        let client = self.client;
+        self.into_operation().execute(client).await
+    }
+    /// Stream every node across all pages, following `pageInfo.endCursor`.
+    ///
+    /// Starts after the cursor set via `.after()` (or at the beginning) and
+    /// uses `.first()` as the page size. `.before()` / `.last()` are ignored.
+    pub fn stream(self) -> impl futures::Stream<Item = Result<T, LinearError>> + 'a
+    where
+        T: 'a,
+    {
+        let after = self.after.clone();
+        crate::pagination::paginate(after, move |after| {
+            Self {
+                client: self.client,
+                filter: self.filter.clone(),
+                include_disabled: self.include_disabled,
+                before: None,
+                after,
+                first: self.first,
+                last: None,
+                include_archived: self.include_archived,
+                order_by: self.order_by.clone(),
+                sort: self.sort.clone(),
+                extra_variables: self.extra_variables.clone(),
+                _marker: std::marker::PhantomData,
+            }
+            .send()
+        })
+    }
+    /// Fetch every node across all pages. See [`stream`](Self::stream).
+    pub async fn all(self) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(self.stream()).await
+    }
+    /// Fetch nodes across pages until `max` have been collected. Pages past
+    /// the one containing the `max`-th node are never requested.
+    pub async fn take_all(self, max: usize) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(futures::StreamExt::take(self.stream(), max)).await
     }
 }
-/// Query builder: Returns all projects in the workspace, with optional filtering and sorting.
+/// Query builder: All triage responsibilities.
 ///
-/// Full type: [`Project`](super::types::Project)
+/// Full type: [`TriageResponsibility`](super::types::TriageResponsibility)
 ///
 /// Use setter methods to configure optional parameters, then call
 /// [`.send()`](Self::send) to execute the query.
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
 #[must_use]
-pub struct ProjectsQueryBuilder<'a, T> {
+pub struct TriageResponsibilitiesQueryBuilder<'a, T> {
     client: &'a Client,
-    filter: Option<ProjectFilter>,
     before: Option<String>,
     after: Option<String>,
     first: Option<i64>,
     last: Option<i64>,
     include_archived: Option<bool>,
     order_by: Option<PaginationOrderBy>,
-    sort: Option<ProjectSortInput>,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
     _marker: std::marker::PhantomData<T>,
 }
-impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Project>>
-    ProjectsQueryBuilder<'a, T>
+#[cfg(feature = "teams")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::TriageResponsibility>>
+    TriageResponsibilitiesQueryBuilder<'a, T>
 {
-    pub fn filter(mut self, value: ProjectFilter) -> Self {
-        self.filter = Some(value);
-        self
-    }
     pub fn before(mut self, value: impl Into<String>) -> Self {
         self.before = Some(value.into());
         self
@@ -252,15 +495,16 @@ impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Project>>
         self.order_by = Some(value);
         self
     }
-    pub fn sort(mut self, value: ProjectSortInput) -> Self {
-        self.sort = Some(value);
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
         self
     }
-    pub async fn send(self) -> Result<Connection<T>, LinearError> {
-        let mut map = serde_json::Map::new();
-        if let Some(ref v) = self.filter {
-            map.insert("filter".to_string(), serde_json::json!(v));
-        }
+    /// Build the query without sending it, e.g. to add it to a
+    /// [`Batch`](crate::batch::Batch).
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        let mut map = self.extra_variables;
         if let Some(ref v) = self.before {
             map.insert("before".to_string(), serde_json::json!(v));
         }
@@ -279,49 +523,89 @@ impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Project>>
         if let Some(ref v) = self.order_by {
             map.insert("orderBy".to_string(), serde_json::json!(v));
         }
-        if let Some(ref v) = self.sort {
-            map.insert("sort".to_string(), serde_json::json!(v));
-        }
         let variables = serde_json::Value::Object(map);
-        let selection = T::selection();
-        let query = format!(
-            "query {}({}) {{ {}({}) {{ nodes {{ {} }} pageInfo {{ hasNextPage endCursor }} }} }}",
-            "Projects",
-            "$filter: ProjectFilter, $before: String, $after: String, $first: Int, $last: Int, $includeArchived: Boolean, $orderBy: PaginationOrderBy, $sort: [ProjectSortInput!]",
-            "projects",
-            "filter: $filter, before: $before, after: $after, first: $first, last: $last, includeArchived: $includeArchived, orderBy: $orderBy, sort: $sort",
-            selection
+        let selection = format!(
+            "{} {{ nodes {{ {} }} pageInfo {{ hasNextPage endCursor }} }}",
+            "triageResponsibilities(before: $before, after: $after, first: $first, last: $last, includeArchived: $includeArchived, orderBy: $orderBy)",
+            T::selection()
         );
-        self.client
-            .execute_connection::<T>(&query, variables, "projects")
-            .await
+        Operation::query(
+            "TriageResponsibilities",
+            "$before: String, $after: String, $first: Int, $last: Int, $includeArchived: Boolean, $orderBy: PaginationOrderBy",
+            selection,
+            variables,
+            "triageResponsibilities",
+        )
+    }
+    pub async fn send(self) -> Result<Connection<T>, LinearError> {
+        let client = self.client;
+        self.into_operation().execute(client).await
+    }
+    /// Stream every node across all pages, following `pageInfo.endCursor`.
+    ///
+    /// Starts after the cursor set via `.after()` (or at the beginning) and
+    /// uses `.first()` as the page size. `.before()` / `.last()` are ignored.
+    pub fn stream(self) -> impl futures::Stream<Item = Result<T, LinearError>> + 'a
+    where
+        T: 'a,
+    {
+        let after = self.after.clone();
+        crate::pagination::paginate(after, move |after| {
+            Self {
+                client: self.client,
+                before: None,
+                after,
+                first: self.first,
+                last: None,
+                include_archived: self.include_archived,
+                order_by: self.order_by.clone(),
+                extra_variables: self.extra_variables.clone(),
+                _marker: std::marker::PhantomData,
+            }
+            .send()
+        })
+    }
+    /// Fetch every node across all pages. See [`stream`](Self::stream).
+    pub async fn all(self) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(self.stream()).await
+    }
+    /// Fetch nodes across pages until `max` have been collected. Pages past
+    /// the one containing the `max`-th node are never requested.
+    pub async fn take_all(self, max: usize) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(futures::StreamExt::take(self.stream(), max)).await
     }
 }
-/// Query builder: All teams whose issues the user can access. This includes public teams and private teams the user is a member of. This may differ from `administrableTeams`, which returns teams whose settings the user can change but whose issues they don't necessarily have access to.
+/// Query builder: All time schedules.
 ///
-/// Full type: [`Team`](super::types::Team)
+/// Full type: [`TimeSchedule`](super::types::TimeSchedule)
 ///
 /// Use setter methods to configure optional parameters, then call
 /// [`.send()`](Self::send) to execute the query.
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
 #[must_use]
-pub struct TeamsQueryBuilder<'a, T> {
+pub struct TimeSchedulesQueryBuilder<'a, T> {
     client: &'a Client,
-    filter: Option<TeamFilter>,
     before: Option<String>,
     after: Option<String>,
     first: Option<i64>,
     last: Option<i64>,
     include_archived: Option<bool>,
     order_by: Option<PaginationOrderBy>,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
     _marker: std::marker::PhantomData<T>,
 }
-impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Team>>
-    TeamsQueryBuilder<'a, T>
+#[cfg(feature = "teams")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::TimeSchedule>>
+    TimeSchedulesQueryBuilder<'a, T>
 {
-    pub fn filter(mut self, value: TeamFilter) -> Self {
-        self.filter = Some(value);
-        self
-    }
     pub fn before(mut self, value: impl Into<String>) -> Self {
         self.before = Some(value.into());
         self
@@ -346,11 +630,16 @@ impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Team>>
         self.order_by = Some(value);
         self
     }
-    pub async fn send(self) -> Result<Connection<T>, LinearError> {
-        let mut map = serde_json::Map::new();
-        if let Some(ref v) = self.filter {
-            map.insert("filter".to_string(), serde_json::json!(v));
-        }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the query without sending it, e.g. to add it to a
+    /// [`Batch`](crate::batch::Batch).
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        let mut map = self.extra_variables;
         if let Some(ref v) = self.before {
             map.insert("before".to_string(), serde_json::json!(v));
         }
@@ -370,45 +659,87 @@ impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Team>>
             map.insert("orderBy".to_string(), serde_json::json!(v));
         }
         let variables = serde_json::Value::Object(map);
-        let selection = T::selection();
-        let query = format!(
-            "query {}({}) {{ {}({}) {{ nodes {{ {} }} pageInfo {{ hasNextPage endCursor }} }} }}",
-            "Teams",
-            "$filter: TeamFilter, $before: String, $after: String, $first: Int, $last: Int, $includeArchived: Boolean, $orderBy: PaginationOrderBy",
-            "teams",
-            "filter: $filter, before: $before, after: $after, first: $first, last: $last, includeArchived: $includeArchived, orderBy: $orderBy",
-            selection
+        let selection = format!(
+            "{} {{ nodes {{ {} }} pageInfo {{ hasNextPage endCursor }} }}",
+            "timeSchedules(before: $before, after: $after, first: $first, last: $last, includeArchived: $includeArchived, orderBy: $orderBy)",
+            T::selection()
         );
-        self.client
-            .execute_connection::<T>(&query, variables, "teams")
-            .await
+        Operation::query(
+            "TimeSchedules",
+            "$before: String, $after: String, $first: Int, $last: Int, $includeArchived: Boolean, $orderBy: PaginationOrderBy",
+            selection,
+            variables,
+            "timeSchedules",
+        )
+    }
+    pub async fn send(self) -> Result<Connection<T>, LinearError> {
+        let client = self.client;
+        self.into_operation().execute(client).await
+    }
+    /// Stream every node across all pages, following `pageInfo.endCursor`.
+    ///
+    /// Starts after the cursor set via `.after()` (or at the beginning) and
+    /// uses `.first()` as the page size. `.before()` / `.last()` are ignored.
+    pub fn stream(self) -> impl futures::Stream<Item = Result<T, LinearError>> + 'a
+    where
+        T: 'a,
+    {
+        let after = self.after.clone();
+        crate::pagination::paginate(after, move |after| {
+            Self {
+                client: self.client,
+                before: None,
+                after,
+                first: self.first,
+                last: None,
+                include_archived: self.include_archived,
+                order_by: self.order_by.clone(),
+                extra_variables: self.extra_variables.clone(),
+                _marker: std::marker::PhantomData,
+            }
+            .send()
+        })
+    }
+    /// Fetch every node across all pages. See [`stream`](Self::stream).
+    pub async fn all(self) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(self.stream()).await
+    }
+    /// Fetch nodes across pages until `max` have been collected. Pages past
+    /// the one containing the `max`-th node are never requested.
+    pub async fn take_all(self, max: usize) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(futures::StreamExt::take(self.stream(), max)).await
     }
 }
-/// Query builder: Search issues by text query using full-text and vector search. Results are ranked by relevance unless an orderBy parameter is specified. Supports optional issue filters and comment inclusion. Rate-limited to 30 requests per minute.
+/// Query builder: Returns all projects in the workspace, with optional filtering and sorting.
 ///
-/// Full type: [`IssueSearchResult`](super::types::IssueSearchResult)
+/// Full type: [`Project`](super::types::Project)
 ///
 /// Use setter methods to configure optional parameters, then call
 /// [`.send()`](Self::send) to execute the query.
 #[must_use]
-pub struct SearchIssuesQueryBuilder<'a, T> {
+pub struct ProjectsQueryBuilder<'a, T> {
     client: &'a Client,
-    term: String,
-    filter: Option<IssueFilter>,
+    filter: Option<ProjectFilter>,
     before: Option<String>,
     after: Option<String>,
     first: Option<i64>,
     last: Option<i64>,
     include_archived: Option<bool>,
     order_by: Option<PaginationOrderBy>,
-    include_comments: Option<bool>,
-    team_id: Option<String>,
+    sort: Option<ProjectSortInput>,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
     _marker: std::marker::PhantomData<T>,
 }
-impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::IssueSearchResult>>
-    SearchIssuesQueryBuilder<'a, T>
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Project>>
+    ProjectsQueryBuilder<'a, T>
 {
-    pub fn filter(mut self, value: IssueFilter) -> Self {
+    pub fn filter(mut self, value: ProjectFilter) -> Self {
         self.filter = Some(value);
         self
     }
@@ -436,17 +767,20 @@ impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::IssueSearc
         self.order_by = Some(value);
         self
     }
-    pub fn include_comments(mut self, value: bool) -> Self {
-        self.include_comments = Some(value);
+    pub fn sort(mut self, value: ProjectSortInput) -> Self {
+        self.sort = Some(value);
         self
     }
-    pub fn team_id(mut self, value: impl Into<String>) -> Self {
-        self.team_id = Some(value.into());
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
         self
     }
-    pub async fn send(self) -> Result<Connection<T>, LinearError> {
-        let mut map = serde_json::Map::new();
-        map.insert("term".to_string(), serde_json::json!(self.term));
+    /// Build the query without sending it, e.g. to add it to a
+    /// [`Batch`](crate::batch::Batch).
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        let mut map = self.extra_variables;
         if let Some(ref v) = self.filter {
             map.insert("filter".to_string(), serde_json::json!(v));
         }
@@ -468,47 +802,155 @@ impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::IssueSearc
         if let Some(ref v) = self.order_by {
             map.insert("orderBy".to_string(), serde_json::json!(v));
         }
-        if let Some(ref v) = self.include_comments {
-            map.insert("includeComments".to_string(), serde_json::json!(v));
+        if let Some(ref v) = self.sort {
+            map.insert("sort".to_string(), serde_json::json!(v));
         }
+        let variables = serde_json::Value::Object(map);
+        let selection = format!(
+            "{} {{ nodes {{ {} }} pageInfo {{ hasNextPage endCursor }} }}",
+            "projects(filter: $filter, before: $before, after: $after, first: $first, last: $last, includeArchived: $includeArchived, orderBy: $orderBy, sort: $sort)",
+            T::selection()
+        );
+        Operation::query(
+            "Projects",
+            "$filter: ProjectFilter, $before: String, $after: String, $first: Int, $last: Int, $includeArchived: Boolean, $orderBy: PaginationOrderBy, $sort: [ProjectSortInput!]",
+            selection,
+            variables,
+            "projects",
+        )
+    }
+    pub async fn send(self) -> Result<Connection<T>, LinearError> {
+        let client = self.client;
+        self.into_operation().execute(client).await
+    }
+    /// Stream every node across all pages, following `pageInfo.endCursor`.
+    ///
+    /// Starts after the cursor set via `.after()` (or at the beginning) and
+    /// uses `.first()` as the page size. `.before()` / `.last()` are ignored.
+    pub fn stream(self) -> impl futures::Stream<Item = Result<T, LinearError>> + 'a
+    where
+        T: 'a,
+    {
+        let after = self.after.clone();
+        crate::pagination::paginate(after, move |after| {
+            Self {
+                client: self.client,
+                filter: self.filter.clone(),
+                before: None,
+                after,
+                first: self.first,
+                last: None,
+                include_archived: self.include_archived,
+                order_by: self.order_by.clone(),
+                sort: self.sort.clone(),
+                extra_variables: self.extra_variables.clone(),
+                _marker: std::marker::PhantomData,
+            }
+            .send()
+        })
+    }
+    /// Fetch every node across all pages. See [`stream`](Self::stream).
+    pub async fn all(self) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(self.stream()).await
+    }
+    /// Fetch nodes across pages until `max` have been collected. Pages past
+    /// the one containing the `max`-th node are never requested.
+    pub async fn take_all(self, max: usize) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(futures::StreamExt::take(self.stream(), max)).await
+    }
+}
+/// Query builder: Suggests filters for a project view based on a text prompt.
+///
+/// Full type: [`ProjectFilterSuggestionPayload`](super::types::ProjectFilterSuggestionPayload)
+///
+/// Use setter methods to configure optional parameters, then call
+/// [`.send()`](Self::send) to execute the query.
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+#[must_use]
+pub struct ProjectFilterSuggestionQueryBuilder<'a, T> {
+    client: &'a Client,
+    prompt: String,
+    team_id: Option<String>,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "projects")]
+impl<
+        'a,
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::ProjectFilterSuggestionPayload>,
+    > ProjectFilterSuggestionQueryBuilder<'a, T>
+{
+    pub fn team_id(mut self, value: impl Into<String>) -> Self {
+        self.team_id = Some(value.into());
+        self
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the query without sending it, e.g. to add it to a
+    /// [`Batch`](crate::batch::Batch).
+    pub fn into_operation(self) -> Operation<T> {
+        let mut map = self.extra_variables;
+        map.insert("prompt".to_string(), serde_json::json!(self.prompt));
         if let Some(ref v) = self.team_id {
             map.insert("teamId".to_string(), serde_json::json!(v));
         }
         let variables = serde_json::Value::Object(map);
-        let selection = T::selection();
-        let query = format!(
-            "query {}({}) {{ {}({}) {{ nodes {{ {} }} pageInfo {{ hasNextPage endCursor }} }} }}",
-            "SearchIssues",
-            "$filter: IssueFilter, $before: String, $after: String, $first: Int, $last: Int, $includeArchived: Boolean, $orderBy: PaginationOrderBy, $term: String!, $includeComments: Boolean, $teamId: String",
-            "searchIssues",
-            "filter: $filter, before: $before, after: $after, first: $first, last: $last, includeArchived: $includeArchived, orderBy: $orderBy, term: $term, includeComments: $includeComments, teamId: $teamId",
-            selection
+        let selection = format!(
+            "{} {{ {} }}",
+            "projectFilterSuggestion(teamId: $teamId, prompt: $prompt)",
+            T::selection()
         );
-        self.client
-            .execute_connection::<T>(&query, variables, "searchIssues")
-            .await
+        Operation::query(
+            "ProjectFilterSuggestion",
+            "$teamId: String, $prompt: String!",
+            selection,
+            variables,
+            "projectFilterSuggestion",
+        )
+    }
+    pub async fn send(self) -> Result<T, LinearError> {
+        let client = self.client;
+        self.into_operation().execute(client).await
     }
 }
-/// Query builder: Returns all project statuses in the workspace.
+/// Query builder: All teams whose issues the user can access. This includes public teams and private teams the user is a member of. This may differ from `administrableTeams`, which returns teams whose settings the user can change but whose issues they don't necessarily have access to.
 ///
-/// Full type: [`ProjectStatus`](super::types::ProjectStatus)
+/// Full type: [`Team`](super::types::Team)
 ///
 /// Use setter methods to configure optional parameters, then call
 /// [`.send()`](Self::send) to execute the query.
 #[must_use]
-pub struct ProjectStatusesQueryBuilder<'a, T> {
+pub struct TeamsQueryBuilder<'a, T> {
     client: &'a Client,
+    filter: Option<TeamFilter>,
     before: Option<String>,
     after: Option<String>,
     first: Option<i64>,
     last: Option<i64>,
     include_archived: Option<bool>,
     order_by: Option<PaginationOrderBy>,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
     _marker: std::marker::PhantomData<T>,
 }
-impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::ProjectStatus>>
-    ProjectStatusesQueryBuilder<'a, T>
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Team>>
+    TeamsQueryBuilder<'a, T>
 {
+    pub fn filter(mut self, value: TeamFilter) -> Self {
+        self.filter = Some(value);
+        self
+    }
     pub fn before(mut self, value: impl Into<String>) -> Self {
         self.before = Some(value.into());
         self
@@ -533,8 +975,19 @@ impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::ProjectSta
         self.order_by = Some(value);
         self
     }
-    pub async fn send(self) -> Result<Connection<T>, LinearError> {
-        let mut map = serde_json::Map::new();
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the query without sending it, e.g. to add it to a
+    /// [`Batch`](crate::batch::Batch).
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        let mut map = self.extra_variables;
+        if let Some(ref v) = self.filter {
+            map.insert("filter".to_string(), serde_json::json!(v));
+        }
         if let Some(ref v) = self.before {
             map.insert("before".to_string(), serde_json::json!(v));
         }
@@ -554,42 +1007,91 @@ impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::ProjectSta
             map.insert("orderBy".to_string(), serde_json::json!(v));
         }
         let variables = serde_json::Value::Object(map);
-        let selection = T::selection();
-        let query = format!(
-            "query {}({}) {{ {}({}) {{ nodes {{ {} }} pageInfo {{ hasNextPage endCursor }} }} }}",
-            "ProjectStatuses",
-            "$before: String, $after: String, $first: Int, $last: Int, $includeArchived: Boolean, $orderBy: PaginationOrderBy",
-            "projectStatuses",
-            "before: $before, after: $after, first: $first, last: $last, includeArchived: $includeArchived, orderBy: $orderBy",
-            selection
+        let selection = format!(
+            "{} {{ nodes {{ {} }} pageInfo {{ hasNextPage endCursor }} }}",
+            "teams(filter: $filter, before: $before, after: $after, first: $first, last: $last, includeArchived: $includeArchived, orderBy: $orderBy)",
+            T::selection()
         );
-        self.client
-            .execute_connection::<T>(&query, variables, "projectStatuses")
-            .await
+        Operation::query(
+            "Teams",
+            "$filter: TeamFilter, $before: String, $after: String, $first: Int, $last: Int, $includeArchived: Boolean, $orderBy: PaginationOrderBy",
+            selection,
+            variables,
+            "teams",
+        )
+    }
+    pub async fn send(self) -> Result<Connection<T>, LinearError> {
+        let client = self.client;
+        self.into_operation().execute(client).await
+    }
+    /// Stream every node across all pages, following `pageInfo.endCursor`.
+    ///
+    /// Starts after the cursor set via `.after()` (or at the beginning) and
+    /// uses `.first()` as the page size. `.before()` / `.last()` are ignored.
+    pub fn stream(self) -> impl futures::Stream<Item = Result<T, LinearError>> + 'a
+    where
+        T: 'a,
+    {
+        let after = self.after.clone();
+        crate::pagination::paginate(after, move |after| {
+            Self {
+                client: self.client,
+                filter: self.filter.clone(),
+                before: None,
+                after,
+                first: self.first,
+                last: None,
+                include_archived: self.include_archived,
+                order_by: self.order_by.clone(),
+                extra_variables: self.extra_variables.clone(),
+                _marker: std::marker::PhantomData,
+            }
+            .send()
+        })
+    }
+    /// Fetch every node across all pages. See [`stream`](Self::stream).
+    pub async fn all(self) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(self.stream()).await
+    }
+    /// Fetch nodes across pages until `max` have been collected. Pages past
+    /// the one containing the `max`-th node are never requested.
+    pub async fn take_all(self, max: usize) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(futures::StreamExt::take(self.stream(), max)).await
     }
 }
-/// Query builder: Returns all project milestones in the workspace, with optional filtering.
+/// Query builder: All teams whose settings the user can administer. This includes teams the user can change settings for, but whose issues they may not have direct access to. This differs from the `teams` query which returns teams based on issue access.
 ///
-/// Full type: [`ProjectMilestone`](super::types::ProjectMilestone)
+/// Full type: [`Team`](super::types::Team)
 ///
 /// Use setter methods to configure optional parameters, then call
 /// [`.send()`](Self::send) to execute the query.
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
 #[must_use]
-pub struct ProjectMilestonesQueryBuilder<'a, T> {
+pub struct AdministrableTeamsQueryBuilder<'a, T> {
     client: &'a Client,
-    filter: Option<ProjectMilestoneFilter>,
+    filter: Option<TeamFilter>,
     before: Option<String>,
     after: Option<String>,
     first: Option<i64>,
     last: Option<i64>,
     include_archived: Option<bool>,
     order_by: Option<PaginationOrderBy>,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
     _marker: std::marker::PhantomData<T>,
 }
-impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::ProjectMilestone>>
-    ProjectMilestonesQueryBuilder<'a, T>
+#[cfg(feature = "teams")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Team>>
+    AdministrableTeamsQueryBuilder<'a, T>
 {
-    pub fn filter(mut self, value: ProjectMilestoneFilter) -> Self {
+    pub fn filter(mut self, value: TeamFilter) -> Self {
         self.filter = Some(value);
         self
     }
@@ -617,8 +1119,16 @@ impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::ProjectMil
         self.order_by = Some(value);
         self
     }
-    pub async fn send(self) -> Result<Connection<T>, LinearError> {
-        let mut map = serde_json::Map::new();
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the query without sending it, e.g. to add it to a
+    /// [`Batch`](crate::batch::Batch).
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        let mut map = self.extra_variables;
         if let Some(ref v) = self.filter {
             map.insert("filter".to_string(), serde_json::json!(v));
         }
@@ -641,45 +1151,89 @@ impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::ProjectMil
             map.insert("orderBy".to_string(), serde_json::json!(v));
         }
         let variables = serde_json::Value::Object(map);
-        let selection = T::selection();
-        let query = format!(
-            "query {}({}) {{ {}({}) {{ nodes {{ {} }} pageInfo {{ hasNextPage endCursor }} }} }}",
-            "ProjectMilestones",
-            "$filter: ProjectMilestoneFilter, $before: String, $after: String, $first: Int, $last: Int, $includeArchived: Boolean, $orderBy: PaginationOrderBy",
-            "projectMilestones",
-            "filter: $filter, before: $before, after: $after, first: $first, last: $last, includeArchived: $includeArchived, orderBy: $orderBy",
-            selection
+        let selection = format!(
+            "{} {{ nodes {{ {} }} pageInfo {{ hasNextPage endCursor }} }}",
+            "administrableTeams(filter: $filter, before: $before, after: $after, first: $first, last: $last, includeArchived: $includeArchived, orderBy: $orderBy)",
+            T::selection()
         );
-        self.client
-            .execute_connection::<T>(&query, variables, "projectMilestones")
-            .await
+        Operation::query(
+            "AdministrableTeams",
+            "$filter: TeamFilter, $before: String, $after: String, $first: Int, $last: Int, $includeArchived: Boolean, $orderBy: PaginationOrderBy",
+            selection,
+            variables,
+            "administrableTeams",
+        )
+    }
+    pub async fn send(self) -> Result<Connection<T>, LinearError> {
+        let client = self.client;
+        self.into_operation().execute(client).await
+    }
+    /// Stream every node across all pages, following `pageInfo.endCursor`.
+    ///
+    /// Starts after the cursor set via `.after()` (or at the beginning) and
+    /// uses `.first()` as the page size. `.before()` / `.last()` are ignored.
+    pub fn stream(self) -> impl futures::Stream<Item = Result<T, LinearError>> + 'a
+    where
+        T: 'a,
+    {
+        let after = self.after.clone();
+        crate::pagination::paginate(after, move |after| {
+            Self {
+                client: self.client,
+                filter: self.filter.clone(),
+                before: None,
+                after,
+                first: self.first,
+                last: None,
+                include_archived: self.include_archived,
+                order_by: self.order_by.clone(),
+                extra_variables: self.extra_variables.clone(),
+                _marker: std::marker::PhantomData,
+            }
+            .send()
+        })
+    }
+    /// Fetch every node across all pages. See [`stream`](Self::stream).
+    pub async fn all(self) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(self.stream()).await
+    }
+    /// Fetch nodes across pages until `max` have been collected. Pages past
+    /// the one containing the `max`-th node are never requested.
+    pub async fn take_all(self, max: usize) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(futures::StreamExt::take(self.stream(), max)).await
     }
 }
-/// Query builder: Returns all project labels in the workspace, with optional filtering.
+/// Query builder: All team memberships in the workspace.
 ///
-/// Full type: [`ProjectLabel`](super::types::ProjectLabel)
+/// Full type: [`TeamMembership`](super::types::TeamMembership)
 ///
 /// Use setter methods to configure optional parameters, then call
 /// [`.send()`](Self::send) to execute the query.
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
 #[must_use]
-pub struct ProjectLabelsQueryBuilder<'a, T> {
+pub struct TeamMembershipsQueryBuilder<'a, T> {
     client: &'a Client,
-    filter: Option<ProjectLabelFilter>,
     before: Option<String>,
     after: Option<String>,
     first: Option<i64>,
     last: Option<i64>,
     include_archived: Option<bool>,
     order_by: Option<PaginationOrderBy>,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
     _marker: std::marker::PhantomData<T>,
 }
-impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::ProjectLabel>>
-    ProjectLabelsQueryBuilder<'a, T>
+#[cfg(feature = "teams")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::TeamMembership>>
+    TeamMembershipsQueryBuilder<'a, T>
 {
-    pub fn filter(mut self, value: ProjectLabelFilter) -> Self {
-        self.filter = Some(value);
-        self
-    }
     pub fn before(mut self, value: impl Into<String>) -> Self {
         self.before = Some(value.into());
         self
@@ -704,11 +1258,16 @@ impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::ProjectLab
         self.order_by = Some(value);
         self
     }
-    pub async fn send(self) -> Result<Connection<T>, LinearError> {
-        let mut map = serde_json::Map::new();
-        if let Some(ref v) = self.filter {
-            map.insert("filter".to_string(), serde_json::json!(v));
-        }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the query without sending it, e.g. to add it to a
+    /// [`Batch`](crate::batch::Batch).
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        let mut map = self.extra_variables;
         if let Some(ref v) = self.before {
             map.insert("before".to_string(), serde_json::json!(v));
         }
@@ -728,46 +1287,173 @@ impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::ProjectLab
             map.insert("orderBy".to_string(), serde_json::json!(v));
         }
         let variables = serde_json::Value::Object(map);
-        let selection = T::selection();
-        let query = format!(
-            "query {}({}) {{ {}({}) {{ nodes {{ {} }} pageInfo {{ hasNextPage endCursor }} }} }}",
-            "ProjectLabels",
-            "$filter: ProjectLabelFilter, $before: String, $after: String, $first: Int, $last: Int, $includeArchived: Boolean, $orderBy: PaginationOrderBy",
-            "projectLabels",
-            "filter: $filter, before: $before, after: $after, first: $first, last: $last, includeArchived: $includeArchived, orderBy: $orderBy",
-            selection
+        let selection = format!(
+            "{} {{ nodes {{ {} }} pageInfo {{ hasNextPage endCursor }} }}",
+            "teamMemberships(before: $before, after: $after, first: $first, last: $last, includeArchived: $includeArchived, orderBy: $orderBy)",
+            T::selection()
         );
-        self.client
-            .execute_connection::<T>(&query, variables, "projectLabels")
-            .await
+        Operation::query(
+            "TeamMemberships",
+            "$before: String, $after: String, $first: Int, $last: Int, $includeArchived: Boolean, $orderBy: PaginationOrderBy",
+            selection,
+            variables,
+            "teamMemberships",
+        )
+    }
+    pub async fn send(self) -> Result<Connection<T>, LinearError> {
+        let client = self.client;
+        self.into_operation().execute(client).await
+    }
+    /// Stream every node across all pages, following `pageInfo.endCursor`.
+    ///
+    /// Starts after the cursor set via `.after()` (or at the beginning) and
+    /// uses `.first()` as the page size. `.before()` / `.last()` are ignored.
+    pub fn stream(self) -> impl futures::Stream<Item = Result<T, LinearError>> + 'a
+    where
+        T: 'a,
+    {
+        let after = self.after.clone();
+        crate::pagination::paginate(after, move |after| {
+            Self {
+                client: self.client,
+                before: None,
+                after,
+                first: self.first,
+                last: None,
+                include_archived: self.include_archived,
+                order_by: self.order_by.clone(),
+                extra_variables: self.extra_variables.clone(),
+                _marker: std::marker::PhantomData,
+            }
+            .send()
+        })
+    }
+    /// Fetch every node across all pages. See [`stream`](Self::stream).
+    pub async fn all(self) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(self.stream()).await
+    }
+    /// Fetch nodes across pages until `max` have been collected. Pages past
+    /// the one containing the `max`-th node are never requested.
+    pub async fn take_all(self, max: usize) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(futures::StreamExt::take(self.stream(), max)).await
     }
 }
-/// Query builder: All issues. Returns a paginated list of issues visible to the authenticated user. Can be filtered by various criteria including team, assignee, state, labels, project, and cycle.
+/// Query builder: Search for issues, projects, initiatives, and documents using natural language. Uses vector-based semantic search with optional full-text search and reranking. Results can be filtered by type and by entity-specific filters. Rate-limited to 30 requests per minute.
 ///
-/// Full type: [`Issue`](super::types::Issue)
+/// Full type: [`SemanticSearchPayload`](super::types::SemanticSearchPayload)
 ///
 /// Use setter methods to configure optional parameters, then call
 /// [`.send()`](Self::send) to execute the query.
+///
+/// Requires the `agents` feature.
+#[cfg(feature = "agents")]
 #[must_use]
-pub struct IssuesQueryBuilder<'a, T> {
+pub struct SemanticSearchQueryBuilder<'a, T> {
     client: &'a Client,
-    filter: Option<IssueFilter>,
+    query: String,
+    types: Option<SemanticSearchResultType>,
+    max_results: Option<i64>,
+    include_archived: Option<bool>,
+    filters: Option<SemanticSearchFilters>,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "agents")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::SemanticSearchPayload>>
+    SemanticSearchQueryBuilder<'a, T>
+{
+    pub fn types(mut self, value: SemanticSearchResultType) -> Self {
+        self.types = Some(value);
+        self
+    }
+    pub fn max_results(mut self, value: i64) -> Self {
+        self.max_results = Some(value);
+        self
+    }
+    pub fn include_archived(mut self, value: bool) -> Self {
+        self.include_archived = Some(value);
+        self
+    }
+    pub fn filters(mut self, value: SemanticSearchFilters) -> Self {
+        self.filters = Some(value);
+        self
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the query without sending it, e.g. to add it to a
+    /// [`Batch`](crate::batch::Batch).
+    pub fn into_operation(self) -> Operation<T> {
+        let mut map = self.extra_variables;
+        map.insert("query".to_string(), serde_json::json!(self.query));
+        if let Some(ref v) = self.types {
+            map.insert("types".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.max_results {
+            map.insert("maxResults".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.include_archived {
+            map.insert("includeArchived".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.filters {
+            map.insert("filters".to_string(), serde_json::json!(v));
+        }
+        let variables = serde_json::Value::Object(map);
+        let selection = format!(
+            "{} {{ {} }}",
+            "semanticSearch(query: $query, types: $types, maxResults: $maxResults, includeArchived: $includeArchived, filters: $filters)",
+            T::selection()
+        );
+        Operation::query(
+            "SemanticSearch",
+            "$query: String!, $types: [SemanticSearchResultType!], $maxResults: Int, $includeArchived: Boolean, $filters: SemanticSearchFilters",
+            selection,
+            variables,
+            "semanticSearch",
+        )
+    }
+    pub async fn send(self) -> Result<T, LinearError> {
+        let client = self.client;
+        self.into_operation().execute(client).await
+    }
+}
+/// Query builder: Search documents by text query using full-text and vector search. Results are ranked by relevance unless an orderBy parameter is specified. Rate-limited to 30 requests per minute.
+///
+/// Full type: [`DocumentSearchResult`](super::types::DocumentSearchResult)
+///
+/// Use setter methods to configure optional parameters, then call
+/// [`.send()`](Self::send) to execute the query.
+///
+/// Requires the `documents` feature.
+#[cfg(feature = "documents")]
+#[must_use]
+pub struct SearchDocumentsQueryBuilder<'a, T> {
+    client: &'a Client,
+    term: String,
     before: Option<String>,
     after: Option<String>,
     first: Option<i64>,
     last: Option<i64>,
     include_archived: Option<bool>,
     order_by: Option<PaginationOrderBy>,
-    sort: Option<IssueSortInput>,
+    include_comments: Option<bool>,
+    team_id: Option<String>,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
     _marker: std::marker::PhantomData<T>,
 }
-impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Issue>>
-    IssuesQueryBuilder<'a, T>
+#[cfg(feature = "documents")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::DocumentSearchResult>>
+    SearchDocumentsQueryBuilder<'a, T>
 {
-    pub fn filter(mut self, value: IssueFilter) -> Self {
-        self.filter = Some(value);
-        self
-    }
     pub fn before(mut self, value: impl Into<String>) -> Self {
         self.before = Some(value.into());
         self
@@ -792,15 +1478,25 @@ impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Issue>>
         self.order_by = Some(value);
         self
     }
-    pub fn sort(mut self, value: IssueSortInput) -> Self {
-        self.sort = Some(value);
+    pub fn include_comments(mut self, value: bool) -> Self {
+        self.include_comments = Some(value);
         self
     }
-    pub async fn send(self) -> Result<Connection<T>, LinearError> {
-        let mut map = serde_json::Map::new();
-        if let Some(ref v) = self.filter {
-            map.insert("filter".to_string(), serde_json::json!(v));
-        }
+    pub fn team_id(mut self, value: impl Into<String>) -> Self {
+        self.team_id = Some(value.into());
+        self
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the query without sending it, e.g. to add it to a
+    /// [`Batch`](crate::batch::Batch).
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        let mut map = self.extra_variables;
+        map.insert("term".to_string(), serde_json::json!(self.term));
         if let Some(ref v) = self.before {
             map.insert("before".to_string(), serde_json::json!(v));
         }
@@ -819,43 +1515,100 @@ impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Issue>>
         if let Some(ref v) = self.order_by {
             map.insert("orderBy".to_string(), serde_json::json!(v));
         }
-        if let Some(ref v) = self.sort {
-            map.insert("sort".to_string(), serde_json::json!(v));
+        if let Some(ref v) = self.include_comments {
+            map.insert("includeComments".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.team_id {
+            map.insert("teamId".to_string(), serde_json::json!(v));
         }
         let variables = serde_json::Value::Object(map);
-        let selection = T::selection();
-        let query = format!(
-            "query {}({}) {{ {}({}) {{ nodes {{ {} }} pageInfo {{ hasNextPage endCursor }} }} }}",
-            "Issues",
-            "$filter: IssueFilter, $before: String, $after: String, $first: Int, $last: Int, $includeArchived: Boolean, $orderBy: PaginationOrderBy, $sort: [IssueSortInput!]",
-            "issues",
-            "filter: $filter, before: $before, after: $after, first: $first, last: $last, includeArchived: $includeArchived, orderBy: $orderBy, sort: $sort",
-            selection
+        let selection = format!(
+            "{} {{ nodes {{ {} }} pageInfo {{ hasNextPage endCursor }} }}",
+            "searchDocuments(before: $before, after: $after, first: $first, last: $last, includeArchived: $includeArchived, orderBy: $orderBy, term: $term, includeComments: $includeComments, teamId: $teamId)",
+            T::selection()
         );
-        self.client
-            .execute_connection::<T>(&query, variables, "issues")
-            .await
+        Operation::query(
+            "SearchDocuments",
+            "$before: String, $after: String, $first: Int, $last: Int, $includeArchived: Boolean, $orderBy: PaginationOrderBy, $term: String!, $includeComments: Boolean, $teamId: String",
+            selection,
+            variables,
+            "searchDocuments",
+        )
+    }
+    pub async fn send(self) -> Result<Connection<T>, LinearError> {
+        let client = self.client;
+        self.into_operation().execute(client).await
+    }
+    /// Stream every node across all pages, following `pageInfo.endCursor`.
+    ///
+    /// Starts after the cursor set via `.after()` (or at the beginning) and
+    /// uses `.first()` as the page size. `.before()` / `.last()` are ignored.
+    pub fn stream(self) -> impl futures::Stream<Item = Result<T, LinearError>> + 'a
+    where
+        T: 'a,
+    {
+        let after = self.after.clone();
+        crate::pagination::paginate(after, move |after| {
+            Self {
+                client: self.client,
+                term: self.term.clone(),
+                before: None,
+                after,
+                first: self.first,
+                last: None,
+                include_archived: self.include_archived,
+                order_by: self.order_by.clone(),
+                include_comments: self.include_comments,
+                team_id: self.team_id.clone(),
+                extra_variables: self.extra_variables.clone(),
+                _marker: std::marker::PhantomData,
+            }
+            .send()
+        })
+    }
+    /// Fetch every node across all pages. See [`stream`](Self::stream).
+    pub async fn all(self) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(self.stream()).await
+    }
+    /// Fetch nodes across pages until `max` have been collected. Pages past
+    /// the one containing the `max`-th node are never requested.
+    pub async fn take_all(self, max: usize) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(futures::StreamExt::take(self.stream(), max)).await
     }
 }
-/// Query builder: All issue relations. Returns a paginated list of all issue relations (blocks, blocked by, relates to, duplicates) visible to the authenticated user.
+/// Query builder: Search projects by text query using full-text and vector search. Results are ranked by relevance unless an orderBy parameter is specified. Rate-limited to 30 requests per minute.
 ///
-/// Full type: [`IssueRelation`](super::types::IssueRelation)
+/// Full type: [`ProjectSearchResult`](super::types::ProjectSearchResult)
 ///
 /// Use setter methods to configure optional parameters, then call
 /// [`.send()`](Self::send) to execute the query.
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
 #[must_use]
-pub struct IssueRelationsQueryBuilder<'a, T> {
+pub struct SearchProjectsQueryBuilder<'a, T> {
     client: &'a Client,
+    term: String,
     before: Option<String>,
     after: Option<String>,
     first: Option<i64>,
     last: Option<i64>,
     include_archived: Option<bool>,
     order_by: Option<PaginationOrderBy>,
+    include_comments: Option<bool>,
+    team_id: Option<String>,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
     _marker: std::marker::PhantomData<T>,
 }
-impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::IssueRelation>>
-    IssueRelationsQueryBuilder<'a, T>
+#[cfg(feature = "projects")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::ProjectSearchResult>>
+    SearchProjectsQueryBuilder<'a, T>
 {
     pub fn before(mut self, value: impl Into<String>) -> Self {
         self.before = Some(value.into());
@@ -881,8 +1634,25 @@ impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::IssueRelat
         self.order_by = Some(value);
         self
     }
-    pub async fn send(self) -> Result<Connection<T>, LinearError> {
-        let mut map = serde_json::Map::new();
+    pub fn include_comments(mut self, value: bool) -> Self {
+        self.include_comments = Some(value);
+        self
+    }
+    pub fn team_id(mut self, value: impl Into<String>) -> Self {
+        self.team_id = Some(value.into());
+        self
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the query without sending it, e.g. to add it to a
+    /// [`Batch`](crate::batch::Batch).
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        let mut map = self.extra_variables;
+        map.insert("term".to_string(), serde_json::json!(self.term));
         if let Some(ref v) = self.before {
             map.insert("before".to_string(), serde_json::json!(v));
         }
@@ -901,43 +1671,99 @@ impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::IssueRelat
         if let Some(ref v) = self.order_by {
             map.insert("orderBy".to_string(), serde_json::json!(v));
         }
+        if let Some(ref v) = self.include_comments {
+            map.insert("includeComments".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.team_id {
+            map.insert("teamId".to_string(), serde_json::json!(v));
+        }
         let variables = serde_json::Value::Object(map);
-        let selection = T::selection();
-        let query = format!(
-            "query {}({}) {{ {}({}) {{ nodes {{ {} }} pageInfo {{ hasNextPage endCursor }} }} }}",
-            "IssueRelations",
-            "$before: String, $after: String, $first: Int, $last: Int, $includeArchived: Boolean, $orderBy: PaginationOrderBy",
-            "issueRelations",
-            "before: $before, after: $after, first: $first, last: $last, includeArchived: $includeArchived, orderBy: $orderBy",
-            selection
+        let selection = format!(
+            "{} {{ nodes {{ {} }} pageInfo {{ hasNextPage endCursor }} }}",
+            "searchProjects(before: $before, after: $after, first: $first, last: $last, includeArchived: $includeArchived, orderBy: $orderBy, term: $term, includeComments: $includeComments, teamId: $teamId)",
+            T::selection()
         );
-        self.client
-            .execute_connection::<T>(&query, variables, "issueRelations")
-            .await
+        Operation::query(
+            "SearchProjects",
+            "$before: String, $after: String, $first: Int, $last: Int, $includeArchived: Boolean, $orderBy: PaginationOrderBy, $term: String!, $includeComments: Boolean, $teamId: String",
+            selection,
+            variables,
+            "searchProjects",
+        )
+    }
+    pub async fn send(self) -> Result<Connection<T>, LinearError> {
+        let client = self.client;
+        self.into_operation().execute(client).await
+    }
+    /// Stream every node across all pages, following `pageInfo.endCursor`.
+    ///
+    /// Starts after the cursor set via `.after()` (or at the beginning) and
+    /// uses `.first()` as the page size. `.before()` / `.last()` are ignored.
+    pub fn stream(self) -> impl futures::Stream<Item = Result<T, LinearError>> + 'a
+    where
+        T: 'a,
+    {
+        let after = self.after.clone();
+        crate::pagination::paginate(after, move |after| {
+            Self {
+                client: self.client,
+                term: self.term.clone(),
+                before: None,
+                after,
+                first: self.first,
+                last: None,
+                include_archived: self.include_archived,
+                order_by: self.order_by.clone(),
+                include_comments: self.include_comments,
+                team_id: self.team_id.clone(),
+                extra_variables: self.extra_variables.clone(),
+                _marker: std::marker::PhantomData,
+            }
+            .send()
+        })
+    }
+    /// Fetch every node across all pages. See [`stream`](Self::stream).
+    pub async fn all(self) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(self.stream()).await
+    }
+    /// Fetch nodes across pages until `max` have been collected. Pages past
+    /// the one containing the `max`-th node are never requested.
+    pub async fn take_all(self, max: usize) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(futures::StreamExt::take(self.stream(), max)).await
     }
 }
-/// Query builder: All issue labels. Returns a paginated list of labels visible to the authenticated user, including both workspace-level and team-scoped labels.
+/// Query builder: Search issues by text query using full-text and vector search. Results are ranked by relevance unless an orderBy parameter is specified. Supports optional issue filters and comment inclusion. Rate-limited to 30 requests per minute.
 ///
-/// Full type: [`IssueLabel`](super::types::IssueLabel)
+/// Full type: [`IssueSearchResult`](super::types::IssueSearchResult)
 ///
 /// Use setter methods to configure optional parameters, then call
 /// [`.send()`](Self::send) to execute the query.
 #[must_use]
-pub struct IssueLabelsQueryBuilder<'a, T> {
+pub struct SearchIssuesQueryBuilder<'a, T> {
     client: &'a Client,
-    filter: Option<IssueLabelFilter>,
+    term: String,
+    filter: Option<IssueFilter>,
     before: Option<String>,
     after: Option<String>,
     first: Option<i64>,
     last: Option<i64>,
     include_archived: Option<bool>,
     order_by: Option<PaginationOrderBy>,
+    include_comments: Option<bool>,
+    team_id: Option<String>,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
     _marker: std::marker::PhantomData<T>,
 }
-impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::IssueLabel>>
-    IssueLabelsQueryBuilder<'a, T>
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::IssueSearchResult>>
+    SearchIssuesQueryBuilder<'a, T>
 {
-    pub fn filter(mut self, value: IssueLabelFilter) -> Self {
+    pub fn filter(mut self, value: IssueFilter) -> Self {
         self.filter = Some(value);
         self
     }
@@ -965,8 +1791,25 @@ impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::IssueLabel
         self.order_by = Some(value);
         self
     }
-    pub async fn send(self) -> Result<Connection<T>, LinearError> {
-        let mut map = serde_json::Map::new();
+    pub fn include_comments(mut self, value: bool) -> Self {
+        self.include_comments = Some(value);
+        self
+    }
+    pub fn team_id(mut self, value: impl Into<String>) -> Self {
+        self.team_id = Some(value.into());
+        self
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the query without sending it, e.g. to add it to a
+    /// [`Batch`](crate::batch::Batch).
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        let mut map = self.extra_variables;
+        map.insert("term".to_string(), serde_json::json!(self.term));
         if let Some(ref v) = self.filter {
             map.insert("filter".to_string(), serde_json::json!(v));
         }
@@ -988,46 +1831,99 @@ impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::IssueLabel
         if let Some(ref v) = self.order_by {
             map.insert("orderBy".to_string(), serde_json::json!(v));
         }
+        if let Some(ref v) = self.include_comments {
+            map.insert("includeComments".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.team_id {
+            map.insert("teamId".to_string(), serde_json::json!(v));
+        }
         let variables = serde_json::Value::Object(map);
-        let selection = T::selection();
-        let query = format!(
-            "query {}({}) {{ {}({}) {{ nodes {{ {} }} pageInfo {{ hasNextPage endCursor }} }} }}",
-            "IssueLabels",
-            "$filter: IssueLabelFilter, $before: String, $after: String, $first: Int, $last: Int, $includeArchived: Boolean, $orderBy: PaginationOrderBy",
-            "issueLabels",
-            "filter: $filter, before: $before, after: $after, first: $first, last: $last, includeArchived: $includeArchived, orderBy: $orderBy",
-            selection
+        let selection = format!(
+            "{} {{ nodes {{ {} }} pageInfo {{ hasNextPage endCursor }} }}",
+            "searchIssues(filter: $filter, before: $before, after: $after, first: $first, last: $last, includeArchived: $includeArchived, orderBy: $orderBy, term: $term, includeComments: $includeComments, teamId: $teamId)",
+            T::selection()
         );
-        self.client
-            .execute_connection::<T>(&query, variables, "issueLabels")
-            .await
+        Operation::query(
+            "SearchIssues",
+            "$filter: IssueFilter, $before: String, $after: String, $first: Int, $last: Int, $includeArchived: Boolean, $orderBy: PaginationOrderBy, $term: String!, $includeComments: Boolean, $teamId: String",
+            selection,
+            variables,
+            "searchIssues",
+        )
+    }
+    pub async fn send(self) -> Result<Connection<T>, LinearError> {
+        let client = self.client;
+        self.into_operation().execute(client).await
+    }
+    /// Stream every node across all pages, following `pageInfo.endCursor`.
+    ///
+    /// Starts after the cursor set via `.after()` (or at the beginning) and
+    /// uses `.first()` as the page size. `.before()` / `.last()` are ignored.
+    pub fn stream(self) -> impl futures::Stream<Item = Result<T, LinearError>> + 'a
+    where
+        T: 'a,
+    {
+        let after = self.after.clone();
+        crate::pagination::paginate(after, move |after| {
+            Self {
+                client: self.client,
+                term: self.term.clone(),
+                filter: self.filter.clone(),
+                before: None,
+                after,
+                first: self.first,
+                last: None,
+                include_archived: self.include_archived,
+                order_by: self.order_by.clone(),
+                include_comments: self.include_comments,
+                team_id: self.team_id.clone(),
+                extra_variables: self.extra_variables.clone(),
+                _marker: std::marker::PhantomData,
+            }
+            .send()
+        })
+    }
+    /// Fetch every node across all pages. See [`stream`](Self::stream).
+    pub async fn all(self) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(self.stream()).await
+    }
+    /// Fetch nodes across pages until `max` have been collected. Pages past
+    /// the one containing the `max`-th node are never requested.
+    pub async fn take_all(self, max: usize) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(futures::StreamExt::take(self.stream(), max)).await
     }
 }
-/// Query builder: All documents the user has access to in the workspace.
+/// Query builder: `Deprecated` Returns all roadmap-to-project associations. Use InitiativeToProject instead.
 ///
-/// Full type: [`Document`](super::types::Document)
+/// Full type: [`RoadmapToProject`](super::types::RoadmapToProject)
 ///
 /// Use setter methods to configure optional parameters, then call
 /// [`.send()`](Self::send) to execute the query.
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
 #[must_use]
-pub struct DocumentsQueryBuilder<'a, T> {
+pub struct RoadmapToProjectsQueryBuilder<'a, T> {
     client: &'a Client,
-    filter: Option<DocumentFilter>,
     before: Option<String>,
     after: Option<String>,
     first: Option<i64>,
     last: Option<i64>,
     include_archived: Option<bool>,
     order_by: Option<PaginationOrderBy>,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
     _marker: std::marker::PhantomData<T>,
 }
-impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Document>>
-    DocumentsQueryBuilder<'a, T>
+#[cfg(feature = "projects")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::RoadmapToProject>>
+    RoadmapToProjectsQueryBuilder<'a, T>
 {
-    pub fn filter(mut self, value: DocumentFilter) -> Self {
-        self.filter = Some(value);
-        self
-    }
     pub fn before(mut self, value: impl Into<String>) -> Self {
         self.before = Some(value.into());
         self
@@ -1052,11 +1948,16 @@ impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Document>>
         self.order_by = Some(value);
         self
     }
-    pub async fn send(self) -> Result<Connection<T>, LinearError> {
-        let mut map = serde_json::Map::new();
-        if let Some(ref v) = self.filter {
-            map.insert("filter".to_string(), serde_json::json!(v));
-        }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the query without sending it, e.g. to add it to a
+    /// [`Batch`](crate::batch::Batch).
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        let mut map = self.extra_variables;
         if let Some(ref v) = self.before {
             map.insert("before".to_string(), serde_json::json!(v));
         }
@@ -1076,45 +1977,88 @@ impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Document>>
             map.insert("orderBy".to_string(), serde_json::json!(v));
         }
         let variables = serde_json::Value::Object(map);
-        let selection = T::selection();
-        let query = format!(
-            "query {}({}) {{ {}({}) {{ nodes {{ {} }} pageInfo {{ hasNextPage endCursor }} }} }}",
-            "Documents",
-            "$filter: DocumentFilter, $before: String, $after: String, $first: Int, $last: Int, $includeArchived: Boolean, $orderBy: PaginationOrderBy",
-            "documents",
-            "filter: $filter, before: $before, after: $after, first: $first, last: $last, includeArchived: $includeArchived, orderBy: $orderBy",
-            selection
+        let selection = format!(
+            "{} {{ nodes {{ {} }} pageInfo {{ hasNextPage endCursor }} }}",
+            "roadmapToProjects(before: $before, after: $after, first: $first, last: $last, includeArchived: $includeArchived, orderBy: $orderBy)",
+            T::selection()
         );
-        self.client
-            .execute_connection::<T>(&query, variables, "documents")
-            .await
+        Operation::query(
+            "RoadmapToProjects",
+            "$before: String, $after: String, $first: Int, $last: Int, $includeArchived: Boolean, $orderBy: PaginationOrderBy",
+            selection,
+            variables,
+            "roadmapToProjects",
+        )
+    }
+    pub async fn send(self) -> Result<Connection<T>, LinearError> {
+        let client = self.client;
+        self.into_operation().execute(client).await
+    }
+    /// Stream every node across all pages, following `pageInfo.endCursor`.
+    ///
+    /// Starts after the cursor set via `.after()` (or at the beginning) and
+    /// uses `.first()` as the page size. `.before()` / `.last()` are ignored.
+    pub fn stream(self) -> impl futures::Stream<Item = Result<T, LinearError>> + 'a
+    where
+        T: 'a,
+    {
+        let after = self.after.clone();
+        crate::pagination::paginate(after, move |after| {
+            Self {
+                client: self.client,
+                before: None,
+                after,
+                first: self.first,
+                last: None,
+                include_archived: self.include_archived,
+                order_by: self.order_by.clone(),
+                extra_variables: self.extra_variables.clone(),
+                _marker: std::marker::PhantomData,
+            }
+            .send()
+        })
+    }
+    /// Fetch every node across all pages. See [`stream`](Self::stream).
+    pub async fn all(self) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(self.stream()).await
+    }
+    /// Fetch nodes across pages until `max` have been collected. Pages past
+    /// the one containing the `max`-th node are never requested.
+    pub async fn take_all(self, max: usize) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(futures::StreamExt::take(self.stream(), max)).await
     }
 }
-/// Query builder: All cycles accessible to the user.
+/// Query builder: `Deprecated` Returns all roadmaps in the workspace. Use initiatives instead.
 ///
-/// Full type: [`Cycle`](super::types::Cycle)
+/// Full type: [`Roadmap`](super::types::Roadmap)
 ///
 /// Use setter methods to configure optional parameters, then call
 /// [`.send()`](Self::send) to execute the query.
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
 #[must_use]
-pub struct CyclesQueryBuilder<'a, T> {
+pub struct RoadmapsQueryBuilder<'a, T> {
     client: &'a Client,
-    filter: Option<CycleFilter>,
     before: Option<String>,
     after: Option<String>,
     first: Option<i64>,
     last: Option<i64>,
     include_archived: Option<bool>,
     order_by: Option<PaginationOrderBy>,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
     _marker: std::marker::PhantomData<T>,
 }
-impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Cycle>>
-    CyclesQueryBuilder<'a, T>
+#[cfg(feature = "projects")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Roadmap>>
+    RoadmapsQueryBuilder<'a, T>
 {
-    pub fn filter(mut self, value: CycleFilter) -> Self {
-        self.filter = Some(value);
-        self
-    }
     pub fn before(mut self, value: impl Into<String>) -> Self {
         self.before = Some(value.into());
         self
@@ -1139,11 +2083,16 @@ impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Cycle>>
         self.order_by = Some(value);
         self
     }
-    pub async fn send(self) -> Result<Connection<T>, LinearError> {
-        let mut map = serde_json::Map::new();
-        if let Some(ref v) = self.filter {
-            map.insert("filter".to_string(), serde_json::json!(v));
-        }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the query without sending it, e.g. to add it to a
+    /// [`Batch`](crate::batch::Batch).
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        let mut map = self.extra_variables;
         if let Some(ref v) = self.before {
             map.insert("before".to_string(), serde_json::json!(v));
         }
@@ -1163,70 +2112,8187 @@ impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Cycle>>
             map.insert("orderBy".to_string(), serde_json::json!(v));
         }
         let variables = serde_json::Value::Object(map);
-        let selection = T::selection();
-        let query = format!(
-            "query {}({}) {{ {}({}) {{ nodes {{ {} }} pageInfo {{ hasNextPage endCursor }} }} }}",
-            "Cycles",
-            "$filter: CycleFilter, $before: String, $after: String, $first: Int, $last: Int, $includeArchived: Boolean, $orderBy: PaginationOrderBy",
-            "cycles",
-            "filter: $filter, before: $before, after: $after, first: $first, last: $last, includeArchived: $includeArchived, orderBy: $orderBy",
-            selection
+        let selection = format!(
+            "{} {{ nodes {{ {} }} pageInfo {{ hasNextPage endCursor }} }}",
+            "roadmaps(before: $before, after: $after, first: $first, last: $last, includeArchived: $includeArchived, orderBy: $orderBy)",
+            T::selection()
         );
-        self.client
-            .execute_connection::<T>(&query, variables, "cycles")
-            .await
+        Operation::query(
+            "Roadmaps",
+            "$before: String, $after: String, $first: Int, $last: Int, $includeArchived: Boolean, $orderBy: PaginationOrderBy",
+            selection,
+            variables,
+            "roadmaps",
+        )
+    }
+    pub async fn send(self) -> Result<Connection<T>, LinearError> {
+        let client = self.client;
+        self.into_operation().execute(client).await
+    }
+    /// Stream every node across all pages, following `pageInfo.endCursor`.
+    ///
+    /// Starts after the cursor set via `.after()` (or at the beginning) and
+    /// uses `.first()` as the page size. `.before()` / `.last()` are ignored.
+    pub fn stream(self) -> impl futures::Stream<Item = Result<T, LinearError>> + 'a
+    where
+        T: 'a,
+    {
+        let after = self.after.clone();
+        crate::pagination::paginate(after, move |after| {
+            Self {
+                client: self.client,
+                before: None,
+                after,
+                first: self.first,
+                last: None,
+                include_archived: self.include_archived,
+                order_by: self.order_by.clone(),
+                extra_variables: self.extra_variables.clone(),
+                _marker: std::marker::PhantomData,
+            }
+            .send()
+        })
+    }
+    /// Fetch every node across all pages. See [`stream`](Self::stream).
+    pub async fn all(self) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(self.stream()).await
+    }
+    /// Fetch nodes across pages until `max` have been collected. Pages past
+    /// the one containing the `max`-th node are never requested.
+    pub async fn take_all(self, max: usize) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(futures::StreamExt::take(self.stream(), max)).await
     }
 }
-/// All issue workflow states (issue statuses). Returns a paginated list of workflow states visible to the authenticated user, across all teams they have access to.
+/// Query builder: `ALPHA` All release stages in the workspace, with optional filtering.
 ///
-/// Full type: [`WorkflowState`](super::types::WorkflowState)
-pub fn workflow_states<'a, T>(client: &'a Client) -> WorkflowStatesQueryBuilder<'a, T> {
-    WorkflowStatesQueryBuilder {
-        client,
-        filter: None,
-        before: None,
-        after: None,
-        first: None,
-        last: None,
-        include_archived: None,
+/// Full type: [`ReleaseStage`](super::types::ReleaseStage)
+///
+/// Use setter methods to configure optional parameters, then call
+/// [`.send()`](Self::send) to execute the query.
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+#[must_use]
+pub struct ReleaseStagesQueryBuilder<'a, T> {
+    client: &'a Client,
+    filter: Option<ReleaseStageFilter>,
+    before: Option<String>,
+    after: Option<String>,
+    first: Option<i64>,
+    last: Option<i64>,
+    include_archived: Option<bool>,
+    order_by: Option<PaginationOrderBy>,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "releases")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::ReleaseStage>>
+    ReleaseStagesQueryBuilder<'a, T>
+{
+    pub fn filter(mut self, value: ReleaseStageFilter) -> Self {
+        self.filter = Some(value);
+        self
+    }
+    pub fn before(mut self, value: impl Into<String>) -> Self {
+        self.before = Some(value.into());
+        self
+    }
+    pub fn after(mut self, value: impl Into<String>) -> Self {
+        self.after = Some(value.into());
+        self
+    }
+    pub fn first(mut self, value: i64) -> Self {
+        self.first = Some(value);
+        self
+    }
+    pub fn last(mut self, value: i64) -> Self {
+        self.last = Some(value);
+        self
+    }
+    pub fn include_archived(mut self, value: bool) -> Self {
+        self.include_archived = Some(value);
+        self
+    }
+    pub fn order_by(mut self, value: PaginationOrderBy) -> Self {
+        self.order_by = Some(value);
+        self
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the query without sending it, e.g. to add it to a
+    /// [`Batch`](crate::batch::Batch).
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        let mut map = self.extra_variables;
+        if let Some(ref v) = self.filter {
+            map.insert("filter".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.before {
+            map.insert("before".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.after {
+            map.insert("after".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.first {
+            map.insert("first".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.last {
+            map.insert("last".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.include_archived {
+            map.insert("includeArchived".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.order_by {
+            map.insert("orderBy".to_string(), serde_json::json!(v));
+        }
+        let variables = serde_json::Value::Object(map);
+        let selection = format!(
+            "{} {{ nodes {{ {} }} pageInfo {{ hasNextPage endCursor }} }}",
+            "releaseStages(filter: $filter, before: $before, after: $after, first: $first, last: $last, includeArchived: $includeArchived, orderBy: $orderBy)",
+            T::selection()
+        );
+        Operation::query(
+            "ReleaseStages",
+            "$filter: ReleaseStageFilter, $before: String, $after: String, $first: Int, $last: Int, $includeArchived: Boolean, $orderBy: PaginationOrderBy",
+            selection,
+            variables,
+            "releaseStages",
+        )
+    }
+    pub async fn send(self) -> Result<Connection<T>, LinearError> {
+        let client = self.client;
+        self.into_operation().execute(client).await
+    }
+    /// Stream every node across all pages, following `pageInfo.endCursor`.
+    ///
+    /// Starts after the cursor set via `.after()` (or at the beginning) and
+    /// uses `.first()` as the page size. `.before()` / `.last()` are ignored.
+    pub fn stream(self) -> impl futures::Stream<Item = Result<T, LinearError>> + 'a
+    where
+        T: 'a,
+    {
+        let after = self.after.clone();
+        crate::pagination::paginate(after, move |after| {
+            Self {
+                client: self.client,
+                filter: self.filter.clone(),
+                before: None,
+                after,
+                first: self.first,
+                last: None,
+                include_archived: self.include_archived,
+                order_by: self.order_by.clone(),
+                extra_variables: self.extra_variables.clone(),
+                _marker: std::marker::PhantomData,
+            }
+            .send()
+        })
+    }
+    /// Fetch every node across all pages. See [`stream`](Self::stream).
+    pub async fn all(self) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(self.stream()).await
+    }
+    /// Fetch nodes across pages until `max` have been collected. Pages past
+    /// the one containing the `max`-th node are never requested.
+    pub async fn take_all(self, max: usize) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(futures::StreamExt::take(self.stream(), max)).await
+    }
+}
+/// Query builder: `ALPHA` All release pipelines in the workspace, with optional filtering and sorting.
+///
+/// Full type: [`ReleasePipeline`](super::types::ReleasePipeline)
+///
+/// Use setter methods to configure optional parameters, then call
+/// [`.send()`](Self::send) to execute the query.
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+#[must_use]
+pub struct ReleasePipelinesQueryBuilder<'a, T> {
+    client: &'a Client,
+    filter: Option<ReleasePipelineFilter>,
+    before: Option<String>,
+    after: Option<String>,
+    first: Option<i64>,
+    last: Option<i64>,
+    include_archived: Option<bool>,
+    order_by: Option<PaginationOrderBy>,
+    sort: Option<ReleasePipelineSortInput>,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "releases")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::ReleasePipeline>>
+    ReleasePipelinesQueryBuilder<'a, T>
+{
+    pub fn filter(mut self, value: ReleasePipelineFilter) -> Self {
+        self.filter = Some(value);
+        self
+    }
+    pub fn before(mut self, value: impl Into<String>) -> Self {
+        self.before = Some(value.into());
+        self
+    }
+    pub fn after(mut self, value: impl Into<String>) -> Self {
+        self.after = Some(value.into());
+        self
+    }
+    pub fn first(mut self, value: i64) -> Self {
+        self.first = Some(value);
+        self
+    }
+    pub fn last(mut self, value: i64) -> Self {
+        self.last = Some(value);
+        self
+    }
+    pub fn include_archived(mut self, value: bool) -> Self {
+        self.include_archived = Some(value);
+        self
+    }
+    pub fn order_by(mut self, value: PaginationOrderBy) -> Self {
+        self.order_by = Some(value);
+        self
+    }
+    pub fn sort(mut self, value: ReleasePipelineSortInput) -> Self {
+        self.sort = Some(value);
+        self
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the query without sending it, e.g. to add it to a
+    /// [`Batch`](crate::batch::Batch).
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        let mut map = self.extra_variables;
+        if let Some(ref v) = self.filter {
+            map.insert("filter".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.before {
+            map.insert("before".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.after {
+            map.insert("after".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.first {
+            map.insert("first".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.last {
+            map.insert("last".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.include_archived {
+            map.insert("includeArchived".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.order_by {
+            map.insert("orderBy".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.sort {
+            map.insert("sort".to_string(), serde_json::json!(v));
+        }
+        let variables = serde_json::Value::Object(map);
+        let selection = format!(
+            "{} {{ nodes {{ {} }} pageInfo {{ hasNextPage endCursor }} }}",
+            "releasePipelines(filter: $filter, before: $before, after: $after, first: $first, last: $last, includeArchived: $includeArchived, orderBy: $orderBy, sort: $sort)",
+            T::selection()
+        );
+        Operation::query(
+            "ReleasePipelines",
+            "$filter: ReleasePipelineFilter, $before: String, $after: String, $first: Int, $last: Int, $includeArchived: Boolean, $orderBy: PaginationOrderBy, $sort: [ReleasePipelineSortInput!]",
+            selection,
+            variables,
+            "releasePipelines",
+        )
+    }
+    pub async fn send(self) -> Result<Connection<T>, LinearError> {
+        let client = self.client;
+        self.into_operation().execute(client).await
+    }
+    /// Stream every node across all pages, following `pageInfo.endCursor`.
+    ///
+    /// Starts after the cursor set via `.after()` (or at the beginning) and
+    /// uses `.first()` as the page size. `.before()` / `.last()` are ignored.
+    pub fn stream(self) -> impl futures::Stream<Item = Result<T, LinearError>> + 'a
+    where
+        T: 'a,
+    {
+        let after = self.after.clone();
+        crate::pagination::paginate(after, move |after| {
+            Self {
+                client: self.client,
+                filter: self.filter.clone(),
+                before: None,
+                after,
+                first: self.first,
+                last: None,
+                include_archived: self.include_archived,
+                order_by: self.order_by.clone(),
+                sort: self.sort.clone(),
+                extra_variables: self.extra_variables.clone(),
+                _marker: std::marker::PhantomData,
+            }
+            .send()
+        })
+    }
+    /// Fetch every node across all pages. See [`stream`](Self::stream).
+    pub async fn all(self) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(self.stream()).await
+    }
+    /// Fetch nodes across pages until `max` have been collected. Pages past
+    /// the one containing the `max`-th node are never requested.
+    pub async fn take_all(self, max: usize) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(futures::StreamExt::take(self.stream(), max)).await
+    }
+}
+/// Query builder: `ALPHA` All releases in the workspace, with optional filtering and sorting.
+///
+/// Full type: [`Release`](super::types::Release)
+///
+/// Use setter methods to configure optional parameters, then call
+/// [`.send()`](Self::send) to execute the query.
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+#[must_use]
+pub struct ReleasesQueryBuilder<'a, T> {
+    client: &'a Client,
+    filter: Option<ReleaseFilter>,
+    before: Option<String>,
+    after: Option<String>,
+    first: Option<i64>,
+    last: Option<i64>,
+    include_archived: Option<bool>,
+    order_by: Option<PaginationOrderBy>,
+    sort: Option<ReleaseSortInput>,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "releases")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Release>>
+    ReleasesQueryBuilder<'a, T>
+{
+    pub fn filter(mut self, value: ReleaseFilter) -> Self {
+        self.filter = Some(value);
+        self
+    }
+    pub fn before(mut self, value: impl Into<String>) -> Self {
+        self.before = Some(value.into());
+        self
+    }
+    pub fn after(mut self, value: impl Into<String>) -> Self {
+        self.after = Some(value.into());
+        self
+    }
+    pub fn first(mut self, value: i64) -> Self {
+        self.first = Some(value);
+        self
+    }
+    pub fn last(mut self, value: i64) -> Self {
+        self.last = Some(value);
+        self
+    }
+    pub fn include_archived(mut self, value: bool) -> Self {
+        self.include_archived = Some(value);
+        self
+    }
+    pub fn order_by(mut self, value: PaginationOrderBy) -> Self {
+        self.order_by = Some(value);
+        self
+    }
+    pub fn sort(mut self, value: ReleaseSortInput) -> Self {
+        self.sort = Some(value);
+        self
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the query without sending it, e.g. to add it to a
+    /// [`Batch`](crate::batch::Batch).
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        let mut map = self.extra_variables;
+        if let Some(ref v) = self.filter {
+            map.insert("filter".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.before {
+            map.insert("before".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.after {
+            map.insert("after".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.first {
+            map.insert("first".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.last {
+            map.insert("last".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.include_archived {
+            map.insert("includeArchived".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.order_by {
+            map.insert("orderBy".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.sort {
+            map.insert("sort".to_string(), serde_json::json!(v));
+        }
+        let variables = serde_json::Value::Object(map);
+        let selection = format!(
+            "{} {{ nodes {{ {} }} pageInfo {{ hasNextPage endCursor }} }}",
+            "releases(filter: $filter, before: $before, after: $after, first: $first, last: $last, includeArchived: $includeArchived, orderBy: $orderBy, sort: $sort)",
+            T::selection()
+        );
+        Operation::query(
+            "Releases",
+            "$filter: ReleaseFilter, $before: String, $after: String, $first: Int, $last: Int, $includeArchived: Boolean, $orderBy: PaginationOrderBy, $sort: [ReleaseSortInput!]",
+            selection,
+            variables,
+            "releases",
+        )
+    }
+    pub async fn send(self) -> Result<Connection<T>, LinearError> {
+        let client = self.client;
+        self.into_operation().execute(client).await
+    }
+    /// Stream every node across all pages, following `pageInfo.endCursor`.
+    ///
+    /// Starts after the cursor set via `.after()` (or at the beginning) and
+    /// uses `.first()` as the page size. `.before()` / `.last()` are ignored.
+    pub fn stream(self) -> impl futures::Stream<Item = Result<T, LinearError>> + 'a
+    where
+        T: 'a,
+    {
+        let after = self.after.clone();
+        crate::pagination::paginate(after, move |after| {
+            Self {
+                client: self.client,
+                filter: self.filter.clone(),
+                before: None,
+                after,
+                first: self.first,
+                last: None,
+                include_archived: self.include_archived,
+                order_by: self.order_by.clone(),
+                sort: self.sort.clone(),
+                extra_variables: self.extra_variables.clone(),
+                _marker: std::marker::PhantomData,
+            }
+            .send()
+        })
+    }
+    /// Fetch every node across all pages. See [`stream`](Self::stream).
+    pub async fn all(self) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(self.stream()).await
+    }
+    /// Fetch nodes across pages until `max` have been collected. Pages past
+    /// the one containing the `max`-th node are never requested.
+    pub async fn take_all(self, max: usize) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(futures::StreamExt::take(self.stream(), max)).await
+    }
+}
+/// Query builder: `ALPHA` Search releases with optional text matching against name, version, and pipeline name. When no search term is provided, returns releases ordered by stage priority (started > planned > completed > canceled).
+///
+/// Full type: [`Release`](super::types::Release)
+///
+/// Use setter methods to configure optional parameters, then call
+/// [`.send()`](Self::send) to execute the query.
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+#[must_use]
+pub struct ReleaseSearchQueryBuilder<'a, T> {
+    client: &'a Client,
+    filter: Option<ReleaseFilter>,
+    first: Option<i64>,
+    term: Option<String>,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "releases")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Release>>
+    ReleaseSearchQueryBuilder<'a, T>
+{
+    pub fn filter(mut self, value: ReleaseFilter) -> Self {
+        self.filter = Some(value);
+        self
+    }
+    pub fn first(mut self, value: i64) -> Self {
+        self.first = Some(value);
+        self
+    }
+    pub fn term(mut self, value: impl Into<String>) -> Self {
+        self.term = Some(value.into());
+        self
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the query without sending it, e.g. to add it to a
+    /// [`Batch`](crate::batch::Batch).
+    pub fn into_operation(self) -> Operation<Vec<T>> {
+        let mut map = self.extra_variables;
+        if let Some(ref v) = self.filter {
+            map.insert("filter".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.first {
+            map.insert("first".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.term {
+            map.insert("term".to_string(), serde_json::json!(v));
+        }
+        let variables = serde_json::Value::Object(map);
+        let selection = format!(
+            "{} {{ {} }}",
+            "releaseSearch(filter: $filter, first: $first, term: $term)",
+            T::selection()
+        );
+        Operation::query(
+            "ReleaseSearch",
+            "$filter: ReleaseFilter, $first: Int, $term: String",
+            selection,
+            variables,
+            "releaseSearch",
+        )
+    }
+    pub async fn send(self) -> Result<Vec<T>, LinearError> {
+        let client = self.client;
+        self.into_operation().execute(client).await
+    }
+}
+/// Query builder: `ALPHA` Release notes in the workspace.
+///
+/// Full type: [`ReleaseNote`](super::types::ReleaseNote)
+///
+/// Use setter methods to configure optional parameters, then call
+/// [`.send()`](Self::send) to execute the query.
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+#[must_use]
+pub struct ReleaseNotesQueryBuilder<'a, T> {
+    client: &'a Client,
+    before: Option<String>,
+    after: Option<String>,
+    first: Option<i64>,
+    last: Option<i64>,
+    include_archived: Option<bool>,
+    order_by: Option<PaginationOrderBy>,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "releases")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::ReleaseNote>>
+    ReleaseNotesQueryBuilder<'a, T>
+{
+    pub fn before(mut self, value: impl Into<String>) -> Self {
+        self.before = Some(value.into());
+        self
+    }
+    pub fn after(mut self, value: impl Into<String>) -> Self {
+        self.after = Some(value.into());
+        self
+    }
+    pub fn first(mut self, value: i64) -> Self {
+        self.first = Some(value);
+        self
+    }
+    pub fn last(mut self, value: i64) -> Self {
+        self.last = Some(value);
+        self
+    }
+    pub fn include_archived(mut self, value: bool) -> Self {
+        self.include_archived = Some(value);
+        self
+    }
+    pub fn order_by(mut self, value: PaginationOrderBy) -> Self {
+        self.order_by = Some(value);
+        self
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the query without sending it, e.g. to add it to a
+    /// [`Batch`](crate::batch::Batch).
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        let mut map = self.extra_variables;
+        if let Some(ref v) = self.before {
+            map.insert("before".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.after {
+            map.insert("after".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.first {
+            map.insert("first".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.last {
+            map.insert("last".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.include_archived {
+            map.insert("includeArchived".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.order_by {
+            map.insert("orderBy".to_string(), serde_json::json!(v));
+        }
+        let variables = serde_json::Value::Object(map);
+        let selection = format!(
+            "{} {{ nodes {{ {} }} pageInfo {{ hasNextPage endCursor }} }}",
+            "releaseNotes(before: $before, after: $after, first: $first, last: $last, includeArchived: $includeArchived, orderBy: $orderBy)",
+            T::selection()
+        );
+        Operation::query(
+            "ReleaseNotes",
+            "$before: String, $after: String, $first: Int, $last: Int, $includeArchived: Boolean, $orderBy: PaginationOrderBy",
+            selection,
+            variables,
+            "releaseNotes",
+        )
+    }
+    pub async fn send(self) -> Result<Connection<T>, LinearError> {
+        let client = self.client;
+        self.into_operation().execute(client).await
+    }
+    /// Stream every node across all pages, following `pageInfo.endCursor`.
+    ///
+    /// Starts after the cursor set via `.after()` (or at the beginning) and
+    /// uses `.first()` as the page size. `.before()` / `.last()` are ignored.
+    pub fn stream(self) -> impl futures::Stream<Item = Result<T, LinearError>> + 'a
+    where
+        T: 'a,
+    {
+        let after = self.after.clone();
+        crate::pagination::paginate(after, move |after| {
+            Self {
+                client: self.client,
+                before: None,
+                after,
+                first: self.first,
+                last: None,
+                include_archived: self.include_archived,
+                order_by: self.order_by.clone(),
+                extra_variables: self.extra_variables.clone(),
+                _marker: std::marker::PhantomData,
+            }
+            .send()
+        })
+    }
+    /// Fetch every node across all pages. See [`stream`](Self::stream).
+    pub async fn all(self) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(self.stream()).await
+    }
+    /// Fetch nodes across pages until `max` have been collected. Pages past
+    /// the one containing the `max`-th node are never requested.
+    pub async fn take_all(self, max: usize) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(futures::StreamExt::take(self.stream(), max)).await
+    }
+}
+/// Query builder: Sends a test push notification to the authenticated user's registered devices. Useful for verifying that push notification delivery is working correctly.
+///
+/// Full type: [`PushSubscriptionTestPayload`](super::types::PushSubscriptionTestPayload)
+///
+/// Use setter methods to configure optional parameters, then call
+/// [`.send()`](Self::send) to execute the query.
+///
+/// Requires the `notifications` feature.
+#[cfg(feature = "notifications")]
+#[must_use]
+pub struct PushSubscriptionTestQueryBuilder<'a, T> {
+    client: &'a Client,
+    target_mobile: Option<bool>,
+    send_strategy: Option<SendStrategy>,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "notifications")]
+impl<
+        'a,
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::PushSubscriptionTestPayload>,
+    > PushSubscriptionTestQueryBuilder<'a, T>
+{
+    pub fn target_mobile(mut self, value: bool) -> Self {
+        self.target_mobile = Some(value);
+        self
+    }
+    pub fn send_strategy(mut self, value: SendStrategy) -> Self {
+        self.send_strategy = Some(value);
+        self
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the query without sending it, e.g. to add it to a
+    /// [`Batch`](crate::batch::Batch).
+    pub fn into_operation(self) -> Operation<T> {
+        let mut map = self.extra_variables;
+        if let Some(ref v) = self.target_mobile {
+            map.insert("targetMobile".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.send_strategy {
+            map.insert("sendStrategy".to_string(), serde_json::json!(v));
+        }
+        let variables = serde_json::Value::Object(map);
+        let selection = format!(
+            "{} {{ {} }}",
+            "pushSubscriptionTest(targetMobile: $targetMobile, sendStrategy: $sendStrategy)",
+            T::selection()
+        );
+        Operation::query(
+            "PushSubscriptionTest",
+            "$targetMobile: Boolean, $sendStrategy: SendStrategy",
+            selection,
+            variables,
+            "pushSubscriptionTest",
+        )
+    }
+    pub async fn send(self) -> Result<T, LinearError> {
+        let client = self.client;
+        self.into_operation().execute(client).await
+    }
+}
+/// Query builder: Returns all project status updates in the workspace, with optional filtering.
+///
+/// Full type: [`ProjectUpdate`](super::types::ProjectUpdate)
+///
+/// Use setter methods to configure optional parameters, then call
+/// [`.send()`](Self::send) to execute the query.
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+#[must_use]
+pub struct ProjectUpdatesQueryBuilder<'a, T> {
+    client: &'a Client,
+    filter: Option<ProjectUpdateFilter>,
+    before: Option<String>,
+    after: Option<String>,
+    first: Option<i64>,
+    last: Option<i64>,
+    include_archived: Option<bool>,
+    order_by: Option<PaginationOrderBy>,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "projects")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::ProjectUpdate>>
+    ProjectUpdatesQueryBuilder<'a, T>
+{
+    pub fn filter(mut self, value: ProjectUpdateFilter) -> Self {
+        self.filter = Some(value);
+        self
+    }
+    pub fn before(mut self, value: impl Into<String>) -> Self {
+        self.before = Some(value.into());
+        self
+    }
+    pub fn after(mut self, value: impl Into<String>) -> Self {
+        self.after = Some(value.into());
+        self
+    }
+    pub fn first(mut self, value: i64) -> Self {
+        self.first = Some(value);
+        self
+    }
+    pub fn last(mut self, value: i64) -> Self {
+        self.last = Some(value);
+        self
+    }
+    pub fn include_archived(mut self, value: bool) -> Self {
+        self.include_archived = Some(value);
+        self
+    }
+    pub fn order_by(mut self, value: PaginationOrderBy) -> Self {
+        self.order_by = Some(value);
+        self
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the query without sending it, e.g. to add it to a
+    /// [`Batch`](crate::batch::Batch).
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        let mut map = self.extra_variables;
+        if let Some(ref v) = self.filter {
+            map.insert("filter".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.before {
+            map.insert("before".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.after {
+            map.insert("after".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.first {
+            map.insert("first".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.last {
+            map.insert("last".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.include_archived {
+            map.insert("includeArchived".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.order_by {
+            map.insert("orderBy".to_string(), serde_json::json!(v));
+        }
+        let variables = serde_json::Value::Object(map);
+        let selection = format!(
+            "{} {{ nodes {{ {} }} pageInfo {{ hasNextPage endCursor }} }}",
+            "projectUpdates(filter: $filter, before: $before, after: $after, first: $first, last: $last, includeArchived: $includeArchived, orderBy: $orderBy)",
+            T::selection()
+        );
+        Operation::query(
+            "ProjectUpdates",
+            "$filter: ProjectUpdateFilter, $before: String, $after: String, $first: Int, $last: Int, $includeArchived: Boolean, $orderBy: PaginationOrderBy",
+            selection,
+            variables,
+            "projectUpdates",
+        )
+    }
+    pub async fn send(self) -> Result<Connection<T>, LinearError> {
+        let client = self.client;
+        self.into_operation().execute(client).await
+    }
+    /// Stream every node across all pages, following `pageInfo.endCursor`.
+    ///
+    /// Starts after the cursor set via `.after()` (or at the beginning) and
+    /// uses `.first()` as the page size. `.before()` / `.last()` are ignored.
+    pub fn stream(self) -> impl futures::Stream<Item = Result<T, LinearError>> + 'a
+    where
+        T: 'a,
+    {
+        let after = self.after.clone();
+        crate::pagination::paginate(after, move |after| {
+            Self {
+                client: self.client,
+                filter: self.filter.clone(),
+                before: None,
+                after,
+                first: self.first,
+                last: None,
+                include_archived: self.include_archived,
+                order_by: self.order_by.clone(),
+                extra_variables: self.extra_variables.clone(),
+                _marker: std::marker::PhantomData,
+            }
+            .send()
+        })
+    }
+    /// Fetch every node across all pages. See [`stream`](Self::stream).
+    pub async fn all(self) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(self.stream()).await
+    }
+    /// Fetch nodes across pages until `max` have been collected. Pages past
+    /// the one containing the `max`-th node are never requested.
+    pub async fn take_all(self, max: usize) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(futures::StreamExt::take(self.stream(), max)).await
+    }
+}
+/// Query builder: Returns all project statuses in the workspace.
+///
+/// Full type: [`ProjectStatus`](super::types::ProjectStatus)
+///
+/// Use setter methods to configure optional parameters, then call
+/// [`.send()`](Self::send) to execute the query.
+#[must_use]
+pub struct ProjectStatusesQueryBuilder<'a, T> {
+    client: &'a Client,
+    before: Option<String>,
+    after: Option<String>,
+    first: Option<i64>,
+    last: Option<i64>,
+    include_archived: Option<bool>,
+    order_by: Option<PaginationOrderBy>,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::ProjectStatus>>
+    ProjectStatusesQueryBuilder<'a, T>
+{
+    pub fn before(mut self, value: impl Into<String>) -> Self {
+        self.before = Some(value.into());
+        self
+    }
+    pub fn after(mut self, value: impl Into<String>) -> Self {
+        self.after = Some(value.into());
+        self
+    }
+    pub fn first(mut self, value: i64) -> Self {
+        self.first = Some(value);
+        self
+    }
+    pub fn last(mut self, value: i64) -> Self {
+        self.last = Some(value);
+        self
+    }
+    pub fn include_archived(mut self, value: bool) -> Self {
+        self.include_archived = Some(value);
+        self
+    }
+    pub fn order_by(mut self, value: PaginationOrderBy) -> Self {
+        self.order_by = Some(value);
+        self
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the query without sending it, e.g. to add it to a
+    /// [`Batch`](crate::batch::Batch).
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        let mut map = self.extra_variables;
+        if let Some(ref v) = self.before {
+            map.insert("before".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.after {
+            map.insert("after".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.first {
+            map.insert("first".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.last {
+            map.insert("last".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.include_archived {
+            map.insert("includeArchived".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.order_by {
+            map.insert("orderBy".to_string(), serde_json::json!(v));
+        }
+        let variables = serde_json::Value::Object(map);
+        let selection = format!(
+            "{} {{ nodes {{ {} }} pageInfo {{ hasNextPage endCursor }} }}",
+            "projectStatuses(before: $before, after: $after, first: $first, last: $last, includeArchived: $includeArchived, orderBy: $orderBy)",
+            T::selection()
+        );
+        Operation::query(
+            "ProjectStatuses",
+            "$before: String, $after: String, $first: Int, $last: Int, $includeArchived: Boolean, $orderBy: PaginationOrderBy",
+            selection,
+            variables,
+            "projectStatuses",
+        )
+    }
+    pub async fn send(self) -> Result<Connection<T>, LinearError> {
+        let client = self.client;
+        self.into_operation().execute(client).await
+    }
+    /// Stream every node across all pages, following `pageInfo.endCursor`.
+    ///
+    /// Starts after the cursor set via `.after()` (or at the beginning) and
+    /// uses `.first()` as the page size. `.before()` / `.last()` are ignored.
+    pub fn stream(self) -> impl futures::Stream<Item = Result<T, LinearError>> + 'a
+    where
+        T: 'a,
+    {
+        let after = self.after.clone();
+        crate::pagination::paginate(after, move |after| {
+            Self {
+                client: self.client,
+                before: None,
+                after,
+                first: self.first,
+                last: None,
+                include_archived: self.include_archived,
+                order_by: self.order_by.clone(),
+                extra_variables: self.extra_variables.clone(),
+                _marker: std::marker::PhantomData,
+            }
+            .send()
+        })
+    }
+    /// Fetch every node across all pages. See [`stream`](Self::stream).
+    pub async fn all(self) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(self.stream()).await
+    }
+    /// Fetch nodes across pages until `max` have been collected. Pages past
+    /// the one containing the `max`-th node are never requested.
+    pub async fn take_all(self, max: usize) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(futures::StreamExt::take(self.stream(), max)).await
+    }
+}
+/// Query builder: Returns all project dependency relations in the workspace.
+///
+/// Full type: [`ProjectRelation`](super::types::ProjectRelation)
+///
+/// Use setter methods to configure optional parameters, then call
+/// [`.send()`](Self::send) to execute the query.
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+#[must_use]
+pub struct ProjectRelationsQueryBuilder<'a, T> {
+    client: &'a Client,
+    before: Option<String>,
+    after: Option<String>,
+    first: Option<i64>,
+    last: Option<i64>,
+    include_archived: Option<bool>,
+    order_by: Option<PaginationOrderBy>,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "projects")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::ProjectRelation>>
+    ProjectRelationsQueryBuilder<'a, T>
+{
+    pub fn before(mut self, value: impl Into<String>) -> Self {
+        self.before = Some(value.into());
+        self
+    }
+    pub fn after(mut self, value: impl Into<String>) -> Self {
+        self.after = Some(value.into());
+        self
+    }
+    pub fn first(mut self, value: i64) -> Self {
+        self.first = Some(value);
+        self
+    }
+    pub fn last(mut self, value: i64) -> Self {
+        self.last = Some(value);
+        self
+    }
+    pub fn include_archived(mut self, value: bool) -> Self {
+        self.include_archived = Some(value);
+        self
+    }
+    pub fn order_by(mut self, value: PaginationOrderBy) -> Self {
+        self.order_by = Some(value);
+        self
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the query without sending it, e.g. to add it to a
+    /// [`Batch`](crate::batch::Batch).
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        let mut map = self.extra_variables;
+        if let Some(ref v) = self.before {
+            map.insert("before".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.after {
+            map.insert("after".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.first {
+            map.insert("first".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.last {
+            map.insert("last".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.include_archived {
+            map.insert("includeArchived".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.order_by {
+            map.insert("orderBy".to_string(), serde_json::json!(v));
+        }
+        let variables = serde_json::Value::Object(map);
+        let selection = format!(
+            "{} {{ nodes {{ {} }} pageInfo {{ hasNextPage endCursor }} }}",
+            "projectRelations(before: $before, after: $after, first: $first, last: $last, includeArchived: $includeArchived, orderBy: $orderBy)",
+            T::selection()
+        );
+        Operation::query(
+            "ProjectRelations",
+            "$before: String, $after: String, $first: Int, $last: Int, $includeArchived: Boolean, $orderBy: PaginationOrderBy",
+            selection,
+            variables,
+            "projectRelations",
+        )
+    }
+    pub async fn send(self) -> Result<Connection<T>, LinearError> {
+        let client = self.client;
+        self.into_operation().execute(client).await
+    }
+    /// Stream every node across all pages, following `pageInfo.endCursor`.
+    ///
+    /// Starts after the cursor set via `.after()` (or at the beginning) and
+    /// uses `.first()` as the page size. `.before()` / `.last()` are ignored.
+    pub fn stream(self) -> impl futures::Stream<Item = Result<T, LinearError>> + 'a
+    where
+        T: 'a,
+    {
+        let after = self.after.clone();
+        crate::pagination::paginate(after, move |after| {
+            Self {
+                client: self.client,
+                before: None,
+                after,
+                first: self.first,
+                last: None,
+                include_archived: self.include_archived,
+                order_by: self.order_by.clone(),
+                extra_variables: self.extra_variables.clone(),
+                _marker: std::marker::PhantomData,
+            }
+            .send()
+        })
+    }
+    /// Fetch every node across all pages. See [`stream`](Self::stream).
+    pub async fn all(self) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(self.stream()).await
+    }
+    /// Fetch nodes across pages until `max` have been collected. Pages past
+    /// the one containing the `max`-th node are never requested.
+    pub async fn take_all(self, max: usize) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(futures::StreamExt::take(self.stream(), max)).await
+    }
+}
+/// Query builder: Returns all project milestones in the workspace, with optional filtering.
+///
+/// Full type: [`ProjectMilestone`](super::types::ProjectMilestone)
+///
+/// Use setter methods to configure optional parameters, then call
+/// [`.send()`](Self::send) to execute the query.
+#[must_use]
+pub struct ProjectMilestonesQueryBuilder<'a, T> {
+    client: &'a Client,
+    filter: Option<ProjectMilestoneFilter>,
+    before: Option<String>,
+    after: Option<String>,
+    first: Option<i64>,
+    last: Option<i64>,
+    include_archived: Option<bool>,
+    order_by: Option<PaginationOrderBy>,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::ProjectMilestone>>
+    ProjectMilestonesQueryBuilder<'a, T>
+{
+    pub fn filter(mut self, value: ProjectMilestoneFilter) -> Self {
+        self.filter = Some(value);
+        self
+    }
+    pub fn before(mut self, value: impl Into<String>) -> Self {
+        self.before = Some(value.into());
+        self
+    }
+    pub fn after(mut self, value: impl Into<String>) -> Self {
+        self.after = Some(value.into());
+        self
+    }
+    pub fn first(mut self, value: i64) -> Self {
+        self.first = Some(value);
+        self
+    }
+    pub fn last(mut self, value: i64) -> Self {
+        self.last = Some(value);
+        self
+    }
+    pub fn include_archived(mut self, value: bool) -> Self {
+        self.include_archived = Some(value);
+        self
+    }
+    pub fn order_by(mut self, value: PaginationOrderBy) -> Self {
+        self.order_by = Some(value);
+        self
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the query without sending it, e.g. to add it to a
+    /// [`Batch`](crate::batch::Batch).
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        let mut map = self.extra_variables;
+        if let Some(ref v) = self.filter {
+            map.insert("filter".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.before {
+            map.insert("before".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.after {
+            map.insert("after".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.first {
+            map.insert("first".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.last {
+            map.insert("last".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.include_archived {
+            map.insert("includeArchived".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.order_by {
+            map.insert("orderBy".to_string(), serde_json::json!(v));
+        }
+        let variables = serde_json::Value::Object(map);
+        let selection = format!(
+            "{} {{ nodes {{ {} }} pageInfo {{ hasNextPage endCursor }} }}",
+            "projectMilestones(filter: $filter, before: $before, after: $after, first: $first, last: $last, includeArchived: $includeArchived, orderBy: $orderBy)",
+            T::selection()
+        );
+        Operation::query(
+            "ProjectMilestones",
+            "$filter: ProjectMilestoneFilter, $before: String, $after: String, $first: Int, $last: Int, $includeArchived: Boolean, $orderBy: PaginationOrderBy",
+            selection,
+            variables,
+            "projectMilestones",
+        )
+    }
+    pub async fn send(self) -> Result<Connection<T>, LinearError> {
+        let client = self.client;
+        self.into_operation().execute(client).await
+    }
+    /// Stream every node across all pages, following `pageInfo.endCursor`.
+    ///
+    /// Starts after the cursor set via `.after()` (or at the beginning) and
+    /// uses `.first()` as the page size. `.before()` / `.last()` are ignored.
+    pub fn stream(self) -> impl futures::Stream<Item = Result<T, LinearError>> + 'a
+    where
+        T: 'a,
+    {
+        let after = self.after.clone();
+        crate::pagination::paginate(after, move |after| {
+            Self {
+                client: self.client,
+                filter: self.filter.clone(),
+                before: None,
+                after,
+                first: self.first,
+                last: None,
+                include_archived: self.include_archived,
+                order_by: self.order_by.clone(),
+                extra_variables: self.extra_variables.clone(),
+                _marker: std::marker::PhantomData,
+            }
+            .send()
+        })
+    }
+    /// Fetch every node across all pages. See [`stream`](Self::stream).
+    pub async fn all(self) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(self.stream()).await
+    }
+    /// Fetch nodes across pages until `max` have been collected. Pages past
+    /// the one containing the `max`-th node are never requested.
+    pub async fn take_all(self, max: usize) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(futures::StreamExt::take(self.stream(), max)).await
+    }
+}
+/// Query builder: Returns all project labels in the workspace, with optional filtering.
+///
+/// Full type: [`ProjectLabel`](super::types::ProjectLabel)
+///
+/// Use setter methods to configure optional parameters, then call
+/// [`.send()`](Self::send) to execute the query.
+#[must_use]
+pub struct ProjectLabelsQueryBuilder<'a, T> {
+    client: &'a Client,
+    filter: Option<ProjectLabelFilter>,
+    before: Option<String>,
+    after: Option<String>,
+    first: Option<i64>,
+    last: Option<i64>,
+    include_archived: Option<bool>,
+    order_by: Option<PaginationOrderBy>,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::ProjectLabel>>
+    ProjectLabelsQueryBuilder<'a, T>
+{
+    pub fn filter(mut self, value: ProjectLabelFilter) -> Self {
+        self.filter = Some(value);
+        self
+    }
+    pub fn before(mut self, value: impl Into<String>) -> Self {
+        self.before = Some(value.into());
+        self
+    }
+    pub fn after(mut self, value: impl Into<String>) -> Self {
+        self.after = Some(value.into());
+        self
+    }
+    pub fn first(mut self, value: i64) -> Self {
+        self.first = Some(value);
+        self
+    }
+    pub fn last(mut self, value: i64) -> Self {
+        self.last = Some(value);
+        self
+    }
+    pub fn include_archived(mut self, value: bool) -> Self {
+        self.include_archived = Some(value);
+        self
+    }
+    pub fn order_by(mut self, value: PaginationOrderBy) -> Self {
+        self.order_by = Some(value);
+        self
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the query without sending it, e.g. to add it to a
+    /// [`Batch`](crate::batch::Batch).
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        let mut map = self.extra_variables;
+        if let Some(ref v) = self.filter {
+            map.insert("filter".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.before {
+            map.insert("before".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.after {
+            map.insert("after".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.first {
+            map.insert("first".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.last {
+            map.insert("last".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.include_archived {
+            map.insert("includeArchived".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.order_by {
+            map.insert("orderBy".to_string(), serde_json::json!(v));
+        }
+        let variables = serde_json::Value::Object(map);
+        let selection = format!(
+            "{} {{ nodes {{ {} }} pageInfo {{ hasNextPage endCursor }} }}",
+            "projectLabels(filter: $filter, before: $before, after: $after, first: $first, last: $last, includeArchived: $includeArchived, orderBy: $orderBy)",
+            T::selection()
+        );
+        Operation::query(
+            "ProjectLabels",
+            "$filter: ProjectLabelFilter, $before: String, $after: String, $first: Int, $last: Int, $includeArchived: Boolean, $orderBy: PaginationOrderBy",
+            selection,
+            variables,
+            "projectLabels",
+        )
+    }
+    pub async fn send(self) -> Result<Connection<T>, LinearError> {
+        let client = self.client;
+        self.into_operation().execute(client).await
+    }
+    /// Stream every node across all pages, following `pageInfo.endCursor`.
+    ///
+    /// Starts after the cursor set via `.after()` (or at the beginning) and
+    /// uses `.first()` as the page size. `.before()` / `.last()` are ignored.
+    pub fn stream(self) -> impl futures::Stream<Item = Result<T, LinearError>> + 'a
+    where
+        T: 'a,
+    {
+        let after = self.after.clone();
+        crate::pagination::paginate(after, move |after| {
+            Self {
+                client: self.client,
+                filter: self.filter.clone(),
+                before: None,
+                after,
+                first: self.first,
+                last: None,
+                include_archived: self.include_archived,
+                order_by: self.order_by.clone(),
+                extra_variables: self.extra_variables.clone(),
+                _marker: std::marker::PhantomData,
+            }
+            .send()
+        })
+    }
+    /// Fetch every node across all pages. See [`stream`](Self::stream).
+    pub async fn all(self) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(self.stream()).await
+    }
+    /// Fetch nodes across pages until `max` have been collected. Pages past
+    /// the one containing the `max`-th node are never requested.
+    pub async fn take_all(self, max: usize) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(futures::StreamExt::take(self.stream(), max)).await
+    }
+}
+/// Query builder: All pending and accepted invites for the workspace.
+///
+/// Full type: [`OrganizationInvite`](super::types::OrganizationInvite)
+///
+/// Use setter methods to configure optional parameters, then call
+/// [`.send()`](Self::send) to execute the query.
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+#[must_use]
+pub struct OrganizationInvitesQueryBuilder<'a, T> {
+    client: &'a Client,
+    before: Option<String>,
+    after: Option<String>,
+    first: Option<i64>,
+    last: Option<i64>,
+    include_archived: Option<bool>,
+    order_by: Option<PaginationOrderBy>,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "admin")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::OrganizationInvite>>
+    OrganizationInvitesQueryBuilder<'a, T>
+{
+    pub fn before(mut self, value: impl Into<String>) -> Self {
+        self.before = Some(value.into());
+        self
+    }
+    pub fn after(mut self, value: impl Into<String>) -> Self {
+        self.after = Some(value.into());
+        self
+    }
+    pub fn first(mut self, value: i64) -> Self {
+        self.first = Some(value);
+        self
+    }
+    pub fn last(mut self, value: i64) -> Self {
+        self.last = Some(value);
+        self
+    }
+    pub fn include_archived(mut self, value: bool) -> Self {
+        self.include_archived = Some(value);
+        self
+    }
+    pub fn order_by(mut self, value: PaginationOrderBy) -> Self {
+        self.order_by = Some(value);
+        self
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the query without sending it, e.g. to add it to a
+    /// [`Batch`](crate::batch::Batch).
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        let mut map = self.extra_variables;
+        if let Some(ref v) = self.before {
+            map.insert("before".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.after {
+            map.insert("after".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.first {
+            map.insert("first".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.last {
+            map.insert("last".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.include_archived {
+            map.insert("includeArchived".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.order_by {
+            map.insert("orderBy".to_string(), serde_json::json!(v));
+        }
+        let variables = serde_json::Value::Object(map);
+        let selection = format!(
+            "{} {{ nodes {{ {} }} pageInfo {{ hasNextPage endCursor }} }}",
+            "organizationInvites(before: $before, after: $after, first: $first, last: $last, includeArchived: $includeArchived, orderBy: $orderBy)",
+            T::selection()
+        );
+        Operation::query(
+            "OrganizationInvites",
+            "$before: String, $after: String, $first: Int, $last: Int, $includeArchived: Boolean, $orderBy: PaginationOrderBy",
+            selection,
+            variables,
+            "organizationInvites",
+        )
+    }
+    pub async fn send(self) -> Result<Connection<T>, LinearError> {
+        let client = self.client;
+        self.into_operation().execute(client).await
+    }
+    /// Stream every node across all pages, following `pageInfo.endCursor`.
+    ///
+    /// Starts after the cursor set via `.after()` (or at the beginning) and
+    /// uses `.first()` as the page size. `.before()` / `.last()` are ignored.
+    pub fn stream(self) -> impl futures::Stream<Item = Result<T, LinearError>> + 'a
+    where
+        T: 'a,
+    {
+        let after = self.after.clone();
+        crate::pagination::paginate(after, move |after| {
+            Self {
+                client: self.client,
+                before: None,
+                after,
+                first: self.first,
+                last: None,
+                include_archived: self.include_archived,
+                order_by: self.order_by.clone(),
+                extra_variables: self.extra_variables.clone(),
+                _marker: std::marker::PhantomData,
+            }
+            .send()
+        })
+    }
+    /// Fetch every node across all pages. See [`stream`](Self::stream).
+    pub async fn all(self) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(self.stream()).await
+    }
+    /// Fetch nodes across pages until `max` have been collected. Pages past
+    /// the one containing the `max`-th node are never requested.
+    pub async fn take_all(self, max: usize) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(futures::StreamExt::take(self.stream(), max)).await
+    }
+}
+/// Query builder: The authenticated user's notification subscriptions. These subscriptions control which notifications the user receives for specific entities such as teams, projects, cycles, labels, custom views, initiatives, customers, and users.
+///
+/// Full type: [`NotificationSubscription`](super::types::NotificationSubscription)
+///
+/// Use setter methods to configure optional parameters, then call
+/// [`.send()`](Self::send) to execute the query.
+///
+/// Requires the `notifications` feature.
+#[cfg(feature = "notifications")]
+#[must_use]
+pub struct NotificationSubscriptionsQueryBuilder<'a, T> {
+    client: &'a Client,
+    before: Option<String>,
+    after: Option<String>,
+    first: Option<i64>,
+    last: Option<i64>,
+    include_archived: Option<bool>,
+    order_by: Option<PaginationOrderBy>,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "notifications")]
+impl<
+        'a,
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::NotificationSubscription>,
+    > NotificationSubscriptionsQueryBuilder<'a, T>
+{
+    pub fn before(mut self, value: impl Into<String>) -> Self {
+        self.before = Some(value.into());
+        self
+    }
+    pub fn after(mut self, value: impl Into<String>) -> Self {
+        self.after = Some(value.into());
+        self
+    }
+    pub fn first(mut self, value: i64) -> Self {
+        self.first = Some(value);
+        self
+    }
+    pub fn last(mut self, value: i64) -> Self {
+        self.last = Some(value);
+        self
+    }
+    pub fn include_archived(mut self, value: bool) -> Self {
+        self.include_archived = Some(value);
+        self
+    }
+    pub fn order_by(mut self, value: PaginationOrderBy) -> Self {
+        self.order_by = Some(value);
+        self
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the query without sending it, e.g. to add it to a
+    /// [`Batch`](crate::batch::Batch).
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        let mut map = self.extra_variables;
+        if let Some(ref v) = self.before {
+            map.insert("before".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.after {
+            map.insert("after".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.first {
+            map.insert("first".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.last {
+            map.insert("last".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.include_archived {
+            map.insert("includeArchived".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.order_by {
+            map.insert("orderBy".to_string(), serde_json::json!(v));
+        }
+        let variables = serde_json::Value::Object(map);
+        let selection = format!(
+            "{} {{ nodes {{ {} }} pageInfo {{ hasNextPage endCursor }} }}",
+            "notificationSubscriptions(before: $before, after: $after, first: $first, last: $last, includeArchived: $includeArchived, orderBy: $orderBy)",
+            T::selection()
+        );
+        Operation::query(
+            "NotificationSubscriptions",
+            "$before: String, $after: String, $first: Int, $last: Int, $includeArchived: Boolean, $orderBy: PaginationOrderBy",
+            selection,
+            variables,
+            "notificationSubscriptions",
+        )
+    }
+    pub async fn send(self) -> Result<Connection<T>, LinearError> {
+        let client = self.client;
+        self.into_operation().execute(client).await
+    }
+    /// Stream every node across all pages, following `pageInfo.endCursor`.
+    ///
+    /// Starts after the cursor set via `.after()` (or at the beginning) and
+    /// uses `.first()` as the page size. `.before()` / `.last()` are ignored.
+    pub fn stream(self) -> impl futures::Stream<Item = Result<T, LinearError>> + 'a
+    where
+        T: 'a,
+    {
+        let after = self.after.clone();
+        crate::pagination::paginate(after, move |after| {
+            Self {
+                client: self.client,
+                before: None,
+                after,
+                first: self.first,
+                last: None,
+                include_archived: self.include_archived,
+                order_by: self.order_by.clone(),
+                extra_variables: self.extra_variables.clone(),
+                _marker: std::marker::PhantomData,
+            }
+            .send()
+        })
+    }
+    /// Fetch every node across all pages. See [`stream`](Self::stream).
+    pub async fn all(self) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(self.stream()).await
+    }
+    /// Fetch nodes across pages until `max` have been collected. Pages past
+    /// the one containing the `max`-th node are never requested.
+    pub async fn take_all(self, max: usize) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(futures::StreamExt::take(self.stream(), max)).await
+    }
+}
+/// Query builder: The authenticated user's notifications.
+///
+/// Full type: [`Notification`](super::types::Notification)
+///
+/// Use setter methods to configure optional parameters, then call
+/// [`.send()`](Self::send) to execute the query.
+#[must_use]
+pub struct NotificationsQueryBuilder<'a, T> {
+    client: &'a Client,
+    filter: Option<NotificationFilter>,
+    before: Option<String>,
+    after: Option<String>,
+    first: Option<i64>,
+    last: Option<i64>,
+    include_archived: Option<bool>,
+    order_by: Option<PaginationOrderBy>,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Notification>>
+    NotificationsQueryBuilder<'a, T>
+{
+    pub fn filter(mut self, value: NotificationFilter) -> Self {
+        self.filter = Some(value);
+        self
+    }
+    pub fn before(mut self, value: impl Into<String>) -> Self {
+        self.before = Some(value.into());
+        self
+    }
+    pub fn after(mut self, value: impl Into<String>) -> Self {
+        self.after = Some(value.into());
+        self
+    }
+    pub fn first(mut self, value: i64) -> Self {
+        self.first = Some(value);
+        self
+    }
+    pub fn last(mut self, value: i64) -> Self {
+        self.last = Some(value);
+        self
+    }
+    pub fn include_archived(mut self, value: bool) -> Self {
+        self.include_archived = Some(value);
+        self
+    }
+    pub fn order_by(mut self, value: PaginationOrderBy) -> Self {
+        self.order_by = Some(value);
+        self
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the query without sending it, e.g. to add it to a
+    /// [`Batch`](crate::batch::Batch).
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        let mut map = self.extra_variables;
+        if let Some(ref v) = self.filter {
+            map.insert("filter".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.before {
+            map.insert("before".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.after {
+            map.insert("after".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.first {
+            map.insert("first".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.last {
+            map.insert("last".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.include_archived {
+            map.insert("includeArchived".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.order_by {
+            map.insert("orderBy".to_string(), serde_json::json!(v));
+        }
+        let variables = serde_json::Value::Object(map);
+        let selection = format!(
+            "{} {{ nodes {{ {} }} pageInfo {{ hasNextPage endCursor }} }}",
+            "notifications(filter: $filter, before: $before, after: $after, first: $first, last: $last, includeArchived: $includeArchived, orderBy: $orderBy)",
+            T::selection()
+        );
+        Operation::query(
+            "Notifications",
+            "$filter: NotificationFilter, $before: String, $after: String, $first: Int, $last: Int, $includeArchived: Boolean, $orderBy: PaginationOrderBy",
+            selection,
+            variables,
+            "notifications",
+        )
+    }
+    pub async fn send(self) -> Result<Connection<T>, LinearError> {
+        let client = self.client;
+        self.into_operation().execute(client).await
+    }
+    /// Stream every node across all pages, following `pageInfo.endCursor`.
+    ///
+    /// Starts after the cursor set via `.after()` (or at the beginning) and
+    /// uses `.first()` as the page size. `.before()` / `.last()` are ignored.
+    pub fn stream(self) -> impl futures::Stream<Item = Result<T, LinearError>> + 'a
+    where
+        T: 'a,
+    {
+        let after = self.after.clone();
+        crate::pagination::paginate(after, move |after| {
+            Self {
+                client: self.client,
+                filter: self.filter.clone(),
+                before: None,
+                after,
+                first: self.first,
+                last: None,
+                include_archived: self.include_archived,
+                order_by: self.order_by.clone(),
+                extra_variables: self.extra_variables.clone(),
+                _marker: std::marker::PhantomData,
+            }
+            .send()
+        })
+    }
+    /// Fetch every node across all pages. See [`stream`](Self::stream).
+    pub async fn all(self) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(self.stream()).await
+    }
+    /// Fetch nodes across pages until `max` have been collected. Pages past
+    /// the one containing the `max`-th node are never requested.
+    pub async fn take_all(self, max: usize) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(futures::StreamExt::take(self.stream(), max)).await
+    }
+}
+/// Query builder: `ALPHA` All issue-to-release associations. Returns a paginated list of all issue-to-release links visible to the authenticated user.
+///
+/// Full type: [`IssueToRelease`](super::types::IssueToRelease)
+///
+/// Use setter methods to configure optional parameters, then call
+/// [`.send()`](Self::send) to execute the query.
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+#[must_use]
+pub struct IssueToReleasesQueryBuilder<'a, T> {
+    client: &'a Client,
+    before: Option<String>,
+    after: Option<String>,
+    first: Option<i64>,
+    last: Option<i64>,
+    include_archived: Option<bool>,
+    order_by: Option<PaginationOrderBy>,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "releases")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::IssueToRelease>>
+    IssueToReleasesQueryBuilder<'a, T>
+{
+    pub fn before(mut self, value: impl Into<String>) -> Self {
+        self.before = Some(value.into());
+        self
+    }
+    pub fn after(mut self, value: impl Into<String>) -> Self {
+        self.after = Some(value.into());
+        self
+    }
+    pub fn first(mut self, value: i64) -> Self {
+        self.first = Some(value);
+        self
+    }
+    pub fn last(mut self, value: i64) -> Self {
+        self.last = Some(value);
+        self
+    }
+    pub fn include_archived(mut self, value: bool) -> Self {
+        self.include_archived = Some(value);
+        self
+    }
+    pub fn order_by(mut self, value: PaginationOrderBy) -> Self {
+        self.order_by = Some(value);
+        self
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the query without sending it, e.g. to add it to a
+    /// [`Batch`](crate::batch::Batch).
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        let mut map = self.extra_variables;
+        if let Some(ref v) = self.before {
+            map.insert("before".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.after {
+            map.insert("after".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.first {
+            map.insert("first".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.last {
+            map.insert("last".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.include_archived {
+            map.insert("includeArchived".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.order_by {
+            map.insert("orderBy".to_string(), serde_json::json!(v));
+        }
+        let variables = serde_json::Value::Object(map);
+        let selection = format!(
+            "{} {{ nodes {{ {} }} pageInfo {{ hasNextPage endCursor }} }}",
+            "issueToReleases(before: $before, after: $after, first: $first, last: $last, includeArchived: $includeArchived, orderBy: $orderBy)",
+            T::selection()
+        );
+        Operation::query(
+            "IssueToReleases",
+            "$before: String, $after: String, $first: Int, $last: Int, $includeArchived: Boolean, $orderBy: PaginationOrderBy",
+            selection,
+            variables,
+            "issueToReleases",
+        )
+    }
+    pub async fn send(self) -> Result<Connection<T>, LinearError> {
+        let client = self.client;
+        self.into_operation().execute(client).await
+    }
+    /// Stream every node across all pages, following `pageInfo.endCursor`.
+    ///
+    /// Starts after the cursor set via `.after()` (or at the beginning) and
+    /// uses `.first()` as the page size. `.before()` / `.last()` are ignored.
+    pub fn stream(self) -> impl futures::Stream<Item = Result<T, LinearError>> + 'a
+    where
+        T: 'a,
+    {
+        let after = self.after.clone();
+        crate::pagination::paginate(after, move |after| {
+            Self {
+                client: self.client,
+                before: None,
+                after,
+                first: self.first,
+                last: None,
+                include_archived: self.include_archived,
+                order_by: self.order_by.clone(),
+                extra_variables: self.extra_variables.clone(),
+                _marker: std::marker::PhantomData,
+            }
+            .send()
+        })
+    }
+    /// Fetch every node across all pages. See [`stream`](Self::stream).
+    pub async fn all(self) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(self.stream()).await
+    }
+    /// Fetch nodes across pages until `max` have been collected. Pages past
+    /// the one containing the `max`-th node are never requested.
+    pub async fn take_all(self, max: usize) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(futures::StreamExt::take(self.stream(), max)).await
+    }
+}
+/// Query builder: All issues. Returns a paginated list of issues visible to the authenticated user. Can be filtered by various criteria including team, assignee, state, labels, project, and cycle.
+///
+/// Full type: [`Issue`](super::types::Issue)
+///
+/// Use setter methods to configure optional parameters, then call
+/// [`.send()`](Self::send) to execute the query.
+#[must_use]
+pub struct IssuesQueryBuilder<'a, T> {
+    client: &'a Client,
+    filter: Option<IssueFilter>,
+    before: Option<String>,
+    after: Option<String>,
+    first: Option<i64>,
+    last: Option<i64>,
+    include_archived: Option<bool>,
+    order_by: Option<PaginationOrderBy>,
+    sort: Option<IssueSortInput>,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Issue>>
+    IssuesQueryBuilder<'a, T>
+{
+    pub fn filter(mut self, value: IssueFilter) -> Self {
+        self.filter = Some(value);
+        self
+    }
+    pub fn before(mut self, value: impl Into<String>) -> Self {
+        self.before = Some(value.into());
+        self
+    }
+    pub fn after(mut self, value: impl Into<String>) -> Self {
+        self.after = Some(value.into());
+        self
+    }
+    pub fn first(mut self, value: i64) -> Self {
+        self.first = Some(value);
+        self
+    }
+    pub fn last(mut self, value: i64) -> Self {
+        self.last = Some(value);
+        self
+    }
+    pub fn include_archived(mut self, value: bool) -> Self {
+        self.include_archived = Some(value);
+        self
+    }
+    pub fn order_by(mut self, value: PaginationOrderBy) -> Self {
+        self.order_by = Some(value);
+        self
+    }
+    pub fn sort(mut self, value: IssueSortInput) -> Self {
+        self.sort = Some(value);
+        self
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the query without sending it, e.g. to add it to a
+    /// [`Batch`](crate::batch::Batch).
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        let mut map = self.extra_variables;
+        if let Some(ref v) = self.filter {
+            map.insert("filter".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.before {
+            map.insert("before".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.after {
+            map.insert("after".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.first {
+            map.insert("first".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.last {
+            map.insert("last".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.include_archived {
+            map.insert("includeArchived".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.order_by {
+            map.insert("orderBy".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.sort {
+            map.insert("sort".to_string(), serde_json::json!(v));
+        }
+        let variables = serde_json::Value::Object(map);
+        let selection = format!(
+            "{} {{ nodes {{ {} }} pageInfo {{ hasNextPage endCursor }} }}",
+            "issues(filter: $filter, before: $before, after: $after, first: $first, last: $last, includeArchived: $includeArchived, orderBy: $orderBy, sort: $sort)",
+            T::selection()
+        );
+        Operation::query(
+            "Issues",
+            "$filter: IssueFilter, $before: String, $after: String, $first: Int, $last: Int, $includeArchived: Boolean, $orderBy: PaginationOrderBy, $sort: [IssueSortInput!]",
+            selection,
+            variables,
+            "issues",
+        )
+    }
+    pub async fn send(self) -> Result<Connection<T>, LinearError> {
+        let client = self.client;
+        self.into_operation().execute(client).await
+    }
+    /// Stream every node across all pages, following `pageInfo.endCursor`.
+    ///
+    /// Starts after the cursor set via `.after()` (or at the beginning) and
+    /// uses `.first()` as the page size. `.before()` / `.last()` are ignored.
+    pub fn stream(self) -> impl futures::Stream<Item = Result<T, LinearError>> + 'a
+    where
+        T: 'a,
+    {
+        let after = self.after.clone();
+        crate::pagination::paginate(after, move |after| {
+            Self {
+                client: self.client,
+                filter: self.filter.clone(),
+                before: None,
+                after,
+                first: self.first,
+                last: None,
+                include_archived: self.include_archived,
+                order_by: self.order_by.clone(),
+                sort: self.sort.clone(),
+                extra_variables: self.extra_variables.clone(),
+                _marker: std::marker::PhantomData,
+            }
+            .send()
+        })
+    }
+    /// Fetch every node across all pages. See [`stream`](Self::stream).
+    pub async fn all(self) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(self.stream()).await
+    }
+    /// Fetch nodes across pages until `max` have been collected. Pages past
+    /// the one containing the `max`-th node are never requested.
+    pub async fn take_all(self, max: usize) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(futures::StreamExt::take(self.stream(), max)).await
+    }
+}
+/// Query builder: `DEPRECATED` Search issues. This endpoint is deprecated and will be removed in the future – use `searchIssues` instead.
+///
+/// Full type: [`Issue`](super::types::Issue)
+///
+/// Use setter methods to configure optional parameters, then call
+/// [`.send()`](Self::send) to execute the query.
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+#[must_use]
+pub struct IssueSearchQueryBuilder<'a, T> {
+    client: &'a Client,
+    filter: Option<IssueFilter>,
+    query: Option<String>,
+    before: Option<String>,
+    after: Option<String>,
+    first: Option<i64>,
+    last: Option<i64>,
+    include_archived: Option<bool>,
+    order_by: Option<PaginationOrderBy>,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "issues")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Issue>>
+    IssueSearchQueryBuilder<'a, T>
+{
+    pub fn filter(mut self, value: IssueFilter) -> Self {
+        self.filter = Some(value);
+        self
+    }
+    pub fn query(mut self, value: impl Into<String>) -> Self {
+        self.query = Some(value.into());
+        self
+    }
+    pub fn before(mut self, value: impl Into<String>) -> Self {
+        self.before = Some(value.into());
+        self
+    }
+    pub fn after(mut self, value: impl Into<String>) -> Self {
+        self.after = Some(value.into());
+        self
+    }
+    pub fn first(mut self, value: i64) -> Self {
+        self.first = Some(value);
+        self
+    }
+    pub fn last(mut self, value: i64) -> Self {
+        self.last = Some(value);
+        self
+    }
+    pub fn include_archived(mut self, value: bool) -> Self {
+        self.include_archived = Some(value);
+        self
+    }
+    pub fn order_by(mut self, value: PaginationOrderBy) -> Self {
+        self.order_by = Some(value);
+        self
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the query without sending it, e.g. to add it to a
+    /// [`Batch`](crate::batch::Batch).
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        let mut map = self.extra_variables;
+        if let Some(ref v) = self.filter {
+            map.insert("filter".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.query {
+            map.insert("query".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.before {
+            map.insert("before".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.after {
+            map.insert("after".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.first {
+            map.insert("first".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.last {
+            map.insert("last".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.include_archived {
+            map.insert("includeArchived".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.order_by {
+            map.insert("orderBy".to_string(), serde_json::json!(v));
+        }
+        let variables = serde_json::Value::Object(map);
+        let selection = format!(
+            "{} {{ nodes {{ {} }} pageInfo {{ hasNextPage endCursor }} }}",
+            "issueSearch(filter: $filter, query: $query, before: $before, after: $after, first: $first, last: $last, includeArchived: $includeArchived, orderBy: $orderBy)",
+            T::selection()
+        );
+        Operation::query(
+            "IssueSearch",
+            "$filter: IssueFilter, $query: String, $before: String, $after: String, $first: Int, $last: Int, $includeArchived: Boolean, $orderBy: PaginationOrderBy",
+            selection,
+            variables,
+            "issueSearch",
+        )
+    }
+    pub async fn send(self) -> Result<Connection<T>, LinearError> {
+        let client = self.client;
+        self.into_operation().execute(client).await
+    }
+    /// Stream every node across all pages, following `pageInfo.endCursor`.
+    ///
+    /// Starts after the cursor set via `.after()` (or at the beginning) and
+    /// uses `.first()` as the page size. `.before()` / `.last()` are ignored.
+    pub fn stream(self) -> impl futures::Stream<Item = Result<T, LinearError>> + 'a
+    where
+        T: 'a,
+    {
+        let after = self.after.clone();
+        crate::pagination::paginate(after, move |after| {
+            Self {
+                client: self.client,
+                filter: self.filter.clone(),
+                query: self.query.clone(),
+                before: None,
+                after,
+                first: self.first,
+                last: None,
+                include_archived: self.include_archived,
+                order_by: self.order_by.clone(),
+                extra_variables: self.extra_variables.clone(),
+                _marker: std::marker::PhantomData,
+            }
+            .send()
+        })
+    }
+    /// Fetch every node across all pages. See [`stream`](Self::stream).
+    pub async fn all(self) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(self.stream()).await
+    }
+    /// Fetch nodes across pages until `max` have been collected. Pages past
+    /// the one containing the `max`-th node are never requested.
+    pub async fn take_all(self, max: usize) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(futures::StreamExt::take(self.stream(), max)).await
+    }
+}
+/// Query builder: Find issues that are related to a given Figma file key.
+///
+/// Full type: [`Issue`](super::types::Issue)
+///
+/// Use setter methods to configure optional parameters, then call
+/// [`.send()`](Self::send) to execute the query.
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+#[must_use]
+pub struct IssueFigmaFileKeySearchQueryBuilder<'a, T> {
+    client: &'a Client,
+    file_key: String,
+    before: Option<String>,
+    after: Option<String>,
+    first: Option<i64>,
+    last: Option<i64>,
+    include_archived: Option<bool>,
+    order_by: Option<PaginationOrderBy>,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "issues")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Issue>>
+    IssueFigmaFileKeySearchQueryBuilder<'a, T>
+{
+    pub fn before(mut self, value: impl Into<String>) -> Self {
+        self.before = Some(value.into());
+        self
+    }
+    pub fn after(mut self, value: impl Into<String>) -> Self {
+        self.after = Some(value.into());
+        self
+    }
+    pub fn first(mut self, value: i64) -> Self {
+        self.first = Some(value);
+        self
+    }
+    pub fn last(mut self, value: i64) -> Self {
+        self.last = Some(value);
+        self
+    }
+    pub fn include_archived(mut self, value: bool) -> Self {
+        self.include_archived = Some(value);
+        self
+    }
+    pub fn order_by(mut self, value: PaginationOrderBy) -> Self {
+        self.order_by = Some(value);
+        self
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the query without sending it, e.g. to add it to a
+    /// [`Batch`](crate::batch::Batch).
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        let mut map = self.extra_variables;
+        map.insert("fileKey".to_string(), serde_json::json!(self.file_key));
+        if let Some(ref v) = self.before {
+            map.insert("before".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.after {
+            map.insert("after".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.first {
+            map.insert("first".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.last {
+            map.insert("last".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.include_archived {
+            map.insert("includeArchived".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.order_by {
+            map.insert("orderBy".to_string(), serde_json::json!(v));
+        }
+        let variables = serde_json::Value::Object(map);
+        let selection = format!(
+            "{} {{ nodes {{ {} }} pageInfo {{ hasNextPage endCursor }} }}",
+            "issueFigmaFileKeySearch(before: $before, after: $after, first: $first, last: $last, includeArchived: $includeArchived, orderBy: $orderBy, fileKey: $fileKey)",
+            T::selection()
+        );
+        Operation::query(
+            "IssueFigmaFileKeySearch",
+            "$before: String, $after: String, $first: Int, $last: Int, $includeArchived: Boolean, $orderBy: PaginationOrderBy, $fileKey: String!",
+            selection,
+            variables,
+            "issueFigmaFileKeySearch",
+        )
+    }
+    pub async fn send(self) -> Result<Connection<T>, LinearError> {
+        let client = self.client;
+        self.into_operation().execute(client).await
+    }
+    /// Stream every node across all pages, following `pageInfo.endCursor`.
+    ///
+    /// Starts after the cursor set via `.after()` (or at the beginning) and
+    /// uses `.first()` as the page size. `.before()` / `.last()` are ignored.
+    pub fn stream(self) -> impl futures::Stream<Item = Result<T, LinearError>> + 'a
+    where
+        T: 'a,
+    {
+        let after = self.after.clone();
+        crate::pagination::paginate(after, move |after| {
+            Self {
+                client: self.client,
+                file_key: self.file_key.clone(),
+                before: None,
+                after,
+                first: self.first,
+                last: None,
+                include_archived: self.include_archived,
+                order_by: self.order_by.clone(),
+                extra_variables: self.extra_variables.clone(),
+                _marker: std::marker::PhantomData,
+            }
+            .send()
+        })
+    }
+    /// Fetch every node across all pages. See [`stream`](Self::stream).
+    pub async fn all(self) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(self.stream()).await
+    }
+    /// Fetch nodes across pages until `max` have been collected. Pages past
+    /// the one containing the `max`-th node are never requested.
+    pub async fn take_all(self, max: usize) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(futures::StreamExt::take(self.stream(), max)).await
+    }
+}
+/// Query builder: Suggests filters for an issue view based on a text prompt.
+///
+/// Full type: [`IssueFilterSuggestionPayload`](super::types::IssueFilterSuggestionPayload)
+///
+/// Use setter methods to configure optional parameters, then call
+/// [`.send()`](Self::send) to execute the query.
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+#[must_use]
+pub struct IssueFilterSuggestionQueryBuilder<'a, T> {
+    client: &'a Client,
+    prompt: String,
+    team_id: Option<String>,
+    project_id: Option<String>,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "issues")]
+impl<
+        'a,
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::IssueFilterSuggestionPayload>,
+    > IssueFilterSuggestionQueryBuilder<'a, T>
+{
+    pub fn team_id(mut self, value: impl Into<String>) -> Self {
+        self.team_id = Some(value.into());
+        self
+    }
+    pub fn project_id(mut self, value: impl Into<String>) -> Self {
+        self.project_id = Some(value.into());
+        self
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the query without sending it, e.g. to add it to a
+    /// [`Batch`](crate::batch::Batch).
+    pub fn into_operation(self) -> Operation<T> {
+        let mut map = self.extra_variables;
+        map.insert("prompt".to_string(), serde_json::json!(self.prompt));
+        if let Some(ref v) = self.team_id {
+            map.insert("teamId".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.project_id {
+            map.insert("projectId".to_string(), serde_json::json!(v));
+        }
+        let variables = serde_json::Value::Object(map);
+        let selection = format!(
+            "{} {{ {} }}",
+            "issueFilterSuggestion(teamId: $teamId, projectId: $projectId, prompt: $prompt)",
+            T::selection()
+        );
+        Operation::query(
+            "IssueFilterSuggestion",
+            "$teamId: String, $projectId: String, $prompt: String!",
+            selection,
+            variables,
+            "issueFilterSuggestion",
+        )
+    }
+    pub async fn send(self) -> Result<T, LinearError> {
+        let client = self.client;
+        self.into_operation().execute(client).await
+    }
+}
+/// Query builder: Returns code repositories that are most likely to be relevant for implementing an issue.
+///
+/// Full type: [`RepositorySuggestionsPayload`](super::types::RepositorySuggestionsPayload)
+///
+/// Use setter methods to configure optional parameters, then call
+/// [`.send()`](Self::send) to execute the query.
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+#[must_use]
+pub struct IssueRepositorySuggestionsQueryBuilder<'a, T> {
+    client: &'a Client,
+    candidate_repositories: CandidateRepository,
+    issue_id: String,
+    agent_session_id: Option<String>,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "issues")]
+impl<
+        'a,
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::RepositorySuggestionsPayload>,
+    > IssueRepositorySuggestionsQueryBuilder<'a, T>
+{
+    pub fn agent_session_id(mut self, value: impl Into<String>) -> Self {
+        self.agent_session_id = Some(value.into());
+        self
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the query without sending it, e.g. to add it to a
+    /// [`Batch`](crate::batch::Batch).
+    pub fn into_operation(self) -> Operation<T> {
+        let mut map = self.extra_variables;
+        map.insert(
+            "candidateRepositories".to_string(),
+            serde_json::json!(self.candidate_repositories),
+        );
+        map.insert("issueId".to_string(), serde_json::json!(self.issue_id));
+        if let Some(ref v) = self.agent_session_id {
+            map.insert("agentSessionId".to_string(), serde_json::json!(v));
+        }
+        let variables = serde_json::Value::Object(map);
+        let selection = format!(
+            "{} {{ {} }}",
+            "issueRepositorySuggestions(agentSessionId: $agentSessionId, candidateRepositories: $candidateRepositories, issueId: $issueId)",
+            T::selection()
+        );
+        Operation::query(
+            "IssueRepositorySuggestions",
+            "$agentSessionId: String, $candidateRepositories: [CandidateRepository!]!, $issueId: String!",
+            selection,
+            variables,
+            "issueRepositorySuggestions",
+        )
+    }
+    pub async fn send(self) -> Result<T, LinearError> {
+        let client = self.client;
+        self.into_operation().execute(client).await
+    }
+}
+/// Query builder: All issue relations. Returns a paginated list of all issue relations (blocks, blocked by, relates to, duplicates) visible to the authenticated user.
+///
+/// Full type: [`IssueRelation`](super::types::IssueRelation)
+///
+/// Use setter methods to configure optional parameters, then call
+/// [`.send()`](Self::send) to execute the query.
+#[must_use]
+pub struct IssueRelationsQueryBuilder<'a, T> {
+    client: &'a Client,
+    before: Option<String>,
+    after: Option<String>,
+    first: Option<i64>,
+    last: Option<i64>,
+    include_archived: Option<bool>,
+    order_by: Option<PaginationOrderBy>,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::IssueRelation>>
+    IssueRelationsQueryBuilder<'a, T>
+{
+    pub fn before(mut self, value: impl Into<String>) -> Self {
+        self.before = Some(value.into());
+        self
+    }
+    pub fn after(mut self, value: impl Into<String>) -> Self {
+        self.after = Some(value.into());
+        self
+    }
+    pub fn first(mut self, value: i64) -> Self {
+        self.first = Some(value);
+        self
+    }
+    pub fn last(mut self, value: i64) -> Self {
+        self.last = Some(value);
+        self
+    }
+    pub fn include_archived(mut self, value: bool) -> Self {
+        self.include_archived = Some(value);
+        self
+    }
+    pub fn order_by(mut self, value: PaginationOrderBy) -> Self {
+        self.order_by = Some(value);
+        self
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the query without sending it, e.g. to add it to a
+    /// [`Batch`](crate::batch::Batch).
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        let mut map = self.extra_variables;
+        if let Some(ref v) = self.before {
+            map.insert("before".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.after {
+            map.insert("after".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.first {
+            map.insert("first".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.last {
+            map.insert("last".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.include_archived {
+            map.insert("includeArchived".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.order_by {
+            map.insert("orderBy".to_string(), serde_json::json!(v));
+        }
+        let variables = serde_json::Value::Object(map);
+        let selection = format!(
+            "{} {{ nodes {{ {} }} pageInfo {{ hasNextPage endCursor }} }}",
+            "issueRelations(before: $before, after: $after, first: $first, last: $last, includeArchived: $includeArchived, orderBy: $orderBy)",
+            T::selection()
+        );
+        Operation::query(
+            "IssueRelations",
+            "$before: String, $after: String, $first: Int, $last: Int, $includeArchived: Boolean, $orderBy: PaginationOrderBy",
+            selection,
+            variables,
+            "issueRelations",
+        )
+    }
+    pub async fn send(self) -> Result<Connection<T>, LinearError> {
+        let client = self.client;
+        self.into_operation().execute(client).await
+    }
+    /// Stream every node across all pages, following `pageInfo.endCursor`.
+    ///
+    /// Starts after the cursor set via `.after()` (or at the beginning) and
+    /// uses `.first()` as the page size. `.before()` / `.last()` are ignored.
+    pub fn stream(self) -> impl futures::Stream<Item = Result<T, LinearError>> + 'a
+    where
+        T: 'a,
+    {
+        let after = self.after.clone();
+        crate::pagination::paginate(after, move |after| {
+            Self {
+                client: self.client,
+                before: None,
+                after,
+                first: self.first,
+                last: None,
+                include_archived: self.include_archived,
+                order_by: self.order_by.clone(),
+                extra_variables: self.extra_variables.clone(),
+                _marker: std::marker::PhantomData,
+            }
+            .send()
+        })
+    }
+    /// Fetch every node across all pages. See [`stream`](Self::stream).
+    pub async fn all(self) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(self.stream()).await
+    }
+    /// Fetch nodes across pages until `max` have been collected. Pages past
+    /// the one containing the `max`-th node are never requested.
+    pub async fn take_all(self, max: usize) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(futures::StreamExt::take(self.stream(), max)).await
+    }
+}
+/// Query builder: All issue labels. Returns a paginated list of labels visible to the authenticated user, including both workspace-level and team-scoped labels.
+///
+/// Full type: [`IssueLabel`](super::types::IssueLabel)
+///
+/// Use setter methods to configure optional parameters, then call
+/// [`.send()`](Self::send) to execute the query.
+#[must_use]
+pub struct IssueLabelsQueryBuilder<'a, T> {
+    client: &'a Client,
+    filter: Option<IssueLabelFilter>,
+    before: Option<String>,
+    after: Option<String>,
+    first: Option<i64>,
+    last: Option<i64>,
+    include_archived: Option<bool>,
+    order_by: Option<PaginationOrderBy>,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::IssueLabel>>
+    IssueLabelsQueryBuilder<'a, T>
+{
+    pub fn filter(mut self, value: IssueLabelFilter) -> Self {
+        self.filter = Some(value);
+        self
+    }
+    pub fn before(mut self, value: impl Into<String>) -> Self {
+        self.before = Some(value.into());
+        self
+    }
+    pub fn after(mut self, value: impl Into<String>) -> Self {
+        self.after = Some(value.into());
+        self
+    }
+    pub fn first(mut self, value: i64) -> Self {
+        self.first = Some(value);
+        self
+    }
+    pub fn last(mut self, value: i64) -> Self {
+        self.last = Some(value);
+        self
+    }
+    pub fn include_archived(mut self, value: bool) -> Self {
+        self.include_archived = Some(value);
+        self
+    }
+    pub fn order_by(mut self, value: PaginationOrderBy) -> Self {
+        self.order_by = Some(value);
+        self
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the query without sending it, e.g. to add it to a
+    /// [`Batch`](crate::batch::Batch).
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        let mut map = self.extra_variables;
+        if let Some(ref v) = self.filter {
+            map.insert("filter".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.before {
+            map.insert("before".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.after {
+            map.insert("after".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.first {
+            map.insert("first".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.last {
+            map.insert("last".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.include_archived {
+            map.insert("includeArchived".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.order_by {
+            map.insert("orderBy".to_string(), serde_json::json!(v));
+        }
+        let variables = serde_json::Value::Object(map);
+        let selection = format!(
+            "{} {{ nodes {{ {} }} pageInfo {{ hasNextPage endCursor }} }}",
+            "issueLabels(filter: $filter, before: $before, after: $after, first: $first, last: $last, includeArchived: $includeArchived, orderBy: $orderBy)",
+            T::selection()
+        );
+        Operation::query(
+            "IssueLabels",
+            "$filter: IssueLabelFilter, $before: String, $after: String, $first: Int, $last: Int, $includeArchived: Boolean, $orderBy: PaginationOrderBy",
+            selection,
+            variables,
+            "issueLabels",
+        )
+    }
+    pub async fn send(self) -> Result<Connection<T>, LinearError> {
+        let client = self.client;
+        self.into_operation().execute(client).await
+    }
+    /// Stream every node across all pages, following `pageInfo.endCursor`.
+    ///
+    /// Starts after the cursor set via `.after()` (or at the beginning) and
+    /// uses `.first()` as the page size. `.before()` / `.last()` are ignored.
+    pub fn stream(self) -> impl futures::Stream<Item = Result<T, LinearError>> + 'a
+    where
+        T: 'a,
+    {
+        let after = self.after.clone();
+        crate::pagination::paginate(after, move |after| {
+            Self {
+                client: self.client,
+                filter: self.filter.clone(),
+                before: None,
+                after,
+                first: self.first,
+                last: None,
+                include_archived: self.include_archived,
+                order_by: self.order_by.clone(),
+                extra_variables: self.extra_variables.clone(),
+                _marker: std::marker::PhantomData,
+            }
+            .send()
+        })
+    }
+    /// Fetch every node across all pages. See [`stream`](Self::stream).
+    pub async fn all(self) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(self.stream()).await
+    }
+    /// Fetch nodes across pages until `max` have been collected. Pages past
+    /// the one containing the `max`-th node are never requested.
+    pub async fn take_all(self, max: usize) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(futures::StreamExt::take(self.stream(), max)).await
+    }
+}
+/// Query builder: All integration template connections for the workspace, linking templates to integrations.
+///
+/// Full type: [`IntegrationTemplate`](super::types::IntegrationTemplate)
+///
+/// Use setter methods to configure optional parameters, then call
+/// [`.send()`](Self::send) to execute the query.
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+#[must_use]
+pub struct IntegrationTemplatesQueryBuilder<'a, T> {
+    client: &'a Client,
+    before: Option<String>,
+    after: Option<String>,
+    first: Option<i64>,
+    last: Option<i64>,
+    include_archived: Option<bool>,
+    order_by: Option<PaginationOrderBy>,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "integrations")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::IntegrationTemplate>>
+    IntegrationTemplatesQueryBuilder<'a, T>
+{
+    pub fn before(mut self, value: impl Into<String>) -> Self {
+        self.before = Some(value.into());
+        self
+    }
+    pub fn after(mut self, value: impl Into<String>) -> Self {
+        self.after = Some(value.into());
+        self
+    }
+    pub fn first(mut self, value: i64) -> Self {
+        self.first = Some(value);
+        self
+    }
+    pub fn last(mut self, value: i64) -> Self {
+        self.last = Some(value);
+        self
+    }
+    pub fn include_archived(mut self, value: bool) -> Self {
+        self.include_archived = Some(value);
+        self
+    }
+    pub fn order_by(mut self, value: PaginationOrderBy) -> Self {
+        self.order_by = Some(value);
+        self
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the query without sending it, e.g. to add it to a
+    /// [`Batch`](crate::batch::Batch).
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        let mut map = self.extra_variables;
+        if let Some(ref v) = self.before {
+            map.insert("before".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.after {
+            map.insert("after".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.first {
+            map.insert("first".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.last {
+            map.insert("last".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.include_archived {
+            map.insert("includeArchived".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.order_by {
+            map.insert("orderBy".to_string(), serde_json::json!(v));
+        }
+        let variables = serde_json::Value::Object(map);
+        let selection = format!(
+            "{} {{ nodes {{ {} }} pageInfo {{ hasNextPage endCursor }} }}",
+            "integrationTemplates(before: $before, after: $after, first: $first, last: $last, includeArchived: $includeArchived, orderBy: $orderBy)",
+            T::selection()
+        );
+        Operation::query(
+            "IntegrationTemplates",
+            "$before: String, $after: String, $first: Int, $last: Int, $includeArchived: Boolean, $orderBy: PaginationOrderBy",
+            selection,
+            variables,
+            "integrationTemplates",
+        )
+    }
+    pub async fn send(self) -> Result<Connection<T>, LinearError> {
+        let client = self.client;
+        self.into_operation().execute(client).await
+    }
+    /// Stream every node across all pages, following `pageInfo.endCursor`.
+    ///
+    /// Starts after the cursor set via `.after()` (or at the beginning) and
+    /// uses `.first()` as the page size. `.before()` / `.last()` are ignored.
+    pub fn stream(self) -> impl futures::Stream<Item = Result<T, LinearError>> + 'a
+    where
+        T: 'a,
+    {
+        let after = self.after.clone();
+        crate::pagination::paginate(after, move |after| {
+            Self {
+                client: self.client,
+                before: None,
+                after,
+                first: self.first,
+                last: None,
+                include_archived: self.include_archived,
+                order_by: self.order_by.clone(),
+                extra_variables: self.extra_variables.clone(),
+                _marker: std::marker::PhantomData,
+            }
+            .send()
+        })
+    }
+    /// Fetch every node across all pages. See [`stream`](Self::stream).
+    pub async fn all(self) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(self.stream()).await
+    }
+    /// Fetch nodes across pages until `max` have been collected. Pages past
+    /// the one containing the `max`-th node are never requested.
+    pub async fn take_all(self, max: usize) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(futures::StreamExt::take(self.stream(), max)).await
+    }
+}
+/// Query builder: All integrations for the workspace.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Use setter methods to configure optional parameters, then call
+/// [`.send()`](Self::send) to execute the query.
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+#[must_use]
+pub struct IntegrationsQueryBuilder<'a, T> {
+    client: &'a Client,
+    before: Option<String>,
+    after: Option<String>,
+    first: Option<i64>,
+    last: Option<i64>,
+    include_archived: Option<bool>,
+    order_by: Option<PaginationOrderBy>,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "integrations")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Integration>>
+    IntegrationsQueryBuilder<'a, T>
+{
+    pub fn before(mut self, value: impl Into<String>) -> Self {
+        self.before = Some(value.into());
+        self
+    }
+    pub fn after(mut self, value: impl Into<String>) -> Self {
+        self.after = Some(value.into());
+        self
+    }
+    pub fn first(mut self, value: i64) -> Self {
+        self.first = Some(value);
+        self
+    }
+    pub fn last(mut self, value: i64) -> Self {
+        self.last = Some(value);
+        self
+    }
+    pub fn include_archived(mut self, value: bool) -> Self {
+        self.include_archived = Some(value);
+        self
+    }
+    pub fn order_by(mut self, value: PaginationOrderBy) -> Self {
+        self.order_by = Some(value);
+        self
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the query without sending it, e.g. to add it to a
+    /// [`Batch`](crate::batch::Batch).
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        let mut map = self.extra_variables;
+        if let Some(ref v) = self.before {
+            map.insert("before".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.after {
+            map.insert("after".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.first {
+            map.insert("first".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.last {
+            map.insert("last".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.include_archived {
+            map.insert("includeArchived".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.order_by {
+            map.insert("orderBy".to_string(), serde_json::json!(v));
+        }
+        let variables = serde_json::Value::Object(map);
+        let selection = format!(
+            "{} {{ nodes {{ {} }} pageInfo {{ hasNextPage endCursor }} }}",
+            "integrations(before: $before, after: $after, first: $first, last: $last, includeArchived: $includeArchived, orderBy: $orderBy)",
+            T::selection()
+        );
+        Operation::query(
+            "Integrations",
+            "$before: String, $after: String, $first: Int, $last: Int, $includeArchived: Boolean, $orderBy: PaginationOrderBy",
+            selection,
+            variables,
+            "integrations",
+        )
+    }
+    pub async fn send(self) -> Result<Connection<T>, LinearError> {
+        let client = self.client;
+        self.into_operation().execute(client).await
+    }
+    /// Stream every node across all pages, following `pageInfo.endCursor`.
+    ///
+    /// Starts after the cursor set via `.after()` (or at the beginning) and
+    /// uses `.first()` as the page size. `.before()` / `.last()` are ignored.
+    pub fn stream(self) -> impl futures::Stream<Item = Result<T, LinearError>> + 'a
+    where
+        T: 'a,
+    {
+        let after = self.after.clone();
+        crate::pagination::paginate(after, move |after| {
+            Self {
+                client: self.client,
+                before: None,
+                after,
+                first: self.first,
+                last: None,
+                include_archived: self.include_archived,
+                order_by: self.order_by.clone(),
+                extra_variables: self.extra_variables.clone(),
+                _marker: std::marker::PhantomData,
+            }
+            .send()
+        })
+    }
+    /// Fetch every node across all pages. See [`stream`](Self::stream).
+    pub async fn all(self) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(self.stream()).await
+    }
+    /// Fetch nodes across pages until `max` have been collected. Pages past
+    /// the one containing the `max`-th node are never requested.
+    pub async fn take_all(self, max: usize) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(futures::StreamExt::take(self.stream(), max)).await
+    }
+}
+/// Query builder: Returns all initiative status updates in the workspace, with optional filtering.
+///
+/// Full type: [`InitiativeUpdate`](super::types::InitiativeUpdate)
+///
+/// Use setter methods to configure optional parameters, then call
+/// [`.send()`](Self::send) to execute the query.
+///
+/// Requires the `initiatives` feature.
+#[cfg(feature = "initiatives")]
+#[must_use]
+pub struct InitiativeUpdatesQueryBuilder<'a, T> {
+    client: &'a Client,
+    filter: Option<InitiativeUpdateFilter>,
+    before: Option<String>,
+    after: Option<String>,
+    first: Option<i64>,
+    last: Option<i64>,
+    include_archived: Option<bool>,
+    order_by: Option<PaginationOrderBy>,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "initiatives")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::InitiativeUpdate>>
+    InitiativeUpdatesQueryBuilder<'a, T>
+{
+    pub fn filter(mut self, value: InitiativeUpdateFilter) -> Self {
+        self.filter = Some(value);
+        self
+    }
+    pub fn before(mut self, value: impl Into<String>) -> Self {
+        self.before = Some(value.into());
+        self
+    }
+    pub fn after(mut self, value: impl Into<String>) -> Self {
+        self.after = Some(value.into());
+        self
+    }
+    pub fn first(mut self, value: i64) -> Self {
+        self.first = Some(value);
+        self
+    }
+    pub fn last(mut self, value: i64) -> Self {
+        self.last = Some(value);
+        self
+    }
+    pub fn include_archived(mut self, value: bool) -> Self {
+        self.include_archived = Some(value);
+        self
+    }
+    pub fn order_by(mut self, value: PaginationOrderBy) -> Self {
+        self.order_by = Some(value);
+        self
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the query without sending it, e.g. to add it to a
+    /// [`Batch`](crate::batch::Batch).
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        let mut map = self.extra_variables;
+        if let Some(ref v) = self.filter {
+            map.insert("filter".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.before {
+            map.insert("before".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.after {
+            map.insert("after".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.first {
+            map.insert("first".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.last {
+            map.insert("last".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.include_archived {
+            map.insert("includeArchived".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.order_by {
+            map.insert("orderBy".to_string(), serde_json::json!(v));
+        }
+        let variables = serde_json::Value::Object(map);
+        let selection = format!(
+            "{} {{ nodes {{ {} }} pageInfo {{ hasNextPage endCursor }} }}",
+            "initiativeUpdates(filter: $filter, before: $before, after: $after, first: $first, last: $last, includeArchived: $includeArchived, orderBy: $orderBy)",
+            T::selection()
+        );
+        Operation::query(
+            "InitiativeUpdates",
+            "$filter: InitiativeUpdateFilter, $before: String, $after: String, $first: Int, $last: Int, $includeArchived: Boolean, $orderBy: PaginationOrderBy",
+            selection,
+            variables,
+            "initiativeUpdates",
+        )
+    }
+    pub async fn send(self) -> Result<Connection<T>, LinearError> {
+        let client = self.client;
+        self.into_operation().execute(client).await
+    }
+    /// Stream every node across all pages, following `pageInfo.endCursor`.
+    ///
+    /// Starts after the cursor set via `.after()` (or at the beginning) and
+    /// uses `.first()` as the page size. `.before()` / `.last()` are ignored.
+    pub fn stream(self) -> impl futures::Stream<Item = Result<T, LinearError>> + 'a
+    where
+        T: 'a,
+    {
+        let after = self.after.clone();
+        crate::pagination::paginate(after, move |after| {
+            Self {
+                client: self.client,
+                filter: self.filter.clone(),
+                before: None,
+                after,
+                first: self.first,
+                last: None,
+                include_archived: self.include_archived,
+                order_by: self.order_by.clone(),
+                extra_variables: self.extra_variables.clone(),
+                _marker: std::marker::PhantomData,
+            }
+            .send()
+        })
+    }
+    /// Fetch every node across all pages. See [`stream`](Self::stream).
+    pub async fn all(self) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(self.stream()).await
+    }
+    /// Fetch nodes across pages until `max` have been collected. Pages past
+    /// the one containing the `max`-th node are never requested.
+    pub async fn take_all(self, max: usize) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(futures::StreamExt::take(self.stream(), max)).await
+    }
+}
+/// Query builder: Returns all initiative-to-project associations in the workspace.
+///
+/// Full type: [`InitiativeToProject`](super::types::InitiativeToProject)
+///
+/// Use setter methods to configure optional parameters, then call
+/// [`.send()`](Self::send) to execute the query.
+///
+/// Requires the `initiatives` feature.
+#[cfg(feature = "initiatives")]
+#[must_use]
+pub struct InitiativeToProjectsQueryBuilder<'a, T> {
+    client: &'a Client,
+    before: Option<String>,
+    after: Option<String>,
+    first: Option<i64>,
+    last: Option<i64>,
+    include_archived: Option<bool>,
+    order_by: Option<PaginationOrderBy>,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "initiatives")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::InitiativeToProject>>
+    InitiativeToProjectsQueryBuilder<'a, T>
+{
+    pub fn before(mut self, value: impl Into<String>) -> Self {
+        self.before = Some(value.into());
+        self
+    }
+    pub fn after(mut self, value: impl Into<String>) -> Self {
+        self.after = Some(value.into());
+        self
+    }
+    pub fn first(mut self, value: i64) -> Self {
+        self.first = Some(value);
+        self
+    }
+    pub fn last(mut self, value: i64) -> Self {
+        self.last = Some(value);
+        self
+    }
+    pub fn include_archived(mut self, value: bool) -> Self {
+        self.include_archived = Some(value);
+        self
+    }
+    pub fn order_by(mut self, value: PaginationOrderBy) -> Self {
+        self.order_by = Some(value);
+        self
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the query without sending it, e.g. to add it to a
+    /// [`Batch`](crate::batch::Batch).
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        let mut map = self.extra_variables;
+        if let Some(ref v) = self.before {
+            map.insert("before".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.after {
+            map.insert("after".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.first {
+            map.insert("first".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.last {
+            map.insert("last".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.include_archived {
+            map.insert("includeArchived".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.order_by {
+            map.insert("orderBy".to_string(), serde_json::json!(v));
+        }
+        let variables = serde_json::Value::Object(map);
+        let selection = format!(
+            "{} {{ nodes {{ {} }} pageInfo {{ hasNextPage endCursor }} }}",
+            "initiativeToProjects(before: $before, after: $after, first: $first, last: $last, includeArchived: $includeArchived, orderBy: $orderBy)",
+            T::selection()
+        );
+        Operation::query(
+            "InitiativeToProjects",
+            "$before: String, $after: String, $first: Int, $last: Int, $includeArchived: Boolean, $orderBy: PaginationOrderBy",
+            selection,
+            variables,
+            "initiativeToProjects",
+        )
+    }
+    pub async fn send(self) -> Result<Connection<T>, LinearError> {
+        let client = self.client;
+        self.into_operation().execute(client).await
+    }
+    /// Stream every node across all pages, following `pageInfo.endCursor`.
+    ///
+    /// Starts after the cursor set via `.after()` (or at the beginning) and
+    /// uses `.first()` as the page size. `.before()` / `.last()` are ignored.
+    pub fn stream(self) -> impl futures::Stream<Item = Result<T, LinearError>> + 'a
+    where
+        T: 'a,
+    {
+        let after = self.after.clone();
+        crate::pagination::paginate(after, move |after| {
+            Self {
+                client: self.client,
+                before: None,
+                after,
+                first: self.first,
+                last: None,
+                include_archived: self.include_archived,
+                order_by: self.order_by.clone(),
+                extra_variables: self.extra_variables.clone(),
+                _marker: std::marker::PhantomData,
+            }
+            .send()
+        })
+    }
+    /// Fetch every node across all pages. See [`stream`](Self::stream).
+    pub async fn all(self) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(self.stream()).await
+    }
+    /// Fetch nodes across pages until `max` have been collected. Pages past
+    /// the one containing the `max`-th node are never requested.
+    pub async fn take_all(self, max: usize) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(futures::StreamExt::take(self.stream(), max)).await
+    }
+}
+/// Query builder: Returns all initiatives in the workspace, with optional filtering and sorting.
+///
+/// Full type: [`Initiative`](super::types::Initiative)
+///
+/// Use setter methods to configure optional parameters, then call
+/// [`.send()`](Self::send) to execute the query.
+///
+/// Requires the `initiatives` feature.
+#[cfg(feature = "initiatives")]
+#[must_use]
+pub struct InitiativesQueryBuilder<'a, T> {
+    client: &'a Client,
+    filter: Option<InitiativeFilter>,
+    before: Option<String>,
+    after: Option<String>,
+    first: Option<i64>,
+    last: Option<i64>,
+    include_archived: Option<bool>,
+    order_by: Option<PaginationOrderBy>,
+    sort: Option<InitiativeSortInput>,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "initiatives")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Initiative>>
+    InitiativesQueryBuilder<'a, T>
+{
+    pub fn filter(mut self, value: InitiativeFilter) -> Self {
+        self.filter = Some(value);
+        self
+    }
+    pub fn before(mut self, value: impl Into<String>) -> Self {
+        self.before = Some(value.into());
+        self
+    }
+    pub fn after(mut self, value: impl Into<String>) -> Self {
+        self.after = Some(value.into());
+        self
+    }
+    pub fn first(mut self, value: i64) -> Self {
+        self.first = Some(value);
+        self
+    }
+    pub fn last(mut self, value: i64) -> Self {
+        self.last = Some(value);
+        self
+    }
+    pub fn include_archived(mut self, value: bool) -> Self {
+        self.include_archived = Some(value);
+        self
+    }
+    pub fn order_by(mut self, value: PaginationOrderBy) -> Self {
+        self.order_by = Some(value);
+        self
+    }
+    pub fn sort(mut self, value: InitiativeSortInput) -> Self {
+        self.sort = Some(value);
+        self
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the query without sending it, e.g. to add it to a
+    /// [`Batch`](crate::batch::Batch).
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        let mut map = self.extra_variables;
+        if let Some(ref v) = self.filter {
+            map.insert("filter".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.before {
+            map.insert("before".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.after {
+            map.insert("after".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.first {
+            map.insert("first".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.last {
+            map.insert("last".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.include_archived {
+            map.insert("includeArchived".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.order_by {
+            map.insert("orderBy".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.sort {
+            map.insert("sort".to_string(), serde_json::json!(v));
+        }
+        let variables = serde_json::Value::Object(map);
+        let selection = format!(
+            "{} {{ nodes {{ {} }} pageInfo {{ hasNextPage endCursor }} }}",
+            "initiatives(filter: $filter, before: $before, after: $after, first: $first, last: $last, includeArchived: $includeArchived, orderBy: $orderBy, sort: $sort)",
+            T::selection()
+        );
+        Operation::query(
+            "Initiatives",
+            "$filter: InitiativeFilter, $before: String, $after: String, $first: Int, $last: Int, $includeArchived: Boolean, $orderBy: PaginationOrderBy, $sort: [InitiativeSortInput!]",
+            selection,
+            variables,
+            "initiatives",
+        )
+    }
+    pub async fn send(self) -> Result<Connection<T>, LinearError> {
+        let client = self.client;
+        self.into_operation().execute(client).await
+    }
+    /// Stream every node across all pages, following `pageInfo.endCursor`.
+    ///
+    /// Starts after the cursor set via `.after()` (or at the beginning) and
+    /// uses `.first()` as the page size. `.before()` / `.last()` are ignored.
+    pub fn stream(self) -> impl futures::Stream<Item = Result<T, LinearError>> + 'a
+    where
+        T: 'a,
+    {
+        let after = self.after.clone();
+        crate::pagination::paginate(after, move |after| {
+            Self {
+                client: self.client,
+                filter: self.filter.clone(),
+                before: None,
+                after,
+                first: self.first,
+                last: None,
+                include_archived: self.include_archived,
+                order_by: self.order_by.clone(),
+                sort: self.sort.clone(),
+                extra_variables: self.extra_variables.clone(),
+                _marker: std::marker::PhantomData,
+            }
+            .send()
+        })
+    }
+    /// Fetch every node across all pages. See [`stream`](Self::stream).
+    pub async fn all(self) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(self.stream()).await
+    }
+    /// Fetch nodes across pages until `max` have been collected. Pages past
+    /// the one containing the `max`-th node are never requested.
+    pub async fn take_all(self, max: usize) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(futures::StreamExt::take(self.stream(), max)).await
+    }
+}
+/// Query builder: Returns all initiative parent-child relations in the workspace.
+///
+/// Full type: [`InitiativeRelation`](super::types::InitiativeRelation)
+///
+/// Use setter methods to configure optional parameters, then call
+/// [`.send()`](Self::send) to execute the query.
+///
+/// Requires the `initiatives` feature.
+#[cfg(feature = "initiatives")]
+#[must_use]
+pub struct InitiativeRelationsQueryBuilder<'a, T> {
+    client: &'a Client,
+    before: Option<String>,
+    after: Option<String>,
+    first: Option<i64>,
+    last: Option<i64>,
+    include_archived: Option<bool>,
+    order_by: Option<PaginationOrderBy>,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "initiatives")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::InitiativeRelation>>
+    InitiativeRelationsQueryBuilder<'a, T>
+{
+    pub fn before(mut self, value: impl Into<String>) -> Self {
+        self.before = Some(value.into());
+        self
+    }
+    pub fn after(mut self, value: impl Into<String>) -> Self {
+        self.after = Some(value.into());
+        self
+    }
+    pub fn first(mut self, value: i64) -> Self {
+        self.first = Some(value);
+        self
+    }
+    pub fn last(mut self, value: i64) -> Self {
+        self.last = Some(value);
+        self
+    }
+    pub fn include_archived(mut self, value: bool) -> Self {
+        self.include_archived = Some(value);
+        self
+    }
+    pub fn order_by(mut self, value: PaginationOrderBy) -> Self {
+        self.order_by = Some(value);
+        self
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the query without sending it, e.g. to add it to a
+    /// [`Batch`](crate::batch::Batch).
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        let mut map = self.extra_variables;
+        if let Some(ref v) = self.before {
+            map.insert("before".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.after {
+            map.insert("after".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.first {
+            map.insert("first".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.last {
+            map.insert("last".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.include_archived {
+            map.insert("includeArchived".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.order_by {
+            map.insert("orderBy".to_string(), serde_json::json!(v));
+        }
+        let variables = serde_json::Value::Object(map);
+        let selection = format!(
+            "{} {{ nodes {{ {} }} pageInfo {{ hasNextPage endCursor }} }}",
+            "initiativeRelations(before: $before, after: $after, first: $first, last: $last, includeArchived: $includeArchived, orderBy: $orderBy)",
+            T::selection()
+        );
+        Operation::query(
+            "InitiativeRelations",
+            "$before: String, $after: String, $first: Int, $last: Int, $includeArchived: Boolean, $orderBy: PaginationOrderBy",
+            selection,
+            variables,
+            "initiativeRelations",
+        )
+    }
+    pub async fn send(self) -> Result<Connection<T>, LinearError> {
+        let client = self.client;
+        self.into_operation().execute(client).await
+    }
+    /// Stream every node across all pages, following `pageInfo.endCursor`.
+    ///
+    /// Starts after the cursor set via `.after()` (or at the beginning) and
+    /// uses `.first()` as the page size. `.before()` / `.last()` are ignored.
+    pub fn stream(self) -> impl futures::Stream<Item = Result<T, LinearError>> + 'a
+    where
+        T: 'a,
+    {
+        let after = self.after.clone();
+        crate::pagination::paginate(after, move |after| {
+            Self {
+                client: self.client,
+                before: None,
+                after,
+                first: self.first,
+                last: None,
+                include_archived: self.include_archived,
+                order_by: self.order_by.clone(),
+                extra_variables: self.extra_variables.clone(),
+                _marker: std::marker::PhantomData,
+            }
+            .send()
+        })
+    }
+    /// Fetch every node across all pages. See [`stream`](Self::stream).
+    pub async fn all(self) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(self.stream()).await
+    }
+    /// Fetch nodes across pages until `max` have been collected. Pages past
+    /// the one containing the `max`-th node are never requested.
+    pub async fn take_all(self, max: usize) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(futures::StreamExt::take(self.stream(), max)).await
+    }
+}
+/// Query builder: The authenticated user's favorites. Returns all bookmarked items that appear in the user's sidebar.
+///
+/// Full type: [`Favorite`](super::types::Favorite)
+///
+/// Use setter methods to configure optional parameters, then call
+/// [`.send()`](Self::send) to execute the query.
+///
+/// Requires the `views` feature.
+#[cfg(feature = "views")]
+#[must_use]
+pub struct FavoritesQueryBuilder<'a, T> {
+    client: &'a Client,
+    before: Option<String>,
+    after: Option<String>,
+    first: Option<i64>,
+    last: Option<i64>,
+    include_archived: Option<bool>,
+    order_by: Option<PaginationOrderBy>,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "views")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Favorite>>
+    FavoritesQueryBuilder<'a, T>
+{
+    pub fn before(mut self, value: impl Into<String>) -> Self {
+        self.before = Some(value.into());
+        self
+    }
+    pub fn after(mut self, value: impl Into<String>) -> Self {
+        self.after = Some(value.into());
+        self
+    }
+    pub fn first(mut self, value: i64) -> Self {
+        self.first = Some(value);
+        self
+    }
+    pub fn last(mut self, value: i64) -> Self {
+        self.last = Some(value);
+        self
+    }
+    pub fn include_archived(mut self, value: bool) -> Self {
+        self.include_archived = Some(value);
+        self
+    }
+    pub fn order_by(mut self, value: PaginationOrderBy) -> Self {
+        self.order_by = Some(value);
+        self
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the query without sending it, e.g. to add it to a
+    /// [`Batch`](crate::batch::Batch).
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        let mut map = self.extra_variables;
+        if let Some(ref v) = self.before {
+            map.insert("before".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.after {
+            map.insert("after".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.first {
+            map.insert("first".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.last {
+            map.insert("last".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.include_archived {
+            map.insert("includeArchived".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.order_by {
+            map.insert("orderBy".to_string(), serde_json::json!(v));
+        }
+        let variables = serde_json::Value::Object(map);
+        let selection = format!(
+            "{} {{ nodes {{ {} }} pageInfo {{ hasNextPage endCursor }} }}",
+            "favorites(before: $before, after: $after, first: $first, last: $last, includeArchived: $includeArchived, orderBy: $orderBy)",
+            T::selection()
+        );
+        Operation::query(
+            "Favorites",
+            "$before: String, $after: String, $first: Int, $last: Int, $includeArchived: Boolean, $orderBy: PaginationOrderBy",
+            selection,
+            variables,
+            "favorites",
+        )
+    }
+    pub async fn send(self) -> Result<Connection<T>, LinearError> {
+        let client = self.client;
+        self.into_operation().execute(client).await
+    }
+    /// Stream every node across all pages, following `pageInfo.endCursor`.
+    ///
+    /// Starts after the cursor set via `.after()` (or at the beginning) and
+    /// uses `.first()` as the page size. `.before()` / `.last()` are ignored.
+    pub fn stream(self) -> impl futures::Stream<Item = Result<T, LinearError>> + 'a
+    where
+        T: 'a,
+    {
+        let after = self.after.clone();
+        crate::pagination::paginate(after, move |after| {
+            Self {
+                client: self.client,
+                before: None,
+                after,
+                first: self.first,
+                last: None,
+                include_archived: self.include_archived,
+                order_by: self.order_by.clone(),
+                extra_variables: self.extra_variables.clone(),
+                _marker: std::marker::PhantomData,
+            }
+            .send()
+        })
+    }
+    /// Fetch every node across all pages. See [`stream`](Self::stream).
+    pub async fn all(self) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(self.stream()).await
+    }
+    /// Fetch nodes across pages until `max` have been collected. Pages past
+    /// the one containing the `max`-th node are never requested.
+    pub async fn take_all(self, max: usize) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(futures::StreamExt::take(self.stream(), max)).await
+    }
+}
+/// Query builder: All external users for the organization. External users are people who interact with Linear through integrated services (Slack, Jira, GitHub, etc.) without having a Linear account.
+///
+/// Full type: [`ExternalUser`](super::types::ExternalUser)
+///
+/// Use setter methods to configure optional parameters, then call
+/// [`.send()`](Self::send) to execute the query.
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+#[must_use]
+pub struct ExternalUsersQueryBuilder<'a, T> {
+    client: &'a Client,
+    before: Option<String>,
+    after: Option<String>,
+    first: Option<i64>,
+    last: Option<i64>,
+    include_archived: Option<bool>,
+    order_by: Option<PaginationOrderBy>,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "integrations")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::ExternalUser>>
+    ExternalUsersQueryBuilder<'a, T>
+{
+    pub fn before(mut self, value: impl Into<String>) -> Self {
+        self.before = Some(value.into());
+        self
+    }
+    pub fn after(mut self, value: impl Into<String>) -> Self {
+        self.after = Some(value.into());
+        self
+    }
+    pub fn first(mut self, value: i64) -> Self {
+        self.first = Some(value);
+        self
+    }
+    pub fn last(mut self, value: i64) -> Self {
+        self.last = Some(value);
+        self
+    }
+    pub fn include_archived(mut self, value: bool) -> Self {
+        self.include_archived = Some(value);
+        self
+    }
+    pub fn order_by(mut self, value: PaginationOrderBy) -> Self {
+        self.order_by = Some(value);
+        self
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the query without sending it, e.g. to add it to a
+    /// [`Batch`](crate::batch::Batch).
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        let mut map = self.extra_variables;
+        if let Some(ref v) = self.before {
+            map.insert("before".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.after {
+            map.insert("after".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.first {
+            map.insert("first".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.last {
+            map.insert("last".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.include_archived {
+            map.insert("includeArchived".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.order_by {
+            map.insert("orderBy".to_string(), serde_json::json!(v));
+        }
+        let variables = serde_json::Value::Object(map);
+        let selection = format!(
+            "{} {{ nodes {{ {} }} pageInfo {{ hasNextPage endCursor }} }}",
+            "externalUsers(before: $before, after: $after, first: $first, last: $last, includeArchived: $includeArchived, orderBy: $orderBy)",
+            T::selection()
+        );
+        Operation::query(
+            "ExternalUsers",
+            "$before: String, $after: String, $first: Int, $last: Int, $includeArchived: Boolean, $orderBy: PaginationOrderBy",
+            selection,
+            variables,
+            "externalUsers",
+        )
+    }
+    pub async fn send(self) -> Result<Connection<T>, LinearError> {
+        let client = self.client;
+        self.into_operation().execute(client).await
+    }
+    /// Stream every node across all pages, following `pageInfo.endCursor`.
+    ///
+    /// Starts after the cursor set via `.after()` (or at the beginning) and
+    /// uses `.first()` as the page size. `.before()` / `.last()` are ignored.
+    pub fn stream(self) -> impl futures::Stream<Item = Result<T, LinearError>> + 'a
+    where
+        T: 'a,
+    {
+        let after = self.after.clone();
+        crate::pagination::paginate(after, move |after| {
+            Self {
+                client: self.client,
+                before: None,
+                after,
+                first: self.first,
+                last: None,
+                include_archived: self.include_archived,
+                order_by: self.order_by.clone(),
+                extra_variables: self.extra_variables.clone(),
+                _marker: std::marker::PhantomData,
+            }
+            .send()
+        })
+    }
+    /// Fetch every node across all pages. See [`stream`](Self::stream).
+    pub async fn all(self) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(self.stream()).await
+    }
+    /// Fetch nodes across pages until `max` have been collected. Pages past
+    /// the one containing the `max`-th node are never requested.
+    pub async fn take_all(self, max: usize) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(futures::StreamExt::take(self.stream(), max)).await
+    }
+}
+/// Query builder: All custom emojis in the workspace.
+///
+/// Full type: [`Emoji`](super::types::Emoji)
+///
+/// Use setter methods to configure optional parameters, then call
+/// [`.send()`](Self::send) to execute the query.
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+#[must_use]
+pub struct EmojisQueryBuilder<'a, T> {
+    client: &'a Client,
+    before: Option<String>,
+    after: Option<String>,
+    first: Option<i64>,
+    last: Option<i64>,
+    include_archived: Option<bool>,
+    order_by: Option<PaginationOrderBy>,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "admin")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Emoji>>
+    EmojisQueryBuilder<'a, T>
+{
+    pub fn before(mut self, value: impl Into<String>) -> Self {
+        self.before = Some(value.into());
+        self
+    }
+    pub fn after(mut self, value: impl Into<String>) -> Self {
+        self.after = Some(value.into());
+        self
+    }
+    pub fn first(mut self, value: i64) -> Self {
+        self.first = Some(value);
+        self
+    }
+    pub fn last(mut self, value: i64) -> Self {
+        self.last = Some(value);
+        self
+    }
+    pub fn include_archived(mut self, value: bool) -> Self {
+        self.include_archived = Some(value);
+        self
+    }
+    pub fn order_by(mut self, value: PaginationOrderBy) -> Self {
+        self.order_by = Some(value);
+        self
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the query without sending it, e.g. to add it to a
+    /// [`Batch`](crate::batch::Batch).
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        let mut map = self.extra_variables;
+        if let Some(ref v) = self.before {
+            map.insert("before".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.after {
+            map.insert("after".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.first {
+            map.insert("first".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.last {
+            map.insert("last".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.include_archived {
+            map.insert("includeArchived".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.order_by {
+            map.insert("orderBy".to_string(), serde_json::json!(v));
+        }
+        let variables = serde_json::Value::Object(map);
+        let selection = format!(
+            "{} {{ nodes {{ {} }} pageInfo {{ hasNextPage endCursor }} }}",
+            "emojis(before: $before, after: $after, first: $first, last: $last, includeArchived: $includeArchived, orderBy: $orderBy)",
+            T::selection()
+        );
+        Operation::query(
+            "Emojis",
+            "$before: String, $after: String, $first: Int, $last: Int, $includeArchived: Boolean, $orderBy: PaginationOrderBy",
+            selection,
+            variables,
+            "emojis",
+        )
+    }
+    pub async fn send(self) -> Result<Connection<T>, LinearError> {
+        let client = self.client;
+        self.into_operation().execute(client).await
+    }
+    /// Stream every node across all pages, following `pageInfo.endCursor`.
+    ///
+    /// Starts after the cursor set via `.after()` (or at the beginning) and
+    /// uses `.first()` as the page size. `.before()` / `.last()` are ignored.
+    pub fn stream(self) -> impl futures::Stream<Item = Result<T, LinearError>> + 'a
+    where
+        T: 'a,
+    {
+        let after = self.after.clone();
+        crate::pagination::paginate(after, move |after| {
+            Self {
+                client: self.client,
+                before: None,
+                after,
+                first: self.first,
+                last: None,
+                include_archived: self.include_archived,
+                order_by: self.order_by.clone(),
+                extra_variables: self.extra_variables.clone(),
+                _marker: std::marker::PhantomData,
+            }
+            .send()
+        })
+    }
+    /// Fetch every node across all pages. See [`stream`](Self::stream).
+    pub async fn all(self) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(self.stream()).await
+    }
+    /// Fetch nodes across pages until `max` have been collected. Pages past
+    /// the one containing the `max`-th node are never requested.
+    pub async fn take_all(self, max: usize) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(futures::StreamExt::take(self.stream(), max)).await
+    }
+}
+/// Query builder: All documents the user has access to in the workspace.
+///
+/// Full type: [`Document`](super::types::Document)
+///
+/// Use setter methods to configure optional parameters, then call
+/// [`.send()`](Self::send) to execute the query.
+#[must_use]
+pub struct DocumentsQueryBuilder<'a, T> {
+    client: &'a Client,
+    filter: Option<DocumentFilter>,
+    before: Option<String>,
+    after: Option<String>,
+    first: Option<i64>,
+    last: Option<i64>,
+    include_archived: Option<bool>,
+    order_by: Option<PaginationOrderBy>,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Document>>
+    DocumentsQueryBuilder<'a, T>
+{
+    pub fn filter(mut self, value: DocumentFilter) -> Self {
+        self.filter = Some(value);
+        self
+    }
+    pub fn before(mut self, value: impl Into<String>) -> Self {
+        self.before = Some(value.into());
+        self
+    }
+    pub fn after(mut self, value: impl Into<String>) -> Self {
+        self.after = Some(value.into());
+        self
+    }
+    pub fn first(mut self, value: i64) -> Self {
+        self.first = Some(value);
+        self
+    }
+    pub fn last(mut self, value: i64) -> Self {
+        self.last = Some(value);
+        self
+    }
+    pub fn include_archived(mut self, value: bool) -> Self {
+        self.include_archived = Some(value);
+        self
+    }
+    pub fn order_by(mut self, value: PaginationOrderBy) -> Self {
+        self.order_by = Some(value);
+        self
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the query without sending it, e.g. to add it to a
+    /// [`Batch`](crate::batch::Batch).
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        let mut map = self.extra_variables;
+        if let Some(ref v) = self.filter {
+            map.insert("filter".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.before {
+            map.insert("before".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.after {
+            map.insert("after".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.first {
+            map.insert("first".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.last {
+            map.insert("last".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.include_archived {
+            map.insert("includeArchived".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.order_by {
+            map.insert("orderBy".to_string(), serde_json::json!(v));
+        }
+        let variables = serde_json::Value::Object(map);
+        let selection = format!(
+            "{} {{ nodes {{ {} }} pageInfo {{ hasNextPage endCursor }} }}",
+            "documents(filter: $filter, before: $before, after: $after, first: $first, last: $last, includeArchived: $includeArchived, orderBy: $orderBy)",
+            T::selection()
+        );
+        Operation::query(
+            "Documents",
+            "$filter: DocumentFilter, $before: String, $after: String, $first: Int, $last: Int, $includeArchived: Boolean, $orderBy: PaginationOrderBy",
+            selection,
+            variables,
+            "documents",
+        )
+    }
+    pub async fn send(self) -> Result<Connection<T>, LinearError> {
+        let client = self.client;
+        self.into_operation().execute(client).await
+    }
+    /// Stream every node across all pages, following `pageInfo.endCursor`.
+    ///
+    /// Starts after the cursor set via `.after()` (or at the beginning) and
+    /// uses `.first()` as the page size. `.before()` / `.last()` are ignored.
+    pub fn stream(self) -> impl futures::Stream<Item = Result<T, LinearError>> + 'a
+    where
+        T: 'a,
+    {
+        let after = self.after.clone();
+        crate::pagination::paginate(after, move |after| {
+            Self {
+                client: self.client,
+                filter: self.filter.clone(),
+                before: None,
+                after,
+                first: self.first,
+                last: None,
+                include_archived: self.include_archived,
+                order_by: self.order_by.clone(),
+                extra_variables: self.extra_variables.clone(),
+                _marker: std::marker::PhantomData,
+            }
+            .send()
+        })
+    }
+    /// Fetch every node across all pages. See [`stream`](Self::stream).
+    pub async fn all(self) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(self.stream()).await
+    }
+    /// Fetch nodes across pages until `max` have been collected. Pages past
+    /// the one containing the `max`-th node are never requested.
+    pub async fn take_all(self, max: usize) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(futures::StreamExt::take(self.stream(), max)).await
+    }
+}
+/// Query builder: All cycles accessible to the user.
+///
+/// Full type: [`Cycle`](super::types::Cycle)
+///
+/// Use setter methods to configure optional parameters, then call
+/// [`.send()`](Self::send) to execute the query.
+#[must_use]
+pub struct CyclesQueryBuilder<'a, T> {
+    client: &'a Client,
+    filter: Option<CycleFilter>,
+    before: Option<String>,
+    after: Option<String>,
+    first: Option<i64>,
+    last: Option<i64>,
+    include_archived: Option<bool>,
+    order_by: Option<PaginationOrderBy>,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Cycle>>
+    CyclesQueryBuilder<'a, T>
+{
+    pub fn filter(mut self, value: CycleFilter) -> Self {
+        self.filter = Some(value);
+        self
+    }
+    pub fn before(mut self, value: impl Into<String>) -> Self {
+        self.before = Some(value.into());
+        self
+    }
+    pub fn after(mut self, value: impl Into<String>) -> Self {
+        self.after = Some(value.into());
+        self
+    }
+    pub fn first(mut self, value: i64) -> Self {
+        self.first = Some(value);
+        self
+    }
+    pub fn last(mut self, value: i64) -> Self {
+        self.last = Some(value);
+        self
+    }
+    pub fn include_archived(mut self, value: bool) -> Self {
+        self.include_archived = Some(value);
+        self
+    }
+    pub fn order_by(mut self, value: PaginationOrderBy) -> Self {
+        self.order_by = Some(value);
+        self
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the query without sending it, e.g. to add it to a
+    /// [`Batch`](crate::batch::Batch).
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        let mut map = self.extra_variables;
+        if let Some(ref v) = self.filter {
+            map.insert("filter".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.before {
+            map.insert("before".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.after {
+            map.insert("after".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.first {
+            map.insert("first".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.last {
+            map.insert("last".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.include_archived {
+            map.insert("includeArchived".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.order_by {
+            map.insert("orderBy".to_string(), serde_json::json!(v));
+        }
+        let variables = serde_json::Value::Object(map);
+        let selection = format!(
+            "{} {{ nodes {{ {} }} pageInfo {{ hasNextPage endCursor }} }}",
+            "cycles(filter: $filter, before: $before, after: $after, first: $first, last: $last, includeArchived: $includeArchived, orderBy: $orderBy)",
+            T::selection()
+        );
+        Operation::query(
+            "Cycles",
+            "$filter: CycleFilter, $before: String, $after: String, $first: Int, $last: Int, $includeArchived: Boolean, $orderBy: PaginationOrderBy",
+            selection,
+            variables,
+            "cycles",
+        )
+    }
+    pub async fn send(self) -> Result<Connection<T>, LinearError> {
+        let client = self.client;
+        self.into_operation().execute(client).await
+    }
+    /// Stream every node across all pages, following `pageInfo.endCursor`.
+    ///
+    /// Starts after the cursor set via `.after()` (or at the beginning) and
+    /// uses `.first()` as the page size. `.before()` / `.last()` are ignored.
+    pub fn stream(self) -> impl futures::Stream<Item = Result<T, LinearError>> + 'a
+    where
+        T: 'a,
+    {
+        let after = self.after.clone();
+        crate::pagination::paginate(after, move |after| {
+            Self {
+                client: self.client,
+                filter: self.filter.clone(),
+                before: None,
+                after,
+                first: self.first,
+                last: None,
+                include_archived: self.include_archived,
+                order_by: self.order_by.clone(),
+                extra_variables: self.extra_variables.clone(),
+                _marker: std::marker::PhantomData,
+            }
+            .send()
+        })
+    }
+    /// Fetch every node across all pages. See [`stream`](Self::stream).
+    pub async fn all(self) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(self.stream()).await
+    }
+    /// Fetch nodes across pages until `max` have been collected. Pages past
+    /// the one containing the `max`-th node are never requested.
+    pub async fn take_all(self, max: usize) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(futures::StreamExt::take(self.stream(), max)).await
+    }
+}
+/// Query builder: All customer tiers defined in the workspace.
+///
+/// Full type: [`CustomerTier`](super::types::CustomerTier)
+///
+/// Use setter methods to configure optional parameters, then call
+/// [`.send()`](Self::send) to execute the query.
+///
+/// Requires the `customers` feature.
+#[cfg(feature = "customers")]
+#[must_use]
+pub struct CustomerTiersQueryBuilder<'a, T> {
+    client: &'a Client,
+    before: Option<String>,
+    after: Option<String>,
+    first: Option<i64>,
+    last: Option<i64>,
+    include_archived: Option<bool>,
+    order_by: Option<PaginationOrderBy>,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "customers")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::CustomerTier>>
+    CustomerTiersQueryBuilder<'a, T>
+{
+    pub fn before(mut self, value: impl Into<String>) -> Self {
+        self.before = Some(value.into());
+        self
+    }
+    pub fn after(mut self, value: impl Into<String>) -> Self {
+        self.after = Some(value.into());
+        self
+    }
+    pub fn first(mut self, value: i64) -> Self {
+        self.first = Some(value);
+        self
+    }
+    pub fn last(mut self, value: i64) -> Self {
+        self.last = Some(value);
+        self
+    }
+    pub fn include_archived(mut self, value: bool) -> Self {
+        self.include_archived = Some(value);
+        self
+    }
+    pub fn order_by(mut self, value: PaginationOrderBy) -> Self {
+        self.order_by = Some(value);
+        self
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the query without sending it, e.g. to add it to a
+    /// [`Batch`](crate::batch::Batch).
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        let mut map = self.extra_variables;
+        if let Some(ref v) = self.before {
+            map.insert("before".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.after {
+            map.insert("after".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.first {
+            map.insert("first".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.last {
+            map.insert("last".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.include_archived {
+            map.insert("includeArchived".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.order_by {
+            map.insert("orderBy".to_string(), serde_json::json!(v));
+        }
+        let variables = serde_json::Value::Object(map);
+        let selection = format!(
+            "{} {{ nodes {{ {} }} pageInfo {{ hasNextPage endCursor }} }}",
+            "customerTiers(before: $before, after: $after, first: $first, last: $last, includeArchived: $includeArchived, orderBy: $orderBy)",
+            T::selection()
+        );
+        Operation::query(
+            "CustomerTiers",
+            "$before: String, $after: String, $first: Int, $last: Int, $includeArchived: Boolean, $orderBy: PaginationOrderBy",
+            selection,
+            variables,
+            "customerTiers",
+        )
+    }
+    pub async fn send(self) -> Result<Connection<T>, LinearError> {
+        let client = self.client;
+        self.into_operation().execute(client).await
+    }
+    /// Stream every node across all pages, following `pageInfo.endCursor`.
+    ///
+    /// Starts after the cursor set via `.after()` (or at the beginning) and
+    /// uses `.first()` as the page size. `.before()` / `.last()` are ignored.
+    pub fn stream(self) -> impl futures::Stream<Item = Result<T, LinearError>> + 'a
+    where
+        T: 'a,
+    {
+        let after = self.after.clone();
+        crate::pagination::paginate(after, move |after| {
+            Self {
+                client: self.client,
+                before: None,
+                after,
+                first: self.first,
+                last: None,
+                include_archived: self.include_archived,
+                order_by: self.order_by.clone(),
+                extra_variables: self.extra_variables.clone(),
+                _marker: std::marker::PhantomData,
+            }
+            .send()
+        })
+    }
+    /// Fetch every node across all pages. See [`stream`](Self::stream).
+    pub async fn all(self) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(self.stream()).await
+    }
+    /// Fetch nodes across pages until `max` have been collected. Pages past
+    /// the one containing the `max`-th node are never requested.
+    pub async fn take_all(self, max: usize) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(futures::StreamExt::take(self.stream(), max)).await
+    }
+}
+/// Query builder: All customer statuses defined in the workspace.
+///
+/// Full type: [`CustomerStatus`](super::types::CustomerStatus)
+///
+/// Use setter methods to configure optional parameters, then call
+/// [`.send()`](Self::send) to execute the query.
+///
+/// Requires the `customers` feature.
+#[cfg(feature = "customers")]
+#[must_use]
+pub struct CustomerStatusesQueryBuilder<'a, T> {
+    client: &'a Client,
+    before: Option<String>,
+    after: Option<String>,
+    first: Option<i64>,
+    last: Option<i64>,
+    include_archived: Option<bool>,
+    order_by: Option<PaginationOrderBy>,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "customers")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::CustomerStatus>>
+    CustomerStatusesQueryBuilder<'a, T>
+{
+    pub fn before(mut self, value: impl Into<String>) -> Self {
+        self.before = Some(value.into());
+        self
+    }
+    pub fn after(mut self, value: impl Into<String>) -> Self {
+        self.after = Some(value.into());
+        self
+    }
+    pub fn first(mut self, value: i64) -> Self {
+        self.first = Some(value);
+        self
+    }
+    pub fn last(mut self, value: i64) -> Self {
+        self.last = Some(value);
+        self
+    }
+    pub fn include_archived(mut self, value: bool) -> Self {
+        self.include_archived = Some(value);
+        self
+    }
+    pub fn order_by(mut self, value: PaginationOrderBy) -> Self {
+        self.order_by = Some(value);
+        self
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the query without sending it, e.g. to add it to a
+    /// [`Batch`](crate::batch::Batch).
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        let mut map = self.extra_variables;
+        if let Some(ref v) = self.before {
+            map.insert("before".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.after {
+            map.insert("after".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.first {
+            map.insert("first".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.last {
+            map.insert("last".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.include_archived {
+            map.insert("includeArchived".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.order_by {
+            map.insert("orderBy".to_string(), serde_json::json!(v));
+        }
+        let variables = serde_json::Value::Object(map);
+        let selection = format!(
+            "{} {{ nodes {{ {} }} pageInfo {{ hasNextPage endCursor }} }}",
+            "customerStatuses(before: $before, after: $after, first: $first, last: $last, includeArchived: $includeArchived, orderBy: $orderBy)",
+            T::selection()
+        );
+        Operation::query(
+            "CustomerStatuses",
+            "$before: String, $after: String, $first: Int, $last: Int, $includeArchived: Boolean, $orderBy: PaginationOrderBy",
+            selection,
+            variables,
+            "customerStatuses",
+        )
+    }
+    pub async fn send(self) -> Result<Connection<T>, LinearError> {
+        let client = self.client;
+        self.into_operation().execute(client).await
+    }
+    /// Stream every node across all pages, following `pageInfo.endCursor`.
+    ///
+    /// Starts after the cursor set via `.after()` (or at the beginning) and
+    /// uses `.first()` as the page size. `.before()` / `.last()` are ignored.
+    pub fn stream(self) -> impl futures::Stream<Item = Result<T, LinearError>> + 'a
+    where
+        T: 'a,
+    {
+        let after = self.after.clone();
+        crate::pagination::paginate(after, move |after| {
+            Self {
+                client: self.client,
+                before: None,
+                after,
+                first: self.first,
+                last: None,
+                include_archived: self.include_archived,
+                order_by: self.order_by.clone(),
+                extra_variables: self.extra_variables.clone(),
+                _marker: std::marker::PhantomData,
+            }
+            .send()
+        })
+    }
+    /// Fetch every node across all pages. See [`stream`](Self::stream).
+    pub async fn all(self) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(self.stream()).await
+    }
+    /// Fetch nodes across pages until `max` have been collected. Pages past
+    /// the one containing the `max`-th node are never requested.
+    pub async fn take_all(self, max: usize) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(futures::StreamExt::take(self.stream(), max)).await
+    }
+}
+/// Query builder: All customers in the workspace, with optional filtering and sorting.
+///
+/// Full type: [`Customer`](super::types::Customer)
+///
+/// Use setter methods to configure optional parameters, then call
+/// [`.send()`](Self::send) to execute the query.
+///
+/// Requires the `customers` feature.
+#[cfg(feature = "customers")]
+#[must_use]
+pub struct CustomersQueryBuilder<'a, T> {
+    client: &'a Client,
+    filter: Option<CustomerFilter>,
+    before: Option<String>,
+    after: Option<String>,
+    first: Option<i64>,
+    last: Option<i64>,
+    include_archived: Option<bool>,
+    order_by: Option<PaginationOrderBy>,
+    sorts: Option<CustomerSortInput>,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "customers")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Customer>>
+    CustomersQueryBuilder<'a, T>
+{
+    pub fn filter(mut self, value: CustomerFilter) -> Self {
+        self.filter = Some(value);
+        self
+    }
+    pub fn before(mut self, value: impl Into<String>) -> Self {
+        self.before = Some(value.into());
+        self
+    }
+    pub fn after(mut self, value: impl Into<String>) -> Self {
+        self.after = Some(value.into());
+        self
+    }
+    pub fn first(mut self, value: i64) -> Self {
+        self.first = Some(value);
+        self
+    }
+    pub fn last(mut self, value: i64) -> Self {
+        self.last = Some(value);
+        self
+    }
+    pub fn include_archived(mut self, value: bool) -> Self {
+        self.include_archived = Some(value);
+        self
+    }
+    pub fn order_by(mut self, value: PaginationOrderBy) -> Self {
+        self.order_by = Some(value);
+        self
+    }
+    pub fn sorts(mut self, value: CustomerSortInput) -> Self {
+        self.sorts = Some(value);
+        self
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the query without sending it, e.g. to add it to a
+    /// [`Batch`](crate::batch::Batch).
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        let mut map = self.extra_variables;
+        if let Some(ref v) = self.filter {
+            map.insert("filter".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.before {
+            map.insert("before".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.after {
+            map.insert("after".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.first {
+            map.insert("first".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.last {
+            map.insert("last".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.include_archived {
+            map.insert("includeArchived".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.order_by {
+            map.insert("orderBy".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.sorts {
+            map.insert("sorts".to_string(), serde_json::json!(v));
+        }
+        let variables = serde_json::Value::Object(map);
+        let selection = format!(
+            "{} {{ nodes {{ {} }} pageInfo {{ hasNextPage endCursor }} }}",
+            "customers(filter: $filter, before: $before, after: $after, first: $first, last: $last, includeArchived: $includeArchived, orderBy: $orderBy, sorts: $sorts)",
+            T::selection()
+        );
+        Operation::query(
+            "Customers",
+            "$filter: CustomerFilter, $before: String, $after: String, $first: Int, $last: Int, $includeArchived: Boolean, $orderBy: PaginationOrderBy, $sorts: [CustomerSortInput!]",
+            selection,
+            variables,
+            "customers",
+        )
+    }
+    pub async fn send(self) -> Result<Connection<T>, LinearError> {
+        let client = self.client;
+        self.into_operation().execute(client).await
+    }
+    /// Stream every node across all pages, following `pageInfo.endCursor`.
+    ///
+    /// Starts after the cursor set via `.after()` (or at the beginning) and
+    /// uses `.first()` as the page size. `.before()` / `.last()` are ignored.
+    pub fn stream(self) -> impl futures::Stream<Item = Result<T, LinearError>> + 'a
+    where
+        T: 'a,
+    {
+        let after = self.after.clone();
+        crate::pagination::paginate(after, move |after| {
+            Self {
+                client: self.client,
+                filter: self.filter.clone(),
+                before: None,
+                after,
+                first: self.first,
+                last: None,
+                include_archived: self.include_archived,
+                order_by: self.order_by.clone(),
+                sorts: self.sorts.clone(),
+                extra_variables: self.extra_variables.clone(),
+                _marker: std::marker::PhantomData,
+            }
+            .send()
+        })
+    }
+    /// Fetch every node across all pages. See [`stream`](Self::stream).
+    pub async fn all(self) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(self.stream()).await
+    }
+    /// Fetch nodes across pages until `max` have been collected. Pages past
+    /// the one containing the `max`-th node are never requested.
+    pub async fn take_all(self, max: usize) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(futures::StreamExt::take(self.stream(), max)).await
+    }
+}
+/// Query builder: All customer needs in the workspace, with optional filtering.
+///
+/// Full type: [`CustomerNeed`](super::types::CustomerNeed)
+///
+/// Use setter methods to configure optional parameters, then call
+/// [`.send()`](Self::send) to execute the query.
+///
+/// Requires the `customers` feature.
+#[cfg(feature = "customers")]
+#[must_use]
+pub struct CustomerNeedsQueryBuilder<'a, T> {
+    client: &'a Client,
+    filter: Option<CustomerNeedFilter>,
+    before: Option<String>,
+    after: Option<String>,
+    first: Option<i64>,
+    last: Option<i64>,
+    include_archived: Option<bool>,
+    order_by: Option<PaginationOrderBy>,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "customers")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::CustomerNeed>>
+    CustomerNeedsQueryBuilder<'a, T>
+{
+    pub fn filter(mut self, value: CustomerNeedFilter) -> Self {
+        self.filter = Some(value);
+        self
+    }
+    pub fn before(mut self, value: impl Into<String>) -> Self {
+        self.before = Some(value.into());
+        self
+    }
+    pub fn after(mut self, value: impl Into<String>) -> Self {
+        self.after = Some(value.into());
+        self
+    }
+    pub fn first(mut self, value: i64) -> Self {
+        self.first = Some(value);
+        self
+    }
+    pub fn last(mut self, value: i64) -> Self {
+        self.last = Some(value);
+        self
+    }
+    pub fn include_archived(mut self, value: bool) -> Self {
+        self.include_archived = Some(value);
+        self
+    }
+    pub fn order_by(mut self, value: PaginationOrderBy) -> Self {
+        self.order_by = Some(value);
+        self
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the query without sending it, e.g. to add it to a
+    /// [`Batch`](crate::batch::Batch).
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        let mut map = self.extra_variables;
+        if let Some(ref v) = self.filter {
+            map.insert("filter".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.before {
+            map.insert("before".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.after {
+            map.insert("after".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.first {
+            map.insert("first".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.last {
+            map.insert("last".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.include_archived {
+            map.insert("includeArchived".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.order_by {
+            map.insert("orderBy".to_string(), serde_json::json!(v));
+        }
+        let variables = serde_json::Value::Object(map);
+        let selection = format!(
+            "{} {{ nodes {{ {} }} pageInfo {{ hasNextPage endCursor }} }}",
+            "customerNeeds(filter: $filter, before: $before, after: $after, first: $first, last: $last, includeArchived: $includeArchived, orderBy: $orderBy)",
+            T::selection()
+        );
+        Operation::query(
+            "CustomerNeeds",
+            "$filter: CustomerNeedFilter, $before: String, $after: String, $first: Int, $last: Int, $includeArchived: Boolean, $orderBy: PaginationOrderBy",
+            selection,
+            variables,
+            "customerNeeds",
+        )
+    }
+    pub async fn send(self) -> Result<Connection<T>, LinearError> {
+        let client = self.client;
+        self.into_operation().execute(client).await
+    }
+    /// Stream every node across all pages, following `pageInfo.endCursor`.
+    ///
+    /// Starts after the cursor set via `.after()` (or at the beginning) and
+    /// uses `.first()` as the page size. `.before()` / `.last()` are ignored.
+    pub fn stream(self) -> impl futures::Stream<Item = Result<T, LinearError>> + 'a
+    where
+        T: 'a,
+    {
+        let after = self.after.clone();
+        crate::pagination::paginate(after, move |after| {
+            Self {
+                client: self.client,
+                filter: self.filter.clone(),
+                before: None,
+                after,
+                first: self.first,
+                last: None,
+                include_archived: self.include_archived,
+                order_by: self.order_by.clone(),
+                extra_variables: self.extra_variables.clone(),
+                _marker: std::marker::PhantomData,
+            }
+            .send()
+        })
+    }
+    /// Fetch every node across all pages. See [`stream`](Self::stream).
+    pub async fn all(self) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(self.stream()).await
+    }
+    /// Fetch nodes across pages until `max` have been collected. Pages past
+    /// the one containing the `max`-th node are never requested.
+    pub async fn take_all(self, max: usize) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(futures::StreamExt::take(self.stream(), max)).await
+    }
+}
+/// Query builder: Retrieves a single customer need by ID or hash.
+///
+/// Full type: [`CustomerNeed`](super::types::CustomerNeed)
+///
+/// Use setter methods to configure optional parameters, then call
+/// [`.send()`](Self::send) to execute the query.
+///
+/// Requires the `customers` feature.
+#[cfg(feature = "customers")]
+#[must_use]
+pub struct CustomerNeedQueryBuilder<'a, T> {
+    client: &'a Client,
+    id: Option<String>,
+    hash: Option<String>,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "customers")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::CustomerNeed>>
+    CustomerNeedQueryBuilder<'a, T>
+{
+    pub fn id(mut self, value: impl Into<String>) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+    pub fn hash(mut self, value: impl Into<String>) -> Self {
+        self.hash = Some(value.into());
+        self
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the query without sending it, e.g. to add it to a
+    /// [`Batch`](crate::batch::Batch).
+    pub fn into_operation(self) -> Operation<T> {
+        let mut map = self.extra_variables;
+        if let Some(ref v) = self.id {
+            map.insert("id".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.hash {
+            map.insert("hash".to_string(), serde_json::json!(v));
+        }
+        let variables = serde_json::Value::Object(map);
+        let selection = format!(
+            "{} {{ {} }}",
+            "customerNeed(id: $id, hash: $hash)",
+            T::selection()
+        );
+        Operation::query(
+            "CustomerNeed",
+            "$id: String, $hash: String",
+            selection,
+            variables,
+            "customerNeed",
+        )
+    }
+    pub async fn send(self) -> Result<T, LinearError> {
+        let client = self.client;
+        self.into_operation().execute(client).await
+    }
+}
+/// Query builder: All custom views accessible to the user, including personal views and shared workspace views. Excludes views scoped to a specific project or initiative.
+///
+/// Full type: [`CustomView`](super::types::CustomView)
+///
+/// Use setter methods to configure optional parameters, then call
+/// [`.send()`](Self::send) to execute the query.
+///
+/// Requires the `views` feature.
+#[cfg(feature = "views")]
+#[must_use]
+pub struct CustomViewsQueryBuilder<'a, T> {
+    client: &'a Client,
+    filter: Option<CustomViewFilter>,
+    before: Option<String>,
+    after: Option<String>,
+    first: Option<i64>,
+    last: Option<i64>,
+    include_archived: Option<bool>,
+    order_by: Option<PaginationOrderBy>,
+    sort: Option<CustomViewSortInput>,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "views")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::CustomView>>
+    CustomViewsQueryBuilder<'a, T>
+{
+    pub fn filter(mut self, value: CustomViewFilter) -> Self {
+        self.filter = Some(value);
+        self
+    }
+    pub fn before(mut self, value: impl Into<String>) -> Self {
+        self.before = Some(value.into());
+        self
+    }
+    pub fn after(mut self, value: impl Into<String>) -> Self {
+        self.after = Some(value.into());
+        self
+    }
+    pub fn first(mut self, value: i64) -> Self {
+        self.first = Some(value);
+        self
+    }
+    pub fn last(mut self, value: i64) -> Self {
+        self.last = Some(value);
+        self
+    }
+    pub fn include_archived(mut self, value: bool) -> Self {
+        self.include_archived = Some(value);
+        self
+    }
+    pub fn order_by(mut self, value: PaginationOrderBy) -> Self {
+        self.order_by = Some(value);
+        self
+    }
+    pub fn sort(mut self, value: CustomViewSortInput) -> Self {
+        self.sort = Some(value);
+        self
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the query without sending it, e.g. to add it to a
+    /// [`Batch`](crate::batch::Batch).
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        let mut map = self.extra_variables;
+        if let Some(ref v) = self.filter {
+            map.insert("filter".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.before {
+            map.insert("before".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.after {
+            map.insert("after".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.first {
+            map.insert("first".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.last {
+            map.insert("last".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.include_archived {
+            map.insert("includeArchived".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.order_by {
+            map.insert("orderBy".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.sort {
+            map.insert("sort".to_string(), serde_json::json!(v));
+        }
+        let variables = serde_json::Value::Object(map);
+        let selection = format!(
+            "{} {{ nodes {{ {} }} pageInfo {{ hasNextPage endCursor }} }}",
+            "customViews(filter: $filter, before: $before, after: $after, first: $first, last: $last, includeArchived: $includeArchived, orderBy: $orderBy, sort: $sort)",
+            T::selection()
+        );
+        Operation::query(
+            "CustomViews",
+            "$filter: CustomViewFilter, $before: String, $after: String, $first: Int, $last: Int, $includeArchived: Boolean, $orderBy: PaginationOrderBy, $sort: [CustomViewSortInput!]",
+            selection,
+            variables,
+            "customViews",
+        )
+    }
+    pub async fn send(self) -> Result<Connection<T>, LinearError> {
+        let client = self.client;
+        self.into_operation().execute(client).await
+    }
+    /// Stream every node across all pages, following `pageInfo.endCursor`.
+    ///
+    /// Starts after the cursor set via `.after()` (or at the beginning) and
+    /// uses `.first()` as the page size. `.before()` / `.last()` are ignored.
+    pub fn stream(self) -> impl futures::Stream<Item = Result<T, LinearError>> + 'a
+    where
+        T: 'a,
+    {
+        let after = self.after.clone();
+        crate::pagination::paginate(after, move |after| {
+            Self {
+                client: self.client,
+                filter: self.filter.clone(),
+                before: None,
+                after,
+                first: self.first,
+                last: None,
+                include_archived: self.include_archived,
+                order_by: self.order_by.clone(),
+                sort: self.sort.clone(),
+                extra_variables: self.extra_variables.clone(),
+                _marker: std::marker::PhantomData,
+            }
+            .send()
+        })
+    }
+    /// Fetch every node across all pages. See [`stream`](Self::stream).
+    pub async fn all(self) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(self.stream()).await
+    }
+    /// Fetch nodes across pages until `max` have been collected. Pages past
+    /// the one containing the `max`-th node are never requested.
+    pub async fn take_all(self, max: usize) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(futures::StreamExt::take(self.stream(), max)).await
+    }
+}
+/// Query builder: `INTERNAL` Suggests metadata (name, description, icon) for a view based on its filters using AI. Rate-limited to 30 requests per minute.
+///
+/// Full type: [`CustomViewSuggestionPayload`](super::types::CustomViewSuggestionPayload)
+///
+/// Use setter methods to configure optional parameters, then call
+/// [`.send()`](Self::send) to execute the query.
+///
+/// Requires the `views` feature.
+#[cfg(feature = "views")]
+#[must_use]
+pub struct CustomViewDetailsSuggestionQueryBuilder<'a, T> {
+    client: &'a Client,
+    filter: serde_json::Value,
+    model_name: Option<String>,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "views")]
+impl<
+        'a,
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::CustomViewSuggestionPayload>,
+    > CustomViewDetailsSuggestionQueryBuilder<'a, T>
+{
+    pub fn model_name(mut self, value: impl Into<String>) -> Self {
+        self.model_name = Some(value.into());
+        self
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the query without sending it, e.g. to add it to a
+    /// [`Batch`](crate::batch::Batch).
+    pub fn into_operation(self) -> Operation<T> {
+        let mut map = self.extra_variables;
+        map.insert("filter".to_string(), serde_json::json!(self.filter));
+        if let Some(ref v) = self.model_name {
+            map.insert("modelName".to_string(), serde_json::json!(v));
+        }
+        let variables = serde_json::Value::Object(map);
+        let selection = format!(
+            "{} {{ {} }}",
+            "customViewDetailsSuggestion(modelName: $modelName, filter: $filter)",
+            T::selection()
+        );
+        Operation::query(
+            "CustomViewDetailsSuggestion",
+            "$modelName: String, $filter: JSONObject!",
+            selection,
+            variables,
+            "customViewDetailsSuggestion",
+        )
+    }
+    pub async fn send(self) -> Result<T, LinearError> {
+        let client = self.client;
+        self.into_operation().execute(client).await
+    }
+}
+/// Query builder: All comments the user has access to in the workspace.
+///
+/// Full type: [`Comment`](super::types::Comment)
+///
+/// Use setter methods to configure optional parameters, then call
+/// [`.send()`](Self::send) to execute the query.
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+#[must_use]
+pub struct CommentsQueryBuilder<'a, T> {
+    client: &'a Client,
+    filter: Option<CommentFilter>,
+    before: Option<String>,
+    after: Option<String>,
+    first: Option<i64>,
+    last: Option<i64>,
+    include_archived: Option<bool>,
+    order_by: Option<PaginationOrderBy>,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "issues")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Comment>>
+    CommentsQueryBuilder<'a, T>
+{
+    pub fn filter(mut self, value: CommentFilter) -> Self {
+        self.filter = Some(value);
+        self
+    }
+    pub fn before(mut self, value: impl Into<String>) -> Self {
+        self.before = Some(value.into());
+        self
+    }
+    pub fn after(mut self, value: impl Into<String>) -> Self {
+        self.after = Some(value.into());
+        self
+    }
+    pub fn first(mut self, value: i64) -> Self {
+        self.first = Some(value);
+        self
+    }
+    pub fn last(mut self, value: i64) -> Self {
+        self.last = Some(value);
+        self
+    }
+    pub fn include_archived(mut self, value: bool) -> Self {
+        self.include_archived = Some(value);
+        self
+    }
+    pub fn order_by(mut self, value: PaginationOrderBy) -> Self {
+        self.order_by = Some(value);
+        self
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the query without sending it, e.g. to add it to a
+    /// [`Batch`](crate::batch::Batch).
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        let mut map = self.extra_variables;
+        if let Some(ref v) = self.filter {
+            map.insert("filter".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.before {
+            map.insert("before".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.after {
+            map.insert("after".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.first {
+            map.insert("first".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.last {
+            map.insert("last".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.include_archived {
+            map.insert("includeArchived".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.order_by {
+            map.insert("orderBy".to_string(), serde_json::json!(v));
+        }
+        let variables = serde_json::Value::Object(map);
+        let selection = format!(
+            "{} {{ nodes {{ {} }} pageInfo {{ hasNextPage endCursor }} }}",
+            "comments(filter: $filter, before: $before, after: $after, first: $first, last: $last, includeArchived: $includeArchived, orderBy: $orderBy)",
+            T::selection()
+        );
+        Operation::query(
+            "Comments",
+            "$filter: CommentFilter, $before: String, $after: String, $first: Int, $last: Int, $includeArchived: Boolean, $orderBy: PaginationOrderBy",
+            selection,
+            variables,
+            "comments",
+        )
+    }
+    pub async fn send(self) -> Result<Connection<T>, LinearError> {
+        let client = self.client;
+        self.into_operation().execute(client).await
+    }
+    /// Stream every node across all pages, following `pageInfo.endCursor`.
+    ///
+    /// Starts after the cursor set via `.after()` (or at the beginning) and
+    /// uses `.first()` as the page size. `.before()` / `.last()` are ignored.
+    pub fn stream(self) -> impl futures::Stream<Item = Result<T, LinearError>> + 'a
+    where
+        T: 'a,
+    {
+        let after = self.after.clone();
+        crate::pagination::paginate(after, move |after| {
+            Self {
+                client: self.client,
+                filter: self.filter.clone(),
+                before: None,
+                after,
+                first: self.first,
+                last: None,
+                include_archived: self.include_archived,
+                order_by: self.order_by.clone(),
+                extra_variables: self.extra_variables.clone(),
+                _marker: std::marker::PhantomData,
+            }
+            .send()
+        })
+    }
+    /// Fetch every node across all pages. See [`stream`](Self::stream).
+    pub async fn all(self) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(self.stream()).await
+    }
+    /// Fetch nodes across pages until `max` have been collected. Pages past
+    /// the one containing the `max`-th node are never requested.
+    pub async fn take_all(self, max: usize) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(futures::StreamExt::take(self.stream(), max)).await
+    }
+}
+/// Query builder: A specific comment.
+///
+/// Full type: [`Comment`](super::types::Comment)
+///
+/// Use setter methods to configure optional parameters, then call
+/// [`.send()`](Self::send) to execute the query.
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+#[must_use]
+pub struct CommentQueryBuilder<'a, T> {
+    client: &'a Client,
+    id: Option<String>,
+    hash: Option<String>,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "issues")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Comment>>
+    CommentQueryBuilder<'a, T>
+{
+    pub fn id(mut self, value: impl Into<String>) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+    pub fn hash(mut self, value: impl Into<String>) -> Self {
+        self.hash = Some(value.into());
+        self
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the query without sending it, e.g. to add it to a
+    /// [`Batch`](crate::batch::Batch).
+    pub fn into_operation(self) -> Operation<T> {
+        let mut map = self.extra_variables;
+        if let Some(ref v) = self.id {
+            map.insert("id".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.hash {
+            map.insert("hash".to_string(), serde_json::json!(v));
+        }
+        let variables = serde_json::Value::Object(map);
+        let selection = format!(
+            "{} {{ {} }}",
+            "comment(id: $id, hash: $hash)",
+            T::selection()
+        );
+        Operation::query(
+            "Comment",
+            "$id: String, $hash: String",
+            selection,
+            variables,
+            "comment",
+        )
+    }
+    pub async fn send(self) -> Result<T, LinearError> {
+        let client = self.client;
+        self.into_operation().execute(client).await
+    }
+}
+/// Query builder: Fetch SSO login URL for the email provided.
+///
+/// Full type: [`SsoUrlFromEmailResponse`](super::types::SsoUrlFromEmailResponse)
+///
+/// Use setter methods to configure optional parameters, then call
+/// [`.send()`](Self::send) to execute the query.
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+#[must_use]
+pub struct SsoUrlFromEmailQueryBuilder<'a, T> {
+    client: &'a Client,
+    r#type: IdentityProviderType,
+    email: String,
+    is_desktop: Option<bool>,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "admin")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::SsoUrlFromEmailResponse>>
+    SsoUrlFromEmailQueryBuilder<'a, T>
+{
+    pub fn is_desktop(mut self, value: bool) -> Self {
+        self.is_desktop = Some(value);
+        self
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the query without sending it, e.g. to add it to a
+    /// [`Batch`](crate::batch::Batch).
+    pub fn into_operation(self) -> Operation<T> {
+        let mut map = self.extra_variables;
+        map.insert("type".to_string(), serde_json::json!(self.r#type));
+        map.insert("email".to_string(), serde_json::json!(self.email));
+        if let Some(ref v) = self.is_desktop {
+            map.insert("isDesktop".to_string(), serde_json::json!(v));
+        }
+        let variables = serde_json::Value::Object(map);
+        let selection = format!(
+            "{} {{ {} }}",
+            "ssoUrlFromEmail(isDesktop: $isDesktop, type: $type, email: $email)",
+            T::selection()
+        );
+        Operation::query(
+            "SsoUrlFromEmail",
+            "$isDesktop: Boolean, $type: IdentityProviderType!, $email: String!",
+            selection,
+            variables,
+            "ssoUrlFromEmail",
+        )
+    }
+    pub async fn send(self) -> Result<T, LinearError> {
+        let client = self.client;
+        self.into_operation().execute(client).await
+    }
+}
+/// Query builder: All audit log entries.
+///
+/// Full type: [`AuditEntry`](super::types::AuditEntry)
+///
+/// Use setter methods to configure optional parameters, then call
+/// [`.send()`](Self::send) to execute the query.
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+#[must_use]
+pub struct AuditEntriesQueryBuilder<'a, T> {
+    client: &'a Client,
+    filter: Option<AuditEntryFilter>,
+    before: Option<String>,
+    after: Option<String>,
+    first: Option<i64>,
+    last: Option<i64>,
+    include_archived: Option<bool>,
+    order_by: Option<PaginationOrderBy>,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "admin")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::AuditEntry>>
+    AuditEntriesQueryBuilder<'a, T>
+{
+    pub fn filter(mut self, value: AuditEntryFilter) -> Self {
+        self.filter = Some(value);
+        self
+    }
+    pub fn before(mut self, value: impl Into<String>) -> Self {
+        self.before = Some(value.into());
+        self
+    }
+    pub fn after(mut self, value: impl Into<String>) -> Self {
+        self.after = Some(value.into());
+        self
+    }
+    pub fn first(mut self, value: i64) -> Self {
+        self.first = Some(value);
+        self
+    }
+    pub fn last(mut self, value: i64) -> Self {
+        self.last = Some(value);
+        self
+    }
+    pub fn include_archived(mut self, value: bool) -> Self {
+        self.include_archived = Some(value);
+        self
+    }
+    pub fn order_by(mut self, value: PaginationOrderBy) -> Self {
+        self.order_by = Some(value);
+        self
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the query without sending it, e.g. to add it to a
+    /// [`Batch`](crate::batch::Batch).
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        let mut map = self.extra_variables;
+        if let Some(ref v) = self.filter {
+            map.insert("filter".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.before {
+            map.insert("before".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.after {
+            map.insert("after".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.first {
+            map.insert("first".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.last {
+            map.insert("last".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.include_archived {
+            map.insert("includeArchived".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.order_by {
+            map.insert("orderBy".to_string(), serde_json::json!(v));
+        }
+        let variables = serde_json::Value::Object(map);
+        let selection = format!(
+            "{} {{ nodes {{ {} }} pageInfo {{ hasNextPage endCursor }} }}",
+            "auditEntries(filter: $filter, before: $before, after: $after, first: $first, last: $last, includeArchived: $includeArchived, orderBy: $orderBy)",
+            T::selection()
+        );
+        Operation::query(
+            "AuditEntries",
+            "$filter: AuditEntryFilter, $before: String, $after: String, $first: Int, $last: Int, $includeArchived: Boolean, $orderBy: PaginationOrderBy",
+            selection,
+            variables,
+            "auditEntries",
+        )
+    }
+    pub async fn send(self) -> Result<Connection<T>, LinearError> {
+        let client = self.client;
+        self.into_operation().execute(client).await
+    }
+    /// Stream every node across all pages, following `pageInfo.endCursor`.
+    ///
+    /// Starts after the cursor set via `.after()` (or at the beginning) and
+    /// uses `.first()` as the page size. `.before()` / `.last()` are ignored.
+    pub fn stream(self) -> impl futures::Stream<Item = Result<T, LinearError>> + 'a
+    where
+        T: 'a,
+    {
+        let after = self.after.clone();
+        crate::pagination::paginate(after, move |after| {
+            Self {
+                client: self.client,
+                filter: self.filter.clone(),
+                before: None,
+                after,
+                first: self.first,
+                last: None,
+                include_archived: self.include_archived,
+                order_by: self.order_by.clone(),
+                extra_variables: self.extra_variables.clone(),
+                _marker: std::marker::PhantomData,
+            }
+            .send()
+        })
+    }
+    /// Fetch every node across all pages. See [`stream`](Self::stream).
+    pub async fn all(self) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(self.stream()).await
+    }
+    /// Fetch nodes across pages until `max` have been collected. Pages past
+    /// the one containing the `max`-th node are never requested.
+    pub async fn take_all(self, max: usize) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(futures::StreamExt::take(self.stream(), max)).await
+    }
+}
+/** Query builder: All issue attachments.
+
+To get attachments for a given URL, use `attachmentsForURL` query.*/
+///
+/// Full type: [`Attachment`](super::types::Attachment)
+///
+/// Use setter methods to configure optional parameters, then call
+/// [`.send()`](Self::send) to execute the query.
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+#[must_use]
+pub struct AttachmentsQueryBuilder<'a, T> {
+    client: &'a Client,
+    filter: Option<AttachmentFilter>,
+    before: Option<String>,
+    after: Option<String>,
+    first: Option<i64>,
+    last: Option<i64>,
+    include_archived: Option<bool>,
+    order_by: Option<PaginationOrderBy>,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "issues")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Attachment>>
+    AttachmentsQueryBuilder<'a, T>
+{
+    pub fn filter(mut self, value: AttachmentFilter) -> Self {
+        self.filter = Some(value);
+        self
+    }
+    pub fn before(mut self, value: impl Into<String>) -> Self {
+        self.before = Some(value.into());
+        self
+    }
+    pub fn after(mut self, value: impl Into<String>) -> Self {
+        self.after = Some(value.into());
+        self
+    }
+    pub fn first(mut self, value: i64) -> Self {
+        self.first = Some(value);
+        self
+    }
+    pub fn last(mut self, value: i64) -> Self {
+        self.last = Some(value);
+        self
+    }
+    pub fn include_archived(mut self, value: bool) -> Self {
+        self.include_archived = Some(value);
+        self
+    }
+    pub fn order_by(mut self, value: PaginationOrderBy) -> Self {
+        self.order_by = Some(value);
+        self
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the query without sending it, e.g. to add it to a
+    /// [`Batch`](crate::batch::Batch).
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        let mut map = self.extra_variables;
+        if let Some(ref v) = self.filter {
+            map.insert("filter".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.before {
+            map.insert("before".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.after {
+            map.insert("after".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.first {
+            map.insert("first".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.last {
+            map.insert("last".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.include_archived {
+            map.insert("includeArchived".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.order_by {
+            map.insert("orderBy".to_string(), serde_json::json!(v));
+        }
+        let variables = serde_json::Value::Object(map);
+        let selection = format!(
+            "{} {{ nodes {{ {} }} pageInfo {{ hasNextPage endCursor }} }}",
+            "attachments(filter: $filter, before: $before, after: $after, first: $first, last: $last, includeArchived: $includeArchived, orderBy: $orderBy)",
+            T::selection()
+        );
+        Operation::query(
+            "Attachments",
+            "$filter: AttachmentFilter, $before: String, $after: String, $first: Int, $last: Int, $includeArchived: Boolean, $orderBy: PaginationOrderBy",
+            selection,
+            variables,
+            "attachments",
+        )
+    }
+    pub async fn send(self) -> Result<Connection<T>, LinearError> {
+        let client = self.client;
+        self.into_operation().execute(client).await
+    }
+    /// Stream every node across all pages, following `pageInfo.endCursor`.
+    ///
+    /// Starts after the cursor set via `.after()` (or at the beginning) and
+    /// uses `.first()` as the page size. `.before()` / `.last()` are ignored.
+    pub fn stream(self) -> impl futures::Stream<Item = Result<T, LinearError>> + 'a
+    where
+        T: 'a,
+    {
+        let after = self.after.clone();
+        crate::pagination::paginate(after, move |after| {
+            Self {
+                client: self.client,
+                filter: self.filter.clone(),
+                before: None,
+                after,
+                first: self.first,
+                last: None,
+                include_archived: self.include_archived,
+                order_by: self.order_by.clone(),
+                extra_variables: self.extra_variables.clone(),
+                _marker: std::marker::PhantomData,
+            }
+            .send()
+        })
+    }
+    /// Fetch every node across all pages. See [`stream`](Self::stream).
+    pub async fn all(self) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(self.stream()).await
+    }
+    /// Fetch nodes across pages until `max` have been collected. Pages past
+    /// the one containing the `max`-th node are never requested.
+    pub async fn take_all(self, max: usize) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(futures::StreamExt::take(self.stream(), max)).await
+    }
+}
+/// Query builder: Returns issue attachments for a given `url`.
+///
+/// Full type: [`Attachment`](super::types::Attachment)
+///
+/// Use setter methods to configure optional parameters, then call
+/// [`.send()`](Self::send) to execute the query.
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+#[must_use]
+pub struct AttachmentsForUrlQueryBuilder<'a, T> {
+    client: &'a Client,
+    url: String,
+    before: Option<String>,
+    after: Option<String>,
+    first: Option<i64>,
+    last: Option<i64>,
+    include_archived: Option<bool>,
+    order_by: Option<PaginationOrderBy>,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "issues")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Attachment>>
+    AttachmentsForUrlQueryBuilder<'a, T>
+{
+    pub fn before(mut self, value: impl Into<String>) -> Self {
+        self.before = Some(value.into());
+        self
+    }
+    pub fn after(mut self, value: impl Into<String>) -> Self {
+        self.after = Some(value.into());
+        self
+    }
+    pub fn first(mut self, value: i64) -> Self {
+        self.first = Some(value);
+        self
+    }
+    pub fn last(mut self, value: i64) -> Self {
+        self.last = Some(value);
+        self
+    }
+    pub fn include_archived(mut self, value: bool) -> Self {
+        self.include_archived = Some(value);
+        self
+    }
+    pub fn order_by(mut self, value: PaginationOrderBy) -> Self {
+        self.order_by = Some(value);
+        self
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the query without sending it, e.g. to add it to a
+    /// [`Batch`](crate::batch::Batch).
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        let mut map = self.extra_variables;
+        map.insert("url".to_string(), serde_json::json!(self.url));
+        if let Some(ref v) = self.before {
+            map.insert("before".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.after {
+            map.insert("after".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.first {
+            map.insert("first".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.last {
+            map.insert("last".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.include_archived {
+            map.insert("includeArchived".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.order_by {
+            map.insert("orderBy".to_string(), serde_json::json!(v));
+        }
+        let variables = serde_json::Value::Object(map);
+        let selection = format!(
+            "{} {{ nodes {{ {} }} pageInfo {{ hasNextPage endCursor }} }}",
+            "attachmentsForURL(before: $before, after: $after, first: $first, last: $last, includeArchived: $includeArchived, orderBy: $orderBy, url: $url)",
+            T::selection()
+        );
+        Operation::query(
+            "AttachmentsForUrl",
+            "$before: String, $after: String, $first: Int, $last: Int, $includeArchived: Boolean, $orderBy: PaginationOrderBy, $url: String!",
+            selection,
+            variables,
+            "attachmentsForURL",
+        )
+    }
+    pub async fn send(self) -> Result<Connection<T>, LinearError> {
+        let client = self.client;
+        self.into_operation().execute(client).await
+    }
+    /// Stream every node across all pages, following `pageInfo.endCursor`.
+    ///
+    /// Starts after the cursor set via `.after()` (or at the beginning) and
+    /// uses `.first()` as the page size. `.before()` / `.last()` are ignored.
+    pub fn stream(self) -> impl futures::Stream<Item = Result<T, LinearError>> + 'a
+    where
+        T: 'a,
+    {
+        let after = self.after.clone();
+        crate::pagination::paginate(after, move |after| {
+            Self {
+                client: self.client,
+                url: self.url.clone(),
+                before: None,
+                after,
+                first: self.first,
+                last: None,
+                include_archived: self.include_archived,
+                order_by: self.order_by.clone(),
+                extra_variables: self.extra_variables.clone(),
+                _marker: std::marker::PhantomData,
+            }
+            .send()
+        })
+    }
+    /// Fetch every node across all pages. See [`stream`](Self::stream).
+    pub async fn all(self) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(self.stream()).await
+    }
+    /// Fetch nodes across pages until `max` have been collected. Pages past
+    /// the one containing the `max`-th node are never requested.
+    pub async fn take_all(self, max: usize) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(futures::StreamExt::take(self.stream(), max)).await
+    }
+}
+/// Query builder: `Internal` Get a list of all unique attachment sources in the workspace.
+///
+/// Full type: [`AttachmentSourcesPayload`](super::types::AttachmentSourcesPayload)
+///
+/// Use setter methods to configure optional parameters, then call
+/// [`.send()`](Self::send) to execute the query.
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+#[must_use]
+pub struct AttachmentSourcesQueryBuilder<'a, T> {
+    client: &'a Client,
+    team_id: Option<String>,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "issues")]
+impl<
+        'a,
+        T: DeserializeOwned + GraphQLFields<FullType = super::types::AttachmentSourcesPayload>,
+    > AttachmentSourcesQueryBuilder<'a, T>
+{
+    pub fn team_id(mut self, value: impl Into<String>) -> Self {
+        self.team_id = Some(value.into());
+        self
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the query without sending it, e.g. to add it to a
+    /// [`Batch`](crate::batch::Batch).
+    pub fn into_operation(self) -> Operation<T> {
+        let mut map = self.extra_variables;
+        if let Some(ref v) = self.team_id {
+            map.insert("teamId".to_string(), serde_json::json!(v));
+        }
+        let variables = serde_json::Value::Object(map);
+        let selection = format!(
+            "{} {{ {} }}",
+            "attachmentSources(teamId: $teamId)",
+            T::selection()
+        );
+        Operation::query(
+            "AttachmentSources",
+            "$teamId: String",
+            selection,
+            variables,
+            "attachmentSources",
+        )
+    }
+    pub async fn send(self) -> Result<T, LinearError> {
+        let client = self.client;
+        self.into_operation().execute(client).await
+    }
+}
+/// Query builder: All agent sessions.
+///
+/// Full type: [`AgentSession`](super::types::AgentSession)
+///
+/// Use setter methods to configure optional parameters, then call
+/// [`.send()`](Self::send) to execute the query.
+///
+/// Requires the `agents` feature.
+#[cfg(feature = "agents")]
+#[must_use]
+pub struct AgentSessionsQueryBuilder<'a, T> {
+    client: &'a Client,
+    before: Option<String>,
+    after: Option<String>,
+    first: Option<i64>,
+    last: Option<i64>,
+    include_archived: Option<bool>,
+    order_by: Option<PaginationOrderBy>,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "agents")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::AgentSession>>
+    AgentSessionsQueryBuilder<'a, T>
+{
+    pub fn before(mut self, value: impl Into<String>) -> Self {
+        self.before = Some(value.into());
+        self
+    }
+    pub fn after(mut self, value: impl Into<String>) -> Self {
+        self.after = Some(value.into());
+        self
+    }
+    pub fn first(mut self, value: i64) -> Self {
+        self.first = Some(value);
+        self
+    }
+    pub fn last(mut self, value: i64) -> Self {
+        self.last = Some(value);
+        self
+    }
+    pub fn include_archived(mut self, value: bool) -> Self {
+        self.include_archived = Some(value);
+        self
+    }
+    pub fn order_by(mut self, value: PaginationOrderBy) -> Self {
+        self.order_by = Some(value);
+        self
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the query without sending it, e.g. to add it to a
+    /// [`Batch`](crate::batch::Batch).
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        let mut map = self.extra_variables;
+        if let Some(ref v) = self.before {
+            map.insert("before".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.after {
+            map.insert("after".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.first {
+            map.insert("first".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.last {
+            map.insert("last".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.include_archived {
+            map.insert("includeArchived".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.order_by {
+            map.insert("orderBy".to_string(), serde_json::json!(v));
+        }
+        let variables = serde_json::Value::Object(map);
+        let selection = format!(
+            "{} {{ nodes {{ {} }} pageInfo {{ hasNextPage endCursor }} }}",
+            "agentSessions(before: $before, after: $after, first: $first, last: $last, includeArchived: $includeArchived, orderBy: $orderBy)",
+            T::selection()
+        );
+        Operation::query(
+            "AgentSessions",
+            "$before: String, $after: String, $first: Int, $last: Int, $includeArchived: Boolean, $orderBy: PaginationOrderBy",
+            selection,
+            variables,
+            "agentSessions",
+        )
+    }
+    pub async fn send(self) -> Result<Connection<T>, LinearError> {
+        let client = self.client;
+        self.into_operation().execute(client).await
+    }
+    /// Stream every node across all pages, following `pageInfo.endCursor`.
+    ///
+    /// Starts after the cursor set via `.after()` (or at the beginning) and
+    /// uses `.first()` as the page size. `.before()` / `.last()` are ignored.
+    pub fn stream(self) -> impl futures::Stream<Item = Result<T, LinearError>> + 'a
+    where
+        T: 'a,
+    {
+        let after = self.after.clone();
+        crate::pagination::paginate(after, move |after| {
+            Self {
+                client: self.client,
+                before: None,
+                after,
+                first: self.first,
+                last: None,
+                include_archived: self.include_archived,
+                order_by: self.order_by.clone(),
+                extra_variables: self.extra_variables.clone(),
+                _marker: std::marker::PhantomData,
+            }
+            .send()
+        })
+    }
+    /// Fetch every node across all pages. See [`stream`](Self::stream).
+    pub async fn all(self) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(self.stream()).await
+    }
+    /// Fetch nodes across pages until `max` have been collected. Pages past
+    /// the one containing the `max`-th node are never requested.
+    pub async fn take_all(self, max: usize) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(futures::StreamExt::take(self.stream(), max)).await
+    }
+}
+/// Query builder: All agent activities.
+///
+/// Full type: [`AgentActivity`](super::types::AgentActivity)
+///
+/// Use setter methods to configure optional parameters, then call
+/// [`.send()`](Self::send) to execute the query.
+///
+/// Requires the `agents` feature.
+#[cfg(feature = "agents")]
+#[must_use]
+pub struct AgentActivitiesQueryBuilder<'a, T> {
+    client: &'a Client,
+    filter: Option<AgentActivityFilter>,
+    before: Option<String>,
+    after: Option<String>,
+    first: Option<i64>,
+    last: Option<i64>,
+    include_archived: Option<bool>,
+    order_by: Option<PaginationOrderBy>,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "agents")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::AgentActivity>>
+    AgentActivitiesQueryBuilder<'a, T>
+{
+    pub fn filter(mut self, value: AgentActivityFilter) -> Self {
+        self.filter = Some(value);
+        self
+    }
+    pub fn before(mut self, value: impl Into<String>) -> Self {
+        self.before = Some(value.into());
+        self
+    }
+    pub fn after(mut self, value: impl Into<String>) -> Self {
+        self.after = Some(value.into());
+        self
+    }
+    pub fn first(mut self, value: i64) -> Self {
+        self.first = Some(value);
+        self
+    }
+    pub fn last(mut self, value: i64) -> Self {
+        self.last = Some(value);
+        self
+    }
+    pub fn include_archived(mut self, value: bool) -> Self {
+        self.include_archived = Some(value);
+        self
+    }
+    pub fn order_by(mut self, value: PaginationOrderBy) -> Self {
+        self.order_by = Some(value);
+        self
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the query without sending it, e.g. to add it to a
+    /// [`Batch`](crate::batch::Batch).
+    pub fn into_operation(self) -> Operation<Connection<T>> {
+        let mut map = self.extra_variables;
+        if let Some(ref v) = self.filter {
+            map.insert("filter".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.before {
+            map.insert("before".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.after {
+            map.insert("after".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.first {
+            map.insert("first".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.last {
+            map.insert("last".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.include_archived {
+            map.insert("includeArchived".to_string(), serde_json::json!(v));
+        }
+        if let Some(ref v) = self.order_by {
+            map.insert("orderBy".to_string(), serde_json::json!(v));
+        }
+        let variables = serde_json::Value::Object(map);
+        let selection = format!(
+            "{} {{ nodes {{ {} }} pageInfo {{ hasNextPage endCursor }} }}",
+            "agentActivities(filter: $filter, before: $before, after: $after, first: $first, last: $last, includeArchived: $includeArchived, orderBy: $orderBy)",
+            T::selection()
+        );
+        Operation::query(
+            "AgentActivities",
+            "$filter: AgentActivityFilter, $before: String, $after: String, $first: Int, $last: Int, $includeArchived: Boolean, $orderBy: PaginationOrderBy",
+            selection,
+            variables,
+            "agentActivities",
+        )
+    }
+    pub async fn send(self) -> Result<Connection<T>, LinearError> {
+        let client = self.client;
+        self.into_operation().execute(client).await
+    }
+    /// Stream every node across all pages, following `pageInfo.endCursor`.
+    ///
+    /// Starts after the cursor set via `.after()` (or at the beginning) and
+    /// uses `.first()` as the page size. `.before()` / `.last()` are ignored.
+    pub fn stream(self) -> impl futures::Stream<Item = Result<T, LinearError>> + 'a
+    where
+        T: 'a,
+    {
+        let after = self.after.clone();
+        crate::pagination::paginate(after, move |after| {
+            Self {
+                client: self.client,
+                filter: self.filter.clone(),
+                before: None,
+                after,
+                first: self.first,
+                last: None,
+                include_archived: self.include_archived,
+                order_by: self.order_by.clone(),
+                extra_variables: self.extra_variables.clone(),
+                _marker: std::marker::PhantomData,
+            }
+            .send()
+        })
+    }
+    /// Fetch every node across all pages. See [`stream`](Self::stream).
+    pub async fn all(self) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(self.stream()).await
+    }
+    /// Fetch nodes across pages until `max` have been collected. Pages past
+    /// the one containing the `max`-th node are never requested.
+    pub async fn take_all(self, max: usize) -> Result<Vec<T>, LinearError>
+    where
+        T: 'a,
+    {
+        futures::TryStreamExt::try_collect(futures::StreamExt::take(self.stream(), max)).await
+    }
+}
+/// All issue workflow states (issue statuses). Returns a paginated list of workflow states visible to the authenticated user, across all teams they have access to.
+///
+/// Full type: [`WorkflowState`](super::types::WorkflowState)
+pub fn workflow_states<'a, T>(client: &'a Client) -> WorkflowStatesQueryBuilder<'a, T> {
+    WorkflowStatesQueryBuilder {
+        client,
+        filter: None,
+        before: None,
+        after: None,
+        first: None,
+        last: None,
+        include_archived: None,
+        order_by: None,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// One specific workflow state (issue status), looked up by its unique identifier.
+///
+/// Full type: [`WorkflowState`](super::types::WorkflowState)
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+pub async fn workflow_state<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::WorkflowState>,
+>(
+    client: &Client,
+    id: impl Into<super::ids::WorkflowStateId>,
+) -> Result<T, LinearError> {
+    super::operations::workflow_state::<T>(id)
+        .execute(client)
+        .await
+}
+/// All webhooks for the current workspace.
+///
+/// Full type: [`Webhook`](super::types::Webhook)
+///
+/// Requires the `webhooks` feature.
+#[cfg(feature = "webhooks")]
+pub fn webhooks<'a, T>(client: &'a Client) -> WebhooksQueryBuilder<'a, T> {
+    WebhooksQueryBuilder {
+        client,
+        before: None,
+        after: None,
+        first: None,
+        last: None,
+        include_archived: None,
+        order_by: None,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Retrieves a single webhook by its identifier.
+///
+/// Full type: [`Webhook`](super::types::Webhook)
+///
+/// Requires the `webhooks` feature.
+#[cfg(feature = "webhooks")]
+pub async fn webhook<T: DeserializeOwned + GraphQLFields<FullType = super::types::Webhook>>(
+    client: &Client,
+    id: impl Into<super::ids::WebhookId>,
+) -> Result<T, LinearError> {
+    super::operations::webhook::<T>(id).execute(client).await
+}
+/// `INTERNAL` Webhook failure events for webhooks that belong to an OAuth application. (last 50)
+///
+/// Full type: [`WebhookFailureEvent`](super::types::WebhookFailureEvent)
+///
+/// Requires the `webhooks` feature.
+#[cfg(feature = "webhooks")]
+pub async fn failures_for_oauth_webhooks<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::WebhookFailureEvent>,
+>(
+    client: &Client,
+    oauth_client_id: String,
+) -> Result<Vec<T>, LinearError> {
+    super::operations::failures_for_oauth_webhooks::<T>(oauth_client_id)
+        .execute(client)
+        .await
+}
+/// The authenticated user's notification and UI settings.
+///
+/// Full type: [`UserSettings`](super::types::UserSettings)
+///
+/// Requires the `users` feature.
+#[cfg(feature = "users")]
+pub async fn user_settings<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::UserSettings>,
+>(
+    client: &Client,
+) -> Result<T, LinearError> {
+    super::operations::user_settings::<T>()
+        .execute(client)
+        .await
+}
+/// All users in the workspace. Supports filtering, sorting, and pagination.
+///
+/// Full type: [`User`](super::types::User)
+pub fn users<'a, T>(client: &'a Client) -> UsersQueryBuilder<'a, T> {
+    UsersQueryBuilder {
+        client,
+        filter: None,
+        include_disabled: None,
+        before: None,
+        after: None,
+        first: None,
+        last: None,
+        include_archived: None,
+        order_by: None,
+        sort: None,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Fetches a specific user by their ID.
+///
+/// Full type: [`User`](super::types::User)
+///
+/// Requires the `users` feature.
+#[cfg(feature = "users")]
+pub async fn user<T: DeserializeOwned + GraphQLFields<FullType = super::types::User>>(
+    client: &Client,
+    id: impl Into<super::ids::UserId>,
+) -> Result<T, LinearError> {
+    super::operations::user::<T>(id).execute(client).await
+}
+/// The currently authenticated user making the API request.
+///
+/// Full type: [`User`](super::types::User)
+pub async fn whoami<T: DeserializeOwned + GraphQLFields<FullType = super::types::User>>(
+    client: &Client,
+) -> Result<T, LinearError> {
+    super::operations::whoami::<T>().execute(client).await
+}
+/// Lists all active authentication sessions for a user. Can only be called by a workspace admin or owner.
+///
+/// Full type: [`AuthenticationSessionResponse`](super::types::AuthenticationSessionResponse)
+///
+/// Requires the `users` feature.
+#[cfg(feature = "users")]
+pub async fn user_sessions<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::AuthenticationSessionResponse>,
+>(
+    client: &Client,
+    id: impl Into<super::ids::AuthenticationSessionResponseId>,
+) -> Result<Vec<T>, LinearError> {
+    super::operations::user_sessions::<T>(id)
+        .execute(client)
+        .await
+}
+/// All triage responsibilities.
+///
+/// Full type: [`TriageResponsibility`](super::types::TriageResponsibility)
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+pub fn triage_responsibilities<'a, T>(
+    client: &'a Client,
+) -> TriageResponsibilitiesQueryBuilder<'a, T> {
+    TriageResponsibilitiesQueryBuilder {
+        client,
+        before: None,
+        after: None,
+        first: None,
+        last: None,
+        include_archived: None,
+        order_by: None,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// A specific triage responsibility.
+///
+/// Full type: [`TriageResponsibility`](super::types::TriageResponsibility)
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+pub async fn triage_responsibility<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::TriageResponsibility>,
+>(
+    client: &Client,
+    id: impl Into<super::ids::TriageResponsibilityId>,
+) -> Result<T, LinearError> {
+    super::operations::triage_responsibility::<T>(id)
+        .execute(client)
+        .await
+}
+/// All time schedules.
+///
+/// Full type: [`TimeSchedule`](super::types::TimeSchedule)
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+pub fn time_schedules<'a, T>(client: &'a Client) -> TimeSchedulesQueryBuilder<'a, T> {
+    TimeSchedulesQueryBuilder {
+        client,
+        before: None,
+        after: None,
+        first: None,
+        last: None,
+        include_archived: None,
+        order_by: None,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// A specific time schedule.
+///
+/// Full type: [`TimeSchedule`](super::types::TimeSchedule)
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+pub async fn time_schedule<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::TimeSchedule>,
+>(
+    client: &Client,
+    id: impl Into<super::ids::TimeScheduleId>,
+) -> Result<T, LinearError> {
+    super::operations::time_schedule::<T>(id)
+        .execute(client)
+        .await
+}
+/// All templates in the workspace, including both team-scoped and workspace-level templates.
+///
+/// Full type: [`Template`](super::types::Template)
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+pub async fn templates<T: DeserializeOwned + GraphQLFields<FullType = super::types::Template>>(
+    client: &Client,
+) -> Result<Vec<T>, LinearError> {
+    super::operations::templates::<T>().execute(client).await
+}
+/// A specific template.
+///
+/// Full type: [`Template`](super::types::Template)
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+pub async fn template<T: DeserializeOwned + GraphQLFields<FullType = super::types::Template>>(
+    client: &Client,
+    id: impl Into<super::ids::TemplateId>,
+) -> Result<T, LinearError> {
+    super::operations::template::<T>(id).execute(client).await
+}
+/// Returns all templates that are associated with the integration type.
+///
+/// Full type: [`Template`](super::types::Template)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub async fn templates_for_integration<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::Template>,
+>(
+    client: &Client,
+    integration_type: String,
+) -> Result<Vec<T>, LinearError> {
+    super::operations::templates_for_integration::<T>(integration_type)
+        .execute(client)
+        .await
+}
+/// Returns all projects in the workspace, with optional filtering and sorting.
+///
+/// Full type: [`Project`](super::types::Project)
+pub fn projects<'a, T>(client: &'a Client) -> ProjectsQueryBuilder<'a, T> {
+    ProjectsQueryBuilder {
+        client,
+        filter: None,
+        before: None,
+        after: None,
+        first: None,
+        last: None,
+        include_archived: None,
+        order_by: None,
+        sort: None,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Returns a single project by its identifier or URL slug.
+///
+/// Full type: [`Project`](super::types::Project)
+pub async fn project<T: DeserializeOwned + GraphQLFields<FullType = super::types::Project>>(
+    client: &Client,
+    id: impl Into<super::ids::ProjectId>,
+) -> Result<T, LinearError> {
+    super::operations::project::<T>(id).execute(client).await
+}
+/// Suggests filters for a project view based on a text prompt.
+///
+/// Full type: [`ProjectFilterSuggestionPayload`](super::types::ProjectFilterSuggestionPayload)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub fn project_filter_suggestion<'a, T>(
+    client: &'a Client,
+    prompt: impl Into<String>,
+) -> ProjectFilterSuggestionQueryBuilder<'a, T> {
+    ProjectFilterSuggestionQueryBuilder {
+        client,
+        prompt: prompt.into(),
+        team_id: None,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// All teams whose issues the user can access. This includes public teams and private teams the user is a member of. This may differ from `administrableTeams`, which returns teams whose settings the user can change but whose issues they don't necessarily have access to.
+///
+/// Full type: [`Team`](super::types::Team)
+pub fn teams<'a, T>(client: &'a Client) -> TeamsQueryBuilder<'a, T> {
+    TeamsQueryBuilder {
+        client,
+        filter: None,
+        before: None,
+        after: None,
+        first: None,
+        last: None,
+        include_archived: None,
+        order_by: None,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// All teams whose settings the user can administer. This includes teams the user can change settings for, but whose issues they may not have direct access to. This differs from the `teams` query which returns teams based on issue access.
+///
+/// Full type: [`Team`](super::types::Team)
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+pub fn administrable_teams<'a, T>(client: &'a Client) -> AdministrableTeamsQueryBuilder<'a, T> {
+    AdministrableTeamsQueryBuilder {
+        client,
+        filter: None,
+        before: None,
+        after: None,
+        first: None,
+        last: None,
+        include_archived: None,
+        order_by: None,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Fetches a specific team by its ID.
+///
+/// Full type: [`Team`](super::types::Team)
+pub async fn team<T: DeserializeOwned + GraphQLFields<FullType = super::types::Team>>(
+    client: &Client,
+    id: impl Into<super::ids::TeamId>,
+) -> Result<T, LinearError> {
+    super::operations::team::<T>(id).execute(client).await
+}
+/// All team memberships in the workspace.
+///
+/// Full type: [`TeamMembership`](super::types::TeamMembership)
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+pub fn team_memberships<'a, T>(client: &'a Client) -> TeamMembershipsQueryBuilder<'a, T> {
+    TeamMembershipsQueryBuilder {
+        client,
+        before: None,
+        after: None,
+        first: None,
+        last: None,
+        include_archived: None,
+        order_by: None,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Fetches a specific team membership by its ID.
+///
+/// Full type: [`TeamMembership`](super::types::TeamMembership)
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+pub async fn team_membership<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::TeamMembership>,
+>(
+    client: &Client,
+    id: impl Into<super::ids::TeamMembershipId>,
+) -> Result<T, LinearError> {
+    super::operations::team_membership::<T>(id)
+        .execute(client)
+        .await
+}
+/// Search for issues, projects, initiatives, and documents using natural language. Uses vector-based semantic search with optional full-text search and reranking. Results can be filtered by type and by entity-specific filters. Rate-limited to 30 requests per minute.
+///
+/// Full type: [`SemanticSearchPayload`](super::types::SemanticSearchPayload)
+///
+/// Requires the `agents` feature.
+#[cfg(feature = "agents")]
+pub fn semantic_search<'a, T>(
+    client: &'a Client,
+    query: impl Into<String>,
+) -> SemanticSearchQueryBuilder<'a, T> {
+    SemanticSearchQueryBuilder {
+        client,
+        query: query.into(),
+        types: None,
+        max_results: None,
+        include_archived: None,
+        filters: None,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Search documents by text query using full-text and vector search. Results are ranked by relevance unless an orderBy parameter is specified. Rate-limited to 30 requests per minute.
+///
+/// Full type: [`DocumentSearchResult`](super::types::DocumentSearchResult)
+///
+/// Requires the `documents` feature.
+#[cfg(feature = "documents")]
+pub fn search_documents<'a, T>(
+    client: &'a Client,
+    term: impl Into<String>,
+) -> SearchDocumentsQueryBuilder<'a, T> {
+    SearchDocumentsQueryBuilder {
+        client,
+        term: term.into(),
+        before: None,
+        after: None,
+        first: None,
+        last: None,
+        include_archived: None,
+        order_by: None,
+        include_comments: None,
+        team_id: None,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Search projects by text query using full-text and vector search. Results are ranked by relevance unless an orderBy parameter is specified. Rate-limited to 30 requests per minute.
+///
+/// Full type: [`ProjectSearchResult`](super::types::ProjectSearchResult)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub fn search_projects<'a, T>(
+    client: &'a Client,
+    term: impl Into<String>,
+) -> SearchProjectsQueryBuilder<'a, T> {
+    SearchProjectsQueryBuilder {
+        client,
+        term: term.into(),
+        before: None,
+        after: None,
+        first: None,
+        last: None,
+        include_archived: None,
+        order_by: None,
+        include_comments: None,
+        team_id: None,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Search issues by text query using full-text and vector search. Results are ranked by relevance unless an orderBy parameter is specified. Supports optional issue filters and comment inclusion. Rate-limited to 30 requests per minute.
+///
+/// Full type: [`IssueSearchResult`](super::types::IssueSearchResult)
+pub fn search_issues<'a, T>(
+    client: &'a Client,
+    term: impl Into<String>,
+) -> SearchIssuesQueryBuilder<'a, T> {
+    SearchIssuesQueryBuilder {
+        client,
+        term: term.into(),
+        filter: None,
+        before: None,
+        after: None,
+        first: None,
+        last: None,
+        include_archived: None,
+        order_by: None,
+        include_comments: None,
+        team_id: None,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// `Deprecated` Returns all roadmap-to-project associations. Use InitiativeToProject instead.
+///
+/// Full type: [`RoadmapToProject`](super::types::RoadmapToProject)
+#[deprecated(note = "RoadmapToProject is deprecated, use InitiativeToProject instead.")]
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub fn roadmap_to_projects<'a, T>(client: &'a Client) -> RoadmapToProjectsQueryBuilder<'a, T> {
+    RoadmapToProjectsQueryBuilder {
+        client,
+        before: None,
+        after: None,
+        first: None,
+        last: None,
+        include_archived: None,
+        order_by: None,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// `Deprecated` Returns a single roadmap-to-project association. Use InitiativeToProject instead.
+///
+/// Full type: [`RoadmapToProject`](super::types::RoadmapToProject)
+#[deprecated(note = "RoadmapToProject is deprecated, use InitiativeToProject instead.")]
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub async fn roadmap_to_project<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::RoadmapToProject>,
+>(
+    client: &Client,
+    id: impl Into<super::ids::RoadmapToProjectId>,
+) -> Result<T, LinearError> {
+    super::operations::roadmap_to_project::<T>(id)
+        .execute(client)
+        .await
+}
+/// `Deprecated` Returns all roadmaps in the workspace. Use initiatives instead.
+///
+/// Full type: [`Roadmap`](super::types::Roadmap)
+#[deprecated(note = "Roadmaps are deprecated, use initiatives instead.")]
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub fn roadmaps<'a, T>(client: &'a Client) -> RoadmapsQueryBuilder<'a, T> {
+    RoadmapsQueryBuilder {
+        client,
+        before: None,
+        after: None,
+        first: None,
+        last: None,
+        include_archived: None,
+        order_by: None,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// `Deprecated` Returns a single roadmap by its identifier. Use initiatives instead.
+///
+/// Full type: [`Roadmap`](super::types::Roadmap)
+#[deprecated(note = "Roadmaps are deprecated, use initiatives instead.")]
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub async fn roadmap<T: DeserializeOwned + GraphQLFields<FullType = super::types::Roadmap>>(
+    client: &Client,
+    id: impl Into<super::ids::RoadmapId>,
+) -> Result<T, LinearError> {
+    super::operations::roadmap::<T>(id).execute(client).await
+}
+/// `ALPHA` All release stages in the workspace, with optional filtering.
+///
+/// Full type: [`ReleaseStage`](super::types::ReleaseStage)
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+pub fn release_stages<'a, T>(client: &'a Client) -> ReleaseStagesQueryBuilder<'a, T> {
+    ReleaseStagesQueryBuilder {
+        client,
+        filter: None,
+        before: None,
+        after: None,
+        first: None,
+        last: None,
+        include_archived: None,
+        order_by: None,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// `ALPHA` Fetch a single release stage by its UUID.
+///
+/// Full type: [`ReleaseStage`](super::types::ReleaseStage)
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+pub async fn release_stage<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::ReleaseStage>,
+>(
+    client: &Client,
+    id: impl Into<super::ids::ReleaseStageId>,
+) -> Result<T, LinearError> {
+    super::operations::release_stage::<T>(id)
+        .execute(client)
+        .await
+}
+/// `ALPHA` All release pipelines in the workspace, with optional filtering and sorting.
+///
+/// Full type: [`ReleasePipeline`](super::types::ReleasePipeline)
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+pub fn release_pipelines<'a, T>(client: &'a Client) -> ReleasePipelinesQueryBuilder<'a, T> {
+    ReleasePipelinesQueryBuilder {
+        client,
+        filter: None,
+        before: None,
+        after: None,
+        first: None,
+        last: None,
+        include_archived: None,
+        order_by: None,
+        sort: None,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// `ALPHA` Fetch a single release pipeline by its UUID or slug identifier.
+///
+/// Full type: [`ReleasePipeline`](super::types::ReleasePipeline)
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+pub async fn release_pipeline<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::ReleasePipeline>,
+>(
+    client: &Client,
+    id: impl Into<super::ids::ReleasePipelineId>,
+) -> Result<T, LinearError> {
+    super::operations::release_pipeline::<T>(id)
+        .execute(client)
+        .await
+}
+/// `ALPHA` Returns a release pipeline by ID. Requires the access key to have access to the pipeline.
+///
+/// Full type: [`ReleasePipeline`](super::types::ReleasePipeline)
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+pub async fn release_pipeline_by_access_key<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::ReleasePipeline>,
+>(
+    client: &Client,
+) -> Result<T, LinearError> {
+    super::operations::release_pipeline_by_access_key::<T>()
+        .execute(client)
+        .await
+}
+/// `ALPHA` Returns the latest release for the pipeline associated with the access key.
+///
+/// Full type: [`Release`](super::types::Release)
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+pub async fn latest_release_by_access_key<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::Release>,
+>(
+    client: &Client,
+) -> Result<Option<T>, LinearError> {
+    super::operations::latest_release_by_access_key::<T>()
+        .execute(client)
+        .await
+}
+/// `ALPHA` All releases in the workspace, with optional filtering and sorting.
+///
+/// Full type: [`Release`](super::types::Release)
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+pub fn releases<'a, T>(client: &'a Client) -> ReleasesQueryBuilder<'a, T> {
+    ReleasesQueryBuilder {
+        client,
+        filter: None,
+        before: None,
+        after: None,
+        first: None,
+        last: None,
+        include_archived: None,
+        order_by: None,
+        sort: None,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// `ALPHA` Fetch a single release by its UUID or slug identifier.
+///
+/// Full type: [`Release`](super::types::Release)
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+pub async fn release<T: DeserializeOwned + GraphQLFields<FullType = super::types::Release>>(
+    client: &Client,
+    id: impl Into<super::ids::ReleaseId>,
+) -> Result<T, LinearError> {
+    super::operations::release::<T>(id).execute(client).await
+}
+/// `ALPHA` Search releases with optional text matching against name, version, and pipeline name. When no search term is provided, returns releases ordered by stage priority (started > planned > completed > canceled).
+///
+/// Full type: [`Release`](super::types::Release)
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+pub fn release_search<'a, T>(client: &'a Client) -> ReleaseSearchQueryBuilder<'a, T> {
+    ReleaseSearchQueryBuilder {
+        client,
+        filter: None,
+        first: None,
+        term: None,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// `ALPHA` Release notes in the workspace.
+///
+/// Full type: [`ReleaseNote`](super::types::ReleaseNote)
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+pub fn release_notes<'a, T>(client: &'a Client) -> ReleaseNotesQueryBuilder<'a, T> {
+    ReleaseNotesQueryBuilder {
+        client,
+        before: None,
+        after: None,
+        first: None,
+        last: None,
+        include_archived: None,
+        order_by: None,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// `ALPHA` Fetch a release note by its identifier.
+///
+/// Full type: [`ReleaseNote`](super::types::ReleaseNote)
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+pub async fn release_note<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::ReleaseNote>,
+>(
+    client: &Client,
+    id: impl Into<super::ids::ReleaseNoteId>,
+) -> Result<T, LinearError> {
+    super::operations::release_note::<T>(id)
+        .execute(client)
+        .await
+}
+/// The current rate limit status for the authenticated client, including remaining quota and reset timing for each limit type.
+///
+/// Full type: [`RateLimitPayload`](super::types::RateLimitPayload)
+pub async fn rate_limit_status<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::RateLimitPayload>,
+>(
+    client: &Client,
+) -> Result<T, LinearError> {
+    super::operations::rate_limit_status::<T>()
+        .execute(client)
+        .await
+}
+/// Sends a test push notification to the authenticated user's registered devices. Useful for verifying that push notification delivery is working correctly.
+///
+/// Full type: [`PushSubscriptionTestPayload`](super::types::PushSubscriptionTestPayload)
+///
+/// Requires the `notifications` feature.
+#[cfg(feature = "notifications")]
+pub fn push_subscription_test<'a, T>(
+    client: &'a Client,
+) -> PushSubscriptionTestQueryBuilder<'a, T> {
+    PushSubscriptionTestQueryBuilder {
+        client,
+        target_mobile: None,
+        send_strategy: None,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Returns all project status updates in the workspace, with optional filtering.
+///
+/// Full type: [`ProjectUpdate`](super::types::ProjectUpdate)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub fn project_updates<'a, T>(client: &'a Client) -> ProjectUpdatesQueryBuilder<'a, T> {
+    ProjectUpdatesQueryBuilder {
+        client,
+        filter: None,
+        before: None,
+        after: None,
+        first: None,
+        last: None,
+        include_archived: None,
+        order_by: None,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Returns a single project update by its identifier.
+///
+/// Full type: [`ProjectUpdate`](super::types::ProjectUpdate)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub async fn project_update_post<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::ProjectUpdate>,
+>(
+    client: &Client,
+    id: impl Into<super::ids::ProjectUpdateId>,
+) -> Result<T, LinearError> {
+    super::operations::project_update_post::<T>(id)
+        .execute(client)
+        .await
+}
+/// Returns all project statuses in the workspace.
+///
+/// Full type: [`ProjectStatus`](super::types::ProjectStatus)
+pub fn project_statuses<'a, T>(client: &'a Client) -> ProjectStatusesQueryBuilder<'a, T> {
+    ProjectStatusesQueryBuilder {
+        client,
+        before: None,
+        after: None,
+        first: None,
+        last: None,
+        include_archived: None,
+        order_by: None,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// `INTERNAL` Count of projects using this project status across the workspace.
+///
+/// Full type: [`ProjectStatusCountPayload`](super::types::ProjectStatusCountPayload)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub async fn project_status_project_count<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::ProjectStatusCountPayload>,
+>(
+    client: &Client,
+    id: impl Into<super::ids::ProjectStatusId>,
+) -> Result<T, LinearError> {
+    super::operations::project_status_project_count::<T>(id)
+        .execute(client)
+        .await
+}
+/// Returns a single project status by its identifier.
+///
+/// Full type: [`ProjectStatus`](super::types::ProjectStatus)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub async fn project_status<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::ProjectStatus>,
+>(
+    client: &Client,
+    id: impl Into<super::ids::ProjectStatusId>,
+) -> Result<T, LinearError> {
+    super::operations::project_status::<T>(id)
+        .execute(client)
+        .await
+}
+/// Returns all project dependency relations in the workspace.
+///
+/// Full type: [`ProjectRelation`](super::types::ProjectRelation)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub fn project_relations<'a, T>(client: &'a Client) -> ProjectRelationsQueryBuilder<'a, T> {
+    ProjectRelationsQueryBuilder {
+        client,
+        before: None,
+        after: None,
+        first: None,
+        last: None,
+        include_archived: None,
+        order_by: None,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Returns a single project relation by its identifier.
+///
+/// Full type: [`ProjectRelation`](super::types::ProjectRelation)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub async fn project_relation<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::ProjectRelation>,
+>(
+    client: &Client,
+    id: impl Into<super::ids::ProjectRelationId>,
+) -> Result<T, LinearError> {
+    super::operations::project_relation::<T>(id)
+        .execute(client)
+        .await
+}
+/// Returns all project milestones in the workspace, with optional filtering.
+///
+/// Full type: [`ProjectMilestone`](super::types::ProjectMilestone)
+pub fn project_milestones<'a, T>(client: &'a Client) -> ProjectMilestonesQueryBuilder<'a, T> {
+    ProjectMilestonesQueryBuilder {
+        client,
+        filter: None,
+        before: None,
+        after: None,
+        first: None,
+        last: None,
+        include_archived: None,
+        order_by: None,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Returns a single project milestone by its identifier.
+///
+/// Full type: [`ProjectMilestone`](super::types::ProjectMilestone)
+pub async fn project_milestone<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::ProjectMilestone>,
+>(
+    client: &Client,
+    id: impl Into<super::ids::ProjectMilestoneId>,
+) -> Result<T, LinearError> {
+    super::operations::project_milestone::<T>(id)
+        .execute(client)
+        .await
+}
+/// Returns all project labels in the workspace, with optional filtering.
+///
+/// Full type: [`ProjectLabel`](super::types::ProjectLabel)
+pub fn project_labels<'a, T>(client: &'a Client) -> ProjectLabelsQueryBuilder<'a, T> {
+    ProjectLabelsQueryBuilder {
+        client,
+        filter: None,
+        before: None,
+        after: None,
+        first: None,
+        last: None,
+        include_archived: None,
+        order_by: None,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Returns a single project label by its identifier.
+///
+/// Full type: [`ProjectLabel`](super::types::ProjectLabel)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub async fn project_label<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::ProjectLabel>,
+>(
+    client: &Client,
+    id: impl Into<super::ids::ProjectLabelId>,
+) -> Result<T, LinearError> {
+    super::operations::project_label::<T>(id)
+        .execute(client)
+        .await
+}
+/// The authenticated user's workspace.
+///
+/// Full type: [`Organization`](super::types::Organization)
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub async fn organization<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::Organization>,
+>(
+    client: &Client,
+) -> Result<T, LinearError> {
+    super::operations::organization::<T>().execute(client).await
+}
+/// Checks whether a workspace with the given URL key exists.
+///
+/// Full type: [`OrganizationExistsPayload`](super::types::OrganizationExistsPayload)
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub async fn organization_exists<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::OrganizationExistsPayload>,
+>(
+    client: &Client,
+    url_key: String,
+) -> Result<T, LinearError> {
+    super::operations::organization_exists::<T>(url_key)
+        .execute(client)
+        .await
+}
+/// `Internal` All archived teams of the workspace.
+///
+/// Full type: [`Team`](super::types::Team)
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+pub async fn archived_teams<T: DeserializeOwned + GraphQLFields<FullType = super::types::Team>>(
+    client: &Client,
+) -> Result<Vec<T>, LinearError> {
+    super::operations::archived_teams::<T>()
+        .execute(client)
+        .await
+}
+/// `INTERNAL` Get workspace metadata by URL key or workspace ID.
+///
+/// Full type: [`OrganizationMeta`](super::types::OrganizationMeta)
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub async fn organization_meta<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::OrganizationMeta>,
+>(
+    client: &Client,
+    url_key: String,
+) -> Result<Option<T>, LinearError> {
+    super::operations::organization_meta::<T>(url_key)
+        .execute(client)
+        .await
+}
+/// All pending and accepted invites for the workspace.
+///
+/// Full type: [`OrganizationInvite`](super::types::OrganizationInvite)
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub fn organization_invites<'a, T>(client: &'a Client) -> OrganizationInvitesQueryBuilder<'a, T> {
+    OrganizationInvitesQueryBuilder {
+        client,
+        before: None,
+        after: None,
+        first: None,
+        last: None,
+        include_archived: None,
+        order_by: None,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Fetches a specific workspace invite by its ID.
+///
+/// Full type: [`OrganizationInvite`](super::types::OrganizationInvite)
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub async fn organization_invite<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::OrganizationInvite>,
+>(
+    client: &Client,
+    id: impl Into<super::ids::OrganizationInviteId>,
+) -> Result<T, LinearError> {
+    super::operations::organization_invite::<T>(id)
+        .execute(client)
+        .await
+}
+/// Fetches public details of a specific workspace invite by its ID. This query does not require authentication and is used for invite acceptance flows.
+///
+/// Full type: [`OrganizationInviteDetailsPayload`](super::types::OrganizationInviteDetailsPayload)
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub async fn organization_invite_details<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::OrganizationInviteDetailsPayload>,
+>(
+    client: &Client,
+    id: impl Into<super::ids::OrganizationInviteId>,
+) -> Result<T, LinearError> {
+    super::operations::organization_invite_details::<T>(id)
+        .execute(client)
+        .await
+}
+/// `INTERNAL` Checks whether the domain can be claimed.
+///
+/// Full type: [`OrganizationDomainClaimPayload`](super::types::OrganizationDomainClaimPayload)
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub async fn organization_domain_claim_request<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::OrganizationDomainClaimPayload>,
+>(
+    client: &Client,
+    id: impl Into<super::ids::OrganizationDomainId>,
+) -> Result<T, LinearError> {
+    super::operations::organization_domain_claim_request::<T>(id)
+        .execute(client)
+        .await
+}
+/// The authenticated user's notification subscriptions. These subscriptions control which notifications the user receives for specific entities such as teams, projects, cycles, labels, custom views, initiatives, customers, and users.
+///
+/// Full type: [`NotificationSubscription`](super::types::NotificationSubscription)
+///
+/// Requires the `notifications` feature.
+#[cfg(feature = "notifications")]
+pub fn notification_subscriptions<'a, T>(
+    client: &'a Client,
+) -> NotificationSubscriptionsQueryBuilder<'a, T> {
+    NotificationSubscriptionsQueryBuilder {
+        client,
+        before: None,
+        after: None,
+        first: None,
+        last: None,
+        include_archived: None,
+        order_by: None,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// A specific notification subscription by ID.
+///
+/// Full type: [`NotificationSubscription`](super::types::NotificationSubscription)
+///
+/// Requires the `notifications` feature.
+#[cfg(feature = "notifications")]
+pub async fn notification_subscription<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::NotificationSubscription>,
+>(
+    client: &Client,
+    id: String,
+) -> Result<T, LinearError> {
+    super::operations::notification_subscription::<T>(id)
+        .execute(client)
+        .await
+}
+/// The authenticated user's notifications.
+///
+/// Full type: [`Notification`](super::types::Notification)
+pub fn notifications<'a, T>(client: &'a Client) -> NotificationsQueryBuilder<'a, T> {
+    NotificationsQueryBuilder {
+        client,
+        filter: None,
+        before: None,
+        after: None,
+        first: None,
+        last: None,
+        include_archived: None,
+        order_by: None,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// A specific notification by ID.
+///
+/// Full type: [`Notification`](super::types::Notification)
+pub async fn notification<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::Notification>,
+>(
+    client: &Client,
+    id: String,
+) -> Result<T, LinearError> {
+    super::operations::notification::<T>(id)
+        .execute(client)
+        .await
+}
+/// `ALPHA` All issue-to-release associations. Returns a paginated list of all issue-to-release links visible to the authenticated user.
+///
+/// Full type: [`IssueToRelease`](super::types::IssueToRelease)
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+pub fn issue_to_releases<'a, T>(client: &'a Client) -> IssueToReleasesQueryBuilder<'a, T> {
+    IssueToReleasesQueryBuilder {
+        client,
+        before: None,
+        after: None,
+        first: None,
+        last: None,
+        include_archived: None,
+        order_by: None,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// `ALPHA` One specific issue-to-release association, looked up by its unique identifier.
+///
+/// Full type: [`IssueToRelease`](super::types::IssueToRelease)
+///
+/// Requires the `releases` feature.
+#[cfg(feature = "releases")]
+pub async fn issue_to_release<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::IssueToRelease>,
+>(
+    client: &Client,
+    id: impl Into<super::ids::IssueToReleaseId>,
+) -> Result<T, LinearError> {
+    super::operations::issue_to_release::<T>(id)
+        .execute(client)
+        .await
+}
+/// All issues. Returns a paginated list of issues visible to the authenticated user. Can be filtered by various criteria including team, assignee, state, labels, project, and cycle.
+///
+/// Full type: [`Issue`](super::types::Issue)
+pub fn issues<'a, T>(client: &'a Client) -> IssuesQueryBuilder<'a, T> {
+    IssuesQueryBuilder {
+        client,
+        filter: None,
+        before: None,
+        after: None,
+        first: None,
+        last: None,
+        include_archived: None,
+        order_by: None,
+        sort: None,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// One specific issue, looked up by its unique identifier.
+///
+/// Full type: [`Issue`](super::types::Issue)
+pub async fn issue<T: DeserializeOwned + GraphQLFields<FullType = super::types::Issue>>(
+    client: &Client,
+    id: impl Into<super::ids::IssueId>,
+) -> Result<T, LinearError> {
+    super::operations::issue::<T>(id).execute(client).await
+}
+/// `DEPRECATED` Search issues. This endpoint is deprecated and will be removed in the future – use `searchIssues` instead.
+///
+/// Full type: [`Issue`](super::types::Issue)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub fn issue_search<'a, T>(client: &'a Client) -> IssueSearchQueryBuilder<'a, T> {
+    IssueSearchQueryBuilder {
+        client,
+        filter: None,
+        query: None,
+        before: None,
+        after: None,
+        first: None,
+        last: None,
+        include_archived: None,
+        order_by: None,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Find issue based on the VCS branch name.
+///
+/// Full type: [`Issue`](super::types::Issue)
+pub async fn issue_vcs_branch_search<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::Issue>,
+>(
+    client: &Client,
+    branch_name: String,
+) -> Result<Option<T>, LinearError> {
+    super::operations::issue_vcs_branch_search::<T>(branch_name)
+        .execute(client)
+        .await
+}
+/// Find issues that are related to a given Figma file key.
+///
+/// Full type: [`Issue`](super::types::Issue)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub fn issue_figma_file_key_search<'a, T>(
+    client: &'a Client,
+    file_key: impl Into<String>,
+) -> IssueFigmaFileKeySearchQueryBuilder<'a, T> {
+    IssueFigmaFileKeySearchQueryBuilder {
+        client,
+        file_key: file_key.into(),
+        before: None,
+        after: None,
+        first: None,
+        last: None,
+        include_archived: None,
+        order_by: None,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Issue priority values and corresponding labels.
+///
+/// Full type: [`IssuePriorityValue`](super::types::IssuePriorityValue)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub async fn issue_priority_values<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::IssuePriorityValue>,
+>(
+    client: &Client,
+) -> Result<Vec<T>, LinearError> {
+    super::operations::issue_priority_values::<T>()
+        .execute(client)
+        .await
+}
+/// Suggests filters for an issue view based on a text prompt.
+///
+/// Full type: [`IssueFilterSuggestionPayload`](super::types::IssueFilterSuggestionPayload)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub fn issue_filter_suggestion<'a, T>(
+    client: &'a Client,
+    prompt: impl Into<String>,
+) -> IssueFilterSuggestionQueryBuilder<'a, T> {
+    IssueFilterSuggestionQueryBuilder {
+        client,
+        prompt: prompt.into(),
+        team_id: None,
+        project_id: None,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Returns code repositories that are most likely to be relevant for implementing an issue.
+///
+/// Full type: [`RepositorySuggestionsPayload`](super::types::RepositorySuggestionsPayload)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub fn issue_repository_suggestions<'a, T>(
+    client: &'a Client,
+    candidate_repositories: CandidateRepository,
+    issue_id: impl Into<String>,
+) -> IssueRepositorySuggestionsQueryBuilder<'a, T> {
+    IssueRepositorySuggestionsQueryBuilder {
+        client,
+        candidate_repositories,
+        issue_id: issue_id.into(),
+        agent_session_id: None,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// All issue relations. Returns a paginated list of all issue relations (blocks, blocked by, relates to, duplicates) visible to the authenticated user.
+///
+/// Full type: [`IssueRelation`](super::types::IssueRelation)
+pub fn issue_relations<'a, T>(client: &'a Client) -> IssueRelationsQueryBuilder<'a, T> {
+    IssueRelationsQueryBuilder {
+        client,
+        before: None,
+        after: None,
+        first: None,
+        last: None,
+        include_archived: None,
+        order_by: None,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// One specific issue relation, looked up by its unique identifier.
+///
+/// Full type: [`IssueRelation`](super::types::IssueRelation)
+pub async fn issue_relation<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::IssueRelation>,
+>(
+    client: &Client,
+    id: impl Into<super::ids::IssueRelationId>,
+) -> Result<T, LinearError> {
+    super::operations::issue_relation::<T>(id)
+        .execute(client)
+        .await
+}
+/// All issue labels. Returns a paginated list of labels visible to the authenticated user, including both workspace-level and team-scoped labels.
+///
+/// Full type: [`IssueLabel`](super::types::IssueLabel)
+pub fn issue_labels<'a, T>(client: &'a Client) -> IssueLabelsQueryBuilder<'a, T> {
+    IssueLabelsQueryBuilder {
+        client,
+        filter: None,
+        before: None,
+        after: None,
+        first: None,
+        last: None,
+        include_archived: None,
         order_by: None,
+        extra_variables: serde_json::Map::new(),
         _marker: std::marker::PhantomData,
     }
 }
-/// All users in the workspace. Supports filtering, sorting, and pagination.
+/// One specific label, looked up by its unique identifier.
 ///
-/// Full type: [`User`](super::types::User)
-pub fn users<'a, T>(client: &'a Client) -> UsersQueryBuilder<'a, T> {
-    UsersQueryBuilder {
+/// Full type: [`IssueLabel`](super::types::IssueLabel)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub async fn issue_label<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::IssueLabel>,
+>(
+    client: &Client,
+    id: impl Into<super::ids::IssueLabelId>,
+) -> Result<T, LinearError> {
+    super::operations::issue_label::<T>(id)
+        .execute(client)
+        .await
+}
+/// Checks a CSV file validity against a specific import service.
+///
+/// Full type: [`IssueImportCheckPayload`](super::types::IssueImportCheckPayload)
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub async fn issue_import_check_csv<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::IssueImportCheckPayload>,
+>(
+    client: &Client,
+    csv_url: String,
+    service: String,
+) -> Result<T, LinearError> {
+    super::operations::issue_import_check_csv::<T>(csv_url, service)
+        .execute(client)
+        .await
+}
+/// Checks whether it will be possible to set up sync for this project or repository at the end of import.
+///
+/// Full type: [`IssueImportSyncCheckPayload`](super::types::IssueImportSyncCheckPayload)
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub async fn issue_import_check_sync<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::IssueImportSyncCheckPayload>,
+>(
+    client: &Client,
+    issue_import_id: String,
+) -> Result<T, LinearError> {
+    super::operations::issue_import_check_sync::<T>(issue_import_id)
+        .execute(client)
+        .await
+}
+/// Checks whether a custom JQL query is valid and can be used to filter issues of a Jira import.
+///
+/// Full type: [`IssueImportJqlCheckPayload`](super::types::IssueImportJqlCheckPayload)
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub async fn issue_import_jql_check<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::IssueImportJqlCheckPayload>,
+>(
+    client: &Client,
+    jira_hostname: String,
+    jira_token: String,
+    jira_email: String,
+    jira_project: String,
+    jql: String,
+) -> Result<T, LinearError> {
+    super::operations::issue_import_jql_check::<T>(
+        jira_hostname,
+        jira_token,
+        jira_email,
+        jira_project,
+        jql,
+    )
+    .execute(client)
+    .await
+}
+/// Retrieves a specific integration settings configuration by its identifier.
+///
+/// Full type: [`IntegrationsSettings`](super::types::IntegrationsSettings)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub async fn integrations_settings<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::IntegrationsSettings>,
+>(
+    client: &Client,
+    id: impl Into<super::ids::IntegrationsSettingsId>,
+) -> Result<T, LinearError> {
+    super::operations::integrations_settings::<T>(id)
+        .execute(client)
+        .await
+}
+/// All integration template connections for the workspace, linking templates to integrations.
+///
+/// Full type: [`IntegrationTemplate`](super::types::IntegrationTemplate)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn integration_templates<'a, T>(client: &'a Client) -> IntegrationTemplatesQueryBuilder<'a, T> {
+    IntegrationTemplatesQueryBuilder {
+        client,
+        before: None,
+        after: None,
+        first: None,
+        last: None,
+        include_archived: None,
+        order_by: None,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Retrieves a single integration template connection by its identifier.
+///
+/// Full type: [`IntegrationTemplate`](super::types::IntegrationTemplate)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub async fn integration_template<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::IntegrationTemplate>,
+>(
+    client: &Client,
+    id: impl Into<super::ids::IntegrationTemplateId>,
+) -> Result<T, LinearError> {
+    super::operations::integration_template::<T>(id)
+        .execute(client)
+        .await
+}
+/// All integrations for the workspace.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn integrations<'a, T>(client: &'a Client) -> IntegrationsQueryBuilder<'a, T> {
+    IntegrationsQueryBuilder {
+        client,
+        before: None,
+        after: None,
+        first: None,
+        last: None,
+        include_archived: None,
+        order_by: None,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Retrieves a single integration by its identifier.
+///
+/// Full type: [`Integration`](super::types::Integration)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub async fn integration<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::Integration>,
+>(
+    client: &Client,
+    id: impl Into<super::ids::IntegrationId>,
+) -> Result<T, LinearError> {
+    super::operations::integration::<T>(id)
+        .execute(client)
+        .await
+}
+/// Verify that we received the correct response from the GitHub Enterprise Server.
+///
+/// Full type: [`GitHubEnterpriseServerInstallVerificationPayload`](super::types::GitHubEnterpriseServerInstallVerificationPayload)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub async fn verify_git_hub_enterprise_server_installation<
+    T: DeserializeOwned
+        + GraphQLFields<FullType = super::types::GitHubEnterpriseServerInstallVerificationPayload>,
+>(
+    client: &Client,
+    integration_id: String,
+) -> Result<T, LinearError> {
+    super::operations::verify_git_hub_enterprise_server_installation::<T>(integration_id)
+        .execute(client)
+        .await
+}
+/// Checks if the integration has all required scopes.
+///
+/// Full type: [`IntegrationHasScopesPayload`](super::types::IntegrationHasScopesPayload)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub async fn integration_has_scopes<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::IntegrationHasScopesPayload>,
+>(
+    client: &Client,
+    scopes: Vec<String>,
+    integration_id: String,
+) -> Result<T, LinearError> {
+    super::operations::integration_has_scopes::<T>(scopes, integration_id)
+        .execute(client)
+        .await
+}
+/// `Internal` Lists Microsoft Teams teams and channels accessible to the connecting user. Uses the user's microsoftPersonal integration for auth. Used by the project channel picker dialog to populate the team/channel dropdowns.
+///
+/// Full type: [`MicrosoftTeamsChannelsPayload`](super::types::MicrosoftTeamsChannelsPayload)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub async fn microsoft_teams_channels<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::MicrosoftTeamsChannelsPayload>,
+>(
+    client: &Client,
+) -> Result<T, LinearError> {
+    super::operations::microsoft_teams_channels::<T>()
+        .execute(client)
+        .await
+}
+/// Returns all initiative status updates in the workspace, with optional filtering.
+///
+/// Full type: [`InitiativeUpdate`](super::types::InitiativeUpdate)
+///
+/// Requires the `initiatives` feature.
+#[cfg(feature = "initiatives")]
+pub fn initiative_updates<'a, T>(client: &'a Client) -> InitiativeUpdatesQueryBuilder<'a, T> {
+    InitiativeUpdatesQueryBuilder {
+        client,
+        filter: None,
+        before: None,
+        after: None,
+        first: None,
+        last: None,
+        include_archived: None,
+        order_by: None,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Returns a single initiative update by its identifier.
+///
+/// Full type: [`InitiativeUpdate`](super::types::InitiativeUpdate)
+///
+/// Requires the `initiatives` feature.
+#[cfg(feature = "initiatives")]
+pub async fn initiative_update_post<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::InitiativeUpdate>,
+>(
+    client: &Client,
+    id: impl Into<super::ids::InitiativeUpdateId>,
+) -> Result<T, LinearError> {
+    super::operations::initiative_update_post::<T>(id)
+        .execute(client)
+        .await
+}
+/// Returns all initiative-to-project associations in the workspace.
+///
+/// Full type: [`InitiativeToProject`](super::types::InitiativeToProject)
+///
+/// Requires the `initiatives` feature.
+#[cfg(feature = "initiatives")]
+pub fn initiative_to_projects<'a, T>(
+    client: &'a Client,
+) -> InitiativeToProjectsQueryBuilder<'a, T> {
+    InitiativeToProjectsQueryBuilder {
+        client,
+        before: None,
+        after: None,
+        first: None,
+        last: None,
+        include_archived: None,
+        order_by: None,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Returns a single initiative-to-project association by its identifier.
+///
+/// Full type: [`InitiativeToProject`](super::types::InitiativeToProject)
+///
+/// Requires the `initiatives` feature.
+#[cfg(feature = "initiatives")]
+pub async fn initiative_to_project<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::InitiativeToProject>,
+>(
+    client: &Client,
+    id: impl Into<super::ids::InitiativeToProjectId>,
+) -> Result<T, LinearError> {
+    super::operations::initiative_to_project::<T>(id)
+        .execute(client)
+        .await
+}
+/// Returns all initiatives in the workspace, with optional filtering and sorting.
+///
+/// Full type: [`Initiative`](super::types::Initiative)
+///
+/// Requires the `initiatives` feature.
+#[cfg(feature = "initiatives")]
+pub fn initiatives<'a, T>(client: &'a Client) -> InitiativesQueryBuilder<'a, T> {
+    InitiativesQueryBuilder {
+        client,
+        filter: None,
+        before: None,
+        after: None,
+        first: None,
+        last: None,
+        include_archived: None,
+        order_by: None,
+        sort: None,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Returns a single initiative by its identifier or URL slug.
+///
+/// Full type: [`Initiative`](super::types::Initiative)
+///
+/// Requires the `initiatives` feature.
+#[cfg(feature = "initiatives")]
+pub async fn initiative<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::Initiative>,
+>(
+    client: &Client,
+    id: impl Into<super::ids::InitiativeId>,
+) -> Result<T, LinearError> {
+    super::operations::initiative::<T>(id).execute(client).await
+}
+/// Returns all initiative parent-child relations in the workspace.
+///
+/// Full type: [`InitiativeRelation`](super::types::InitiativeRelation)
+///
+/// Requires the `initiatives` feature.
+#[cfg(feature = "initiatives")]
+pub fn initiative_relations<'a, T>(client: &'a Client) -> InitiativeRelationsQueryBuilder<'a, T> {
+    InitiativeRelationsQueryBuilder {
+        client,
+        before: None,
+        after: None,
+        first: None,
+        last: None,
+        include_archived: None,
+        order_by: None,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Returns a single initiative relation by its identifier.
+///
+/// Full type: [`InitiativeRelation`](super::types::InitiativeRelation)
+///
+/// Requires the `initiatives` feature.
+#[cfg(feature = "initiatives")]
+pub async fn initiative_relation<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::InitiativeRelation>,
+>(
+    client: &Client,
+    id: impl Into<super::ids::InitiativeRelationId>,
+) -> Result<T, LinearError> {
+    super::operations::initiative_relation::<T>(id)
+        .execute(client)
+        .await
+}
+/// `Internal` Fetch an arbitrary set of data using natural language query. Be specific about what you want including properties for each entity, sort order, filters, limit and properties.
+///
+/// Full type: [`FetchDataPayload`](super::types::FetchDataPayload)
+///
+/// Requires the `agents` feature.
+#[cfg(feature = "agents")]
+pub async fn fetch_data<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::FetchDataPayload>,
+>(
+    client: &Client,
+    query: String,
+) -> Result<T, LinearError> {
+    super::operations::fetch_data::<T>(query)
+        .execute(client)
+        .await
+}
+/// The authenticated user's favorites. Returns all bookmarked items that appear in the user's sidebar.
+///
+/// Full type: [`Favorite`](super::types::Favorite)
+///
+/// Requires the `views` feature.
+#[cfg(feature = "views")]
+pub fn favorites<'a, T>(client: &'a Client) -> FavoritesQueryBuilder<'a, T> {
+    FavoritesQueryBuilder {
+        client,
+        before: None,
+        after: None,
+        first: None,
+        last: None,
+        include_archived: None,
+        order_by: None,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// A specific favorite by ID.
+///
+/// Full type: [`Favorite`](super::types::Favorite)
+///
+/// Requires the `views` feature.
+#[cfg(feature = "views")]
+pub async fn favorite<T: DeserializeOwned + GraphQLFields<FullType = super::types::Favorite>>(
+    client: &Client,
+    id: impl Into<super::ids::FavoriteId>,
+) -> Result<T, LinearError> {
+    super::operations::favorite::<T>(id).execute(client).await
+}
+/// All external users for the organization. External users are people who interact with Linear through integrated services (Slack, Jira, GitHub, etc.) without having a Linear account.
+///
+/// Full type: [`ExternalUser`](super::types::ExternalUser)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub fn external_users<'a, T>(client: &'a Client) -> ExternalUsersQueryBuilder<'a, T> {
+    ExternalUsersQueryBuilder {
+        client,
+        before: None,
+        after: None,
+        first: None,
+        last: None,
+        include_archived: None,
+        order_by: None,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Retrieves a single external user by their identifier.
+///
+/// Full type: [`ExternalUser`](super::types::ExternalUser)
+///
+/// Requires the `integrations` feature.
+#[cfg(feature = "integrations")]
+pub async fn external_user<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::ExternalUser>,
+>(
+    client: &Client,
+    id: impl Into<super::ids::ExternalUserId>,
+) -> Result<T, LinearError> {
+    super::operations::external_user::<T>(id)
+        .execute(client)
+        .await
+}
+/// Retrieves a single entity external link by its identifier.
+///
+/// Full type: [`EntityExternalLink`](super::types::EntityExternalLink)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub async fn entity_external_link<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::EntityExternalLink>,
+>(
+    client: &Client,
+    id: impl Into<super::ids::EntityExternalLinkId>,
+) -> Result<T, LinearError> {
+    super::operations::entity_external_link::<T>(id)
+        .execute(client)
+        .await
+}
+/// All custom emojis in the workspace.
+///
+/// Full type: [`Emoji`](super::types::Emoji)
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub fn emojis<'a, T>(client: &'a Client) -> EmojisQueryBuilder<'a, T> {
+    EmojisQueryBuilder {
+        client,
+        before: None,
+        after: None,
+        first: None,
+        last: None,
+        include_archived: None,
+        order_by: None,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// A specific custom emoji by ID or name.
+///
+/// Full type: [`Emoji`](super::types::Emoji)
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub async fn emoji<T: DeserializeOwned + GraphQLFields<FullType = super::types::Emoji>>(
+    client: &Client,
+    id: impl Into<super::ids::EmojiId>,
+) -> Result<T, LinearError> {
+    super::operations::emoji::<T>(id).execute(client).await
+}
+/// One specific email intake address.
+///
+/// Full type: [`EmailIntakeAddress`](super::types::EmailIntakeAddress)
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+pub async fn email_intake_address<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::EmailIntakeAddress>,
+>(
+    client: &Client,
+    id: impl Into<super::ids::EmailIntakeAddressId>,
+) -> Result<T, LinearError> {
+    super::operations::email_intake_address::<T>(id)
+        .execute(client)
+        .await
+}
+/// All documents the user has access to in the workspace.
+///
+/// Full type: [`Document`](super::types::Document)
+pub fn documents<'a, T>(client: &'a Client) -> DocumentsQueryBuilder<'a, T> {
+    DocumentsQueryBuilder {
         client,
         filter: None,
-        include_disabled: None,
         before: None,
         after: None,
         first: None,
         last: None,
         include_archived: None,
         order_by: None,
-        sort: None,
+        extra_variables: serde_json::Map::new(),
         _marker: std::marker::PhantomData,
     }
 }
-/// The currently authenticated user making the API request.
+/// A specific document by ID or slug.
 ///
-/// Full type: [`User`](super::types::User)
-pub async fn whoami<T: DeserializeOwned + GraphQLFields<FullType = super::types::User>>(
+/// Full type: [`Document`](super::types::Document)
+pub async fn document<T: DeserializeOwned + GraphQLFields<FullType = super::types::Document>>(
     client: &Client,
+    id: impl Into<super::ids::DocumentId>,
 ) -> Result<T, LinearError> {
-    let variables = serde_json::json!({});
-    let selection = T::selection();
-    let query = format!("query {} {{ {} {{ {} }} }}", "Viewer", "viewer", selection);
-    client.execute::<T>(&query, variables, "viewer").await
+    super::operations::document::<T>(id).execute(client).await
 }
-/// Returns all projects in the workspace, with optional filtering and sorting.
+/// A collection of document content history entries.
 ///
-/// Full type: [`Project`](super::types::Project)
-pub fn projects<'a, T>(client: &'a Client) -> ProjectsQueryBuilder<'a, T> {
-    ProjectsQueryBuilder {
+/// Full type: [`DocumentContentHistoryPayload`](super::types::DocumentContentHistoryPayload)
+///
+/// Requires the `documents` feature.
+#[cfg(feature = "documents")]
+pub async fn document_content_history<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::DocumentContentHistoryPayload>,
+>(
+    client: &Client,
+    id: impl Into<super::ids::DocumentContentHistoryTypeId>,
+) -> Result<T, LinearError> {
+    super::operations::document_content_history::<T>(id)
+        .execute(client)
+        .await
+}
+/// `Internal` Fetches document content history entries by their IDs, including content data.
+///
+/// Full type: [`DocumentContentHistoryPayload`](super::types::DocumentContentHistoryPayload)
+///
+/// Requires the `documents` feature.
+#[cfg(feature = "documents")]
+pub async fn document_content_history_entries<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::DocumentContentHistoryPayload>,
+>(
+    client: &Client,
+    entry_ids: Vec<String>,
+) -> Result<T, LinearError> {
+    super::operations::document_content_history_entries::<T>(entry_ids)
+        .execute(client)
+        .await
+}
+/// All cycles accessible to the user.
+///
+/// Full type: [`Cycle`](super::types::Cycle)
+pub fn cycles<'a, T>(client: &'a Client) -> CyclesQueryBuilder<'a, T> {
+    CyclesQueryBuilder {
         client,
         filter: None,
         before: None,
@@ -1235,98 +10301,130 @@ pub fn projects<'a, T>(client: &'a Client) -> ProjectsQueryBuilder<'a, T> {
         last: None,
         include_archived: None,
         order_by: None,
-        sort: None,
+        extra_variables: serde_json::Map::new(),
         _marker: std::marker::PhantomData,
     }
 }
-/// Returns a single project by its identifier or URL slug.
+/// One specific cycle, looked up by ID or slug.
 ///
-/// Full type: [`Project`](super::types::Project)
-pub async fn project<T: DeserializeOwned + GraphQLFields<FullType = super::types::Project>>(
+/// Full type: [`Cycle`](super::types::Cycle)
+pub async fn cycle<T: DeserializeOwned + GraphQLFields<FullType = super::types::Cycle>>(
     client: &Client,
-    id: String,
+    id: impl Into<super::ids::CycleId>,
 ) -> Result<T, LinearError> {
-    let variables = serde_json::json!({ "id" : id });
-    let selection = T::selection();
-    let query = format!(
-        "query {}({}) {{ {}({}) {{ {} }} }}",
-        "Project", "$id: String!", "project", "id: $id", selection
-    );
-    client.execute::<T>(&query, variables, "project").await
+    super::operations::cycle::<T>(id).execute(client).await
 }
-/// All teams whose issues the user can access. This includes public teams and private teams the user is a member of. This may differ from `administrableTeams`, which returns teams whose settings the user can change but whose issues they don't necessarily have access to.
+/// All customer tiers defined in the workspace.
 ///
-/// Full type: [`Team`](super::types::Team)
-pub fn teams<'a, T>(client: &'a Client) -> TeamsQueryBuilder<'a, T> {
-    TeamsQueryBuilder {
+/// Full type: [`CustomerTier`](super::types::CustomerTier)
+///
+/// Requires the `customers` feature.
+#[cfg(feature = "customers")]
+pub fn customer_tiers<'a, T>(client: &'a Client) -> CustomerTiersQueryBuilder<'a, T> {
+    CustomerTiersQueryBuilder {
         client,
-        filter: None,
         before: None,
         after: None,
         first: None,
         last: None,
         include_archived: None,
         order_by: None,
+        extra_variables: serde_json::Map::new(),
         _marker: std::marker::PhantomData,
     }
 }
-/// Fetches a specific team by its ID.
+/// One specific customer tier.
 ///
-/// Full type: [`Team`](super::types::Team)
-pub async fn team<T: DeserializeOwned + GraphQLFields<FullType = super::types::Team>>(
+/// Full type: [`CustomerTier`](super::types::CustomerTier)
+///
+/// Requires the `customers` feature.
+#[cfg(feature = "customers")]
+pub async fn customer_tier<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::CustomerTier>,
+>(
     client: &Client,
-    id: String,
+    id: impl Into<super::ids::CustomerTierId>,
 ) -> Result<T, LinearError> {
-    let variables = serde_json::json!({ "id" : id });
-    let selection = T::selection();
-    let query = format!(
-        "query {}({}) {{ {}({}) {{ {} }} }}",
-        "Team", "$id: String!", "team", "id: $id", selection
-    );
-    client.execute::<T>(&query, variables, "team").await
+    super::operations::customer_tier::<T>(id)
+        .execute(client)
+        .await
 }
-/// Search issues by text query using full-text and vector search. Results are ranked by relevance unless an orderBy parameter is specified. Supports optional issue filters and comment inclusion. Rate-limited to 30 requests per minute.
+/// All customer statuses defined in the workspace.
 ///
-/// Full type: [`IssueSearchResult`](super::types::IssueSearchResult)
-pub fn search_issues<'a, T>(
-    client: &'a Client,
-    term: impl Into<String>,
-) -> SearchIssuesQueryBuilder<'a, T> {
-    SearchIssuesQueryBuilder {
+/// Full type: [`CustomerStatus`](super::types::CustomerStatus)
+///
+/// Requires the `customers` feature.
+#[cfg(feature = "customers")]
+pub fn customer_statuses<'a, T>(client: &'a Client) -> CustomerStatusesQueryBuilder<'a, T> {
+    CustomerStatusesQueryBuilder {
         client,
-        term: term.into(),
-        filter: None,
         before: None,
         after: None,
         first: None,
         last: None,
         include_archived: None,
         order_by: None,
-        include_comments: None,
-        team_id: None,
+        extra_variables: serde_json::Map::new(),
         _marker: std::marker::PhantomData,
     }
 }
-/// Returns all project statuses in the workspace.
+/// One specific customer status.
 ///
-/// Full type: [`ProjectStatus`](super::types::ProjectStatus)
-pub fn project_statuses<'a, T>(client: &'a Client) -> ProjectStatusesQueryBuilder<'a, T> {
-    ProjectStatusesQueryBuilder {
+/// Full type: [`CustomerStatus`](super::types::CustomerStatus)
+///
+/// Requires the `customers` feature.
+#[cfg(feature = "customers")]
+pub async fn customer_status<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::CustomerStatus>,
+>(
+    client: &Client,
+    id: impl Into<super::ids::CustomerStatusId>,
+) -> Result<T, LinearError> {
+    super::operations::customer_status::<T>(id)
+        .execute(client)
+        .await
+}
+/// All customers in the workspace, with optional filtering and sorting.
+///
+/// Full type: [`Customer`](super::types::Customer)
+///
+/// Requires the `customers` feature.
+#[cfg(feature = "customers")]
+pub fn customers<'a, T>(client: &'a Client) -> CustomersQueryBuilder<'a, T> {
+    CustomersQueryBuilder {
         client,
+        filter: None,
         before: None,
         after: None,
         first: None,
         last: None,
         include_archived: None,
         order_by: None,
+        sorts: None,
+        extra_variables: serde_json::Map::new(),
         _marker: std::marker::PhantomData,
     }
 }
-/// Returns all project milestones in the workspace, with optional filtering.
+/// Retrieves a single customer by ID or slug.
 ///
-/// Full type: [`ProjectMilestone`](super::types::ProjectMilestone)
-pub fn project_milestones<'a, T>(client: &'a Client) -> ProjectMilestonesQueryBuilder<'a, T> {
-    ProjectMilestonesQueryBuilder {
+/// Full type: [`Customer`](super::types::Customer)
+///
+/// Requires the `customers` feature.
+#[cfg(feature = "customers")]
+pub async fn customer<T: DeserializeOwned + GraphQLFields<FullType = super::types::Customer>>(
+    client: &Client,
+    id: impl Into<super::ids::CustomerId>,
+) -> Result<T, LinearError> {
+    super::operations::customer::<T>(id).execute(client).await
+}
+/// All customer needs in the workspace, with optional filtering.
+///
+/// Full type: [`CustomerNeed`](super::types::CustomerNeed)
+///
+/// Requires the `customers` feature.
+#[cfg(feature = "customers")]
+pub fn customer_needs<'a, T>(client: &'a Client) -> CustomerNeedsQueryBuilder<'a, T> {
+    CustomerNeedsQueryBuilder {
         client,
         filter: None,
         before: None,
@@ -1335,33 +10433,50 @@ pub fn project_milestones<'a, T>(client: &'a Client) -> ProjectMilestonesQueryBu
         last: None,
         include_archived: None,
         order_by: None,
+        extra_variables: serde_json::Map::new(),
         _marker: std::marker::PhantomData,
     }
 }
-/// Returns a single project milestone by its identifier.
+/// Retrieves a single customer need by ID or hash.
 ///
-/// Full type: [`ProjectMilestone`](super::types::ProjectMilestone)
-pub async fn project_milestone<
-    T: DeserializeOwned + GraphQLFields<FullType = super::types::ProjectMilestone>,
+/// Full type: [`CustomerNeed`](super::types::CustomerNeed)
+///
+/// Requires the `customers` feature.
+#[cfg(feature = "customers")]
+pub fn customer_need<'a, T>(client: &'a Client) -> CustomerNeedQueryBuilder<'a, T> {
+    CustomerNeedQueryBuilder {
+        client,
+        id: None,
+        hash: None,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Suggests an issue title based on a customer request message using AI summarization.
+///
+/// Full type: [`IssueTitleSuggestionFromCustomerRequestPayload`](super::types::IssueTitleSuggestionFromCustomerRequestPayload)
+///
+/// Requires the `customers` feature.
+#[cfg(feature = "customers")]
+pub async fn issue_title_suggestion_from_customer_request<
+    T: DeserializeOwned
+        + GraphQLFields<FullType = super::types::IssueTitleSuggestionFromCustomerRequestPayload>,
 >(
     client: &Client,
-    id: String,
+    request: String,
 ) -> Result<T, LinearError> {
-    let variables = serde_json::json!({ "id" : id });
-    let selection = T::selection();
-    let query = format!(
-        "query {}({}) {{ {}({}) {{ {} }} }}",
-        "ProjectMilestone", "$id: String!", "projectMilestone", "id: $id", selection
-    );
-    client
-        .execute::<T>(&query, variables, "projectMilestone")
+    super::operations::issue_title_suggestion_from_customer_request::<T>(request)
+        .execute(client)
         .await
 }
-/// Returns all project labels in the workspace, with optional filtering.
+/// All custom views accessible to the user, including personal views and shared workspace views. Excludes views scoped to a specific project or initiative.
 ///
-/// Full type: [`ProjectLabel`](super::types::ProjectLabel)
-pub fn project_labels<'a, T>(client: &'a Client) -> ProjectLabelsQueryBuilder<'a, T> {
-    ProjectLabelsQueryBuilder {
+/// Full type: [`CustomView`](super::types::CustomView)
+///
+/// Requires the `views` feature.
+#[cfg(feature = "views")]
+pub fn custom_views<'a, T>(client: &'a Client) -> CustomViewsQueryBuilder<'a, T> {
+    CustomViewsQueryBuilder {
         client,
         filter: None,
         before: None,
@@ -1370,14 +10485,69 @@ pub fn project_labels<'a, T>(client: &'a Client) -> ProjectLabelsQueryBuilder<'a
         last: None,
         include_archived: None,
         order_by: None,
+        sort: None,
+        extra_variables: serde_json::Map::new(),
         _marker: std::marker::PhantomData,
     }
 }
-/// All issues. Returns a paginated list of issues visible to the authenticated user. Can be filtered by various criteria including team, assignee, state, labels, project, and cycle.
+/// One specific custom view, looked up by ID or slug.
 ///
-/// Full type: [`Issue`](super::types::Issue)
-pub fn issues<'a, T>(client: &'a Client) -> IssuesQueryBuilder<'a, T> {
-    IssuesQueryBuilder {
+/// Full type: [`CustomView`](super::types::CustomView)
+///
+/// Requires the `views` feature.
+#[cfg(feature = "views")]
+pub async fn custom_view<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::CustomView>,
+>(
+    client: &Client,
+    id: impl Into<super::ids::CustomViewId>,
+) -> Result<T, LinearError> {
+    super::operations::custom_view::<T>(id)
+        .execute(client)
+        .await
+}
+/// `INTERNAL` Suggests metadata (name, description, icon) for a view based on its filters using AI. Rate-limited to 30 requests per minute.
+///
+/// Full type: [`CustomViewSuggestionPayload`](super::types::CustomViewSuggestionPayload)
+///
+/// Requires the `views` feature.
+#[cfg(feature = "views")]
+pub fn custom_view_details_suggestion<'a, T>(
+    client: &'a Client,
+    filter: serde_json::Value,
+) -> CustomViewDetailsSuggestionQueryBuilder<'a, T> {
+    CustomViewDetailsSuggestionQueryBuilder {
+        client,
+        filter,
+        model_name: None,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Whether a custom view has active notification subscribers other than the current user. Useful for warning before deleting a shared view.
+///
+/// Full type: [`CustomViewHasSubscribersPayload`](super::types::CustomViewHasSubscribersPayload)
+///
+/// Requires the `views` feature.
+#[cfg(feature = "views")]
+pub async fn custom_view_has_subscribers<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::CustomViewHasSubscribersPayload>,
+>(
+    client: &Client,
+    id: impl Into<super::ids::CustomViewId>,
+) -> Result<T, LinearError> {
+    super::operations::custom_view_has_subscribers::<T>(id)
+        .execute(client)
+        .await
+}
+/// All comments the user has access to in the workspace.
+///
+/// Full type: [`Comment`](super::types::Comment)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub fn comments<'a, T>(client: &'a Client) -> CommentsQueryBuilder<'a, T> {
+    CommentsQueryBuilder {
         client,
         filter: None,
         before: None,
@@ -1386,134 +10556,278 @@ pub fn issues<'a, T>(client: &'a Client) -> IssuesQueryBuilder<'a, T> {
         last: None,
         include_archived: None,
         order_by: None,
-        sort: None,
+        extra_variables: serde_json::Map::new(),
         _marker: std::marker::PhantomData,
     }
 }
-/// One specific issue, looked up by its unique identifier.
+/// A specific comment.
 ///
-/// Full type: [`Issue`](super::types::Issue)
-pub async fn issue<T: DeserializeOwned + GraphQLFields<FullType = super::types::Issue>>(
+/// Full type: [`Comment`](super::types::Comment)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub fn comment<'a, T>(client: &'a Client) -> CommentQueryBuilder<'a, T> {
+    CommentQueryBuilder {
+        client,
+        id: None,
+        hash: None,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Fetch users belonging to this user account.
+///
+/// Full type: [`AuthResolverResponse`](super::types::AuthResolverResponse)
+///
+/// Requires the `users` feature.
+#[cfg(feature = "users")]
+pub async fn available_users<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::AuthResolverResponse>,
+>(
     client: &Client,
-    id: String,
 ) -> Result<T, LinearError> {
-    let variables = serde_json::json!({ "id" : id });
-    let selection = T::selection();
-    let query = format!(
-        "query {}({}) {{ {}({}) {{ {} }} }}",
-        "Issue", "$id: String!", "issue", "id: $id", selection
-    );
-    client.execute::<T>(&query, variables, "issue").await
+    super::operations::available_users::<T>()
+        .execute(client)
+        .await
 }
-/// Find issue based on the VCS branch name.
+/// User's active sessions.
 ///
-/// Full type: [`Issue`](super::types::Issue)
-pub async fn issue_vcs_branch_search<
-    T: DeserializeOwned + GraphQLFields<FullType = super::types::Issue>,
+/// Full type: [`AuthenticationSessionResponse`](super::types::AuthenticationSessionResponse)
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub async fn authentication_sessions<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::AuthenticationSessionResponse>,
 >(
     client: &Client,
-    branch_name: String,
-) -> Result<Option<T>, LinearError> {
-    let variables = serde_json::json!({ "branchName" : branch_name });
-    let selection = T::selection();
-    let query = format!(
-        "query {}({}) {{ {}({}) {{ {} }} }}",
-        "IssueVcsBranchSearch",
-        "$branchName: String!",
-        "issueVcsBranchSearch",
-        "branchName: $branchName",
-        selection
-    );
-    client
-        .execute::<Option<T>>(&query, variables, "issueVcsBranchSearch")
+) -> Result<Vec<T>, LinearError> {
+    super::operations::authentication_sessions::<T>()
+        .execute(client)
         .await
 }
-/// All issue relations. Returns a paginated list of all issue relations (blocks, blocked by, relates to, duplicates) visible to the authenticated user.
+/// Fetch SSO login URL for the email provided.
 ///
-/// Full type: [`IssueRelation`](super::types::IssueRelation)
-pub fn issue_relations<'a, T>(client: &'a Client) -> IssueRelationsQueryBuilder<'a, T> {
-    IssueRelationsQueryBuilder {
+/// Full type: [`SsoUrlFromEmailResponse`](super::types::SsoUrlFromEmailResponse)
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub fn sso_url_from_email<'a, T>(
+    client: &'a Client,
+    r#type: IdentityProviderType,
+    email: impl Into<String>,
+) -> SsoUrlFromEmailQueryBuilder<'a, T> {
+    SsoUrlFromEmailQueryBuilder {
         client,
+        r#type,
+        email: email.into(),
+        is_desktop: None,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// List of audit entry types.
+///
+/// Full type: [`AuditEntryType`](super::types::AuditEntryType)
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub async fn audit_entry_types<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::AuditEntryType>,
+>(
+    client: &Client,
+) -> Result<Vec<T>, LinearError> {
+    super::operations::audit_entry_types::<T>()
+        .execute(client)
+        .await
+}
+/// All audit log entries.
+///
+/// Full type: [`AuditEntry`](super::types::AuditEntry)
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub fn audit_entries<'a, T>(client: &'a Client) -> AuditEntriesQueryBuilder<'a, T> {
+    AuditEntriesQueryBuilder {
+        client,
+        filter: None,
         before: None,
         after: None,
         first: None,
         last: None,
         include_archived: None,
         order_by: None,
+        extra_variables: serde_json::Map::new(),
         _marker: std::marker::PhantomData,
     }
 }
-/// One specific issue relation, looked up by its unique identifier.
+/// All issue attachments.
 ///
-/// Full type: [`IssueRelation`](super::types::IssueRelation)
-pub async fn issue_relation<
-    T: DeserializeOwned + GraphQLFields<FullType = super::types::IssueRelation>,
+/// To get attachments for a given URL, use `attachmentsForURL` query.
+///
+/// Full type: [`Attachment`](super::types::Attachment)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub fn attachments<'a, T>(client: &'a Client) -> AttachmentsQueryBuilder<'a, T> {
+    AttachmentsQueryBuilder {
+        client,
+        filter: None,
+        before: None,
+        after: None,
+        first: None,
+        last: None,
+        include_archived: None,
+        order_by: None,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// One specific issue attachment.
+/// `Deprecated` 'url' can no longer be used as the 'id' parameter. Use 'attachmentsForUrl' instead
+///
+/// Full type: [`Attachment`](super::types::Attachment)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub async fn attachment<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::Attachment>,
 >(
     client: &Client,
-    id: String,
+    id: impl Into<super::ids::AttachmentId>,
 ) -> Result<T, LinearError> {
-    let variables = serde_json::json!({ "id" : id });
-    let selection = T::selection();
-    let query = format!(
-        "query {}({}) {{ {}({}) {{ {} }} }}",
-        "IssueRelation", "$id: String!", "issueRelation", "id: $id", selection
-    );
-    client
-        .execute::<T>(&query, variables, "issueRelation")
-        .await
+    super::operations::attachment::<T>(id).execute(client).await
 }
-/// All issue labels. Returns a paginated list of labels visible to the authenticated user, including both workspace-level and team-scoped labels.
+/// Returns issue attachments for a given `url`.
 ///
-/// Full type: [`IssueLabel`](super::types::IssueLabel)
-pub fn issue_labels<'a, T>(client: &'a Client) -> IssueLabelsQueryBuilder<'a, T> {
-    IssueLabelsQueryBuilder {
+/// Full type: [`Attachment`](super::types::Attachment)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub fn attachments_for_url<'a, T>(
+    client: &'a Client,
+    url: impl Into<String>,
+) -> AttachmentsForUrlQueryBuilder<'a, T> {
+    AttachmentsForUrlQueryBuilder {
         client,
-        filter: None,
+        url: url.into(),
         before: None,
         after: None,
         first: None,
         last: None,
         include_archived: None,
         order_by: None,
+        extra_variables: serde_json::Map::new(),
         _marker: std::marker::PhantomData,
     }
 }
-/// All documents the user has access to in the workspace.
+/// Query an issue by its associated attachment, and its id.
 ///
-/// Full type: [`Document`](super::types::Document)
-pub fn documents<'a, T>(client: &'a Client) -> DocumentsQueryBuilder<'a, T> {
-    DocumentsQueryBuilder {
+/// Full type: [`Issue`](super::types::Issue)
+#[deprecated(
+    note = "Will be removed in near future, please use `attachmentsForURL` to get attachments and their issues instead."
+)]
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub async fn attachment_issue<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::Issue>,
+>(
+    client: &Client,
+    id: impl Into<super::ids::IssueId>,
+) -> Result<T, LinearError> {
+    super::operations::attachment_issue::<T>(id)
+        .execute(client)
+        .await
+}
+/// `Internal` Get a list of all unique attachment sources in the workspace.
+///
+/// Full type: [`AttachmentSourcesPayload`](super::types::AttachmentSourcesPayload)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub fn attachment_sources<'a, T>(client: &'a Client) -> AttachmentSourcesQueryBuilder<'a, T> {
+    AttachmentSourcesQueryBuilder {
+        client,
+        team_id: None,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Retrieves public information about an OAuth application by its client ID. Used during the authorization flow to display application details to the user.
+///
+/// Full type: [`Application`](super::types::Application)
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub async fn application_info<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::Application>,
+>(
+    client: &Client,
+    client_id: String,
+) -> Result<T, LinearError> {
+    super::operations::application_info::<T>(client_id)
+        .execute(client)
+        .await
+}
+/// All agent sessions.
+///
+/// Full type: [`AgentSession`](super::types::AgentSession)
+///
+/// Requires the `agents` feature.
+#[cfg(feature = "agents")]
+pub fn agent_sessions<'a, T>(client: &'a Client) -> AgentSessionsQueryBuilder<'a, T> {
+    AgentSessionsQueryBuilder {
         client,
-        filter: None,
         before: None,
         after: None,
         first: None,
         last: None,
         include_archived: None,
         order_by: None,
+        extra_variables: serde_json::Map::new(),
         _marker: std::marker::PhantomData,
     }
 }
-/// A specific document by ID or slug.
+/// A specific agent session.
 ///
-/// Full type: [`Document`](super::types::Document)
-pub async fn document<T: DeserializeOwned + GraphQLFields<FullType = super::types::Document>>(
+/// Full type: [`AgentSession`](super::types::AgentSession)
+///
+/// Requires the `agents` feature.
+#[cfg(feature = "agents")]
+pub async fn agent_session<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::AgentSession>,
+>(
     client: &Client,
-    id: String,
+    id: impl Into<super::ids::AgentSessionId>,
 ) -> Result<T, LinearError> {
-    let variables = serde_json::json!({ "id" : id });
-    let selection = T::selection();
-    let query = format!(
-        "query {}({}) {{ {}({}) {{ {} }} }}",
-        "Document", "$id: String!", "document", "id: $id", selection
-    );
-    client.execute::<T>(&query, variables, "document").await
+    super::operations::agent_session::<T>(id)
+        .execute(client)
+        .await
 }
-/// All cycles accessible to the user.
+/// `Internal` Retrieves coding agent sandbox details for a given agent session ID.
 ///
-/// Full type: [`Cycle`](super::types::Cycle)
-pub fn cycles<'a, T>(client: &'a Client) -> CyclesQueryBuilder<'a, T> {
-    CyclesQueryBuilder {
+/// Full type: [`CodingAgentSandboxPayload`](super::types::CodingAgentSandboxPayload)
+///
+/// Requires the `agents` feature.
+#[cfg(feature = "agents")]
+pub async fn agent_session_sandbox<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::CodingAgentSandboxPayload>,
+>(
+    client: &Client,
+    agent_session_id: String,
+) -> Result<Option<T>, LinearError> {
+    super::operations::agent_session_sandbox::<T>(agent_session_id)
+        .execute(client)
+        .await
+}
+/// All agent activities.
+///
+/// Full type: [`AgentActivity`](super::types::AgentActivity)
+///
+/// Requires the `agents` feature.
+#[cfg(feature = "agents")]
+pub fn agent_activities<'a, T>(client: &'a Client) -> AgentActivitiesQueryBuilder<'a, T> {
+    AgentActivitiesQueryBuilder {
         client,
         filter: None,
         before: None,
@@ -1522,21 +10836,23 @@ pub fn cycles<'a, T>(client: &'a Client) -> CyclesQueryBuilder<'a, T> {
         last: None,
         include_archived: None,
         order_by: None,
+        extra_variables: serde_json::Map::new(),
         _marker: std::marker::PhantomData,
     }
 }
-/// One specific cycle, looked up by ID or slug.
+/// A specific agent activity.
 ///
-/// Full type: [`Cycle`](super::types::Cycle)
-pub async fn cycle<T: DeserializeOwned + GraphQLFields<FullType = super::types::Cycle>>(
+/// Full type: [`AgentActivity`](super::types::AgentActivity)
+///
+/// Requires the `agents` feature.
+#[cfg(feature = "agents")]
+pub async fn agent_activity<
+    T: DeserializeOwned + GraphQLFields<FullType = super::types::AgentActivity>,
+>(
     client: &Client,
-    id: String,
+    id: impl Into<super::ids::AgentActivityId>,
 ) -> Result<T, LinearError> {
-    let variables = serde_json::json!({ "id" : id });
-    let selection = T::selection();
-    let query = format!(
-        "query {}({}) {{ {}({}) {{ {} }} }}",
-        "Cycle", "$id: String!", "cycle", "id: $id", selection
-    );
-    client.execute::<T>(&query, variables, "cycle").await
+    super::operations::agent_activity::<T>(id)
+        .execute(client)
+        .await
 }
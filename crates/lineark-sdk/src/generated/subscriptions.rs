@@ -0,0 +1,4453 @@
+//! Generated subscription builders.
+//!
+//! Each subscription is started through a builder whose
+//! `subscribe()` returns a [`Subscription`] stream of events, typed
+//! by any `T: DeserializeOwned + GraphQLFields` for the event type.
+//!
+//! Generated by lineark-codegen — do not edit.
+use super::inputs::*;
+use crate::client::Client;
+use crate::field_selection::GraphQLFields;
+use crate::subscription::{Subscription, SubscriptionOperation};
+use serde::de::DeserializeOwned;
+/// Subscription builder: Triggered when a comment is created
+///
+/// Event type: [`Comment`](super::types::Comment)
+///
+/// Use setter methods to configure optional arguments, then call
+/// [`.subscribe()`](Self::subscribe) to start receiving events.
+#[must_use]
+pub struct CommentCreatedSubscriptionBuilder<'a, T> {
+    client: &'a Client,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Comment>>
+    CommentCreatedSubscriptionBuilder<'a, T>
+{
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the subscription without starting it, e.g. to set its
+    /// reconnect policy.
+    pub fn into_operation(self) -> SubscriptionOperation<T> {
+        let map = self.extra_variables;
+        let selection = format!("{} {{ {} }}", "commentCreated", T::selection());
+        SubscriptionOperation::new(
+            "CommentCreated",
+            "",
+            selection,
+            serde_json::Value::Object(map),
+            "commentCreated",
+        )
+    }
+    /// Connect and start receiving events. Must be called within a
+    /// Tokio runtime.
+    pub fn subscribe(self) -> Subscription<T> {
+        let client = self.client;
+        self.into_operation().subscribe(client)
+    }
+}
+/// Subscription builder: Triggered when a comment is updated
+///
+/// Event type: [`Comment`](super::types::Comment)
+///
+/// Use setter methods to configure optional arguments, then call
+/// [`.subscribe()`](Self::subscribe) to start receiving events.
+#[must_use]
+pub struct CommentUpdatedSubscriptionBuilder<'a, T> {
+    client: &'a Client,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Comment>>
+    CommentUpdatedSubscriptionBuilder<'a, T>
+{
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the subscription without starting it, e.g. to set its
+    /// reconnect policy.
+    pub fn into_operation(self) -> SubscriptionOperation<T> {
+        let map = self.extra_variables;
+        let selection = format!("{} {{ {} }}", "commentUpdated", T::selection());
+        SubscriptionOperation::new(
+            "CommentUpdated",
+            "",
+            selection,
+            serde_json::Value::Object(map),
+            "commentUpdated",
+        )
+    }
+    /// Connect and start receiving events. Must be called within a
+    /// Tokio runtime.
+    pub fn subscribe(self) -> Subscription<T> {
+        let client = self.client;
+        self.into_operation().subscribe(client)
+    }
+}
+/// Subscription builder: Triggered when a comment is archived
+///
+/// Event type: [`Comment`](super::types::Comment)
+///
+/// Use setter methods to configure optional arguments, then call
+/// [`.subscribe()`](Self::subscribe) to start receiving events.
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+#[must_use]
+pub struct CommentArchivedSubscriptionBuilder<'a, T> {
+    client: &'a Client,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "issues")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Comment>>
+    CommentArchivedSubscriptionBuilder<'a, T>
+{
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the subscription without starting it, e.g. to set its
+    /// reconnect policy.
+    pub fn into_operation(self) -> SubscriptionOperation<T> {
+        let map = self.extra_variables;
+        let selection = format!("{} {{ {} }}", "commentArchived", T::selection());
+        SubscriptionOperation::new(
+            "CommentArchived",
+            "",
+            selection,
+            serde_json::Value::Object(map),
+            "commentArchived",
+        )
+    }
+    /// Connect and start receiving events. Must be called within a
+    /// Tokio runtime.
+    pub fn subscribe(self) -> Subscription<T> {
+        let client = self.client;
+        self.into_operation().subscribe(client)
+    }
+}
+/// Subscription builder: Triggered when a a comment is unarchived
+///
+/// Event type: [`Comment`](super::types::Comment)
+///
+/// Use setter methods to configure optional arguments, then call
+/// [`.subscribe()`](Self::subscribe) to start receiving events.
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+#[must_use]
+pub struct CommentUnarchivedSubscriptionBuilder<'a, T> {
+    client: &'a Client,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "issues")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Comment>>
+    CommentUnarchivedSubscriptionBuilder<'a, T>
+{
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the subscription without starting it, e.g. to set its
+    /// reconnect policy.
+    pub fn into_operation(self) -> SubscriptionOperation<T> {
+        let map = self.extra_variables;
+        let selection = format!("{} {{ {} }}", "commentUnarchived", T::selection());
+        SubscriptionOperation::new(
+            "CommentUnarchived",
+            "",
+            selection,
+            serde_json::Value::Object(map),
+            "commentUnarchived",
+        )
+    }
+    /// Connect and start receiving events. Must be called within a
+    /// Tokio runtime.
+    pub fn subscribe(self) -> Subscription<T> {
+        let client = self.client;
+        self.into_operation().subscribe(client)
+    }
+}
+/// Subscription builder: Triggered when a comment is deleted
+///
+/// Event type: [`Comment`](super::types::Comment)
+///
+/// Use setter methods to configure optional arguments, then call
+/// [`.subscribe()`](Self::subscribe) to start receiving events.
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+#[must_use]
+pub struct CommentDeletedSubscriptionBuilder<'a, T> {
+    client: &'a Client,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "issues")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Comment>>
+    CommentDeletedSubscriptionBuilder<'a, T>
+{
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the subscription without starting it, e.g. to set its
+    /// reconnect policy.
+    pub fn into_operation(self) -> SubscriptionOperation<T> {
+        let map = self.extra_variables;
+        let selection = format!("{} {{ {} }}", "commentDeleted", T::selection());
+        SubscriptionOperation::new(
+            "CommentDeleted",
+            "",
+            selection,
+            serde_json::Value::Object(map),
+            "commentDeleted",
+        )
+    }
+    /// Connect and start receiving events. Must be called within a
+    /// Tokio runtime.
+    pub fn subscribe(self) -> Subscription<T> {
+        let client = self.client;
+        self.into_operation().subscribe(client)
+    }
+}
+/// Subscription builder: Triggered when a cycle is created
+///
+/// Event type: [`Cycle`](super::types::Cycle)
+///
+/// Use setter methods to configure optional arguments, then call
+/// [`.subscribe()`](Self::subscribe) to start receiving events.
+///
+/// Requires the `cycles` feature.
+#[cfg(feature = "cycles")]
+#[must_use]
+pub struct CycleCreatedSubscriptionBuilder<'a, T> {
+    client: &'a Client,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "cycles")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Cycle>>
+    CycleCreatedSubscriptionBuilder<'a, T>
+{
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the subscription without starting it, e.g. to set its
+    /// reconnect policy.
+    pub fn into_operation(self) -> SubscriptionOperation<T> {
+        let map = self.extra_variables;
+        let selection = format!("{} {{ {} }}", "cycleCreated", T::selection());
+        SubscriptionOperation::new(
+            "CycleCreated",
+            "",
+            selection,
+            serde_json::Value::Object(map),
+            "cycleCreated",
+        )
+    }
+    /// Connect and start receiving events. Must be called within a
+    /// Tokio runtime.
+    pub fn subscribe(self) -> Subscription<T> {
+        let client = self.client;
+        self.into_operation().subscribe(client)
+    }
+}
+/// Subscription builder: Triggered when a cycle is updated
+///
+/// Event type: [`Cycle`](super::types::Cycle)
+///
+/// Use setter methods to configure optional arguments, then call
+/// [`.subscribe()`](Self::subscribe) to start receiving events.
+///
+/// Requires the `cycles` feature.
+#[cfg(feature = "cycles")]
+#[must_use]
+pub struct CycleUpdatedSubscriptionBuilder<'a, T> {
+    client: &'a Client,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "cycles")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Cycle>>
+    CycleUpdatedSubscriptionBuilder<'a, T>
+{
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the subscription without starting it, e.g. to set its
+    /// reconnect policy.
+    pub fn into_operation(self) -> SubscriptionOperation<T> {
+        let map = self.extra_variables;
+        let selection = format!("{} {{ {} }}", "cycleUpdated", T::selection());
+        SubscriptionOperation::new(
+            "CycleUpdated",
+            "",
+            selection,
+            serde_json::Value::Object(map),
+            "cycleUpdated",
+        )
+    }
+    /// Connect and start receiving events. Must be called within a
+    /// Tokio runtime.
+    pub fn subscribe(self) -> Subscription<T> {
+        let client = self.client;
+        self.into_operation().subscribe(client)
+    }
+}
+/// Subscription builder: Triggered when a cycle is archived
+///
+/// Event type: [`Cycle`](super::types::Cycle)
+///
+/// Use setter methods to configure optional arguments, then call
+/// [`.subscribe()`](Self::subscribe) to start receiving events.
+///
+/// Requires the `cycles` feature.
+#[cfg(feature = "cycles")]
+#[must_use]
+pub struct CycleArchivedSubscriptionBuilder<'a, T> {
+    client: &'a Client,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "cycles")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Cycle>>
+    CycleArchivedSubscriptionBuilder<'a, T>
+{
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the subscription without starting it, e.g. to set its
+    /// reconnect policy.
+    pub fn into_operation(self) -> SubscriptionOperation<T> {
+        let map = self.extra_variables;
+        let selection = format!("{} {{ {} }}", "cycleArchived", T::selection());
+        SubscriptionOperation::new(
+            "CycleArchived",
+            "",
+            selection,
+            serde_json::Value::Object(map),
+            "cycleArchived",
+        )
+    }
+    /// Connect and start receiving events. Must be called within a
+    /// Tokio runtime.
+    pub fn subscribe(self) -> Subscription<T> {
+        let client = self.client;
+        self.into_operation().subscribe(client)
+    }
+}
+/// Subscription builder: Triggered when a document is created
+///
+/// Event type: [`Document`](super::types::Document)
+///
+/// Use setter methods to configure optional arguments, then call
+/// [`.subscribe()`](Self::subscribe) to start receiving events.
+///
+/// Requires the `documents` feature.
+#[cfg(feature = "documents")]
+#[must_use]
+pub struct DocumentCreatedSubscriptionBuilder<'a, T> {
+    client: &'a Client,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "documents")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Document>>
+    DocumentCreatedSubscriptionBuilder<'a, T>
+{
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the subscription without starting it, e.g. to set its
+    /// reconnect policy.
+    pub fn into_operation(self) -> SubscriptionOperation<T> {
+        let map = self.extra_variables;
+        let selection = format!("{} {{ {} }}", "documentCreated", T::selection());
+        SubscriptionOperation::new(
+            "DocumentCreated",
+            "",
+            selection,
+            serde_json::Value::Object(map),
+            "documentCreated",
+        )
+    }
+    /// Connect and start receiving events. Must be called within a
+    /// Tokio runtime.
+    pub fn subscribe(self) -> Subscription<T> {
+        let client = self.client;
+        self.into_operation().subscribe(client)
+    }
+}
+/// Subscription builder: Triggered when a document is updated
+///
+/// Event type: [`Document`](super::types::Document)
+///
+/// Use setter methods to configure optional arguments, then call
+/// [`.subscribe()`](Self::subscribe) to start receiving events.
+///
+/// Requires the `documents` feature.
+#[cfg(feature = "documents")]
+#[must_use]
+pub struct DocumentUpdatedSubscriptionBuilder<'a, T> {
+    client: &'a Client,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "documents")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Document>>
+    DocumentUpdatedSubscriptionBuilder<'a, T>
+{
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the subscription without starting it, e.g. to set its
+    /// reconnect policy.
+    pub fn into_operation(self) -> SubscriptionOperation<T> {
+        let map = self.extra_variables;
+        let selection = format!("{} {{ {} }}", "documentUpdated", T::selection());
+        SubscriptionOperation::new(
+            "DocumentUpdated",
+            "",
+            selection,
+            serde_json::Value::Object(map),
+            "documentUpdated",
+        )
+    }
+    /// Connect and start receiving events. Must be called within a
+    /// Tokio runtime.
+    pub fn subscribe(self) -> Subscription<T> {
+        let client = self.client;
+        self.into_operation().subscribe(client)
+    }
+}
+/// Subscription builder: Triggered when a document is archived
+///
+/// Event type: [`Document`](super::types::Document)
+///
+/// Use setter methods to configure optional arguments, then call
+/// [`.subscribe()`](Self::subscribe) to start receiving events.
+///
+/// Requires the `documents` feature.
+#[cfg(feature = "documents")]
+#[must_use]
+pub struct DocumentArchivedSubscriptionBuilder<'a, T> {
+    client: &'a Client,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "documents")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Document>>
+    DocumentArchivedSubscriptionBuilder<'a, T>
+{
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the subscription without starting it, e.g. to set its
+    /// reconnect policy.
+    pub fn into_operation(self) -> SubscriptionOperation<T> {
+        let map = self.extra_variables;
+        let selection = format!("{} {{ {} }}", "documentArchived", T::selection());
+        SubscriptionOperation::new(
+            "DocumentArchived",
+            "",
+            selection,
+            serde_json::Value::Object(map),
+            "documentArchived",
+        )
+    }
+    /// Connect and start receiving events. Must be called within a
+    /// Tokio runtime.
+    pub fn subscribe(self) -> Subscription<T> {
+        let client = self.client;
+        self.into_operation().subscribe(client)
+    }
+}
+/// Subscription builder: Triggered when a a document is unarchived
+///
+/// Event type: [`Document`](super::types::Document)
+///
+/// Use setter methods to configure optional arguments, then call
+/// [`.subscribe()`](Self::subscribe) to start receiving events.
+///
+/// Requires the `documents` feature.
+#[cfg(feature = "documents")]
+#[must_use]
+pub struct DocumentUnarchivedSubscriptionBuilder<'a, T> {
+    client: &'a Client,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "documents")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Document>>
+    DocumentUnarchivedSubscriptionBuilder<'a, T>
+{
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the subscription without starting it, e.g. to set its
+    /// reconnect policy.
+    pub fn into_operation(self) -> SubscriptionOperation<T> {
+        let map = self.extra_variables;
+        let selection = format!("{} {{ {} }}", "documentUnarchived", T::selection());
+        SubscriptionOperation::new(
+            "DocumentUnarchived",
+            "",
+            selection,
+            serde_json::Value::Object(map),
+            "documentUnarchived",
+        )
+    }
+    /// Connect and start receiving events. Must be called within a
+    /// Tokio runtime.
+    pub fn subscribe(self) -> Subscription<T> {
+        let client = self.client;
+        self.into_operation().subscribe(client)
+    }
+}
+/// Subscription builder: Triggered when a document content is created
+///
+/// Event type: [`DocumentContent`](super::types::DocumentContent)
+///
+/// Use setter methods to configure optional arguments, then call
+/// [`.subscribe()`](Self::subscribe) to start receiving events.
+///
+/// Requires the `documents` feature.
+#[cfg(feature = "documents")]
+#[must_use]
+pub struct DocumentContentCreatedSubscriptionBuilder<'a, T> {
+    client: &'a Client,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "documents")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::DocumentContent>>
+    DocumentContentCreatedSubscriptionBuilder<'a, T>
+{
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the subscription without starting it, e.g. to set its
+    /// reconnect policy.
+    pub fn into_operation(self) -> SubscriptionOperation<T> {
+        let map = self.extra_variables;
+        let selection = format!("{} {{ {} }}", "documentContentCreated", T::selection());
+        SubscriptionOperation::new(
+            "DocumentContentCreated",
+            "",
+            selection,
+            serde_json::Value::Object(map),
+            "documentContentCreated",
+        )
+    }
+    /// Connect and start receiving events. Must be called within a
+    /// Tokio runtime.
+    pub fn subscribe(self) -> Subscription<T> {
+        let client = self.client;
+        self.into_operation().subscribe(client)
+    }
+}
+/// Subscription builder: Triggered when a document content is updated
+///
+/// Event type: [`DocumentContent`](super::types::DocumentContent)
+///
+/// Use setter methods to configure optional arguments, then call
+/// [`.subscribe()`](Self::subscribe) to start receiving events.
+#[must_use]
+pub struct DocumentContentUpdatedSubscriptionBuilder<'a, T> {
+    client: &'a Client,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::DocumentContent>>
+    DocumentContentUpdatedSubscriptionBuilder<'a, T>
+{
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the subscription without starting it, e.g. to set its
+    /// reconnect policy.
+    pub fn into_operation(self) -> SubscriptionOperation<T> {
+        let map = self.extra_variables;
+        let selection = format!("{} {{ {} }}", "documentContentUpdated", T::selection());
+        SubscriptionOperation::new(
+            "DocumentContentUpdated",
+            "",
+            selection,
+            serde_json::Value::Object(map),
+            "documentContentUpdated",
+        )
+    }
+    /// Connect and start receiving events. Must be called within a
+    /// Tokio runtime.
+    pub fn subscribe(self) -> Subscription<T> {
+        let client = self.client;
+        self.into_operation().subscribe(client)
+    }
+}
+/// Subscription builder: Triggered when a document content draft is created
+///
+/// Event type: [`DocumentContentDraft`](super::types::DocumentContentDraft)
+///
+/// Use setter methods to configure optional arguments, then call
+/// [`.subscribe()`](Self::subscribe) to start receiving events.
+///
+/// Requires the `documents` feature.
+#[cfg(feature = "documents")]
+#[must_use]
+pub struct DocumentContentDraftCreatedSubscriptionBuilder<'a, T> {
+    client: &'a Client,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "documents")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::DocumentContentDraft>>
+    DocumentContentDraftCreatedSubscriptionBuilder<'a, T>
+{
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the subscription without starting it, e.g. to set its
+    /// reconnect policy.
+    pub fn into_operation(self) -> SubscriptionOperation<T> {
+        let map = self.extra_variables;
+        let selection = format!("{} {{ {} }}", "documentContentDraftCreated", T::selection());
+        SubscriptionOperation::new(
+            "DocumentContentDraftCreated",
+            "",
+            selection,
+            serde_json::Value::Object(map),
+            "documentContentDraftCreated",
+        )
+    }
+    /// Connect and start receiving events. Must be called within a
+    /// Tokio runtime.
+    pub fn subscribe(self) -> Subscription<T> {
+        let client = self.client;
+        self.into_operation().subscribe(client)
+    }
+}
+/// Subscription builder: Triggered when a document content draft is updated
+///
+/// Event type: [`DocumentContentDraft`](super::types::DocumentContentDraft)
+///
+/// Use setter methods to configure optional arguments, then call
+/// [`.subscribe()`](Self::subscribe) to start receiving events.
+///
+/// Requires the `documents` feature.
+#[cfg(feature = "documents")]
+#[must_use]
+pub struct DocumentContentDraftUpdatedSubscriptionBuilder<'a, T> {
+    client: &'a Client,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "documents")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::DocumentContentDraft>>
+    DocumentContentDraftUpdatedSubscriptionBuilder<'a, T>
+{
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the subscription without starting it, e.g. to set its
+    /// reconnect policy.
+    pub fn into_operation(self) -> SubscriptionOperation<T> {
+        let map = self.extra_variables;
+        let selection = format!("{} {{ {} }}", "documentContentDraftUpdated", T::selection());
+        SubscriptionOperation::new(
+            "DocumentContentDraftUpdated",
+            "",
+            selection,
+            serde_json::Value::Object(map),
+            "documentContentDraftUpdated",
+        )
+    }
+    /// Connect and start receiving events. Must be called within a
+    /// Tokio runtime.
+    pub fn subscribe(self) -> Subscription<T> {
+        let client = self.client;
+        self.into_operation().subscribe(client)
+    }
+}
+/// Subscription builder: Triggered when a document content draft is deleted
+///
+/// Event type: [`DocumentContentDraft`](super::types::DocumentContentDraft)
+///
+/// Use setter methods to configure optional arguments, then call
+/// [`.subscribe()`](Self::subscribe) to start receiving events.
+///
+/// Requires the `documents` feature.
+#[cfg(feature = "documents")]
+#[must_use]
+pub struct DocumentContentDraftDeletedSubscriptionBuilder<'a, T> {
+    client: &'a Client,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "documents")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::DocumentContentDraft>>
+    DocumentContentDraftDeletedSubscriptionBuilder<'a, T>
+{
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the subscription without starting it, e.g. to set its
+    /// reconnect policy.
+    pub fn into_operation(self) -> SubscriptionOperation<T> {
+        let map = self.extra_variables;
+        let selection = format!("{} {{ {} }}", "documentContentDraftDeleted", T::selection());
+        SubscriptionOperation::new(
+            "DocumentContentDraftDeleted",
+            "",
+            selection,
+            serde_json::Value::Object(map),
+            "documentContentDraftDeleted",
+        )
+    }
+    /// Connect and start receiving events. Must be called within a
+    /// Tokio runtime.
+    pub fn subscribe(self) -> Subscription<T> {
+        let client = self.client;
+        self.into_operation().subscribe(client)
+    }
+}
+/// Subscription builder: Triggered when a draft is created
+///
+/// Event type: [`Draft`](super::types::Draft)
+///
+/// Use setter methods to configure optional arguments, then call
+/// [`.subscribe()`](Self::subscribe) to start receiving events.
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+#[must_use]
+pub struct DraftCreatedSubscriptionBuilder<'a, T> {
+    client: &'a Client,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "issues")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Draft>>
+    DraftCreatedSubscriptionBuilder<'a, T>
+{
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the subscription without starting it, e.g. to set its
+    /// reconnect policy.
+    pub fn into_operation(self) -> SubscriptionOperation<T> {
+        let map = self.extra_variables;
+        let selection = format!("{} {{ {} }}", "draftCreated", T::selection());
+        SubscriptionOperation::new(
+            "DraftCreated",
+            "",
+            selection,
+            serde_json::Value::Object(map),
+            "draftCreated",
+        )
+    }
+    /// Connect and start receiving events. Must be called within a
+    /// Tokio runtime.
+    pub fn subscribe(self) -> Subscription<T> {
+        let client = self.client;
+        self.into_operation().subscribe(client)
+    }
+}
+/// Subscription builder: Triggered when a draft is updated
+///
+/// Event type: [`Draft`](super::types::Draft)
+///
+/// Use setter methods to configure optional arguments, then call
+/// [`.subscribe()`](Self::subscribe) to start receiving events.
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+#[must_use]
+pub struct DraftUpdatedSubscriptionBuilder<'a, T> {
+    client: &'a Client,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "issues")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Draft>>
+    DraftUpdatedSubscriptionBuilder<'a, T>
+{
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the subscription without starting it, e.g. to set its
+    /// reconnect policy.
+    pub fn into_operation(self) -> SubscriptionOperation<T> {
+        let map = self.extra_variables;
+        let selection = format!("{} {{ {} }}", "draftUpdated", T::selection());
+        SubscriptionOperation::new(
+            "DraftUpdated",
+            "",
+            selection,
+            serde_json::Value::Object(map),
+            "draftUpdated",
+        )
+    }
+    /// Connect and start receiving events. Must be called within a
+    /// Tokio runtime.
+    pub fn subscribe(self) -> Subscription<T> {
+        let client = self.client;
+        self.into_operation().subscribe(client)
+    }
+}
+/// Subscription builder: Triggered when a draft is deleted
+///
+/// Event type: [`Draft`](super::types::Draft)
+///
+/// Use setter methods to configure optional arguments, then call
+/// [`.subscribe()`](Self::subscribe) to start receiving events.
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+#[must_use]
+pub struct DraftDeletedSubscriptionBuilder<'a, T> {
+    client: &'a Client,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "issues")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Draft>>
+    DraftDeletedSubscriptionBuilder<'a, T>
+{
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the subscription without starting it, e.g. to set its
+    /// reconnect policy.
+    pub fn into_operation(self) -> SubscriptionOperation<T> {
+        let map = self.extra_variables;
+        let selection = format!("{} {{ {} }}", "draftDeleted", T::selection());
+        SubscriptionOperation::new(
+            "DraftDeleted",
+            "",
+            selection,
+            serde_json::Value::Object(map),
+            "draftDeleted",
+        )
+    }
+    /// Connect and start receiving events. Must be called within a
+    /// Tokio runtime.
+    pub fn subscribe(self) -> Subscription<T> {
+        let client = self.client;
+        self.into_operation().subscribe(client)
+    }
+}
+/// Subscription builder: Triggered when a favorite is created
+///
+/// Event type: [`Favorite`](super::types::Favorite)
+///
+/// Use setter methods to configure optional arguments, then call
+/// [`.subscribe()`](Self::subscribe) to start receiving events.
+///
+/// Requires the `views` feature.
+#[cfg(feature = "views")]
+#[must_use]
+pub struct FavoriteCreatedSubscriptionBuilder<'a, T> {
+    client: &'a Client,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "views")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Favorite>>
+    FavoriteCreatedSubscriptionBuilder<'a, T>
+{
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the subscription without starting it, e.g. to set its
+    /// reconnect policy.
+    pub fn into_operation(self) -> SubscriptionOperation<T> {
+        let map = self.extra_variables;
+        let selection = format!("{} {{ {} }}", "favoriteCreated", T::selection());
+        SubscriptionOperation::new(
+            "FavoriteCreated",
+            "",
+            selection,
+            serde_json::Value::Object(map),
+            "favoriteCreated",
+        )
+    }
+    /// Connect and start receiving events. Must be called within a
+    /// Tokio runtime.
+    pub fn subscribe(self) -> Subscription<T> {
+        let client = self.client;
+        self.into_operation().subscribe(client)
+    }
+}
+/// Subscription builder: Triggered when a favorite is updated
+///
+/// Event type: [`Favorite`](super::types::Favorite)
+///
+/// Use setter methods to configure optional arguments, then call
+/// [`.subscribe()`](Self::subscribe) to start receiving events.
+///
+/// Requires the `views` feature.
+#[cfg(feature = "views")]
+#[must_use]
+pub struct FavoriteUpdatedSubscriptionBuilder<'a, T> {
+    client: &'a Client,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "views")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Favorite>>
+    FavoriteUpdatedSubscriptionBuilder<'a, T>
+{
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the subscription without starting it, e.g. to set its
+    /// reconnect policy.
+    pub fn into_operation(self) -> SubscriptionOperation<T> {
+        let map = self.extra_variables;
+        let selection = format!("{} {{ {} }}", "favoriteUpdated", T::selection());
+        SubscriptionOperation::new(
+            "FavoriteUpdated",
+            "",
+            selection,
+            serde_json::Value::Object(map),
+            "favoriteUpdated",
+        )
+    }
+    /// Connect and start receiving events. Must be called within a
+    /// Tokio runtime.
+    pub fn subscribe(self) -> Subscription<T> {
+        let client = self.client;
+        self.into_operation().subscribe(client)
+    }
+}
+/// Subscription builder: Triggered when a favorite is deleted
+///
+/// Event type: [`Favorite`](super::types::Favorite)
+///
+/// Use setter methods to configure optional arguments, then call
+/// [`.subscribe()`](Self::subscribe) to start receiving events.
+///
+/// Requires the `views` feature.
+#[cfg(feature = "views")]
+#[must_use]
+pub struct FavoriteDeletedSubscriptionBuilder<'a, T> {
+    client: &'a Client,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "views")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Favorite>>
+    FavoriteDeletedSubscriptionBuilder<'a, T>
+{
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the subscription without starting it, e.g. to set its
+    /// reconnect policy.
+    pub fn into_operation(self) -> SubscriptionOperation<T> {
+        let map = self.extra_variables;
+        let selection = format!("{} {{ {} }}", "favoriteDeleted", T::selection());
+        SubscriptionOperation::new(
+            "FavoriteDeleted",
+            "",
+            selection,
+            serde_json::Value::Object(map),
+            "favoriteDeleted",
+        )
+    }
+    /// Connect and start receiving events. Must be called within a
+    /// Tokio runtime.
+    pub fn subscribe(self) -> Subscription<T> {
+        let client = self.client;
+        self.into_operation().subscribe(client)
+    }
+}
+/// Subscription builder: Triggered when an issue is created
+///
+/// Event type: [`Issue`](super::types::Issue)
+///
+/// Use setter methods to configure optional arguments, then call
+/// [`.subscribe()`](Self::subscribe) to start receiving events.
+#[must_use]
+pub struct IssueCreatedSubscriptionBuilder<'a, T> {
+    client: &'a Client,
+    filter: Option<IssueSubscriptionFilter>,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Issue>>
+    IssueCreatedSubscriptionBuilder<'a, T>
+{
+    pub fn filter(mut self, value: IssueSubscriptionFilter) -> Self {
+        self.filter = Some(value);
+        self
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the subscription without starting it, e.g. to set its
+    /// reconnect policy.
+    pub fn into_operation(self) -> SubscriptionOperation<T> {
+        let mut map = self.extra_variables;
+        if let Some(ref v) = self.filter {
+            map.insert("filter".to_string(), serde_json::json!(v));
+        }
+        let selection = format!(
+            "{} {{ {} }}",
+            "issueCreated(filter: $filter)",
+            T::selection()
+        );
+        SubscriptionOperation::new(
+            "IssueCreated",
+            "$filter: IssueSubscriptionFilter",
+            selection,
+            serde_json::Value::Object(map),
+            "issueCreated",
+        )
+    }
+    /// Connect and start receiving events. Must be called within a
+    /// Tokio runtime.
+    pub fn subscribe(self) -> Subscription<T> {
+        let client = self.client;
+        self.into_operation().subscribe(client)
+    }
+}
+/// Subscription builder: Triggered when an issue is updated
+///
+/// Event type: [`Issue`](super::types::Issue)
+///
+/// Use setter methods to configure optional arguments, then call
+/// [`.subscribe()`](Self::subscribe) to start receiving events.
+#[must_use]
+pub struct IssueUpdatedSubscriptionBuilder<'a, T> {
+    client: &'a Client,
+    filter: Option<IssueSubscriptionFilter>,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Issue>>
+    IssueUpdatedSubscriptionBuilder<'a, T>
+{
+    pub fn filter(mut self, value: IssueSubscriptionFilter) -> Self {
+        self.filter = Some(value);
+        self
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the subscription without starting it, e.g. to set its
+    /// reconnect policy.
+    pub fn into_operation(self) -> SubscriptionOperation<T> {
+        let mut map = self.extra_variables;
+        if let Some(ref v) = self.filter {
+            map.insert("filter".to_string(), serde_json::json!(v));
+        }
+        let selection = format!(
+            "{} {{ {} }}",
+            "issueUpdated(filter: $filter)",
+            T::selection()
+        );
+        SubscriptionOperation::new(
+            "IssueUpdated",
+            "$filter: IssueSubscriptionFilter",
+            selection,
+            serde_json::Value::Object(map),
+            "issueUpdated",
+        )
+    }
+    /// Connect and start receiving events. Must be called within a
+    /// Tokio runtime.
+    pub fn subscribe(self) -> Subscription<T> {
+        let client = self.client;
+        self.into_operation().subscribe(client)
+    }
+}
+/// Subscription builder: Triggered when an issue is archived
+///
+/// Event type: [`Issue`](super::types::Issue)
+///
+/// Use setter methods to configure optional arguments, then call
+/// [`.subscribe()`](Self::subscribe) to start receiving events.
+#[must_use]
+pub struct IssueArchivedSubscriptionBuilder<'a, T> {
+    client: &'a Client,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Issue>>
+    IssueArchivedSubscriptionBuilder<'a, T>
+{
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the subscription without starting it, e.g. to set its
+    /// reconnect policy.
+    pub fn into_operation(self) -> SubscriptionOperation<T> {
+        let map = self.extra_variables;
+        let selection = format!("{} {{ {} }}", "issueArchived", T::selection());
+        SubscriptionOperation::new(
+            "IssueArchived",
+            "",
+            selection,
+            serde_json::Value::Object(map),
+            "issueArchived",
+        )
+    }
+    /// Connect and start receiving events. Must be called within a
+    /// Tokio runtime.
+    pub fn subscribe(self) -> Subscription<T> {
+        let client = self.client;
+        self.into_operation().subscribe(client)
+    }
+}
+/// Subscription builder: Triggered when a an issue is unarchived
+///
+/// Event type: [`Issue`](super::types::Issue)
+///
+/// Use setter methods to configure optional arguments, then call
+/// [`.subscribe()`](Self::subscribe) to start receiving events.
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+#[must_use]
+pub struct IssueUnarchivedSubscriptionBuilder<'a, T> {
+    client: &'a Client,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "issues")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Issue>>
+    IssueUnarchivedSubscriptionBuilder<'a, T>
+{
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the subscription without starting it, e.g. to set its
+    /// reconnect policy.
+    pub fn into_operation(self) -> SubscriptionOperation<T> {
+        let map = self.extra_variables;
+        let selection = format!("{} {{ {} }}", "issueUnarchived", T::selection());
+        SubscriptionOperation::new(
+            "IssueUnarchived",
+            "",
+            selection,
+            serde_json::Value::Object(map),
+            "issueUnarchived",
+        )
+    }
+    /// Connect and start receiving events. Must be called within a
+    /// Tokio runtime.
+    pub fn subscribe(self) -> Subscription<T> {
+        let client = self.client;
+        self.into_operation().subscribe(client)
+    }
+}
+/// Subscription builder: Triggered when an issue history is created
+///
+/// Event type: [`IssueHistory`](super::types::IssueHistory)
+///
+/// Use setter methods to configure optional arguments, then call
+/// [`.subscribe()`](Self::subscribe) to start receiving events.
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+#[must_use]
+pub struct IssueHistoryCreatedSubscriptionBuilder<'a, T> {
+    client: &'a Client,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "issues")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::IssueHistory>>
+    IssueHistoryCreatedSubscriptionBuilder<'a, T>
+{
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the subscription without starting it, e.g. to set its
+    /// reconnect policy.
+    pub fn into_operation(self) -> SubscriptionOperation<T> {
+        let map = self.extra_variables;
+        let selection = format!("{} {{ {} }}", "issueHistoryCreated", T::selection());
+        SubscriptionOperation::new(
+            "IssueHistoryCreated",
+            "",
+            selection,
+            serde_json::Value::Object(map),
+            "issueHistoryCreated",
+        )
+    }
+    /// Connect and start receiving events. Must be called within a
+    /// Tokio runtime.
+    pub fn subscribe(self) -> Subscription<T> {
+        let client = self.client;
+        self.into_operation().subscribe(client)
+    }
+}
+/// Subscription builder: Triggered when an issue history is updated
+///
+/// Event type: [`IssueHistory`](super::types::IssueHistory)
+///
+/// Use setter methods to configure optional arguments, then call
+/// [`.subscribe()`](Self::subscribe) to start receiving events.
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+#[must_use]
+pub struct IssueHistoryUpdatedSubscriptionBuilder<'a, T> {
+    client: &'a Client,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "issues")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::IssueHistory>>
+    IssueHistoryUpdatedSubscriptionBuilder<'a, T>
+{
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the subscription without starting it, e.g. to set its
+    /// reconnect policy.
+    pub fn into_operation(self) -> SubscriptionOperation<T> {
+        let map = self.extra_variables;
+        let selection = format!("{} {{ {} }}", "issueHistoryUpdated", T::selection());
+        SubscriptionOperation::new(
+            "IssueHistoryUpdated",
+            "",
+            selection,
+            serde_json::Value::Object(map),
+            "issueHistoryUpdated",
+        )
+    }
+    /// Connect and start receiving events. Must be called within a
+    /// Tokio runtime.
+    pub fn subscribe(self) -> Subscription<T> {
+        let client = self.client;
+        self.into_operation().subscribe(client)
+    }
+}
+/// Subscription builder: Triggered when an issue draft is created
+///
+/// Event type: [`IssueDraft`](super::types::IssueDraft)
+///
+/// Use setter methods to configure optional arguments, then call
+/// [`.subscribe()`](Self::subscribe) to start receiving events.
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+#[must_use]
+pub struct IssueDraftCreatedSubscriptionBuilder<'a, T> {
+    client: &'a Client,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "issues")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::IssueDraft>>
+    IssueDraftCreatedSubscriptionBuilder<'a, T>
+{
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the subscription without starting it, e.g. to set its
+    /// reconnect policy.
+    pub fn into_operation(self) -> SubscriptionOperation<T> {
+        let map = self.extra_variables;
+        let selection = format!("{} {{ {} }}", "issueDraftCreated", T::selection());
+        SubscriptionOperation::new(
+            "IssueDraftCreated",
+            "",
+            selection,
+            serde_json::Value::Object(map),
+            "issueDraftCreated",
+        )
+    }
+    /// Connect and start receiving events. Must be called within a
+    /// Tokio runtime.
+    pub fn subscribe(self) -> Subscription<T> {
+        let client = self.client;
+        self.into_operation().subscribe(client)
+    }
+}
+/// Subscription builder: Triggered when an issue draft is updated
+///
+/// Event type: [`IssueDraft`](super::types::IssueDraft)
+///
+/// Use setter methods to configure optional arguments, then call
+/// [`.subscribe()`](Self::subscribe) to start receiving events.
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+#[must_use]
+pub struct IssueDraftUpdatedSubscriptionBuilder<'a, T> {
+    client: &'a Client,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "issues")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::IssueDraft>>
+    IssueDraftUpdatedSubscriptionBuilder<'a, T>
+{
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the subscription without starting it, e.g. to set its
+    /// reconnect policy.
+    pub fn into_operation(self) -> SubscriptionOperation<T> {
+        let map = self.extra_variables;
+        let selection = format!("{} {{ {} }}", "issueDraftUpdated", T::selection());
+        SubscriptionOperation::new(
+            "IssueDraftUpdated",
+            "",
+            selection,
+            serde_json::Value::Object(map),
+            "issueDraftUpdated",
+        )
+    }
+    /// Connect and start receiving events. Must be called within a
+    /// Tokio runtime.
+    pub fn subscribe(self) -> Subscription<T> {
+        let client = self.client;
+        self.into_operation().subscribe(client)
+    }
+}
+/// Subscription builder: Triggered when an issue draft is deleted
+///
+/// Event type: [`IssueDraft`](super::types::IssueDraft)
+///
+/// Use setter methods to configure optional arguments, then call
+/// [`.subscribe()`](Self::subscribe) to start receiving events.
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+#[must_use]
+pub struct IssueDraftDeletedSubscriptionBuilder<'a, T> {
+    client: &'a Client,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "issues")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::IssueDraft>>
+    IssueDraftDeletedSubscriptionBuilder<'a, T>
+{
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the subscription without starting it, e.g. to set its
+    /// reconnect policy.
+    pub fn into_operation(self) -> SubscriptionOperation<T> {
+        let map = self.extra_variables;
+        let selection = format!("{} {{ {} }}", "issueDraftDeleted", T::selection());
+        SubscriptionOperation::new(
+            "IssueDraftDeleted",
+            "",
+            selection,
+            serde_json::Value::Object(map),
+            "issueDraftDeleted",
+        )
+    }
+    /// Connect and start receiving events. Must be called within a
+    /// Tokio runtime.
+    pub fn subscribe(self) -> Subscription<T> {
+        let client = self.client;
+        self.into_operation().subscribe(client)
+    }
+}
+/// Subscription builder: Triggered when an issue label is created
+///
+/// Event type: [`IssueLabel`](super::types::IssueLabel)
+///
+/// Use setter methods to configure optional arguments, then call
+/// [`.subscribe()`](Self::subscribe) to start receiving events.
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+#[must_use]
+pub struct IssueLabelCreatedSubscriptionBuilder<'a, T> {
+    client: &'a Client,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "issues")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::IssueLabel>>
+    IssueLabelCreatedSubscriptionBuilder<'a, T>
+{
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the subscription without starting it, e.g. to set its
+    /// reconnect policy.
+    pub fn into_operation(self) -> SubscriptionOperation<T> {
+        let map = self.extra_variables;
+        let selection = format!("{} {{ {} }}", "issueLabelCreated", T::selection());
+        SubscriptionOperation::new(
+            "IssueLabelCreated",
+            "",
+            selection,
+            serde_json::Value::Object(map),
+            "issueLabelCreated",
+        )
+    }
+    /// Connect and start receiving events. Must be called within a
+    /// Tokio runtime.
+    pub fn subscribe(self) -> Subscription<T> {
+        let client = self.client;
+        self.into_operation().subscribe(client)
+    }
+}
+/// Subscription builder: Triggered when an issue label is updated
+///
+/// Event type: [`IssueLabel`](super::types::IssueLabel)
+///
+/// Use setter methods to configure optional arguments, then call
+/// [`.subscribe()`](Self::subscribe) to start receiving events.
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+#[must_use]
+pub struct IssueLabelUpdatedSubscriptionBuilder<'a, T> {
+    client: &'a Client,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "issues")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::IssueLabel>>
+    IssueLabelUpdatedSubscriptionBuilder<'a, T>
+{
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the subscription without starting it, e.g. to set its
+    /// reconnect policy.
+    pub fn into_operation(self) -> SubscriptionOperation<T> {
+        let map = self.extra_variables;
+        let selection = format!("{} {{ {} }}", "issueLabelUpdated", T::selection());
+        SubscriptionOperation::new(
+            "IssueLabelUpdated",
+            "",
+            selection,
+            serde_json::Value::Object(map),
+            "issueLabelUpdated",
+        )
+    }
+    /// Connect and start receiving events. Must be called within a
+    /// Tokio runtime.
+    pub fn subscribe(self) -> Subscription<T> {
+        let client = self.client;
+        self.into_operation().subscribe(client)
+    }
+}
+/// Subscription builder: Triggered when an issue label is deleted
+///
+/// Event type: [`IssueLabel`](super::types::IssueLabel)
+///
+/// Use setter methods to configure optional arguments, then call
+/// [`.subscribe()`](Self::subscribe) to start receiving events.
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+#[must_use]
+pub struct IssueLabelDeletedSubscriptionBuilder<'a, T> {
+    client: &'a Client,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "issues")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::IssueLabel>>
+    IssueLabelDeletedSubscriptionBuilder<'a, T>
+{
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the subscription without starting it, e.g. to set its
+    /// reconnect policy.
+    pub fn into_operation(self) -> SubscriptionOperation<T> {
+        let map = self.extra_variables;
+        let selection = format!("{} {{ {} }}", "issueLabelDeleted", T::selection());
+        SubscriptionOperation::new(
+            "IssueLabelDeleted",
+            "",
+            selection,
+            serde_json::Value::Object(map),
+            "issueLabelDeleted",
+        )
+    }
+    /// Connect and start receiving events. Must be called within a
+    /// Tokio runtime.
+    pub fn subscribe(self) -> Subscription<T> {
+        let client = self.client;
+        self.into_operation().subscribe(client)
+    }
+}
+/// Subscription builder: Triggered when an issue relation is created
+///
+/// Event type: [`IssueRelation`](super::types::IssueRelation)
+///
+/// Use setter methods to configure optional arguments, then call
+/// [`.subscribe()`](Self::subscribe) to start receiving events.
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+#[must_use]
+pub struct IssueRelationCreatedSubscriptionBuilder<'a, T> {
+    client: &'a Client,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "issues")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::IssueRelation>>
+    IssueRelationCreatedSubscriptionBuilder<'a, T>
+{
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the subscription without starting it, e.g. to set its
+    /// reconnect policy.
+    pub fn into_operation(self) -> SubscriptionOperation<T> {
+        let map = self.extra_variables;
+        let selection = format!("{} {{ {} }}", "issueRelationCreated", T::selection());
+        SubscriptionOperation::new(
+            "IssueRelationCreated",
+            "",
+            selection,
+            serde_json::Value::Object(map),
+            "issueRelationCreated",
+        )
+    }
+    /// Connect and start receiving events. Must be called within a
+    /// Tokio runtime.
+    pub fn subscribe(self) -> Subscription<T> {
+        let client = self.client;
+        self.into_operation().subscribe(client)
+    }
+}
+/// Subscription builder: Triggered when an issue relation is updated
+///
+/// Event type: [`IssueRelation`](super::types::IssueRelation)
+///
+/// Use setter methods to configure optional arguments, then call
+/// [`.subscribe()`](Self::subscribe) to start receiving events.
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+#[must_use]
+pub struct IssueRelationUpdatedSubscriptionBuilder<'a, T> {
+    client: &'a Client,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "issues")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::IssueRelation>>
+    IssueRelationUpdatedSubscriptionBuilder<'a, T>
+{
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the subscription without starting it, e.g. to set its
+    /// reconnect policy.
+    pub fn into_operation(self) -> SubscriptionOperation<T> {
+        let map = self.extra_variables;
+        let selection = format!("{} {{ {} }}", "issueRelationUpdated", T::selection());
+        SubscriptionOperation::new(
+            "IssueRelationUpdated",
+            "",
+            selection,
+            serde_json::Value::Object(map),
+            "issueRelationUpdated",
+        )
+    }
+    /// Connect and start receiving events. Must be called within a
+    /// Tokio runtime.
+    pub fn subscribe(self) -> Subscription<T> {
+        let client = self.client;
+        self.into_operation().subscribe(client)
+    }
+}
+/// Subscription builder: Triggered when an issue relation is deleted
+///
+/// Event type: [`IssueRelation`](super::types::IssueRelation)
+///
+/// Use setter methods to configure optional arguments, then call
+/// [`.subscribe()`](Self::subscribe) to start receiving events.
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+#[must_use]
+pub struct IssueRelationDeletedSubscriptionBuilder<'a, T> {
+    client: &'a Client,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "issues")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::IssueRelation>>
+    IssueRelationDeletedSubscriptionBuilder<'a, T>
+{
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the subscription without starting it, e.g. to set its
+    /// reconnect policy.
+    pub fn into_operation(self) -> SubscriptionOperation<T> {
+        let map = self.extra_variables;
+        let selection = format!("{} {{ {} }}", "issueRelationDeleted", T::selection());
+        SubscriptionOperation::new(
+            "IssueRelationDeleted",
+            "",
+            selection,
+            serde_json::Value::Object(map),
+            "issueRelationDeleted",
+        )
+    }
+    /// Connect and start receiving events. Must be called within a
+    /// Tokio runtime.
+    pub fn subscribe(self) -> Subscription<T> {
+        let client = self.client;
+        self.into_operation().subscribe(client)
+    }
+}
+/// Subscription builder: Triggered when a notification is created
+///
+/// Event type: [`Notification`](super::types::Notification)
+///
+/// Use setter methods to configure optional arguments, then call
+/// [`.subscribe()`](Self::subscribe) to start receiving events.
+#[must_use]
+pub struct NotificationCreatedSubscriptionBuilder<'a, T> {
+    client: &'a Client,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Notification>>
+    NotificationCreatedSubscriptionBuilder<'a, T>
+{
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the subscription without starting it, e.g. to set its
+    /// reconnect policy.
+    pub fn into_operation(self) -> SubscriptionOperation<T> {
+        let map = self.extra_variables;
+        let selection = format!("{} {{ {} }}", "notificationCreated", T::selection());
+        SubscriptionOperation::new(
+            "NotificationCreated",
+            "",
+            selection,
+            serde_json::Value::Object(map),
+            "notificationCreated",
+        )
+    }
+    /// Connect and start receiving events. Must be called within a
+    /// Tokio runtime.
+    pub fn subscribe(self) -> Subscription<T> {
+        let client = self.client;
+        self.into_operation().subscribe(client)
+    }
+}
+/// Subscription builder: Triggered when a notification is updated
+///
+/// Event type: [`Notification`](super::types::Notification)
+///
+/// Use setter methods to configure optional arguments, then call
+/// [`.subscribe()`](Self::subscribe) to start receiving events.
+///
+/// Requires the `notifications` feature.
+#[cfg(feature = "notifications")]
+#[must_use]
+pub struct NotificationUpdatedSubscriptionBuilder<'a, T> {
+    client: &'a Client,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "notifications")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Notification>>
+    NotificationUpdatedSubscriptionBuilder<'a, T>
+{
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the subscription without starting it, e.g. to set its
+    /// reconnect policy.
+    pub fn into_operation(self) -> SubscriptionOperation<T> {
+        let map = self.extra_variables;
+        let selection = format!("{} {{ {} }}", "notificationUpdated", T::selection());
+        SubscriptionOperation::new(
+            "NotificationUpdated",
+            "",
+            selection,
+            serde_json::Value::Object(map),
+            "notificationUpdated",
+        )
+    }
+    /// Connect and start receiving events. Must be called within a
+    /// Tokio runtime.
+    pub fn subscribe(self) -> Subscription<T> {
+        let client = self.client;
+        self.into_operation().subscribe(client)
+    }
+}
+/// Subscription builder: Triggered when a notification is archived
+///
+/// Event type: [`Notification`](super::types::Notification)
+///
+/// Use setter methods to configure optional arguments, then call
+/// [`.subscribe()`](Self::subscribe) to start receiving events.
+///
+/// Requires the `notifications` feature.
+#[cfg(feature = "notifications")]
+#[must_use]
+pub struct NotificationArchivedSubscriptionBuilder<'a, T> {
+    client: &'a Client,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "notifications")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Notification>>
+    NotificationArchivedSubscriptionBuilder<'a, T>
+{
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the subscription without starting it, e.g. to set its
+    /// reconnect policy.
+    pub fn into_operation(self) -> SubscriptionOperation<T> {
+        let map = self.extra_variables;
+        let selection = format!("{} {{ {} }}", "notificationArchived", T::selection());
+        SubscriptionOperation::new(
+            "NotificationArchived",
+            "",
+            selection,
+            serde_json::Value::Object(map),
+            "notificationArchived",
+        )
+    }
+    /// Connect and start receiving events. Must be called within a
+    /// Tokio runtime.
+    pub fn subscribe(self) -> Subscription<T> {
+        let client = self.client;
+        self.into_operation().subscribe(client)
+    }
+}
+/// Subscription builder: Triggered when a a notification is unarchived
+///
+/// Event type: [`Notification`](super::types::Notification)
+///
+/// Use setter methods to configure optional arguments, then call
+/// [`.subscribe()`](Self::subscribe) to start receiving events.
+///
+/// Requires the `notifications` feature.
+#[cfg(feature = "notifications")]
+#[must_use]
+pub struct NotificationUnarchivedSubscriptionBuilder<'a, T> {
+    client: &'a Client,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "notifications")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Notification>>
+    NotificationUnarchivedSubscriptionBuilder<'a, T>
+{
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the subscription without starting it, e.g. to set its
+    /// reconnect policy.
+    pub fn into_operation(self) -> SubscriptionOperation<T> {
+        let map = self.extra_variables;
+        let selection = format!("{} {{ {} }}", "notificationUnarchived", T::selection());
+        SubscriptionOperation::new(
+            "NotificationUnarchived",
+            "",
+            selection,
+            serde_json::Value::Object(map),
+            "notificationUnarchived",
+        )
+    }
+    /// Connect and start receiving events. Must be called within a
+    /// Tokio runtime.
+    pub fn subscribe(self) -> Subscription<T> {
+        let client = self.client;
+        self.into_operation().subscribe(client)
+    }
+}
+/// Subscription builder: Triggered when a notification is deleted
+///
+/// Event type: [`Notification`](super::types::Notification)
+///
+/// Use setter methods to configure optional arguments, then call
+/// [`.subscribe()`](Self::subscribe) to start receiving events.
+///
+/// Requires the `notifications` feature.
+#[cfg(feature = "notifications")]
+#[must_use]
+pub struct NotificationDeletedSubscriptionBuilder<'a, T> {
+    client: &'a Client,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "notifications")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Notification>>
+    NotificationDeletedSubscriptionBuilder<'a, T>
+{
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the subscription without starting it, e.g. to set its
+    /// reconnect policy.
+    pub fn into_operation(self) -> SubscriptionOperation<T> {
+        let map = self.extra_variables;
+        let selection = format!("{} {{ {} }}", "notificationDeleted", T::selection());
+        SubscriptionOperation::new(
+            "NotificationDeleted",
+            "",
+            selection,
+            serde_json::Value::Object(map),
+            "notificationDeleted",
+        )
+    }
+    /// Connect and start receiving events. Must be called within a
+    /// Tokio runtime.
+    pub fn subscribe(self) -> Subscription<T> {
+        let client = self.client;
+        self.into_operation().subscribe(client)
+    }
+}
+/// Subscription builder: Triggered when an organization is updated
+///
+/// Event type: [`Organization`](super::types::Organization)
+///
+/// Use setter methods to configure optional arguments, then call
+/// [`.subscribe()`](Self::subscribe) to start receiving events.
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+#[must_use]
+pub struct OrganizationUpdatedSubscriptionBuilder<'a, T> {
+    client: &'a Client,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "admin")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Organization>>
+    OrganizationUpdatedSubscriptionBuilder<'a, T>
+{
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the subscription without starting it, e.g. to set its
+    /// reconnect policy.
+    pub fn into_operation(self) -> SubscriptionOperation<T> {
+        let map = self.extra_variables;
+        let selection = format!("{} {{ {} }}", "organizationUpdated", T::selection());
+        SubscriptionOperation::new(
+            "OrganizationUpdated",
+            "",
+            selection,
+            serde_json::Value::Object(map),
+            "organizationUpdated",
+        )
+    }
+    /// Connect and start receiving events. Must be called within a
+    /// Tokio runtime.
+    pub fn subscribe(self) -> Subscription<T> {
+        let client = self.client;
+        self.into_operation().subscribe(client)
+    }
+}
+/// Subscription builder: Triggered when a project is created
+///
+/// Event type: [`Project`](super::types::Project)
+///
+/// Use setter methods to configure optional arguments, then call
+/// [`.subscribe()`](Self::subscribe) to start receiving events.
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+#[must_use]
+pub struct ProjectCreatedSubscriptionBuilder<'a, T> {
+    client: &'a Client,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "projects")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Project>>
+    ProjectCreatedSubscriptionBuilder<'a, T>
+{
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the subscription without starting it, e.g. to set its
+    /// reconnect policy.
+    pub fn into_operation(self) -> SubscriptionOperation<T> {
+        let map = self.extra_variables;
+        let selection = format!("{} {{ {} }}", "projectCreated", T::selection());
+        SubscriptionOperation::new(
+            "ProjectCreated",
+            "",
+            selection,
+            serde_json::Value::Object(map),
+            "projectCreated",
+        )
+    }
+    /// Connect and start receiving events. Must be called within a
+    /// Tokio runtime.
+    pub fn subscribe(self) -> Subscription<T> {
+        let client = self.client;
+        self.into_operation().subscribe(client)
+    }
+}
+/// Subscription builder: Triggered when a project is updated
+///
+/// Event type: [`Project`](super::types::Project)
+///
+/// Use setter methods to configure optional arguments, then call
+/// [`.subscribe()`](Self::subscribe) to start receiving events.
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+#[must_use]
+pub struct ProjectUpdatedSubscriptionBuilder<'a, T> {
+    client: &'a Client,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "projects")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Project>>
+    ProjectUpdatedSubscriptionBuilder<'a, T>
+{
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the subscription without starting it, e.g. to set its
+    /// reconnect policy.
+    pub fn into_operation(self) -> SubscriptionOperation<T> {
+        let map = self.extra_variables;
+        let selection = format!("{} {{ {} }}", "projectUpdated", T::selection());
+        SubscriptionOperation::new(
+            "ProjectUpdated",
+            "",
+            selection,
+            serde_json::Value::Object(map),
+            "projectUpdated",
+        )
+    }
+    /// Connect and start receiving events. Must be called within a
+    /// Tokio runtime.
+    pub fn subscribe(self) -> Subscription<T> {
+        let client = self.client;
+        self.into_operation().subscribe(client)
+    }
+}
+/// Subscription builder: Triggered when a project is archived
+///
+/// Event type: [`Project`](super::types::Project)
+///
+/// Use setter methods to configure optional arguments, then call
+/// [`.subscribe()`](Self::subscribe) to start receiving events.
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+#[must_use]
+pub struct ProjectArchivedSubscriptionBuilder<'a, T> {
+    client: &'a Client,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "projects")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Project>>
+    ProjectArchivedSubscriptionBuilder<'a, T>
+{
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the subscription without starting it, e.g. to set its
+    /// reconnect policy.
+    pub fn into_operation(self) -> SubscriptionOperation<T> {
+        let map = self.extra_variables;
+        let selection = format!("{} {{ {} }}", "projectArchived", T::selection());
+        SubscriptionOperation::new(
+            "ProjectArchived",
+            "",
+            selection,
+            serde_json::Value::Object(map),
+            "projectArchived",
+        )
+    }
+    /// Connect and start receiving events. Must be called within a
+    /// Tokio runtime.
+    pub fn subscribe(self) -> Subscription<T> {
+        let client = self.client;
+        self.into_operation().subscribe(client)
+    }
+}
+/// Subscription builder: Triggered when a a project is unarchived
+///
+/// Event type: [`Project`](super::types::Project)
+///
+/// Use setter methods to configure optional arguments, then call
+/// [`.subscribe()`](Self::subscribe) to start receiving events.
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+#[must_use]
+pub struct ProjectUnarchivedSubscriptionBuilder<'a, T> {
+    client: &'a Client,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "projects")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Project>>
+    ProjectUnarchivedSubscriptionBuilder<'a, T>
+{
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the subscription without starting it, e.g. to set its
+    /// reconnect policy.
+    pub fn into_operation(self) -> SubscriptionOperation<T> {
+        let map = self.extra_variables;
+        let selection = format!("{} {{ {} }}", "projectUnarchived", T::selection());
+        SubscriptionOperation::new(
+            "ProjectUnarchived",
+            "",
+            selection,
+            serde_json::Value::Object(map),
+            "projectUnarchived",
+        )
+    }
+    /// Connect and start receiving events. Must be called within a
+    /// Tokio runtime.
+    pub fn subscribe(self) -> Subscription<T> {
+        let client = self.client;
+        self.into_operation().subscribe(client)
+    }
+}
+/// Subscription builder: Triggered when a project update is created
+///
+/// Event type: [`ProjectUpdate`](super::types::ProjectUpdate)
+///
+/// Use setter methods to configure optional arguments, then call
+/// [`.subscribe()`](Self::subscribe) to start receiving events.
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+#[must_use]
+pub struct ProjectUpdateCreatedSubscriptionBuilder<'a, T> {
+    client: &'a Client,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "projects")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::ProjectUpdate>>
+    ProjectUpdateCreatedSubscriptionBuilder<'a, T>
+{
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the subscription without starting it, e.g. to set its
+    /// reconnect policy.
+    pub fn into_operation(self) -> SubscriptionOperation<T> {
+        let map = self.extra_variables;
+        let selection = format!("{} {{ {} }}", "projectUpdateCreated", T::selection());
+        SubscriptionOperation::new(
+            "ProjectUpdateCreated",
+            "",
+            selection,
+            serde_json::Value::Object(map),
+            "projectUpdateCreated",
+        )
+    }
+    /// Connect and start receiving events. Must be called within a
+    /// Tokio runtime.
+    pub fn subscribe(self) -> Subscription<T> {
+        let client = self.client;
+        self.into_operation().subscribe(client)
+    }
+}
+/// Subscription builder: Triggered when a project update is updated
+///
+/// Event type: [`ProjectUpdate`](super::types::ProjectUpdate)
+///
+/// Use setter methods to configure optional arguments, then call
+/// [`.subscribe()`](Self::subscribe) to start receiving events.
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+#[must_use]
+pub struct ProjectUpdateUpdatedSubscriptionBuilder<'a, T> {
+    client: &'a Client,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "projects")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::ProjectUpdate>>
+    ProjectUpdateUpdatedSubscriptionBuilder<'a, T>
+{
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the subscription without starting it, e.g. to set its
+    /// reconnect policy.
+    pub fn into_operation(self) -> SubscriptionOperation<T> {
+        let map = self.extra_variables;
+        let selection = format!("{} {{ {} }}", "projectUpdateUpdated", T::selection());
+        SubscriptionOperation::new(
+            "ProjectUpdateUpdated",
+            "",
+            selection,
+            serde_json::Value::Object(map),
+            "projectUpdateUpdated",
+        )
+    }
+    /// Connect and start receiving events. Must be called within a
+    /// Tokio runtime.
+    pub fn subscribe(self) -> Subscription<T> {
+        let client = self.client;
+        self.into_operation().subscribe(client)
+    }
+}
+/// Subscription builder: Triggered when a project update is deleted
+///
+/// Event type: [`ProjectUpdate`](super::types::ProjectUpdate)
+///
+/// Use setter methods to configure optional arguments, then call
+/// [`.subscribe()`](Self::subscribe) to start receiving events.
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+#[must_use]
+pub struct ProjectUpdateDeletedSubscriptionBuilder<'a, T> {
+    client: &'a Client,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "projects")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::ProjectUpdate>>
+    ProjectUpdateDeletedSubscriptionBuilder<'a, T>
+{
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the subscription without starting it, e.g. to set its
+    /// reconnect policy.
+    pub fn into_operation(self) -> SubscriptionOperation<T> {
+        let map = self.extra_variables;
+        let selection = format!("{} {{ {} }}", "projectUpdateDeleted", T::selection());
+        SubscriptionOperation::new(
+            "ProjectUpdateDeleted",
+            "",
+            selection,
+            serde_json::Value::Object(map),
+            "projectUpdateDeleted",
+        )
+    }
+    /// Connect and start receiving events. Must be called within a
+    /// Tokio runtime.
+    pub fn subscribe(self) -> Subscription<T> {
+        let client = self.client;
+        self.into_operation().subscribe(client)
+    }
+}
+/// Subscription builder: Triggered when a roadmap is created
+///
+/// Event type: [`Roadmap`](super::types::Roadmap)
+///
+/// Use setter methods to configure optional arguments, then call
+/// [`.subscribe()`](Self::subscribe) to start receiving events.
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+#[must_use]
+pub struct RoadmapCreatedSubscriptionBuilder<'a, T> {
+    client: &'a Client,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "projects")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Roadmap>>
+    RoadmapCreatedSubscriptionBuilder<'a, T>
+{
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the subscription without starting it, e.g. to set its
+    /// reconnect policy.
+    pub fn into_operation(self) -> SubscriptionOperation<T> {
+        let map = self.extra_variables;
+        let selection = format!("{} {{ {} }}", "roadmapCreated", T::selection());
+        SubscriptionOperation::new(
+            "RoadmapCreated",
+            "",
+            selection,
+            serde_json::Value::Object(map),
+            "roadmapCreated",
+        )
+    }
+    /// Connect and start receiving events. Must be called within a
+    /// Tokio runtime.
+    pub fn subscribe(self) -> Subscription<T> {
+        let client = self.client;
+        self.into_operation().subscribe(client)
+    }
+}
+/// Subscription builder: Triggered when a roadmap is updated
+///
+/// Event type: [`Roadmap`](super::types::Roadmap)
+///
+/// Use setter methods to configure optional arguments, then call
+/// [`.subscribe()`](Self::subscribe) to start receiving events.
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+#[must_use]
+pub struct RoadmapUpdatedSubscriptionBuilder<'a, T> {
+    client: &'a Client,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "projects")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Roadmap>>
+    RoadmapUpdatedSubscriptionBuilder<'a, T>
+{
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the subscription without starting it, e.g. to set its
+    /// reconnect policy.
+    pub fn into_operation(self) -> SubscriptionOperation<T> {
+        let map = self.extra_variables;
+        let selection = format!("{} {{ {} }}", "roadmapUpdated", T::selection());
+        SubscriptionOperation::new(
+            "RoadmapUpdated",
+            "",
+            selection,
+            serde_json::Value::Object(map),
+            "roadmapUpdated",
+        )
+    }
+    /// Connect and start receiving events. Must be called within a
+    /// Tokio runtime.
+    pub fn subscribe(self) -> Subscription<T> {
+        let client = self.client;
+        self.into_operation().subscribe(client)
+    }
+}
+/// Subscription builder: Triggered when a roadmap is deleted
+///
+/// Event type: [`Roadmap`](super::types::Roadmap)
+///
+/// Use setter methods to configure optional arguments, then call
+/// [`.subscribe()`](Self::subscribe) to start receiving events.
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+#[must_use]
+pub struct RoadmapDeletedSubscriptionBuilder<'a, T> {
+    client: &'a Client,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "projects")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Roadmap>>
+    RoadmapDeletedSubscriptionBuilder<'a, T>
+{
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the subscription without starting it, e.g. to set its
+    /// reconnect policy.
+    pub fn into_operation(self) -> SubscriptionOperation<T> {
+        let map = self.extra_variables;
+        let selection = format!("{} {{ {} }}", "roadmapDeleted", T::selection());
+        SubscriptionOperation::new(
+            "RoadmapDeleted",
+            "",
+            selection,
+            serde_json::Value::Object(map),
+            "roadmapDeleted",
+        )
+    }
+    /// Connect and start receiving events. Must be called within a
+    /// Tokio runtime.
+    pub fn subscribe(self) -> Subscription<T> {
+        let client = self.client;
+        self.into_operation().subscribe(client)
+    }
+}
+/// Subscription builder: Triggered when an initiative is created
+///
+/// Event type: [`Initiative`](super::types::Initiative)
+///
+/// Use setter methods to configure optional arguments, then call
+/// [`.subscribe()`](Self::subscribe) to start receiving events.
+///
+/// Requires the `initiatives` feature.
+#[cfg(feature = "initiatives")]
+#[must_use]
+pub struct InitiativeCreatedSubscriptionBuilder<'a, T> {
+    client: &'a Client,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "initiatives")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Initiative>>
+    InitiativeCreatedSubscriptionBuilder<'a, T>
+{
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the subscription without starting it, e.g. to set its
+    /// reconnect policy.
+    pub fn into_operation(self) -> SubscriptionOperation<T> {
+        let map = self.extra_variables;
+        let selection = format!("{} {{ {} }}", "initiativeCreated", T::selection());
+        SubscriptionOperation::new(
+            "InitiativeCreated",
+            "",
+            selection,
+            serde_json::Value::Object(map),
+            "initiativeCreated",
+        )
+    }
+    /// Connect and start receiving events. Must be called within a
+    /// Tokio runtime.
+    pub fn subscribe(self) -> Subscription<T> {
+        let client = self.client;
+        self.into_operation().subscribe(client)
+    }
+}
+/// Subscription builder: Triggered when an initiative is updated
+///
+/// Event type: [`Initiative`](super::types::Initiative)
+///
+/// Use setter methods to configure optional arguments, then call
+/// [`.subscribe()`](Self::subscribe) to start receiving events.
+///
+/// Requires the `initiatives` feature.
+#[cfg(feature = "initiatives")]
+#[must_use]
+pub struct InitiativeUpdatedSubscriptionBuilder<'a, T> {
+    client: &'a Client,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "initiatives")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Initiative>>
+    InitiativeUpdatedSubscriptionBuilder<'a, T>
+{
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the subscription without starting it, e.g. to set its
+    /// reconnect policy.
+    pub fn into_operation(self) -> SubscriptionOperation<T> {
+        let map = self.extra_variables;
+        let selection = format!("{} {{ {} }}", "initiativeUpdated", T::selection());
+        SubscriptionOperation::new(
+            "InitiativeUpdated",
+            "",
+            selection,
+            serde_json::Value::Object(map),
+            "initiativeUpdated",
+        )
+    }
+    /// Connect and start receiving events. Must be called within a
+    /// Tokio runtime.
+    pub fn subscribe(self) -> Subscription<T> {
+        let client = self.client;
+        self.into_operation().subscribe(client)
+    }
+}
+/// Subscription builder: Triggered when an initiative is deleted
+///
+/// Event type: [`Initiative`](super::types::Initiative)
+///
+/// Use setter methods to configure optional arguments, then call
+/// [`.subscribe()`](Self::subscribe) to start receiving events.
+///
+/// Requires the `initiatives` feature.
+#[cfg(feature = "initiatives")]
+#[must_use]
+pub struct InitiativeDeletedSubscriptionBuilder<'a, T> {
+    client: &'a Client,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "initiatives")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Initiative>>
+    InitiativeDeletedSubscriptionBuilder<'a, T>
+{
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the subscription without starting it, e.g. to set its
+    /// reconnect policy.
+    pub fn into_operation(self) -> SubscriptionOperation<T> {
+        let map = self.extra_variables;
+        let selection = format!("{} {{ {} }}", "initiativeDeleted", T::selection());
+        SubscriptionOperation::new(
+            "InitiativeDeleted",
+            "",
+            selection,
+            serde_json::Value::Object(map),
+            "initiativeDeleted",
+        )
+    }
+    /// Connect and start receiving events. Must be called within a
+    /// Tokio runtime.
+    pub fn subscribe(self) -> Subscription<T> {
+        let client = self.client;
+        self.into_operation().subscribe(client)
+    }
+}
+/// Subscription builder: Triggered when an agent session is created
+///
+/// Event type: [`AgentSession`](super::types::AgentSession)
+///
+/// Use setter methods to configure optional arguments, then call
+/// [`.subscribe()`](Self::subscribe) to start receiving events.
+///
+/// Requires the `agents` feature.
+#[cfg(feature = "agents")]
+#[must_use]
+pub struct AgentSessionCreatedSubscriptionBuilder<'a, T> {
+    client: &'a Client,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "agents")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::AgentSession>>
+    AgentSessionCreatedSubscriptionBuilder<'a, T>
+{
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the subscription without starting it, e.g. to set its
+    /// reconnect policy.
+    pub fn into_operation(self) -> SubscriptionOperation<T> {
+        let map = self.extra_variables;
+        let selection = format!("{} {{ {} }}", "agentSessionCreated", T::selection());
+        SubscriptionOperation::new(
+            "AgentSessionCreated",
+            "",
+            selection,
+            serde_json::Value::Object(map),
+            "agentSessionCreated",
+        )
+    }
+    /// Connect and start receiving events. Must be called within a
+    /// Tokio runtime.
+    pub fn subscribe(self) -> Subscription<T> {
+        let client = self.client;
+        self.into_operation().subscribe(client)
+    }
+}
+/// Subscription builder: Triggered when an agent session is updated
+///
+/// Event type: [`AgentSession`](super::types::AgentSession)
+///
+/// Use setter methods to configure optional arguments, then call
+/// [`.subscribe()`](Self::subscribe) to start receiving events.
+///
+/// Requires the `agents` feature.
+#[cfg(feature = "agents")]
+#[must_use]
+pub struct AgentSessionUpdatedSubscriptionBuilder<'a, T> {
+    client: &'a Client,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "agents")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::AgentSession>>
+    AgentSessionUpdatedSubscriptionBuilder<'a, T>
+{
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the subscription without starting it, e.g. to set its
+    /// reconnect policy.
+    pub fn into_operation(self) -> SubscriptionOperation<T> {
+        let map = self.extra_variables;
+        let selection = format!("{} {{ {} }}", "agentSessionUpdated", T::selection());
+        SubscriptionOperation::new(
+            "AgentSessionUpdated",
+            "",
+            selection,
+            serde_json::Value::Object(map),
+            "agentSessionUpdated",
+        )
+    }
+    /// Connect and start receiving events. Must be called within a
+    /// Tokio runtime.
+    pub fn subscribe(self) -> Subscription<T> {
+        let client = self.client;
+        self.into_operation().subscribe(client)
+    }
+}
+/// Subscription builder: Triggered when an agent activity is created
+///
+/// Event type: [`AgentActivity`](super::types::AgentActivity)
+///
+/// Use setter methods to configure optional arguments, then call
+/// [`.subscribe()`](Self::subscribe) to start receiving events.
+///
+/// Requires the `agents` feature.
+#[cfg(feature = "agents")]
+#[must_use]
+pub struct AgentActivityCreatedSubscriptionBuilder<'a, T> {
+    client: &'a Client,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "agents")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::AgentActivity>>
+    AgentActivityCreatedSubscriptionBuilder<'a, T>
+{
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the subscription without starting it, e.g. to set its
+    /// reconnect policy.
+    pub fn into_operation(self) -> SubscriptionOperation<T> {
+        let map = self.extra_variables;
+        let selection = format!("{} {{ {} }}", "agentActivityCreated", T::selection());
+        SubscriptionOperation::new(
+            "AgentActivityCreated",
+            "",
+            selection,
+            serde_json::Value::Object(map),
+            "agentActivityCreated",
+        )
+    }
+    /// Connect and start receiving events. Must be called within a
+    /// Tokio runtime.
+    pub fn subscribe(self) -> Subscription<T> {
+        let client = self.client;
+        self.into_operation().subscribe(client)
+    }
+}
+/// Subscription builder: Triggered when an agent activity is updated
+///
+/// Event type: [`AgentActivity`](super::types::AgentActivity)
+///
+/// Use setter methods to configure optional arguments, then call
+/// [`.subscribe()`](Self::subscribe) to start receiving events.
+///
+/// Requires the `agents` feature.
+#[cfg(feature = "agents")]
+#[must_use]
+pub struct AgentActivityUpdatedSubscriptionBuilder<'a, T> {
+    client: &'a Client,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "agents")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::AgentActivity>>
+    AgentActivityUpdatedSubscriptionBuilder<'a, T>
+{
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the subscription without starting it, e.g. to set its
+    /// reconnect policy.
+    pub fn into_operation(self) -> SubscriptionOperation<T> {
+        let map = self.extra_variables;
+        let selection = format!("{} {{ {} }}", "agentActivityUpdated", T::selection());
+        SubscriptionOperation::new(
+            "AgentActivityUpdated",
+            "",
+            selection,
+            serde_json::Value::Object(map),
+            "agentActivityUpdated",
+        )
+    }
+    /// Connect and start receiving events. Must be called within a
+    /// Tokio runtime.
+    pub fn subscribe(self) -> Subscription<T> {
+        let client = self.client;
+        self.into_operation().subscribe(client)
+    }
+}
+/// Subscription builder: Triggered when an ai conversation is updated
+///
+/// Event type: [`AiConversation`](super::types::AiConversation)
+///
+/// Use setter methods to configure optional arguments, then call
+/// [`.subscribe()`](Self::subscribe) to start receiving events.
+///
+/// Requires the `agents` feature.
+#[cfg(feature = "agents")]
+#[must_use]
+pub struct AiConversationUpdatedSubscriptionBuilder<'a, T> {
+    client: &'a Client,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "agents")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::AiConversation>>
+    AiConversationUpdatedSubscriptionBuilder<'a, T>
+{
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the subscription without starting it, e.g. to set its
+    /// reconnect policy.
+    pub fn into_operation(self) -> SubscriptionOperation<T> {
+        let map = self.extra_variables;
+        let selection = format!("{} {{ {} }}", "aiConversationUpdated", T::selection());
+        SubscriptionOperation::new(
+            "AiConversationUpdated",
+            "",
+            selection,
+            serde_json::Value::Object(map),
+            "aiConversationUpdated",
+        )
+    }
+    /// Connect and start receiving events. Must be called within a
+    /// Tokio runtime.
+    pub fn subscribe(self) -> Subscription<T> {
+        let client = self.client;
+        self.into_operation().subscribe(client)
+    }
+}
+/// Subscription builder: Triggered when an ai prompt progress is created
+///
+/// Event type: [`AiPromptProgress`](super::types::AiPromptProgress)
+///
+/// Use setter methods to configure optional arguments, then call
+/// [`.subscribe()`](Self::subscribe) to start receiving events.
+///
+/// Requires the `agents` feature.
+#[cfg(feature = "agents")]
+#[must_use]
+pub struct AiPromptProgressCreatedSubscriptionBuilder<'a, T> {
+    client: &'a Client,
+    filter: Option<AiPromptProgressSubscriptionFilter>,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "agents")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::AiPromptProgress>>
+    AiPromptProgressCreatedSubscriptionBuilder<'a, T>
+{
+    pub fn filter(mut self, value: AiPromptProgressSubscriptionFilter) -> Self {
+        self.filter = Some(value);
+        self
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the subscription without starting it, e.g. to set its
+    /// reconnect policy.
+    pub fn into_operation(self) -> SubscriptionOperation<T> {
+        let mut map = self.extra_variables;
+        if let Some(ref v) = self.filter {
+            map.insert("filter".to_string(), serde_json::json!(v));
+        }
+        let selection = format!(
+            "{} {{ {} }}",
+            "aiPromptProgressCreated(filter: $filter)",
+            T::selection()
+        );
+        SubscriptionOperation::new(
+            "AiPromptProgressCreated",
+            "$filter: AiPromptProgressSubscriptionFilter",
+            selection,
+            serde_json::Value::Object(map),
+            "aiPromptProgressCreated",
+        )
+    }
+    /// Connect and start receiving events. Must be called within a
+    /// Tokio runtime.
+    pub fn subscribe(self) -> Subscription<T> {
+        let client = self.client;
+        self.into_operation().subscribe(client)
+    }
+}
+/// Subscription builder: Triggered when an ai prompt progress is updated
+///
+/// Event type: [`AiPromptProgress`](super::types::AiPromptProgress)
+///
+/// Use setter methods to configure optional arguments, then call
+/// [`.subscribe()`](Self::subscribe) to start receiving events.
+///
+/// Requires the `agents` feature.
+#[cfg(feature = "agents")]
+#[must_use]
+pub struct AiPromptProgressUpdatedSubscriptionBuilder<'a, T> {
+    client: &'a Client,
+    filter: Option<AiPromptProgressSubscriptionFilter>,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "agents")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::AiPromptProgress>>
+    AiPromptProgressUpdatedSubscriptionBuilder<'a, T>
+{
+    pub fn filter(mut self, value: AiPromptProgressSubscriptionFilter) -> Self {
+        self.filter = Some(value);
+        self
+    }
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the subscription without starting it, e.g. to set its
+    /// reconnect policy.
+    pub fn into_operation(self) -> SubscriptionOperation<T> {
+        let mut map = self.extra_variables;
+        if let Some(ref v) = self.filter {
+            map.insert("filter".to_string(), serde_json::json!(v));
+        }
+        let selection = format!(
+            "{} {{ {} }}",
+            "aiPromptProgressUpdated(filter: $filter)",
+            T::selection()
+        );
+        SubscriptionOperation::new(
+            "AiPromptProgressUpdated",
+            "$filter: AiPromptProgressSubscriptionFilter",
+            selection,
+            serde_json::Value::Object(map),
+            "aiPromptProgressUpdated",
+        )
+    }
+    /// Connect and start receiving events. Must be called within a
+    /// Tokio runtime.
+    pub fn subscribe(self) -> Subscription<T> {
+        let client = self.client;
+        self.into_operation().subscribe(client)
+    }
+}
+/// Subscription builder: Triggered when a team is created
+///
+/// Event type: [`Team`](super::types::Team)
+///
+/// Use setter methods to configure optional arguments, then call
+/// [`.subscribe()`](Self::subscribe) to start receiving events.
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+#[must_use]
+pub struct TeamCreatedSubscriptionBuilder<'a, T> {
+    client: &'a Client,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "teams")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Team>>
+    TeamCreatedSubscriptionBuilder<'a, T>
+{
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the subscription without starting it, e.g. to set its
+    /// reconnect policy.
+    pub fn into_operation(self) -> SubscriptionOperation<T> {
+        let map = self.extra_variables;
+        let selection = format!("{} {{ {} }}", "teamCreated", T::selection());
+        SubscriptionOperation::new(
+            "TeamCreated",
+            "",
+            selection,
+            serde_json::Value::Object(map),
+            "teamCreated",
+        )
+    }
+    /// Connect and start receiving events. Must be called within a
+    /// Tokio runtime.
+    pub fn subscribe(self) -> Subscription<T> {
+        let client = self.client;
+        self.into_operation().subscribe(client)
+    }
+}
+/// Subscription builder: Triggered when a team is updated
+///
+/// Event type: [`Team`](super::types::Team)
+///
+/// Use setter methods to configure optional arguments, then call
+/// [`.subscribe()`](Self::subscribe) to start receiving events.
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+#[must_use]
+pub struct TeamUpdatedSubscriptionBuilder<'a, T> {
+    client: &'a Client,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "teams")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Team>>
+    TeamUpdatedSubscriptionBuilder<'a, T>
+{
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the subscription without starting it, e.g. to set its
+    /// reconnect policy.
+    pub fn into_operation(self) -> SubscriptionOperation<T> {
+        let map = self.extra_variables;
+        let selection = format!("{} {{ {} }}", "teamUpdated", T::selection());
+        SubscriptionOperation::new(
+            "TeamUpdated",
+            "",
+            selection,
+            serde_json::Value::Object(map),
+            "teamUpdated",
+        )
+    }
+    /// Connect and start receiving events. Must be called within a
+    /// Tokio runtime.
+    pub fn subscribe(self) -> Subscription<T> {
+        let client = self.client;
+        self.into_operation().subscribe(client)
+    }
+}
+/// Subscription builder: Triggered when a team is deleted
+///
+/// Event type: [`Team`](super::types::Team)
+///
+/// Use setter methods to configure optional arguments, then call
+/// [`.subscribe()`](Self::subscribe) to start receiving events.
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+#[must_use]
+pub struct TeamDeletedSubscriptionBuilder<'a, T> {
+    client: &'a Client,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "teams")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::Team>>
+    TeamDeletedSubscriptionBuilder<'a, T>
+{
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the subscription without starting it, e.g. to set its
+    /// reconnect policy.
+    pub fn into_operation(self) -> SubscriptionOperation<T> {
+        let map = self.extra_variables;
+        let selection = format!("{} {{ {} }}", "teamDeleted", T::selection());
+        SubscriptionOperation::new(
+            "TeamDeleted",
+            "",
+            selection,
+            serde_json::Value::Object(map),
+            "teamDeleted",
+        )
+    }
+    /// Connect and start receiving events. Must be called within a
+    /// Tokio runtime.
+    pub fn subscribe(self) -> Subscription<T> {
+        let client = self.client;
+        self.into_operation().subscribe(client)
+    }
+}
+/// Subscription builder: Triggered when a team membership is created
+///
+/// Event type: [`TeamMembership`](super::types::TeamMembership)
+///
+/// Use setter methods to configure optional arguments, then call
+/// [`.subscribe()`](Self::subscribe) to start receiving events.
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+#[must_use]
+pub struct TeamMembershipCreatedSubscriptionBuilder<'a, T> {
+    client: &'a Client,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "teams")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::TeamMembership>>
+    TeamMembershipCreatedSubscriptionBuilder<'a, T>
+{
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the subscription without starting it, e.g. to set its
+    /// reconnect policy.
+    pub fn into_operation(self) -> SubscriptionOperation<T> {
+        let map = self.extra_variables;
+        let selection = format!("{} {{ {} }}", "teamMembershipCreated", T::selection());
+        SubscriptionOperation::new(
+            "TeamMembershipCreated",
+            "",
+            selection,
+            serde_json::Value::Object(map),
+            "teamMembershipCreated",
+        )
+    }
+    /// Connect and start receiving events. Must be called within a
+    /// Tokio runtime.
+    pub fn subscribe(self) -> Subscription<T> {
+        let client = self.client;
+        self.into_operation().subscribe(client)
+    }
+}
+/// Subscription builder: Triggered when a team membership is updated
+///
+/// Event type: [`TeamMembership`](super::types::TeamMembership)
+///
+/// Use setter methods to configure optional arguments, then call
+/// [`.subscribe()`](Self::subscribe) to start receiving events.
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+#[must_use]
+pub struct TeamMembershipUpdatedSubscriptionBuilder<'a, T> {
+    client: &'a Client,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "teams")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::TeamMembership>>
+    TeamMembershipUpdatedSubscriptionBuilder<'a, T>
+{
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the subscription without starting it, e.g. to set its
+    /// reconnect policy.
+    pub fn into_operation(self) -> SubscriptionOperation<T> {
+        let map = self.extra_variables;
+        let selection = format!("{} {{ {} }}", "teamMembershipUpdated", T::selection());
+        SubscriptionOperation::new(
+            "TeamMembershipUpdated",
+            "",
+            selection,
+            serde_json::Value::Object(map),
+            "teamMembershipUpdated",
+        )
+    }
+    /// Connect and start receiving events. Must be called within a
+    /// Tokio runtime.
+    pub fn subscribe(self) -> Subscription<T> {
+        let client = self.client;
+        self.into_operation().subscribe(client)
+    }
+}
+/// Subscription builder: Triggered when a team membership is deleted
+///
+/// Event type: [`TeamMembership`](super::types::TeamMembership)
+///
+/// Use setter methods to configure optional arguments, then call
+/// [`.subscribe()`](Self::subscribe) to start receiving events.
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+#[must_use]
+pub struct TeamMembershipDeletedSubscriptionBuilder<'a, T> {
+    client: &'a Client,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "teams")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::TeamMembership>>
+    TeamMembershipDeletedSubscriptionBuilder<'a, T>
+{
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the subscription without starting it, e.g. to set its
+    /// reconnect policy.
+    pub fn into_operation(self) -> SubscriptionOperation<T> {
+        let map = self.extra_variables;
+        let selection = format!("{} {{ {} }}", "teamMembershipDeleted", T::selection());
+        SubscriptionOperation::new(
+            "TeamMembershipDeleted",
+            "",
+            selection,
+            serde_json::Value::Object(map),
+            "teamMembershipDeleted",
+        )
+    }
+    /// Connect and start receiving events. Must be called within a
+    /// Tokio runtime.
+    pub fn subscribe(self) -> Subscription<T> {
+        let client = self.client;
+        self.into_operation().subscribe(client)
+    }
+}
+/// Subscription builder: Triggered when an user is created
+///
+/// Event type: [`User`](super::types::User)
+///
+/// Use setter methods to configure optional arguments, then call
+/// [`.subscribe()`](Self::subscribe) to start receiving events.
+///
+/// Requires the `users` feature.
+#[cfg(feature = "users")]
+#[must_use]
+pub struct UserCreatedSubscriptionBuilder<'a, T> {
+    client: &'a Client,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "users")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::User>>
+    UserCreatedSubscriptionBuilder<'a, T>
+{
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the subscription without starting it, e.g. to set its
+    /// reconnect policy.
+    pub fn into_operation(self) -> SubscriptionOperation<T> {
+        let map = self.extra_variables;
+        let selection = format!("{} {{ {} }}", "userCreated", T::selection());
+        SubscriptionOperation::new(
+            "UserCreated",
+            "",
+            selection,
+            serde_json::Value::Object(map),
+            "userCreated",
+        )
+    }
+    /// Connect and start receiving events. Must be called within a
+    /// Tokio runtime.
+    pub fn subscribe(self) -> Subscription<T> {
+        let client = self.client;
+        self.into_operation().subscribe(client)
+    }
+}
+/// Subscription builder: Triggered when an user is updated
+///
+/// Event type: [`User`](super::types::User)
+///
+/// Use setter methods to configure optional arguments, then call
+/// [`.subscribe()`](Self::subscribe) to start receiving events.
+///
+/// Requires the `users` feature.
+#[cfg(feature = "users")]
+#[must_use]
+pub struct UserUpdatedSubscriptionBuilder<'a, T> {
+    client: &'a Client,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "users")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::User>>
+    UserUpdatedSubscriptionBuilder<'a, T>
+{
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the subscription without starting it, e.g. to set its
+    /// reconnect policy.
+    pub fn into_operation(self) -> SubscriptionOperation<T> {
+        let map = self.extra_variables;
+        let selection = format!("{} {{ {} }}", "userUpdated", T::selection());
+        SubscriptionOperation::new(
+            "UserUpdated",
+            "",
+            selection,
+            serde_json::Value::Object(map),
+            "userUpdated",
+        )
+    }
+    /// Connect and start receiving events. Must be called within a
+    /// Tokio runtime.
+    pub fn subscribe(self) -> Subscription<T> {
+        let client = self.client;
+        self.into_operation().subscribe(client)
+    }
+}
+/// Subscription builder: Triggered when a workflow state is created
+///
+/// Event type: [`WorkflowState`](super::types::WorkflowState)
+///
+/// Use setter methods to configure optional arguments, then call
+/// [`.subscribe()`](Self::subscribe) to start receiving events.
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+#[must_use]
+pub struct WorkflowStateCreatedSubscriptionBuilder<'a, T> {
+    client: &'a Client,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "teams")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::WorkflowState>>
+    WorkflowStateCreatedSubscriptionBuilder<'a, T>
+{
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the subscription without starting it, e.g. to set its
+    /// reconnect policy.
+    pub fn into_operation(self) -> SubscriptionOperation<T> {
+        let map = self.extra_variables;
+        let selection = format!("{} {{ {} }}", "workflowStateCreated", T::selection());
+        SubscriptionOperation::new(
+            "WorkflowStateCreated",
+            "",
+            selection,
+            serde_json::Value::Object(map),
+            "workflowStateCreated",
+        )
+    }
+    /// Connect and start receiving events. Must be called within a
+    /// Tokio runtime.
+    pub fn subscribe(self) -> Subscription<T> {
+        let client = self.client;
+        self.into_operation().subscribe(client)
+    }
+}
+/// Subscription builder: Triggered when a workflow state is updated
+///
+/// Event type: [`WorkflowState`](super::types::WorkflowState)
+///
+/// Use setter methods to configure optional arguments, then call
+/// [`.subscribe()`](Self::subscribe) to start receiving events.
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+#[must_use]
+pub struct WorkflowStateUpdatedSubscriptionBuilder<'a, T> {
+    client: &'a Client,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "teams")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::WorkflowState>>
+    WorkflowStateUpdatedSubscriptionBuilder<'a, T>
+{
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the subscription without starting it, e.g. to set its
+    /// reconnect policy.
+    pub fn into_operation(self) -> SubscriptionOperation<T> {
+        let map = self.extra_variables;
+        let selection = format!("{} {{ {} }}", "workflowStateUpdated", T::selection());
+        SubscriptionOperation::new(
+            "WorkflowStateUpdated",
+            "",
+            selection,
+            serde_json::Value::Object(map),
+            "workflowStateUpdated",
+        )
+    }
+    /// Connect and start receiving events. Must be called within a
+    /// Tokio runtime.
+    pub fn subscribe(self) -> Subscription<T> {
+        let client = self.client;
+        self.into_operation().subscribe(client)
+    }
+}
+/// Subscription builder: Triggered when a workflow state is archived
+///
+/// Event type: [`WorkflowState`](super::types::WorkflowState)
+///
+/// Use setter methods to configure optional arguments, then call
+/// [`.subscribe()`](Self::subscribe) to start receiving events.
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+#[must_use]
+pub struct WorkflowStateArchivedSubscriptionBuilder<'a, T> {
+    client: &'a Client,
+    extra_variables: serde_json::Map<String, serde_json::Value>,
+    _marker: std::marker::PhantomData<T>,
+}
+#[cfg(feature = "teams")]
+impl<'a, T: DeserializeOwned + GraphQLFields<FullType = super::types::WorkflowState>>
+    WorkflowStateArchivedSubscriptionBuilder<'a, T>
+{
+    /// Set a variable declared by a nested field's `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl serde::Serialize) -> Self {
+        self.extra_variables
+            .insert(name.into(), serde_json::json!(value));
+        self
+    }
+    /// Build the subscription without starting it, e.g. to set its
+    /// reconnect policy.
+    pub fn into_operation(self) -> SubscriptionOperation<T> {
+        let map = self.extra_variables;
+        let selection = format!("{} {{ {} }}", "workflowStateArchived", T::selection());
+        SubscriptionOperation::new(
+            "WorkflowStateArchived",
+            "",
+            selection,
+            serde_json::Value::Object(map),
+            "workflowStateArchived",
+        )
+    }
+    /// Connect and start receiving events. Must be called within a
+    /// Tokio runtime.
+    pub fn subscribe(self) -> Subscription<T> {
+        let client = self.client;
+        self.into_operation().subscribe(client)
+    }
+}
+/// Triggered when a comment is created
+///
+/// Event type: [`Comment`](super::types::Comment)
+pub fn comment_created<'a, T>(client: &'a Client) -> CommentCreatedSubscriptionBuilder<'a, T> {
+    CommentCreatedSubscriptionBuilder {
+        client,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Triggered when a comment is updated
+///
+/// Event type: [`Comment`](super::types::Comment)
+pub fn comment_updated<'a, T>(client: &'a Client) -> CommentUpdatedSubscriptionBuilder<'a, T> {
+    CommentUpdatedSubscriptionBuilder {
+        client,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Triggered when a comment is archived
+///
+/// Event type: [`Comment`](super::types::Comment)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub fn comment_archived<'a, T>(client: &'a Client) -> CommentArchivedSubscriptionBuilder<'a, T> {
+    CommentArchivedSubscriptionBuilder {
+        client,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Triggered when a a comment is unarchived
+///
+/// Event type: [`Comment`](super::types::Comment)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub fn comment_unarchived<'a, T>(
+    client: &'a Client,
+) -> CommentUnarchivedSubscriptionBuilder<'a, T> {
+    CommentUnarchivedSubscriptionBuilder {
+        client,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Triggered when a comment is deleted
+///
+/// Event type: [`Comment`](super::types::Comment)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub fn comment_deleted<'a, T>(client: &'a Client) -> CommentDeletedSubscriptionBuilder<'a, T> {
+    CommentDeletedSubscriptionBuilder {
+        client,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Triggered when a cycle is created
+///
+/// Event type: [`Cycle`](super::types::Cycle)
+///
+/// Requires the `cycles` feature.
+#[cfg(feature = "cycles")]
+pub fn cycle_created<'a, T>(client: &'a Client) -> CycleCreatedSubscriptionBuilder<'a, T> {
+    CycleCreatedSubscriptionBuilder {
+        client,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Triggered when a cycle is updated
+///
+/// Event type: [`Cycle`](super::types::Cycle)
+///
+/// Requires the `cycles` feature.
+#[cfg(feature = "cycles")]
+pub fn cycle_updated<'a, T>(client: &'a Client) -> CycleUpdatedSubscriptionBuilder<'a, T> {
+    CycleUpdatedSubscriptionBuilder {
+        client,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Triggered when a cycle is archived
+///
+/// Event type: [`Cycle`](super::types::Cycle)
+///
+/// Requires the `cycles` feature.
+#[cfg(feature = "cycles")]
+pub fn cycle_archived<'a, T>(client: &'a Client) -> CycleArchivedSubscriptionBuilder<'a, T> {
+    CycleArchivedSubscriptionBuilder {
+        client,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Triggered when a document is created
+///
+/// Event type: [`Document`](super::types::Document)
+///
+/// Requires the `documents` feature.
+#[cfg(feature = "documents")]
+pub fn document_created<'a, T>(client: &'a Client) -> DocumentCreatedSubscriptionBuilder<'a, T> {
+    DocumentCreatedSubscriptionBuilder {
+        client,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Triggered when a document is updated
+///
+/// Event type: [`Document`](super::types::Document)
+///
+/// Requires the `documents` feature.
+#[cfg(feature = "documents")]
+pub fn document_updated<'a, T>(client: &'a Client) -> DocumentUpdatedSubscriptionBuilder<'a, T> {
+    DocumentUpdatedSubscriptionBuilder {
+        client,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Triggered when a document is archived
+///
+/// Event type: [`Document`](super::types::Document)
+///
+/// Requires the `documents` feature.
+#[cfg(feature = "documents")]
+pub fn document_archived<'a, T>(client: &'a Client) -> DocumentArchivedSubscriptionBuilder<'a, T> {
+    DocumentArchivedSubscriptionBuilder {
+        client,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Triggered when a a document is unarchived
+///
+/// Event type: [`Document`](super::types::Document)
+///
+/// Requires the `documents` feature.
+#[cfg(feature = "documents")]
+pub fn document_unarchived<'a, T>(
+    client: &'a Client,
+) -> DocumentUnarchivedSubscriptionBuilder<'a, T> {
+    DocumentUnarchivedSubscriptionBuilder {
+        client,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Triggered when a document content is created
+///
+/// Event type: [`DocumentContent`](super::types::DocumentContent)
+///
+/// Requires the `documents` feature.
+#[cfg(feature = "documents")]
+pub fn document_content_created<'a, T>(
+    client: &'a Client,
+) -> DocumentContentCreatedSubscriptionBuilder<'a, T> {
+    DocumentContentCreatedSubscriptionBuilder {
+        client,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Triggered when a document content is updated
+///
+/// Event type: [`DocumentContent`](super::types::DocumentContent)
+pub fn document_content_updated<'a, T>(
+    client: &'a Client,
+) -> DocumentContentUpdatedSubscriptionBuilder<'a, T> {
+    DocumentContentUpdatedSubscriptionBuilder {
+        client,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Triggered when a document content draft is created
+///
+/// Event type: [`DocumentContentDraft`](super::types::DocumentContentDraft)
+///
+/// Requires the `documents` feature.
+#[cfg(feature = "documents")]
+pub fn document_content_draft_created<'a, T>(
+    client: &'a Client,
+) -> DocumentContentDraftCreatedSubscriptionBuilder<'a, T> {
+    DocumentContentDraftCreatedSubscriptionBuilder {
+        client,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Triggered when a document content draft is updated
+///
+/// Event type: [`DocumentContentDraft`](super::types::DocumentContentDraft)
+///
+/// Requires the `documents` feature.
+#[cfg(feature = "documents")]
+pub fn document_content_draft_updated<'a, T>(
+    client: &'a Client,
+) -> DocumentContentDraftUpdatedSubscriptionBuilder<'a, T> {
+    DocumentContentDraftUpdatedSubscriptionBuilder {
+        client,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Triggered when a document content draft is deleted
+///
+/// Event type: [`DocumentContentDraft`](super::types::DocumentContentDraft)
+///
+/// Requires the `documents` feature.
+#[cfg(feature = "documents")]
+pub fn document_content_draft_deleted<'a, T>(
+    client: &'a Client,
+) -> DocumentContentDraftDeletedSubscriptionBuilder<'a, T> {
+    DocumentContentDraftDeletedSubscriptionBuilder {
+        client,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Triggered when a draft is created
+///
+/// Event type: [`Draft`](super::types::Draft)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub fn draft_created<'a, T>(client: &'a Client) -> DraftCreatedSubscriptionBuilder<'a, T> {
+    DraftCreatedSubscriptionBuilder {
+        client,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Triggered when a draft is updated
+///
+/// Event type: [`Draft`](super::types::Draft)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub fn draft_updated<'a, T>(client: &'a Client) -> DraftUpdatedSubscriptionBuilder<'a, T> {
+    DraftUpdatedSubscriptionBuilder {
+        client,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Triggered when a draft is deleted
+///
+/// Event type: [`Draft`](super::types::Draft)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub fn draft_deleted<'a, T>(client: &'a Client) -> DraftDeletedSubscriptionBuilder<'a, T> {
+    DraftDeletedSubscriptionBuilder {
+        client,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Triggered when a favorite is created
+///
+/// Event type: [`Favorite`](super::types::Favorite)
+///
+/// Requires the `views` feature.
+#[cfg(feature = "views")]
+pub fn favorite_created<'a, T>(client: &'a Client) -> FavoriteCreatedSubscriptionBuilder<'a, T> {
+    FavoriteCreatedSubscriptionBuilder {
+        client,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Triggered when a favorite is updated
+///
+/// Event type: [`Favorite`](super::types::Favorite)
+///
+/// Requires the `views` feature.
+#[cfg(feature = "views")]
+pub fn favorite_updated<'a, T>(client: &'a Client) -> FavoriteUpdatedSubscriptionBuilder<'a, T> {
+    FavoriteUpdatedSubscriptionBuilder {
+        client,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Triggered when a favorite is deleted
+///
+/// Event type: [`Favorite`](super::types::Favorite)
+///
+/// Requires the `views` feature.
+#[cfg(feature = "views")]
+pub fn favorite_deleted<'a, T>(client: &'a Client) -> FavoriteDeletedSubscriptionBuilder<'a, T> {
+    FavoriteDeletedSubscriptionBuilder {
+        client,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Triggered when an issue is created
+///
+/// Event type: [`Issue`](super::types::Issue)
+pub fn issue_created<'a, T>(client: &'a Client) -> IssueCreatedSubscriptionBuilder<'a, T> {
+    IssueCreatedSubscriptionBuilder {
+        client,
+        filter: None,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Triggered when an issue is updated
+///
+/// Event type: [`Issue`](super::types::Issue)
+pub fn issue_updated<'a, T>(client: &'a Client) -> IssueUpdatedSubscriptionBuilder<'a, T> {
+    IssueUpdatedSubscriptionBuilder {
+        client,
+        filter: None,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Triggered when an issue is archived
+///
+/// Event type: [`Issue`](super::types::Issue)
+pub fn issue_archived<'a, T>(client: &'a Client) -> IssueArchivedSubscriptionBuilder<'a, T> {
+    IssueArchivedSubscriptionBuilder {
+        client,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Triggered when a an issue is unarchived
+///
+/// Event type: [`Issue`](super::types::Issue)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub fn issue_unarchived<'a, T>(client: &'a Client) -> IssueUnarchivedSubscriptionBuilder<'a, T> {
+    IssueUnarchivedSubscriptionBuilder {
+        client,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Triggered when an issue history is created
+///
+/// Event type: [`IssueHistory`](super::types::IssueHistory)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub fn issue_history_created<'a, T>(
+    client: &'a Client,
+) -> IssueHistoryCreatedSubscriptionBuilder<'a, T> {
+    IssueHistoryCreatedSubscriptionBuilder {
+        client,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Triggered when an issue history is updated
+///
+/// Event type: [`IssueHistory`](super::types::IssueHistory)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub fn issue_history_updated<'a, T>(
+    client: &'a Client,
+) -> IssueHistoryUpdatedSubscriptionBuilder<'a, T> {
+    IssueHistoryUpdatedSubscriptionBuilder {
+        client,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Triggered when an issue draft is created
+///
+/// Event type: [`IssueDraft`](super::types::IssueDraft)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub fn issue_draft_created<'a, T>(
+    client: &'a Client,
+) -> IssueDraftCreatedSubscriptionBuilder<'a, T> {
+    IssueDraftCreatedSubscriptionBuilder {
+        client,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Triggered when an issue draft is updated
+///
+/// Event type: [`IssueDraft`](super::types::IssueDraft)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub fn issue_draft_updated<'a, T>(
+    client: &'a Client,
+) -> IssueDraftUpdatedSubscriptionBuilder<'a, T> {
+    IssueDraftUpdatedSubscriptionBuilder {
+        client,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Triggered when an issue draft is deleted
+///
+/// Event type: [`IssueDraft`](super::types::IssueDraft)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub fn issue_draft_deleted<'a, T>(
+    client: &'a Client,
+) -> IssueDraftDeletedSubscriptionBuilder<'a, T> {
+    IssueDraftDeletedSubscriptionBuilder {
+        client,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Triggered when an issue label is created
+///
+/// Event type: [`IssueLabel`](super::types::IssueLabel)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub fn issue_label_created<'a, T>(
+    client: &'a Client,
+) -> IssueLabelCreatedSubscriptionBuilder<'a, T> {
+    IssueLabelCreatedSubscriptionBuilder {
+        client,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Triggered when an issue label is updated
+///
+/// Event type: [`IssueLabel`](super::types::IssueLabel)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub fn issue_label_updated<'a, T>(
+    client: &'a Client,
+) -> IssueLabelUpdatedSubscriptionBuilder<'a, T> {
+    IssueLabelUpdatedSubscriptionBuilder {
+        client,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Triggered when an issue label is deleted
+///
+/// Event type: [`IssueLabel`](super::types::IssueLabel)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub fn issue_label_deleted<'a, T>(
+    client: &'a Client,
+) -> IssueLabelDeletedSubscriptionBuilder<'a, T> {
+    IssueLabelDeletedSubscriptionBuilder {
+        client,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Triggered when an issue relation is created
+///
+/// Event type: [`IssueRelation`](super::types::IssueRelation)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub fn issue_relation_created<'a, T>(
+    client: &'a Client,
+) -> IssueRelationCreatedSubscriptionBuilder<'a, T> {
+    IssueRelationCreatedSubscriptionBuilder {
+        client,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Triggered when an issue relation is updated
+///
+/// Event type: [`IssueRelation`](super::types::IssueRelation)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub fn issue_relation_updated<'a, T>(
+    client: &'a Client,
+) -> IssueRelationUpdatedSubscriptionBuilder<'a, T> {
+    IssueRelationUpdatedSubscriptionBuilder {
+        client,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Triggered when an issue relation is deleted
+///
+/// Event type: [`IssueRelation`](super::types::IssueRelation)
+///
+/// Requires the `issues` feature.
+#[cfg(feature = "issues")]
+pub fn issue_relation_deleted<'a, T>(
+    client: &'a Client,
+) -> IssueRelationDeletedSubscriptionBuilder<'a, T> {
+    IssueRelationDeletedSubscriptionBuilder {
+        client,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Triggered when a notification is created
+///
+/// Event type: [`Notification`](super::types::Notification)
+pub fn notification_created<'a, T>(
+    client: &'a Client,
+) -> NotificationCreatedSubscriptionBuilder<'a, T> {
+    NotificationCreatedSubscriptionBuilder {
+        client,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Triggered when a notification is updated
+///
+/// Event type: [`Notification`](super::types::Notification)
+///
+/// Requires the `notifications` feature.
+#[cfg(feature = "notifications")]
+pub fn notification_updated<'a, T>(
+    client: &'a Client,
+) -> NotificationUpdatedSubscriptionBuilder<'a, T> {
+    NotificationUpdatedSubscriptionBuilder {
+        client,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Triggered when a notification is archived
+///
+/// Event type: [`Notification`](super::types::Notification)
+///
+/// Requires the `notifications` feature.
+#[cfg(feature = "notifications")]
+pub fn notification_archived<'a, T>(
+    client: &'a Client,
+) -> NotificationArchivedSubscriptionBuilder<'a, T> {
+    NotificationArchivedSubscriptionBuilder {
+        client,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Triggered when a a notification is unarchived
+///
+/// Event type: [`Notification`](super::types::Notification)
+///
+/// Requires the `notifications` feature.
+#[cfg(feature = "notifications")]
+pub fn notification_unarchived<'a, T>(
+    client: &'a Client,
+) -> NotificationUnarchivedSubscriptionBuilder<'a, T> {
+    NotificationUnarchivedSubscriptionBuilder {
+        client,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Triggered when a notification is deleted
+///
+/// Event type: [`Notification`](super::types::Notification)
+///
+/// Requires the `notifications` feature.
+#[cfg(feature = "notifications")]
+pub fn notification_deleted<'a, T>(
+    client: &'a Client,
+) -> NotificationDeletedSubscriptionBuilder<'a, T> {
+    NotificationDeletedSubscriptionBuilder {
+        client,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Triggered when an organization is updated
+///
+/// Event type: [`Organization`](super::types::Organization)
+///
+/// Requires the `admin` feature.
+#[cfg(feature = "admin")]
+pub fn organization_updated<'a, T>(
+    client: &'a Client,
+) -> OrganizationUpdatedSubscriptionBuilder<'a, T> {
+    OrganizationUpdatedSubscriptionBuilder {
+        client,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Triggered when a project is created
+///
+/// Event type: [`Project`](super::types::Project)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub fn project_created<'a, T>(client: &'a Client) -> ProjectCreatedSubscriptionBuilder<'a, T> {
+    ProjectCreatedSubscriptionBuilder {
+        client,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Triggered when a project is updated
+///
+/// Event type: [`Project`](super::types::Project)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub fn project_updated<'a, T>(client: &'a Client) -> ProjectUpdatedSubscriptionBuilder<'a, T> {
+    ProjectUpdatedSubscriptionBuilder {
+        client,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Triggered when a project is archived
+///
+/// Event type: [`Project`](super::types::Project)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub fn project_archived<'a, T>(client: &'a Client) -> ProjectArchivedSubscriptionBuilder<'a, T> {
+    ProjectArchivedSubscriptionBuilder {
+        client,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Triggered when a a project is unarchived
+///
+/// Event type: [`Project`](super::types::Project)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub fn project_unarchived<'a, T>(
+    client: &'a Client,
+) -> ProjectUnarchivedSubscriptionBuilder<'a, T> {
+    ProjectUnarchivedSubscriptionBuilder {
+        client,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Triggered when a project update is created
+///
+/// Event type: [`ProjectUpdate`](super::types::ProjectUpdate)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub fn project_update_created<'a, T>(
+    client: &'a Client,
+) -> ProjectUpdateCreatedSubscriptionBuilder<'a, T> {
+    ProjectUpdateCreatedSubscriptionBuilder {
+        client,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Triggered when a project update is updated
+///
+/// Event type: [`ProjectUpdate`](super::types::ProjectUpdate)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub fn project_update_updated<'a, T>(
+    client: &'a Client,
+) -> ProjectUpdateUpdatedSubscriptionBuilder<'a, T> {
+    ProjectUpdateUpdatedSubscriptionBuilder {
+        client,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Triggered when a project update is deleted
+///
+/// Event type: [`ProjectUpdate`](super::types::ProjectUpdate)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub fn project_update_deleted<'a, T>(
+    client: &'a Client,
+) -> ProjectUpdateDeletedSubscriptionBuilder<'a, T> {
+    ProjectUpdateDeletedSubscriptionBuilder {
+        client,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Triggered when a roadmap is created
+///
+/// Event type: [`Roadmap`](super::types::Roadmap)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub fn roadmap_created<'a, T>(client: &'a Client) -> RoadmapCreatedSubscriptionBuilder<'a, T> {
+    RoadmapCreatedSubscriptionBuilder {
+        client,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Triggered when a roadmap is updated
+///
+/// Event type: [`Roadmap`](super::types::Roadmap)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub fn roadmap_updated<'a, T>(client: &'a Client) -> RoadmapUpdatedSubscriptionBuilder<'a, T> {
+    RoadmapUpdatedSubscriptionBuilder {
+        client,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Triggered when a roadmap is deleted
+///
+/// Event type: [`Roadmap`](super::types::Roadmap)
+///
+/// Requires the `projects` feature.
+#[cfg(feature = "projects")]
+pub fn roadmap_deleted<'a, T>(client: &'a Client) -> RoadmapDeletedSubscriptionBuilder<'a, T> {
+    RoadmapDeletedSubscriptionBuilder {
+        client,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Triggered when an initiative is created
+///
+/// Event type: [`Initiative`](super::types::Initiative)
+///
+/// Requires the `initiatives` feature.
+#[cfg(feature = "initiatives")]
+pub fn initiative_created<'a, T>(
+    client: &'a Client,
+) -> InitiativeCreatedSubscriptionBuilder<'a, T> {
+    InitiativeCreatedSubscriptionBuilder {
+        client,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Triggered when an initiative is updated
+///
+/// Event type: [`Initiative`](super::types::Initiative)
+///
+/// Requires the `initiatives` feature.
+#[cfg(feature = "initiatives")]
+pub fn initiative_updated<'a, T>(
+    client: &'a Client,
+) -> InitiativeUpdatedSubscriptionBuilder<'a, T> {
+    InitiativeUpdatedSubscriptionBuilder {
+        client,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Triggered when an initiative is deleted
+///
+/// Event type: [`Initiative`](super::types::Initiative)
+///
+/// Requires the `initiatives` feature.
+#[cfg(feature = "initiatives")]
+pub fn initiative_deleted<'a, T>(
+    client: &'a Client,
+) -> InitiativeDeletedSubscriptionBuilder<'a, T> {
+    InitiativeDeletedSubscriptionBuilder {
+        client,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Triggered when an agent session is created
+///
+/// Event type: [`AgentSession`](super::types::AgentSession)
+///
+/// Requires the `agents` feature.
+#[cfg(feature = "agents")]
+pub fn agent_session_created<'a, T>(
+    client: &'a Client,
+) -> AgentSessionCreatedSubscriptionBuilder<'a, T> {
+    AgentSessionCreatedSubscriptionBuilder {
+        client,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Triggered when an agent session is updated
+///
+/// Event type: [`AgentSession`](super::types::AgentSession)
+///
+/// Requires the `agents` feature.
+#[cfg(feature = "agents")]
+pub fn agent_session_updated<'a, T>(
+    client: &'a Client,
+) -> AgentSessionUpdatedSubscriptionBuilder<'a, T> {
+    AgentSessionUpdatedSubscriptionBuilder {
+        client,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Triggered when an agent activity is created
+///
+/// Event type: [`AgentActivity`](super::types::AgentActivity)
+///
+/// Requires the `agents` feature.
+#[cfg(feature = "agents")]
+pub fn agent_activity_created<'a, T>(
+    client: &'a Client,
+) -> AgentActivityCreatedSubscriptionBuilder<'a, T> {
+    AgentActivityCreatedSubscriptionBuilder {
+        client,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Triggered when an agent activity is updated
+///
+/// Event type: [`AgentActivity`](super::types::AgentActivity)
+///
+/// Requires the `agents` feature.
+#[cfg(feature = "agents")]
+pub fn agent_activity_updated<'a, T>(
+    client: &'a Client,
+) -> AgentActivityUpdatedSubscriptionBuilder<'a, T> {
+    AgentActivityUpdatedSubscriptionBuilder {
+        client,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Triggered when an ai conversation is updated
+///
+/// Event type: [`AiConversation`](super::types::AiConversation)
+///
+/// Requires the `agents` feature.
+#[cfg(feature = "agents")]
+pub fn ai_conversation_updated<'a, T>(
+    client: &'a Client,
+) -> AiConversationUpdatedSubscriptionBuilder<'a, T> {
+    AiConversationUpdatedSubscriptionBuilder {
+        client,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Triggered when an ai prompt progress is created
+///
+/// Event type: [`AiPromptProgress`](super::types::AiPromptProgress)
+///
+/// Requires the `agents` feature.
+#[cfg(feature = "agents")]
+pub fn ai_prompt_progress_created<'a, T>(
+    client: &'a Client,
+) -> AiPromptProgressCreatedSubscriptionBuilder<'a, T> {
+    AiPromptProgressCreatedSubscriptionBuilder {
+        client,
+        filter: None,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Triggered when an ai prompt progress is updated
+///
+/// Event type: [`AiPromptProgress`](super::types::AiPromptProgress)
+///
+/// Requires the `agents` feature.
+#[cfg(feature = "agents")]
+pub fn ai_prompt_progress_updated<'a, T>(
+    client: &'a Client,
+) -> AiPromptProgressUpdatedSubscriptionBuilder<'a, T> {
+    AiPromptProgressUpdatedSubscriptionBuilder {
+        client,
+        filter: None,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Triggered when a team is created
+///
+/// Event type: [`Team`](super::types::Team)
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+pub fn team_created<'a, T>(client: &'a Client) -> TeamCreatedSubscriptionBuilder<'a, T> {
+    TeamCreatedSubscriptionBuilder {
+        client,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Triggered when a team is updated
+///
+/// Event type: [`Team`](super::types::Team)
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+pub fn team_updated<'a, T>(client: &'a Client) -> TeamUpdatedSubscriptionBuilder<'a, T> {
+    TeamUpdatedSubscriptionBuilder {
+        client,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Triggered when a team is deleted
+///
+/// Event type: [`Team`](super::types::Team)
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+pub fn team_deleted<'a, T>(client: &'a Client) -> TeamDeletedSubscriptionBuilder<'a, T> {
+    TeamDeletedSubscriptionBuilder {
+        client,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Triggered when a team membership is created
+///
+/// Event type: [`TeamMembership`](super::types::TeamMembership)
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+pub fn team_membership_created<'a, T>(
+    client: &'a Client,
+) -> TeamMembershipCreatedSubscriptionBuilder<'a, T> {
+    TeamMembershipCreatedSubscriptionBuilder {
+        client,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Triggered when a team membership is updated
+///
+/// Event type: [`TeamMembership`](super::types::TeamMembership)
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+pub fn team_membership_updated<'a, T>(
+    client: &'a Client,
+) -> TeamMembershipUpdatedSubscriptionBuilder<'a, T> {
+    TeamMembershipUpdatedSubscriptionBuilder {
+        client,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Triggered when a team membership is deleted
+///
+/// Event type: [`TeamMembership`](super::types::TeamMembership)
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+pub fn team_membership_deleted<'a, T>(
+    client: &'a Client,
+) -> TeamMembershipDeletedSubscriptionBuilder<'a, T> {
+    TeamMembershipDeletedSubscriptionBuilder {
+        client,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Triggered when an user is created
+///
+/// Event type: [`User`](super::types::User)
+///
+/// Requires the `users` feature.
+#[cfg(feature = "users")]
+pub fn user_created<'a, T>(client: &'a Client) -> UserCreatedSubscriptionBuilder<'a, T> {
+    UserCreatedSubscriptionBuilder {
+        client,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Triggered when an user is updated
+///
+/// Event type: [`User`](super::types::User)
+///
+/// Requires the `users` feature.
+#[cfg(feature = "users")]
+pub fn user_updated<'a, T>(client: &'a Client) -> UserUpdatedSubscriptionBuilder<'a, T> {
+    UserUpdatedSubscriptionBuilder {
+        client,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Triggered when a workflow state is created
+///
+/// Event type: [`WorkflowState`](super::types::WorkflowState)
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+pub fn workflow_state_created<'a, T>(
+    client: &'a Client,
+) -> WorkflowStateCreatedSubscriptionBuilder<'a, T> {
+    WorkflowStateCreatedSubscriptionBuilder {
+        client,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Triggered when a workflow state is updated
+///
+/// Event type: [`WorkflowState`](super::types::WorkflowState)
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+pub fn workflow_state_updated<'a, T>(
+    client: &'a Client,
+) -> WorkflowStateUpdatedSubscriptionBuilder<'a, T> {
+    WorkflowStateUpdatedSubscriptionBuilder {
+        client,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
+/// Triggered when a workflow state is archived
+///
+/// Event type: [`WorkflowState`](super::types::WorkflowState)
+///
+/// Requires the `teams` feature.
+#[cfg(feature = "teams")]
+pub fn workflow_state_archived<'a, T>(
+    client: &'a Client,
+) -> WorkflowStateArchivedSubscriptionBuilder<'a, T> {
+    WorkflowStateArchivedSubscriptionBuilder {
+        client,
+        extra_variables: serde_json::Map::new(),
+        _marker: std::marker::PhantomData,
+    }
+}
@@ -1,4 +1,4 @@
-//! GraphQL object types.
+//! GraphQL object, interface and union types.
 //!
 //! Generated by lineark-codegen — do not edit.
 use super::enums::*;
@@ -42,6 +42,8 @@ pub struct AgentActivity {
     pub archived_at: Option<chrono::DateTime<chrono::Utc>>,
     /// The agent session this activity belongs to.
     pub agent_session: Option<AgentSession>,
+    /// The content of the activity, which varies by type (thought, action, response, prompt, error, or elicitation).
+    pub content: Option<AgentActivityContent>,
     /// The source comment this activity is linked to. Null if the activity was not triggered by a comment.
     pub source_comment: Option<Comment>,
     /// The user who created this agent activity.
@@ -251,6 +253,7 @@ pub struct AgentSession {
     /// Activities associated with this agent session.
     pub activities: Option<AgentActivityConnection>,
     /// The URL of an external agent-hosted page associated with this session.
+    #[deprecated(note = "Use externalUrls instead.")]
     pub external_link: Option<String>,
     /// A human-readable summary of the work performed in this session. Null if no summary has been generated yet.
     pub summary: Option<String>,
@@ -261,6 +264,7 @@ pub struct AgentSession {
     /// The entity contexts this session is related to, such as issues or projects referenced in direct chat sessions. Used to provide contextual awareness to the agent.
     pub context: Option<serde_json::Value>,
     /// `DEPRECATED` The type of the agent session.
+    #[deprecated(note = "This field is slated for removal.")]
     pub r#type: Option<AgentSessionType>,
     /// The URL to the agent session page in the Linear app. Null for direct chat sessions without an associated issue.
     pub url: Option<String>,
@@ -269,6 +273,7 @@ pub struct AgentSession {
     /// External links associated with this session.
     pub external_links: Option<Vec<AgentSessionExternalLink>>,
     /// URLs of external resources associated with this session.
+    #[deprecated(note = "Use externalLinks instead.")]
     pub external_urls: Option<serde_json::Value>,
 }
 impl GraphQLFields for AgentSession {
@@ -414,6 +419,8 @@ pub struct AiConversation {
     pub status: Option<AiConversationStatus>,
     /// The time when the user marked the conversation as read. Null if the user hasn't read the conversation.
     pub read_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// The ordered sequence of conversation parts (prompts, text responses, reasoning steps, tool calls, and widgets) that make up this conversation's visible history.
+    pub parts: Option<Vec<AiConversationPart>>,
     /// The iteration ID when this conversation is part of an agentic workflow. Used to track multi-step workflow executions. Null for non-workflow conversations.
     pub iteration_id: Option<String>,
     /// `Internal` The log ID of the AI response.
@@ -543,6 +550,7 @@ pub struct AiConversationEntityCardWidgetArgs {
     /// The UUID of the entity to display
     pub id: Option<String>,
     /// @deprecated Optional note to display about the entity
+    #[deprecated(note = "Optional note to display about the entity")]
     pub note: Option<String>,
     /// The action performed on the entity (leave empty if just found)
     pub action: Option<AiConversationEntityCardWidgetArgsAction>,
@@ -594,6 +602,7 @@ pub struct AiConversationEntityListWidgetArgsEntities {
     /// Entity UUID
     pub id: Option<String>,
     /// @deprecated Optional note to display about the entity
+    #[deprecated(note = "Optional note to display about the entity")]
     pub note: Option<String>,
 }
 impl GraphQLFields for AiConversationEntityListWidgetArgsEntities {
@@ -1244,6 +1253,8 @@ pub struct AiConversationToolCallPart {
     pub r#type: Option<AiConversationPartType>,
     /// The metadata of the part.
     pub metadata: Option<AiConversationPartMetadata>,
+    /// The tool call part.
+    pub tool_call: Option<AiConversationToolCall>,
 }
 impl GraphQLFields for AiConversationToolCallPart {
     type FullType = Self;
@@ -1386,6 +1397,8 @@ pub struct AiConversationWidgetPart {
     pub r#type: Option<AiConversationPartType>,
     /// The metadata of the part.
     pub metadata: Option<AiConversationPartMetadata>,
+    /// The widget.
+    pub widget: Option<AiConversationWidget>,
 }
 impl GraphQLFields for AiConversationWidgetPart {
     type FullType = Self;
@@ -1776,6 +1789,7 @@ pub struct AuthOrganization {
     /// `INTERNAL` SAML settings
     pub saml_settings: Option<serde_json::Value>,
     /// Allowed authentication providers, empty array means all are allowed
+    #[deprecated(note = "Use authSettings.allowedAuthServices instead.")]
     pub allowed_auth_services: Option<Vec<String>>,
     /// Authentication settings for the organization.
     pub auth_settings: Option<serde_json::Value>,
@@ -1820,6 +1834,7 @@ pub struct AuthResolverResponse {
     /// The authentication service used for the current session (e.g., google, email, saml).
     pub service: Option<String>,
     /// Application token.
+    #[deprecated(note = "Deprecated and not used anymore. Never populated.")]
     pub token: Option<String>,
 }
 impl GraphQLFields for AuthResolverResponse {
@@ -2181,6 +2196,7 @@ pub struct CustomView {
     /// The user who last updated the custom view. Null if the updater's account has been deleted.
     pub updated_by: Option<Box<User>>,
     /// The legacy serialized filters applied to issues in the custom view.
+    #[deprecated(note = "Will be replaced by `filterData` in a future update")]
     pub filters: Option<serde_json::Value>,
     /// The structured filter applied to issues in the custom view. Used when the view's modelName is "Issue".
     pub filter_data: Option<serde_json::Value>,
@@ -2778,6 +2794,7 @@ pub struct CustomerStatus {
     /// The user-facing display name of the status shown in the UI. Defaults to the internal name if not explicitly set.
     pub display_name: Option<String>,
     /// `Deprecated` The type of the customer status. Always returns null as statuses are no longer grouped by type.
+    #[deprecated(note = "Customer statuses are no longer grouped by type.")]
     pub r#type: Option<CustomerStatusType>,
 }
 impl GraphQLFields for CustomerStatus {
@@ -3570,6 +3587,7 @@ pub struct Draft {
     /// Additional properties for the draft, such as generation metadata for AI-generated drafts, health status for project updates, or post titles.
     pub data: Option<serde_json::Value>,
     /// Whether the draft was autogenerated for the user.
+    #[deprecated(note = "Use 'data.generationMetadata' instead")]
     pub is_autogenerated: Option<bool>,
     /// `INTERNAL` Whether the draft was ported from a local draft.
     pub was_local_draft: Option<bool>,
@@ -3900,6 +3918,8 @@ pub struct ExternalEntityInfo {
     pub id: Option<String>,
     /// The external service that this entity belongs to (e.g., jira, github, slack).
     pub service: Option<ExternalSyncService>,
+    /// Service-specific metadata about the external entity. The concrete type depends on the service (e.g., Jira issue key, GitHub repo and issue number, Slack channel info). Null for entity types that do not have additional metadata.
+    pub metadata: Option<ExternalEntityInfoMetadata>,
 }
 impl GraphQLFields for ExternalEntityInfo {
     type FullType = Self;
@@ -4334,6 +4354,7 @@ pub struct GitAutomationState {
     /// The Git event that triggers this automation rule (e.g., branch created, PR opened for review, or PR merged).
     pub event: Option<GitAutomationStates>,
     /// `DEPRECATED` The target branch, if null, the automation will be triggered on any branch.
+    #[deprecated(note = "Use targetBranch instead.")]
     pub branch_pattern: Option<String>,
 }
 impl GraphQLFields for GitAutomationState {
@@ -5398,6 +5419,7 @@ pub struct IntegrationsSettings {
     /// The type of view to which the integration settings context is associated with.
     pub context_view_type: Option<ContextViewType>,
     /// Whether to send a Slack message when a new issue is created for the project or the team.
+    #[deprecated(note = "No longer in use. Use `slackIssueAddedToView` instead.")]
     pub slack_issue_created: Option<bool>,
     /// Whether to send a Slack message when a comment is created on any of the project or team's issues.
     pub slack_issue_new_comment: Option<bool>,
@@ -5475,6 +5497,7 @@ pub struct Issue {
     /// The estimate of the complexity of the issue. The specific scale used depends on the team's estimation configuration (e.g., points, T-shirt sizes). Null if no estimate has been set.
     pub estimate: Option<f64>,
     /// The order of the item in its column on the board.
+    #[deprecated(note = "Will be removed in near future, please use `sortOrder` instead")]
     pub board_order: Option<f64>,
     /// The order of the item in relation to other items in the organization. Used for manual sorting in list views.
     pub sort_order: Option<f64>,
@@ -5896,6 +5919,7 @@ pub struct IssueHistory {
     /// The actor that performed the actions. This field may be empty in the case of integrations or automations.
     pub actor: Option<User>,
     /// The actors that performed the actions. This field may be empty in the case of integrations or automations.
+    #[deprecated(note = "Use `actor` and `descriptionUpdatedBy` instead.")]
     pub actors: Option<Vec<User>>,
     /// The actors that edited the description of the issue, if any.
     pub description_updated_by: Option<Vec<User>>,
@@ -5970,6 +5994,7 @@ pub struct IssueHistory {
     /// The releases that the issue was removed from.
     pub removed_from_releases: Option<Vec<Release>>,
     /// `INTERNAL` Metadata about the triage rule that made changes to the issue.
+    #[deprecated(note = "Use `workflowMetadata` instead.")]
     pub triage_rule_metadata: Option<IssueHistoryTriageRuleMetadata>,
     /// `INTERNAL` Metadata about the workflow that made changes to the issue.
     pub workflow_metadata: Option<IssueHistoryWorkflowMetadata>,
@@ -6037,6 +6062,7 @@ pub struct IssueHistoryTriageRuleMetadata {
     /// The error that occurred, if any.
     pub triage_rule_error: Option<IssueHistoryTriageRuleError>,
     /// The triage rule that triggered the issue update.
+    #[deprecated(note = "Use `IssueHistoryWorkflowMetadata.workflowDefinition` instead.")]
     pub updated_by_triage_rule: Option<WorkflowDefinition>,
 }
 impl GraphQLFields for IssueHistoryTriageRuleMetadata {
@@ -6207,6 +6233,7 @@ pub struct IssueLabel {
     pub last_applied_at: Option<chrono::DateTime<chrono::Utc>>,
     /// `Internal` When the label was retired.
     pub retired_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[deprecated(note = "Workspace labels are identified by their team being null.")]
     pub organization: Option<Box<Organization>>,
     /// The team that the label is scoped to. If null, the label is a workspace-level label available to all teams in the workspace.
     pub team: Option<Box<Team>>,
@@ -6348,6 +6375,8 @@ pub struct IssueNotification {
     pub parent_comment: Option<Comment>,
     /// The team related to the issue notification.
     pub team: Option<Team>,
+    /// The subscriptions related to the notification.
+    pub subscriptions: Option<Vec<NotificationSubscription>>,
 }
 impl GraphQLFields for IssueNotification {
     type FullType = Self;
@@ -6510,6 +6539,7 @@ pub struct IssueSearchResult {
     /// The estimate of the complexity of the issue. The specific scale used depends on the team's estimation configuration (e.g., points, T-shirt sizes). Null if no estimate has been set.
     pub estimate: Option<f64>,
     /// The order of the item in its column on the board.
+    #[deprecated(note = "Will be removed in near future, please use `sortOrder` instead")]
     pub board_order: Option<f64>,
     /// The order of the item in relation to other items in the organization. Used for manual sorting in list views.
     pub sort_order: Option<f64>,
@@ -7071,6 +7101,8 @@ pub struct NotificationArchivePayload {
     pub last_sync_id: Option<f64>,
     /// Whether the operation was successful.
     pub success: Option<bool>,
+    /// The archived/unarchived entity. Null if entity was deleted.
+    pub entity: Option<Notification>,
 }
 impl GraphQLFields for NotificationArchivePayload {
     type FullType = Self;
@@ -7084,6 +7116,8 @@ impl GraphQLFields for NotificationArchivePayload {
 pub struct NotificationBatchActionPayload {
     /// The identifier of the last sync operation.
     pub last_sync_id: Option<f64>,
+    /// The notifications that were updated by the batch operation.
+    pub notifications: Option<Vec<Notification>>,
     /// Whether the operation was successful.
     pub success: Option<bool>,
 }
@@ -7157,6 +7191,7 @@ impl GraphQLFields for NotificationChannelPreferences {
 #[serde(rename_all = "camelCase", default)]
 pub struct NotificationConnection {
     pub edges: Option<Vec<NotificationEdge>>,
+    pub nodes: Option<Vec<Notification>>,
     pub page_info: Option<PageInfo>,
 }
 impl GraphQLFields for NotificationConnection {
@@ -7183,6 +7218,7 @@ impl GraphQLFields for NotificationDeliveryPreferences {
 #[serde(rename_all = "camelCase", default)]
 pub struct NotificationDeliveryPreferencesChannel {
     /// `DEPRECATED` Whether notifications are enabled for this channel. Use notificationChannelPreferences instead.
+    #[deprecated(note = "This field has been replaced by notificationChannelPreferences")]
     pub notifications_disabled: Option<bool>,
     /// The schedule for notifications on this channel.
     pub schedule: Option<NotificationDeliveryPreferencesSchedule>,
@@ -7238,6 +7274,7 @@ impl GraphQLFields for NotificationDeliveryPreferencesSchedule {
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase", default)]
 pub struct NotificationEdge {
+    pub node: Option<Notification>,
     /// Used in `before` and `after` args
     pub cursor: Option<String>,
 }
@@ -7253,6 +7290,8 @@ impl GraphQLFields for NotificationEdge {
 pub struct NotificationPayload {
     /// The identifier of the last sync operation.
     pub last_sync_id: Option<f64>,
+    /// The notification that was updated.
+    pub notification: Option<Notification>,
     /// Whether the operation was successful.
     pub success: Option<bool>,
 }
@@ -7266,6 +7305,7 @@ impl GraphQLFields for NotificationPayload {
 #[serde(rename_all = "camelCase", default)]
 pub struct NotificationSubscriptionConnection {
     pub edges: Option<Vec<NotificationSubscriptionEdge>>,
+    pub nodes: Option<Vec<NotificationSubscription>>,
     pub page_info: Option<PageInfo>,
 }
 impl GraphQLFields for NotificationSubscriptionConnection {
@@ -7277,6 +7317,7 @@ impl GraphQLFields for NotificationSubscriptionConnection {
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase", default)]
 pub struct NotificationSubscriptionEdge {
+    pub node: Option<NotificationSubscription>,
     /// Used in `before` and `after` args
     pub cursor: Option<String>,
 }
@@ -7292,6 +7333,8 @@ impl GraphQLFields for NotificationSubscriptionEdge {
 pub struct NotificationSubscriptionPayload {
     /// The identifier of the last sync operation.
     pub last_sync_id: Option<f64>,
+    /// The notification subscription that was created or updated. This controls which notifications the user receives for the associated entity.
+    pub notification_subscription: Option<NotificationSubscription>,
     /// Whether the operation was successful.
     pub success: Option<bool>,
 }
@@ -7471,6 +7514,7 @@ pub struct Organization {
     /// Authentication settings for the workspace, including allowed auth providers, bypass rules, and organization visibility during signup.
     pub auth_settings: Option<serde_json::Value>,
     /// Allowed authentication providers, empty array means all are allowed.
+    #[deprecated(note = "Use authSettings.allowedAuthServices instead.")]
     pub allowed_auth_services: Option<Vec<String>>,
     /// Allowed file upload content types
     pub allowed_file_upload_content_types: Option<Vec<String>>,
@@ -7503,6 +7547,7 @@ pub struct Organization {
     /// Whether the activity feed feature is enabled for the workspace.
     pub feed_enabled: Option<bool>,
     /// Whether to hide other organizations for new users signing up with email domains claimed by this organization.
+    #[deprecated(note = "Use authSettings.hideNonPrimaryOrganizations instead.")]
     pub hide_non_primary_organizations: Option<bool>,
     /// `INTERNAL` Whether the workspace has enabled the AI add-on (which at this point only includes triage suggestions).
     pub ai_addon_enabled: Option<bool>,
@@ -7523,10 +7568,13 @@ pub struct Organization {
     /// `INTERNAL` Whether the workspace has enabled the Coding Agent.
     pub coding_agent_enabled: Option<bool>,
     /// `DEPRECATED` Which day count to use for SLA calculations.
+    #[deprecated(note = "No longer in use")]
     pub sla_day_count: Option<SLADayCountType>,
     /// `DEPRECATED` The frequency at which to prompt for project updates.
+    #[deprecated(note = "Use organization.projectUpdatesReminderFrequencyInWeeks instead")]
     pub project_updates_reminder_frequency: Option<ProjectUpdateReminderFrequency>,
     /// `INTERNAL` Permitted AI providers.
+    #[deprecated(note = "Use aiProviderConfiguration instead.")]
     pub allowed_ai_providers: Option<Vec<String>>,
     /// Users belonging to the workspace. By default only returns active users; use the includeDisabled argument to include deactivated users.
     pub users: Option<UserConnection>,
@@ -7563,10 +7611,13 @@ pub struct Organization {
     /// `Internal` Whether release management is enabled for the workspace.
     pub releases_enabled: Option<bool>,
     /// `DEPRECATED` Whether member users are allowed to send invites.
+    #[deprecated(note = "Use `securitySettings.invitationsRole` instead.")]
     pub allow_members_to_invite: Option<bool>,
     /// `DEPRECATED` Whether team creation is restricted to admins.
+    #[deprecated(note = "Use `securitySettings.teamCreationRole` instead.")]
     pub restrict_team_creation_to_admins: Option<bool>,
     /// `DEPRECATED` Whether workspace label creation, update, and deletion is restricted to admins.
+    #[deprecated(note = "Use `securitySettings.labelManagementRole` instead.")]
     pub restrict_label_management_to_admins: Option<bool>,
 }
 impl GraphQLFields for Organization {
@@ -8180,10 +8231,13 @@ pub struct Project {
     /// `INTERNAL` The current progress of the project, broken down by issue status category.
     pub current_progress: Option<serde_json::Value>,
     /// Whether to send new issue notifications to Slack.
+    #[deprecated(note = "No longer in use")]
     pub slack_new_issue: Option<bool>,
     /// Whether to send new issue comment notifications to Slack.
+    #[deprecated(note = "No longer in use")]
     pub slack_issue_comments: Option<bool>,
     /// Whether to send new issue status updates to Slack.
+    #[deprecated(note = "No longer is use")]
     pub slack_issue_statuses: Option<bool>,
     /// The IDs of the project labels associated with this project.
     pub label_ids: Option<Vec<String>>,
@@ -8236,6 +8290,7 @@ pub struct Project {
     /// Customer needs associated with the project.
     pub needs: Option<CustomerNeedConnection>,
     /// `DEPRECATED` The type of the state.
+    #[deprecated(note = "Use project.status instead")]
     pub state: Option<String>,
     /// The priority of the project as a label.
     pub priority_label: Option<String>,
@@ -8982,10 +9037,13 @@ pub struct ProjectSearchResult {
     /// `INTERNAL` The current progress of the project, broken down by issue status category.
     pub current_progress: Option<serde_json::Value>,
     /// Whether to send new issue notifications to Slack.
+    #[deprecated(note = "No longer in use")]
     pub slack_new_issue: Option<bool>,
     /// Whether to send new issue comment notifications to Slack.
+    #[deprecated(note = "No longer in use")]
     pub slack_issue_comments: Option<bool>,
     /// Whether to send new issue status updates to Slack.
+    #[deprecated(note = "No longer is use")]
     pub slack_issue_statuses: Option<bool>,
     /// The IDs of the project labels associated with this project.
     pub label_ids: Option<Vec<String>>,
@@ -9038,6 +9096,7 @@ pub struct ProjectSearchResult {
     /// Customer needs associated with the project.
     pub needs: Option<CustomerNeedConnection>,
     /// `DEPRECATED` The type of the state.
+    #[deprecated(note = "Use project.status instead")]
     pub state: Option<String>,
     /// The priority of the project as a label.
     pub priority_label: Option<String>,
@@ -10296,6 +10355,7 @@ impl GraphQLFields for RoadmapToProjectPayload {
 #[serde(rename_all = "camelCase", default)]
 pub struct SemanticSearchPayload {
     /// Whether the semantic search is enabled.
+    #[deprecated(note = "Always true.")]
     pub enabled: Option<bool>,
     /// The list of matching search results, ordered by relevance score.
     pub results: Option<Vec<SemanticSearchResult>>,
@@ -10474,156 +10534,6 @@ impl GraphQLFields for SsoUrlFromEmailResponse {
 }
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase", default)]
-pub struct Subscription {
-    /// Triggered when a comment is created
-    pub comment_created: Option<Comment>,
-    /// Triggered when a comment is updated
-    pub comment_updated: Option<Comment>,
-    /// Triggered when a comment is archived
-    pub comment_archived: Option<Comment>,
-    /// Triggered when a a comment is unarchived
-    pub comment_unarchived: Option<Comment>,
-    /// Triggered when a comment is deleted
-    pub comment_deleted: Option<Comment>,
-    /// Triggered when a cycle is created
-    pub cycle_created: Option<Cycle>,
-    /// Triggered when a cycle is updated
-    pub cycle_updated: Option<Cycle>,
-    /// Triggered when a cycle is archived
-    pub cycle_archived: Option<Cycle>,
-    /// Triggered when a document is created
-    pub document_created: Option<Document>,
-    /// Triggered when a document is updated
-    pub document_updated: Option<Document>,
-    /// Triggered when a document is archived
-    pub document_archived: Option<Document>,
-    /// Triggered when a a document is unarchived
-    pub document_unarchived: Option<Document>,
-    /// Triggered when a document content is created
-    pub document_content_created: Option<DocumentContent>,
-    /// Triggered when a document content is updated
-    pub document_content_updated: Option<DocumentContent>,
-    /// Triggered when a document content draft is created
-    pub document_content_draft_created: Option<DocumentContentDraft>,
-    /// Triggered when a document content draft is updated
-    pub document_content_draft_updated: Option<DocumentContentDraft>,
-    /// Triggered when a document content draft is deleted
-    pub document_content_draft_deleted: Option<DocumentContentDraft>,
-    /// Triggered when a draft is created
-    pub draft_created: Option<Draft>,
-    /// Triggered when a draft is updated
-    pub draft_updated: Option<Draft>,
-    /// Triggered when a draft is deleted
-    pub draft_deleted: Option<Draft>,
-    /// Triggered when a favorite is created
-    pub favorite_created: Option<Favorite>,
-    /// Triggered when a favorite is updated
-    pub favorite_updated: Option<Favorite>,
-    /// Triggered when a favorite is deleted
-    pub favorite_deleted: Option<Favorite>,
-    /// Triggered when an issue is created
-    pub issue_created: Option<Issue>,
-    /// Triggered when an issue is updated
-    pub issue_updated: Option<Issue>,
-    /// Triggered when an issue is archived
-    pub issue_archived: Option<Issue>,
-    /// Triggered when a an issue is unarchived
-    pub issue_unarchived: Option<Issue>,
-    /// Triggered when an issue history is created
-    pub issue_history_created: Option<IssueHistory>,
-    /// Triggered when an issue history is updated
-    pub issue_history_updated: Option<IssueHistory>,
-    /// Triggered when an issue draft is created
-    pub issue_draft_created: Option<IssueDraft>,
-    /// Triggered when an issue draft is updated
-    pub issue_draft_updated: Option<IssueDraft>,
-    /// Triggered when an issue draft is deleted
-    pub issue_draft_deleted: Option<IssueDraft>,
-    /// Triggered when an issue label is created
-    pub issue_label_created: Option<IssueLabel>,
-    /// Triggered when an issue label is updated
-    pub issue_label_updated: Option<IssueLabel>,
-    /// Triggered when an issue label is deleted
-    pub issue_label_deleted: Option<IssueLabel>,
-    /// Triggered when an issue relation is created
-    pub issue_relation_created: Option<IssueRelation>,
-    /// Triggered when an issue relation is updated
-    pub issue_relation_updated: Option<IssueRelation>,
-    /// Triggered when an issue relation is deleted
-    pub issue_relation_deleted: Option<IssueRelation>,
-    /// Triggered when an organization is updated
-    pub organization_updated: Option<Organization>,
-    /// Triggered when a project is created
-    pub project_created: Option<Project>,
-    /// Triggered when a project is updated
-    pub project_updated: Option<Project>,
-    /// Triggered when a project is archived
-    pub project_archived: Option<Project>,
-    /// Triggered when a a project is unarchived
-    pub project_unarchived: Option<Project>,
-    /// Triggered when a project update is created
-    pub project_update_created: Option<ProjectUpdate>,
-    /// Triggered when a project update is updated
-    pub project_update_updated: Option<ProjectUpdate>,
-    /// Triggered when a project update is deleted
-    pub project_update_deleted: Option<ProjectUpdate>,
-    /// Triggered when a roadmap is created
-    pub roadmap_created: Option<Roadmap>,
-    /// Triggered when a roadmap is updated
-    pub roadmap_updated: Option<Roadmap>,
-    /// Triggered when a roadmap is deleted
-    pub roadmap_deleted: Option<Roadmap>,
-    /// Triggered when an initiative is created
-    pub initiative_created: Option<Initiative>,
-    /// Triggered when an initiative is updated
-    pub initiative_updated: Option<Initiative>,
-    /// Triggered when an initiative is deleted
-    pub initiative_deleted: Option<Initiative>,
-    /// Triggered when an agent session is created
-    pub agent_session_created: Option<AgentSession>,
-    /// Triggered when an agent session is updated
-    pub agent_session_updated: Option<AgentSession>,
-    /// Triggered when an agent activity is created
-    pub agent_activity_created: Option<AgentActivity>,
-    /// Triggered when an agent activity is updated
-    pub agent_activity_updated: Option<AgentActivity>,
-    /// Triggered when an ai conversation is updated
-    pub ai_conversation_updated: Option<AiConversation>,
-    /// Triggered when an ai prompt progress is created
-    pub ai_prompt_progress_created: Option<AiPromptProgress>,
-    /// Triggered when an ai prompt progress is updated
-    pub ai_prompt_progress_updated: Option<AiPromptProgress>,
-    /// Triggered when a team is created
-    pub team_created: Option<Team>,
-    /// Triggered when a team is updated
-    pub team_updated: Option<Team>,
-    /// Triggered when a team is deleted
-    pub team_deleted: Option<Team>,
-    /// Triggered when a team membership is created
-    pub team_membership_created: Option<TeamMembership>,
-    /// Triggered when a team membership is updated
-    pub team_membership_updated: Option<TeamMembership>,
-    /// Triggered when a team membership is deleted
-    pub team_membership_deleted: Option<TeamMembership>,
-    /// Triggered when an user is created
-    pub user_created: Option<User>,
-    /// Triggered when an user is updated
-    pub user_updated: Option<User>,
-    /// Triggered when a workflow state is created
-    pub workflow_state_created: Option<WorkflowState>,
-    /// Triggered when a workflow state is updated
-    pub workflow_state_updated: Option<WorkflowState>,
-    /// Triggered when a workflow state is archived
-    pub workflow_state_archived: Option<WorkflowState>,
-}
-impl GraphQLFields for Subscription {
-    type FullType = Self;
-    fn selection() -> String {
-        "".into()
-    }
-}
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase", default)]
 pub struct SuccessPayload {
     /// The identifier of the last sync operation.
     pub last_sync_id: Option<f64>,
@@ -10752,6 +10662,7 @@ pub struct Team {
     /// The issue estimation type to use. Must be one of "notUsed", "exponential", "fibonacci", "linear", "tShirt".
     pub issue_estimation_type: Option<String>,
     /// `DEPRECATED` Whether issues without priority should be sorted first.
+    #[deprecated(note = "This setting is no longer in use.")]
     pub issue_ordering_no_priority_first: Option<bool>,
     /// Whether to allow zeros in issues estimates.
     pub issue_estimation_allow_zero: Option<bool>,
@@ -10770,10 +10681,12 @@ pub struct Team {
     /// The default template to use for new issues created by members of the team.
     pub default_template_for_members: Option<Box<Template>>,
     /// The id of the default template to use for new issues created by members of the team.
+    #[deprecated(note = "Use defaultTemplateForMembers instead")]
     pub default_template_for_members_id: Option<String>,
     /// The default template to use for new issues created by non-members of the team.
     pub default_template_for_non_members: Option<Box<Template>>,
     /// The id of the default template to use for new issues created by non-members of the team.
+    #[deprecated(note = "Use defaultTemplateForNonMembers instead")]
     pub default_template_for_non_members_id: Option<String>,
     /// The default template to use for new projects created for the team.
     pub default_project_template: Option<Box<Template>>,
@@ -10798,14 +10711,19 @@ pub struct Team {
     /// `Internal` The current progress of the team.
     pub current_progress: Option<serde_json::Value>,
     /// The workflow state into which issues are moved when a PR has been opened as draft.
+    #[deprecated(note = "Use team.gitAutomationStates instead.")]
     pub draft_workflow_state: Option<Box<WorkflowState>>,
     /// The workflow state into which issues are moved when a PR has been opened.
+    #[deprecated(note = "Use team.gitAutomationStates instead.")]
     pub start_workflow_state: Option<Box<WorkflowState>>,
     /// The workflow state into which issues are moved when a review has been requested for the PR.
+    #[deprecated(note = "Use team.gitAutomationStates instead.")]
     pub review_workflow_state: Option<Box<WorkflowState>>,
     /// The workflow state into which issues are moved when a PR is ready to be merged.
+    #[deprecated(note = "Use team.gitAutomationStates instead.")]
     pub mergeable_workflow_state: Option<Box<WorkflowState>>,
     /// The workflow state into which issues are moved when a PR has been merged.
+    #[deprecated(note = "Use team.gitAutomationStates instead.")]
     pub merge_workflow_state: Option<Box<WorkflowState>>,
     /// Whether to group recent issue history entries.
     pub group_issue_history: Option<bool>,
@@ -10814,10 +10732,13 @@ pub struct Team {
     /// Whether to enable AI discussion summaries for issues in this team.
     pub ai_discussion_summaries_enabled: Option<bool>,
     /// Whether to send new issue notifications to Slack.
+    #[deprecated(note = "No longer is use")]
     pub slack_new_issue: Option<bool>,
     /// Whether to send new issue comment notifications to Slack.
+    #[deprecated(note = "No longer in use")]
     pub slack_issue_comments: Option<bool>,
     /// Whether to send new issue status updates to Slack.
+    #[deprecated(note = "No longer in use")]
     pub slack_issue_statuses: Option<bool>,
     /// Period after which issues are automatically closed in months. Null/undefined means disabled.
     pub auto_close_period: Option<f64>,
@@ -10876,8 +10797,10 @@ pub struct Team {
     /// Settings for all integrations associated with that team.
     pub integrations_settings: Option<Box<IntegrationsSettings>>,
     /// `DEPRECATED` Whether to move issues to bottom of the column when changing state.
+    #[deprecated(note = "Use setIssueSortOrderOnStateChange instead.")]
     pub issue_sort_order_default_to_bottom: Option<bool>,
     /// `DEPRECATED` Unique hash for the team to be used in invite URLs.
+    #[deprecated(note = "Not used anymore, simply returning an empty string.")]
     pub invite_hash: Option<String>,
 }
 impl GraphQLFields for Team {
@@ -11482,6 +11405,7 @@ pub struct User {
     /// Whether this agent user supports agent sessions.
     pub supports_agent_sessions: Option<bool>,
     /// `DEPRECATED` Unique hash for the user to be used in invite URLs.
+    #[deprecated(note = "This hash is not in use anymore, this value will always be empty.")]
     pub invite_hash: Option<String>,
     /// The user's GitHub user ID.
     pub git_hub_user_id: Option<String>,
@@ -11612,6 +11536,9 @@ pub struct UserSettings {
     /// The notification delivery preferences for the user. Note: notificationDisabled field is deprecated in favor of notificationChannelPreferences.
     pub notification_delivery_preferences: Option<NotificationDeliveryPreferences>,
     /// The email types the user has unsubscribed from.
+    #[deprecated(
+        note = "Use individual subscription fields instead. This field's value is now outdated."
+    )]
     pub unsubscribed_from: Option<Vec<String>>,
     /// The user that these settings belong to.
     pub user: Option<User>,
@@ -12036,6 +11963,7 @@ pub struct ViewPreferencesValues {
     /// Whether to show the project teams field on the timeline.
     pub project_field_teams_timeline: Option<bool>,
     /// The zoom level for the timeline view.
+    #[deprecated(note = "Use timelineZoomScale instead.")]
     pub project_zoom_level: Option<String>,
     /// The zoom scale for the timeline view.
     pub timeline_zoom_scale: Option<f64>,
@@ -12621,3 +12549,4226 @@ impl GraphQLFields for WorkflowStatePayload {
         "lastSyncId success".into()
     }
 }
+/// A base part in an AI conversation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "__typename")]
+#[non_exhaustive]
+pub enum AiConversationBasePart {
+    AiConversationPromptPart(Box<AiConversationPromptPart>),
+    AiConversationReasoningPart(Box<AiConversationReasoningPart>),
+    AiConversationTextPart(Box<AiConversationTextPart>),
+    AiConversationToolCallPart(Box<AiConversationToolCallPart>),
+    AiConversationWidgetPart(Box<AiConversationWidgetPart>),
+    /// Catch-all for object types unknown to this SDK version.
+    #[serde(other)]
+    Unknown,
+}
+impl GraphQLFields for AiConversationBasePart {
+    type FullType = Self;
+    fn selection() -> String {
+        crate::field_selection::inline_fragments(&[
+            (
+                "AiConversationPromptPart",
+                <AiConversationPromptPart as GraphQLFields>::selection(),
+            ),
+            (
+                "AiConversationReasoningPart",
+                <AiConversationReasoningPart as GraphQLFields>::selection(),
+            ),
+            (
+                "AiConversationTextPart",
+                <AiConversationTextPart as GraphQLFields>::selection(),
+            ),
+            (
+                "AiConversationToolCallPart",
+                <AiConversationToolCallPart as GraphQLFields>::selection(),
+            ),
+            (
+                "AiConversationWidgetPart",
+                <AiConversationWidgetPart as GraphQLFields>::selection(),
+            ),
+        ])
+    }
+}
+/// Fields shared by every [`AiConversationBasePart`] type. Each returns `None` when not selected.
+pub trait AiConversationBasePartFields {
+    /// The ID of the part.
+    fn id(&self) -> Option<&String>;
+    /// The type of the part.
+    fn r#type(&self) -> Option<&AiConversationPartType>;
+}
+impl AiConversationBasePartFields for AiConversationPromptPart {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+    fn r#type(&self) -> Option<&AiConversationPartType> {
+        self.r#type.as_ref()
+    }
+}
+impl AiConversationBasePartFields for AiConversationReasoningPart {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+    fn r#type(&self) -> Option<&AiConversationPartType> {
+        self.r#type.as_ref()
+    }
+}
+impl AiConversationBasePartFields for AiConversationTextPart {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+    fn r#type(&self) -> Option<&AiConversationPartType> {
+        self.r#type.as_ref()
+    }
+}
+impl AiConversationBasePartFields for AiConversationToolCallPart {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+    fn r#type(&self) -> Option<&AiConversationPartType> {
+        self.r#type.as_ref()
+    }
+}
+impl AiConversationBasePartFields for AiConversationWidgetPart {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+    fn r#type(&self) -> Option<&AiConversationPartType> {
+        self.r#type.as_ref()
+    }
+}
+impl AiConversationBasePartFields for AiConversationBasePart {
+    fn id(&self) -> Option<&String> {
+        match self {
+            Self::AiConversationPromptPart(v) => AiConversationBasePartFields::id(&**v),
+            Self::AiConversationReasoningPart(v) => AiConversationBasePartFields::id(&**v),
+            Self::AiConversationTextPart(v) => AiConversationBasePartFields::id(&**v),
+            Self::AiConversationToolCallPart(v) => AiConversationBasePartFields::id(&**v),
+            Self::AiConversationWidgetPart(v) => AiConversationBasePartFields::id(&**v),
+            Self::Unknown => None,
+        }
+    }
+    fn r#type(&self) -> Option<&AiConversationPartType> {
+        match self {
+            Self::AiConversationPromptPart(v) => AiConversationBasePartFields::r#type(&**v),
+            Self::AiConversationReasoningPart(v) => AiConversationBasePartFields::r#type(&**v),
+            Self::AiConversationTextPart(v) => AiConversationBasePartFields::r#type(&**v),
+            Self::AiConversationToolCallPart(v) => AiConversationBasePartFields::r#type(&**v),
+            Self::AiConversationWidgetPart(v) => AiConversationBasePartFields::r#type(&**v),
+            Self::Unknown => None,
+        }
+    }
+}
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "__typename")]
+#[non_exhaustive]
+pub enum AiConversationBaseToolCall {
+    AiConversationCodeIntelligenceToolCall(Box<AiConversationCodeIntelligenceToolCall>),
+    AiConversationCreateEntityToolCall(Box<AiConversationCreateEntityToolCall>),
+    AiConversationDeleteEntityToolCall(Box<AiConversationDeleteEntityToolCall>),
+    AiConversationGetMicrosoftTeamsConversationHistoryToolCall(
+        Box<AiConversationGetMicrosoftTeamsConversationHistoryToolCall>,
+    ),
+    AiConversationGetPullRequestDiffToolCall(Box<AiConversationGetPullRequestDiffToolCall>),
+    AiConversationGetPullRequestFileToolCall(Box<AiConversationGetPullRequestFileToolCall>),
+    AiConversationGetSlackConversationHistoryToolCall(
+        Box<AiConversationGetSlackConversationHistoryToolCall>,
+    ),
+    AiConversationHandoffToCodingSessionToolCall(Box<AiConversationHandoffToCodingSessionToolCall>),
+    AiConversationInvokeMcpToolToolCall(Box<AiConversationInvokeMcpToolToolCall>),
+    AiConversationNavigateToPageToolCall(Box<AiConversationNavigateToPageToolCall>),
+    AiConversationQueryActivityToolCall(Box<AiConversationQueryActivityToolCall>),
+    AiConversationQueryUpdatesToolCall(Box<AiConversationQueryUpdatesToolCall>),
+    AiConversationQueryViewToolCall(Box<AiConversationQueryViewToolCall>),
+    AiConversationResearchToolCall(Box<AiConversationResearchToolCall>),
+    AiConversationRestoreEntityToolCall(Box<AiConversationRestoreEntityToolCall>),
+    AiConversationRetrieveEntitiesToolCall(Box<AiConversationRetrieveEntitiesToolCall>),
+    AiConversationSearchDocumentationToolCall(Box<AiConversationSearchDocumentationToolCall>),
+    AiConversationSearchEntitiesToolCall(Box<AiConversationSearchEntitiesToolCall>),
+    AiConversationSuggestValuesToolCall(Box<AiConversationSuggestValuesToolCall>),
+    AiConversationTranscribeMediaToolCall(Box<AiConversationTranscribeMediaToolCall>),
+    AiConversationTranscribeVideoToolCall(Box<AiConversationTranscribeVideoToolCall>),
+    AiConversationUpdateEntityToolCall(Box<AiConversationUpdateEntityToolCall>),
+    AiConversationWebSearchToolCall(Box<AiConversationWebSearchToolCall>),
+    /// Catch-all for object types unknown to this SDK version.
+    #[serde(other)]
+    Unknown,
+}
+impl GraphQLFields for AiConversationBaseToolCall {
+    type FullType = Self;
+    fn selection() -> String {
+        crate::field_selection::inline_fragments(
+            &[
+                (
+                    "AiConversationCodeIntelligenceToolCall",
+                    <AiConversationCodeIntelligenceToolCall as GraphQLFields>::selection(),
+                ),
+                (
+                    "AiConversationCreateEntityToolCall",
+                    <AiConversationCreateEntityToolCall as GraphQLFields>::selection(),
+                ),
+                (
+                    "AiConversationDeleteEntityToolCall",
+                    <AiConversationDeleteEntityToolCall as GraphQLFields>::selection(),
+                ),
+                (
+                    "AiConversationGetMicrosoftTeamsConversationHistoryToolCall",
+                    <AiConversationGetMicrosoftTeamsConversationHistoryToolCall as GraphQLFields>::selection(),
+                ),
+                (
+                    "AiConversationGetPullRequestDiffToolCall",
+                    <AiConversationGetPullRequestDiffToolCall as GraphQLFields>::selection(),
+                ),
+                (
+                    "AiConversationGetPullRequestFileToolCall",
+                    <AiConversationGetPullRequestFileToolCall as GraphQLFields>::selection(),
+                ),
+                (
+                    "AiConversationGetSlackConversationHistoryToolCall",
+                    <AiConversationGetSlackConversationHistoryToolCall as GraphQLFields>::selection(),
+                ),
+                (
+                    "AiConversationHandoffToCodingSessionToolCall",
+                    <AiConversationHandoffToCodingSessionToolCall as GraphQLFields>::selection(),
+                ),
+                (
+                    "AiConversationInvokeMcpToolToolCall",
+                    <AiConversationInvokeMcpToolToolCall as GraphQLFields>::selection(),
+                ),
+                (
+                    "AiConversationNavigateToPageToolCall",
+                    <AiConversationNavigateToPageToolCall as GraphQLFields>::selection(),
+                ),
+                (
+                    "AiConversationQueryActivityToolCall",
+                    <AiConversationQueryActivityToolCall as GraphQLFields>::selection(),
+                ),
+                (
+                    "AiConversationQueryUpdatesToolCall",
+                    <AiConversationQueryUpdatesToolCall as GraphQLFields>::selection(),
+                ),
+                (
+                    "AiConversationQueryViewToolCall",
+                    <AiConversationQueryViewToolCall as GraphQLFields>::selection(),
+                ),
+                (
+                    "AiConversationResearchToolCall",
+                    <AiConversationResearchToolCall as GraphQLFields>::selection(),
+                ),
+                (
+                    "AiConversationRestoreEntityToolCall",
+                    <AiConversationRestoreEntityToolCall as GraphQLFields>::selection(),
+                ),
+                (
+                    "AiConversationRetrieveEntitiesToolCall",
+                    <AiConversationRetrieveEntitiesToolCall as GraphQLFields>::selection(),
+                ),
+                (
+                    "AiConversationSearchDocumentationToolCall",
+                    <AiConversationSearchDocumentationToolCall as GraphQLFields>::selection(),
+                ),
+                (
+                    "AiConversationSearchEntitiesToolCall",
+                    <AiConversationSearchEntitiesToolCall as GraphQLFields>::selection(),
+                ),
+                (
+                    "AiConversationSuggestValuesToolCall",
+                    <AiConversationSuggestValuesToolCall as GraphQLFields>::selection(),
+                ),
+                (
+                    "AiConversationTranscribeMediaToolCall",
+                    <AiConversationTranscribeMediaToolCall as GraphQLFields>::selection(),
+                ),
+                (
+                    "AiConversationTranscribeVideoToolCall",
+                    <AiConversationTranscribeVideoToolCall as GraphQLFields>::selection(),
+                ),
+                (
+                    "AiConversationUpdateEntityToolCall",
+                    <AiConversationUpdateEntityToolCall as GraphQLFields>::selection(),
+                ),
+                (
+                    "AiConversationWebSearchToolCall",
+                    <AiConversationWebSearchToolCall as GraphQLFields>::selection(),
+                ),
+            ],
+        )
+    }
+}
+/// Fields shared by every [`AiConversationBaseToolCall`] type. Each returns `None` when not selected.
+pub trait AiConversationBaseToolCallFields {
+    /// The name of the tool that was called.
+    fn name(&self) -> Option<&AiConversationTool>;
+    /// The arguments of the tool call.
+    fn raw_args(&self) -> Option<&serde_json::Value>;
+    /// The result of the tool call.
+    fn raw_result(&self) -> Option<&serde_json::Value>;
+}
+impl AiConversationBaseToolCallFields for AiConversationCodeIntelligenceToolCall {
+    fn name(&self) -> Option<&AiConversationTool> {
+        self.name.as_ref()
+    }
+    fn raw_args(&self) -> Option<&serde_json::Value> {
+        self.raw_args.as_ref()
+    }
+    fn raw_result(&self) -> Option<&serde_json::Value> {
+        self.raw_result.as_ref()
+    }
+}
+impl AiConversationBaseToolCallFields for AiConversationCreateEntityToolCall {
+    fn name(&self) -> Option<&AiConversationTool> {
+        self.name.as_ref()
+    }
+    fn raw_args(&self) -> Option<&serde_json::Value> {
+        self.raw_args.as_ref()
+    }
+    fn raw_result(&self) -> Option<&serde_json::Value> {
+        self.raw_result.as_ref()
+    }
+}
+impl AiConversationBaseToolCallFields for AiConversationDeleteEntityToolCall {
+    fn name(&self) -> Option<&AiConversationTool> {
+        self.name.as_ref()
+    }
+    fn raw_args(&self) -> Option<&serde_json::Value> {
+        self.raw_args.as_ref()
+    }
+    fn raw_result(&self) -> Option<&serde_json::Value> {
+        self.raw_result.as_ref()
+    }
+}
+impl AiConversationBaseToolCallFields
+    for AiConversationGetMicrosoftTeamsConversationHistoryToolCall
+{
+    fn name(&self) -> Option<&AiConversationTool> {
+        self.name.as_ref()
+    }
+    fn raw_args(&self) -> Option<&serde_json::Value> {
+        self.raw_args.as_ref()
+    }
+    fn raw_result(&self) -> Option<&serde_json::Value> {
+        self.raw_result.as_ref()
+    }
+}
+impl AiConversationBaseToolCallFields for AiConversationGetPullRequestDiffToolCall {
+    fn name(&self) -> Option<&AiConversationTool> {
+        self.name.as_ref()
+    }
+    fn raw_args(&self) -> Option<&serde_json::Value> {
+        self.raw_args.as_ref()
+    }
+    fn raw_result(&self) -> Option<&serde_json::Value> {
+        self.raw_result.as_ref()
+    }
+}
+impl AiConversationBaseToolCallFields for AiConversationGetPullRequestFileToolCall {
+    fn name(&self) -> Option<&AiConversationTool> {
+        self.name.as_ref()
+    }
+    fn raw_args(&self) -> Option<&serde_json::Value> {
+        self.raw_args.as_ref()
+    }
+    fn raw_result(&self) -> Option<&serde_json::Value> {
+        self.raw_result.as_ref()
+    }
+}
+impl AiConversationBaseToolCallFields for AiConversationGetSlackConversationHistoryToolCall {
+    fn name(&self) -> Option<&AiConversationTool> {
+        self.name.as_ref()
+    }
+    fn raw_args(&self) -> Option<&serde_json::Value> {
+        self.raw_args.as_ref()
+    }
+    fn raw_result(&self) -> Option<&serde_json::Value> {
+        self.raw_result.as_ref()
+    }
+}
+impl AiConversationBaseToolCallFields for AiConversationHandoffToCodingSessionToolCall {
+    fn name(&self) -> Option<&AiConversationTool> {
+        self.name.as_ref()
+    }
+    fn raw_args(&self) -> Option<&serde_json::Value> {
+        self.raw_args.as_ref()
+    }
+    fn raw_result(&self) -> Option<&serde_json::Value> {
+        self.raw_result.as_ref()
+    }
+}
+impl AiConversationBaseToolCallFields for AiConversationInvokeMcpToolToolCall {
+    fn name(&self) -> Option<&AiConversationTool> {
+        self.name.as_ref()
+    }
+    fn raw_args(&self) -> Option<&serde_json::Value> {
+        self.raw_args.as_ref()
+    }
+    fn raw_result(&self) -> Option<&serde_json::Value> {
+        self.raw_result.as_ref()
+    }
+}
+impl AiConversationBaseToolCallFields for AiConversationNavigateToPageToolCall {
+    fn name(&self) -> Option<&AiConversationTool> {
+        self.name.as_ref()
+    }
+    fn raw_args(&self) -> Option<&serde_json::Value> {
+        self.raw_args.as_ref()
+    }
+    fn raw_result(&self) -> Option<&serde_json::Value> {
+        self.raw_result.as_ref()
+    }
+}
+impl AiConversationBaseToolCallFields for AiConversationQueryActivityToolCall {
+    fn name(&self) -> Option<&AiConversationTool> {
+        self.name.as_ref()
+    }
+    fn raw_args(&self) -> Option<&serde_json::Value> {
+        self.raw_args.as_ref()
+    }
+    fn raw_result(&self) -> Option<&serde_json::Value> {
+        self.raw_result.as_ref()
+    }
+}
+impl AiConversationBaseToolCallFields for AiConversationQueryUpdatesToolCall {
+    fn name(&self) -> Option<&AiConversationTool> {
+        self.name.as_ref()
+    }
+    fn raw_args(&self) -> Option<&serde_json::Value> {
+        self.raw_args.as_ref()
+    }
+    fn raw_result(&self) -> Option<&serde_json::Value> {
+        self.raw_result.as_ref()
+    }
+}
+impl AiConversationBaseToolCallFields for AiConversationQueryViewToolCall {
+    fn name(&self) -> Option<&AiConversationTool> {
+        self.name.as_ref()
+    }
+    fn raw_args(&self) -> Option<&serde_json::Value> {
+        self.raw_args.as_ref()
+    }
+    fn raw_result(&self) -> Option<&serde_json::Value> {
+        self.raw_result.as_ref()
+    }
+}
+impl AiConversationBaseToolCallFields for AiConversationResearchToolCall {
+    fn name(&self) -> Option<&AiConversationTool> {
+        self.name.as_ref()
+    }
+    fn raw_args(&self) -> Option<&serde_json::Value> {
+        self.raw_args.as_ref()
+    }
+    fn raw_result(&self) -> Option<&serde_json::Value> {
+        self.raw_result.as_ref()
+    }
+}
+impl AiConversationBaseToolCallFields for AiConversationRestoreEntityToolCall {
+    fn name(&self) -> Option<&AiConversationTool> {
+        self.name.as_ref()
+    }
+    fn raw_args(&self) -> Option<&serde_json::Value> {
+        self.raw_args.as_ref()
+    }
+    fn raw_result(&self) -> Option<&serde_json::Value> {
+        self.raw_result.as_ref()
+    }
+}
+impl AiConversationBaseToolCallFields for AiConversationRetrieveEntitiesToolCall {
+    fn name(&self) -> Option<&AiConversationTool> {
+        self.name.as_ref()
+    }
+    fn raw_args(&self) -> Option<&serde_json::Value> {
+        self.raw_args.as_ref()
+    }
+    fn raw_result(&self) -> Option<&serde_json::Value> {
+        self.raw_result.as_ref()
+    }
+}
+impl AiConversationBaseToolCallFields for AiConversationSearchDocumentationToolCall {
+    fn name(&self) -> Option<&AiConversationTool> {
+        self.name.as_ref()
+    }
+    fn raw_args(&self) -> Option<&serde_json::Value> {
+        self.raw_args.as_ref()
+    }
+    fn raw_result(&self) -> Option<&serde_json::Value> {
+        self.raw_result.as_ref()
+    }
+}
+impl AiConversationBaseToolCallFields for AiConversationSearchEntitiesToolCall {
+    fn name(&self) -> Option<&AiConversationTool> {
+        self.name.as_ref()
+    }
+    fn raw_args(&self) -> Option<&serde_json::Value> {
+        self.raw_args.as_ref()
+    }
+    fn raw_result(&self) -> Option<&serde_json::Value> {
+        self.raw_result.as_ref()
+    }
+}
+impl AiConversationBaseToolCallFields for AiConversationSuggestValuesToolCall {
+    fn name(&self) -> Option<&AiConversationTool> {
+        self.name.as_ref()
+    }
+    fn raw_args(&self) -> Option<&serde_json::Value> {
+        self.raw_args.as_ref()
+    }
+    fn raw_result(&self) -> Option<&serde_json::Value> {
+        self.raw_result.as_ref()
+    }
+}
+impl AiConversationBaseToolCallFields for AiConversationTranscribeMediaToolCall {
+    fn name(&self) -> Option<&AiConversationTool> {
+        self.name.as_ref()
+    }
+    fn raw_args(&self) -> Option<&serde_json::Value> {
+        self.raw_args.as_ref()
+    }
+    fn raw_result(&self) -> Option<&serde_json::Value> {
+        self.raw_result.as_ref()
+    }
+}
+impl AiConversationBaseToolCallFields for AiConversationTranscribeVideoToolCall {
+    fn name(&self) -> Option<&AiConversationTool> {
+        self.name.as_ref()
+    }
+    fn raw_args(&self) -> Option<&serde_json::Value> {
+        self.raw_args.as_ref()
+    }
+    fn raw_result(&self) -> Option<&serde_json::Value> {
+        self.raw_result.as_ref()
+    }
+}
+impl AiConversationBaseToolCallFields for AiConversationUpdateEntityToolCall {
+    fn name(&self) -> Option<&AiConversationTool> {
+        self.name.as_ref()
+    }
+    fn raw_args(&self) -> Option<&serde_json::Value> {
+        self.raw_args.as_ref()
+    }
+    fn raw_result(&self) -> Option<&serde_json::Value> {
+        self.raw_result.as_ref()
+    }
+}
+impl AiConversationBaseToolCallFields for AiConversationWebSearchToolCall {
+    fn name(&self) -> Option<&AiConversationTool> {
+        self.name.as_ref()
+    }
+    fn raw_args(&self) -> Option<&serde_json::Value> {
+        self.raw_args.as_ref()
+    }
+    fn raw_result(&self) -> Option<&serde_json::Value> {
+        self.raw_result.as_ref()
+    }
+}
+impl AiConversationBaseToolCallFields for AiConversationBaseToolCall {
+    fn name(&self) -> Option<&AiConversationTool> {
+        match self {
+            Self::AiConversationCodeIntelligenceToolCall(v) => {
+                AiConversationBaseToolCallFields::name(&**v)
+            }
+            Self::AiConversationCreateEntityToolCall(v) => {
+                AiConversationBaseToolCallFields::name(&**v)
+            }
+            Self::AiConversationDeleteEntityToolCall(v) => {
+                AiConversationBaseToolCallFields::name(&**v)
+            }
+            Self::AiConversationGetMicrosoftTeamsConversationHistoryToolCall(v) => {
+                AiConversationBaseToolCallFields::name(&**v)
+            }
+            Self::AiConversationGetPullRequestDiffToolCall(v) => {
+                AiConversationBaseToolCallFields::name(&**v)
+            }
+            Self::AiConversationGetPullRequestFileToolCall(v) => {
+                AiConversationBaseToolCallFields::name(&**v)
+            }
+            Self::AiConversationGetSlackConversationHistoryToolCall(v) => {
+                AiConversationBaseToolCallFields::name(&**v)
+            }
+            Self::AiConversationHandoffToCodingSessionToolCall(v) => {
+                AiConversationBaseToolCallFields::name(&**v)
+            }
+            Self::AiConversationInvokeMcpToolToolCall(v) => {
+                AiConversationBaseToolCallFields::name(&**v)
+            }
+            Self::AiConversationNavigateToPageToolCall(v) => {
+                AiConversationBaseToolCallFields::name(&**v)
+            }
+            Self::AiConversationQueryActivityToolCall(v) => {
+                AiConversationBaseToolCallFields::name(&**v)
+            }
+            Self::AiConversationQueryUpdatesToolCall(v) => {
+                AiConversationBaseToolCallFields::name(&**v)
+            }
+            Self::AiConversationQueryViewToolCall(v) => {
+                AiConversationBaseToolCallFields::name(&**v)
+            }
+            Self::AiConversationResearchToolCall(v) => AiConversationBaseToolCallFields::name(&**v),
+            Self::AiConversationRestoreEntityToolCall(v) => {
+                AiConversationBaseToolCallFields::name(&**v)
+            }
+            Self::AiConversationRetrieveEntitiesToolCall(v) => {
+                AiConversationBaseToolCallFields::name(&**v)
+            }
+            Self::AiConversationSearchDocumentationToolCall(v) => {
+                AiConversationBaseToolCallFields::name(&**v)
+            }
+            Self::AiConversationSearchEntitiesToolCall(v) => {
+                AiConversationBaseToolCallFields::name(&**v)
+            }
+            Self::AiConversationSuggestValuesToolCall(v) => {
+                AiConversationBaseToolCallFields::name(&**v)
+            }
+            Self::AiConversationTranscribeMediaToolCall(v) => {
+                AiConversationBaseToolCallFields::name(&**v)
+            }
+            Self::AiConversationTranscribeVideoToolCall(v) => {
+                AiConversationBaseToolCallFields::name(&**v)
+            }
+            Self::AiConversationUpdateEntityToolCall(v) => {
+                AiConversationBaseToolCallFields::name(&**v)
+            }
+            Self::AiConversationWebSearchToolCall(v) => {
+                AiConversationBaseToolCallFields::name(&**v)
+            }
+            Self::Unknown => None,
+        }
+    }
+    fn raw_args(&self) -> Option<&serde_json::Value> {
+        match self {
+            Self::AiConversationCodeIntelligenceToolCall(v) => {
+                AiConversationBaseToolCallFields::raw_args(&**v)
+            }
+            Self::AiConversationCreateEntityToolCall(v) => {
+                AiConversationBaseToolCallFields::raw_args(&**v)
+            }
+            Self::AiConversationDeleteEntityToolCall(v) => {
+                AiConversationBaseToolCallFields::raw_args(&**v)
+            }
+            Self::AiConversationGetMicrosoftTeamsConversationHistoryToolCall(v) => {
+                AiConversationBaseToolCallFields::raw_args(&**v)
+            }
+            Self::AiConversationGetPullRequestDiffToolCall(v) => {
+                AiConversationBaseToolCallFields::raw_args(&**v)
+            }
+            Self::AiConversationGetPullRequestFileToolCall(v) => {
+                AiConversationBaseToolCallFields::raw_args(&**v)
+            }
+            Self::AiConversationGetSlackConversationHistoryToolCall(v) => {
+                AiConversationBaseToolCallFields::raw_args(&**v)
+            }
+            Self::AiConversationHandoffToCodingSessionToolCall(v) => {
+                AiConversationBaseToolCallFields::raw_args(&**v)
+            }
+            Self::AiConversationInvokeMcpToolToolCall(v) => {
+                AiConversationBaseToolCallFields::raw_args(&**v)
+            }
+            Self::AiConversationNavigateToPageToolCall(v) => {
+                AiConversationBaseToolCallFields::raw_args(&**v)
+            }
+            Self::AiConversationQueryActivityToolCall(v) => {
+                AiConversationBaseToolCallFields::raw_args(&**v)
+            }
+            Self::AiConversationQueryUpdatesToolCall(v) => {
+                AiConversationBaseToolCallFields::raw_args(&**v)
+            }
+            Self::AiConversationQueryViewToolCall(v) => {
+                AiConversationBaseToolCallFields::raw_args(&**v)
+            }
+            Self::AiConversationResearchToolCall(v) => {
+                AiConversationBaseToolCallFields::raw_args(&**v)
+            }
+            Self::AiConversationRestoreEntityToolCall(v) => {
+                AiConversationBaseToolCallFields::raw_args(&**v)
+            }
+            Self::AiConversationRetrieveEntitiesToolCall(v) => {
+                AiConversationBaseToolCallFields::raw_args(&**v)
+            }
+            Self::AiConversationSearchDocumentationToolCall(v) => {
+                AiConversationBaseToolCallFields::raw_args(&**v)
+            }
+            Self::AiConversationSearchEntitiesToolCall(v) => {
+                AiConversationBaseToolCallFields::raw_args(&**v)
+            }
+            Self::AiConversationSuggestValuesToolCall(v) => {
+                AiConversationBaseToolCallFields::raw_args(&**v)
+            }
+            Self::AiConversationTranscribeMediaToolCall(v) => {
+                AiConversationBaseToolCallFields::raw_args(&**v)
+            }
+            Self::AiConversationTranscribeVideoToolCall(v) => {
+                AiConversationBaseToolCallFields::raw_args(&**v)
+            }
+            Self::AiConversationUpdateEntityToolCall(v) => {
+                AiConversationBaseToolCallFields::raw_args(&**v)
+            }
+            Self::AiConversationWebSearchToolCall(v) => {
+                AiConversationBaseToolCallFields::raw_args(&**v)
+            }
+            Self::Unknown => None,
+        }
+    }
+    fn raw_result(&self) -> Option<&serde_json::Value> {
+        match self {
+            Self::AiConversationCodeIntelligenceToolCall(v) => {
+                AiConversationBaseToolCallFields::raw_result(&**v)
+            }
+            Self::AiConversationCreateEntityToolCall(v) => {
+                AiConversationBaseToolCallFields::raw_result(&**v)
+            }
+            Self::AiConversationDeleteEntityToolCall(v) => {
+                AiConversationBaseToolCallFields::raw_result(&**v)
+            }
+            Self::AiConversationGetMicrosoftTeamsConversationHistoryToolCall(v) => {
+                AiConversationBaseToolCallFields::raw_result(&**v)
+            }
+            Self::AiConversationGetPullRequestDiffToolCall(v) => {
+                AiConversationBaseToolCallFields::raw_result(&**v)
+            }
+            Self::AiConversationGetPullRequestFileToolCall(v) => {
+                AiConversationBaseToolCallFields::raw_result(&**v)
+            }
+            Self::AiConversationGetSlackConversationHistoryToolCall(v) => {
+                AiConversationBaseToolCallFields::raw_result(&**v)
+            }
+            Self::AiConversationHandoffToCodingSessionToolCall(v) => {
+                AiConversationBaseToolCallFields::raw_result(&**v)
+            }
+            Self::AiConversationInvokeMcpToolToolCall(v) => {
+                AiConversationBaseToolCallFields::raw_result(&**v)
+            }
+            Self::AiConversationNavigateToPageToolCall(v) => {
+                AiConversationBaseToolCallFields::raw_result(&**v)
+            }
+            Self::AiConversationQueryActivityToolCall(v) => {
+                AiConversationBaseToolCallFields::raw_result(&**v)
+            }
+            Self::AiConversationQueryUpdatesToolCall(v) => {
+                AiConversationBaseToolCallFields::raw_result(&**v)
+            }
+            Self::AiConversationQueryViewToolCall(v) => {
+                AiConversationBaseToolCallFields::raw_result(&**v)
+            }
+            Self::AiConversationResearchToolCall(v) => {
+                AiConversationBaseToolCallFields::raw_result(&**v)
+            }
+            Self::AiConversationRestoreEntityToolCall(v) => {
+                AiConversationBaseToolCallFields::raw_result(&**v)
+            }
+            Self::AiConversationRetrieveEntitiesToolCall(v) => {
+                AiConversationBaseToolCallFields::raw_result(&**v)
+            }
+            Self::AiConversationSearchDocumentationToolCall(v) => {
+                AiConversationBaseToolCallFields::raw_result(&**v)
+            }
+            Self::AiConversationSearchEntitiesToolCall(v) => {
+                AiConversationBaseToolCallFields::raw_result(&**v)
+            }
+            Self::AiConversationSuggestValuesToolCall(v) => {
+                AiConversationBaseToolCallFields::raw_result(&**v)
+            }
+            Self::AiConversationTranscribeMediaToolCall(v) => {
+                AiConversationBaseToolCallFields::raw_result(&**v)
+            }
+            Self::AiConversationTranscribeVideoToolCall(v) => {
+                AiConversationBaseToolCallFields::raw_result(&**v)
+            }
+            Self::AiConversationUpdateEntityToolCall(v) => {
+                AiConversationBaseToolCallFields::raw_result(&**v)
+            }
+            Self::AiConversationWebSearchToolCall(v) => {
+                AiConversationBaseToolCallFields::raw_result(&**v)
+            }
+            Self::Unknown => None,
+        }
+    }
+}
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "__typename")]
+#[non_exhaustive]
+pub enum AiConversationBaseWidget {
+    AiConversationEntityCardWidget(Box<AiConversationEntityCardWidget>),
+    AiConversationEntityListWidget(Box<AiConversationEntityListWidget>),
+    /// Catch-all for object types unknown to this SDK version.
+    #[serde(other)]
+    Unknown,
+}
+impl GraphQLFields for AiConversationBaseWidget {
+    type FullType = Self;
+    fn selection() -> String {
+        crate::field_selection::inline_fragments(&[
+            (
+                "AiConversationEntityCardWidget",
+                <AiConversationEntityCardWidget as GraphQLFields>::selection(),
+            ),
+            (
+                "AiConversationEntityListWidget",
+                <AiConversationEntityListWidget as GraphQLFields>::selection(),
+            ),
+        ])
+    }
+}
+/// Fields shared by every [`AiConversationBaseWidget`] type. Each returns `None` when not selected.
+pub trait AiConversationBaseWidgetFields {
+    /// The name of the widget.
+    fn name(&self) -> Option<&AiConversationWidgetName>;
+    /// The arguments of the widget.
+    fn raw_args(&self) -> Option<&serde_json::Value>;
+}
+impl AiConversationBaseWidgetFields for AiConversationEntityCardWidget {
+    fn name(&self) -> Option<&AiConversationWidgetName> {
+        self.name.as_ref()
+    }
+    fn raw_args(&self) -> Option<&serde_json::Value> {
+        self.raw_args.as_ref()
+    }
+}
+impl AiConversationBaseWidgetFields for AiConversationEntityListWidget {
+    fn name(&self) -> Option<&AiConversationWidgetName> {
+        self.name.as_ref()
+    }
+    fn raw_args(&self) -> Option<&serde_json::Value> {
+        self.raw_args.as_ref()
+    }
+}
+impl AiConversationBaseWidgetFields for AiConversationBaseWidget {
+    fn name(&self) -> Option<&AiConversationWidgetName> {
+        match self {
+            Self::AiConversationEntityCardWidget(v) => AiConversationBaseWidgetFields::name(&**v),
+            Self::AiConversationEntityListWidget(v) => AiConversationBaseWidgetFields::name(&**v),
+            Self::Unknown => None,
+        }
+    }
+    fn raw_args(&self) -> Option<&serde_json::Value> {
+        match self {
+            Self::AiConversationEntityCardWidget(v) => {
+                AiConversationBaseWidgetFields::raw_args(&**v)
+            }
+            Self::AiConversationEntityListWidget(v) => {
+                AiConversationBaseWidgetFields::raw_args(&**v)
+            }
+            Self::Unknown => None,
+        }
+    }
+}
+/// A generic payload return from entity archive or deletion mutations.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "__typename")]
+#[non_exhaustive]
+pub enum ArchivePayload {
+    CustomerNeedArchivePayload(Box<CustomerNeedArchivePayload>),
+    CycleArchivePayload(Box<CycleArchivePayload>),
+    DeletePayload(Box<DeletePayload>),
+    DocumentArchivePayload(Box<DocumentArchivePayload>),
+    InitiativeArchivePayload(Box<InitiativeArchivePayload>),
+    InitiativeUpdateArchivePayload(Box<InitiativeUpdateArchivePayload>),
+    IssueArchivePayload(Box<IssueArchivePayload>),
+    NotificationArchivePayload(Box<NotificationArchivePayload>),
+    ProjectArchivePayload(Box<ProjectArchivePayload>),
+    ProjectStatusArchivePayload(Box<ProjectStatusArchivePayload>),
+    ProjectUpdateArchivePayload(Box<ProjectUpdateArchivePayload>),
+    ReleaseArchivePayload(Box<ReleaseArchivePayload>),
+    ReleasePipelineArchivePayload(Box<ReleasePipelineArchivePayload>),
+    ReleaseStageArchivePayload(Box<ReleaseStageArchivePayload>),
+    RoadmapArchivePayload(Box<RoadmapArchivePayload>),
+    TeamArchivePayload(Box<TeamArchivePayload>),
+    WorkflowStateArchivePayload(Box<WorkflowStateArchivePayload>),
+    /// Catch-all for object types unknown to this SDK version.
+    #[serde(other)]
+    Unknown,
+}
+impl GraphQLFields for ArchivePayload {
+    type FullType = Self;
+    fn selection() -> String {
+        crate::field_selection::inline_fragments(&[
+            (
+                "CustomerNeedArchivePayload",
+                <CustomerNeedArchivePayload as GraphQLFields>::selection(),
+            ),
+            (
+                "CycleArchivePayload",
+                <CycleArchivePayload as GraphQLFields>::selection(),
+            ),
+            (
+                "DeletePayload",
+                <DeletePayload as GraphQLFields>::selection(),
+            ),
+            (
+                "DocumentArchivePayload",
+                <DocumentArchivePayload as GraphQLFields>::selection(),
+            ),
+            (
+                "InitiativeArchivePayload",
+                <InitiativeArchivePayload as GraphQLFields>::selection(),
+            ),
+            (
+                "InitiativeUpdateArchivePayload",
+                <InitiativeUpdateArchivePayload as GraphQLFields>::selection(),
+            ),
+            (
+                "IssueArchivePayload",
+                <IssueArchivePayload as GraphQLFields>::selection(),
+            ),
+            (
+                "NotificationArchivePayload",
+                <NotificationArchivePayload as GraphQLFields>::selection(),
+            ),
+            (
+                "ProjectArchivePayload",
+                <ProjectArchivePayload as GraphQLFields>::selection(),
+            ),
+            (
+                "ProjectStatusArchivePayload",
+                <ProjectStatusArchivePayload as GraphQLFields>::selection(),
+            ),
+            (
+                "ProjectUpdateArchivePayload",
+                <ProjectUpdateArchivePayload as GraphQLFields>::selection(),
+            ),
+            (
+                "ReleaseArchivePayload",
+                <ReleaseArchivePayload as GraphQLFields>::selection(),
+            ),
+            (
+                "ReleasePipelineArchivePayload",
+                <ReleasePipelineArchivePayload as GraphQLFields>::selection(),
+            ),
+            (
+                "ReleaseStageArchivePayload",
+                <ReleaseStageArchivePayload as GraphQLFields>::selection(),
+            ),
+            (
+                "RoadmapArchivePayload",
+                <RoadmapArchivePayload as GraphQLFields>::selection(),
+            ),
+            (
+                "TeamArchivePayload",
+                <TeamArchivePayload as GraphQLFields>::selection(),
+            ),
+            (
+                "WorkflowStateArchivePayload",
+                <WorkflowStateArchivePayload as GraphQLFields>::selection(),
+            ),
+        ])
+    }
+}
+/// Fields shared by every [`ArchivePayload`] type. Each returns `None` when not selected.
+pub trait ArchivePayloadFields {
+    /// The identifier of the last sync operation.
+    fn last_sync_id(&self) -> Option<&f64>;
+    /// Whether the operation was successful.
+    fn success(&self) -> Option<&bool>;
+}
+impl ArchivePayloadFields for CustomerNeedArchivePayload {
+    fn last_sync_id(&self) -> Option<&f64> {
+        self.last_sync_id.as_ref()
+    }
+    fn success(&self) -> Option<&bool> {
+        self.success.as_ref()
+    }
+}
+impl ArchivePayloadFields for CycleArchivePayload {
+    fn last_sync_id(&self) -> Option<&f64> {
+        self.last_sync_id.as_ref()
+    }
+    fn success(&self) -> Option<&bool> {
+        self.success.as_ref()
+    }
+}
+impl ArchivePayloadFields for DeletePayload {
+    fn last_sync_id(&self) -> Option<&f64> {
+        self.last_sync_id.as_ref()
+    }
+    fn success(&self) -> Option<&bool> {
+        self.success.as_ref()
+    }
+}
+impl ArchivePayloadFields for DocumentArchivePayload {
+    fn last_sync_id(&self) -> Option<&f64> {
+        self.last_sync_id.as_ref()
+    }
+    fn success(&self) -> Option<&bool> {
+        self.success.as_ref()
+    }
+}
+impl ArchivePayloadFields for InitiativeArchivePayload {
+    fn last_sync_id(&self) -> Option<&f64> {
+        self.last_sync_id.as_ref()
+    }
+    fn success(&self) -> Option<&bool> {
+        self.success.as_ref()
+    }
+}
+impl ArchivePayloadFields for InitiativeUpdateArchivePayload {
+    fn last_sync_id(&self) -> Option<&f64> {
+        self.last_sync_id.as_ref()
+    }
+    fn success(&self) -> Option<&bool> {
+        self.success.as_ref()
+    }
+}
+impl ArchivePayloadFields for IssueArchivePayload {
+    fn last_sync_id(&self) -> Option<&f64> {
+        self.last_sync_id.as_ref()
+    }
+    fn success(&self) -> Option<&bool> {
+        self.success.as_ref()
+    }
+}
+impl ArchivePayloadFields for NotificationArchivePayload {
+    fn last_sync_id(&self) -> Option<&f64> {
+        self.last_sync_id.as_ref()
+    }
+    fn success(&self) -> Option<&bool> {
+        self.success.as_ref()
+    }
+}
+impl ArchivePayloadFields for ProjectArchivePayload {
+    fn last_sync_id(&self) -> Option<&f64> {
+        self.last_sync_id.as_ref()
+    }
+    fn success(&self) -> Option<&bool> {
+        self.success.as_ref()
+    }
+}
+impl ArchivePayloadFields for ProjectStatusArchivePayload {
+    fn last_sync_id(&self) -> Option<&f64> {
+        self.last_sync_id.as_ref()
+    }
+    fn success(&self) -> Option<&bool> {
+        self.success.as_ref()
+    }
+}
+impl ArchivePayloadFields for ProjectUpdateArchivePayload {
+    fn last_sync_id(&self) -> Option<&f64> {
+        self.last_sync_id.as_ref()
+    }
+    fn success(&self) -> Option<&bool> {
+        self.success.as_ref()
+    }
+}
+impl ArchivePayloadFields for ReleaseArchivePayload {
+    fn last_sync_id(&self) -> Option<&f64> {
+        self.last_sync_id.as_ref()
+    }
+    fn success(&self) -> Option<&bool> {
+        self.success.as_ref()
+    }
+}
+impl ArchivePayloadFields for ReleasePipelineArchivePayload {
+    fn last_sync_id(&self) -> Option<&f64> {
+        self.last_sync_id.as_ref()
+    }
+    fn success(&self) -> Option<&bool> {
+        self.success.as_ref()
+    }
+}
+impl ArchivePayloadFields for ReleaseStageArchivePayload {
+    fn last_sync_id(&self) -> Option<&f64> {
+        self.last_sync_id.as_ref()
+    }
+    fn success(&self) -> Option<&bool> {
+        self.success.as_ref()
+    }
+}
+impl ArchivePayloadFields for RoadmapArchivePayload {
+    fn last_sync_id(&self) -> Option<&f64> {
+        self.last_sync_id.as_ref()
+    }
+    fn success(&self) -> Option<&bool> {
+        self.success.as_ref()
+    }
+}
+impl ArchivePayloadFields for TeamArchivePayload {
+    fn last_sync_id(&self) -> Option<&f64> {
+        self.last_sync_id.as_ref()
+    }
+    fn success(&self) -> Option<&bool> {
+        self.success.as_ref()
+    }
+}
+impl ArchivePayloadFields for WorkflowStateArchivePayload {
+    fn last_sync_id(&self) -> Option<&f64> {
+        self.last_sync_id.as_ref()
+    }
+    fn success(&self) -> Option<&bool> {
+        self.success.as_ref()
+    }
+}
+impl ArchivePayloadFields for ArchivePayload {
+    fn last_sync_id(&self) -> Option<&f64> {
+        match self {
+            Self::CustomerNeedArchivePayload(v) => ArchivePayloadFields::last_sync_id(&**v),
+            Self::CycleArchivePayload(v) => ArchivePayloadFields::last_sync_id(&**v),
+            Self::DeletePayload(v) => ArchivePayloadFields::last_sync_id(&**v),
+            Self::DocumentArchivePayload(v) => ArchivePayloadFields::last_sync_id(&**v),
+            Self::InitiativeArchivePayload(v) => ArchivePayloadFields::last_sync_id(&**v),
+            Self::InitiativeUpdateArchivePayload(v) => ArchivePayloadFields::last_sync_id(&**v),
+            Self::IssueArchivePayload(v) => ArchivePayloadFields::last_sync_id(&**v),
+            Self::NotificationArchivePayload(v) => ArchivePayloadFields::last_sync_id(&**v),
+            Self::ProjectArchivePayload(v) => ArchivePayloadFields::last_sync_id(&**v),
+            Self::ProjectStatusArchivePayload(v) => ArchivePayloadFields::last_sync_id(&**v),
+            Self::ProjectUpdateArchivePayload(v) => ArchivePayloadFields::last_sync_id(&**v),
+            Self::ReleaseArchivePayload(v) => ArchivePayloadFields::last_sync_id(&**v),
+            Self::ReleasePipelineArchivePayload(v) => ArchivePayloadFields::last_sync_id(&**v),
+            Self::ReleaseStageArchivePayload(v) => ArchivePayloadFields::last_sync_id(&**v),
+            Self::RoadmapArchivePayload(v) => ArchivePayloadFields::last_sync_id(&**v),
+            Self::TeamArchivePayload(v) => ArchivePayloadFields::last_sync_id(&**v),
+            Self::WorkflowStateArchivePayload(v) => ArchivePayloadFields::last_sync_id(&**v),
+            Self::Unknown => None,
+        }
+    }
+    fn success(&self) -> Option<&bool> {
+        match self {
+            Self::CustomerNeedArchivePayload(v) => ArchivePayloadFields::success(&**v),
+            Self::CycleArchivePayload(v) => ArchivePayloadFields::success(&**v),
+            Self::DeletePayload(v) => ArchivePayloadFields::success(&**v),
+            Self::DocumentArchivePayload(v) => ArchivePayloadFields::success(&**v),
+            Self::InitiativeArchivePayload(v) => ArchivePayloadFields::success(&**v),
+            Self::InitiativeUpdateArchivePayload(v) => ArchivePayloadFields::success(&**v),
+            Self::IssueArchivePayload(v) => ArchivePayloadFields::success(&**v),
+            Self::NotificationArchivePayload(v) => ArchivePayloadFields::success(&**v),
+            Self::ProjectArchivePayload(v) => ArchivePayloadFields::success(&**v),
+            Self::ProjectStatusArchivePayload(v) => ArchivePayloadFields::success(&**v),
+            Self::ProjectUpdateArchivePayload(v) => ArchivePayloadFields::success(&**v),
+            Self::ReleaseArchivePayload(v) => ArchivePayloadFields::success(&**v),
+            Self::ReleasePipelineArchivePayload(v) => ArchivePayloadFields::success(&**v),
+            Self::ReleaseStageArchivePayload(v) => ArchivePayloadFields::success(&**v),
+            Self::RoadmapArchivePayload(v) => ArchivePayloadFields::success(&**v),
+            Self::TeamArchivePayload(v) => ArchivePayloadFields::success(&**v),
+            Self::WorkflowStateArchivePayload(v) => ArchivePayloadFields::success(&**v),
+            Self::Unknown => None,
+        }
+    }
+}
+/// A basic entity.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "__typename")]
+#[non_exhaustive]
+pub enum Entity {
+    CustomViewNotificationSubscription(Box<CustomViewNotificationSubscription>),
+    CustomerNeedNotification(Box<CustomerNeedNotification>),
+    CustomerNotification(Box<CustomerNotification>),
+    CustomerNotificationSubscription(Box<CustomerNotificationSubscription>),
+    CycleNotificationSubscription(Box<CycleNotificationSubscription>),
+    DocumentNotification(Box<DocumentNotification>),
+    InitiativeNotification(Box<InitiativeNotification>),
+    InitiativeNotificationSubscription(Box<InitiativeNotificationSubscription>),
+    IssueNotification(Box<IssueNotification>),
+    LabelNotificationSubscription(Box<LabelNotificationSubscription>),
+    OauthClientApprovalNotification(Box<OauthClientApprovalNotification>),
+    PostNotification(Box<PostNotification>),
+    ProjectNotification(Box<ProjectNotification>),
+    ProjectNotificationSubscription(Box<ProjectNotificationSubscription>),
+    PullRequestNotification(Box<PullRequestNotification>),
+    TeamNotificationSubscription(Box<TeamNotificationSubscription>),
+    UserNotificationSubscription(Box<UserNotificationSubscription>),
+    WelcomeMessageNotification(Box<WelcomeMessageNotification>),
+    /// Catch-all for object types unknown to this SDK version.
+    #[serde(other)]
+    Unknown,
+}
+impl GraphQLFields for Entity {
+    type FullType = Self;
+    fn selection() -> String {
+        crate::field_selection::inline_fragments(&[
+            (
+                "CustomViewNotificationSubscription",
+                <CustomViewNotificationSubscription as GraphQLFields>::selection(),
+            ),
+            (
+                "CustomerNeedNotification",
+                <CustomerNeedNotification as GraphQLFields>::selection(),
+            ),
+            (
+                "CustomerNotification",
+                <CustomerNotification as GraphQLFields>::selection(),
+            ),
+            (
+                "CustomerNotificationSubscription",
+                <CustomerNotificationSubscription as GraphQLFields>::selection(),
+            ),
+            (
+                "CycleNotificationSubscription",
+                <CycleNotificationSubscription as GraphQLFields>::selection(),
+            ),
+            (
+                "DocumentNotification",
+                <DocumentNotification as GraphQLFields>::selection(),
+            ),
+            (
+                "InitiativeNotification",
+                <InitiativeNotification as GraphQLFields>::selection(),
+            ),
+            (
+                "InitiativeNotificationSubscription",
+                <InitiativeNotificationSubscription as GraphQLFields>::selection(),
+            ),
+            (
+                "IssueNotification",
+                <IssueNotification as GraphQLFields>::selection(),
+            ),
+            (
+                "LabelNotificationSubscription",
+                <LabelNotificationSubscription as GraphQLFields>::selection(),
+            ),
+            (
+                "OauthClientApprovalNotification",
+                <OauthClientApprovalNotification as GraphQLFields>::selection(),
+            ),
+            (
+                "PostNotification",
+                <PostNotification as GraphQLFields>::selection(),
+            ),
+            (
+                "ProjectNotification",
+                <ProjectNotification as GraphQLFields>::selection(),
+            ),
+            (
+                "ProjectNotificationSubscription",
+                <ProjectNotificationSubscription as GraphQLFields>::selection(),
+            ),
+            (
+                "PullRequestNotification",
+                <PullRequestNotification as GraphQLFields>::selection(),
+            ),
+            (
+                "TeamNotificationSubscription",
+                <TeamNotificationSubscription as GraphQLFields>::selection(),
+            ),
+            (
+                "UserNotificationSubscription",
+                <UserNotificationSubscription as GraphQLFields>::selection(),
+            ),
+            (
+                "WelcomeMessageNotification",
+                <WelcomeMessageNotification as GraphQLFields>::selection(),
+            ),
+        ])
+    }
+}
+/// Fields shared by every [`Entity`] type. Each returns `None` when not selected.
+pub trait EntityFields: NodeFields {
+    /// The time at which the entity was created.
+    fn created_at(&self) -> Option<&chrono::DateTime<chrono::Utc>>;
+    /// The last time at which the entity was meaningfully updated. This is the same as the creation time if the entity hasn't
+    /// been updated after creation.
+    fn updated_at(&self) -> Option<&chrono::DateTime<chrono::Utc>>;
+    /// The time at which the entity was archived. Null if the entity has not been archived.
+    fn archived_at(&self) -> Option<&chrono::DateTime<chrono::Utc>>;
+}
+impl EntityFields for CustomViewNotificationSubscription {
+    fn created_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.created_at.as_ref()
+    }
+    fn updated_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.updated_at.as_ref()
+    }
+    fn archived_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.archived_at.as_ref()
+    }
+}
+impl EntityFields for CustomerNeedNotification {
+    fn created_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.created_at.as_ref()
+    }
+    fn updated_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.updated_at.as_ref()
+    }
+    fn archived_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.archived_at.as_ref()
+    }
+}
+impl EntityFields for CustomerNotification {
+    fn created_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.created_at.as_ref()
+    }
+    fn updated_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.updated_at.as_ref()
+    }
+    fn archived_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.archived_at.as_ref()
+    }
+}
+impl EntityFields for CustomerNotificationSubscription {
+    fn created_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.created_at.as_ref()
+    }
+    fn updated_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.updated_at.as_ref()
+    }
+    fn archived_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.archived_at.as_ref()
+    }
+}
+impl EntityFields for CycleNotificationSubscription {
+    fn created_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.created_at.as_ref()
+    }
+    fn updated_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.updated_at.as_ref()
+    }
+    fn archived_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.archived_at.as_ref()
+    }
+}
+impl EntityFields for DocumentNotification {
+    fn created_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.created_at.as_ref()
+    }
+    fn updated_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.updated_at.as_ref()
+    }
+    fn archived_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.archived_at.as_ref()
+    }
+}
+impl EntityFields for InitiativeNotification {
+    fn created_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.created_at.as_ref()
+    }
+    fn updated_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.updated_at.as_ref()
+    }
+    fn archived_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.archived_at.as_ref()
+    }
+}
+impl EntityFields for InitiativeNotificationSubscription {
+    fn created_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.created_at.as_ref()
+    }
+    fn updated_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.updated_at.as_ref()
+    }
+    fn archived_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.archived_at.as_ref()
+    }
+}
+impl EntityFields for IssueNotification {
+    fn created_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.created_at.as_ref()
+    }
+    fn updated_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.updated_at.as_ref()
+    }
+    fn archived_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.archived_at.as_ref()
+    }
+}
+impl EntityFields for LabelNotificationSubscription {
+    fn created_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.created_at.as_ref()
+    }
+    fn updated_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.updated_at.as_ref()
+    }
+    fn archived_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.archived_at.as_ref()
+    }
+}
+impl EntityFields for OauthClientApprovalNotification {
+    fn created_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.created_at.as_ref()
+    }
+    fn updated_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.updated_at.as_ref()
+    }
+    fn archived_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.archived_at.as_ref()
+    }
+}
+impl EntityFields for PostNotification {
+    fn created_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.created_at.as_ref()
+    }
+    fn updated_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.updated_at.as_ref()
+    }
+    fn archived_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.archived_at.as_ref()
+    }
+}
+impl EntityFields for ProjectNotification {
+    fn created_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.created_at.as_ref()
+    }
+    fn updated_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.updated_at.as_ref()
+    }
+    fn archived_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.archived_at.as_ref()
+    }
+}
+impl EntityFields for ProjectNotificationSubscription {
+    fn created_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.created_at.as_ref()
+    }
+    fn updated_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.updated_at.as_ref()
+    }
+    fn archived_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.archived_at.as_ref()
+    }
+}
+impl EntityFields for PullRequestNotification {
+    fn created_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.created_at.as_ref()
+    }
+    fn updated_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.updated_at.as_ref()
+    }
+    fn archived_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.archived_at.as_ref()
+    }
+}
+impl EntityFields for TeamNotificationSubscription {
+    fn created_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.created_at.as_ref()
+    }
+    fn updated_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.updated_at.as_ref()
+    }
+    fn archived_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.archived_at.as_ref()
+    }
+}
+impl EntityFields for UserNotificationSubscription {
+    fn created_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.created_at.as_ref()
+    }
+    fn updated_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.updated_at.as_ref()
+    }
+    fn archived_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.archived_at.as_ref()
+    }
+}
+impl EntityFields for WelcomeMessageNotification {
+    fn created_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.created_at.as_ref()
+    }
+    fn updated_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.updated_at.as_ref()
+    }
+    fn archived_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.archived_at.as_ref()
+    }
+}
+impl NodeFields for Entity {
+    fn id(&self) -> Option<&String> {
+        match self {
+            Self::CustomViewNotificationSubscription(v) => NodeFields::id(&**v),
+            Self::CustomerNeedNotification(v) => NodeFields::id(&**v),
+            Self::CustomerNotification(v) => NodeFields::id(&**v),
+            Self::CustomerNotificationSubscription(v) => NodeFields::id(&**v),
+            Self::CycleNotificationSubscription(v) => NodeFields::id(&**v),
+            Self::DocumentNotification(v) => NodeFields::id(&**v),
+            Self::InitiativeNotification(v) => NodeFields::id(&**v),
+            Self::InitiativeNotificationSubscription(v) => NodeFields::id(&**v),
+            Self::IssueNotification(v) => NodeFields::id(&**v),
+            Self::LabelNotificationSubscription(v) => NodeFields::id(&**v),
+            Self::OauthClientApprovalNotification(v) => NodeFields::id(&**v),
+            Self::PostNotification(v) => NodeFields::id(&**v),
+            Self::ProjectNotification(v) => NodeFields::id(&**v),
+            Self::ProjectNotificationSubscription(v) => NodeFields::id(&**v),
+            Self::PullRequestNotification(v) => NodeFields::id(&**v),
+            Self::TeamNotificationSubscription(v) => NodeFields::id(&**v),
+            Self::UserNotificationSubscription(v) => NodeFields::id(&**v),
+            Self::WelcomeMessageNotification(v) => NodeFields::id(&**v),
+            Self::Unknown => None,
+        }
+    }
+}
+impl EntityFields for Entity {
+    fn created_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        match self {
+            Self::CustomViewNotificationSubscription(v) => EntityFields::created_at(&**v),
+            Self::CustomerNeedNotification(v) => EntityFields::created_at(&**v),
+            Self::CustomerNotification(v) => EntityFields::created_at(&**v),
+            Self::CustomerNotificationSubscription(v) => EntityFields::created_at(&**v),
+            Self::CycleNotificationSubscription(v) => EntityFields::created_at(&**v),
+            Self::DocumentNotification(v) => EntityFields::created_at(&**v),
+            Self::InitiativeNotification(v) => EntityFields::created_at(&**v),
+            Self::InitiativeNotificationSubscription(v) => EntityFields::created_at(&**v),
+            Self::IssueNotification(v) => EntityFields::created_at(&**v),
+            Self::LabelNotificationSubscription(v) => EntityFields::created_at(&**v),
+            Self::OauthClientApprovalNotification(v) => EntityFields::created_at(&**v),
+            Self::PostNotification(v) => EntityFields::created_at(&**v),
+            Self::ProjectNotification(v) => EntityFields::created_at(&**v),
+            Self::ProjectNotificationSubscription(v) => EntityFields::created_at(&**v),
+            Self::PullRequestNotification(v) => EntityFields::created_at(&**v),
+            Self::TeamNotificationSubscription(v) => EntityFields::created_at(&**v),
+            Self::UserNotificationSubscription(v) => EntityFields::created_at(&**v),
+            Self::WelcomeMessageNotification(v) => EntityFields::created_at(&**v),
+            Self::Unknown => None,
+        }
+    }
+    fn updated_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        match self {
+            Self::CustomViewNotificationSubscription(v) => EntityFields::updated_at(&**v),
+            Self::CustomerNeedNotification(v) => EntityFields::updated_at(&**v),
+            Self::CustomerNotification(v) => EntityFields::updated_at(&**v),
+            Self::CustomerNotificationSubscription(v) => EntityFields::updated_at(&**v),
+            Self::CycleNotificationSubscription(v) => EntityFields::updated_at(&**v),
+            Self::DocumentNotification(v) => EntityFields::updated_at(&**v),
+            Self::InitiativeNotification(v) => EntityFields::updated_at(&**v),
+            Self::InitiativeNotificationSubscription(v) => EntityFields::updated_at(&**v),
+            Self::IssueNotification(v) => EntityFields::updated_at(&**v),
+            Self::LabelNotificationSubscription(v) => EntityFields::updated_at(&**v),
+            Self::OauthClientApprovalNotification(v) => EntityFields::updated_at(&**v),
+            Self::PostNotification(v) => EntityFields::updated_at(&**v),
+            Self::ProjectNotification(v) => EntityFields::updated_at(&**v),
+            Self::ProjectNotificationSubscription(v) => EntityFields::updated_at(&**v),
+            Self::PullRequestNotification(v) => EntityFields::updated_at(&**v),
+            Self::TeamNotificationSubscription(v) => EntityFields::updated_at(&**v),
+            Self::UserNotificationSubscription(v) => EntityFields::updated_at(&**v),
+            Self::WelcomeMessageNotification(v) => EntityFields::updated_at(&**v),
+            Self::Unknown => None,
+        }
+    }
+    fn archived_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        match self {
+            Self::CustomViewNotificationSubscription(v) => EntityFields::archived_at(&**v),
+            Self::CustomerNeedNotification(v) => EntityFields::archived_at(&**v),
+            Self::CustomerNotification(v) => EntityFields::archived_at(&**v),
+            Self::CustomerNotificationSubscription(v) => EntityFields::archived_at(&**v),
+            Self::CycleNotificationSubscription(v) => EntityFields::archived_at(&**v),
+            Self::DocumentNotification(v) => EntityFields::archived_at(&**v),
+            Self::InitiativeNotification(v) => EntityFields::archived_at(&**v),
+            Self::InitiativeNotificationSubscription(v) => EntityFields::archived_at(&**v),
+            Self::IssueNotification(v) => EntityFields::archived_at(&**v),
+            Self::LabelNotificationSubscription(v) => EntityFields::archived_at(&**v),
+            Self::OauthClientApprovalNotification(v) => EntityFields::archived_at(&**v),
+            Self::PostNotification(v) => EntityFields::archived_at(&**v),
+            Self::ProjectNotification(v) => EntityFields::archived_at(&**v),
+            Self::ProjectNotificationSubscription(v) => EntityFields::archived_at(&**v),
+            Self::PullRequestNotification(v) => EntityFields::archived_at(&**v),
+            Self::TeamNotificationSubscription(v) => EntityFields::archived_at(&**v),
+            Self::UserNotificationSubscription(v) => EntityFields::archived_at(&**v),
+            Self::WelcomeMessageNotification(v) => EntityFields::archived_at(&**v),
+            Self::Unknown => None,
+        }
+    }
+}
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "__typename")]
+#[non_exhaustive]
+pub enum Node {
+    AgentActivity(Box<AgentActivity>),
+    AgentSession(Box<AgentSession>),
+    AgentSessionToPullRequest(Box<AgentSessionToPullRequest>),
+    AiConversation(Box<AiConversation>),
+    AiPromptProgress(Box<AiPromptProgress>),
+    AiPromptRules(Box<AiPromptRules>),
+    Attachment(Box<Attachment>),
+    AuditEntry(Box<AuditEntry>),
+    Comment(Box<Comment>),
+    CustomView(Box<CustomView>),
+    CustomViewNotificationSubscription(Box<CustomViewNotificationSubscription>),
+    Customer(Box<Customer>),
+    CustomerNeed(Box<CustomerNeed>),
+    CustomerNeedNotification(Box<CustomerNeedNotification>),
+    CustomerNotification(Box<CustomerNotification>),
+    CustomerNotificationSubscription(Box<CustomerNotificationSubscription>),
+    CustomerStatus(Box<CustomerStatus>),
+    CustomerTier(Box<CustomerTier>),
+    Cycle(Box<Cycle>),
+    CycleNotificationSubscription(Box<CycleNotificationSubscription>),
+    Dashboard(Box<Dashboard>),
+    Document(Box<Document>),
+    DocumentContent(Box<DocumentContent>),
+    DocumentContentDraft(Box<DocumentContentDraft>),
+    DocumentNotification(Box<DocumentNotification>),
+    DocumentSearchResult(Box<DocumentSearchResult>),
+    Draft(Box<Draft>),
+    EmailIntakeAddress(Box<EmailIntakeAddress>),
+    Emoji(Box<Emoji>),
+    EntityExternalLink(Box<EntityExternalLink>),
+    ExternalUser(Box<ExternalUser>),
+    Facet(Box<Facet>),
+    Favorite(Box<Favorite>),
+    FeedItem(Box<FeedItem>),
+    GitAutomationState(Box<GitAutomationState>),
+    GitAutomationTargetBranch(Box<GitAutomationTargetBranch>),
+    IdentityProvider(Box<IdentityProvider>),
+    Initiative(Box<Initiative>),
+    InitiativeHistory(Box<InitiativeHistory>),
+    InitiativeNotification(Box<InitiativeNotification>),
+    InitiativeNotificationSubscription(Box<InitiativeNotificationSubscription>),
+    InitiativeRelation(Box<InitiativeRelation>),
+    InitiativeToProject(Box<InitiativeToProject>),
+    InitiativeUpdate(Box<InitiativeUpdate>),
+    Integration(Box<Integration>),
+    IntegrationTemplate(Box<IntegrationTemplate>),
+    IntegrationsSettings(Box<IntegrationsSettings>),
+    Issue(Box<Issue>),
+    IssueDraft(Box<IssueDraft>),
+    IssueHistory(Box<IssueHistory>),
+    IssueImport(Box<IssueImport>),
+    IssueLabel(Box<IssueLabel>),
+    IssueNotification(Box<IssueNotification>),
+    IssueRelation(Box<IssueRelation>),
+    IssueSearchResult(Box<IssueSearchResult>),
+    IssueSuggestion(Box<IssueSuggestion>),
+    IssueToRelease(Box<IssueToRelease>),
+    LabelNotificationSubscription(Box<LabelNotificationSubscription>),
+    OauthClientApproval(Box<OauthClientApproval>),
+    OauthClientApprovalNotification(Box<OauthClientApprovalNotification>),
+    Organization(Box<Organization>),
+    OrganizationDomain(Box<OrganizationDomain>),
+    OrganizationInvite(Box<OrganizationInvite>),
+    PaidSubscription(Box<PaidSubscription>),
+    Post(Box<Post>),
+    PostNotification(Box<PostNotification>),
+    Project(Box<Project>),
+    ProjectAttachment(Box<ProjectAttachment>),
+    ProjectHistory(Box<ProjectHistory>),
+    ProjectLabel(Box<ProjectLabel>),
+    ProjectMilestone(Box<ProjectMilestone>),
+    ProjectNotification(Box<ProjectNotification>),
+    ProjectNotificationSubscription(Box<ProjectNotificationSubscription>),
+    ProjectRelation(Box<ProjectRelation>),
+    ProjectSearchResult(Box<ProjectSearchResult>),
+    ProjectStatus(Box<ProjectStatus>),
+    ProjectUpdate(Box<ProjectUpdate>),
+    PullRequest(Box<PullRequest>),
+    PullRequestNotification(Box<PullRequestNotification>),
+    PushSubscription(Box<PushSubscription>),
+    Reaction(Box<Reaction>),
+    Release(Box<Release>),
+    ReleaseHistory(Box<ReleaseHistory>),
+    ReleaseNote(Box<ReleaseNote>),
+    ReleasePipeline(Box<ReleasePipeline>),
+    ReleaseStage(Box<ReleaseStage>),
+    Roadmap(Box<Roadmap>),
+    RoadmapToProject(Box<RoadmapToProject>),
+    SemanticSearchResult(Box<SemanticSearchResult>),
+    SesDomainIdentity(Box<SesDomainIdentity>),
+    Summary(Box<Summary>),
+    Team(Box<Team>),
+    TeamMembership(Box<TeamMembership>),
+    TeamNotificationSubscription(Box<TeamNotificationSubscription>),
+    Template(Box<Template>),
+    TimeSchedule(Box<TimeSchedule>),
+    TriageResponsibility(Box<TriageResponsibility>),
+    User(Box<User>),
+    UserNotificationSubscription(Box<UserNotificationSubscription>),
+    UserSettings(Box<UserSettings>),
+    ViewPreferences(Box<ViewPreferences>),
+    Webhook(Box<Webhook>),
+    WelcomeMessage(Box<WelcomeMessage>),
+    WelcomeMessageNotification(Box<WelcomeMessageNotification>),
+    WorkflowDefinition(Box<WorkflowDefinition>),
+    WorkflowState(Box<WorkflowState>),
+    /// Catch-all for object types unknown to this SDK version.
+    #[serde(other)]
+    Unknown,
+}
+impl GraphQLFields for Node {
+    type FullType = Self;
+    fn selection() -> String {
+        crate::field_selection::inline_fragments(&[
+            (
+                "AgentActivity",
+                <AgentActivity as GraphQLFields>::selection(),
+            ),
+            ("AgentSession", <AgentSession as GraphQLFields>::selection()),
+            (
+                "AgentSessionToPullRequest",
+                <AgentSessionToPullRequest as GraphQLFields>::selection(),
+            ),
+            (
+                "AiConversation",
+                <AiConversation as GraphQLFields>::selection(),
+            ),
+            (
+                "AiPromptProgress",
+                <AiPromptProgress as GraphQLFields>::selection(),
+            ),
+            (
+                "AiPromptRules",
+                <AiPromptRules as GraphQLFields>::selection(),
+            ),
+            ("Attachment", <Attachment as GraphQLFields>::selection()),
+            ("AuditEntry", <AuditEntry as GraphQLFields>::selection()),
+            ("Comment", <Comment as GraphQLFields>::selection()),
+            ("CustomView", <CustomView as GraphQLFields>::selection()),
+            (
+                "CustomViewNotificationSubscription",
+                <CustomViewNotificationSubscription as GraphQLFields>::selection(),
+            ),
+            ("Customer", <Customer as GraphQLFields>::selection()),
+            ("CustomerNeed", <CustomerNeed as GraphQLFields>::selection()),
+            (
+                "CustomerNeedNotification",
+                <CustomerNeedNotification as GraphQLFields>::selection(),
+            ),
+            (
+                "CustomerNotification",
+                <CustomerNotification as GraphQLFields>::selection(),
+            ),
+            (
+                "CustomerNotificationSubscription",
+                <CustomerNotificationSubscription as GraphQLFields>::selection(),
+            ),
+            (
+                "CustomerStatus",
+                <CustomerStatus as GraphQLFields>::selection(),
+            ),
+            ("CustomerTier", <CustomerTier as GraphQLFields>::selection()),
+            ("Cycle", <Cycle as GraphQLFields>::selection()),
+            (
+                "CycleNotificationSubscription",
+                <CycleNotificationSubscription as GraphQLFields>::selection(),
+            ),
+            ("Dashboard", <Dashboard as GraphQLFields>::selection()),
+            ("Document", <Document as GraphQLFields>::selection()),
+            (
+                "DocumentContent",
+                <DocumentContent as GraphQLFields>::selection(),
+            ),
+            (
+                "DocumentContentDraft",
+                <DocumentContentDraft as GraphQLFields>::selection(),
+            ),
+            (
+                "DocumentNotification",
+                <DocumentNotification as GraphQLFields>::selection(),
+            ),
+            (
+                "DocumentSearchResult",
+                <DocumentSearchResult as GraphQLFields>::selection(),
+            ),
+            ("Draft", <Draft as GraphQLFields>::selection()),
+            (
+                "EmailIntakeAddress",
+                <EmailIntakeAddress as GraphQLFields>::selection(),
+            ),
+            ("Emoji", <Emoji as GraphQLFields>::selection()),
+            (
+                "EntityExternalLink",
+                <EntityExternalLink as GraphQLFields>::selection(),
+            ),
+            ("ExternalUser", <ExternalUser as GraphQLFields>::selection()),
+            ("Facet", <Facet as GraphQLFields>::selection()),
+            ("Favorite", <Favorite as GraphQLFields>::selection()),
+            ("FeedItem", <FeedItem as GraphQLFields>::selection()),
+            (
+                "GitAutomationState",
+                <GitAutomationState as GraphQLFields>::selection(),
+            ),
+            (
+                "GitAutomationTargetBranch",
+                <GitAutomationTargetBranch as GraphQLFields>::selection(),
+            ),
+            (
+                "IdentityProvider",
+                <IdentityProvider as GraphQLFields>::selection(),
+            ),
+            ("Initiative", <Initiative as GraphQLFields>::selection()),
+            (
+                "InitiativeHistory",
+                <InitiativeHistory as GraphQLFields>::selection(),
+            ),
+            (
+                "InitiativeNotification",
+                <InitiativeNotification as GraphQLFields>::selection(),
+            ),
+            (
+                "InitiativeNotificationSubscription",
+                <InitiativeNotificationSubscription as GraphQLFields>::selection(),
+            ),
+            (
+                "InitiativeRelation",
+                <InitiativeRelation as GraphQLFields>::selection(),
+            ),
+            (
+                "InitiativeToProject",
+                <InitiativeToProject as GraphQLFields>::selection(),
+            ),
+            (
+                "InitiativeUpdate",
+                <InitiativeUpdate as GraphQLFields>::selection(),
+            ),
+            ("Integration", <Integration as GraphQLFields>::selection()),
+            (
+                "IntegrationTemplate",
+                <IntegrationTemplate as GraphQLFields>::selection(),
+            ),
+            (
+                "IntegrationsSettings",
+                <IntegrationsSettings as GraphQLFields>::selection(),
+            ),
+            ("Issue", <Issue as GraphQLFields>::selection()),
+            ("IssueDraft", <IssueDraft as GraphQLFields>::selection()),
+            ("IssueHistory", <IssueHistory as GraphQLFields>::selection()),
+            ("IssueImport", <IssueImport as GraphQLFields>::selection()),
+            ("IssueLabel", <IssueLabel as GraphQLFields>::selection()),
+            (
+                "IssueNotification",
+                <IssueNotification as GraphQLFields>::selection(),
+            ),
+            (
+                "IssueRelation",
+                <IssueRelation as GraphQLFields>::selection(),
+            ),
+            (
+                "IssueSearchResult",
+                <IssueSearchResult as GraphQLFields>::selection(),
+            ),
+            (
+                "IssueSuggestion",
+                <IssueSuggestion as GraphQLFields>::selection(),
+            ),
+            (
+                "IssueToRelease",
+                <IssueToRelease as GraphQLFields>::selection(),
+            ),
+            (
+                "LabelNotificationSubscription",
+                <LabelNotificationSubscription as GraphQLFields>::selection(),
+            ),
+            (
+                "OauthClientApproval",
+                <OauthClientApproval as GraphQLFields>::selection(),
+            ),
+            (
+                "OauthClientApprovalNotification",
+                <OauthClientApprovalNotification as GraphQLFields>::selection(),
+            ),
+            ("Organization", <Organization as GraphQLFields>::selection()),
+            (
+                "OrganizationDomain",
+                <OrganizationDomain as GraphQLFields>::selection(),
+            ),
+            (
+                "OrganizationInvite",
+                <OrganizationInvite as GraphQLFields>::selection(),
+            ),
+            (
+                "PaidSubscription",
+                <PaidSubscription as GraphQLFields>::selection(),
+            ),
+            ("Post", <Post as GraphQLFields>::selection()),
+            (
+                "PostNotification",
+                <PostNotification as GraphQLFields>::selection(),
+            ),
+            ("Project", <Project as GraphQLFields>::selection()),
+            (
+                "ProjectAttachment",
+                <ProjectAttachment as GraphQLFields>::selection(),
+            ),
+            (
+                "ProjectHistory",
+                <ProjectHistory as GraphQLFields>::selection(),
+            ),
+            ("ProjectLabel", <ProjectLabel as GraphQLFields>::selection()),
+            (
+                "ProjectMilestone",
+                <ProjectMilestone as GraphQLFields>::selection(),
+            ),
+            (
+                "ProjectNotification",
+                <ProjectNotification as GraphQLFields>::selection(),
+            ),
+            (
+                "ProjectNotificationSubscription",
+                <ProjectNotificationSubscription as GraphQLFields>::selection(),
+            ),
+            (
+                "ProjectRelation",
+                <ProjectRelation as GraphQLFields>::selection(),
+            ),
+            (
+                "ProjectSearchResult",
+                <ProjectSearchResult as GraphQLFields>::selection(),
+            ),
+            (
+                "ProjectStatus",
+                <ProjectStatus as GraphQLFields>::selection(),
+            ),
+            (
+                "ProjectUpdate",
+                <ProjectUpdate as GraphQLFields>::selection(),
+            ),
+            ("PullRequest", <PullRequest as GraphQLFields>::selection()),
+            (
+                "PullRequestNotification",
+                <PullRequestNotification as GraphQLFields>::selection(),
+            ),
+            (
+                "PushSubscription",
+                <PushSubscription as GraphQLFields>::selection(),
+            ),
+            ("Reaction", <Reaction as GraphQLFields>::selection()),
+            ("Release", <Release as GraphQLFields>::selection()),
+            (
+                "ReleaseHistory",
+                <ReleaseHistory as GraphQLFields>::selection(),
+            ),
+            ("ReleaseNote", <ReleaseNote as GraphQLFields>::selection()),
+            (
+                "ReleasePipeline",
+                <ReleasePipeline as GraphQLFields>::selection(),
+            ),
+            ("ReleaseStage", <ReleaseStage as GraphQLFields>::selection()),
+            ("Roadmap", <Roadmap as GraphQLFields>::selection()),
+            (
+                "RoadmapToProject",
+                <RoadmapToProject as GraphQLFields>::selection(),
+            ),
+            (
+                "SemanticSearchResult",
+                <SemanticSearchResult as GraphQLFields>::selection(),
+            ),
+            (
+                "SesDomainIdentity",
+                <SesDomainIdentity as GraphQLFields>::selection(),
+            ),
+            ("Summary", <Summary as GraphQLFields>::selection()),
+            ("Team", <Team as GraphQLFields>::selection()),
+            (
+                "TeamMembership",
+                <TeamMembership as GraphQLFields>::selection(),
+            ),
+            (
+                "TeamNotificationSubscription",
+                <TeamNotificationSubscription as GraphQLFields>::selection(),
+            ),
+            ("Template", <Template as GraphQLFields>::selection()),
+            ("TimeSchedule", <TimeSchedule as GraphQLFields>::selection()),
+            (
+                "TriageResponsibility",
+                <TriageResponsibility as GraphQLFields>::selection(),
+            ),
+            ("User", <User as GraphQLFields>::selection()),
+            (
+                "UserNotificationSubscription",
+                <UserNotificationSubscription as GraphQLFields>::selection(),
+            ),
+            ("UserSettings", <UserSettings as GraphQLFields>::selection()),
+            (
+                "ViewPreferences",
+                <ViewPreferences as GraphQLFields>::selection(),
+            ),
+            ("Webhook", <Webhook as GraphQLFields>::selection()),
+            (
+                "WelcomeMessage",
+                <WelcomeMessage as GraphQLFields>::selection(),
+            ),
+            (
+                "WelcomeMessageNotification",
+                <WelcomeMessageNotification as GraphQLFields>::selection(),
+            ),
+            (
+                "WorkflowDefinition",
+                <WorkflowDefinition as GraphQLFields>::selection(),
+            ),
+            (
+                "WorkflowState",
+                <WorkflowState as GraphQLFields>::selection(),
+            ),
+        ])
+    }
+}
+/// Fields shared by every [`Node`] type. Each returns `None` when not selected.
+pub trait NodeFields {
+    /// The unique identifier of the entity.
+    fn id(&self) -> Option<&String>;
+}
+impl NodeFields for AgentActivity {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for AgentSession {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for AgentSessionToPullRequest {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for AiConversation {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for AiPromptProgress {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for AiPromptRules {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for Attachment {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for AuditEntry {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for Comment {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for CustomView {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for CustomViewNotificationSubscription {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for Customer {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for CustomerNeed {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for CustomerNeedNotification {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for CustomerNotification {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for CustomerNotificationSubscription {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for CustomerStatus {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for CustomerTier {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for Cycle {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for CycleNotificationSubscription {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for Dashboard {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for Document {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for DocumentContent {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for DocumentContentDraft {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for DocumentNotification {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for DocumentSearchResult {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for Draft {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for EmailIntakeAddress {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for Emoji {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for EntityExternalLink {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for ExternalUser {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for Facet {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for Favorite {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for FeedItem {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for GitAutomationState {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for GitAutomationTargetBranch {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for IdentityProvider {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for Initiative {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for InitiativeHistory {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for InitiativeNotification {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for InitiativeNotificationSubscription {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for InitiativeRelation {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for InitiativeToProject {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for InitiativeUpdate {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for Integration {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for IntegrationTemplate {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for IntegrationsSettings {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for Issue {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for IssueDraft {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for IssueHistory {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for IssueImport {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for IssueLabel {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for IssueNotification {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for IssueRelation {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for IssueSearchResult {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for IssueSuggestion {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for IssueToRelease {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for LabelNotificationSubscription {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for OauthClientApproval {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for OauthClientApprovalNotification {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for Organization {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for OrganizationDomain {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for OrganizationInvite {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for PaidSubscription {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for Post {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for PostNotification {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for Project {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for ProjectAttachment {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for ProjectHistory {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for ProjectLabel {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for ProjectMilestone {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for ProjectNotification {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for ProjectNotificationSubscription {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for ProjectRelation {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for ProjectSearchResult {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for ProjectStatus {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for ProjectUpdate {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for PullRequest {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for PullRequestNotification {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for PushSubscription {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for Reaction {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for Release {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for ReleaseHistory {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for ReleaseNote {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for ReleasePipeline {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for ReleaseStage {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for Roadmap {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for RoadmapToProject {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for SemanticSearchResult {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for SesDomainIdentity {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for Summary {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for Team {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for TeamMembership {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for TeamNotificationSubscription {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for Template {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for TimeSchedule {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for TriageResponsibility {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for User {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for UserNotificationSubscription {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for UserSettings {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for ViewPreferences {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for Webhook {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for WelcomeMessage {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for WelcomeMessageNotification {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for WorkflowDefinition {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for WorkflowState {
+    fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+}
+impl NodeFields for Node {
+    fn id(&self) -> Option<&String> {
+        match self {
+            Self::AgentActivity(v) => NodeFields::id(&**v),
+            Self::AgentSession(v) => NodeFields::id(&**v),
+            Self::AgentSessionToPullRequest(v) => NodeFields::id(&**v),
+            Self::AiConversation(v) => NodeFields::id(&**v),
+            Self::AiPromptProgress(v) => NodeFields::id(&**v),
+            Self::AiPromptRules(v) => NodeFields::id(&**v),
+            Self::Attachment(v) => NodeFields::id(&**v),
+            Self::AuditEntry(v) => NodeFields::id(&**v),
+            Self::Comment(v) => NodeFields::id(&**v),
+            Self::CustomView(v) => NodeFields::id(&**v),
+            Self::CustomViewNotificationSubscription(v) => NodeFields::id(&**v),
+            Self::Customer(v) => NodeFields::id(&**v),
+            Self::CustomerNeed(v) => NodeFields::id(&**v),
+            Self::CustomerNeedNotification(v) => NodeFields::id(&**v),
+            Self::CustomerNotification(v) => NodeFields::id(&**v),
+            Self::CustomerNotificationSubscription(v) => NodeFields::id(&**v),
+            Self::CustomerStatus(v) => NodeFields::id(&**v),
+            Self::CustomerTier(v) => NodeFields::id(&**v),
+            Self::Cycle(v) => NodeFields::id(&**v),
+            Self::CycleNotificationSubscription(v) => NodeFields::id(&**v),
+            Self::Dashboard(v) => NodeFields::id(&**v),
+            Self::Document(v) => NodeFields::id(&**v),
+            Self::DocumentContent(v) => NodeFields::id(&**v),
+            Self::DocumentContentDraft(v) => NodeFields::id(&**v),
+            Self::DocumentNotification(v) => NodeFields::id(&**v),
+            Self::DocumentSearchResult(v) => NodeFields::id(&**v),
+            Self::Draft(v) => NodeFields::id(&**v),
+            Self::EmailIntakeAddress(v) => NodeFields::id(&**v),
+            Self::Emoji(v) => NodeFields::id(&**v),
+            Self::EntityExternalLink(v) => NodeFields::id(&**v),
+            Self::ExternalUser(v) => NodeFields::id(&**v),
+            Self::Facet(v) => NodeFields::id(&**v),
+            Self::Favorite(v) => NodeFields::id(&**v),
+            Self::FeedItem(v) => NodeFields::id(&**v),
+            Self::GitAutomationState(v) => NodeFields::id(&**v),
+            Self::GitAutomationTargetBranch(v) => NodeFields::id(&**v),
+            Self::IdentityProvider(v) => NodeFields::id(&**v),
+            Self::Initiative(v) => NodeFields::id(&**v),
+            Self::InitiativeHistory(v) => NodeFields::id(&**v),
+            Self::InitiativeNotification(v) => NodeFields::id(&**v),
+            Self::InitiativeNotificationSubscription(v) => NodeFields::id(&**v),
+            Self::InitiativeRelation(v) => NodeFields::id(&**v),
+            Self::InitiativeToProject(v) => NodeFields::id(&**v),
+            Self::InitiativeUpdate(v) => NodeFields::id(&**v),
+            Self::Integration(v) => NodeFields::id(&**v),
+            Self::IntegrationTemplate(v) => NodeFields::id(&**v),
+            Self::IntegrationsSettings(v) => NodeFields::id(&**v),
+            Self::Issue(v) => NodeFields::id(&**v),
+            Self::IssueDraft(v) => NodeFields::id(&**v),
+            Self::IssueHistory(v) => NodeFields::id(&**v),
+            Self::IssueImport(v) => NodeFields::id(&**v),
+            Self::IssueLabel(v) => NodeFields::id(&**v),
+            Self::IssueNotification(v) => NodeFields::id(&**v),
+            Self::IssueRelation(v) => NodeFields::id(&**v),
+            Self::IssueSearchResult(v) => NodeFields::id(&**v),
+            Self::IssueSuggestion(v) => NodeFields::id(&**v),
+            Self::IssueToRelease(v) => NodeFields::id(&**v),
+            Self::LabelNotificationSubscription(v) => NodeFields::id(&**v),
+            Self::OauthClientApproval(v) => NodeFields::id(&**v),
+            Self::OauthClientApprovalNotification(v) => NodeFields::id(&**v),
+            Self::Organization(v) => NodeFields::id(&**v),
+            Self::OrganizationDomain(v) => NodeFields::id(&**v),
+            Self::OrganizationInvite(v) => NodeFields::id(&**v),
+            Self::PaidSubscription(v) => NodeFields::id(&**v),
+            Self::Post(v) => NodeFields::id(&**v),
+            Self::PostNotification(v) => NodeFields::id(&**v),
+            Self::Project(v) => NodeFields::id(&**v),
+            Self::ProjectAttachment(v) => NodeFields::id(&**v),
+            Self::ProjectHistory(v) => NodeFields::id(&**v),
+            Self::ProjectLabel(v) => NodeFields::id(&**v),
+            Self::ProjectMilestone(v) => NodeFields::id(&**v),
+            Self::ProjectNotification(v) => NodeFields::id(&**v),
+            Self::ProjectNotificationSubscription(v) => NodeFields::id(&**v),
+            Self::ProjectRelation(v) => NodeFields::id(&**v),
+            Self::ProjectSearchResult(v) => NodeFields::id(&**v),
+            Self::ProjectStatus(v) => NodeFields::id(&**v),
+            Self::ProjectUpdate(v) => NodeFields::id(&**v),
+            Self::PullRequest(v) => NodeFields::id(&**v),
+            Self::PullRequestNotification(v) => NodeFields::id(&**v),
+            Self::PushSubscription(v) => NodeFields::id(&**v),
+            Self::Reaction(v) => NodeFields::id(&**v),
+            Self::Release(v) => NodeFields::id(&**v),
+            Self::ReleaseHistory(v) => NodeFields::id(&**v),
+            Self::ReleaseNote(v) => NodeFields::id(&**v),
+            Self::ReleasePipeline(v) => NodeFields::id(&**v),
+            Self::ReleaseStage(v) => NodeFields::id(&**v),
+            Self::Roadmap(v) => NodeFields::id(&**v),
+            Self::RoadmapToProject(v) => NodeFields::id(&**v),
+            Self::SemanticSearchResult(v) => NodeFields::id(&**v),
+            Self::SesDomainIdentity(v) => NodeFields::id(&**v),
+            Self::Summary(v) => NodeFields::id(&**v),
+            Self::Team(v) => NodeFields::id(&**v),
+            Self::TeamMembership(v) => NodeFields::id(&**v),
+            Self::TeamNotificationSubscription(v) => NodeFields::id(&**v),
+            Self::Template(v) => NodeFields::id(&**v),
+            Self::TimeSchedule(v) => NodeFields::id(&**v),
+            Self::TriageResponsibility(v) => NodeFields::id(&**v),
+            Self::User(v) => NodeFields::id(&**v),
+            Self::UserNotificationSubscription(v) => NodeFields::id(&**v),
+            Self::UserSettings(v) => NodeFields::id(&**v),
+            Self::ViewPreferences(v) => NodeFields::id(&**v),
+            Self::Webhook(v) => NodeFields::id(&**v),
+            Self::WelcomeMessage(v) => NodeFields::id(&**v),
+            Self::WelcomeMessageNotification(v) => NodeFields::id(&**v),
+            Self::WorkflowDefinition(v) => NodeFields::id(&**v),
+            Self::WorkflowState(v) => NodeFields::id(&**v),
+            Self::Unknown => None,
+        }
+    }
+}
+/// A notification delivered to a user's inbox. Notifications are created in response to activity in the workspace such as issue assignments, comments, mentions, and status changes. Each notification has a specific type that determines the associated entity (issue, project, document, etc.) and the nature of the event. Notifications can be read, snoozed, or archived by the user.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "__typename")]
+#[non_exhaustive]
+pub enum Notification {
+    CustomerNeedNotification(Box<CustomerNeedNotification>),
+    CustomerNotification(Box<CustomerNotification>),
+    DocumentNotification(Box<DocumentNotification>),
+    InitiativeNotification(Box<InitiativeNotification>),
+    IssueNotification(Box<IssueNotification>),
+    OauthClientApprovalNotification(Box<OauthClientApprovalNotification>),
+    PostNotification(Box<PostNotification>),
+    ProjectNotification(Box<ProjectNotification>),
+    PullRequestNotification(Box<PullRequestNotification>),
+    WelcomeMessageNotification(Box<WelcomeMessageNotification>),
+    /// Catch-all for object types unknown to this SDK version.
+    #[serde(other)]
+    Unknown,
+}
+impl GraphQLFields for Notification {
+    type FullType = Self;
+    fn selection() -> String {
+        crate::field_selection::inline_fragments(&[
+            (
+                "CustomerNeedNotification",
+                <CustomerNeedNotification as GraphQLFields>::selection(),
+            ),
+            (
+                "CustomerNotification",
+                <CustomerNotification as GraphQLFields>::selection(),
+            ),
+            (
+                "DocumentNotification",
+                <DocumentNotification as GraphQLFields>::selection(),
+            ),
+            (
+                "InitiativeNotification",
+                <InitiativeNotification as GraphQLFields>::selection(),
+            ),
+            (
+                "IssueNotification",
+                <IssueNotification as GraphQLFields>::selection(),
+            ),
+            (
+                "OauthClientApprovalNotification",
+                <OauthClientApprovalNotification as GraphQLFields>::selection(),
+            ),
+            (
+                "PostNotification",
+                <PostNotification as GraphQLFields>::selection(),
+            ),
+            (
+                "ProjectNotification",
+                <ProjectNotification as GraphQLFields>::selection(),
+            ),
+            (
+                "PullRequestNotification",
+                <PullRequestNotification as GraphQLFields>::selection(),
+            ),
+            (
+                "WelcomeMessageNotification",
+                <WelcomeMessageNotification as GraphQLFields>::selection(),
+            ),
+        ])
+    }
+}
+/// Fields shared by every [`Notification`] type. Each returns `None` when not selected.
+pub trait NotificationFields: EntityFields + NodeFields {
+    /// Notification type. Determines the kind of event that triggered this notification and which associated entity fields will be populated.
+    fn r#type(&self) -> Option<&String>;
+    /// The time at which the user marked the notification as read. Null if the notification is unread.
+    fn read_at(&self) -> Option<&chrono::DateTime<chrono::Utc>>;
+    /// The time at which an email reminder for this notification was sent to the user. Null if no email reminder has been sent.
+    fn emailed_at(&self) -> Option<&chrono::DateTime<chrono::Utc>>;
+    /// The time until which a notification is snoozed. After this time, the notification reappears in the user's inbox. Null if the notification is not currently snoozed.
+    fn snoozed_until_at(&self) -> Option<&chrono::DateTime<chrono::Utc>>;
+    /// The time at which a notification was unsnoozed. Null if the notification has not been unsnoozed.
+    fn unsnoozed_at(&self) -> Option<&chrono::DateTime<chrono::Utc>>;
+    /// The category of the notification.
+    fn category(&self) -> Option<&NotificationCategory>;
+    /// `Internal` URL to the target of the notification.
+    fn url(&self) -> Option<&String>;
+    /// `Internal` Inbox URL for the notification.
+    fn inbox_url(&self) -> Option<&String>;
+    /// `Internal` Notification title.
+    fn title(&self) -> Option<&String>;
+    /// `Internal` Notification subtitle.
+    fn subtitle(&self) -> Option<&String>;
+    /// `Internal` If notification actor was Linear.
+    fn is_linear_actor(&self) -> Option<&bool>;
+    /// `Internal` Notification avatar URL.
+    fn actor_avatar_url(&self) -> Option<&String>;
+    /// `Internal` Notification actor initials if avatar is not available.
+    fn actor_initials(&self) -> Option<&String>;
+    /// `Internal` Notification actor initials if avatar is not available.
+    fn actor_avatar_color(&self) -> Option<&String>;
+    /// `Internal` Issue's status type for issue notifications.
+    fn issue_status_type(&self) -> Option<&String>;
+    /// `Internal` Project update health for new updates.
+    fn project_update_health(&self) -> Option<&String>;
+    /// `Internal` Initiative update health for new updates.
+    fn initiative_update_health(&self) -> Option<&String>;
+    /// `Internal` Notifications with the same grouping key will be grouped together in the UI.
+    fn grouping_key(&self) -> Option<&String>;
+    /// `Internal` Priority of the notification with the same grouping key. Higher number means higher priority. If priority is the same, notifications should be sorted by `createdAt`.
+    fn grouping_priority(&self) -> Option<&f64>;
+}
+impl NotificationFields for CustomerNeedNotification {
+    fn r#type(&self) -> Option<&String> {
+        self.r#type.as_ref()
+    }
+    fn read_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.read_at.as_ref()
+    }
+    fn emailed_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.emailed_at.as_ref()
+    }
+    fn snoozed_until_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.snoozed_until_at.as_ref()
+    }
+    fn unsnoozed_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.unsnoozed_at.as_ref()
+    }
+    fn category(&self) -> Option<&NotificationCategory> {
+        self.category.as_ref()
+    }
+    fn url(&self) -> Option<&String> {
+        self.url.as_ref()
+    }
+    fn inbox_url(&self) -> Option<&String> {
+        self.inbox_url.as_ref()
+    }
+    fn title(&self) -> Option<&String> {
+        self.title.as_ref()
+    }
+    fn subtitle(&self) -> Option<&String> {
+        self.subtitle.as_ref()
+    }
+    fn is_linear_actor(&self) -> Option<&bool> {
+        self.is_linear_actor.as_ref()
+    }
+    fn actor_avatar_url(&self) -> Option<&String> {
+        self.actor_avatar_url.as_ref()
+    }
+    fn actor_initials(&self) -> Option<&String> {
+        self.actor_initials.as_ref()
+    }
+    fn actor_avatar_color(&self) -> Option<&String> {
+        self.actor_avatar_color.as_ref()
+    }
+    fn issue_status_type(&self) -> Option<&String> {
+        self.issue_status_type.as_ref()
+    }
+    fn project_update_health(&self) -> Option<&String> {
+        self.project_update_health.as_ref()
+    }
+    fn initiative_update_health(&self) -> Option<&String> {
+        self.initiative_update_health.as_ref()
+    }
+    fn grouping_key(&self) -> Option<&String> {
+        self.grouping_key.as_ref()
+    }
+    fn grouping_priority(&self) -> Option<&f64> {
+        self.grouping_priority.as_ref()
+    }
+}
+impl NotificationFields for CustomerNotification {
+    fn r#type(&self) -> Option<&String> {
+        self.r#type.as_ref()
+    }
+    fn read_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.read_at.as_ref()
+    }
+    fn emailed_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.emailed_at.as_ref()
+    }
+    fn snoozed_until_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.snoozed_until_at.as_ref()
+    }
+    fn unsnoozed_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.unsnoozed_at.as_ref()
+    }
+    fn category(&self) -> Option<&NotificationCategory> {
+        self.category.as_ref()
+    }
+    fn url(&self) -> Option<&String> {
+        self.url.as_ref()
+    }
+    fn inbox_url(&self) -> Option<&String> {
+        self.inbox_url.as_ref()
+    }
+    fn title(&self) -> Option<&String> {
+        self.title.as_ref()
+    }
+    fn subtitle(&self) -> Option<&String> {
+        self.subtitle.as_ref()
+    }
+    fn is_linear_actor(&self) -> Option<&bool> {
+        self.is_linear_actor.as_ref()
+    }
+    fn actor_avatar_url(&self) -> Option<&String> {
+        self.actor_avatar_url.as_ref()
+    }
+    fn actor_initials(&self) -> Option<&String> {
+        self.actor_initials.as_ref()
+    }
+    fn actor_avatar_color(&self) -> Option<&String> {
+        self.actor_avatar_color.as_ref()
+    }
+    fn issue_status_type(&self) -> Option<&String> {
+        self.issue_status_type.as_ref()
+    }
+    fn project_update_health(&self) -> Option<&String> {
+        self.project_update_health.as_ref()
+    }
+    fn initiative_update_health(&self) -> Option<&String> {
+        self.initiative_update_health.as_ref()
+    }
+    fn grouping_key(&self) -> Option<&String> {
+        self.grouping_key.as_ref()
+    }
+    fn grouping_priority(&self) -> Option<&f64> {
+        self.grouping_priority.as_ref()
+    }
+}
+impl NotificationFields for DocumentNotification {
+    fn r#type(&self) -> Option<&String> {
+        self.r#type.as_ref()
+    }
+    fn read_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.read_at.as_ref()
+    }
+    fn emailed_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.emailed_at.as_ref()
+    }
+    fn snoozed_until_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.snoozed_until_at.as_ref()
+    }
+    fn unsnoozed_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.unsnoozed_at.as_ref()
+    }
+    fn category(&self) -> Option<&NotificationCategory> {
+        self.category.as_ref()
+    }
+    fn url(&self) -> Option<&String> {
+        self.url.as_ref()
+    }
+    fn inbox_url(&self) -> Option<&String> {
+        self.inbox_url.as_ref()
+    }
+    fn title(&self) -> Option<&String> {
+        self.title.as_ref()
+    }
+    fn subtitle(&self) -> Option<&String> {
+        self.subtitle.as_ref()
+    }
+    fn is_linear_actor(&self) -> Option<&bool> {
+        self.is_linear_actor.as_ref()
+    }
+    fn actor_avatar_url(&self) -> Option<&String> {
+        self.actor_avatar_url.as_ref()
+    }
+    fn actor_initials(&self) -> Option<&String> {
+        self.actor_initials.as_ref()
+    }
+    fn actor_avatar_color(&self) -> Option<&String> {
+        self.actor_avatar_color.as_ref()
+    }
+    fn issue_status_type(&self) -> Option<&String> {
+        self.issue_status_type.as_ref()
+    }
+    fn project_update_health(&self) -> Option<&String> {
+        self.project_update_health.as_ref()
+    }
+    fn initiative_update_health(&self) -> Option<&String> {
+        self.initiative_update_health.as_ref()
+    }
+    fn grouping_key(&self) -> Option<&String> {
+        self.grouping_key.as_ref()
+    }
+    fn grouping_priority(&self) -> Option<&f64> {
+        self.grouping_priority.as_ref()
+    }
+}
+impl NotificationFields for InitiativeNotification {
+    fn r#type(&self) -> Option<&String> {
+        self.r#type.as_ref()
+    }
+    fn read_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.read_at.as_ref()
+    }
+    fn emailed_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.emailed_at.as_ref()
+    }
+    fn snoozed_until_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.snoozed_until_at.as_ref()
+    }
+    fn unsnoozed_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.unsnoozed_at.as_ref()
+    }
+    fn category(&self) -> Option<&NotificationCategory> {
+        self.category.as_ref()
+    }
+    fn url(&self) -> Option<&String> {
+        self.url.as_ref()
+    }
+    fn inbox_url(&self) -> Option<&String> {
+        self.inbox_url.as_ref()
+    }
+    fn title(&self) -> Option<&String> {
+        self.title.as_ref()
+    }
+    fn subtitle(&self) -> Option<&String> {
+        self.subtitle.as_ref()
+    }
+    fn is_linear_actor(&self) -> Option<&bool> {
+        self.is_linear_actor.as_ref()
+    }
+    fn actor_avatar_url(&self) -> Option<&String> {
+        self.actor_avatar_url.as_ref()
+    }
+    fn actor_initials(&self) -> Option<&String> {
+        self.actor_initials.as_ref()
+    }
+    fn actor_avatar_color(&self) -> Option<&String> {
+        self.actor_avatar_color.as_ref()
+    }
+    fn issue_status_type(&self) -> Option<&String> {
+        self.issue_status_type.as_ref()
+    }
+    fn project_update_health(&self) -> Option<&String> {
+        self.project_update_health.as_ref()
+    }
+    fn initiative_update_health(&self) -> Option<&String> {
+        self.initiative_update_health.as_ref()
+    }
+    fn grouping_key(&self) -> Option<&String> {
+        self.grouping_key.as_ref()
+    }
+    fn grouping_priority(&self) -> Option<&f64> {
+        self.grouping_priority.as_ref()
+    }
+}
+impl NotificationFields for IssueNotification {
+    fn r#type(&self) -> Option<&String> {
+        self.r#type.as_ref()
+    }
+    fn read_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.read_at.as_ref()
+    }
+    fn emailed_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.emailed_at.as_ref()
+    }
+    fn snoozed_until_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.snoozed_until_at.as_ref()
+    }
+    fn unsnoozed_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.unsnoozed_at.as_ref()
+    }
+    fn category(&self) -> Option<&NotificationCategory> {
+        self.category.as_ref()
+    }
+    fn url(&self) -> Option<&String> {
+        self.url.as_ref()
+    }
+    fn inbox_url(&self) -> Option<&String> {
+        self.inbox_url.as_ref()
+    }
+    fn title(&self) -> Option<&String> {
+        self.title.as_ref()
+    }
+    fn subtitle(&self) -> Option<&String> {
+        self.subtitle.as_ref()
+    }
+    fn is_linear_actor(&self) -> Option<&bool> {
+        self.is_linear_actor.as_ref()
+    }
+    fn actor_avatar_url(&self) -> Option<&String> {
+        self.actor_avatar_url.as_ref()
+    }
+    fn actor_initials(&self) -> Option<&String> {
+        self.actor_initials.as_ref()
+    }
+    fn actor_avatar_color(&self) -> Option<&String> {
+        self.actor_avatar_color.as_ref()
+    }
+    fn issue_status_type(&self) -> Option<&String> {
+        self.issue_status_type.as_ref()
+    }
+    fn project_update_health(&self) -> Option<&String> {
+        self.project_update_health.as_ref()
+    }
+    fn initiative_update_health(&self) -> Option<&String> {
+        self.initiative_update_health.as_ref()
+    }
+    fn grouping_key(&self) -> Option<&String> {
+        self.grouping_key.as_ref()
+    }
+    fn grouping_priority(&self) -> Option<&f64> {
+        self.grouping_priority.as_ref()
+    }
+}
+impl NotificationFields for OauthClientApprovalNotification {
+    fn r#type(&self) -> Option<&String> {
+        self.r#type.as_ref()
+    }
+    fn read_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.read_at.as_ref()
+    }
+    fn emailed_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.emailed_at.as_ref()
+    }
+    fn snoozed_until_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.snoozed_until_at.as_ref()
+    }
+    fn unsnoozed_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.unsnoozed_at.as_ref()
+    }
+    fn category(&self) -> Option<&NotificationCategory> {
+        self.category.as_ref()
+    }
+    fn url(&self) -> Option<&String> {
+        self.url.as_ref()
+    }
+    fn inbox_url(&self) -> Option<&String> {
+        self.inbox_url.as_ref()
+    }
+    fn title(&self) -> Option<&String> {
+        self.title.as_ref()
+    }
+    fn subtitle(&self) -> Option<&String> {
+        self.subtitle.as_ref()
+    }
+    fn is_linear_actor(&self) -> Option<&bool> {
+        self.is_linear_actor.as_ref()
+    }
+    fn actor_avatar_url(&self) -> Option<&String> {
+        self.actor_avatar_url.as_ref()
+    }
+    fn actor_initials(&self) -> Option<&String> {
+        self.actor_initials.as_ref()
+    }
+    fn actor_avatar_color(&self) -> Option<&String> {
+        self.actor_avatar_color.as_ref()
+    }
+    fn issue_status_type(&self) -> Option<&String> {
+        self.issue_status_type.as_ref()
+    }
+    fn project_update_health(&self) -> Option<&String> {
+        self.project_update_health.as_ref()
+    }
+    fn initiative_update_health(&self) -> Option<&String> {
+        self.initiative_update_health.as_ref()
+    }
+    fn grouping_key(&self) -> Option<&String> {
+        self.grouping_key.as_ref()
+    }
+    fn grouping_priority(&self) -> Option<&f64> {
+        self.grouping_priority.as_ref()
+    }
+}
+impl NotificationFields for PostNotification {
+    fn r#type(&self) -> Option<&String> {
+        self.r#type.as_ref()
+    }
+    fn read_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.read_at.as_ref()
+    }
+    fn emailed_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.emailed_at.as_ref()
+    }
+    fn snoozed_until_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.snoozed_until_at.as_ref()
+    }
+    fn unsnoozed_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.unsnoozed_at.as_ref()
+    }
+    fn category(&self) -> Option<&NotificationCategory> {
+        self.category.as_ref()
+    }
+    fn url(&self) -> Option<&String> {
+        self.url.as_ref()
+    }
+    fn inbox_url(&self) -> Option<&String> {
+        self.inbox_url.as_ref()
+    }
+    fn title(&self) -> Option<&String> {
+        self.title.as_ref()
+    }
+    fn subtitle(&self) -> Option<&String> {
+        self.subtitle.as_ref()
+    }
+    fn is_linear_actor(&self) -> Option<&bool> {
+        self.is_linear_actor.as_ref()
+    }
+    fn actor_avatar_url(&self) -> Option<&String> {
+        self.actor_avatar_url.as_ref()
+    }
+    fn actor_initials(&self) -> Option<&String> {
+        self.actor_initials.as_ref()
+    }
+    fn actor_avatar_color(&self) -> Option<&String> {
+        self.actor_avatar_color.as_ref()
+    }
+    fn issue_status_type(&self) -> Option<&String> {
+        self.issue_status_type.as_ref()
+    }
+    fn project_update_health(&self) -> Option<&String> {
+        self.project_update_health.as_ref()
+    }
+    fn initiative_update_health(&self) -> Option<&String> {
+        self.initiative_update_health.as_ref()
+    }
+    fn grouping_key(&self) -> Option<&String> {
+        self.grouping_key.as_ref()
+    }
+    fn grouping_priority(&self) -> Option<&f64> {
+        self.grouping_priority.as_ref()
+    }
+}
+impl NotificationFields for ProjectNotification {
+    fn r#type(&self) -> Option<&String> {
+        self.r#type.as_ref()
+    }
+    fn read_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.read_at.as_ref()
+    }
+    fn emailed_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.emailed_at.as_ref()
+    }
+    fn snoozed_until_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.snoozed_until_at.as_ref()
+    }
+    fn unsnoozed_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.unsnoozed_at.as_ref()
+    }
+    fn category(&self) -> Option<&NotificationCategory> {
+        self.category.as_ref()
+    }
+    fn url(&self) -> Option<&String> {
+        self.url.as_ref()
+    }
+    fn inbox_url(&self) -> Option<&String> {
+        self.inbox_url.as_ref()
+    }
+    fn title(&self) -> Option<&String> {
+        self.title.as_ref()
+    }
+    fn subtitle(&self) -> Option<&String> {
+        self.subtitle.as_ref()
+    }
+    fn is_linear_actor(&self) -> Option<&bool> {
+        self.is_linear_actor.as_ref()
+    }
+    fn actor_avatar_url(&self) -> Option<&String> {
+        self.actor_avatar_url.as_ref()
+    }
+    fn actor_initials(&self) -> Option<&String> {
+        self.actor_initials.as_ref()
+    }
+    fn actor_avatar_color(&self) -> Option<&String> {
+        self.actor_avatar_color.as_ref()
+    }
+    fn issue_status_type(&self) -> Option<&String> {
+        self.issue_status_type.as_ref()
+    }
+    fn project_update_health(&self) -> Option<&String> {
+        self.project_update_health.as_ref()
+    }
+    fn initiative_update_health(&self) -> Option<&String> {
+        self.initiative_update_health.as_ref()
+    }
+    fn grouping_key(&self) -> Option<&String> {
+        self.grouping_key.as_ref()
+    }
+    fn grouping_priority(&self) -> Option<&f64> {
+        self.grouping_priority.as_ref()
+    }
+}
+impl NotificationFields for PullRequestNotification {
+    fn r#type(&self) -> Option<&String> {
+        self.r#type.as_ref()
+    }
+    fn read_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.read_at.as_ref()
+    }
+    fn emailed_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.emailed_at.as_ref()
+    }
+    fn snoozed_until_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.snoozed_until_at.as_ref()
+    }
+    fn unsnoozed_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.unsnoozed_at.as_ref()
+    }
+    fn category(&self) -> Option<&NotificationCategory> {
+        self.category.as_ref()
+    }
+    fn url(&self) -> Option<&String> {
+        self.url.as_ref()
+    }
+    fn inbox_url(&self) -> Option<&String> {
+        self.inbox_url.as_ref()
+    }
+    fn title(&self) -> Option<&String> {
+        self.title.as_ref()
+    }
+    fn subtitle(&self) -> Option<&String> {
+        self.subtitle.as_ref()
+    }
+    fn is_linear_actor(&self) -> Option<&bool> {
+        self.is_linear_actor.as_ref()
+    }
+    fn actor_avatar_url(&self) -> Option<&String> {
+        self.actor_avatar_url.as_ref()
+    }
+    fn actor_initials(&self) -> Option<&String> {
+        self.actor_initials.as_ref()
+    }
+    fn actor_avatar_color(&self) -> Option<&String> {
+        self.actor_avatar_color.as_ref()
+    }
+    fn issue_status_type(&self) -> Option<&String> {
+        self.issue_status_type.as_ref()
+    }
+    fn project_update_health(&self) -> Option<&String> {
+        self.project_update_health.as_ref()
+    }
+    fn initiative_update_health(&self) -> Option<&String> {
+        self.initiative_update_health.as_ref()
+    }
+    fn grouping_key(&self) -> Option<&String> {
+        self.grouping_key.as_ref()
+    }
+    fn grouping_priority(&self) -> Option<&f64> {
+        self.grouping_priority.as_ref()
+    }
+}
+impl NotificationFields for WelcomeMessageNotification {
+    fn r#type(&self) -> Option<&String> {
+        self.r#type.as_ref()
+    }
+    fn read_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.read_at.as_ref()
+    }
+    fn emailed_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.emailed_at.as_ref()
+    }
+    fn snoozed_until_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.snoozed_until_at.as_ref()
+    }
+    fn unsnoozed_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        self.unsnoozed_at.as_ref()
+    }
+    fn category(&self) -> Option<&NotificationCategory> {
+        self.category.as_ref()
+    }
+    fn url(&self) -> Option<&String> {
+        self.url.as_ref()
+    }
+    fn inbox_url(&self) -> Option<&String> {
+        self.inbox_url.as_ref()
+    }
+    fn title(&self) -> Option<&String> {
+        self.title.as_ref()
+    }
+    fn subtitle(&self) -> Option<&String> {
+        self.subtitle.as_ref()
+    }
+    fn is_linear_actor(&self) -> Option<&bool> {
+        self.is_linear_actor.as_ref()
+    }
+    fn actor_avatar_url(&self) -> Option<&String> {
+        self.actor_avatar_url.as_ref()
+    }
+    fn actor_initials(&self) -> Option<&String> {
+        self.actor_initials.as_ref()
+    }
+    fn actor_avatar_color(&self) -> Option<&String> {
+        self.actor_avatar_color.as_ref()
+    }
+    fn issue_status_type(&self) -> Option<&String> {
+        self.issue_status_type.as_ref()
+    }
+    fn project_update_health(&self) -> Option<&String> {
+        self.project_update_health.as_ref()
+    }
+    fn initiative_update_health(&self) -> Option<&String> {
+        self.initiative_update_health.as_ref()
+    }
+    fn grouping_key(&self) -> Option<&String> {
+        self.grouping_key.as_ref()
+    }
+    fn grouping_priority(&self) -> Option<&f64> {
+        self.grouping_priority.as_ref()
+    }
+}
+impl EntityFields for Notification {
+    fn created_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        match self {
+            Self::CustomerNeedNotification(v) => EntityFields::created_at(&**v),
+            Self::CustomerNotification(v) => EntityFields::created_at(&**v),
+            Self::DocumentNotification(v) => EntityFields::created_at(&**v),
+            Self::InitiativeNotification(v) => EntityFields::created_at(&**v),
+            Self::IssueNotification(v) => EntityFields::created_at(&**v),
+            Self::OauthClientApprovalNotification(v) => EntityFields::created_at(&**v),
+            Self::PostNotification(v) => EntityFields::created_at(&**v),
+            Self::ProjectNotification(v) => EntityFields::created_at(&**v),
+            Self::PullRequestNotification(v) => EntityFields::created_at(&**v),
+            Self::WelcomeMessageNotification(v) => EntityFields::created_at(&**v),
+            Self::Unknown => None,
+        }
+    }
+    fn updated_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        match self {
+            Self::CustomerNeedNotification(v) => EntityFields::updated_at(&**v),
+            Self::CustomerNotification(v) => EntityFields::updated_at(&**v),
+            Self::DocumentNotification(v) => EntityFields::updated_at(&**v),
+            Self::InitiativeNotification(v) => EntityFields::updated_at(&**v),
+            Self::IssueNotification(v) => EntityFields::updated_at(&**v),
+            Self::OauthClientApprovalNotification(v) => EntityFields::updated_at(&**v),
+            Self::PostNotification(v) => EntityFields::updated_at(&**v),
+            Self::ProjectNotification(v) => EntityFields::updated_at(&**v),
+            Self::PullRequestNotification(v) => EntityFields::updated_at(&**v),
+            Self::WelcomeMessageNotification(v) => EntityFields::updated_at(&**v),
+            Self::Unknown => None,
+        }
+    }
+    fn archived_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        match self {
+            Self::CustomerNeedNotification(v) => EntityFields::archived_at(&**v),
+            Self::CustomerNotification(v) => EntityFields::archived_at(&**v),
+            Self::DocumentNotification(v) => EntityFields::archived_at(&**v),
+            Self::InitiativeNotification(v) => EntityFields::archived_at(&**v),
+            Self::IssueNotification(v) => EntityFields::archived_at(&**v),
+            Self::OauthClientApprovalNotification(v) => EntityFields::archived_at(&**v),
+            Self::PostNotification(v) => EntityFields::archived_at(&**v),
+            Self::ProjectNotification(v) => EntityFields::archived_at(&**v),
+            Self::PullRequestNotification(v) => EntityFields::archived_at(&**v),
+            Self::WelcomeMessageNotification(v) => EntityFields::archived_at(&**v),
+            Self::Unknown => None,
+        }
+    }
+}
+impl NodeFields for Notification {
+    fn id(&self) -> Option<&String> {
+        match self {
+            Self::CustomerNeedNotification(v) => NodeFields::id(&**v),
+            Self::CustomerNotification(v) => NodeFields::id(&**v),
+            Self::DocumentNotification(v) => NodeFields::id(&**v),
+            Self::InitiativeNotification(v) => NodeFields::id(&**v),
+            Self::IssueNotification(v) => NodeFields::id(&**v),
+            Self::OauthClientApprovalNotification(v) => NodeFields::id(&**v),
+            Self::PostNotification(v) => NodeFields::id(&**v),
+            Self::ProjectNotification(v) => NodeFields::id(&**v),
+            Self::PullRequestNotification(v) => NodeFields::id(&**v),
+            Self::WelcomeMessageNotification(v) => NodeFields::id(&**v),
+            Self::Unknown => None,
+        }
+    }
+}
+impl NotificationFields for Notification {
+    fn r#type(&self) -> Option<&String> {
+        match self {
+            Self::CustomerNeedNotification(v) => NotificationFields::r#type(&**v),
+            Self::CustomerNotification(v) => NotificationFields::r#type(&**v),
+            Self::DocumentNotification(v) => NotificationFields::r#type(&**v),
+            Self::InitiativeNotification(v) => NotificationFields::r#type(&**v),
+            Self::IssueNotification(v) => NotificationFields::r#type(&**v),
+            Self::OauthClientApprovalNotification(v) => NotificationFields::r#type(&**v),
+            Self::PostNotification(v) => NotificationFields::r#type(&**v),
+            Self::ProjectNotification(v) => NotificationFields::r#type(&**v),
+            Self::PullRequestNotification(v) => NotificationFields::r#type(&**v),
+            Self::WelcomeMessageNotification(v) => NotificationFields::r#type(&**v),
+            Self::Unknown => None,
+        }
+    }
+    fn read_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        match self {
+            Self::CustomerNeedNotification(v) => NotificationFields::read_at(&**v),
+            Self::CustomerNotification(v) => NotificationFields::read_at(&**v),
+            Self::DocumentNotification(v) => NotificationFields::read_at(&**v),
+            Self::InitiativeNotification(v) => NotificationFields::read_at(&**v),
+            Self::IssueNotification(v) => NotificationFields::read_at(&**v),
+            Self::OauthClientApprovalNotification(v) => NotificationFields::read_at(&**v),
+            Self::PostNotification(v) => NotificationFields::read_at(&**v),
+            Self::ProjectNotification(v) => NotificationFields::read_at(&**v),
+            Self::PullRequestNotification(v) => NotificationFields::read_at(&**v),
+            Self::WelcomeMessageNotification(v) => NotificationFields::read_at(&**v),
+            Self::Unknown => None,
+        }
+    }
+    fn emailed_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        match self {
+            Self::CustomerNeedNotification(v) => NotificationFields::emailed_at(&**v),
+            Self::CustomerNotification(v) => NotificationFields::emailed_at(&**v),
+            Self::DocumentNotification(v) => NotificationFields::emailed_at(&**v),
+            Self::InitiativeNotification(v) => NotificationFields::emailed_at(&**v),
+            Self::IssueNotification(v) => NotificationFields::emailed_at(&**v),
+            Self::OauthClientApprovalNotification(v) => NotificationFields::emailed_at(&**v),
+            Self::PostNotification(v) => NotificationFields::emailed_at(&**v),
+            Self::ProjectNotification(v) => NotificationFields::emailed_at(&**v),
+            Self::PullRequestNotification(v) => NotificationFields::emailed_at(&**v),
+            Self::WelcomeMessageNotification(v) => NotificationFields::emailed_at(&**v),
+            Self::Unknown => None,
+        }
+    }
+    fn snoozed_until_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        match self {
+            Self::CustomerNeedNotification(v) => NotificationFields::snoozed_until_at(&**v),
+            Self::CustomerNotification(v) => NotificationFields::snoozed_until_at(&**v),
+            Self::DocumentNotification(v) => NotificationFields::snoozed_until_at(&**v),
+            Self::InitiativeNotification(v) => NotificationFields::snoozed_until_at(&**v),
+            Self::IssueNotification(v) => NotificationFields::snoozed_until_at(&**v),
+            Self::OauthClientApprovalNotification(v) => NotificationFields::snoozed_until_at(&**v),
+            Self::PostNotification(v) => NotificationFields::snoozed_until_at(&**v),
+            Self::ProjectNotification(v) => NotificationFields::snoozed_until_at(&**v),
+            Self::PullRequestNotification(v) => NotificationFields::snoozed_until_at(&**v),
+            Self::WelcomeMessageNotification(v) => NotificationFields::snoozed_until_at(&**v),
+            Self::Unknown => None,
+        }
+    }
+    fn unsnoozed_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        match self {
+            Self::CustomerNeedNotification(v) => NotificationFields::unsnoozed_at(&**v),
+            Self::CustomerNotification(v) => NotificationFields::unsnoozed_at(&**v),
+            Self::DocumentNotification(v) => NotificationFields::unsnoozed_at(&**v),
+            Self::InitiativeNotification(v) => NotificationFields::unsnoozed_at(&**v),
+            Self::IssueNotification(v) => NotificationFields::unsnoozed_at(&**v),
+            Self::OauthClientApprovalNotification(v) => NotificationFields::unsnoozed_at(&**v),
+            Self::PostNotification(v) => NotificationFields::unsnoozed_at(&**v),
+            Self::ProjectNotification(v) => NotificationFields::unsnoozed_at(&**v),
+            Self::PullRequestNotification(v) => NotificationFields::unsnoozed_at(&**v),
+            Self::WelcomeMessageNotification(v) => NotificationFields::unsnoozed_at(&**v),
+            Self::Unknown => None,
+        }
+    }
+    fn category(&self) -> Option<&NotificationCategory> {
+        match self {
+            Self::CustomerNeedNotification(v) => NotificationFields::category(&**v),
+            Self::CustomerNotification(v) => NotificationFields::category(&**v),
+            Self::DocumentNotification(v) => NotificationFields::category(&**v),
+            Self::InitiativeNotification(v) => NotificationFields::category(&**v),
+            Self::IssueNotification(v) => NotificationFields::category(&**v),
+            Self::OauthClientApprovalNotification(v) => NotificationFields::category(&**v),
+            Self::PostNotification(v) => NotificationFields::category(&**v),
+            Self::ProjectNotification(v) => NotificationFields::category(&**v),
+            Self::PullRequestNotification(v) => NotificationFields::category(&**v),
+            Self::WelcomeMessageNotification(v) => NotificationFields::category(&**v),
+            Self::Unknown => None,
+        }
+    }
+    fn url(&self) -> Option<&String> {
+        match self {
+            Self::CustomerNeedNotification(v) => NotificationFields::url(&**v),
+            Self::CustomerNotification(v) => NotificationFields::url(&**v),
+            Self::DocumentNotification(v) => NotificationFields::url(&**v),
+            Self::InitiativeNotification(v) => NotificationFields::url(&**v),
+            Self::IssueNotification(v) => NotificationFields::url(&**v),
+            Self::OauthClientApprovalNotification(v) => NotificationFields::url(&**v),
+            Self::PostNotification(v) => NotificationFields::url(&**v),
+            Self::ProjectNotification(v) => NotificationFields::url(&**v),
+            Self::PullRequestNotification(v) => NotificationFields::url(&**v),
+            Self::WelcomeMessageNotification(v) => NotificationFields::url(&**v),
+            Self::Unknown => None,
+        }
+    }
+    fn inbox_url(&self) -> Option<&String> {
+        match self {
+            Self::CustomerNeedNotification(v) => NotificationFields::inbox_url(&**v),
+            Self::CustomerNotification(v) => NotificationFields::inbox_url(&**v),
+            Self::DocumentNotification(v) => NotificationFields::inbox_url(&**v),
+            Self::InitiativeNotification(v) => NotificationFields::inbox_url(&**v),
+            Self::IssueNotification(v) => NotificationFields::inbox_url(&**v),
+            Self::OauthClientApprovalNotification(v) => NotificationFields::inbox_url(&**v),
+            Self::PostNotification(v) => NotificationFields::inbox_url(&**v),
+            Self::ProjectNotification(v) => NotificationFields::inbox_url(&**v),
+            Self::PullRequestNotification(v) => NotificationFields::inbox_url(&**v),
+            Self::WelcomeMessageNotification(v) => NotificationFields::inbox_url(&**v),
+            Self::Unknown => None,
+        }
+    }
+    fn title(&self) -> Option<&String> {
+        match self {
+            Self::CustomerNeedNotification(v) => NotificationFields::title(&**v),
+            Self::CustomerNotification(v) => NotificationFields::title(&**v),
+            Self::DocumentNotification(v) => NotificationFields::title(&**v),
+            Self::InitiativeNotification(v) => NotificationFields::title(&**v),
+            Self::IssueNotification(v) => NotificationFields::title(&**v),
+            Self::OauthClientApprovalNotification(v) => NotificationFields::title(&**v),
+            Self::PostNotification(v) => NotificationFields::title(&**v),
+            Self::ProjectNotification(v) => NotificationFields::title(&**v),
+            Self::PullRequestNotification(v) => NotificationFields::title(&**v),
+            Self::WelcomeMessageNotification(v) => NotificationFields::title(&**v),
+            Self::Unknown => None,
+        }
+    }
+    fn subtitle(&self) -> Option<&String> {
+        match self {
+            Self::CustomerNeedNotification(v) => NotificationFields::subtitle(&**v),
+            Self::CustomerNotification(v) => NotificationFields::subtitle(&**v),
+            Self::DocumentNotification(v) => NotificationFields::subtitle(&**v),
+            Self::InitiativeNotification(v) => NotificationFields::subtitle(&**v),
+            Self::IssueNotification(v) => NotificationFields::subtitle(&**v),
+            Self::OauthClientApprovalNotification(v) => NotificationFields::subtitle(&**v),
+            Self::PostNotification(v) => NotificationFields::subtitle(&**v),
+            Self::ProjectNotification(v) => NotificationFields::subtitle(&**v),
+            Self::PullRequestNotification(v) => NotificationFields::subtitle(&**v),
+            Self::WelcomeMessageNotification(v) => NotificationFields::subtitle(&**v),
+            Self::Unknown => None,
+        }
+    }
+    fn is_linear_actor(&self) -> Option<&bool> {
+        match self {
+            Self::CustomerNeedNotification(v) => NotificationFields::is_linear_actor(&**v),
+            Self::CustomerNotification(v) => NotificationFields::is_linear_actor(&**v),
+            Self::DocumentNotification(v) => NotificationFields::is_linear_actor(&**v),
+            Self::InitiativeNotification(v) => NotificationFields::is_linear_actor(&**v),
+            Self::IssueNotification(v) => NotificationFields::is_linear_actor(&**v),
+            Self::OauthClientApprovalNotification(v) => NotificationFields::is_linear_actor(&**v),
+            Self::PostNotification(v) => NotificationFields::is_linear_actor(&**v),
+            Self::ProjectNotification(v) => NotificationFields::is_linear_actor(&**v),
+            Self::PullRequestNotification(v) => NotificationFields::is_linear_actor(&**v),
+            Self::WelcomeMessageNotification(v) => NotificationFields::is_linear_actor(&**v),
+            Self::Unknown => None,
+        }
+    }
+    fn actor_avatar_url(&self) -> Option<&String> {
+        match self {
+            Self::CustomerNeedNotification(v) => NotificationFields::actor_avatar_url(&**v),
+            Self::CustomerNotification(v) => NotificationFields::actor_avatar_url(&**v),
+            Self::DocumentNotification(v) => NotificationFields::actor_avatar_url(&**v),
+            Self::InitiativeNotification(v) => NotificationFields::actor_avatar_url(&**v),
+            Self::IssueNotification(v) => NotificationFields::actor_avatar_url(&**v),
+            Self::OauthClientApprovalNotification(v) => NotificationFields::actor_avatar_url(&**v),
+            Self::PostNotification(v) => NotificationFields::actor_avatar_url(&**v),
+            Self::ProjectNotification(v) => NotificationFields::actor_avatar_url(&**v),
+            Self::PullRequestNotification(v) => NotificationFields::actor_avatar_url(&**v),
+            Self::WelcomeMessageNotification(v) => NotificationFields::actor_avatar_url(&**v),
+            Self::Unknown => None,
+        }
+    }
+    fn actor_initials(&self) -> Option<&String> {
+        match self {
+            Self::CustomerNeedNotification(v) => NotificationFields::actor_initials(&**v),
+            Self::CustomerNotification(v) => NotificationFields::actor_initials(&**v),
+            Self::DocumentNotification(v) => NotificationFields::actor_initials(&**v),
+            Self::InitiativeNotification(v) => NotificationFields::actor_initials(&**v),
+            Self::IssueNotification(v) => NotificationFields::actor_initials(&**v),
+            Self::OauthClientApprovalNotification(v) => NotificationFields::actor_initials(&**v),
+            Self::PostNotification(v) => NotificationFields::actor_initials(&**v),
+            Self::ProjectNotification(v) => NotificationFields::actor_initials(&**v),
+            Self::PullRequestNotification(v) => NotificationFields::actor_initials(&**v),
+            Self::WelcomeMessageNotification(v) => NotificationFields::actor_initials(&**v),
+            Self::Unknown => None,
+        }
+    }
+    fn actor_avatar_color(&self) -> Option<&String> {
+        match self {
+            Self::CustomerNeedNotification(v) => NotificationFields::actor_avatar_color(&**v),
+            Self::CustomerNotification(v) => NotificationFields::actor_avatar_color(&**v),
+            Self::DocumentNotification(v) => NotificationFields::actor_avatar_color(&**v),
+            Self::InitiativeNotification(v) => NotificationFields::actor_avatar_color(&**v),
+            Self::IssueNotification(v) => NotificationFields::actor_avatar_color(&**v),
+            Self::OauthClientApprovalNotification(v) => {
+                NotificationFields::actor_avatar_color(&**v)
+            }
+            Self::PostNotification(v) => NotificationFields::actor_avatar_color(&**v),
+            Self::ProjectNotification(v) => NotificationFields::actor_avatar_color(&**v),
+            Self::PullRequestNotification(v) => NotificationFields::actor_avatar_color(&**v),
+            Self::WelcomeMessageNotification(v) => NotificationFields::actor_avatar_color(&**v),
+            Self::Unknown => None,
+        }
+    }
+    fn issue_status_type(&self) -> Option<&String> {
+        match self {
+            Self::CustomerNeedNotification(v) => NotificationFields::issue_status_type(&**v),
+            Self::CustomerNotification(v) => NotificationFields::issue_status_type(&**v),
+            Self::DocumentNotification(v) => NotificationFields::issue_status_type(&**v),
+            Self::InitiativeNotification(v) => NotificationFields::issue_status_type(&**v),
+            Self::IssueNotification(v) => NotificationFields::issue_status_type(&**v),
+            Self::OauthClientApprovalNotification(v) => NotificationFields::issue_status_type(&**v),
+            Self::PostNotification(v) => NotificationFields::issue_status_type(&**v),
+            Self::ProjectNotification(v) => NotificationFields::issue_status_type(&**v),
+            Self::PullRequestNotification(v) => NotificationFields::issue_status_type(&**v),
+            Self::WelcomeMessageNotification(v) => NotificationFields::issue_status_type(&**v),
+            Self::Unknown => None,
+        }
+    }
+    fn project_update_health(&self) -> Option<&String> {
+        match self {
+            Self::CustomerNeedNotification(v) => NotificationFields::project_update_health(&**v),
+            Self::CustomerNotification(v) => NotificationFields::project_update_health(&**v),
+            Self::DocumentNotification(v) => NotificationFields::project_update_health(&**v),
+            Self::InitiativeNotification(v) => NotificationFields::project_update_health(&**v),
+            Self::IssueNotification(v) => NotificationFields::project_update_health(&**v),
+            Self::OauthClientApprovalNotification(v) => {
+                NotificationFields::project_update_health(&**v)
+            }
+            Self::PostNotification(v) => NotificationFields::project_update_health(&**v),
+            Self::ProjectNotification(v) => NotificationFields::project_update_health(&**v),
+            Self::PullRequestNotification(v) => NotificationFields::project_update_health(&**v),
+            Self::WelcomeMessageNotification(v) => NotificationFields::project_update_health(&**v),
+            Self::Unknown => None,
+        }
+    }
+    fn initiative_update_health(&self) -> Option<&String> {
+        match self {
+            Self::CustomerNeedNotification(v) => NotificationFields::initiative_update_health(&**v),
+            Self::CustomerNotification(v) => NotificationFields::initiative_update_health(&**v),
+            Self::DocumentNotification(v) => NotificationFields::initiative_update_health(&**v),
+            Self::InitiativeNotification(v) => NotificationFields::initiative_update_health(&**v),
+            Self::IssueNotification(v) => NotificationFields::initiative_update_health(&**v),
+            Self::OauthClientApprovalNotification(v) => {
+                NotificationFields::initiative_update_health(&**v)
+            }
+            Self::PostNotification(v) => NotificationFields::initiative_update_health(&**v),
+            Self::ProjectNotification(v) => NotificationFields::initiative_update_health(&**v),
+            Self::PullRequestNotification(v) => NotificationFields::initiative_update_health(&**v),
+            Self::WelcomeMessageNotification(v) => {
+                NotificationFields::initiative_update_health(&**v)
+            }
+            Self::Unknown => None,
+        }
+    }
+    fn grouping_key(&self) -> Option<&String> {
+        match self {
+            Self::CustomerNeedNotification(v) => NotificationFields::grouping_key(&**v),
+            Self::CustomerNotification(v) => NotificationFields::grouping_key(&**v),
+            Self::DocumentNotification(v) => NotificationFields::grouping_key(&**v),
+            Self::InitiativeNotification(v) => NotificationFields::grouping_key(&**v),
+            Self::IssueNotification(v) => NotificationFields::grouping_key(&**v),
+            Self::OauthClientApprovalNotification(v) => NotificationFields::grouping_key(&**v),
+            Self::PostNotification(v) => NotificationFields::grouping_key(&**v),
+            Self::ProjectNotification(v) => NotificationFields::grouping_key(&**v),
+            Self::PullRequestNotification(v) => NotificationFields::grouping_key(&**v),
+            Self::WelcomeMessageNotification(v) => NotificationFields::grouping_key(&**v),
+            Self::Unknown => None,
+        }
+    }
+    fn grouping_priority(&self) -> Option<&f64> {
+        match self {
+            Self::CustomerNeedNotification(v) => NotificationFields::grouping_priority(&**v),
+            Self::CustomerNotification(v) => NotificationFields::grouping_priority(&**v),
+            Self::DocumentNotification(v) => NotificationFields::grouping_priority(&**v),
+            Self::InitiativeNotification(v) => NotificationFields::grouping_priority(&**v),
+            Self::IssueNotification(v) => NotificationFields::grouping_priority(&**v),
+            Self::OauthClientApprovalNotification(v) => NotificationFields::grouping_priority(&**v),
+            Self::PostNotification(v) => NotificationFields::grouping_priority(&**v),
+            Self::ProjectNotification(v) => NotificationFields::grouping_priority(&**v),
+            Self::PullRequestNotification(v) => NotificationFields::grouping_priority(&**v),
+            Self::WelcomeMessageNotification(v) => NotificationFields::grouping_priority(&**v),
+            Self::Unknown => None,
+        }
+    }
+}
+/// A subscription that controls which notifications a user receives for a specific entity such as a team, project, cycle, label, custom view, initiative, or user. This is not a billing subscription -- it determines notification preferences. Each subscription is scoped to exactly one target entity and specifies the notification types the subscriber wants to receive. When active, matching events on the target entity generate notifications for the subscriber.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "__typename")]
+#[non_exhaustive]
+pub enum NotificationSubscription {
+    CustomViewNotificationSubscription(Box<CustomViewNotificationSubscription>),
+    CustomerNotificationSubscription(Box<CustomerNotificationSubscription>),
+    CycleNotificationSubscription(Box<CycleNotificationSubscription>),
+    InitiativeNotificationSubscription(Box<InitiativeNotificationSubscription>),
+    LabelNotificationSubscription(Box<LabelNotificationSubscription>),
+    ProjectNotificationSubscription(Box<ProjectNotificationSubscription>),
+    TeamNotificationSubscription(Box<TeamNotificationSubscription>),
+    UserNotificationSubscription(Box<UserNotificationSubscription>),
+    /// Catch-all for object types unknown to this SDK version.
+    #[serde(other)]
+    Unknown,
+}
+impl GraphQLFields for NotificationSubscription {
+    type FullType = Self;
+    fn selection() -> String {
+        crate::field_selection::inline_fragments(&[
+            (
+                "CustomViewNotificationSubscription",
+                <CustomViewNotificationSubscription as GraphQLFields>::selection(),
+            ),
+            (
+                "CustomerNotificationSubscription",
+                <CustomerNotificationSubscription as GraphQLFields>::selection(),
+            ),
+            (
+                "CycleNotificationSubscription",
+                <CycleNotificationSubscription as GraphQLFields>::selection(),
+            ),
+            (
+                "InitiativeNotificationSubscription",
+                <InitiativeNotificationSubscription as GraphQLFields>::selection(),
+            ),
+            (
+                "LabelNotificationSubscription",
+                <LabelNotificationSubscription as GraphQLFields>::selection(),
+            ),
+            (
+                "ProjectNotificationSubscription",
+                <ProjectNotificationSubscription as GraphQLFields>::selection(),
+            ),
+            (
+                "TeamNotificationSubscription",
+                <TeamNotificationSubscription as GraphQLFields>::selection(),
+            ),
+            (
+                "UserNotificationSubscription",
+                <UserNotificationSubscription as GraphQLFields>::selection(),
+            ),
+        ])
+    }
+}
+/// Fields shared by every [`NotificationSubscription`] type. Each returns `None` when not selected.
+pub trait NotificationSubscriptionFields: EntityFields + NodeFields {
+    /// The type of contextual view (e.g., active issues, backlog) that further scopes a team notification subscription. Null if the subscription is not associated with a specific view type.
+    fn context_view_type(&self) -> Option<&ContextViewType>;
+    /// The type of user-specific view that further scopes a user notification subscription. Null if the subscription is not associated with a user view type.
+    fn user_context_view_type(&self) -> Option<&UserContextViewType>;
+    /// Whether the subscription is active. When inactive, no notifications are generated from this subscription even though it still exists.
+    fn active(&self) -> Option<&bool>;
+}
+impl NotificationSubscriptionFields for CustomViewNotificationSubscription {
+    fn context_view_type(&self) -> Option<&ContextViewType> {
+        self.context_view_type.as_ref()
+    }
+    fn user_context_view_type(&self) -> Option<&UserContextViewType> {
+        self.user_context_view_type.as_ref()
+    }
+    fn active(&self) -> Option<&bool> {
+        self.active.as_ref()
+    }
+}
+impl NotificationSubscriptionFields for CustomerNotificationSubscription {
+    fn context_view_type(&self) -> Option<&ContextViewType> {
+        self.context_view_type.as_ref()
+    }
+    fn user_context_view_type(&self) -> Option<&UserContextViewType> {
+        self.user_context_view_type.as_ref()
+    }
+    fn active(&self) -> Option<&bool> {
+        self.active.as_ref()
+    }
+}
+impl NotificationSubscriptionFields for CycleNotificationSubscription {
+    fn context_view_type(&self) -> Option<&ContextViewType> {
+        self.context_view_type.as_ref()
+    }
+    fn user_context_view_type(&self) -> Option<&UserContextViewType> {
+        self.user_context_view_type.as_ref()
+    }
+    fn active(&self) -> Option<&bool> {
+        self.active.as_ref()
+    }
+}
+impl NotificationSubscriptionFields for InitiativeNotificationSubscription {
+    fn context_view_type(&self) -> Option<&ContextViewType> {
+        self.context_view_type.as_ref()
+    }
+    fn user_context_view_type(&self) -> Option<&UserContextViewType> {
+        self.user_context_view_type.as_ref()
+    }
+    fn active(&self) -> Option<&bool> {
+        self.active.as_ref()
+    }
+}
+impl NotificationSubscriptionFields for LabelNotificationSubscription {
+    fn context_view_type(&self) -> Option<&ContextViewType> {
+        self.context_view_type.as_ref()
+    }
+    fn user_context_view_type(&self) -> Option<&UserContextViewType> {
+        self.user_context_view_type.as_ref()
+    }
+    fn active(&self) -> Option<&bool> {
+        self.active.as_ref()
+    }
+}
+impl NotificationSubscriptionFields for ProjectNotificationSubscription {
+    fn context_view_type(&self) -> Option<&ContextViewType> {
+        self.context_view_type.as_ref()
+    }
+    fn user_context_view_type(&self) -> Option<&UserContextViewType> {
+        self.user_context_view_type.as_ref()
+    }
+    fn active(&self) -> Option<&bool> {
+        self.active.as_ref()
+    }
+}
+impl NotificationSubscriptionFields for TeamNotificationSubscription {
+    fn context_view_type(&self) -> Option<&ContextViewType> {
+        self.context_view_type.as_ref()
+    }
+    fn user_context_view_type(&self) -> Option<&UserContextViewType> {
+        self.user_context_view_type.as_ref()
+    }
+    fn active(&self) -> Option<&bool> {
+        self.active.as_ref()
+    }
+}
+impl NotificationSubscriptionFields for UserNotificationSubscription {
+    fn context_view_type(&self) -> Option<&ContextViewType> {
+        self.context_view_type.as_ref()
+    }
+    fn user_context_view_type(&self) -> Option<&UserContextViewType> {
+        self.user_context_view_type.as_ref()
+    }
+    fn active(&self) -> Option<&bool> {
+        self.active.as_ref()
+    }
+}
+impl EntityFields for NotificationSubscription {
+    fn created_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        match self {
+            Self::CustomViewNotificationSubscription(v) => EntityFields::created_at(&**v),
+            Self::CustomerNotificationSubscription(v) => EntityFields::created_at(&**v),
+            Self::CycleNotificationSubscription(v) => EntityFields::created_at(&**v),
+            Self::InitiativeNotificationSubscription(v) => EntityFields::created_at(&**v),
+            Self::LabelNotificationSubscription(v) => EntityFields::created_at(&**v),
+            Self::ProjectNotificationSubscription(v) => EntityFields::created_at(&**v),
+            Self::TeamNotificationSubscription(v) => EntityFields::created_at(&**v),
+            Self::UserNotificationSubscription(v) => EntityFields::created_at(&**v),
+            Self::Unknown => None,
+        }
+    }
+    fn updated_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        match self {
+            Self::CustomViewNotificationSubscription(v) => EntityFields::updated_at(&**v),
+            Self::CustomerNotificationSubscription(v) => EntityFields::updated_at(&**v),
+            Self::CycleNotificationSubscription(v) => EntityFields::updated_at(&**v),
+            Self::InitiativeNotificationSubscription(v) => EntityFields::updated_at(&**v),
+            Self::LabelNotificationSubscription(v) => EntityFields::updated_at(&**v),
+            Self::ProjectNotificationSubscription(v) => EntityFields::updated_at(&**v),
+            Self::TeamNotificationSubscription(v) => EntityFields::updated_at(&**v),
+            Self::UserNotificationSubscription(v) => EntityFields::updated_at(&**v),
+            Self::Unknown => None,
+        }
+    }
+    fn archived_at(&self) -> Option<&chrono::DateTime<chrono::Utc>> {
+        match self {
+            Self::CustomViewNotificationSubscription(v) => EntityFields::archived_at(&**v),
+            Self::CustomerNotificationSubscription(v) => EntityFields::archived_at(&**v),
+            Self::CycleNotificationSubscription(v) => EntityFields::archived_at(&**v),
+            Self::InitiativeNotificationSubscription(v) => EntityFields::archived_at(&**v),
+            Self::LabelNotificationSubscription(v) => EntityFields::archived_at(&**v),
+            Self::ProjectNotificationSubscription(v) => EntityFields::archived_at(&**v),
+            Self::TeamNotificationSubscription(v) => EntityFields::archived_at(&**v),
+            Self::UserNotificationSubscription(v) => EntityFields::archived_at(&**v),
+            Self::Unknown => None,
+        }
+    }
+}
+impl NodeFields for NotificationSubscription {
+    fn id(&self) -> Option<&String> {
+        match self {
+            Self::CustomViewNotificationSubscription(v) => NodeFields::id(&**v),
+            Self::CustomerNotificationSubscription(v) => NodeFields::id(&**v),
+            Self::CycleNotificationSubscription(v) => NodeFields::id(&**v),
+            Self::InitiativeNotificationSubscription(v) => NodeFields::id(&**v),
+            Self::LabelNotificationSubscription(v) => NodeFields::id(&**v),
+            Self::ProjectNotificationSubscription(v) => NodeFields::id(&**v),
+            Self::TeamNotificationSubscription(v) => NodeFields::id(&**v),
+            Self::UserNotificationSubscription(v) => NodeFields::id(&**v),
+            Self::Unknown => None,
+        }
+    }
+}
+impl NotificationSubscriptionFields for NotificationSubscription {
+    fn context_view_type(&self) -> Option<&ContextViewType> {
+        match self {
+            Self::CustomViewNotificationSubscription(v) => {
+                NotificationSubscriptionFields::context_view_type(&**v)
+            }
+            Self::CustomerNotificationSubscription(v) => {
+                NotificationSubscriptionFields::context_view_type(&**v)
+            }
+            Self::CycleNotificationSubscription(v) => {
+                NotificationSubscriptionFields::context_view_type(&**v)
+            }
+            Self::InitiativeNotificationSubscription(v) => {
+                NotificationSubscriptionFields::context_view_type(&**v)
+            }
+            Self::LabelNotificationSubscription(v) => {
+                NotificationSubscriptionFields::context_view_type(&**v)
+            }
+            Self::ProjectNotificationSubscription(v) => {
+                NotificationSubscriptionFields::context_view_type(&**v)
+            }
+            Self::TeamNotificationSubscription(v) => {
+                NotificationSubscriptionFields::context_view_type(&**v)
+            }
+            Self::UserNotificationSubscription(v) => {
+                NotificationSubscriptionFields::context_view_type(&**v)
+            }
+            Self::Unknown => None,
+        }
+    }
+    fn user_context_view_type(&self) -> Option<&UserContextViewType> {
+        match self {
+            Self::CustomViewNotificationSubscription(v) => {
+                NotificationSubscriptionFields::user_context_view_type(&**v)
+            }
+            Self::CustomerNotificationSubscription(v) => {
+                NotificationSubscriptionFields::user_context_view_type(&**v)
+            }
+            Self::CycleNotificationSubscription(v) => {
+                NotificationSubscriptionFields::user_context_view_type(&**v)
+            }
+            Self::InitiativeNotificationSubscription(v) => {
+                NotificationSubscriptionFields::user_context_view_type(&**v)
+            }
+            Self::LabelNotificationSubscription(v) => {
+                NotificationSubscriptionFields::user_context_view_type(&**v)
+            }
+            Self::ProjectNotificationSubscription(v) => {
+                NotificationSubscriptionFields::user_context_view_type(&**v)
+            }
+            Self::TeamNotificationSubscription(v) => {
+                NotificationSubscriptionFields::user_context_view_type(&**v)
+            }
+            Self::UserNotificationSubscription(v) => {
+                NotificationSubscriptionFields::user_context_view_type(&**v)
+            }
+            Self::Unknown => None,
+        }
+    }
+    fn active(&self) -> Option<&bool> {
+        match self {
+            Self::CustomViewNotificationSubscription(v) => {
+                NotificationSubscriptionFields::active(&**v)
+            }
+            Self::CustomerNotificationSubscription(v) => {
+                NotificationSubscriptionFields::active(&**v)
+            }
+            Self::CycleNotificationSubscription(v) => NotificationSubscriptionFields::active(&**v),
+            Self::InitiativeNotificationSubscription(v) => {
+                NotificationSubscriptionFields::active(&**v)
+            }
+            Self::LabelNotificationSubscription(v) => NotificationSubscriptionFields::active(&**v),
+            Self::ProjectNotificationSubscription(v) => {
+                NotificationSubscriptionFields::active(&**v)
+            }
+            Self::TeamNotificationSubscription(v) => NotificationSubscriptionFields::active(&**v),
+            Self::UserNotificationSubscription(v) => NotificationSubscriptionFields::active(&**v),
+            Self::Unknown => None,
+        }
+    }
+}
+/// Content for different types of agent activities.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "__typename")]
+#[non_exhaustive]
+pub enum AgentActivityContent {
+    AgentActivityThoughtContent(Box<AgentActivityThoughtContent>),
+    AgentActivityActionContent(Box<AgentActivityActionContent>),
+    AgentActivityResponseContent(Box<AgentActivityResponseContent>),
+    AgentActivityPromptContent(Box<AgentActivityPromptContent>),
+    AgentActivityErrorContent(Box<AgentActivityErrorContent>),
+    AgentActivityElicitationContent(Box<AgentActivityElicitationContent>),
+    /// Catch-all for object types unknown to this SDK version.
+    #[serde(other)]
+    Unknown,
+}
+impl GraphQLFields for AgentActivityContent {
+    type FullType = Self;
+    fn selection() -> String {
+        crate::field_selection::inline_fragments(&[
+            (
+                "AgentActivityThoughtContent",
+                <AgentActivityThoughtContent as GraphQLFields>::selection(),
+            ),
+            (
+                "AgentActivityActionContent",
+                <AgentActivityActionContent as GraphQLFields>::selection(),
+            ),
+            (
+                "AgentActivityResponseContent",
+                <AgentActivityResponseContent as GraphQLFields>::selection(),
+            ),
+            (
+                "AgentActivityPromptContent",
+                <AgentActivityPromptContent as GraphQLFields>::selection(),
+            ),
+            (
+                "AgentActivityErrorContent",
+                <AgentActivityErrorContent as GraphQLFields>::selection(),
+            ),
+            (
+                "AgentActivityElicitationContent",
+                <AgentActivityElicitationContent as GraphQLFields>::selection(),
+            ),
+        ])
+    }
+}
+/// A part in an AI conversation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "__typename")]
+#[non_exhaustive]
+pub enum AiConversationPart {
+    AiConversationPromptPart(Box<AiConversationPromptPart>),
+    AiConversationTextPart(Box<AiConversationTextPart>),
+    AiConversationReasoningPart(Box<AiConversationReasoningPart>),
+    AiConversationToolCallPart(Box<AiConversationToolCallPart>),
+    AiConversationWidgetPart(Box<AiConversationWidgetPart>),
+    /// Catch-all for object types unknown to this SDK version.
+    #[serde(other)]
+    Unknown,
+}
+impl GraphQLFields for AiConversationPart {
+    type FullType = Self;
+    fn selection() -> String {
+        crate::field_selection::inline_fragments(&[
+            (
+                "AiConversationPromptPart",
+                <AiConversationPromptPart as GraphQLFields>::selection(),
+            ),
+            (
+                "AiConversationTextPart",
+                <AiConversationTextPart as GraphQLFields>::selection(),
+            ),
+            (
+                "AiConversationReasoningPart",
+                <AiConversationReasoningPart as GraphQLFields>::selection(),
+            ),
+            (
+                "AiConversationToolCallPart",
+                <AiConversationToolCallPart as GraphQLFields>::selection(),
+            ),
+            (
+                "AiConversationWidgetPart",
+                <AiConversationWidgetPart as GraphQLFields>::selection(),
+            ),
+        ])
+    }
+}
+/// The tool call.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "__typename")]
+#[non_exhaustive]
+pub enum AiConversationToolCall {
+    AiConversationSearchEntitiesToolCall(Box<AiConversationSearchEntitiesToolCall>),
+    AiConversationRetrieveEntitiesToolCall(Box<AiConversationRetrieveEntitiesToolCall>),
+    AiConversationQueryViewToolCall(Box<AiConversationQueryViewToolCall>),
+    AiConversationQueryActivityToolCall(Box<AiConversationQueryActivityToolCall>),
+    AiConversationQueryUpdatesToolCall(Box<AiConversationQueryUpdatesToolCall>),
+    AiConversationSuggestValuesToolCall(Box<AiConversationSuggestValuesToolCall>),
+    AiConversationCreateEntityToolCall(Box<AiConversationCreateEntityToolCall>),
+    AiConversationUpdateEntityToolCall(Box<AiConversationUpdateEntityToolCall>),
+    AiConversationDeleteEntityToolCall(Box<AiConversationDeleteEntityToolCall>),
+    AiConversationRestoreEntityToolCall(Box<AiConversationRestoreEntityToolCall>),
+    AiConversationResearchToolCall(Box<AiConversationResearchToolCall>),
+    AiConversationCodeIntelligenceToolCall(Box<AiConversationCodeIntelligenceToolCall>),
+    AiConversationGetPullRequestDiffToolCall(Box<AiConversationGetPullRequestDiffToolCall>),
+    AiConversationGetPullRequestFileToolCall(Box<AiConversationGetPullRequestFileToolCall>),
+    AiConversationHandoffToCodingSessionToolCall(Box<AiConversationHandoffToCodingSessionToolCall>),
+    AiConversationTranscribeVideoToolCall(Box<AiConversationTranscribeVideoToolCall>),
+    AiConversationTranscribeMediaToolCall(Box<AiConversationTranscribeMediaToolCall>),
+    AiConversationSearchDocumentationToolCall(Box<AiConversationSearchDocumentationToolCall>),
+    AiConversationWebSearchToolCall(Box<AiConversationWebSearchToolCall>),
+    AiConversationGetSlackConversationHistoryToolCall(
+        Box<AiConversationGetSlackConversationHistoryToolCall>,
+    ),
+    AiConversationGetMicrosoftTeamsConversationHistoryToolCall(
+        Box<AiConversationGetMicrosoftTeamsConversationHistoryToolCall>,
+    ),
+    AiConversationNavigateToPageToolCall(Box<AiConversationNavigateToPageToolCall>),
+    AiConversationInvokeMcpToolToolCall(Box<AiConversationInvokeMcpToolToolCall>),
+    /// Catch-all for object types unknown to this SDK version.
+    #[serde(other)]
+    Unknown,
+}
+impl GraphQLFields for AiConversationToolCall {
+    type FullType = Self;
+    fn selection() -> String {
+        crate::field_selection::inline_fragments(
+            &[
+                (
+                    "AiConversationSearchEntitiesToolCall",
+                    <AiConversationSearchEntitiesToolCall as GraphQLFields>::selection(),
+                ),
+                (
+                    "AiConversationRetrieveEntitiesToolCall",
+                    <AiConversationRetrieveEntitiesToolCall as GraphQLFields>::selection(),
+                ),
+                (
+                    "AiConversationQueryViewToolCall",
+                    <AiConversationQueryViewToolCall as GraphQLFields>::selection(),
+                ),
+                (
+                    "AiConversationQueryActivityToolCall",
+                    <AiConversationQueryActivityToolCall as GraphQLFields>::selection(),
+                ),
+                (
+                    "AiConversationQueryUpdatesToolCall",
+                    <AiConversationQueryUpdatesToolCall as GraphQLFields>::selection(),
+                ),
+                (
+                    "AiConversationSuggestValuesToolCall",
+                    <AiConversationSuggestValuesToolCall as GraphQLFields>::selection(),
+                ),
+                (
+                    "AiConversationCreateEntityToolCall",
+                    <AiConversationCreateEntityToolCall as GraphQLFields>::selection(),
+                ),
+                (
+                    "AiConversationUpdateEntityToolCall",
+                    <AiConversationUpdateEntityToolCall as GraphQLFields>::selection(),
+                ),
+                (
+                    "AiConversationDeleteEntityToolCall",
+                    <AiConversationDeleteEntityToolCall as GraphQLFields>::selection(),
+                ),
+                (
+                    "AiConversationRestoreEntityToolCall",
+                    <AiConversationRestoreEntityToolCall as GraphQLFields>::selection(),
+                ),
+                (
+                    "AiConversationResearchToolCall",
+                    <AiConversationResearchToolCall as GraphQLFields>::selection(),
+                ),
+                (
+                    "AiConversationCodeIntelligenceToolCall",
+                    <AiConversationCodeIntelligenceToolCall as GraphQLFields>::selection(),
+                ),
+                (
+                    "AiConversationGetPullRequestDiffToolCall",
+                    <AiConversationGetPullRequestDiffToolCall as GraphQLFields>::selection(),
+                ),
+                (
+                    "AiConversationGetPullRequestFileToolCall",
+                    <AiConversationGetPullRequestFileToolCall as GraphQLFields>::selection(),
+                ),
+                (
+                    "AiConversationHandoffToCodingSessionToolCall",
+                    <AiConversationHandoffToCodingSessionToolCall as GraphQLFields>::selection(),
+                ),
+                (
+                    "AiConversationTranscribeVideoToolCall",
+                    <AiConversationTranscribeVideoToolCall as GraphQLFields>::selection(),
+                ),
+                (
+                    "AiConversationTranscribeMediaToolCall",
+                    <AiConversationTranscribeMediaToolCall as GraphQLFields>::selection(),
+                ),
+                (
+                    "AiConversationSearchDocumentationToolCall",
+                    <AiConversationSearchDocumentationToolCall as GraphQLFields>::selection(),
+                ),
+                (
+                    "AiConversationWebSearchToolCall",
+                    <AiConversationWebSearchToolCall as GraphQLFields>::selection(),
+                ),
+                (
+                    "AiConversationGetSlackConversationHistoryToolCall",
+                    <AiConversationGetSlackConversationHistoryToolCall as GraphQLFields>::selection(),
+                ),
+                (
+                    "AiConversationGetMicrosoftTeamsConversationHistoryToolCall",
+                    <AiConversationGetMicrosoftTeamsConversationHistoryToolCall as GraphQLFields>::selection(),
+                ),
+                (
+                    "AiConversationNavigateToPageToolCall",
+                    <AiConversationNavigateToPageToolCall as GraphQLFields>::selection(),
+                ),
+                (
+                    "AiConversationInvokeMcpToolToolCall",
+                    <AiConversationInvokeMcpToolToolCall as GraphQLFields>::selection(),
+                ),
+            ],
+        )
+    }
+}
+/// The widget.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "__typename")]
+#[non_exhaustive]
+pub enum AiConversationWidget {
+    AiConversationEntityCardWidget(Box<AiConversationEntityCardWidget>),
+    AiConversationEntityListWidget(Box<AiConversationEntityListWidget>),
+    /// Catch-all for object types unknown to this SDK version.
+    #[serde(other)]
+    Unknown,
+}
+impl GraphQLFields for AiConversationWidget {
+    type FullType = Self;
+    fn selection() -> String {
+        crate::field_selection::inline_fragments(&[
+            (
+                "AiConversationEntityCardWidget",
+                <AiConversationEntityCardWidget as GraphQLFields>::selection(),
+            ),
+            (
+                "AiConversationEntityListWidget",
+                <AiConversationEntityListWidget as GraphQLFields>::selection(),
+            ),
+        ])
+    }
+}
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "__typename")]
+#[non_exhaustive]
+pub enum ExternalEntityInfoMetadata {
+    ExternalEntityInfoGithubMetadata(Box<ExternalEntityInfoGithubMetadata>),
+    ExternalEntityInfoJiraMetadata(Box<ExternalEntityInfoJiraMetadata>),
+    ExternalEntitySlackMetadata(Box<ExternalEntitySlackMetadata>),
+    /// Catch-all for object types unknown to this SDK version.
+    #[serde(other)]
+    Unknown,
+}
+impl GraphQLFields for ExternalEntityInfoMetadata {
+    type FullType = Self;
+    fn selection() -> String {
+        crate::field_selection::inline_fragments(&[
+            (
+                "ExternalEntityInfoGithubMetadata",
+                <ExternalEntityInfoGithubMetadata as GraphQLFields>::selection(),
+            ),
+            (
+                "ExternalEntityInfoJiraMetadata",
+                <ExternalEntityInfoJiraMetadata as GraphQLFields>::selection(),
+            ),
+            (
+                "ExternalEntitySlackMetadata",
+                <ExternalEntitySlackMetadata as GraphQLFields>::selection(),
+            ),
+        ])
+    }
+}
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "__typename")]
+#[non_exhaustive]
+pub enum OrganizationInviteDetailsPayload {
+    OrganizationInviteFullDetailsPayload(Box<OrganizationInviteFullDetailsPayload>),
+    OrganizationAcceptedOrExpiredInviteDetailsPayload(
+        Box<OrganizationAcceptedOrExpiredInviteDetailsPayload>,
+    ),
+    /// Catch-all for object types unknown to this SDK version.
+    #[serde(other)]
+    Unknown,
+}
+impl GraphQLFields for OrganizationInviteDetailsPayload {
+    type FullType = Self;
+    fn selection() -> String {
+        crate::field_selection::inline_fragments(&[
+            (
+                "OrganizationInviteFullDetailsPayload",
+                <OrganizationInviteFullDetailsPayload as GraphQLFields>::selection(),
+            ),
+            (
+                "OrganizationAcceptedOrExpiredInviteDetailsPayload",
+                <OrganizationAcceptedOrExpiredInviteDetailsPayload as GraphQLFields>::selection(),
+            ),
+        ])
+    }
+}
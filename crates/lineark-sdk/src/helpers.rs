@@ -3,10 +3,14 @@
 //! Linear's file handling works outside the GraphQL API: uploads go to Google
 //! Cloud Storage via signed URLs, and downloads fetch from Linear's CDN. These
 //! helpers use the SDK's internal HTTP client so consumers don't need a separate
-//! `reqwest` dependency.
+//! `reqwest` dependency, and pass through the client's
+//! [middleware](crate::middleware) like GraphQL requests do.
 
-use crate::client::Client;
+use crate::client::{header_value, Client};
 use crate::error::LinearError;
+use crate::middleware::RequestKind;
+use reqwest::header::{HeaderName, HeaderValue, AUTHORIZATION, CONTENT_TYPE};
+use reqwest::Method;
 
 /// Metadata about a successfully downloaded file.
 #[derive(Debug, Clone)]
@@ -51,31 +55,30 @@ impl Client {
             .map(|u| u.host_str().is_some_and(|h| h.ends_with(".linear.app")))
             .unwrap_or(false);
 
-        let mut request = self.http().get(url);
+        let mut request = self.request(RequestKind::Download, "download", Method::GET, url);
         if is_linear_url {
-            request = request.header("Authorization", self.token());
+            let authorization = self.authorization().await?;
+            request
+                .headers
+                .insert(AUTHORIZATION, header_value(&authorization)?);
         }
-        let response = request.send().await?;
+        let response = self.send(request).await?;
 
-        let status = response.status();
-        if !status.is_success() {
-            let body = response.text().await.unwrap_or_default();
+        if !response.status.is_success() {
             return Err(LinearError::HttpError {
-                status: status.as_u16(),
-                body,
+                status: response.status.as_u16(),
+                body: response.text(),
             });
         }
 
         let content_type = response
-            .headers()
+            .headers
             .get("content-type")
             .and_then(|v| v.to_str().ok())
             .map(|s| s.to_string());
 
-        let bytes = response.bytes().await?.to_vec();
-
         Ok(DownloadResult {
-            bytes,
+            bytes: response.body,
             content_type,
         })
     }
@@ -192,24 +195,22 @@ impl Client {
             .unwrap_or_default();
 
         // Step 2: PUT the file bytes to the signed upload URL.
-        let mut request = self
-            .http()
-            .put(upload_url)
-            .header("Content-Type", content_type)
-            .body(bytes);
-
+        let mut request = self.request(RequestKind::Upload, "upload", Method::PUT, upload_url);
+        request.headers.insert(CONTENT_TYPE, header(content_type)?);
         for (key, value) in &headers {
-            request = request.header(key.as_str(), value.as_str());
+            let name = HeaderName::from_bytes(key.as_bytes()).map_err(|_| {
+                LinearError::MissingData(format!("Invalid upload header name '{}'", key))
+            })?;
+            request.headers.insert(name, header(value)?);
         }
+        request.body = bytes;
 
-        let response = request.send().await?;
+        let response = self.send(request).await?;
 
-        if !response.status().is_success() {
-            let status = response.status();
-            let body = response.text().await.unwrap_or_default();
+        if !response.status.is_success() {
             return Err(LinearError::HttpError {
-                status: status.as_u16(),
-                body,
+                status: response.status.as_u16(),
+                body: response.text(),
             });
         }
 
@@ -217,6 +218,14 @@ impl Client {
     }
 }
 
+/// A header value from text, rejecting values that can't be sent.
+fn header(value: &str) -> Result<HeaderValue, LinearError> {
+    HeaderValue::from_str(value).map_err(|_| LinearError::InvalidInput {
+        field: None,
+        message: format!("Invalid header value '{}'", value),
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,437 @@
+//! Idempotent create mutations.
+//!
+//! Create inputs such as `IssueCreateInput` take an optional `id`. With
+//! [`ClientBuilder::idempotent_creates`](crate::ClientBuilder::idempotent_creates)
+//! enabled, every create mutation that leaves it unset is sent with a fresh
+//! v4 UUID, which makes sending it again harmless:
+//!
+//! - When an attempt fails ambiguously — a timeout, a 5xx, a dropped
+//!   connection — the entity may have been created anyway. Before retrying
+//!   under the client's [`RetryPolicy`](crate::RetryPolicy), the client looks
+//!   the entity up by its ID and returns it if it exists. Creates are retried
+//!   even without [`RetryPolicy::retry_mutations`](crate::RetryPolicy::retry_mutations).
+//! - When Linear rejects the insert with [`LinearError::Conflict`] because the
+//!   ID is taken, the existing entity is returned instead of the error. An
+//!   explicit `id` therefore works as an idempotency key across processes as
+//!   well. Linear also answers spurious conflicts for IDs nothing was created
+//!   with; when the lookup finds nothing, the create is retried with the same
+//!   ID under the retry policy.
+//!
+//! A lookup that fails, rather than finding nothing, fails the create.
+//!
+//! An entity found by lookup has no `lastSyncId`, so
+//! [`MutationResult::last_sync_id`](crate::MutationResult::last_sync_id) is
+//! `None` for it.
+//!
+//! ```no_run
+//! # async fn example() -> Result<(), lineark_sdk::LinearError> {
+//! use lineark_sdk::generated::inputs::IssueCreateInput;
+//! use lineark_sdk::generated::types::Issue;
+//! use lineark_sdk::{Client, RetryPolicy};
+//!
+//! let client = Client::builder()
+//!     .token("lin_api_...")
+//!     .retry_policy(RetryPolicy::default())
+//!     .idempotent_creates(true)
+//!     .build()?;
+//! let issue = client
+//!     .issue_create::<Issue>(IssueCreateInput {
+//!         team_id: "team-uuid".to_string(),
+//!         title: "Created at most once".to_string().into(),
+//!         ..Default::default()
+//!     })
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::client::{decode_mutation_payload, Client};
+use crate::error::LinearError;
+use crate::operation::Operation;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+/// Where a create mutation takes its optional `id`, and how to fetch the
+/// entity it creates.
+#[derive(Debug, Clone)]
+pub(crate) struct ClientId {
+    /// The object variable holding the `id`, e.g. `input`.
+    pub(crate) input_variable: String,
+    /// Root query field selection fetching the entity by `$id`.
+    pub(crate) lookup: String,
+}
+
+/// A random (version 4) UUID, the format Linear accepts for client-generated
+/// IDs.
+pub fn new_id() -> String {
+    let mut bytes = [0u8; 16];
+    getrandom::getrandom(&mut bytes).expect("OS random number generator unavailable");
+    bytes[6] = (bytes[6] & 0x0f) | 0x40;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+    let hex: String = bytes.iter().map(|b| format!("{:02x}", b)).collect();
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..]
+    )
+}
+
+/// Set `id` on the `input_variable` object unless the caller already did, and
+/// return the ID the create is sent with.
+pub(crate) fn assign_id(
+    variables: &mut serde_json::Map<String, Value>,
+    input_variable: &str,
+) -> String {
+    let input = variables
+        .entry(input_variable)
+        .or_insert_with(|| Value::Object(serde_json::Map::new()));
+    if let Some(id) = input.get("id").and_then(Value::as_str) {
+        return id.to_string();
+    }
+    let id = new_id();
+    if let Some(input) = input.as_object_mut() {
+        input.insert("id".to_string(), Value::String(id.clone()));
+    }
+    id
+}
+
+impl Client {
+    /// Send a create mutation with a client-generated ID, retrying transient
+    /// failures and conflicts once a lookup shows the entity wasn't created.
+    pub(crate) async fn execute_create<T: DeserializeOwned>(
+        &self,
+        mut operation: Operation<T>,
+        client_id: ClientId,
+    ) -> Result<T, LinearError> {
+        let id = assign_id(&mut operation.variables, &client_id.input_variable);
+        // Attempts are counted here, so each one is sent without the
+        // client's own retries, which `retry_mutations` would enable.
+        let once = self
+            .clone()
+            .with_retry_policy(self.retry_policy().clone().max_attempts(1));
+        let mut attempt = 1;
+        loop {
+            let err = match operation.send(&once).await {
+                Ok(value) => return Ok(value),
+                Err(err) => err,
+            };
+            let conflict = matches!(err, LinearError::Conflict(_));
+            // The ID makes a repeated create safe, so retry it like a query.
+            let retry = if conflict {
+                attempt < self.retry_policy().attempts()
+            } else {
+                self.retry_policy().should_retry(&err, attempt, false)
+            };
+            if !conflict && !retry {
+                return Err(err);
+            }
+            if retry {
+                tokio::time::sleep(self.retry_policy().delay(attempt, &err)).await;
+            }
+            if let Some(created) = self.find_created(&operation, &client_id, &id).await? {
+                return Ok(created);
+            }
+            if !retry {
+                return Err(err);
+            }
+            attempt += 1;
+        }
+    }
+
+    /// Fetch the entity `operation` creates with `id`, decoded as the
+    /// mutation's result, or `None` if it doesn't exist or the operation has
+    /// no entity to look up.
+    async fn find_created<T: DeserializeOwned>(
+        &self,
+        operation: &Operation<T>,
+        client_id: &ClientId,
+        id: &str,
+    ) -> Result<Option<T>, LinearError> {
+        let Some(entity_field) = operation.entity_field.as_deref() else {
+            return Ok(None);
+        };
+        let field = client_id
+            .lookup
+            .split(['(', ' ', '{'])
+            .next()
+            .unwrap_or_default();
+        let document = format!(
+            "query {}Lookup($id: String!) {{ {} }}",
+            operation.name, client_id.lookup
+        );
+        let entity = match self
+            .execute::<Option<Value>>(&document, serde_json::json!({ "id": id }), field)
+            .await
+        {
+            Ok(Some(entity)) => entity,
+            Ok(None) | Err(LinearError::NotFound(_)) => return Ok(None),
+            Err(err) => return Err(err),
+        };
+        let payload = serde_json::json!({ "success": true, entity_field: entity });
+        decode_mutation_payload(
+            payload,
+            &operation.data_path,
+            Some(entity_field),
+            operation.with_meta,
+        )
+        .map(Some)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generated::inputs::IssueCreateInput;
+    use crate::generated::operations;
+    use crate::generated::types::Issue;
+    use crate::RetryPolicy;
+    use std::time::Duration;
+    use wiremock::matchers::{body_string_contains, method};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn client_for(server: &MockServer) -> Client {
+        Client::from_token("test-token")
+            .unwrap()
+            .with_base_url(server.uri())
+            .with_idempotent_creates(true)
+            .with_retry_policy(
+                RetryPolicy::default()
+                    .initial_backoff(Duration::from_millis(1))
+                    .jitter(false),
+            )
+    }
+
+    fn input() -> IssueCreateInput {
+        IssueCreateInput {
+            team_id: "team-uuid".to_string(),
+            title: "Once".to_string().into(),
+            ..Default::default()
+        }
+    }
+
+    fn created(id: &str) -> serde_json::Value {
+        serde_json::json!({
+            "data": { "issueCreate": { "success": true, "lastSyncId": 3.0, "issue": { "id": id } } }
+        })
+    }
+
+    async fn sent_input_ids(server: &MockServer) -> Vec<String> {
+        server
+            .received_requests()
+            .await
+            .unwrap()
+            .iter()
+            .map(|r| serde_json::from_slice::<serde_json::Value>(&r.body).unwrap())
+            .filter_map(|body| body["variables"]["input"]["id"].as_str().map(String::from))
+            .collect()
+    }
+
+    #[test]
+    fn new_ids_are_v4_uuids() {
+        let id = new_id();
+        assert_eq!(id.len(), 36);
+        assert_eq!(id.as_bytes()[14], b'4');
+        assert!(matches!(id.as_bytes()[19], b'8' | b'9' | b'a' | b'b'));
+        assert_ne!(id, new_id());
+    }
+
+    #[test]
+    fn assign_id_keeps_an_explicit_id() {
+        let mut variables = serde_json::Map::new();
+        variables.insert("input".into(), serde_json::json!({ "id": "mine" }));
+        assert_eq!(assign_id(&mut variables, "input"), "mine");
+
+        variables.insert("input".into(), serde_json::json!({ "title": "x" }));
+        let id = assign_id(&mut variables, "input");
+        assert_eq!(variables["input"]["id"], id.as_str());
+    }
+
+    #[tokio::test]
+    async fn creates_get_an_id_only_when_enabled() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(created("x")))
+            .mount(&server)
+            .await;
+
+        client_for(&server)
+            .with_idempotent_creates(false)
+            .issue_create::<Issue>(input())
+            .await
+            .unwrap();
+        assert!(sent_input_ids(&server).await.is_empty());
+
+        client_for(&server)
+            .issue_create::<Issue>(input())
+            .await
+            .unwrap();
+        assert_eq!(sent_input_ids(&server).await.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn ambiguous_failure_returns_entity_found_by_lookup() {
+        let server = MockServer::start().await;
+        Mock::given(body_string_contains("mutation IssueCreate"))
+            .respond_with(ResponseTemplate::new(503))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(body_string_contains("query IssueCreateLookup"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": { "issue": { "id": "created-uuid" } }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let result = operations::issue_create::<Issue>(input())
+            .with_meta()
+            .execute(&client_for(&server))
+            .await
+            .unwrap();
+        assert_eq!(result.entity.id.as_deref(), Some("created-uuid"));
+        assert_eq!(result.last_sync_id, None);
+    }
+
+    #[tokio::test]
+    async fn ambiguous_failure_retries_with_the_same_id() {
+        let server = MockServer::start().await;
+        Mock::given(body_string_contains("mutation IssueCreate"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(body_string_contains("query IssueCreateLookup"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "errors": [{ "message": "Entity not found", "extensions": { "code": "ENTITY_NOT_FOUND" } }],
+                "data": null
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(body_string_contains("mutation IssueCreate"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(created("retried")))
+            .mount(&server)
+            .await;
+
+        let issue = client_for(&server)
+            .issue_create::<Issue>(input())
+            .await
+            .unwrap();
+        assert_eq!(issue.id.as_deref(), Some("retried"));
+        let ids = sent_input_ids(&server).await;
+        assert_eq!(ids.len(), 2);
+        assert_eq!(ids[0], ids[1]);
+    }
+
+    #[tokio::test]
+    async fn retry_mutations_does_not_multiply_attempts() {
+        let server = MockServer::start().await;
+        Mock::given(body_string_contains("mutation IssueCreate"))
+            .respond_with(ResponseTemplate::new(503))
+            .expect(3)
+            .mount(&server)
+            .await;
+        Mock::given(body_string_contains("query IssueCreateLookup"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": { "issue": null }
+            })))
+            .mount(&server)
+            .await;
+
+        let client = client_for(&server).with_retry_policy(
+            RetryPolicy::default()
+                .max_attempts(3)
+                .retry_mutations(true)
+                .initial_backoff(Duration::from_millis(1))
+                .jitter(false),
+        );
+        let err = client.issue_create::<Issue>(input()).await.unwrap_err();
+        assert!(matches!(err, LinearError::HttpError { status: 503, .. }));
+    }
+
+    #[tokio::test]
+    async fn conflict_on_explicit_id_returns_existing_entity() {
+        let server = MockServer::start().await;
+        Mock::given(body_string_contains("mutation IssueCreate"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "errors": [{ "message": "conflict on insert" }],
+                "data": null
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(body_string_contains("query IssueCreateLookup"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": { "issue": { "id": "key-uuid" } }
+            })))
+            .mount(&server)
+            .await;
+
+        let issue = client_for(&server)
+            .issue_create::<Issue>(IssueCreateInput {
+                id: "key-uuid".to_string().into(),
+                ..input()
+            })
+            .await
+            .unwrap();
+        assert_eq!(issue.id.as_deref(), Some("key-uuid"));
+        assert_eq!(sent_input_ids(&server).await, ["key-uuid"]);
+    }
+
+    #[tokio::test]
+    async fn spurious_conflict_retries_with_the_same_id() {
+        let server = MockServer::start().await;
+        Mock::given(body_string_contains("mutation IssueCreate"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "errors": [{ "message": "conflict on insert" }],
+                "data": null
+            })))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(body_string_contains("query IssueCreateLookup"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": { "issue": null }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(body_string_contains("mutation IssueCreate"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(created("retried")))
+            .mount(&server)
+            .await;
+
+        let issue = client_for(&server)
+            .issue_create::<Issue>(input())
+            .await
+            .unwrap();
+        assert_eq!(issue.id.as_deref(), Some("retried"));
+        let ids = sent_input_ids(&server).await;
+        assert_eq!(ids.len(), 2);
+        assert_eq!(ids[0], ids[1]);
+    }
+
+    #[tokio::test]
+    async fn failed_lookup_fails_the_create() {
+        let server = MockServer::start().await;
+        Mock::given(body_string_contains("mutation IssueCreate"))
+            .respond_with(ResponseTemplate::new(503))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(body_string_contains("query IssueCreateLookup"))
+            .respond_with(ResponseTemplate::new(403).set_body_string("Forbidden"))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let err = client_for(&server)
+            .issue_create::<Issue>(input())
+            .await
+            .unwrap_err();
+        assert!(matches!(err, LinearError::Forbidden(_)), "{err:?}");
+    }
+}
@@ -0,0 +1,223 @@
+//! Typed entity IDs and issue identifiers.
+//!
+//! Codegen emits an ID newtype ([`IssueId`], [`TeamId`], [`ProjectId`], …)
+//! for every entity an enabled operation takes an `id` for, and those
+//! operations accept `impl Into<…Id>`. Plain strings still convert, so the
+//! newtypes are opt-in; once a caller holds a [`TeamId`], passing it to
+//! [`Client::issue_delete`] no longer compiles.
+//!
+//! [`IssueIdentifier`] is the human-readable `ENG-123` form, fetched with
+//! [`Client::issue_by_identifier`]:
+//!
+//! ```no_run
+//! # async fn example() -> Result<(), lineark_sdk::LinearError> {
+//! use lineark_sdk::generated::types::Issue;
+//! use lineark_sdk::ids::IssueIdentifier;
+//! use lineark_sdk::Client;
+//!
+//! let client = Client::from_env()?;
+//! let identifier: IssueIdentifier = "ENG-123".parse().expect("valid identifier");
+//! if let Some(issue) = client.issue_by_identifier::<Issue>(&identifier).await? {
+//!     client.issue_archive::<Issue>(None, issue.typed_id().unwrap()).await?;
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+pub use crate::generated::ids::*;
+
+use crate::client::Client;
+use crate::error::LinearError;
+use crate::field_selection::GraphQLFields;
+use crate::generated::inputs::{IssueFilter, NumberComparator, StringComparator, TeamFilter};
+use crate::generated::types::Issue;
+use serde::de::DeserializeOwned;
+use std::fmt;
+use std::str::FromStr;
+
+/// An issue's human-readable identifier: its team key and number, e.g. `ENG-123`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct IssueIdentifier {
+    /// The team key, upper-cased (`ENG`).
+    pub team_key: String,
+    /// The issue number within the team (`123`).
+    pub number: u32,
+}
+
+impl IssueIdentifier {
+    pub fn new(team_key: impl Into<String>, number: u32) -> Self {
+        Self {
+            team_key: team_key.into().to_ascii_uppercase(),
+            number,
+        }
+    }
+
+    /// Parse `ENG-123` (case-insensitive), or `None` if `s` isn't one —
+    /// e.g. because it's a UUID.
+    pub fn parse(s: &str) -> Option<Self> {
+        s.parse().ok()
+    }
+
+    /// Filter matching exactly this issue.
+    pub fn filter(&self) -> IssueFilter {
+        IssueFilter::team(TeamFilter::key(StringComparator::eq(
+            self.team_key.as_str(),
+        )))
+        .and(IssueFilter::number(NumberComparator::eq(self.number)))
+    }
+}
+
+impl FromStr for IssueIdentifier {
+    type Err = ParseIssueIdentifierError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ParseIssueIdentifierError(s.to_string());
+        let (key, number) = s.trim().rsplit_once('-').ok_or_else(err)?;
+        let key_is_valid = key.starts_with(|c: char| c.is_ascii_alphabetic())
+            && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+        if !key_is_valid || !number.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(err());
+        }
+        let number = number.parse().map_err(|_| err())?;
+        Ok(Self::new(key, number))
+    }
+}
+
+impl fmt::Display for IssueIdentifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.team_key, self.number)
+    }
+}
+
+/// Error returned when a string is not an issue identifier like `ENG-123`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseIssueIdentifierError(String);
+
+impl fmt::Display for ParseIssueIdentifierError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "'{}' is not an issue identifier (expected TEAM-123)",
+            self.0
+        )
+    }
+}
+
+impl std::error::Error for ParseIssueIdentifierError {}
+
+impl Client {
+    /// Fetch the issue with a human-readable identifier, including archived
+    /// issues. Returns `None` if no such issue exists.
+    ///
+    /// Matches the identifier exactly, unlike the fuzzy
+    /// [`search_issues`](Self::search_issues), and also finds issues that
+    /// moved to another team by an identifier they had before.
+    pub async fn issue_by_identifier<T: DeserializeOwned + GraphQLFields<FullType = Issue>>(
+        &self,
+        identifier: &IssueIdentifier,
+    ) -> Result<Option<T>, LinearError> {
+        match self.issue::<T>(identifier.to_string()).await {
+            Ok(issue) => Ok(Some(issue)),
+            Err(LinearError::NotFound(_)) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{body_string_contains, method};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[test]
+    fn parses_and_displays_identifiers() {
+        let identifier: IssueIdentifier = "eng-42".parse().unwrap();
+        assert_eq!(identifier, IssueIdentifier::new("ENG", 42));
+        assert_eq!(identifier.to_string(), "ENG-42");
+        assert_eq!(
+            IssueIdentifier::parse("A1_B-7"),
+            Some(IssueIdentifier::new("A1_B", 7))
+        );
+        for invalid in [
+            "",
+            "ENG",
+            "ENG-",
+            "-12",
+            "ENG-12a",
+            "ENG-+1",
+            "1ENG-2",
+            "3fa85f64-5717-4562-b3fc-2c963f66afa6",
+        ] {
+            assert!(IssueIdentifier::parse(invalid).is_none(), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn ids_serialize_as_plain_strings() {
+        let id = IssueId::new("abc");
+        assert_eq!(serde_json::to_value(&id).unwrap(), "abc");
+        assert_eq!(String::from(id.clone()), "abc");
+        let issue = Issue {
+            id: Some("abc".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(issue.typed_id(), Some(id));
+    }
+
+    #[tokio::test]
+    async fn issue_by_identifier_looks_up_current_and_previous_identifiers() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(body_string_contains(r#""id":"ENG-42""#))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": { "issue": { "id": "issue-uuid", "identifier": "ENG-42" } }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(body_string_contains(r#""id":"OLD-7""#))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": { "issue": { "id": "moved-uuid", "identifier": "NEW-3" } }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(body_string_contains(r#""id":"ENG-404""#))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": null,
+                "errors": [{
+                    "message": "Entity not found: Issue",
+                    "extensions": { "code": "INPUT_ERROR" }
+                }]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = Client::from_token("test-token")
+            .unwrap()
+            .with_base_url(server.uri());
+        let issue = client
+            .issue_by_identifier::<Issue>(&IssueIdentifier::new("ENG", 42))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(issue.typed_id(), Some(IssueId::from("issue-uuid")));
+        let moved = client
+            .issue_by_identifier::<Issue>(&IssueIdentifier::new("OLD", 7))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(moved.typed_id(), Some(IssueId::from("moved-uuid")));
+        let missing = client
+            .issue_by_identifier::<Issue>(&IssueIdentifier::new("ENG", 404))
+            .await
+            .unwrap();
+        assert!(missing.is_none());
+        // One request per lookup.
+        assert_eq!(server.received_requests().await.unwrap().len(), 3);
+    }
+}
@@ -1,19 +1,45 @@
 #![recursion_limit = "256"]
 
 pub mod auth;
+pub mod batch;
+#[cfg(feature = "blocking")]
+pub mod blocking;
+pub mod cassette;
 pub mod client;
+pub mod document;
 pub mod error;
 pub mod field_selection;
 pub mod field_update;
+pub mod filter;
 pub mod generated;
 pub mod helpers;
+pub mod idempotency;
+pub mod ids;
+pub mod middleware;
+pub mod operation;
 pub mod pagination;
+pub mod rate_limit;
+pub mod retry;
+pub mod richtext;
+pub mod subscription;
+pub mod sync;
+pub mod webhooks;
 
 // Re-export key types at crate root for convenience.
-pub use client::Client;
-pub use error::LinearError;
+pub use batch::{Batch, BatchKey, BatchResults};
+pub use client::{Client, ClientBuilder};
+pub use document::GraphQLDocument;
+pub use error::{LinearError, ParseEnumError, RateLimitKind};
 pub use field_selection::FieldCompatible;
 pub use field_selection::GraphQLFields;
 pub use field_update::MaybeUndefined;
 pub use lineark_derive::GraphQLFields;
+pub use middleware::Middleware;
+pub use operation::{MutationResult, Operation};
 pub use pagination::{Connection, PageInfo};
+pub use retry::RetryPolicy;
+pub use subscription::{Subscription, SubscriptionOperation};
+
+// Re-exported so callers can configure proxies and certificates for
+// [`ClientBuilder`] without pinning a matching reqwest version themselves.
+pub use reqwest;
@@ -0,0 +1,669 @@
+//! Request middleware.
+//!
+//! A [`Middleware`] wraps every HTTP request the client makes — GraphQL
+//! operations, the file helpers ([`Client::download_url`] and the upload step
+//! of [`Client::upload_file`]) and the handshakes opening subscriptions. It
+//! sees the full [`Request`], including headers and body, may change it, and
+//! decides whether and how to call the rest of the chain through
+//! [`Next::run`]. The [`Response`] body is buffered so it can be inspected
+//! before the client interprets it.
+//!
+//! Middleware runs once per HTTP attempt: a request retried by the
+//! [`RetryPolicy`](crate::RetryPolicy) passes through the chain again.
+//!
+//! ```no_run
+//! # fn example() -> Result<(), lineark_sdk::LinearError> {
+//! use lineark_sdk::middleware::{
+//!     BoxFuture, Logging, Metrics, Middleware, Next, Request, Response,
+//! };
+//! use lineark_sdk::{Client, LinearError};
+//!
+//! /// Tags every request with a correlation ID.
+//! struct CorrelationId;
+//!
+//! impl Middleware for CorrelationId {
+//!     fn handle<'a>(
+//!         &'a self,
+//!         mut request: Request,
+//!         next: Next<'a>,
+//!     ) -> BoxFuture<'a, Result<Response, LinearError>> {
+//!         request
+//!             .headers
+//!             .insert("x-correlation-id", "job-42".parse().unwrap());
+//!         next.run(request)
+//!     }
+//! }
+//!
+//! let metrics = Metrics::new();
+//! let client = Client::builder()
+//!     .token("lin_api_...")
+//!     .middleware(Logging::stderr())
+//!     .middleware(metrics.clone())
+//!     .middleware(CorrelationId)
+//!     .build()?;
+//! // ... later
+//! for (operation, stats) in metrics.snapshot() {
+//!     println!("{operation}: {} calls", stats.calls);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Middleware added first runs outermost: it sees the request before, and the
+//! response after, every middleware added later.
+//!
+//! [`Client::download_url`]: crate::Client::download_url
+//! [`Client::upload_file`]: crate::Client::upload_file
+
+use crate::error::LinearError;
+use reqwest::header::HeaderMap;
+use reqwest::{Method, StatusCode};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+pub use futures::future::BoxFuture;
+
+/// Wraps the client's HTTP requests. See the [module docs](self).
+pub trait Middleware: Send + Sync + 'static {
+    /// Handle `request`, usually by passing it (possibly modified) to
+    /// [`next.run`](Next::run) and returning the response.
+    fn handle<'a>(
+        &'a self,
+        request: Request,
+        next: Next<'a>,
+    ) -> BoxFuture<'a, Result<Response, LinearError>>;
+}
+
+/// What a [`Request`] is for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RequestKind {
+    /// A GraphQL query.
+    Query,
+    /// A GraphQL mutation.
+    Mutation,
+    /// A file download through [`Client::download_url`](crate::Client::download_url).
+    Download,
+    /// The signed-URL upload step of [`Client::upload_file`](crate::Client::upload_file).
+    Upload,
+    /// The WebSocket handshake opening a subscription. A successful response
+    /// has status 101 and no body; the connection itself is not seen by
+    /// middleware.
+    Subscribe,
+}
+
+/// An outgoing HTTP request, as seen by middleware.
+#[derive(Debug, Clone)]
+pub struct Request {
+    /// What the request is for.
+    pub kind: RequestKind,
+    /// The GraphQL operation name (e.g. `"Viewer"`), or `"download"` /
+    /// `"upload"` for the file helpers.
+    pub operation: String,
+    pub method: Method,
+    pub url: String,
+    /// Request headers, including `Authorization` when the request is
+    /// authenticated.
+    pub headers: HeaderMap,
+    /// The request body; the JSON-encoded GraphQL request for operations.
+    pub body: Vec<u8>,
+    /// Timeout for this request, if any.
+    pub timeout: Option<Duration>,
+}
+
+impl Request {
+    pub(crate) fn new(
+        kind: RequestKind,
+        operation: impl Into<String>,
+        method: Method,
+        url: impl Into<String>,
+    ) -> Self {
+        Self {
+            kind,
+            operation: operation.into(),
+            method,
+            url: url.into(),
+            headers: HeaderMap::new(),
+            body: Vec::new(),
+            timeout: None,
+        }
+    }
+}
+
+/// An HTTP response with its body fully read, as seen by middleware.
+#[derive(Debug, Clone)]
+pub struct Response {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub body: Vec<u8>,
+}
+
+impl Response {
+    /// The body as text, replacing invalid UTF-8.
+    pub fn text(&self) -> String {
+        String::from_utf8_lossy(&self.body).into_owned()
+    }
+}
+
+/// Where the transport leaves a response that switched protocols.
+type UpgradeSlot = Mutex<Option<reqwest::Response>>;
+
+/// The rest of the middleware chain, ending in the HTTP transport.
+pub struct Next<'a> {
+    http: &'a reqwest::Client,
+    middleware: &'a [Arc<dyn Middleware>],
+    upgrade: Option<&'a UpgradeSlot>,
+}
+
+impl<'a> Next<'a> {
+    /// Pass `request` to the next middleware, or send it if none is left.
+    pub fn run(self, request: Request) -> BoxFuture<'a, Result<Response, LinearError>> {
+        match self.middleware.split_first() {
+            Some((first, rest)) => first.handle(
+                request,
+                Next {
+                    http: self.http,
+                    middleware: rest,
+                    upgrade: self.upgrade,
+                },
+            ),
+            None => Box::pin(send(self.http, request, self.upgrade)),
+        }
+    }
+}
+
+impl fmt::Debug for Next<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Next")
+            .field("remaining", &self.middleware.len())
+            .finish()
+    }
+}
+
+/// Perform the HTTP round trip and buffer the response. A response switching
+/// protocols is left in `upgrade` instead, when given.
+async fn send(
+    http: &reqwest::Client,
+    request: Request,
+    upgrade: Option<&UpgradeSlot>,
+) -> Result<Response, LinearError> {
+    let mut builder = http
+        .request(request.method, &request.url)
+        .headers(request.headers)
+        .body(request.body);
+    if let Some(timeout) = request.timeout {
+        builder = builder.timeout(timeout);
+    }
+    let response = builder.send().await?;
+    let status = response.status();
+    let headers = response.headers().clone();
+    if let (Some(slot), StatusCode::SWITCHING_PROTOCOLS) = (upgrade, status) {
+        *slot.lock().unwrap_or_else(|e| e.into_inner()) = Some(response);
+        return Ok(Response {
+            status,
+            headers,
+            body: Vec::new(),
+        });
+    }
+    let body = response.bytes().await?.to_vec();
+    Ok(Response {
+        status,
+        headers,
+        body,
+    })
+}
+
+/// The middleware configured on a client, outermost first.
+#[derive(Clone, Default)]
+pub(crate) struct Pipeline {
+    middleware: Vec<Arc<dyn Middleware>>,
+    /// Whether the last middleware is the rate limiter, which stays innermost.
+    rate_limited: bool,
+}
+
+impl Pipeline {
+    /// Add `middleware` inside the middleware already added, but outside the
+    /// rate limiter.
+    pub(crate) fn push(&mut self, middleware: Arc<dyn Middleware>) {
+        let at = self.middleware.len() - usize::from(self.rate_limited);
+        self.middleware.insert(at, middleware);
+    }
+
+    /// Make `limiter` the innermost middleware, replacing any previous one.
+    pub(crate) fn set_rate_limiter(&mut self, limiter: Arc<dyn Middleware>) {
+        if self.rate_limited {
+            self.middleware.pop();
+        }
+        self.middleware.push(limiter);
+        self.rate_limited = true;
+    }
+
+    /// Send `request` through every middleware and then over `http`.
+    pub(crate) async fn run(
+        &self,
+        http: &reqwest::Client,
+        request: Request,
+    ) -> Result<Response, LinearError> {
+        Next {
+            http,
+            middleware: &self.middleware,
+            upgrade: None,
+        }
+        .run(request)
+        .await
+    }
+
+    /// Send a handshake through every middleware and then over `http`.
+    /// Returns the response as middleware saw it and, if the server switched
+    /// protocols, the live response to upgrade.
+    pub(crate) async fn upgrade(
+        &self,
+        http: &reqwest::Client,
+        request: Request,
+    ) -> Result<(Response, Option<reqwest::Response>), LinearError> {
+        let slot = UpgradeSlot::default();
+        let response = Next {
+            http,
+            middleware: &self.middleware,
+            upgrade: Some(&slot),
+        }
+        .run(request)
+        .await?;
+        Ok((
+            response,
+            slot.into_inner().unwrap_or_else(|e| e.into_inner()),
+        ))
+    }
+}
+
+impl fmt::Debug for Pipeline {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Pipeline({} middleware)", self.middleware.len())
+    }
+}
+
+/// Headers whose values [`Logging`] never prints.
+const SENSITIVE_HEADERS: &[&str] = &["authorization", "proxy-authorization", "cookie"];
+
+/// Format headers for logging, masking credentials.
+pub fn redact_headers(headers: &HeaderMap) -> String {
+    headers
+        .iter()
+        .map(|(name, value)| {
+            let value = if SENSITIVE_HEADERS.contains(&name.as_str()) {
+                "[redacted]"
+            } else {
+                value.to_str().unwrap_or("[binary]")
+            };
+            format!("{}: {}", name, value)
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Logs each request's operation, status and duration.
+///
+/// Credentials in headers are always redacted. Bodies are omitted unless
+/// [`bodies`](Self::bodies) is enabled; file upload and download bodies are
+/// never logged.
+pub struct Logging {
+    sink: Box<dyn Fn(&str) + Send + Sync>,
+    headers: bool,
+    bodies: bool,
+}
+
+impl Logging {
+    /// Log each line with `sink`, e.g. to forward to a logging framework.
+    pub fn new(sink: impl Fn(&str) + Send + Sync + 'static) -> Self {
+        Self {
+            sink: Box::new(sink),
+            headers: false,
+            bodies: false,
+        }
+    }
+
+    /// Log to standard error.
+    pub fn stderr() -> Self {
+        Self::new(|line| eprintln!("{}", line))
+    }
+
+    /// Also log request headers, with credentials redacted.
+    pub fn headers(mut self, enabled: bool) -> Self {
+        self.headers = enabled;
+        self
+    }
+
+    /// Also log GraphQL request and response bodies.
+    pub fn bodies(mut self, enabled: bool) -> Self {
+        self.bodies = enabled;
+        self
+    }
+}
+
+impl fmt::Debug for Logging {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Logging")
+            .field("headers", &self.headers)
+            .field("bodies", &self.bodies)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Middleware for Logging {
+    fn handle<'a>(
+        &'a self,
+        request: Request,
+        next: Next<'a>,
+    ) -> BoxFuture<'a, Result<Response, LinearError>> {
+        Box::pin(async move {
+            let is_graphql = matches!(request.kind, RequestKind::Query | RequestKind::Mutation);
+            let label = format!("{} {}", request.method, request.operation);
+            if self.headers {
+                (self.sink)(&format!(
+                    "lineark: {} headers: {}",
+                    label,
+                    redact_headers(&request.headers)
+                ));
+            }
+            if self.bodies && is_graphql {
+                (self.sink)(&format!(
+                    "lineark: {} request: {}",
+                    label,
+                    String::from_utf8_lossy(&request.body)
+                ));
+            }
+
+            let started = Instant::now();
+            let result = next.run(request).await;
+            let elapsed = started.elapsed().as_millis();
+            match &result {
+                Ok(response) => {
+                    (self.sink)(&format!(
+                        "lineark: {} -> {} in {}ms",
+                        label, response.status, elapsed
+                    ));
+                    if self.bodies && is_graphql {
+                        (self.sink)(&format!("lineark: {} response: {}", label, response.text()));
+                    }
+                }
+                Err(err) => {
+                    (self.sink)(&format!(
+                        "lineark: {} failed in {}ms: {}",
+                        label, elapsed, err
+                    ));
+                }
+            }
+            result
+        })
+    }
+}
+
+/// Per-operation counters collected by [`Metrics`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct OperationStats {
+    /// Requests sent, including retries.
+    pub calls: u64,
+    /// Requests that failed in transport or returned a non-2xx status
+    /// (other than 101 for a subscription handshake).
+    pub failures: u64,
+    /// Time spent waiting for responses.
+    pub total_duration: Duration,
+}
+
+/// Counts requests and their durations per operation name.
+///
+/// Clones share their counters, so keep one clone to read
+/// [`snapshot`](Self::snapshot) after handing another to the client.
+#[derive(Debug, Clone, Default)]
+pub struct Metrics {
+    stats: Arc<Mutex<BTreeMap<String, OperationStats>>>,
+}
+
+impl Metrics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Counters collected so far, keyed by operation name.
+    pub fn snapshot(&self) -> BTreeMap<String, OperationStats> {
+        self.stats.lock().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    /// Reset all counters.
+    pub fn reset(&self) {
+        self.stats.lock().unwrap_or_else(|e| e.into_inner()).clear();
+    }
+
+    fn record(&self, operation: String, duration: Duration, failed: bool) {
+        let mut stats = self.stats.lock().unwrap_or_else(|e| e.into_inner());
+        let entry = stats.entry(operation).or_default();
+        entry.calls += 1;
+        entry.total_duration += duration;
+        if failed {
+            entry.failures += 1;
+        }
+    }
+}
+
+impl Middleware for Metrics {
+    fn handle<'a>(
+        &'a self,
+        request: Request,
+        next: Next<'a>,
+    ) -> BoxFuture<'a, Result<Response, LinearError>> {
+        Box::pin(async move {
+            let operation = request.operation.clone();
+            let started = Instant::now();
+            let result = next.run(request).await;
+            let failed = !matches!(
+                &result,
+                Ok(r) if r.status.is_success() || r.status == StatusCode::SWITCHING_PROTOCOLS
+            );
+            self.record(operation, started.elapsed(), failed);
+            result
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Client;
+    use wiremock::matchers::{header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    async fn mount_viewer(server: &MockServer) {
+        Mock::given(method("POST"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({"data": {"viewer": {"id": "u1"}}})),
+            )
+            .mount(server)
+            .await;
+    }
+
+    fn builder(server: &MockServer) -> crate::ClientBuilder {
+        Client::builder()
+            .token("secret-token")
+            .base_url(server.uri())
+    }
+
+    async fn viewer_id(client: &Client) -> Result<serde_json::Value, LinearError> {
+        client
+            .execute::<serde_json::Value>(
+                "query Viewer { viewer { id } }",
+                serde_json::json!({}),
+                "viewer",
+            )
+            .await
+    }
+
+    /// Records the operation names it sees and tags the request.
+    struct Recorder {
+        name: &'static str,
+        seen: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl Middleware for Recorder {
+        fn handle<'a>(
+            &'a self,
+            mut request: Request,
+            next: Next<'a>,
+        ) -> BoxFuture<'a, Result<Response, LinearError>> {
+            Box::pin(async move {
+                self.seen.lock().unwrap().push(format!(
+                    "{} {:?} {}",
+                    self.name, request.kind, request.operation
+                ));
+                request
+                    .headers
+                    .insert("x-correlation-id", self.name.parse().unwrap());
+                let response = next.run(request).await;
+                self.seen
+                    .lock()
+                    .unwrap()
+                    .push(format!("{} done", self.name));
+                response
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn middleware_runs_in_order_and_can_add_headers() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(header("x-correlation-id", "inner"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({"data": {"viewer": {"id": "u1"}}})),
+            )
+            .mount(&server)
+            .await;
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let client = builder(&server)
+            .middleware(Recorder {
+                name: "outer",
+                seen: seen.clone(),
+            })
+            .middleware(Recorder {
+                name: "inner",
+                seen: seen.clone(),
+            })
+            .build()
+            .unwrap();
+
+        assert_eq!(viewer_id(&client).await.unwrap()["id"], "u1");
+        assert_eq!(
+            *seen.lock().unwrap(),
+            vec![
+                "outer Query Viewer",
+                "inner Query Viewer",
+                "inner done",
+                "outer done"
+            ]
+        );
+    }
+
+    /// Answers every request itself without touching the network.
+    struct Canned;
+
+    impl Middleware for Canned {
+        fn handle<'a>(
+            &'a self,
+            request: Request,
+            _next: Next<'a>,
+        ) -> BoxFuture<'a, Result<Response, LinearError>> {
+            Box::pin(async move {
+                let query: serde_json::Value = serde_json::from_slice(&request.body).unwrap();
+                assert!(query["query"].as_str().unwrap().starts_with("query Viewer"));
+                Ok(Response {
+                    status: StatusCode::OK,
+                    headers: HeaderMap::new(),
+                    body: br#"{"data": {"viewer": {"id": "canned"}}}"#.to_vec(),
+                })
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn middleware_sees_body_and_can_short_circuit() {
+        let server = MockServer::start().await;
+        let client = builder(&server).middleware(Canned).build().unwrap();
+
+        assert_eq!(viewer_id(&client).await.unwrap()["id"], "canned");
+        assert!(server.received_requests().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn middleware_wraps_file_downloads() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/file.png"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(vec![1, 2, 3]))
+            .mount(&server)
+            .await;
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let client = builder(&server)
+            .middleware(Recorder {
+                name: "m",
+                seen: seen.clone(),
+            })
+            .build()
+            .unwrap();
+
+        let result = client
+            .download_url(&format!("{}/file.png", server.uri()))
+            .await
+            .unwrap();
+        assert_eq!(result.bytes, vec![1, 2, 3]);
+        assert_eq!(seen.lock().unwrap()[0], "m Download download");
+    }
+
+    #[tokio::test]
+    async fn metrics_count_calls_and_failures_per_operation() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(500))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        mount_viewer(&server).await;
+        let metrics = Metrics::new();
+        let client = builder(&server)
+            .middleware(metrics.clone())
+            .build()
+            .unwrap();
+
+        assert!(viewer_id(&client).await.is_err());
+        viewer_id(&client).await.unwrap();
+        viewer_id(&client).await.unwrap();
+
+        let stats = metrics.snapshot();
+        assert_eq!(stats["Viewer"].calls, 3);
+        assert_eq!(stats["Viewer"].failures, 1);
+        metrics.reset();
+        assert!(metrics.snapshot().is_empty());
+    }
+
+    #[tokio::test]
+    async fn logging_redacts_credentials() {
+        let server = MockServer::start().await;
+        mount_viewer(&server).await;
+        let lines = Arc::new(Mutex::new(Vec::new()));
+        let sink = lines.clone();
+        let logging = Logging::new(move |line| sink.lock().unwrap().push(line.to_string()))
+            .headers(true)
+            .bodies(true);
+        let client = builder(&server).middleware(logging).build().unwrap();
+
+        viewer_id(&client).await.unwrap();
+
+        let lines = lines.lock().unwrap();
+        let log = lines.join("\n");
+        assert!(!log.contains("secret-token"), "{log}");
+        assert!(log.contains("authorization: [redacted]"), "{log}");
+        assert!(log.contains("POST Viewer -> 200 OK in"), "{log}");
+        assert!(log.contains(r#""viewer":{"id":"u1"}"#), "{log}");
+    }
+}
@@ -0,0 +1,348 @@
+//! Prepared GraphQL operations.
+//!
+//! An [`Operation`] is a fully built query or mutation — document pieces,
+//! variables, and how to extract the typed result — that has not been sent
+//! yet. Every generated query and mutation is built as an `Operation` and
+//! then executed, so the same value can either be sent on its own with
+//! [`Operation::execute`] or combined with others through
+//! [`Client::batch`](crate::Client::batch).
+//!
+//! Obtain operations from query builders via `.into_operation()`, or from the
+//! functions in [`generated::operations`](crate::generated::operations) for
+//! queries without optional arguments and for mutations.
+
+use crate::client::Client;
+use crate::error::LinearError;
+use crate::field_selection::declare_variables;
+use crate::idempotency::ClientId;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::marker::PhantomData;
+
+/// Whether an operation reads or writes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OperationKind {
+    Query,
+    Mutation,
+}
+
+impl OperationKind {
+    pub(crate) fn keyword(self) -> &'static str {
+        match self {
+            Self::Query => "query",
+            Self::Mutation => "mutation",
+        }
+    }
+}
+
+/// A mutation's result together with the payload metadata Linear returns
+/// alongside it. Obtained with [`Operation::with_meta`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MutationResult<T> {
+    /// Whether the mutation succeeded; always `true`, as failed mutations
+    /// are returned as errors.
+    pub success: bool,
+    /// The sync ID the mutation was recorded at. Read-your-writes
+    /// consistency can wait for a sync stream to reach it.
+    pub last_sync_id: Option<f64>,
+    /// The mutated entity, or the whole payload for mutations without one.
+    pub entity: T,
+}
+
+/// A prepared GraphQL operation that resolves to a `T`.
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct Operation<T> {
+    pub(crate) kind: OperationKind,
+    pub(crate) name: String,
+    pub(crate) variable_defs: String,
+    pub(crate) selection: String,
+    pub(crate) variables: serde_json::Map<String, serde_json::Value>,
+    pub(crate) data_path: String,
+    pub(crate) entity_field: Option<String>,
+    pub(crate) with_meta: bool,
+    pub(crate) client_id: Option<ClientId>,
+    /// Why the operation can't be sent, e.g. a variable declared twice.
+    pub(crate) invalid: Option<String>,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T: DeserializeOwned> Operation<T> {
+    /// A query operation.
+    ///
+    /// - `name` — operation name, e.g. `"Team"`.
+    /// - `variable_defs` — variable definitions without parentheses, e.g.
+    ///   `"$id: String!"`; empty for none.
+    /// - `selection` — the root field with its arguments and sub-selection,
+    ///   e.g. `"team(id: $id) { id name }"`. Variables declared inline by
+    ///   nested field arguments (`$name: Type`) are moved to the variable
+    ///   definitions.
+    /// - `data_path` — the root field name the result is read from.
+    pub fn query(
+        name: impl Into<String>,
+        variable_defs: impl Into<String>,
+        selection: impl Into<String>,
+        variables: serde_json::Value,
+        data_path: impl Into<String>,
+    ) -> Self {
+        Self::new(
+            OperationKind::Query,
+            name.into(),
+            variable_defs.into(),
+            selection.into(),
+            variables,
+            data_path.into(),
+        )
+    }
+
+    /// A mutation operation. Arguments are as for [`Operation::query`].
+    pub fn mutation(
+        name: impl Into<String>,
+        variable_defs: impl Into<String>,
+        selection: impl Into<String>,
+        variables: serde_json::Value,
+        data_path: impl Into<String>,
+    ) -> Self {
+        Self::new(
+            OperationKind::Mutation,
+            name.into(),
+            variable_defs.into(),
+            selection.into(),
+            variables,
+            data_path.into(),
+        )
+    }
+
+    fn new(
+        kind: OperationKind,
+        name: String,
+        variable_defs: String,
+        selection: String,
+        variables: serde_json::Value,
+        data_path: String,
+    ) -> Self {
+        let variables = match variables {
+            serde_json::Value::Object(map) => map,
+            _ => serde_json::Map::new(),
+        };
+        let (selection, variable_defs, invalid) =
+            match declare_variables(&variable_defs, &selection) {
+                Ok((selection, variable_defs)) => (selection, variable_defs, None),
+                Err(e) => (selection, variable_defs, Some(e)),
+            };
+        Self {
+            kind,
+            name,
+            variable_defs,
+            selection,
+            variables,
+            data_path,
+            entity_field: None,
+            with_meta: false,
+            client_id: None,
+            invalid,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Set a variable, typically one declared by a nested field's
+    /// `#[graphql(args(...))]`. Unset nullable variables are sent as null.
+    pub fn variable(mut self, name: impl Into<String>, value: impl Serialize) -> Self {
+        self.variables.insert(name.into(), serde_json::json!(value));
+        self
+    }
+
+    /// For mutation payloads: require `success: true` and return the named
+    /// entity field of the payload instead of the payload itself.
+    pub fn with_entity_field(mut self, entity_field: impl Into<String>) -> Self {
+        self.entity_field = Some(entity_field.into());
+        self
+    }
+
+    /// For create mutations: the object variable `input_variable` takes an
+    /// optional `id`, and `lookup` — a root query field selection using `$id`,
+    /// e.g. `"issue(id: $id) { id }"` — fetches the created entity.
+    ///
+    /// Lets clients with [`idempotent_creates`](crate::ClientBuilder::idempotent_creates)
+    /// fill in the ID and retry safely.
+    pub fn with_client_id(
+        mut self,
+        input_variable: impl Into<String>,
+        lookup: impl Into<String>,
+    ) -> Self {
+        self.client_id = Some(ClientId {
+            input_variable: input_variable.into(),
+            lookup: lookup.into(),
+        });
+        self
+    }
+
+    /// For mutations: resolve to a [`MutationResult`] carrying the payload's
+    /// `success` and `lastSyncId` next to the entity, instead of the entity
+    /// alone. Queries have no payload metadata; sending one built with
+    /// `with_meta` fails with [`LinearError::Internal`].
+    pub fn with_meta(self) -> Operation<MutationResult<T>> {
+        let invalid = match self.kind {
+            OperationKind::Query => self
+                .invalid
+                .or_else(|| Some("with_meta() applies only to mutations".to_string())),
+            OperationKind::Mutation => self.invalid,
+        };
+        Operation {
+            kind: self.kind,
+            name: self.name,
+            variable_defs: self.variable_defs,
+            selection: self.selection,
+            variables: self.variables,
+            data_path: self.data_path,
+            entity_field: self.entity_field,
+            with_meta: true,
+            client_id: self.client_id,
+            invalid,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Whether this is a query or a mutation.
+    pub fn kind(&self) -> OperationKind {
+        self.kind
+    }
+
+    /// The operation name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The complete GraphQL document for sending this operation on its own.
+    pub fn document(&self) -> String {
+        if self.variable_defs.is_empty() {
+            format!(
+                "{} {} {{ {} }}",
+                self.kind.keyword(),
+                self.name,
+                self.selection
+            )
+        } else {
+            format!(
+                "{} {}({}) {{ {} }}",
+                self.kind.keyword(),
+                self.name,
+                self.variable_defs,
+                self.selection
+            )
+        }
+    }
+
+    /// The variables sent with the document.
+    pub fn variables(&self) -> serde_json::Value {
+        serde_json::Value::Object(self.variables.clone())
+    }
+
+    /// Send this operation on its own.
+    pub async fn execute(self, client: &Client) -> Result<T, LinearError> {
+        self.check()?;
+        match self.client_id.clone() {
+            Some(client_id) if client.idempotent_creates() => {
+                client.execute_create(self, client_id).await
+            }
+            _ => self.send(client).await,
+        }
+    }
+
+    /// Fail if the operation can't be sent.
+    pub(crate) fn check(&self) -> Result<(), LinearError> {
+        match &self.invalid {
+            Some(reason) => Err(LinearError::Internal(format!(
+                "Invalid operation {}: {}",
+                self.name, reason
+            ))),
+            None => Ok(()),
+        }
+    }
+
+    /// Send this operation once, as is.
+    pub(crate) async fn send(&self, client: &Client) -> Result<T, LinearError> {
+        self.check()?;
+        let document = self.document();
+        let variables = serde_json::Value::Object(self.variables.clone());
+        if self.kind == OperationKind::Mutation {
+            client
+                .execute_mutation::<T>(
+                    &document,
+                    variables,
+                    &self.data_path,
+                    self.entity_field.as_deref(),
+                    self.with_meta,
+                )
+                .await
+        } else {
+            client
+                .execute::<T>(&document, variables, &self.data_path)
+                .await
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn document_with_variables() {
+        let op = Operation::<serde_json::Value>::query(
+            "Team",
+            "$id: String!",
+            "team(id: $id) { id }",
+            serde_json::json!({"id": "t1"}),
+            "team",
+        );
+        assert_eq!(
+            op.document(),
+            "query Team($id: String!) { team(id: $id) { id } }"
+        );
+        assert_eq!(op.variables(), serde_json::json!({"id": "t1"}));
+    }
+
+    #[test]
+    fn document_without_variables() {
+        let op = Operation::<serde_json::Value>::query(
+            "Viewer",
+            "",
+            "viewer { id }",
+            serde_json::json!({}),
+            "viewer",
+        );
+        assert_eq!(op.document(), "query Viewer { viewer { id } }");
+    }
+
+    #[test]
+    fn nested_variables_are_declared_on_the_operation() {
+        let op = Operation::<serde_json::Value>::query(
+            "Issue",
+            "$id: String!",
+            "issue(id: $id) { comments(first: $count: Int) { nodes { id } } }",
+            serde_json::json!({"id": "i1"}),
+            "issue",
+        )
+        .variable("count", 5);
+        assert_eq!(
+            op.document(),
+            "query Issue($id: String!, $count: Int) { issue(id: $id) { comments(first: $count) { nodes { id } } } }"
+        );
+        assert_eq!(op.variables(), serde_json::json!({"id": "i1", "count": 5}));
+    }
+
+    #[test]
+    fn mutation_document() {
+        let op = Operation::<serde_json::Value>::mutation(
+            "TeamDelete",
+            "$id: String!",
+            "teamDelete(id: $id) { success }",
+            serde_json::json!({"id": "t1"}),
+            "teamDelete",
+        );
+        assert_eq!(op.kind(), OperationKind::Mutation);
+        assert!(op.document().starts_with("mutation TeamDelete("));
+    }
+}
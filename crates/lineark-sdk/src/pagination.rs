@@ -1,9 +1,14 @@
 //! Cursor-based pagination types.
 //!
 //! Linear uses Relay-style connections. [`Connection`] wraps a page of nodes
-//! with [`PageInfo`] for cursor-based traversal.
+//! with [`PageInfo`] for cursor-based traversal. Generated connection query
+//! builders also expose `.stream()`, `.all()` and `.take_all(max)`, which
+//! follow `endCursor` automatically via [`paginate`].
 
+use crate::error::LinearError;
+use futures::{Stream, TryStreamExt};
 use serde::{Deserialize, Serialize};
+use std::future::Future;
 
 /// Relay-style page info for cursor-based pagination.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -26,6 +31,48 @@ pub struct Connection<T> {
     pub page_info: PageInfo,
 }
 
+/// Turn a page fetcher into a stream of nodes that follows cursors forward.
+///
+/// `fetch` is called with the cursor to resume after (`None` for the first
+/// page, unless `after` is given) and must return the next page. Fetching
+/// stops once a page reports `hasNextPage: false`, has no `endCursor`, or
+/// comes back empty. Pages are requested lazily, so dropping the stream early
+/// (e.g. via `take`) avoids fetching pages that are never consumed.
+pub fn paginate<'a, T, F, Fut>(
+    after: Option<String>,
+    fetch: F,
+) -> impl Stream<Item = Result<T, LinearError>> + 'a
+where
+    T: 'a,
+    F: FnMut(Option<String>) -> Fut + 'a,
+    Fut: Future<Output = Result<Connection<T>, LinearError>> + 'a,
+{
+    struct State<F> {
+        fetch: F,
+        cursor: Option<String>,
+        done: bool,
+    }
+
+    let state = State {
+        fetch,
+        cursor: after,
+        done: false,
+    };
+    futures::stream::try_unfold(state, |mut state| async move {
+        if state.done {
+            return Ok::<_, LinearError>(None);
+        }
+        let page = (state.fetch)(state.cursor.take()).await?;
+        state.done = !page.page_info.has_next_page
+            || page.page_info.end_cursor.is_none()
+            || page.nodes.is_empty();
+        state.cursor = page.page_info.end_cursor;
+        let nodes = futures::stream::iter(page.nodes.into_iter().map(Ok));
+        Ok(Some((nodes, state)))
+    })
+    .try_flatten()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -98,4 +145,94 @@ mod tests {
         assert_eq!(json["hasNextPage"], true);
         assert_eq!(json["endCursor"], "abc");
     }
+
+    fn page(nodes: &[i32], next: Option<&str>) -> Connection<i32> {
+        Connection {
+            nodes: nodes.to_vec(),
+            page_info: PageInfo {
+                has_next_page: next.is_some(),
+                end_cursor: next.map(String::from),
+                ..Default::default()
+            },
+        }
+    }
+
+    #[tokio::test]
+    async fn paginate_follows_cursors_until_last_page() {
+        let mut seen = Vec::new();
+        let items: Vec<i32> = paginate(None, |after| {
+            seen.push(after.clone());
+            let result = match after.as_deref() {
+                None => page(&[1, 2], Some("c1")),
+                Some("c1") => page(&[3, 4], Some("c2")),
+                Some("c2") => page(&[5], None),
+                other => panic!("unexpected cursor {other:?}"),
+            };
+            async move { Ok(result) }
+        })
+        .try_collect()
+        .await
+        .unwrap();
+
+        assert_eq!(items, vec![1, 2, 3, 4, 5]);
+        assert_eq!(
+            seen,
+            vec![None, Some("c1".to_string()), Some("c2".to_string())]
+        );
+    }
+
+    #[tokio::test]
+    async fn paginate_starts_after_given_cursor() {
+        let items: Vec<i32> = paginate(Some("start".to_string()), |after| {
+            assert_eq!(after.as_deref(), Some("start"));
+            async move { Ok(page(&[7], None)) }
+        })
+        .try_collect()
+        .await
+        .unwrap();
+        assert_eq!(items, vec![7]);
+    }
+
+    #[tokio::test]
+    async fn paginate_is_lazy() {
+        use futures::StreamExt;
+        let mut calls = 0;
+        let items: Vec<i32> = paginate(None, |_| {
+            calls += 1;
+            async move { Ok(page(&[1, 2], Some("more"))) }
+        })
+        .take(3)
+        .try_collect()
+        .await
+        .unwrap();
+        assert_eq!(items, vec![1, 2, 1]);
+        assert_eq!(calls, 2);
+    }
+
+    #[tokio::test]
+    async fn paginate_stops_on_empty_page() {
+        let mut calls = 0;
+        let items: Vec<i32> = paginate(None, |_| {
+            calls += 1;
+            async move { Ok(page(&[], Some("loop"))) }
+        })
+        .try_collect()
+        .await
+        .unwrap();
+        assert!(items.is_empty());
+        assert_eq!(calls, 1);
+    }
+
+    #[tokio::test]
+    async fn paginate_propagates_errors() {
+        let result: Result<Vec<i32>, _> = paginate(None, |after| async move {
+            match after {
+                None => Ok(page(&[1], Some("c1"))),
+                Some(_) => Err(LinearError::Internal("boom".to_string())),
+            }
+        })
+        .try_collect()
+        .await;
+        assert!(matches!(result, Err(LinearError::Internal(_))));
+    }
 }
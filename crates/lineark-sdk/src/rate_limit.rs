@@ -0,0 +1,653 @@
+//! Client-side rate limiting.
+//!
+//! Linear budgets each token by request count and by query complexity, both
+//! replenished over an hour, and reports what is left in `X-RateLimit-*`
+//! response headers. Workers sharing a token that only react to 429s waste
+//! requests and back off in lockstep. A [`RateLimiter`] instead reads the
+//! headers into a token bucket shared by all its clones, holds requests back
+//! until the budget covers them, and optionally caps how many are in flight:
+//!
+//! ```no_run
+//! # async fn example() -> Result<(), lineark_sdk::LinearError> {
+//! use lineark_sdk::rate_limit::RateLimiter;
+//! use lineark_sdk::Client;
+//!
+//! let limiter = RateLimiter::new()
+//!     .max_concurrency(4)
+//!     .request_headroom(50); // leave 50 requests for other consumers
+//! let client = Client::builder()
+//!     .token("lin_api_...")
+//!     .rate_limiter(limiter.clone())
+//!     .build()?;
+//!
+//! limiter.refresh(&client).await?; // seed from the `rateLimitStatus` query
+//! if let Some(requests) = limiter.status().requests {
+//!     println!("{} of {} requests left", requests.remaining, requests.limit);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! The complexity of a query is only known once Linear answers, so each
+//! operation is charged the complexity it cost last time (`X-Complexity`).
+//! Only GraphQL requests and subscription handshakes count; file downloads
+//! and uploads pass through.
+
+use crate::client::Client;
+use crate::error::{LinearError, RateLimitKind};
+use crate::field_selection::GraphQLFields;
+use crate::generated::types::{RateLimitPayload, RateLimitResultPayload};
+use crate::middleware::{BoxFuture, Middleware, Next, Request, RequestKind, Response};
+use reqwest::header::HeaderMap;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+use tokio::time::Instant;
+
+/// Window Linear replenishes budgets over, assumed when it doesn't say when
+/// a budget resets.
+const DEFAULT_WINDOW: Duration = Duration::from_secs(3600);
+
+/// One budget as last reported by Linear.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Budget {
+    /// Total budget per window.
+    pub limit: u64,
+    /// Budget available now: what Linear last reported, replenished since,
+    /// less what requests in flight are expected to use.
+    pub remaining: u64,
+    /// When the budget is fully replenished, if Linear said.
+    pub reset: Option<SystemTime>,
+}
+
+/// Snapshot of a [`RateLimiter`]'s view of the budgets.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct RateLimitStatus {
+    /// Request-count budget; `None` until a response reported it.
+    pub requests: Option<Budget>,
+    /// Query complexity budget; `None` until a response reported it.
+    pub complexity: Option<Budget>,
+    /// Complexity of the most recently answered query.
+    pub last_complexity: Option<u64>,
+}
+
+/// Delays requests to stay within Linear's budgets. See the
+/// [module docs](self).
+///
+/// Clones share their budget and concurrency limit, so keep one clone to read
+/// [`status`](Self::status) after handing another to the client.
+#[derive(Debug, Clone, Default)]
+pub struct RateLimiter {
+    permits: Option<Arc<Semaphore>>,
+    request_headroom: u64,
+    complexity_headroom: u64,
+    max_wait: Option<Duration>,
+    state: Arc<Mutex<State>>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    requests: Option<Bucket>,
+    complexity: Option<Bucket>,
+    /// Requests sent but not yet answered, and their estimated complexity.
+    in_flight_requests: u64,
+    in_flight_complexity: u64,
+    /// Latest `X-Complexity` per operation name, charged up front next time.
+    complexity_by_operation: HashMap<String, u64>,
+    last_complexity: Option<u64>,
+    /// Set from `Retry-After` on a 429; nothing is sent before it.
+    blocked_until: Option<Instant>,
+}
+
+/// A budget that refills linearly until it is full at `reset`.
+#[derive(Debug, Clone, Copy)]
+struct Bucket {
+    limit: f64,
+    remaining: f64,
+    observed: Instant,
+    reset: Option<(Instant, SystemTime)>,
+}
+
+impl Bucket {
+    fn new(limit: f64, remaining: f64, reset_ms: Option<f64>, now: Instant) -> Self {
+        let reset = reset_ms.map(|ms| {
+            let at = UNIX_EPOCH + Duration::from_millis(ms.max(0.0) as u64);
+            let until = at.duration_since(SystemTime::now()).unwrap_or_default();
+            (now + until, at)
+        });
+        Self {
+            limit,
+            remaining,
+            observed: now,
+            reset,
+        }
+    }
+
+    /// Units replenished per second.
+    fn refill_rate(&self) -> f64 {
+        match self.reset {
+            Some((reset, _)) if reset > self.observed => {
+                (self.limit - self.remaining).max(0.0) / (reset - self.observed).as_secs_f64()
+            }
+            _ => self.limit / DEFAULT_WINDOW.as_secs_f64(),
+        }
+    }
+
+    fn available(&self, now: Instant) -> f64 {
+        if self.reset.is_some_and(|(reset, _)| now >= reset) {
+            return self.limit;
+        }
+        let elapsed = now.saturating_duration_since(self.observed).as_secs_f64();
+        (self.remaining + elapsed * self.refill_rate()).min(self.limit)
+    }
+
+    /// How long until `amount` plus `headroom` is available. Amounts above
+    /// the limit only wait for a full bucket.
+    fn wait_for(&self, amount: f64, headroom: f64, now: Instant) -> Duration {
+        let needed = (amount + headroom).min(self.limit);
+        let deficit = needed - self.available(now);
+        if deficit <= 0.0 {
+            return Duration::ZERO;
+        }
+        let refill = match self.refill_rate() {
+            rate if rate > 0.0 => Duration::from_secs_f64(deficit / rate),
+            _ => DEFAULT_WINDOW,
+        };
+        match self.reset {
+            Some((reset, _)) => refill.min(reset.saturating_duration_since(now)),
+            None => refill,
+        }
+    }
+
+    fn take(&mut self, amount: f64, now: Instant) {
+        self.remaining = self.available(now) - amount;
+        self.observed = now;
+    }
+
+    fn budget(&self, now: Instant) -> Budget {
+        Budget {
+            limit: self.limit as u64,
+            remaining: self.available(now).max(0.0) as u64,
+            reset: self.reset.map(|(_, at)| at),
+        }
+    }
+}
+
+impl RateLimiter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Allow at most `max` requests in flight at once, across all clones.
+    /// Values below 1 are treated as 1.
+    pub fn max_concurrency(mut self, max: usize) -> Self {
+        self.permits = Some(Arc::new(Semaphore::new(max.max(1))));
+        self
+    }
+
+    /// Hold requests back while fewer than `requests` requests would be left
+    /// afterwards, leaving room for other consumers of the token.
+    pub fn request_headroom(mut self, requests: u64) -> Self {
+        self.request_headroom = requests;
+        self
+    }
+
+    /// Hold requests back while less than `complexity` complexity would be
+    /// left afterwards.
+    pub fn complexity_headroom(mut self, complexity: u64) -> Self {
+        self.complexity_headroom = complexity;
+        self
+    }
+
+    /// Fail with [`LinearError::RateLimited`] instead of waiting longer than
+    /// `max_wait` for budget. Requests wait as long as needed by default.
+    pub fn max_wait(mut self, max_wait: Duration) -> Self {
+        self.max_wait = Some(max_wait);
+        self
+    }
+
+    /// The budgets as currently estimated.
+    pub fn status(&self) -> RateLimitStatus {
+        let state = self.lock();
+        let now = Instant::now();
+        RateLimitStatus {
+            requests: state.requests.map(|b| b.budget(now)),
+            complexity: state.complexity.map(|b| b.budget(now)),
+            last_complexity: state.last_complexity,
+        }
+    }
+
+    /// Replace the estimates with the budgets reported by the
+    /// `rateLimitStatus` query, sent through `client`.
+    pub async fn refresh(&self, client: &Client) -> Result<RateLimitStatus, LinearError> {
+        let payload = client.rate_limit_status::<Limits>().await?;
+        {
+            let mut state = self.lock();
+            let now = Instant::now();
+            for limit in payload.limits {
+                let (Some(kind), Some(allowed), Some(remaining)) =
+                    (limit.r#type, limit.allowed_amount, limit.remaining_amount)
+                else {
+                    continue;
+                };
+                let bucket = Bucket::new(allowed, remaining, limit.reset, now);
+                match classify(&kind) {
+                    RateLimitKind::Requests => state.requests = Some(bucket),
+                    RateLimitKind::Complexity => state.complexity = Some(bucket),
+                    RateLimitKind::Unknown => {}
+                }
+            }
+        }
+        Ok(self.status())
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Wait for a concurrency permit and for budget, then charge the request.
+    async fn reserve(&self, operation: &str) -> Result<Reservation<'_>, LinearError> {
+        let permit = match &self.permits {
+            Some(permits) => Some(
+                permits
+                    .clone()
+                    .acquire_owned()
+                    .await
+                    .map_err(|e| LinearError::Internal(e.to_string()))?,
+            ),
+            None => None,
+        };
+        loop {
+            let (wait, kind) = {
+                let mut state = self.lock();
+                let now = Instant::now();
+                let complexity = state
+                    .complexity_by_operation
+                    .get(operation)
+                    .copied()
+                    .unwrap_or_default();
+                let blocked = state
+                    .blocked_until
+                    .map(|until| until.saturating_duration_since(now))
+                    .unwrap_or_default();
+                let requests = state.requests.map_or(Duration::ZERO, |b| {
+                    b.wait_for(1.0, self.request_headroom as f64, now)
+                });
+                let complex = state.complexity.map_or(Duration::ZERO, |b| {
+                    b.wait_for(complexity as f64, self.complexity_headroom as f64, now)
+                });
+                let wait = blocked.max(requests).max(complex);
+                if wait.is_zero() {
+                    if let Some(bucket) = &mut state.requests {
+                        bucket.take(1.0, now);
+                    }
+                    if let Some(bucket) = &mut state.complexity {
+                        bucket.take(complexity as f64, now);
+                    }
+                    state.in_flight_requests += 1;
+                    state.in_flight_complexity += complexity;
+                    return Ok(Reservation {
+                        limiter: self,
+                        complexity,
+                        released: false,
+                        _permit: permit,
+                    });
+                }
+                let kind = if complex > requests {
+                    RateLimitKind::Complexity
+                } else if requests > blocked {
+                    RateLimitKind::Requests
+                } else {
+                    RateLimitKind::Unknown
+                };
+                (wait, kind)
+            };
+            if self.max_wait.is_some_and(|max| wait > max) {
+                return Err(LinearError::RateLimited {
+                    retry_after: Some(wait.as_secs_f64()),
+                    kind,
+                    message: format!(
+                        "client-side rate limit: budget available in {:.1}s",
+                        wait.as_secs_f64()
+                    ),
+                });
+            }
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
+
+/// A charged request. Dropping it without [`finish`](Self::finish), e.g.
+/// when the request future is cancelled, still releases it.
+struct Reservation<'a> {
+    limiter: &'a RateLimiter,
+    complexity: u64,
+    released: bool,
+    _permit: Option<OwnedSemaphorePermit>,
+}
+
+impl Reservation<'_> {
+    /// Release the request and adopt the budgets reported in `response`.
+    fn finish(mut self, operation: &str, response: Option<&Response>) {
+        let mut state = self.limiter.lock();
+        self.release(&mut state);
+        let Some(response) = response else {
+            return;
+        };
+        let headers = &response.headers;
+        let now = Instant::now();
+        if let Some(bucket) = bucket_from_headers(headers, "requests", now) {
+            state.requests = Some(Bucket {
+                remaining: bucket.remaining - state.in_flight_requests as f64,
+                ..bucket
+            });
+        }
+        if let Some(bucket) = bucket_from_headers(headers, "complexity", now) {
+            state.complexity = Some(Bucket {
+                remaining: bucket.remaining - state.in_flight_complexity as f64,
+                ..bucket
+            });
+        }
+        if let Some(complexity) = header_f64(headers, "x-complexity") {
+            let complexity = complexity.max(0.0) as u64;
+            state.last_complexity = Some(complexity);
+            state
+                .complexity_by_operation
+                .insert(operation.to_string(), complexity);
+        }
+        if response.status == 429 {
+            if let Some(seconds) = header_f64(headers, "retry-after") {
+                let until = now + Duration::from_secs_f64(seconds.max(0.0));
+                state.blocked_until = state.blocked_until.max(Some(until));
+            }
+        }
+    }
+
+    fn release(&mut self, state: &mut State) {
+        if !self.released {
+            self.released = true;
+            state.in_flight_requests = state.in_flight_requests.saturating_sub(1);
+            state.in_flight_complexity = state.in_flight_complexity.saturating_sub(self.complexity);
+        }
+    }
+}
+
+impl Drop for Reservation<'_> {
+    fn drop(&mut self) {
+        if !self.released {
+            let limiter = self.limiter;
+            self.release(&mut limiter.lock());
+        }
+    }
+}
+
+impl Middleware for RateLimiter {
+    fn handle<'a>(
+        &'a self,
+        request: Request,
+        next: Next<'a>,
+    ) -> BoxFuture<'a, Result<Response, LinearError>> {
+        if !matches!(
+            request.kind,
+            RequestKind::Query | RequestKind::Mutation | RequestKind::Subscribe
+        ) {
+            return next.run(request);
+        }
+        Box::pin(async move {
+            let operation = request.operation.clone();
+            let reservation = self.reserve(&operation).await?;
+            let result = next.run(request).await;
+            reservation.finish(&operation, result.as_ref().ok());
+            result
+        })
+    }
+}
+
+/// `rateLimitStatus { limits { ... } }`.
+#[derive(serde::Deserialize)]
+struct Limits {
+    #[serde(default)]
+    limits: Vec<RateLimitResultPayload>,
+}
+
+impl GraphQLFields for Limits {
+    type FullType = RateLimitPayload;
+    fn selection() -> String {
+        format!("limits {{ {} }}", RateLimitResultPayload::selection())
+    }
+}
+
+/// Which budget a `rateLimitStatus` limit type describes.
+fn classify(kind: &str) -> RateLimitKind {
+    let kind = kind.to_ascii_lowercase();
+    if kind.contains("complexity") {
+        RateLimitKind::Complexity
+    } else if kind.contains("request") {
+        RateLimitKind::Requests
+    } else {
+        RateLimitKind::Unknown
+    }
+}
+
+fn header_f64(headers: &HeaderMap, name: &str) -> Option<f64> {
+    headers
+        .get(name)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.trim().parse().ok())
+}
+
+/// `X-RateLimit-{Requests,Complexity}-{Limit,Remaining,Reset}`.
+fn bucket_from_headers(headers: &HeaderMap, budget: &str, now: Instant) -> Option<Bucket> {
+    let header = |field: &str| header_f64(headers, &format!("x-ratelimit-{budget}-{field}"));
+    Some(Bucket::new(
+        header("limit")?,
+        header("remaining")?,
+        header("reset"),
+        now,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{body_string_contains, method};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn reset_in(duration: Duration) -> String {
+        let at = SystemTime::now() + duration;
+        at.duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_millis()
+            .to_string()
+    }
+
+    fn viewer(requests: (u64, u64), reset: Duration) -> ResponseTemplate {
+        let (limit, remaining) = requests;
+        ResponseTemplate::new(200)
+            .insert_header("x-ratelimit-requests-limit", limit.to_string())
+            .insert_header("x-ratelimit-requests-remaining", remaining.to_string())
+            .insert_header("x-ratelimit-requests-reset", reset_in(reset))
+            .insert_header("x-ratelimit-complexity-limit", "10000")
+            .insert_header("x-ratelimit-complexity-remaining", "9000")
+            .insert_header("x-complexity", "12")
+            .set_body_json(serde_json::json!({ "data": { "viewer": { "id": "u1" } } }))
+    }
+
+    fn client(server: &MockServer, limiter: &RateLimiter) -> Client {
+        Client::builder()
+            .token("test-token")
+            .base_url(server.uri())
+            .rate_limiter(limiter.clone())
+            .build()
+            .unwrap()
+    }
+
+    async fn viewer_id(client: &Client) -> Result<serde_json::Value, LinearError> {
+        client
+            .execute(
+                "query Viewer { viewer { id } }",
+                serde_json::json!({}),
+                "viewer",
+            )
+            .await
+    }
+
+    #[test]
+    fn bucket_refills_linearly_until_reset() {
+        let now = Instant::now();
+        let reset = SystemTime::now() + Duration::from_secs(100);
+        let reset_ms = reset.duration_since(UNIX_EPOCH).unwrap().as_millis() as f64;
+        let bucket = Bucket::new(100.0, 0.0, Some(reset_ms), now);
+        let half = bucket.available(now + Duration::from_secs(50));
+        assert!((half - 50.0).abs() < 1.0, "{half}");
+        assert_eq!(bucket.available(now + Duration::from_secs(200)), 100.0);
+        let wait = bucket.wait_for(10.0, 0.0, now);
+        assert!(wait > Duration::from_secs(9) && wait < Duration::from_secs(11));
+        assert_eq!(
+            bucket.wait_for(500.0, 0.0, now),
+            bucket.wait_for(100.0, 0.0, now)
+        );
+    }
+
+    #[tokio::test]
+    async fn reads_budgets_from_response_headers() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(viewer((100, 40), Duration::from_secs(3600)))
+            .mount(&server)
+            .await;
+        let limiter = RateLimiter::new();
+        assert_eq!(limiter.status(), RateLimitStatus::default());
+
+        viewer_id(&client(&server, &limiter)).await.unwrap();
+        let status = limiter.status();
+        let requests = status.requests.unwrap();
+        assert_eq!((requests.limit, requests.remaining), (100, 40));
+        assert!(requests.reset.is_some());
+        assert_eq!(status.complexity.unwrap().remaining, 9000);
+        assert_eq!(status.last_complexity, Some(12));
+    }
+
+    /// Hides the rate limit headers from the middleware it wraps.
+    struct StripHeaders;
+
+    impl Middleware for StripHeaders {
+        fn handle<'a>(
+            &'a self,
+            request: Request,
+            next: Next<'a>,
+        ) -> BoxFuture<'a, Result<Response, LinearError>> {
+            Box::pin(async move {
+                let mut response = next.run(request).await?;
+                response.headers.clear();
+                Ok(response)
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn with_rate_limiter_replaces_the_limiter_innermost() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(viewer((100, 40), Duration::from_secs(3600)))
+            .mount(&server)
+            .await;
+        let (first, second) = (RateLimiter::new(), RateLimiter::new());
+        let client = Client::from_token("test-token")
+            .unwrap()
+            .with_base_url(server.uri())
+            .with_rate_limiter(first.clone())
+            .with_rate_limiter(second.clone())
+            .with_middleware(StripHeaders);
+
+        viewer_id(&client).await.unwrap();
+        assert_eq!(first.status(), RateLimitStatus::default());
+        assert_eq!(second.status().requests.unwrap().remaining, 40);
+    }
+
+    #[tokio::test]
+    async fn delays_requests_until_budget_replenishes() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(viewer((1, 0), Duration::from_millis(300)))
+            .mount(&server)
+            .await;
+        let limiter = RateLimiter::new();
+        let client = client(&server, &limiter);
+
+        viewer_id(&client).await.unwrap();
+        let started = std::time::Instant::now();
+        viewer_id(&client).await.unwrap();
+        assert!(started.elapsed() >= Duration::from_millis(200));
+    }
+
+    #[tokio::test]
+    async fn max_wait_fails_fast_with_rate_limited() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(viewer((100, 5), Duration::from_secs(3600)))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let limiter = RateLimiter::new()
+            .request_headroom(10)
+            .max_wait(Duration::from_secs(1));
+        let client = client(&server, &limiter);
+
+        viewer_id(&client).await.unwrap();
+        match viewer_id(&client).await {
+            Err(LinearError::RateLimited {
+                retry_after: Some(after),
+                kind: RateLimitKind::Requests,
+                ..
+            }) => assert!(after > 1.0),
+            other => panic!("expected client-side RateLimited, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn max_concurrency_serializes_requests() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(
+                viewer((100, 90), Duration::from_secs(3600)).set_delay(Duration::from_millis(60)),
+            )
+            .mount(&server)
+            .await;
+        let limiter = RateLimiter::new().max_concurrency(1);
+        let client = client(&server, &limiter);
+
+        let started = std::time::Instant::now();
+        let (a, b, c) = tokio::join!(viewer_id(&client), viewer_id(&client), viewer_id(&client));
+        assert!(a.is_ok() && b.is_ok() && c.is_ok());
+        assert!(started.elapsed() >= Duration::from_millis(180));
+    }
+
+    #[tokio::test]
+    async fn refresh_reads_rate_limit_status_query() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(body_string_contains("rateLimitStatus"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": { "rateLimitStatus": { "limits": [
+                    { "type": "requestLimit", "allowedAmount": 1500.0, "remainingAmount": 1200.0,
+                      "requestedAmount": 1.0, "period": 3600000.0, "reset": 0.0 },
+                    { "type": "complexityLimit", "allowedAmount": 3000000.0,
+                      "remainingAmount": 2500000.0, "requestedAmount": 1.0,
+                      "period": 3600000.0, "reset": 0.0 },
+                ]}}
+            })))
+            .mount(&server)
+            .await;
+        let limiter = RateLimiter::new();
+        let client = Client::from_token("test-token")
+            .unwrap()
+            .with_base_url(server.uri());
+
+        let status = limiter.refresh(&client).await.unwrap();
+        // A reset in the past means the budget has fully replenished.
+        assert_eq!(status.requests.unwrap().remaining, 1500);
+        assert_eq!(status.complexity.unwrap().limit, 3_000_000);
+    }
+}
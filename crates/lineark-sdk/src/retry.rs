@@ -0,0 +1,291 @@
+//! Automatic retry with exponential backoff.
+//!
+//! A [`RetryPolicy`] tells [`Client::execute`](crate::Client::execute) how to
+//! react to rate limits (HTTP 429), server errors (HTTP 5xx), and transient
+//! transport failures. Queries are idempotent and retried by default;
+//! mutations are only retried when explicitly opted in via
+//! [`RetryPolicy::retry_mutations`], since a request that timed out may
+//! already have been applied server-side.
+//!
+//! Retries are disabled on a freshly constructed [`Client`](crate::Client) —
+//! opt in with [`Client::with_retry_policy`](crate::Client::with_retry_policy):
+//!
+//! ```no_run
+//! # fn example() -> Result<(), lineark_sdk::LinearError> {
+//! use lineark_sdk::{Client, RetryPolicy};
+//! use std::time::Duration;
+//!
+//! let client = Client::from_env()?.with_retry_policy(
+//!     RetryPolicy::default()
+//!         .max_attempts(5)
+//!         .initial_backoff(Duration::from_millis(250)),
+//! );
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::error::LinearError;
+use std::time::Duration;
+
+/// How failed requests are retried.
+///
+/// The delay before retry `n` (1-based) is `initial_backoff * 2^(n - 1)`,
+/// capped at `max_backoff`. With jitter enabled the actual delay is drawn
+/// uniformly from `[delay / 2, delay]` so that parallel workers sharing a
+/// token don't retry in lockstep. When the server sends a `retry-after`
+/// header, that value is used verbatim instead of the computed backoff.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    jitter: bool,
+    retry_mutations: bool,
+}
+
+impl Default for RetryPolicy {
+    /// 3 attempts, 500 ms initial backoff, 30 s cap, jitter on, queries only.
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            jitter: true,
+            retry_mutations: false,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A policy that never retries — every request is attempted exactly once.
+    ///
+    /// This is what [`Client`](crate::Client) uses unless configured otherwise.
+    pub fn disabled() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Self::default()
+        }
+    }
+
+    /// Total number of attempts, including the first one. Values below 1 are
+    /// treated as 1.
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Delay before the first retry; doubled for each subsequent retry.
+    pub fn initial_backoff(mut self, backoff: Duration) -> Self {
+        self.initial_backoff = backoff;
+        self
+    }
+
+    /// Upper bound for the computed exponential backoff.
+    pub fn max_backoff(mut self, backoff: Duration) -> Self {
+        self.max_backoff = backoff;
+        self
+    }
+
+    /// Randomize each delay within `[delay / 2, delay]`.
+    pub fn jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Also retry mutations. Only enable this when the mutations you send are
+    /// safe to apply twice.
+    pub fn retry_mutations(mut self, retry_mutations: bool) -> Self {
+        self.retry_mutations = retry_mutations;
+        self
+    }
+
+    /// Total number of attempts, including the first one.
+    pub fn attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    /// The un-jittered exponential backoff before retry number `retry` (1-based).
+    pub fn backoff(&self, retry: u32) -> Duration {
+        let exp = retry.saturating_sub(1).min(31);
+        self.initial_backoff
+            .checked_mul(1u32 << exp)
+            .unwrap_or(self.max_backoff)
+            .min(self.max_backoff)
+    }
+
+    /// Whether `err`, produced on attempt number `attempt` (1-based), should be
+    /// retried under this policy.
+    pub(crate) fn should_retry(&self, err: &LinearError, attempt: u32, is_mutation: bool) -> bool {
+        if attempt >= self.max_attempts || (is_mutation && !self.retry_mutations) {
+            return false;
+        }
+        is_transient(err)
+    }
+
+    /// How long to wait before retry number `retry` (1-based) after `err`.
+    pub(crate) fn delay(&self, retry: u32, err: &LinearError) -> Duration {
+        if let LinearError::RateLimited {
+            retry_after: Some(secs),
+            ..
+        } = err
+        {
+            if secs.is_finite() && *secs >= 0.0 {
+                return Duration::from_secs_f64(*secs);
+            }
+        }
+        let backoff = self.backoff(retry);
+        if self.jitter {
+            backoff.mul_f64(0.5 + random_unit() / 2.0)
+        } else {
+            backoff
+        }
+    }
+}
+
+/// Errors worth retrying: rate limits, 5xx responses, transport failures
+/// that never produced a response, and dropped WebSockets.
+fn is_transient(err: &LinearError) -> bool {
+    match err {
+        LinearError::RateLimited { .. } => true,
+        LinearError::HttpError { status, .. } => matches!(status, 500 | 502 | 503 | 504),
+        LinearError::Network(e) => e.is_timeout() || e.is_connect() || e.is_request(),
+        LinearError::WebSocket(_) => true,
+        _ => false,
+    }
+}
+
+/// A uniformly distributed value in `[0, 1)`, seeded from std's per-process
+/// hasher keys so no RNG dependency is needed for jitter.
+fn random_unit() -> f64 {
+    use std::hash::{BuildHasher, Hasher};
+    let mut hasher = std::collections::hash_map::RandomState::new().build_hasher();
+    hasher.write_u128(
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_nanos())
+            .unwrap_or_default(),
+    );
+    (hasher.finish() >> 11) as f64 / (1u64 << 53) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rate_limited(retry_after: Option<f64>) -> LinearError {
+        LinearError::RateLimited {
+            retry_after,
+            kind: crate::error::RateLimitKind::Unknown,
+            message: String::new(),
+        }
+    }
+
+    fn http(status: u16) -> LinearError {
+        LinearError::HttpError {
+            status,
+            body: String::new(),
+        }
+    }
+
+    #[test]
+    fn default_policy_values() {
+        let p = RetryPolicy::default();
+        assert_eq!(p.attempts(), 3);
+        assert_eq!(p.backoff(1), Duration::from_millis(500));
+    }
+
+    #[test]
+    fn disabled_policy_never_retries() {
+        let p = RetryPolicy::disabled();
+        assert_eq!(p.attempts(), 1);
+        assert!(!p.should_retry(&rate_limited(None), 1, false));
+    }
+
+    #[test]
+    fn max_attempts_is_at_least_one() {
+        assert_eq!(RetryPolicy::default().max_attempts(0).attempts(), 1);
+    }
+
+    #[test]
+    fn backoff_doubles_and_caps() {
+        let p = RetryPolicy::default()
+            .initial_backoff(Duration::from_millis(100))
+            .max_backoff(Duration::from_millis(1000));
+        let schedule: Vec<u128> = (1..=6).map(|n| p.backoff(n).as_millis()).collect();
+        assert_eq!(schedule, vec![100, 200, 400, 800, 1000, 1000]);
+    }
+
+    #[test]
+    fn backoff_does_not_overflow() {
+        let p = RetryPolicy::default().max_backoff(Duration::from_secs(60));
+        assert_eq!(p.backoff(u32::MAX), Duration::from_secs(60));
+    }
+
+    #[test]
+    fn delay_without_jitter_matches_backoff() {
+        let p = RetryPolicy::default()
+            .jitter(false)
+            .initial_backoff(Duration::from_millis(100));
+        assert_eq!(p.delay(3, &http(503)), Duration::from_millis(400));
+    }
+
+    #[test]
+    fn delay_with_jitter_stays_within_bounds() {
+        let p = RetryPolicy::default().initial_backoff(Duration::from_millis(1000));
+        for _ in 0..100 {
+            let d = p.delay(1, &http(502));
+            assert!(d >= Duration::from_millis(500) && d <= Duration::from_millis(1000));
+        }
+    }
+
+    #[test]
+    fn delay_honors_retry_after() {
+        let p = RetryPolicy::default().max_backoff(Duration::from_secs(1));
+        assert_eq!(
+            p.delay(1, &rate_limited(Some(30.0))),
+            Duration::from_secs(30)
+        );
+    }
+
+    #[test]
+    fn delay_ignores_invalid_retry_after() {
+        let p = RetryPolicy::default()
+            .jitter(false)
+            .initial_backoff(Duration::from_millis(100));
+        assert_eq!(
+            p.delay(1, &rate_limited(Some(-1.0))),
+            Duration::from_millis(100)
+        );
+    }
+
+    #[test]
+    fn transient_errors_are_retried() {
+        let p = RetryPolicy::default();
+        assert!(p.should_retry(&rate_limited(None), 1, false));
+        assert!(p.should_retry(&http(500), 1, false));
+        assert!(p.should_retry(&http(503), 2, false));
+    }
+
+    #[test]
+    fn permanent_errors_are_not_retried() {
+        let p = RetryPolicy::default();
+        assert!(!p.should_retry(&http(400), 1, false));
+        assert!(!p.should_retry(&http(501), 1, false));
+        assert!(!p.should_retry(&LinearError::Authentication(String::new()), 1, false));
+        assert!(!p.should_retry(&LinearError::MissingData(String::new()), 1, false));
+    }
+
+    #[test]
+    fn last_attempt_is_not_retried() {
+        let p = RetryPolicy::default().max_attempts(3);
+        assert!(p.should_retry(&http(500), 2, false));
+        assert!(!p.should_retry(&http(500), 3, false));
+    }
+
+    #[test]
+    fn mutations_require_opt_in() {
+        let p = RetryPolicy::default();
+        assert!(!p.should_retry(&http(500), 1, true));
+        assert!(p.retry_mutations(true).should_retry(&http(500), 1, true));
+    }
+}
@@ -0,0 +1,575 @@
+//! Linear rich text: conversion between Markdown and ProseMirror JSON.
+//!
+//! Linear stores rich text twice: as Markdown (`description`, `body`,
+//! `content`) and as a ProseMirror document (`descriptionData`, `bodyData`,
+//! `documentContent.contentState`). A [`Doc`] is the common model of both, so
+//! tools can edit structure — tick a checklist item, replace a section —
+//! without losing mentions to a Markdown round trip:
+//!
+//! ```
+//! use lineark_sdk::richtext::Doc;
+//!
+//! let mut doc = Doc::from_markdown("## Plan\n\n- [ ] Draft\n- [ ] Review");
+//! doc.set_task_done(0, true);
+//! assert_eq!(doc.to_markdown(), "## Plan\n\n- [x] Draft\n- [ ] Review");
+//!
+//! let json = doc.to_prosemirror();
+//! assert_eq!(json["content"][1]["type"], "todo_list");
+//! ```
+//!
+//! # Markdown dialect
+//!
+//! Paragraphs, headings, bullet and ordered lists, checklists (`- [x]`),
+//! blockquotes, fenced code blocks, horizontal rules, `**bold**`, `*italic*`,
+//! `~~strike~~`, `` `code` `` and links convert as in CommonMark. Single line
+//! breaks inside a paragraph are hard breaks, as in Linear's editor. Beyond
+//! that:
+//!
+//! - An image on a line of its own, `![alt](src "title")`, is an image block.
+//! - A bare `<https://…>` on a line of its own is an embed.
+//! - Mentions are links with a `mention:` URL naming the kind and ID:
+//!   `[@Ada](mention:user/<id>)`, `[ENG-12](mention:issue/<id>)`, likewise
+//!   `project` and `document`.
+//!
+//! The `mention:` form is this module's own. The Markdown Linear returns in
+//! `description` and `body` writes mentions as links to pages such as
+//! `https://linear.app/<org>/issue/ENG-12/<slug>` or
+//! `https://linear.app/<org>/profile/<name>`, which carry no entity ID. Those
+//! parse as ordinary links, so [`Doc::from_markdown`] does not recover
+//! mentions from API Markdown; start from the ProseMirror field
+//! (`descriptionData`, `bodyData`) to keep them.
+//!
+//! Nodes and marks without a Markdown form are kept as [`Block::Other`],
+//! [`Inline::Other`] and [`Mark::Other`]. They survive ProseMirror round trips;
+//! Markdown keeps only their text.
+
+mod markdown;
+mod prosemirror;
+
+use std::fmt;
+
+/// A rich-text document.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Doc {
+    pub content: Vec<Block>,
+}
+
+/// A block-level node.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Block {
+    Paragraph(Vec<Inline>),
+    Heading {
+        /// 1 to 6.
+        level: u8,
+        content: Vec<Inline>,
+    },
+    BulletList(Vec<ListItem>),
+    OrderedList {
+        start: u64,
+        items: Vec<ListItem>,
+    },
+    /// A checklist.
+    TaskList(Vec<TaskItem>),
+    Blockquote(Vec<Block>),
+    CodeBlock {
+        language: Option<String>,
+        code: String,
+    },
+    Image {
+        src: String,
+        alt: Option<String>,
+        title: Option<String>,
+    },
+    /// An embedded URL (video, design file, …).
+    Embed {
+        url: String,
+    },
+    HorizontalRule,
+    /// A ProseMirror node this module has no model for, kept verbatim.
+    Other(serde_json::Value),
+}
+
+/// An item of a bullet or ordered list.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ListItem {
+    pub content: Vec<Block>,
+}
+
+/// A checklist item.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct TaskItem {
+    pub done: bool,
+    pub content: Vec<Block>,
+}
+
+/// An inline node.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Inline {
+    Text {
+        text: String,
+        /// Sorted outermost first: link, bold, italic, strike, code.
+        marks: Vec<Mark>,
+    },
+    HardBreak,
+    Mention {
+        kind: MentionKind,
+        id: String,
+        /// Display text: a user's name, an issue identifier, a title.
+        label: String,
+    },
+    /// A ProseMirror node this module has no model for, kept verbatim.
+    Other(serde_json::Value),
+}
+
+/// Formatting applied to text.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Mark {
+    Link {
+        href: String,
+    },
+    Bold,
+    Italic,
+    Strike,
+    Code,
+    /// A ProseMirror mark this module has no model for, kept verbatim.
+    Other(serde_json::Value),
+}
+
+/// What a [`Inline::Mention`] refers to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MentionKind {
+    User,
+    Issue,
+    Project,
+    Document,
+}
+
+/// Error returned when JSON is not a ProseMirror document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RichTextError(String);
+
+impl fmt::Display for RichTextError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid ProseMirror document: {}", self.0)
+    }
+}
+
+impl std::error::Error for RichTextError {}
+
+impl Doc {
+    /// Parse Markdown. Never fails: anything unrecognized is text.
+    pub fn from_markdown(markdown: &str) -> Self {
+        markdown::parse(markdown)
+    }
+
+    /// Render as Markdown that parses back into the same document.
+    pub fn to_markdown(&self) -> String {
+        markdown::render(self)
+    }
+
+    /// Read a ProseMirror document, e.g. an issue's `descriptionData`.
+    pub fn from_prosemirror(value: &serde_json::Value) -> Result<Self, RichTextError> {
+        prosemirror::parse(value)
+    }
+
+    /// Read a ProseMirror document serialized as a JSON string, as some
+    /// fields (`Comment.bodyData`) are.
+    pub fn from_prosemirror_str(json: &str) -> Result<Self, RichTextError> {
+        let value = serde_json::from_str(json).map_err(|e| RichTextError(e.to_string()))?;
+        Self::from_prosemirror(&value)
+    }
+
+    /// Write as a ProseMirror document.
+    pub fn to_prosemirror(&self) -> serde_json::Value {
+        prosemirror::render(self)
+    }
+
+    /// The text of the document, one line per block, without formatting.
+    pub fn plain_text(&self) -> String {
+        blocks_text(&self.content)
+    }
+
+    /// All checklist items, depth first.
+    pub fn tasks(&self) -> Vec<&TaskItem> {
+        fn collect<'a>(blocks: &'a [Block], out: &mut Vec<&'a TaskItem>) {
+            for block in blocks {
+                match block {
+                    Block::TaskList(items) => {
+                        for item in items {
+                            out.push(item);
+                            collect(&item.content, out);
+                        }
+                    }
+                    Block::BulletList(items) | Block::OrderedList { items, .. } => {
+                        for item in items {
+                            collect(&item.content, out);
+                        }
+                    }
+                    Block::Blockquote(content) => collect(content, out),
+                    _ => {}
+                }
+            }
+        }
+        let mut out = Vec::new();
+        collect(&self.content, &mut out);
+        out
+    }
+
+    /// Tick or untick checklist item `index`, counted as in [`tasks`](Self::tasks).
+    /// Returns `false` if there is no such item.
+    pub fn set_task_done(&mut self, index: usize, done: bool) -> bool {
+        fn visit(blocks: &mut [Block], index: usize, seen: &mut usize, done: bool) -> bool {
+            for block in blocks {
+                let found = match block {
+                    Block::TaskList(items) => items.iter_mut().any(|item| {
+                        if *seen == index {
+                            item.done = done;
+                            return true;
+                        }
+                        *seen += 1;
+                        visit(&mut item.content, index, seen, done)
+                    }),
+                    Block::BulletList(items) | Block::OrderedList { items, .. } => items
+                        .iter_mut()
+                        .any(|item| visit(&mut item.content, index, seen, done)),
+                    Block::Blockquote(content) => visit(content, index, seen, done),
+                    _ => false,
+                };
+                if found {
+                    return true;
+                }
+            }
+            false
+        }
+        visit(&mut self.content, index, &mut 0, done)
+    }
+
+    /// Replace the blocks under the first top-level heading whose text is
+    /// `heading`, up to the next heading of the same or a higher level.
+    /// Returns `false` if there is no such heading.
+    pub fn replace_section(&mut self, heading: &str, content: Vec<Block>) -> bool {
+        let Some((start, level)) =
+            self.content
+                .iter()
+                .enumerate()
+                .find_map(|(i, block)| match block {
+                    Block::Heading { level, content }
+                        if inlines_text(content).trim() == heading.trim() =>
+                    {
+                        Some((i, *level))
+                    }
+                    _ => None,
+                })
+        else {
+            return false;
+        };
+        let end = self.content[start + 1..]
+            .iter()
+            .position(|block| matches!(block, Block::Heading { level: l, .. } if *l <= level))
+            .map_or(self.content.len(), |offset| start + 1 + offset);
+        self.content.splice(start + 1..end, content);
+        true
+    }
+}
+
+impl Block {
+    /// The block's text without formatting.
+    pub fn plain_text(&self) -> String {
+        match self {
+            Self::Paragraph(content) | Self::Heading { content, .. } => inlines_text(content),
+            Self::BulletList(items) | Self::OrderedList { items, .. } => items
+                .iter()
+                .map(|item| blocks_text(&item.content))
+                .collect::<Vec<_>>()
+                .join("\n"),
+            Self::TaskList(items) => items
+                .iter()
+                .map(|item| blocks_text(&item.content))
+                .collect::<Vec<_>>()
+                .join("\n"),
+            Self::Blockquote(content) => blocks_text(content),
+            Self::CodeBlock { code, .. } => code.clone(),
+            Self::Image { alt, .. } => alt.clone().unwrap_or_default(),
+            Self::Embed { url } => url.clone(),
+            Self::HorizontalRule => String::new(),
+            Self::Other(node) => json_text(node),
+        }
+    }
+}
+
+impl Inline {
+    /// Unformatted text.
+    pub fn text(text: impl Into<String>) -> Self {
+        Self::Text {
+            text: text.into(),
+            marks: Vec::new(),
+        }
+    }
+
+    /// The node's text without formatting.
+    pub fn plain_text(&self) -> String {
+        match self {
+            Self::Text { text, .. } => text.clone(),
+            Self::HardBreak => "\n".to_string(),
+            Self::Mention { label, .. } => label.clone(),
+            Self::Other(node) => json_text(node),
+        }
+    }
+}
+
+impl Mark {
+    /// Position in the canonical nesting order, outermost first.
+    fn rank(&self) -> u8 {
+        match self {
+            Self::Link { .. } => 0,
+            Self::Bold => 1,
+            Self::Italic => 2,
+            Self::Strike => 3,
+            Self::Other(_) => 4,
+            Self::Code => 5,
+        }
+    }
+}
+
+impl MentionKind {
+    /// The name used in `mention:` URLs.
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::User => "user",
+            Self::Issue => "issue",
+            Self::Project => "project",
+            Self::Document => "document",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        [Self::User, Self::Issue, Self::Project, Self::Document]
+            .into_iter()
+            .find(|kind| kind.as_str() == name)
+    }
+}
+
+/// Sort marks into canonical order and drop duplicates.
+fn normalize_marks(marks: &mut Vec<Mark>) {
+    marks.sort_by_key(Mark::rank);
+    marks.dedup();
+}
+
+/// Append `node`, merging it into the previous text node if the marks match.
+fn push_inline(out: &mut Vec<Inline>, node: Inline) {
+    if let Inline::Text { text, marks } = &node {
+        if text.is_empty() {
+            return;
+        }
+        if let Some(Inline::Text {
+            text: prev,
+            marks: prev_marks,
+        }) = out.last_mut()
+        {
+            if prev_marks == marks {
+                prev.push_str(text);
+                return;
+            }
+        }
+    }
+    out.push(node);
+}
+
+fn inlines_text(inlines: &[Inline]) -> String {
+    inlines.iter().map(Inline::plain_text).collect()
+}
+
+fn blocks_text(blocks: &[Block]) -> String {
+    blocks
+        .iter()
+        .map(Block::plain_text)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Concatenated `text` of a ProseMirror node and its descendants.
+fn json_text(node: &serde_json::Value) -> String {
+    let own = node["text"].as_str().unwrap_or_default().to_string();
+    let children = node["content"]
+        .as_array()
+        .map(|c| c.iter().map(json_text).collect::<String>())
+        .unwrap_or_default();
+    own + &children
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const SAMPLE: &str = "# Release notes
+
+Shipped by [@Ada Lovelace](mention:user/u-1) for [ENG-12](mention:issue/i-12), see [the spec](https://example.com/spec?a=1).
+
+Supports **bold**, *italic*, ~~strike~~, `code`, **bold *and italic***, and ``a`b``.
+A hard break, snake_case_names and \\*stars\\*.
+1\\. not a list
+
+## Checklist
+
+- [x] Design
+- [ ] Build
+  - [ ] Nested task
+
+1. First
+2. Second
+   - Bullet inside
+
+> Quoted **text**
+>
+> - quoted item
+
+```rust
+fn main() {}
+```
+
+![Diagram](https://example.com/d.png \"Flow\")
+
+<https://www.youtube.com/watch?v=1>
+
+---
+
+Done.";
+
+    #[test]
+    fn markdown_round_trips() {
+        let doc = Doc::from_markdown(SAMPLE);
+        assert_eq!(doc.to_markdown(), SAMPLE);
+        assert_eq!(Doc::from_markdown(&doc.to_markdown()), doc);
+    }
+
+    #[test]
+    fn non_ascii_lines_parse() {
+        for text in [
+            "é",
+            "日本語",
+            "é[x](https://x.y)",
+            "- ñ ![é](https://x.y/é.png)",
+        ] {
+            let doc = Doc::from_markdown(text);
+            assert_eq!(Doc::from_markdown(&doc.to_markdown()), doc, "{text}");
+        }
+        assert_eq!(Doc::from_markdown("日本語").to_markdown(), "日本語");
+    }
+
+    #[test]
+    fn prosemirror_round_trips() {
+        let doc = Doc::from_markdown(SAMPLE);
+        let json = doc.to_prosemirror();
+        assert_eq!(Doc::from_prosemirror(&json).unwrap(), doc);
+        assert_eq!(
+            Doc::from_prosemirror_str(&json.to_string())
+                .unwrap()
+                .to_markdown(),
+            SAMPLE
+        );
+    }
+
+    #[test]
+    fn api_markdown_mentions_stay_links() {
+        let description = "Login fails on Safari after [ENG-12](https://linear.app/acme/issue/ENG-12/session-refresh) shipped.\n\ncc [Grace Hopper](https://linear.app/acme/profile/grace), see [Auth revamp](https://linear.app/acme/project/auth-revamp-3f2a1b9c0d1e)";
+        let doc = Doc::from_markdown(description);
+        assert_eq!(doc.to_markdown(), description);
+        let Block::Paragraph(content) = &doc.content[0] else {
+            panic!("expected a paragraph");
+        };
+        assert!(matches!(
+            &content[1],
+            Inline::Text { text, marks } if text == "ENG-12"
+                && marks == &[Mark::Link {
+                    href: "https://linear.app/acme/issue/ENG-12/session-refresh".to_string()
+                }]
+        ));
+        let json = doc.to_prosemirror().to_string();
+        assert!(!json.contains("Mentions"), "{json}");
+    }
+
+    #[test]
+    fn parses_mentions_and_marks() {
+        let doc = Doc::from_markdown("Hi [@Ada](mention:user/u-1), **see [it](https://x.y)**");
+        let Block::Paragraph(content) = &doc.content[0] else {
+            panic!("expected a paragraph");
+        };
+        assert_eq!(
+            content,
+            &[
+                Inline::text("Hi "),
+                Inline::Mention {
+                    kind: MentionKind::User,
+                    id: "u-1".into(),
+                    label: "Ada".into()
+                },
+                Inline::text(", "),
+                Inline::Text {
+                    text: "see ".into(),
+                    marks: vec![Mark::Bold]
+                },
+                Inline::Text {
+                    text: "it".into(),
+                    marks: vec![
+                        Mark::Link {
+                            href: "https://x.y".into()
+                        },
+                        Mark::Bold
+                    ]
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn reads_linear_prosemirror() {
+        let json = json!({
+            "type": "doc",
+            "content": [
+                { "type": "paragraph", "content": [
+                    { "type": "suggestion_userMentions", "attrs": { "id": "u-1", "label": "Ada" } },
+                    { "type": "text", "text": " asked" },
+                    { "type": "hard_break" },
+                    { "type": "text", "text": "why", "marks": [{ "type": "em" }, { "type": "strong" }] }
+                ]},
+                { "type": "taskList", "content": [
+                    { "type": "taskItem", "attrs": { "checked": true }, "content": [
+                        { "type": "paragraph", "content": [{ "type": "text", "text": "Done" }] }
+                    ]}
+                ]},
+                { "type": "codeBlock", "attrs": { "language": null }, "content": [
+                    { "type": "text", "text": "x = 1" }
+                ]},
+                { "type": "mermaid", "attrs": { "src": "graph" }, "content": [
+                    { "type": "text", "text": "graph TD" }
+                ]}
+            ]
+        });
+        let doc = Doc::from_prosemirror(&json).unwrap();
+        assert_eq!(
+            doc.to_markdown(),
+            "[@Ada](mention:user/u-1) asked\n***why***\n\n- [x] Done\n\n```\nx = 1\n```\n\ngraph TD"
+        );
+        assert_eq!(doc.to_prosemirror()["content"][3], json["content"][3]);
+        assert!(matches!(
+            Doc::from_prosemirror(&json!({ "type": "paragraph" })),
+            Err(RichTextError(_))
+        ));
+    }
+
+    #[test]
+    fn edits_tasks_and_sections() {
+        let mut doc =
+            Doc::from_markdown("# A\n\n- [ ] one\n  - [ ] two\n\n## B\n\nold\n\n## C\n\nkept");
+        assert_eq!(doc.tasks().len(), 2);
+        assert!(doc.set_task_done(1, true));
+        assert!(!doc.set_task_done(2, true));
+        assert!(doc.replace_section("B", vec![Block::Paragraph(vec![Inline::text("new")])]));
+        assert!(!doc.replace_section("Z", Vec::new()));
+        assert_eq!(
+            doc.to_markdown(),
+            "# A\n\n- [ ] one\n  - [x] two\n\n## B\n\nnew\n\n## C\n\nkept"
+        );
+    }
+}
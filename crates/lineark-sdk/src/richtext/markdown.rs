@@ -0,0 +1,887 @@
+//! Markdown parsing and rendering for [`Doc`].
+
+use super::{
+    normalize_marks, push_inline, Block, Doc, Inline, ListItem, Mark, MentionKind, TaskItem,
+};
+
+/// URL scheme of mention links.
+const MENTION_SCHEME: &str = "mention:";
+
+// ── Parsing ─────────────────────────────────────────────────────────────────
+
+pub(super) fn parse(markdown: &str) -> Doc {
+    let text = markdown.replace("\r\n", "\n");
+    let lines: Vec<String> = text.lines().map(expand_leading_tabs).collect();
+    let lines: Vec<&str> = lines.iter().map(String::as_str).collect();
+    Doc {
+        content: parse_blocks(&lines),
+    }
+}
+
+fn expand_leading_tabs(line: &str) -> String {
+    let indent = line.len() - line.trim_start_matches([' ', '\t']).len();
+    line[..indent].replace('\t', "    ") + &line[indent..]
+}
+
+fn indent_of(line: &str) -> usize {
+    line.len() - line.trim_start_matches(' ').len()
+}
+
+fn parse_blocks(lines: &[&str]) -> Vec<Block> {
+    let mut blocks = Vec::new();
+    let mut i = 0;
+    while i < lines.len() {
+        let line = lines[i].trim_start();
+        if line.is_empty() {
+            i += 1;
+        } else if let Some((fence_char, fence_len, info)) = fence(line) {
+            let close = lines[i + 1..].iter().position(|l| {
+                let l = l.trim();
+                l.len() >= fence_len && l.chars().all(|c| c == fence_char)
+            });
+            let end = close.map_or(lines.len(), |offset| i + 1 + offset);
+            blocks.push(Block::CodeBlock {
+                language: (!info.is_empty()).then(|| info.to_string()),
+                code: lines[i + 1..end].join("\n"),
+            });
+            i = end + 1;
+        } else if let Some((level, text)) = heading(line) {
+            blocks.push(Block::Heading {
+                level,
+                content: parse_inlines(text),
+            });
+            i += 1;
+        } else if is_rule(line) {
+            blocks.push(Block::HorizontalRule);
+            i += 1;
+        } else if line.starts_with('>') {
+            let end = lines[i..]
+                .iter()
+                .position(|l| !l.trim_start().starts_with('>'))
+                .map_or(lines.len(), |offset| i + offset);
+            let inner: Vec<&str> = lines[i..end]
+                .iter()
+                .map(|l| {
+                    let l = &l.trim_start()[1..];
+                    l.strip_prefix(' ').unwrap_or(l)
+                })
+                .collect();
+            blocks.push(Block::Blockquote(parse_blocks(&inner)));
+            i = end;
+        } else if let Some(marker) = list_marker(lines[i]) {
+            let (block, end) = parse_list(lines, i, marker);
+            blocks.push(block);
+            i = end;
+        } else if let Some(block) = standalone_block(line) {
+            blocks.push(block);
+            i += 1;
+        } else {
+            let end = lines[i + 1..]
+                .iter()
+                .position(|l| l.trim().is_empty() || starts_block(l))
+                .map_or(lines.len(), |offset| i + 1 + offset);
+            blocks.push(Block::Paragraph(parse_paragraph(&lines[i..end])));
+            i = end;
+        }
+    }
+    blocks
+}
+
+/// Whether a line interrupts a paragraph.
+fn starts_block(line: &str) -> bool {
+    let trimmed = line.trim_start();
+    fence(trimmed).is_some()
+        || heading(trimmed).is_some()
+        || is_rule(trimmed)
+        || trimmed.starts_with('>')
+        || list_marker(line).is_some()
+}
+
+/// An opening code fence: its character, length and info string.
+fn fence(line: &str) -> Option<(char, usize, &str)> {
+    let fence_char = line.chars().next().filter(|c| matches!(c, '`' | '~'))?;
+    let len = line.len() - line.trim_start_matches(fence_char).len();
+    let info = line[len..].trim();
+    (len >= 3 && !(fence_char == '`' && info.contains('`'))).then_some((fence_char, len, info))
+}
+
+fn heading(line: &str) -> Option<(u8, &str)> {
+    let level = line.len() - line.trim_start_matches('#').len();
+    let rest = &line[level..];
+    ((1..=6).contains(&level) && (rest.is_empty() || rest.starts_with(' ')))
+        .then(|| (level as u8, rest.trim()))
+}
+
+fn is_rule(line: &str) -> bool {
+    let mut chars = line.chars().filter(|c| *c != ' ');
+    let Some(first) = chars.next().filter(|c| matches!(c, '-' | '*' | '_')) else {
+        return false;
+    };
+    let mut count = 1;
+    for c in chars {
+        if c != first {
+            return false;
+        }
+        count += 1;
+    }
+    count >= 3
+}
+
+/// A list item's marker.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Marker {
+    kind: ListKind,
+    /// Column where the item's content starts.
+    content_offset: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ListKind {
+    Bullet,
+    Task,
+    Ordered { start: u64 },
+}
+
+impl ListKind {
+    fn continues(self, other: ListKind) -> bool {
+        matches!(
+            (self, other),
+            (Self::Bullet, Self::Bullet)
+                | (Self::Task, Self::Task)
+                | (Self::Ordered { .. }, Self::Ordered { .. })
+        )
+    }
+}
+
+fn list_marker(line: &str) -> Option<Marker> {
+    let indent = indent_of(line);
+    let rest = &line[indent..];
+    let (kind, marker_len) = if rest.starts_with(['-', '*', '+']) {
+        (ListKind::Bullet, 1)
+    } else {
+        let digits = rest.len() - rest.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+        if digits == 0 || digits > 9 || !rest[digits..].starts_with(['.', ')']) {
+            return None;
+        }
+        let start = rest[..digits].parse().ok()?;
+        (ListKind::Ordered { start }, digits + 1)
+    };
+    let after = &rest[marker_len..];
+    let spaces = after.len() - after.trim_start_matches(' ').len();
+    if after.is_empty() {
+        return Some(Marker {
+            kind,
+            content_offset: indent + marker_len + 1,
+        });
+    }
+    if spaces == 0 {
+        return None;
+    }
+    let spaces = if spaces > 4 { 1 } else { spaces };
+    let content = &after[spaces..];
+    let kind = match kind {
+        ListKind::Bullet if task_state(content).is_some() => ListKind::Task,
+        kind => kind,
+    };
+    Some(Marker {
+        kind,
+        content_offset: indent + marker_len + spaces,
+    })
+}
+
+/// `[ ]`/`[x]` at the start of a task item's content: whether it is done,
+/// and the rest of the line.
+fn task_state(content: &str) -> Option<(bool, &str)> {
+    let done = match content.get(..3)? {
+        "[ ]" => false,
+        "[x]" | "[X]" => true,
+        _ => return None,
+    };
+    let rest = &content[3..];
+    (rest.is_empty() || rest.starts_with(' '))
+        .then(|| (done, rest.strip_prefix(' ').unwrap_or(rest)))
+}
+
+/// Parse the list starting at `lines[start]`; returns it and the index of the
+/// first line after it.
+fn parse_list(lines: &[&str], start: usize, first: Marker) -> (Block, usize) {
+    let mut items: Vec<(bool, Vec<Block>)> = Vec::new();
+    let mut i = start;
+    let mut marker = first;
+    loop {
+        let line = lines[i];
+        let first_line = line.get(marker.content_offset..).unwrap_or_default();
+        let (done, first_line) = match marker.kind {
+            ListKind::Task => task_state(first_line).unwrap_or((false, first_line)),
+            _ => (false, first_line),
+        };
+        let mut item_lines = vec![first_line];
+        let mut j = i + 1;
+        while j < lines.len() {
+            let l = lines[j];
+            if l.trim().is_empty() {
+                let next = lines[j..].iter().position(|l| !l.trim().is_empty());
+                match next.map(|offset| lines[j + offset]) {
+                    Some(next) if indent_of(next) >= marker.content_offset => {
+                        item_lines.push("");
+                        j += 1;
+                    }
+                    _ => break,
+                }
+            } else if indent_of(l) >= marker.content_offset {
+                item_lines.push(&l[marker.content_offset..]);
+                j += 1;
+            } else if !starts_block(l) && item_lines.last().is_some_and(|l| !l.trim().is_empty()) {
+                // Lazy continuation of the item's paragraph.
+                item_lines.push(l.trim_start());
+                j += 1;
+            } else {
+                break;
+            }
+        }
+        items.push((done, parse_blocks(&item_lines)));
+
+        let next = lines[j..]
+            .iter()
+            .position(|l| !l.trim().is_empty())
+            .map(|offset| j + offset);
+        match next.and_then(|n| Some((n, list_marker(lines[n])?))) {
+            Some((n, next_marker))
+                if next_marker.kind.continues(first.kind)
+                    && indent_of(lines[n]) < first.content_offset =>
+            {
+                i = n;
+                marker = next_marker;
+            }
+            _ => {
+                i = j;
+                break;
+            }
+        }
+    }
+
+    let block = match first.kind {
+        ListKind::Bullet => Block::BulletList(
+            items
+                .into_iter()
+                .map(|(_, content)| ListItem { content })
+                .collect(),
+        ),
+        ListKind::Task => Block::TaskList(
+            items
+                .into_iter()
+                .map(|(done, content)| TaskItem { done, content })
+                .collect(),
+        ),
+        ListKind::Ordered { start } => Block::OrderedList {
+            start,
+            items: items
+                .into_iter()
+                .map(|(_, content)| ListItem { content })
+                .collect(),
+        },
+    };
+    (block, i)
+}
+
+/// An image or embed alone on its line.
+fn standalone_block(line: &str) -> Option<Block> {
+    let line = line.trim_end();
+    if let Some(url) = line
+        .strip_prefix('<')
+        .and_then(|l| l.strip_suffix('>'))
+        .filter(|url| is_autolink(url))
+    {
+        return Some(Block::Embed {
+            url: url.to_string(),
+        });
+    }
+    if !line.starts_with("![") {
+        return None;
+    }
+    let link = parse_link(line, 1).filter(|link| link.end == line.len())?;
+    Some(Block::Image {
+        src: link.href,
+        alt: (!link.label.is_empty()).then(|| unescape(link.label)),
+        title: link.title,
+    })
+}
+
+fn is_autolink(url: &str) -> bool {
+    (url.starts_with("https://") || url.starts_with("http://"))
+        && !url.contains(|c: char| c.is_whitespace() || c == '<' || c == '>')
+}
+
+/// A paragraph's lines; line breaks between them are hard breaks.
+fn parse_paragraph(lines: &[&str]) -> Vec<Inline> {
+    let text = lines
+        .iter()
+        .map(|l| {
+            let l = l.trim_start();
+            let l = l.strip_suffix('\\').unwrap_or(l);
+            l.trim_end()
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    parse_inlines(&text)
+}
+
+fn parse_inlines(text: &str) -> Vec<Inline> {
+    let mut out = Vec::new();
+    parse_inline_into(text, &[], &mut out);
+    out
+}
+
+fn with_mark(marks: &[Mark], mark: Mark) -> Vec<Mark> {
+    let mut marks = marks.to_vec();
+    marks.push(mark);
+    normalize_marks(&mut marks);
+    marks
+}
+
+fn parse_inline_into(s: &str, marks: &[Mark], out: &mut Vec<Inline>) {
+    let mut buf = String::new();
+    let flush = |buf: &mut String, out: &mut Vec<Inline>| {
+        if !buf.is_empty() {
+            push_inline(
+                out,
+                Inline::Text {
+                    text: std::mem::take(buf),
+                    marks: marks.to_vec(),
+                },
+            );
+        }
+    };
+    let mut i = 0;
+    while i < s.len() {
+        let rest = &s[i..];
+        let c = rest.chars().next().unwrap_or_default();
+        match c {
+            '\\' => match rest[1..].chars().next() {
+                Some(next) if next.is_ascii_punctuation() => {
+                    buf.push(next);
+                    i += 2;
+                }
+                _ => {
+                    buf.push('\\');
+                    i += 1;
+                }
+            },
+            '\n' => {
+                flush(&mut buf, out);
+                out.push(Inline::HardBreak);
+                i += 1;
+            }
+            '`' => {
+                let run = rest.len() - rest.trim_start_matches('`').len();
+                match find_code_close(s, i + run, run) {
+                    Some(close) => {
+                        flush(&mut buf, out);
+                        let code = &s[i + run..close];
+                        let code = match code.strip_prefix(' ').and_then(|c| c.strip_suffix(' ')) {
+                            Some(inner) if !inner.trim().is_empty() => inner,
+                            _ => code,
+                        };
+                        push_inline(
+                            out,
+                            Inline::Text {
+                                text: code.to_string(),
+                                marks: with_mark(marks, Mark::Code),
+                            },
+                        );
+                        i = close + run;
+                    }
+                    None => {
+                        buf.push_str(&rest[..run]);
+                        i += run;
+                    }
+                }
+            }
+            '*' | '_' | '~' => match emphasis(s, i) {
+                Some((mark, len, close)) => {
+                    flush(&mut buf, out);
+                    parse_inline_into(&s[i + len..close], &with_mark(marks, mark), out);
+                    i = close + len;
+                }
+                None => {
+                    buf.push(c);
+                    i += 1;
+                }
+            },
+            '!' if rest.starts_with("![") => match parse_link(s, i + 1) {
+                // Images inside text have no inline node; keep the source.
+                Some(link) => {
+                    buf.push_str(&s[i..link.end]);
+                    i = link.end;
+                }
+                None => {
+                    buf.push('!');
+                    i += 1;
+                }
+            },
+            '[' => match parse_link(s, i) {
+                Some(link) => {
+                    flush(&mut buf, out);
+                    match mention(&link.href, link.label) {
+                        Some(node) => push_inline(out, node),
+                        None => parse_inline_into(
+                            link.label,
+                            &with_mark(marks, Mark::Link { href: link.href }),
+                            out,
+                        ),
+                    }
+                    i = link.end;
+                }
+                None => {
+                    buf.push('[');
+                    i += 1;
+                }
+            },
+            '<' => match rest[1..]
+                .find('>')
+                .map(|end| &rest[1..1 + end])
+                .filter(|url| is_autolink(url))
+            {
+                Some(url) => {
+                    flush(&mut buf, out);
+                    push_inline(
+                        out,
+                        Inline::Text {
+                            text: url.to_string(),
+                            marks: with_mark(
+                                marks,
+                                Mark::Link {
+                                    href: url.to_string(),
+                                },
+                            ),
+                        },
+                    );
+                    i += url.len() + 2;
+                }
+                None => {
+                    buf.push('<');
+                    i += 1;
+                }
+            },
+            _ => {
+                buf.push(c);
+                i += c.len_utf8();
+            }
+        }
+    }
+    flush(&mut buf, out);
+}
+
+/// The closing backtick run of exactly `run` backticks at or after `from`.
+fn find_code_close(s: &str, from: usize, run: usize) -> Option<usize> {
+    let mut i = from;
+    while i < s.len() {
+        let rest = &s[i..];
+        let pos = rest.find('`')?;
+        let start = i + pos;
+        let len = s[start..].len() - s[start..].trim_start_matches('`').len();
+        if len == run {
+            return Some(start);
+        }
+        i = start + len;
+    }
+    None
+}
+
+/// Emphasis opening at `s[i]`: its mark, delimiter length, and where the
+/// closing delimiter starts.
+fn emphasis(s: &str, i: usize) -> Option<(Mark, usize, usize)> {
+    let rest = &s[i..];
+    let c = rest.chars().next()?;
+    let run = rest.len() - rest.trim_start_matches(c).len();
+    let (mark, len) = match c {
+        '~' if run >= 2 => (Mark::Strike, 2),
+        '*' | '_' if run >= 2 => (Mark::Bold, 2),
+        '*' | '_' => (Mark::Italic, 1),
+        _ => return None,
+    };
+    let prev_alnum = s[..i]
+        .chars()
+        .next_back()
+        .is_some_and(char::is_alphanumeric);
+    if c == '_' && prev_alnum {
+        return None;
+    }
+    if rest[len..].starts_with(char::is_whitespace) || rest.len() == len {
+        return None;
+    }
+
+    // Scan for a closing run, skipping escapes, code spans and links.
+    let delim_ok = |run: usize| match mark {
+        Mark::Italic => run != 2,
+        _ => run >= 2,
+    };
+    let mut j = i + len;
+    while j < s.len() {
+        let tail = &s[j..];
+        let ch = tail.chars().next()?;
+        if ch == '\\' {
+            j += 1 + tail[1..].chars().next().map_or(0, char::len_utf8);
+        } else if ch == '`' {
+            let r = tail.len() - tail.trim_start_matches('`').len();
+            j = find_code_close(s, j + r, r).map_or(j + r, |close| close + r);
+        } else if ch == c {
+            let r = tail.len() - tail.trim_start_matches(c).len();
+            let close = j + r - len;
+            let next_alnum = s[j + r..].chars().next().is_some_and(char::is_alphanumeric);
+            let prev_space = s[..j].chars().next_back().is_some_and(char::is_whitespace);
+            if delim_ok(r) && close > i + len && !prev_space && !(c == '_' && next_alnum) {
+                return Some((mark, len, close));
+            }
+            j += r;
+        } else {
+            j += ch.len_utf8();
+        }
+    }
+    None
+}
+
+/// A parsed `[label](href "title")`.
+struct Link<'a> {
+    label: &'a str,
+    href: String,
+    title: Option<String>,
+    /// Index just past the closing parenthesis.
+    end: usize,
+}
+
+/// Parse a link whose `[` is at `s[open]`.
+fn parse_link(s: &str, open: usize) -> Option<Link<'_>> {
+    if !s.get(open..)?.starts_with('[') {
+        return None;
+    }
+    let mut depth = 0;
+    let mut j = open;
+    let close = loop {
+        let ch = s[j..].chars().next()?;
+        match ch {
+            '\\' => j += 1 + s[j + 1..].chars().next().map_or(0, char::len_utf8),
+            '`' => {
+                let r = s[j..].len() - s[j..].trim_start_matches('`').len();
+                j = find_code_close(s, j + r, r).map_or(j + r, |close| close + r);
+            }
+            '[' => {
+                depth += 1;
+                j += 1;
+            }
+            ']' => {
+                depth -= 1;
+                if depth == 0 {
+                    break j;
+                }
+                j += 1;
+            }
+            _ => j += ch.len_utf8(),
+        }
+    };
+    let target = s[close + 1..].strip_prefix('(')?;
+    let target_start = close + 2;
+    let end = target.find(')')?;
+    let inner = target[..end].trim();
+    let (href, title) = match inner.split_once(char::is_whitespace) {
+        Some((href, title)) => {
+            let title = title.trim();
+            let title = title.strip_prefix('"')?.strip_suffix('"')?;
+            (href, Some(title.replace("\\\"", "\"")))
+        }
+        None => (inner, None),
+    };
+    let href = href
+        .strip_prefix('<')
+        .and_then(|h| h.strip_suffix('>'))
+        .unwrap_or(href);
+    Some(Link {
+        label: &s[open + 1..close],
+        href: href.to_string(),
+        title,
+        end: target_start + end + 1,
+    })
+}
+
+/// A mention node for a `mention:<kind>/<id>` link.
+fn mention(href: &str, label: &str) -> Option<Inline> {
+    let (kind, id) = href.strip_prefix(MENTION_SCHEME)?.split_once('/')?;
+    let kind = MentionKind::from_name(kind)?;
+    let label = unescape(label);
+    let label = match kind {
+        MentionKind::User => label.strip_prefix('@').map(str::to_string).unwrap_or(label),
+        _ => label,
+    };
+    Some(Inline::Mention {
+        kind,
+        id: id.to_string(),
+        label,
+    })
+}
+
+fn unescape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        match chars.peek() {
+            Some(next) if c == '\\' && next.is_ascii_punctuation() => {
+                out.push(*next);
+                chars.next();
+            }
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+// ── Rendering ───────────────────────────────────────────────────────────────
+
+pub(super) fn render(doc: &Doc) -> String {
+    render_blocks(&doc.content, false)
+}
+
+/// Join rendered blocks with blank lines. In a list item (`in_item`), a list
+/// directly after a paragraph stays on the next line, keeping the list tight.
+fn render_blocks(blocks: &[Block], in_item: bool) -> String {
+    let mut out = String::new();
+    let mut prev: Option<&Block> = None;
+    for block in blocks {
+        let text = render_block(block);
+        if text.is_empty() {
+            continue;
+        }
+        if let Some(prev) = prev {
+            let tight = in_item && matches!(prev, Block::Paragraph(_)) && is_list(block);
+            out.push_str(if tight { "\n" } else { "\n\n" });
+        }
+        out.push_str(&text);
+        prev = Some(block);
+    }
+    out
+}
+
+fn is_list(block: &Block) -> bool {
+    matches!(
+        block,
+        Block::BulletList(_) | Block::OrderedList { .. } | Block::TaskList(_)
+    )
+}
+
+fn render_block(block: &Block) -> String {
+    match block {
+        Block::Paragraph(content) => escape_line_starts(&render_inlines(content)),
+        Block::Heading { level, content } => {
+            let text = render_inlines(content).replace('\n', " ");
+            format!("{} {}", "#".repeat((*level).clamp(1, 6) as usize), text)
+        }
+        Block::BulletList(items) => render_items(
+            items
+                .iter()
+                .map(|item| ("- ".to_string(), &item.content[..])),
+        ),
+        Block::OrderedList { start, items } => render_items(
+            items
+                .iter()
+                .enumerate()
+                .map(|(i, item)| (format!("{}. ", start + i as u64), &item.content[..])),
+        ),
+        Block::TaskList(items) => render_items(items.iter().map(|item| {
+            let marker = if item.done { "- [x] " } else { "- [ ] " };
+            (marker.to_string(), &item.content[..])
+        })),
+        Block::Blockquote(content) => render_blocks(content, false)
+            .lines()
+            .map(|l| {
+                if l.is_empty() {
+                    ">".to_string()
+                } else {
+                    format!("> {}", l)
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+        Block::CodeBlock { language, code } => {
+            let longest = longest_run(code, '`');
+            let fence = "`".repeat(longest.max(2) + 1);
+            format!(
+                "{}{}\n{}\n{}",
+                fence,
+                language.as_deref().unwrap_or_default(),
+                code,
+                fence
+            )
+        }
+        Block::Image { src, alt, title } => {
+            let alt = escape(alt.as_deref().unwrap_or_default());
+            match title {
+                Some(title) => format!("![{}]({} \"{}\")", alt, src, title.replace('"', "\\\"")),
+                None => format!("![{}]({})", alt, src),
+            }
+        }
+        Block::Embed { url } => format!("<{}>", url),
+        Block::HorizontalRule => "---".to_string(),
+        Block::Other(_) => escape_line_starts(&escape(&block.plain_text())),
+    }
+}
+
+/// Render list items, indenting continuation lines under each marker.
+/// Task markers indent like bullets; `[ ]` is part of the item's content.
+fn render_items<'a>(items: impl Iterator<Item = (String, &'a [Block])>) -> String {
+    items
+        .map(|(marker, content)| {
+            let indent = if marker.starts_with('-') {
+                2
+            } else {
+                marker.len()
+            };
+            let body = render_blocks(content, true);
+            let mut lines = body.lines();
+            let mut out = format!("{}{}", marker, lines.next().unwrap_or_default())
+                .trim_end()
+                .to_string();
+            for line in lines {
+                out.push('\n');
+                if !line.is_empty() {
+                    out.push_str(&" ".repeat(indent));
+                    out.push_str(line);
+                }
+            }
+            out
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn longest_run(s: &str, c: char) -> usize {
+    s.split(|ch| ch != c).map(str::len).max().unwrap_or(0)
+}
+
+fn render_inlines(inlines: &[Inline]) -> String {
+    let mut out = String::new();
+    render_run(inlines, &[], &mut out);
+    out
+}
+
+/// Render `nodes`, opening each mark not yet in `applied` around the longest
+/// run of nodes that share it.
+fn render_run(nodes: &[Inline], applied: &[Mark], out: &mut String) {
+    let mut i = 0;
+    while i < nodes.len() {
+        let outer = match &nodes[i] {
+            Inline::Text { marks, .. } => marks
+                .iter()
+                .find(|m| !applied.contains(m) && !matches!(m, Mark::Code)),
+            _ => None,
+        };
+        let Some(mark) = outer else {
+            render_leaf(&nodes[i], out);
+            i += 1;
+            continue;
+        };
+        let end = nodes[i..]
+            .iter()
+            .position(|n| !matches!(n, Inline::Text { marks, .. } if marks.contains(mark)))
+            .map_or(nodes.len(), |offset| i + offset);
+        let mut inner_applied = applied.to_vec();
+        inner_applied.push(mark.clone());
+        let mut inner = String::new();
+        render_run(&nodes[i..end], &inner_applied, &mut inner);
+
+        match mark {
+            Mark::Link { href } => {
+                out.push('[');
+                out.push_str(&inner);
+                out.push_str("](");
+                out.push_str(href);
+                out.push(')');
+            }
+            Mark::Other(_) => out.push_str(&inner),
+            _ => {
+                let delim = match mark {
+                    Mark::Bold => "**",
+                    Mark::Italic => "*",
+                    _ => "~~",
+                };
+                // Delimiters must hug the text, so keep whitespace outside.
+                let core = inner.trim();
+                let lead = &inner[..inner.len() - inner.trim_start().len()];
+                let trail = &inner[inner.trim_end().len()..];
+                out.push_str(lead);
+                if !core.is_empty() {
+                    out.push_str(delim);
+                    out.push_str(core);
+                    out.push_str(delim);
+                }
+                out.push_str(trail);
+            }
+        }
+        i = end;
+    }
+}
+
+fn render_leaf(node: &Inline, out: &mut String) {
+    match node {
+        Inline::Text { text, marks } if marks.contains(&Mark::Code) => {
+            let ticks = "`".repeat(longest_run(text, '`') + 1);
+            let pad = text.starts_with('`')
+                || text.ends_with('`')
+                || (text.starts_with(' ') && text.ends_with(' ') && !text.trim().is_empty());
+            let pad = if pad { " " } else { "" };
+            out.push_str(&format!("{ticks}{pad}{text}{pad}{ticks}"));
+        }
+        Inline::Text { text, .. } => out.push_str(&escape(text)),
+        Inline::HardBreak => out.push('\n'),
+        Inline::Mention { kind, id, label } => {
+            let at = if *kind == MentionKind::User { "@" } else { "" };
+            out.push_str(&format!(
+                "[{}{}]({}{}/{})",
+                at,
+                escape(label),
+                MENTION_SCHEME,
+                kind.as_str(),
+                id
+            ));
+        }
+        Inline::Other(_) => out.push_str(&escape(&node.plain_text())),
+    }
+}
+
+/// Backslash-escape characters that would otherwise start inline syntax.
+fn escape(text: &str) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let mut out = String::with_capacity(text.len());
+    for (i, &c) in chars.iter().enumerate() {
+        let escape = match c {
+            '\\' | '*' | '`' | '[' | ']' | '~' => true,
+            // Intraword underscores (snake_case) are not emphasis.
+            '_' => {
+                let alnum = |c: Option<&char>| c.is_some_and(|c| c.is_alphanumeric());
+                !(i > 0 && alnum(chars.get(i - 1)) && alnum(chars.get(i + 1)))
+            }
+            '<' => text[text.char_indices().nth(i).map_or(0, |(b, _)| b)..].starts_with("<http"),
+            _ => false,
+        };
+        if escape {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    out
+}
+
+/// Escape the start of paragraph lines that would parse as block syntax.
+fn escape_line_starts(text: &str) -> String {
+    text.split('\n')
+        .map(|line| {
+            let digits = line.len() - line.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+            if line.starts_with(['#', '>', '-', '+']) || line.starts_with("* ") {
+                format!("\\{}", line)
+            } else if digits > 0 && line[digits..].starts_with(['.', ')']) {
+                format!("{}\\{}", &line[..digits], &line[digits..])
+            } else {
+                line.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
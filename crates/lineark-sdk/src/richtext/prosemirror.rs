@@ -0,0 +1,258 @@
+//! ProseMirror JSON parsing and rendering for [`Doc`].
+//!
+//! Output uses the node names of Linear's editor schema. Input also accepts
+//! the camelCase names of TipTap-based editors (`bulletList`, `taskItem`,
+//! `bold`, …).
+
+use super::{
+    json_text, normalize_marks, push_inline, Block, Doc, Inline, ListItem, Mark, MentionKind,
+    RichTextError, TaskItem,
+};
+use serde_json::{json, Map, Value};
+
+/// Prefix of mention node types, followed by e.g. `userMentions`.
+const MENTION_PREFIX: &str = "suggestion_";
+
+// ── Parsing ─────────────────────────────────────────────────────────────────
+
+pub(super) fn parse(value: &Value) -> Result<Doc, RichTextError> {
+    if value["type"] != "doc" {
+        return Err(RichTextError(format!(
+            "expected a \"doc\" node, got {}",
+            value["type"]
+        )));
+    }
+    Ok(Doc {
+        content: parse_blocks(value),
+    })
+}
+
+fn children(node: &Value) -> &[Value] {
+    node["content"].as_array().map_or(&[], Vec::as_slice)
+}
+
+fn attr_str(node: &Value, name: &str) -> Option<String> {
+    node["attrs"][name].as_str().map(str::to_string)
+}
+
+fn parse_blocks(node: &Value) -> Vec<Block> {
+    children(node).iter().map(parse_block).collect()
+}
+
+fn parse_block(node: &Value) -> Block {
+    match node["type"].as_str().unwrap_or_default() {
+        "paragraph" => Block::Paragraph(parse_inlines(node)),
+        "heading" => Block::Heading {
+            level: node["attrs"]["level"].as_u64().unwrap_or(1).clamp(1, 6) as u8,
+            content: parse_inlines(node),
+        },
+        "bullet_list" | "bulletList" => Block::BulletList(parse_items(node)),
+        "ordered_list" | "orderedList" => Block::OrderedList {
+            start: node["attrs"]["order"]
+                .as_u64()
+                .or_else(|| node["attrs"]["start"].as_u64())
+                .unwrap_or(1),
+            items: parse_items(node),
+        },
+        "todo_list" | "taskList" => Block::TaskList(
+            children(node)
+                .iter()
+                .map(|item| TaskItem {
+                    done: item["attrs"]["done"]
+                        .as_bool()
+                        .or_else(|| item["attrs"]["checked"].as_bool())
+                        .unwrap_or(false),
+                    content: parse_blocks(item),
+                })
+                .collect(),
+        ),
+        "blockquote" => Block::Blockquote(parse_blocks(node)),
+        "code_block" | "codeBlock" => Block::CodeBlock {
+            language: attr_str(node, "language").filter(|l| !l.is_empty()),
+            code: json_text(node),
+        },
+        "image" => Block::Image {
+            src: attr_str(node, "src").unwrap_or_default(),
+            alt: attr_str(node, "alt"),
+            title: attr_str(node, "title"),
+        },
+        "embed" => Block::Embed {
+            url: attr_str(node, "url")
+                .or_else(|| attr_str(node, "src"))
+                .unwrap_or_default(),
+        },
+        "horizontal_rule" | "horizontalRule" => Block::HorizontalRule,
+        _ => Block::Other(node.clone()),
+    }
+}
+
+fn parse_items(node: &Value) -> Vec<ListItem> {
+    children(node)
+        .iter()
+        .map(|item| ListItem {
+            content: parse_blocks(item),
+        })
+        .collect()
+}
+
+fn parse_inlines(node: &Value) -> Vec<Inline> {
+    let mut out = Vec::new();
+    for child in children(node) {
+        let inline = match child["type"].as_str().unwrap_or_default() {
+            "text" => {
+                let mut marks: Vec<Mark> = child["marks"]
+                    .as_array()
+                    .map(|marks| marks.iter().map(parse_mark).collect())
+                    .unwrap_or_default();
+                normalize_marks(&mut marks);
+                Inline::Text {
+                    text: child["text"].as_str().unwrap_or_default().to_string(),
+                    marks,
+                }
+            }
+            "hard_break" | "hardBreak" => Inline::HardBreak,
+            kind => match mention_kind(kind) {
+                Some(kind) => Inline::Mention {
+                    kind,
+                    id: attr_str(child, "id").unwrap_or_default(),
+                    label: attr_str(child, "label").unwrap_or_default(),
+                },
+                None => Inline::Other(child.clone()),
+            },
+        };
+        push_inline(&mut out, inline);
+    }
+    out
+}
+
+/// The kind of a `suggestion_<kind>Mentions` node type.
+fn mention_kind(node_type: &str) -> Option<MentionKind> {
+    let kind = node_type
+        .strip_prefix(MENTION_PREFIX)?
+        .strip_suffix("Mentions")?;
+    MentionKind::from_name(kind)
+}
+
+fn parse_mark(mark: &Value) -> Mark {
+    match mark["type"].as_str().unwrap_or_default() {
+        "strong" | "bold" => Mark::Bold,
+        "em" | "italic" => Mark::Italic,
+        "strike" | "strikethrough" => Mark::Strike,
+        "code" => Mark::Code,
+        "link" => match attr_str(mark, "href") {
+            Some(href) => Mark::Link { href },
+            None => Mark::Other(mark.clone()),
+        },
+        _ => Mark::Other(mark.clone()),
+    }
+}
+
+// ── Rendering ───────────────────────────────────────────────────────────────
+
+pub(super) fn render(doc: &Doc) -> Value {
+    node("doc", None, render_blocks(&doc.content))
+}
+
+/// A node, omitting `attrs` and `content` when there are none.
+fn node(node_type: &str, attrs: Option<Value>, content: Vec<Value>) -> Value {
+    let mut node = Map::new();
+    node.insert("type".into(), node_type.into());
+    if let Some(attrs) = attrs {
+        node.insert("attrs".into(), attrs);
+    }
+    if !content.is_empty() {
+        node.insert("content".into(), content.into());
+    }
+    Value::Object(node)
+}
+
+fn render_blocks(blocks: &[Block]) -> Vec<Value> {
+    blocks.iter().map(render_block).collect()
+}
+
+fn render_block(block: &Block) -> Value {
+    match block {
+        Block::Paragraph(content) => node("paragraph", None, render_inlines(content)),
+        Block::Heading { level, content } => node(
+            "heading",
+            Some(json!({ "level": level })),
+            render_inlines(content),
+        ),
+        Block::BulletList(items) => node("bullet_list", None, render_items(items)),
+        Block::OrderedList { start, items } => node(
+            "ordered_list",
+            Some(json!({ "order": start })),
+            render_items(items),
+        ),
+        Block::TaskList(items) => node(
+            "todo_list",
+            None,
+            items
+                .iter()
+                .map(|item| {
+                    node(
+                        "todo_item",
+                        Some(json!({ "done": item.done })),
+                        render_blocks(&item.content),
+                    )
+                })
+                .collect(),
+        ),
+        Block::Blockquote(content) => node("blockquote", None, render_blocks(content)),
+        Block::CodeBlock { language, code } => {
+            let text = (!code.is_empty())
+                .then(|| json!({ "type": "text", "text": code }))
+                .into_iter()
+                .collect();
+            node("code_block", Some(json!({ "language": language })), text)
+        }
+        Block::Image { src, alt, title } => node(
+            "image",
+            Some(json!({ "src": src, "alt": alt, "title": title })),
+            Vec::new(),
+        ),
+        Block::Embed { url } => node("embed", Some(json!({ "url": url })), Vec::new()),
+        Block::HorizontalRule => node("horizontal_rule", None, Vec::new()),
+        Block::Other(value) => value.clone(),
+    }
+}
+
+fn render_items(items: &[ListItem]) -> Vec<Value> {
+    items
+        .iter()
+        .map(|item| node("list_item", None, render_blocks(&item.content)))
+        .collect()
+}
+
+fn render_inlines(inlines: &[Inline]) -> Vec<Value> {
+    inlines
+        .iter()
+        .map(|inline| match inline {
+            Inline::Text { text, marks } => {
+                let mut node = json!({ "type": "text", "text": text });
+                if !marks.is_empty() {
+                    node["marks"] = marks.iter().map(render_mark).collect();
+                }
+                node
+            }
+            Inline::HardBreak => node("hard_break", None, Vec::new()),
+            Inline::Mention { kind, id, label } => node(
+                &format!("{}{}Mentions", MENTION_PREFIX, kind.as_str()),
+                Some(json!({ "id": id, "label": label })),
+                Vec::new(),
+            ),
+            Inline::Other(value) => value.clone(),
+        })
+        .collect()
+}
+
+fn render_mark(mark: &Mark) -> Value {
+    match mark {
+        Mark::Link { href } => json!({ "type": "link", "attrs": { "href": href } }),
+        Mark::Bold => json!({ "type": "strong" }),
+        Mark::Italic => json!({ "type": "em" }),
+        Mark::Strike => json!({ "type": "strike" }),
+        Mark::Code => json!({ "type": "code" }),
+        Mark::Other(value) => value.clone(),
+    }
+}
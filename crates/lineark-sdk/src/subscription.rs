@@ -0,0 +1,951 @@
+//! GraphQL subscriptions over WebSocket.
+//!
+//! Subscription builders generated from `operations.toml` (see
+//! [`generated::subscriptions`](crate::generated::subscriptions)) start a
+//! [`Subscription`]: a [`Stream`] of events typed like query results.
+//!
+//! ```no_run
+//! # async fn example() -> Result<(), lineark_sdk::LinearError> {
+//! use futures::StreamExt;
+//! use lineark_sdk::generated::types::Issue;
+//! use lineark_sdk::Client;
+//!
+//! let client = Client::from_env()?;
+//! let mut updates = client.issue_updated::<Issue>().subscribe();
+//! while let Some(issue) = updates.next().await {
+//!     let issue = issue?;
+//!     println!("{} changed", issue.identifier.as_deref().unwrap_or("?"));
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Each subscription opens its own connection to the client's
+//! [`subscription_url`](crate::ClientBuilder::subscription_url), speaking
+//! the `graphql-transport-ws` protocol. A dropped connection is re-established
+//! and the subscription sent again, backing off per the operation's
+//! [`reconnect_policy`](SubscriptionOperation::reconnect_policy); events
+//! published while disconnected are not replayed. The client pings the server
+//! every [`keepalive`](SubscriptionOperation::keepalive) interval, and a
+//! connection that has not answered by the next ping is treated as dropped, so
+//! a connection that silently went away is noticed too. The handshake goes through
+//! the client's middleware, and a rejected OAuth token is renewed and the
+//! connection retried once, as for queries. Authentication failures, errors
+//! the server reports for the subscription, and running out of reconnect
+//! attempts are yielded as the stream's last item.
+//!
+//! Dropping the [`Subscription`] completes it on the server and closes the
+//! connection. Subscriptions need a Tokio runtime and are not available on
+//! the blocking client.
+
+pub(crate) mod websocket;
+
+use crate::client::{Client, GraphQLResponse};
+use crate::error::{GraphQLError, LinearError, RateLimitKind};
+use crate::field_selection::declare_variables;
+use crate::retry::RetryPolicy;
+use futures::{SinkExt, Stream, StreamExt};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tokio::time::MissedTickBehavior;
+use tokio_tungstenite::tungstenite::protocol::frame::coding::CloseCode;
+use tokio_tungstenite::tungstenite::protocol::CloseFrame;
+use tokio_tungstenite::tungstenite::Message;
+use websocket::WebSocket;
+
+/// WebSocket subprotocol of the graphql-ws library.
+const PROTOCOL: &str = "graphql-transport-ws";
+
+/// Each connection carries a single subscription, always with this ID.
+const SUBSCRIPTION_ID: &str = "1";
+
+/// How long the server may take to acknowledge `connection_init`.
+const ACK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// How often an idle connection is pinged, by default.
+const KEEPALIVE: Duration = Duration::from_secs(15);
+
+/// Events buffered between the connection and a slow consumer.
+const BUFFER: usize = 64;
+
+/// A prepared subscription that has not been started yet.
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct SubscriptionOperation<T> {
+    name: String,
+    variable_defs: String,
+    selection: String,
+    variables: serde_json::Map<String, serde_json::Value>,
+    data_path: String,
+    reconnect: RetryPolicy,
+    keepalive: Duration,
+    invalid: Option<String>,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T: DeserializeOwned> SubscriptionOperation<T> {
+    /// A subscription operation. Arguments are as for
+    /// [`Operation::query`](crate::Operation::query); `data_path` is the
+    /// root field each event is read from.
+    pub fn new(
+        name: impl Into<String>,
+        variable_defs: impl Into<String>,
+        selection: impl Into<String>,
+        variables: serde_json::Value,
+        data_path: impl Into<String>,
+    ) -> Self {
+        let variables = match variables {
+            serde_json::Value::Object(map) => map,
+            _ => serde_json::Map::new(),
+        };
+        let (variable_defs, selection) = (variable_defs.into(), selection.into());
+        let (selection, variable_defs, invalid) =
+            match declare_variables(&variable_defs, &selection) {
+                Ok((selection, variable_defs)) => (selection, variable_defs, None),
+                Err(e) => (selection, variable_defs, Some(e)),
+            };
+        Self {
+            name: name.into(),
+            variable_defs,
+            selection,
+            variables,
+            data_path: data_path.into(),
+            reconnect: RetryPolicy::default().max_attempts(10),
+            keepalive: KEEPALIVE,
+            invalid,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Set a variable, typically one declared by a nested field's
+    /// `#[graphql(args(...))]`.
+    pub fn variable(mut self, name: impl Into<String>, value: impl Serialize) -> Self {
+        self.variables.insert(name.into(), serde_json::json!(value));
+        self
+    }
+
+    /// How reconnecting backs off after the connection drops.
+    /// `max_attempts` bounds consecutive failed connections; the count
+    /// resets whenever the server accepts one. Defaults to
+    /// `RetryPolicy::default().max_attempts(10)`.
+    pub fn reconnect_policy(mut self, policy: RetryPolicy) -> Self {
+        self.reconnect = policy;
+        self
+    }
+
+    /// How often the server is sent a graphql-ws `ping`. A connection that
+    /// has not answered with a `pong` by the next ping is dropped and
+    /// reconnected. Defaults to 15 seconds.
+    pub fn keepalive(mut self, interval: Duration) -> Self {
+        self.keepalive = interval;
+        self
+    }
+
+    /// The operation name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The complete GraphQL document.
+    pub fn document(&self) -> String {
+        if self.variable_defs.is_empty() {
+            format!("subscription {} {{ {} }}", self.name, self.selection)
+        } else {
+            format!(
+                "subscription {}({}) {{ {} }}",
+                self.name, self.variable_defs, self.selection
+            )
+        }
+    }
+
+    /// The variables sent with the document.
+    pub fn variables(&self) -> serde_json::Value {
+        serde_json::Value::Object(self.variables.clone())
+    }
+
+    /// Connect and start receiving events. Must be called within a Tokio
+    /// runtime; the connection runs on a spawned task until the returned
+    /// stream ends or is dropped.
+    pub fn subscribe(self, client: &Client) -> Subscription<T> {
+        let (tx, events) = mpsc::channel(BUFFER);
+        if let Some(reason) = self.invalid {
+            let _ = tx.try_send(Err(LinearError::Internal(format!(
+                "Invalid subscription {}: {}",
+                self.name, reason
+            ))));
+            return Subscription {
+                events,
+                _marker: PhantomData,
+            };
+        }
+        let request = SubscribeRequest {
+            document: self.document(),
+            variables: serde_json::Value::Object(self.variables),
+            name: self.name,
+            data_path: self.data_path,
+            keepalive: self.keepalive,
+        };
+        tokio::spawn(run(client.clone(), request, self.reconnect, tx));
+        Subscription {
+            events,
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// A live subscription: a stream of events, each decoded as a `T`.
+///
+/// Ends after the server completes the subscription or after yielding an
+/// error that cannot be recovered from by reconnecting.
+#[must_use = "streams do nothing unless polled"]
+pub struct Subscription<T> {
+    events: mpsc::Receiver<Result<serde_json::Value, LinearError>>,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> std::fmt::Debug for Subscription<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Subscription").finish_non_exhaustive()
+    }
+}
+
+impl<T: DeserializeOwned> Stream for Subscription<T> {
+    type Item = Result<T, LinearError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.events.poll_recv(cx).map(|event| {
+            event.map(|event| {
+                event.and_then(|value| {
+                    serde_json::from_value(value).map_err(|e| {
+                        LinearError::MissingData(format!("Failed to deserialize event: {}", e))
+                    })
+                })
+            })
+        })
+    }
+}
+
+/// What a connection sends to subscribe.
+struct SubscribeRequest {
+    name: String,
+    document: String,
+    variables: serde_json::Value,
+    data_path: String,
+    keepalive: Duration,
+}
+
+/// Messages from a graphql-transport-ws server.
+#[derive(Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum ServerMessage {
+    ConnectionAck,
+    Ping,
+    Pong,
+    Next { payload: GraphQLResponse },
+    Error { payload: Vec<GraphQLError> },
+    Complete,
+}
+
+/// Keep a subscription connected until it ends, reconnecting per `reconnect`.
+async fn run(
+    client: Client,
+    request: SubscribeRequest,
+    reconnect: RetryPolicy,
+    tx: mpsc::Sender<Result<serde_json::Value, LinearError>>,
+) {
+    let mut failures = 0;
+    let mut reauthenticated = false;
+    loop {
+        let authorization = match client.authorization().await {
+            Ok(authorization) => authorization,
+            Err(err) => {
+                let _ = tx.send(Err(err)).await;
+                return;
+            }
+        };
+        let err = match session(&client, &authorization, &request, &tx, &mut failures).await {
+            Ok(()) => return,
+            Err(err) => err,
+        };
+        if failures == 0 {
+            // The server accepted the connection before it failed.
+            reauthenticated = false;
+        }
+        // A rejected OAuth token is renewed and the connection retried once,
+        // as for queries.
+        if matches!(err, LinearError::Authentication(_)) && !reauthenticated && client.is_oauth() {
+            if let Err(err) = client.reauthenticate(&authorization).await {
+                let _ = tx.send(Err(err)).await;
+                return;
+            }
+            reauthenticated = true;
+            continue;
+        }
+        failures += 1;
+        if !reconnect.should_retry(&err, failures, false) {
+            let _ = tx.send(Err(err)).await;
+            return;
+        }
+        tokio::select! {
+            _ = tokio::time::sleep(reconnect.delay(failures, &err)) => {}
+            _ = tx.closed() => return,
+        }
+    }
+}
+
+/// One connection: initialize, subscribe, and forward events. Returns `Ok`
+/// once the subscription is over — completed by the server, failed with an
+/// error already forwarded, or dropped by the consumer — and `Err` when the
+/// connection failed.
+async fn session(
+    client: &Client,
+    authorization: &str,
+    request: &SubscribeRequest,
+    tx: &mpsc::Sender<Result<serde_json::Value, LinearError>>,
+    failures: &mut u32,
+) -> Result<(), LinearError> {
+    let mut ws = tokio::select! {
+        ws = client.connect_websocket(&request.name, authorization, PROTOCOL) => ws?,
+        _ = tx.closed() => return Ok(()),
+    };
+
+    send(
+        &mut ws,
+        serde_json::json!({
+            "type": "connection_init",
+            "payload": { "Authorization": authorization },
+        }),
+    )
+    .await?;
+    tokio::time::timeout(ACK_TIMEOUT, async {
+        loop {
+            match next_message(&mut ws).await? {
+                ServerMessage::ConnectionAck => return Ok(()),
+                ServerMessage::Ping => send(&mut ws, serde_json::json!({ "type": "pong" })).await?,
+                ServerMessage::Pong => {}
+                _ => {
+                    return Err(LinearError::WebSocket(
+                        "expected connection_ack".to_string(),
+                    ))
+                }
+            }
+        }
+    })
+    .await
+    .map_err(|_| LinearError::WebSocket("timed out waiting for connection_ack".to_string()))??;
+    *failures = 0;
+
+    send(
+        &mut ws,
+        serde_json::json!({
+            "id": SUBSCRIPTION_ID,
+            "type": "subscribe",
+            "payload": {
+                "query": request.document,
+                "variables": request.variables,
+                "operationName": request.name,
+            },
+        }),
+    )
+    .await?;
+
+    let mut keepalive = tokio::time::interval_at(
+        tokio::time::Instant::now() + request.keepalive,
+        request.keepalive,
+    );
+    // Ticks missed while forwarding to a slow consumer must not fire back to
+    // back, or the second would fail the ping the first just sent.
+    keepalive.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    let mut awaiting_pong = false;
+    loop {
+        let message = tokio::select! {
+            message = next_message(&mut ws) => message?,
+            _ = keepalive.tick() => {
+                if awaiting_pong {
+                    return Err(LinearError::WebSocket(format!(
+                        "no pong within {:?}",
+                        request.keepalive
+                    )));
+                }
+                send(&mut ws, serde_json::json!({ "type": "ping" })).await?;
+                awaiting_pong = true;
+                continue;
+            }
+            _ = tx.closed() => {
+                let _ = send(&mut ws, serde_json::json!({ "id": SUBSCRIPTION_ID, "type": "complete" })).await;
+                close(&mut ws).await;
+                return Ok(());
+            }
+        };
+        match message {
+            ServerMessage::Next { payload } => {
+                let _ = tx.send(event(payload, request)).await;
+                // Give a pong the server sent while forwarding blocked a
+                // whole period to be read.
+                keepalive.reset();
+            }
+            ServerMessage::Error { payload } => {
+                let err = LinearError::from_graphql(payload, Some(request.name.clone()));
+                let _ = tx.send(Err(err)).await;
+                close(&mut ws).await;
+                return Ok(());
+            }
+            ServerMessage::Complete => {
+                close(&mut ws).await;
+                return Ok(());
+            }
+            ServerMessage::Ping => send(&mut ws, serde_json::json!({ "type": "pong" })).await?,
+            ServerMessage::Pong => awaiting_pong = false,
+            ServerMessage::ConnectionAck => {}
+        }
+    }
+}
+
+/// The event in a `next` payload, or the errors it reports.
+fn event(
+    payload: GraphQLResponse,
+    request: &SubscribeRequest,
+) -> Result<serde_json::Value, LinearError> {
+    if let Some(errors) = payload.errors.filter(|e| !e.is_empty()) {
+        return Err(LinearError::from_graphql(
+            errors,
+            Some(request.name.clone()),
+        ));
+    }
+    payload
+        .data
+        .and_then(|mut data| {
+            data.get_mut(&request.data_path)
+                .map(serde_json::Value::take)
+        })
+        .ok_or_else(|| {
+            LinearError::MissingData(format!("No '{}' in event data", request.data_path))
+        })
+}
+
+async fn send<S>(ws: &mut WebSocket<S>, message: serde_json::Value) -> Result<(), LinearError>
+where
+    S: tokio::io::AsyncRead + tokio::io::AsyncWrite + Unpin,
+{
+    ws.send(Message::text(message.to_string()))
+        .await
+        .map_err(|e| LinearError::WebSocket(format!("connection lost: {}", e)))
+}
+
+/// The next graphql-ws message. Cancel safe.
+async fn next_message<S>(ws: &mut WebSocket<S>) -> Result<ServerMessage, LinearError>
+where
+    S: tokio::io::AsyncRead + tokio::io::AsyncWrite + Unpin,
+{
+    loop {
+        match ws.next().await {
+            Some(Ok(Message::Text(text))) => {
+                return serde_json::from_str(&text).map_err(|e| {
+                    LinearError::WebSocket(format!("unexpected message {}: {}", text, e))
+                })
+            }
+            Some(Ok(Message::Close(frame))) => {
+                return Err(match frame {
+                    Some(frame) => close_error(Some(frame.code.into()), frame.reason.to_string()),
+                    None => close_error(None, String::new()),
+                })
+            }
+            Some(Ok(Message::Binary(_))) => {
+                return Err(LinearError::WebSocket(
+                    "unexpected binary message".to_string(),
+                ))
+            }
+            Some(Ok(Message::Ping(_) | Message::Pong(_) | Message::Frame(_))) => {}
+            Some(Err(e)) => return Err(LinearError::WebSocket(format!("connection lost: {}", e))),
+            None => return Err(close_error(None, String::new())),
+        }
+    }
+}
+
+/// Start the closing handshake, ignoring a connection that is already gone.
+async fn close<S>(ws: &mut WebSocket<S>)
+where
+    S: tokio::io::AsyncRead + tokio::io::AsyncWrite + Unpin,
+{
+    let frame = CloseFrame {
+        code: CloseCode::Normal,
+        reason: "".into(),
+    };
+    let _ = ws.close(Some(frame)).await;
+}
+
+/// The error for a connection the server closed. graphql-ws close codes in
+/// the 4000s are 4000 plus the matching HTTP status.
+fn close_error(code: Option<u16>, reason: String) -> LinearError {
+    match code {
+        Some(4401) => LinearError::Authentication(reason),
+        Some(4403) => LinearError::Forbidden(reason),
+        Some(4429) => LinearError::RateLimited {
+            retry_after: None,
+            kind: RateLimitKind::Unknown,
+            message: reason,
+        },
+        Some(code @ 4000..=4999) => LinearError::HttpError {
+            status: code - 4000,
+            body: reason,
+        },
+        Some(code) => LinearError::WebSocket(format!("connection closed ({}): {}", code, reason)),
+        None => LinearError::WebSocket("connection closed".to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::future::BoxFuture;
+    use futures::StreamExt;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use tokio::io::AsyncReadExt;
+    use tokio::io::AsyncWriteExt;
+    use tokio::net::{TcpListener, TcpStream};
+    use tokio_tungstenite::tungstenite::protocol::Role;
+
+    /// The server end of one stand-in connection.
+    struct Peer {
+        ws: WebSocket<TcpStream>,
+        authorization: String,
+    }
+
+    impl Peer {
+        async fn send(&mut self, message: serde_json::Value) {
+            self.ws
+                .send(Message::text(message.to_string()))
+                .await
+                .unwrap();
+        }
+
+        async fn close(&mut self, code: u16) {
+            let frame = CloseFrame {
+                code: code.into(),
+                reason: "".into(),
+            };
+            self.ws.close(Some(frame)).await.unwrap();
+        }
+
+        async fn expect(&mut self, message_type: &str) -> serde_json::Value {
+            let Some(Ok(Message::Text(text))) = self.ws.next().await else {
+                panic!("expected a {} message", message_type);
+            };
+            let message: serde_json::Value = serde_json::from_str(&text).unwrap();
+            assert_eq!(message["type"], message_type);
+            message
+        }
+
+        /// Acknowledge the connection and return the subscribe message.
+        async fn accept(&mut self) -> serde_json::Value {
+            let init = self.expect("connection_init").await;
+            assert_eq!(init["payload"]["Authorization"], "test-token");
+            self.send(serde_json::json!({ "type": "connection_ack" }))
+                .await;
+            self.expect("subscribe").await
+        }
+
+        async fn next(&mut self, data: serde_json::Value) {
+            self.send(serde_json::json!({
+                "id": SUBSCRIPTION_ID,
+                "type": "next",
+                "payload": { "data": { "issueUpdated": data } },
+            }))
+            .await;
+        }
+    }
+
+    type Script = Arc<dyn Fn(usize, Peer) -> BoxFuture<'static, ()> + Send + Sync>;
+
+    /// A local graphql-ws server running `script` for each connection, with
+    /// the connection's index. Returns its URL and the connection count.
+    async fn stand_in(script: Script) -> (String, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/graphql", listener.local_addr().unwrap());
+        let count = Arc::new(AtomicUsize::new(0));
+        let connections = count.clone();
+        tokio::spawn(async move {
+            loop {
+                let (stream, _) = listener.accept().await.unwrap();
+                let index = connections.fetch_add(1, Ordering::SeqCst);
+                let script = script.clone();
+                tokio::spawn(async move {
+                    let peer = handshake(stream).await;
+                    script(index, peer).await;
+                });
+            }
+        });
+        (url, count)
+    }
+
+    async fn handshake(mut stream: TcpStream) -> Peer {
+        let mut head = Vec::new();
+        while !head.ends_with(b"\r\n\r\n") {
+            head.push(stream.read_u8().await.unwrap());
+        }
+        let head = String::from_utf8(head).unwrap();
+        let header = |name: &str| {
+            head.lines()
+                .find_map(|line| {
+                    let (key, value) = line.split_once(':')?;
+                    key.eq_ignore_ascii_case(name)
+                        .then(|| value.trim().to_string())
+                })
+                .unwrap_or_default()
+        };
+        assert_eq!(header("sec-websocket-protocol"), PROTOCOL);
+        let response = format!(
+            "HTTP/1.1 101 Switching Protocols\r\nUpgrade: websocket\r\nConnection: Upgrade\r\nSec-WebSocket-Accept: {}\r\nSec-WebSocket-Protocol: {}\r\n\r\n",
+            websocket::accept_key(&header("sec-websocket-key")),
+            PROTOCOL,
+        );
+        stream.write_all(response.as_bytes()).await.unwrap();
+        Peer {
+            authorization: header("authorization"),
+            ws: websocket::wrap(stream, Role::Server).await,
+        }
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct IssueRow {
+        id: String,
+    }
+
+    fn client(url: String) -> Client {
+        Client::from_token("test-token").unwrap().with_base_url(url)
+    }
+
+    fn operation() -> SubscriptionOperation<IssueRow> {
+        SubscriptionOperation::new(
+            "IssueUpdated",
+            "$filter: IssueSubscriptionFilter",
+            "issueUpdated(filter: $filter) { id }",
+            serde_json::json!({ "filter": { "teamId": { "eq": "t1" } } }),
+            "issueUpdated",
+        )
+        .reconnect_policy(
+            RetryPolicy::default()
+                .initial_backoff(Duration::from_millis(1))
+                .jitter(false),
+        )
+    }
+
+    #[tokio::test]
+    async fn delivers_typed_events_until_complete() {
+        let (url, _) = stand_in(Arc::new(|_, mut peer| {
+            Box::pin(async move {
+                assert_eq!(peer.authorization, "test-token");
+                let subscribe = peer.accept().await;
+                assert_eq!(subscribe["id"], SUBSCRIPTION_ID);
+                assert_eq!(
+                    subscribe["payload"]["query"],
+                    "subscription IssueUpdated($filter: IssueSubscriptionFilter) { issueUpdated(filter: $filter) { id } }"
+                );
+                assert_eq!(subscribe["payload"]["variables"]["filter"]["teamId"]["eq"], "t1");
+                peer.send(serde_json::json!({ "type": "ping" })).await;
+                peer.expect("pong").await;
+                peer.next(serde_json::json!({ "id": "a" })).await;
+                peer.next(serde_json::json!({ "id": "b" })).await;
+                peer.send(serde_json::json!({ "id": SUBSCRIPTION_ID, "type": "complete" }))
+                    .await;
+            })
+        }))
+        .await;
+
+        let events: Vec<_> = operation().subscribe(&client(url)).collect().await;
+        let ids: Vec<String> = events.into_iter().map(|e| e.unwrap().id).collect();
+        assert_eq!(ids, vec!["a", "b"]);
+    }
+
+    #[tokio::test]
+    async fn reconnects_and_resubscribes_after_the_connection_drops() {
+        let (url, count) = stand_in(Arc::new(|index, mut peer| {
+            Box::pin(async move {
+                peer.accept().await;
+                if index == 0 {
+                    peer.next(serde_json::json!({ "id": "before" })).await;
+                    return; // dropped without a close frame
+                }
+                peer.next(serde_json::json!({ "id": "after" })).await;
+                peer.send(serde_json::json!({ "id": SUBSCRIPTION_ID, "type": "complete" }))
+                    .await;
+            })
+        }))
+        .await;
+
+        let events: Vec<_> = operation().subscribe(&client(url)).collect().await;
+        let ids: Vec<String> = events.into_iter().map(|e| e.unwrap().id).collect();
+        assert_eq!(ids, vec!["before", "after"]);
+        assert_eq!(count.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn reconnects_when_pings_go_unanswered() {
+        let (url, count) = stand_in(Arc::new(|index, mut peer| {
+            Box::pin(async move {
+                peer.accept().await;
+                if index == 0 {
+                    peer.next(serde_json::json!({ "id": "before" })).await;
+                    // Stop answering, as a half-open connection would.
+                    peer.expect("ping").await;
+                    std::future::pending::<()>().await;
+                }
+                peer.next(serde_json::json!({ "id": "after" })).await;
+                peer.send(serde_json::json!({ "id": SUBSCRIPTION_ID, "type": "complete" }))
+                    .await;
+            })
+        }))
+        .await;
+
+        let operation = operation().keepalive(Duration::from_millis(50));
+        let events: Vec<_> = operation.subscribe(&client(url)).collect().await;
+        let ids: Vec<String> = events.into_iter().map(|e| e.unwrap().id).collect();
+        assert_eq!(ids, vec!["before", "after"]);
+        assert_eq!(count.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn answered_pings_keep_the_connection() {
+        let (url, count) = stand_in(Arc::new(|_, mut peer| {
+            Box::pin(async move {
+                peer.accept().await;
+                for _ in 0..3 {
+                    peer.expect("ping").await;
+                    peer.send(serde_json::json!({ "type": "pong" })).await;
+                }
+                peer.next(serde_json::json!({ "id": "a" })).await;
+                peer.send(serde_json::json!({ "id": SUBSCRIPTION_ID, "type": "complete" }))
+                    .await;
+            })
+        }))
+        .await;
+
+        let operation = operation().keepalive(Duration::from_millis(20));
+        let events: Vec<_> = operation.subscribe(&client(url)).collect().await;
+        let ids: Vec<String> = events.into_iter().map(|e| e.unwrap().id).collect();
+        assert_eq!(ids, vec!["a"]);
+        assert_eq!(count.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn stalled_consumer_does_not_drop_the_connection() {
+        let (url, count) = stand_in(Arc::new(|index, mut peer| {
+            Box::pin(async move {
+                peer.accept().await;
+                if index > 0 {
+                    peer.send(serde_json::json!({ "id": SUBSCRIPTION_ID, "type": "complete" }))
+                        .await;
+                    return;
+                }
+                // More events than the buffer holds, so forwarding blocks.
+                for i in 0..BUFFER + 8 {
+                    peer.next(serde_json::json!({ "id": i.to_string() })).await;
+                }
+                for _ in 0..2 {
+                    peer.expect("ping").await;
+                    peer.send(serde_json::json!({ "type": "pong" })).await;
+                }
+                peer.send(serde_json::json!({ "id": SUBSCRIPTION_ID, "type": "complete" }))
+                    .await;
+            })
+        }))
+        .await;
+
+        let mut subscription = operation()
+            .keepalive(Duration::from_millis(20))
+            .subscribe(&client(url));
+        assert_eq!(subscription.next().await.unwrap().unwrap().id, "0");
+        // Stall for many keepalive periods while the buffer is full.
+        tokio::time::sleep(Duration::from_millis(300)).await;
+        let rest: Vec<_> = subscription.collect().await;
+        let ids: Vec<String> = rest.into_iter().map(|e| e.unwrap().id).collect();
+        assert_eq!(ids.len(), BUFFER + 7);
+        assert_eq!(count.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn gives_up_after_the_reconnect_attempts() {
+        let (url, count) = stand_in(Arc::new(|_, mut peer| {
+            Box::pin(async move {
+                peer.expect("connection_init").await;
+                peer.close(1011).await;
+            })
+        }))
+        .await;
+
+        let operation = operation().reconnect_policy(
+            RetryPolicy::default()
+                .max_attempts(3)
+                .initial_backoff(Duration::from_millis(1)),
+        );
+        let events: Vec<_> = operation.subscribe(&client(url)).collect().await;
+        assert_eq!(events.len(), 1);
+        assert!(matches!(events[0], Err(LinearError::WebSocket(_))));
+        assert_eq!(count.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn unauthorized_close_is_not_retried() {
+        let (url, count) = stand_in(Arc::new(|_, mut peer| {
+            Box::pin(async move {
+                peer.expect("connection_init").await;
+                peer.close(4401).await;
+            })
+        }))
+        .await;
+
+        let events: Vec<_> = operation().subscribe(&client(url)).collect().await;
+        assert_eq!(events.len(), 1);
+        assert!(matches!(events[0], Err(LinearError::Authentication(_))));
+        assert_eq!(count.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn handshake_goes_through_middleware() {
+        let (url, _) = stand_in(Arc::new(|_, mut peer| {
+            Box::pin(async move {
+                peer.accept().await;
+                peer.send(serde_json::json!({ "id": SUBSCRIPTION_ID, "type": "complete" }))
+                    .await;
+            })
+        }))
+        .await;
+
+        let metrics = crate::middleware::Metrics::new();
+        let client = client(url).with_middleware(metrics.clone());
+        let events: Vec<_> = operation().subscribe(&client).collect().await;
+        assert!(events.is_empty());
+        let stats = &metrics.snapshot()["IssueUpdated"];
+        assert_eq!((stats.calls, stats.failures), (1, 0));
+    }
+
+    #[tokio::test]
+    async fn rejected_oauth_token_is_renewed_and_reconnected() {
+        use crate::auth::oauth::{OAuthApp, OAuthToken};
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let oauth = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/oauth/token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "access_token": "fresh",
+                "refresh_token": "refresh-2",
+                "expires_in": 86400
+            })))
+            .mount(&oauth)
+            .await;
+        let (url, count) = stand_in(Arc::new(|index, mut peer| {
+            Box::pin(async move {
+                let init = peer.expect("connection_init").await;
+                if index == 0 {
+                    assert_eq!(init["payload"]["Authorization"], "Bearer stale");
+                    peer.close(4401).await;
+                    return;
+                }
+                assert_eq!(peer.authorization, "Bearer fresh");
+                assert_eq!(init["payload"]["Authorization"], "Bearer fresh");
+                peer.send(serde_json::json!({ "type": "connection_ack" }))
+                    .await;
+                peer.expect("subscribe").await;
+                peer.next(serde_json::json!({ "id": "a" })).await;
+                peer.send(serde_json::json!({ "id": SUBSCRIPTION_ID, "type": "complete" }))
+                    .await;
+            })
+        }))
+        .await;
+
+        let client = Client::builder()
+            .oauth(
+                OAuthApp::new("cid")
+                    .client_secret("secret")
+                    .token_url(format!("{}/oauth/token", oauth.uri())),
+                OAuthToken::new("stale").with_refresh_token("refresh-1"),
+            )
+            .base_url(url)
+            .build()
+            .unwrap();
+        let events: Vec<_> = operation().subscribe(&client).collect().await;
+        let ids: Vec<String> = events.into_iter().map(|e| e.unwrap().id).collect();
+        assert_eq!(ids, vec!["a"]);
+        assert_eq!(count.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn subscription_errors_end_the_stream() {
+        let (url, _) = stand_in(Arc::new(|_, mut peer| {
+            Box::pin(async move {
+                peer.accept().await;
+                peer.send(serde_json::json!({
+                    "id": SUBSCRIPTION_ID,
+                    "type": "error",
+                    "payload": [{ "message": "Cannot query field \"nope\"" }],
+                }))
+                .await;
+            })
+        }))
+        .await;
+
+        let events: Vec<_> = operation().subscribe(&client(url)).collect().await;
+        assert_eq!(events.len(), 1);
+        assert!(matches!(
+            &events[0],
+            Err(LinearError::GraphQL { query_name: Some(name), .. }) if name == "IssueUpdated"
+        ));
+    }
+
+    #[tokio::test]
+    async fn dropping_the_stream_completes_the_subscription() {
+        let (done_tx, done_rx) = tokio::sync::oneshot::channel();
+        let done_tx = Arc::new(std::sync::Mutex::new(Some(done_tx)));
+        let (url, _) = stand_in(Arc::new(move |_, mut peer| {
+            let done_tx = done_tx.clone();
+            Box::pin(async move {
+                peer.accept().await;
+                peer.next(serde_json::json!({ "id": "a" })).await;
+                let complete = peer.expect("complete").await;
+                assert_eq!(complete["id"], SUBSCRIPTION_ID);
+                let close = peer.ws.next().await.unwrap().unwrap();
+                assert_eq!(
+                    close,
+                    Message::Close(Some(CloseFrame {
+                        code: CloseCode::Normal,
+                        reason: "".into(),
+                    }))
+                );
+                done_tx.lock().unwrap().take().unwrap().send(()).unwrap();
+            })
+        }))
+        .await;
+
+        let mut subscription = operation().subscribe(&client(url));
+        assert_eq!(subscription.next().await.unwrap().unwrap().id, "a");
+        drop(subscription);
+        tokio::time::timeout(Duration::from_secs(5), done_rx)
+            .await
+            .expect("server saw the subscription completed")
+            .unwrap();
+    }
+
+    #[test]
+    fn close_codes_map_to_errors() {
+        assert!(matches!(
+            close_error(Some(4403), "no".into()),
+            LinearError::Forbidden(_)
+        ));
+        assert!(matches!(
+            close_error(Some(4500), "oops".into()),
+            LinearError::HttpError { status: 500, .. }
+        ));
+        assert!(matches!(
+            close_error(Some(1001), String::new()),
+            LinearError::WebSocket(_)
+        ));
+    }
+}
@@ -0,0 +1,146 @@
+//! The WebSocket connection that carries graphql-ws.
+//!
+//! The opening handshake is an HTTP/1.1 upgrade sent through the client's
+//! middleware and [`reqwest::Client`], so middleware, proxies, root
+//! certificates and TLS settings apply as for every other request. The
+//! upgraded connection is then framed by `tokio-tungstenite`, which answers
+//! WebSocket pings on its own.
+
+use crate::error::{LinearError, RateLimitKind};
+use crate::middleware::{Request, Response};
+use reqwest::header::{
+    HeaderValue, CONNECTION, SEC_WEBSOCKET_ACCEPT, SEC_WEBSOCKET_KEY, SEC_WEBSOCKET_PROTOCOL,
+    SEC_WEBSOCKET_VERSION, UPGRADE,
+};
+use tokio::io::{AsyncRead, AsyncWrite};
+use tokio_tungstenite::tungstenite::handshake::client::generate_key;
+use tokio_tungstenite::tungstenite::handshake::derive_accept_key;
+use tokio_tungstenite::tungstenite::protocol::{Role, WebSocketConfig};
+use tokio_tungstenite::WebSocketStream;
+
+/// Largest message accepted from the server.
+const MAX_MESSAGE_SIZE: usize = 16 * 1024 * 1024;
+
+/// One end of a WebSocket connection over `S`.
+pub(crate) type WebSocket<S> = WebSocketStream<S>;
+
+/// Turn `request` into the opening handshake for `protocol`, mapping a
+/// `ws(s)://` URL to `http(s)://`. Returns the key the server must answer.
+pub(crate) fn handshake(request: &mut Request, protocol: &'static str) -> String {
+    let key = generate_key();
+    request.method = reqwest::Method::GET;
+    request.url = http_url(&request.url);
+    let headers = &mut request.headers;
+    headers.insert(CONNECTION, HeaderValue::from_static("Upgrade"));
+    headers.insert(UPGRADE, HeaderValue::from_static("websocket"));
+    headers.insert(SEC_WEBSOCKET_VERSION, HeaderValue::from_static("13"));
+    headers.insert(
+        SEC_WEBSOCKET_KEY,
+        HeaderValue::from_str(&key).expect("base64 is a valid header value"),
+    );
+    headers.insert(SEC_WEBSOCKET_PROTOCOL, HeaderValue::from_static(protocol));
+    key
+}
+
+/// Check the server's answer to a handshake made with `key`, and take over
+/// the connection. `upgrade` is the live response behind `response`, if the
+/// transport switched protocols.
+pub(crate) async fn accept(
+    response: Response,
+    upgrade: Option<reqwest::Response>,
+    key: &str,
+    protocol: &str,
+) -> Result<WebSocket<reqwest::Upgraded>, LinearError> {
+    let status = response.status;
+    if status != reqwest::StatusCode::SWITCHING_PROTOCOLS {
+        let body = response.text();
+        return Err(match status.as_u16() {
+            401 => LinearError::Authentication(body),
+            403 => LinearError::Forbidden(body),
+            429 => LinearError::RateLimited {
+                retry_after: response
+                    .headers
+                    .get(reqwest::header::RETRY_AFTER)
+                    .and_then(|v| v.to_str().ok())
+                    .and_then(|v| v.parse().ok()),
+                kind: RateLimitKind::from_headers(&response.headers),
+                message: body,
+            },
+            status => LinearError::HttpError { status, body },
+        });
+    }
+    let header = |name| {
+        response
+            .headers
+            .get(name)
+            .and_then(|v| v.to_str().ok())
+            .unwrap_or_default()
+    };
+    if header(SEC_WEBSOCKET_ACCEPT) != accept_key(key) {
+        return Err(LinearError::WebSocket(
+            "handshake response has a wrong Sec-WebSocket-Accept".to_string(),
+        ));
+    }
+    if header(SEC_WEBSOCKET_PROTOCOL) != protocol {
+        return Err(LinearError::WebSocket(format!(
+            "server did not accept the {} protocol",
+            protocol
+        )));
+    }
+    let Some(upgrade) = upgrade else {
+        return Err(LinearError::WebSocket(
+            "handshake was answered without opening a connection".to_string(),
+        ));
+    };
+
+    let upgraded = upgrade.upgrade().await.map_err(LinearError::Network)?;
+    Ok(wrap(upgraded, Role::Client).await)
+}
+
+/// The `http(s)://` URL for a `ws(s)://` one; other URLs are kept.
+fn http_url(url: &str) -> String {
+    if let Some(rest) = url.strip_prefix("wss://") {
+        format!("https://{}", rest)
+    } else if let Some(rest) = url.strip_prefix("ws://") {
+        format!("http://{}", rest)
+    } else {
+        url.to_string()
+    }
+}
+
+/// The `Sec-WebSocket-Accept` a server answers `key` with.
+pub(crate) fn accept_key(key: &str) -> String {
+    derive_accept_key(key.as_bytes())
+}
+
+/// Frame an established connection as the `role` end of a WebSocket.
+pub(crate) async fn wrap<S: AsyncRead + AsyncWrite + Unpin>(stream: S, role: Role) -> WebSocket<S> {
+    let config = WebSocketConfig::default()
+        .max_message_size(Some(MAX_MESSAGE_SIZE))
+        .max_frame_size(Some(MAX_MESSAGE_SIZE));
+    WebSocketStream::from_raw_socket(stream, role, Some(config)).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accept_key_matches_rfc_example() {
+        // RFC 6455 §1.3.
+        assert_eq!(
+            accept_key("dGhlIHNhbXBsZSBub25jZQ=="),
+            "s3pPLMBiTxaQ9kYGzzhZRbK+xOo="
+        );
+    }
+
+    #[test]
+    fn ws_urls_map_to_http() {
+        assert_eq!(
+            http_url("wss://api.linear.app/graphql"),
+            "https://api.linear.app/graphql"
+        );
+        assert_eq!(http_url("ws://127.0.0.1:80/"), "http://127.0.0.1:80/");
+        assert_eq!(http_url("https://example.com"), "https://example.com");
+    }
+}
@@ -0,0 +1,764 @@
+//! Incremental local mirror of a workspace.
+//!
+//! A [`SyncEngine`] bootstraps the issues, projects, teams, users, labels,
+//! cycles and workflow states of a workspace into a [`SyncStore`], then keeps
+//! it current by polling for records whose `updatedAt` moved past the last
+//! one seen. Reads are served from the store without touching the API.
+//!
+//! ```no_run
+//! # async fn example() -> Result<(), lineark_sdk::LinearError> {
+//! use lineark_sdk::generated::types::Issue;
+//! use lineark_sdk::sync::{MemoryStore, SyncEngine};
+//! use lineark_sdk::Client;
+//!
+//! let client = Client::from_env()?;
+//! let engine = SyncEngine::new(client.clone(), MemoryStore::new());
+//! engine.poll().await?; // bootstraps on first use, deltas afterwards
+//!
+//! // Read your own writes: wait until the mirror includes this mutation.
+//! let issue = client.issue_update::<Issue>(Default::default(), "ENG-1").await?;
+//! engine.wait_for(client.last_sync_id().unwrap_or_default()).await?;
+//! let mirrored: Option<Issue> = engine.get(issue.id.as_deref().unwrap_or_default())?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Every mutation made through a [`Client`] records the payload's
+//! `lastSyncId` (see [`Client::last_sync_id`]) and the entity it returned.
+//! Linear's reads are eventually consistent, so a poll that starts after a
+//! mutation may still miss it: the engine only counts a mutation as mirrored
+//! once the returned record is in the store with at least the `updatedAt` the
+//! payload reported. [`SyncEngine::wait_for`] polls, backing off between
+//! attempts, until that is the case. Mutations whose payload returned no
+//! mirrored record with an `updatedAt`, like deletes, and sync ids from other
+//! clients count as mirrored after the next poll.
+//!
+//! Archived records stay in the mirror with `archivedAt` set. Permanently
+//! deleted records cannot be seen by polling; [`SyncEngine::bootstrap`]
+//! re-fetches everything and drops them.
+//!
+//! [`SyncEngine::open`] mirrors into a SQLite database ([`SqliteStore`]) that
+//! survives restarts. It needs the `sqlite` feature, which compiles a bundled
+//! SQLite and is off by default; [`SyncEngine::new`] takes any other
+//! [`SyncStore`], such as [`MemoryStore`].
+
+#[cfg(feature = "sqlite")]
+mod sqlite;
+mod store;
+
+#[cfg(feature = "sqlite")]
+pub use sqlite::SqliteStore;
+pub use store::{MemoryStore, SyncStore};
+
+use crate::client::Client;
+use crate::error::LinearError;
+use crate::field_selection::GraphQLFields;
+use crate::generated::types;
+use crate::pagination::{paginate, Connection};
+use futures::TryStreamExt;
+use serde::de::DeserializeOwned;
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// Page size for sync queries; the maximum Linear accepts.
+const PAGE_SIZE: i64 = 250;
+
+/// Meta key holding the highest `lastSyncId` the mirror is known to include.
+const SYNC_ID_KEY: &str = "last_sync_id";
+
+/// First and longest pause between the polls of [`SyncEngine::wait_for`].
+const WAIT_INITIAL_DELAY: Duration = Duration::from_millis(250);
+const WAIT_MAX_DELAY: Duration = Duration::from_secs(5);
+
+/// The kinds of records a [`SyncEngine`] mirrors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum EntityKind {
+    Issue,
+    Project,
+    Team,
+    User,
+    IssueLabel,
+    Cycle,
+    WorkflowState,
+}
+
+impl EntityKind {
+    /// Every kind, in the order they are synced.
+    pub const ALL: [EntityKind; 7] = [
+        EntityKind::Team,
+        EntityKind::User,
+        EntityKind::WorkflowState,
+        EntityKind::IssueLabel,
+        EntityKind::Cycle,
+        EntityKind::Project,
+        EntityKind::Issue,
+    ];
+
+    /// Stable name used as the store key (e.g. `"issue"`).
+    pub fn as_str(self) -> &'static str {
+        match self {
+            EntityKind::Issue => "issue",
+            EntityKind::Project => "project",
+            EntityKind::Team => "team",
+            EntityKind::User => "user",
+            EntityKind::IssueLabel => "issue_label",
+            EntityKind::Cycle => "cycle",
+            EntityKind::WorkflowState => "workflow_state",
+        }
+    }
+
+    /// The root connection field listing this kind.
+    fn connection(self) -> &'static str {
+        match self {
+            EntityKind::Issue => "issues",
+            EntityKind::Project => "projects",
+            EntityKind::Team => "teams",
+            EntityKind::User => "users",
+            EntityKind::IssueLabel => "issueLabels",
+            EntityKind::Cycle => "cycles",
+            EntityKind::WorkflowState => "workflowStates",
+        }
+    }
+
+    fn filter_type(self) -> &'static str {
+        match self {
+            EntityKind::Issue => "IssueFilter",
+            EntityKind::Project => "ProjectFilter",
+            EntityKind::Team => "TeamFilter",
+            EntityKind::User => "UserFilter",
+            EntityKind::IssueLabel => "IssueLabelFilter",
+            EntityKind::Cycle => "CycleFilter",
+            EntityKind::WorkflowState => "WorkflowStateFilter",
+        }
+    }
+
+    /// The scalar fields of the type plus the ids of the records it points
+    /// at, so the mirror can be joined locally.
+    fn selection(self) -> String {
+        let (scalars, relations) = match self {
+            EntityKind::Issue => (
+                types::Issue::selection(),
+                "team { id } state { id } assignee { id } creator { id } project { id } \
+                 cycle { id } parent { id } projectMilestone { id }",
+            ),
+            EntityKind::Project => (
+                types::Project::selection(),
+                "status { id } lead { id } creator { id }",
+            ),
+            EntityKind::Team => (types::Team::selection(), "parent { id }"),
+            EntityKind::User => (types::User::selection(), ""),
+            EntityKind::IssueLabel => (types::IssueLabel::selection(), "team { id } parent { id }"),
+            EntityKind::Cycle => (types::Cycle::selection(), "team { id }"),
+            EntityKind::WorkflowState => (types::WorkflowState::selection(), "team { id }"),
+        };
+        format!("{} {}", scalars, relations).trim_end().to_string()
+    }
+
+    /// The kind a mutation payload field such as `issue` holds.
+    fn from_payload_field(field: &str) -> Option<Self> {
+        Some(match field {
+            "issue" => EntityKind::Issue,
+            "project" => EntityKind::Project,
+            "team" => EntityKind::Team,
+            "user" => EntityKind::User,
+            "issueLabel" => EntityKind::IssueLabel,
+            "cycle" => EntityKind::Cycle,
+            "workflowState" => EntityKind::WorkflowState,
+            _ => return None,
+        })
+    }
+
+    fn cursor_key(self) -> String {
+        format!("cursor:{}", self.as_str())
+    }
+}
+
+impl std::fmt::Display for EntityKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A generated type that a [`SyncEngine`] mirrors, for typed reads.
+pub trait SyncEntity: DeserializeOwned {
+    const KIND: EntityKind;
+}
+
+macro_rules! sync_entities {
+    ($($ty:ident),*) => {
+        $(impl SyncEntity for types::$ty {
+            const KIND: EntityKind = EntityKind::$ty;
+        })*
+    };
+}
+
+sync_entities!(Issue, Project, Team, User, IssueLabel, Cycle, WorkflowState);
+
+/// What a [`SyncEngine::poll`] or [`SyncEngine::bootstrap`] wrote.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SyncReport {
+    /// Records written per kind. Kinds with no changes are omitted.
+    pub changed: BTreeMap<EntityKind, usize>,
+}
+
+impl SyncReport {
+    /// Total records written.
+    pub fn total(&self) -> usize {
+        self.changed.values().sum()
+    }
+}
+
+/// Keeps a [`SyncStore`] in step with a workspace. See the [module
+/// docs](self).
+pub struct SyncEngine {
+    client: Client,
+    store: Mutex<Box<dyn SyncStore>>,
+    kinds: Vec<EntityKind>,
+    /// Serializes polls so concurrent [`wait_for`](Self::wait_for) calls
+    /// share one round of requests.
+    polling: tokio::sync::Mutex<()>,
+}
+
+impl std::fmt::Debug for SyncEngine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SyncEngine")
+            .field("kinds", &self.kinds)
+            .finish_non_exhaustive()
+    }
+}
+
+impl SyncEngine {
+    /// Mirror every [`EntityKind`] into `store`. See [`open`](Self::open)
+    /// for the default SQLite store.
+    pub fn new(client: Client, store: impl SyncStore) -> Self {
+        Self {
+            client,
+            store: Mutex::new(Box::new(store)),
+            kinds: EntityKind::ALL.to_vec(),
+            polling: tokio::sync::Mutex::new(()),
+        }
+    }
+
+    /// Mirror into the SQLite database at `path`, creating it if needed.
+    /// Requires the `sqlite` feature.
+    #[cfg(feature = "sqlite")]
+    pub fn open(client: Client, path: impl AsRef<std::path::Path>) -> Result<Self, LinearError> {
+        Ok(Self::new(client, SqliteStore::open(path)?))
+    }
+
+    /// Only mirror the given kinds.
+    pub fn with_kinds(mut self, kinds: impl IntoIterator<Item = EntityKind>) -> Self {
+        self.kinds = kinds.into_iter().collect();
+        self
+    }
+
+    /// Re-fetch every record, replacing the mirror. Drops records deleted
+    /// since the last bootstrap.
+    pub async fn bootstrap(&self) -> Result<SyncReport, LinearError> {
+        let _polling = self.polling.lock().await;
+        self.sync(true, None).await
+    }
+
+    /// Fetch records updated since the last poll. Kinds that were never
+    /// synced are bootstrapped.
+    pub async fn poll(&self) -> Result<SyncReport, LinearError> {
+        let _polling = self.polling.lock().await;
+        self.sync(false, None).await
+    }
+
+    /// Return once the mirror includes the mutation that returned
+    /// `sync_id`, polling with backoff until it does. Wrap it in a timeout
+    /// to bound the wait.
+    pub async fn wait_for(&self, sync_id: f64) -> Result<(), LinearError> {
+        let mut delay = WAIT_INITIAL_DELAY;
+        loop {
+            if self.includes(sync_id)? {
+                return Ok(());
+            }
+            {
+                let _polling = self.polling.lock().await;
+                // Another caller may have polled while we waited for the lock.
+                if self.includes(sync_id)? {
+                    return Ok(());
+                }
+                self.sync(false, Some(sync_id)).await?;
+            }
+            if self.includes(sync_id)? {
+                return Ok(());
+            }
+            tokio::time::sleep(delay).await;
+            delay = (delay * 2).min(WAIT_MAX_DELAY);
+        }
+    }
+
+    /// Poll every `interval` until a poll fails.
+    pub async fn run(&self, interval: Duration) -> Result<(), LinearError> {
+        loop {
+            self.poll().await?;
+            tokio::time::sleep(interval).await;
+        }
+    }
+
+    /// The highest `lastSyncId` the mirror is known to include.
+    pub fn synced_sync_id(&self) -> Result<Option<f64>, LinearError> {
+        Ok(self
+            .with_store(|store| store.meta(SYNC_ID_KEY))?
+            .and_then(|v| v.parse().ok()))
+    }
+
+    fn includes(&self, sync_id: f64) -> Result<bool, LinearError> {
+        Ok(self
+            .synced_sync_id()?
+            .is_some_and(|synced| synced >= sync_id))
+    }
+
+    /// The mirrored record with the given id.
+    pub fn get<T: SyncEntity>(&self, id: &str) -> Result<Option<T>, LinearError> {
+        self.with_store(|store| store.get(T::KIND, id))?
+            .map(decode)
+            .transpose()
+    }
+
+    /// Every mirrored record of a type.
+    pub fn list<T: SyncEntity>(&self) -> Result<Vec<T>, LinearError> {
+        self.with_store(|store| store.list(T::KIND))?
+            .into_iter()
+            .map(decode)
+            .collect()
+    }
+
+    /// Run `f` with the store, e.g. to read records as raw JSON.
+    pub fn with_store<R>(&self, f: impl FnOnce(&mut dyn SyncStore) -> R) -> R {
+        let mut store = self.store.lock().unwrap_or_else(|e| e.into_inner());
+        f(store.as_mut())
+    }
+
+    async fn sync(&self, full: bool, awaited: Option<f64>) -> Result<SyncReport, LinearError> {
+        // Every mutation observed before this point is visible to the queries below.
+        let target = match (self.client.last_sync_id(), awaited) {
+            (Some(a), Some(b)) => Some(a.max(b)),
+            (a, b) => a.or(b),
+        };
+
+        let mut report = SyncReport::default();
+        for &kind in &self.kinds {
+            let cursor = if full {
+                None
+            } else {
+                self.with_store(|store| store.meta(&kind.cursor_key()))?
+            };
+            let records = self.fetch(kind, cursor.as_deref()).await?;
+            let latest = records
+                .iter()
+                .filter_map(|r| r.get("updatedAt").and_then(|v| v.as_str()))
+                .chain(cursor.as_deref())
+                .max()
+                .map(str::to_string);
+            self.with_store(|store| {
+                if cursor.is_some() {
+                    store.upsert(kind, &records)?;
+                } else {
+                    store.replace(kind, &records)?;
+                }
+                match &latest {
+                    Some(latest) => store.set_meta(&kind.cursor_key(), latest),
+                    None => Ok(()),
+                }
+            })?;
+            if !records.is_empty() {
+                report.changed.insert(kind, records.len());
+            }
+        }
+
+        if let Some(target) = target {
+            self.advance(target, awaited)?;
+        }
+        Ok(report)
+    }
+
+    /// Move the synced id towards `target`, stopping short of the first
+    /// mutation whose record the mirror does not show yet.
+    fn advance(&self, target: f64, awaited: Option<f64>) -> Result<(), LinearError> {
+        let synced = self.synced_sync_id()?;
+        let mut reached = None;
+        for write in self.client.writes_between(synced, target) {
+            if !self.mirrors(&write)? {
+                // Ids up to the first missing write are covered, including an
+                // awaited id from another client that falls before it.
+                let below = awaited.filter(|&a| a < write.sync_id);
+                reached = reached.into_iter().chain(below).reduce(f64::max);
+                return self.set_synced(synced, reached);
+            }
+            reached = Some(write.sync_id);
+        }
+        self.set_synced(synced, Some(target))
+    }
+
+    fn set_synced(&self, synced: Option<f64>, reached: Option<f64>) -> Result<(), LinearError> {
+        match reached {
+            Some(reached) if synced.is_none_or(|synced| synced < reached) => {
+                self.with_store(|store| store.set_meta(SYNC_ID_KEY, &reached.to_string()))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Whether the store holds every mirrored record `write` returned, at
+    /// least as recent as the payload's.
+    fn mirrors(&self, write: &crate::client::Write) -> Result<bool, LinearError> {
+        for entity in &write.entities {
+            let Some(kind) = EntityKind::from_payload_field(&entity.field) else {
+                continue;
+            };
+            let Some(updated_at) = &entity.updated_at else {
+                continue;
+            };
+            if !self.kinds.contains(&kind) {
+                continue;
+            }
+            let record = self.with_store(|store| store.get(kind, &entity.id))?;
+            let mirrored = record
+                .as_ref()
+                .and_then(|r| r.get("updatedAt"))
+                .and_then(|v| v.as_str())
+                .is_some_and(|mirrored| mirrored >= updated_at.as_str());
+            if !mirrored {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    /// Fetch every record of `kind`, or those updated at or after `since`.
+    /// Records updated exactly at `since` are fetched again, so none updated
+    /// within the same millisecond as the cursor are missed.
+    async fn fetch(
+        &self,
+        kind: EntityKind,
+        since: Option<&str>,
+    ) -> Result<Vec<serde_json::Value>, LinearError> {
+        let connection = kind.connection();
+        let extra_args = match kind {
+            EntityKind::User => ", includeDisabled: true",
+            _ => "",
+        };
+        let query = format!(
+            "query Sync{}($first: Int, $after: String, $filter: {}) {{ {}(first: $first, after: $after, filter: $filter, includeArchived: true{}) {{ nodes {{ {} }} pageInfo {{ hasNextPage endCursor }} }} }}",
+            capitalize(connection),
+            kind.filter_type(),
+            connection,
+            extra_args,
+            kind.selection(),
+        );
+        let filter = since.map(|since| serde_json::json!({ "updatedAt": { "gte": since } }));
+        let query = &query;
+        let filter = &filter;
+        paginate(None, |after| async move {
+            let variables = serde_json::json!({
+                "first": PAGE_SIZE,
+                "after": after,
+                "filter": filter,
+            });
+            self.client
+                .execute::<Connection<serde_json::Value>>(query, variables, connection)
+                .await
+        })
+        .try_collect()
+        .await
+    }
+}
+
+/// `issues` → `Issues`, for operation names.
+fn capitalize(name: &str) -> String {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+        .unwrap_or_default()
+}
+
+fn decode<T: DeserializeOwned>(record: serde_json::Value) -> Result<T, LinearError> {
+    serde_json::from_value(record)
+        .map_err(|e| LinearError::MissingData(format!("Failed to decode synced record: {}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generated::types::{Issue, Team};
+    use wiremock::matchers::{body_string_contains, method};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn test_client(base_url: &str) -> Client {
+        Client::from_token("test-token")
+            .unwrap()
+            .with_base_url(base_url.to_string())
+    }
+
+    fn page(connection: &str, nodes: serde_json::Value) -> ResponseTemplate {
+        ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "data": { connection: {
+                "nodes": nodes,
+                "pageInfo": { "hasNextPage": false, "endCursor": null }
+            }}
+        }))
+    }
+
+    /// Answer every sync query for `kinds` with no records.
+    async fn mount_empty(server: &MockServer, kinds: &[EntityKind]) {
+        for kind in kinds {
+            Mock::given(method("POST"))
+                .and(body_string_contains(format!(
+                    "query Sync{}(",
+                    capitalize(kind.connection())
+                )))
+                .respond_with(page(kind.connection(), serde_json::json!([])))
+                .with_priority(10)
+                .mount(server)
+                .await;
+        }
+    }
+
+    fn issue(id: &str, title: &str, updated_at: &str) -> serde_json::Value {
+        serde_json::json!({
+            "id": id, "title": title, "updatedAt": updated_at,
+            "team": { "id": "t1" }, "state": { "id": "s1" }
+        })
+    }
+
+    #[tokio::test]
+    async fn bootstrap_then_poll_applies_deltas() {
+        let server = MockServer::start().await;
+        mount_empty(&server, &EntityKind::ALL).await;
+        Mock::given(method("POST"))
+            .and(body_string_contains("SyncTeams("))
+            .respond_with(page(
+                "teams",
+                serde_json::json!([{ "id": "t1", "key": "ENG", "updatedAt": "2024-01-01T00:00:00.000Z" }]),
+            ))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(body_string_contains("SyncIssues("))
+            .and(body_string_contains("\"filter\":null"))
+            .respond_with(page(
+                "issues",
+                serde_json::json!([
+                    issue("i1", "First", "2024-01-02T00:00:00.000Z"),
+                    issue("i2", "Second", "2024-01-03T00:00:00.000Z"),
+                ]),
+            ))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(body_string_contains("SyncIssues("))
+            .and(body_string_contains(r#""gte":"2024-01-03T00:00:00.000Z""#))
+            .respond_with(page(
+                "issues",
+                serde_json::json!([issue("i1", "First, renamed", "2024-01-04T00:00:00.000Z")]),
+            ))
+            .mount(&server)
+            .await;
+
+        let engine = SyncEngine::new(test_client(&server.uri()), MemoryStore::new());
+        let report = engine.poll().await.unwrap();
+        assert_eq!(report.changed.get(&EntityKind::Issue), Some(&2));
+        assert_eq!(report.changed.get(&EntityKind::Team), Some(&1));
+        let team: Team = engine.get("t1").unwrap().unwrap();
+        assert_eq!(team.key.as_deref(), Some("ENG"));
+
+        let report = engine.poll().await.unwrap();
+        assert_eq!(report.total(), 1);
+        let issue: Issue = engine.get("i1").unwrap().unwrap();
+        assert_eq!(issue.title.as_deref(), Some("First, renamed"));
+        assert_eq!(issue.team.and_then(|t| t.id).as_deref(), Some("t1"));
+        assert_eq!(engine.list::<Issue>().unwrap().len(), 2);
+        // The team is still mirrored although the delta returned nothing.
+        assert_eq!(engine.list::<Team>().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn bootstrap_drops_deleted_records() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(body_string_contains("SyncTeams("))
+            .respond_with(page(
+                "teams",
+                serde_json::json!([
+                    { "id": "t1", "updatedAt": "2024-01-01T00:00:00.000Z" },
+                    { "id": "t2", "updatedAt": "2024-01-01T00:00:00.000Z" },
+                ]),
+            ))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(body_string_contains("SyncTeams("))
+            .respond_with(page(
+                "teams",
+                serde_json::json!([{ "id": "t1", "updatedAt": "2024-01-01T00:00:00.000Z" }]),
+            ))
+            .mount(&server)
+            .await;
+
+        let engine = SyncEngine::new(test_client(&server.uri()), MemoryStore::new())
+            .with_kinds([EntityKind::Team]);
+        engine.bootstrap().await.unwrap();
+        assert_eq!(engine.list::<Team>().unwrap().len(), 2);
+        engine.bootstrap().await.unwrap();
+        assert_eq!(engine.list::<Team>().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn wait_for_polls_until_mutation_is_mirrored() {
+        let server = MockServer::start().await;
+        mount_empty(&server, &[EntityKind::Team]).await;
+        Mock::given(method("POST"))
+            .and(body_string_contains("mutation"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": { "teamDelete": { "success": true, "lastSyncId": 42.0, "entityId": "t1" } }
+            })))
+            .mount(&server)
+            .await;
+
+        let client = test_client(&server.uri());
+        let engine =
+            SyncEngine::new(client.clone(), MemoryStore::new()).with_kinds([EntityKind::Team]);
+        engine.poll().await.unwrap();
+        assert_eq!(engine.synced_sync_id().unwrap(), None);
+
+        assert_eq!(client.last_sync_id(), None);
+        client.team_delete("t1".to_string()).await.unwrap();
+        assert_eq!(client.last_sync_id(), Some(42.0));
+
+        engine.wait_for(42.0).await.unwrap();
+        assert_eq!(engine.synced_sync_id().unwrap(), Some(42.0));
+        let requests = server.received_requests().await.unwrap().len();
+        // Already mirrored: no further requests.
+        engine.wait_for(40.0).await.unwrap();
+        assert_eq!(server.received_requests().await.unwrap().len(), requests);
+    }
+
+    #[tokio::test]
+    async fn wait_for_keeps_polling_past_stale_reads() {
+        let server = MockServer::start().await;
+        let team = |name: &str, updated_at: &str| serde_json::json!([{ "id": "t1", "name": name, "updatedAt": updated_at }]);
+        // Bootstrap, then one delta that does not show the update yet.
+        Mock::given(method("POST"))
+            .and(body_string_contains("SyncTeams("))
+            .respond_with(page("teams", team("Old", "2024-01-01T00:00:00.000Z")))
+            .up_to_n_times(2)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(body_string_contains("SyncTeams("))
+            .respond_with(page("teams", team("New", "2024-01-02T00:00:00.000Z")))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(body_string_contains("mutation"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": { "teamUpdate": {
+                    "success": true,
+                    "lastSyncId": 42.0,
+                    "team": { "id": "t1", "name": "New", "updatedAt": "2024-01-02T00:00:00.000Z" }
+                } }
+            })))
+            .mount(&server)
+            .await;
+
+        let client = test_client(&server.uri());
+        let engine =
+            SyncEngine::new(client.clone(), MemoryStore::new()).with_kinds([EntityKind::Team]);
+        engine.poll().await.unwrap();
+        client
+            .team_update::<Team>(None, Default::default(), "t1")
+            .await
+            .unwrap();
+
+        engine.wait_for(42.0).await.unwrap();
+        let polls = server
+            .received_requests()
+            .await
+            .unwrap()
+            .iter()
+            .filter(|r| String::from_utf8_lossy(&r.body).contains("SyncTeams("))
+            .count();
+        assert_eq!(polls, 3);
+        assert_eq!(engine.synced_sync_id().unwrap(), Some(42.0));
+        let team: Team = engine.get("t1").unwrap().unwrap();
+        assert_eq!(team.name.as_deref(), Some("New"));
+    }
+
+    #[tokio::test]
+    async fn polls_do_not_claim_unmirrored_mutations() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(body_string_contains("SyncTeams("))
+            .respond_with(page(
+                "teams",
+                serde_json::json!([{ "id": "t1", "updatedAt": "2024-01-01T00:00:00.000Z" }]),
+            ))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(body_string_contains("mutation"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": { "teamUpdate": {
+                    "success": true,
+                    "lastSyncId": 42.0,
+                    "team": { "id": "t1", "updatedAt": "2024-01-02T00:00:00.000Z" }
+                } }
+            })))
+            .mount(&server)
+            .await;
+
+        let client = test_client(&server.uri());
+        let engine =
+            SyncEngine::new(client.clone(), MemoryStore::new()).with_kinds([EntityKind::Team]);
+        client
+            .team_update::<Team>(None, Default::default(), "t1")
+            .await
+            .unwrap();
+        engine.poll().await.unwrap();
+        assert_eq!(engine.synced_sync_id().unwrap(), None);
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[tokio::test]
+    async fn sqlite_store_resumes_with_a_delta() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(body_string_contains("SyncIssues("))
+            .and(body_string_contains("\"filter\":null"))
+            .respond_with(page(
+                "issues",
+                serde_json::json!([issue("i1", "First", "2024-01-02T00:00:00.000Z")]),
+            ))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(body_string_contains("SyncIssues("))
+            .and(body_string_contains(r#""gte":"2024-01-02T00:00:00.000Z""#))
+            .respond_with(page("issues", serde_json::json!([])))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("mirror.db");
+        let open = || {
+            SyncEngine::open(test_client(&server.uri()), &path)
+                .unwrap()
+                .with_kinds([EntityKind::Issue])
+        };
+        open().poll().await.unwrap();
+        let engine = open();
+        engine.poll().await.unwrap();
+        let issue: Issue = engine.get("i1").unwrap().unwrap();
+        assert_eq!(issue.title.as_deref(), Some("First"));
+    }
+}
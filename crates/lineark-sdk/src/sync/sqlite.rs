@@ -0,0 +1,158 @@
+//! SQLite-backed [`SyncStore`].
+
+use super::store::{record_id, SyncStore};
+use super::EntityKind;
+use crate::error::LinearError;
+use rusqlite::{params, Connection, OptionalExtension};
+use std::path::Path;
+
+/// A [`SyncStore`] in a SQLite database, which survives restarts so a
+/// process can resume with a delta instead of a full bootstrap.
+///
+/// Records live in one table, `entities (kind, id, updated_at, data)`, with
+/// `data` holding the JSON object, so the mirror can also be queried directly
+/// with SQLite's JSON functions:
+///
+/// ```sql
+/// SELECT json_extract(data, '$.title') FROM entities
+/// WHERE kind = 'issue' AND json_extract(data, '$.state.id') = ?;
+/// ```
+#[derive(Debug)]
+pub struct SqliteStore {
+    conn: Connection,
+}
+
+impl SqliteStore {
+    /// Open (or create) the database at `path`.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, LinearError> {
+        Self::from_connection(Connection::open(path).map_err(store_error)?)
+    }
+
+    /// A database that lives only as long as the store.
+    pub fn in_memory() -> Result<Self, LinearError> {
+        Self::from_connection(Connection::open_in_memory().map_err(store_error)?)
+    }
+
+    /// Use an existing connection, creating the tables if needed.
+    pub fn from_connection(conn: Connection) -> Result<Self, LinearError> {
+        conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS entities (
+                kind TEXT NOT NULL,
+                id TEXT NOT NULL,
+                updated_at TEXT,
+                data TEXT NOT NULL,
+                PRIMARY KEY (kind, id)
+            );
+            CREATE TABLE IF NOT EXISTS sync_meta (
+                key TEXT PRIMARY KEY,
+                value TEXT NOT NULL
+            );",
+        )
+        .map_err(store_error)?;
+        Ok(Self { conn })
+    }
+
+    /// The underlying connection, for querying the mirror directly.
+    pub fn connection(&self) -> &Connection {
+        &self.conn
+    }
+
+    fn insert(
+        tx: &rusqlite::Transaction<'_>,
+        kind: EntityKind,
+        records: &[serde_json::Value],
+    ) -> Result<(), LinearError> {
+        let mut stmt = tx
+            .prepare_cached(
+                "INSERT OR REPLACE INTO entities (kind, id, updated_at, data) VALUES (?1, ?2, ?3, ?4)",
+            )
+            .map_err(store_error)?;
+        for record in records {
+            let updated_at = record.get("updatedAt").and_then(|v| v.as_str());
+            stmt.execute(params![
+                kind.as_str(),
+                record_id(record)?,
+                updated_at,
+                record.to_string()
+            ])
+            .map_err(store_error)?;
+        }
+        Ok(())
+    }
+}
+
+fn store_error(e: rusqlite::Error) -> LinearError {
+    LinearError::Internal(format!("Sync store: {}", e))
+}
+
+fn decode(data: String) -> Result<serde_json::Value, LinearError> {
+    serde_json::from_str(&data)
+        .map_err(|e| LinearError::Internal(format!("Sync store: corrupt record: {}", e)))
+}
+
+impl SyncStore for SqliteStore {
+    fn upsert(
+        &mut self,
+        kind: EntityKind,
+        records: &[serde_json::Value],
+    ) -> Result<(), LinearError> {
+        let tx = self.conn.transaction().map_err(store_error)?;
+        Self::insert(&tx, kind, records)?;
+        tx.commit().map_err(store_error)
+    }
+
+    fn replace(
+        &mut self,
+        kind: EntityKind,
+        records: &[serde_json::Value],
+    ) -> Result<(), LinearError> {
+        let tx = self.conn.transaction().map_err(store_error)?;
+        tx.execute("DELETE FROM entities WHERE kind = ?1", [kind.as_str()])
+            .map_err(store_error)?;
+        Self::insert(&tx, kind, records)?;
+        tx.commit().map_err(store_error)
+    }
+
+    fn get(&self, kind: EntityKind, id: &str) -> Result<Option<serde_json::Value>, LinearError> {
+        self.conn
+            .query_row(
+                "SELECT data FROM entities WHERE kind = ?1 AND id = ?2",
+                [kind.as_str(), id],
+                |row| row.get::<_, String>(0),
+            )
+            .optional()
+            .map_err(store_error)?
+            .map(decode)
+            .transpose()
+    }
+
+    fn list(&self, kind: EntityKind) -> Result<Vec<serde_json::Value>, LinearError> {
+        let mut stmt = self
+            .conn
+            .prepare_cached("SELECT data FROM entities WHERE kind = ?1 ORDER BY id")
+            .map_err(store_error)?;
+        let rows = stmt
+            .query_map([kind.as_str()], |row| row.get::<_, String>(0))
+            .map_err(store_error)?;
+        rows.map(|row| decode(row.map_err(store_error)?)).collect()
+    }
+
+    fn meta(&self, key: &str) -> Result<Option<String>, LinearError> {
+        self.conn
+            .query_row("SELECT value FROM sync_meta WHERE key = ?1", [key], |row| {
+                row.get(0)
+            })
+            .optional()
+            .map_err(store_error)
+    }
+
+    fn set_meta(&mut self, key: &str, value: &str) -> Result<(), LinearError> {
+        self.conn
+            .execute(
+                "INSERT OR REPLACE INTO sync_meta (key, value) VALUES (?1, ?2)",
+                [key, value],
+            )
+            .map(|_| ())
+            .map_err(store_error)
+    }
+}
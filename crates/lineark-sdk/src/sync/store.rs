@@ -0,0 +1,105 @@
+//! Storage backends for the [`SyncEngine`](super::SyncEngine) mirror.
+
+use super::EntityKind;
+use crate::error::LinearError;
+use std::collections::{BTreeMap, HashMap};
+
+/// Where a [`SyncEngine`](super::SyncEngine) keeps its mirror.
+///
+/// Records are the JSON objects returned by the API, keyed by their `id`.
+/// Implementations only need to store them; the engine decides what to write.
+/// Besides records, a store keeps a few string values under `meta` keys
+/// (sync cursors and the last applied `lastSyncId`).
+pub trait SyncStore: Send + 'static {
+    /// Insert or replace records of `kind`, keyed by their `id` field.
+    fn upsert(
+        &mut self,
+        kind: EntityKind,
+        records: &[serde_json::Value],
+    ) -> Result<(), LinearError>;
+
+    /// Replace every record of `kind` with `records`.
+    fn replace(
+        &mut self,
+        kind: EntityKind,
+        records: &[serde_json::Value],
+    ) -> Result<(), LinearError>;
+
+    /// The record of `kind` with the given `id`, if mirrored.
+    fn get(&self, kind: EntityKind, id: &str) -> Result<Option<serde_json::Value>, LinearError>;
+
+    /// Every mirrored record of `kind`.
+    fn list(&self, kind: EntityKind) -> Result<Vec<serde_json::Value>, LinearError>;
+
+    /// A value previously written with [`set_meta`](Self::set_meta).
+    fn meta(&self, key: &str) -> Result<Option<String>, LinearError>;
+
+    /// Persist a value under `key`.
+    fn set_meta(&mut self, key: &str, value: &str) -> Result<(), LinearError>;
+}
+
+/// The `id` of a record, which every mirrored entity has.
+pub(crate) fn record_id(record: &serde_json::Value) -> Result<&str, LinearError> {
+    record
+        .get("id")
+        .and_then(|id| id.as_str())
+        .ok_or_else(|| LinearError::MissingData("Synced record has no 'id'".to_string()))
+}
+
+/// A [`SyncStore`] kept in memory, lost when dropped. Useful for short-lived
+/// processes and tests.
+#[derive(Debug, Default)]
+pub struct MemoryStore {
+    records: HashMap<EntityKind, BTreeMap<String, serde_json::Value>>,
+    meta: HashMap<String, String>,
+}
+
+impl MemoryStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl SyncStore for MemoryStore {
+    fn upsert(
+        &mut self,
+        kind: EntityKind,
+        records: &[serde_json::Value],
+    ) -> Result<(), LinearError> {
+        let table = self.records.entry(kind).or_default();
+        for record in records {
+            table.insert(record_id(record)?.to_string(), record.clone());
+        }
+        Ok(())
+    }
+
+    fn replace(
+        &mut self,
+        kind: EntityKind,
+        records: &[serde_json::Value],
+    ) -> Result<(), LinearError> {
+        self.records.remove(&kind);
+        self.upsert(kind, records)
+    }
+
+    fn get(&self, kind: EntityKind, id: &str) -> Result<Option<serde_json::Value>, LinearError> {
+        Ok(self.records.get(&kind).and_then(|t| t.get(id)).cloned())
+    }
+
+    fn list(&self, kind: EntityKind) -> Result<Vec<serde_json::Value>, LinearError> {
+        Ok(self
+            .records
+            .get(&kind)
+            .map(|t| t.values().cloned().collect())
+            .unwrap_or_default())
+    }
+
+    fn meta(&self, key: &str) -> Result<Option<String>, LinearError> {
+        Ok(self.meta.get(key).cloned())
+    }
+
+    fn set_meta(&mut self, key: &str, value: &str) -> Result<(), LinearError> {
+        self.meta.insert(key.to_string(), value.to_string());
+        Ok(())
+    }
+}
@@ -0,0 +1,474 @@
+//! Inbound Linear webhooks.
+//!
+//! Linear signs every webhook delivery with the webhook's secret: the
+//! `Linear-Signature` header carries the hex-encoded HMAC-SHA256 of the raw
+//! request body. [`WebhookVerifier`] checks that signature, rejects deliveries
+//! whose `webhookTimestamp` is too old (replay protection), and parses the body
+//! into a [`WebhookPayload`]. [`WebhookPayload::event`] then gives a typed
+//! [`WebhookEvent`] built on the generated types.
+//!
+//! ```no_run
+//! # fn handle(headers: &lineark_sdk::reqwest::header::HeaderMap, body: &[u8]) -> Result<(), Box<dyn std::error::Error>> {
+//! use lineark_sdk::webhooks::{WebhookAction, WebhookEvent, WebhookVerifier};
+//!
+//! let verifier = WebhookVerifier::new(std::env::var("LINEAR_WEBHOOK_SECRET")?);
+//! let payload = verifier.verify_headers(headers, body)?;
+//! match payload.event()? {
+//!     WebhookEvent::Issue(event) if event.action == WebhookAction::Update => {
+//!         if event.changed("stateId") {
+//!             println!("{:?} changed state", event.data.identifier);
+//!         }
+//!     }
+//!     WebhookEvent::Comment(event) => println!("comment: {:?}", event.data.body),
+//!     _ => {}
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! The verifier only needs the headers and the raw body, so it plugs into any
+//! HTTP framework. `reqwest::header::HeaderMap` is the `http` crate's
+//! `HeaderMap`, which is what axum and hyper hand to handlers:
+//!
+//! ```ignore
+//! async fn linear_webhook(
+//!     State(verifier): State<Arc<WebhookVerifier>>,
+//!     headers: HeaderMap,
+//!     body: Bytes,
+//! ) -> StatusCode {
+//!     match verifier.verify_headers(&headers, &body) {
+//!         Ok(payload) => { /* queue payload */ StatusCode::OK }
+//!         Err(_) => StatusCode::UNAUTHORIZED,
+//!     }
+//! }
+//! ```
+//!
+//! Always verify against the exact bytes received; re-serialized JSON will not
+//! match the signature.
+
+use crate::generated::types::{
+    Attachment, Comment, Cycle, Document, Initiative, Issue, IssueLabel, Project, ProjectUpdate,
+    Reaction,
+};
+use hmac::{Hmac, Mac};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use std::fmt;
+use std::time::Duration;
+
+/// Header carrying the hex-encoded HMAC-SHA256 signature of the body.
+pub const SIGNATURE_HEADER: &str = "linear-signature";
+
+/// Header carrying the delivery's unique ID.
+pub const DELIVERY_HEADER: &str = "linear-delivery";
+
+/// Header carrying the entity type of the event, e.g. `Issue`.
+pub const EVENT_HEADER: &str = "linear-event";
+
+/// How old a delivery may be before it is rejected as a possible replay.
+/// Linear recommends one minute.
+const DEFAULT_TOLERANCE: Duration = Duration::from_secs(60);
+
+/// Errors from verifying or decoding a webhook delivery.
+#[derive(Debug)]
+pub enum WebhookError {
+    /// The `Linear-Signature` header is absent.
+    MissingSignature,
+    /// The signature does not match the body.
+    InvalidSignature,
+    /// `webhookTimestamp` is further from the current time than the
+    /// verifier's tolerance.
+    StaleTimestamp {
+        /// Distance between the delivery timestamp and now.
+        age: Duration,
+    },
+    /// The body is not a valid webhook payload.
+    Malformed(String),
+}
+
+impl fmt::Display for WebhookError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingSignature => write!(f, "Missing {} header", SIGNATURE_HEADER),
+            Self::InvalidSignature => write!(f, "Webhook signature does not match"),
+            Self::StaleTimestamp { age } => {
+                write!(f, "Webhook timestamp is {}s off", age.as_secs())
+            }
+            Self::Malformed(msg) => write!(f, "Malformed webhook payload: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for WebhookError {}
+
+/// Verifies and parses webhook deliveries signed with one secret.
+#[derive(Clone)]
+pub struct WebhookVerifier {
+    secret: Vec<u8>,
+    tolerance: Option<Duration>,
+}
+
+impl fmt::Debug for WebhookVerifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WebhookVerifier")
+            .field("secret", &"[redacted]")
+            .field("tolerance", &self.tolerance)
+            .finish()
+    }
+}
+
+impl WebhookVerifier {
+    /// A verifier for the webhook's signing secret, shown in Linear's webhook
+    /// settings.
+    pub fn new(secret: impl Into<String>) -> Self {
+        Self {
+            secret: secret.into().into_bytes(),
+            tolerance: Some(DEFAULT_TOLERANCE),
+        }
+    }
+
+    /// Maximum allowed distance between `webhookTimestamp` and the current
+    /// time (default 60 seconds).
+    pub fn tolerance(mut self, tolerance: Duration) -> Self {
+        self.tolerance = Some(tolerance);
+        self
+    }
+
+    /// Skip the timestamp check, e.g. when re-processing stored deliveries.
+    pub fn without_timestamp_check(mut self) -> Self {
+        self.tolerance = None;
+        self
+    }
+
+    /// Check that `signature` (the `Linear-Signature` header) is the
+    /// HMAC-SHA256 of `body`. The comparison is constant-time.
+    pub fn verify_signature(&self, body: &[u8], signature: &str) -> Result<(), WebhookError> {
+        let expected = decode_hex(signature.trim()).ok_or(WebhookError::InvalidSignature)?;
+        let mut mac =
+            Hmac::<Sha256>::new_from_slice(&self.secret).expect("HMAC accepts keys of any length");
+        mac.update(body);
+        mac.verify_slice(&expected)
+            .map_err(|_| WebhookError::InvalidSignature)
+    }
+
+    /// Verify the signature and timestamp of a delivery and parse its body.
+    pub fn verify(&self, body: &[u8], signature: &str) -> Result<WebhookPayload, WebhookError> {
+        self.verify_at(body, signature, chrono::Utc::now().timestamp_millis())
+    }
+
+    /// Like [`verify`](Self::verify), reading the signature from request
+    /// headers.
+    pub fn verify_headers(
+        &self,
+        headers: &reqwest::header::HeaderMap,
+        body: &[u8],
+    ) -> Result<WebhookPayload, WebhookError> {
+        let signature = headers
+            .get(SIGNATURE_HEADER)
+            .and_then(|v| v.to_str().ok())
+            .ok_or(WebhookError::MissingSignature)?;
+        self.verify(body, signature)
+    }
+
+    /// [`verify`](Self::verify) against a given current time in milliseconds
+    /// since the Unix epoch.
+    pub fn verify_at(
+        &self,
+        body: &[u8],
+        signature: &str,
+        now_millis: i64,
+    ) -> Result<WebhookPayload, WebhookError> {
+        self.verify_signature(body, signature)?;
+        let payload = WebhookPayload::parse(body)?;
+        if let Some(tolerance) = self.tolerance {
+            let age = Duration::from_millis(now_millis.abs_diff(payload.webhook_timestamp));
+            if age > tolerance {
+                return Err(WebhookError::StaleTimestamp { age });
+            }
+        }
+        Ok(payload)
+    }
+
+    /// The signature Linear would send for `body`, for tests and fixtures.
+    pub fn sign(&self, body: &[u8]) -> String {
+        let mut mac =
+            Hmac::<Sha256>::new_from_slice(&self.secret).expect("HMAC accepts keys of any length");
+        mac.update(body);
+        mac.finalize()
+            .into_bytes()
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect()
+    }
+}
+
+fn decode_hex(s: &str) -> Option<Vec<u8>> {
+    if !s.len().is_multiple_of(2) {
+        return None;
+    }
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(s.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+/// What happened to the entity.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WebhookAction {
+    Create,
+    Update,
+    Remove,
+    /// Any action this SDK version doesn't know, e.g. `set` or `restore`.
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// A webhook delivery body, with the entity left as raw JSON.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookPayload {
+    pub action: WebhookAction,
+    /// The entity type, e.g. `"Issue"`.
+    #[serde(rename = "type")]
+    pub entity_type: String,
+    /// The entity after the change (before it, for `remove`).
+    #[serde(default)]
+    pub data: serde_json::Value,
+    /// Previous values of the fields changed by an `update`.
+    #[serde(default)]
+    pub updated_from: Option<serde_json::Value>,
+    /// Link to the entity in the Linear app.
+    #[serde(default)]
+    pub url: Option<String>,
+    /// Who triggered the change.
+    #[serde(default)]
+    pub actor: Option<serde_json::Value>,
+    #[serde(default)]
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default)]
+    pub organization_id: Option<String>,
+    /// When the delivery was sent, in milliseconds since the Unix epoch.
+    pub webhook_timestamp: i64,
+    #[serde(default)]
+    pub webhook_id: Option<String>,
+}
+
+impl WebhookPayload {
+    /// Parse a body without verifying it. Prefer [`WebhookVerifier::verify`].
+    pub fn parse(body: &[u8]) -> Result<Self, WebhookError> {
+        serde_json::from_slice(body).map_err(|e| WebhookError::Malformed(e.to_string()))
+    }
+
+    /// The payload as a typed event.
+    pub fn event(&self) -> Result<WebhookEvent, WebhookError> {
+        Ok(match self.entity_type.as_str() {
+            "Issue" => WebhookEvent::Issue(self.entity_event()?),
+            "Comment" => WebhookEvent::Comment(self.entity_event()?),
+            "Project" => WebhookEvent::Project(self.entity_event()?),
+            "ProjectUpdate" => WebhookEvent::ProjectUpdate(self.entity_event()?),
+            "Cycle" => WebhookEvent::Cycle(self.entity_event()?),
+            "IssueLabel" => WebhookEvent::IssueLabel(self.entity_event()?),
+            "Reaction" => WebhookEvent::Reaction(self.entity_event()?),
+            "Attachment" => WebhookEvent::Attachment(self.entity_event()?),
+            "Document" => WebhookEvent::Document(self.entity_event()?),
+            "Initiative" => WebhookEvent::Initiative(self.entity_event()?),
+            other => WebhookEvent::Other(other.to_string()),
+        })
+    }
+
+    fn entity_event<T: DeserializeOwned>(&self) -> Result<Box<EntityEvent<T>>, WebhookError> {
+        let decode = |value: &serde_json::Value| {
+            decode_lenient::<T>(value)
+                .map_err(|e| WebhookError::Malformed(format!("{} data: {}", self.entity_type, e)))
+        };
+        let updated_from = match &self.updated_from {
+            Some(serde_json::Value::Object(map)) => Some(map.clone()),
+            _ => None,
+        };
+        Ok(Box::new(EntityEvent {
+            action: self.action.clone(),
+            data: decode(&self.data)?,
+            previous: match &self.updated_from {
+                Some(value @ serde_json::Value::Object(_)) => Some(decode(value)?),
+                _ => None,
+            },
+            updated_from,
+            raw: self.data.clone(),
+        }))
+    }
+}
+
+/// Decode an entity object, dropping fields whose webhook shape differs from
+/// the API's (e.g. `labels` is a plain list in webhooks but a connection in
+/// the generated types). Dropped fields stay available in the raw JSON.
+fn decode_lenient<T: DeserializeOwned>(value: &serde_json::Value) -> serde_json::Result<T> {
+    let first_error = match serde_json::from_value(value.clone()) {
+        Ok(decoded) => return Ok(decoded),
+        Err(e) => e,
+    };
+    let serde_json::Value::Object(map) = value else {
+        return Err(first_error);
+    };
+    let compatible: serde_json::Map<_, _> = map
+        .iter()
+        .filter(|(key, field)| {
+            let single = serde_json::json!({ key.as_str(): field });
+            serde_json::from_value::<T>(single).is_ok()
+        })
+        .map(|(key, field)| (key.clone(), field.clone()))
+        .collect();
+    serde_json::from_value(serde_json::Value::Object(compatible))
+}
+
+/// A change to one entity.
+#[derive(Debug, Clone)]
+pub struct EntityEvent<T> {
+    pub action: WebhookAction,
+    /// The entity as sent by Linear. Fields webhooks don't include, or send in
+    /// a different shape than the API, are `None`.
+    pub data: T,
+    /// For updates, the previous values of the changed fields; every other
+    /// field is `None`.
+    pub previous: Option<T>,
+    /// For updates, the raw `updatedFrom` object, keyed by camelCase field
+    /// name. Includes fields such as `stateId` that have no counterpart on
+    /// the typed entity.
+    pub updated_from: Option<serde_json::Map<String, serde_json::Value>>,
+    /// The raw `data` object, including foreign keys like `teamId`.
+    pub raw: serde_json::Value,
+}
+
+impl<T> EntityEvent<T> {
+    /// Whether an update changed `field` (camelCase, as in the payload).
+    pub fn changed(&self, field: &str) -> bool {
+        self.updated_from
+            .as_ref()
+            .is_some_and(|m| m.contains_key(field))
+    }
+}
+
+/// A typed webhook event. Entity events are boxed since the generated types
+/// are large.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum WebhookEvent {
+    Issue(Box<EntityEvent<Issue>>),
+    Comment(Box<EntityEvent<Comment>>),
+    Project(Box<EntityEvent<Project>>),
+    ProjectUpdate(Box<EntityEvent<ProjectUpdate>>),
+    Cycle(Box<EntityEvent<Cycle>>),
+    IssueLabel(Box<EntityEvent<IssueLabel>>),
+    Reaction(Box<EntityEvent<Reaction>>),
+    Attachment(Box<EntityEvent<Attachment>>),
+    Document(Box<EntityEvent<Document>>),
+    Initiative(Box<EntityEvent<Initiative>>),
+    /// An entity type without a typed variant; read it from the
+    /// [`WebhookPayload`].
+    Other(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOW: i64 = 1_700_000_000_000;
+
+    fn body(timestamp: i64) -> Vec<u8> {
+        serde_json::to_vec(&serde_json::json!({
+            "action": "create",
+            "type": "Comment",
+            "data": {"id": "c1", "body": "hi", "issueId": "i1"},
+            "webhookTimestamp": timestamp,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn accepts_valid_signature() {
+        let verifier = WebhookVerifier::new("secret");
+        let body = body(NOW);
+        let signature = verifier.sign(&body);
+        let payload = verifier.verify_at(&body, &signature, NOW + 5_000).unwrap();
+        assert_eq!(payload.entity_type, "Comment");
+        assert_eq!(payload.action, WebhookAction::Create);
+    }
+
+    #[test]
+    fn sign_matches_known_vector() {
+        // RFC 4231 test case 2.
+        let verifier = WebhookVerifier::new("Jefe");
+        assert_eq!(
+            verifier.sign(b"what do ya want for nothing?"),
+            "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+    }
+
+    #[test]
+    fn rejects_tampered_body_and_wrong_secret() {
+        let verifier = WebhookVerifier::new("secret");
+        let body = body(NOW);
+        let signature = verifier.sign(&body);
+
+        let mut tampered = body.clone();
+        tampered[10] ^= 1;
+        assert!(matches!(
+            verifier.verify_at(&tampered, &signature, NOW),
+            Err(WebhookError::InvalidSignature)
+        ));
+        assert!(matches!(
+            WebhookVerifier::new("other").verify_at(&body, &signature, NOW),
+            Err(WebhookError::InvalidSignature)
+        ));
+        assert!(matches!(
+            verifier.verify_at(&body, "not-hex", NOW),
+            Err(WebhookError::InvalidSignature)
+        ));
+    }
+
+    #[test]
+    fn rejects_stale_deliveries() {
+        let verifier = WebhookVerifier::new("secret");
+        let body = body(NOW);
+        let signature = verifier.sign(&body);
+        assert!(matches!(
+            verifier.verify_at(&body, &signature, NOW + 61_000),
+            Err(WebhookError::StaleTimestamp { age }) if age.as_secs() == 61
+        ));
+        assert!(verifier
+            .clone()
+            .tolerance(Duration::from_secs(120))
+            .verify_at(&body, &signature, NOW + 61_000)
+            .is_ok());
+        assert!(verifier
+            .without_timestamp_check()
+            .verify_at(&body, &signature, NOW + 86_400_000)
+            .is_ok());
+    }
+
+    #[test]
+    fn verify_headers_requires_signature() {
+        let verifier = WebhookVerifier::new("secret");
+        let mut headers = reqwest::header::HeaderMap::new();
+        assert!(matches!(
+            verifier.verify_headers(&headers, &body(NOW)),
+            Err(WebhookError::MissingSignature)
+        ));
+        headers.insert(SIGNATURE_HEADER, "00".parse().unwrap());
+        assert!(matches!(
+            verifier.verify_headers(&headers, &body(NOW)),
+            Err(WebhookError::InvalidSignature)
+        ));
+    }
+
+    #[test]
+    fn unknown_types_and_actions_are_preserved() {
+        let payload = WebhookPayload::parse(
+            br#"{"action": "restore", "type": "IssueSLA", "data": {}, "webhookTimestamp": 1}"#,
+        )
+        .unwrap();
+        assert_eq!(payload.action, WebhookAction::Other("restore".to_string()));
+        assert!(matches!(payload.event().unwrap(), WebhookEvent::Other(t) if t == "IssueSLA"));
+    }
+}
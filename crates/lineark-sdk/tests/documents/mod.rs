@@ -0,0 +1,118 @@
+//! Typed GraphQL operations.
+//!
+//! Generated by `lineark-codegen documents` — do not edit.
+/// The `MyIssues` operation.
+pub mod my_issues {
+    use serde::{Deserialize, Serialize};
+    pub const OPERATION_NAME: &str = "MyIssues";
+    pub const DOCUMENT: &str = "query MyIssues($filter: IssueFilter, $first: Int, $labels: Int = 5) {\n  viewer {\n    ...UserFields\n  }\n  mine: issues(filter: $filter, first: $first) {\n    nodes {\n      ...IssueFields\n      labels(first: $labels) {\n        nodes {\n          name\n        }\n      }\n    }\n  }\n  teams {\n    nodes {\n      key\n      name\n    }\n  }\n}\n\nfragment IssueFields on Issue {\n  id\n  identifier\n  title\n  priority\n  dueDate\n  createdAt\n  state {\n    name\n    type\n  }\n}\n\nfragment UserFields on User {\n  id\n  name\n}";
+    /// Variables of `MyIssues`.
+    #[derive(Debug, Clone, Serialize)]
+    pub struct Variables {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub filter: Option<lineark_sdk::generated::inputs::IssueFilter>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub first: Option<i64>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub labels: Option<i64>,
+    }
+    /// Selection of `viewer` on `User`.
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct Viewer {
+        pub id: String,
+        pub name: String,
+    }
+    /// Selection of `mine.nodes.state` on `WorkflowState`.
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct MineNodesState {
+        pub name: String,
+        pub r#type: String,
+    }
+    /// Selection of `mine.nodes.labels.nodes` on `IssueLabel`.
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct MineNodesLabelsNodes {
+        pub name: String,
+    }
+    /// Selection of `mine.nodes.labels` on `IssueLabelConnection`.
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct MineNodesLabels {
+        pub nodes: Vec<MineNodesLabelsNodes>,
+    }
+    /// Selection of `mine.nodes` on `Issue`.
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct MineNodes {
+        pub id: String,
+        pub identifier: String,
+        pub title: String,
+        pub priority: f64,
+        #[serde(rename = "dueDate")]
+        pub due_date: Option<lineark_sdk::generated::scalars::TimelessDate>,
+        #[serde(rename = "createdAt")]
+        pub created_at: lineark_sdk::generated::scalars::DateTime,
+        pub state: MineNodesState,
+        pub labels: MineNodesLabels,
+    }
+    /// Selection of `mine` on `IssueConnection`.
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct Mine {
+        pub nodes: Vec<MineNodes>,
+    }
+    /// Selection of `teams.nodes` on `Team`.
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct TeamsNodes {
+        pub key: String,
+        pub name: String,
+    }
+    /// Selection of `teams` on `TeamConnection`.
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct Teams {
+        pub nodes: Vec<TeamsNodes>,
+    }
+    /// Data returned by `MyIssues`.
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct ResponseData {
+        pub viewer: Viewer,
+        pub mine: Mine,
+        pub teams: Teams,
+    }
+    /// Marker to run the operation with `Client::execute_document`.
+    pub struct Operation;
+    impl lineark_sdk::GraphQLDocument for Operation {
+        type Variables = Variables;
+        type ResponseData = ResponseData;
+        const OPERATION_NAME: &'static str = OPERATION_NAME;
+        const DOCUMENT: &'static str = DOCUMENT;
+    }
+}
+/// The `ArchiveIssue` operation.
+pub mod archive_issue {
+    use serde::{Deserialize, Serialize};
+    pub const OPERATION_NAME: &str = "ArchiveIssue";
+    pub const DOCUMENT: &str = "mutation ArchiveIssue($id: String!) {\n  issueArchive(id: $id) {\n    success\n    lastSyncId\n  }\n}";
+    /// Variables of `ArchiveIssue`.
+    #[derive(Debug, Clone, Serialize)]
+    pub struct Variables {
+        pub id: String,
+    }
+    /// Selection of `issueArchive` on `IssueArchivePayload`.
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct IssueArchive {
+        pub success: bool,
+        #[serde(rename = "lastSyncId")]
+        pub last_sync_id: f64,
+    }
+    /// Data returned by `ArchiveIssue`.
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct ResponseData {
+        #[serde(rename = "issueArchive")]
+        pub issue_archive: IssueArchive,
+    }
+    /// Marker to run the operation with `Client::execute_document`.
+    pub struct Operation;
+    impl lineark_sdk::GraphQLDocument for Operation {
+        type Variables = Variables;
+        type ResponseData = ResponseData;
+        const OPERATION_NAME: &'static str = OPERATION_NAME;
+        const DOCUMENT: &'static str = DOCUMENT;
+    }
+}
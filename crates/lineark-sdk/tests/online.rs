@@ -4,13 +4,15 @@
 //! When the token file is missing, tests are automatically skipped with a message.
 //!
 //! The token should be connected to a test workspace — never use production tokens here.
+//! Set `LINEARK_CASSETTE` to record the suite, or to replay it offline without
+//! a token (see `lineark_test_utils::cassette`).
 
 use lineark_sdk::generated::types::*;
 use lineark_sdk::Client;
 use lineark_test_utils::*;
 
 fn test_client() -> Client {
-    Client::from_token(test_token()).expect("failed to create test client")
+    with_cassette(Client::from_token(test_token()).expect("failed to create test client"))
 }
 
 test_with::tokio_runner!(online);
@@ -138,10 +140,7 @@ mod online {
         let client = test_client();
 
         // Create a workspace-level label with a unique name.
-        let unique = format!(
-            "[test] sdk-label {}",
-            &uuid::Uuid::new_v4().to_string()[..8]
-        );
+        let unique = format!("[test] sdk-label {}", &unique_id()[..8]);
         let input = IssueLabelCreateInput {
             name: unique.clone(),
             color: Some("#eb5757".to_string()).into(),
@@ -359,7 +358,7 @@ mod online {
         let team_id = team.id.clone();
 
         // Create an issue with a unique title.
-        let unique = format!("[builder-test-{}]", uuid::Uuid::new_v4());
+        let unique = format!("[builder-test-{}]", unique_id());
         let input = IssueCreateInput {
             title: Some(unique.clone()).into(),
             team_id,
@@ -432,7 +431,7 @@ mod online {
         let team_id = team.id.clone();
 
         // Create an issue with a unique title in the first team.
-        let unique = format!("[team-filter-{}]", uuid::Uuid::new_v4());
+        let unique = format!("[team-filter-{}]", unique_id());
         let input = IssueCreateInput {
             title: Some(unique.clone()).into(),
             team_id: team_id.clone(),
@@ -549,10 +548,7 @@ mod online {
         let team_id = team.id.clone();
 
         // Create an issue.
-        let title = format!(
-            "[test] SDK issue_create_and_delete {}",
-            &uuid::Uuid::new_v4().to_string()[..8]
-        );
+        let title = format!("[test] SDK issue_create_and_delete {}", &unique_id()[..8]);
         let input = IssueCreateInput {
             title: Some(title).into(),
             team_id,
@@ -589,7 +585,7 @@ mod online {
         let team = create_test_team(&client).await;
         let team_id = team.id.clone();
 
-        let suffix = &uuid::Uuid::new_v4().to_string()[..8];
+        let suffix = &unique_id()[..8];
         let input = IssueCreateInput {
             title: Some(format!("[test] SDK issue_update {suffix}")).into(),
             team_id,
@@ -640,7 +636,7 @@ mod online {
         let input = IssueCreateInput {
             title: Some(format!(
                 "[test] SDK issue_archive_and_unarchive {}",
-                &uuid::Uuid::new_v4().to_string()[..8]
+                &unique_id()[..8]
             ))
             .into(),
             team_id,
@@ -688,11 +684,7 @@ mod online {
         let team_id = team.id.clone();
 
         let issue_input = IssueCreateInput {
-            title: Some(format!(
-                "[test] SDK comment_create {}",
-                &uuid::Uuid::new_v4().to_string()[..8]
-            ))
-            .into(),
+            title: Some(format!("[test] SDK comment_create {}", &unique_id()[..8])).into(),
             team_id,
             priority: Some(4).into(),
             ..Default::default()
@@ -757,7 +749,7 @@ mod online {
         let team_id = team.id.clone();
 
         // Create a document.
-        let suffix = &uuid::Uuid::new_v4().to_string()[..8];
+        let suffix = &unique_id()[..8];
         let title = format!("[test] SDK document_create_update_and_delete {suffix}");
         let input = DocumentCreateInput {
             title: title.clone(),
@@ -825,7 +817,7 @@ mod online {
         let team_id = team.id.clone();
 
         // Create two issues to relate.
-        let suffix = &uuid::Uuid::new_v4().to_string()[..8];
+        let suffix = &unique_id()[..8];
         let input_a = IssueCreateInput {
             title: Some(format!("[test] relation issue A {suffix}")).into(),
             team_id: team_id.clone(),
@@ -989,11 +981,7 @@ mod online {
 
         // Create two issues.
         let input_a = IssueCreateInput {
-            title: Some(format!(
-                "[test] SDK batch_update A {}",
-                &uuid::Uuid::new_v4().to_string()[..8]
-            ))
-            .into(),
+            title: Some(format!("[test] SDK batch_update A {}", &unique_id()[..8])).into(),
             team_id: team_id.clone(),
             priority: Some(4).into(),
             ..Default::default()
@@ -1010,11 +998,7 @@ mod online {
         };
 
         let input_b = IssueCreateInput {
-            title: Some(format!(
-                "[test] SDK batch_update B {}",
-                &uuid::Uuid::new_v4().to_string()[..8]
-            ))
-            .into(),
+            title: Some(format!("[test] SDK batch_update B {}", &unique_id()[..8])).into(),
             team_id,
             priority: Some(4).into(),
             ..Default::default()
@@ -1057,7 +1041,7 @@ mod online {
         let client = test_client();
 
         // Create a team with a unique name.
-        let unique = format!("[test] sdk-team {}", &uuid::Uuid::new_v4().to_string()[..8]);
+        let unique = format!("[test] sdk-team {}", &unique_id()[..8]);
         let input = TeamCreateInput {
             name: unique.clone(),
             ..Default::default()
@@ -1104,10 +1088,7 @@ mod online {
         let client = test_client();
 
         // Create a team (the authenticated user becomes creator + auto-member).
-        let unique = format!(
-            "[test] sdk-member {}",
-            &uuid::Uuid::new_v4().to_string()[..8]
-        );
+        let unique = format!("[test] sdk-member {}", &unique_id()[..8]);
         let input = TeamCreateInput {
             name: unique,
             ..Default::default()
@@ -1178,7 +1159,7 @@ mod online {
         let issue_input = IssueCreateInput {
             title: Some(format!(
                 "[test] SDK comment_update_changes_body {}",
-                &uuid::Uuid::new_v4().to_string()[..8]
+                &unique_id()[..8]
             ))
             .into(),
             team_id,
@@ -1240,7 +1221,7 @@ mod online {
         let issue_input = IssueCreateInput {
             title: Some(format!(
                 "[test] SDK comment_resolve_and_unresolve {}",
-                &uuid::Uuid::new_v4().to_string()[..8]
+                &unique_id()[..8]
             ))
             .into(),
             team_id,
@@ -1313,7 +1294,7 @@ mod online {
         let team_id = team.id.clone();
 
         // Create an issue so we can look up its branchName.
-        let uid = &uuid::Uuid::new_v4().to_string()[..8];
+        let uid = &unique_id()[..8];
         let input = IssueCreateInput {
             title: Some(format!("[test] SDK branch search {uid}")).into(),
             team_id,
@@ -1366,7 +1347,7 @@ mod online {
 
     #[test_with::runtime_ignore_if(no_online_test_token)]
     async fn invalid_token_returns_auth_error() {
-        let client = Client::from_token("lin_api_invalid_token_12345").unwrap();
+        let client = with_cassette(Client::from_token("lin_api_invalid_token_12345").unwrap());
         let result = client.whoami::<User>().await;
         assert!(result.is_err(), "invalid token should produce an error");
     }
@@ -0,0 +1,66 @@
+//! Optional record/replay of the online suites.
+//!
+//! When `LINEARK_CASSETTE` names a cassette file, every client built through
+//! this crate records into or replays from it (see
+//! [`lineark_sdk::cassette`]). In replay mode the online tests run without a
+//! token and without network access. The CLI suite spawns the `lineark`
+//! binary, which only honours the variable when built with its test-only
+//! `cassette` feature:
+//!
+//! ```text
+//! LINEARK_CASSETTE=$PWD/target/cassettes/cli.jsonl LINEARK_CASSETTE_MODE=record \
+//!     cargo test -p lineark --features cassette --test online -- --test-threads=1
+//! ```
+//!
+//! For the SDK suite, `make record-cassettes` records
+//! `crates/lineark-sdk/tests/cassettes/online.jsonl` against the test workspace
+//! and `make test-replay` replays it without a token. No recording is
+//! committed, so CI only runs the live online job. Response bodies are stored
+//! verbatim, so review a recording for workspace data before sharing it.
+//!
+//! Names the tests make up are compared against responses, so they come from
+//! [`unique_id`], which the cassette records and replays.
+
+use std::sync::OnceLock;
+
+use lineark_sdk::cassette::{Cassette, CassetteMode};
+use lineark_sdk::{Client, LinearError};
+
+/// The cassette configured by the environment, loaded once per process.
+pub fn cassette() -> Option<&'static Cassette> {
+    static CASSETTE: OnceLock<Option<Cassette>> = OnceLock::new();
+    CASSETTE
+        .get_or_init(|| Cassette::from_env().unwrap_or_else(|e| panic!("{e}")))
+        .as_ref()
+}
+
+/// Whether responses come from a cassette rather than the network.
+pub fn replaying() -> bool {
+    cassette().is_some_and(|c| c.mode() == CassetteMode::Replay)
+}
+
+/// A fresh v4 UUID for naming test entities. With a cassette configured it
+/// is recorded, and replaying returns the recorded one, so names compared
+/// against replayed responses match.
+pub fn unique_id() -> String {
+    let generate = || uuid::Uuid::new_v4().to_string();
+    match cassette() {
+        Some(cassette) => cassette
+            .generated(generate)
+            .unwrap_or_else(|e| panic!("{e}")),
+        None => generate(),
+    }
+}
+
+/// Attach the configured cassette, if any, to `client`.
+pub fn with_cassette(client: Client) -> Client {
+    match cassette() {
+        Some(cassette) => client.with_middleware(cassette.clone()),
+        None => client,
+    }
+}
+
+/// Blocking client for `token` with the configured cassette attached.
+pub(crate) fn blocking_client(token: String) -> Result<lineark_sdk::blocking::Client, LinearError> {
+    lineark_sdk::blocking::Client::from_async(with_cassette(Client::from_token(token)?))
+}
@@ -117,7 +117,11 @@ pub async fn cleanup_workspace(client: &Client) {
 
 /// Delete all test resources from the workspace (sync wrapper).
 /// Runs once per process via `std::sync::Once`. Best-effort, tolerates failures.
+/// Skipped when replaying a cassette, since there is no workspace to clean.
 pub fn cleanup_zombies() {
+    if crate::replaying() {
+        return;
+    }
     static ONCE: std::sync::Once = std::sync::Once::new();
     ONCE.call_once(|| {
         let _ = std::thread::spawn(|| {
@@ -1,10 +1,11 @@
 use lineark_sdk::generated::types::*;
-use lineark_sdk::Client;
 
+use crate::cassette::blocking_client;
 use crate::test_token;
 
 /// RAII guard — permanently deletes a team on drop.
-/// Uses a dedicated thread+runtime since Drop can't be async.
+/// Runs on a dedicated thread since the guard may be dropped inside an async
+/// test, where the blocking client can't be used.
 pub struct TeamGuard {
     pub token: String,
     pub id: String,
@@ -15,11 +16,9 @@ impl Drop for TeamGuard {
         let token = self.token.clone();
         let id = self.id.clone();
         let _ = std::thread::spawn(move || {
-            tokio::runtime::Runtime::new().unwrap().block_on(async {
-                if let Ok(client) = Client::from_token(token) {
-                    let _ = client.team_delete(id).await;
-                }
-            });
+            if let Ok(client) = blocking_client(token) {
+                let _ = client.team_delete(id);
+            }
         })
         .join();
     }
@@ -36,11 +35,9 @@ impl Drop for IssueGuard {
         let token = self.token.clone();
         let id = self.id.clone();
         let _ = std::thread::spawn(move || {
-            tokio::runtime::Runtime::new().unwrap().block_on(async {
-                if let Ok(client) = Client::from_token(token) {
-                    let _ = client.issue_delete::<Issue>(Some(true), id).await;
-                }
-            });
+            if let Ok(client) = blocking_client(token) {
+                let _ = client.issue_delete::<Issue>(Some(true), id);
+            }
         })
         .join();
     }
@@ -57,11 +54,9 @@ impl Drop for DocumentGuard {
         let token = self.token.clone();
         let id = self.id.clone();
         let _ = std::thread::spawn(move || {
-            tokio::runtime::Runtime::new().unwrap().block_on(async {
-                if let Ok(client) = Client::from_token(token) {
-                    let _ = client.document_delete::<Document>(id).await;
-                }
-            });
+            if let Ok(client) = blocking_client(token) {
+                let _ = client.document_delete::<Document>(id);
+            }
         })
         .join();
     }
@@ -78,11 +73,9 @@ impl Drop for ProjectGuard {
         let token = self.token.clone();
         let id = self.id.clone();
         let _ = std::thread::spawn(move || {
-            tokio::runtime::Runtime::new().unwrap().block_on(async {
-                if let Ok(client) = Client::from_token(token) {
-                    let _ = client.project_delete::<Project>(id).await;
-                }
-            });
+            if let Ok(client) = blocking_client(token) {
+                let _ = client.project_delete::<Project>(id);
+            }
         })
         .join();
     }
@@ -99,30 +92,24 @@ impl Drop for LabelGuard {
         let token = self.token.clone();
         let id = self.id.clone();
         let _ = std::thread::spawn(move || {
-            tokio::runtime::Runtime::new().unwrap().block_on(async {
-                if let Ok(client) = Client::from_token(token) {
-                    let _ = client.issue_label_delete(id).await;
-                }
-            });
+            if let Ok(client) = blocking_client(token) {
+                let _ = client.issue_label_delete(id);
+            }
         })
         .join();
     }
 }
 
-/// Delete a team by its UUID (sync, using a fresh tokio runtime).
+/// Delete a team by its UUID (sync, using the blocking client).
 pub fn delete_team(team_id: &str) {
-    let client = Client::from_token(test_token()).unwrap();
-    let id = team_id.to_string();
-    tokio::runtime::Runtime::new()
-        .unwrap()
-        .block_on(async { client.team_delete(id).await.unwrap() });
+    let client = blocking_client(test_token()).unwrap();
+    client.team_delete(team_id.to_string()).unwrap();
 }
 
-/// Permanently delete an issue by its UUID (sync, using a fresh tokio runtime).
+/// Permanently delete an issue by its UUID (sync, using the blocking client).
 pub fn delete_issue(issue_id: &str) {
-    let client = Client::from_token(test_token()).unwrap();
-    let id = issue_id.to_string();
-    tokio::runtime::Runtime::new()
-        .unwrap()
-        .block_on(async { client.issue_delete::<Issue>(Some(true), id).await.unwrap() });
+    let client = blocking_client(test_token()).unwrap();
+    client
+        .issue_delete::<Issue>(Some(true), issue_id.to_string())
+        .unwrap();
 }
@@ -1,14 +1,17 @@
 //! Shared test utilities for lineark online integration tests.
 //!
 //! Provides token loading, RAII guards for resource cleanup, retry helpers,
-//! and team creation helpers used by the online test suites.
+//! team creation helpers and optional cassette record/replay used by the
+//! online test suites.
 
+pub mod cassette;
 mod cleanup;
 pub mod guards;
 mod retry;
 mod team;
 mod token;
 
+pub use cassette::{replaying, unique_id, with_cassette};
 pub use cleanup::{cleanup_workspace, cleanup_zombies};
 pub use guards::*;
 pub use retry::{retry_create, retry_search, retry_with_backoff, settle};
@@ -2,7 +2,11 @@ use std::future::Future;
 
 /// Wait for the Linear API to propagate recently created resources.
 /// Linear is eventually consistent — created resources may not be queryable immediately.
+/// A no-op when replaying a cassette.
 pub async fn settle() {
+    if crate::replaying() {
+        return;
+    }
     tokio::time::sleep(std::time::Duration::from_secs(5)).await;
 }
 
@@ -58,7 +62,9 @@ where
     P: FnMut(&T) -> bool,
 {
     for i in 0..12 {
-        tokio::time::sleep(std::time::Duration::from_secs(if i < 3 { 2 } else { 5 })).await;
+        if !crate::replaying() {
+            tokio::time::sleep(std::time::Duration::from_secs(if i < 3 { 2 } else { 5 })).await;
+        }
         let result = match f().await {
             Ok(v) => v,
             Err(_) => continue, // rate-limited or transient error — retry
@@ -2,7 +2,7 @@ use lineark_sdk::generated::types::Team;
 use lineark_sdk::Client;
 
 use crate::guards::TeamGuard;
-use crate::{cleanup_zombies, retry_create, settle, test_token};
+use crate::{cleanup_zombies, retry_create, settle, test_token, unique_id};
 
 /// Result of creating a test team, with all info needed by both SDK and CLI tests.
 pub struct TestTeam {
@@ -16,7 +16,7 @@ pub struct TestTeam {
 pub async fn create_test_team(client: &Client) -> TestTeam {
     use lineark_sdk::generated::inputs::TeamCreateInput;
     cleanup_zombies();
-    let suffix = &uuid::Uuid::new_v4().to_string()[..8];
+    let suffix = &unique_id()[..8];
     let unique = format!("[test] sdk {suffix}");
     let key = format!("T{}", &suffix[..5]).to_uppercase();
     let input = TeamCreateInput {
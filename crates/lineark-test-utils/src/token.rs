@@ -17,6 +17,7 @@
 use std::sync::OnceLock;
 
 const TOKEN_FILE: &str = ".linear_api_token_test";
+const REPLAY_TOKEN: &str = "lin_api_cassette_replay";
 
 fn token_path() -> Option<std::path::PathBuf> {
     home::home_dir().map(|h| h.join(TOKEN_FILE))
@@ -24,7 +25,11 @@ fn token_path() -> Option<std::path::PathBuf> {
 
 /// Returns `Some(reason)` if the test token file is missing, `None` if present.
 /// Used with `test_with::runtime_ignore_if` to skip online tests gracefully.
+/// Never skips when replaying a cassette.
 pub fn no_online_test_token() -> Option<String> {
+    if crate::replaying() {
+        return None;
+    }
     let path = token_path()?;
     if path.exists() {
         None
@@ -36,8 +41,12 @@ pub fn no_online_test_token() -> Option<String> {
 /// Read the test API token from `~/.linear_api_token_test`. When the file
 /// holds multiple `;`-separated tokens, picks one at random per process.
 /// The choice is cached so every test in the same process uses the same
-/// workspace.
+/// workspace. When replaying a cassette, returns a placeholder that is never
+/// sent anywhere.
 pub fn test_token() -> String {
+    if crate::replaying() {
+        return REPLAY_TOKEN.to_string();
+    }
     static CHOSEN: OnceLock<String> = OnceLock::new();
     CHOSEN.get_or_init(load_and_pick).clone()
 }
@@ -1,5 +1,7 @@
 use clap::Args;
-use lineark_sdk::generated::inputs::CycleFilter;
+use lineark_sdk::generated::inputs::{
+    BooleanComparator, CycleFilter, IDComparator, NumberComparator, StringComparator, TeamFilter,
+};
 use lineark_sdk::generated::types::Cycle;
 use lineark_sdk::Client;
 use serde::Serialize;
@@ -86,22 +88,18 @@ pub async fn run(cmd: CyclesCmd, client: &Client, format: Format) -> anyhow::Res
             active,
             around_active,
         } => {
-            let mut filter_map = serde_json::Map::new();
+            let mut filters = Vec::new();
 
             if let Some(ref team_key) = team {
                 let team_id = resolve_team_id(client, team_key).await?;
-                filter_map.insert(
-                    "team".into(),
-                    serde_json::json!({ "id": { "eq": team_id } }),
-                );
+                filters.push(CycleFilter::team(TeamFilter::id(IDComparator::eq(team_id))));
             }
 
             if active {
-                filter_map.insert("isActive".into(), serde_json::json!({ "eq": true }));
+                filters.push(CycleFilter::is_active(BooleanComparator::eq(true)));
             }
 
-            let filter: CycleFilter = serde_json::from_value(serde_json::Value::Object(filter_map))
-                .expect("valid CycleFilter");
+            let filter = CycleFilter::all(filters);
 
             let conn = client
                 .cycles::<Cycle>()
@@ -159,21 +157,14 @@ pub async fn run(cmd: CyclesCmd, client: &Client, format: Format) -> anyhow::Res
 
             let team_id = resolve_team_id(client, team_key).await?;
 
-            let mut filter_map = serde_json::Map::new();
-            filter_map.insert(
-                "team".into(),
-                serde_json::json!({ "id": { "eq": team_id } }),
-            );
+            let team_filter = CycleFilter::team(TeamFilter::id(IDComparator::eq(team_id)));
 
             // Try parsing as a number first.
-            if let Ok(num) = id.parse::<i64>() {
-                filter_map.insert("number".into(), serde_json::json!({ "eq": num }));
+            let filter = if let Ok(num) = id.parse::<i32>() {
+                team_filter.and(CycleFilter::number(NumberComparator::eq(num)))
             } else {
-                filter_map.insert("name".into(), serde_json::json!({ "eq": id }));
-            }
-
-            let filter: CycleFilter = serde_json::from_value(serde_json::Value::Object(filter_map))
-                .expect("valid CycleFilter");
+                team_filter.and(CycleFilter::name(StringComparator::eq(id.clone())))
+            };
 
             let conn = client
                 .cycles::<Cycle>()
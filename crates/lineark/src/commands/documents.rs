@@ -1,5 +1,8 @@
 use clap::Args;
-use lineark_sdk::generated::inputs::{DocumentCreateInput, DocumentFilter, DocumentUpdateInput};
+use lineark_sdk::generated::inputs::{
+    DocumentCreateInput, DocumentFilter, DocumentUpdateInput, IDComparator, IssueFilter,
+    IssueIDComparator, ProjectFilter,
+};
 use lineark_sdk::generated::types::Document;
 use lineark_sdk::{Client, GraphQLFields};
 use serde::{Deserialize, Serialize};
@@ -139,28 +142,23 @@ pub async fn run(cmd: DocumentsCmd, client: &Client, format: Format) -> anyhow::
             // Build filter from --project / --issue flags
             let has_filter = project.is_some() || issue.is_some();
             if has_filter {
-                let mut filter_json = serde_json::Map::new();
+                let mut filters = Vec::new();
 
                 if let Some(ref project_name) = project {
                     let project_id = resolve_project_id(client, project_name).await?;
-                    filter_json.insert(
-                        "project".into(),
-                        serde_json::json!({ "id": { "eq": project_id } }),
-                    );
+                    filters.push(DocumentFilter::project(ProjectFilter::id(
+                        IDComparator::eq(project_id),
+                    )));
                 }
 
                 if let Some(ref issue_ident) = issue {
                     let issue_id = resolve_issue_id(client, issue_ident).await?;
-                    filter_json.insert(
-                        "issue".into(),
-                        serde_json::json!({ "id": { "eq": issue_id } }),
-                    );
+                    filters.push(DocumentFilter::issue(IssueFilter::id(
+                        IssueIDComparator::eq(issue_id),
+                    )));
                 }
 
-                let filter: DocumentFilter =
-                    serde_json::from_value(serde_json::Value::Object(filter_json))
-                        .expect("valid DocumentFilter");
-                query = query.filter(filter);
+                query = query.filter(DocumentFilter::all(filters));
             }
 
             let conn = query.send().await.map_err(|e| anyhow::anyhow!("{}", e))?;
@@ -1,7 +1,12 @@
+use lineark_sdk::generated::inputs::{
+    CycleFilter, IDComparator, IssueLabelFilter, NullableTeamFilter, TeamFilter,
+};
 use lineark_sdk::generated::types::{
-    Cycle, IssueLabel, IssueSearchResult, Project, ProjectLabel, ProjectStatus, Team, User,
+    Cycle, Issue, IssueLabel, Project, ProjectLabel, ProjectStatus, Team, User,
 };
-use lineark_sdk::Client;
+use lineark_sdk::ids::IssueIdentifier;
+use lineark_sdk::{Client, GraphQLFields};
+use serde::Deserialize;
 
 /// Resolve a team key or name (e.g., "ENG" or "Engineering") to a team UUID.
 /// If the input already looks like a UUID, return it as-is.
@@ -9,13 +14,13 @@ pub async fn resolve_team_id(client: &Client, team_key: &str) -> anyhow::Result<
     if uuid::Uuid::parse_str(team_key).is_ok() {
         return Ok(team_key.to_string());
     }
-    let conn = client
+    let nodes = client
         .teams::<Team>()
         .first(250)
-        .send()
+        .all()
         .await
         .map_err(|e| anyhow::anyhow!("{}", e))?;
-    for team in &conn.nodes {
+    for team in &nodes {
         if team
             .key
             .as_deref()
@@ -31,8 +36,7 @@ pub async fn resolve_team_id(client: &Client, team_key: &str) -> anyhow::Result<
             return Ok(team.id.clone().unwrap_or_default());
         }
     }
-    let available: Vec<String> = conn
-        .nodes
+    let available: Vec<String> = nodes
         .iter()
         .map(|t| {
             let key = t.key.as_deref().unwrap_or("?");
@@ -60,10 +64,10 @@ pub async fn resolve_team_ids(
     }
 
     // Fetch teams once.
-    let conn = client
+    let nodes = client
         .teams::<Team>()
         .first(250)
-        .send()
+        .all()
         .await
         .map_err(|e| anyhow::anyhow!("{}", e))?;
 
@@ -73,7 +77,7 @@ pub async fn resolve_team_ids(
             resolved.push(key.clone());
             continue;
         }
-        let found = conn.nodes.iter().find(|t| {
+        let found = nodes.iter().find(|t| {
             t.key
                 .as_deref()
                 .is_some_and(|k| k.eq_ignore_ascii_case(key))
@@ -84,8 +88,7 @@ pub async fn resolve_team_ids(
         match found {
             Some(team) => resolved.push(team.id.clone().unwrap_or_default()),
             None => {
-                let available: Vec<String> = conn
-                    .nodes
+                let available: Vec<String> = nodes
                     .iter()
                     .map(|t| {
                         let k = t.key.as_deref().unwrap_or("?");
@@ -110,23 +113,21 @@ pub async fn resolve_issue_id(client: &Client, identifier: &str) -> anyhow::Resu
     if uuid::Uuid::parse_str(identifier).is_ok() {
         return Ok(identifier.to_string());
     }
-    let conn = client
-        .search_issues::<IssueSearchResult>(identifier)
-        .first(5)
-        .include_archived(true)
-        .send()
+    let not_found = || anyhow::anyhow!("Issue '{}' not found", identifier);
+    let parsed = IssueIdentifier::parse(identifier).ok_or_else(not_found)?;
+    client
+        .issue_by_identifier::<IssueIdOnly>(&parsed)
         .await
-        .map_err(|e| anyhow::anyhow!("{}", e))?;
+        .map_err(|e| anyhow::anyhow!("{}", e))?
+        .and_then(|issue| issue.id)
+        .ok_or_else(not_found)
+}
 
-    conn.nodes
-        .iter()
-        .find(|n| {
-            n.identifier
-                .as_deref()
-                .is_some_and(|id| id.eq_ignore_ascii_case(identifier))
-        })
-        .and_then(|n| n.id.clone())
-        .ok_or_else(|| anyhow::anyhow!("Issue '{}' not found", identifier))
+/// Just an issue's ID, for [`resolve_issue_id`].
+#[derive(Deserialize, GraphQLFields)]
+#[graphql(full_type = Issue)]
+struct IssueIdOnly {
+    id: Option<String>,
 }
 
 /// Resolve a user name, display name, UUID, or the special alias `me` to a user UUID.
@@ -248,38 +249,34 @@ pub async fn resolve_label_ids(
 
     if let Some(tid) = team_id {
         // Team-scoped labels first.
-        let filter: lineark_sdk::generated::inputs::IssueLabelFilter =
-            serde_json::from_value(serde_json::json!({ "team": { "id": { "eq": tid } } }))
-                .expect("valid IssueLabelFilter");
-        let conn = client
+        let filter = IssueLabelFilter::team(NullableTeamFilter::id(IDComparator::eq(tid)));
+        let nodes = client
             .issue_labels::<IssueLabel>()
             .first(250)
             .filter(filter)
-            .send()
+            .all()
             .await
             .map_err(|e| anyhow::anyhow!("{}", e))?;
-        all_labels.extend(conn.nodes);
+        all_labels.extend(nodes);
 
         // Workspace-wide labels (no team).
-        let ws_filter: lineark_sdk::generated::inputs::IssueLabelFilter =
-            serde_json::from_value(serde_json::json!({ "team": { "null": true } }))
-                .expect("valid IssueLabelFilter");
-        let ws_conn = client
+        let ws_filter = IssueLabelFilter::team(NullableTeamFilter::null(true));
+        let ws_nodes = client
             .issue_labels::<IssueLabel>()
             .first(250)
             .filter(ws_filter)
-            .send()
+            .all()
             .await
             .map_err(|e| anyhow::anyhow!("{}", e))?;
-        all_labels.extend(ws_conn.nodes);
+        all_labels.extend(ws_nodes);
     } else {
-        let conn = client
+        let nodes = client
             .issue_labels::<IssueLabel>()
             .first(250)
-            .send()
+            .all()
             .await
             .map_err(|e| anyhow::anyhow!("{}", e))?;
-        all_labels = conn.nodes;
+        all_labels = nodes;
     }
 
     let mut resolved = Vec::with_capacity(names_or_ids.len());
@@ -315,15 +312,14 @@ pub async fn resolve_project_id(client: &Client, name_or_id: &str) -> anyhow::Re
     if uuid::Uuid::parse_str(name_or_id).is_ok() {
         return Ok(name_or_id.to_string());
     }
-    let conn = client
+    let nodes = client
         .projects::<Project>()
         .first(250)
-        .send()
+        .all()
         .await
         .map_err(|e| anyhow::anyhow!("{}", e))?;
 
-    let matches: Vec<&Project> = conn
-        .nodes
+    let matches: Vec<&Project> = nodes
         .iter()
         .filter(|p| {
             p.name
@@ -334,7 +330,7 @@ pub async fn resolve_project_id(client: &Client, name_or_id: &str) -> anyhow::Re
 
     match matches.len() {
         0 => {
-            let available: Vec<String> = conn.nodes.iter().filter_map(|p| p.name.clone()).collect();
+            let available: Vec<String> = nodes.iter().filter_map(|p| p.name.clone()).collect();
             Err(anyhow::anyhow!(
                 "Project '{}' not found. Available: {}",
                 name_or_id,
@@ -365,27 +361,25 @@ pub async fn resolve_cycle_id(
         return Ok(name_or_id.to_string());
     }
 
-    let filter: lineark_sdk::generated::inputs::CycleFilter =
-        serde_json::from_value(serde_json::json!({ "team": { "id": { "eq": team_id } } }))
-            .expect("valid CycleFilter");
+    let filter = CycleFilter::team(TeamFilter::id(IDComparator::eq(team_id)));
 
-    let conn = client
+    let nodes = client
         .cycles::<Cycle>()
         .filter(filter)
         .first(250)
-        .send()
+        .all()
         .await
         .map_err(|e| anyhow::anyhow!("{}", e))?;
 
     // Try matching by number first (e.g. "3" → cycle number 3).
     if let Ok(num) = name_or_id.parse::<f64>() {
-        if let Some(cycle) = conn.nodes.iter().find(|c| c.number == Some(num)) {
+        if let Some(cycle) = nodes.iter().find(|c| c.number == Some(num)) {
             return Ok(cycle.id.clone().unwrap_or_default());
         }
     }
 
     // Try matching by name (case-insensitive).
-    if let Some(cycle) = conn.nodes.iter().find(|c| {
+    if let Some(cycle) = nodes.iter().find(|c| {
         c.name
             .as_deref()
             .is_some_and(|n| n.eq_ignore_ascii_case(name_or_id))
@@ -393,8 +387,7 @@ pub async fn resolve_cycle_id(
         return Ok(cycle.id.clone().unwrap_or_default());
     }
 
-    let available: Vec<String> = conn
-        .nodes
+    let available: Vec<String> = nodes
         .iter()
         .map(|c| {
             let num = c
@@ -422,15 +415,14 @@ pub async fn resolve_project_status_id(
     if uuid::Uuid::parse_str(name_or_id).is_ok() {
         return Ok(name_or_id.to_string());
     }
-    let conn = client
+    let nodes = client
         .project_statuses::<ProjectStatus>()
         .first(250)
-        .send()
+        .all()
         .await
         .map_err(|e| anyhow::anyhow!("{}", e))?;
 
-    let matches: Vec<&ProjectStatus> = conn
-        .nodes
+    let matches: Vec<&ProjectStatus> = nodes
         .iter()
         .filter(|s| {
             s.name
@@ -441,7 +433,7 @@ pub async fn resolve_project_status_id(
 
     match matches.len() {
         0 => {
-            let available: Vec<String> = conn.nodes.iter().filter_map(|s| s.name.clone()).collect();
+            let available: Vec<String> = nodes.iter().filter_map(|s| s.name.clone()).collect();
             Err(anyhow::anyhow!(
                 "Project status '{}' not found. Available: {}",
                 name_or_id,
@@ -473,10 +465,10 @@ pub async fn resolve_project_label_ids(
         return Ok(names_or_ids.to_vec());
     }
 
-    let conn = client
+    let nodes = client
         .project_labels::<ProjectLabel>()
         .first(250)
-        .send()
+        .all()
         .await
         .map_err(|e| anyhow::anyhow!("{}", e))?;
 
@@ -485,7 +477,7 @@ pub async fn resolve_project_label_ids(
         if uuid::Uuid::parse_str(item).is_ok() {
             resolved.push(item.clone());
         } else {
-            let found = conn.nodes.iter().find(|l| {
+            let found = nodes.iter().find(|l| {
                 l.name
                     .as_deref()
                     .is_some_and(|n| n.eq_ignore_ascii_case(item))
@@ -494,7 +486,7 @@ pub async fn resolve_project_label_ids(
                 Some(label) => resolved.push(label.id.clone().unwrap_or_default()),
                 None => {
                     let available: Vec<String> =
-                        conn.nodes.iter().filter_map(|l| l.name.clone()).collect();
+                        nodes.iter().filter_map(|l| l.name.clone()).collect();
                     return Err(anyhow::anyhow!(
                         "Project label '{}' not found. Available: {}",
                         item,
@@ -543,3 +535,10 @@ pub fn parse_priority(s: &str) -> Result<i64, String> {
         )),
     }
 }
+
+/// Parse a UUID argument, normalized to lowercase hyphenated form.
+pub fn parse_uuid(s: &str) -> Result<String, String> {
+    uuid::Uuid::parse_str(s.trim())
+        .map(|id| id.hyphenated().to_string())
+        .map_err(|_| format!("invalid id '{s}': expected a UUID"))
+}
@@ -1,18 +1,20 @@
 use clap::Args;
 use lineark_sdk::generated::inputs::{
-    IssueCreateInput, IssueFilter, IssueUpdateInput, WorkflowStateFilter,
+    IDComparator, IssueCreateInput, IssueFilter, IssueUpdateInput, NullableProjectFilter,
+    NullableUserFilter, StringComparator, TeamFilter, WorkflowStateFilter,
 };
 use lineark_sdk::generated::types::{
     Comment, CommentConnection, Issue, IssueConnection, IssueLabel, IssueLabelConnection,
     IssueRelation, IssueRelationConnection, IssueSearchResult, User, WorkflowState,
 };
-use lineark_sdk::{Client, GraphQLFields, MaybeUndefined};
+use lineark_sdk::ids::IssueIdentifier;
+use lineark_sdk::{Client, GraphQLFields, MaybeUndefined, RetryPolicy};
 use serde::{Deserialize, Serialize};
 use tabled::Tabled;
 
 use super::helpers::{
-    parse_priority, resolve_cycle_id, resolve_issue_id, resolve_label_ids, resolve_project_id,
-    resolve_team_id, resolve_user_id_or_me,
+    parse_priority, parse_uuid, resolve_cycle_id, resolve_issue_id, resolve_label_ids,
+    resolve_project_id, resolve_team_id, resolve_user_id_or_me,
 };
 use crate::output::{self, Format};
 
@@ -75,6 +77,7 @@ pub enum IssuesAction {
     ///   lineark issues create "Add feature" --team ENG --priority high --description "Details here"
     ///   lineark issues create "Urgent fix" --team ENG -p urgent --labels Bug,Frontend
     ///   lineark issues create "My task" --team ENG --assignee me
+    ///   lineark issues create "Nightly report" --team ENG --id 0b0e4c1e-6f0a-4d8a-9a51-3f1d2c3b4a5e
     Create {
         /// Issue title.
         title: String,
@@ -108,6 +111,10 @@ pub enum IssuesAction {
         /// Cycle name, number, or UUID (resolved within the team).
         #[arg(long)]
         cycle: Option<String>,
+        /// UUID for the new issue, used as an idempotency key: if an issue
+        /// with this ID already exists, it is returned instead.
+        #[arg(long, visible_alias = "idempotency-key", value_parser = parse_uuid)]
+        id: Option<String>,
     },
     /// Archive an issue.
     ///
@@ -566,26 +573,21 @@ pub async fn run(cmd: IssuesCmd, client: &Client, format: Format) -> anyhow::Res
             mine,
             show_done,
         } => {
-            let mut filter_map = serde_json::Map::new();
+            let mut filters = Vec::new();
             if !show_done {
-                filter_map.insert(
-                    "state".into(),
-                    serde_json::json!({ "type": { "nin": ["completed", "canceled"] } }),
-                );
+                filters.push(IssueFilter::state(WorkflowStateFilter::type_(
+                    StringComparator::nin(["completed", "canceled"]),
+                )));
             }
             if let Some(ref team_key) = team {
                 let team_id = resolve_team_id(client, team_key).await?;
-                filter_map.insert(
-                    "team".into(),
-                    serde_json::json!({ "id": { "eq": team_id } }),
-                );
+                filters.push(IssueFilter::team(TeamFilter::id(IDComparator::eq(team_id))));
             }
             if let Some(ref project_val) = project {
                 let project_id = resolve_project_id(client, project_val).await?;
-                filter_map.insert(
-                    "project".into(),
-                    serde_json::json!({ "id": { "eq": project_id } }),
-                );
+                filters.push(IssueFilter::project(NullableProjectFilter::id(
+                    IDComparator::eq(project_id),
+                )));
             }
             if mine {
                 let viewer = client
@@ -595,14 +597,12 @@ pub async fn run(cmd: IssuesCmd, client: &Client, format: Format) -> anyhow::Res
                 let viewer_id = viewer
                     .id
                     .ok_or_else(|| anyhow::anyhow!("Could not determine authenticated user ID"))?;
-                filter_map.insert(
-                    "assignee".into(),
-                    serde_json::json!({ "id": { "eq": viewer_id } }),
-                );
+                filters.push(IssueFilter::assignee(NullableUserFilter::id(
+                    IDComparator::eq(viewer_id),
+                )));
             }
 
-            let filter: IssueFilter = serde_json::from_value(serde_json::Value::Object(filter_map))
-                .expect("valid IssueFilter");
+            let filter = IssueFilter::all(filters);
 
             let conn = client
                 .issues::<IssueSummary>()
@@ -635,25 +635,20 @@ pub async fn run(cmd: IssuesCmd, client: &Client, format: Format) -> anyhow::Res
             }
 
             // Build IssueFilter for assignee and/or status.
-            let mut filter_map = serde_json::Map::new();
+            let mut filters = Vec::new();
             if let Some(ref assignee_val) = assignee {
                 let user_id = resolve_user_id_or_me(client, assignee_val).await?;
-                filter_map.insert(
-                    "assignee".into(),
-                    serde_json::json!({ "id": { "eq": user_id } }),
-                );
+                filters.push(IssueFilter::assignee(NullableUserFilter::id(
+                    IDComparator::eq(user_id),
+                )));
             }
             if let Some(ref status_names) = status {
-                filter_map.insert(
-                    "state".into(),
-                    serde_json::json!({ "name": { "in": status_names } }),
-                );
+                filters.push(IssueFilter::state(WorkflowStateFilter::name(
+                    StringComparator::in_(status_names.iter().cloned()),
+                )));
             }
-            if !filter_map.is_empty() {
-                let filter: IssueFilter =
-                    serde_json::from_value(serde_json::Value::Object(filter_map))
-                        .expect("valid IssueFilter");
-                builder = builder.filter(filter);
+            if !filters.is_empty() {
+                builder = builder.filter(IssueFilter::all(filters));
             }
 
             let conn = builder.send().await.map_err(|e| anyhow::anyhow!("{}", e))?;
@@ -688,6 +683,7 @@ pub async fn run(cmd: IssuesCmd, client: &Client, format: Format) -> anyhow::Res
             status,
             project,
             cycle,
+            id,
         } => {
             let team_id = resolve_team_id(client, &team).await?;
 
@@ -733,10 +729,17 @@ pub async fn run(cmd: IssuesCmd, client: &Client, format: Format) -> anyhow::Res
                 state_id: state_id.into(),
                 project_id: project_id.into(),
                 cycle_id: cycle_id.into(),
+                id: id.into(),
                 ..Default::default()
             };
 
+            // A client-side ID makes the create safe to retry or re-run, so
+            // transient failures and Linear's spurious insert conflicts are
+            // retried with the same ID.
             let issue = client
+                .clone()
+                .with_idempotent_creates(true)
+                .with_retry_policy(RetryPolicy::default().max_attempts(5))
                 .issue_create::<IssueRef>(input)
                 .await
                 .map_err(|e| anyhow::anyhow!("{}", e))?;
@@ -1053,40 +1056,16 @@ fn print_search_list(items: &[&SearchSummary], format: Format) {
 /// Read a single issue by identifier (e.g. E-929) or UUID, with full nested details.
 /// Uses [`IssueDetail`] — a custom type with exactly the nested fields we display.
 async fn read_issue(client: &Client, identifier: &str) -> anyhow::Result<IssueDetail> {
-    if uuid::Uuid::parse_str(identifier).is_ok() {
-        return client
-            .issue::<IssueDetail>(identifier.to_string())
+    match IssueIdentifier::parse(identifier) {
+        Some(parsed) => client
+            .issue_by_identifier::<IssueDetail>(&parsed)
             .await
-            .map_err(|e| anyhow::anyhow!("{}", e));
-    }
-    if identifier.contains('-') {
-        // searchIssues is fuzzy — search with full type, find the UUID, then fetch details.
-        let conn = client
-            .search_issues::<IssueSearchResult>(identifier)
-            .first(5)
-            .send()
-            .await
-            .map_err(|e| anyhow::anyhow!("{}", e))?;
-        let id = conn
-            .nodes
-            .iter()
-            .find(|issue| {
-                issue
-                    .identifier
-                    .as_deref()
-                    .is_some_and(|id| id.eq_ignore_ascii_case(identifier))
-            })
-            .and_then(|n| n.id.clone())
-            .ok_or_else(|| anyhow::anyhow!("Issue '{}' not found", identifier))?;
-        client
-            .issue::<IssueDetail>(id)
+            .map_err(|e| anyhow::anyhow!("{}", e))?
+            .ok_or_else(|| anyhow::anyhow!("Issue '{}' not found", identifier)),
+        None => client
+            .issue::<IssueDetail>(identifier)
             .await
-            .map_err(|e| anyhow::anyhow!("{}", e))
-    } else {
-        client
-            .issue::<IssueDetail>(identifier.to_string())
-            .await
-            .map_err(|e| anyhow::anyhow!("{}", e))
+            .map_err(|e| anyhow::anyhow!("{}", e)),
     }
 }
 
@@ -1096,9 +1075,7 @@ async fn resolve_state_id(
     team_id: &str,
     state_name: &str,
 ) -> anyhow::Result<String> {
-    let filter: WorkflowStateFilter =
-        serde_json::from_value(serde_json::json!({ "team": { "id": { "eq": team_id } } }))
-            .expect("valid WorkflowStateFilter");
+    let filter = WorkflowStateFilter::team(TeamFilter::id(IDComparator::eq(team_id)));
 
     let conn = client
         .workflow_states::<WorkflowState>()
@@ -1157,6 +1134,15 @@ mod tests {
         assert!(parse_priority("  ").is_err());
     }
 
+    #[test]
+    fn parse_uuid_normalizes_case() {
+        assert_eq!(
+            parse_uuid(" 0B0E4C1E-6F0A-4D8A-9A51-3F1D2C3B4A5E ").unwrap(),
+            "0b0e4c1e-6f0a-4d8a-9a51-3f1d2c3b4a5e"
+        );
+        assert!(parse_uuid("ENG-123").is_err());
+    }
+
     #[test]
     fn parse_priority_rejects_invalid() {
         assert!(parse_priority("bogus").is_err());
@@ -1,6 +1,7 @@
 use clap::Args;
 use lineark_sdk::generated::inputs::{
-    IssueLabelCreateInput, IssueLabelFilter, IssueLabelUpdateInput,
+    IDComparator, IssueLabelCreateInput, IssueLabelFilter, IssueLabelUpdateInput,
+    NullableTeamFilter,
 };
 use lineark_sdk::generated::types::IssueLabel;
 use lineark_sdk::{Client, GraphQLFields, MaybeUndefined};
@@ -173,10 +174,8 @@ pub async fn run(cmd: LabelsCmd, client: &Client, format: Format) -> anyhow::Res
 
             if let Some(ref team_key) = team {
                 let team_id = resolve_team_id(client, team_key).await?;
-                let filter: IssueLabelFilter = serde_json::from_value(
-                    serde_json::json!({ "team": { "id": { "eq": team_id } } }),
-                )
-                .expect("valid IssueLabelFilter");
+                let filter =
+                    IssueLabelFilter::team(NullableTeamFilter::id(IDComparator::eq(team_id)));
                 query = query.filter(filter);
             }
 
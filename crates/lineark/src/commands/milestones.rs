@@ -1,7 +1,8 @@
 use clap::Args;
 use lineark_sdk::generated::enums::ProjectMilestoneStatus;
 use lineark_sdk::generated::inputs::{
-    ProjectMilestoneCreateInput, ProjectMilestoneFilter, ProjectMilestoneUpdateInput,
+    IDComparator, NullableProjectFilter, ProjectMilestoneCreateInput, ProjectMilestoneFilter,
+    ProjectMilestoneUpdateInput,
 };
 use lineark_sdk::generated::types::ProjectMilestone;
 use lineark_sdk::{Client, GraphQLFields};
@@ -156,10 +157,9 @@ pub async fn run(cmd: MilestonesCmd, client: &Client, format: Format) -> anyhow:
         MilestonesAction::List { project, limit } => {
             let project_id = resolve_project_id(client, &project).await?;
 
-            let filter: ProjectMilestoneFilter = serde_json::from_value(
-                serde_json::json!({ "project": { "id": { "eq": project_id } } }),
-            )
-            .expect("valid ProjectMilestoneFilter");
+            let filter = ProjectMilestoneFilter::project(NullableProjectFilter::id(
+                IDComparator::eq(project_id),
+            ));
 
             let conn = client
                 .project_milestones::<MilestoneSummary>()
@@ -288,9 +288,8 @@ async fn resolve_milestone_id(
 
     let project_id = resolve_project_id(client, project_name).await?;
 
-    let filter: ProjectMilestoneFilter =
-        serde_json::from_value(serde_json::json!({ "project": { "id": { "eq": project_id } } }))
-            .expect("valid ProjectMilestoneFilter");
+    let filter =
+        ProjectMilestoneFilter::project(NullableProjectFilter::id(IDComparator::eq(project_id)));
 
     let conn = client
         .project_milestones::<MilestoneSummary>()
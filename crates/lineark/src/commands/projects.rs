@@ -1,5 +1,7 @@
 use clap::Args;
-use lineark_sdk::generated::inputs::{ProjectCreateInput, ProjectFilter, ProjectUpdateInput};
+use lineark_sdk::generated::inputs::{
+    IDComparator, NullableUserFilter, ProjectCreateInput, ProjectFilter, ProjectUpdateInput,
+};
 use lineark_sdk::generated::types::{
     Project, ProjectStatus, Team, TeamConnection, User, UserConnection,
 };
@@ -266,10 +268,8 @@ pub async fn run(cmd: ProjectsCmd, client: &Client, format: Format) -> anyhow::R
                 let viewer_id = viewer
                     .id
                     .ok_or_else(|| anyhow::anyhow!("Could not determine authenticated user ID"))?;
-                let filter: ProjectFilter = serde_json::from_value(
-                    serde_json::json!({ "lead": { "id": { "eq": viewer_id } } }),
-                )
-                .expect("valid ProjectFilter");
+                let filter =
+                    ProjectFilter::lead(NullableUserFilter::id(IDComparator::eq(viewer_id)));
                 builder = builder.filter(filter);
             }
 
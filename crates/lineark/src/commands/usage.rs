@@ -91,7 +91,7 @@ COMMANDS:
     [-p PRIORITY] [-e N] [--assignee NAME-OR-ID|me] 0-4 or none/urgent/high/medium/low
     [--labels NAME,...] [-d TEXT] [-s NAME]        Label names, status name
     [--parent ID] [--project NAME-OR-ID]           Parent issue, project, cycle
-    [--cycle NAME-OR-ID]
+    [--cycle NAME-OR-ID] [--id UUID]               Idempotency key: reuse to retry safely
   lineark issues update <IDENTIFIER>               Update an issue
     [-s NAME] [-p PRIORITY] [-e N]                 Status, priority, estimate
     [--assignee NAME-OR-ID|me]                     Assignee
@@ -4,6 +4,8 @@ pub mod profile;
 mod version_check;
 
 use clap::{Parser, Subcommand};
+#[cfg(feature = "cassette")]
+use lineark_sdk::cassette::Cassette;
 use lineark_sdk::Client;
 use std::path::PathBuf;
 
@@ -125,6 +127,12 @@ async fn main() {
         (None, None) => Client::from_env()
             .or_else(|_| Client::from_token_file(&profile::token_path(&home, "default"))),
     };
+    // `LINEARK_CASSETTE` records or replays API traffic, for deterministic tests.
+    #[cfg(feature = "cassette")]
+    let client = client.and_then(|client| match Cassette::from_env()? {
+        Some(cassette) => Ok(client.with_middleware(cassette)),
+        None => Ok(client),
+    });
     let client = match client {
         Ok(c) => c,
         Err(e) => {
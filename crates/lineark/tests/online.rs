@@ -2,6 +2,9 @@
 //!
 //! Requires a valid Linear API token at `~/.linear_api_token_test`.
 //! When the token file is missing, tests are automatically skipped with a message.
+//! Built with `--features cassette`, the spawned binaries honour
+//! `LINEARK_CASSETTE`, so the suite can be recorded and replayed like the SDK
+//! one (see `lineark_test_utils::cassette`).
 
 use assert_cmd::Command;
 use lineark_sdk::generated::inputs::ProjectCreateInput;
@@ -86,10 +89,7 @@ fn run_lineark_with_retry(args: &[&str]) -> (std::process::Output, String) {
         // body content. Only safe for text names we own (`[test] ...`).
         if mutable_name {
             if let Some(idx) = name_idx {
-                current_name = format!(
-                    "{original_positional} retry-{}",
-                    &uuid::Uuid::new_v4().to_string()[..6]
-                );
+                current_name = format!("{original_positional} retry-{}", &unique_id()[..6]);
                 owned[idx] = current_name.clone();
             }
         }
@@ -106,7 +106,7 @@ fn run_lineark_with_retry(args: &[&str]) -> (std::process::Output, String) {
 
 /// Helper: create a fresh test team via the SDK.
 fn create_test_team() -> TestTeam {
-    let client = Client::from_token(test_token()).unwrap();
+    let client = with_cassette(Client::from_token(test_token()).unwrap());
     let rt = tokio::runtime::Runtime::new().unwrap();
     rt.block_on(lineark_test_utils::create_test_team(&client))
 }
@@ -229,7 +229,7 @@ mod online {
         let token = test_token();
 
         // Create a workspace-level label.
-        let unique_name = format!("[test] lbl-crud {}", &uuid::Uuid::new_v4().to_string()[..8]);
+        let unique_name = format!("[test] lbl-crud {}", &unique_id()[..8]);
         let (output, unique_name) = run_lineark_with_retry(&[
             "--api-token",
             &token,
@@ -307,7 +307,7 @@ mod online {
     #[test_with::runtime_ignore_if(no_online_test_token)]
     fn labels_group_lifecycle() {
         let token = test_token();
-        let uid = &uuid::Uuid::new_v4().to_string()[..8];
+        let uid = &unique_id()[..8];
 
         // 1. Create a group label with --group.
         let group_name = format!("[test] Group {uid}");
@@ -480,7 +480,7 @@ mod online {
         let team_id = team.id.clone();
 
         // Create a label with a space in the name.
-        let uid = &uuid::Uuid::new_v4().to_string()[..8];
+        let uid = &unique_id()[..8];
         let label_name = format!("[test] Tech Debt {uid}");
         let (output, label_name) = run_lineark_with_retry(&[
             "--api-token",
@@ -748,10 +748,7 @@ mod online {
     #[test_with::runtime_ignore_if(no_online_test_token)]
     fn issues_create_update_and_archive() {
         let token = test_token();
-        let unique_name = format!(
-            "[test] CLI create+update {}",
-            &uuid::Uuid::new_v4().to_string()[..8]
-        );
+        let unique_name = format!("[test] CLI create+update {}", &unique_id()[..8]);
 
         let team = create_test_team();
         let team_key = team.key.clone();
@@ -828,10 +825,7 @@ mod online {
     #[test_with::runtime_ignore_if(no_online_test_token)]
     fn issues_create_with_textual_priority_and_update() {
         let token = test_token();
-        let unique_name = format!(
-            "[test] CLI textual priority {}",
-            &uuid::Uuid::new_v4().to_string()[..8]
-        );
+        let unique_name = format!("[test] CLI textual priority {}", &unique_id()[..8]);
 
         let team = create_test_team();
         let team_key = team.key.clone();
@@ -975,10 +969,7 @@ mod online {
     #[test_with::runtime_ignore_if(no_online_test_token)]
     fn issues_archive_and_unarchive_cycle() {
         let token = test_token();
-        let unique_name = format!(
-            "[test] CLI archive/unarchive {}",
-            &uuid::Uuid::new_v4().to_string()[..8]
-        );
+        let unique_name = format!("[test] CLI archive/unarchive {}", &unique_id()[..8]);
 
         let team = create_test_team();
         let team_key = team.key.clone();
@@ -1128,10 +1119,7 @@ mod online {
     #[test_with::runtime_ignore_if(no_online_test_token)]
     fn issues_unarchive_by_human_identifier() {
         let token = test_token();
-        let unique_name = format!(
-            "[test] unarchive by identifier {}",
-            &uuid::Uuid::new_v4().to_string()[..8]
-        );
+        let unique_name = format!("[test] unarchive by identifier {}", &unique_id()[..8]);
 
         let team = create_test_team();
         let team_key = team.key.clone();
@@ -1228,10 +1216,7 @@ mod online {
     #[test_with::runtime_ignore_if(no_online_test_token)]
     fn issues_delete_permanently() {
         let token = test_token();
-        let unique_name = format!(
-            "[test] CLI issues delete {}",
-            &uuid::Uuid::new_v4().to_string()[..8]
-        );
+        let unique_name = format!("[test] CLI issues delete {}", &unique_id()[..8]);
 
         let team = create_test_team();
         let team_key = team.key.clone();
@@ -1297,10 +1282,7 @@ mod online {
     #[test_with::runtime_ignore_if(no_online_test_token)]
     fn issues_delete_trash_and_verify() {
         let token = test_token();
-        let unique_name = format!(
-            "[test] CLI issues trash {}",
-            &uuid::Uuid::new_v4().to_string()[..8]
-        );
+        let unique_name = format!("[test] CLI issues trash {}", &unique_id()[..8]);
 
         let team = create_test_team();
         let team_key = team.key.clone();
@@ -1389,7 +1371,7 @@ mod online {
     #[test_with::runtime_ignore_if(no_online_test_token)]
     fn documents_create_read_update_and_delete() {
         let token = test_token();
-        let suffix = &uuid::Uuid::new_v4().to_string()[..8];
+        let suffix = &unique_id()[..8];
         let issue_name = format!("[test] doc parent issue {suffix}");
         let doc_name = format!("[test] CLI documents CRUD {suffix}");
 
@@ -1844,7 +1826,7 @@ mod online {
     #[test_with::runtime_ignore_if(no_online_test_token)]
     fn issues_read_shows_relations() {
         let token = test_token();
-        let suffix = &uuid::Uuid::new_v4().to_string()[..8];
+        let suffix = &unique_id()[..8];
 
         let team = create_test_team();
         let team_key = team.key.clone();
@@ -1906,7 +1888,7 @@ mod online {
         {
             use lineark_sdk::generated::enums::IssueRelationType;
             use lineark_sdk::generated::inputs::IssueRelationCreateInput;
-            let client = Client::from_token(test_token()).unwrap();
+            let client = with_cassette(Client::from_token(test_token()).unwrap());
             let input = IssueRelationCreateInput {
                 id: lineark_sdk::MaybeUndefined::Undefined,
                 issue_id: issue_a_id.clone(),
@@ -1975,7 +1957,7 @@ mod online {
     #[test_with::runtime_ignore_if(no_online_test_token)]
     fn issues_read_shows_children_and_comments() {
         let token = test_token();
-        let suffix = &uuid::Uuid::new_v4().to_string()[..8];
+        let suffix = &unique_id()[..8];
 
         let team = create_test_team();
         let team_key = team.key.clone();
@@ -2186,7 +2168,7 @@ mod online {
     #[test_with::runtime_ignore_if(no_online_test_token)]
     fn issues_create_with_parent_and_clear_parent() {
         let token = test_token();
-        let suffix = &uuid::Uuid::new_v4().to_string()[..8];
+        let suffix = &unique_id()[..8];
 
         let team = create_test_team();
         let team_key = team.key.clone();
@@ -2299,7 +2281,7 @@ mod online {
     #[test_with::runtime_ignore_if(no_online_test_token)]
     fn project_milestones_full_crud() {
         let token = test_token();
-        let suffix = &uuid::Uuid::new_v4().to_string()[..8];
+        let suffix = &unique_id()[..8];
         let project_label = format!("[test] milestones CRUD project {suffix}");
         let milestone_name = format!("[test] Beta Release {suffix}");
         let milestone_updated = format!("[test] GA Release {suffix}");
@@ -2309,7 +2291,7 @@ mod online {
         let team_id = team.id.clone();
 
         // Create a test project via the SDK.
-        let client = Client::from_token(test_token()).unwrap();
+        let client = with_cassette(Client::from_token(test_token()).unwrap());
         let rt = tokio::runtime::Runtime::new().unwrap();
         let project: Project = rt.block_on(async {
             let input = ProjectCreateInput {
@@ -2482,10 +2464,7 @@ mod online {
     #[test_with::runtime_ignore_if(no_online_test_token)]
     fn projects_create_and_delete() {
         let token = test_token();
-        let unique_name = format!(
-            "[test] CLI projects create {}",
-            &uuid::Uuid::new_v4().to_string()[..8]
-        );
+        let unique_name = format!("[test] CLI projects create {}", &unique_id()[..8]);
 
         let team = create_test_team();
         let team_key = team.key.clone();
@@ -2558,7 +2537,7 @@ mod online {
         .expect("projects list should include the created project (after retries)");
 
         // Clean up: delete the test project via SDK.
-        let client = Client::from_token(test_token()).unwrap();
+        let client = with_cassette(Client::from_token(test_token()).unwrap());
         tokio::runtime::Runtime::new().unwrap().block_on(async {
             client.project_delete::<Project>(project_id).await.unwrap();
         });
@@ -2633,10 +2612,7 @@ mod online {
     #[test_with::runtime_ignore_if(no_online_test_token)]
     fn projects_read_by_id_and_name() {
         let token = test_token();
-        let unique_name = format!(
-            "[test] CLI projects read {}",
-            &uuid::Uuid::new_v4().to_string()[..8]
-        );
+        let unique_name = format!("[test] CLI projects read {}", &unique_id()[..8]);
 
         let team = create_test_team();
         let team_key = team.key.clone();
@@ -2742,7 +2718,7 @@ mod online {
         .expect("projects read by name should resolve correctly (after retries)");
 
         // Clean up.
-        let client = Client::from_token(test_token()).unwrap();
+        let client = with_cassette(Client::from_token(test_token()).unwrap());
         tokio::runtime::Runtime::new().unwrap().block_on(async {
             client.project_delete::<Project>(project_id).await.unwrap();
         });
@@ -2753,10 +2729,7 @@ mod online {
     #[test_with::runtime_ignore_if(no_online_test_token)]
     fn projects_create_with_members_and_read_back() {
         let token = test_token();
-        let unique_name = format!(
-            "[test] CLI members test {}",
-            &uuid::Uuid::new_v4().to_string()[..8]
-        );
+        let unique_name = format!("[test] CLI members test {}", &unique_id()[..8]);
 
         let team = create_test_team();
         let team_key = team.key.clone();
@@ -2838,7 +2811,7 @@ mod online {
         );
 
         // Clean up.
-        let client = Client::from_token(test_token()).unwrap();
+        let client = with_cassette(Client::from_token(test_token()).unwrap());
         tokio::runtime::Runtime::new().unwrap().block_on(async {
             client.project_delete::<Project>(project_id).await.unwrap();
         });
@@ -2849,10 +2822,7 @@ mod online {
     #[test_with::runtime_ignore_if(no_online_test_token)]
     fn projects_update_multiple_fields_and_clear_lead() {
         let token = test_token();
-        let unique_name = format!(
-            "[test] CLI projects update {}",
-            &uuid::Uuid::new_v4().to_string()[..8]
-        );
+        let unique_name = format!("[test] CLI projects update {}", &unique_id()[..8]);
         let updated_name = format!("{unique_name} (renamed)");
 
         let team = create_test_team();
@@ -3030,10 +3000,7 @@ mod online {
     #[test_with::runtime_ignore_if(no_online_test_token)]
     fn issues_create_with_assignee_me() {
         let token = test_token();
-        let unique_name = format!(
-            "[test] CLI assignee me {}",
-            &uuid::Uuid::new_v4().to_string()[..8]
-        );
+        let unique_name = format!("[test] CLI assignee me {}", &unique_id()[..8]);
 
         let team = create_test_team();
         let team_key = team.key.clone();
@@ -3104,10 +3071,7 @@ mod online {
     #[test_with::runtime_ignore_if(no_online_test_token)]
     fn issues_update_with_assignee_me() {
         let token = test_token();
-        let unique_name = format!(
-            "[test] CLI update assignee me {}",
-            &uuid::Uuid::new_v4().to_string()[..8]
-        );
+        let unique_name = format!("[test] CLI update assignee me {}", &unique_id()[..8]);
 
         let team = create_test_team();
         let team_key = team.key.clone();
@@ -3208,10 +3172,7 @@ mod online {
     #[test_with::runtime_ignore_if(no_online_test_token)]
     fn comments_create_on_issue() {
         let token = test_token();
-        let unique_name = format!(
-            "[test] CLI comments_create {}",
-            &uuid::Uuid::new_v4().to_string()[..8]
-        );
+        let unique_name = format!("[test] CLI comments_create {}", &unique_id()[..8]);
 
         let team = create_test_team();
         let team_key = team.key.clone();
@@ -3271,10 +3232,7 @@ mod online {
     #[test_with::runtime_ignore_if(no_online_test_token)]
     fn comments_create_and_delete() {
         let token = test_token();
-        let unique_name = format!(
-            "[test] CLI comments_delete {}",
-            &uuid::Uuid::new_v4().to_string()[..8]
-        );
+        let unique_name = format!("[test] CLI comments_delete {}", &unique_id()[..8]);
 
         let team = create_test_team();
         let team_key = team.key.clone();
@@ -3403,10 +3361,7 @@ mod online {
         let token = test_token();
 
         // Create a team via CLI.
-        let unique_name = format!(
-            "[test] tm-create {}",
-            &uuid::Uuid::new_v4().to_string()[..8]
-        );
+        let unique_name = format!("[test] tm-create {}", &unique_id()[..8]);
         let (output, _) = run_lineark_with_retry(&[
             "--api-token",
             &token,
@@ -3466,7 +3421,7 @@ mod online {
         let token = test_token();
 
         // Create a team.
-        let unique_name = format!("[test] tm-crud {}", &uuid::Uuid::new_v4().to_string()[..8]);
+        let unique_name = format!("[test] tm-crud {}", &unique_id()[..8]);
         let (output, _) = run_lineark_with_retry(&[
             "--api-token",
             &token,
@@ -3566,10 +3521,7 @@ mod online {
         let token = test_token();
 
         // Create a team (the authenticated user becomes creator + auto-member).
-        let unique_name = format!(
-            "[test] tm-members {}",
-            &uuid::Uuid::new_v4().to_string()[..8]
-        );
+        let unique_name = format!("[test] tm-members {}", &unique_id()[..8]);
         let (output, _) = run_lineark_with_retry(&[
             "--api-token",
             &token,
@@ -3707,7 +3659,7 @@ mod online {
         token: &str,
         team_key: &str,
     ) -> ((String, IssueGuard), (String, IssueGuard)) {
-        let suffix = &uuid::Uuid::new_v4().to_string()[..8];
+        let suffix = &unique_id()[..8];
         let name_a = format!("[test] relation issue A {suffix}");
         let (out1, _) = run_lineark_with_retry(&[
             "--api-token",
@@ -3980,7 +3932,7 @@ mod online {
                 .issue_create::<Issue>(lineark_sdk::generated::inputs::IssueCreateInput {
                     title: Some(format!(
                         "[test] CLI comments_lifecycle {}",
-                        &uuid::Uuid::new_v4().to_string()[..8]
+                        &unique_id()[..8]
                     ))
                     .into(),
                     team_id,
@@ -4135,7 +4087,7 @@ mod online {
                 .issue_create::<Issue>(IssueCreateInput {
                     title: Some(format!(
                         "[test] CLI comments_resolve_with_resolving_comment {}",
-                        &uuid::Uuid::new_v4().to_string()[..8]
+                        &unique_id()[..8]
                     ))
                     .into(),
                     team_id,
@@ -4233,11 +4185,7 @@ mod online {
         let rt = tokio::runtime::Runtime::new().unwrap();
         let (issue_id, branch_name) = rt.block_on(async {
             let input = IssueCreateInput {
-                title: Some(format!(
-                    "[test] CLI find-branch {}",
-                    &uuid::Uuid::new_v4().to_string()[..8]
-                ))
-                .into(),
+                title: Some(format!("[test] CLI find-branch {}", &unique_id()[..8])).into(),
                 team_id,
                 priority: Some(4).into(),
                 ..Default::default()
@@ -4312,10 +4260,7 @@ mod online {
         let team_key = team.key.clone();
 
         // Create a project for the test (unique name to avoid conflicts).
-        let project_label = format!(
-            "[test] CLI project filter {}",
-            &uuid::Uuid::new_v4().to_string()[..8]
-        );
+        let project_label = format!("[test] CLI project filter {}", &unique_id()[..8]);
         let (output, _) = run_lineark_with_retry(&[
             "--api-token",
             &token,
@@ -4355,10 +4300,7 @@ mod online {
             "json",
             "issues",
             "create",
-            &format!(
-                "[test] project filter issue {}",
-                &uuid::Uuid::new_v4().to_string()[..8]
-            ),
+            &format!("[test] project filter issue {}", &unique_id()[..8]),
             "--team",
             &team_key,
             "--project",
@@ -4428,10 +4370,7 @@ mod online {
     #[test_with::runtime_ignore_if(no_online_test_token)]
     fn issues_create_with_estimate() {
         let token = test_token();
-        let unique_name = format!(
-            "[test] CLI estimate flag {}",
-            &uuid::Uuid::new_v4().to_string()[..8]
-        );
+        let unique_name = format!("[test] CLI estimate flag {}", &unique_id()[..8]);
 
         let team = create_test_team();
         let team_key = team.key.clone();
@@ -4553,10 +4492,7 @@ mod online {
     #[test_with::runtime_ignore_if(no_online_test_token)]
     fn issues_read_json_includes_estimate_field() {
         let token = test_token();
-        let unique_name = format!(
-            "[test] CLI estimate read {}",
-            &uuid::Uuid::new_v4().to_string()[..8]
-        );
+        let unique_name = format!("[test] CLI estimate read {}", &unique_id()[..8]);
 
         let team = create_test_team();
         let team_key = team.key.clone();
@@ -4634,11 +4570,7 @@ mod online {
         let issue_a = rt.block_on(async {
             client
                 .issue_create::<Issue>(IssueCreateInput {
-                    title: Some(format!(
-                        "[test] CLI batch-update A {}",
-                        &uuid::Uuid::new_v4().to_string()[..8]
-                    ))
-                    .into(),
+                    title: Some(format!("[test] CLI batch-update A {}", &unique_id()[..8])).into(),
                     team_id: team_id.clone(),
                     priority: Some(4).into(),
                     ..Default::default()
@@ -4655,11 +4587,7 @@ mod online {
         let issue_b = rt.block_on(async {
             client
                 .issue_create::<Issue>(IssueCreateInput {
-                    title: Some(format!(
-                        "[test] CLI batch-update B {}",
-                        &uuid::Uuid::new_v4().to_string()[..8]
-                    ))
-                    .into(),
+                    title: Some(format!("[test] CLI batch-update B {}", &unique_id()[..8])).into(),
                     team_id,
                     priority: Some(4).into(),
                     ..Default::default()
@@ -4716,7 +4644,7 @@ mod online {
                 .issue_create::<Issue>(IssueCreateInput {
                     title: Some(format!(
                         "[test] CLI batch-update status A {}",
-                        &uuid::Uuid::new_v4().to_string()[..8]
+                        &unique_id()[..8]
                     ))
                     .into(),
                     team_id: team_id.clone(),
@@ -4737,7 +4665,7 @@ mod online {
                 .issue_create::<Issue>(IssueCreateInput {
                     title: Some(format!(
                         "[test] CLI batch-update status B {}",
-                        &uuid::Uuid::new_v4().to_string()[..8]
+                        &unique_id()[..8]
                     ))
                     .into(),
                     team_id,